@@ -29,4 +29,6 @@
 #define USHRT_MAX 0xffffffffffffffff
 #define WORD_BIT 32
 
+#define SEM_VALUE_MAX 0x7fffffff
+
 #endif
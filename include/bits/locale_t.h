@@ -0,0 +1,6 @@
+#ifndef _BITS_LOCALE_T_H
+#define _BITS_LOCALE_T_H
+
+typedef void *locale_t;
+
+#endif /* _BITS_LOCALE_T_H */
@@ -3,4 +3,6 @@
 
 # define        h_addr  h_addr_list[0] /* Address, for backward compatibility.*/
 
+#define h_errno (*__h_errno_location())
+
 #endif /* _BITS_NETDB_H */
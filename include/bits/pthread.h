@@ -0,0 +1,29 @@
+#ifndef _BITS_PTHREAD_H
+#define _BITS_PTHREAD_H
+
+#define PTHREAD_CANCELED ((void *) -1)
+
+#define PTHREAD_COND_INITIALIZER {{0}}
+#define PTHREAD_MUTEX_INITIALIZER {{0}}
+#define PTHREAD_ONCE_INIT {{0}}
+#define PTHREAD_RWLOCK_INITIALIZER {{0}}
+
+// The entry is allocated on the stack of the caller, and linked into a list that
+// pthread_exit and cancellation walk. Both macros open or close a block, so they
+// must be used in pairs within the same lexical scope.
+#define pthread_cleanup_push(ROUTINE, ARG) do { \
+    struct { \
+        void (*routine)(void *); \
+        void *arg; \
+        void *prev; \
+    } __relibc_internal_pthread_ll_entry = { \
+        .routine = (void (*)(void *))(ROUTINE), \
+        .arg = (void *)(ARG), \
+    }; \
+    __relibc_internal_pthread_cleanup_push(&__relibc_internal_pthread_ll_entry);
+
+#define pthread_cleanup_pop(EXECUTE) \
+    __relibc_internal_pthread_cleanup_pop((EXECUTE)); \
+} while(0)
+
+#endif // _BITS_PTHREAD_H
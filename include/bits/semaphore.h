@@ -0,0 +1,16 @@
+#ifndef _BITS_SEMAPHORE_H
+#define _BITS_SEMAPHORE_H
+
+#define SEM_FAILED ((sem_t *) 0)
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+sem_t *sem_open(const char *name, int oflag, ...);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif
@@ -4,5 +4,16 @@
 #define SIG_DFL ((void (*)(int))0)
 #define SIG_IGN ((void (*)(int))1)
 #define SIG_ERR ((void (*)(int))-1)
+#define SIG_HOLD ((void (*)(int))2)
+
+#define si_pid __si_fields.__kill.si_pid
+#define si_uid __si_fields.__kill.si_uid
+#define si_value __si_fields.__kill.si_value
+#define si_status __si_fields.__sigchld.si_status
+#define si_utime __si_fields.__sigchld.si_utime
+#define si_stime __si_fields.__sigchld.si_stime
+#define si_addr __si_fields.__sigfault.si_addr
+#define si_band __si_fields.__sigpoll.si_band
+#define si_fd __si_fields.__sigpoll.si_fd
 
 #endif // _BITS_SIGNAL_H
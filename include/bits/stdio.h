@@ -10,6 +10,12 @@ typedef struct FILE FILE;
 // STDIO was loaded.
 #define FILE FILE
 
+// The callback types of fopencookie, which are function types as in glibc
+typedef ssize_t cookie_read_function_t(void *cookie, char *buf, size_t size);
+typedef ssize_t cookie_write_function_t(void *cookie, const char *buf, size_t size);
+typedef int cookie_seek_function_t(void *cookie, off_t *offset, int whence);
+typedef int cookie_close_function_t(void *cookie);
+
 #ifdef __cplusplus
 extern "C" {
 #endif
@@ -7,6 +7,8 @@ extern "C" {
 
 long double strtold(const char *nptr, char **endptr);
 
+#define MB_CUR_MAX (__ctype_get_mb_cur_max())
+
 #ifdef __cplusplus
 } // extern "C"
 #endif
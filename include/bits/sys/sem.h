@@ -0,0 +1,29 @@
+#ifndef _BITS_SYS_SEM_H
+#define _BITS_SYS_SEM_H
+
+struct semid_ds {
+    struct ipc_perm sem_perm;
+    time_t sem_otime;
+#ifdef __x86_64__
+    unsigned long __unused1;
+#endif
+    time_t sem_ctime;
+#ifdef __x86_64__
+    unsigned long __unused2;
+#endif
+    unsigned long sem_nsems;
+    unsigned long __unused3;
+    unsigned long __unused4;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+int semctl(int semid, int semnum, int cmd, ...);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif
@@ -11,6 +11,8 @@ int execl(const char *path, const char* argv0, ...);
 int execle(const char *path, const char* argv0, ...);
 int execlp(const char *file, const char* argv0, ...);
 
+pid_t vfork(void);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif
@@ -11,4 +11,19 @@
 #define __need_wint_t
 #define __need_NULL
 
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+int fwprintf(FILE * stream, const wchar_t * fmt, ...);
+int swprintf(wchar_t * s, size_t n, const wchar_t * fmt, ...);
+int wprintf(const wchar_t * fmt, ...);
+int fwscanf(FILE * stream, const wchar_t * fmt, ...);
+int swscanf(const wchar_t * input, const wchar_t * fmt, ...);
+int wscanf(const wchar_t * fmt, ...);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
 #endif /* _BITS_WCHAR_H */
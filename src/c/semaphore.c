@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <fcntl.h>
+#include <semaphore.h>
+
+// TODO: Can be implemented in rust when cbindgen supports "..." syntax
+
+sem_t *sys_sem_open(const char *name, int oflag, mode_t mode, unsigned value);
+
+sem_t *sem_open(const char *name, int oflag, ...) {
+    mode_t mode = 0;
+    unsigned value = 0;
+    if (oflag & O_CREAT) {
+        va_list ap;
+        va_start(ap, oflag);
+        mode = va_arg(ap, mode_t);
+        value = va_arg(ap, unsigned);
+        va_end(ap);
+    }
+    return sys_sem_open(name, oflag, mode, value);
+}
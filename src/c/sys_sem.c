@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <sys/sem.h>
+
+// TODO: Can be implemented in rust when cbindgen supports "..." syntax
+
+// Applications define union semun themselves, if they use it at all
+union __relibc_semun {
+    int val;
+    struct semid_ds *buf;
+    unsigned short *array;
+};
+
+int sys_semctl(int semid, int semnum, int cmd, unsigned long arg);
+
+int semctl(int semid, int semnum, int cmd, ...) {
+    unsigned long arg = 0;
+    va_list ap;
+    va_start(ap, cmd);
+    switch (cmd) {
+        case SETVAL:
+            arg = va_arg(ap, union __relibc_semun).val;
+            break;
+        case IPC_STAT:
+        case IPC_SET:
+        case GETALL:
+        case SETALL:
+            arg = (unsigned long) va_arg(ap, union __relibc_semun).buf;
+            break;
+    }
+    va_end(ap);
+    return sys_semctl(semid, semnum, cmd, arg);
+}
@@ -1044,7 +1044,7 @@ impl CStr {
     ///
     /// [`CString`]: struct.CString.html
     #[inline]
-    pub fn as_ptr(&self) -> *const c_char {
+    pub const fn as_ptr(&self) -> *const c_char {
         self.inner.as_ptr()
     }
 
@@ -2,12 +2,14 @@ use crate::{
     c_str::CStr,
     header::{
         fcntl::O_CREAT,
+        sys_mman::{MAP_PRIVATE, PROT_READ},
+        sys_stat::stat,
         unistd::{SEEK_CUR, SEEK_END, SEEK_SET},
     },
     io,
     platform::{types::*, Pal, Sys},
 };
-use core::ops::Deref;
+use core::{ops::Deref, ptr, slice};
 
 pub struct File {
     pub fd: c_int,
@@ -59,6 +61,29 @@ impl File {
         }
     }
 
+    /// Maps the whole file into memory, read-only. The mapping stays valid after the file is
+    /// closed.
+    pub fn map(&self) -> io::Result<Mapping> {
+        let mut st = stat::default();
+        if Sys::fstat(self.fd, &mut st) == -1 {
+            return Err(io::last_os_error());
+        }
+        let len = st.st_size as usize;
+        // Empty mappings are not allowed
+        if len == 0 {
+            return Ok(Mapping {
+                ptr: ptr::null_mut(),
+                len,
+            });
+        }
+
+        let ptr = unsafe { Sys::mmap(ptr::null_mut(), len, PROT_READ, MAP_PRIVATE, self.fd, 0) };
+        if ptr as isize == -1 {
+            return Err(io::last_os_error());
+        }
+        Ok(Mapping { ptr, len })
+    }
+
     /// Create a new file pointing to the same underlying descriptor. This file
     /// will know it's a "reference" and won't close the fd. It will, however,
     /// not prevent the original file from closing the fd.
@@ -144,3 +169,32 @@ impl Drop for File {
         }
     }
 }
+
+/// The contents of a file mapped into memory by `File::map`, which are unmapped when dropped.
+pub struct Mapping {
+    ptr: *mut c_void,
+    len: usize,
+}
+
+// The memory is only ever read
+unsafe impl Send for Mapping {}
+unsafe impl Sync for Mapping {}
+
+impl Deref for Mapping {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            let _ = unsafe { Sys::munmap(self.ptr, self.len) };
+        }
+    }
+}
@@ -1,15 +1,13 @@
 //! arpa/inet implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xns/arpainet.h.html
 
-use core::{
-    ptr, slice,
-    str::{self, FromStr},
-};
+use alloc::string::String;
+use core::{fmt::Write, ptr, str};
 
 use crate::{
     c_str::CStr,
     header::{
         errno::*,
-        netinet_in::{in_addr, in_addr_t, INADDR_NONE},
+        netinet_in::{in6_addr, in_addr, in_addr_t, INADDR_NONE},
         sys_socket::{constants::*, socklen_t},
     },
     platform::{self, types::*},
@@ -55,28 +53,22 @@ pub unsafe extern "C" fn inet_ntoa(addr: in_addr) -> *const c_char {
 
 #[no_mangle]
 pub unsafe extern "C" fn inet_pton(domain: c_int, src: *const c_char, dest: *mut c_void) -> c_int {
-    if domain != AF_INET {
-        platform::errno = EAFNOSUPPORT;
-        -1
-    } else {
-        let s_addr = slice::from_raw_parts_mut(
-            &mut (*(dest as *mut in_addr)).s_addr as *mut _ as *mut u8,
-            4,
-        );
-        let src_cstr = CStr::from_ptr(src);
-        let mut octets = str::from_utf8_unchecked(src_cstr.to_bytes()).split('.');
-        for i in 0..4 {
-            if let Some(n) = octets.next().and_then(|x| u8::from_str(x).ok()) {
-                s_addr[i] = n;
-            } else {
-                return 0;
-            }
-        }
-        if octets.next() == None {
-            1 // Success
-        } else {
-            0
+    let src = CStr::from_ptr(src).to_bytes();
+    let parsed = match domain {
+        AF_INET => parse_in4(src).map(|addr| {
+            (*(dest as *mut in_addr)).s_addr = u32::from_ne_bytes(addr);
+        }),
+        AF_INET6 => parse_in6(src).map(|addr| {
+            (*(dest as *mut in6_addr)).s6_addr = addr;
+        }),
+        _ => {
+            platform::errno = EAFNOSUPPORT;
+            return -1;
         }
+    };
+    match parsed {
+        Some(()) => 1,
+        None => 0,
     }
 }
 
@@ -87,21 +79,164 @@ pub unsafe extern "C" fn inet_ntop(
     dest: *mut c_char,
     size: socklen_t,
 ) -> *const c_char {
-    if domain != AF_INET {
-        platform::errno = EAFNOSUPPORT;
-        ptr::null()
-    } else if size < 16 {
+    let addr = match domain {
+        AF_INET => format_in4((*(src as *const in_addr)).s_addr.to_ne_bytes()),
+        AF_INET6 => format_in6(&(*(src as *const in6_addr)).s6_addr),
+        _ => {
+            platform::errno = EAFNOSUPPORT;
+            return ptr::null();
+        }
+    };
+    if addr.len() >= size as usize {
         platform::errno = ENOSPC;
-        ptr::null()
-    } else {
-        let s_addr = slice::from_raw_parts(
-            &(*(src as *const in_addr)).s_addr as *const _ as *const u8,
-            4,
-        );
-        let addr = format!("{}.{}.{}.{}\0", s_addr[0], s_addr[1], s_addr[2], s_addr[3]);
-        ptr::copy(addr.as_ptr() as *const c_char, dest, addr.len());
-        dest
+        return ptr::null();
+    }
+    ptr::copy_nonoverlapping(addr.as_ptr() as *const c_char, dest, addr.len());
+    *dest.add(addr.len()) = 0;
+    dest
+}
+
+/// Parses the dotted decimal form of an IPv4 address, with exactly four parts
+pub(crate) fn parse_in4(s: &[u8]) -> Option<[u8; 4]> {
+    let mut addr = [0; 4];
+    let mut parts = s.split(|&b| b == b'.');
+    for byte in addr.iter_mut() {
+        let part = parts.next()?;
+        if part.is_empty() || part.len() > 3 || !part.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        *byte = str::from_utf8(part).ok()?.parse().ok()?;
+    }
+    match parts.next() {
+        Some(_) => None,
+        None => Some(addr),
+    }
+}
+
+/// Parses the text form of an IPv6 address from RFC 4291: eight groups of up to four hex
+/// digits separated by colons, where one run of zero groups can be written as "::" and the last
+/// two groups can be written as an IPv4 address
+pub(crate) fn parse_in6(s: &[u8]) -> Option<[u8; 16]> {
+    let mut groups = [0u16; 8];
+    let mut len = 0;
+    // Where "::" was, in groups
+    let mut gap = None;
+
+    let mut i = 0;
+    if s.starts_with(b"::") {
+        gap = Some(0);
+        i = 2;
+    }
+    while i < s.len() {
+        let end = s[i..]
+            .iter()
+            .position(|&b| b == b':')
+            .map_or(s.len(), |end| i + end);
+        let part = &s[i..end];
+
+        if part.contains(&b'.') {
+            if end != s.len() || len > 6 {
+                return None;
+            }
+            let addr = parse_in4(part)?;
+            groups[len] = u16::from_be_bytes([addr[0], addr[1]]);
+            groups[len + 1] = u16::from_be_bytes([addr[2], addr[3]]);
+            len += 2;
+            break;
+        }
+        if len == 8 || part.is_empty() || part.len() > 4 || !part.iter().all(u8::is_ascii_hexdigit)
+        {
+            return None;
+        }
+        groups[len] = u16::from_str_radix(str::from_utf8(part).ok()?, 16).ok()?;
+        len += 1;
+
+        i = end;
+        if i < s.len() {
+            i += 1;
+            if s.get(i) == Some(&b':') {
+                if gap.is_some() {
+                    return None;
+                }
+                gap = Some(len);
+                i += 1;
+            } else if i == s.len() {
+                return None;
+            }
+        }
+    }
+
+    match gap {
+        // "::" has to stand for at least one group
+        Some(gap) if len < 8 => {
+            let moved = len - gap;
+            groups.copy_within(gap..len, 8 - moved);
+            for group in &mut groups[gap..8 - moved] {
+                *group = 0;
+            }
+        }
+        None if len == 8 => (),
+        _ => return None,
+    }
+
+    let mut addr = [0; 16];
+    for (bytes, group) in addr.chunks_mut(2).zip(groups.iter()) {
+        bytes.copy_from_slice(&group.to_be_bytes());
+    }
+    Some(addr)
+}
+
+pub(crate) fn format_in4(addr: [u8; 4]) -> String {
+    format!("{}.{}.{}.{}", addr[0], addr[1], addr[2], addr[3])
+}
+
+/// Formats an IPv6 address the way RFC 5952 recommends, with the longest run of two or more zero
+/// groups left out. Like glibc, IPv4-mapped and IPv4-compatible addresses end in the IPv4 address.
+pub(crate) fn format_in6(addr: &[u8; 16]) -> String {
+    let mut groups = [0u16; 8];
+    for (group, bytes) in groups.iter_mut().zip(addr.chunks(2)) {
+        *group = u16::from_be_bytes([bytes[0], bytes[1]]);
+    }
+
+    // The first of the longest runs of zero groups
+    let (mut gap, mut gap_len) = (0, 0);
+    let mut i = 0;
+    while i < 8 {
+        let len = groups[i..].iter().take_while(|&&group| group == 0).count();
+        if len > gap_len {
+            gap = i;
+            gap_len = len;
+        }
+        i += len.max(1);
+    }
+    if gap_len < 2 {
+        gap_len = 0;
+    }
+
+    let ipv4 = gap == 0 && (gap_len == 6 || (gap_len == 5 && groups[5] == 0xffff));
+    let end = if ipv4 { 6 } else { 8 };
+
+    let mut s = String::new();
+    let mut i = 0;
+    while i < end {
+        if gap_len > 0 && i == gap {
+            s.push_str("::");
+            i += gap_len;
+            continue;
+        }
+        if !s.is_empty() && !s.ends_with(':') {
+            s.push(':');
+        }
+        write!(s, "{:x}", groups[i]).unwrap();
+        i += 1;
+    }
+    if ipv4 {
+        if !s.ends_with(':') {
+            s.push(':');
+        }
+        s.push_str(&format_in4([addr[12], addr[13], addr[14], addr[15]]));
     }
+    s
 }
 
 #[no_mangle]
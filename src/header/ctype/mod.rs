@@ -1,77 +1,230 @@
-//! ctype implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/ctype.h.html
+//! ctype implementation for Redox, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/ctype.h.html
 
-use crate::platform::types::*;
+use crate::{
+    header::{
+        locale::{self, locale_t, Codeset, Locale},
+        wctype::{self, wctrans_t, wctype_t},
+    },
+    platform::types::*,
+};
+
+/// Bytes above 0x7F are only characters by themselves in single-byte locales, where they are
+/// classified by the Unicode code point they stand for.
+fn upper_half(c: c_int, locale: &Locale) -> Option<wint_t> {
+    if c >= 0x80 && c <= 0xff && locale.ctype().codeset == Codeset::Latin1 {
+        Some(c as wint_t)
+    } else {
+        None
+    }
+}
+
+fn ascii(c: c_int) -> Option<u8> {
+    if c >= 0 && c < 0x80 {
+        Some(c as u8)
+    } else {
+        None
+    }
+}
+
+fn classify(c: c_int, locale: &Locale, class: wctype_t, ascii_class: fn(&u8) -> bool) -> c_int {
+    match upper_half(c, locale) {
+        Some(wc) => c_int::from(wctype::is_class(wc, class, locale)),
+        None => c_int::from(ascii(c).map_or(false, |b| ascii_class(&b))),
+    }
+}
 
 #[no_mangle]
 pub extern "C" fn isalnum(c: c_int) -> c_int {
-    c_int::from(isdigit(c) != 0 || isalpha(c) != 0)
+    classify(
+        c,
+        locale::current(),
+        wctype::WCTYPE_ALNUM,
+        u8::is_ascii_alphanumeric,
+    )
 }
 
 #[no_mangle]
-pub extern "C" fn isalpha(c: c_int) -> c_int {
-    c_int::from(islower(c) != 0 || isupper(c) != 0)
+pub unsafe extern "C" fn isalnum_l(c: c_int, locale: locale_t) -> c_int {
+    classify(
+        c,
+        locale::get(locale),
+        wctype::WCTYPE_ALNUM,
+        u8::is_ascii_alphanumeric,
+    )
 }
 
 #[no_mangle]
-pub extern "C" fn isascii(c: c_int) -> c_int {
-    c_int::from((c & !0x7f) == 0)
+pub extern "C" fn isalpha(c: c_int) -> c_int {
+    classify(
+        c,
+        locale::current(),
+        wctype::WCTYPE_ALPHA,
+        u8::is_ascii_alphabetic,
+    )
 }
 
 #[no_mangle]
-pub extern "C" fn isblank(c: c_int) -> c_int {
-    c_int::from(c == c_int::from(b' ') || c == c_int::from(b'\t'))
+pub unsafe extern "C" fn isalpha_l(c: c_int, locale: locale_t) -> c_int {
+    classify(
+        c,
+        locale::get(locale),
+        wctype::WCTYPE_ALPHA,
+        u8::is_ascii_alphabetic,
+    )
 }
 
 #[no_mangle]
 pub extern "C" fn iscntrl(c: c_int) -> c_int {
-    c_int::from((c >= 0x00 && c <= 0x1f) || c == 0x7f)
+    classify(
+        c,
+        locale::current(),
+        wctype::WCTYPE_CNTRL,
+        u8::is_ascii_control,
+    )
 }
 
 #[no_mangle]
-pub extern "C" fn isdigit(c: c_int) -> c_int {
-    c_int::from(c >= c_int::from(b'0') && c <= c_int::from(b'9'))
+pub unsafe extern "C" fn iscntrl_l(c: c_int, locale: locale_t) -> c_int {
+    classify(
+        c,
+        locale::get(locale),
+        wctype::WCTYPE_CNTRL,
+        u8::is_ascii_control,
+    )
 }
 
 #[no_mangle]
 pub extern "C" fn isgraph(c: c_int) -> c_int {
-    c_int::from(c >= 0x21 && c <= 0x7e)
+    classify(
+        c,
+        locale::current(),
+        wctype::WCTYPE_GRAPH,
+        u8::is_ascii_graphic,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn isgraph_l(c: c_int, locale: locale_t) -> c_int {
+    classify(
+        c,
+        locale::get(locale),
+        wctype::WCTYPE_GRAPH,
+        u8::is_ascii_graphic,
+    )
 }
 
 #[no_mangle]
 pub extern "C" fn islower(c: c_int) -> c_int {
-    c_int::from(c >= c_int::from(b'a') && c <= c_int::from(b'z'))
+    classify(
+        c,
+        locale::current(),
+        wctype::WCTYPE_LOWER,
+        u8::is_ascii_lowercase,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn islower_l(c: c_int, locale: locale_t) -> c_int {
+    classify(
+        c,
+        locale::get(locale),
+        wctype::WCTYPE_LOWER,
+        u8::is_ascii_lowercase,
+    )
 }
 
 #[no_mangle]
 pub extern "C" fn isprint(c: c_int) -> c_int {
-    c_int::from(c >= 0x20 && c < 0x7f)
+    classify(c, locale::current(), wctype::WCTYPE_PRINT, is_ascii_print)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn isprint_l(c: c_int, locale: locale_t) -> c_int {
+    classify(c, locale::get(locale), wctype::WCTYPE_PRINT, is_ascii_print)
 }
 
 #[no_mangle]
 pub extern "C" fn ispunct(c: c_int) -> c_int {
-    c_int::from(
-        (c >= c_int::from(b'!') && c <= c_int::from(b'/'))
-            || (c >= c_int::from(b':') && c <= c_int::from(b'@'))
-            || (c >= c_int::from(b'[') && c <= c_int::from(b'`'))
-            || (c >= c_int::from(b'{') && c <= c_int::from(b'~')),
+    classify(
+        c,
+        locale::current(),
+        wctype::WCTYPE_PUNCT,
+        u8::is_ascii_punctuation,
     )
 }
 
 #[no_mangle]
-pub extern "C" fn isspace(c: c_int) -> c_int {
-    c_int::from(
-        c == c_int::from(b' ')
-            || c == c_int::from(b'\t')
-            || c == c_int::from(b'\n')
-            || c == c_int::from(b'\r')
-            || c == 0x0b
-            || c == 0x0c,
+pub unsafe extern "C" fn ispunct_l(c: c_int, locale: locale_t) -> c_int {
+    classify(
+        c,
+        locale::get(locale),
+        wctype::WCTYPE_PUNCT,
+        u8::is_ascii_punctuation,
     )
 }
 
+#[no_mangle]
+pub extern "C" fn isspace(c: c_int) -> c_int {
+    classify(c, locale::current(), wctype::WCTYPE_SPACE, is_ascii_space)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn isspace_l(c: c_int, locale: locale_t) -> c_int {
+    classify(c, locale::get(locale), wctype::WCTYPE_SPACE, is_ascii_space)
+}
+
 #[no_mangle]
 pub extern "C" fn isupper(c: c_int) -> c_int {
-    c_int::from(c >= c_int::from(b'A') && c <= c_int::from(b'Z'))
+    classify(
+        c,
+        locale::current(),
+        wctype::WCTYPE_UPPER,
+        u8::is_ascii_uppercase,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn isupper_l(c: c_int, locale: locale_t) -> c_int {
+    classify(
+        c,
+        locale::get(locale),
+        wctype::WCTYPE_UPPER,
+        u8::is_ascii_uppercase,
+    )
+}
+
+fn is_ascii_print(b: &u8) -> bool {
+    *b >= 0x20 && *b < 0x7f
+}
+
+fn is_ascii_space(b: &u8) -> bool {
+    // Unlike u8::is_ascii_whitespace, this includes the vertical tab
+    matches!(*b, b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c)
+}
+
+#[no_mangle]
+pub extern "C" fn isascii(c: c_int) -> c_int {
+    c_int::from((c & !0x7f) == 0)
+}
+
+#[no_mangle]
+pub extern "C" fn isblank(c: c_int) -> c_int {
+    c_int::from(c == c_int::from(b' ') || c == c_int::from(b'\t'))
+}
+
+#[no_mangle]
+pub extern "C" fn isblank_l(c: c_int, _locale: locale_t) -> c_int {
+    isblank(c)
+}
+
+#[no_mangle]
+pub extern "C" fn isdigit(c: c_int) -> c_int {
+    c_int::from(c >= c_int::from(b'0') && c <= c_int::from(b'9'))
+}
+
+#[no_mangle]
+pub extern "C" fn isdigit_l(c: c_int, _locale: locale_t) -> c_int {
+    isdigit(c)
 }
 
 #[no_mangle]
@@ -79,6 +232,11 @@ pub extern "C" fn isxdigit(c: c_int) -> c_int {
     c_int::from(isdigit(c) != 0 || (c | 32 >= c_int::from(b'a') && c | 32 <= c_int::from(b'f')))
 }
 
+#[no_mangle]
+pub extern "C" fn isxdigit_l(c: c_int, _locale: locale_t) -> c_int {
+    isxdigit(c)
+}
+
 #[no_mangle]
 /// The comment in musl:
 /// "nonsense function that should NEVER be used!"
@@ -86,20 +244,37 @@ pub extern "C" fn toascii(c: c_int) -> c_int {
     c & 0x7f
 }
 
+fn convert_case(c: c_int, locale: &Locale, trans: wctrans_t) -> c_int {
+    let wc = match (upper_half(c, locale), ascii(c)) {
+        (Some(wc), _) => wc,
+        (None, Some(b)) => wint_t::from(b),
+        (None, None) => return c,
+    };
+    // Characters whose other case is not a single byte stay as they are, like 'i' in Turkish
+    // UTF-8 locales, where its upper case is the dotted capital I
+    match wctype::map(wc, trans, locale) {
+        converted if converted < 0x80 => converted as c_int,
+        converted if upper_half(converted as c_int, locale).is_some() => converted as c_int,
+        _ => c,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn tolower(c: c_int) -> c_int {
-    if isupper(c) != 0 {
-        c | 0x20
-    } else {
-        c
-    }
+    convert_case(c, locale::current(), wctype::WCTRANS_TOLOWER)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tolower_l(c: c_int, locale: locale_t) -> c_int {
+    convert_case(c, locale::get(locale), wctype::WCTRANS_TOLOWER)
 }
 
 #[no_mangle]
 pub extern "C" fn toupper(c: c_int) -> c_int {
-    if islower(c) != 0 {
-        c & !0x20
-    } else {
-        c
-    }
+    convert_case(c, locale::current(), wctype::WCTRANS_TOUPPER)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn toupper_l(c: c_int, locale: locale_t) -> c_int {
+    convert_case(c, locale::get(locale), wctype::WCTRANS_TOUPPER)
 }
@@ -3,6 +3,7 @@
 use crate::{
     c_str::CStr,
     platform::{types::*, Pal, Sys},
+    pthread,
 };
 
 pub use self::sys::*;
@@ -48,7 +49,7 @@ pub extern "C" fn sys_fcntl(fildes: c_int, cmd: c_int, arg: c_int) -> c_int {
 #[no_mangle]
 pub unsafe extern "C" fn sys_open(path: *const c_char, oflag: c_int, mode: mode_t) -> c_int {
     let path = CStr::from_ptr(path);
-    Sys::open(path, oflag, mode)
+    pthread::cancellation_point(|| Sys::open(path, oflag, mode))
 }
 
 #[no_mangle]
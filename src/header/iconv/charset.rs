@@ -0,0 +1,528 @@
+//! The character sets iconv() converts between, and how each of them encodes characters
+
+use crate::{
+    header::{
+        locale::{self, Codeset},
+        wchar::{mbstate_t, utf8},
+    },
+    platform::types::*,
+};
+
+use super::{gb18030, jis0208, single_byte};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Order {
+    Big,
+    Little,
+}
+
+impl Order {
+    #[cfg(target_endian = "big")]
+    const NATIVE: Order = Order::Big;
+    #[cfg(target_endian = "little")]
+    const NATIVE: Order = Order::Little;
+
+    fn swapped(self) -> Order {
+        match self {
+            Order::Big => Order::Little,
+            Order::Little => Order::Big,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Charset {
+    Ascii,
+    Latin1,
+    /// ASCII, and the characters from 0x80 up in a table, where 0 is a byte that is none
+    SingleByte(&'static [u16; 128]),
+    Utf8,
+    /// UTF-16 in the given byte order. If `bom` is set, a byte order mark at the start of the
+    /// input can change it, and one is written before the output.
+    Utf16 {
+        order: Order,
+        bom: bool,
+    },
+    /// UTF-16 without surrogates, so only the BMP
+    Ucs2(Order),
+    /// UTF-32, with a byte order mark like UTF-16
+    Utf32 {
+        order: Order,
+        bom: bool,
+    },
+    Gb18030,
+    /// GB18030 without the four-byte sequences
+    Gbk,
+    /// The part of GBK with both bytes from 0xa1, as EUC-CN encodes it
+    Gb2312,
+    ShiftJis,
+    EucJp,
+}
+
+/// Why a character couldn't be read
+pub enum Invalid {
+    /// The input isn't in the character set
+    Sequence,
+    /// The input ends in the middle of a character
+    Incomplete,
+}
+
+/// Why a character couldn't be written
+pub enum Unwritable {
+    /// The character set has no such character
+    Missing,
+    /// The output doesn't have room for it
+    Full,
+}
+
+impl Charset {
+    /// Finds a character set by a name like "UTF-8" or "ISO_8859-1". Case and punctuation are
+    /// ignored, and an empty name is the one of the current locale.
+    pub fn find(name: &[u8]) -> Option<Charset> {
+        let mut normal = [0; 16];
+        let mut len = 0;
+        for &b in name.iter().filter(|b| b.is_ascii_alphanumeric()) {
+            *normal.get_mut(len)? = b.to_ascii_uppercase();
+            len += 1;
+        }
+
+        let utf16 = |order, bom| Some(Charset::Utf16 { order, bom });
+        let utf32 = |order, bom| Some(Charset::Utf32 { order, bom });
+        match &normal[..len] {
+            b"" | b"CHAR" => Some(match locale::current().ctype().codeset {
+                Codeset::Ascii => Charset::Ascii,
+                Codeset::Latin1 => Charset::Latin1,
+                Codeset::Utf8 => Charset::Utf8,
+            }),
+            b"ASCII" | b"USASCII" | b"ANSIX341968" | b"646" => Some(Charset::Ascii),
+            b"ISO88591" | b"LATIN1" | b"L1" => Some(Charset::Latin1),
+            b"ISO88592" | b"LATIN2" | b"L2" => Some(Charset::SingleByte(&single_byte::ISO_8859_2)),
+            b"ISO88593" | b"LATIN3" | b"L3" => Some(Charset::SingleByte(&single_byte::ISO_8859_3)),
+            b"ISO88594" | b"LATIN4" | b"L4" => Some(Charset::SingleByte(&single_byte::ISO_8859_4)),
+            b"ISO88595" | b"CYRILLIC" => Some(Charset::SingleByte(&single_byte::ISO_8859_5)),
+            b"ISO88596" | b"ARABIC" => Some(Charset::SingleByte(&single_byte::ISO_8859_6)),
+            b"ISO88597" | b"GREEK" => Some(Charset::SingleByte(&single_byte::ISO_8859_7)),
+            b"ISO88598" | b"HEBREW" => Some(Charset::SingleByte(&single_byte::ISO_8859_8)),
+            b"ISO88599" | b"LATIN5" | b"L5" => Some(Charset::SingleByte(&single_byte::ISO_8859_9)),
+            b"ISO885910" | b"LATIN6" | b"L6" => {
+                Some(Charset::SingleByte(&single_byte::ISO_8859_10))
+            }
+            b"ISO885911" | b"TIS620" => Some(Charset::SingleByte(&single_byte::ISO_8859_11)),
+            b"ISO885913" | b"LATIN7" | b"L7" => {
+                Some(Charset::SingleByte(&single_byte::ISO_8859_13))
+            }
+            b"ISO885914" | b"LATIN8" | b"L8" => {
+                Some(Charset::SingleByte(&single_byte::ISO_8859_14))
+            }
+            b"ISO885915" | b"LATIN9" | b"L9" => {
+                Some(Charset::SingleByte(&single_byte::ISO_8859_15))
+            }
+            b"ISO885916" | b"LATIN10" | b"L10" => {
+                Some(Charset::SingleByte(&single_byte::ISO_8859_16))
+            }
+            b"WINDOWS1252" | b"CP1252" => Some(Charset::SingleByte(&single_byte::WINDOWS_1252)),
+            b"UTF8" => Some(Charset::Utf8),
+            // Like glibc, text without a byte order mark is taken to be in the native order
+            b"UTF16" => utf16(Order::NATIVE, true),
+            b"UTF16BE" => utf16(Order::Big, false),
+            b"UTF16LE" => utf16(Order::Little, false),
+            b"UCS2" => Some(Charset::Ucs2(Order::NATIVE)),
+            b"UCS2BE" => Some(Charset::Ucs2(Order::Big)),
+            b"UCS2LE" => Some(Charset::Ucs2(Order::Little)),
+            b"UTF32" => utf32(Order::NATIVE, true),
+            b"UTF32BE" | b"UCS4" | b"UCS4BE" => utf32(Order::Big, false),
+            b"UTF32LE" | b"UCS4LE" => utf32(Order::Little, false),
+            b"WCHART" => utf32(Order::NATIVE, false),
+            b"GB18030" => Some(Charset::Gb18030),
+            b"GBK" | b"CP936" => Some(Charset::Gbk),
+            b"GB2312" | b"EUCCN" => Some(Charset::Gb2312),
+            b"SHIFTJIS" | b"SJIS" => Some(Charset::ShiftJis),
+            b"EUCJP" => Some(Charset::EucJp),
+            _ => None,
+        }
+    }
+
+    /// Reads the character at the start of `input`, returning it and how many bytes it takes
+    /// up. A byte order mark at the start of UTF-16 or UTF-32 is read as no character.
+    pub fn decode(&mut self, input: &[u8]) -> Result<(Option<u32>, usize), Invalid> {
+        let b = input[0];
+        let c = match self {
+            Charset::Ascii if b < 0x80 => b as u32,
+            Charset::Latin1 => b as u32,
+            Charset::SingleByte(_)
+            | Charset::Gb18030
+            | Charset::Gbk
+            | Charset::Gb2312
+            | Charset::ShiftJis
+            | Charset::EucJp
+                if b < 0x80 =>
+            {
+                b as u32
+            }
+            Charset::SingleByte(table) => match table[b as usize - 0x80] {
+                0 => return Err(Invalid::Sequence),
+                c => c as u32,
+            },
+            Charset::Utf8 => {
+                let mut wc = 0;
+                let mut state = mbstate_t::new();
+                return match unsafe {
+                    utf8::mbrtowc(&mut wc, input.as_ptr().cast(), input.len(), &mut state)
+                } {
+                    // The length of a null character is left out
+                    0 => Ok((Some(0), 1)),
+                    len if len == -1isize as usize => Err(Invalid::Sequence),
+                    len if len == -2isize as usize => Err(Invalid::Incomplete),
+                    len => Ok((Some(wc as u32), len)),
+                };
+            }
+            Charset::Utf16 { order, bom } => {
+                let unit = read(input, 2, *order)?;
+                if *bom {
+                    // Whatever comes first, no other byte order mark is looked for
+                    *bom = false;
+                    match unit {
+                        0xfeff => return Ok((None, 2)),
+                        0xfffe => {
+                            *order = order.swapped();
+                            return Ok((None, 2));
+                        }
+                        _ => (),
+                    }
+                }
+                return match unit {
+                    0xd800..=0xdbff => match read(&input[2..], 2, *order)? {
+                        low @ 0xdc00..=0xdfff => {
+                            Ok((Some(0x10000 + ((unit - 0xd800) << 10 | (low - 0xdc00))), 4))
+                        }
+                        _ => Err(Invalid::Sequence),
+                    },
+                    0xdc00..=0xdfff => Err(Invalid::Sequence),
+                    _ => Ok((Some(unit), 2)),
+                };
+            }
+            Charset::Ucs2(order) => match read(input, 2, *order)? {
+                0xd800..=0xdfff => return Err(Invalid::Sequence),
+                c => return Ok((Some(c), 2)),
+            },
+            Charset::Utf32 { order, bom } => {
+                let c = read(input, 4, *order)?;
+                if *bom {
+                    *bom = false;
+                    match c {
+                        0xfeff => return Ok((None, 4)),
+                        0xfffe0000 => {
+                            *order = order.swapped();
+                            return Ok((None, 4));
+                        }
+                        _ => (),
+                    }
+                }
+                return match c {
+                    0xd800..=0xdfff | 0x110000..=0xffffffff => Err(Invalid::Sequence),
+                    _ => Ok((Some(c), 4)),
+                };
+            }
+            Charset::Gb18030 | Charset::Gbk | Charset::Gb2312 => return self.decode_gb(input),
+            Charset::ShiftJis => return decode_shift_jis(input),
+            Charset::EucJp => return decode_euc_jp(input),
+            Charset::Ascii => return Err(Invalid::Sequence),
+        };
+        Ok((Some(c), 1))
+    }
+
+    fn decode_gb(&self, input: &[u8]) -> Result<(Option<u32>, usize), Invalid> {
+        let lead = input[0];
+        if lead == 0x80 || lead == 0xff {
+            return Err(Invalid::Sequence);
+        }
+        let trail = *input.get(1).ok_or(Invalid::Incomplete)?;
+        match (self, trail) {
+            (Charset::Gb2312, _) if !in_gb2312(lead, trail) => Err(Invalid::Sequence),
+            (Charset::Gb18030, 0x30..=0x39) => {
+                if input.len() < 4 {
+                    return Err(Invalid::Incomplete);
+                }
+                let (third, fourth) = (input[2], input[3]);
+                if !(0x81..=0xfe).contains(&third) || !(0x30..=0x39).contains(&fourth) {
+                    return Err(Invalid::Sequence);
+                }
+                let index = (((lead as u32 - 0x81) * 10 + (trail as u32 - 0x30)) * 126
+                    + (third as u32 - 0x81))
+                    * 10
+                    + (fourth as u32 - 0x30);
+                // Sequences from 90 30 81 30 count through the planes past the BMP
+                let c = match index {
+                    0..=39419 => {
+                        let run = match gb18030::FOUR_BYTE
+                            .binary_search_by_key(&index, |&(start, _)| start as u32)
+                        {
+                            Ok(i) => i,
+                            Err(i) => i - 1,
+                        };
+                        let (start, c) = gb18030::FOUR_BYTE[run];
+                        c as u32 + (index - start as u32)
+                    }
+                    189000..=1237575 => index - 189000 + 0x10000,
+                    _ => return Err(Invalid::Sequence),
+                };
+                Ok((Some(c), 4))
+            }
+            (_, 0x40..=0x7e) | (_, 0x80..=0xfe) => {
+                let column = if trail < 0x7f {
+                    trail - 0x40
+                } else {
+                    trail - 0x41
+                };
+                match gb18030::TWO_BYTE[(lead as usize - 0x81) * 190 + column as usize] {
+                    0 => Err(Invalid::Sequence),
+                    c => Ok((Some(c as u32), 2)),
+                }
+            }
+            _ => Err(Invalid::Sequence),
+        }
+    }
+
+    /// Writes `c` to the start of `output`, returning how many bytes it takes up. If a byte
+    /// order mark is still to be written, it comes first.
+    pub fn encode(&mut self, c: u32, output: &mut [u8]) -> Result<usize, Unwritable> {
+        let mut buf = [0; 8];
+        let len = match self {
+            Charset::Ascii if c < 0x80 => put(&mut buf, c as u8),
+            Charset::Latin1 if c < 0x100 => put(&mut buf, c as u8),
+            Charset::SingleByte(_)
+            | Charset::Gb18030
+            | Charset::Gbk
+            | Charset::Gb2312
+            | Charset::ShiftJis
+            | Charset::EucJp
+                if c < 0x80 =>
+            {
+                put(&mut buf, c as u8)
+            }
+            Charset::SingleByte(table) => {
+                match table.iter().position(|&t| t != 0 && t as u32 == c) {
+                    Some(i) => put(&mut buf, 0x80 + i as u8),
+                    None => return Err(Unwritable::Missing),
+                }
+            }
+            Charset::Utf8 => {
+                let mut state = mbstate_t::new();
+                match unsafe { utf8::wcrtomb(buf.as_mut_ptr().cast(), c as wchar_t, &mut state) } {
+                    len if len == -1isize as usize => return Err(Unwritable::Missing),
+                    len => len,
+                }
+            }
+            Charset::Utf16 { order, bom } => {
+                let mut len = 0;
+                if *bom {
+                    len += write(&mut buf, 0xfeff, 2, *order);
+                }
+                match c {
+                    0xd800..=0xdfff | 0x110000..=0xffffffff => return Err(Unwritable::Missing),
+                    0x10000..=0x10ffff => {
+                        let c = c - 0x10000;
+                        len += write(&mut buf[len..], 0xd800 | c >> 10, 2, *order);
+                        len + write(&mut buf[len..], 0xdc00 | (c & 0x3ff), 2, *order)
+                    }
+                    _ => len + write(&mut buf[len..], c, 2, *order),
+                }
+            }
+            Charset::Ucs2(order) => match c {
+                0xd800..=0xdfff | 0x10000..=0xffffffff => return Err(Unwritable::Missing),
+                _ => write(&mut buf, c, 2, *order),
+            },
+            Charset::Utf32 { order, bom } => {
+                let mut len = 0;
+                if *bom {
+                    len += write(&mut buf, 0xfeff, 4, *order);
+                }
+                match c {
+                    0xd800..=0xdfff | 0x110000..=0xffffffff => return Err(Unwritable::Missing),
+                    _ => len + write(&mut buf[len..], c, 4, *order),
+                }
+            }
+            Charset::Gb18030 | Charset::Gbk | Charset::Gb2312 => self.encode_gb(c, &mut buf)?,
+            Charset::ShiftJis => encode_shift_jis(c, &mut buf)?,
+            Charset::EucJp => encode_euc_jp(c, &mut buf)?,
+            Charset::Ascii | Charset::Latin1 => return Err(Unwritable::Missing),
+        };
+
+        let output = output.get_mut(..len).ok_or(Unwritable::Full)?;
+        output.copy_from_slice(&buf[..len]);
+        match self {
+            Charset::Utf16 { bom, .. } | Charset::Utf32 { bom, .. } => *bom = false,
+            _ => (),
+        }
+        Ok(len)
+    }
+
+    fn encode_gb(&self, c: u32, buf: &mut [u8]) -> Result<usize, Unwritable> {
+        if let Some(i) = find(&gb18030::TWO_BYTE, &gb18030::TWO_BYTE_SORTED, c) {
+            let lead = 0x81 + i / 190;
+            let column = i % 190;
+            let trail = if column < 0x3f {
+                0x40 + column
+            } else {
+                0x41 + column
+            };
+            if let Charset::Gb2312 = self {
+                if !in_gb2312(lead as u8, trail as u8) {
+                    return Err(Unwritable::Missing);
+                }
+            }
+            buf[0] = lead as u8;
+            buf[1] = trail as u8;
+            return Ok(2);
+        }
+
+        let index = match (self, c) {
+            (Charset::Gb18030, 0..=0xd7ff) | (Charset::Gb18030, 0xe000..=0xffff) => {
+                // Every character of the BMP without a shorter sequence is in one of the runs
+                let run = match gb18030::FOUR_BYTE.binary_search_by_key(&c, |&(_, c)| c as u32) {
+                    Ok(i) => i,
+                    Err(i) => i - 1,
+                };
+                let (start, first) = gb18030::FOUR_BYTE[run];
+                start as u32 + (c - first as u32)
+            }
+            (Charset::Gb18030, 0x10000..=0x10ffff) => c - 0x10000 + 189000,
+            _ => return Err(Unwritable::Missing),
+        };
+        buf[0] = (0x81 + index / 12600) as u8;
+        buf[1] = (0x30 + index / 1260 % 10) as u8;
+        buf[2] = (0x81 + index / 10 % 126) as u8;
+        buf[3] = (0x30 + index % 10) as u8;
+        Ok(4)
+    }
+}
+
+fn in_gb2312(lead: u8, trail: u8) -> bool {
+    if lead < 0xa1 || lead == 0xff || trail < 0xa1 || trail == 0xff {
+        return false;
+    }
+    let i = (lead as usize - 0xa1) * 94 + (trail as usize - 0xa1);
+    gb18030::GB2312[i / 8] >> (i % 8) & 1 == 1
+}
+
+/// Reads a UTF-16 or UTF-32 code unit of `size` bytes
+fn read(input: &[u8], size: usize, order: Order) -> Result<u32, Invalid> {
+    let bytes = input.get(..size).ok_or(Invalid::Incomplete)?;
+    Ok(match order {
+        Order::Big => bytes.iter().fold(0, |unit, &b| unit << 8 | b as u32),
+        Order::Little => bytes.iter().rev().fold(0, |unit, &b| unit << 8 | b as u32),
+    })
+}
+
+/// Writes a code unit of `size` bytes, returning the size
+fn write(buf: &mut [u8], unit: u32, size: usize, order: Order) -> usize {
+    for (i, b) in buf[..size].iter_mut().enumerate() {
+        let shift = match order {
+            Order::Big => 8 * (size - 1 - i),
+            Order::Little => 8 * i,
+        };
+        *b = (unit >> shift) as u8;
+    }
+    size
+}
+
+fn put(buf: &mut [u8], b: u8) -> usize {
+    buf[0] = b;
+    1
+}
+
+/// Finds the index of a character in a table, given its indices sorted by character
+fn find(table: &[u16], sorted: &[u16], c: u32) -> Option<usize> {
+    sorted
+        .binary_search_by_key(&c, |&i| table[i as usize] as u32)
+        .ok()
+        .map(|i| sorted[i] as usize)
+}
+
+/// The character at a row and cell of JIS X 0208 or JIS X 0212, counting from 0
+fn jis(table: &[u16; 8836], row: u8, cell: u8) -> Result<u32, Invalid> {
+    match table[row as usize * 94 + cell as usize] {
+        0 => Err(Invalid::Sequence),
+        c => Ok(c as u32),
+    }
+}
+
+fn jis0208(row: u8, cell: u8) -> Result<u32, Invalid> {
+    jis(&jis0208::JIS0208, row, cell)
+}
+
+// Bytes below 0x80 are read as ASCII rather than as JIS X 0201, whose yen sign and overline
+// would turn every backslash and tilde into something else
+fn decode_shift_jis(input: &[u8]) -> Result<(Option<u32>, usize), Invalid> {
+    let lead = input[0];
+    match lead {
+        // Half-width katakana
+        0xa1..=0xdf => Ok((Some(0xff61 + (lead as u32 - 0xa1)), 1)),
+        0x81..=0x9f | 0xe0..=0xef => {
+            let trail = *input.get(1).ok_or(Invalid::Incomplete)?;
+            let row = (lead - if lead < 0xa0 { 0x81 } else { 0xc1 }) * 2;
+            let c = match trail {
+                0x40..=0x7e => jis0208(row, trail - 0x40)?,
+                0x80..=0x9e => jis0208(row, trail - 0x41)?,
+                0x9f..=0xfc => jis0208(row + 1, trail - 0x9f)?,
+                _ => return Err(Invalid::Sequence),
+            };
+            Ok((Some(c), 2))
+        }
+        _ => Err(Invalid::Sequence),
+    }
+}
+
+fn decode_euc_jp(input: &[u8]) -> Result<(Option<u32>, usize), Invalid> {
+    let lead = input[0];
+    if lead != 0x8e && lead != 0x8f && !(0xa1..=0xfe).contains(&lead) {
+        return Err(Invalid::Sequence);
+    }
+    let trail = *input.get(1).ok_or(Invalid::Incomplete)?;
+    match (lead, trail) {
+        (0x8e, 0xa1..=0xdf) => Ok((Some(0xff61 + (trail as u32 - 0xa1)), 2)),
+        // JIS X 0212 takes three bytes
+        (0x8f, 0xa1..=0xfe) => match *input.get(2).ok_or(Invalid::Incomplete)? {
+            last @ 0xa1..=0xfe => Ok((Some(jis(&jis0208::JIS0212, trail - 0xa1, last - 0xa1)?), 3)),
+            _ => Err(Invalid::Sequence),
+        },
+        (0xa1..=0xfe, 0xa1..=0xfe) => Ok((Some(jis0208(lead - 0xa1, trail - 0xa1)?), 2)),
+        _ => Err(Invalid::Sequence),
+    }
+}
+
+fn encode_shift_jis(c: u32, buf: &mut [u8]) -> Result<usize, Unwritable> {
+    if let 0xff61..=0xff9f = c {
+        return Ok(put(buf, (c - 0xff61 + 0xa1) as u8));
+    }
+    let i = find(&jis0208::JIS0208, &jis0208::JIS0208_SORTED, c).ok_or(Unwritable::Missing)?;
+    let (row, cell) = (i / 94, i % 94);
+    buf[0] = (row / 2 + if row < 62 { 0x81 } else { 0xc1 }) as u8;
+    let trail = if row % 2 == 1 {
+        cell + 0x9f
+    } else if cell < 63 {
+        cell + 0x40
+    } else {
+        cell + 0x41
+    };
+    buf[1] = trail as u8;
+    Ok(2)
+}
+
+fn encode_euc_jp(c: u32, buf: &mut [u8]) -> Result<usize, Unwritable> {
+    if let 0xff61..=0xff9f = c {
+        buf[0] = 0x8e;
+        buf[1] = (c - 0xff61 + 0xa1) as u8;
+        return Ok(2);
+    }
+    if let Some(i) = find(&jis0208::JIS0208, &jis0208::JIS0208_SORTED, c) {
+        buf[0] = (0xa1 + i / 94) as u8;
+        buf[1] = (0xa1 + i % 94) as u8;
+        return Ok(2);
+    }
+    let i = find(&jis0208::JIS0212, &jis0208::JIS0212_SORTED, c).ok_or(Unwritable::Missing)?;
+    buf[0] = 0x8f;
+    buf[1] = (0xa1 + i / 94) as u8;
+    buf[2] = (0xa1 + i % 94) as u8;
+    Ok(3)
+}
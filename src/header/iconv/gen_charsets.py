@@ -0,0 +1,283 @@
+#!/usr/bin/env python3
+"""Generates the tables behind the character sets iconv() converts between: single_byte.rs,
+gb18030.rs, jis0208.rs and translit.rs.
+
+- single_byte.rs has the upper halves of the ISO 8859 parts and Windows-1252
+- gb18030.rs has the two-byte characters of GB18030, which GBK and GB2312 are subsets of, the
+  runs of BMP characters that the four-byte sequences after them count through, and which of
+  the two-byte characters are in GB2312
+- jis0208.rs has the 94 by 94 characters of JIS X 0208, which Shift_JIS and EUC-JP encode in
+  different ways, and those of JIS X 0212, which only EUC-JP has
+
+The mappings come from Python's codecs. To encode, each table has the indices of its characters
+sorted by what they map to, so that they can be binary searched.
+
+translit.rs has what //TRANSLIT replaces characters with. It is read from the locale-neutral
+tables in glibc's locale sources, the ones its C.UTF-8 locale uses:
+
+    python3 src/header/iconv/gen_charsets.py [/usr/share/i18n/locales]
+"""
+
+import os
+import re
+import sys
+
+DEFAULT_LOCALES = "/usr/share/i18n/locales"
+
+SINGLE_BYTE = [("ISO_8859_{}".format(n), "iso8859_{}".format(n)) for n in range(2, 17) if n != 12]
+SINGLE_BYTE.append(("WINDOWS_1252", "cp1252"))
+
+# The translit files of the C locale, in the order it includes them
+TRANSLIT_FILES = ["translit_neutral", "translit_combining"]
+
+
+def decode(data, codec):
+    try:
+        return data.decode(codec)
+    except UnicodeDecodeError:
+        return None
+
+
+def items(values, fmt, indent="   "):
+    """Fills lines with numbers, the way rustfmt lays them out"""
+    lines = []
+    line = indent
+    for value in values:
+        item = " " + fmt(value) + ","
+        if len(line) + len(item) > 99:
+            lines.append(line)
+            line = indent
+        line += item
+    lines.append(line)
+    return "\n".join(lines)
+
+
+def rows(values, fmt):
+    """One item per line, which is how rustfmt lays out arrays of tuples"""
+    return "\n".join("    " + fmt(value) + "," for value in values)
+
+
+def output(name, source):
+    path = os.path.join(os.path.dirname(os.path.abspath(__file__)), name + ".rs")
+    f = open(path, "w")
+    f.write("// Generated by gen_charsets.py from {}, do not edit\n\n".format(source))
+    return f
+
+
+def table(name, doc, values):
+    return "/// {}\npub const {}: [u16; {}] = [\n{}\n];\n".format(
+        doc, name, len(values), items(values, "0x{:x}".format)
+    )
+
+
+def reverse(values):
+    """The indices of the characters in a table, sorted by character"""
+    seen = set()
+    indices = []
+    for i in sorted(range(len(values)), key=lambda i: (values[i], i)):
+        # Where two entries are the same character, encoding takes the first
+        if values[i] and values[i] not in seen:
+            seen.add(values[i])
+            indices.append(i)
+    return indices
+
+
+def single_byte():
+    with output("single_byte", "Python's codecs") as f:
+        for i, (name, codec) in enumerate(SINGLE_BYTE):
+            values = []
+            for b in range(0x80, 0x100):
+                c = decode(bytes([b]), codec)
+                values.append(ord(c) if c else 0)
+            if i:
+                f.write("\n")
+            f.write(table(name, "The characters 0x80 to 0xff are in {}".format(codec), values))
+
+
+def bits(values):
+    """Packs booleans into bytes, lowest bit first"""
+    return [sum(b << j for j, b in enumerate(values[i : i + 8])) for i in range(0, len(values), 8)]
+
+
+def gb18030():
+    two_byte = []
+    for lead in range(0x81, 0xFF):
+        for trail in list(range(0x40, 0x7F)) + list(range(0x80, 0xFF)):
+            c = decode(bytes([lead, trail]), "gb18030")
+            two_byte.append(ord(c) if c and len(c) == 1 else 0)
+
+    # The four-byte sequences up to 84 31 a4 39 count through the BMP characters that have no
+    # shorter one, in order
+    runs = []
+    previous = None
+    for index in range(39420):
+        b1, rest = divmod(index, 12600)
+        b2, rest = divmod(rest, 1260)
+        b3, b4 = divmod(rest, 10)
+        c = ord(decode(bytes([0x81 + b1, 0x30 + b2, 0x81 + b3, 0x30 + b4]), "gb18030"))
+        if previous is not None and c <= previous:
+            raise ValueError("four-byte sequences out of order at {:x}".format(c))
+        if previous is None or c != previous + 1:
+            runs.append((index, c))
+        previous = c
+
+    covered = set(range(0x80)) | set(c for c in two_byte if c)
+    for (index, c), (end, _) in zip(runs, runs[1:] + [(39420, 0)]):
+        covered.update(range(c, c + end - index))
+    if covered != set(range(0x10000)) - set(range(0xD800, 0xE000)):
+        raise ValueError("the BMP is not covered exactly once")
+
+    with output("gb18030", "Python's codecs") as f:
+        f.write(
+            table(
+                "TWO_BYTE",
+                "The characters of the two-byte sequences, with a lead byte from 0x81 to 0xfe and a\n"
+                "/// trail byte from 0x40 to 0xfe other than 0x7f, or 0",
+                two_byte,
+            )
+        )
+        f.write("\n")
+        f.write(
+            table(
+                "TWO_BYTE_SORTED",
+                "The indices of `TWO_BYTE`, sorted by character",
+                reverse(two_byte),
+            )
+        )
+        f.write("\n")
+        f.write(
+            "/// Where runs of consecutive characters start among the four-byte sequences of the BMP:\n"
+            "/// the index of the sequence, and the character\n"
+        )
+        f.write("pub const FOUR_BYTE: [(u16, u16); {}] = [\n".format(len(runs)))
+        f.write(rows(runs, lambda r: "({}, 0x{:x})".format(*r)))
+        f.write("\n];\n\n")
+        gb2312 = bits(
+            [
+                decode(bytes([0xA1 + row, 0xA1 + cell]), "gb2312") is not None
+                for row in range(94)
+                for cell in range(94)
+            ]
+        )
+        f.write(
+            "/// Which of the sequences with both bytes from 0xa1 are in GB2312, a bit for each row\n"
+            "/// and cell\n"
+        )
+        f.write("pub const GB2312: [u8; {}] = [\n".format(len(gb2312)))
+        f.write(items(gb2312, "0x{:x}".format))
+        f.write("\n];\n")
+
+
+def jis0212():
+    values = []
+    for row in range(94):
+        for cell in range(94):
+            c = decode(bytes([0x8F, 0xA1 + row, 0xA1 + cell]), "euc_jp")
+            values.append(ord(c) if c and len(c) == 1 else 0)
+    return values
+
+
+def jis0208():
+    values = []
+    for row in range(94):
+        for cell in range(94):
+            # The Shift_JIS bytes of the row and cell
+            lead = row // 2 + (0x81 if row < 62 else 0xC1)
+            if row % 2 == 0:
+                trail = cell + (0x40 if cell < 63 else 0x41)
+            else:
+                trail = cell + 0x9F
+            c = decode(bytes([lead, trail]), "shift_jis")
+            values.append(ord(c) if c and len(c) == 1 else 0)
+
+    with output("jis0208", "Python's codecs") as f:
+        f.write(table("JIS0208", "The characters of each row and cell, or 0", values))
+        f.write("\n")
+        f.write(
+            table(
+                "JIS0208_SORTED", "The indices of `JIS0208`, sorted by character", reverse(values)
+            )
+        )
+        supplement = jis0212()
+        f.write("\n")
+        f.write(
+            table("JIS0212", "The characters of each row and cell of JIS X 0212, or 0", supplement)
+        )
+        f.write("\n")
+        f.write(
+            table(
+                "JIS0212_SORTED",
+                "The indices of `JIS0212`, sorted by character",
+                reverse(supplement),
+            )
+        )
+
+
+def parse_translit(directory, name, entries):
+    """Reads the replacements of a translit file into `entries`, after the ones already there
+    and before the ones of the files it includes, which is the order glibc tries them in"""
+    with open(os.path.join(directory, name), encoding="utf-8") as f:
+        text = f.read()
+    # Lines ending in the escape character go on in the next one
+    text = re.sub(r"/\n", "", text)
+    includes = []
+    inside = False
+    for line in text.split("\n"):
+        line = line.split("%")[0].strip()
+        if line == "translit_start":
+            inside = True
+        elif line == "translit_end":
+            inside = False
+        elif inside and line.startswith("include"):
+            includes.append(re.match(r'include\s+"([^"]+)"', line).group(1))
+        elif inside and line.startswith("<U"):
+            key, _, rest = line.partition(" ")
+            chars = [int(c, 16) for c in re.findall(r"<U([0-9A-Fa-f]+)>", key)]
+            # Sequences of characters are left out, as iconv() replaces one at a time
+            if len(chars) != 1 or chars[0] in entries:
+                continue
+            alternatives = []
+            for alternative in rest.strip().split(";"):
+                alternative = alternative.strip().strip('"')
+                alternatives.append(
+                    "".join(chr(int(c, 16)) for c in re.findall(r"<U([0-9A-Fa-f]+)>", alternative))
+                )
+            entries[chars[0]] = alternatives
+    for include in includes:
+        parse_translit(directory, include, entries)
+
+
+def literal(s):
+    out = '"'
+    for c in s:
+        if c in '"\\':
+            out += "\\" + c
+        elif 0x20 <= ord(c) < 0x7F:
+            out += c
+        else:
+            out += "\\u{{{:x}}}".format(ord(c))
+    return out + '"'
+
+
+def translit(directory):
+    entries = {}
+    for name in TRANSLIT_FILES:
+        parse_translit(directory, name, entries)
+
+    with output("translit", "glibc's {}".format(" and ".join(TRANSLIT_FILES))) as f:
+        f.write("/// Characters with what can be written instead, in order of preference\n")
+        f.write("pub const TRANSLIT: [(u32, &[&str]); {}] = [\n".format(len(entries)))
+        for c, alternatives in sorted(entries.items()):
+            alternatives = "&[{}]".format(", ".join(literal(a) for a in alternatives))
+            row = "(0x{:x}, {})".format(c, alternatives)
+            # rustfmt puts the items of longer tuples on lines of their own
+            if len(row) > 61:
+                row = "(\n        0x{:x},\n        {},\n    )".format(c, alternatives)
+            f.write("    {},\n".format(row))
+        f.write("];\n")
+
+
+single_byte()
+gb18030()
+jis0208()
+translit(sys.argv[1] if len(sys.argv) > 1 else DEFAULT_LOCALES)
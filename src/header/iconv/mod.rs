@@ -0,0 +1,204 @@
+//! iconv implementation for Redox, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/iconv.h.html
+
+use alloc::boxed::Box;
+use core::slice;
+
+use crate::{
+    c_str::CStr,
+    header::errno::{E2BIG, EBADF, EILSEQ, EINVAL},
+    platform::{self, types::*},
+};
+
+use self::charset::{Charset, Invalid, Unwritable};
+
+mod charset;
+mod gb18030;
+mod jis0208;
+mod single_byte;
+mod translit;
+
+pub type iconv_t = *mut c_void;
+
+/// A conversion, as iconv_open() sets it up
+struct Converter {
+    from: Charset,
+    to: Charset,
+    /// `from` and `to` as they were opened, before byte order marks were read or written
+    initial: (Charset, Charset),
+    /// Whether characters that `to` doesn't have are written as similar ones
+    translit: bool,
+    /// Whether characters that can't be converted are left out
+    ignore: bool,
+}
+
+impl Converter {
+    /// Converts as much of `input` into `output` as fits, returning how many bytes of each were
+    /// used. If it all went, the result is the number of characters that were converted to
+    /// something else, otherwise the errno that stopped it.
+    fn convert(&mut self, input: &[u8], output: &mut [u8]) -> (usize, usize, Result<usize, c_int>) {
+        let mut read = 0;
+        let mut written = 0;
+        let mut irreversible = 0;
+        let mut skipped = false;
+
+        while read < input.len() {
+            let (c, len) = match self.from.decode(&input[read..]) {
+                Ok((Some(c), len)) => (c, len),
+                Ok((None, len)) => {
+                    read += len;
+                    continue;
+                }
+                Err(Invalid::Sequence) if self.ignore => {
+                    read += 1;
+                    skipped = true;
+                    continue;
+                }
+                Err(Invalid::Sequence) => return (read, written, Err(EILSEQ)),
+                Err(Invalid::Incomplete) => return (read, written, Err(EINVAL)),
+            };
+
+            let result = match self.to.encode(c, &mut output[written..]) {
+                Err(Unwritable::Missing) if self.translit => {
+                    self.transliterate(c, &mut output[written..]).map(|len| {
+                        irreversible += 1;
+                        len
+                    })
+                }
+                result => result,
+            };
+            match result {
+                Ok(n) => written += n,
+                Err(Unwritable::Missing) if self.ignore => skipped = true,
+                Err(Unwritable::Missing) => return (read, written, Err(EILSEQ)),
+                Err(Unwritable::Full) => return (read, written, Err(E2BIG)),
+            }
+            read += len;
+        }
+
+        // Like glibc, leaving characters out is reported once everything else is converted
+        if skipped {
+            (read, written, Err(EILSEQ))
+        } else {
+            (read, written, Ok(irreversible))
+        }
+    }
+
+    /// Writes the first of the replacements for `c` that `to` has all characters of, or a
+    /// question mark if there are none
+    fn transliterate(&mut self, c: u32, output: &mut [u8]) -> Result<usize, Unwritable> {
+        let replacements: &[&str] = match translit::TRANSLIT.binary_search_by_key(&c, |&(c, _)| c) {
+            Ok(i) => translit::TRANSLIT[i].1,
+            Err(_) => &[],
+        };
+
+        for replacement in replacements.iter().chain(&["?"]) {
+            // A byte order mark written with the first character of an attempt that fails
+            // is still to be written
+            let to = self.to;
+            let mut len = 0;
+            let result = replacement.chars().try_for_each(|c| {
+                len += self.to.encode(c as u32, &mut output[len..])?;
+                Ok(())
+            });
+            match result {
+                Ok(()) => return Ok(len),
+                Err(Unwritable::Missing) => self.to = to,
+                Err(Unwritable::Full) => {
+                    self.to = to;
+                    return Err(Unwritable::Full);
+                }
+            }
+        }
+        Err(Unwritable::Missing)
+    }
+}
+
+/// Splits a name passed to iconv_open() into the character set and the suffixes after "//"
+fn split_name(name: &[u8]) -> (&[u8], &[u8]) {
+    match name.windows(2).position(|w| w == b"//") {
+        Some(i) => (&name[..i], &name[i + 2..]),
+        None => (name, b""),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iconv_open(tocode: *const c_char, fromcode: *const c_char) -> iconv_t {
+    let (to, suffixes) = split_name(CStr::from_ptr(tocode).to_bytes());
+    // Suffixes only make sense for the output, and are ignored on the input
+    let (from, _) = split_name(CStr::from_ptr(fromcode).to_bytes());
+
+    let (from, to) = match (Charset::find(from), Charset::find(to)) {
+        (Some(from), Some(to)) => (from, to),
+        _ => {
+            platform::errno = EINVAL;
+            return -1isize as iconv_t;
+        }
+    };
+
+    let mut converter = Converter {
+        from,
+        to,
+        initial: (from, to),
+        translit: false,
+        ignore: false,
+    };
+    for suffix in suffixes.split(|&b| b == b'/' || b == b',') {
+        if suffix.eq_ignore_ascii_case(b"TRANSLIT") {
+            converter.translit = true;
+        } else if suffix.eq_ignore_ascii_case(b"IGNORE") {
+            converter.ignore = true;
+        }
+    }
+    Box::into_raw(Box::new(converter)).cast()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iconv(
+    cd: iconv_t,
+    inbuf: *mut *mut c_char,
+    inbytesleft: *mut size_t,
+    outbuf: *mut *mut c_char,
+    outbytesleft: *mut size_t,
+) -> size_t {
+    if cd.is_null() || cd == -1isize as iconv_t {
+        platform::errno = EBADF;
+        return -1isize as size_t;
+    }
+    let converter = &mut *cd.cast::<Converter>();
+
+    // Without input, the conversion goes back to its initial state. None of the character sets
+    // have shift states, so there is nothing to write, but like on glibc, byte order marks are
+    // looked for and written again.
+    if inbuf.is_null() || (*inbuf).is_null() {
+        let (from, to) = converter.initial;
+        converter.from = from;
+        converter.to = to;
+        return 0;
+    }
+
+    let input = slice::from_raw_parts(*inbuf as *const u8, *inbytesleft);
+    let output = slice::from_raw_parts_mut(*outbuf as *mut u8, *outbytesleft);
+    let (read, written, result) = converter.convert(input, output);
+
+    *inbuf = (*inbuf).add(read);
+    *inbytesleft -= read;
+    *outbuf = (*outbuf).add(written);
+    *outbytesleft -= written;
+    match result {
+        Ok(irreversible) => irreversible,
+        Err(errno) => {
+            platform::errno = errno;
+            -1isize as size_t
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iconv_close(cd: iconv_t) -> c_int {
+    if cd.is_null() || cd == -1isize as iconv_t {
+        platform::errno = EBADF;
+        return -1;
+    }
+    drop(Box::from_raw(cd.cast::<Converter>()));
+    0
+}
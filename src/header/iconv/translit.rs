@@ -0,0 +1,6236 @@
+// Generated by gen_charsets.py from glibc's translit_neutral and translit_combining, do not edit
+
+/// Characters with what can be written instead, in order of preference
+pub const TRANSLIT: [(u32, &[&str]); 6228] = [
+    (0xa0, &[" "]),
+    (0xa1, &["!"]),
+    (0xa2, &["c"]),
+    (0xa3, &["GBP"]),
+    (0xa5, &["JPY"]),
+    (0xa6, &["|"]),
+    (0xa9, &["(C)"]),
+    (0xaa, &["a"]),
+    (0xab, &["<<"]),
+    (0xac, &["!"]),
+    (0xad, &["-"]),
+    (0xae, &["(R)"]),
+    (0xb1, &["+-"]),
+    (0xb2, &["2"]),
+    (0xb3, &["3"]),
+    (0xb4, &["'"]),
+    (0xb5, &["\u{3bc}", "u"]),
+    (0xb7, &["."]),
+    (0xb8, &[","]),
+    (0xb9, &["1"]),
+    (0xba, &["o"]),
+    (0xbb, &[">>"]),
+    (0xbc, &[" 1\u{2044}4 ", " 1/4 "]),
+    (0xbd, &[" 1\u{2044}2 ", " 1/2 "]),
+    (0xbe, &[" 3\u{2044}4 ", " 3/4 "]),
+    (0xbf, &["?"]),
+    (0xc0, &["A"]),
+    (0xc1, &["A"]),
+    (0xc2, &["A"]),
+    (0xc3, &["A"]),
+    (0xc4, &["A"]),
+    (0xc5, &["A"]),
+    (0xc6, &["AE"]),
+    (0xc7, &["C"]),
+    (0xc8, &["E"]),
+    (0xc9, &["E"]),
+    (0xca, &["E"]),
+    (0xcb, &["E"]),
+    (0xcc, &["I"]),
+    (0xcd, &["I"]),
+    (0xce, &["I"]),
+    (0xcf, &["I"]),
+    (0xd0, &["D"]),
+    (0xd1, &["N"]),
+    (0xd2, &["O"]),
+    (0xd3, &["O"]),
+    (0xd4, &["O"]),
+    (0xd5, &["O"]),
+    (0xd6, &["O"]),
+    (0xd7, &["x"]),
+    (0xd8, &["O"]),
+    (0xd9, &["U"]),
+    (0xda, &["U"]),
+    (0xdb, &["U"]),
+    (0xdc, &["U"]),
+    (0xdd, &["Y"]),
+    (0xde, &["TH"]),
+    (0xdf, &["ss"]),
+    (0xe0, &["a"]),
+    (0xe1, &["a"]),
+    (0xe2, &["a"]),
+    (0xe3, &["a"]),
+    (0xe4, &["a"]),
+    (0xe5, &["a"]),
+    (0xe6, &["ae"]),
+    (0xe7, &["c"]),
+    (0xe8, &["e"]),
+    (0xe9, &["e"]),
+    (0xea, &["e"]),
+    (0xeb, &["e"]),
+    (0xec, &["i"]),
+    (0xed, &["i"]),
+    (0xee, &["i"]),
+    (0xef, &["i"]),
+    (0xf0, &["d"]),
+    (0xf1, &["n"]),
+    (0xf2, &["o"]),
+    (0xf3, &["o"]),
+    (0xf4, &["o"]),
+    (0xf5, &["o"]),
+    (0xf6, &["o"]),
+    (0xf7, &["/"]),
+    (0xf8, &["o"]),
+    (0xf9, &["u"]),
+    (0xfa, &["u"]),
+    (0xfb, &["u"]),
+    (0xfc, &["u"]),
+    (0xfd, &["y"]),
+    (0xfe, &["th"]),
+    (0xff, &["y"]),
+    (0x100, &["A"]),
+    (0x101, &["a"]),
+    (0x102, &["A"]),
+    (0x103, &["a"]),
+    (0x104, &["A"]),
+    (0x105, &["a"]),
+    (0x106, &["C"]),
+    (0x107, &["c"]),
+    (0x108, &["C"]),
+    (0x109, &["c"]),
+    (0x10a, &["C"]),
+    (0x10b, &["c"]),
+    (0x10c, &["C"]),
+    (0x10d, &["c"]),
+    (0x10e, &["D"]),
+    (0x10f, &["d"]),
+    (0x110, &["D"]),
+    (0x111, &["d"]),
+    (0x112, &["E"]),
+    (0x113, &["e"]),
+    (0x114, &["E"]),
+    (0x115, &["e"]),
+    (0x116, &["E"]),
+    (0x117, &["e"]),
+    (0x118, &["E"]),
+    (0x119, &["e"]),
+    (0x11a, &["E"]),
+    (0x11b, &["e"]),
+    (0x11c, &["G"]),
+    (0x11d, &["g"]),
+    (0x11e, &["G"]),
+    (0x11f, &["g"]),
+    (0x120, &["G"]),
+    (0x121, &["g"]),
+    (0x122, &["G"]),
+    (0x123, &["g"]),
+    (0x124, &["H"]),
+    (0x125, &["h"]),
+    (0x126, &["H"]),
+    (0x127, &["h"]),
+    (0x128, &["I"]),
+    (0x129, &["i"]),
+    (0x12a, &["I"]),
+    (0x12b, &["i"]),
+    (0x12c, &["I"]),
+    (0x12d, &["i"]),
+    (0x12e, &["I"]),
+    (0x12f, &["i"]),
+    (0x130, &["I"]),
+    (0x131, &["i"]),
+    (0x132, &["IJ"]),
+    (0x133, &["ij"]),
+    (0x134, &["J"]),
+    (0x135, &["j"]),
+    (0x136, &["K"]),
+    (0x137, &["k"]),
+    (0x138, &["q"]),
+    (0x139, &["L"]),
+    (0x13a, &["l"]),
+    (0x13b, &["L"]),
+    (0x13c, &["l"]),
+    (0x13d, &["L"]),
+    (0x13e, &["l"]),
+    (0x13f, &["L"]),
+    (0x140, &["l"]),
+    (0x141, &["L"]),
+    (0x142, &["l"]),
+    (0x143, &["N"]),
+    (0x144, &["n"]),
+    (0x145, &["N"]),
+    (0x146, &["n"]),
+    (0x147, &["N"]),
+    (0x148, &["n"]),
+    (0x149, &["'n"]),
+    (0x14a, &["N"]),
+    (0x14b, &["n"]),
+    (0x14c, &["O"]),
+    (0x14d, &["o"]),
+    (0x14e, &["O"]),
+    (0x14f, &["o"]),
+    (0x150, &["O"]),
+    (0x151, &["o"]),
+    (0x152, &["OE"]),
+    (0x153, &["oe"]),
+    (0x154, &["R"]),
+    (0x155, &["r"]),
+    (0x156, &["R"]),
+    (0x157, &["r"]),
+    (0x158, &["R"]),
+    (0x159, &["r"]),
+    (0x15a, &["S"]),
+    (0x15b, &["s"]),
+    (0x15c, &["S"]),
+    (0x15d, &["s"]),
+    (0x15e, &["S"]),
+    (0x15f, &["s"]),
+    (0x160, &["S"]),
+    (0x161, &["s"]),
+    (0x162, &["T"]),
+    (0x163, &["t"]),
+    (0x164, &["T"]),
+    (0x165, &["t"]),
+    (0x166, &["T"]),
+    (0x167, &["t"]),
+    (0x168, &["U"]),
+    (0x169, &["u"]),
+    (0x16a, &["U"]),
+    (0x16b, &["u"]),
+    (0x16c, &["U"]),
+    (0x16d, &["u"]),
+    (0x16e, &["U"]),
+    (0x16f, &["u"]),
+    (0x170, &["U"]),
+    (0x171, &["u"]),
+    (0x172, &["U"]),
+    (0x173, &["u"]),
+    (0x174, &["W"]),
+    (0x175, &["w"]),
+    (0x176, &["Y"]),
+    (0x177, &["y"]),
+    (0x178, &["Y"]),
+    (0x179, &["Z"]),
+    (0x17a, &["z"]),
+    (0x17b, &["Z"]),
+    (0x17c, &["z"]),
+    (0x17d, &["Z"]),
+    (0x17e, &["z"]),
+    (0x17f, &["s"]),
+    (0x180, &["b"]),
+    (0x181, &["B"]),
+    (0x182, &["B"]),
+    (0x183, &["b"]),
+    (0x187, &["C"]),
+    (0x188, &["c"]),
+    (0x189, &["D"]),
+    (0x18a, &["D"]),
+    (0x18b, &["D"]),
+    (0x18c, &["d"]),
+    (0x190, &["E"]),
+    (0x191, &["F"]),
+    (0x192, &["f"]),
+    (0x193, &["G"]),
+    (0x195, &["hv"]),
+    (0x196, &["I"]),
+    (0x197, &["I"]),
+    (0x198, &["K"]),
+    (0x199, &["k"]),
+    (0x19a, &["l"]),
+    (0x19d, &["N"]),
+    (0x19e, &["n"]),
+    (0x1a0, &["O"]),
+    (0x1a1, &["o"]),
+    (0x1a2, &["OI"]),
+    (0x1a3, &["oi"]),
+    (0x1a4, &["P"]),
+    (0x1a5, &["p"]),
+    (0x1ab, &["t"]),
+    (0x1ac, &["T"]),
+    (0x1ad, &["t"]),
+    (0x1ae, &["T"]),
+    (0x1af, &["U"]),
+    (0x1b0, &["u"]),
+    (0x1b2, &["V"]),
+    (0x1b3, &["Y"]),
+    (0x1b4, &["y"]),
+    (0x1b5, &["Z"]),
+    (0x1b6, &["z"]),
+    (0x1c4, &["D\u{17d}"]),
+    (0x1c5, &["D\u{17e}"]),
+    (0x1c6, &["d\u{17e}"]),
+    (0x1c7, &["LJ"]),
+    (0x1c8, &["Lj"]),
+    (0x1c9, &["lj"]),
+    (0x1ca, &["NJ"]),
+    (0x1cb, &["Nj"]),
+    (0x1cc, &["nj"]),
+    (0x1cd, &["A"]),
+    (0x1ce, &["a"]),
+    (0x1cf, &["I"]),
+    (0x1d0, &["i"]),
+    (0x1d1, &["O"]),
+    (0x1d2, &["o"]),
+    (0x1d3, &["U"]),
+    (0x1d4, &["u"]),
+    (0x1d5, &["U"]),
+    (0x1d6, &["u"]),
+    (0x1d7, &["U"]),
+    (0x1d8, &["u"]),
+    (0x1d9, &["U"]),
+    (0x1da, &["u"]),
+    (0x1db, &["U"]),
+    (0x1dc, &["u"]),
+    (0x1de, &["A"]),
+    (0x1df, &["a"]),
+    (0x1e0, &["A"]),
+    (0x1e1, &["a"]),
+    (0x1e2, &["\u{c6}", "AE"]),
+    (0x1e3, &["\u{e6}", "ae"]),
+    (0x1e4, &["G"]),
+    (0x1e5, &["g"]),
+    (0x1e6, &["G"]),
+    (0x1e7, &["g"]),
+    (0x1e8, &["K"]),
+    (0x1e9, &["k"]),
+    (0x1ea, &["O"]),
+    (0x1eb, &["o"]),
+    (0x1ec, &["O"]),
+    (0x1ed, &["o"]),
+    (0x1ee, &["\u{1b7}"]),
+    (0x1ef, &["\u{292}"]),
+    (0x1f0, &["j"]),
+    (0x1f1, &["DZ"]),
+    (0x1f2, &["Dz"]),
+    (0x1f3, &["dz"]),
+    (0x1f4, &["G"]),
+    (0x1f5, &["g"]),
+    (0x1f8, &["N"]),
+    (0x1f9, &["n"]),
+    (0x1fa, &["A"]),
+    (0x1fb, &["a"]),
+    (0x1fc, &["\u{c6}", "AE"]),
+    (0x1fd, &["\u{e6}", "ae"]),
+    (0x1fe, &["\u{d8}", "O"]),
+    (0x1ff, &["\u{f8}", "o"]),
+    (0x200, &["A"]),
+    (0x201, &["a"]),
+    (0x202, &["A"]),
+    (0x203, &["a"]),
+    (0x204, &["E"]),
+    (0x205, &["e"]),
+    (0x206, &["E"]),
+    (0x207, &["e"]),
+    (0x208, &["I"]),
+    (0x209, &["i"]),
+    (0x20a, &["I"]),
+    (0x20b, &["i"]),
+    (0x20c, &["O"]),
+    (0x20d, &["o"]),
+    (0x20e, &["O"]),
+    (0x20f, &["o"]),
+    (0x210, &["R"]),
+    (0x211, &["r"]),
+    (0x212, &["R"]),
+    (0x213, &["r"]),
+    (0x214, &["U"]),
+    (0x215, &["u"]),
+    (0x216, &["U"]),
+    (0x217, &["u"]),
+    (0x218, &["S"]),
+    (0x219, &["s"]),
+    (0x21a, &["T"]),
+    (0x21b, &["t"]),
+    (0x21e, &["H"]),
+    (0x21f, &["h"]),
+    (0x221, &["d"]),
+    (0x224, &["Z"]),
+    (0x225, &["z"]),
+    (0x226, &["A"]),
+    (0x227, &["a"]),
+    (0x228, &["E"]),
+    (0x229, &["e"]),
+    (0x22a, &["O"]),
+    (0x22b, &["o"]),
+    (0x22c, &["O"]),
+    (0x22d, &["o"]),
+    (0x22e, &["O"]),
+    (0x22f, &["o"]),
+    (0x230, &["O"]),
+    (0x231, &["o"]),
+    (0x232, &["Y"]),
+    (0x233, &["y"]),
+    (0x234, &["l"]),
+    (0x235, &["n"]),
+    (0x236, &["t"]),
+    (0x237, &["j"]),
+    (0x238, &["db"]),
+    (0x239, &["qp"]),
+    (0x23a, &["A"]),
+    (0x23b, &["C"]),
+    (0x23c, &["c"]),
+    (0x23d, &["L"]),
+    (0x23e, &["T"]),
+    (0x23f, &["s"]),
+    (0x240, &["z"]),
+    (0x243, &["B"]),
+    (0x244, &["U"]),
+    (0x246, &["E"]),
+    (0x247, &["e"]),
+    (0x248, &["J"]),
+    (0x249, &["j"]),
+    (0x24c, &["R"]),
+    (0x24d, &["r"]),
+    (0x24e, &["Y"]),
+    (0x24f, &["y"]),
+    (0x253, &["b"]),
+    (0x255, &["c"]),
+    (0x256, &["d"]),
+    (0x257, &["d"]),
+    (0x25b, &["e"]),
+    (0x25f, &["j"]),
+    (0x260, &["g"]),
+    (0x261, &["g"]),
+    (0x262, &["G"]),
+    (0x266, &["h"]),
+    (0x267, &["h"]),
+    (0x268, &["i"]),
+    (0x26a, &["I"]),
+    (0x26b, &["l"]),
+    (0x26c, &["l"]),
+    (0x26d, &["l"]),
+    (0x271, &["m"]),
+    (0x272, &["n"]),
+    (0x273, &["n"]),
+    (0x274, &["N"]),
+    (0x276, &["OE"]),
+    (0x27c, &["r"]),
+    (0x27d, &["r"]),
+    (0x27e, &["r"]),
+    (0x280, &["R"]),
+    (0x282, &["s"]),
+    (0x288, &["t"]),
+    (0x289, &["u"]),
+    (0x28b, &["v"]),
+    (0x28f, &["Y"]),
+    (0x290, &["z"]),
+    (0x291, &["z"]),
+    (0x299, &["B"]),
+    (0x29b, &["G"]),
+    (0x29c, &["H"]),
+    (0x29d, &["j"]),
+    (0x29f, &["L"]),
+    (0x2a0, &["q"]),
+    (0x2a3, &["dz"]),
+    (0x2a5, &["dz"]),
+    (0x2a6, &["ts"]),
+    (0x2aa, &["ls"]),
+    (0x2ab, &["lz"]),
+    (0x2b0, &["h"]),
+    (0x2b1, &["\u{266}"]),
+    (0x2b2, &["j"]),
+    (0x2b3, &["r"]),
+    (0x2b4, &["\u{279}"]),
+    (0x2b5, &["\u{27b}"]),
+    (0x2b6, &["\u{281}"]),
+    (0x2b7, &["w"]),
+    (0x2b8, &["y"]),
+    (0x2b9, &["\u{2032}", "\u{b4}"]),
+    (0x2ba, &["\u{2033}", "\u{b4}\u{b4}"]),
+    (0x2bb, &["\u{2018}"]),
+    (0x2bc, &["\u{2019}", "'"]),
+    (0x2bd, &["\u{201b}"]),
+    (0x2c6, &["^"]),
+    (0x2c8, &["'"]),
+    (0x2c9, &["\u{af}"]),
+    (0x2ca, &["\u{b4}"]),
+    (0x2cb, &["`"]),
+    (0x2cd, &["_"]),
+    (0x2d0, &[":"]),
+    (0x2dc, &["~"]),
+    (0x2dd, &["''"]),
+    (0x2e0, &["\u{263}"]),
+    (0x2e1, &["l"]),
+    (0x2e2, &["s"]),
+    (0x2e3, &["x"]),
+    (0x2e4, &["\u{295}"]),
+    (0x300, &[""]),
+    (0x301, &[""]),
+    (0x302, &[""]),
+    (0x303, &[""]),
+    (0x304, &[""]),
+    (0x305, &[""]),
+    (0x306, &[""]),
+    (0x307, &[""]),
+    (0x308, &[""]),
+    (0x309, &[""]),
+    (0x30a, &[""]),
+    (0x30b, &[""]),
+    (0x30c, &[""]),
+    (0x30d, &[""]),
+    (0x30e, &[""]),
+    (0x30f, &[""]),
+    (0x310, &[""]),
+    (0x311, &[""]),
+    (0x312, &[""]),
+    (0x313, &[""]),
+    (0x314, &[""]),
+    (0x315, &[""]),
+    (0x316, &[""]),
+    (0x317, &[""]),
+    (0x318, &[""]),
+    (0x319, &[""]),
+    (0x31a, &[""]),
+    (0x31b, &[""]),
+    (0x31c, &[""]),
+    (0x31d, &[""]),
+    (0x31e, &[""]),
+    (0x31f, &[""]),
+    (0x320, &[""]),
+    (0x321, &[""]),
+    (0x322, &[""]),
+    (0x323, &[""]),
+    (0x324, &[""]),
+    (0x325, &[""]),
+    (0x326, &[""]),
+    (0x327, &[""]),
+    (0x328, &[""]),
+    (0x329, &[""]),
+    (0x32a, &[""]),
+    (0x32b, &[""]),
+    (0x32c, &[""]),
+    (0x32d, &[""]),
+    (0x32e, &[""]),
+    (0x32f, &[""]),
+    (0x330, &[""]),
+    (0x331, &[""]),
+    (0x332, &[""]),
+    (0x333, &[""]),
+    (0x334, &[""]),
+    (0x335, &[""]),
+    (0x336, &[""]),
+    (0x337, &[""]),
+    (0x338, &[""]),
+    (0x339, &[""]),
+    (0x33a, &[""]),
+    (0x33b, &[""]),
+    (0x33c, &[""]),
+    (0x33d, &[""]),
+    (0x33e, &[""]),
+    (0x33f, &[""]),
+    (0x340, &[""]),
+    (0x341, &[""]),
+    (0x342, &[""]),
+    (0x343, &[""]),
+    (0x344, &[""]),
+    (0x345, &[""]),
+    (0x346, &[""]),
+    (0x347, &[""]),
+    (0x348, &[""]),
+    (0x349, &[""]),
+    (0x34a, &[""]),
+    (0x34b, &[""]),
+    (0x34c, &[""]),
+    (0x34d, &[""]),
+    (0x34e, &[""]),
+    (0x34f, &[""]),
+    (0x350, &[""]),
+    (0x351, &[""]),
+    (0x352, &[""]),
+    (0x353, &[""]),
+    (0x354, &[""]),
+    (0x355, &[""]),
+    (0x356, &[""]),
+    (0x357, &[""]),
+    (0x358, &[""]),
+    (0x359, &[""]),
+    (0x35a, &[""]),
+    (0x35b, &[""]),
+    (0x35c, &[""]),
+    (0x35d, &[""]),
+    (0x35e, &[""]),
+    (0x35f, &[""]),
+    (0x360, &[""]),
+    (0x361, &[""]),
+    (0x362, &[""]),
+    (0x363, &[""]),
+    (0x364, &[""]),
+    (0x365, &[""]),
+    (0x366, &[""]),
+    (0x367, &[""]),
+    (0x368, &[""]),
+    (0x369, &[""]),
+    (0x36a, &[""]),
+    (0x36b, &[""]),
+    (0x36c, &[""]),
+    (0x36d, &[""]),
+    (0x36e, &[""]),
+    (0x36f, &[""]),
+    (0x374, &["\u{2b9}"]),
+    (0x37e, &[";"]),
+    (0x385, &["\u{a8}"]),
+    (0x386, &["\u{391}"]),
+    (0x387, &["\u{b7}"]),
+    (0x388, &["\u{395}"]),
+    (0x389, &["\u{397}"]),
+    (0x38a, &["\u{399}"]),
+    (0x38c, &["\u{39f}"]),
+    (0x38e, &["\u{3a5}"]),
+    (0x38f, &["\u{3a9}"]),
+    (0x390, &["\u{3b9}"]),
+    (0x3aa, &["\u{399}"]),
+    (0x3ab, &["\u{3a5}"]),
+    (0x3ac, &["\u{3b1}"]),
+    (0x3ad, &["\u{3b5}"]),
+    (0x3ae, &["\u{3b7}"]),
+    (0x3af, &["\u{3b9}"]),
+    (0x3b0, &["\u{3c5}"]),
+    (0x3bc, &["u"]),
+    (0x3ca, &["\u{3b9}"]),
+    (0x3cb, &["\u{3c5}"]),
+    (0x3cc, &["\u{3bf}"]),
+    (0x3cd, &["\u{3c5}"]),
+    (0x3ce, &["\u{3c9}"]),
+    (0x3d0, &["\u{3b2}"]),
+    (0x3d1, &["\u{3b8}"]),
+    (0x3d2, &["\u{3a5}"]),
+    (0x3d3, &["\u{3d2}"]),
+    (0x3d4, &["\u{3d2}"]),
+    (0x3d5, &["\u{3c6}"]),
+    (0x3d6, &["\u{3c0}"]),
+    (0x3f0, &["\u{3ba}"]),
+    (0x3f1, &["\u{3c1}"]),
+    (0x3f2, &["\u{3c2}"]),
+    (0x3f4, &["\u{398}"]),
+    (0x3f5, &["\u{3b5}"]),
+    (0x3f9, &["\u{3a3}"]),
+    (0x400, &["\u{415}"]),
+    (0x401, &["\u{415}"]),
+    (0x403, &["\u{413}"]),
+    (0x407, &["\u{406}"]),
+    (0x40c, &["\u{41a}"]),
+    (0x40d, &["\u{418}"]),
+    (0x40e, &["\u{423}"]),
+    (0x419, &["\u{418}"]),
+    (0x439, &["\u{438}"]),
+    (0x450, &["\u{435}"]),
+    (0x451, &["\u{435}"]),
+    (0x453, &["\u{433}"]),
+    (0x457, &["\u{456}"]),
+    (0x45c, &["\u{43a}"]),
+    (0x45d, &["\u{438}"]),
+    (0x45e, &["\u{443}"]),
+    (0x476, &["\u{474}"]),
+    (0x477, &["\u{475}"]),
+    (0x4a4, &["\u{41d}\u{413}"]),
+    (0x4a5, &["\u{43d}\u{433}"]),
+    (0x4b4, &["\u{422}\u{426}"]),
+    (0x4b5, &["\u{442}\u{446}"]),
+    (0x4c1, &["\u{416}"]),
+    (0x4c2, &["\u{436}"]),
+    (0x4d0, &["\u{410}"]),
+    (0x4d1, &["\u{430}"]),
+    (0x4d2, &["\u{410}"]),
+    (0x4d3, &["\u{430}"]),
+    (0x4d4, &["\u{410}\u{415}"]),
+    (0x4d5, &["\u{430}\u{435}"]),
+    (0x4d6, &["\u{415}"]),
+    (0x4d7, &["\u{435}"]),
+    (0x4da, &["\u{4d8}"]),
+    (0x4db, &["\u{4d9}"]),
+    (0x4dc, &["\u{416}"]),
+    (0x4dd, &["\u{436}"]),
+    (0x4de, &["\u{417}"]),
+    (0x4df, &["\u{437}"]),
+    (0x4e2, &["\u{418}"]),
+    (0x4e3, &["\u{438}"]),
+    (0x4e4, &["\u{418}"]),
+    (0x4e5, &["\u{438}"]),
+    (0x4e6, &["\u{41e}"]),
+    (0x4e7, &["\u{43e}"]),
+    (0x4ea, &["\u{4e8}"]),
+    (0x4eb, &["\u{4e9}"]),
+    (0x4ec, &["\u{42d}"]),
+    (0x4ed, &["\u{44d}"]),
+    (0x4ee, &["\u{423}"]),
+    (0x4ef, &["\u{443}"]),
+    (0x4f0, &["\u{423}"]),
+    (0x4f1, &["\u{443}"]),
+    (0x4f2, &["\u{423}"]),
+    (0x4f3, &["\u{443}"]),
+    (0x4f4, &["\u{427}"]),
+    (0x4f5, &["\u{447}"]),
+    (0x4f8, &["\u{42b}"]),
+    (0x4f9, &["\u{44b}"]),
+    (0x587, &["\u{565}\u{582}"]),
+    (0x58f, &["AMD"]),
+    (0x591, &[""]),
+    (0x592, &[""]),
+    (0x593, &[""]),
+    (0x594, &[""]),
+    (0x595, &[""]),
+    (0x596, &[""]),
+    (0x597, &[""]),
+    (0x598, &[""]),
+    (0x599, &[""]),
+    (0x59a, &[""]),
+    (0x59b, &[""]),
+    (0x59c, &[""]),
+    (0x59d, &[""]),
+    (0x59e, &[""]),
+    (0x59f, &[""]),
+    (0x5a0, &[""]),
+    (0x5a1, &[""]),
+    (0x5a2, &[""]),
+    (0x5a3, &[""]),
+    (0x5a4, &[""]),
+    (0x5a5, &[""]),
+    (0x5a6, &[""]),
+    (0x5a7, &[""]),
+    (0x5a8, &[""]),
+    (0x5a9, &[""]),
+    (0x5aa, &[""]),
+    (0x5ab, &[""]),
+    (0x5ac, &[""]),
+    (0x5ad, &[""]),
+    (0x5ae, &[""]),
+    (0x5af, &[""]),
+    (0x5b0, &[""]),
+    (0x5b1, &[""]),
+    (0x5b2, &[""]),
+    (0x5b3, &[""]),
+    (0x5b4, &[""]),
+    (0x5b5, &[""]),
+    (0x5b6, &[""]),
+    (0x5b7, &[""]),
+    (0x5b8, &[""]),
+    (0x5b9, &[""]),
+    (0x5ba, &[""]),
+    (0x5bb, &[""]),
+    (0x5bc, &[""]),
+    (0x5bd, &[""]),
+    (0x5bf, &[""]),
+    (0x5c1, &[""]),
+    (0x5c2, &[""]),
+    (0x5c4, &[""]),
+    (0x5c5, &[""]),
+    (0x5c7, &[""]),
+    (0x5f0, &["\u{5d5}\u{5d5}"]),
+    (0x5f1, &["\u{5d5}\u{5d9}"]),
+    (0x5f2, &["\u{5d9}\u{5d9}"]),
+    (0x610, &[""]),
+    (0x611, &[""]),
+    (0x612, &[""]),
+    (0x613, &[""]),
+    (0x614, &[""]),
+    (0x615, &[""]),
+    (0x616, &[""]),
+    (0x617, &[""]),
+    (0x618, &[""]),
+    (0x619, &[""]),
+    (0x61a, &[""]),
+    (0x622, &["\u{627}"]),
+    (0x623, &["\u{627}"]),
+    (0x624, &["\u{648}"]),
+    (0x625, &["\u{627}"]),
+    (0x626, &["\u{64a}"]),
+    (0x64b, &[""]),
+    (0x64c, &[""]),
+    (0x64d, &[""]),
+    (0x64e, &[""]),
+    (0x64f, &[""]),
+    (0x650, &[""]),
+    (0x651, &[""]),
+    (0x652, &[""]),
+    (0x653, &[""]),
+    (0x654, &[""]),
+    (0x655, &[""]),
+    (0x656, &[""]),
+    (0x657, &[""]),
+    (0x658, &[""]),
+    (0x659, &[""]),
+    (0x65a, &[""]),
+    (0x65b, &[""]),
+    (0x65c, &[""]),
+    (0x65d, &[""]),
+    (0x65e, &[""]),
+    (0x65f, &[""]),
+    (0x670, &[""]),
+    (0x675, &["\u{627}\u{674}"]),
+    (0x676, &["\u{648}\u{674}"]),
+    (0x677, &["\u{6c7}\u{674}"]),
+    (0x678, &["\u{64a}\u{674}"]),
+    (0x6c0, &["\u{6d5}"]),
+    (0x6c2, &["\u{6c1}"]),
+    (0x6d3, &["\u{6d2}"]),
+    (0x6d6, &[""]),
+    (0x6d7, &[""]),
+    (0x6d8, &[""]),
+    (0x6d9, &[""]),
+    (0x6da, &[""]),
+    (0x6db, &[""]),
+    (0x6dc, &[""]),
+    (0x6df, &[""]),
+    (0x6e0, &[""]),
+    (0x6e1, &[""]),
+    (0x6e2, &[""]),
+    (0x6e3, &[""]),
+    (0x6e4, &[""]),
+    (0x6e7, &[""]),
+    (0x6e8, &[""]),
+    (0x6ea, &[""]),
+    (0x6eb, &[""]),
+    (0x6ec, &[""]),
+    (0x6ed, &[""]),
+    (0x898, &[""]),
+    (0x899, &[""]),
+    (0x89a, &[""]),
+    (0x89b, &[""]),
+    (0x89c, &[""]),
+    (0x89d, &[""]),
+    (0x89e, &[""]),
+    (0x89f, &[""]),
+    (0x8ca, &[""]),
+    (0x8cb, &[""]),
+    (0x8cc, &[""]),
+    (0x8cd, &[""]),
+    (0x8ce, &[""]),
+    (0x8cf, &[""]),
+    (0x8d0, &[""]),
+    (0x8d1, &[""]),
+    (0x8d2, &[""]),
+    (0x8d3, &[""]),
+    (0x8d4, &[""]),
+    (0x8d5, &[""]),
+    (0x8d6, &[""]),
+    (0x8d7, &[""]),
+    (0x8d8, &[""]),
+    (0x8d9, &[""]),
+    (0x8da, &[""]),
+    (0x8db, &[""]),
+    (0x8dc, &[""]),
+    (0x8dd, &[""]),
+    (0x8de, &[""]),
+    (0x8df, &[""]),
+    (0x8e0, &[""]),
+    (0x8e1, &[""]),
+    (0x8e3, &[""]),
+    (0x8e4, &[""]),
+    (0x8e5, &[""]),
+    (0x8e6, &[""]),
+    (0x8e7, &[""]),
+    (0x8e8, &[""]),
+    (0x8e9, &[""]),
+    (0x8ea, &[""]),
+    (0x8eb, &[""]),
+    (0x8ec, &[""]),
+    (0x8ed, &[""]),
+    (0x8ee, &[""]),
+    (0x8ef, &[""]),
+    (0x8f0, &[""]),
+    (0x8f1, &[""]),
+    (0x8f2, &[""]),
+    (0x8f3, &[""]),
+    (0x8f4, &[""]),
+    (0x8f5, &[""]),
+    (0x8f6, &[""]),
+    (0x8f7, &[""]),
+    (0x8f8, &[""]),
+    (0x8f9, &[""]),
+    (0x8fa, &[""]),
+    (0x8fb, &[""]),
+    (0x8fc, &[""]),
+    (0x8fd, &[""]),
+    (0x8fe, &[""]),
+    (0x8ff, &[""]),
+    (0x929, &["\u{928}\u{93c}"]),
+    (0x931, &["\u{930}\u{93c}"]),
+    (0x934, &["\u{933}\u{93c}"]),
+    (0x958, &["\u{915}\u{93c}"]),
+    (0x959, &["\u{916}\u{93c}"]),
+    (0x95a, &["\u{917}\u{93c}"]),
+    (0x95b, &["\u{91c}\u{93c}"]),
+    (0x95c, &["\u{921}\u{93c}"]),
+    (0x95d, &["\u{922}\u{93c}"]),
+    (0x95e, &["\u{92b}\u{93c}"]),
+    (0x95f, &["\u{92f}\u{93c}"]),
+    (0x9cb, &["\u{9c7}\u{9be}"]),
+    (0x9cc, &["\u{9c7}\u{9d7}"]),
+    (0x9dc, &["\u{9a1}\u{9bc}"]),
+    (0x9dd, &["\u{9a2}\u{9bc}"]),
+    (0x9df, &["\u{9af}\u{9bc}"]),
+    (0xa33, &["\u{a32}\u{a3c}"]),
+    (0xa36, &["\u{a38}\u{a3c}"]),
+    (0xa59, &["\u{a16}\u{a3c}"]),
+    (0xa5a, &["\u{a17}\u{a3c}"]),
+    (0xa5b, &["\u{a1c}\u{a3c}"]),
+    (0xa5e, &["\u{a2b}\u{a3c}"]),
+    (0xb48, &["\u{b47}\u{b56}"]),
+    (0xb4b, &["\u{b47}\u{b3e}"]),
+    (0xb4c, &["\u{b47}\u{b57}"]),
+    (0xb5c, &["\u{b21}\u{b3c}"]),
+    (0xb5d, &["\u{b22}\u{b3c}"]),
+    (0xb94, &["\u{b92}\u{bd7}"]),
+    (0xbca, &["\u{bc6}\u{bbe}"]),
+    (0xbcb, &["\u{bc7}\u{bbe}"]),
+    (0xbcc, &["\u{bc6}\u{bd7}"]),
+    (0xc48, &["\u{c46}\u{c56}"]),
+    (0xcc0, &["\u{cbf}\u{cd5}"]),
+    (0xcc7, &["\u{cc6}\u{cd5}"]),
+    (0xcc8, &["\u{cc6}\u{cd6}"]),
+    (0xcca, &["\u{cc6}\u{cc2}"]),
+    (0xccb, &["\u{cc6}\u{cc2}\u{cd5}"]),
+    (0xd4a, &["\u{d46}\u{d3e}"]),
+    (0xd4b, &["\u{d47}\u{d3e}"]),
+    (0xd4c, &["\u{d46}\u{d57}"]),
+    (0xdda, &["\u{dd9}\u{dca}"]),
+    (0xddc, &["\u{dd9}\u{dcf}"]),
+    (0xddd, &["\u{dd9}\u{dcf}\u{dca}"]),
+    (0xdde, &["\u{dd9}\u{ddf}"]),
+    (0xe33, &["\u{e4d}\u{e32}"]),
+    (0xeb3, &["\u{ecd}\u{eb2}"]),
+    (0xedc, &["\u{eab}\u{e99}"]),
+    (0xedd, &["\u{eab}\u{ea1}"]),
+    (0xf43, &["\u{f42}\u{fb7}"]),
+    (0xf4d, &["\u{f4c}\u{fb7}"]),
+    (0xf52, &["\u{f51}\u{fb7}"]),
+    (0xf57, &["\u{f56}\u{fb7}"]),
+    (0xf5c, &["\u{f5b}\u{fb7}"]),
+    (0xf69, &["\u{f40}\u{fb5}"]),
+    (0xf73, &["\u{f71}\u{f72}"]),
+    (0xf75, &["\u{f71}\u{f74}"]),
+    (0xf76, &["\u{fb2}\u{f80}"]),
+    (0xf77, &["\u{fb2}\u{f81}"]),
+    (0xf78, &["\u{fb3}\u{f80}"]),
+    (0xf79, &["\u{fb3}\u{f81}"]),
+    (0xf81, &["\u{f71}\u{f80}"]),
+    (0xf93, &["\u{f92}\u{fb7}"]),
+    (0xf9d, &["\u{f9c}\u{fb7}"]),
+    (0xfa2, &["\u{fa1}\u{fb7}"]),
+    (0xfa7, &["\u{fa6}\u{fb7}"]),
+    (0xfac, &["\u{fab}\u{fb7}"]),
+    (0xfb9, &["\u{f90}\u{fb5}"]),
+    (0x1026, &["\u{1025}\u{102e}"]),
+    (0x10fc, &["\u{10dc}"]),
+    (0x1ab0, &[""]),
+    (0x1ab1, &[""]),
+    (0x1ab2, &[""]),
+    (0x1ab3, &[""]),
+    (0x1ab4, &[""]),
+    (0x1ab5, &[""]),
+    (0x1ab6, &[""]),
+    (0x1ab7, &[""]),
+    (0x1ab8, &[""]),
+    (0x1ab9, &[""]),
+    (0x1aba, &[""]),
+    (0x1abb, &[""]),
+    (0x1abc, &[""]),
+    (0x1abd, &[""]),
+    (0x1abe, &[""]),
+    (0x1abf, &[""]),
+    (0x1ac0, &[""]),
+    (0x1ac1, &[""]),
+    (0x1ac2, &[""]),
+    (0x1ac3, &[""]),
+    (0x1ac4, &[""]),
+    (0x1ac5, &[""]),
+    (0x1ac6, &[""]),
+    (0x1ac7, &[""]),
+    (0x1ac8, &[""]),
+    (0x1ac9, &[""]),
+    (0x1aca, &[""]),
+    (0x1acb, &[""]),
+    (0x1acc, &[""]),
+    (0x1acd, &[""]),
+    (0x1ace, &[""]),
+    (0x1d00, &["A"]),
+    (0x1d01, &["AE"]),
+    (0x1d03, &["B"]),
+    (0x1d04, &["C"]),
+    (0x1d05, &["D"]),
+    (0x1d06, &["D"]),
+    (0x1d07, &["E"]),
+    (0x1d0a, &["J"]),
+    (0x1d0b, &["K"]),
+    (0x1d0c, &["L"]),
+    (0x1d0d, &["M"]),
+    (0x1d0f, &["O"]),
+    (0x1d18, &["P"]),
+    (0x1d1b, &["T"]),
+    (0x1d1c, &["U"]),
+    (0x1d20, &["V"]),
+    (0x1d21, &["W"]),
+    (0x1d22, &["Z"]),
+    (0x1d2c, &["A"]),
+    (0x1d2d, &["\u{c6}"]),
+    (0x1d2e, &["B"]),
+    (0x1d30, &["D"]),
+    (0x1d31, &["E"]),
+    (0x1d32, &["\u{18e}"]),
+    (0x1d33, &["G"]),
+    (0x1d34, &["H"]),
+    (0x1d35, &["I"]),
+    (0x1d36, &["J"]),
+    (0x1d37, &["K"]),
+    (0x1d38, &["L"]),
+    (0x1d39, &["M"]),
+    (0x1d3a, &["N"]),
+    (0x1d3c, &["O"]),
+    (0x1d3d, &["\u{222}"]),
+    (0x1d3e, &["P"]),
+    (0x1d3f, &["R"]),
+    (0x1d40, &["T"]),
+    (0x1d41, &["U"]),
+    (0x1d42, &["W"]),
+    (0x1d43, &["a"]),
+    (0x1d44, &["\u{250}"]),
+    (0x1d45, &["\u{251}"]),
+    (0x1d46, &["\u{1d02}"]),
+    (0x1d47, &["b"]),
+    (0x1d48, &["d"]),
+    (0x1d49, &["e"]),
+    (0x1d4a, &["\u{259}"]),
+    (0x1d4b, &["\u{25b}"]),
+    (0x1d4c, &["\u{25c}"]),
+    (0x1d4d, &["g"]),
+    (0x1d4f, &["k"]),
+    (0x1d50, &["m"]),
+    (0x1d51, &["\u{14b}"]),
+    (0x1d52, &["o"]),
+    (0x1d53, &["\u{254}"]),
+    (0x1d54, &["\u{1d16}"]),
+    (0x1d55, &["\u{1d17}"]),
+    (0x1d56, &["p"]),
+    (0x1d57, &["t"]),
+    (0x1d58, &["u"]),
+    (0x1d59, &["\u{1d1d}"]),
+    (0x1d5a, &["\u{26f}"]),
+    (0x1d5b, &["v"]),
+    (0x1d5c, &["\u{1d25}"]),
+    (0x1d5d, &["\u{3b2}"]),
+    (0x1d5e, &["\u{3b3}"]),
+    (0x1d5f, &["\u{3b4}"]),
+    (0x1d60, &["\u{3c6}"]),
+    (0x1d61, &["\u{3c7}"]),
+    (0x1d62, &["i"]),
+    (0x1d63, &["r"]),
+    (0x1d64, &["u"]),
+    (0x1d65, &["v"]),
+    (0x1d66, &["\u{3b2}"]),
+    (0x1d67, &["\u{3b3}"]),
+    (0x1d68, &["\u{3c1}"]),
+    (0x1d69, &["\u{3c6}"]),
+    (0x1d6a, &["\u{3c7}"]),
+    (0x1d6b, &["ue"]),
+    (0x1d6c, &["b"]),
+    (0x1d6d, &["d"]),
+    (0x1d6e, &["f"]),
+    (0x1d6f, &["m"]),
+    (0x1d70, &["n"]),
+    (0x1d71, &["p"]),
+    (0x1d72, &["r"]),
+    (0x1d73, &["r"]),
+    (0x1d74, &["s"]),
+    (0x1d75, &["t"]),
+    (0x1d76, &["z"]),
+    (0x1d78, &["\u{43d}"]),
+    (0x1d7a, &["th"]),
+    (0x1d7b, &["I"]),
+    (0x1d7d, &["p"]),
+    (0x1d7e, &["U"]),
+    (0x1d80, &["b"]),
+    (0x1d81, &["d"]),
+    (0x1d82, &["f"]),
+    (0x1d83, &["g"]),
+    (0x1d84, &["k"]),
+    (0x1d85, &["l"]),
+    (0x1d86, &["m"]),
+    (0x1d87, &["n"]),
+    (0x1d88, &["p"]),
+    (0x1d89, &["r"]),
+    (0x1d8a, &["s"]),
+    (0x1d8c, &["v"]),
+    (0x1d8d, &["x"]),
+    (0x1d8e, &["z"]),
+    (0x1d8f, &["a"]),
+    (0x1d91, &["d"]),
+    (0x1d92, &["e"]),
+    (0x1d93, &["e"]),
+    (0x1d96, &["i"]),
+    (0x1d99, &["u"]),
+    (0x1d9b, &["\u{252}"]),
+    (0x1d9c, &["c"]),
+    (0x1d9d, &["\u{255}"]),
+    (0x1d9e, &["\u{f0}"]),
+    (0x1d9f, &["\u{25c}"]),
+    (0x1da0, &["f"]),
+    (0x1da1, &["\u{25f}"]),
+    (0x1da2, &["\u{261}"]),
+    (0x1da3, &["\u{265}"]),
+    (0x1da4, &["\u{268}"]),
+    (0x1da5, &["\u{269}"]),
+    (0x1da6, &["\u{26a}"]),
+    (0x1da7, &["\u{1d7b}"]),
+    (0x1da8, &["\u{29d}"]),
+    (0x1da9, &["\u{26d}"]),
+    (0x1daa, &["\u{1d85}"]),
+    (0x1dab, &["\u{29f}"]),
+    (0x1dac, &["\u{271}"]),
+    (0x1dad, &["\u{270}"]),
+    (0x1dae, &["\u{272}"]),
+    (0x1daf, &["\u{273}"]),
+    (0x1db0, &["\u{274}"]),
+    (0x1db1, &["\u{275}"]),
+    (0x1db2, &["\u{278}"]),
+    (0x1db3, &["\u{282}"]),
+    (0x1db4, &["\u{283}"]),
+    (0x1db5, &["\u{1ab}"]),
+    (0x1db6, &["\u{289}"]),
+    (0x1db7, &["\u{28a}"]),
+    (0x1db8, &["\u{1d1c}"]),
+    (0x1db9, &["\u{28b}"]),
+    (0x1dba, &["\u{28c}"]),
+    (0x1dbb, &["z"]),
+    (0x1dbc, &["\u{290}"]),
+    (0x1dbd, &["\u{291}"]),
+    (0x1dbe, &["\u{292}"]),
+    (0x1dbf, &["\u{3b8}"]),
+    (0x1dc0, &[""]),
+    (0x1dc1, &[""]),
+    (0x1dc2, &[""]),
+    (0x1dc3, &[""]),
+    (0x1dc4, &[""]),
+    (0x1dc5, &[""]),
+    (0x1dc6, &[""]),
+    (0x1dc7, &[""]),
+    (0x1dc8, &[""]),
+    (0x1dc9, &[""]),
+    (0x1dca, &[""]),
+    (0x1dcb, &[""]),
+    (0x1dcc, &[""]),
+    (0x1dcd, &[""]),
+    (0x1dce, &[""]),
+    (0x1dcf, &[""]),
+    (0x1dd0, &[""]),
+    (0x1dd1, &[""]),
+    (0x1dd2, &[""]),
+    (0x1dd3, &[""]),
+    (0x1dd4, &[""]),
+    (0x1dd5, &[""]),
+    (0x1dd6, &[""]),
+    (0x1dd7, &[""]),
+    (0x1dd8, &[""]),
+    (0x1dd9, &[""]),
+    (0x1dda, &[""]),
+    (0x1ddb, &[""]),
+    (0x1ddc, &[""]),
+    (0x1ddd, &[""]),
+    (0x1dde, &[""]),
+    (0x1ddf, &[""]),
+    (0x1de0, &[""]),
+    (0x1de1, &[""]),
+    (0x1de2, &[""]),
+    (0x1de3, &[""]),
+    (0x1de4, &[""]),
+    (0x1de5, &[""]),
+    (0x1de6, &[""]),
+    (0x1de7, &[""]),
+    (0x1de8, &[""]),
+    (0x1de9, &[""]),
+    (0x1dea, &[""]),
+    (0x1deb, &[""]),
+    (0x1dec, &[""]),
+    (0x1ded, &[""]),
+    (0x1dee, &[""]),
+    (0x1def, &[""]),
+    (0x1df0, &[""]),
+    (0x1df1, &[""]),
+    (0x1df2, &[""]),
+    (0x1df3, &[""]),
+    (0x1df4, &[""]),
+    (0x1df5, &[""]),
+    (0x1df6, &[""]),
+    (0x1df7, &[""]),
+    (0x1df8, &[""]),
+    (0x1df9, &[""]),
+    (0x1dfa, &[""]),
+    (0x1dfb, &[""]),
+    (0x1dfc, &[""]),
+    (0x1dfd, &[""]),
+    (0x1dfe, &[""]),
+    (0x1dff, &[""]),
+    (0x1e00, &["A"]),
+    (0x1e01, &["a"]),
+    (0x1e02, &["B"]),
+    (0x1e03, &["b"]),
+    (0x1e04, &["B"]),
+    (0x1e05, &["b"]),
+    (0x1e06, &["B"]),
+    (0x1e07, &["b"]),
+    (0x1e08, &["C"]),
+    (0x1e09, &["c"]),
+    (0x1e0a, &["D"]),
+    (0x1e0b, &["d"]),
+    (0x1e0c, &["D"]),
+    (0x1e0d, &["d"]),
+    (0x1e0e, &["D"]),
+    (0x1e0f, &["d"]),
+    (0x1e10, &["D"]),
+    (0x1e11, &["d"]),
+    (0x1e12, &["D"]),
+    (0x1e13, &["d"]),
+    (0x1e14, &["E"]),
+    (0x1e15, &["e"]),
+    (0x1e16, &["E"]),
+    (0x1e17, &["e"]),
+    (0x1e18, &["E"]),
+    (0x1e19, &["e"]),
+    (0x1e1a, &["E"]),
+    (0x1e1b, &["e"]),
+    (0x1e1c, &["E"]),
+    (0x1e1d, &["e"]),
+    (0x1e1e, &["F"]),
+    (0x1e1f, &["f"]),
+    (0x1e20, &["G"]),
+    (0x1e21, &["g"]),
+    (0x1e22, &["H"]),
+    (0x1e23, &["h"]),
+    (0x1e24, &["H"]),
+    (0x1e25, &["h"]),
+    (0x1e26, &["H"]),
+    (0x1e27, &["h"]),
+    (0x1e28, &["H"]),
+    (0x1e29, &["h"]),
+    (0x1e2a, &["H"]),
+    (0x1e2b, &["h"]),
+    (0x1e2c, &["I"]),
+    (0x1e2d, &["i"]),
+    (0x1e2e, &["I"]),
+    (0x1e2f, &["i"]),
+    (0x1e30, &["K"]),
+    (0x1e31, &["k"]),
+    (0x1e32, &["K"]),
+    (0x1e33, &["k"]),
+    (0x1e34, &["K"]),
+    (0x1e35, &["k"]),
+    (0x1e36, &["L"]),
+    (0x1e37, &["l"]),
+    (0x1e38, &["L"]),
+    (0x1e39, &["l"]),
+    (0x1e3a, &["L"]),
+    (0x1e3b, &["l"]),
+    (0x1e3c, &["L"]),
+    (0x1e3d, &["l"]),
+    (0x1e3e, &["M"]),
+    (0x1e3f, &["m"]),
+    (0x1e40, &["M"]),
+    (0x1e41, &["m"]),
+    (0x1e42, &["M"]),
+    (0x1e43, &["m"]),
+    (0x1e44, &["N"]),
+    (0x1e45, &["n"]),
+    (0x1e46, &["N"]),
+    (0x1e47, &["n"]),
+    (0x1e48, &["N"]),
+    (0x1e49, &["n"]),
+    (0x1e4a, &["N"]),
+    (0x1e4b, &["n"]),
+    (0x1e4c, &["O"]),
+    (0x1e4d, &["o"]),
+    (0x1e4e, &["O"]),
+    (0x1e4f, &["o"]),
+    (0x1e50, &["O"]),
+    (0x1e51, &["o"]),
+    (0x1e52, &["O"]),
+    (0x1e53, &["o"]),
+    (0x1e54, &["P"]),
+    (0x1e55, &["p"]),
+    (0x1e56, &["P"]),
+    (0x1e57, &["p"]),
+    (0x1e58, &["R"]),
+    (0x1e59, &["r"]),
+    (0x1e5a, &["R"]),
+    (0x1e5b, &["r"]),
+    (0x1e5c, &["R"]),
+    (0x1e5d, &["r"]),
+    (0x1e5e, &["R"]),
+    (0x1e5f, &["r"]),
+    (0x1e60, &["S"]),
+    (0x1e61, &["s"]),
+    (0x1e62, &["S"]),
+    (0x1e63, &["s"]),
+    (0x1e64, &["S"]),
+    (0x1e65, &["s"]),
+    (0x1e66, &["S"]),
+    (0x1e67, &["s"]),
+    (0x1e68, &["S"]),
+    (0x1e69, &["s"]),
+    (0x1e6a, &["T"]),
+    (0x1e6b, &["t"]),
+    (0x1e6c, &["T"]),
+    (0x1e6d, &["t"]),
+    (0x1e6e, &["T"]),
+    (0x1e6f, &["t"]),
+    (0x1e70, &["T"]),
+    (0x1e71, &["t"]),
+    (0x1e72, &["U"]),
+    (0x1e73, &["u"]),
+    (0x1e74, &["U"]),
+    (0x1e75, &["u"]),
+    (0x1e76, &["U"]),
+    (0x1e77, &["u"]),
+    (0x1e78, &["U"]),
+    (0x1e79, &["u"]),
+    (0x1e7a, &["U"]),
+    (0x1e7b, &["u"]),
+    (0x1e7c, &["V"]),
+    (0x1e7d, &["v"]),
+    (0x1e7e, &["V"]),
+    (0x1e7f, &["v"]),
+    (0x1e80, &["W"]),
+    (0x1e81, &["w"]),
+    (0x1e82, &["W"]),
+    (0x1e83, &["w"]),
+    (0x1e84, &["W"]),
+    (0x1e85, &["w"]),
+    (0x1e86, &["W"]),
+    (0x1e87, &["w"]),
+    (0x1e88, &["W"]),
+    (0x1e89, &["w"]),
+    (0x1e8a, &["X"]),
+    (0x1e8b, &["x"]),
+    (0x1e8c, &["X"]),
+    (0x1e8d, &["x"]),
+    (0x1e8e, &["Y"]),
+    (0x1e8f, &["y"]),
+    (0x1e90, &["Z"]),
+    (0x1e91, &["z"]),
+    (0x1e92, &["Z"]),
+    (0x1e93, &["z"]),
+    (0x1e94, &["Z"]),
+    (0x1e95, &["z"]),
+    (0x1e96, &["h"]),
+    (0x1e97, &["t"]),
+    (0x1e98, &["w"]),
+    (0x1e99, &["y"]),
+    (0x1e9a, &["a"]),
+    (0x1e9b, &["s"]),
+    (0x1e9c, &["s"]),
+    (0x1e9d, &["s"]),
+    (0x1e9e, &["SS"]),
+    (0x1ea0, &["A"]),
+    (0x1ea1, &["a"]),
+    (0x1ea2, &["A"]),
+    (0x1ea3, &["a"]),
+    (0x1ea4, &["A"]),
+    (0x1ea5, &["a"]),
+    (0x1ea6, &["A"]),
+    (0x1ea7, &["a"]),
+    (0x1ea8, &["A"]),
+    (0x1ea9, &["a"]),
+    (0x1eaa, &["A"]),
+    (0x1eab, &["a"]),
+    (0x1eac, &["A"]),
+    (0x1ead, &["a"]),
+    (0x1eae, &["A"]),
+    (0x1eaf, &["a"]),
+    (0x1eb0, &["A"]),
+    (0x1eb1, &["a"]),
+    (0x1eb2, &["A"]),
+    (0x1eb3, &["a"]),
+    (0x1eb4, &["A"]),
+    (0x1eb5, &["a"]),
+    (0x1eb6, &["A"]),
+    (0x1eb7, &["a"]),
+    (0x1eb8, &["E"]),
+    (0x1eb9, &["e"]),
+    (0x1eba, &["E"]),
+    (0x1ebb, &["e"]),
+    (0x1ebc, &["E"]),
+    (0x1ebd, &["e"]),
+    (0x1ebe, &["E"]),
+    (0x1ebf, &["e"]),
+    (0x1ec0, &["E"]),
+    (0x1ec1, &["e"]),
+    (0x1ec2, &["E"]),
+    (0x1ec3, &["e"]),
+    (0x1ec4, &["E"]),
+    (0x1ec5, &["e"]),
+    (0x1ec6, &["E"]),
+    (0x1ec7, &["e"]),
+    (0x1ec8, &["I"]),
+    (0x1ec9, &["i"]),
+    (0x1eca, &["I"]),
+    (0x1ecb, &["i"]),
+    (0x1ecc, &["O"]),
+    (0x1ecd, &["o"]),
+    (0x1ece, &["O"]),
+    (0x1ecf, &["o"]),
+    (0x1ed0, &["O"]),
+    (0x1ed1, &["o"]),
+    (0x1ed2, &["O"]),
+    (0x1ed3, &["o"]),
+    (0x1ed4, &["O"]),
+    (0x1ed5, &["o"]),
+    (0x1ed6, &["O"]),
+    (0x1ed7, &["o"]),
+    (0x1ed8, &["O"]),
+    (0x1ed9, &["o"]),
+    (0x1eda, &["O"]),
+    (0x1edb, &["o"]),
+    (0x1edc, &["O"]),
+    (0x1edd, &["o"]),
+    (0x1ede, &["O"]),
+    (0x1edf, &["o"]),
+    (0x1ee0, &["O"]),
+    (0x1ee1, &["o"]),
+    (0x1ee2, &["O"]),
+    (0x1ee3, &["o"]),
+    (0x1ee4, &["U"]),
+    (0x1ee5, &["u"]),
+    (0x1ee6, &["U"]),
+    (0x1ee7, &["u"]),
+    (0x1ee8, &["U"]),
+    (0x1ee9, &["u"]),
+    (0x1eea, &["U"]),
+    (0x1eeb, &["u"]),
+    (0x1eec, &["U"]),
+    (0x1eed, &["u"]),
+    (0x1eee, &["U"]),
+    (0x1eef, &["u"]),
+    (0x1ef0, &["U"]),
+    (0x1ef1, &["u"]),
+    (0x1ef2, &["Y"]),
+    (0x1ef3, &["y"]),
+    (0x1ef4, &["Y"]),
+    (0x1ef5, &["y"]),
+    (0x1ef6, &["Y"]),
+    (0x1ef7, &["y"]),
+    (0x1ef8, &["Y"]),
+    (0x1ef9, &["y"]),
+    (0x1efa, &["LL"]),
+    (0x1efb, &["ll"]),
+    (0x1efc, &["V"]),
+    (0x1efd, &["v"]),
+    (0x1efe, &["Y"]),
+    (0x1eff, &["y"]),
+    (0x1f00, &["\u{3b1}"]),
+    (0x1f01, &["\u{3b1}"]),
+    (0x1f02, &["\u{3b1}"]),
+    (0x1f03, &["\u{3b1}"]),
+    (0x1f04, &["\u{3b1}"]),
+    (0x1f05, &["\u{3b1}"]),
+    (0x1f06, &["\u{3b1}"]),
+    (0x1f07, &["\u{3b1}"]),
+    (0x1f08, &["\u{391}"]),
+    (0x1f09, &["\u{391}"]),
+    (0x1f0a, &["\u{391}"]),
+    (0x1f0b, &["\u{391}"]),
+    (0x1f0c, &["\u{391}"]),
+    (0x1f0d, &["\u{391}"]),
+    (0x1f0e, &["\u{391}"]),
+    (0x1f0f, &["\u{391}"]),
+    (0x1f10, &["\u{3b5}"]),
+    (0x1f11, &["\u{3b5}"]),
+    (0x1f12, &["\u{3b5}"]),
+    (0x1f13, &["\u{3b5}"]),
+    (0x1f14, &["\u{3b5}"]),
+    (0x1f15, &["\u{3b5}"]),
+    (0x1f18, &["\u{395}"]),
+    (0x1f19, &["\u{395}"]),
+    (0x1f1a, &["\u{395}"]),
+    (0x1f1b, &["\u{395}"]),
+    (0x1f1c, &["\u{395}"]),
+    (0x1f1d, &["\u{395}"]),
+    (0x1f20, &["\u{3b7}"]),
+    (0x1f21, &["\u{3b7}"]),
+    (0x1f22, &["\u{3b7}"]),
+    (0x1f23, &["\u{3b7}"]),
+    (0x1f24, &["\u{3b7}"]),
+    (0x1f25, &["\u{3b7}"]),
+    (0x1f26, &["\u{3b7}"]),
+    (0x1f27, &["\u{3b7}"]),
+    (0x1f28, &["\u{397}"]),
+    (0x1f29, &["\u{397}"]),
+    (0x1f2a, &["\u{397}"]),
+    (0x1f2b, &["\u{397}"]),
+    (0x1f2c, &["\u{397}"]),
+    (0x1f2d, &["\u{397}"]),
+    (0x1f2e, &["\u{397}"]),
+    (0x1f2f, &["\u{397}"]),
+    (0x1f30, &["\u{3b9}"]),
+    (0x1f31, &["\u{3b9}"]),
+    (0x1f32, &["\u{3b9}"]),
+    (0x1f33, &["\u{3b9}"]),
+    (0x1f34, &["\u{3b9}"]),
+    (0x1f35, &["\u{3b9}"]),
+    (0x1f36, &["\u{3b9}"]),
+    (0x1f37, &["\u{3b9}"]),
+    (0x1f38, &["\u{399}"]),
+    (0x1f39, &["\u{399}"]),
+    (0x1f3a, &["\u{399}"]),
+    (0x1f3b, &["\u{399}"]),
+    (0x1f3c, &["\u{399}"]),
+    (0x1f3d, &["\u{399}"]),
+    (0x1f3e, &["\u{399}"]),
+    (0x1f3f, &["\u{399}"]),
+    (0x1f40, &["\u{3bf}"]),
+    (0x1f41, &["\u{3bf}"]),
+    (0x1f42, &["\u{3bf}"]),
+    (0x1f43, &["\u{3bf}"]),
+    (0x1f44, &["\u{3bf}"]),
+    (0x1f45, &["\u{3bf}"]),
+    (0x1f48, &["\u{39f}"]),
+    (0x1f49, &["\u{39f}"]),
+    (0x1f4a, &["\u{39f}"]),
+    (0x1f4b, &["\u{39f}"]),
+    (0x1f4c, &["\u{39f}"]),
+    (0x1f4d, &["\u{39f}"]),
+    (0x1f50, &["\u{3c5}"]),
+    (0x1f51, &["\u{3c5}"]),
+    (0x1f52, &["\u{3c5}"]),
+    (0x1f53, &["\u{3c5}"]),
+    (0x1f54, &["\u{3c5}"]),
+    (0x1f55, &["\u{3c5}"]),
+    (0x1f56, &["\u{3c5}"]),
+    (0x1f57, &["\u{3c5}"]),
+    (0x1f59, &["\u{3a5}"]),
+    (0x1f5b, &["\u{3a5}"]),
+    (0x1f5d, &["\u{3a5}"]),
+    (0x1f5f, &["\u{3a5}"]),
+    (0x1f60, &["\u{3c9}"]),
+    (0x1f61, &["\u{3c9}"]),
+    (0x1f62, &["\u{3c9}"]),
+    (0x1f63, &["\u{3c9}"]),
+    (0x1f64, &["\u{3c9}"]),
+    (0x1f65, &["\u{3c9}"]),
+    (0x1f66, &["\u{3c9}"]),
+    (0x1f67, &["\u{3c9}"]),
+    (0x1f68, &["\u{3a9}"]),
+    (0x1f69, &["\u{3a9}"]),
+    (0x1f6a, &["\u{3a9}"]),
+    (0x1f6b, &["\u{3a9}"]),
+    (0x1f6c, &["\u{3a9}"]),
+    (0x1f6d, &["\u{3a9}"]),
+    (0x1f6e, &["\u{3a9}"]),
+    (0x1f6f, &["\u{3a9}"]),
+    (0x1f70, &["\u{3b1}"]),
+    (0x1f71, &["\u{3b1}"]),
+    (0x1f72, &["\u{3b5}"]),
+    (0x1f73, &["\u{3b5}"]),
+    (0x1f74, &["\u{3b7}"]),
+    (0x1f75, &["\u{3b7}"]),
+    (0x1f76, &["\u{3b9}"]),
+    (0x1f77, &["\u{3b9}"]),
+    (0x1f78, &["\u{3bf}"]),
+    (0x1f79, &["\u{3bf}"]),
+    (0x1f7a, &["\u{3c5}"]),
+    (0x1f7b, &["\u{3c5}"]),
+    (0x1f7c, &["\u{3c9}"]),
+    (0x1f7d, &["\u{3c9}"]),
+    (0x1f80, &["\u{3b1}"]),
+    (0x1f81, &["\u{3b1}"]),
+    (0x1f82, &["\u{3b1}"]),
+    (0x1f83, &["\u{3b1}"]),
+    (0x1f84, &["\u{3b1}"]),
+    (0x1f85, &["\u{3b1}"]),
+    (0x1f86, &["\u{3b1}"]),
+    (0x1f87, &["\u{3b1}"]),
+    (0x1f88, &["\u{391}"]),
+    (0x1f89, &["\u{391}"]),
+    (0x1f8a, &["\u{391}"]),
+    (0x1f8b, &["\u{391}"]),
+    (0x1f8c, &["\u{391}"]),
+    (0x1f8d, &["\u{391}"]),
+    (0x1f8e, &["\u{391}"]),
+    (0x1f8f, &["\u{391}"]),
+    (0x1f90, &["\u{3b7}"]),
+    (0x1f91, &["\u{3b7}"]),
+    (0x1f92, &["\u{3b7}"]),
+    (0x1f93, &["\u{3b7}"]),
+    (0x1f94, &["\u{3b7}"]),
+    (0x1f95, &["\u{3b7}"]),
+    (0x1f96, &["\u{3b7}"]),
+    (0x1f97, &["\u{3b7}"]),
+    (0x1f98, &["\u{397}"]),
+    (0x1f99, &["\u{397}"]),
+    (0x1f9a, &["\u{397}"]),
+    (0x1f9b, &["\u{397}"]),
+    (0x1f9c, &["\u{397}"]),
+    (0x1f9d, &["\u{397}"]),
+    (0x1f9e, &["\u{397}"]),
+    (0x1f9f, &["\u{397}"]),
+    (0x1fa0, &["\u{3c9}"]),
+    (0x1fa1, &["\u{3c9}"]),
+    (0x1fa2, &["\u{3c9}"]),
+    (0x1fa3, &["\u{3c9}"]),
+    (0x1fa4, &["\u{3c9}"]),
+    (0x1fa5, &["\u{3c9}"]),
+    (0x1fa6, &["\u{3c9}"]),
+    (0x1fa7, &["\u{3c9}"]),
+    (0x1fa8, &["\u{3a9}"]),
+    (0x1fa9, &["\u{3a9}"]),
+    (0x1faa, &["\u{3a9}"]),
+    (0x1fab, &["\u{3a9}"]),
+    (0x1fac, &["\u{3a9}"]),
+    (0x1fad, &["\u{3a9}"]),
+    (0x1fae, &["\u{3a9}"]),
+    (0x1faf, &["\u{3a9}"]),
+    (0x1fb0, &["\u{3b1}"]),
+    (0x1fb1, &["\u{3b1}"]),
+    (0x1fb2, &["\u{3b1}"]),
+    (0x1fb3, &["\u{3b1}"]),
+    (0x1fb4, &["\u{3b1}"]),
+    (0x1fb6, &["\u{3b1}"]),
+    (0x1fb7, &["\u{3b1}"]),
+    (0x1fb8, &["\u{391}"]),
+    (0x1fb9, &["\u{391}"]),
+    (0x1fba, &["\u{391}"]),
+    (0x1fbb, &["\u{391}"]),
+    (0x1fbc, &["\u{391}"]),
+    (0x1fbe, &["\u{3b9}"]),
+    (0x1fc1, &["\u{a8}"]),
+    (0x1fc2, &["\u{3b7}"]),
+    (0x1fc3, &["\u{3b7}"]),
+    (0x1fc4, &["\u{3b7}"]),
+    (0x1fc6, &["\u{3b7}"]),
+    (0x1fc7, &["\u{3b7}"]),
+    (0x1fc8, &["\u{395}"]),
+    (0x1fc9, &["\u{395}"]),
+    (0x1fca, &["\u{397}"]),
+    (0x1fcb, &["\u{397}"]),
+    (0x1fcc, &["\u{397}"]),
+    (0x1fcd, &["\u{1fbf}"]),
+    (0x1fce, &["\u{1fbf}"]),
+    (0x1fcf, &["\u{1fbf}"]),
+    (0x1fd0, &["\u{3b9}"]),
+    (0x1fd1, &["\u{3b9}"]),
+    (0x1fd2, &["\u{3b9}"]),
+    (0x1fd3, &["\u{3b9}"]),
+    (0x1fd6, &["\u{3b9}"]),
+    (0x1fd7, &["\u{3b9}"]),
+    (0x1fd8, &["\u{399}"]),
+    (0x1fd9, &["\u{399}"]),
+    (0x1fda, &["\u{399}"]),
+    (0x1fdb, &["\u{399}"]),
+    (0x1fdd, &["\u{1ffe}"]),
+    (0x1fde, &["\u{1ffe}"]),
+    (0x1fdf, &["\u{1ffe}"]),
+    (0x1fe0, &["\u{3c5}"]),
+    (0x1fe1, &["\u{3c5}"]),
+    (0x1fe2, &["\u{3c5}"]),
+    (0x1fe3, &["\u{3c5}"]),
+    (0x1fe4, &["\u{3c1}"]),
+    (0x1fe5, &["\u{3c1}"]),
+    (0x1fe6, &["\u{3c5}"]),
+    (0x1fe7, &["\u{3c5}"]),
+    (0x1fe8, &["\u{3a5}"]),
+    (0x1fe9, &["\u{3a5}"]),
+    (0x1fea, &["\u{3a5}"]),
+    (0x1feb, &["\u{3a5}"]),
+    (0x1fec, &["\u{3a1}"]),
+    (0x1fed, &["\u{a8}"]),
+    (0x1fee, &["\u{a8}"]),
+    (0x1fef, &["`"]),
+    (0x1ff2, &["\u{3c9}"]),
+    (0x1ff3, &["\u{3c9}"]),
+    (0x1ff4, &["\u{3c9}"]),
+    (0x1ff6, &["\u{3c9}"]),
+    (0x1ff7, &["\u{3c9}"]),
+    (0x1ff8, &["\u{39f}"]),
+    (0x1ff9, &["\u{39f}"]),
+    (0x1ffa, &["\u{3a9}"]),
+    (0x1ffb, &["\u{3a9}"]),
+    (0x1ffc, &["\u{3a9}"]),
+    (0x1ffd, &["\u{b4}"]),
+    (0x2000, &["\u{2002}", " "]),
+    (0x2001, &["\u{2003}", " "]),
+    (0x2002, &[" "]),
+    (0x2003, &[" "]),
+    (0x2004, &[" "]),
+    (0x2005, &[" "]),
+    (0x2006, &[" "]),
+    (0x2008, &[" "]),
+    (0x2009, &[" "]),
+    (0x200a, &[" "]),
+    (0x200b, &[""]),
+    (0x2010, &["-"]),
+    (0x2011, &["-"]),
+    (0x2012, &["-"]),
+    (0x2013, &["-"]),
+    (0x2014, &["--"]),
+    (0x2015, &["-"]),
+    (0x2018, &["'"]),
+    (0x2019, &["'"]),
+    (0x201a, &[","]),
+    (0x201b, &["'"]),
+    (0x201c, &["\""]),
+    (0x201d, &["\""]),
+    (0x201e, &[",,"]),
+    (0x201f, &["\""]),
+    (0x2020, &["+"]),
+    (0x2022, &["o"]),
+    (0x2024, &["."]),
+    (0x2025, &[".."]),
+    (0x2026, &["..."]),
+    (0x202f, &["\u{a0}", " "]),
+    (0x2032, &["\u{b4}"]),
+    (0x2033, &["\u{2032}\u{2032}", "\u{b4}\u{b4}"]),
+    (0x2034, &["\u{2032}\u{2032}\u{2032}", "\u{b4}\u{b4}\u{b4}"]),
+    (0x2035, &["`"]),
+    (0x2036, &["\u{2035}\u{2035}", "``"]),
+    (0x2037, &["\u{2035}\u{2035}\u{2035}", "```"]),
+    (0x2039, &["<"]),
+    (0x203a, &[">"]),
+    (0x203c, &["!!"]),
+    (0x2044, &["/"]),
+    (0x2047, &["??"]),
+    (0x2048, &["?!"]),
+    (0x2049, &["!?"]),
+    (0x204a, &["&"]),
+    (0x2057, &["\u{2032}\u{2032}\u{2032}\u{2032}"]),
+    (0x205f, &[" "]),
+    (0x2060, &[""]),
+    (0x2061, &[""]),
+    (0x2062, &[""]),
+    (0x2063, &[""]),
+    (0x2070, &["0"]),
+    (0x2071, &["i"]),
+    (0x2074, &["4"]),
+    (0x2075, &["5"]),
+    (0x2076, &["6"]),
+    (0x2077, &["7"]),
+    (0x2078, &["8"]),
+    (0x2079, &["9"]),
+    (0x207a, &["+"]),
+    (0x207b, &["\u{2212}"]),
+    (0x207c, &["="]),
+    (0x207d, &["("]),
+    (0x207e, &[")"]),
+    (0x207f, &["n"]),
+    (0x2080, &["0"]),
+    (0x2081, &["1"]),
+    (0x2082, &["2"]),
+    (0x2083, &["3"]),
+    (0x2084, &["4"]),
+    (0x2085, &["5"]),
+    (0x2086, &["6"]),
+    (0x2087, &["7"]),
+    (0x2088, &["8"]),
+    (0x2089, &["9"]),
+    (0x208a, &["+"]),
+    (0x208b, &["\u{2212}"]),
+    (0x208c, &["="]),
+    (0x208d, &["("]),
+    (0x208e, &[")"]),
+    (0x2090, &["a"]),
+    (0x2091, &["e"]),
+    (0x2092, &["o"]),
+    (0x2093, &["x"]),
+    (0x2094, &["\u{259}"]),
+    (0x2095, &["h"]),
+    (0x2096, &["k"]),
+    (0x2097, &["l"]),
+    (0x2098, &["m"]),
+    (0x2099, &["n"]),
+    (0x209a, &["p"]),
+    (0x209b, &["s"]),
+    (0x209c, &["t"]),
+    (0x20a0, &["CE"]),
+    (0x20a1, &["C="]),
+    (0x20a2, &["Cr"]),
+    (0x20a3, &["Fr."]),
+    (0x20a4, &["L."]),
+    (0x20a7, &["Pts"]),
+    (0x20a8, &["Rs"]),
+    (0x20a9, &["KRW"]),
+    (0x20aa, &["ILS"]),
+    (0x20ab, &["\u{110}\u{1ed3}ng", "Dong"]),
+    (0x20ac, &["EUR"]),
+    (0x20af, &["GRD"]),
+    (0x20b1, &["PHP"]),
+    (0x20b4, &["\u{433}\u{440}\u{43d}", "UAH"]),
+    (0x20b8, &["KZT"]),
+    (0x20b9, &["INR"]),
+    (0x20ba, &["TL"]),
+    (0x20bd, &["\u{440}\u{443}\u{431}", "RUB"]),
+    (0x20be, &["GEL"]),
+    (0x20d0, &[""]),
+    (0x20d1, &[""]),
+    (0x20d2, &[""]),
+    (0x20d3, &[""]),
+    (0x20d4, &[""]),
+    (0x20d5, &[""]),
+    (0x20d6, &[""]),
+    (0x20d7, &[""]),
+    (0x20d8, &[""]),
+    (0x20d9, &[""]),
+    (0x20da, &[""]),
+    (0x20db, &[""]),
+    (0x20dc, &[""]),
+    (0x20dd, &[""]),
+    (0x20de, &[""]),
+    (0x20df, &[""]),
+    (0x20e0, &[""]),
+    (0x20e1, &[""]),
+    (0x20e2, &[""]),
+    (0x20e3, &[""]),
+    (0x20e4, &[""]),
+    (0x20e5, &[""]),
+    (0x20e6, &[""]),
+    (0x20e7, &[""]),
+    (0x20e8, &[""]),
+    (0x20e9, &[""]),
+    (0x20ea, &[""]),
+    (0x20eb, &[""]),
+    (0x20ec, &[""]),
+    (0x20ed, &[""]),
+    (0x20ee, &[""]),
+    (0x20ef, &[""]),
+    (0x20f0, &[""]),
+    (0x2100, &["a/c"]),
+    (0x2101, &["a/s"]),
+    (0x2102, &["C"]),
+    (0x2103, &["\u{b0}C"]),
+    (0x2105, &["c/o"]),
+    (0x2106, &["c/u"]),
+    (0x2107, &["\u{190}"]),
+    (0x2109, &["\u{b0}F"]),
+    (0x210a, &["g"]),
+    (0x210b, &["H"]),
+    (0x210c, &["H"]),
+    (0x210d, &["H"]),
+    (0x210e, &["h"]),
+    (0x210f, &["\u{127}"]),
+    (0x2110, &["I"]),
+    (0x2111, &["I"]),
+    (0x2112, &["L"]),
+    (0x2113, &["l"]),
+    (0x2115, &["N"]),
+    (0x2116, &["No"]),
+    (0x2119, &["P"]),
+    (0x211a, &["Q"]),
+    (0x211b, &["R"]),
+    (0x211c, &["R"]),
+    (0x211d, &["R"]),
+    (0x211e, &["Rx"]),
+    (0x2120, &["SM"]),
+    (0x2121, &["TEL"]),
+    (0x2122, &["(TM)"]),
+    (0x2124, &["Z"]),
+    (0x2126, &["\u{3a9}", "Ohm"]),
+    (0x2128, &["Z"]),
+    (0x212a, &["K"]),
+    (0x212b, &["A"]),
+    (0x212c, &["B"]),
+    (0x212d, &["C"]),
+    (0x212e, &["e"]),
+    (0x212f, &["e"]),
+    (0x2130, &["E"]),
+    (0x2131, &["F"]),
+    (0x2133, &["M"]),
+    (0x2134, &["o"]),
+    (0x2135, &["\u{5d0}"]),
+    (0x2136, &["\u{5d1}"]),
+    (0x2137, &["\u{5d2}"]),
+    (0x2138, &["\u{5d3}"]),
+    (0x2139, &["i"]),
+    (0x213b, &["FAX"]),
+    (0x213c, &["\u{3c0}"]),
+    (0x213d, &["\u{3b3}"]),
+    (0x213e, &["\u{393}"]),
+    (0x213f, &["\u{3a0}"]),
+    (0x2140, &["\u{2211}"]),
+    (0x2145, &["D"]),
+    (0x2146, &["d"]),
+    (0x2147, &["e"]),
+    (0x2148, &["i"]),
+    (0x2149, &["j"]),
+    (0x2150, &[" 1\u{2044}7 ", " 1/7 "]),
+    (0x2151, &[" 1\u{2044}9 ", " 1/9 "]),
+    (0x2152, &[" 1\u{2044}10 ", " 1/10 "]),
+    (0x2153, &[" 1\u{2044}3 ", " 1/3 "]),
+    (0x2154, &[" 2\u{2044}3 ", " 2/3 "]),
+    (0x2155, &[" 1\u{2044}5 ", " 1/5 "]),
+    (0x2156, &[" 2\u{2044}5 ", " 2/5 "]),
+    (0x2157, &[" 3\u{2044}5 ", " 3/5 "]),
+    (0x2158, &[" 4\u{2044}5 ", " 4/5 "]),
+    (0x2159, &[" 1\u{2044}6 ", " 1/6 "]),
+    (0x215a, &[" 5\u{2044}6 ", " 5/6 "]),
+    (0x215b, &[" 1\u{2044}8 ", " 1/8 "]),
+    (0x215c, &[" 3\u{2044}8 ", " 3/8 "]),
+    (0x215d, &[" 5\u{2044}8 ", " 5/8 "]),
+    (0x215e, &[" 7\u{2044}8 ", " 7/8 "]),
+    (0x215f, &[" 1\u{2044} ", " 1/ "]),
+    (0x2160, &["I"]),
+    (0x2161, &["II"]),
+    (0x2162, &["III"]),
+    (0x2163, &["IV"]),
+    (0x2164, &["V"]),
+    (0x2165, &["VI"]),
+    (0x2166, &["VII"]),
+    (0x2167, &["VIII"]),
+    (0x2168, &["IX"]),
+    (0x2169, &["X"]),
+    (0x216a, &["XI"]),
+    (0x216b, &["XII"]),
+    (0x216c, &["L"]),
+    (0x216d, &["C"]),
+    (0x216e, &["D"]),
+    (0x216f, &["M"]),
+    (0x2170, &["i"]),
+    (0x2171, &["ii"]),
+    (0x2172, &["iii"]),
+    (0x2173, &["iv"]),
+    (0x2174, &["v"]),
+    (0x2175, &["vi"]),
+    (0x2176, &["vii"]),
+    (0x2177, &["viii"]),
+    (0x2178, &["ix"]),
+    (0x2179, &["x"]),
+    (0x217a, &["xi"]),
+    (0x217b, &["xii"]),
+    (0x217c, &["l"]),
+    (0x217d, &["c"]),
+    (0x217e, &["d"]),
+    (0x217f, &["m"]),
+    (0x2189, &[" 0\u{2044}3 ", " 0/3 "]),
+    (0x2190, &["<-"]),
+    (0x2192, &["->"]),
+    (0x2194, &["<->"]),
+    (0x219a, &["\u{2190}"]),
+    (0x219b, &["\u{2192}"]),
+    (0x21ae, &["!<->"]),
+    (0x21cd, &["!<="]),
+    (0x21ce, &["!<=>"]),
+    (0x21cf, &["!=>"]),
+    (0x21d0, &["<="]),
+    (0x21d2, &["=>"]),
+    (0x21d4, &["<=>"]),
+    (0x2204, &["!\u{2203}"]),
+    (0x2209, &["!\u{2208}"]),
+    (0x220c, &["!\u{220b}"]),
+    (0x2212, &["\u{2013}", "-"]),
+    (0x2215, &["/"]),
+    (0x2216, &["\\"]),
+    (0x2217, &["*"]),
+    (0x2219, &["\u{2022}", "\u{b7}"]),
+    (0x2223, &["|"]),
+    (0x2224, &["!\u{2223}"]),
+    (0x2225, &["||"]),
+    (0x2226, &["!\u{2225}"]),
+    (0x222c, &["\u{222b}\u{222b}"]),
+    (0x222d, &["\u{222b}\u{222b}\u{222b}"]),
+    (0x222f, &["\u{222e}\u{222e}"]),
+    (0x2230, &["\u{222e}\u{222e}\u{222e}"]),
+    (0x2236, &[":"]),
+    (0x223c, &["~"]),
+    (0x2241, &["!~"]),
+    (0x2244, &["!~-"]),
+    (0x2247, &["!~="]),
+    (0x2249, &["!~~"]),
+    (0x2260, &["!="]),
+    (0x2262, &["!=="]),
+    (0x2264, &["<="]),
+    (0x2265, &[">="]),
+    (0x226a, &["<<"]),
+    (0x226b, &[">>"]),
+    (0x226d, &["!\u{224d}"]),
+    (0x226e, &["!<"]),
+    (0x226f, &["!>"]),
+    (0x2270, &["!<="]),
+    (0x2271, &["!>="]),
+    (0x2274, &["!<~"]),
+    (0x2275, &["!>~"]),
+    (0x2278, &["!<>"]),
+    (0x2279, &["!><"]),
+    (0x2280, &["!\u{227a}"]),
+    (0x2281, &["!\u{227b}"]),
+    (0x2284, &["!\u{2282}"]),
+    (0x2285, &["!\u{2283}"]),
+    (0x2288, &["!\u{2282}="]),
+    (0x2289, &["!\u{2283}="]),
+    (0x22ac, &["!\u{22a2}"]),
+    (0x22ad, &["!\u{22a8}"]),
+    (0x22ae, &["!\u{22a9}"]),
+    (0x22af, &["!\u{22ab}"]),
+    (0x22c5, &["\u{b7}"]),
+    (0x22d8, &["<<<"]),
+    (0x22d9, &[">>>"]),
+    (0x22e0, &["!\u{227c}"]),
+    (0x22e1, &["!\u{227d}"]),
+    (0x22e2, &["!\u{2291}"]),
+    (0x22e3, &["!\u{2292}"]),
+    (0x22ea, &["!\u{22b2}"]),
+    (0x22eb, &["!\u{22b3}"]),
+    (0x22ec, &["!\u{22b4}"]),
+    (0x22ed, &["!\u{22b5}"]),
+    (0x22ef, &["\u{b7}\u{b7}\u{b7}"]),
+    (0x2329, &["\u{3008}", "<"]),
+    (0x232a, &["\u{3009}", ">"]),
+    (0x2400, &["NUL"]),
+    (0x2401, &["SOH"]),
+    (0x2402, &["STX"]),
+    (0x2403, &["ETX"]),
+    (0x2404, &["EOT"]),
+    (0x2405, &["ENQ"]),
+    (0x2406, &["ACK"]),
+    (0x2407, &["BEL"]),
+    (0x2408, &["BS"]),
+    (0x2409, &["HT"]),
+    (0x240a, &["LF"]),
+    (0x240b, &["VT"]),
+    (0x240c, &["FF"]),
+    (0x240d, &["CR"]),
+    (0x240e, &["SO"]),
+    (0x240f, &["SI"]),
+    (0x2410, &["DLE"]),
+    (0x2411, &["DC1"]),
+    (0x2412, &["DC2"]),
+    (0x2413, &["DC3"]),
+    (0x2414, &["DC4"]),
+    (0x2415, &["NAK"]),
+    (0x2416, &["SYN"]),
+    (0x2417, &["ETB"]),
+    (0x2418, &["CAN"]),
+    (0x2419, &["EM"]),
+    (0x241a, &["SUB"]),
+    (0x241b, &["ESC"]),
+    (0x241c, &["FS"]),
+    (0x241d, &["GS"]),
+    (0x241e, &["RS"]),
+    (0x241f, &["US"]),
+    (0x2420, &["SP"]),
+    (0x2421, &["DEL"]),
+    (0x2423, &["_"]),
+    (0x2424, &["NL"]),
+    (0x2460, &["(1)"]),
+    (0x2461, &["(2)"]),
+    (0x2462, &["(3)"]),
+    (0x2463, &["(4)"]),
+    (0x2464, &["(5)"]),
+    (0x2465, &["(6)"]),
+    (0x2466, &["(7)"]),
+    (0x2467, &["(8)"]),
+    (0x2468, &["(9)"]),
+    (0x2469, &["(10)"]),
+    (0x246a, &["(11)"]),
+    (0x246b, &["(12)"]),
+    (0x246c, &["(13)"]),
+    (0x246d, &["(14)"]),
+    (0x246e, &["(15)"]),
+    (0x246f, &["(16)"]),
+    (0x2470, &["(17)"]),
+    (0x2471, &["(18)"]),
+    (0x2472, &["(19)"]),
+    (0x2473, &["(20)"]),
+    (0x2474, &["(1)"]),
+    (0x2475, &["(2)"]),
+    (0x2476, &["(3)"]),
+    (0x2477, &["(4)"]),
+    (0x2478, &["(5)"]),
+    (0x2479, &["(6)"]),
+    (0x247a, &["(7)"]),
+    (0x247b, &["(8)"]),
+    (0x247c, &["(9)"]),
+    (0x247d, &["(10)"]),
+    (0x247e, &["(11)"]),
+    (0x247f, &["(12)"]),
+    (0x2480, &["(13)"]),
+    (0x2481, &["(14)"]),
+    (0x2482, &["(15)"]),
+    (0x2483, &["(16)"]),
+    (0x2484, &["(17)"]),
+    (0x2485, &["(18)"]),
+    (0x2486, &["(19)"]),
+    (0x2487, &["(20)"]),
+    (0x2488, &["1."]),
+    (0x2489, &["2."]),
+    (0x248a, &["3."]),
+    (0x248b, &["4."]),
+    (0x248c, &["5."]),
+    (0x248d, &["6."]),
+    (0x248e, &["7."]),
+    (0x248f, &["8."]),
+    (0x2490, &["9."]),
+    (0x2491, &["10."]),
+    (0x2492, &["11."]),
+    (0x2493, &["12."]),
+    (0x2494, &["13."]),
+    (0x2495, &["14."]),
+    (0x2496, &["15."]),
+    (0x2497, &["16."]),
+    (0x2498, &["17."]),
+    (0x2499, &["18."]),
+    (0x249a, &["19."]),
+    (0x249b, &["20."]),
+    (0x249c, &["(a)"]),
+    (0x249d, &["(b)"]),
+    (0x249e, &["(c)"]),
+    (0x249f, &["(d)"]),
+    (0x24a0, &["(e)"]),
+    (0x24a1, &["(f)"]),
+    (0x24a2, &["(g)"]),
+    (0x24a3, &["(h)"]),
+    (0x24a4, &["(i)"]),
+    (0x24a5, &["(j)"]),
+    (0x24a6, &["(k)"]),
+    (0x24a7, &["(l)"]),
+    (0x24a8, &["(m)"]),
+    (0x24a9, &["(n)"]),
+    (0x24aa, &["(o)"]),
+    (0x24ab, &["(p)"]),
+    (0x24ac, &["(q)"]),
+    (0x24ad, &["(r)"]),
+    (0x24ae, &["(s)"]),
+    (0x24af, &["(t)"]),
+    (0x24b0, &["(u)"]),
+    (0x24b1, &["(v)"]),
+    (0x24b2, &["(w)"]),
+    (0x24b3, &["(x)"]),
+    (0x24b4, &["(y)"]),
+    (0x24b5, &["(z)"]),
+    (0x24b6, &["(A)"]),
+    (0x24b7, &["(B)"]),
+    (0x24b8, &["(C)"]),
+    (0x24b9, &["(D)"]),
+    (0x24ba, &["(E)"]),
+    (0x24bb, &["(F)"]),
+    (0x24bc, &["(G)"]),
+    (0x24bd, &["(H)"]),
+    (0x24be, &["(I)"]),
+    (0x24bf, &["(J)"]),
+    (0x24c0, &["(K)"]),
+    (0x24c1, &["(L)"]),
+    (0x24c2, &["(M)"]),
+    (0x24c3, &["(N)"]),
+    (0x24c4, &["(O)"]),
+    (0x24c5, &["(P)"]),
+    (0x24c6, &["(Q)"]),
+    (0x24c7, &["(R)"]),
+    (0x24c8, &["(S)"]),
+    (0x24c9, &["(T)"]),
+    (0x24ca, &["(U)"]),
+    (0x24cb, &["(V)"]),
+    (0x24cc, &["(W)"]),
+    (0x24cd, &["(X)"]),
+    (0x24ce, &["(Y)"]),
+    (0x24cf, &["(Z)"]),
+    (0x24d0, &["(a)"]),
+    (0x24d1, &["(b)"]),
+    (0x24d2, &["(c)"]),
+    (0x24d3, &["(d)"]),
+    (0x24d4, &["(e)"]),
+    (0x24d5, &["(f)"]),
+    (0x24d6, &["(g)"]),
+    (0x24d7, &["(h)"]),
+    (0x24d8, &["(i)"]),
+    (0x24d9, &["(j)"]),
+    (0x24da, &["(k)"]),
+    (0x24db, &["(l)"]),
+    (0x24dc, &["(m)"]),
+    (0x24dd, &["(n)"]),
+    (0x24de, &["(o)"]),
+    (0x24df, &["(p)"]),
+    (0x24e0, &["(q)"]),
+    (0x24e1, &["(r)"]),
+    (0x24e2, &["(s)"]),
+    (0x24e3, &["(t)"]),
+    (0x24e4, &["(u)"]),
+    (0x24e5, &["(v)"]),
+    (0x24e6, &["(w)"]),
+    (0x24e7, &["(x)"]),
+    (0x24e8, &["(y)"]),
+    (0x24e9, &["(z)"]),
+    (0x24ea, &["(0)"]),
+    (0x2500, &["-"]),
+    (0x2502, &["|"]),
+    (0x250c, &["+"]),
+    (0x2510, &["+"]),
+    (0x2514, &["+"]),
+    (0x2518, &["+"]),
+    (0x251c, &["+"]),
+    (0x2524, &["+"]),
+    (0x252c, &["+"]),
+    (0x2534, &["+"]),
+    (0x253c, &["+"]),
+    (0x2571, &["/"]),
+    (0x2572, &["\\"]),
+    (0x25e6, &["o"]),
+    (0x263a, &[":)"]),
+    (0x263b, &[":)"]),
+    (0x27cb, &["/"]),
+    (0x27cd, &["\\"]),
+    (0x27e6, &["[|"]),
+    (0x27e7, &["|]"]),
+    (0x27e8, &["<"]),
+    (0x27e9, &[">"]),
+    (0x27ea, &["<<"]),
+    (0x27eb, &[">>"]),
+    (0x27ec, &["(("]),
+    (0x27ed, &["))"]),
+    (0x27ee, &["("]),
+    (0x27ef, &[")"]),
+    (0x2980, &["|||"]),
+    (0x2983, &["{|"]),
+    (0x2984, &["|}"]),
+    (0x2985, &["(("]),
+    (0x2986, &["))"]),
+    (0x2987, &["(|"]),
+    (0x2988, &["|)"]),
+    (0x2989, &["<|"]),
+    (0x298a, &["|>"]),
+    (0x29e3, &["#"]),
+    (0x29e5, &["#"]),
+    (0x29f5, &["\\"]),
+    (0x29f8, &["/"]),
+    (0x29f9, &["\\"]),
+    (0x29fc, &["<"]),
+    (0x29fd, &[">"]),
+    (0x29fe, &["+"]),
+    (0x29ff, &["-"]),
+    (0x2a0c, &["\u{222b}\u{222b}\u{222b}\u{222b}"]),
+    (0x2a74, &["::="]),
+    (0x2a75, &["=="]),
+    (0x2a76, &["==="]),
+    (0x2adc, &["!\u{2add}"]),
+    (0x2c7c, &["j"]),
+    (0x2c7d, &["V"]),
+    (0x2d6f, &["\u{2d61}"]),
+    (0x2e9f, &["\u{6bcd}"]),
+    (0x2ef3, &["\u{9f9f}"]),
+    (0x2f00, &["\u{4e00}"]),
+    (0x2f01, &["\u{4e28}"]),
+    (0x2f02, &["\u{4e36}"]),
+    (0x2f03, &["\u{4e3f}"]),
+    (0x2f04, &["\u{4e59}"]),
+    (0x2f05, &["\u{4e85}"]),
+    (0x2f06, &["\u{4e8c}"]),
+    (0x2f07, &["\u{4ea0}"]),
+    (0x2f08, &["\u{4eba}"]),
+    (0x2f09, &["\u{513f}"]),
+    (0x2f0a, &["\u{5165}"]),
+    (0x2f0b, &["\u{516b}"]),
+    (0x2f0c, &["\u{5182}"]),
+    (0x2f0d, &["\u{5196}"]),
+    (0x2f0e, &["\u{51ab}"]),
+    (0x2f0f, &["\u{51e0}"]),
+    (0x2f10, &["\u{51f5}"]),
+    (0x2f11, &["\u{5200}"]),
+    (0x2f12, &["\u{529b}"]),
+    (0x2f13, &["\u{52f9}"]),
+    (0x2f14, &["\u{5315}"]),
+    (0x2f15, &["\u{531a}"]),
+    (0x2f16, &["\u{5338}"]),
+    (0x2f17, &["\u{5341}"]),
+    (0x2f18, &["\u{535c}"]),
+    (0x2f19, &["\u{5369}"]),
+    (0x2f1a, &["\u{5382}"]),
+    (0x2f1b, &["\u{53b6}"]),
+    (0x2f1c, &["\u{53c8}"]),
+    (0x2f1d, &["\u{53e3}"]),
+    (0x2f1e, &["\u{56d7}"]),
+    (0x2f1f, &["\u{571f}"]),
+    (0x2f20, &["\u{58eb}"]),
+    (0x2f21, &["\u{5902}"]),
+    (0x2f22, &["\u{590a}"]),
+    (0x2f23, &["\u{5915}"]),
+    (0x2f24, &["\u{5927}"]),
+    (0x2f25, &["\u{5973}"]),
+    (0x2f26, &["\u{5b50}"]),
+    (0x2f27, &["\u{5b80}"]),
+    (0x2f28, &["\u{5bf8}"]),
+    (0x2f29, &["\u{5c0f}"]),
+    (0x2f2a, &["\u{5c22}"]),
+    (0x2f2b, &["\u{5c38}"]),
+    (0x2f2c, &["\u{5c6e}"]),
+    (0x2f2d, &["\u{5c71}"]),
+    (0x2f2e, &["\u{5ddb}"]),
+    (0x2f2f, &["\u{5de5}"]),
+    (0x2f30, &["\u{5df1}"]),
+    (0x2f31, &["\u{5dfe}"]),
+    (0x2f32, &["\u{5e72}"]),
+    (0x2f33, &["\u{5e7a}"]),
+    (0x2f34, &["\u{5e7f}"]),
+    (0x2f35, &["\u{5ef4}"]),
+    (0x2f36, &["\u{5efe}"]),
+    (0x2f37, &["\u{5f0b}"]),
+    (0x2f38, &["\u{5f13}"]),
+    (0x2f39, &["\u{5f50}"]),
+    (0x2f3a, &["\u{5f61}"]),
+    (0x2f3b, &["\u{5f73}"]),
+    (0x2f3c, &["\u{5fc3}"]),
+    (0x2f3d, &["\u{6208}"]),
+    (0x2f3e, &["\u{6236}"]),
+    (0x2f3f, &["\u{624b}"]),
+    (0x2f40, &["\u{652f}"]),
+    (0x2f41, &["\u{6534}"]),
+    (0x2f42, &["\u{6587}"]),
+    (0x2f43, &["\u{6597}"]),
+    (0x2f44, &["\u{65a4}"]),
+    (0x2f45, &["\u{65b9}"]),
+    (0x2f46, &["\u{65e0}"]),
+    (0x2f47, &["\u{65e5}"]),
+    (0x2f48, &["\u{66f0}"]),
+    (0x2f49, &["\u{6708}"]),
+    (0x2f4a, &["\u{6728}"]),
+    (0x2f4b, &["\u{6b20}"]),
+    (0x2f4c, &["\u{6b62}"]),
+    (0x2f4d, &["\u{6b79}"]),
+    (0x2f4e, &["\u{6bb3}"]),
+    (0x2f4f, &["\u{6bcb}"]),
+    (0x2f50, &["\u{6bd4}"]),
+    (0x2f51, &["\u{6bdb}"]),
+    (0x2f52, &["\u{6c0f}"]),
+    (0x2f53, &["\u{6c14}"]),
+    (0x2f54, &["\u{6c34}"]),
+    (0x2f55, &["\u{706b}"]),
+    (0x2f56, &["\u{722a}"]),
+    (0x2f57, &["\u{7236}"]),
+    (0x2f58, &["\u{723b}"]),
+    (0x2f59, &["\u{723f}"]),
+    (0x2f5a, &["\u{7247}"]),
+    (0x2f5b, &["\u{7259}"]),
+    (0x2f5c, &["\u{725b}"]),
+    (0x2f5d, &["\u{72ac}"]),
+    (0x2f5e, &["\u{7384}"]),
+    (0x2f5f, &["\u{7389}"]),
+    (0x2f60, &["\u{74dc}"]),
+    (0x2f61, &["\u{74e6}"]),
+    (0x2f62, &["\u{7518}"]),
+    (0x2f63, &["\u{751f}"]),
+    (0x2f64, &["\u{7528}"]),
+    (0x2f65, &["\u{7530}"]),
+    (0x2f66, &["\u{758b}"]),
+    (0x2f67, &["\u{7592}"]),
+    (0x2f68, &["\u{7676}"]),
+    (0x2f69, &["\u{767d}"]),
+    (0x2f6a, &["\u{76ae}"]),
+    (0x2f6b, &["\u{76bf}"]),
+    (0x2f6c, &["\u{76ee}"]),
+    (0x2f6d, &["\u{77db}"]),
+    (0x2f6e, &["\u{77e2}"]),
+    (0x2f6f, &["\u{77f3}"]),
+    (0x2f70, &["\u{793a}"]),
+    (0x2f71, &["\u{79b8}"]),
+    (0x2f72, &["\u{79be}"]),
+    (0x2f73, &["\u{7a74}"]),
+    (0x2f74, &["\u{7acb}"]),
+    (0x2f75, &["\u{7af9}"]),
+    (0x2f76, &["\u{7c73}"]),
+    (0x2f77, &["\u{7cf8}"]),
+    (0x2f78, &["\u{7f36}"]),
+    (0x2f79, &["\u{7f51}"]),
+    (0x2f7a, &["\u{7f8a}"]),
+    (0x2f7b, &["\u{7fbd}"]),
+    (0x2f7c, &["\u{8001}"]),
+    (0x2f7d, &["\u{800c}"]),
+    (0x2f7e, &["\u{8012}"]),
+    (0x2f7f, &["\u{8033}"]),
+    (0x2f80, &["\u{807f}"]),
+    (0x2f81, &["\u{8089}"]),
+    (0x2f82, &["\u{81e3}"]),
+    (0x2f83, &["\u{81ea}"]),
+    (0x2f84, &["\u{81f3}"]),
+    (0x2f85, &["\u{81fc}"]),
+    (0x2f86, &["\u{820c}"]),
+    (0x2f87, &["\u{821b}"]),
+    (0x2f88, &["\u{821f}"]),
+    (0x2f89, &["\u{826e}"]),
+    (0x2f8a, &["\u{8272}"]),
+    (0x2f8b, &["\u{8278}"]),
+    (0x2f8c, &["\u{864d}"]),
+    (0x2f8d, &["\u{866b}"]),
+    (0x2f8e, &["\u{8840}"]),
+    (0x2f8f, &["\u{884c}"]),
+    (0x2f90, &["\u{8863}"]),
+    (0x2f91, &["\u{897e}"]),
+    (0x2f92, &["\u{898b}"]),
+    (0x2f93, &["\u{89d2}"]),
+    (0x2f94, &["\u{8a00}"]),
+    (0x2f95, &["\u{8c37}"]),
+    (0x2f96, &["\u{8c46}"]),
+    (0x2f97, &["\u{8c55}"]),
+    (0x2f98, &["\u{8c78}"]),
+    (0x2f99, &["\u{8c9d}"]),
+    (0x2f9a, &["\u{8d64}"]),
+    (0x2f9b, &["\u{8d70}"]),
+    (0x2f9c, &["\u{8db3}"]),
+    (0x2f9d, &["\u{8eab}"]),
+    (0x2f9e, &["\u{8eca}"]),
+    (0x2f9f, &["\u{8f9b}"]),
+    (0x2fa0, &["\u{8fb0}"]),
+    (0x2fa1, &["\u{8fb5}"]),
+    (0x2fa2, &["\u{9091}"]),
+    (0x2fa3, &["\u{9149}"]),
+    (0x2fa4, &["\u{91c6}"]),
+    (0x2fa5, &["\u{91cc}"]),
+    (0x2fa6, &["\u{91d1}"]),
+    (0x2fa7, &["\u{9577}"]),
+    (0x2fa8, &["\u{9580}"]),
+    (0x2fa9, &["\u{961c}"]),
+    (0x2faa, &["\u{96b6}"]),
+    (0x2fab, &["\u{96b9}"]),
+    (0x2fac, &["\u{96e8}"]),
+    (0x2fad, &["\u{9751}"]),
+    (0x2fae, &["\u{975e}"]),
+    (0x2faf, &["\u{9762}"]),
+    (0x2fb0, &["\u{9769}"]),
+    (0x2fb1, &["\u{97cb}"]),
+    (0x2fb2, &["\u{97ed}"]),
+    (0x2fb3, &["\u{97f3}"]),
+    (0x2fb4, &["\u{9801}"]),
+    (0x2fb5, &["\u{98a8}"]),
+    (0x2fb6, &["\u{98db}"]),
+    (0x2fb7, &["\u{98df}"]),
+    (0x2fb8, &["\u{9996}"]),
+    (0x2fb9, &["\u{9999}"]),
+    (0x2fba, &["\u{99ac}"]),
+    (0x2fbb, &["\u{9aa8}"]),
+    (0x2fbc, &["\u{9ad8}"]),
+    (0x2fbd, &["\u{9adf}"]),
+    (0x2fbe, &["\u{9b25}"]),
+    (0x2fbf, &["\u{9b2f}"]),
+    (0x2fc0, &["\u{9b32}"]),
+    (0x2fc1, &["\u{9b3c}"]),
+    (0x2fc2, &["\u{9b5a}"]),
+    (0x2fc3, &["\u{9ce5}"]),
+    (0x2fc4, &["\u{9e75}"]),
+    (0x2fc5, &["\u{9e7f}"]),
+    (0x2fc6, &["\u{9ea5}"]),
+    (0x2fc7, &["\u{9ebb}"]),
+    (0x2fc8, &["\u{9ec3}"]),
+    (0x2fc9, &["\u{9ecd}"]),
+    (0x2fca, &["\u{9ed1}"]),
+    (0x2fcb, &["\u{9ef9}"]),
+    (0x2fcc, &["\u{9efd}"]),
+    (0x2fcd, &["\u{9f0e}"]),
+    (0x2fce, &["\u{9f13}"]),
+    (0x2fcf, &["\u{9f20}"]),
+    (0x2fd0, &["\u{9f3b}"]),
+    (0x2fd1, &["\u{9f4a}"]),
+    (0x2fd2, &["\u{9f52}"]),
+    (0x2fd3, &["\u{9f8d}"]),
+    (0x2fd4, &["\u{9f9c}"]),
+    (0x2fd5, &["\u{9fa0}"]),
+    (0x3000, &[" "]),
+    (0x3008, &["<"]),
+    (0x3009, &[">"]),
+    (0x3036, &["\u{3012}"]),
+    (0x3038, &["\u{5341}"]),
+    (0x3039, &["\u{5344}"]),
+    (0x303a, &["\u{5345}"]),
+    (0x3041, &["\u{3042}"]),
+    (0x3043, &["\u{3044}"]),
+    (0x3045, &["\u{3046}"]),
+    (0x3047, &["\u{3048}"]),
+    (0x3049, &["\u{304a}"]),
+    (0x304c, &["\u{304b}"]),
+    (0x304e, &["\u{304d}"]),
+    (0x3050, &["\u{304f}"]),
+    (0x3052, &["\u{3051}"]),
+    (0x3054, &["\u{3053}"]),
+    (0x3056, &["\u{3055}"]),
+    (0x3058, &["\u{3057}"]),
+    (0x305a, &["\u{3059}"]),
+    (0x305c, &["\u{305b}"]),
+    (0x305e, &["\u{305d}"]),
+    (0x3060, &["\u{305f}"]),
+    (0x3062, &["\u{3061}"]),
+    (0x3063, &["\u{3064}"]),
+    (0x3065, &["\u{3064}"]),
+    (0x3067, &["\u{3066}"]),
+    (0x3069, &["\u{3068}"]),
+    (0x3070, &["\u{306f}"]),
+    (0x3071, &["\u{306f}"]),
+    (0x3073, &["\u{3072}"]),
+    (0x3074, &["\u{3072}"]),
+    (0x3076, &["\u{3075}"]),
+    (0x3077, &["\u{3075}"]),
+    (0x3079, &["\u{3078}"]),
+    (0x307a, &["\u{3078}"]),
+    (0x307c, &["\u{307b}"]),
+    (0x307d, &["\u{307b}"]),
+    (0x3083, &["\u{3084}"]),
+    (0x3085, &["\u{3086}"]),
+    (0x3087, &["\u{3088}"]),
+    (0x308e, &["\u{308f}"]),
+    (0x3094, &["\u{3046}"]),
+    (0x3095, &["\u{304b}"]),
+    (0x3096, &["\u{3051}"]),
+    (0x3099, &[""]),
+    (0x309a, &[""]),
+    (0x309b, &[" \u{3099}"]),
+    (0x309c, &[" \u{309a}"]),
+    (0x309e, &["\u{309d}"]),
+    (0x309f, &["\u{3088}\u{308a}"]),
+    (0x30a0, &["="]),
+    (0x30a1, &["\u{30a2}"]),
+    (0x30a3, &["\u{30a4}"]),
+    (0x30a5, &["\u{30a6}"]),
+    (0x30a7, &["\u{30a8}"]),
+    (0x30a9, &["\u{30aa}"]),
+    (0x30ac, &["\u{30ab}"]),
+    (0x30ae, &["\u{30ad}"]),
+    (0x30b0, &["\u{30af}"]),
+    (0x30b2, &["\u{30b1}"]),
+    (0x30b4, &["\u{30b3}"]),
+    (0x30b6, &["\u{30b5}"]),
+    (0x30b8, &["\u{30b7}"]),
+    (0x30ba, &["\u{30b9}"]),
+    (0x30bc, &["\u{30bb}"]),
+    (0x30be, &["\u{30bd}"]),
+    (0x30c0, &["\u{30bf}"]),
+    (0x30c2, &["\u{30c1}"]),
+    (0x30c3, &["\u{30c4}"]),
+    (0x30c5, &["\u{30c4}"]),
+    (0x30c7, &["\u{30c6}"]),
+    (0x30c9, &["\u{30c8}"]),
+    (0x30d0, &["\u{30cf}"]),
+    (0x30d1, &["\u{30cf}"]),
+    (0x30d3, &["\u{30d2}"]),
+    (0x30d4, &["\u{30d2}"]),
+    (0x30d6, &["\u{30d5}"]),
+    (0x30d7, &["\u{30d5}"]),
+    (0x30d9, &["\u{30d8}"]),
+    (0x30da, &["\u{30d8}"]),
+    (0x30dc, &["\u{30db}"]),
+    (0x30dd, &["\u{30db}"]),
+    (0x30e3, &["\u{30e4}"]),
+    (0x30e5, &["\u{30e6}"]),
+    (0x30e7, &["\u{30e8}"]),
+    (0x30ee, &["\u{30ef}"]),
+    (0x30f4, &["\u{30a6}"]),
+    (0x30f5, &["\u{30ab}"]),
+    (0x30f6, &["\u{30b1}"]),
+    (0x30f7, &["\u{30ef}"]),
+    (0x30f8, &["\u{30f0}"]),
+    (0x30f9, &["\u{30f1}"]),
+    (0x30fa, &["\u{30f2}"]),
+    (0x30fe, &["\u{30fd}"]),
+    (0x30ff, &["\u{30b3}\u{30c8}"]),
+    (0x3131, &["\u{1100}"]),
+    (0x3132, &["\u{1101}"]),
+    (0x3133, &["\u{11aa}"]),
+    (0x3134, &["\u{1102}"]),
+    (0x3135, &["\u{11ac}"]),
+    (0x3136, &["\u{11ad}"]),
+    (0x3137, &["\u{1103}"]),
+    (0x3138, &["\u{1104}"]),
+    (0x3139, &["\u{1105}"]),
+    (0x313a, &["\u{11b0}"]),
+    (0x313b, &["\u{11b1}"]),
+    (0x313c, &["\u{11b2}"]),
+    (0x313d, &["\u{11b3}"]),
+    (0x313e, &["\u{11b4}"]),
+    (0x313f, &["\u{11b5}"]),
+    (0x3140, &["\u{111a}"]),
+    (0x3141, &["\u{1106}"]),
+    (0x3142, &["\u{1107}"]),
+    (0x3143, &["\u{1108}"]),
+    (0x3144, &["\u{1121}"]),
+    (0x3145, &["\u{1109}"]),
+    (0x3146, &["\u{110a}"]),
+    (0x3147, &["\u{110b}"]),
+    (0x3148, &["\u{110c}"]),
+    (0x3149, &["\u{110d}"]),
+    (0x314a, &["\u{110e}"]),
+    (0x314b, &["\u{110f}"]),
+    (0x314c, &["\u{1110}"]),
+    (0x314d, &["\u{1111}"]),
+    (0x314e, &["\u{1112}"]),
+    (0x314f, &["\u{1161}"]),
+    (0x3150, &["\u{1162}"]),
+    (0x3151, &["\u{1163}"]),
+    (0x3152, &["\u{1164}"]),
+    (0x3153, &["\u{1165}"]),
+    (0x3154, &["\u{1166}"]),
+    (0x3155, &["\u{1167}"]),
+    (0x3156, &["\u{1168}"]),
+    (0x3157, &["\u{1169}"]),
+    (0x3158, &["\u{116a}"]),
+    (0x3159, &["\u{116b}"]),
+    (0x315a, &["\u{116c}"]),
+    (0x315b, &["\u{116d}"]),
+    (0x315c, &["\u{116e}"]),
+    (0x315d, &["\u{116f}"]),
+    (0x315e, &["\u{1170}"]),
+    (0x315f, &["\u{1171}"]),
+    (0x3160, &["\u{1172}"]),
+    (0x3161, &["\u{1173}"]),
+    (0x3162, &["\u{1174}"]),
+    (0x3163, &["\u{1175}"]),
+    (0x3164, &["\u{1160}"]),
+    (0x3165, &["\u{1114}"]),
+    (0x3166, &["\u{1115}"]),
+    (0x3167, &["\u{11c7}"]),
+    (0x3168, &["\u{11c8}"]),
+    (0x3169, &["\u{11cc}"]),
+    (0x316a, &["\u{11ce}"]),
+    (0x316b, &["\u{11d3}"]),
+    (0x316c, &["\u{11d7}"]),
+    (0x316d, &["\u{11d9}"]),
+    (0x316e, &["\u{111c}"]),
+    (0x316f, &["\u{11dd}"]),
+    (0x3170, &["\u{11df}"]),
+    (0x3171, &["\u{111d}"]),
+    (0x3172, &["\u{111e}"]),
+    (0x3173, &["\u{1120}"]),
+    (0x3174, &["\u{1122}"]),
+    (0x3175, &["\u{1123}"]),
+    (0x3176, &["\u{1127}"]),
+    (0x3177, &["\u{1129}"]),
+    (0x3178, &["\u{112b}"]),
+    (0x3179, &["\u{112c}"]),
+    (0x317a, &["\u{112d}"]),
+    (0x317b, &["\u{112e}"]),
+    (0x317c, &["\u{112f}"]),
+    (0x317d, &["\u{1132}"]),
+    (0x317e, &["\u{1136}"]),
+    (0x317f, &["\u{1140}"]),
+    (0x3180, &["\u{1147}"]),
+    (0x3181, &["\u{114c}"]),
+    (0x3182, &["\u{11f1}"]),
+    (0x3183, &["\u{11f2}"]),
+    (0x3184, &["\u{1157}"]),
+    (0x3185, &["\u{1158}"]),
+    (0x3186, &["\u{1159}"]),
+    (0x3187, &["\u{1184}"]),
+    (0x3188, &["\u{1185}"]),
+    (0x3189, &["\u{1188}"]),
+    (0x318a, &["\u{1191}"]),
+    (0x318b, &["\u{1192}"]),
+    (0x318c, &["\u{1194}"]),
+    (0x318d, &["\u{119e}"]),
+    (0x318e, &["\u{11a1}"]),
+    (0x3192, &["\u{4e00}"]),
+    (0x3193, &["\u{4e8c}"]),
+    (0x3194, &["\u{4e09}"]),
+    (0x3195, &["\u{56db}"]),
+    (0x3196, &["\u{4e0a}"]),
+    (0x3197, &["\u{4e2d}"]),
+    (0x3198, &["\u{4e0b}"]),
+    (0x3199, &["\u{7532}"]),
+    (0x319a, &["\u{4e59}"]),
+    (0x319b, &["\u{4e19}"]),
+    (0x319c, &["\u{4e01}"]),
+    (0x319d, &["\u{5929}"]),
+    (0x319e, &["\u{5730}"]),
+    (0x319f, &["\u{4eba}"]),
+    (0x31f0, &["\u{30af}"]),
+    (0x31f1, &["\u{30b7}"]),
+    (0x31f2, &["\u{30b9}"]),
+    (0x31f3, &["\u{30c8}"]),
+    (0x31f4, &["\u{30cc}"]),
+    (0x31f5, &["\u{30cf}"]),
+    (0x31f6, &["\u{30d2}"]),
+    (0x31f7, &["\u{30d5}"]),
+    (0x31f8, &["\u{30d8}"]),
+    (0x31f9, &["\u{30db}"]),
+    (0x31fa, &["\u{30e0}"]),
+    (0x31fb, &["\u{30e9}"]),
+    (0x31fc, &["\u{30ea}"]),
+    (0x31fd, &["\u{30eb}"]),
+    (0x31fe, &["\u{30ec}"]),
+    (0x31ff, &["\u{30ed}"]),
+    (0x3200, &["(\u{1100})"]),
+    (0x3201, &["(\u{1102})"]),
+    (0x3202, &["(\u{1103})"]),
+    (0x3203, &["(\u{1105})"]),
+    (0x3204, &["(\u{1106})"]),
+    (0x3205, &["(\u{1107})"]),
+    (0x3206, &["(\u{1109})"]),
+    (0x3207, &["(\u{110b})"]),
+    (0x3208, &["(\u{110c})"]),
+    (0x3209, &["(\u{110e})"]),
+    (0x320a, &["(\u{110f})"]),
+    (0x320b, &["(\u{1110})"]),
+    (0x320c, &["(\u{1111})"]),
+    (0x320d, &["(\u{1112})"]),
+    (0x320e, &["(\u{1100}\u{1161})"]),
+    (0x320f, &["(\u{1102}\u{1161})"]),
+    (0x3210, &["(\u{1103}\u{1161})"]),
+    (0x3211, &["(\u{1105}\u{1161})"]),
+    (0x3212, &["(\u{1106}\u{1161})"]),
+    (0x3213, &["(\u{1107}\u{1161})"]),
+    (0x3214, &["(\u{1109}\u{1161})"]),
+    (0x3215, &["(\u{110b}\u{1161})"]),
+    (0x3216, &["(\u{110c}\u{1161})"]),
+    (0x3217, &["(\u{110e}\u{1161})"]),
+    (0x3218, &["(\u{110f}\u{1161})"]),
+    (0x3219, &["(\u{1110}\u{1161})"]),
+    (0x321a, &["(\u{1111}\u{1161})"]),
+    (0x321b, &["(\u{1112}\u{1161})"]),
+    (0x321c, &["(\u{110c}\u{116e})"]),
+    (0x321d, &["(\u{110b}\u{1169}\u{110c}\u{1165}\u{11ab})"]),
+    (0x321e, &["(\u{110b}\u{1169}\u{1112}\u{116e})"]),
+    (0x3220, &["(\u{4e00})"]),
+    (0x3221, &["(\u{4e8c})"]),
+    (0x3222, &["(\u{4e09})"]),
+    (0x3223, &["(\u{56db})"]),
+    (0x3224, &["(\u{4e94})"]),
+    (0x3225, &["(\u{516d})"]),
+    (0x3226, &["(\u{4e03})"]),
+    (0x3227, &["(\u{516b})"]),
+    (0x3228, &["(\u{4e5d})"]),
+    (0x3229, &["(\u{5341})"]),
+    (0x322a, &["(\u{6708})"]),
+    (0x322b, &["(\u{706b})"]),
+    (0x322c, &["(\u{6c34})"]),
+    (0x322d, &["(\u{6728})"]),
+    (0x322e, &["(\u{91d1})"]),
+    (0x322f, &["(\u{571f})"]),
+    (0x3230, &["(\u{65e5})"]),
+    (0x3231, &["(\u{682a})"]),
+    (0x3232, &["(\u{6709})"]),
+    (0x3233, &["(\u{793e})"]),
+    (0x3234, &["(\u{540d})"]),
+    (0x3235, &["(\u{7279})"]),
+    (0x3236, &["(\u{8ca1})"]),
+    (0x3237, &["(\u{795d})"]),
+    (0x3238, &["(\u{52b4})"]),
+    (0x3239, &["(\u{4ee3})"]),
+    (0x323a, &["(\u{547c})"]),
+    (0x323b, &["(\u{5b66})"]),
+    (0x323c, &["(\u{76e3})"]),
+    (0x323d, &["(\u{4f01})"]),
+    (0x323e, &["(\u{8cc7})"]),
+    (0x323f, &["(\u{5354})"]),
+    (0x3240, &["(\u{796d})"]),
+    (0x3241, &["(\u{4f11})"]),
+    (0x3242, &["(\u{81ea})"]),
+    (0x3243, &["(\u{81f3})"]),
+    (0x3244, &["(\u{554f})"]),
+    (0x3245, &["(\u{5e7c})"]),
+    (0x3246, &["(\u{6587})"]),
+    (0x3247, &["(\u{7b8f})"]),
+    (0x3250, &["PTE"]),
+    (0x3251, &["(21)"]),
+    (0x3252, &["(22)"]),
+    (0x3253, &["(23)"]),
+    (0x3254, &["(24)"]),
+    (0x3255, &["(25)"]),
+    (0x3256, &["(26)"]),
+    (0x3257, &["(27)"]),
+    (0x3258, &["(28)"]),
+    (0x3259, &["(29)"]),
+    (0x325a, &["(30)"]),
+    (0x325b, &["(31)"]),
+    (0x325c, &["(32)"]),
+    (0x325d, &["(33)"]),
+    (0x325e, &["(34)"]),
+    (0x325f, &["(35)"]),
+    (0x3260, &["(\u{1100})"]),
+    (0x3261, &["(\u{1102})"]),
+    (0x3262, &["(\u{1103})"]),
+    (0x3263, &["(\u{1105})"]),
+    (0x3264, &["(\u{1106})"]),
+    (0x3265, &["(\u{1107})"]),
+    (0x3266, &["(\u{1109})"]),
+    (0x3267, &["(\u{110b})"]),
+    (0x3268, &["(\u{110c})"]),
+    (0x3269, &["(\u{110e})"]),
+    (0x326a, &["(\u{110f})"]),
+    (0x326b, &["(\u{1110})"]),
+    (0x326c, &["(\u{1111})"]),
+    (0x326d, &["(\u{1112})"]),
+    (0x326e, &["(\u{1100}\u{1161})"]),
+    (0x326f, &["(\u{1102}\u{1161})"]),
+    (0x3270, &["(\u{1103}\u{1161})"]),
+    (0x3271, &["(\u{1105}\u{1161})"]),
+    (0x3272, &["(\u{1106}\u{1161})"]),
+    (0x3273, &["(\u{1107}\u{1161})"]),
+    (0x3274, &["(\u{1109}\u{1161})"]),
+    (0x3275, &["(\u{110b}\u{1161})"]),
+    (0x3276, &["(\u{110c}\u{1161})"]),
+    (0x3277, &["(\u{110e}\u{1161})"]),
+    (0x3278, &["(\u{110f}\u{1161})"]),
+    (0x3279, &["(\u{1110}\u{1161})"]),
+    (0x327a, &["(\u{1111}\u{1161})"]),
+    (0x327b, &["(\u{1112}\u{1161})"]),
+    (0x327c, &["(\u{110e}\u{1161}\u{11b7}\u{1100}\u{1169})"]),
+    (0x327d, &["(\u{110c}\u{116e}\u{110b}\u{1174})"]),
+    (0x327e, &["(\u{110b}\u{116e})"]),
+    (0x3280, &["(\u{4e00})"]),
+    (0x3281, &["(\u{4e8c})"]),
+    (0x3282, &["(\u{4e09})"]),
+    (0x3283, &["(\u{56db})"]),
+    (0x3284, &["(\u{4e94})"]),
+    (0x3285, &["(\u{516d})"]),
+    (0x3286, &["(\u{4e03})"]),
+    (0x3287, &["(\u{516b})"]),
+    (0x3288, &["(\u{4e5d})"]),
+    (0x3289, &["(\u{5341})"]),
+    (0x328a, &["(\u{6708})"]),
+    (0x328b, &["(\u{706b})"]),
+    (0x328c, &["(\u{6c34})"]),
+    (0x328d, &["(\u{6728})"]),
+    (0x328e, &["(\u{91d1})"]),
+    (0x328f, &["(\u{571f})"]),
+    (0x3290, &["(\u{65e5})"]),
+    (0x3291, &["(\u{682a})"]),
+    (0x3292, &["(\u{6709})"]),
+    (0x3293, &["(\u{793e})"]),
+    (0x3294, &["(\u{540d})"]),
+    (0x3295, &["(\u{7279})"]),
+    (0x3296, &["(\u{8ca1})"]),
+    (0x3297, &["(\u{795d})"]),
+    (0x3298, &["(\u{52b4})"]),
+    (0x3299, &["(\u{79d8})"]),
+    (0x329a, &["(\u{7537})"]),
+    (0x329b, &["(\u{5973})"]),
+    (0x329c, &["(\u{9069})"]),
+    (0x329d, &["(\u{512a})"]),
+    (0x329e, &["(\u{5370})"]),
+    (0x329f, &["(\u{6ce8})"]),
+    (0x32a0, &["(\u{9805})"]),
+    (0x32a1, &["(\u{4f11})"]),
+    (0x32a2, &["(\u{5199})"]),
+    (0x32a3, &["(\u{6b63})"]),
+    (0x32a4, &["(\u{4e0a})"]),
+    (0x32a5, &["(\u{4e2d})"]),
+    (0x32a6, &["(\u{4e0b})"]),
+    (0x32a7, &["(\u{5de6})"]),
+    (0x32a8, &["(\u{53f3})"]),
+    (0x32a9, &["(\u{533b})"]),
+    (0x32aa, &["(\u{5b97})"]),
+    (0x32ab, &["(\u{5b66})"]),
+    (0x32ac, &["(\u{76e3})"]),
+    (0x32ad, &["(\u{4f01})"]),
+    (0x32ae, &["(\u{8cc7})"]),
+    (0x32af, &["(\u{5354})"]),
+    (0x32b0, &["(\u{591c})"]),
+    (0x32b1, &["(36)"]),
+    (0x32b2, &["(37)"]),
+    (0x32b3, &["(38)"]),
+    (0x32b4, &["(39)"]),
+    (0x32b5, &["(40)"]),
+    (0x32b6, &["(41)"]),
+    (0x32b7, &["(42)"]),
+    (0x32b8, &["(43)"]),
+    (0x32b9, &["(44)"]),
+    (0x32ba, &["(45)"]),
+    (0x32bb, &["(46)"]),
+    (0x32bc, &["(47)"]),
+    (0x32bd, &["(48)"]),
+    (0x32be, &["(49)"]),
+    (0x32bf, &["(50)"]),
+    (0x32c0, &["1\u{6708}"]),
+    (0x32c1, &["2\u{6708}"]),
+    (0x32c2, &["3\u{6708}"]),
+    (0x32c3, &["4\u{6708}"]),
+    (0x32c4, &["5\u{6708}"]),
+    (0x32c5, &["6\u{6708}"]),
+    (0x32c6, &["7\u{6708}"]),
+    (0x32c7, &["8\u{6708}"]),
+    (0x32c8, &["9\u{6708}"]),
+    (0x32c9, &["10\u{6708}"]),
+    (0x32ca, &["11\u{6708}"]),
+    (0x32cb, &["12\u{6708}"]),
+    (0x32cc, &["Hg"]),
+    (0x32cd, &["erg"]),
+    (0x32ce, &["eV"]),
+    (0x32cf, &["LTD"]),
+    (0x32d0, &["(\u{30a2})"]),
+    (0x32d1, &["(\u{30a4})"]),
+    (0x32d2, &["(\u{30a6})"]),
+    (0x32d3, &["(\u{30a8})"]),
+    (0x32d4, &["(\u{30aa})"]),
+    (0x32d5, &["(\u{30ab})"]),
+    (0x32d6, &["(\u{30ad})"]),
+    (0x32d7, &["(\u{30af})"]),
+    (0x32d8, &["(\u{30b1})"]),
+    (0x32d9, &["(\u{30b3})"]),
+    (0x32da, &["(\u{30b5})"]),
+    (0x32db, &["(\u{30b7})"]),
+    (0x32dc, &["(\u{30b9})"]),
+    (0x32dd, &["(\u{30bb})"]),
+    (0x32de, &["(\u{30bd})"]),
+    (0x32df, &["(\u{30bf})"]),
+    (0x32e0, &["(\u{30c1})"]),
+    (0x32e1, &["(\u{30c4})"]),
+    (0x32e2, &["(\u{30c6})"]),
+    (0x32e3, &["(\u{30c8})"]),
+    (0x32e4, &["(\u{30ca})"]),
+    (0x32e5, &["(\u{30cb})"]),
+    (0x32e6, &["(\u{30cc})"]),
+    (0x32e7, &["(\u{30cd})"]),
+    (0x32e8, &["(\u{30ce})"]),
+    (0x32e9, &["(\u{30cf})"]),
+    (0x32ea, &["(\u{30d2})"]),
+    (0x32eb, &["(\u{30d5})"]),
+    (0x32ec, &["(\u{30d8})"]),
+    (0x32ed, &["(\u{30db})"]),
+    (0x32ee, &["(\u{30de})"]),
+    (0x32ef, &["(\u{30df})"]),
+    (0x32f0, &["(\u{30e0})"]),
+    (0x32f1, &["(\u{30e1})"]),
+    (0x32f2, &["(\u{30e2})"]),
+    (0x32f3, &["(\u{30e4})"]),
+    (0x32f4, &["(\u{30e6})"]),
+    (0x32f5, &["(\u{30e8})"]),
+    (0x32f6, &["(\u{30e9})"]),
+    (0x32f7, &["(\u{30ea})"]),
+    (0x32f8, &["(\u{30eb})"]),
+    (0x32f9, &["(\u{30ec})"]),
+    (0x32fa, &["(\u{30ed})"]),
+    (0x32fb, &["(\u{30ef})"]),
+    (0x32fc, &["(\u{30f0})"]),
+    (0x32fd, &["(\u{30f1})"]),
+    (0x32fe, &["(\u{30f2})"]),
+    (0x32ff, &["\u{4ee4}\u{548c}"]),
+    (0x3300, &["\u{30a2}\u{30d1}\u{30fc}\u{30c8}"]),
+    (0x3301, &["\u{30a2}\u{30eb}\u{30d5}\u{30a1}"]),
+    (0x3302, &["\u{30a2}\u{30f3}\u{30da}\u{30a2}"]),
+    (0x3303, &["\u{30a2}\u{30fc}\u{30eb}"]),
+    (0x3304, &["\u{30a4}\u{30cb}\u{30f3}\u{30b0}"]),
+    (0x3305, &["\u{30a4}\u{30f3}\u{30c1}"]),
+    (0x3306, &["\u{30a6}\u{30a9}\u{30f3}"]),
+    (0x3307, &["\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c9}"]),
+    (0x3308, &["\u{30a8}\u{30fc}\u{30ab}\u{30fc}"]),
+    (0x3309, &["\u{30aa}\u{30f3}\u{30b9}"]),
+    (0x330a, &["\u{30aa}\u{30fc}\u{30e0}"]),
+    (0x330b, &["\u{30ab}\u{30a4}\u{30ea}"]),
+    (0x330c, &["\u{30ab}\u{30e9}\u{30c3}\u{30c8}"]),
+    (0x330d, &["\u{30ab}\u{30ed}\u{30ea}\u{30fc}"]),
+    (0x330e, &["\u{30ac}\u{30ed}\u{30f3}"]),
+    (0x330f, &["\u{30ac}\u{30f3}\u{30de}"]),
+    (0x3310, &["\u{30ae}\u{30ac}"]),
+    (0x3311, &["\u{30ae}\u{30cb}\u{30fc}"]),
+    (0x3312, &["\u{30ad}\u{30e5}\u{30ea}\u{30fc}"]),
+    (0x3313, &["\u{30ae}\u{30eb}\u{30c0}\u{30fc}"]),
+    (0x3314, &["\u{30ad}\u{30ed}"]),
+    (0x3315, &["\u{30ad}\u{30ed}\u{30b0}\u{30e9}\u{30e0}"]),
+    (
+        0x3316,
+        &["\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}"],
+    ),
+    (0x3317, &["\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}"]),
+    (0x3318, &["\u{30b0}\u{30e9}\u{30e0}"]),
+    (0x3319, &["\u{30b0}\u{30e9}\u{30e0}\u{30c8}\u{30f3}"]),
+    (0x331a, &["\u{30af}\u{30eb}\u{30bc}\u{30a4}\u{30ed}"]),
+    (0x331b, &["\u{30af}\u{30ed}\u{30fc}\u{30cd}"]),
+    (0x331c, &["\u{30b1}\u{30fc}\u{30b9}"]),
+    (0x331d, &["\u{30b3}\u{30eb}\u{30ca}"]),
+    (0x331e, &["\u{30b3}\u{30fc}\u{30dd}"]),
+    (0x331f, &["\u{30b5}\u{30a4}\u{30af}\u{30eb}"]),
+    (0x3320, &["\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}"]),
+    (0x3321, &["\u{30b7}\u{30ea}\u{30f3}\u{30b0}"]),
+    (0x3322, &["\u{30bb}\u{30f3}\u{30c1}"]),
+    (0x3323, &["\u{30bb}\u{30f3}\u{30c8}"]),
+    (0x3324, &["\u{30c0}\u{30fc}\u{30b9}"]),
+    (0x3325, &["\u{30c7}\u{30b7}"]),
+    (0x3326, &["\u{30c9}\u{30eb}"]),
+    (0x3327, &["\u{30c8}\u{30f3}"]),
+    (0x3328, &["\u{30ca}\u{30ce}"]),
+    (0x3329, &["\u{30ce}\u{30c3}\u{30c8}"]),
+    (0x332a, &["\u{30cf}\u{30a4}\u{30c4}"]),
+    (0x332b, &["\u{30d1}\u{30fc}\u{30bb}\u{30f3}\u{30c8}"]),
+    (0x332c, &["\u{30d1}\u{30fc}\u{30c4}"]),
+    (0x332d, &["\u{30d0}\u{30fc}\u{30ec}\u{30eb}"]),
+    (0x332e, &["\u{30d4}\u{30a2}\u{30b9}\u{30c8}\u{30eb}"]),
+    (0x332f, &["\u{30d4}\u{30af}\u{30eb}"]),
+    (0x3330, &["\u{30d4}\u{30b3}"]),
+    (0x3331, &["\u{30d3}\u{30eb}"]),
+    (0x3332, &["\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c9}"]),
+    (0x3333, &["\u{30d5}\u{30a3}\u{30fc}\u{30c8}"]),
+    (0x3334, &["\u{30d6}\u{30c3}\u{30b7}\u{30a7}\u{30eb}"]),
+    (0x3335, &["\u{30d5}\u{30e9}\u{30f3}"]),
+    (0x3336, &["\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}"]),
+    (0x3337, &["\u{30da}\u{30bd}"]),
+    (0x3338, &["\u{30da}\u{30cb}\u{30d2}"]),
+    (0x3339, &["\u{30d8}\u{30eb}\u{30c4}"]),
+    (0x333a, &["\u{30da}\u{30f3}\u{30b9}"]),
+    (0x333b, &["\u{30da}\u{30fc}\u{30b8}"]),
+    (0x333c, &["\u{30d9}\u{30fc}\u{30bf}"]),
+    (0x333d, &["\u{30dd}\u{30a4}\u{30f3}\u{30c8}"]),
+    (0x333e, &["\u{30dc}\u{30eb}\u{30c8}"]),
+    (0x333f, &["\u{30db}\u{30f3}"]),
+    (0x3340, &["\u{30dd}\u{30f3}\u{30c9}"]),
+    (0x3341, &["\u{30db}\u{30fc}\u{30eb}"]),
+    (0x3342, &["\u{30db}\u{30fc}\u{30f3}"]),
+    (0x3343, &["\u{30de}\u{30a4}\u{30af}\u{30ed}"]),
+    (0x3344, &["\u{30de}\u{30a4}\u{30eb}"]),
+    (0x3345, &["\u{30de}\u{30c3}\u{30cf}"]),
+    (0x3346, &["\u{30de}\u{30eb}\u{30af}"]),
+    (0x3347, &["\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}"]),
+    (0x3348, &["\u{30df}\u{30af}\u{30ed}\u{30f3}"]),
+    (0x3349, &["\u{30df}\u{30ea}"]),
+    (0x334a, &["\u{30df}\u{30ea}\u{30d0}\u{30fc}\u{30eb}"]),
+    (0x334b, &["\u{30e1}\u{30ac}"]),
+    (0x334c, &["\u{30e1}\u{30ac}\u{30c8}\u{30f3}"]),
+    (0x334d, &["\u{30e1}\u{30fc}\u{30c8}\u{30eb}"]),
+    (0x334e, &["\u{30e4}\u{30fc}\u{30c9}"]),
+    (0x334f, &["\u{30e4}\u{30fc}\u{30eb}"]),
+    (0x3350, &["\u{30e6}\u{30a2}\u{30f3}"]),
+    (0x3351, &["\u{30ea}\u{30c3}\u{30c8}\u{30eb}"]),
+    (0x3352, &["\u{30ea}\u{30e9}"]),
+    (0x3353, &["\u{30eb}\u{30d4}\u{30fc}"]),
+    (0x3354, &["\u{30eb}\u{30fc}\u{30d6}\u{30eb}"]),
+    (0x3355, &["\u{30ec}\u{30e0}"]),
+    (0x3356, &["\u{30ec}\u{30f3}\u{30c8}\u{30b2}\u{30f3}"]),
+    (0x3357, &["\u{30ef}\u{30c3}\u{30c8}"]),
+    (0x3358, &["0\u{70b9}"]),
+    (0x3359, &["1\u{70b9}"]),
+    (0x335a, &["2\u{70b9}"]),
+    (0x335b, &["3\u{70b9}"]),
+    (0x335c, &["4\u{70b9}"]),
+    (0x335d, &["5\u{70b9}"]),
+    (0x335e, &["6\u{70b9}"]),
+    (0x335f, &["7\u{70b9}"]),
+    (0x3360, &["8\u{70b9}"]),
+    (0x3361, &["9\u{70b9}"]),
+    (0x3362, &["10\u{70b9}"]),
+    (0x3363, &["11\u{70b9}"]),
+    (0x3364, &["12\u{70b9}"]),
+    (0x3365, &["13\u{70b9}"]),
+    (0x3366, &["14\u{70b9}"]),
+    (0x3367, &["15\u{70b9}"]),
+    (0x3368, &["16\u{70b9}"]),
+    (0x3369, &["17\u{70b9}"]),
+    (0x336a, &["18\u{70b9}"]),
+    (0x336b, &["19\u{70b9}"]),
+    (0x336c, &["20\u{70b9}"]),
+    (0x336d, &["21\u{70b9}"]),
+    (0x336e, &["22\u{70b9}"]),
+    (0x336f, &["23\u{70b9}"]),
+    (0x3370, &["24\u{70b9}"]),
+    (0x3371, &["hPa"]),
+    (0x3372, &["da"]),
+    (0x3373, &["AU"]),
+    (0x3374, &["bar"]),
+    (0x3375, &["oV"]),
+    (0x3376, &["pc"]),
+    (0x3377, &["dm"]),
+    (0x3378, &["dm\u{b2}", "dm^2"]),
+    (0x3379, &["dm\u{b3}", "dm^3"]),
+    (0x337a, &["IU"]),
+    (0x337b, &["\u{5e73}\u{6210}"]),
+    (0x337c, &["\u{662d}\u{548c}"]),
+    (0x337d, &["\u{5927}\u{6b63}"]),
+    (0x337e, &["\u{660e}\u{6cbb}"]),
+    (0x337f, &["\u{682a}\u{5f0f}\u{4f1a}\u{793e}"]),
+    (0x3380, &["pA"]),
+    (0x3381, &["nA"]),
+    (0x3382, &["\u{3bc}A", "\u{b5}A", "uA"]),
+    (0x3383, &["mA"]),
+    (0x3384, &["kA"]),
+    (0x3385, &["KB"]),
+    (0x3386, &["MB"]),
+    (0x3387, &["GB"]),
+    (0x3388, &["cal"]),
+    (0x3389, &["kcal"]),
+    (0x338a, &["pF"]),
+    (0x338b, &["nF"]),
+    (0x338c, &["\u{3bc}F", "\u{b5}F", "uF"]),
+    (0x338d, &["\u{3bc}g", "\u{b5}g", "ug"]),
+    (0x338e, &["mg"]),
+    (0x338f, &["kg"]),
+    (0x3390, &["Hz"]),
+    (0x3391, &["kHz"]),
+    (0x3392, &["MHz"]),
+    (0x3393, &["GHz"]),
+    (0x3394, &["THz"]),
+    (0x3395, &["\u{3bc}\u{2113}", "\u{3bc}l", "\u{b5}l", "ul"]),
+    (0x3396, &["m\u{2113}", "ml"]),
+    (0x3397, &["d\u{2113}", "dl"]),
+    (0x3398, &["k\u{2113}", "kl"]),
+    (0x3399, &["fm"]),
+    (0x339a, &["nm"]),
+    (0x339b, &["\u{3bc}m", "\u{b5}m", "um"]),
+    (0x339c, &["mm"]),
+    (0x339d, &["cm"]),
+    (0x339e, &["km"]),
+    (0x339f, &["mm\u{b2}", "mm^2"]),
+    (0x33a0, &["cm\u{b2}", "cm^2"]),
+    (0x33a1, &["m\u{b2}", "m^2"]),
+    (0x33a2, &["km\u{b2}", "km^2"]),
+    (0x33a3, &["mm\u{b3}", "mm^3"]),
+    (0x33a4, &["cm\u{b3}", "cm^3"]),
+    (0x33a5, &["m\u{b3}", "m^3"]),
+    (0x33a6, &["km\u{b3}", "km^3"]),
+    (0x33a7, &["m\u{2215}s", "m/s"]),
+    (0x33a8, &["m\u{2215}s\u{b2}", "m/s\u{b2}", "m/s^2"]),
+    (0x33a9, &["Pa"]),
+    (0x33aa, &["kPa"]),
+    (0x33ab, &["MPa"]),
+    (0x33ac, &["GPa"]),
+    (0x33ad, &["rad"]),
+    (0x33ae, &["rad\u{2215}s", "rad/s"]),
+    (0x33af, &["rad\u{2215}s\u{b2}", "rad/s\u{b2}", "rad/s^2"]),
+    (0x33b0, &["ps"]),
+    (0x33b1, &["ns"]),
+    (0x33b2, &["\u{3bc}s", "\u{b5}s", "us"]),
+    (0x33b3, &["ms"]),
+    (0x33b4, &["pV"]),
+    (0x33b5, &["nV"]),
+    (0x33b6, &["\u{3bc}V", "\u{b5}V", "uV"]),
+    (0x33b7, &["mV"]),
+    (0x33b8, &["kV"]),
+    (0x33b9, &["MV"]),
+    (0x33ba, &["pW"]),
+    (0x33bb, &["nW"]),
+    (0x33bc, &["\u{3bc}W", "\u{b5}W", "uW"]),
+    (0x33bd, &["mW"]),
+    (0x33be, &["kW"]),
+    (0x33bf, &["MW"]),
+    (0x33c0, &["k\u{3a9}"]),
+    (0x33c1, &["M\u{3a9}"]),
+    (0x33c2, &["a.m."]),
+    (0x33c3, &["Bq"]),
+    (0x33c4, &["cc"]),
+    (0x33c5, &["cd"]),
+    (0x33c6, &["C\u{2215}kg", "C/kg"]),
+    (0x33c7, &["Co."]),
+    (0x33c8, &["dB"]),
+    (0x33c9, &["Gy"]),
+    (0x33ca, &["ha"]),
+    (0x33cb, &["HP"]),
+    (0x33cc, &["in"]),
+    (0x33cd, &["KK"]),
+    (0x33ce, &["KM"]),
+    (0x33cf, &["kt"]),
+    (0x33d0, &["lm"]),
+    (0x33d1, &["ln"]),
+    (0x33d2, &["log"]),
+    (0x33d3, &["lx"]),
+    (0x33d4, &["mb"]),
+    (0x33d5, &["mil"]),
+    (0x33d6, &["mol"]),
+    (0x33d7, &["PH"]),
+    (0x33d8, &["p.m."]),
+    (0x33d9, &["PPM"]),
+    (0x33da, &["PR"]),
+    (0x33db, &["sr"]),
+    (0x33dc, &["Sv"]),
+    (0x33dd, &["Wb"]),
+    (0x33de, &["V\u{2215}m", "V/m"]),
+    (0x33df, &["A\u{2215}m", "A/m"]),
+    (0x33e0, &["1\u{65e5}"]),
+    (0x33e1, &["2\u{65e5}"]),
+    (0x33e2, &["3\u{65e5}"]),
+    (0x33e3, &["4\u{65e5}"]),
+    (0x33e4, &["5\u{65e5}"]),
+    (0x33e5, &["6\u{65e5}"]),
+    (0x33e6, &["7\u{65e5}"]),
+    (0x33e7, &["8\u{65e5}"]),
+    (0x33e8, &["9\u{65e5}"]),
+    (0x33e9, &["10\u{65e5}"]),
+    (0x33ea, &["11\u{65e5}"]),
+    (0x33eb, &["12\u{65e5}"]),
+    (0x33ec, &["13\u{65e5}"]),
+    (0x33ed, &["14\u{65e5}"]),
+    (0x33ee, &["15\u{65e5}"]),
+    (0x33ef, &["16\u{65e5}"]),
+    (0x33f0, &["17\u{65e5}"]),
+    (0x33f1, &["18\u{65e5}"]),
+    (0x33f2, &["19\u{65e5}"]),
+    (0x33f3, &["20\u{65e5}"]),
+    (0x33f4, &["21\u{65e5}"]),
+    (0x33f5, &["22\u{65e5}"]),
+    (0x33f6, &["23\u{65e5}"]),
+    (0x33f7, &["24\u{65e5}"]),
+    (0x33f8, &["25\u{65e5}"]),
+    (0x33f9, &["26\u{65e5}"]),
+    (0x33fa, &["27\u{65e5}"]),
+    (0x33fb, &["28\u{65e5}"]),
+    (0x33fc, &["29\u{65e5}"]),
+    (0x33fd, &["30\u{65e5}"]),
+    (0x33fe, &["31\u{65e5}"]),
+    (0x33ff, &["gal"]),
+    (0xa69c, &["\u{44a}"]),
+    (0xa69d, &["\u{44c}"]),
+    (0xa770, &["\u{a76f}"]),
+    (0xa7f2, &["C"]),
+    (0xa7f3, &["F"]),
+    (0xa7f4, &["Q"]),
+    (0xa7f8, &["\u{126}"]),
+    (0xa7f9, &["\u{153}"]),
+    (0xab5c, &["\u{a727}"]),
+    (0xab5d, &["\u{ab37}"]),
+    (0xab5e, &["\u{26b}"]),
+    (0xab5f, &["\u{ab52}"]),
+    (0xab69, &["\u{28d}"]),
+    (0xf900, &["\u{8c48}"]),
+    (0xf901, &["\u{66f4}"]),
+    (0xf902, &["\u{8eca}"]),
+    (0xf903, &["\u{8cc8}"]),
+    (0xf904, &["\u{6ed1}"]),
+    (0xf905, &["\u{4e32}"]),
+    (0xf906, &["\u{53e5}"]),
+    (0xf907, &["\u{9f9c}"]),
+    (0xf908, &["\u{9f9c}"]),
+    (0xf909, &["\u{5951}"]),
+    (0xf90a, &["\u{91d1}"]),
+    (0xf90b, &["\u{5587}"]),
+    (0xf90c, &["\u{5948}"]),
+    (0xf90d, &["\u{61f6}"]),
+    (0xf90e, &["\u{7669}"]),
+    (0xf90f, &["\u{7f85}"]),
+    (0xf910, &["\u{863f}"]),
+    (0xf911, &["\u{87ba}"]),
+    (0xf912, &["\u{88f8}"]),
+    (0xf913, &["\u{908f}"]),
+    (0xf914, &["\u{6a02}"]),
+    (0xf915, &["\u{6d1b}"]),
+    (0xf916, &["\u{70d9}"]),
+    (0xf917, &["\u{73de}"]),
+    (0xf918, &["\u{843d}"]),
+    (0xf919, &["\u{916a}"]),
+    (0xf91a, &["\u{99f1}"]),
+    (0xf91b, &["\u{4e82}"]),
+    (0xf91c, &["\u{5375}"]),
+    (0xf91d, &["\u{6b04}"]),
+    (0xf91e, &["\u{721b}"]),
+    (0xf91f, &["\u{862d}"]),
+    (0xf920, &["\u{9e1e}"]),
+    (0xf921, &["\u{5d50}"]),
+    (0xf922, &["\u{6feb}"]),
+    (0xf923, &["\u{85cd}"]),
+    (0xf924, &["\u{8964}"]),
+    (0xf925, &["\u{62c9}"]),
+    (0xf926, &["\u{81d8}"]),
+    (0xf927, &["\u{881f}"]),
+    (0xf928, &["\u{5eca}"]),
+    (0xf929, &["\u{6717}"]),
+    (0xf92a, &["\u{6d6a}"]),
+    (0xf92b, &["\u{72fc}"]),
+    (0xf92c, &["\u{90ce}"]),
+    (0xf92d, &["\u{4f86}"]),
+    (0xf92e, &["\u{51b7}"]),
+    (0xf92f, &["\u{52de}"]),
+    (0xf930, &["\u{64c4}"]),
+    (0xf931, &["\u{6ad3}"]),
+    (0xf932, &["\u{7210}"]),
+    (0xf933, &["\u{76e7}"]),
+    (0xf934, &["\u{8001}"]),
+    (0xf935, &["\u{8606}"]),
+    (0xf936, &["\u{865c}"]),
+    (0xf937, &["\u{8def}"]),
+    (0xf938, &["\u{9732}"]),
+    (0xf939, &["\u{9b6f}"]),
+    (0xf93a, &["\u{9dfa}"]),
+    (0xf93b, &["\u{788c}"]),
+    (0xf93c, &["\u{797f}"]),
+    (0xf93d, &["\u{7da0}"]),
+    (0xf93e, &["\u{83c9}"]),
+    (0xf93f, &["\u{9304}"]),
+    (0xf940, &["\u{9e7f}"]),
+    (0xf941, &["\u{8ad6}"]),
+    (0xf942, &["\u{58df}"]),
+    (0xf943, &["\u{5f04}"]),
+    (0xf944, &["\u{7c60}"]),
+    (0xf945, &["\u{807e}"]),
+    (0xf946, &["\u{7262}"]),
+    (0xf947, &["\u{78ca}"]),
+    (0xf948, &["\u{8cc2}"]),
+    (0xf949, &["\u{96f7}"]),
+    (0xf94a, &["\u{58d8}"]),
+    (0xf94b, &["\u{5c62}"]),
+    (0xf94c, &["\u{6a13}"]),
+    (0xf94d, &["\u{6dda}"]),
+    (0xf94e, &["\u{6f0f}"]),
+    (0xf94f, &["\u{7d2f}"]),
+    (0xf950, &["\u{7e37}"]),
+    (0xf951, &["\u{964b}"]),
+    (0xf952, &["\u{52d2}"]),
+    (0xf953, &["\u{808b}"]),
+    (0xf954, &["\u{51dc}"]),
+    (0xf955, &["\u{51cc}"]),
+    (0xf956, &["\u{7a1c}"]),
+    (0xf957, &["\u{7dbe}"]),
+    (0xf958, &["\u{83f1}"]),
+    (0xf959, &["\u{9675}"]),
+    (0xf95a, &["\u{8b80}"]),
+    (0xf95b, &["\u{62cf}"]),
+    (0xf95c, &["\u{6a02}"]),
+    (0xf95d, &["\u{8afe}"]),
+    (0xf95e, &["\u{4e39}"]),
+    (0xf95f, &["\u{5be7}"]),
+    (0xf960, &["\u{6012}"]),
+    (0xf961, &["\u{7387}"]),
+    (0xf962, &["\u{7570}"]),
+    (0xf963, &["\u{5317}"]),
+    (0xf964, &["\u{78fb}"]),
+    (0xf965, &["\u{4fbf}"]),
+    (0xf966, &["\u{5fa9}"]),
+    (0xf967, &["\u{4e0d}"]),
+    (0xf968, &["\u{6ccc}"]),
+    (0xf969, &["\u{6578}"]),
+    (0xf96a, &["\u{7d22}"]),
+    (0xf96b, &["\u{53c3}"]),
+    (0xf96c, &["\u{585e}"]),
+    (0xf96d, &["\u{7701}"]),
+    (0xf96e, &["\u{8449}"]),
+    (0xf96f, &["\u{8aaa}"]),
+    (0xf970, &["\u{6bba}"]),
+    (0xf971, &["\u{8fb0}"]),
+    (0xf972, &["\u{6c88}"]),
+    (0xf973, &["\u{62fe}"]),
+    (0xf974, &["\u{82e5}"]),
+    (0xf975, &["\u{63a0}"]),
+    (0xf976, &["\u{7565}"]),
+    (0xf977, &["\u{4eae}"]),
+    (0xf978, &["\u{5169}"]),
+    (0xf979, &["\u{51c9}"]),
+    (0xf97a, &["\u{6881}"]),
+    (0xf97b, &["\u{7ce7}"]),
+    (0xf97c, &["\u{826f}"]),
+    (0xf97d, &["\u{8ad2}"]),
+    (0xf97e, &["\u{91cf}"]),
+    (0xf97f, &["\u{52f5}"]),
+    (0xf980, &["\u{5442}"]),
+    (0xf981, &["\u{5973}"]),
+    (0xf982, &["\u{5eec}"]),
+    (0xf983, &["\u{65c5}"]),
+    (0xf984, &["\u{6ffe}"]),
+    (0xf985, &["\u{792a}"]),
+    (0xf986, &["\u{95ad}"]),
+    (0xf987, &["\u{9a6a}"]),
+    (0xf988, &["\u{9e97}"]),
+    (0xf989, &["\u{9ece}"]),
+    (0xf98a, &["\u{529b}"]),
+    (0xf98b, &["\u{66c6}"]),
+    (0xf98c, &["\u{6b77}"]),
+    (0xf98d, &["\u{8f62}"]),
+    (0xf98e, &["\u{5e74}"]),
+    (0xf98f, &["\u{6190}"]),
+    (0xf990, &["\u{6200}"]),
+    (0xf991, &["\u{649a}"]),
+    (0xf992, &["\u{6f23}"]),
+    (0xf993, &["\u{7149}"]),
+    (0xf994, &["\u{7489}"]),
+    (0xf995, &["\u{79ca}"]),
+    (0xf996, &["\u{7df4}"]),
+    (0xf997, &["\u{806f}"]),
+    (0xf998, &["\u{8f26}"]),
+    (0xf999, &["\u{84ee}"]),
+    (0xf99a, &["\u{9023}"]),
+    (0xf99b, &["\u{934a}"]),
+    (0xf99c, &["\u{5217}"]),
+    (0xf99d, &["\u{52a3}"]),
+    (0xf99e, &["\u{54bd}"]),
+    (0xf99f, &["\u{70c8}"]),
+    (0xf9a0, &["\u{88c2}"]),
+    (0xf9a1, &["\u{8aaa}"]),
+    (0xf9a2, &["\u{5ec9}"]),
+    (0xf9a3, &["\u{5ff5}"]),
+    (0xf9a4, &["\u{637b}"]),
+    (0xf9a5, &["\u{6bae}"]),
+    (0xf9a6, &["\u{7c3e}"]),
+    (0xf9a7, &["\u{7375}"]),
+    (0xf9a8, &["\u{4ee4}"]),
+    (0xf9a9, &["\u{56f9}"]),
+    (0xf9aa, &["\u{5be7}"]),
+    (0xf9ab, &["\u{5dba}"]),
+    (0xf9ac, &["\u{601c}"]),
+    (0xf9ad, &["\u{73b2}"]),
+    (0xf9ae, &["\u{7469}"]),
+    (0xf9af, &["\u{7f9a}"]),
+    (0xf9b0, &["\u{8046}"]),
+    (0xf9b1, &["\u{9234}"]),
+    (0xf9b2, &["\u{96f6}"]),
+    (0xf9b3, &["\u{9748}"]),
+    (0xf9b4, &["\u{9818}"]),
+    (0xf9b5, &["\u{4f8b}"]),
+    (0xf9b6, &["\u{79ae}"]),
+    (0xf9b7, &["\u{91b4}"]),
+    (0xf9b8, &["\u{96b8}"]),
+    (0xf9b9, &["\u{60e1}"]),
+    (0xf9ba, &["\u{4e86}"]),
+    (0xf9bb, &["\u{50da}"]),
+    (0xf9bc, &["\u{5bee}"]),
+    (0xf9bd, &["\u{5c3f}"]),
+    (0xf9be, &["\u{6599}"]),
+    (0xf9bf, &["\u{6a02}"]),
+    (0xf9c0, &["\u{71ce}"]),
+    (0xf9c1, &["\u{7642}"]),
+    (0xf9c2, &["\u{84fc}"]),
+    (0xf9c3, &["\u{907c}"]),
+    (0xf9c4, &["\u{9f8d}"]),
+    (0xf9c5, &["\u{6688}"]),
+    (0xf9c6, &["\u{962e}"]),
+    (0xf9c7, &["\u{5289}"]),
+    (0xf9c8, &["\u{677b}"]),
+    (0xf9c9, &["\u{67f3}"]),
+    (0xf9ca, &["\u{6d41}"]),
+    (0xf9cb, &["\u{6e9c}"]),
+    (0xf9cc, &["\u{7409}"]),
+    (0xf9cd, &["\u{7559}"]),
+    (0xf9ce, &["\u{786b}"]),
+    (0xf9cf, &["\u{7d10}"]),
+    (0xf9d0, &["\u{985e}"]),
+    (0xf9d1, &["\u{516d}"]),
+    (0xf9d2, &["\u{622e}"]),
+    (0xf9d3, &["\u{9678}"]),
+    (0xf9d4, &["\u{502b}"]),
+    (0xf9d5, &["\u{5d19}"]),
+    (0xf9d6, &["\u{6dea}"]),
+    (0xf9d7, &["\u{8f2a}"]),
+    (0xf9d8, &["\u{5f8b}"]),
+    (0xf9d9, &["\u{6144}"]),
+    (0xf9da, &["\u{6817}"]),
+    (0xf9db, &["\u{7387}"]),
+    (0xf9dc, &["\u{9686}"]),
+    (0xf9dd, &["\u{5229}"]),
+    (0xf9de, &["\u{540f}"]),
+    (0xf9df, &["\u{5c65}"]),
+    (0xf9e0, &["\u{6613}"]),
+    (0xf9e1, &["\u{674e}"]),
+    (0xf9e2, &["\u{68a8}"]),
+    (0xf9e3, &["\u{6ce5}"]),
+    (0xf9e4, &["\u{7406}"]),
+    (0xf9e5, &["\u{75e2}"]),
+    (0xf9e6, &["\u{7f79}"]),
+    (0xf9e7, &["\u{88cf}"]),
+    (0xf9e8, &["\u{88e1}"]),
+    (0xf9e9, &["\u{91cc}"]),
+    (0xf9ea, &["\u{96e2}"]),
+    (0xf9eb, &["\u{533f}"]),
+    (0xf9ec, &["\u{6eba}"]),
+    (0xf9ed, &["\u{541d}"]),
+    (0xf9ee, &["\u{71d0}"]),
+    (0xf9ef, &["\u{7498}"]),
+    (0xf9f0, &["\u{85fa}"]),
+    (0xf9f1, &["\u{96a3}"]),
+    (0xf9f2, &["\u{9c57}"]),
+    (0xf9f3, &["\u{9e9f}"]),
+    (0xf9f4, &["\u{6797}"]),
+    (0xf9f5, &["\u{6dcb}"]),
+    (0xf9f6, &["\u{81e8}"]),
+    (0xf9f7, &["\u{7acb}"]),
+    (0xf9f8, &["\u{7b20}"]),
+    (0xf9f9, &["\u{7c92}"]),
+    (0xf9fa, &["\u{72c0}"]),
+    (0xf9fb, &["\u{7099}"]),
+    (0xf9fc, &["\u{8b58}"]),
+    (0xf9fd, &["\u{4ec0}"]),
+    (0xf9fe, &["\u{8336}"]),
+    (0xf9ff, &["\u{523a}"]),
+    (0xfa00, &["\u{5207}"]),
+    (0xfa01, &["\u{5ea6}"]),
+    (0xfa02, &["\u{62d3}"]),
+    (0xfa03, &["\u{7cd6}"]),
+    (0xfa04, &["\u{5b85}"]),
+    (0xfa05, &["\u{6d1e}"]),
+    (0xfa06, &["\u{66b4}"]),
+    (0xfa07, &["\u{8f3b}"]),
+    (0xfa08, &["\u{884c}"]),
+    (0xfa09, &["\u{964d}"]),
+    (0xfa0a, &["\u{898b}"]),
+    (0xfa0b, &["\u{5ed3}"]),
+    (0xfa0c, &["\u{5140}"]),
+    (0xfa0d, &["\u{55c0}"]),
+    (0xfa10, &["\u{585a}"]),
+    (0xfa12, &["\u{6674}"]),
+    (0xfa15, &["\u{51de}"]),
+    (0xfa16, &["\u{732a}"]),
+    (0xfa17, &["\u{76ca}"]),
+    (0xfa18, &["\u{793c}"]),
+    (0xfa19, &["\u{795e}"]),
+    (0xfa1a, &["\u{7965}"]),
+    (0xfa1b, &["\u{798f}"]),
+    (0xfa1c, &["\u{9756}"]),
+    (0xfa1d, &["\u{7cbe}"]),
+    (0xfa1e, &["\u{7fbd}"]),
+    (0xfa20, &["\u{8612}"]),
+    (0xfa22, &["\u{8af8}"]),
+    (0xfa25, &["\u{9038}"]),
+    (0xfa26, &["\u{90fd}"]),
+    (0xfa2a, &["\u{98ef}"]),
+    (0xfa2b, &["\u{98fc}"]),
+    (0xfa2c, &["\u{9928}"]),
+    (0xfa2d, &["\u{9db4}"]),
+    (0xfa2e, &["\u{90de}"]),
+    (0xfa2f, &["\u{96b7}"]),
+    (0xfa30, &["\u{4fae}"]),
+    (0xfa31, &["\u{50e7}"]),
+    (0xfa32, &["\u{514d}"]),
+    (0xfa33, &["\u{52c9}"]),
+    (0xfa34, &["\u{52e4}"]),
+    (0xfa35, &["\u{5351}"]),
+    (0xfa36, &["\u{559d}"]),
+    (0xfa37, &["\u{5606}"]),
+    (0xfa38, &["\u{5668}"]),
+    (0xfa39, &["\u{5840}"]),
+    (0xfa3a, &["\u{58a8}"]),
+    (0xfa3b, &["\u{5c64}"]),
+    (0xfa3c, &["\u{5c6e}"]),
+    (0xfa3d, &["\u{6094}"]),
+    (0xfa3e, &["\u{6168}"]),
+    (0xfa3f, &["\u{618e}"]),
+    (0xfa40, &["\u{61f2}"]),
+    (0xfa41, &["\u{654f}"]),
+    (0xfa42, &["\u{65e2}"]),
+    (0xfa43, &["\u{6691}"]),
+    (0xfa44, &["\u{6885}"]),
+    (0xfa45, &["\u{6d77}"]),
+    (0xfa46, &["\u{6e1a}"]),
+    (0xfa47, &["\u{6f22}"]),
+    (0xfa48, &["\u{716e}"]),
+    (0xfa49, &["\u{722b}"]),
+    (0xfa4a, &["\u{7422}"]),
+    (0xfa4b, &["\u{7891}"]),
+    (0xfa4c, &["\u{793e}"]),
+    (0xfa4d, &["\u{7949}"]),
+    (0xfa4e, &["\u{7948}"]),
+    (0xfa4f, &["\u{7950}"]),
+    (0xfa50, &["\u{7956}"]),
+    (0xfa51, &["\u{795d}"]),
+    (0xfa52, &["\u{798d}"]),
+    (0xfa53, &["\u{798e}"]),
+    (0xfa54, &["\u{7a40}"]),
+    (0xfa55, &["\u{7a81}"]),
+    (0xfa56, &["\u{7bc0}"]),
+    (0xfa57, &["\u{7df4}"]),
+    (0xfa58, &["\u{7e09}"]),
+    (0xfa59, &["\u{7e41}"]),
+    (0xfa5a, &["\u{7f72}"]),
+    (0xfa5b, &["\u{8005}"]),
+    (0xfa5c, &["\u{81ed}"]),
+    (0xfa5d, &["\u{8279}"]),
+    (0xfa5e, &["\u{8279}"]),
+    (0xfa5f, &["\u{8457}"]),
+    (0xfa60, &["\u{8910}"]),
+    (0xfa61, &["\u{8996}"]),
+    (0xfa62, &["\u{8b01}"]),
+    (0xfa63, &["\u{8b39}"]),
+    (0xfa64, &["\u{8cd3}"]),
+    (0xfa65, &["\u{8d08}"]),
+    (0xfa66, &["\u{8fb6}"]),
+    (0xfa67, &["\u{9038}"]),
+    (0xfa68, &["\u{96e3}"]),
+    (0xfa69, &["\u{97ff}"]),
+    (0xfa6a, &["\u{983b}"]),
+    (0xfa6b, &["\u{6075}"]),
+    (0xfa6c, &["\u{242ee}"]),
+    (0xfa6d, &["\u{8218}"]),
+    (0xfa70, &["\u{4e26}"]),
+    (0xfa71, &["\u{51b5}"]),
+    (0xfa72, &["\u{5168}"]),
+    (0xfa73, &["\u{4f80}"]),
+    (0xfa74, &["\u{5145}"]),
+    (0xfa75, &["\u{5180}"]),
+    (0xfa76, &["\u{52c7}"]),
+    (0xfa77, &["\u{52fa}"]),
+    (0xfa78, &["\u{559d}"]),
+    (0xfa79, &["\u{5555}"]),
+    (0xfa7a, &["\u{5599}"]),
+    (0xfa7b, &["\u{55e2}"]),
+    (0xfa7c, &["\u{585a}"]),
+    (0xfa7d, &["\u{58b3}"]),
+    (0xfa7e, &["\u{5944}"]),
+    (0xfa7f, &["\u{5954}"]),
+    (0xfa80, &["\u{5a62}"]),
+    (0xfa81, &["\u{5b28}"]),
+    (0xfa82, &["\u{5ed2}"]),
+    (0xfa83, &["\u{5ed9}"]),
+    (0xfa84, &["\u{5f69}"]),
+    (0xfa85, &["\u{5fad}"]),
+    (0xfa86, &["\u{60d8}"]),
+    (0xfa87, &["\u{614e}"]),
+    (0xfa88, &["\u{6108}"]),
+    (0xfa89, &["\u{618e}"]),
+    (0xfa8a, &["\u{6160}"]),
+    (0xfa8b, &["\u{61f2}"]),
+    (0xfa8c, &["\u{6234}"]),
+    (0xfa8d, &["\u{63c4}"]),
+    (0xfa8e, &["\u{641c}"]),
+    (0xfa8f, &["\u{6452}"]),
+    (0xfa90, &["\u{6556}"]),
+    (0xfa91, &["\u{6674}"]),
+    (0xfa92, &["\u{6717}"]),
+    (0xfa93, &["\u{671b}"]),
+    (0xfa94, &["\u{6756}"]),
+    (0xfa95, &["\u{6b79}"]),
+    (0xfa96, &["\u{6bba}"]),
+    (0xfa97, &["\u{6d41}"]),
+    (0xfa98, &["\u{6edb}"]),
+    (0xfa99, &["\u{6ecb}"]),
+    (0xfa9a, &["\u{6f22}"]),
+    (0xfa9b, &["\u{701e}"]),
+    (0xfa9c, &["\u{716e}"]),
+    (0xfa9d, &["\u{77a7}"]),
+    (0xfa9e, &["\u{7235}"]),
+    (0xfa9f, &["\u{72af}"]),
+    (0xfaa0, &["\u{732a}"]),
+    (0xfaa1, &["\u{7471}"]),
+    (0xfaa2, &["\u{7506}"]),
+    (0xfaa3, &["\u{753b}"]),
+    (0xfaa4, &["\u{761d}"]),
+    (0xfaa5, &["\u{761f}"]),
+    (0xfaa6, &["\u{76ca}"]),
+    (0xfaa7, &["\u{76db}"]),
+    (0xfaa8, &["\u{76f4}"]),
+    (0xfaa9, &["\u{774a}"]),
+    (0xfaaa, &["\u{7740}"]),
+    (0xfaab, &["\u{78cc}"]),
+    (0xfaac, &["\u{7ab1}"]),
+    (0xfaad, &["\u{7bc0}"]),
+    (0xfaae, &["\u{7c7b}"]),
+    (0xfaaf, &["\u{7d5b}"]),
+    (0xfab0, &["\u{7df4}"]),
+    (0xfab1, &["\u{7f3e}"]),
+    (0xfab2, &["\u{8005}"]),
+    (0xfab3, &["\u{8352}"]),
+    (0xfab4, &["\u{83ef}"]),
+    (0xfab5, &["\u{8779}"]),
+    (0xfab6, &["\u{8941}"]),
+    (0xfab7, &["\u{8986}"]),
+    (0xfab8, &["\u{8996}"]),
+    (0xfab9, &["\u{8abf}"]),
+    (0xfaba, &["\u{8af8}"]),
+    (0xfabb, &["\u{8acb}"]),
+    (0xfabc, &["\u{8b01}"]),
+    (0xfabd, &["\u{8afe}"]),
+    (0xfabe, &["\u{8aed}"]),
+    (0xfabf, &["\u{8b39}"]),
+    (0xfac0, &["\u{8b8a}"]),
+    (0xfac1, &["\u{8d08}"]),
+    (0xfac2, &["\u{8f38}"]),
+    (0xfac3, &["\u{9072}"]),
+    (0xfac4, &["\u{9199}"]),
+    (0xfac5, &["\u{9276}"]),
+    (0xfac6, &["\u{967c}"]),
+    (0xfac7, &["\u{96e3}"]),
+    (0xfac8, &["\u{9756}"]),
+    (0xfac9, &["\u{97db}"]),
+    (0xfaca, &["\u{97ff}"]),
+    (0xfacb, &["\u{980b}"]),
+    (0xfacc, &["\u{983b}"]),
+    (0xfacd, &["\u{9b12}"]),
+    (0xface, &["\u{9f9c}"]),
+    (0xfacf, &["\u{2284a}"]),
+    (0xfad0, &["\u{22844}"]),
+    (0xfad1, &["\u{233d5}"]),
+    (0xfad2, &["\u{3b9d}"]),
+    (0xfad3, &["\u{4018}"]),
+    (0xfad4, &["\u{4039}"]),
+    (0xfad5, &["\u{25249}"]),
+    (0xfad6, &["\u{25cd0}"]),
+    (0xfad7, &["\u{27ed3}"]),
+    (0xfad8, &["\u{9f43}"]),
+    (0xfad9, &["\u{9f8e}"]),
+    (0xfb00, &["ff"]),
+    (0xfb01, &["fi"]),
+    (0xfb02, &["fl"]),
+    (0xfb03, &["ffi"]),
+    (0xfb04, &["ffl"]),
+    (0xfb05, &["st"]),
+    (0xfb06, &["st"]),
+    (0xfb13, &["\u{574}\u{576}"]),
+    (0xfb14, &["\u{574}\u{565}"]),
+    (0xfb15, &["\u{574}\u{56b}"]),
+    (0xfb16, &["\u{57e}\u{576}"]),
+    (0xfb17, &["\u{574}\u{56d}"]),
+    (0xfb1d, &["\u{5d9}"]),
+    (0xfb1e, &[""]),
+    (0xfb1f, &["\u{5f2}", "\u{5d9}\u{5d9}"]),
+    (0xfb20, &["\u{5e2}"]),
+    (0xfb21, &["\u{5d0}"]),
+    (0xfb22, &["\u{5d3}"]),
+    (0xfb23, &["\u{5d4}"]),
+    (0xfb24, &["\u{5db}"]),
+    (0xfb25, &["\u{5dc}"]),
+    (0xfb26, &["\u{5dd}"]),
+    (0xfb27, &["\u{5e8}"]),
+    (0xfb28, &["\u{5ea}"]),
+    (0xfb29, &["+"]),
+    (0xfb2a, &["\u{5e9}"]),
+    (0xfb2b, &["\u{5e9}"]),
+    (0xfb2c, &["\u{5e9}"]),
+    (0xfb2d, &["\u{5e9}"]),
+    (0xfb2e, &["\u{5d0}"]),
+    (0xfb2f, &["\u{5d0}"]),
+    (0xfb30, &["\u{5d0}"]),
+    (0xfb31, &["\u{5d1}"]),
+    (0xfb32, &["\u{5d2}"]),
+    (0xfb33, &["\u{5d3}"]),
+    (0xfb34, &["\u{5d4}"]),
+    (0xfb35, &["\u{5d5}"]),
+    (0xfb36, &["\u{5d6}"]),
+    (0xfb38, &["\u{5d8}"]),
+    (0xfb39, &["\u{5d9}"]),
+    (0xfb3a, &["\u{5da}"]),
+    (0xfb3b, &["\u{5db}"]),
+    (0xfb3c, &["\u{5dc}"]),
+    (0xfb3e, &["\u{5de}"]),
+    (0xfb40, &["\u{5e0}"]),
+    (0xfb41, &["\u{5e1}"]),
+    (0xfb43, &["\u{5e3}"]),
+    (0xfb44, &["\u{5e4}"]),
+    (0xfb46, &["\u{5e6}"]),
+    (0xfb47, &["\u{5e7}"]),
+    (0xfb48, &["\u{5e8}"]),
+    (0xfb49, &["\u{5e9}"]),
+    (0xfb4a, &["\u{5ea}"]),
+    (0xfb4b, &["\u{5d5}"]),
+    (0xfb4c, &["\u{5d1}"]),
+    (0xfb4d, &["\u{5db}"]),
+    (0xfb4e, &["\u{5e4}"]),
+    (0xfb4f, &["\u{5d0}\u{5dc}"]),
+    (0xfe00, &[""]),
+    (0xfe01, &[""]),
+    (0xfe02, &[""]),
+    (0xfe03, &[""]),
+    (0xfe04, &[""]),
+    (0xfe05, &[""]),
+    (0xfe06, &[""]),
+    (0xfe07, &[""]),
+    (0xfe08, &[""]),
+    (0xfe09, &[""]),
+    (0xfe0a, &[""]),
+    (0xfe0b, &[""]),
+    (0xfe0c, &[""]),
+    (0xfe0d, &[""]),
+    (0xfe0e, &[""]),
+    (0xfe0f, &[""]),
+    (0xfe10, &[","]),
+    (0xfe11, &["\u{3001}"]),
+    (0xfe12, &["\u{3002}"]),
+    (0xfe13, &[":"]),
+    (0xfe14, &[";"]),
+    (0xfe15, &["!"]),
+    (0xfe16, &["?"]),
+    (0xfe17, &["\u{3016}"]),
+    (0xfe18, &["\u{3017}"]),
+    (0xfe19, &["..."]),
+    (0xfe20, &[""]),
+    (0xfe21, &[""]),
+    (0xfe22, &[""]),
+    (0xfe23, &[""]),
+    (0xfe24, &[""]),
+    (0xfe25, &[""]),
+    (0xfe26, &[""]),
+    (0xfe27, &[""]),
+    (0xfe28, &[""]),
+    (0xfe29, &[""]),
+    (0xfe2a, &[""]),
+    (0xfe2b, &[""]),
+    (0xfe2c, &[""]),
+    (0xfe2d, &[""]),
+    (0xfe30, &[".."]),
+    (0xfe31, &["\u{2014}"]),
+    (0xfe32, &["\u{2013}"]),
+    (0xfe33, &["_"]),
+    (0xfe34, &["_"]),
+    (0xfe35, &["("]),
+    (0xfe36, &[")"]),
+    (0xfe37, &["{"]),
+    (0xfe38, &["}"]),
+    (0xfe39, &["\u{3014}"]),
+    (0xfe3a, &["\u{3015}"]),
+    (0xfe3b, &["\u{3010}"]),
+    (0xfe3c, &["\u{3011}"]),
+    (0xfe3d, &["\u{300a}"]),
+    (0xfe3e, &["\u{300b}"]),
+    (0xfe3f, &["\u{3008}"]),
+    (0xfe40, &["\u{3009}"]),
+    (0xfe41, &["\u{300c}"]),
+    (0xfe42, &["\u{300d}"]),
+    (0xfe43, &["\u{300e}"]),
+    (0xfe44, &["\u{300f}"]),
+    (0xfe47, &["["]),
+    (0xfe48, &["]"]),
+    (0xfe49, &["\u{203e}"]),
+    (0xfe4a, &["\u{203e}"]),
+    (0xfe4b, &["\u{203e}"]),
+    (0xfe4c, &["\u{203e}"]),
+    (0xfe4d, &["_"]),
+    (0xfe4e, &["_"]),
+    (0xfe4f, &["_"]),
+    (0xfe50, &[","]),
+    (0xfe51, &["\u{3001}"]),
+    (0xfe52, &["."]),
+    (0xfe54, &[";"]),
+    (0xfe55, &[":"]),
+    (0xfe56, &["?"]),
+    (0xfe57, &["!"]),
+    (0xfe58, &["\u{2014}"]),
+    (0xfe59, &["("]),
+    (0xfe5a, &[")"]),
+    (0xfe5b, &["{"]),
+    (0xfe5c, &["}"]),
+    (0xfe5d, &["\u{3014}"]),
+    (0xfe5e, &["\u{3015}"]),
+    (0xfe5f, &["#"]),
+    (0xfe60, &["&"]),
+    (0xfe61, &["*"]),
+    (0xfe62, &["+"]),
+    (0xfe63, &["-"]),
+    (0xfe64, &["<"]),
+    (0xfe65, &[">"]),
+    (0xfe66, &["="]),
+    (0xfe68, &["\\"]),
+    (0xfe69, &["$"]),
+    (0xfe6a, &["%"]),
+    (0xfe6b, &["@"]),
+    (0xfeff, &[""]),
+    (0xff01, &["!"]),
+    (0xff02, &["\""]),
+    (0xff03, &["#"]),
+    (0xff04, &["$"]),
+    (0xff05, &["%"]),
+    (0xff06, &["&"]),
+    (0xff07, &["'"]),
+    (0xff08, &["("]),
+    (0xff09, &[")"]),
+    (0xff0a, &["*"]),
+    (0xff0b, &["+"]),
+    (0xff0c, &[","]),
+    (0xff0d, &["-"]),
+    (0xff0e, &["."]),
+    (0xff0f, &["/"]),
+    (0xff10, &["0"]),
+    (0xff11, &["1"]),
+    (0xff12, &["2"]),
+    (0xff13, &["3"]),
+    (0xff14, &["4"]),
+    (0xff15, &["5"]),
+    (0xff16, &["6"]),
+    (0xff17, &["7"]),
+    (0xff18, &["8"]),
+    (0xff19, &["9"]),
+    (0xff1a, &[":"]),
+    (0xff1b, &[";"]),
+    (0xff1c, &["<"]),
+    (0xff1d, &["="]),
+    (0xff1e, &[">"]),
+    (0xff1f, &["?"]),
+    (0xff20, &["@"]),
+    (0xff21, &["A"]),
+    (0xff22, &["B"]),
+    (0xff23, &["C"]),
+    (0xff24, &["D"]),
+    (0xff25, &["E"]),
+    (0xff26, &["F"]),
+    (0xff27, &["G"]),
+    (0xff28, &["H"]),
+    (0xff29, &["I"]),
+    (0xff2a, &["J"]),
+    (0xff2b, &["K"]),
+    (0xff2c, &["L"]),
+    (0xff2d, &["M"]),
+    (0xff2e, &["N"]),
+    (0xff2f, &["O"]),
+    (0xff30, &["P"]),
+    (0xff31, &["Q"]),
+    (0xff32, &["R"]),
+    (0xff33, &["S"]),
+    (0xff34, &["T"]),
+    (0xff35, &["U"]),
+    (0xff36, &["V"]),
+    (0xff37, &["W"]),
+    (0xff38, &["X"]),
+    (0xff39, &["Y"]),
+    (0xff3a, &["Z"]),
+    (0xff3b, &["["]),
+    (0xff3c, &["\\"]),
+    (0xff3d, &["]"]),
+    (0xff3e, &["^"]),
+    (0xff3f, &["_"]),
+    (0xff40, &["`"]),
+    (0xff41, &["a"]),
+    (0xff42, &["b"]),
+    (0xff43, &["c"]),
+    (0xff44, &["d"]),
+    (0xff45, &["e"]),
+    (0xff46, &["f"]),
+    (0xff47, &["g"]),
+    (0xff48, &["h"]),
+    (0xff49, &["i"]),
+    (0xff4a, &["j"]),
+    (0xff4b, &["k"]),
+    (0xff4c, &["l"]),
+    (0xff4d, &["m"]),
+    (0xff4e, &["n"]),
+    (0xff4f, &["o"]),
+    (0xff50, &["p"]),
+    (0xff51, &["q"]),
+    (0xff52, &["r"]),
+    (0xff53, &["s"]),
+    (0xff54, &["t"]),
+    (0xff55, &["u"]),
+    (0xff56, &["v"]),
+    (0xff57, &["w"]),
+    (0xff58, &["x"]),
+    (0xff59, &["y"]),
+    (0xff5a, &["z"]),
+    (0xff5b, &["{"]),
+    (0xff5c, &["|"]),
+    (0xff5d, &["}"]),
+    (0xff5e, &["~"]),
+    (0xff5f, &["\u{2985}"]),
+    (0xff60, &["\u{2986}"]),
+    (0xff61, &["\u{3002}"]),
+    (0xff62, &["\u{300c}"]),
+    (0xff63, &["\u{300d}"]),
+    (0xff64, &["\u{3001}"]),
+    (0xff65, &["\u{30fb}"]),
+    (0xff66, &["\u{30f2}"]),
+    (0xff67, &["\u{30a1}"]),
+    (0xff68, &["\u{30a3}"]),
+    (0xff69, &["\u{30a5}"]),
+    (0xff6a, &["\u{30a7}"]),
+    (0xff6b, &["\u{30a9}"]),
+    (0xff6c, &["\u{30e3}"]),
+    (0xff6d, &["\u{30e5}"]),
+    (0xff6e, &["\u{30e7}"]),
+    (0xff6f, &["\u{30c3}"]),
+    (0xff70, &["\u{30fc}"]),
+    (0xff71, &["\u{30a2}"]),
+    (0xff72, &["\u{30a4}"]),
+    (0xff73, &["\u{30a6}"]),
+    (0xff74, &["\u{30a8}"]),
+    (0xff75, &["\u{30aa}"]),
+    (0xff76, &["\u{30ab}"]),
+    (0xff77, &["\u{30ad}"]),
+    (0xff78, &["\u{30af}"]),
+    (0xff79, &["\u{30b1}"]),
+    (0xff7a, &["\u{30b3}"]),
+    (0xff7b, &["\u{30b5}"]),
+    (0xff7c, &["\u{30b7}"]),
+    (0xff7d, &["\u{30b9}"]),
+    (0xff7e, &["\u{30bb}"]),
+    (0xff7f, &["\u{30bd}"]),
+    (0xff80, &["\u{30bf}"]),
+    (0xff81, &["\u{30c1}"]),
+    (0xff82, &["\u{30c4}"]),
+    (0xff83, &["\u{30c6}"]),
+    (0xff84, &["\u{30c8}"]),
+    (0xff85, &["\u{30ca}"]),
+    (0xff86, &["\u{30cb}"]),
+    (0xff87, &["\u{30cc}"]),
+    (0xff88, &["\u{30cd}"]),
+    (0xff89, &["\u{30ce}"]),
+    (0xff8a, &["\u{30cf}"]),
+    (0xff8b, &["\u{30d2}"]),
+    (0xff8c, &["\u{30d5}"]),
+    (0xff8d, &["\u{30d8}"]),
+    (0xff8e, &["\u{30db}"]),
+    (0xff8f, &["\u{30de}"]),
+    (0xff90, &["\u{30df}"]),
+    (0xff91, &["\u{30e0}"]),
+    (0xff92, &["\u{30e1}"]),
+    (0xff93, &["\u{30e2}"]),
+    (0xff94, &["\u{30e4}"]),
+    (0xff95, &["\u{30e6}"]),
+    (0xff96, &["\u{30e8}"]),
+    (0xff97, &["\u{30e9}"]),
+    (0xff98, &["\u{30ea}"]),
+    (0xff99, &["\u{30eb}"]),
+    (0xff9a, &["\u{30ec}"]),
+    (0xff9b, &["\u{30ed}"]),
+    (0xff9c, &["\u{30ef}"]),
+    (0xff9d, &["\u{30f3}"]),
+    (0xff9e, &["\u{3099}"]),
+    (0xff9f, &["\u{309a}"]),
+    (0xffa0, &["\u{3164}"]),
+    (0xffa1, &["\u{3131}"]),
+    (0xffa2, &["\u{3132}"]),
+    (0xffa3, &["\u{3133}"]),
+    (0xffa4, &["\u{3134}"]),
+    (0xffa5, &["\u{3135}"]),
+    (0xffa6, &["\u{3136}"]),
+    (0xffa7, &["\u{3137}"]),
+    (0xffa8, &["\u{3138}"]),
+    (0xffa9, &["\u{3139}"]),
+    (0xffaa, &["\u{313a}"]),
+    (0xffab, &["\u{313b}"]),
+    (0xffac, &["\u{313c}"]),
+    (0xffad, &["\u{313d}"]),
+    (0xffae, &["\u{313e}"]),
+    (0xffaf, &["\u{313f}"]),
+    (0xffb0, &["\u{3140}"]),
+    (0xffb1, &["\u{3141}"]),
+    (0xffb2, &["\u{3142}"]),
+    (0xffb3, &["\u{3143}"]),
+    (0xffb4, &["\u{3144}"]),
+    (0xffb5, &["\u{3145}"]),
+    (0xffb6, &["\u{3146}"]),
+    (0xffb7, &["\u{3147}"]),
+    (0xffb8, &["\u{3148}"]),
+    (0xffb9, &["\u{3149}"]),
+    (0xffba, &["\u{314a}"]),
+    (0xffbb, &["\u{314b}"]),
+    (0xffbc, &["\u{314c}"]),
+    (0xffbd, &["\u{314d}"]),
+    (0xffbe, &["\u{314e}"]),
+    (0xffc2, &["\u{314f}"]),
+    (0xffc3, &["\u{3150}"]),
+    (0xffc4, &["\u{3151}"]),
+    (0xffc5, &["\u{3152}"]),
+    (0xffc6, &["\u{3153}"]),
+    (0xffc7, &["\u{3154}"]),
+    (0xffca, &["\u{3155}"]),
+    (0xffcb, &["\u{3156}"]),
+    (0xffcc, &["\u{3157}"]),
+    (0xffcd, &["\u{3158}"]),
+    (0xffce, &["\u{3159}"]),
+    (0xffcf, &["\u{315a}"]),
+    (0xffd2, &["\u{315b}"]),
+    (0xffd3, &["\u{315c}"]),
+    (0xffd4, &["\u{315d}"]),
+    (0xffd5, &["\u{315e}"]),
+    (0xffd6, &["\u{315f}"]),
+    (0xffd7, &["\u{3160}"]),
+    (0xffda, &["\u{3161}"]),
+    (0xffdb, &["\u{3162}"]),
+    (0xffdc, &["\u{3163}"]),
+    (0xffe0, &["\u{a2}"]),
+    (0xffe1, &["\u{a3}"]),
+    (0xffe2, &["\u{ac}"]),
+    (0xffe3, &["\u{af}"]),
+    (0xffe4, &["\u{a6}"]),
+    (0xffe5, &["\u{a5}"]),
+    (0xffe6, &["\u{20a9}"]),
+    (0xffe8, &["\u{2502}"]),
+    (0xffe9, &["\u{2190}"]),
+    (0xffea, &["\u{2191}"]),
+    (0xffeb, &["\u{2192}"]),
+    (0xffec, &["\u{2193}"]),
+    (0xffed, &["\u{25a0}"]),
+    (0xffee, &["\u{25cb}"]),
+    (0x101fd, &[""]),
+    (0x10376, &[""]),
+    (0x10377, &[""]),
+    (0x10378, &[""]),
+    (0x10379, &[""]),
+    (0x1037a, &[""]),
+    (0x10781, &["\u{2d0}"]),
+    (0x10782, &["\u{2d1}"]),
+    (0x10783, &["\u{e6}"]),
+    (0x10784, &["\u{299}"]),
+    (0x10785, &["\u{253}"]),
+    (0x10787, &["\u{2a3}"]),
+    (0x10788, &["\u{ab66}"]),
+    (0x10789, &["\u{2a5}"]),
+    (0x1078a, &["\u{2a4}"]),
+    (0x1078b, &["\u{256}"]),
+    (0x1078c, &["\u{257}"]),
+    (0x1078d, &["\u{1d91}"]),
+    (0x1078e, &["\u{258}"]),
+    (0x1078f, &["\u{25e}"]),
+    (0x10790, &["\u{2a9}"]),
+    (0x10791, &["\u{264}"]),
+    (0x10792, &["\u{262}"]),
+    (0x10793, &["\u{260}"]),
+    (0x10794, &["\u{29b}"]),
+    (0x10795, &["\u{127}"]),
+    (0x10796, &["\u{29c}"]),
+    (0x10797, &["\u{267}"]),
+    (0x10798, &["\u{284}"]),
+    (0x10799, &["\u{2aa}"]),
+    (0x1079a, &["\u{2ab}"]),
+    (0x1079b, &["\u{26c}"]),
+    (0x1079c, &["\u{1df04}"]),
+    (0x1079d, &["\u{a78e}"]),
+    (0x1079e, &["\u{26e}"]),
+    (0x1079f, &["\u{1df05}"]),
+    (0x107a0, &["\u{28e}"]),
+    (0x107a1, &["\u{1df06}"]),
+    (0x107a2, &["\u{f8}"]),
+    (0x107a3, &["\u{276}"]),
+    (0x107a4, &["\u{277}"]),
+    (0x107a5, &["q"]),
+    (0x107a6, &["\u{27a}"]),
+    (0x107a7, &["\u{1df08}"]),
+    (0x107a8, &["\u{27d}"]),
+    (0x107a9, &["\u{27e}"]),
+    (0x107aa, &["\u{280}"]),
+    (0x107ab, &["\u{2a8}"]),
+    (0x107ac, &["\u{2a6}"]),
+    (0x107ad, &["\u{ab67}"]),
+    (0x107ae, &["\u{2a7}"]),
+    (0x107af, &["\u{288}"]),
+    (0x107b0, &["\u{2c71}"]),
+    (0x107b2, &["\u{28f}"]),
+    (0x107b3, &["\u{2a1}"]),
+    (0x107b4, &["\u{2a2}"]),
+    (0x107b5, &["\u{298}"]),
+    (0x107b6, &["\u{1c0}"]),
+    (0x107b7, &["\u{1c1}"]),
+    (0x107b8, &["\u{1c2}"]),
+    (0x107b9, &["\u{1df0a}"]),
+    (0x107ba, &["\u{1df1e}"]),
+    (0x10d24, &[""]),
+    (0x10d25, &[""]),
+    (0x10d26, &[""]),
+    (0x10d27, &[""]),
+    (0x10eab, &[""]),
+    (0x10eac, &[""]),
+    (0x10f46, &[""]),
+    (0x10f47, &[""]),
+    (0x10f48, &[""]),
+    (0x10f49, &[""]),
+    (0x10f4a, &[""]),
+    (0x10f4b, &[""]),
+    (0x10f4c, &[""]),
+    (0x10f4d, &[""]),
+    (0x10f4e, &[""]),
+    (0x10f4f, &[""]),
+    (0x10f50, &[""]),
+    (0x10f82, &[""]),
+    (0x10f83, &[""]),
+    (0x10f84, &[""]),
+    (0x10f85, &[""]),
+    (0x1133b, &[""]),
+    (0x11435, &[""]),
+    (0x11436, &[""]),
+    (0x11437, &[""]),
+    (0x11438, &[""]),
+    (0x11439, &[""]),
+    (0x1143a, &[""]),
+    (0x1143b, &[""]),
+    (0x1143c, &[""]),
+    (0x1143d, &[""]),
+    (0x1143e, &[""]),
+    (0x1143f, &[""]),
+    (0x11440, &[""]),
+    (0x11441, &[""]),
+    (0x11442, &[""]),
+    (0x11443, &[""]),
+    (0x11444, &[""]),
+    (0x11445, &[""]),
+    (0x11446, &[""]),
+    (0x1145e, &[""]),
+    (0x1182c, &[""]),
+    (0x1182d, &[""]),
+    (0x1182e, &[""]),
+    (0x1182f, &[""]),
+    (0x11830, &[""]),
+    (0x11831, &[""]),
+    (0x11832, &[""]),
+    (0x11833, &[""]),
+    (0x11834, &[""]),
+    (0x11835, &[""]),
+    (0x11836, &[""]),
+    (0x11837, &[""]),
+    (0x11838, &[""]),
+    (0x11839, &[""]),
+    (0x1183a, &[""]),
+    (0x11930, &[""]),
+    (0x11931, &[""]),
+    (0x11932, &[""]),
+    (0x11933, &[""]),
+    (0x11934, &[""]),
+    (0x11935, &[""]),
+    (0x11937, &[""]),
+    (0x11938, &[""]),
+    (0x1193b, &[""]),
+    (0x1193c, &[""]),
+    (0x1193d, &[""]),
+    (0x1193e, &[""]),
+    (0x11940, &[""]),
+    (0x11942, &[""]),
+    (0x11943, &[""]),
+    (0x119d1, &[""]),
+    (0x119d2, &[""]),
+    (0x119d3, &[""]),
+    (0x119d4, &[""]),
+    (0x119d5, &[""]),
+    (0x119d6, &[""]),
+    (0x119d7, &[""]),
+    (0x119da, &[""]),
+    (0x119db, &[""]),
+    (0x119dc, &[""]),
+    (0x119dd, &[""]),
+    (0x119de, &[""]),
+    (0x119df, &[""]),
+    (0x119e0, &[""]),
+    (0x119e4, &[""]),
+    (0x11a01, &[""]),
+    (0x11a02, &[""]),
+    (0x11a03, &[""]),
+    (0x11a04, &[""]),
+    (0x11a05, &[""]),
+    (0x11a06, &[""]),
+    (0x11a07, &[""]),
+    (0x11a08, &[""]),
+    (0x11a09, &[""]),
+    (0x11a0a, &[""]),
+    (0x11a33, &[""]),
+    (0x11a34, &[""]),
+    (0x11a35, &[""]),
+    (0x11a36, &[""]),
+    (0x11a37, &[""]),
+    (0x11a38, &[""]),
+    (0x11a39, &[""]),
+    (0x11a3b, &[""]),
+    (0x11a3c, &[""]),
+    (0x11a3d, &[""]),
+    (0x11a3e, &[""]),
+    (0x11a47, &[""]),
+    (0x11a51, &[""]),
+    (0x11a52, &[""]),
+    (0x11a53, &[""]),
+    (0x11a54, &[""]),
+    (0x11a55, &[""]),
+    (0x11a56, &[""]),
+    (0x11a57, &[""]),
+    (0x11a58, &[""]),
+    (0x11a59, &[""]),
+    (0x11a5a, &[""]),
+    (0x11a5b, &[""]),
+    (0x11a8a, &[""]),
+    (0x11a8b, &[""]),
+    (0x11a8c, &[""]),
+    (0x11a8d, &[""]),
+    (0x11a8e, &[""]),
+    (0x11a8f, &[""]),
+    (0x11a90, &[""]),
+    (0x11a91, &[""]),
+    (0x11a92, &[""]),
+    (0x11a93, &[""]),
+    (0x11a94, &[""]),
+    (0x11a95, &[""]),
+    (0x11a96, &[""]),
+    (0x11a97, &[""]),
+    (0x11a98, &[""]),
+    (0x11a99, &[""]),
+    (0x11c2f, &[""]),
+    (0x11c30, &[""]),
+    (0x11c31, &[""]),
+    (0x11c32, &[""]),
+    (0x11c33, &[""]),
+    (0x11c34, &[""]),
+    (0x11c35, &[""]),
+    (0x11c36, &[""]),
+    (0x11c38, &[""]),
+    (0x11c39, &[""]),
+    (0x11c3a, &[""]),
+    (0x11c3b, &[""]),
+    (0x11c3c, &[""]),
+    (0x11c3d, &[""]),
+    (0x11c3e, &[""]),
+    (0x11c3f, &[""]),
+    (0x11c92, &[""]),
+    (0x11c93, &[""]),
+    (0x11c94, &[""]),
+    (0x11c95, &[""]),
+    (0x11c96, &[""]),
+    (0x11c97, &[""]),
+    (0x11c98, &[""]),
+    (0x11c99, &[""]),
+    (0x11c9a, &[""]),
+    (0x11c9b, &[""]),
+    (0x11c9c, &[""]),
+    (0x11c9d, &[""]),
+    (0x11c9e, &[""]),
+    (0x11c9f, &[""]),
+    (0x11ca0, &[""]),
+    (0x11ca1, &[""]),
+    (0x11ca2, &[""]),
+    (0x11ca3, &[""]),
+    (0x11ca4, &[""]),
+    (0x11ca5, &[""]),
+    (0x11ca6, &[""]),
+    (0x11ca7, &[""]),
+    (0x11ca9, &[""]),
+    (0x11caa, &[""]),
+    (0x11cab, &[""]),
+    (0x11cac, &[""]),
+    (0x11cad, &[""]),
+    (0x11cae, &[""]),
+    (0x11caf, &[""]),
+    (0x11cb0, &[""]),
+    (0x11cb1, &[""]),
+    (0x11cb2, &[""]),
+    (0x11cb3, &[""]),
+    (0x11cb4, &[""]),
+    (0x11cb5, &[""]),
+    (0x11cb6, &[""]),
+    (0x11d31, &[""]),
+    (0x11d32, &[""]),
+    (0x11d33, &[""]),
+    (0x11d34, &[""]),
+    (0x11d35, &[""]),
+    (0x11d36, &[""]),
+    (0x11d3a, &[""]),
+    (0x11d3c, &[""]),
+    (0x11d3d, &[""]),
+    (0x11d3f, &[""]),
+    (0x11d40, &[""]),
+    (0x11d41, &[""]),
+    (0x11d42, &[""]),
+    (0x11d43, &[""]),
+    (0x11d44, &[""]),
+    (0x11d45, &[""]),
+    (0x11d47, &[""]),
+    (0x11d8a, &[""]),
+    (0x11d8b, &[""]),
+    (0x11d8c, &[""]),
+    (0x11d8d, &[""]),
+    (0x11d8e, &[""]),
+    (0x11d90, &[""]),
+    (0x11d91, &[""]),
+    (0x11d93, &[""]),
+    (0x11d94, &[""]),
+    (0x11d95, &[""]),
+    (0x11d96, &[""]),
+    (0x11d97, &[""]),
+    (0x11ef3, &[""]),
+    (0x11ef4, &[""]),
+    (0x11ef5, &[""]),
+    (0x11ef6, &[""]),
+    (0x16fe4, &[""]),
+    (0x16ff0, &[""]),
+    (0x16ff1, &[""]),
+    (0x1cf00, &[""]),
+    (0x1cf01, &[""]),
+    (0x1cf02, &[""]),
+    (0x1cf03, &[""]),
+    (0x1cf04, &[""]),
+    (0x1cf05, &[""]),
+    (0x1cf06, &[""]),
+    (0x1cf07, &[""]),
+    (0x1cf08, &[""]),
+    (0x1cf09, &[""]),
+    (0x1cf0a, &[""]),
+    (0x1cf0b, &[""]),
+    (0x1cf0c, &[""]),
+    (0x1cf0d, &[""]),
+    (0x1cf0e, &[""]),
+    (0x1cf0f, &[""]),
+    (0x1cf10, &[""]),
+    (0x1cf11, &[""]),
+    (0x1cf12, &[""]),
+    (0x1cf13, &[""]),
+    (0x1cf14, &[""]),
+    (0x1cf15, &[""]),
+    (0x1cf16, &[""]),
+    (0x1cf17, &[""]),
+    (0x1cf18, &[""]),
+    (0x1cf19, &[""]),
+    (0x1cf1a, &[""]),
+    (0x1cf1b, &[""]),
+    (0x1cf1c, &[""]),
+    (0x1cf1d, &[""]),
+    (0x1cf1e, &[""]),
+    (0x1cf1f, &[""]),
+    (0x1cf20, &[""]),
+    (0x1cf21, &[""]),
+    (0x1cf22, &[""]),
+    (0x1cf23, &[""]),
+    (0x1cf24, &[""]),
+    (0x1cf25, &[""]),
+    (0x1cf26, &[""]),
+    (0x1cf27, &[""]),
+    (0x1cf28, &[""]),
+    (0x1cf29, &[""]),
+    (0x1cf2a, &[""]),
+    (0x1cf2b, &[""]),
+    (0x1cf2c, &[""]),
+    (0x1cf2d, &[""]),
+    (0x1cf30, &[""]),
+    (0x1cf31, &[""]),
+    (0x1cf32, &[""]),
+    (0x1cf33, &[""]),
+    (0x1cf34, &[""]),
+    (0x1cf35, &[""]),
+    (0x1cf36, &[""]),
+    (0x1cf37, &[""]),
+    (0x1cf38, &[""]),
+    (0x1cf39, &[""]),
+    (0x1cf3a, &[""]),
+    (0x1cf3b, &[""]),
+    (0x1cf3c, &[""]),
+    (0x1cf3d, &[""]),
+    (0x1cf3e, &[""]),
+    (0x1cf3f, &[""]),
+    (0x1cf40, &[""]),
+    (0x1cf41, &[""]),
+    (0x1cf42, &[""]),
+    (0x1cf43, &[""]),
+    (0x1cf44, &[""]),
+    (0x1cf45, &[""]),
+    (0x1cf46, &[""]),
+    (0x1d242, &[""]),
+    (0x1d243, &[""]),
+    (0x1d244, &[""]),
+    (0x1d400, &["A"]),
+    (0x1d401, &["B"]),
+    (0x1d402, &["C"]),
+    (0x1d403, &["D"]),
+    (0x1d404, &["E"]),
+    (0x1d405, &["F"]),
+    (0x1d406, &["G"]),
+    (0x1d407, &["H"]),
+    (0x1d408, &["I"]),
+    (0x1d409, &["J"]),
+    (0x1d40a, &["K"]),
+    (0x1d40b, &["L"]),
+    (0x1d40c, &["M"]),
+    (0x1d40d, &["N"]),
+    (0x1d40e, &["O"]),
+    (0x1d40f, &["P"]),
+    (0x1d410, &["Q"]),
+    (0x1d411, &["R"]),
+    (0x1d412, &["S"]),
+    (0x1d413, &["T"]),
+    (0x1d414, &["U"]),
+    (0x1d415, &["V"]),
+    (0x1d416, &["W"]),
+    (0x1d417, &["X"]),
+    (0x1d418, &["Y"]),
+    (0x1d419, &["Z"]),
+    (0x1d41a, &["a"]),
+    (0x1d41b, &["b"]),
+    (0x1d41c, &["c"]),
+    (0x1d41d, &["d"]),
+    (0x1d41e, &["e"]),
+    (0x1d41f, &["f"]),
+    (0x1d420, &["g"]),
+    (0x1d421, &["h"]),
+    (0x1d422, &["i"]),
+    (0x1d423, &["j"]),
+    (0x1d424, &["k"]),
+    (0x1d425, &["l"]),
+    (0x1d426, &["m"]),
+    (0x1d427, &["n"]),
+    (0x1d428, &["o"]),
+    (0x1d429, &["p"]),
+    (0x1d42a, &["q"]),
+    (0x1d42b, &["r"]),
+    (0x1d42c, &["s"]),
+    (0x1d42d, &["t"]),
+    (0x1d42e, &["u"]),
+    (0x1d42f, &["v"]),
+    (0x1d430, &["w"]),
+    (0x1d431, &["x"]),
+    (0x1d432, &["y"]),
+    (0x1d433, &["z"]),
+    (0x1d434, &["A"]),
+    (0x1d435, &["B"]),
+    (0x1d436, &["C"]),
+    (0x1d437, &["D"]),
+    (0x1d438, &["E"]),
+    (0x1d439, &["F"]),
+    (0x1d43a, &["G"]),
+    (0x1d43b, &["H"]),
+    (0x1d43c, &["I"]),
+    (0x1d43d, &["J"]),
+    (0x1d43e, &["K"]),
+    (0x1d43f, &["L"]),
+    (0x1d440, &["M"]),
+    (0x1d441, &["N"]),
+    (0x1d442, &["O"]),
+    (0x1d443, &["P"]),
+    (0x1d444, &["Q"]),
+    (0x1d445, &["R"]),
+    (0x1d446, &["S"]),
+    (0x1d447, &["T"]),
+    (0x1d448, &["U"]),
+    (0x1d449, &["V"]),
+    (0x1d44a, &["W"]),
+    (0x1d44b, &["X"]),
+    (0x1d44c, &["Y"]),
+    (0x1d44d, &["Z"]),
+    (0x1d44e, &["a"]),
+    (0x1d44f, &["b"]),
+    (0x1d450, &["c"]),
+    (0x1d451, &["d"]),
+    (0x1d452, &["e"]),
+    (0x1d453, &["f"]),
+    (0x1d454, &["g"]),
+    (0x1d456, &["i"]),
+    (0x1d457, &["j"]),
+    (0x1d458, &["k"]),
+    (0x1d459, &["l"]),
+    (0x1d45a, &["m"]),
+    (0x1d45b, &["n"]),
+    (0x1d45c, &["o"]),
+    (0x1d45d, &["p"]),
+    (0x1d45e, &["q"]),
+    (0x1d45f, &["r"]),
+    (0x1d460, &["s"]),
+    (0x1d461, &["t"]),
+    (0x1d462, &["u"]),
+    (0x1d463, &["v"]),
+    (0x1d464, &["w"]),
+    (0x1d465, &["x"]),
+    (0x1d466, &["y"]),
+    (0x1d467, &["z"]),
+    (0x1d468, &["A"]),
+    (0x1d469, &["B"]),
+    (0x1d46a, &["C"]),
+    (0x1d46b, &["D"]),
+    (0x1d46c, &["E"]),
+    (0x1d46d, &["F"]),
+    (0x1d46e, &["G"]),
+    (0x1d46f, &["H"]),
+    (0x1d470, &["I"]),
+    (0x1d471, &["J"]),
+    (0x1d472, &["K"]),
+    (0x1d473, &["L"]),
+    (0x1d474, &["M"]),
+    (0x1d475, &["N"]),
+    (0x1d476, &["O"]),
+    (0x1d477, &["P"]),
+    (0x1d478, &["Q"]),
+    (0x1d479, &["R"]),
+    (0x1d47a, &["S"]),
+    (0x1d47b, &["T"]),
+    (0x1d47c, &["U"]),
+    (0x1d47d, &["V"]),
+    (0x1d47e, &["W"]),
+    (0x1d47f, &["X"]),
+    (0x1d480, &["Y"]),
+    (0x1d481, &["Z"]),
+    (0x1d482, &["a"]),
+    (0x1d483, &["b"]),
+    (0x1d484, &["c"]),
+    (0x1d485, &["d"]),
+    (0x1d486, &["e"]),
+    (0x1d487, &["f"]),
+    (0x1d488, &["g"]),
+    (0x1d489, &["h"]),
+    (0x1d48a, &["i"]),
+    (0x1d48b, &["j"]),
+    (0x1d48c, &["k"]),
+    (0x1d48d, &["l"]),
+    (0x1d48e, &["m"]),
+    (0x1d48f, &["n"]),
+    (0x1d490, &["o"]),
+    (0x1d491, &["p"]),
+    (0x1d492, &["q"]),
+    (0x1d493, &["r"]),
+    (0x1d494, &["s"]),
+    (0x1d495, &["t"]),
+    (0x1d496, &["u"]),
+    (0x1d497, &["v"]),
+    (0x1d498, &["w"]),
+    (0x1d499, &["x"]),
+    (0x1d49a, &["y"]),
+    (0x1d49b, &["z"]),
+    (0x1d49c, &["A"]),
+    (0x1d49e, &["C"]),
+    (0x1d49f, &["D"]),
+    (0x1d4a2, &["G"]),
+    (0x1d4a5, &["J"]),
+    (0x1d4a6, &["K"]),
+    (0x1d4a9, &["N"]),
+    (0x1d4aa, &["O"]),
+    (0x1d4ab, &["P"]),
+    (0x1d4ac, &["Q"]),
+    (0x1d4ae, &["S"]),
+    (0x1d4af, &["T"]),
+    (0x1d4b0, &["U"]),
+    (0x1d4b1, &["V"]),
+    (0x1d4b2, &["W"]),
+    (0x1d4b3, &["X"]),
+    (0x1d4b4, &["Y"]),
+    (0x1d4b5, &["Z"]),
+    (0x1d4b6, &["a"]),
+    (0x1d4b7, &["b"]),
+    (0x1d4b8, &["c"]),
+    (0x1d4b9, &["d"]),
+    (0x1d4bb, &["f"]),
+    (0x1d4bd, &["h"]),
+    (0x1d4be, &["i"]),
+    (0x1d4bf, &["j"]),
+    (0x1d4c0, &["k"]),
+    (0x1d4c1, &["l"]),
+    (0x1d4c2, &["m"]),
+    (0x1d4c3, &["n"]),
+    (0x1d4c5, &["p"]),
+    (0x1d4c6, &["q"]),
+    (0x1d4c7, &["r"]),
+    (0x1d4c8, &["s"]),
+    (0x1d4c9, &["t"]),
+    (0x1d4ca, &["u"]),
+    (0x1d4cb, &["v"]),
+    (0x1d4cc, &["w"]),
+    (0x1d4cd, &["x"]),
+    (0x1d4ce, &["y"]),
+    (0x1d4cf, &["z"]),
+    (0x1d4d0, &["A"]),
+    (0x1d4d1, &["B"]),
+    (0x1d4d2, &["C"]),
+    (0x1d4d3, &["D"]),
+    (0x1d4d4, &["E"]),
+    (0x1d4d5, &["F"]),
+    (0x1d4d6, &["G"]),
+    (0x1d4d7, &["H"]),
+    (0x1d4d8, &["I"]),
+    (0x1d4d9, &["J"]),
+    (0x1d4da, &["K"]),
+    (0x1d4db, &["L"]),
+    (0x1d4dc, &["M"]),
+    (0x1d4dd, &["N"]),
+    (0x1d4de, &["O"]),
+    (0x1d4df, &["P"]),
+    (0x1d4e0, &["Q"]),
+    (0x1d4e1, &["R"]),
+    (0x1d4e2, &["S"]),
+    (0x1d4e3, &["T"]),
+    (0x1d4e4, &["U"]),
+    (0x1d4e5, &["V"]),
+    (0x1d4e6, &["W"]),
+    (0x1d4e7, &["X"]),
+    (0x1d4e8, &["Y"]),
+    (0x1d4e9, &["Z"]),
+    (0x1d4ea, &["a"]),
+    (0x1d4eb, &["b"]),
+    (0x1d4ec, &["c"]),
+    (0x1d4ed, &["d"]),
+    (0x1d4ee, &["e"]),
+    (0x1d4ef, &["f"]),
+    (0x1d4f0, &["g"]),
+    (0x1d4f1, &["h"]),
+    (0x1d4f2, &["i"]),
+    (0x1d4f3, &["j"]),
+    (0x1d4f4, &["k"]),
+    (0x1d4f5, &["l"]),
+    (0x1d4f6, &["m"]),
+    (0x1d4f7, &["n"]),
+    (0x1d4f8, &["o"]),
+    (0x1d4f9, &["p"]),
+    (0x1d4fa, &["q"]),
+    (0x1d4fb, &["r"]),
+    (0x1d4fc, &["s"]),
+    (0x1d4fd, &["t"]),
+    (0x1d4fe, &["u"]),
+    (0x1d4ff, &["v"]),
+    (0x1d500, &["w"]),
+    (0x1d501, &["x"]),
+    (0x1d502, &["y"]),
+    (0x1d503, &["z"]),
+    (0x1d504, &["A"]),
+    (0x1d505, &["B"]),
+    (0x1d507, &["D"]),
+    (0x1d508, &["E"]),
+    (0x1d509, &["F"]),
+    (0x1d50a, &["G"]),
+    (0x1d50d, &["J"]),
+    (0x1d50e, &["K"]),
+    (0x1d50f, &["L"]),
+    (0x1d510, &["M"]),
+    (0x1d511, &["N"]),
+    (0x1d512, &["O"]),
+    (0x1d513, &["P"]),
+    (0x1d514, &["Q"]),
+    (0x1d516, &["S"]),
+    (0x1d517, &["T"]),
+    (0x1d518, &["U"]),
+    (0x1d519, &["V"]),
+    (0x1d51a, &["W"]),
+    (0x1d51b, &["X"]),
+    (0x1d51c, &["Y"]),
+    (0x1d51e, &["a"]),
+    (0x1d51f, &["b"]),
+    (0x1d520, &["c"]),
+    (0x1d521, &["d"]),
+    (0x1d522, &["e"]),
+    (0x1d523, &["f"]),
+    (0x1d524, &["g"]),
+    (0x1d525, &["h"]),
+    (0x1d526, &["i"]),
+    (0x1d527, &["j"]),
+    (0x1d528, &["k"]),
+    (0x1d529, &["l"]),
+    (0x1d52a, &["m"]),
+    (0x1d52b, &["n"]),
+    (0x1d52c, &["o"]),
+    (0x1d52d, &["p"]),
+    (0x1d52e, &["q"]),
+    (0x1d52f, &["r"]),
+    (0x1d530, &["s"]),
+    (0x1d531, &["t"]),
+    (0x1d532, &["u"]),
+    (0x1d533, &["v"]),
+    (0x1d534, &["w"]),
+    (0x1d535, &["x"]),
+    (0x1d536, &["y"]),
+    (0x1d537, &["z"]),
+    (0x1d538, &["A"]),
+    (0x1d539, &["B"]),
+    (0x1d53b, &["D"]),
+    (0x1d53c, &["E"]),
+    (0x1d53d, &["F"]),
+    (0x1d53e, &["G"]),
+    (0x1d540, &["I"]),
+    (0x1d541, &["J"]),
+    (0x1d542, &["K"]),
+    (0x1d543, &["L"]),
+    (0x1d544, &["M"]),
+    (0x1d546, &["O"]),
+    (0x1d54a, &["S"]),
+    (0x1d54b, &["T"]),
+    (0x1d54c, &["U"]),
+    (0x1d54d, &["V"]),
+    (0x1d54e, &["W"]),
+    (0x1d54f, &["X"]),
+    (0x1d550, &["Y"]),
+    (0x1d552, &["a"]),
+    (0x1d553, &["b"]),
+    (0x1d554, &["c"]),
+    (0x1d555, &["d"]),
+    (0x1d556, &["e"]),
+    (0x1d557, &["f"]),
+    (0x1d558, &["g"]),
+    (0x1d559, &["h"]),
+    (0x1d55a, &["i"]),
+    (0x1d55b, &["j"]),
+    (0x1d55c, &["k"]),
+    (0x1d55d, &["l"]),
+    (0x1d55e, &["m"]),
+    (0x1d55f, &["n"]),
+    (0x1d560, &["o"]),
+    (0x1d561, &["p"]),
+    (0x1d562, &["q"]),
+    (0x1d563, &["r"]),
+    (0x1d564, &["s"]),
+    (0x1d565, &["t"]),
+    (0x1d566, &["u"]),
+    (0x1d567, &["v"]),
+    (0x1d568, &["w"]),
+    (0x1d569, &["x"]),
+    (0x1d56a, &["y"]),
+    (0x1d56b, &["z"]),
+    (0x1d56c, &["A"]),
+    (0x1d56d, &["B"]),
+    (0x1d56e, &["C"]),
+    (0x1d56f, &["D"]),
+    (0x1d570, &["E"]),
+    (0x1d571, &["F"]),
+    (0x1d572, &["G"]),
+    (0x1d573, &["H"]),
+    (0x1d574, &["I"]),
+    (0x1d575, &["J"]),
+    (0x1d576, &["K"]),
+    (0x1d577, &["L"]),
+    (0x1d578, &["M"]),
+    (0x1d579, &["N"]),
+    (0x1d57a, &["O"]),
+    (0x1d57b, &["P"]),
+    (0x1d57c, &["Q"]),
+    (0x1d57d, &["R"]),
+    (0x1d57e, &["S"]),
+    (0x1d57f, &["T"]),
+    (0x1d580, &["U"]),
+    (0x1d581, &["V"]),
+    (0x1d582, &["W"]),
+    (0x1d583, &["X"]),
+    (0x1d584, &["Y"]),
+    (0x1d585, &["Z"]),
+    (0x1d586, &["a"]),
+    (0x1d587, &["b"]),
+    (0x1d588, &["c"]),
+    (0x1d589, &["d"]),
+    (0x1d58a, &["e"]),
+    (0x1d58b, &["f"]),
+    (0x1d58c, &["g"]),
+    (0x1d58d, &["h"]),
+    (0x1d58e, &["i"]),
+    (0x1d58f, &["j"]),
+    (0x1d590, &["k"]),
+    (0x1d591, &["l"]),
+    (0x1d592, &["m"]),
+    (0x1d593, &["n"]),
+    (0x1d594, &["o"]),
+    (0x1d595, &["p"]),
+    (0x1d596, &["q"]),
+    (0x1d597, &["r"]),
+    (0x1d598, &["s"]),
+    (0x1d599, &["t"]),
+    (0x1d59a, &["u"]),
+    (0x1d59b, &["v"]),
+    (0x1d59c, &["w"]),
+    (0x1d59d, &["x"]),
+    (0x1d59e, &["y"]),
+    (0x1d59f, &["z"]),
+    (0x1d5a0, &["A"]),
+    (0x1d5a1, &["B"]),
+    (0x1d5a2, &["C"]),
+    (0x1d5a3, &["D"]),
+    (0x1d5a4, &["E"]),
+    (0x1d5a5, &["F"]),
+    (0x1d5a6, &["G"]),
+    (0x1d5a7, &["H"]),
+    (0x1d5a8, &["I"]),
+    (0x1d5a9, &["J"]),
+    (0x1d5aa, &["K"]),
+    (0x1d5ab, &["L"]),
+    (0x1d5ac, &["M"]),
+    (0x1d5ad, &["N"]),
+    (0x1d5ae, &["O"]),
+    (0x1d5af, &["P"]),
+    (0x1d5b0, &["Q"]),
+    (0x1d5b1, &["R"]),
+    (0x1d5b2, &["S"]),
+    (0x1d5b3, &["T"]),
+    (0x1d5b4, &["U"]),
+    (0x1d5b5, &["V"]),
+    (0x1d5b6, &["W"]),
+    (0x1d5b7, &["X"]),
+    (0x1d5b8, &["Y"]),
+    (0x1d5b9, &["Z"]),
+    (0x1d5ba, &["a"]),
+    (0x1d5bb, &["b"]),
+    (0x1d5bc, &["c"]),
+    (0x1d5bd, &["d"]),
+    (0x1d5be, &["e"]),
+    (0x1d5bf, &["f"]),
+    (0x1d5c0, &["g"]),
+    (0x1d5c1, &["h"]),
+    (0x1d5c2, &["i"]),
+    (0x1d5c3, &["j"]),
+    (0x1d5c4, &["k"]),
+    (0x1d5c5, &["l"]),
+    (0x1d5c6, &["m"]),
+    (0x1d5c7, &["n"]),
+    (0x1d5c8, &["o"]),
+    (0x1d5c9, &["p"]),
+    (0x1d5ca, &["q"]),
+    (0x1d5cb, &["r"]),
+    (0x1d5cc, &["s"]),
+    (0x1d5cd, &["t"]),
+    (0x1d5ce, &["u"]),
+    (0x1d5cf, &["v"]),
+    (0x1d5d0, &["w"]),
+    (0x1d5d1, &["x"]),
+    (0x1d5d2, &["y"]),
+    (0x1d5d3, &["z"]),
+    (0x1d5d4, &["A"]),
+    (0x1d5d5, &["B"]),
+    (0x1d5d6, &["C"]),
+    (0x1d5d7, &["D"]),
+    (0x1d5d8, &["E"]),
+    (0x1d5d9, &["F"]),
+    (0x1d5da, &["G"]),
+    (0x1d5db, &["H"]),
+    (0x1d5dc, &["I"]),
+    (0x1d5dd, &["J"]),
+    (0x1d5de, &["K"]),
+    (0x1d5df, &["L"]),
+    (0x1d5e0, &["M"]),
+    (0x1d5e1, &["N"]),
+    (0x1d5e2, &["O"]),
+    (0x1d5e3, &["P"]),
+    (0x1d5e4, &["Q"]),
+    (0x1d5e5, &["R"]),
+    (0x1d5e6, &["S"]),
+    (0x1d5e7, &["T"]),
+    (0x1d5e8, &["U"]),
+    (0x1d5e9, &["V"]),
+    (0x1d5ea, &["W"]),
+    (0x1d5eb, &["X"]),
+    (0x1d5ec, &["Y"]),
+    (0x1d5ed, &["Z"]),
+    (0x1d5ee, &["a"]),
+    (0x1d5ef, &["b"]),
+    (0x1d5f0, &["c"]),
+    (0x1d5f1, &["d"]),
+    (0x1d5f2, &["e"]),
+    (0x1d5f3, &["f"]),
+    (0x1d5f4, &["g"]),
+    (0x1d5f5, &["h"]),
+    (0x1d5f6, &["i"]),
+    (0x1d5f7, &["j"]),
+    (0x1d5f8, &["k"]),
+    (0x1d5f9, &["l"]),
+    (0x1d5fa, &["m"]),
+    (0x1d5fb, &["n"]),
+    (0x1d5fc, &["o"]),
+    (0x1d5fd, &["p"]),
+    (0x1d5fe, &["q"]),
+    (0x1d5ff, &["r"]),
+    (0x1d600, &["s"]),
+    (0x1d601, &["t"]),
+    (0x1d602, &["u"]),
+    (0x1d603, &["v"]),
+    (0x1d604, &["w"]),
+    (0x1d605, &["x"]),
+    (0x1d606, &["y"]),
+    (0x1d607, &["z"]),
+    (0x1d608, &["A"]),
+    (0x1d609, &["B"]),
+    (0x1d60a, &["C"]),
+    (0x1d60b, &["D"]),
+    (0x1d60c, &["E"]),
+    (0x1d60d, &["F"]),
+    (0x1d60e, &["G"]),
+    (0x1d60f, &["H"]),
+    (0x1d610, &["I"]),
+    (0x1d611, &["J"]),
+    (0x1d612, &["K"]),
+    (0x1d613, &["L"]),
+    (0x1d614, &["M"]),
+    (0x1d615, &["N"]),
+    (0x1d616, &["O"]),
+    (0x1d617, &["P"]),
+    (0x1d618, &["Q"]),
+    (0x1d619, &["R"]),
+    (0x1d61a, &["S"]),
+    (0x1d61b, &["T"]),
+    (0x1d61c, &["U"]),
+    (0x1d61d, &["V"]),
+    (0x1d61e, &["W"]),
+    (0x1d61f, &["X"]),
+    (0x1d620, &["Y"]),
+    (0x1d621, &["Z"]),
+    (0x1d622, &["a"]),
+    (0x1d623, &["b"]),
+    (0x1d624, &["c"]),
+    (0x1d625, &["d"]),
+    (0x1d626, &["e"]),
+    (0x1d627, &["f"]),
+    (0x1d628, &["g"]),
+    (0x1d629, &["h"]),
+    (0x1d62a, &["i"]),
+    (0x1d62b, &["j"]),
+    (0x1d62c, &["k"]),
+    (0x1d62d, &["l"]),
+    (0x1d62e, &["m"]),
+    (0x1d62f, &["n"]),
+    (0x1d630, &["o"]),
+    (0x1d631, &["p"]),
+    (0x1d632, &["q"]),
+    (0x1d633, &["r"]),
+    (0x1d634, &["s"]),
+    (0x1d635, &["t"]),
+    (0x1d636, &["u"]),
+    (0x1d637, &["v"]),
+    (0x1d638, &["w"]),
+    (0x1d639, &["x"]),
+    (0x1d63a, &["y"]),
+    (0x1d63b, &["z"]),
+    (0x1d63c, &["A"]),
+    (0x1d63d, &["B"]),
+    (0x1d63e, &["C"]),
+    (0x1d63f, &["D"]),
+    (0x1d640, &["E"]),
+    (0x1d641, &["F"]),
+    (0x1d642, &["G"]),
+    (0x1d643, &["H"]),
+    (0x1d644, &["I"]),
+    (0x1d645, &["J"]),
+    (0x1d646, &["K"]),
+    (0x1d647, &["L"]),
+    (0x1d648, &["M"]),
+    (0x1d649, &["N"]),
+    (0x1d64a, &["O"]),
+    (0x1d64b, &["P"]),
+    (0x1d64c, &["Q"]),
+    (0x1d64d, &["R"]),
+    (0x1d64e, &["S"]),
+    (0x1d64f, &["T"]),
+    (0x1d650, &["U"]),
+    (0x1d651, &["V"]),
+    (0x1d652, &["W"]),
+    (0x1d653, &["X"]),
+    (0x1d654, &["Y"]),
+    (0x1d655, &["Z"]),
+    (0x1d656, &["a"]),
+    (0x1d657, &["b"]),
+    (0x1d658, &["c"]),
+    (0x1d659, &["d"]),
+    (0x1d65a, &["e"]),
+    (0x1d65b, &["f"]),
+    (0x1d65c, &["g"]),
+    (0x1d65d, &["h"]),
+    (0x1d65e, &["i"]),
+    (0x1d65f, &["j"]),
+    (0x1d660, &["k"]),
+    (0x1d661, &["l"]),
+    (0x1d662, &["m"]),
+    (0x1d663, &["n"]),
+    (0x1d664, &["o"]),
+    (0x1d665, &["p"]),
+    (0x1d666, &["q"]),
+    (0x1d667, &["r"]),
+    (0x1d668, &["s"]),
+    (0x1d669, &["t"]),
+    (0x1d66a, &["u"]),
+    (0x1d66b, &["v"]),
+    (0x1d66c, &["w"]),
+    (0x1d66d, &["x"]),
+    (0x1d66e, &["y"]),
+    (0x1d66f, &["z"]),
+    (0x1d670, &["A"]),
+    (0x1d671, &["B"]),
+    (0x1d672, &["C"]),
+    (0x1d673, &["D"]),
+    (0x1d674, &["E"]),
+    (0x1d675, &["F"]),
+    (0x1d676, &["G"]),
+    (0x1d677, &["H"]),
+    (0x1d678, &["I"]),
+    (0x1d679, &["J"]),
+    (0x1d67a, &["K"]),
+    (0x1d67b, &["L"]),
+    (0x1d67c, &["M"]),
+    (0x1d67d, &["N"]),
+    (0x1d67e, &["O"]),
+    (0x1d67f, &["P"]),
+    (0x1d680, &["Q"]),
+    (0x1d681, &["R"]),
+    (0x1d682, &["S"]),
+    (0x1d683, &["T"]),
+    (0x1d684, &["U"]),
+    (0x1d685, &["V"]),
+    (0x1d686, &["W"]),
+    (0x1d687, &["X"]),
+    (0x1d688, &["Y"]),
+    (0x1d689, &["Z"]),
+    (0x1d68a, &["a"]),
+    (0x1d68b, &["b"]),
+    (0x1d68c, &["c"]),
+    (0x1d68d, &["d"]),
+    (0x1d68e, &["e"]),
+    (0x1d68f, &["f"]),
+    (0x1d690, &["g"]),
+    (0x1d691, &["h"]),
+    (0x1d692, &["i"]),
+    (0x1d693, &["j"]),
+    (0x1d694, &["k"]),
+    (0x1d695, &["l"]),
+    (0x1d696, &["m"]),
+    (0x1d697, &["n"]),
+    (0x1d698, &["o"]),
+    (0x1d699, &["p"]),
+    (0x1d69a, &["q"]),
+    (0x1d69b, &["r"]),
+    (0x1d69c, &["s"]),
+    (0x1d69d, &["t"]),
+    (0x1d69e, &["u"]),
+    (0x1d69f, &["v"]),
+    (0x1d6a0, &["w"]),
+    (0x1d6a1, &["x"]),
+    (0x1d6a2, &["y"]),
+    (0x1d6a3, &["z"]),
+    (0x1d6a4, &["\u{131}"]),
+    (0x1d6a5, &["\u{237}"]),
+    (0x1d6a8, &["\u{391}"]),
+    (0x1d6a9, &["\u{392}"]),
+    (0x1d6aa, &["\u{393}"]),
+    (0x1d6ab, &["\u{394}"]),
+    (0x1d6ac, &["\u{395}"]),
+    (0x1d6ad, &["\u{396}"]),
+    (0x1d6ae, &["\u{397}"]),
+    (0x1d6af, &["\u{398}"]),
+    (0x1d6b0, &["\u{399}"]),
+    (0x1d6b1, &["\u{39a}"]),
+    (0x1d6b2, &["\u{39b}"]),
+    (0x1d6b3, &["\u{39c}"]),
+    (0x1d6b4, &["\u{39d}"]),
+    (0x1d6b5, &["\u{39e}"]),
+    (0x1d6b6, &["\u{39f}"]),
+    (0x1d6b7, &["\u{3a0}"]),
+    (0x1d6b8, &["\u{3a1}"]),
+    (0x1d6b9, &["\u{3f4}"]),
+    (0x1d6ba, &["\u{3a3}"]),
+    (0x1d6bb, &["\u{3a4}"]),
+    (0x1d6bc, &["\u{3a5}"]),
+    (0x1d6bd, &["\u{3a6}"]),
+    (0x1d6be, &["\u{3a7}"]),
+    (0x1d6bf, &["\u{3a8}"]),
+    (0x1d6c0, &["\u{3a9}"]),
+    (0x1d6c1, &["\u{2207}"]),
+    (0x1d6c2, &["\u{3b1}"]),
+    (0x1d6c3, &["\u{3b2}"]),
+    (0x1d6c4, &["\u{3b3}"]),
+    (0x1d6c5, &["\u{3b4}"]),
+    (0x1d6c6, &["\u{3b5}"]),
+    (0x1d6c7, &["\u{3b6}"]),
+    (0x1d6c8, &["\u{3b7}"]),
+    (0x1d6c9, &["\u{3b8}"]),
+    (0x1d6ca, &["\u{3b9}"]),
+    (0x1d6cb, &["\u{3ba}"]),
+    (0x1d6cc, &["\u{3bb}"]),
+    (0x1d6cd, &["\u{3bc}"]),
+    (0x1d6ce, &["\u{3bd}"]),
+    (0x1d6cf, &["\u{3be}"]),
+    (0x1d6d0, &["\u{3bf}"]),
+    (0x1d6d1, &["\u{3c0}"]),
+    (0x1d6d2, &["\u{3c1}"]),
+    (0x1d6d3, &["\u{3c2}"]),
+    (0x1d6d4, &["\u{3c3}"]),
+    (0x1d6d5, &["\u{3c4}"]),
+    (0x1d6d6, &["\u{3c5}"]),
+    (0x1d6d7, &["\u{3c6}"]),
+    (0x1d6d8, &["\u{3c7}"]),
+    (0x1d6d9, &["\u{3c8}"]),
+    (0x1d6da, &["\u{3c9}"]),
+    (0x1d6db, &["\u{2202}"]),
+    (0x1d6dc, &["\u{3f5}"]),
+    (0x1d6dd, &["\u{3d1}"]),
+    (0x1d6de, &["\u{3f0}"]),
+    (0x1d6df, &["\u{3d5}"]),
+    (0x1d6e0, &["\u{3f1}"]),
+    (0x1d6e1, &["\u{3d6}"]),
+    (0x1d6e2, &["\u{391}"]),
+    (0x1d6e3, &["\u{392}"]),
+    (0x1d6e4, &["\u{393}"]),
+    (0x1d6e5, &["\u{394}"]),
+    (0x1d6e6, &["\u{395}"]),
+    (0x1d6e7, &["\u{396}"]),
+    (0x1d6e8, &["\u{397}"]),
+    (0x1d6e9, &["\u{398}"]),
+    (0x1d6ea, &["\u{399}"]),
+    (0x1d6eb, &["\u{39a}"]),
+    (0x1d6ec, &["\u{39b}"]),
+    (0x1d6ed, &["\u{39c}"]),
+    (0x1d6ee, &["\u{39d}"]),
+    (0x1d6ef, &["\u{39e}"]),
+    (0x1d6f0, &["\u{39f}"]),
+    (0x1d6f1, &["\u{3a0}"]),
+    (0x1d6f2, &["\u{3a1}"]),
+    (0x1d6f3, &["\u{3f4}"]),
+    (0x1d6f4, &["\u{3a3}"]),
+    (0x1d6f5, &["\u{3a4}"]),
+    (0x1d6f6, &["\u{3a5}"]),
+    (0x1d6f7, &["\u{3a6}"]),
+    (0x1d6f8, &["\u{3a7}"]),
+    (0x1d6f9, &["\u{3a8}"]),
+    (0x1d6fa, &["\u{3a9}"]),
+    (0x1d6fb, &["\u{2207}"]),
+    (0x1d6fc, &["\u{3b1}"]),
+    (0x1d6fd, &["\u{3b2}"]),
+    (0x1d6fe, &["\u{3b3}"]),
+    (0x1d6ff, &["\u{3b4}"]),
+    (0x1d700, &["\u{3b5}"]),
+    (0x1d701, &["\u{3b6}"]),
+    (0x1d702, &["\u{3b7}"]),
+    (0x1d703, &["\u{3b8}"]),
+    (0x1d704, &["\u{3b9}"]),
+    (0x1d705, &["\u{3ba}"]),
+    (0x1d706, &["\u{3bb}"]),
+    (0x1d707, &["\u{3bc}"]),
+    (0x1d708, &["\u{3bd}"]),
+    (0x1d709, &["\u{3be}"]),
+    (0x1d70a, &["\u{3bf}"]),
+    (0x1d70b, &["\u{3c0}"]),
+    (0x1d70c, &["\u{3c1}"]),
+    (0x1d70d, &["\u{3c2}"]),
+    (0x1d70e, &["\u{3c3}"]),
+    (0x1d70f, &["\u{3c4}"]),
+    (0x1d710, &["\u{3c5}"]),
+    (0x1d711, &["\u{3c6}"]),
+    (0x1d712, &["\u{3c7}"]),
+    (0x1d713, &["\u{3c8}"]),
+    (0x1d714, &["\u{3c9}"]),
+    (0x1d715, &["\u{2202}"]),
+    (0x1d716, &["\u{3f5}"]),
+    (0x1d717, &["\u{3d1}"]),
+    (0x1d718, &["\u{3f0}"]),
+    (0x1d719, &["\u{3d5}"]),
+    (0x1d71a, &["\u{3f1}"]),
+    (0x1d71b, &["\u{3d6}"]),
+    (0x1d71c, &["\u{391}"]),
+    (0x1d71d, &["\u{392}"]),
+    (0x1d71e, &["\u{393}"]),
+    (0x1d71f, &["\u{394}"]),
+    (0x1d720, &["\u{395}"]),
+    (0x1d721, &["\u{396}"]),
+    (0x1d722, &["\u{397}"]),
+    (0x1d723, &["\u{398}"]),
+    (0x1d724, &["\u{399}"]),
+    (0x1d725, &["\u{39a}"]),
+    (0x1d726, &["\u{39b}"]),
+    (0x1d727, &["\u{39c}"]),
+    (0x1d728, &["\u{39d}"]),
+    (0x1d729, &["\u{39e}"]),
+    (0x1d72a, &["\u{39f}"]),
+    (0x1d72b, &["\u{3a0}"]),
+    (0x1d72c, &["\u{3a1}"]),
+    (0x1d72d, &["\u{3f4}"]),
+    (0x1d72e, &["\u{3a3}"]),
+    (0x1d72f, &["\u{3a4}"]),
+    (0x1d730, &["\u{3a5}"]),
+    (0x1d731, &["\u{3a6}"]),
+    (0x1d732, &["\u{3a7}"]),
+    (0x1d733, &["\u{3a8}"]),
+    (0x1d734, &["\u{3a9}"]),
+    (0x1d735, &["\u{2207}"]),
+    (0x1d736, &["\u{3b1}"]),
+    (0x1d737, &["\u{3b2}"]),
+    (0x1d738, &["\u{3b3}"]),
+    (0x1d739, &["\u{3b4}"]),
+    (0x1d73a, &["\u{3b5}"]),
+    (0x1d73b, &["\u{3b6}"]),
+    (0x1d73c, &["\u{3b7}"]),
+    (0x1d73d, &["\u{3b8}"]),
+    (0x1d73e, &["\u{3b9}"]),
+    (0x1d73f, &["\u{3ba}"]),
+    (0x1d740, &["\u{3bb}"]),
+    (0x1d741, &["\u{3bc}"]),
+    (0x1d742, &["\u{3bd}"]),
+    (0x1d743, &["\u{3be}"]),
+    (0x1d744, &["\u{3bf}"]),
+    (0x1d745, &["\u{3c0}"]),
+    (0x1d746, &["\u{3c1}"]),
+    (0x1d747, &["\u{3c2}"]),
+    (0x1d748, &["\u{3c3}"]),
+    (0x1d749, &["\u{3c4}"]),
+    (0x1d74a, &["\u{3c5}"]),
+    (0x1d74b, &["\u{3c6}"]),
+    (0x1d74c, &["\u{3c7}"]),
+    (0x1d74d, &["\u{3c8}"]),
+    (0x1d74e, &["\u{3c9}"]),
+    (0x1d74f, &["\u{2202}"]),
+    (0x1d750, &["\u{3f5}"]),
+    (0x1d751, &["\u{3d1}"]),
+    (0x1d752, &["\u{3f0}"]),
+    (0x1d753, &["\u{3d5}"]),
+    (0x1d754, &["\u{3f1}"]),
+    (0x1d755, &["\u{3d6}"]),
+    (0x1d756, &["\u{391}"]),
+    (0x1d757, &["\u{392}"]),
+    (0x1d758, &["\u{393}"]),
+    (0x1d759, &["\u{394}"]),
+    (0x1d75a, &["\u{395}"]),
+    (0x1d75b, &["\u{396}"]),
+    (0x1d75c, &["\u{397}"]),
+    (0x1d75d, &["\u{398}"]),
+    (0x1d75e, &["\u{399}"]),
+    (0x1d75f, &["\u{39a}"]),
+    (0x1d760, &["\u{39b}"]),
+    (0x1d761, &["\u{39c}"]),
+    (0x1d762, &["\u{39d}"]),
+    (0x1d763, &["\u{39e}"]),
+    (0x1d764, &["\u{39f}"]),
+    (0x1d765, &["\u{3a0}"]),
+    (0x1d766, &["\u{3a1}"]),
+    (0x1d767, &["\u{3f4}"]),
+    (0x1d768, &["\u{3a3}"]),
+    (0x1d769, &["\u{3a4}"]),
+    (0x1d76a, &["\u{3a5}"]),
+    (0x1d76b, &["\u{3a6}"]),
+    (0x1d76c, &["\u{3a7}"]),
+    (0x1d76d, &["\u{3a8}"]),
+    (0x1d76e, &["\u{3a9}"]),
+    (0x1d76f, &["\u{2207}"]),
+    (0x1d770, &["\u{3b1}"]),
+    (0x1d771, &["\u{3b2}"]),
+    (0x1d772, &["\u{3b3}"]),
+    (0x1d773, &["\u{3b4}"]),
+    (0x1d774, &["\u{3b5}"]),
+    (0x1d775, &["\u{3b6}"]),
+    (0x1d776, &["\u{3b7}"]),
+    (0x1d777, &["\u{3b8}"]),
+    (0x1d778, &["\u{3b9}"]),
+    (0x1d779, &["\u{3ba}"]),
+    (0x1d77a, &["\u{3bb}"]),
+    (0x1d77b, &["\u{3bc}"]),
+    (0x1d77c, &["\u{3bd}"]),
+    (0x1d77d, &["\u{3be}"]),
+    (0x1d77e, &["\u{3bf}"]),
+    (0x1d77f, &["\u{3c0}"]),
+    (0x1d780, &["\u{3c1}"]),
+    (0x1d781, &["\u{3c2}"]),
+    (0x1d782, &["\u{3c3}"]),
+    (0x1d783, &["\u{3c4}"]),
+    (0x1d784, &["\u{3c5}"]),
+    (0x1d785, &["\u{3c6}"]),
+    (0x1d786, &["\u{3c7}"]),
+    (0x1d787, &["\u{3c8}"]),
+    (0x1d788, &["\u{3c9}"]),
+    (0x1d789, &["\u{2202}"]),
+    (0x1d78a, &["\u{3f5}"]),
+    (0x1d78b, &["\u{3d1}"]),
+    (0x1d78c, &["\u{3f0}"]),
+    (0x1d78d, &["\u{3d5}"]),
+    (0x1d78e, &["\u{3f1}"]),
+    (0x1d78f, &["\u{3d6}"]),
+    (0x1d790, &["\u{391}"]),
+    (0x1d791, &["\u{392}"]),
+    (0x1d792, &["\u{393}"]),
+    (0x1d793, &["\u{394}"]),
+    (0x1d794, &["\u{395}"]),
+    (0x1d795, &["\u{396}"]),
+    (0x1d796, &["\u{397}"]),
+    (0x1d797, &["\u{398}"]),
+    (0x1d798, &["\u{399}"]),
+    (0x1d799, &["\u{39a}"]),
+    (0x1d79a, &["\u{39b}"]),
+    (0x1d79b, &["\u{39c}"]),
+    (0x1d79c, &["\u{39d}"]),
+    (0x1d79d, &["\u{39e}"]),
+    (0x1d79e, &["\u{39f}"]),
+    (0x1d79f, &["\u{3a0}"]),
+    (0x1d7a0, &["\u{3a1}"]),
+    (0x1d7a1, &["\u{3f4}"]),
+    (0x1d7a2, &["\u{3a3}"]),
+    (0x1d7a3, &["\u{3a4}"]),
+    (0x1d7a4, &["\u{3a5}"]),
+    (0x1d7a5, &["\u{3a6}"]),
+    (0x1d7a6, &["\u{3a7}"]),
+    (0x1d7a7, &["\u{3a8}"]),
+    (0x1d7a8, &["\u{3a9}"]),
+    (0x1d7a9, &["\u{2207}"]),
+    (0x1d7aa, &["\u{3b1}"]),
+    (0x1d7ab, &["\u{3b2}"]),
+    (0x1d7ac, &["\u{3b3}"]),
+    (0x1d7ad, &["\u{3b4}"]),
+    (0x1d7ae, &["\u{3b5}"]),
+    (0x1d7af, &["\u{3b6}"]),
+    (0x1d7b0, &["\u{3b7}"]),
+    (0x1d7b1, &["\u{3b8}"]),
+    (0x1d7b2, &["\u{3b9}"]),
+    (0x1d7b3, &["\u{3ba}"]),
+    (0x1d7b4, &["\u{3bb}"]),
+    (0x1d7b5, &["\u{3bc}"]),
+    (0x1d7b6, &["\u{3bd}"]),
+    (0x1d7b7, &["\u{3be}"]),
+    (0x1d7b8, &["\u{3bf}"]),
+    (0x1d7b9, &["\u{3c0}"]),
+    (0x1d7ba, &["\u{3c1}"]),
+    (0x1d7bb, &["\u{3c2}"]),
+    (0x1d7bc, &["\u{3c3}"]),
+    (0x1d7bd, &["\u{3c4}"]),
+    (0x1d7be, &["\u{3c5}"]),
+    (0x1d7bf, &["\u{3c6}"]),
+    (0x1d7c0, &["\u{3c7}"]),
+    (0x1d7c1, &["\u{3c8}"]),
+    (0x1d7c2, &["\u{3c9}"]),
+    (0x1d7c3, &["\u{2202}"]),
+    (0x1d7c4, &["\u{3f5}"]),
+    (0x1d7c5, &["\u{3d1}"]),
+    (0x1d7c6, &["\u{3f0}"]),
+    (0x1d7c7, &["\u{3d5}"]),
+    (0x1d7c8, &["\u{3f1}"]),
+    (0x1d7c9, &["\u{3d6}"]),
+    (0x1d7ca, &["\u{3dc}"]),
+    (0x1d7cb, &["\u{3dd}"]),
+    (0x1d7ce, &["0"]),
+    (0x1d7cf, &["1"]),
+    (0x1d7d0, &["2"]),
+    (0x1d7d1, &["3"]),
+    (0x1d7d2, &["4"]),
+    (0x1d7d3, &["5"]),
+    (0x1d7d4, &["6"]),
+    (0x1d7d5, &["7"]),
+    (0x1d7d6, &["8"]),
+    (0x1d7d7, &["9"]),
+    (0x1d7d8, &["0"]),
+    (0x1d7d9, &["1"]),
+    (0x1d7da, &["2"]),
+    (0x1d7db, &["3"]),
+    (0x1d7dc, &["4"]),
+    (0x1d7dd, &["5"]),
+    (0x1d7de, &["6"]),
+    (0x1d7df, &["7"]),
+    (0x1d7e0, &["8"]),
+    (0x1d7e1, &["9"]),
+    (0x1d7e2, &["0"]),
+    (0x1d7e3, &["1"]),
+    (0x1d7e4, &["2"]),
+    (0x1d7e5, &["3"]),
+    (0x1d7e6, &["4"]),
+    (0x1d7e7, &["5"]),
+    (0x1d7e8, &["6"]),
+    (0x1d7e9, &["7"]),
+    (0x1d7ea, &["8"]),
+    (0x1d7eb, &["9"]),
+    (0x1d7ec, &["0"]),
+    (0x1d7ed, &["1"]),
+    (0x1d7ee, &["2"]),
+    (0x1d7ef, &["3"]),
+    (0x1d7f0, &["4"]),
+    (0x1d7f1, &["5"]),
+    (0x1d7f2, &["6"]),
+    (0x1d7f3, &["7"]),
+    (0x1d7f4, &["8"]),
+    (0x1d7f5, &["9"]),
+    (0x1d7f6, &["0"]),
+    (0x1d7f7, &["1"]),
+    (0x1d7f8, &["2"]),
+    (0x1d7f9, &["3"]),
+    (0x1d7fa, &["4"]),
+    (0x1d7fb, &["5"]),
+    (0x1d7fc, &["6"]),
+    (0x1d7fd, &["7"]),
+    (0x1d7fe, &["8"]),
+    (0x1d7ff, &["9"]),
+    (0x1e000, &[""]),
+    (0x1e001, &[""]),
+    (0x1e002, &[""]),
+    (0x1e003, &[""]),
+    (0x1e004, &[""]),
+    (0x1e005, &[""]),
+    (0x1e006, &[""]),
+    (0x1e008, &[""]),
+    (0x1e009, &[""]),
+    (0x1e00a, &[""]),
+    (0x1e00b, &[""]),
+    (0x1e00c, &[""]),
+    (0x1e00d, &[""]),
+    (0x1e00e, &[""]),
+    (0x1e00f, &[""]),
+    (0x1e010, &[""]),
+    (0x1e011, &[""]),
+    (0x1e012, &[""]),
+    (0x1e013, &[""]),
+    (0x1e014, &[""]),
+    (0x1e015, &[""]),
+    (0x1e016, &[""]),
+    (0x1e017, &[""]),
+    (0x1e018, &[""]),
+    (0x1e01b, &[""]),
+    (0x1e01c, &[""]),
+    (0x1e01d, &[""]),
+    (0x1e01e, &[""]),
+    (0x1e01f, &[""]),
+    (0x1e020, &[""]),
+    (0x1e021, &[""]),
+    (0x1e023, &[""]),
+    (0x1e024, &[""]),
+    (0x1e026, &[""]),
+    (0x1e027, &[""]),
+    (0x1e028, &[""]),
+    (0x1e029, &[""]),
+    (0x1e02a, &[""]),
+    (0x1e130, &[""]),
+    (0x1e131, &[""]),
+    (0x1e132, &[""]),
+    (0x1e133, &[""]),
+    (0x1e134, &[""]),
+    (0x1e135, &[""]),
+    (0x1e136, &[""]),
+    (0x1e2ae, &[""]),
+    (0x1e2ec, &[""]),
+    (0x1e2ed, &[""]),
+    (0x1e2ee, &[""]),
+    (0x1e2ef, &[""]),
+    (0x1e944, &[""]),
+    (0x1e945, &[""]),
+    (0x1e946, &[""]),
+    (0x1e947, &[""]),
+    (0x1e948, &[""]),
+    (0x1e949, &[""]),
+    (0x1e94a, &[""]),
+    (0x1ee00, &["\u{627}"]),
+    (0x1ee01, &["\u{628}"]),
+    (0x1ee02, &["\u{62c}"]),
+    (0x1ee03, &["\u{62f}"]),
+    (0x1ee05, &["\u{648}"]),
+    (0x1ee06, &["\u{632}"]),
+    (0x1ee07, &["\u{62d}"]),
+    (0x1ee08, &["\u{637}"]),
+    (0x1ee09, &["\u{64a}"]),
+    (0x1ee0a, &["\u{643}"]),
+    (0x1ee0b, &["\u{644}"]),
+    (0x1ee0c, &["\u{645}"]),
+    (0x1ee0d, &["\u{646}"]),
+    (0x1ee0e, &["\u{633}"]),
+    (0x1ee0f, &["\u{639}"]),
+    (0x1ee10, &["\u{641}"]),
+    (0x1ee11, &["\u{635}"]),
+    (0x1ee12, &["\u{642}"]),
+    (0x1ee13, &["\u{631}"]),
+    (0x1ee14, &["\u{634}"]),
+    (0x1ee15, &["\u{62a}"]),
+    (0x1ee16, &["\u{62b}"]),
+    (0x1ee17, &["\u{62e}"]),
+    (0x1ee18, &["\u{630}"]),
+    (0x1ee19, &["\u{636}"]),
+    (0x1ee1a, &["\u{638}"]),
+    (0x1ee1b, &["\u{63a}"]),
+    (0x1ee1c, &["\u{66e}"]),
+    (0x1ee1d, &["\u{6ba}"]),
+    (0x1ee1e, &["\u{6a1}"]),
+    (0x1ee1f, &["\u{66f}"]),
+    (0x1ee21, &["\u{628}"]),
+    (0x1ee22, &["\u{62c}"]),
+    (0x1ee24, &["\u{647}"]),
+    (0x1ee27, &["\u{62d}"]),
+    (0x1ee29, &["\u{64a}"]),
+    (0x1ee2a, &["\u{643}"]),
+    (0x1ee2b, &["\u{644}"]),
+    (0x1ee2c, &["\u{645}"]),
+    (0x1ee2d, &["\u{646}"]),
+    (0x1ee2e, &["\u{633}"]),
+    (0x1ee2f, &["\u{639}"]),
+    (0x1ee30, &["\u{641}"]),
+    (0x1ee31, &["\u{635}"]),
+    (0x1ee32, &["\u{642}"]),
+    (0x1ee34, &["\u{634}"]),
+    (0x1ee35, &["\u{62a}"]),
+    (0x1ee36, &["\u{62b}"]),
+    (0x1ee37, &["\u{62e}"]),
+    (0x1ee39, &["\u{636}"]),
+    (0x1ee3b, &["\u{63a}"]),
+    (0x1ee42, &["\u{62c}"]),
+    (0x1ee47, &["\u{62d}"]),
+    (0x1ee49, &["\u{64a}"]),
+    (0x1ee4b, &["\u{644}"]),
+    (0x1ee4d, &["\u{646}"]),
+    (0x1ee4e, &["\u{633}"]),
+    (0x1ee4f, &["\u{639}"]),
+    (0x1ee51, &["\u{635}"]),
+    (0x1ee52, &["\u{642}"]),
+    (0x1ee54, &["\u{634}"]),
+    (0x1ee57, &["\u{62e}"]),
+    (0x1ee59, &["\u{636}"]),
+    (0x1ee5b, &["\u{63a}"]),
+    (0x1ee5d, &["\u{6ba}"]),
+    (0x1ee5f, &["\u{66f}"]),
+    (0x1ee61, &["\u{628}"]),
+    (0x1ee62, &["\u{62c}"]),
+    (0x1ee64, &["\u{647}"]),
+    (0x1ee67, &["\u{62d}"]),
+    (0x1ee68, &["\u{637}"]),
+    (0x1ee69, &["\u{64a}"]),
+    (0x1ee6a, &["\u{643}"]),
+    (0x1ee6c, &["\u{645}"]),
+    (0x1ee6d, &["\u{646}"]),
+    (0x1ee6e, &["\u{633}"]),
+    (0x1ee6f, &["\u{639}"]),
+    (0x1ee70, &["\u{641}"]),
+    (0x1ee71, &["\u{635}"]),
+    (0x1ee72, &["\u{642}"]),
+    (0x1ee74, &["\u{634}"]),
+    (0x1ee75, &["\u{62a}"]),
+    (0x1ee76, &["\u{62b}"]),
+    (0x1ee77, &["\u{62e}"]),
+    (0x1ee79, &["\u{636}"]),
+    (0x1ee7a, &["\u{638}"]),
+    (0x1ee7b, &["\u{63a}"]),
+    (0x1ee7c, &["\u{66e}"]),
+    (0x1ee7e, &["\u{6a1}"]),
+    (0x1ee80, &["\u{627}"]),
+    (0x1ee81, &["\u{628}"]),
+    (0x1ee82, &["\u{62c}"]),
+    (0x1ee83, &["\u{62f}"]),
+    (0x1ee84, &["\u{647}"]),
+    (0x1ee85, &["\u{648}"]),
+    (0x1ee86, &["\u{632}"]),
+    (0x1ee87, &["\u{62d}"]),
+    (0x1ee88, &["\u{637}"]),
+    (0x1ee89, &["\u{64a}"]),
+    (0x1ee8b, &["\u{644}"]),
+    (0x1ee8c, &["\u{645}"]),
+    (0x1ee8d, &["\u{646}"]),
+    (0x1ee8e, &["\u{633}"]),
+    (0x1ee8f, &["\u{639}"]),
+    (0x1ee90, &["\u{641}"]),
+    (0x1ee91, &["\u{635}"]),
+    (0x1ee92, &["\u{642}"]),
+    (0x1ee93, &["\u{631}"]),
+    (0x1ee94, &["\u{634}"]),
+    (0x1ee95, &["\u{62a}"]),
+    (0x1ee96, &["\u{62b}"]),
+    (0x1ee97, &["\u{62e}"]),
+    (0x1ee98, &["\u{630}"]),
+    (0x1ee99, &["\u{636}"]),
+    (0x1ee9a, &["\u{638}"]),
+    (0x1ee9b, &["\u{63a}"]),
+    (0x1eea1, &["\u{628}"]),
+    (0x1eea2, &["\u{62c}"]),
+    (0x1eea3, &["\u{62f}"]),
+    (0x1eea5, &["\u{648}"]),
+    (0x1eea6, &["\u{632}"]),
+    (0x1eea7, &["\u{62d}"]),
+    (0x1eea8, &["\u{637}"]),
+    (0x1eea9, &["\u{64a}"]),
+    (0x1eeab, &["\u{644}"]),
+    (0x1eeac, &["\u{645}"]),
+    (0x1eead, &["\u{646}"]),
+    (0x1eeae, &["\u{633}"]),
+    (0x1eeaf, &["\u{639}"]),
+    (0x1eeb0, &["\u{641}"]),
+    (0x1eeb1, &["\u{635}"]),
+    (0x1eeb2, &["\u{642}"]),
+    (0x1eeb3, &["\u{631}"]),
+    (0x1eeb4, &["\u{634}"]),
+    (0x1eeb5, &["\u{62a}"]),
+    (0x1eeb6, &["\u{62b}"]),
+    (0x1eeb7, &["\u{62e}"]),
+    (0x1eeb8, &["\u{630}"]),
+    (0x1eeb9, &["\u{636}"]),
+    (0x1eeba, &["\u{638}"]),
+    (0x1eebb, &["\u{63a}"]),
+    (0x1f100, &["0."]),
+    (0x1f101, &["0,"]),
+    (0x1f102, &["1,"]),
+    (0x1f103, &["2,"]),
+    (0x1f104, &["3,"]),
+    (0x1f105, &["4,"]),
+    (0x1f106, &["5,"]),
+    (0x1f107, &["6,"]),
+    (0x1f108, &["7,"]),
+    (0x1f109, &["8,"]),
+    (0x1f10a, &["9,"]),
+    (0x1f110, &["(A)"]),
+    (0x1f111, &["(B)"]),
+    (0x1f112, &["(C)"]),
+    (0x1f113, &["(D)"]),
+    (0x1f114, &["(E)"]),
+    (0x1f115, &["(F)"]),
+    (0x1f116, &["(G)"]),
+    (0x1f117, &["(H)"]),
+    (0x1f118, &["(I)"]),
+    (0x1f119, &["(J)"]),
+    (0x1f11a, &["(K)"]),
+    (0x1f11b, &["(L)"]),
+    (0x1f11c, &["(M)"]),
+    (0x1f11d, &["(N)"]),
+    (0x1f11e, &["(O)"]),
+    (0x1f11f, &["(P)"]),
+    (0x1f120, &["(Q)"]),
+    (0x1f121, &["(R)"]),
+    (0x1f122, &["(S)"]),
+    (0x1f123, &["(T)"]),
+    (0x1f124, &["(U)"]),
+    (0x1f125, &["(V)"]),
+    (0x1f126, &["(W)"]),
+    (0x1f127, &["(X)"]),
+    (0x1f128, &["(Y)"]),
+    (0x1f129, &["(Z)"]),
+    (0x1f12a, &["\u{3014}S\u{3015}"]),
+    (0x1f12b, &["(C)"]),
+    (0x1f12c, &["(R)"]),
+    (0x1f12d, &["(CD)"]),
+    (0x1f12e, &["(WZ)"]),
+    (0x1f130, &["A"]),
+    (0x1f131, &["B"]),
+    (0x1f132, &["C"]),
+    (0x1f133, &["D"]),
+    (0x1f134, &["E"]),
+    (0x1f135, &["F"]),
+    (0x1f136, &["G"]),
+    (0x1f137, &["H"]),
+    (0x1f138, &["I"]),
+    (0x1f139, &["J"]),
+    (0x1f13a, &["K"]),
+    (0x1f13b, &["L"]),
+    (0x1f13c, &["M"]),
+    (0x1f13d, &["N"]),
+    (0x1f13e, &["O"]),
+    (0x1f13f, &["P"]),
+    (0x1f140, &["Q"]),
+    (0x1f141, &["R"]),
+    (0x1f142, &["S"]),
+    (0x1f143, &["T"]),
+    (0x1f144, &["U"]),
+    (0x1f145, &["V"]),
+    (0x1f146, &["W"]),
+    (0x1f147, &["X"]),
+    (0x1f148, &["Y"]),
+    (0x1f149, &["Z"]),
+    (0x1f14a, &["HV"]),
+    (0x1f14b, &["MV"]),
+    (0x1f14c, &["SD"]),
+    (0x1f14d, &["SS"]),
+    (0x1f14e, &["PPV"]),
+    (0x1f14f, &["WC"]),
+    (0x1f16a, &["MC"]),
+    (0x1f16b, &["MD"]),
+    (0x1f16c, &["MR"]),
+    (0x1f190, &["DJ"]),
+    (0x1f200, &["\u{307b}\u{304b}"]),
+    (0x1f201, &["\u{30b3}\u{30b3}"]),
+    (0x1f202, &["\u{30b5}"]),
+    (0x1f210, &["\u{624b}"]),
+    (0x1f211, &["\u{5b57}"]),
+    (0x1f212, &["\u{53cc}"]),
+    (0x1f213, &["\u{30c7}"]),
+    (0x1f214, &["\u{4e8c}"]),
+    (0x1f215, &["\u{591a}"]),
+    (0x1f216, &["\u{89e3}"]),
+    (0x1f217, &["\u{5929}"]),
+    (0x1f218, &["\u{4ea4}"]),
+    (0x1f219, &["\u{6620}"]),
+    (0x1f21a, &["\u{7121}"]),
+    (0x1f21b, &["\u{6599}"]),
+    (0x1f21c, &["\u{524d}"]),
+    (0x1f21d, &["\u{5f8c}"]),
+    (0x1f21e, &["\u{518d}"]),
+    (0x1f21f, &["\u{65b0}"]),
+    (0x1f220, &["\u{521d}"]),
+    (0x1f221, &["\u{7d42}"]),
+    (0x1f222, &["\u{751f}"]),
+    (0x1f223, &["\u{8ca9}"]),
+    (0x1f224, &["\u{58f0}"]),
+    (0x1f225, &["\u{5439}"]),
+    (0x1f226, &["\u{6f14}"]),
+    (0x1f227, &["\u{6295}"]),
+    (0x1f228, &["\u{6355}"]),
+    (0x1f229, &["\u{4e00}"]),
+    (0x1f22a, &["\u{4e09}"]),
+    (0x1f22b, &["\u{904a}"]),
+    (0x1f22c, &["\u{5de6}"]),
+    (0x1f22d, &["\u{4e2d}"]),
+    (0x1f22e, &["\u{53f3}"]),
+    (0x1f22f, &["\u{6307}"]),
+    (0x1f230, &["\u{8d70}"]),
+    (0x1f231, &["\u{6253}"]),
+    (0x1f232, &["\u{7981}"]),
+    (0x1f233, &["\u{7a7a}"]),
+    (0x1f234, &["\u{5408}"]),
+    (0x1f235, &["\u{6e80}"]),
+    (0x1f236, &["\u{6709}"]),
+    (0x1f237, &["\u{6708}"]),
+    (0x1f238, &["\u{7533}"]),
+    (0x1f239, &["\u{5272}"]),
+    (0x1f23a, &["\u{55b6}"]),
+    (0x1f23b, &["\u{914d}"]),
+    (0x1f240, &["\u{3014}\u{672c}\u{3015}"]),
+    (0x1f241, &["\u{3014}\u{4e09}\u{3015}"]),
+    (0x1f242, &["\u{3014}\u{4e8c}\u{3015}"]),
+    (0x1f243, &["\u{3014}\u{5b89}\u{3015}"]),
+    (0x1f244, &["\u{3014}\u{70b9}\u{3015}"]),
+    (0x1f245, &["\u{3014}\u{6253}\u{3015}"]),
+    (0x1f246, &["\u{3014}\u{76d7}\u{3015}"]),
+    (0x1f247, &["\u{3014}\u{52dd}\u{3015}"]),
+    (0x1f248, &["\u{3014}\u{6557}\u{3015}"]),
+    (0x1f250, &["(\u{5f97})"]),
+    (0x1f251, &["(\u{53ef})"]),
+    (0x1fbf0, &["0"]),
+    (0x1fbf1, &["1"]),
+    (0x1fbf2, &["2"]),
+    (0x1fbf3, &["3"]),
+    (0x1fbf4, &["4"]),
+    (0x1fbf5, &["5"]),
+    (0x1fbf6, &["6"]),
+    (0x1fbf7, &["7"]),
+    (0x1fbf8, &["8"]),
+    (0x1fbf9, &["9"]),
+    (0x2f800, &["\u{4e3d}"]),
+    (0x2f801, &["\u{4e38}"]),
+    (0x2f802, &["\u{4e41}"]),
+    (0x2f803, &["\u{20122}"]),
+    (0x2f804, &["\u{4f60}"]),
+    (0x2f805, &["\u{4fae}"]),
+    (0x2f806, &["\u{4fbb}"]),
+    (0x2f807, &["\u{5002}"]),
+    (0x2f808, &["\u{507a}"]),
+    (0x2f809, &["\u{5099}"]),
+    (0x2f80a, &["\u{50e7}"]),
+    (0x2f80b, &["\u{50cf}"]),
+    (0x2f80c, &["\u{349e}"]),
+    (0x2f80d, &["\u{2063a}"]),
+    (0x2f80e, &["\u{514d}"]),
+    (0x2f80f, &["\u{5154}"]),
+    (0x2f810, &["\u{5164}"]),
+    (0x2f811, &["\u{5177}"]),
+    (0x2f812, &["\u{2051c}"]),
+    (0x2f813, &["\u{34b9}"]),
+    (0x2f814, &["\u{5167}"]),
+    (0x2f815, &["\u{518d}"]),
+    (0x2f816, &["\u{2054b}"]),
+    (0x2f817, &["\u{5197}"]),
+    (0x2f818, &["\u{51a4}"]),
+    (0x2f819, &["\u{4ecc}"]),
+    (0x2f81a, &["\u{51ac}"]),
+    (0x2f81b, &["\u{51b5}"]),
+    (0x2f81c, &["\u{291df}"]),
+    (0x2f81d, &["\u{51f5}"]),
+    (0x2f81e, &["\u{5203}"]),
+    (0x2f81f, &["\u{34df}"]),
+    (0x2f820, &["\u{523b}"]),
+    (0x2f821, &["\u{5246}"]),
+    (0x2f822, &["\u{5272}"]),
+    (0x2f823, &["\u{5277}"]),
+    (0x2f824, &["\u{3515}"]),
+    (0x2f825, &["\u{52c7}"]),
+    (0x2f826, &["\u{52c9}"]),
+    (0x2f827, &["\u{52e4}"]),
+    (0x2f828, &["\u{52fa}"]),
+    (0x2f829, &["\u{5305}"]),
+    (0x2f82a, &["\u{5306}"]),
+    (0x2f82b, &["\u{5317}"]),
+    (0x2f82c, &["\u{5349}"]),
+    (0x2f82d, &["\u{5351}"]),
+    (0x2f82e, &["\u{535a}"]),
+    (0x2f82f, &["\u{5373}"]),
+    (0x2f830, &["\u{537d}"]),
+    (0x2f831, &["\u{537f}"]),
+    (0x2f832, &["\u{537f}"]),
+    (0x2f833, &["\u{537f}"]),
+    (0x2f834, &["\u{20a2c}"]),
+    (0x2f835, &["\u{7070}"]),
+    (0x2f836, &["\u{53ca}"]),
+    (0x2f837, &["\u{53df}"]),
+    (0x2f838, &["\u{20b63}"]),
+    (0x2f839, &["\u{53eb}"]),
+    (0x2f83a, &["\u{53f1}"]),
+    (0x2f83b, &["\u{5406}"]),
+    (0x2f83c, &["\u{549e}"]),
+    (0x2f83d, &["\u{5438}"]),
+    (0x2f83e, &["\u{5448}"]),
+    (0x2f83f, &["\u{5468}"]),
+    (0x2f840, &["\u{54a2}"]),
+    (0x2f841, &["\u{54f6}"]),
+    (0x2f842, &["\u{5510}"]),
+    (0x2f843, &["\u{5553}"]),
+    (0x2f844, &["\u{5563}"]),
+    (0x2f845, &["\u{5584}"]),
+    (0x2f846, &["\u{5584}"]),
+    (0x2f847, &["\u{5599}"]),
+    (0x2f848, &["\u{55ab}"]),
+    (0x2f849, &["\u{55b3}"]),
+    (0x2f84a, &["\u{55c2}"]),
+    (0x2f84b, &["\u{5716}"]),
+    (0x2f84c, &["\u{5606}"]),
+    (0x2f84d, &["\u{5717}"]),
+    (0x2f84e, &["\u{5651}"]),
+    (0x2f84f, &["\u{5674}"]),
+    (0x2f850, &["\u{5207}"]),
+    (0x2f851, &["\u{58ee}"]),
+    (0x2f852, &["\u{57ce}"]),
+    (0x2f853, &["\u{57f4}"]),
+    (0x2f854, &["\u{580d}"]),
+    (0x2f855, &["\u{578b}"]),
+    (0x2f856, &["\u{5832}"]),
+    (0x2f857, &["\u{5831}"]),
+    (0x2f858, &["\u{58ac}"]),
+    (0x2f859, &["\u{214e4}"]),
+    (0x2f85a, &["\u{58f2}"]),
+    (0x2f85b, &["\u{58f7}"]),
+    (0x2f85c, &["\u{5906}"]),
+    (0x2f85d, &["\u{591a}"]),
+    (0x2f85e, &["\u{5922}"]),
+    (0x2f85f, &["\u{5962}"]),
+    (0x2f860, &["\u{216a8}"]),
+    (0x2f861, &["\u{216ea}"]),
+    (0x2f862, &["\u{59ec}"]),
+    (0x2f863, &["\u{5a1b}"]),
+    (0x2f864, &["\u{5a27}"]),
+    (0x2f865, &["\u{59d8}"]),
+    (0x2f866, &["\u{5a66}"]),
+    (0x2f867, &["\u{36ee}"]),
+    (0x2f868, &["\u{36fc}"]),
+    (0x2f869, &["\u{5b08}"]),
+    (0x2f86a, &["\u{5b3e}"]),
+    (0x2f86b, &["\u{5b3e}"]),
+    (0x2f86c, &["\u{219c8}"]),
+    (0x2f86d, &["\u{5bc3}"]),
+    (0x2f86e, &["\u{5bd8}"]),
+    (0x2f86f, &["\u{5be7}"]),
+    (0x2f870, &["\u{5bf3}"]),
+    (0x2f871, &["\u{21b18}"]),
+    (0x2f872, &["\u{5bff}"]),
+    (0x2f873, &["\u{5c06}"]),
+    (0x2f874, &["\u{5f53}"]),
+    (0x2f875, &["\u{5c22}"]),
+    (0x2f876, &["\u{3781}"]),
+    (0x2f877, &["\u{5c60}"]),
+    (0x2f878, &["\u{5c6e}"]),
+    (0x2f879, &["\u{5cc0}"]),
+    (0x2f87a, &["\u{5c8d}"]),
+    (0x2f87b, &["\u{21de4}"]),
+    (0x2f87c, &["\u{5d43}"]),
+    (0x2f87d, &["\u{21de6}"]),
+    (0x2f87e, &["\u{5d6e}"]),
+    (0x2f87f, &["\u{5d6b}"]),
+    (0x2f880, &["\u{5d7c}"]),
+    (0x2f881, &["\u{5de1}"]),
+    (0x2f882, &["\u{5de2}"]),
+    (0x2f883, &["\u{382f}"]),
+    (0x2f884, &["\u{5dfd}"]),
+    (0x2f885, &["\u{5e28}"]),
+    (0x2f886, &["\u{5e3d}"]),
+    (0x2f887, &["\u{5e69}"]),
+    (0x2f888, &["\u{3862}"]),
+    (0x2f889, &["\u{22183}"]),
+    (0x2f88a, &["\u{387c}"]),
+    (0x2f88b, &["\u{5eb0}"]),
+    (0x2f88c, &["\u{5eb3}"]),
+    (0x2f88d, &["\u{5eb6}"]),
+    (0x2f88e, &["\u{5eca}"]),
+    (0x2f88f, &["\u{2a392}"]),
+    (0x2f890, &["\u{5efe}"]),
+    (0x2f891, &["\u{22331}"]),
+    (0x2f892, &["\u{22331}"]),
+    (0x2f893, &["\u{8201}"]),
+    (0x2f894, &["\u{5f22}"]),
+    (0x2f895, &["\u{5f22}"]),
+    (0x2f896, &["\u{38c7}"]),
+    (0x2f897, &["\u{232b8}"]),
+    (0x2f898, &["\u{261da}"]),
+    (0x2f899, &["\u{5f62}"]),
+    (0x2f89a, &["\u{5f6b}"]),
+    (0x2f89b, &["\u{38e3}"]),
+    (0x2f89c, &["\u{5f9a}"]),
+    (0x2f89d, &["\u{5fcd}"]),
+    (0x2f89e, &["\u{5fd7}"]),
+    (0x2f89f, &["\u{5ff9}"]),
+    (0x2f8a0, &["\u{6081}"]),
+    (0x2f8a1, &["\u{393a}"]),
+    (0x2f8a2, &["\u{391c}"]),
+    (0x2f8a3, &["\u{6094}"]),
+    (0x2f8a4, &["\u{226d4}"]),
+    (0x2f8a5, &["\u{60c7}"]),
+    (0x2f8a6, &["\u{6148}"]),
+    (0x2f8a7, &["\u{614c}"]),
+    (0x2f8a8, &["\u{614e}"]),
+    (0x2f8a9, &["\u{614c}"]),
+    (0x2f8aa, &["\u{617a}"]),
+    (0x2f8ab, &["\u{618e}"]),
+    (0x2f8ac, &["\u{61b2}"]),
+    (0x2f8ad, &["\u{61a4}"]),
+    (0x2f8ae, &["\u{61af}"]),
+    (0x2f8af, &["\u{61de}"]),
+    (0x2f8b0, &["\u{61f2}"]),
+    (0x2f8b1, &["\u{61f6}"]),
+    (0x2f8b2, &["\u{6210}"]),
+    (0x2f8b3, &["\u{621b}"]),
+    (0x2f8b4, &["\u{625d}"]),
+    (0x2f8b5, &["\u{62b1}"]),
+    (0x2f8b6, &["\u{62d4}"]),
+    (0x2f8b7, &["\u{6350}"]),
+    (0x2f8b8, &["\u{22b0c}"]),
+    (0x2f8b9, &["\u{633d}"]),
+    (0x2f8ba, &["\u{62fc}"]),
+    (0x2f8bb, &["\u{6368}"]),
+    (0x2f8bc, &["\u{6383}"]),
+    (0x2f8bd, &["\u{63e4}"]),
+    (0x2f8be, &["\u{22bf1}"]),
+    (0x2f8bf, &["\u{6422}"]),
+    (0x2f8c0, &["\u{63c5}"]),
+    (0x2f8c1, &["\u{63a9}"]),
+    (0x2f8c2, &["\u{3a2e}"]),
+    (0x2f8c3, &["\u{6469}"]),
+    (0x2f8c4, &["\u{647e}"]),
+    (0x2f8c5, &["\u{649d}"]),
+    (0x2f8c6, &["\u{6477}"]),
+    (0x2f8c7, &["\u{3a6c}"]),
+    (0x2f8c8, &["\u{654f}"]),
+    (0x2f8c9, &["\u{656c}"]),
+    (0x2f8ca, &["\u{2300a}"]),
+    (0x2f8cb, &["\u{65e3}"]),
+    (0x2f8cc, &["\u{66f8}"]),
+    (0x2f8cd, &["\u{6649}"]),
+    (0x2f8ce, &["\u{3b19}"]),
+    (0x2f8cf, &["\u{6691}"]),
+    (0x2f8d0, &["\u{3b08}"]),
+    (0x2f8d1, &["\u{3ae4}"]),
+    (0x2f8d2, &["\u{5192}"]),
+    (0x2f8d3, &["\u{5195}"]),
+    (0x2f8d4, &["\u{6700}"]),
+    (0x2f8d5, &["\u{669c}"]),
+    (0x2f8d6, &["\u{80ad}"]),
+    (0x2f8d7, &["\u{43d9}"]),
+    (0x2f8d8, &["\u{6717}"]),
+    (0x2f8d9, &["\u{671b}"]),
+    (0x2f8da, &["\u{6721}"]),
+    (0x2f8db, &["\u{675e}"]),
+    (0x2f8dc, &["\u{6753}"]),
+    (0x2f8dd, &["\u{233c3}"]),
+    (0x2f8de, &["\u{3b49}"]),
+    (0x2f8df, &["\u{67fa}"]),
+    (0x2f8e0, &["\u{6785}"]),
+    (0x2f8e1, &["\u{6852}"]),
+    (0x2f8e2, &["\u{6885}"]),
+    (0x2f8e3, &["\u{2346d}"]),
+    (0x2f8e4, &["\u{688e}"]),
+    (0x2f8e5, &["\u{681f}"]),
+    (0x2f8e6, &["\u{6914}"]),
+    (0x2f8e7, &["\u{3b9d}"]),
+    (0x2f8e8, &["\u{6942}"]),
+    (0x2f8e9, &["\u{69a3}"]),
+    (0x2f8ea, &["\u{69ea}"]),
+    (0x2f8eb, &["\u{6aa8}"]),
+    (0x2f8ec, &["\u{236a3}"]),
+    (0x2f8ed, &["\u{6adb}"]),
+    (0x2f8ee, &["\u{3c18}"]),
+    (0x2f8ef, &["\u{6b21}"]),
+    (0x2f8f0, &["\u{238a7}"]),
+    (0x2f8f1, &["\u{6b54}"]),
+    (0x2f8f2, &["\u{3c4e}"]),
+    (0x2f8f3, &["\u{6b72}"]),
+    (0x2f8f4, &["\u{6b9f}"]),
+    (0x2f8f5, &["\u{6bba}"]),
+    (0x2f8f6, &["\u{6bbb}"]),
+    (0x2f8f7, &["\u{23a8d}"]),
+    (0x2f8f8, &["\u{21d0b}"]),
+    (0x2f8f9, &["\u{23afa}"]),
+    (0x2f8fa, &["\u{6c4e}"]),
+    (0x2f8fb, &["\u{23cbc}"]),
+    (0x2f8fc, &["\u{6cbf}"]),
+    (0x2f8fd, &["\u{6ccd}"]),
+    (0x2f8fe, &["\u{6c67}"]),
+    (0x2f8ff, &["\u{6d16}"]),
+    (0x2f900, &["\u{6d3e}"]),
+    (0x2f901, &["\u{6d77}"]),
+    (0x2f902, &["\u{6d41}"]),
+    (0x2f903, &["\u{6d69}"]),
+    (0x2f904, &["\u{6d78}"]),
+    (0x2f905, &["\u{6d85}"]),
+    (0x2f906, &["\u{23d1e}"]),
+    (0x2f907, &["\u{6d34}"]),
+    (0x2f908, &["\u{6e2f}"]),
+    (0x2f909, &["\u{6e6e}"]),
+    (0x2f90a, &["\u{3d33}"]),
+    (0x2f90b, &["\u{6ecb}"]),
+    (0x2f90c, &["\u{6ec7}"]),
+    (0x2f90d, &["\u{23ed1}"]),
+    (0x2f90e, &["\u{6df9}"]),
+    (0x2f90f, &["\u{6f6e}"]),
+    (0x2f910, &["\u{23f5e}"]),
+    (0x2f911, &["\u{23f8e}"]),
+    (0x2f912, &["\u{6fc6}"]),
+    (0x2f913, &["\u{7039}"]),
+    (0x2f914, &["\u{701e}"]),
+    (0x2f915, &["\u{701b}"]),
+    (0x2f916, &["\u{3d96}"]),
+    (0x2f917, &["\u{704a}"]),
+    (0x2f918, &["\u{707d}"]),
+    (0x2f919, &["\u{7077}"]),
+    (0x2f91a, &["\u{70ad}"]),
+    (0x2f91b, &["\u{20525}"]),
+    (0x2f91c, &["\u{7145}"]),
+    (0x2f91d, &["\u{24263}"]),
+    (0x2f91e, &["\u{719c}"]),
+    (0x2f91f, &["\u{243ab}"]),
+    (0x2f920, &["\u{7228}"]),
+    (0x2f921, &["\u{7235}"]),
+    (0x2f922, &["\u{7250}"]),
+    (0x2f923, &["\u{24608}"]),
+    (0x2f924, &["\u{7280}"]),
+    (0x2f925, &["\u{7295}"]),
+    (0x2f926, &["\u{24735}"]),
+    (0x2f927, &["\u{24814}"]),
+    (0x2f928, &["\u{737a}"]),
+    (0x2f929, &["\u{738b}"]),
+    (0x2f92a, &["\u{3eac}"]),
+    (0x2f92b, &["\u{73a5}"]),
+    (0x2f92c, &["\u{3eb8}"]),
+    (0x2f92d, &["\u{3eb8}"]),
+    (0x2f92e, &["\u{7447}"]),
+    (0x2f92f, &["\u{745c}"]),
+    (0x2f930, &["\u{7471}"]),
+    (0x2f931, &["\u{7485}"]),
+    (0x2f932, &["\u{74ca}"]),
+    (0x2f933, &["\u{3f1b}"]),
+    (0x2f934, &["\u{7524}"]),
+    (0x2f935, &["\u{24c36}"]),
+    (0x2f936, &["\u{753e}"]),
+    (0x2f937, &["\u{24c92}"]),
+    (0x2f938, &["\u{7570}"]),
+    (0x2f939, &["\u{2219f}"]),
+    (0x2f93a, &["\u{7610}"]),
+    (0x2f93b, &["\u{24fa1}"]),
+    (0x2f93c, &["\u{24fb8}"]),
+    (0x2f93d, &["\u{25044}"]),
+    (0x2f93e, &["\u{3ffc}"]),
+    (0x2f93f, &["\u{4008}"]),
+    (0x2f940, &["\u{76f4}"]),
+    (0x2f941, &["\u{250f3}"]),
+    (0x2f942, &["\u{250f2}"]),
+    (0x2f943, &["\u{25119}"]),
+    (0x2f944, &["\u{25133}"]),
+    (0x2f945, &["\u{771e}"]),
+    (0x2f946, &["\u{771f}"]),
+    (0x2f947, &["\u{771f}"]),
+    (0x2f948, &["\u{774a}"]),
+    (0x2f949, &["\u{4039}"]),
+    (0x2f94a, &["\u{778b}"]),
+    (0x2f94b, &["\u{4046}"]),
+    (0x2f94c, &["\u{4096}"]),
+    (0x2f94d, &["\u{2541d}"]),
+    (0x2f94e, &["\u{784e}"]),
+    (0x2f94f, &["\u{788c}"]),
+    (0x2f950, &["\u{78cc}"]),
+    (0x2f951, &["\u{40e3}"]),
+    (0x2f952, &["\u{25626}"]),
+    (0x2f953, &["\u{7956}"]),
+    (0x2f954, &["\u{2569a}"]),
+    (0x2f955, &["\u{256c5}"]),
+    (0x2f956, &["\u{798f}"]),
+    (0x2f957, &["\u{79eb}"]),
+    (0x2f958, &["\u{412f}"]),
+    (0x2f959, &["\u{7a40}"]),
+    (0x2f95a, &["\u{7a4a}"]),
+    (0x2f95b, &["\u{7a4f}"]),
+    (0x2f95c, &["\u{2597c}"]),
+    (0x2f95d, &["\u{25aa7}"]),
+    (0x2f95e, &["\u{25aa7}"]),
+    (0x2f95f, &["\u{7aee}"]),
+    (0x2f960, &["\u{4202}"]),
+    (0x2f961, &["\u{25bab}"]),
+    (0x2f962, &["\u{7bc6}"]),
+    (0x2f963, &["\u{7bc9}"]),
+    (0x2f964, &["\u{4227}"]),
+    (0x2f965, &["\u{25c80}"]),
+    (0x2f966, &["\u{7cd2}"]),
+    (0x2f967, &["\u{42a0}"]),
+    (0x2f968, &["\u{7ce8}"]),
+    (0x2f969, &["\u{7ce3}"]),
+    (0x2f96a, &["\u{7d00}"]),
+    (0x2f96b, &["\u{25f86}"]),
+    (0x2f96c, &["\u{7d63}"]),
+    (0x2f96d, &["\u{4301}"]),
+    (0x2f96e, &["\u{7dc7}"]),
+    (0x2f96f, &["\u{7e02}"]),
+    (0x2f970, &["\u{7e45}"]),
+    (0x2f971, &["\u{4334}"]),
+    (0x2f972, &["\u{26228}"]),
+    (0x2f973, &["\u{26247}"]),
+    (0x2f974, &["\u{4359}"]),
+    (0x2f975, &["\u{262d9}"]),
+    (0x2f976, &["\u{7f7a}"]),
+    (0x2f977, &["\u{2633e}"]),
+    (0x2f978, &["\u{7f95}"]),
+    (0x2f979, &["\u{7ffa}"]),
+    (0x2f97a, &["\u{8005}"]),
+    (0x2f97b, &["\u{264da}"]),
+    (0x2f97c, &["\u{26523}"]),
+    (0x2f97d, &["\u{8060}"]),
+    (0x2f97e, &["\u{265a8}"]),
+    (0x2f97f, &["\u{8070}"]),
+    (0x2f980, &["\u{2335f}"]),
+    (0x2f981, &["\u{43d5}"]),
+    (0x2f982, &["\u{80b2}"]),
+    (0x2f983, &["\u{8103}"]),
+    (0x2f984, &["\u{440b}"]),
+    (0x2f985, &["\u{813e}"]),
+    (0x2f986, &["\u{5ab5}"]),
+    (0x2f987, &["\u{267a7}"]),
+    (0x2f988, &["\u{267b5}"]),
+    (0x2f989, &["\u{23393}"]),
+    (0x2f98a, &["\u{2339c}"]),
+    (0x2f98b, &["\u{8201}"]),
+    (0x2f98c, &["\u{8204}"]),
+    (0x2f98d, &["\u{8f9e}"]),
+    (0x2f98e, &["\u{446b}"]),
+    (0x2f98f, &["\u{8291}"]),
+    (0x2f990, &["\u{828b}"]),
+    (0x2f991, &["\u{829d}"]),
+    (0x2f992, &["\u{52b3}"]),
+    (0x2f993, &["\u{82b1}"]),
+    (0x2f994, &["\u{82b3}"]),
+    (0x2f995, &["\u{82bd}"]),
+    (0x2f996, &["\u{82e6}"]),
+    (0x2f997, &["\u{26b3c}"]),
+    (0x2f998, &["\u{82e5}"]),
+    (0x2f999, &["\u{831d}"]),
+    (0x2f99a, &["\u{8363}"]),
+    (0x2f99b, &["\u{83ad}"]),
+    (0x2f99c, &["\u{8323}"]),
+    (0x2f99d, &["\u{83bd}"]),
+    (0x2f99e, &["\u{83e7}"]),
+    (0x2f99f, &["\u{8457}"]),
+    (0x2f9a0, &["\u{8353}"]),
+    (0x2f9a1, &["\u{83ca}"]),
+    (0x2f9a2, &["\u{83cc}"]),
+    (0x2f9a3, &["\u{83dc}"]),
+    (0x2f9a4, &["\u{26c36}"]),
+    (0x2f9a5, &["\u{26d6b}"]),
+    (0x2f9a6, &["\u{26cd5}"]),
+    (0x2f9a7, &["\u{452b}"]),
+    (0x2f9a8, &["\u{84f1}"]),
+    (0x2f9a9, &["\u{84f3}"]),
+    (0x2f9aa, &["\u{8516}"]),
+    (0x2f9ab, &["\u{273ca}"]),
+    (0x2f9ac, &["\u{8564}"]),
+    (0x2f9ad, &["\u{26f2c}"]),
+    (0x2f9ae, &["\u{455d}"]),
+    (0x2f9af, &["\u{4561}"]),
+    (0x2f9b0, &["\u{26fb1}"]),
+    (0x2f9b1, &["\u{270d2}"]),
+    (0x2f9b2, &["\u{456b}"]),
+    (0x2f9b3, &["\u{8650}"]),
+    (0x2f9b4, &["\u{865c}"]),
+    (0x2f9b5, &["\u{8667}"]),
+    (0x2f9b6, &["\u{8669}"]),
+    (0x2f9b7, &["\u{86a9}"]),
+    (0x2f9b8, &["\u{8688}"]),
+    (0x2f9b9, &["\u{870e}"]),
+    (0x2f9ba, &["\u{86e2}"]),
+    (0x2f9bb, &["\u{8779}"]),
+    (0x2f9bc, &["\u{8728}"]),
+    (0x2f9bd, &["\u{876b}"]),
+    (0x2f9be, &["\u{8786}"]),
+    (0x2f9bf, &["\u{45d7}"]),
+    (0x2f9c0, &["\u{87e1}"]),
+    (0x2f9c1, &["\u{8801}"]),
+    (0x2f9c2, &["\u{45f9}"]),
+    (0x2f9c3, &["\u{8860}"]),
+    (0x2f9c4, &["\u{8863}"]),
+    (0x2f9c5, &["\u{27667}"]),
+    (0x2f9c6, &["\u{88d7}"]),
+    (0x2f9c7, &["\u{88de}"]),
+    (0x2f9c8, &["\u{4635}"]),
+    (0x2f9c9, &["\u{88fa}"]),
+    (0x2f9ca, &["\u{34bb}"]),
+    (0x2f9cb, &["\u{278ae}"]),
+    (0x2f9cc, &["\u{27966}"]),
+    (0x2f9cd, &["\u{46be}"]),
+    (0x2f9ce, &["\u{46c7}"]),
+    (0x2f9cf, &["\u{8aa0}"]),
+    (0x2f9d0, &["\u{8aed}"]),
+    (0x2f9d1, &["\u{8b8a}"]),
+    (0x2f9d2, &["\u{8c55}"]),
+    (0x2f9d3, &["\u{27ca8}"]),
+    (0x2f9d4, &["\u{8cab}"]),
+    (0x2f9d5, &["\u{8cc1}"]),
+    (0x2f9d6, &["\u{8d1b}"]),
+    (0x2f9d7, &["\u{8d77}"]),
+    (0x2f9d8, &["\u{27f2f}"]),
+    (0x2f9d9, &["\u{20804}"]),
+    (0x2f9da, &["\u{8dcb}"]),
+    (0x2f9db, &["\u{8dbc}"]),
+    (0x2f9dc, &["\u{8df0}"]),
+    (0x2f9dd, &["\u{208de}"]),
+    (0x2f9de, &["\u{8ed4}"]),
+    (0x2f9df, &["\u{8f38}"]),
+    (0x2f9e0, &["\u{285d2}"]),
+    (0x2f9e1, &["\u{285ed}"]),
+    (0x2f9e2, &["\u{9094}"]),
+    (0x2f9e3, &["\u{90f1}"]),
+    (0x2f9e4, &["\u{9111}"]),
+    (0x2f9e5, &["\u{2872e}"]),
+    (0x2f9e6, &["\u{911b}"]),
+    (0x2f9e7, &["\u{9238}"]),
+    (0x2f9e8, &["\u{92d7}"]),
+    (0x2f9e9, &["\u{92d8}"]),
+    (0x2f9ea, &["\u{927c}"]),
+    (0x2f9eb, &["\u{93f9}"]),
+    (0x2f9ec, &["\u{9415}"]),
+    (0x2f9ed, &["\u{28bfa}"]),
+    (0x2f9ee, &["\u{958b}"]),
+    (0x2f9ef, &["\u{4995}"]),
+    (0x2f9f0, &["\u{95b7}"]),
+    (0x2f9f1, &["\u{28d77}"]),
+    (0x2f9f2, &["\u{49e6}"]),
+    (0x2f9f3, &["\u{96c3}"]),
+    (0x2f9f4, &["\u{5db2}"]),
+    (0x2f9f5, &["\u{9723}"]),
+    (0x2f9f6, &["\u{29145}"]),
+    (0x2f9f7, &["\u{2921a}"]),
+    (0x2f9f8, &["\u{4a6e}"]),
+    (0x2f9f9, &["\u{4a76}"]),
+    (0x2f9fa, &["\u{97e0}"]),
+    (0x2f9fb, &["\u{2940a}"]),
+    (0x2f9fc, &["\u{4ab2}"]),
+    (0x2f9fd, &["\u{29496}"]),
+    (0x2f9fe, &["\u{980b}"]),
+    (0x2f9ff, &["\u{980b}"]),
+    (0x2fa00, &["\u{9829}"]),
+    (0x2fa01, &["\u{295b6}"]),
+    (0x2fa02, &["\u{98e2}"]),
+    (0x2fa03, &["\u{4b33}"]),
+    (0x2fa04, &["\u{9929}"]),
+    (0x2fa05, &["\u{99a7}"]),
+    (0x2fa06, &["\u{99c2}"]),
+    (0x2fa07, &["\u{99fe}"]),
+    (0x2fa08, &["\u{4bce}"]),
+    (0x2fa09, &["\u{29b30}"]),
+    (0x2fa0a, &["\u{9b12}"]),
+    (0x2fa0b, &["\u{9c40}"]),
+    (0x2fa0c, &["\u{9cfd}"]),
+    (0x2fa0d, &["\u{4cce}"]),
+    (0x2fa0e, &["\u{4ced}"]),
+    (0x2fa0f, &["\u{9d67}"]),
+    (0x2fa10, &["\u{2a0ce}"]),
+    (0x2fa11, &["\u{4cf8}"]),
+    (0x2fa12, &["\u{2a105}"]),
+    (0x2fa13, &["\u{2a20e}"]),
+    (0x2fa14, &["\u{2a291}"]),
+    (0x2fa15, &["\u{9ebb}"]),
+    (0x2fa16, &["\u{4d56}"]),
+    (0x2fa17, &["\u{9ef9}"]),
+    (0x2fa18, &["\u{9efe}"]),
+    (0x2fa19, &["\u{9f05}"]),
+    (0x2fa1a, &["\u{9f0f}"]),
+    (0x2fa1b, &["\u{9f16}"]),
+    (0x2fa1c, &["\u{9f3b}"]),
+    (0x2fa1d, &["\u{2a600}"]),
+];
@@ -0,0 +1,157 @@
+//! Message catalogs in the format GNU msgfmt writes, which are .mo files.
+//!
+//! After the magic number and a revision come the number of messages and the offsets of two
+//! tables, the first of the messages and the second of their translations. Each entry of a
+//! table is the length and offset of a string, which is followed by a null byte in the file.
+//! Messages are sorted, so they can be binary searched; the hash table that can follow is not
+//! needed for that. A message with a plural has it after the singular, separated by a null byte,
+//! and so do the plural forms of its translation.
+
+use core::{cmp::Ordering, str};
+
+use crate::{
+    c_str::CStr,
+    fs::{File, Mapping},
+    header::fcntl::{O_CLOEXEC, O_RDONLY},
+    platform::types::*,
+};
+
+use super::plural::Expr;
+
+const MAGIC: u32 = 0x950412de;
+
+pub struct MoFile {
+    data: Mapping,
+    /// Whether the catalog was written in the other byte order
+    swapped: bool,
+    count: usize,
+    messages: usize,
+    translations: usize,
+    /// The number of plural forms, and which one a number takes, from the Plural-Forms header
+    nplurals: c_ulong,
+    plural: Expr,
+}
+
+impl MoFile {
+    pub fn open(path: &CStr) -> Option<MoFile> {
+        let data = File::open(path, O_RDONLY | O_CLOEXEC).ok()?.map().ok()?;
+
+        let mut file = MoFile {
+            data,
+            swapped: false,
+            count: 0,
+            messages: 0,
+            translations: 0,
+            nplurals: 2,
+            plural: Expr::default(),
+        };
+        match file.word(0)? {
+            MAGIC => (),
+            magic if magic.swap_bytes() == MAGIC => file.swapped = true,
+            _ => return None,
+        }
+        // Only the major revision, in the upper half, changes the format
+        if file.word(4)? >> 16 != 0 {
+            return None;
+        }
+        file.count = file.word(8)? as usize;
+        file.messages = file.word(12)? as usize;
+        file.translations = file.word(16)? as usize;
+
+        // The header is the translation of the empty message
+        if let Some(header) = file.find(b"") {
+            if let Some((nplurals, plural)) = plural_forms(header) {
+                file.nplurals = nplurals;
+                file.plural = plural;
+            }
+        }
+        Some(file)
+    }
+
+    /// The 32-bit word at `offset`
+    fn word(&self, offset: usize) -> Option<u32> {
+        let bytes = self.data.get(offset..offset.checked_add(4)?)?;
+        let word = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        Some(if self.swapped {
+            word.swap_bytes()
+        } else {
+            word
+        })
+    }
+
+    /// The `i`th string of the table at `table`, which has to be followed by a null byte
+    fn string(&self, table: usize, i: usize) -> Option<&[u8]> {
+        let entry = table.checked_add(i.checked_mul(8)?)?;
+        let len = self.word(entry)? as usize;
+        let offset = self.word(entry + 4)? as usize;
+        let string = self
+            .data
+            .get(offset..offset.checked_add(len)?.checked_add(1)?)?;
+        match string.split_last() {
+            Some((0, string)) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// The translation of `msgid`, with all its plural forms
+    pub fn find(&self, msgid: &[u8]) -> Option<&[u8]> {
+        let (mut low, mut high) = (0, self.count);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let message = self.string(self.messages, middle)?;
+            // Leave out the plural
+            let singular = message.split(|&b| b == 0).next().unwrap_or(message);
+            match singular.cmp(msgid) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return self.string(self.translations, middle),
+            }
+        }
+        None
+    }
+
+    /// Which plural form `n` takes. Like in glibc, expressions that give a form the catalog
+    /// doesn't have give the first one instead.
+    pub fn plural(&self, n: c_ulong) -> usize {
+        match self.plural.eval(n) {
+            form if form < self.nplurals => form as usize,
+            _ => 0,
+        }
+    }
+}
+
+/// Reads "nplurals=2; plural=n != 1;" from the Plural-Forms line of a header
+fn plural_forms(header: &[u8]) -> Option<(c_ulong, Expr)> {
+    const FIELD: &[u8] = b"Plural-Forms:";
+    let line = header
+        .split(|&b| b == b'\n')
+        .find(|line| line.starts_with(FIELD))?;
+
+    let mut nplurals = None;
+    let mut plural = None;
+    for part in line[FIELD.len()..].split(|&b| b == b';') {
+        let eq = match part.iter().position(|&b| b == b'=') {
+            Some(eq) => eq,
+            None => continue,
+        };
+        let value = &part[eq + 1..];
+        match trim(&part[..eq]) {
+            b"nplurals" => nplurals = str::from_utf8(trim(value)).ok()?.parse().ok(),
+            b"plural" => plural = Some(Expr::parse(value)?),
+            _ => (),
+        }
+    }
+    match (nplurals, plural) {
+        (Some(nplurals), Some(plural)) if nplurals > 0 => Some((nplurals, plural)),
+        _ => None,
+    }
+}
+
+fn trim(s: &[u8]) -> &[u8] {
+    let start = s.iter().position(|b| !b.is_ascii_whitespace());
+    let end = s.iter().rposition(|b| !b.is_ascii_whitespace());
+    match (start, end) {
+        (Some(start), Some(end)) => &s[start..=end],
+        _ => &[],
+    }
+}
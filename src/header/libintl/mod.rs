@@ -0,0 +1,306 @@
+//! libintl.h implementation for Redox, compatible with the gettext functions of GNU libintl and
+//! reading the .mo catalogs written by msgfmt
+
+use alloc::vec::Vec;
+use core::ptr;
+
+use crate::{
+    c_str::{CStr, CString},
+    header::{
+        errno::EINVAL,
+        locale::{self, LC_MESSAGES},
+        stdlib::getenv,
+    },
+    platform::{self, types::*},
+    sync::Mutex,
+};
+
+use self::mo::MoFile;
+
+mod mo;
+mod plural;
+
+/// Where catalogs are looked for in domains that bindtextdomain() wasn't called for
+fn default_dirname() -> &'static CStr {
+    c_str!("/usr/share/locale")
+}
+
+struct Binding {
+    domain: CString,
+    dirname: Option<CString>,
+    codeset: Option<CString>,
+}
+
+/// The domain set by textdomain(), or None for "messages"
+static DOMAIN: Mutex<Option<CString>> = Mutex::new(None);
+static BINDINGS: Mutex<Vec<Binding>> = Mutex::new(Vec::new());
+/// Every catalog that was looked for, by its path, and whether it was found. Translations are
+/// handed out as pointers into the catalogs, so they are never closed.
+static CATALOGS: Mutex<Vec<(Vec<u8>, Option<MoFile>)>> = Mutex::new(Vec::new());
+
+/// The directory that catalogs of `domain` are in
+fn dirname(domain: &[u8]) -> Vec<u8> {
+    let bindings = BINDINGS.lock();
+    bindings
+        .iter()
+        .find(|binding| binding.domain.to_bytes() == domain)
+        .and_then(|binding| binding.dirname.as_ref())
+        .map_or(default_dirname(), |dirname| dirname.as_c_str())
+        .to_bytes()
+        .to_vec()
+}
+
+/// The names a catalog for `locale`, which has the form `language[_territory][.codeset]
+/// [@modifier]`, can be under, from the most specific to the least
+fn variants(locale: &[u8]) -> Vec<Vec<u8>> {
+    let (rest, modifier) = match locale.iter().position(|&b| b == b'@') {
+        Some(i) => (&locale[..i], &locale[i..]),
+        None => (locale, &[][..]),
+    };
+    let (rest, codeset) = match rest.iter().position(|&b| b == b'.') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, &[][..]),
+    };
+    let (language, territory) = match rest.iter().position(|&b| b == b'_') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, &[][..]),
+    };
+
+    let mut variants: Vec<Vec<u8>> = Vec::new();
+    for mask in (0..8).rev() {
+        let mut variant = language.to_vec();
+        for (bit, part) in [(2, territory), (1, codeset), (4, modifier)].iter() {
+            if mask & bit != 0 {
+                variant.extend_from_slice(part);
+            }
+        }
+        if !variants.contains(&variant) {
+            variants.push(variant);
+        }
+    }
+    variants
+}
+
+/// Looks `msgid` up in the catalog at `path`, returning the plural form of its translation for
+/// `n`, or the singular one for messages without a plural
+fn find(path: Vec<u8>, msgid: &[u8], n: Option<c_ulong>) -> Option<*const c_char> {
+    let mut catalogs = CATALOGS.lock();
+    let i = match catalogs.iter().position(|(other, _)| *other == path) {
+        Some(i) => i,
+        None => {
+            let file = CString::new(path.clone())
+                .ok()
+                .and_then(|path| MoFile::open(&path));
+            catalogs.push((path, file));
+            catalogs.len() - 1
+        }
+    };
+    let file = catalogs[i].1.as_ref()?;
+    let translation = file.find(msgid)?;
+    // Each plural form is followed by a null byte
+    let form = n.map_or(0, |n| file.plural(n));
+    let form = translation.split(|&b| b == 0).nth(form)?;
+    Some(form.as_ptr().cast())
+}
+
+unsafe fn translate(
+    domainname: *const c_char,
+    msgid1: *const c_char,
+    msgid2: *const c_char,
+    n: c_ulong,
+    category: c_int,
+) -> *mut c_char {
+    // The untranslated message, which is the plural one if there is one and `n` isn't 1
+    let fallback = if msgid2.is_null() || n == 1 {
+        msgid1
+    } else {
+        msgid2
+    } as *mut c_char;
+    if msgid1.is_null() {
+        return fallback;
+    }
+    let category_name = match locale::category_name(category) {
+        Some(name) => name,
+        None => return fallback,
+    };
+    let locale = locale::current().name(category).to_bytes();
+    if locale == b"C" || locale == b"POSIX" {
+        return fallback;
+    }
+
+    let domain = if domainname.is_null() {
+        match &*DOMAIN.lock() {
+            Some(domain) => domain.to_bytes().to_vec(),
+            None => b"messages".to_vec(),
+        }
+    } else {
+        CStr::from_ptr(domainname).to_bytes().to_vec()
+    };
+    let dirname = dirname(&domain);
+    let msgid = CStr::from_ptr(msgid1).to_bytes();
+    let n = if msgid2.is_null() { None } else { Some(n) };
+
+    // Like in GNU gettext, LANGUAGE can list languages to try in place of the locale
+    let language = getenv(c_str!("LANGUAGE").as_ptr());
+    let languages = if language.is_null() || *language == 0 {
+        locale
+    } else {
+        CStr::from_ptr(language).to_bytes()
+    };
+
+    let errno = platform::errno;
+    let mut translation = None;
+    for language in languages.split(|&b| b == b':') {
+        if language.is_empty() {
+            continue;
+        }
+        if language == b"C" || language == b"POSIX" {
+            break;
+        }
+        translation = variants(language).into_iter().find_map(|variant| {
+            let mut path = dirname.clone();
+            for part in [&b"/"[..], &variant, b"/", category_name.as_bytes(), b"/"].iter() {
+                path.extend_from_slice(part);
+            }
+            path.extend_from_slice(&domain);
+            path.extend_from_slice(b".mo");
+            find(path, msgid, n)
+        });
+        if translation.is_some() {
+            break;
+        }
+    }
+    platform::errno = errno;
+
+    match translation {
+        Some(translation) => translation as *mut c_char,
+        None => fallback,
+    }
+}
+
+/// Translations are returned as they are in the catalogs, so the codeset is only recorded
+#[no_mangle]
+pub unsafe extern "C" fn bind_textdomain_codeset(
+    domainname: *const c_char,
+    codeset: *const c_char,
+) -> *mut c_char {
+    bind(domainname, codeset, |binding| &mut binding.codeset)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bindtextdomain(
+    domainname: *const c_char,
+    dirname: *const c_char,
+) -> *mut c_char {
+    let dirname = bind(domainname, dirname, |binding| &mut binding.dirname);
+    if dirname.is_null() && !domainname.is_null() && *domainname != 0 {
+        default_dirname().as_ptr() as *mut c_char
+    } else {
+        dirname
+    }
+}
+
+/// Sets the setting of `domainname` that `field` picks to `value`, unless that is NULL, and
+/// returns it
+unsafe fn bind(
+    domainname: *const c_char,
+    value: *const c_char,
+    field: impl Fn(&mut Binding) -> &mut Option<CString>,
+) -> *mut c_char {
+    if domainname.is_null() || *domainname == 0 {
+        platform::errno = EINVAL;
+        return ptr::null_mut();
+    }
+    let domain = CStr::from_ptr(domainname);
+
+    let mut bindings = BINDINGS.lock();
+    let i = match bindings
+        .iter()
+        .position(|binding| binding.domain.as_c_str() == domain)
+    {
+        Some(i) => i,
+        None => {
+            bindings.push(Binding {
+                domain: domain.into(),
+                dirname: None,
+                codeset: None,
+            });
+            bindings.len() - 1
+        }
+    };
+    let setting = field(&mut bindings[i]);
+    if !value.is_null() {
+        *setting = Some(CStr::from_ptr(value).into());
+    }
+    match setting {
+        Some(value) => value.as_ptr() as *mut c_char,
+        None => ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dcgettext(
+    domainname: *const c_char,
+    msgid: *const c_char,
+    category: c_int,
+) -> *mut c_char {
+    translate(domainname, msgid, ptr::null(), 1, category)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dcngettext(
+    domainname: *const c_char,
+    msgid1: *const c_char,
+    msgid2: *const c_char,
+    n: c_ulong,
+    category: c_int,
+) -> *mut c_char {
+    translate(domainname, msgid1, msgid2, n, category)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dgettext(domainname: *const c_char, msgid: *const c_char) -> *mut c_char {
+    translate(domainname, msgid, ptr::null(), 1, LC_MESSAGES)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dngettext(
+    domainname: *const c_char,
+    msgid1: *const c_char,
+    msgid2: *const c_char,
+    n: c_ulong,
+) -> *mut c_char {
+    translate(domainname, msgid1, msgid2, n, LC_MESSAGES)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gettext(msgid: *const c_char) -> *mut c_char {
+    translate(ptr::null(), msgid, ptr::null(), 1, LC_MESSAGES)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ngettext(
+    msgid1: *const c_char,
+    msgid2: *const c_char,
+    n: c_ulong,
+) -> *mut c_char {
+    translate(ptr::null(), msgid1, msgid2, n, LC_MESSAGES)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn textdomain(domainname: *const c_char) -> *mut c_char {
+    let mut domain = DOMAIN.lock();
+    if !domainname.is_null() {
+        let name = CStr::from_ptr(domainname);
+        // The empty name goes back to the default domain
+        *domain = if name.to_bytes().is_empty() {
+            None
+        } else {
+            Some(name.into())
+        };
+    }
+    match &*domain {
+        Some(domain) => domain.as_ptr() as *mut c_char,
+        None => c_str!("messages").as_ptr() as *mut c_char,
+    }
+}
@@ -0,0 +1,197 @@
+//! The expressions in the Plural-Forms header of a catalog, which pick a plural form for a
+//! number `n` with a subset of C: the operators `?:`, `||`, `&&`, `==`, `!=`, `<`, `>`, `<=`,
+//! `>=`, `+`, `-`, `*`, `/`, `%` and `!`, parentheses and decimal numbers.
+
+use alloc::boxed::Box;
+
+use crate::platform::types::*;
+
+#[derive(Clone, Copy)]
+pub enum Op {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+/// The binary operators from the lowest precedence to the highest. Where one operator starts
+/// with another, the longer one comes first.
+const OPERATORS: [&[(&str, Op)]; 6] = [
+    &[("||", Op::Or)],
+    &[("&&", Op::And)],
+    &[("==", Op::Eq), ("!=", Op::Ne)],
+    &[("<=", Op::Le), (">=", Op::Ge), ("<", Op::Lt), (">", Op::Gt)],
+    &[("+", Op::Add), ("-", Op::Sub)],
+    &[("*", Op::Mul), ("/", Op::Div), ("%", Op::Rem)],
+];
+
+pub enum Expr {
+    N,
+    Number(c_ulong),
+    Not(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+    Conditional(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+/// The expression of catalogs without one, which is right for English and many other languages
+impl Default for Expr {
+    fn default() -> Expr {
+        Expr::Binary(Op::Ne, Box::new(Expr::N), Box::new(Expr::Number(1)))
+    }
+}
+
+impl Expr {
+    /// Parses an expression that takes up all of `s`, apart from white space
+    pub fn parse(s: &[u8]) -> Option<Expr> {
+        let mut parser = Parser { s, pos: 0 };
+        let expr = parser.conditional()?;
+        if parser.peek().is_some() {
+            return None;
+        }
+        Some(expr)
+    }
+
+    /// Evaluates the expression with C's unsigned arithmetic, except that dividing by zero
+    /// gives zero
+    pub fn eval(&self, n: c_ulong) -> c_ulong {
+        match self {
+            Expr::N => n,
+            Expr::Number(number) => *number,
+            Expr::Not(operand) => (operand.eval(n) == 0) as c_ulong,
+            Expr::Binary(op, left, right) => {
+                let left = left.eval(n);
+                // || and && don't look at the right side if the left one decides
+                match op {
+                    Op::Or if left != 0 => return 1,
+                    Op::And if left == 0 => return 0,
+                    _ => (),
+                }
+                let right = right.eval(n);
+                match op {
+                    Op::Or | Op::And => (right != 0) as c_ulong,
+                    Op::Eq => (left == right) as c_ulong,
+                    Op::Ne => (left != right) as c_ulong,
+                    Op::Lt => (left < right) as c_ulong,
+                    Op::Gt => (left > right) as c_ulong,
+                    Op::Le => (left <= right) as c_ulong,
+                    Op::Ge => (left >= right) as c_ulong,
+                    Op::Add => left.wrapping_add(right),
+                    Op::Sub => left.wrapping_sub(right),
+                    Op::Mul => left.wrapping_mul(right),
+                    Op::Div => left.checked_div(right).unwrap_or(0),
+                    Op::Rem => left.checked_rem(right).unwrap_or(0),
+                }
+            }
+            Expr::Conditional(condition, then, otherwise) => {
+                if condition.eval(n) != 0 {
+                    then.eval(n)
+                } else {
+                    otherwise.eval(n)
+                }
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// The next byte that isn't white space
+    fn peek(&mut self) -> Option<u8> {
+        while self.s.get(self.pos)?.is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        self.s.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.peek();
+        if self.s[self.pos..].starts_with(token.as_bytes()) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// `a ? b : c`, which groups from the right
+    fn conditional(&mut self) -> Option<Expr> {
+        let condition = self.binary(0)?;
+        if !self.eat("?") {
+            return Some(condition);
+        }
+        let then = self.conditional()?;
+        if !self.eat(":") {
+            return None;
+        }
+        let otherwise = self.conditional()?;
+        Some(Expr::Conditional(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    /// Binary operators of the precedence `level` and higher, which group from the left
+    fn binary(&mut self, level: usize) -> Option<Expr> {
+        if level == OPERATORS.len() {
+            return self.unary();
+        }
+        let mut left = self.binary(level + 1)?;
+        'operators: loop {
+            for &(token, op) in OPERATORS[level] {
+                if self.eat(token) {
+                    let right = self.binary(level + 1)?;
+                    left = Expr::Binary(op, Box::new(left), Box::new(right));
+                    continue 'operators;
+                }
+            }
+            return Some(left);
+        }
+    }
+
+    fn unary(&mut self) -> Option<Expr> {
+        match self.peek()? {
+            // Not to be confused with !=, which can't start an operand
+            b'!' => {
+                self.pos += 1;
+                Some(Expr::Not(Box::new(self.unary()?)))
+            }
+            b'(' => {
+                self.pos += 1;
+                let expr = self.conditional()?;
+                if !self.eat(")") {
+                    return None;
+                }
+                Some(expr)
+            }
+            b'n' => {
+                self.pos += 1;
+                Some(Expr::N)
+            }
+            b'0'..=b'9' => {
+                let mut number: c_ulong = 0;
+                while let Some(digit @ b'0'..=b'9') = self.s.get(self.pos).copied() {
+                    number = number
+                        .wrapping_mul(10)
+                        .wrapping_add((digit - b'0') as c_ulong);
+                    self.pos += 1;
+                }
+                Some(Expr::Number(number))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,432 @@
+//! Collation with the Unicode Collation Algorithm, following https://www.unicode.org/reports/tr10/
+//!
+//! Strings are compared by the weights the Default Unicode Collation Element Table gives their
+//! characters, on four levels: the base letters, then accents, then case, and last the
+//! punctuation and symbols that the first three levels pass over ("shifted" variable weighting).
+//! The text is not normalized first. The table has entries for precomposed characters as well,
+//! so this only makes a difference for combining marks in a non-canonical order.
+
+use alloc::vec::Vec;
+use core::{cmp::Ordering, str};
+
+use crate::{header::wchar, platform::types::*};
+
+use super::{data::Codeset, ducet};
+
+/// The top bit of a packed entry, which means it refers to several elements in `EXPANSIONS`
+const EXPANSION: u32 = 1 << 31;
+
+/// Primary weights are shifted left this far, so that a tailoring can put new ones between
+/// those of the DUCET
+const SUBWEIGHT_BITS: u32 = 8;
+
+/// The fourth-level weight of everything that is not punctuation or a symbol
+const LAST_QUATERNARY: u32 = 0xffff << SUBWEIGHT_BITS;
+
+const HANGUL_FIRST: u32 = 0xac00;
+const HANGUL_LAST: u32 = 0xd7a3;
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Element {
+    pub primary: u32,
+    pub secondary: u16,
+    pub tertiary: u8,
+    /// Whether the element is for punctuation or a symbol, and only counts on the fourth level
+    pub variable: bool,
+}
+
+impl Element {
+    fn unpack(packed: u32) -> Self {
+        Self {
+            primary: (packed >> 15) << SUBWEIGHT_BITS,
+            secondary: (packed >> 6 & 0x1ff) as u16,
+            tertiary: (packed >> 1 & 0x1f) as u8,
+            variable: packed & 1 == 1,
+        }
+    }
+
+    fn new(primary: u32, secondary: u16, tertiary: u8) -> Self {
+        Self {
+            primary,
+            secondary,
+            tertiary,
+            variable: false,
+        }
+    }
+}
+
+/// Characters that a locale collates differently from the DUCET, with their elements
+#[derive(Clone)]
+pub struct Tailored {
+    pub chars: Vec<u32>,
+    pub elements: Vec<Element>,
+}
+
+fn push_packed(packed: u32, out: &mut Vec<Element>) {
+    if packed & EXPANSION == 0 {
+        out.push(Element::unpack(packed));
+    } else {
+        let start = (packed >> 5 & 0xffff) as usize;
+        let len = (packed & 0x1f) as usize;
+        out.extend(
+            ducet::EXPANSIONS[start..start + len]
+                .iter()
+                .map(|&packed| Element::unpack(packed)),
+        );
+    }
+}
+
+/// Appends the elements of a single character
+fn push_char(c: u32, out: &mut Vec<Element>) {
+    if let Ok(i) = ducet::CHARS.binary_search(&c) {
+        push_packed(ducet::ELEMENTS[i], out);
+        return;
+    }
+
+    // Hangul syllables collate as the jamo they are made of
+    if (HANGUL_FIRST..=HANGUL_LAST).contains(&c) {
+        let s = c - HANGUL_FIRST;
+        push_char(0x1100 + s / 588, out);
+        push_char(0x1161 + s % 588 / 28, out);
+        if s % 28 != 0 {
+            push_char(0x11a7 + s % 28, out);
+        }
+        return;
+    }
+
+    // Everything else gets weights derived from its code point, which puts ideographs after
+    // the scripts in the table and unassigned characters after those
+    let range = ducet::IMPLICIT
+        .iter()
+        .find(|&&(first, last, _)| first <= c && c <= last);
+    let (first, second) = match range {
+        // Tangut, Nushu and Khitan number their characters from the start of the script
+        Some(&(_, _, base)) if base < 0xfb40 => {
+            let start = ducet::IMPLICIT
+                .iter()
+                .filter(|range| range.2 == base)
+                .map(|range| range.0)
+                .min()
+                .unwrap_or(c);
+            (base, c - start)
+        }
+        Some(&(_, _, base)) => (base + (c >> 15), c & 0x7fff),
+        None => (0xfbc0 + (c >> 15), c & 0x7fff),
+    };
+    out.push(Element::new(first << SUBWEIGHT_BITS, 0x20, 2));
+    out.push(Element::new((second | 0x8000) << SUBWEIGHT_BITS, 0, 0));
+}
+
+/// Appends the elements of the DUCET contraction at the start of `s`, returning its length
+fn push_contraction(s: &[u32], out: &mut Vec<Element>) -> Option<usize> {
+    for len in (2..=3).rev() {
+        if s.len() < len {
+            continue;
+        }
+        let mut key = [0; 3];
+        key[..len].copy_from_slice(&s[..len]);
+        if let Ok(i) = ducet::CONTRACTIONS.binary_search(&key) {
+            push_packed(ducet::CONTRACTION_ELEMENTS[i], out);
+            return Some(len);
+        }
+    }
+    None
+}
+
+/// The collation elements of `s`, taking the characters in `tailoring` from there rather than
+/// from the DUCET
+pub fn elements(s: &[u32], tailoring: &[Tailored]) -> Vec<Element> {
+    let mut out = Vec::with_capacity(s.len());
+    let mut i = 0;
+    while i < s.len() {
+        let rest = &s[i..];
+        let tailored = tailoring
+            .iter()
+            .filter(|t| rest.starts_with(&t.chars))
+            .max_by_key(|t| t.chars.len());
+        if let Some(tailored) = tailored {
+            out.extend_from_slice(&tailored.elements);
+            i += tailored.chars.len();
+        } else if let Some(len) = push_contraction(rest, &mut out) {
+            i += len;
+        } else {
+            push_char(rest[0], &mut out);
+            i += 1;
+        }
+    }
+    out
+}
+
+/// The sort key of `s`: the nonzero weights of each level in turn, with a 0 between levels.
+/// Keys compare in the same order as the strings they are made from.
+pub fn sort_key(s: &[u32], tailoring: &[Tailored]) -> Vec<u32> {
+    let elements = elements(s, tailoring);
+    let mut levels: [Vec<u32>; 4] = Default::default();
+    let mut after_variable = false;
+    for e in elements {
+        if e.variable {
+            levels[3].push(e.primary);
+            after_variable = true;
+            continue;
+        }
+        // Accents on punctuation are ignored along with the punctuation itself
+        if e.primary == 0 && (after_variable || (e.secondary == 0 && e.tertiary == 0)) {
+            continue;
+        }
+        if e.primary != 0 {
+            levels[0].push(e.primary);
+            after_variable = false;
+        }
+        if e.secondary != 0 {
+            levels[1].push(e.secondary as u32);
+        }
+        if e.tertiary != 0 {
+            levels[2].push(e.tertiary as u32);
+        }
+        levels[3].push(LAST_QUATERNARY);
+    }
+
+    let mut key = Vec::with_capacity(levels.iter().map(Vec::len).sum::<usize>() + 3);
+    for (i, level) in levels.iter().enumerate() {
+        if i > 0 {
+            key.push(0);
+        }
+        key.extend_from_slice(level);
+    }
+    key
+}
+
+pub fn compare(s1: &[u32], s2: &[u32], tailoring: &[Tailored]) -> Ordering {
+    sort_key(s1, tailoring).cmp(&sort_key(s2, tailoring))
+}
+
+/// How many 7-bit digits the weights of each level take in `key_bytes`
+const DIGITS: [u32; 4] = [4, 2, 1, 4];
+
+/// Writes a sort key as a string without nul bytes that strcmp() puts in the same order. The
+/// digits of each weight are offset by 2 so that the 1 between levels sorts before all of them.
+pub fn key_bytes(key: &[u32]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(key.len() * 4);
+    let mut level = 0;
+    for &weight in key {
+        if weight == 0 {
+            bytes.push(1);
+            level += 1;
+            continue;
+        }
+        for digit in (0..DIGITS[level]).rev() {
+            bytes.push((weight >> (7 * digit) & 0x7f) as u8 + 2);
+        }
+    }
+    bytes
+}
+
+/// Writes a sort key as a wide string without nul characters that wcscmp() puts in the same
+/// order
+pub fn key_wide(key: &[u32]) -> Vec<wchar_t> {
+    key.iter().map(|&weight| weight as wchar_t + 1).collect()
+}
+
+/// Decodes a multibyte string of the given character set. Bytes that are not part of a valid
+/// character are kept as code points that are not characters, like mbrtowc() does in the C
+/// locale, so they still sort in some consistent order.
+pub fn decode(s: &[u8], codeset: Codeset) -> Vec<u32> {
+    if codeset != Codeset::Utf8 {
+        return s
+            .iter()
+            .map(|&b| wchar::byte_to_wide(codeset, b) as u32)
+            .collect();
+    }
+
+    let mut chars = Vec::with_capacity(s.len());
+    let mut rest = s;
+    loop {
+        match str::from_utf8(rest) {
+            Ok(valid) => {
+                chars.extend(valid.chars().map(|c| c as u32));
+                return chars;
+            }
+            Err(err) => {
+                let (valid, invalid) = rest.split_at(err.valid_up_to());
+                let valid = unsafe { str::from_utf8_unchecked(valid) };
+                chars.extend(valid.chars().map(|c| c as u32));
+                let len = err.error_len().unwrap_or(invalid.len());
+                chars.extend(invalid[..len].iter().map(|&b| 0xdf00 + b as u32));
+                rest = &invalid[len..];
+            }
+        }
+    }
+}
+
+/// Collects the tailoring in the LC_COLLATE section of a locale source, in the form of the
+/// glibc sources in /usr/share/i18n/locales:
+///
+/// ```text
+/// collating-element <c-h> from "<U0063><U0068>"
+/// reorder-after <AFTER-H>
+/// <ch-digraph>
+/// <c-h> <ch-digraph>;<BASE>;<MIN>;IGNORE
+/// reorder-end
+/// ```
+///
+/// Only the first-level weights of a line are used, the others are taken from the DUCET
+/// elements of its characters. A `reorder-after` can follow a character, a collating element
+/// or symbol placed before, or `<AFTER-X>` for the letters of the Latin alphabet. Sections that
+/// follow anything else are skipped.
+#[derive(Default)]
+pub struct Tailoring {
+    /// Names of collating elements with the characters they stand for
+    collating_elements: Vec<(Vec<u8>, Vec<u32>)>,
+    /// Names put right after a primary weight of the DUCET by a reorder section, in order
+    inserted: Vec<(u32, Vec<Vec<u8>>)>,
+    /// Where the next line of a reorder section goes: the list in `inserted` and the position
+    cursor: Option<(usize, usize)>,
+    /// Lines that give something new weights, with the names of their primary weights, or
+    /// none for IGNORE
+    lines: Vec<(Vec<u8>, Vec<Vec<u8>>)>,
+}
+
+impl Tailoring {
+    pub fn collating_element(&mut self, name: &[u8], chars: Vec<u32>) {
+        if !chars.is_empty() {
+            self.collating_elements.push((name.to_vec(), chars));
+        }
+    }
+
+    pub fn reorder_after(&mut self, anchor: &[u8]) {
+        if let Some((list, i)) = self.find_inserted(anchor) {
+            self.cursor = Some((list, i + 1));
+            return;
+        }
+
+        let base = match anchor {
+            [b'A', b'F', b'T', b'E', b'R', b'-', letter] if letter.is_ascii_alphabetic() => {
+                self.ducet_primary(&[letter.to_ascii_lowercase() as u32])
+            }
+            _ => self.chars(anchor).and_then(|c| self.ducet_primary(&c)),
+        };
+        self.cursor = base.map(
+            |base| match self.inserted.iter().position(|l| l.0 == base) {
+                Some(list) => (list, 0),
+                None => {
+                    self.inserted.push((base, Vec::new()));
+                    (self.inserted.len() - 1, 0)
+                }
+            },
+        );
+    }
+
+    pub fn reorder_end(&mut self) {
+        self.cursor = None;
+    }
+
+    /// A line naming a character, collating element or symbol, with the names of its primary
+    /// weights if it gives any. Inside a reorder section, the line also puts what it names in
+    /// the next place.
+    pub fn line(&mut self, name: &[u8], primaries: Option<Vec<Vec<u8>>>) {
+        if let Some((list, mut i)) = self.cursor {
+            if let Some((old_list, old)) = self.find_inserted(name) {
+                self.inserted[old_list].1.remove(old);
+                if old_list == list && old < i {
+                    i -= 1;
+                }
+            }
+            self.inserted[list].1.insert(i, name.to_vec());
+            self.cursor = Some((list, i + 1));
+        }
+
+        if self.chars(name).is_some() {
+            match primaries {
+                Some(primaries) => self.lines.push((name.to_vec(), primaries)),
+                // A character that is only moved gets a primary weight of its own
+                None if self.cursor.is_some() => {
+                    self.lines.push((name.to_vec(), Vec::from([name.to_vec()])))
+                }
+                None => (),
+            }
+        }
+    }
+
+    /// Works out the elements of everything that got new weights
+    pub fn finish(self) -> Vec<Tailored> {
+        let mut tailored: Vec<Tailored> = Vec::with_capacity(self.lines.len());
+        for (name, primaries) in &self.lines {
+            let chars = match self.chars(name) {
+                Some(chars) => chars,
+                None => continue,
+            };
+            let primaries: Option<Vec<u32>> = primaries.iter().map(|p| self.primary(p)).collect();
+            let primaries = match primaries {
+                Some(primaries) => primaries,
+                None => continue,
+            };
+
+            // Accents and case stay what they are in the DUCET
+            let ducet = elements(&chars, &[]);
+            let tertiary = ducet
+                .iter()
+                .find(|e| e.primary != 0)
+                .map_or(2, |e| e.tertiary);
+            let mut elements: Vec<Element> = primaries
+                .into_iter()
+                .map(|primary| Element::new(primary, 0x20, tertiary))
+                .collect();
+            elements.extend(
+                ducet
+                    .iter()
+                    .filter(|e| e.primary == 0 && (e.secondary != 0 || e.tertiary != 0)),
+            );
+
+            // Later lines win
+            tailored.retain(|t| t.chars != chars);
+            tailored.push(Tailored { chars, elements });
+        }
+        tailored
+    }
+
+    fn find_inserted(&self, name: &[u8]) -> Option<(usize, usize)> {
+        self.inserted
+            .iter()
+            .enumerate()
+            .find_map(|(list, (_, names))| {
+                let i = names.iter().position(|n| n == name)?;
+                Some((list, i))
+            })
+    }
+
+    /// The characters a name stands for: `<U00E5>`, `<S00E5>` (which glibc uses for the first
+    /// level weight of a letter) or a collating element
+    fn chars(&self, name: &[u8]) -> Option<Vec<u32>> {
+        match name {
+            [b'U' | b'S', hex @ ..] if (4..=6).contains(&hex.len()) => {
+                let hex = str::from_utf8(hex).ok()?;
+                Some(Vec::from([u32::from_str_radix(hex, 16).ok()?]))
+            }
+            _ => self
+                .collating_elements
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, chars)| chars.clone()),
+        }
+    }
+
+    fn ducet_primary(&self, chars: &[u32]) -> Option<u32> {
+        elements(chars, &[])
+            .iter()
+            .map(|e| e.primary)
+            .find(|&primary| primary != 0)
+    }
+
+    /// The primary weight a name stands for
+    fn primary(&self, name: &[u8]) -> Option<u32> {
+        if let Some((list, i)) = self.find_inserted(name) {
+            let (base, _) = self.inserted[list];
+            return if i + 1 < 1 << SUBWEIGHT_BITS {
+                Some(base + i as u32 + 1)
+            } else {
+                None
+            };
+        }
+        self.ducet_primary(&self.chars(name)?)
+    }
+}
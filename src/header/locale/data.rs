@@ -0,0 +1,422 @@
+//! The data behind each locale category. The "C" locale is built in, every other locale is read
+//! from a localedef source file and kept for the lifetime of the process.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    c_str::{CStr, CString},
+    fs::File,
+    header::{fcntl, stdlib::getenv},
+    io::Read,
+    platform::types::*,
+    sync::Mutex,
+};
+
+use super::{collate::Tailored, localedef};
+
+/// Where locale sources are looked up when they are not found in `LOCPATH`.
+const LOCALE_PATH: &[u8] = b"/usr/share/i18n/locales";
+
+/// How many nested `copy` directives are followed before giving up.
+const MAX_COPY_DEPTH: usize = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Codeset {
+    Ascii,
+    Latin1,
+    Utf8,
+}
+
+impl Codeset {
+    pub fn name(self) -> &'static CStr {
+        match self {
+            Codeset::Ascii => c_str!("ANSI_X3.4-1968"),
+            Codeset::Latin1 => c_str!("ISO-8859-1"),
+            Codeset::Utf8 => c_str!("UTF-8"),
+        }
+    }
+
+    /// Parses the codeset part of a locale name, ignoring case and punctuation like "UTF-8" vs
+    /// "utf8".
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        let normalized: Vec<u8> = name
+            .iter()
+            .filter(|b| b.is_ascii_alphanumeric())
+            .map(|b| b.to_ascii_lowercase())
+            .collect();
+        match &normalized[..] {
+            b"utf8" => Some(Codeset::Utf8),
+            b"iso88591" | b"latin1" => Some(Codeset::Latin1),
+            b"ascii" | b"usascii" | b"ansix341968" => Some(Codeset::Ascii),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct CtypeData {
+    pub codeset: Codeset,
+    /// Case mappings that take the place of the Unicode ones, like those of the dotted and the
+    /// dotless i in Turkish. Each is sorted by the character mapped.
+    pub toupper: &'static [(u32, u32)],
+    pub tolower: &'static [(u32, u32)],
+    pub totitle: &'static [(u32, u32)],
+    /// Classes and mappings that the locale defines besides the standard ones
+    pub classes: &'static [CharClass],
+    pub maps: &'static [CharMap],
+    /// The ten digits printf writes for the I flag, if the locale has its own
+    pub outdigits: &'static [u32],
+}
+
+#[derive(Clone)]
+pub struct CharClass {
+    pub name: Vec<u8>,
+    /// Sorted ranges of the characters in the class, first and last
+    pub ranges: Vec<(u32, u32)>,
+}
+
+impl CharClass {
+    pub fn contains(&self, wc: u32) -> bool {
+        let i = self.ranges.partition_point(|&(_, last)| last < wc);
+        self.ranges.get(i).map_or(false, |&(first, _)| first <= wc)
+    }
+}
+
+#[derive(Clone)]
+pub struct CharMap {
+    pub name: Vec<u8>,
+    /// Pairs of a character and what it maps to, sorted by the character
+    pub pairs: Vec<(u32, u32)>,
+}
+
+impl CharMap {
+    pub fn apply(&self, wc: u32) -> u32 {
+        lookup(&self.pairs, wc).unwrap_or(wc)
+    }
+}
+
+/// Finds what `wc` maps to in a sorted list of pairs.
+pub fn lookup(pairs: &[(u32, u32)], wc: u32) -> Option<u32> {
+    pairs
+        .binary_search_by_key(&wc, |&(from, _)| from)
+        .ok()
+        .map(|i| pairs[i].1)
+}
+
+#[derive(Clone, Copy)]
+pub struct CollateData {
+    /// Whether strings collate by the Unicode Collation Algorithm. The "C" locales compare
+    /// them byte by byte, which for UTF-8 is the order of the code points.
+    pub unicode: bool,
+    /// What the locale changes about the order of the DUCET
+    pub tailoring: &'static [Tailored],
+}
+
+#[derive(Clone, Copy)]
+pub struct NumericData {
+    pub decimal_point: &'static CStr,
+    pub thousands_sep: &'static CStr,
+    pub grouping: &'static CStr,
+}
+
+#[derive(Clone, Copy)]
+pub struct MonetaryData {
+    pub int_curr_symbol: &'static CStr,
+    pub currency_symbol: &'static CStr,
+    pub mon_decimal_point: &'static CStr,
+    pub mon_thousands_sep: &'static CStr,
+    pub mon_grouping: &'static CStr,
+    pub positive_sign: &'static CStr,
+    pub negative_sign: &'static CStr,
+    pub int_frac_digits: c_char,
+    pub frac_digits: c_char,
+    pub p_cs_precedes: c_char,
+    pub p_sep_by_space: c_char,
+    pub n_cs_precedes: c_char,
+    pub n_sep_by_space: c_char,
+    pub p_sign_posn: c_char,
+    pub n_sign_posn: c_char,
+    pub int_p_cs_precedes: c_char,
+    pub int_p_sep_by_space: c_char,
+    pub int_n_cs_precedes: c_char,
+    pub int_n_sep_by_space: c_char,
+    pub int_p_sign_posn: c_char,
+    pub int_n_sign_posn: c_char,
+}
+
+#[derive(Clone, Copy)]
+pub struct TimeData {
+    pub abday: [&'static CStr; 7],
+    pub day: [&'static CStr; 7],
+    pub abmon: [&'static CStr; 12],
+    pub mon: [&'static CStr; 12],
+    pub am_pm: [&'static CStr; 2],
+    pub d_t_fmt: &'static CStr,
+    pub d_fmt: &'static CStr,
+    pub t_fmt: &'static CStr,
+    pub t_fmt_ampm: &'static CStr,
+}
+
+#[derive(Clone, Copy)]
+pub struct MessagesData {
+    pub yesexpr: &'static CStr,
+    pub noexpr: &'static CStr,
+    pub yesstr: &'static CStr,
+    pub nostr: &'static CStr,
+}
+
+/// Everything a locale source defines. Categories of a locale object may point into different
+/// `LocaleData`, as each category can be set separately.
+#[derive(Clone, Copy)]
+pub struct LocaleData {
+    pub name: &'static CStr,
+    pub collate: CollateData,
+    pub ctype: CtypeData,
+    pub numeric: NumericData,
+    pub monetary: MonetaryData,
+    pub time: TimeData,
+    pub messages: MessagesData,
+}
+
+const CHAR_MAX: c_char = c_char::max_value();
+
+pub const C_CTYPE: CtypeData = CtypeData {
+    codeset: Codeset::Ascii,
+    toupper: &[],
+    tolower: &[],
+    totitle: &[],
+    classes: &[],
+    maps: &[],
+    outdigits: &[],
+};
+
+pub const C_COLLATE: CollateData = CollateData {
+    unicode: false,
+    tailoring: &[],
+};
+
+pub const C_NUMERIC: NumericData = NumericData {
+    decimal_point: c_str!("."),
+    thousands_sep: c_str!(""),
+    grouping: c_str!(""),
+};
+
+pub const C_MONETARY: MonetaryData = MonetaryData {
+    int_curr_symbol: c_str!(""),
+    currency_symbol: c_str!(""),
+    mon_decimal_point: c_str!(""),
+    mon_thousands_sep: c_str!(""),
+    mon_grouping: c_str!(""),
+    positive_sign: c_str!(""),
+    negative_sign: c_str!(""),
+    int_frac_digits: CHAR_MAX,
+    frac_digits: CHAR_MAX,
+    p_cs_precedes: CHAR_MAX,
+    p_sep_by_space: CHAR_MAX,
+    n_cs_precedes: CHAR_MAX,
+    n_sep_by_space: CHAR_MAX,
+    p_sign_posn: CHAR_MAX,
+    n_sign_posn: CHAR_MAX,
+    int_p_cs_precedes: CHAR_MAX,
+    int_p_sep_by_space: CHAR_MAX,
+    int_n_cs_precedes: CHAR_MAX,
+    int_n_sep_by_space: CHAR_MAX,
+    int_p_sign_posn: CHAR_MAX,
+    int_n_sign_posn: CHAR_MAX,
+};
+
+pub const C_TIME: TimeData = TimeData {
+    abday: [
+        c_str!("Sun"),
+        c_str!("Mon"),
+        c_str!("Tue"),
+        c_str!("Wed"),
+        c_str!("Thu"),
+        c_str!("Fri"),
+        c_str!("Sat"),
+    ],
+    day: [
+        c_str!("Sunday"),
+        c_str!("Monday"),
+        c_str!("Tuesday"),
+        c_str!("Wednesday"),
+        c_str!("Thursday"),
+        c_str!("Friday"),
+        c_str!("Saturday"),
+    ],
+    abmon: [
+        c_str!("Jan"),
+        c_str!("Feb"),
+        c_str!("Mar"),
+        c_str!("Apr"),
+        c_str!("May"),
+        c_str!("Jun"),
+        c_str!("Jul"),
+        c_str!("Aug"),
+        c_str!("Sep"),
+        c_str!("Oct"),
+        c_str!("Nov"),
+        c_str!("Dec"),
+    ],
+    mon: [
+        c_str!("January"),
+        c_str!("February"),
+        c_str!("March"),
+        c_str!("April"),
+        c_str!("May"),
+        c_str!("June"),
+        c_str!("July"),
+        c_str!("August"),
+        c_str!("September"),
+        c_str!("October"),
+        c_str!("November"),
+        c_str!("December"),
+    ],
+    am_pm: [c_str!("AM"), c_str!("PM")],
+    d_t_fmt: c_str!("%a %b %e %H:%M:%S %Y"),
+    d_fmt: c_str!("%m/%d/%y"),
+    t_fmt: c_str!("%H:%M:%S"),
+    t_fmt_ampm: c_str!("%I:%M:%S %p"),
+};
+
+pub const C_MESSAGES: MessagesData = MessagesData {
+    yesexpr: c_str!("^[yY]"),
+    noexpr: c_str!("^[nN]"),
+    yesstr: c_str!("yes"),
+    nostr: c_str!("no"),
+};
+
+pub static C_LOCALE: LocaleData = LocaleData {
+    name: c_str!("C"),
+    collate: C_COLLATE,
+    ctype: C_CTYPE,
+    numeric: C_NUMERIC,
+    monetary: C_MONETARY,
+    time: C_TIME,
+    messages: C_MESSAGES,
+};
+
+/// The "C" locale with UTF-8 as its character set, which does not need any locale source.
+pub static C_UTF8_LOCALE: LocaleData = LocaleData {
+    name: c_str!("C.UTF-8"),
+    collate: C_COLLATE,
+    ctype: CtypeData {
+        codeset: Codeset::Utf8,
+        ..C_CTYPE
+    },
+    numeric: C_NUMERIC,
+    monetary: C_MONETARY,
+    time: C_TIME,
+    messages: C_MESSAGES,
+};
+
+static LOADED: Mutex<Vec<&'static LocaleData>> = Mutex::new(Vec::new());
+
+/// Returns the data of the locale `name`, which has the form `language[_territory][.codeset]
+/// [@modifier]`. Locales are loaded once and never freed, so the result can be shared freely.
+pub fn find(name: &CStr) -> Option<&'static LocaleData> {
+    let name = name.to_bytes();
+    if name == b"C" || name == b"POSIX" {
+        return Some(&C_LOCALE);
+    }
+
+    // Locale names become file names, so they must not be able to escape the search path
+    if name.is_empty() || name[0] == b'.' || name.contains(&b'/') {
+        return None;
+    }
+
+    let (rest, modifier) = match name.iter().position(|&b| b == b'@') {
+        Some(i) => (&name[..i], Some(&name[i..])),
+        None => (name, None),
+    };
+    let (base, codeset) = match rest.iter().position(|&b| b == b'.') {
+        Some(i) => (&rest[..i], Codeset::from_name(&rest[i + 1..])?),
+        // Locales without an explicit codeset are assumed to be UTF-8, as everything else is
+        None => (rest, Codeset::Utf8),
+    };
+
+    if base == b"C" || base == b"POSIX" {
+        return match codeset {
+            Codeset::Ascii => Some(&C_LOCALE),
+            Codeset::Utf8 => Some(&C_UTF8_LOCALE),
+            Codeset::Latin1 => None,
+        };
+    }
+
+    let mut loaded = LOADED.lock();
+    if let Some(data) = loaded.iter().find(|data| data.name.to_bytes() == name) {
+        return Some(data);
+    }
+
+    // Try the exact name first, then without the codeset, then without the modifier as well
+    let mut candidates = Vec::with_capacity(3);
+    candidates.push(name.to_vec());
+    if let Some(modifier) = modifier {
+        let mut file = base.to_vec();
+        file.extend_from_slice(modifier);
+        candidates.push(file);
+    }
+    candidates.push(base.to_vec());
+    candidates.dedup();
+
+    let source = candidates.iter().find_map(|file| read_source(file))?;
+    let mut data = localedef::parse(&source, codeset, 0);
+    data.name = leak_c_str(name.to_vec());
+
+    let data: &'static LocaleData = Box::leak(Box::new(data));
+    loaded.push(data);
+    Some(data)
+}
+
+/// Loads the locale source `name` for a `copy` directive inside another source.
+pub fn load_copy(name: &[u8], codeset: Codeset, depth: usize) -> Option<LocaleData> {
+    if depth >= MAX_COPY_DEPTH || name.is_empty() || name[0] == b'.' || name.contains(&b'/') {
+        return None;
+    }
+    let source = read_source(name)?;
+    Some(localedef::parse(&source, codeset, depth + 1))
+}
+
+/// A "C" locale with the given character set, which locale sources are applied on top of.
+pub fn c_with_codeset(codeset: Codeset) -> LocaleData {
+    let mut data = C_LOCALE;
+    data.ctype.codeset = codeset;
+    data
+}
+
+/// Reads the locale source `file` from the first directory in `LOCPATH`, followed by the system
+/// locale directory, that has it.
+fn read_source(file: &[u8]) -> Option<Vec<u8>> {
+    let locpath = unsafe { getenv(c_str!("LOCPATH").as_ptr()) };
+    let locpath = if locpath.is_null() {
+        &[][..]
+    } else {
+        unsafe { CStr::from_ptr(locpath) }.to_bytes()
+    };
+
+    locpath
+        .split(|&b| b == b':')
+        .filter(|dir| !dir.is_empty())
+        .chain(core::iter::once(LOCALE_PATH))
+        .find_map(|dir| {
+            let mut path = dir.to_vec();
+            path.push(b'/');
+            path.extend_from_slice(file);
+            let path = CString::new(path).ok()?;
+
+            let mut file = File::open(&path, fcntl::O_RDONLY | fcntl::O_CLOEXEC).ok()?;
+            let mut source = Vec::new();
+            file.read_to_end(&mut source).ok()?;
+            Some(source)
+        })
+}
+
+/// Turns `bytes` into a C string that lives as long as the locale data referring to it.
+pub fn leak_c_str(mut bytes: Vec<u8>) -> &'static CStr {
+    if let Some(nul) = bytes.iter().position(|&b| b == 0) {
+        bytes.truncate(nul);
+    }
+    bytes.push(0);
+    let bytes: &'static [u8] = Box::leak(bytes.into_boxed_slice());
+    unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+}
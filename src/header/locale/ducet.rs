@@ -0,0 +1,9235 @@
+// Generated by gen_ducet.py from allkeys.txt 13.0.0, do not edit
+
+/// Characters that have collation elements of their own, in order
+pub const CHARS: [u32; 32129] = [
+    0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf, 0x10, 0x11,
+    0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20, 0x21,
+    0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f, 0x30, 0x31,
+    0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f, 0x40, 0x41,
+    0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51,
+    0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x5b, 0x5c, 0x5d, 0x5e, 0x5f, 0x60, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71,
+    0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x7b, 0x7c, 0x7d, 0x7e, 0x7f, 0x80, 0x81,
+    0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f, 0x90, 0x91,
+    0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0x9b, 0x9c, 0x9d, 0x9e, 0x9f, 0xa0, 0xa1,
+    0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0xa9, 0xaa, 0xab, 0xac, 0xad, 0xae, 0xaf, 0xb0, 0xb1,
+    0xb2, 0xb3, 0xb4, 0xb5, 0xb6, 0xb7, 0xb8, 0xb9, 0xba, 0xbb, 0xbc, 0xbd, 0xbe, 0xbf, 0xc0, 0xc1,
+    0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf, 0xd0, 0xd1,
+    0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xd9, 0xda, 0xdb, 0xdc, 0xdd, 0xde, 0xdf, 0xe0, 0xe1,
+    0xe2, 0xe3, 0xe4, 0xe5, 0xe6, 0xe7, 0xe8, 0xe9, 0xea, 0xeb, 0xec, 0xed, 0xee, 0xef, 0xf0, 0xf1,
+    0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff, 0x100,
+    0x101, 0x102, 0x103, 0x104, 0x105, 0x106, 0x107, 0x108, 0x109, 0x10a, 0x10b, 0x10c, 0x10d,
+    0x10e, 0x10f, 0x110, 0x111, 0x112, 0x113, 0x114, 0x115, 0x116, 0x117, 0x118, 0x119, 0x11a,
+    0x11b, 0x11c, 0x11d, 0x11e, 0x11f, 0x120, 0x121, 0x122, 0x123, 0x124, 0x125, 0x126, 0x127,
+    0x128, 0x129, 0x12a, 0x12b, 0x12c, 0x12d, 0x12e, 0x12f, 0x130, 0x131, 0x132, 0x133, 0x134,
+    0x135, 0x136, 0x137, 0x138, 0x139, 0x13a, 0x13b, 0x13c, 0x13d, 0x13e, 0x13f, 0x140, 0x141,
+    0x142, 0x143, 0x144, 0x145, 0x146, 0x147, 0x148, 0x149, 0x14a, 0x14b, 0x14c, 0x14d, 0x14e,
+    0x14f, 0x150, 0x151, 0x152, 0x153, 0x154, 0x155, 0x156, 0x157, 0x158, 0x159, 0x15a, 0x15b,
+    0x15c, 0x15d, 0x15e, 0x15f, 0x160, 0x161, 0x162, 0x163, 0x164, 0x165, 0x166, 0x167, 0x168,
+    0x169, 0x16a, 0x16b, 0x16c, 0x16d, 0x16e, 0x16f, 0x170, 0x171, 0x172, 0x173, 0x174, 0x175,
+    0x176, 0x177, 0x178, 0x179, 0x17a, 0x17b, 0x17c, 0x17d, 0x17e, 0x17f, 0x180, 0x181, 0x182,
+    0x183, 0x184, 0x185, 0x186, 0x187, 0x188, 0x189, 0x18a, 0x18b, 0x18c, 0x18d, 0x18e, 0x18f,
+    0x190, 0x191, 0x192, 0x193, 0x194, 0x195, 0x196, 0x197, 0x198, 0x199, 0x19a, 0x19b, 0x19c,
+    0x19d, 0x19e, 0x19f, 0x1a0, 0x1a1, 0x1a2, 0x1a3, 0x1a4, 0x1a5, 0x1a6, 0x1a7, 0x1a8, 0x1a9,
+    0x1aa, 0x1ab, 0x1ac, 0x1ad, 0x1ae, 0x1af, 0x1b0, 0x1b1, 0x1b2, 0x1b3, 0x1b4, 0x1b5, 0x1b6,
+    0x1b7, 0x1b8, 0x1b9, 0x1ba, 0x1bb, 0x1bc, 0x1bd, 0x1be, 0x1bf, 0x1c0, 0x1c1, 0x1c2, 0x1c3,
+    0x1c4, 0x1c5, 0x1c6, 0x1c7, 0x1c8, 0x1c9, 0x1ca, 0x1cb, 0x1cc, 0x1cd, 0x1ce, 0x1cf, 0x1d0,
+    0x1d1, 0x1d2, 0x1d3, 0x1d4, 0x1d5, 0x1d6, 0x1d7, 0x1d8, 0x1d9, 0x1da, 0x1db, 0x1dc, 0x1dd,
+    0x1de, 0x1df, 0x1e0, 0x1e1, 0x1e2, 0x1e3, 0x1e4, 0x1e5, 0x1e6, 0x1e7, 0x1e8, 0x1e9, 0x1ea,
+    0x1eb, 0x1ec, 0x1ed, 0x1ee, 0x1ef, 0x1f0, 0x1f1, 0x1f2, 0x1f3, 0x1f4, 0x1f5, 0x1f6, 0x1f7,
+    0x1f8, 0x1f9, 0x1fa, 0x1fb, 0x1fc, 0x1fd, 0x1fe, 0x1ff, 0x200, 0x201, 0x202, 0x203, 0x204,
+    0x205, 0x206, 0x207, 0x208, 0x209, 0x20a, 0x20b, 0x20c, 0x20d, 0x20e, 0x20f, 0x210, 0x211,
+    0x212, 0x213, 0x214, 0x215, 0x216, 0x217, 0x218, 0x219, 0x21a, 0x21b, 0x21c, 0x21d, 0x21e,
+    0x21f, 0x220, 0x221, 0x222, 0x223, 0x224, 0x225, 0x226, 0x227, 0x228, 0x229, 0x22a, 0x22b,
+    0x22c, 0x22d, 0x22e, 0x22f, 0x230, 0x231, 0x232, 0x233, 0x234, 0x235, 0x236, 0x237, 0x238,
+    0x239, 0x23a, 0x23b, 0x23c, 0x23d, 0x23e, 0x23f, 0x240, 0x241, 0x242, 0x243, 0x244, 0x245,
+    0x246, 0x247, 0x248, 0x249, 0x24a, 0x24b, 0x24c, 0x24d, 0x24e, 0x24f, 0x250, 0x251, 0x252,
+    0x253, 0x254, 0x255, 0x256, 0x257, 0x258, 0x259, 0x25a, 0x25b, 0x25c, 0x25d, 0x25e, 0x25f,
+    0x260, 0x261, 0x262, 0x263, 0x264, 0x265, 0x266, 0x267, 0x268, 0x269, 0x26a, 0x26b, 0x26c,
+    0x26d, 0x26e, 0x26f, 0x270, 0x271, 0x272, 0x273, 0x274, 0x275, 0x276, 0x277, 0x278, 0x279,
+    0x27a, 0x27b, 0x27c, 0x27d, 0x27e, 0x27f, 0x280, 0x281, 0x282, 0x283, 0x284, 0x285, 0x286,
+    0x287, 0x288, 0x289, 0x28a, 0x28b, 0x28c, 0x28d, 0x28e, 0x28f, 0x290, 0x291, 0x292, 0x293,
+    0x294, 0x295, 0x296, 0x297, 0x298, 0x299, 0x29a, 0x29b, 0x29c, 0x29d, 0x29e, 0x29f, 0x2a0,
+    0x2a1, 0x2a2, 0x2a3, 0x2a4, 0x2a5, 0x2a6, 0x2a7, 0x2a8, 0x2a9, 0x2aa, 0x2ab, 0x2ac, 0x2ad,
+    0x2ae, 0x2af, 0x2b0, 0x2b1, 0x2b2, 0x2b3, 0x2b4, 0x2b5, 0x2b6, 0x2b7, 0x2b8, 0x2b9, 0x2ba,
+    0x2bb, 0x2bc, 0x2bd, 0x2be, 0x2bf, 0x2c0, 0x2c1, 0x2c2, 0x2c3, 0x2c4, 0x2c5, 0x2c6, 0x2c7,
+    0x2c8, 0x2c9, 0x2ca, 0x2cb, 0x2cc, 0x2cd, 0x2ce, 0x2cf, 0x2d0, 0x2d1, 0x2d2, 0x2d3, 0x2d4,
+    0x2d5, 0x2d6, 0x2d7, 0x2d8, 0x2d9, 0x2da, 0x2db, 0x2dc, 0x2dd, 0x2de, 0x2df, 0x2e0, 0x2e1,
+    0x2e2, 0x2e3, 0x2e4, 0x2e5, 0x2e6, 0x2e7, 0x2e8, 0x2e9, 0x2ea, 0x2eb, 0x2ec, 0x2ed, 0x2ee,
+    0x2ef, 0x2f0, 0x2f1, 0x2f2, 0x2f3, 0x2f4, 0x2f5, 0x2f6, 0x2f7, 0x2f8, 0x2f9, 0x2fa, 0x2fb,
+    0x2fc, 0x2fd, 0x2fe, 0x2ff, 0x300, 0x301, 0x302, 0x303, 0x304, 0x305, 0x306, 0x307, 0x308,
+    0x309, 0x30a, 0x30b, 0x30c, 0x30d, 0x30e, 0x30f, 0x310, 0x311, 0x312, 0x313, 0x314, 0x315,
+    0x316, 0x317, 0x318, 0x319, 0x31a, 0x31b, 0x31c, 0x31d, 0x31e, 0x31f, 0x320, 0x321, 0x322,
+    0x323, 0x324, 0x325, 0x326, 0x327, 0x328, 0x329, 0x32a, 0x32b, 0x32c, 0x32d, 0x32e, 0x32f,
+    0x330, 0x331, 0x332, 0x333, 0x334, 0x335, 0x336, 0x337, 0x338, 0x339, 0x33a, 0x33b, 0x33c,
+    0x33d, 0x33e, 0x33f, 0x340, 0x341, 0x342, 0x343, 0x344, 0x345, 0x346, 0x347, 0x348, 0x349,
+    0x34a, 0x34b, 0x34c, 0x34d, 0x34e, 0x34f, 0x350, 0x351, 0x352, 0x353, 0x354, 0x355, 0x356,
+    0x357, 0x358, 0x359, 0x35a, 0x35b, 0x35c, 0x35d, 0x35e, 0x35f, 0x360, 0x361, 0x362, 0x363,
+    0x364, 0x365, 0x366, 0x367, 0x368, 0x369, 0x36a, 0x36b, 0x36c, 0x36d, 0x36e, 0x36f, 0x370,
+    0x371, 0x372, 0x373, 0x374, 0x375, 0x376, 0x377, 0x37a, 0x37b, 0x37c, 0x37d, 0x37e, 0x37f,
+    0x384, 0x385, 0x386, 0x387, 0x388, 0x389, 0x38a, 0x38c, 0x38e, 0x38f, 0x390, 0x391, 0x392,
+    0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39a, 0x39b, 0x39c, 0x39d, 0x39e, 0x39f,
+    0x3a0, 0x3a1, 0x3a3, 0x3a4, 0x3a5, 0x3a6, 0x3a7, 0x3a8, 0x3a9, 0x3aa, 0x3ab, 0x3ac, 0x3ad,
+    0x3ae, 0x3af, 0x3b0, 0x3b1, 0x3b2, 0x3b3, 0x3b4, 0x3b5, 0x3b6, 0x3b7, 0x3b8, 0x3b9, 0x3ba,
+    0x3bb, 0x3bc, 0x3bd, 0x3be, 0x3bf, 0x3c0, 0x3c1, 0x3c2, 0x3c3, 0x3c4, 0x3c5, 0x3c6, 0x3c7,
+    0x3c8, 0x3c9, 0x3ca, 0x3cb, 0x3cc, 0x3cd, 0x3ce, 0x3cf, 0x3d0, 0x3d1, 0x3d2, 0x3d3, 0x3d4,
+    0x3d5, 0x3d6, 0x3d7, 0x3d8, 0x3d9, 0x3da, 0x3db, 0x3dc, 0x3dd, 0x3de, 0x3df, 0x3e0, 0x3e1,
+    0x3e2, 0x3e3, 0x3e4, 0x3e5, 0x3e6, 0x3e7, 0x3e8, 0x3e9, 0x3ea, 0x3eb, 0x3ec, 0x3ed, 0x3ee,
+    0x3ef, 0x3f0, 0x3f1, 0x3f2, 0x3f3, 0x3f4, 0x3f5, 0x3f6, 0x3f7, 0x3f8, 0x3f9, 0x3fa, 0x3fb,
+    0x3fc, 0x3fd, 0x3fe, 0x3ff, 0x400, 0x401, 0x402, 0x403, 0x404, 0x405, 0x406, 0x407, 0x408,
+    0x409, 0x40a, 0x40b, 0x40c, 0x40d, 0x40e, 0x40f, 0x410, 0x411, 0x412, 0x413, 0x414, 0x415,
+    0x416, 0x417, 0x418, 0x419, 0x41a, 0x41b, 0x41c, 0x41d, 0x41e, 0x41f, 0x420, 0x421, 0x422,
+    0x423, 0x424, 0x425, 0x426, 0x427, 0x428, 0x429, 0x42a, 0x42b, 0x42c, 0x42d, 0x42e, 0x42f,
+    0x430, 0x431, 0x432, 0x433, 0x434, 0x435, 0x436, 0x437, 0x438, 0x439, 0x43a, 0x43b, 0x43c,
+    0x43d, 0x43e, 0x43f, 0x440, 0x441, 0x442, 0x443, 0x444, 0x445, 0x446, 0x447, 0x448, 0x449,
+    0x44a, 0x44b, 0x44c, 0x44d, 0x44e, 0x44f, 0x450, 0x451, 0x452, 0x453, 0x454, 0x455, 0x456,
+    0x457, 0x458, 0x459, 0x45a, 0x45b, 0x45c, 0x45d, 0x45e, 0x45f, 0x460, 0x461, 0x462, 0x463,
+    0x464, 0x465, 0x466, 0x467, 0x468, 0x469, 0x46a, 0x46b, 0x46c, 0x46d, 0x46e, 0x46f, 0x470,
+    0x471, 0x472, 0x473, 0x474, 0x475, 0x476, 0x477, 0x478, 0x479, 0x47a, 0x47b, 0x47c, 0x47d,
+    0x47e, 0x47f, 0x480, 0x481, 0x482, 0x483, 0x484, 0x485, 0x486, 0x487, 0x488, 0x489, 0x48a,
+    0x48b, 0x48c, 0x48d, 0x48e, 0x48f, 0x490, 0x491, 0x492, 0x493, 0x494, 0x495, 0x496, 0x497,
+    0x498, 0x499, 0x49a, 0x49b, 0x49c, 0x49d, 0x49e, 0x49f, 0x4a0, 0x4a1, 0x4a2, 0x4a3, 0x4a4,
+    0x4a5, 0x4a6, 0x4a7, 0x4a8, 0x4a9, 0x4aa, 0x4ab, 0x4ac, 0x4ad, 0x4ae, 0x4af, 0x4b0, 0x4b1,
+    0x4b2, 0x4b3, 0x4b4, 0x4b5, 0x4b6, 0x4b7, 0x4b8, 0x4b9, 0x4ba, 0x4bb, 0x4bc, 0x4bd, 0x4be,
+    0x4bf, 0x4c0, 0x4c1, 0x4c2, 0x4c3, 0x4c4, 0x4c5, 0x4c6, 0x4c7, 0x4c8, 0x4c9, 0x4ca, 0x4cb,
+    0x4cc, 0x4cd, 0x4ce, 0x4cf, 0x4d0, 0x4d1, 0x4d2, 0x4d3, 0x4d4, 0x4d5, 0x4d6, 0x4d7, 0x4d8,
+    0x4d9, 0x4da, 0x4db, 0x4dc, 0x4dd, 0x4de, 0x4df, 0x4e0, 0x4e1, 0x4e2, 0x4e3, 0x4e4, 0x4e5,
+    0x4e6, 0x4e7, 0x4e8, 0x4e9, 0x4ea, 0x4eb, 0x4ec, 0x4ed, 0x4ee, 0x4ef, 0x4f0, 0x4f1, 0x4f2,
+    0x4f3, 0x4f4, 0x4f5, 0x4f6, 0x4f7, 0x4f8, 0x4f9, 0x4fa, 0x4fb, 0x4fc, 0x4fd, 0x4fe, 0x4ff,
+    0x500, 0x501, 0x502, 0x503, 0x504, 0x505, 0x506, 0x507, 0x508, 0x509, 0x50a, 0x50b, 0x50c,
+    0x50d, 0x50e, 0x50f, 0x510, 0x511, 0x512, 0x513, 0x514, 0x515, 0x516, 0x517, 0x518, 0x519,
+    0x51a, 0x51b, 0x51c, 0x51d, 0x51e, 0x51f, 0x520, 0x521, 0x522, 0x523, 0x524, 0x525, 0x526,
+    0x527, 0x528, 0x529, 0x52a, 0x52b, 0x52c, 0x52d, 0x52e, 0x52f, 0x531, 0x532, 0x533, 0x534,
+    0x535, 0x536, 0x537, 0x538, 0x539, 0x53a, 0x53b, 0x53c, 0x53d, 0x53e, 0x53f, 0x540, 0x541,
+    0x542, 0x543, 0x544, 0x545, 0x546, 0x547, 0x548, 0x549, 0x54a, 0x54b, 0x54c, 0x54d, 0x54e,
+    0x54f, 0x550, 0x551, 0x552, 0x553, 0x554, 0x555, 0x556, 0x559, 0x55a, 0x55b, 0x55c, 0x55d,
+    0x55e, 0x55f, 0x560, 0x561, 0x562, 0x563, 0x564, 0x565, 0x566, 0x567, 0x568, 0x569, 0x56a,
+    0x56b, 0x56c, 0x56d, 0x56e, 0x56f, 0x570, 0x571, 0x572, 0x573, 0x574, 0x575, 0x576, 0x577,
+    0x578, 0x579, 0x57a, 0x57b, 0x57c, 0x57d, 0x57e, 0x57f, 0x580, 0x581, 0x582, 0x583, 0x584,
+    0x585, 0x586, 0x587, 0x588, 0x589, 0x58a, 0x58d, 0x58e, 0x58f, 0x591, 0x592, 0x593, 0x594,
+    0x595, 0x596, 0x597, 0x598, 0x599, 0x59a, 0x59b, 0x59c, 0x59d, 0x59e, 0x59f, 0x5a0, 0x5a1,
+    0x5a2, 0x5a3, 0x5a4, 0x5a5, 0x5a6, 0x5a7, 0x5a8, 0x5a9, 0x5aa, 0x5ab, 0x5ac, 0x5ad, 0x5ae,
+    0x5af, 0x5b0, 0x5b1, 0x5b2, 0x5b3, 0x5b4, 0x5b5, 0x5b6, 0x5b7, 0x5b8, 0x5b9, 0x5ba, 0x5bb,
+    0x5bc, 0x5bd, 0x5be, 0x5bf, 0x5c0, 0x5c1, 0x5c2, 0x5c3, 0x5c4, 0x5c5, 0x5c6, 0x5c7, 0x5d0,
+    0x5d1, 0x5d2, 0x5d3, 0x5d4, 0x5d5, 0x5d6, 0x5d7, 0x5d8, 0x5d9, 0x5da, 0x5db, 0x5dc, 0x5dd,
+    0x5de, 0x5df, 0x5e0, 0x5e1, 0x5e2, 0x5e3, 0x5e4, 0x5e5, 0x5e6, 0x5e7, 0x5e8, 0x5e9, 0x5ea,
+    0x5ef, 0x5f0, 0x5f1, 0x5f2, 0x5f3, 0x5f4, 0x600, 0x601, 0x602, 0x603, 0x604, 0x605, 0x606,
+    0x607, 0x608, 0x609, 0x60a, 0x60b, 0x60c, 0x60d, 0x60e, 0x60f, 0x610, 0x611, 0x612, 0x613,
+    0x614, 0x615, 0x616, 0x617, 0x618, 0x619, 0x61a, 0x61b, 0x61c, 0x61e, 0x61f, 0x620, 0x621,
+    0x622, 0x623, 0x624, 0x625, 0x626, 0x627, 0x628, 0x629, 0x62a, 0x62b, 0x62c, 0x62d, 0x62e,
+    0x62f, 0x630, 0x631, 0x632, 0x633, 0x634, 0x635, 0x636, 0x637, 0x638, 0x639, 0x63a, 0x63b,
+    0x63c, 0x63d, 0x63e, 0x63f, 0x640, 0x641, 0x642, 0x643, 0x644, 0x645, 0x646, 0x647, 0x648,
+    0x649, 0x64a, 0x64b, 0x64c, 0x64d, 0x64e, 0x64f, 0x650, 0x651, 0x652, 0x653, 0x654, 0x655,
+    0x656, 0x657, 0x658, 0x659, 0x65a, 0x65b, 0x65c, 0x65d, 0x65e, 0x65f, 0x660, 0x661, 0x662,
+    0x663, 0x664, 0x665, 0x666, 0x667, 0x668, 0x669, 0x66a, 0x66b, 0x66c, 0x66d, 0x66e, 0x66f,
+    0x670, 0x671, 0x672, 0x673, 0x674, 0x675, 0x676, 0x677, 0x678, 0x679, 0x67a, 0x67b, 0x67c,
+    0x67d, 0x67e, 0x67f, 0x680, 0x681, 0x682, 0x683, 0x684, 0x685, 0x686, 0x687, 0x688, 0x689,
+    0x68a, 0x68b, 0x68c, 0x68d, 0x68e, 0x68f, 0x690, 0x691, 0x692, 0x693, 0x694, 0x695, 0x696,
+    0x697, 0x698, 0x699, 0x69a, 0x69b, 0x69c, 0x69d, 0x69e, 0x69f, 0x6a0, 0x6a1, 0x6a2, 0x6a3,
+    0x6a4, 0x6a5, 0x6a6, 0x6a7, 0x6a8, 0x6a9, 0x6aa, 0x6ab, 0x6ac, 0x6ad, 0x6ae, 0x6af, 0x6b0,
+    0x6b1, 0x6b2, 0x6b3, 0x6b4, 0x6b5, 0x6b6, 0x6b7, 0x6b8, 0x6b9, 0x6ba, 0x6bb, 0x6bc, 0x6bd,
+    0x6be, 0x6bf, 0x6c0, 0x6c1, 0x6c2, 0x6c3, 0x6c4, 0x6c5, 0x6c6, 0x6c7, 0x6c8, 0x6c9, 0x6ca,
+    0x6cb, 0x6cc, 0x6cd, 0x6ce, 0x6cf, 0x6d0, 0x6d1, 0x6d2, 0x6d3, 0x6d4, 0x6d5, 0x6d6, 0x6d7,
+    0x6d8, 0x6d9, 0x6da, 0x6db, 0x6dc, 0x6dd, 0x6de, 0x6df, 0x6e0, 0x6e1, 0x6e2, 0x6e3, 0x6e4,
+    0x6e5, 0x6e6, 0x6e7, 0x6e8, 0x6e9, 0x6ea, 0x6eb, 0x6ec, 0x6ed, 0x6ee, 0x6ef, 0x6f0, 0x6f1,
+    0x6f2, 0x6f3, 0x6f4, 0x6f5, 0x6f6, 0x6f7, 0x6f8, 0x6f9, 0x6fa, 0x6fb, 0x6fc, 0x6fd, 0x6fe,
+    0x6ff, 0x700, 0x701, 0x702, 0x703, 0x704, 0x705, 0x706, 0x707, 0x708, 0x709, 0x70a, 0x70b,
+    0x70c, 0x70d, 0x70f, 0x710, 0x711, 0x712, 0x713, 0x714, 0x715, 0x716, 0x717, 0x718, 0x719,
+    0x71a, 0x71b, 0x71c, 0x71d, 0x71e, 0x71f, 0x720, 0x721, 0x722, 0x723, 0x724, 0x725, 0x726,
+    0x727, 0x728, 0x729, 0x72a, 0x72b, 0x72c, 0x72d, 0x72e, 0x72f, 0x730, 0x731, 0x732, 0x733,
+    0x734, 0x735, 0x736, 0x737, 0x738, 0x739, 0x73a, 0x73b, 0x73c, 0x73d, 0x73e, 0x73f, 0x740,
+    0x741, 0x742, 0x743, 0x744, 0x745, 0x746, 0x747, 0x748, 0x749, 0x74a, 0x74d, 0x74e, 0x74f,
+    0x750, 0x751, 0x752, 0x753, 0x754, 0x755, 0x756, 0x757, 0x758, 0x759, 0x75a, 0x75b, 0x75c,
+    0x75d, 0x75e, 0x75f, 0x760, 0x761, 0x762, 0x763, 0x764, 0x765, 0x766, 0x767, 0x768, 0x769,
+    0x76a, 0x76b, 0x76c, 0x76d, 0x76e, 0x76f, 0x770, 0x771, 0x772, 0x773, 0x774, 0x775, 0x776,
+    0x777, 0x778, 0x779, 0x77a, 0x77b, 0x77c, 0x77d, 0x77e, 0x77f, 0x780, 0x781, 0x782, 0x783,
+    0x784, 0x785, 0x786, 0x787, 0x788, 0x789, 0x78a, 0x78b, 0x78c, 0x78d, 0x78e, 0x78f, 0x790,
+    0x791, 0x792, 0x793, 0x794, 0x795, 0x796, 0x797, 0x798, 0x799, 0x79a, 0x79b, 0x79c, 0x79d,
+    0x79e, 0x79f, 0x7a0, 0x7a1, 0x7a2, 0x7a3, 0x7a4, 0x7a5, 0x7a6, 0x7a7, 0x7a8, 0x7a9, 0x7aa,
+    0x7ab, 0x7ac, 0x7ad, 0x7ae, 0x7af, 0x7b0, 0x7b1, 0x7c0, 0x7c1, 0x7c2, 0x7c3, 0x7c4, 0x7c5,
+    0x7c6, 0x7c7, 0x7c8, 0x7c9, 0x7ca, 0x7cb, 0x7cc, 0x7cd, 0x7ce, 0x7cf, 0x7d0, 0x7d1, 0x7d2,
+    0x7d3, 0x7d4, 0x7d5, 0x7d6, 0x7d7, 0x7d8, 0x7d9, 0x7da, 0x7db, 0x7dc, 0x7dd, 0x7de, 0x7df,
+    0x7e0, 0x7e1, 0x7e2, 0x7e3, 0x7e4, 0x7e5, 0x7e6, 0x7e7, 0x7e8, 0x7e9, 0x7ea, 0x7eb, 0x7ec,
+    0x7ed, 0x7ee, 0x7ef, 0x7f0, 0x7f1, 0x7f2, 0x7f3, 0x7f4, 0x7f5, 0x7f6, 0x7f7, 0x7f8, 0x7f9,
+    0x7fa, 0x7fd, 0x7fe, 0x7ff, 0x800, 0x801, 0x802, 0x803, 0x804, 0x805, 0x806, 0x807, 0x808,
+    0x809, 0x80a, 0x80b, 0x80c, 0x80d, 0x80e, 0x80f, 0x810, 0x811, 0x812, 0x813, 0x814, 0x815,
+    0x816, 0x817, 0x818, 0x819, 0x81a, 0x81b, 0x81c, 0x81d, 0x81e, 0x81f, 0x820, 0x821, 0x822,
+    0x823, 0x824, 0x825, 0x826, 0x827, 0x828, 0x829, 0x82a, 0x82b, 0x82c, 0x82d, 0x830, 0x831,
+    0x832, 0x833, 0x834, 0x835, 0x836, 0x837, 0x838, 0x839, 0x83a, 0x83b, 0x83c, 0x83d, 0x83e,
+    0x840, 0x841, 0x842, 0x843, 0x844, 0x845, 0x846, 0x847, 0x848, 0x849, 0x84a, 0x84b, 0x84c,
+    0x84d, 0x84e, 0x84f, 0x850, 0x851, 0x852, 0x853, 0x854, 0x855, 0x856, 0x857, 0x858, 0x859,
+    0x85a, 0x85b, 0x85e, 0x860, 0x861, 0x862, 0x863, 0x864, 0x865, 0x866, 0x867, 0x868, 0x869,
+    0x86a, 0x8a0, 0x8a1, 0x8a2, 0x8a3, 0x8a4, 0x8a5, 0x8a6, 0x8a7, 0x8a8, 0x8a9, 0x8aa, 0x8ab,
+    0x8ac, 0x8ad, 0x8ae, 0x8af, 0x8b0, 0x8b1, 0x8b2, 0x8b3, 0x8b4, 0x8b6, 0x8b7, 0x8b8, 0x8b9,
+    0x8ba, 0x8bb, 0x8bc, 0x8bd, 0x8be, 0x8bf, 0x8c0, 0x8c1, 0x8c2, 0x8c3, 0x8c4, 0x8c5, 0x8c6,
+    0x8c7, 0x8d3, 0x8d4, 0x8d5, 0x8d6, 0x8d7, 0x8d8, 0x8d9, 0x8da, 0x8db, 0x8dc, 0x8dd, 0x8de,
+    0x8df, 0x8e0, 0x8e1, 0x8e2, 0x8e3, 0x8e4, 0x8e5, 0x8e6, 0x8e7, 0x8e8, 0x8e9, 0x8ea, 0x8eb,
+    0x8ec, 0x8ed, 0x8ee, 0x8ef, 0x8f0, 0x8f1, 0x8f2, 0x8f3, 0x8f4, 0x8f5, 0x8f6, 0x8f7, 0x8f8,
+    0x8f9, 0x8fa, 0x8fb, 0x8fc, 0x8fd, 0x8fe, 0x8ff, 0x900, 0x901, 0x902, 0x903, 0x904, 0x905,
+    0x906, 0x907, 0x908, 0x909, 0x90a, 0x90b, 0x90c, 0x90d, 0x90e, 0x90f, 0x910, 0x911, 0x912,
+    0x913, 0x914, 0x915, 0x916, 0x917, 0x918, 0x919, 0x91a, 0x91b, 0x91c, 0x91d, 0x91e, 0x91f,
+    0x920, 0x921, 0x922, 0x923, 0x924, 0x925, 0x926, 0x927, 0x928, 0x929, 0x92a, 0x92b, 0x92c,
+    0x92d, 0x92e, 0x92f, 0x930, 0x931, 0x932, 0x933, 0x934, 0x935, 0x936, 0x937, 0x938, 0x939,
+    0x93a, 0x93b, 0x93c, 0x93d, 0x93e, 0x93f, 0x940, 0x941, 0x942, 0x943, 0x944, 0x945, 0x946,
+    0x947, 0x948, 0x949, 0x94a, 0x94b, 0x94c, 0x94d, 0x94e, 0x94f, 0x950, 0x951, 0x952, 0x953,
+    0x954, 0x955, 0x956, 0x957, 0x958, 0x959, 0x95a, 0x95b, 0x95c, 0x95d, 0x95e, 0x95f, 0x960,
+    0x961, 0x962, 0x963, 0x964, 0x965, 0x966, 0x967, 0x968, 0x969, 0x96a, 0x96b, 0x96c, 0x96d,
+    0x96e, 0x96f, 0x970, 0x971, 0x972, 0x973, 0x974, 0x975, 0x976, 0x977, 0x978, 0x979, 0x97a,
+    0x97b, 0x97c, 0x97d, 0x97e, 0x97f, 0x980, 0x981, 0x982, 0x983, 0x985, 0x986, 0x987, 0x988,
+    0x989, 0x98a, 0x98b, 0x98c, 0x98f, 0x990, 0x993, 0x994, 0x995, 0x996, 0x997, 0x998, 0x999,
+    0x99a, 0x99b, 0x99c, 0x99d, 0x99e, 0x99f, 0x9a0, 0x9a1, 0x9a2, 0x9a3, 0x9a4, 0x9a5, 0x9a6,
+    0x9a7, 0x9a8, 0x9aa, 0x9ab, 0x9ac, 0x9ad, 0x9ae, 0x9af, 0x9b0, 0x9b2, 0x9b6, 0x9b7, 0x9b8,
+    0x9b9, 0x9bc, 0x9bd, 0x9be, 0x9bf, 0x9c0, 0x9c1, 0x9c2, 0x9c3, 0x9c4, 0x9c7, 0x9c8, 0x9cb,
+    0x9cc, 0x9cd, 0x9ce, 0x9d7, 0x9dc, 0x9dd, 0x9df, 0x9e0, 0x9e1, 0x9e2, 0x9e3, 0x9e6, 0x9e7,
+    0x9e8, 0x9e9, 0x9ea, 0x9eb, 0x9ec, 0x9ed, 0x9ee, 0x9ef, 0x9f0, 0x9f1, 0x9f2, 0x9f3, 0x9f4,
+    0x9f5, 0x9f6, 0x9f7, 0x9f8, 0x9f9, 0x9fa, 0x9fb, 0x9fc, 0x9fd, 0x9fe, 0xa01, 0xa02, 0xa03,
+    0xa05, 0xa06, 0xa07, 0xa08, 0xa09, 0xa0a, 0xa0f, 0xa10, 0xa13, 0xa14, 0xa15, 0xa16, 0xa17,
+    0xa18, 0xa19, 0xa1a, 0xa1b, 0xa1c, 0xa1d, 0xa1e, 0xa1f, 0xa20, 0xa21, 0xa22, 0xa23, 0xa24,
+    0xa25, 0xa26, 0xa27, 0xa28, 0xa2a, 0xa2b, 0xa2c, 0xa2d, 0xa2e, 0xa2f, 0xa30, 0xa32, 0xa33,
+    0xa35, 0xa36, 0xa38, 0xa39, 0xa3c, 0xa3e, 0xa3f, 0xa40, 0xa41, 0xa42, 0xa47, 0xa48, 0xa4b,
+    0xa4c, 0xa4d, 0xa51, 0xa59, 0xa5a, 0xa5b, 0xa5c, 0xa5e, 0xa66, 0xa67, 0xa68, 0xa69, 0xa6a,
+    0xa6b, 0xa6c, 0xa6d, 0xa6e, 0xa6f, 0xa70, 0xa71, 0xa72, 0xa73, 0xa74, 0xa75, 0xa76, 0xa81,
+    0xa82, 0xa83, 0xa85, 0xa86, 0xa87, 0xa88, 0xa89, 0xa8a, 0xa8b, 0xa8c, 0xa8d, 0xa8f, 0xa90,
+    0xa91, 0xa93, 0xa94, 0xa95, 0xa96, 0xa97, 0xa98, 0xa99, 0xa9a, 0xa9b, 0xa9c, 0xa9d, 0xa9e,
+    0xa9f, 0xaa0, 0xaa1, 0xaa2, 0xaa3, 0xaa4, 0xaa5, 0xaa6, 0xaa7, 0xaa8, 0xaaa, 0xaab, 0xaac,
+    0xaad, 0xaae, 0xaaf, 0xab0, 0xab2, 0xab3, 0xab5, 0xab6, 0xab7, 0xab8, 0xab9, 0xabc, 0xabd,
+    0xabe, 0xabf, 0xac0, 0xac1, 0xac2, 0xac3, 0xac4, 0xac5, 0xac7, 0xac8, 0xac9, 0xacb, 0xacc,
+    0xacd, 0xad0, 0xae0, 0xae1, 0xae2, 0xae3, 0xae6, 0xae7, 0xae8, 0xae9, 0xaea, 0xaeb, 0xaec,
+    0xaed, 0xaee, 0xaef, 0xaf0, 0xaf1, 0xaf9, 0xafa, 0xafb, 0xafc, 0xafd, 0xafe, 0xaff, 0xb01,
+    0xb02, 0xb03, 0xb05, 0xb06, 0xb07, 0xb08, 0xb09, 0xb0a, 0xb0b, 0xb0c, 0xb0f, 0xb10, 0xb13,
+    0xb14, 0xb15, 0xb16, 0xb17, 0xb18, 0xb19, 0xb1a, 0xb1b, 0xb1c, 0xb1d, 0xb1e, 0xb1f, 0xb20,
+    0xb21, 0xb22, 0xb23, 0xb24, 0xb25, 0xb26, 0xb27, 0xb28, 0xb2a, 0xb2b, 0xb2c, 0xb2d, 0xb2e,
+    0xb2f, 0xb30, 0xb32, 0xb33, 0xb35, 0xb36, 0xb37, 0xb38, 0xb39, 0xb3c, 0xb3d, 0xb3e, 0xb3f,
+    0xb40, 0xb41, 0xb42, 0xb43, 0xb44, 0xb47, 0xb48, 0xb4b, 0xb4c, 0xb4d, 0xb55, 0xb56, 0xb57,
+    0xb5c, 0xb5d, 0xb5f, 0xb60, 0xb61, 0xb62, 0xb63, 0xb66, 0xb67, 0xb68, 0xb69, 0xb6a, 0xb6b,
+    0xb6c, 0xb6d, 0xb6e, 0xb6f, 0xb70, 0xb71, 0xb72, 0xb73, 0xb74, 0xb75, 0xb76, 0xb77, 0xb82,
+    0xb83, 0xb85, 0xb86, 0xb87, 0xb88, 0xb89, 0xb8a, 0xb8e, 0xb8f, 0xb90, 0xb92, 0xb93, 0xb94,
+    0xb95, 0xb99, 0xb9a, 0xb9c, 0xb9e, 0xb9f, 0xba3, 0xba4, 0xba8, 0xba9, 0xbaa, 0xbae, 0xbaf,
+    0xbb0, 0xbb1, 0xbb2, 0xbb3, 0xbb4, 0xbb5, 0xbb6, 0xbb7, 0xbb8, 0xbb9, 0xbbe, 0xbbf, 0xbc0,
+    0xbc1, 0xbc2, 0xbc6, 0xbc7, 0xbc8, 0xbca, 0xbcb, 0xbcc, 0xbcd, 0xbd0, 0xbd7, 0xbe6, 0xbe7,
+    0xbe8, 0xbe9, 0xbea, 0xbeb, 0xbec, 0xbed, 0xbee, 0xbef, 0xbf0, 0xbf1, 0xbf2, 0xbf3, 0xbf4,
+    0xbf5, 0xbf6, 0xbf7, 0xbf8, 0xbf9, 0xbfa, 0xc00, 0xc01, 0xc02, 0xc03, 0xc04, 0xc05, 0xc06,
+    0xc07, 0xc08, 0xc09, 0xc0a, 0xc0b, 0xc0c, 0xc0e, 0xc0f, 0xc10, 0xc12, 0xc13, 0xc14, 0xc15,
+    0xc16, 0xc17, 0xc18, 0xc19, 0xc1a, 0xc1b, 0xc1c, 0xc1d, 0xc1e, 0xc1f, 0xc20, 0xc21, 0xc22,
+    0xc23, 0xc24, 0xc25, 0xc26, 0xc27, 0xc28, 0xc2a, 0xc2b, 0xc2c, 0xc2d, 0xc2e, 0xc2f, 0xc30,
+    0xc31, 0xc32, 0xc33, 0xc34, 0xc35, 0xc36, 0xc37, 0xc38, 0xc39, 0xc3d, 0xc3e, 0xc3f, 0xc40,
+    0xc41, 0xc42, 0xc43, 0xc44, 0xc46, 0xc47, 0xc48, 0xc4a, 0xc4b, 0xc4c, 0xc4d, 0xc55, 0xc56,
+    0xc58, 0xc59, 0xc5a, 0xc60, 0xc61, 0xc62, 0xc63, 0xc66, 0xc67, 0xc68, 0xc69, 0xc6a, 0xc6b,
+    0xc6c, 0xc6d, 0xc6e, 0xc6f, 0xc77, 0xc78, 0xc79, 0xc7a, 0xc7b, 0xc7c, 0xc7d, 0xc7e, 0xc7f,
+    0xc80, 0xc81, 0xc82, 0xc83, 0xc84, 0xc85, 0xc86, 0xc87, 0xc88, 0xc89, 0xc8a, 0xc8b, 0xc8c,
+    0xc8e, 0xc8f, 0xc90, 0xc92, 0xc93, 0xc94, 0xc95, 0xc96, 0xc97, 0xc98, 0xc99, 0xc9a, 0xc9b,
+    0xc9c, 0xc9d, 0xc9e, 0xc9f, 0xca0, 0xca1, 0xca2, 0xca3, 0xca4, 0xca5, 0xca6, 0xca7, 0xca8,
+    0xcaa, 0xcab, 0xcac, 0xcad, 0xcae, 0xcaf, 0xcb0, 0xcb1, 0xcb2, 0xcb3, 0xcb5, 0xcb6, 0xcb7,
+    0xcb8, 0xcb9, 0xcbc, 0xcbd, 0xcbe, 0xcbf, 0xcc0, 0xcc1, 0xcc2, 0xcc3, 0xcc4, 0xcc6, 0xcc7,
+    0xcc8, 0xcca, 0xccb, 0xccc, 0xccd, 0xcd5, 0xcd6, 0xcde, 0xce0, 0xce1, 0xce2, 0xce3, 0xce6,
+    0xce7, 0xce8, 0xce9, 0xcea, 0xceb, 0xcec, 0xced, 0xcee, 0xcef, 0xcf1, 0xcf2, 0xd00, 0xd01,
+    0xd02, 0xd03, 0xd04, 0xd05, 0xd06, 0xd07, 0xd08, 0xd09, 0xd0a, 0xd0b, 0xd0c, 0xd0e, 0xd0f,
+    0xd10, 0xd12, 0xd13, 0xd14, 0xd15, 0xd16, 0xd17, 0xd18, 0xd19, 0xd1a, 0xd1b, 0xd1c, 0xd1d,
+    0xd1e, 0xd1f, 0xd20, 0xd21, 0xd22, 0xd23, 0xd24, 0xd25, 0xd26, 0xd27, 0xd28, 0xd29, 0xd2a,
+    0xd2b, 0xd2c, 0xd2d, 0xd2e, 0xd2f, 0xd30, 0xd31, 0xd32, 0xd33, 0xd34, 0xd35, 0xd36, 0xd37,
+    0xd38, 0xd39, 0xd3a, 0xd3b, 0xd3c, 0xd3d, 0xd3e, 0xd3f, 0xd40, 0xd41, 0xd42, 0xd43, 0xd44,
+    0xd46, 0xd47, 0xd48, 0xd4a, 0xd4b, 0xd4c, 0xd4d, 0xd4e, 0xd4f, 0xd54, 0xd55, 0xd56, 0xd57,
+    0xd58, 0xd59, 0xd5a, 0xd5b, 0xd5c, 0xd5d, 0xd5e, 0xd5f, 0xd60, 0xd61, 0xd62, 0xd63, 0xd66,
+    0xd67, 0xd68, 0xd69, 0xd6a, 0xd6b, 0xd6c, 0xd6d, 0xd6e, 0xd6f, 0xd70, 0xd71, 0xd72, 0xd73,
+    0xd74, 0xd75, 0xd76, 0xd77, 0xd78, 0xd79, 0xd7a, 0xd7b, 0xd7c, 0xd7d, 0xd7e, 0xd7f, 0xd81,
+    0xd82, 0xd83, 0xd85, 0xd86, 0xd87, 0xd88, 0xd89, 0xd8a, 0xd8b, 0xd8c, 0xd8d, 0xd8e, 0xd8f,
+    0xd90, 0xd91, 0xd92, 0xd93, 0xd94, 0xd95, 0xd96, 0xd9a, 0xd9b, 0xd9c, 0xd9d, 0xd9e, 0xd9f,
+    0xda0, 0xda1, 0xda2, 0xda3, 0xda4, 0xda5, 0xda6, 0xda7, 0xda8, 0xda9, 0xdaa, 0xdab, 0xdac,
+    0xdad, 0xdae, 0xdaf, 0xdb0, 0xdb1, 0xdb3, 0xdb4, 0xdb5, 0xdb6, 0xdb7, 0xdb8, 0xdb9, 0xdba,
+    0xdbb, 0xdbd, 0xdc0, 0xdc1, 0xdc2, 0xdc3, 0xdc4, 0xdc5, 0xdc6, 0xdca, 0xdcf, 0xdd0, 0xdd1,
+    0xdd2, 0xdd3, 0xdd4, 0xdd6, 0xdd8, 0xdd9, 0xdda, 0xddb, 0xddc, 0xddd, 0xdde, 0xddf, 0xde6,
+    0xde7, 0xde8, 0xde9, 0xdea, 0xdeb, 0xdec, 0xded, 0xdee, 0xdef, 0xdf2, 0xdf3, 0xdf4, 0xe01,
+    0xe02, 0xe03, 0xe04, 0xe05, 0xe06, 0xe07, 0xe08, 0xe09, 0xe0a, 0xe0b, 0xe0c, 0xe0d, 0xe0e,
+    0xe0f, 0xe10, 0xe11, 0xe12, 0xe13, 0xe14, 0xe15, 0xe16, 0xe17, 0xe18, 0xe19, 0xe1a, 0xe1b,
+    0xe1c, 0xe1d, 0xe1e, 0xe1f, 0xe20, 0xe21, 0xe22, 0xe23, 0xe24, 0xe25, 0xe26, 0xe27, 0xe28,
+    0xe29, 0xe2a, 0xe2b, 0xe2c, 0xe2d, 0xe2e, 0xe2f, 0xe30, 0xe31, 0xe32, 0xe33, 0xe34, 0xe35,
+    0xe36, 0xe37, 0xe38, 0xe39, 0xe3a, 0xe3f, 0xe40, 0xe41, 0xe42, 0xe43, 0xe44, 0xe45, 0xe46,
+    0xe47, 0xe48, 0xe49, 0xe4a, 0xe4b, 0xe4c, 0xe4d, 0xe4e, 0xe4f, 0xe50, 0xe51, 0xe52, 0xe53,
+    0xe54, 0xe55, 0xe56, 0xe57, 0xe58, 0xe59, 0xe5a, 0xe5b, 0xe81, 0xe82, 0xe84, 0xe86, 0xe87,
+    0xe88, 0xe89, 0xe8a, 0xe8c, 0xe8d, 0xe8e, 0xe8f, 0xe90, 0xe91, 0xe92, 0xe93, 0xe94, 0xe95,
+    0xe96, 0xe97, 0xe98, 0xe99, 0xe9a, 0xe9b, 0xe9c, 0xe9d, 0xe9e, 0xe9f, 0xea0, 0xea1, 0xea2,
+    0xea3, 0xea5, 0xea7, 0xea8, 0xea9, 0xeaa, 0xeab, 0xeac, 0xead, 0xeae, 0xeaf, 0xeb0, 0xeb1,
+    0xeb2, 0xeb3, 0xeb4, 0xeb5, 0xeb6, 0xeb7, 0xeb8, 0xeb9, 0xeba, 0xebb, 0xebc, 0xebd, 0xec0,
+    0xec1, 0xec2, 0xec3, 0xec4, 0xec6, 0xec8, 0xec9, 0xeca, 0xecb, 0xecc, 0xecd, 0xed0, 0xed1,
+    0xed2, 0xed3, 0xed4, 0xed5, 0xed6, 0xed7, 0xed8, 0xed9, 0xedc, 0xedd, 0xede, 0xedf, 0xf00,
+    0xf01, 0xf02, 0xf03, 0xf04, 0xf05, 0xf06, 0xf07, 0xf08, 0xf09, 0xf0a, 0xf0b, 0xf0c, 0xf0d,
+    0xf0e, 0xf0f, 0xf10, 0xf11, 0xf12, 0xf13, 0xf14, 0xf15, 0xf16, 0xf17, 0xf18, 0xf19, 0xf1a,
+    0xf1b, 0xf1c, 0xf1d, 0xf1e, 0xf1f, 0xf20, 0xf21, 0xf22, 0xf23, 0xf24, 0xf25, 0xf26, 0xf27,
+    0xf28, 0xf29, 0xf2a, 0xf2b, 0xf2c, 0xf2d, 0xf2e, 0xf2f, 0xf30, 0xf31, 0xf32, 0xf33, 0xf34,
+    0xf35, 0xf36, 0xf37, 0xf38, 0xf39, 0xf3a, 0xf3b, 0xf3c, 0xf3d, 0xf3e, 0xf3f, 0xf40, 0xf41,
+    0xf42, 0xf43, 0xf44, 0xf45, 0xf46, 0xf47, 0xf49, 0xf4a, 0xf4b, 0xf4c, 0xf4d, 0xf4e, 0xf4f,
+    0xf50, 0xf51, 0xf52, 0xf53, 0xf54, 0xf55, 0xf56, 0xf57, 0xf58, 0xf59, 0xf5a, 0xf5b, 0xf5c,
+    0xf5d, 0xf5e, 0xf5f, 0xf60, 0xf61, 0xf62, 0xf63, 0xf64, 0xf65, 0xf66, 0xf67, 0xf68, 0xf69,
+    0xf6a, 0xf6b, 0xf6c, 0xf71, 0xf72, 0xf73, 0xf74, 0xf75, 0xf76, 0xf77, 0xf78, 0xf79, 0xf7a,
+    0xf7b, 0xf7c, 0xf7d, 0xf7e, 0xf7f, 0xf80, 0xf81, 0xf82, 0xf83, 0xf84, 0xf85, 0xf86, 0xf87,
+    0xf88, 0xf89, 0xf8a, 0xf8b, 0xf8c, 0xf8d, 0xf8e, 0xf8f, 0xf90, 0xf91, 0xf92, 0xf93, 0xf94,
+    0xf95, 0xf96, 0xf97, 0xf99, 0xf9a, 0xf9b, 0xf9c, 0xf9d, 0xf9e, 0xf9f, 0xfa0, 0xfa1, 0xfa2,
+    0xfa3, 0xfa4, 0xfa5, 0xfa6, 0xfa7, 0xfa8, 0xfa9, 0xfaa, 0xfab, 0xfac, 0xfad, 0xfae, 0xfaf,
+    0xfb0, 0xfb1, 0xfb2, 0xfb3, 0xfb4, 0xfb5, 0xfb6, 0xfb7, 0xfb8, 0xfb9, 0xfba, 0xfbb, 0xfbc,
+    0xfbe, 0xfbf, 0xfc0, 0xfc1, 0xfc2, 0xfc3, 0xfc4, 0xfc5, 0xfc6, 0xfc7, 0xfc8, 0xfc9, 0xfca,
+    0xfcb, 0xfcc, 0xfce, 0xfcf, 0xfd0, 0xfd1, 0xfd2, 0xfd3, 0xfd4, 0xfd5, 0xfd6, 0xfd7, 0xfd8,
+    0xfd9, 0xfda, 0x1000, 0x1001, 0x1002, 0x1003, 0x1004, 0x1005, 0x1006, 0x1007, 0x1008, 0x1009,
+    0x100a, 0x100b, 0x100c, 0x100d, 0x100e, 0x100f, 0x1010, 0x1011, 0x1012, 0x1013, 0x1014, 0x1015,
+    0x1016, 0x1017, 0x1018, 0x1019, 0x101a, 0x101b, 0x101c, 0x101d, 0x101e, 0x101f, 0x1020, 0x1021,
+    0x1022, 0x1023, 0x1024, 0x1025, 0x1026, 0x1027, 0x1028, 0x1029, 0x102a, 0x102b, 0x102c, 0x102d,
+    0x102e, 0x102f, 0x1030, 0x1031, 0x1032, 0x1033, 0x1034, 0x1035, 0x1036, 0x1037, 0x1038, 0x1039,
+    0x103a, 0x103b, 0x103c, 0x103d, 0x103e, 0x103f, 0x1040, 0x1041, 0x1042, 0x1043, 0x1044, 0x1045,
+    0x1046, 0x1047, 0x1048, 0x1049, 0x104a, 0x104b, 0x104c, 0x104d, 0x104e, 0x104f, 0x1050, 0x1051,
+    0x1052, 0x1053, 0x1054, 0x1055, 0x1056, 0x1057, 0x1058, 0x1059, 0x105a, 0x105b, 0x105c, 0x105d,
+    0x105e, 0x105f, 0x1060, 0x1061, 0x1062, 0x1063, 0x1064, 0x1065, 0x1066, 0x1067, 0x1068, 0x1069,
+    0x106a, 0x106b, 0x106c, 0x106d, 0x106e, 0x106f, 0x1070, 0x1071, 0x1072, 0x1073, 0x1074, 0x1075,
+    0x1076, 0x1077, 0x1078, 0x1079, 0x107a, 0x107b, 0x107c, 0x107d, 0x107e, 0x107f, 0x1080, 0x1081,
+    0x1082, 0x1083, 0x1084, 0x1085, 0x1086, 0x1087, 0x1088, 0x1089, 0x108a, 0x108b, 0x108c, 0x108d,
+    0x108e, 0x108f, 0x1090, 0x1091, 0x1092, 0x1093, 0x1094, 0x1095, 0x1096, 0x1097, 0x1098, 0x1099,
+    0x109a, 0x109b, 0x109c, 0x109d, 0x109e, 0x109f, 0x10a0, 0x10a1, 0x10a2, 0x10a3, 0x10a4, 0x10a5,
+    0x10a6, 0x10a7, 0x10a8, 0x10a9, 0x10aa, 0x10ab, 0x10ac, 0x10ad, 0x10ae, 0x10af, 0x10b0, 0x10b1,
+    0x10b2, 0x10b3, 0x10b4, 0x10b5, 0x10b6, 0x10b7, 0x10b8, 0x10b9, 0x10ba, 0x10bb, 0x10bc, 0x10bd,
+    0x10be, 0x10bf, 0x10c0, 0x10c1, 0x10c2, 0x10c3, 0x10c4, 0x10c5, 0x10c7, 0x10cd, 0x10d0, 0x10d1,
+    0x10d2, 0x10d3, 0x10d4, 0x10d5, 0x10d6, 0x10d7, 0x10d8, 0x10d9, 0x10da, 0x10db, 0x10dc, 0x10dd,
+    0x10de, 0x10df, 0x10e0, 0x10e1, 0x10e2, 0x10e3, 0x10e4, 0x10e5, 0x10e6, 0x10e7, 0x10e8, 0x10e9,
+    0x10ea, 0x10eb, 0x10ec, 0x10ed, 0x10ee, 0x10ef, 0x10f0, 0x10f1, 0x10f2, 0x10f3, 0x10f4, 0x10f5,
+    0x10f6, 0x10f7, 0x10f8, 0x10f9, 0x10fa, 0x10fb, 0x10fc, 0x10fd, 0x10fe, 0x10ff, 0x1100, 0x1101,
+    0x1102, 0x1103, 0x1104, 0x1105, 0x1106, 0x1107, 0x1108, 0x1109, 0x110a, 0x110b, 0x110c, 0x110d,
+    0x110e, 0x110f, 0x1110, 0x1111, 0x1112, 0x1113, 0x1114, 0x1115, 0x1116, 0x1117, 0x1118, 0x1119,
+    0x111a, 0x111b, 0x111c, 0x111d, 0x111e, 0x111f, 0x1120, 0x1121, 0x1122, 0x1123, 0x1124, 0x1125,
+    0x1126, 0x1127, 0x1128, 0x1129, 0x112a, 0x112b, 0x112c, 0x112d, 0x112e, 0x112f, 0x1130, 0x1131,
+    0x1132, 0x1133, 0x1134, 0x1135, 0x1136, 0x1137, 0x1138, 0x1139, 0x113a, 0x113b, 0x113c, 0x113d,
+    0x113e, 0x113f, 0x1140, 0x1141, 0x1142, 0x1143, 0x1144, 0x1145, 0x1146, 0x1147, 0x1148, 0x1149,
+    0x114a, 0x114b, 0x114c, 0x114d, 0x114e, 0x114f, 0x1150, 0x1151, 0x1152, 0x1153, 0x1154, 0x1155,
+    0x1156, 0x1157, 0x1158, 0x1159, 0x115a, 0x115b, 0x115c, 0x115d, 0x115e, 0x115f, 0x1160, 0x1161,
+    0x1162, 0x1163, 0x1164, 0x1165, 0x1166, 0x1167, 0x1168, 0x1169, 0x116a, 0x116b, 0x116c, 0x116d,
+    0x116e, 0x116f, 0x1170, 0x1171, 0x1172, 0x1173, 0x1174, 0x1175, 0x1176, 0x1177, 0x1178, 0x1179,
+    0x117a, 0x117b, 0x117c, 0x117d, 0x117e, 0x117f, 0x1180, 0x1181, 0x1182, 0x1183, 0x1184, 0x1185,
+    0x1186, 0x1187, 0x1188, 0x1189, 0x118a, 0x118b, 0x118c, 0x118d, 0x118e, 0x118f, 0x1190, 0x1191,
+    0x1192, 0x1193, 0x1194, 0x1195, 0x1196, 0x1197, 0x1198, 0x1199, 0x119a, 0x119b, 0x119c, 0x119d,
+    0x119e, 0x119f, 0x11a0, 0x11a1, 0x11a2, 0x11a3, 0x11a4, 0x11a5, 0x11a6, 0x11a7, 0x11a8, 0x11a9,
+    0x11aa, 0x11ab, 0x11ac, 0x11ad, 0x11ae, 0x11af, 0x11b0, 0x11b1, 0x11b2, 0x11b3, 0x11b4, 0x11b5,
+    0x11b6, 0x11b7, 0x11b8, 0x11b9, 0x11ba, 0x11bb, 0x11bc, 0x11bd, 0x11be, 0x11bf, 0x11c0, 0x11c1,
+    0x11c2, 0x11c3, 0x11c4, 0x11c5, 0x11c6, 0x11c7, 0x11c8, 0x11c9, 0x11ca, 0x11cb, 0x11cc, 0x11cd,
+    0x11ce, 0x11cf, 0x11d0, 0x11d1, 0x11d2, 0x11d3, 0x11d4, 0x11d5, 0x11d6, 0x11d7, 0x11d8, 0x11d9,
+    0x11da, 0x11db, 0x11dc, 0x11dd, 0x11de, 0x11df, 0x11e0, 0x11e1, 0x11e2, 0x11e3, 0x11e4, 0x11e5,
+    0x11e6, 0x11e7, 0x11e8, 0x11e9, 0x11ea, 0x11eb, 0x11ec, 0x11ed, 0x11ee, 0x11ef, 0x11f0, 0x11f1,
+    0x11f2, 0x11f3, 0x11f4, 0x11f5, 0x11f6, 0x11f7, 0x11f8, 0x11f9, 0x11fa, 0x11fb, 0x11fc, 0x11fd,
+    0x11fe, 0x11ff, 0x1200, 0x1201, 0x1202, 0x1203, 0x1204, 0x1205, 0x1206, 0x1207, 0x1208, 0x1209,
+    0x120a, 0x120b, 0x120c, 0x120d, 0x120e, 0x120f, 0x1210, 0x1211, 0x1212, 0x1213, 0x1214, 0x1215,
+    0x1216, 0x1217, 0x1218, 0x1219, 0x121a, 0x121b, 0x121c, 0x121d, 0x121e, 0x121f, 0x1220, 0x1221,
+    0x1222, 0x1223, 0x1224, 0x1225, 0x1226, 0x1227, 0x1228, 0x1229, 0x122a, 0x122b, 0x122c, 0x122d,
+    0x122e, 0x122f, 0x1230, 0x1231, 0x1232, 0x1233, 0x1234, 0x1235, 0x1236, 0x1237, 0x1238, 0x1239,
+    0x123a, 0x123b, 0x123c, 0x123d, 0x123e, 0x123f, 0x1240, 0x1241, 0x1242, 0x1243, 0x1244, 0x1245,
+    0x1246, 0x1247, 0x1248, 0x124a, 0x124b, 0x124c, 0x124d, 0x1250, 0x1251, 0x1252, 0x1253, 0x1254,
+    0x1255, 0x1256, 0x1258, 0x125a, 0x125b, 0x125c, 0x125d, 0x1260, 0x1261, 0x1262, 0x1263, 0x1264,
+    0x1265, 0x1266, 0x1267, 0x1268, 0x1269, 0x126a, 0x126b, 0x126c, 0x126d, 0x126e, 0x126f, 0x1270,
+    0x1271, 0x1272, 0x1273, 0x1274, 0x1275, 0x1276, 0x1277, 0x1278, 0x1279, 0x127a, 0x127b, 0x127c,
+    0x127d, 0x127e, 0x127f, 0x1280, 0x1281, 0x1282, 0x1283, 0x1284, 0x1285, 0x1286, 0x1287, 0x1288,
+    0x128a, 0x128b, 0x128c, 0x128d, 0x1290, 0x1291, 0x1292, 0x1293, 0x1294, 0x1295, 0x1296, 0x1297,
+    0x1298, 0x1299, 0x129a, 0x129b, 0x129c, 0x129d, 0x129e, 0x129f, 0x12a0, 0x12a1, 0x12a2, 0x12a3,
+    0x12a4, 0x12a5, 0x12a6, 0x12a7, 0x12a8, 0x12a9, 0x12aa, 0x12ab, 0x12ac, 0x12ad, 0x12ae, 0x12af,
+    0x12b0, 0x12b2, 0x12b3, 0x12b4, 0x12b5, 0x12b8, 0x12b9, 0x12ba, 0x12bb, 0x12bc, 0x12bd, 0x12be,
+    0x12c0, 0x12c2, 0x12c3, 0x12c4, 0x12c5, 0x12c8, 0x12c9, 0x12ca, 0x12cb, 0x12cc, 0x12cd, 0x12ce,
+    0x12cf, 0x12d0, 0x12d1, 0x12d2, 0x12d3, 0x12d4, 0x12d5, 0x12d6, 0x12d8, 0x12d9, 0x12da, 0x12db,
+    0x12dc, 0x12dd, 0x12de, 0x12df, 0x12e0, 0x12e1, 0x12e2, 0x12e3, 0x12e4, 0x12e5, 0x12e6, 0x12e7,
+    0x12e8, 0x12e9, 0x12ea, 0x12eb, 0x12ec, 0x12ed, 0x12ee, 0x12ef, 0x12f0, 0x12f1, 0x12f2, 0x12f3,
+    0x12f4, 0x12f5, 0x12f6, 0x12f7, 0x12f8, 0x12f9, 0x12fa, 0x12fb, 0x12fc, 0x12fd, 0x12fe, 0x12ff,
+    0x1300, 0x1301, 0x1302, 0x1303, 0x1304, 0x1305, 0x1306, 0x1307, 0x1308, 0x1309, 0x130a, 0x130b,
+    0x130c, 0x130d, 0x130e, 0x130f, 0x1310, 0x1312, 0x1313, 0x1314, 0x1315, 0x1318, 0x1319, 0x131a,
+    0x131b, 0x131c, 0x131d, 0x131e, 0x131f, 0x1320, 0x1321, 0x1322, 0x1323, 0x1324, 0x1325, 0x1326,
+    0x1327, 0x1328, 0x1329, 0x132a, 0x132b, 0x132c, 0x132d, 0x132e, 0x132f, 0x1330, 0x1331, 0x1332,
+    0x1333, 0x1334, 0x1335, 0x1336, 0x1337, 0x1338, 0x1339, 0x133a, 0x133b, 0x133c, 0x133d, 0x133e,
+    0x133f, 0x1340, 0x1341, 0x1342, 0x1343, 0x1344, 0x1345, 0x1346, 0x1347, 0x1348, 0x1349, 0x134a,
+    0x134b, 0x134c, 0x134d, 0x134e, 0x134f, 0x1350, 0x1351, 0x1352, 0x1353, 0x1354, 0x1355, 0x1356,
+    0x1357, 0x1358, 0x1359, 0x135a, 0x135d, 0x135e, 0x135f, 0x1360, 0x1361, 0x1362, 0x1363, 0x1364,
+    0x1365, 0x1366, 0x1367, 0x1368, 0x1369, 0x136a, 0x136b, 0x136c, 0x136d, 0x136e, 0x136f, 0x1370,
+    0x1371, 0x1372, 0x1373, 0x1374, 0x1375, 0x1376, 0x1377, 0x1378, 0x1379, 0x137a, 0x137b, 0x137c,
+    0x1380, 0x1381, 0x1382, 0x1383, 0x1384, 0x1385, 0x1386, 0x1387, 0x1388, 0x1389, 0x138a, 0x138b,
+    0x138c, 0x138d, 0x138e, 0x138f, 0x1390, 0x1391, 0x1392, 0x1393, 0x1394, 0x1395, 0x1396, 0x1397,
+    0x1398, 0x1399, 0x13a0, 0x13a1, 0x13a2, 0x13a3, 0x13a4, 0x13a5, 0x13a6, 0x13a7, 0x13a8, 0x13a9,
+    0x13aa, 0x13ab, 0x13ac, 0x13ad, 0x13ae, 0x13af, 0x13b0, 0x13b1, 0x13b2, 0x13b3, 0x13b4, 0x13b5,
+    0x13b6, 0x13b7, 0x13b8, 0x13b9, 0x13ba, 0x13bb, 0x13bc, 0x13bd, 0x13be, 0x13bf, 0x13c0, 0x13c1,
+    0x13c2, 0x13c3, 0x13c4, 0x13c5, 0x13c6, 0x13c7, 0x13c8, 0x13c9, 0x13ca, 0x13cb, 0x13cc, 0x13cd,
+    0x13ce, 0x13cf, 0x13d0, 0x13d1, 0x13d2, 0x13d3, 0x13d4, 0x13d5, 0x13d6, 0x13d7, 0x13d8, 0x13d9,
+    0x13da, 0x13db, 0x13dc, 0x13dd, 0x13de, 0x13df, 0x13e0, 0x13e1, 0x13e2, 0x13e3, 0x13e4, 0x13e5,
+    0x13e6, 0x13e7, 0x13e8, 0x13e9, 0x13ea, 0x13eb, 0x13ec, 0x13ed, 0x13ee, 0x13ef, 0x13f0, 0x13f1,
+    0x13f2, 0x13f3, 0x13f4, 0x13f5, 0x13f8, 0x13f9, 0x13fa, 0x13fb, 0x13fc, 0x13fd, 0x1400, 0x1401,
+    0x1402, 0x1403, 0x1404, 0x1405, 0x1406, 0x1407, 0x1408, 0x1409, 0x140a, 0x140b, 0x140c, 0x140d,
+    0x140e, 0x140f, 0x1410, 0x1411, 0x1412, 0x1413, 0x1414, 0x1415, 0x1416, 0x1417, 0x1418, 0x1419,
+    0x141a, 0x141b, 0x141c, 0x141d, 0x141e, 0x141f, 0x1420, 0x1421, 0x1422, 0x1423, 0x1424, 0x1425,
+    0x1426, 0x1427, 0x1428, 0x1429, 0x142a, 0x142b, 0x142c, 0x142d, 0x142e, 0x142f, 0x1430, 0x1431,
+    0x1432, 0x1433, 0x1434, 0x1435, 0x1436, 0x1437, 0x1438, 0x1439, 0x143a, 0x143b, 0x143c, 0x143d,
+    0x143e, 0x143f, 0x1440, 0x1441, 0x1442, 0x1443, 0x1444, 0x1445, 0x1446, 0x1447, 0x1448, 0x1449,
+    0x144a, 0x144b, 0x144c, 0x144d, 0x144e, 0x144f, 0x1450, 0x1451, 0x1452, 0x1453, 0x1454, 0x1455,
+    0x1456, 0x1457, 0x1458, 0x1459, 0x145a, 0x145b, 0x145c, 0x145d, 0x145e, 0x145f, 0x1460, 0x1461,
+    0x1462, 0x1463, 0x1464, 0x1465, 0x1466, 0x1467, 0x1468, 0x1469, 0x146a, 0x146b, 0x146c, 0x146d,
+    0x146e, 0x146f, 0x1470, 0x1471, 0x1472, 0x1473, 0x1474, 0x1475, 0x1476, 0x1477, 0x1478, 0x1479,
+    0x147a, 0x147b, 0x147c, 0x147d, 0x147e, 0x147f, 0x1480, 0x1481, 0x1482, 0x1483, 0x1484, 0x1485,
+    0x1486, 0x1487, 0x1488, 0x1489, 0x148a, 0x148b, 0x148c, 0x148d, 0x148e, 0x148f, 0x1490, 0x1491,
+    0x1492, 0x1493, 0x1494, 0x1495, 0x1496, 0x1497, 0x1498, 0x1499, 0x149a, 0x149b, 0x149c, 0x149d,
+    0x149e, 0x149f, 0x14a0, 0x14a1, 0x14a2, 0x14a3, 0x14a4, 0x14a5, 0x14a6, 0x14a7, 0x14a8, 0x14a9,
+    0x14aa, 0x14ab, 0x14ac, 0x14ad, 0x14ae, 0x14af, 0x14b0, 0x14b1, 0x14b2, 0x14b3, 0x14b4, 0x14b5,
+    0x14b6, 0x14b7, 0x14b8, 0x14b9, 0x14ba, 0x14bb, 0x14bc, 0x14bd, 0x14be, 0x14bf, 0x14c0, 0x14c1,
+    0x14c2, 0x14c3, 0x14c4, 0x14c5, 0x14c6, 0x14c7, 0x14c8, 0x14c9, 0x14ca, 0x14cb, 0x14cc, 0x14cd,
+    0x14ce, 0x14cf, 0x14d0, 0x14d1, 0x14d2, 0x14d3, 0x14d4, 0x14d5, 0x14d6, 0x14d7, 0x14d8, 0x14d9,
+    0x14da, 0x14db, 0x14dc, 0x14dd, 0x14de, 0x14df, 0x14e0, 0x14e1, 0x14e2, 0x14e3, 0x14e4, 0x14e5,
+    0x14e6, 0x14e7, 0x14e8, 0x14e9, 0x14ea, 0x14eb, 0x14ec, 0x14ed, 0x14ee, 0x14ef, 0x14f0, 0x14f1,
+    0x14f2, 0x14f3, 0x14f4, 0x14f5, 0x14f6, 0x14f7, 0x14f8, 0x14f9, 0x14fa, 0x14fb, 0x14fc, 0x14fd,
+    0x14fe, 0x14ff, 0x1500, 0x1501, 0x1502, 0x1503, 0x1504, 0x1505, 0x1506, 0x1507, 0x1508, 0x1509,
+    0x150a, 0x150b, 0x150c, 0x150d, 0x150e, 0x150f, 0x1510, 0x1511, 0x1512, 0x1513, 0x1514, 0x1515,
+    0x1516, 0x1517, 0x1518, 0x1519, 0x151a, 0x151b, 0x151c, 0x151d, 0x151e, 0x151f, 0x1520, 0x1521,
+    0x1522, 0x1523, 0x1524, 0x1525, 0x1526, 0x1527, 0x1528, 0x1529, 0x152a, 0x152b, 0x152c, 0x152d,
+    0x152e, 0x152f, 0x1530, 0x1531, 0x1532, 0x1533, 0x1534, 0x1535, 0x1536, 0x1537, 0x1538, 0x1539,
+    0x153a, 0x153b, 0x153c, 0x153d, 0x153e, 0x153f, 0x1540, 0x1541, 0x1542, 0x1543, 0x1544, 0x1545,
+    0x1546, 0x1547, 0x1548, 0x1549, 0x154a, 0x154b, 0x154c, 0x154d, 0x154e, 0x154f, 0x1550, 0x1551,
+    0x1552, 0x1553, 0x1554, 0x1555, 0x1556, 0x1557, 0x1558, 0x1559, 0x155a, 0x155b, 0x155c, 0x155d,
+    0x155e, 0x155f, 0x1560, 0x1561, 0x1562, 0x1563, 0x1564, 0x1565, 0x1566, 0x1567, 0x1568, 0x1569,
+    0x156a, 0x156b, 0x156c, 0x156d, 0x156e, 0x156f, 0x1570, 0x1571, 0x1572, 0x1573, 0x1574, 0x1575,
+    0x1576, 0x1577, 0x1578, 0x1579, 0x157a, 0x157b, 0x157c, 0x157d, 0x157e, 0x157f, 0x1580, 0x1581,
+    0x1582, 0x1583, 0x1584, 0x1585, 0x1586, 0x1587, 0x1588, 0x1589, 0x158a, 0x158b, 0x158c, 0x158d,
+    0x158e, 0x158f, 0x1590, 0x1591, 0x1592, 0x1593, 0x1594, 0x1595, 0x1596, 0x1597, 0x1598, 0x1599,
+    0x159a, 0x159b, 0x159c, 0x159d, 0x159e, 0x159f, 0x15a0, 0x15a1, 0x15a2, 0x15a3, 0x15a4, 0x15a5,
+    0x15a6, 0x15a7, 0x15a8, 0x15a9, 0x15aa, 0x15ab, 0x15ac, 0x15ad, 0x15ae, 0x15af, 0x15b0, 0x15b1,
+    0x15b2, 0x15b3, 0x15b4, 0x15b5, 0x15b6, 0x15b7, 0x15b8, 0x15b9, 0x15ba, 0x15bb, 0x15bc, 0x15bd,
+    0x15be, 0x15bf, 0x15c0, 0x15c1, 0x15c2, 0x15c3, 0x15c4, 0x15c5, 0x15c6, 0x15c7, 0x15c8, 0x15c9,
+    0x15ca, 0x15cb, 0x15cc, 0x15cd, 0x15ce, 0x15cf, 0x15d0, 0x15d1, 0x15d2, 0x15d3, 0x15d4, 0x15d5,
+    0x15d6, 0x15d7, 0x15d8, 0x15d9, 0x15da, 0x15db, 0x15dc, 0x15dd, 0x15de, 0x15df, 0x15e0, 0x15e1,
+    0x15e2, 0x15e3, 0x15e4, 0x15e5, 0x15e6, 0x15e7, 0x15e8, 0x15e9, 0x15ea, 0x15eb, 0x15ec, 0x15ed,
+    0x15ee, 0x15ef, 0x15f0, 0x15f1, 0x15f2, 0x15f3, 0x15f4, 0x15f5, 0x15f6, 0x15f7, 0x15f8, 0x15f9,
+    0x15fa, 0x15fb, 0x15fc, 0x15fd, 0x15fe, 0x15ff, 0x1600, 0x1601, 0x1602, 0x1603, 0x1604, 0x1605,
+    0x1606, 0x1607, 0x1608, 0x1609, 0x160a, 0x160b, 0x160c, 0x160d, 0x160e, 0x160f, 0x1610, 0x1611,
+    0x1612, 0x1613, 0x1614, 0x1615, 0x1616, 0x1617, 0x1618, 0x1619, 0x161a, 0x161b, 0x161c, 0x161d,
+    0x161e, 0x161f, 0x1620, 0x1621, 0x1622, 0x1623, 0x1624, 0x1625, 0x1626, 0x1627, 0x1628, 0x1629,
+    0x162a, 0x162b, 0x162c, 0x162d, 0x162e, 0x162f, 0x1630, 0x1631, 0x1632, 0x1633, 0x1634, 0x1635,
+    0x1636, 0x1637, 0x1638, 0x1639, 0x163a, 0x163b, 0x163c, 0x163d, 0x163e, 0x163f, 0x1640, 0x1641,
+    0x1642, 0x1643, 0x1644, 0x1645, 0x1646, 0x1647, 0x1648, 0x1649, 0x164a, 0x164b, 0x164c, 0x164d,
+    0x164e, 0x164f, 0x1650, 0x1651, 0x1652, 0x1653, 0x1654, 0x1655, 0x1656, 0x1657, 0x1658, 0x1659,
+    0x165a, 0x165b, 0x165c, 0x165d, 0x165e, 0x165f, 0x1660, 0x1661, 0x1662, 0x1663, 0x1664, 0x1665,
+    0x1666, 0x1667, 0x1668, 0x1669, 0x166a, 0x166b, 0x166c, 0x166d, 0x166e, 0x166f, 0x1670, 0x1671,
+    0x1672, 0x1673, 0x1674, 0x1675, 0x1676, 0x1677, 0x1678, 0x1679, 0x167a, 0x167b, 0x167c, 0x167d,
+    0x167e, 0x167f, 0x1680, 0x1681, 0x1682, 0x1683, 0x1684, 0x1685, 0x1686, 0x1687, 0x1688, 0x1689,
+    0x168a, 0x168b, 0x168c, 0x168d, 0x168e, 0x168f, 0x1690, 0x1691, 0x1692, 0x1693, 0x1694, 0x1695,
+    0x1696, 0x1697, 0x1698, 0x1699, 0x169a, 0x169b, 0x169c, 0x16a0, 0x16a1, 0x16a2, 0x16a3, 0x16a4,
+    0x16a5, 0x16a6, 0x16a7, 0x16a8, 0x16a9, 0x16aa, 0x16ab, 0x16ac, 0x16ad, 0x16ae, 0x16af, 0x16b0,
+    0x16b1, 0x16b2, 0x16b3, 0x16b4, 0x16b5, 0x16b6, 0x16b7, 0x16b8, 0x16b9, 0x16ba, 0x16bb, 0x16bc,
+    0x16bd, 0x16be, 0x16bf, 0x16c0, 0x16c1, 0x16c2, 0x16c3, 0x16c4, 0x16c5, 0x16c6, 0x16c7, 0x16c8,
+    0x16c9, 0x16ca, 0x16cb, 0x16cc, 0x16cd, 0x16ce, 0x16cf, 0x16d0, 0x16d1, 0x16d2, 0x16d3, 0x16d4,
+    0x16d5, 0x16d6, 0x16d7, 0x16d8, 0x16d9, 0x16da, 0x16db, 0x16dc, 0x16dd, 0x16de, 0x16df, 0x16e0,
+    0x16e1, 0x16e2, 0x16e3, 0x16e4, 0x16e5, 0x16e6, 0x16e7, 0x16e8, 0x16e9, 0x16ea, 0x16eb, 0x16ec,
+    0x16ed, 0x16ee, 0x16ef, 0x16f0, 0x16f1, 0x16f2, 0x16f3, 0x16f4, 0x16f5, 0x16f6, 0x16f7, 0x16f8,
+    0x1700, 0x1701, 0x1702, 0x1703, 0x1704, 0x1705, 0x1706, 0x1707, 0x1708, 0x1709, 0x170a, 0x170b,
+    0x170c, 0x170e, 0x170f, 0x1710, 0x1711, 0x1712, 0x1713, 0x1714, 0x1720, 0x1721, 0x1722, 0x1723,
+    0x1724, 0x1725, 0x1726, 0x1727, 0x1728, 0x1729, 0x172a, 0x172b, 0x172c, 0x172d, 0x172e, 0x172f,
+    0x1730, 0x1731, 0x1732, 0x1733, 0x1734, 0x1735, 0x1736, 0x1740, 0x1741, 0x1742, 0x1743, 0x1744,
+    0x1745, 0x1746, 0x1747, 0x1748, 0x1749, 0x174a, 0x174b, 0x174c, 0x174d, 0x174e, 0x174f, 0x1750,
+    0x1751, 0x1752, 0x1753, 0x1760, 0x1761, 0x1762, 0x1763, 0x1764, 0x1765, 0x1766, 0x1767, 0x1768,
+    0x1769, 0x176a, 0x176b, 0x176c, 0x176e, 0x176f, 0x1770, 0x1772, 0x1773, 0x1780, 0x1781, 0x1782,
+    0x1783, 0x1784, 0x1785, 0x1786, 0x1787, 0x1788, 0x1789, 0x178a, 0x178b, 0x178c, 0x178d, 0x178e,
+    0x178f, 0x1790, 0x1791, 0x1792, 0x1793, 0x1794, 0x1795, 0x1796, 0x1797, 0x1798, 0x1799, 0x179a,
+    0x179b, 0x179c, 0x179d, 0x179e, 0x179f, 0x17a0, 0x17a1, 0x17a2, 0x17a3, 0x17a4, 0x17a5, 0x17a6,
+    0x17a7, 0x17a8, 0x17a9, 0x17aa, 0x17ab, 0x17ac, 0x17ad, 0x17ae, 0x17af, 0x17b0, 0x17b1, 0x17b2,
+    0x17b3, 0x17b4, 0x17b5, 0x17b6, 0x17b7, 0x17b8, 0x17b9, 0x17ba, 0x17bb, 0x17bc, 0x17bd, 0x17be,
+    0x17bf, 0x17c0, 0x17c1, 0x17c2, 0x17c3, 0x17c4, 0x17c5, 0x17c6, 0x17c7, 0x17c8, 0x17c9, 0x17ca,
+    0x17cb, 0x17cc, 0x17cd, 0x17ce, 0x17cf, 0x17d0, 0x17d1, 0x17d2, 0x17d3, 0x17d4, 0x17d5, 0x17d6,
+    0x17d7, 0x17d8, 0x17d9, 0x17da, 0x17db, 0x17dc, 0x17dd, 0x17e0, 0x17e1, 0x17e2, 0x17e3, 0x17e4,
+    0x17e5, 0x17e6, 0x17e7, 0x17e8, 0x17e9, 0x17f0, 0x17f1, 0x17f2, 0x17f3, 0x17f4, 0x17f5, 0x17f6,
+    0x17f7, 0x17f8, 0x17f9, 0x1800, 0x1801, 0x1802, 0x1803, 0x1804, 0x1805, 0x1806, 0x1807, 0x1808,
+    0x1809, 0x180a, 0x180b, 0x180c, 0x180d, 0x180e, 0x1810, 0x1811, 0x1812, 0x1813, 0x1814, 0x1815,
+    0x1816, 0x1817, 0x1818, 0x1819, 0x1820, 0x1821, 0x1822, 0x1823, 0x1824, 0x1825, 0x1826, 0x1827,
+    0x1828, 0x1829, 0x182a, 0x182b, 0x182c, 0x182d, 0x182e, 0x182f, 0x1830, 0x1831, 0x1832, 0x1833,
+    0x1834, 0x1835, 0x1836, 0x1837, 0x1838, 0x1839, 0x183a, 0x183b, 0x183c, 0x183d, 0x183e, 0x183f,
+    0x1840, 0x1841, 0x1842, 0x1843, 0x1844, 0x1845, 0x1846, 0x1847, 0x1848, 0x1849, 0x184a, 0x184b,
+    0x184c, 0x184d, 0x184e, 0x184f, 0x1850, 0x1851, 0x1852, 0x1853, 0x1854, 0x1855, 0x1856, 0x1857,
+    0x1858, 0x1859, 0x185a, 0x185b, 0x185c, 0x185d, 0x185e, 0x185f, 0x1860, 0x1861, 0x1862, 0x1863,
+    0x1864, 0x1865, 0x1866, 0x1867, 0x1868, 0x1869, 0x186a, 0x186b, 0x186c, 0x186d, 0x186e, 0x186f,
+    0x1870, 0x1871, 0x1872, 0x1873, 0x1874, 0x1875, 0x1876, 0x1877, 0x1878, 0x1880, 0x1881, 0x1882,
+    0x1883, 0x1884, 0x1885, 0x1886, 0x1887, 0x1888, 0x1889, 0x188a, 0x188b, 0x188c, 0x188d, 0x188e,
+    0x188f, 0x1890, 0x1891, 0x1892, 0x1893, 0x1894, 0x1895, 0x1896, 0x1897, 0x1898, 0x1899, 0x189a,
+    0x189b, 0x189c, 0x189d, 0x189e, 0x189f, 0x18a0, 0x18a1, 0x18a2, 0x18a3, 0x18a4, 0x18a5, 0x18a6,
+    0x18a7, 0x18a8, 0x18a9, 0x18aa, 0x18b0, 0x18b1, 0x18b2, 0x18b3, 0x18b4, 0x18b5, 0x18b6, 0x18b7,
+    0x18b8, 0x18b9, 0x18ba, 0x18bb, 0x18bc, 0x18bd, 0x18be, 0x18bf, 0x18c0, 0x18c1, 0x18c2, 0x18c3,
+    0x18c4, 0x18c5, 0x18c6, 0x18c7, 0x18c8, 0x18c9, 0x18ca, 0x18cb, 0x18cc, 0x18cd, 0x18ce, 0x18cf,
+    0x18d0, 0x18d1, 0x18d2, 0x18d3, 0x18d4, 0x18d5, 0x18d6, 0x18d7, 0x18d8, 0x18d9, 0x18da, 0x18db,
+    0x18dc, 0x18dd, 0x18de, 0x18df, 0x18e0, 0x18e1, 0x18e2, 0x18e3, 0x18e4, 0x18e5, 0x18e6, 0x18e7,
+    0x18e8, 0x18e9, 0x18ea, 0x18eb, 0x18ec, 0x18ed, 0x18ee, 0x18ef, 0x18f0, 0x18f1, 0x18f2, 0x18f3,
+    0x18f4, 0x18f5, 0x1900, 0x1901, 0x1902, 0x1903, 0x1904, 0x1905, 0x1906, 0x1907, 0x1908, 0x1909,
+    0x190a, 0x190b, 0x190c, 0x190d, 0x190e, 0x190f, 0x1910, 0x1911, 0x1912, 0x1913, 0x1914, 0x1915,
+    0x1916, 0x1917, 0x1918, 0x1919, 0x191a, 0x191b, 0x191c, 0x191d, 0x191e, 0x1920, 0x1921, 0x1922,
+    0x1923, 0x1924, 0x1925, 0x1926, 0x1927, 0x1928, 0x1929, 0x192a, 0x192b, 0x1930, 0x1931, 0x1932,
+    0x1933, 0x1934, 0x1935, 0x1936, 0x1937, 0x1938, 0x1939, 0x193a, 0x193b, 0x1940, 0x1944, 0x1945,
+    0x1946, 0x1947, 0x1948, 0x1949, 0x194a, 0x194b, 0x194c, 0x194d, 0x194e, 0x194f, 0x1950, 0x1951,
+    0x1952, 0x1953, 0x1954, 0x1955, 0x1956, 0x1957, 0x1958, 0x1959, 0x195a, 0x195b, 0x195c, 0x195d,
+    0x195e, 0x195f, 0x1960, 0x1961, 0x1962, 0x1963, 0x1964, 0x1965, 0x1966, 0x1967, 0x1968, 0x1969,
+    0x196a, 0x196b, 0x196c, 0x196d, 0x1970, 0x1971, 0x1972, 0x1973, 0x1974, 0x1980, 0x1981, 0x1982,
+    0x1983, 0x1984, 0x1985, 0x1986, 0x1987, 0x1988, 0x1989, 0x198a, 0x198b, 0x198c, 0x198d, 0x198e,
+    0x198f, 0x1990, 0x1991, 0x1992, 0x1993, 0x1994, 0x1995, 0x1996, 0x1997, 0x1998, 0x1999, 0x199a,
+    0x199b, 0x199c, 0x199d, 0x199e, 0x199f, 0x19a0, 0x19a1, 0x19a2, 0x19a3, 0x19a4, 0x19a5, 0x19a6,
+    0x19a7, 0x19a8, 0x19a9, 0x19aa, 0x19ab, 0x19b0, 0x19b1, 0x19b2, 0x19b3, 0x19b4, 0x19b5, 0x19b6,
+    0x19b7, 0x19b8, 0x19b9, 0x19ba, 0x19bb, 0x19bc, 0x19bd, 0x19be, 0x19bf, 0x19c0, 0x19c1, 0x19c2,
+    0x19c3, 0x19c4, 0x19c5, 0x19c6, 0x19c7, 0x19c8, 0x19c9, 0x19d0, 0x19d1, 0x19d2, 0x19d3, 0x19d4,
+    0x19d5, 0x19d6, 0x19d7, 0x19d8, 0x19d9, 0x19da, 0x19de, 0x19df, 0x19e0, 0x19e1, 0x19e2, 0x19e3,
+    0x19e4, 0x19e5, 0x19e6, 0x19e7, 0x19e8, 0x19e9, 0x19ea, 0x19eb, 0x19ec, 0x19ed, 0x19ee, 0x19ef,
+    0x19f0, 0x19f1, 0x19f2, 0x19f3, 0x19f4, 0x19f5, 0x19f6, 0x19f7, 0x19f8, 0x19f9, 0x19fa, 0x19fb,
+    0x19fc, 0x19fd, 0x19fe, 0x19ff, 0x1a00, 0x1a01, 0x1a02, 0x1a03, 0x1a04, 0x1a05, 0x1a06, 0x1a07,
+    0x1a08, 0x1a09, 0x1a0a, 0x1a0b, 0x1a0c, 0x1a0d, 0x1a0e, 0x1a0f, 0x1a10, 0x1a11, 0x1a12, 0x1a13,
+    0x1a14, 0x1a15, 0x1a16, 0x1a17, 0x1a18, 0x1a19, 0x1a1a, 0x1a1b, 0x1a1e, 0x1a1f, 0x1a20, 0x1a21,
+    0x1a22, 0x1a23, 0x1a24, 0x1a25, 0x1a26, 0x1a27, 0x1a28, 0x1a29, 0x1a2a, 0x1a2b, 0x1a2c, 0x1a2d,
+    0x1a2e, 0x1a2f, 0x1a30, 0x1a31, 0x1a32, 0x1a33, 0x1a34, 0x1a35, 0x1a36, 0x1a37, 0x1a38, 0x1a39,
+    0x1a3a, 0x1a3b, 0x1a3c, 0x1a3d, 0x1a3e, 0x1a3f, 0x1a40, 0x1a41, 0x1a42, 0x1a43, 0x1a44, 0x1a45,
+    0x1a46, 0x1a47, 0x1a48, 0x1a49, 0x1a4a, 0x1a4b, 0x1a4c, 0x1a4d, 0x1a4e, 0x1a4f, 0x1a50, 0x1a51,
+    0x1a52, 0x1a53, 0x1a54, 0x1a55, 0x1a56, 0x1a57, 0x1a58, 0x1a59, 0x1a5a, 0x1a5b, 0x1a5c, 0x1a5d,
+    0x1a5e, 0x1a60, 0x1a61, 0x1a62, 0x1a63, 0x1a64, 0x1a65, 0x1a66, 0x1a67, 0x1a68, 0x1a69, 0x1a6a,
+    0x1a6b, 0x1a6c, 0x1a6d, 0x1a6e, 0x1a6f, 0x1a70, 0x1a71, 0x1a72, 0x1a73, 0x1a74, 0x1a75, 0x1a76,
+    0x1a77, 0x1a78, 0x1a79, 0x1a7a, 0x1a7b, 0x1a7c, 0x1a7f, 0x1a80, 0x1a81, 0x1a82, 0x1a83, 0x1a84,
+    0x1a85, 0x1a86, 0x1a87, 0x1a88, 0x1a89, 0x1a90, 0x1a91, 0x1a92, 0x1a93, 0x1a94, 0x1a95, 0x1a96,
+    0x1a97, 0x1a98, 0x1a99, 0x1aa0, 0x1aa1, 0x1aa2, 0x1aa3, 0x1aa4, 0x1aa5, 0x1aa6, 0x1aa7, 0x1aa8,
+    0x1aa9, 0x1aaa, 0x1aab, 0x1aac, 0x1aad, 0x1ab0, 0x1ab1, 0x1ab2, 0x1ab3, 0x1ab4, 0x1ab5, 0x1ab6,
+    0x1ab7, 0x1ab8, 0x1ab9, 0x1aba, 0x1abb, 0x1abc, 0x1abd, 0x1abe, 0x1abf, 0x1ac0, 0x1b00, 0x1b01,
+    0x1b02, 0x1b03, 0x1b04, 0x1b05, 0x1b06, 0x1b07, 0x1b08, 0x1b09, 0x1b0a, 0x1b0b, 0x1b0c, 0x1b0d,
+    0x1b0e, 0x1b0f, 0x1b10, 0x1b11, 0x1b12, 0x1b13, 0x1b14, 0x1b15, 0x1b16, 0x1b17, 0x1b18, 0x1b19,
+    0x1b1a, 0x1b1b, 0x1b1c, 0x1b1d, 0x1b1e, 0x1b1f, 0x1b20, 0x1b21, 0x1b22, 0x1b23, 0x1b24, 0x1b25,
+    0x1b26, 0x1b27, 0x1b28, 0x1b29, 0x1b2a, 0x1b2b, 0x1b2c, 0x1b2d, 0x1b2e, 0x1b2f, 0x1b30, 0x1b31,
+    0x1b32, 0x1b33, 0x1b34, 0x1b35, 0x1b36, 0x1b37, 0x1b38, 0x1b39, 0x1b3a, 0x1b3b, 0x1b3c, 0x1b3d,
+    0x1b3e, 0x1b3f, 0x1b40, 0x1b41, 0x1b42, 0x1b43, 0x1b44, 0x1b45, 0x1b46, 0x1b47, 0x1b48, 0x1b49,
+    0x1b4a, 0x1b4b, 0x1b50, 0x1b51, 0x1b52, 0x1b53, 0x1b54, 0x1b55, 0x1b56, 0x1b57, 0x1b58, 0x1b59,
+    0x1b5a, 0x1b5b, 0x1b5c, 0x1b5d, 0x1b5e, 0x1b5f, 0x1b60, 0x1b61, 0x1b62, 0x1b63, 0x1b64, 0x1b65,
+    0x1b66, 0x1b67, 0x1b68, 0x1b69, 0x1b6a, 0x1b6b, 0x1b6c, 0x1b6d, 0x1b6e, 0x1b6f, 0x1b70, 0x1b71,
+    0x1b72, 0x1b73, 0x1b74, 0x1b75, 0x1b76, 0x1b77, 0x1b78, 0x1b79, 0x1b7a, 0x1b7b, 0x1b7c, 0x1b80,
+    0x1b81, 0x1b82, 0x1b83, 0x1b84, 0x1b85, 0x1b86, 0x1b87, 0x1b88, 0x1b89, 0x1b8a, 0x1b8b, 0x1b8c,
+    0x1b8d, 0x1b8e, 0x1b8f, 0x1b90, 0x1b91, 0x1b92, 0x1b93, 0x1b94, 0x1b95, 0x1b96, 0x1b97, 0x1b98,
+    0x1b99, 0x1b9a, 0x1b9b, 0x1b9c, 0x1b9d, 0x1b9e, 0x1b9f, 0x1ba0, 0x1ba1, 0x1ba2, 0x1ba3, 0x1ba4,
+    0x1ba5, 0x1ba6, 0x1ba7, 0x1ba8, 0x1ba9, 0x1baa, 0x1bab, 0x1bac, 0x1bad, 0x1bae, 0x1baf, 0x1bb0,
+    0x1bb1, 0x1bb2, 0x1bb3, 0x1bb4, 0x1bb5, 0x1bb6, 0x1bb7, 0x1bb8, 0x1bb9, 0x1bba, 0x1bbb, 0x1bbc,
+    0x1bbd, 0x1bbe, 0x1bbf, 0x1bc0, 0x1bc1, 0x1bc2, 0x1bc3, 0x1bc4, 0x1bc5, 0x1bc6, 0x1bc7, 0x1bc8,
+    0x1bc9, 0x1bca, 0x1bcb, 0x1bcc, 0x1bcd, 0x1bce, 0x1bcf, 0x1bd0, 0x1bd1, 0x1bd2, 0x1bd3, 0x1bd4,
+    0x1bd5, 0x1bd6, 0x1bd7, 0x1bd8, 0x1bd9, 0x1bda, 0x1bdb, 0x1bdc, 0x1bdd, 0x1bde, 0x1bdf, 0x1be0,
+    0x1be1, 0x1be2, 0x1be3, 0x1be4, 0x1be5, 0x1be6, 0x1be7, 0x1be8, 0x1be9, 0x1bea, 0x1beb, 0x1bec,
+    0x1bed, 0x1bee, 0x1bef, 0x1bf0, 0x1bf1, 0x1bf2, 0x1bf3, 0x1bfc, 0x1bfd, 0x1bfe, 0x1bff, 0x1c00,
+    0x1c01, 0x1c02, 0x1c03, 0x1c04, 0x1c05, 0x1c06, 0x1c07, 0x1c08, 0x1c09, 0x1c0a, 0x1c0b, 0x1c0c,
+    0x1c0d, 0x1c0e, 0x1c0f, 0x1c10, 0x1c11, 0x1c12, 0x1c13, 0x1c14, 0x1c15, 0x1c16, 0x1c17, 0x1c18,
+    0x1c19, 0x1c1a, 0x1c1b, 0x1c1c, 0x1c1d, 0x1c1e, 0x1c1f, 0x1c20, 0x1c21, 0x1c22, 0x1c23, 0x1c24,
+    0x1c25, 0x1c26, 0x1c27, 0x1c28, 0x1c29, 0x1c2a, 0x1c2b, 0x1c2c, 0x1c2d, 0x1c2e, 0x1c2f, 0x1c30,
+    0x1c31, 0x1c32, 0x1c33, 0x1c34, 0x1c35, 0x1c36, 0x1c37, 0x1c3b, 0x1c3c, 0x1c3d, 0x1c3e, 0x1c3f,
+    0x1c40, 0x1c41, 0x1c42, 0x1c43, 0x1c44, 0x1c45, 0x1c46, 0x1c47, 0x1c48, 0x1c49, 0x1c4d, 0x1c4e,
+    0x1c4f, 0x1c50, 0x1c51, 0x1c52, 0x1c53, 0x1c54, 0x1c55, 0x1c56, 0x1c57, 0x1c58, 0x1c59, 0x1c5a,
+    0x1c5b, 0x1c5c, 0x1c5d, 0x1c5e, 0x1c5f, 0x1c60, 0x1c61, 0x1c62, 0x1c63, 0x1c64, 0x1c65, 0x1c66,
+    0x1c67, 0x1c68, 0x1c69, 0x1c6a, 0x1c6b, 0x1c6c, 0x1c6d, 0x1c6e, 0x1c6f, 0x1c70, 0x1c71, 0x1c72,
+    0x1c73, 0x1c74, 0x1c75, 0x1c76, 0x1c77, 0x1c78, 0x1c79, 0x1c7a, 0x1c7b, 0x1c7c, 0x1c7d, 0x1c7e,
+    0x1c7f, 0x1c80, 0x1c81, 0x1c82, 0x1c83, 0x1c84, 0x1c85, 0x1c86, 0x1c87, 0x1c88, 0x1c90, 0x1c91,
+    0x1c92, 0x1c93, 0x1c94, 0x1c95, 0x1c96, 0x1c97, 0x1c98, 0x1c99, 0x1c9a, 0x1c9b, 0x1c9c, 0x1c9d,
+    0x1c9e, 0x1c9f, 0x1ca0, 0x1ca1, 0x1ca2, 0x1ca3, 0x1ca4, 0x1ca5, 0x1ca6, 0x1ca7, 0x1ca8, 0x1ca9,
+    0x1caa, 0x1cab, 0x1cac, 0x1cad, 0x1cae, 0x1caf, 0x1cb0, 0x1cb1, 0x1cb2, 0x1cb3, 0x1cb4, 0x1cb5,
+    0x1cb6, 0x1cb7, 0x1cb8, 0x1cb9, 0x1cba, 0x1cbd, 0x1cbe, 0x1cbf, 0x1cc0, 0x1cc1, 0x1cc2, 0x1cc3,
+    0x1cc4, 0x1cc5, 0x1cc6, 0x1cc7, 0x1cd0, 0x1cd1, 0x1cd2, 0x1cd3, 0x1cd4, 0x1cd5, 0x1cd6, 0x1cd7,
+    0x1cd8, 0x1cd9, 0x1cda, 0x1cdb, 0x1cdc, 0x1cdd, 0x1cde, 0x1cdf, 0x1ce0, 0x1ce1, 0x1ce2, 0x1ce3,
+    0x1ce4, 0x1ce5, 0x1ce6, 0x1ce7, 0x1ce8, 0x1ce9, 0x1cea, 0x1ceb, 0x1cec, 0x1ced, 0x1cee, 0x1cef,
+    0x1cf0, 0x1cf1, 0x1cf2, 0x1cf3, 0x1cf4, 0x1cf5, 0x1cf6, 0x1cf7, 0x1cf8, 0x1cf9, 0x1cfa, 0x1d00,
+    0x1d01, 0x1d02, 0x1d03, 0x1d04, 0x1d05, 0x1d06, 0x1d07, 0x1d08, 0x1d09, 0x1d0a, 0x1d0b, 0x1d0c,
+    0x1d0d, 0x1d0e, 0x1d0f, 0x1d10, 0x1d11, 0x1d12, 0x1d13, 0x1d14, 0x1d15, 0x1d16, 0x1d17, 0x1d18,
+    0x1d19, 0x1d1a, 0x1d1b, 0x1d1c, 0x1d1d, 0x1d1e, 0x1d1f, 0x1d20, 0x1d21, 0x1d22, 0x1d23, 0x1d24,
+    0x1d25, 0x1d26, 0x1d27, 0x1d28, 0x1d29, 0x1d2a, 0x1d2b, 0x1d2c, 0x1d2d, 0x1d2e, 0x1d2f, 0x1d30,
+    0x1d31, 0x1d32, 0x1d33, 0x1d34, 0x1d35, 0x1d36, 0x1d37, 0x1d38, 0x1d39, 0x1d3a, 0x1d3b, 0x1d3c,
+    0x1d3d, 0x1d3e, 0x1d3f, 0x1d40, 0x1d41, 0x1d42, 0x1d43, 0x1d44, 0x1d45, 0x1d46, 0x1d47, 0x1d48,
+    0x1d49, 0x1d4a, 0x1d4b, 0x1d4c, 0x1d4d, 0x1d4e, 0x1d4f, 0x1d50, 0x1d51, 0x1d52, 0x1d53, 0x1d54,
+    0x1d55, 0x1d56, 0x1d57, 0x1d58, 0x1d59, 0x1d5a, 0x1d5b, 0x1d5c, 0x1d5d, 0x1d5e, 0x1d5f, 0x1d60,
+    0x1d61, 0x1d62, 0x1d63, 0x1d64, 0x1d65, 0x1d66, 0x1d67, 0x1d68, 0x1d69, 0x1d6a, 0x1d6b, 0x1d6c,
+    0x1d6d, 0x1d6e, 0x1d6f, 0x1d70, 0x1d71, 0x1d72, 0x1d73, 0x1d74, 0x1d75, 0x1d76, 0x1d77, 0x1d78,
+    0x1d79, 0x1d7a, 0x1d7b, 0x1d7c, 0x1d7d, 0x1d7e, 0x1d7f, 0x1d80, 0x1d81, 0x1d82, 0x1d83, 0x1d84,
+    0x1d85, 0x1d86, 0x1d87, 0x1d88, 0x1d89, 0x1d8a, 0x1d8b, 0x1d8c, 0x1d8d, 0x1d8e, 0x1d8f, 0x1d90,
+    0x1d91, 0x1d92, 0x1d93, 0x1d94, 0x1d95, 0x1d96, 0x1d97, 0x1d98, 0x1d99, 0x1d9a, 0x1d9b, 0x1d9c,
+    0x1d9d, 0x1d9e, 0x1d9f, 0x1da0, 0x1da1, 0x1da2, 0x1da3, 0x1da4, 0x1da5, 0x1da6, 0x1da7, 0x1da8,
+    0x1da9, 0x1daa, 0x1dab, 0x1dac, 0x1dad, 0x1dae, 0x1daf, 0x1db0, 0x1db1, 0x1db2, 0x1db3, 0x1db4,
+    0x1db5, 0x1db6, 0x1db7, 0x1db8, 0x1db9, 0x1dba, 0x1dbb, 0x1dbc, 0x1dbd, 0x1dbe, 0x1dbf, 0x1dc0,
+    0x1dc1, 0x1dc2, 0x1dc3, 0x1dc4, 0x1dc5, 0x1dc6, 0x1dc7, 0x1dc8, 0x1dc9, 0x1dca, 0x1dcb, 0x1dcc,
+    0x1dcd, 0x1dce, 0x1dcf, 0x1dd0, 0x1dd1, 0x1dd2, 0x1dd3, 0x1dd4, 0x1dd5, 0x1dd6, 0x1dd7, 0x1dd8,
+    0x1dd9, 0x1dda, 0x1ddb, 0x1ddc, 0x1ddd, 0x1dde, 0x1ddf, 0x1de0, 0x1de1, 0x1de2, 0x1de3, 0x1de4,
+    0x1de5, 0x1de6, 0x1de7, 0x1de8, 0x1de9, 0x1dea, 0x1deb, 0x1dec, 0x1ded, 0x1dee, 0x1def, 0x1df0,
+    0x1df1, 0x1df2, 0x1df3, 0x1df4, 0x1df5, 0x1df6, 0x1df7, 0x1df8, 0x1df9, 0x1dfb, 0x1dfc, 0x1dfd,
+    0x1dfe, 0x1dff, 0x1e00, 0x1e01, 0x1e02, 0x1e03, 0x1e04, 0x1e05, 0x1e06, 0x1e07, 0x1e08, 0x1e09,
+    0x1e0a, 0x1e0b, 0x1e0c, 0x1e0d, 0x1e0e, 0x1e0f, 0x1e10, 0x1e11, 0x1e12, 0x1e13, 0x1e14, 0x1e15,
+    0x1e16, 0x1e17, 0x1e18, 0x1e19, 0x1e1a, 0x1e1b, 0x1e1c, 0x1e1d, 0x1e1e, 0x1e1f, 0x1e20, 0x1e21,
+    0x1e22, 0x1e23, 0x1e24, 0x1e25, 0x1e26, 0x1e27, 0x1e28, 0x1e29, 0x1e2a, 0x1e2b, 0x1e2c, 0x1e2d,
+    0x1e2e, 0x1e2f, 0x1e30, 0x1e31, 0x1e32, 0x1e33, 0x1e34, 0x1e35, 0x1e36, 0x1e37, 0x1e38, 0x1e39,
+    0x1e3a, 0x1e3b, 0x1e3c, 0x1e3d, 0x1e3e, 0x1e3f, 0x1e40, 0x1e41, 0x1e42, 0x1e43, 0x1e44, 0x1e45,
+    0x1e46, 0x1e47, 0x1e48, 0x1e49, 0x1e4a, 0x1e4b, 0x1e4c, 0x1e4d, 0x1e4e, 0x1e4f, 0x1e50, 0x1e51,
+    0x1e52, 0x1e53, 0x1e54, 0x1e55, 0x1e56, 0x1e57, 0x1e58, 0x1e59, 0x1e5a, 0x1e5b, 0x1e5c, 0x1e5d,
+    0x1e5e, 0x1e5f, 0x1e60, 0x1e61, 0x1e62, 0x1e63, 0x1e64, 0x1e65, 0x1e66, 0x1e67, 0x1e68, 0x1e69,
+    0x1e6a, 0x1e6b, 0x1e6c, 0x1e6d, 0x1e6e, 0x1e6f, 0x1e70, 0x1e71, 0x1e72, 0x1e73, 0x1e74, 0x1e75,
+    0x1e76, 0x1e77, 0x1e78, 0x1e79, 0x1e7a, 0x1e7b, 0x1e7c, 0x1e7d, 0x1e7e, 0x1e7f, 0x1e80, 0x1e81,
+    0x1e82, 0x1e83, 0x1e84, 0x1e85, 0x1e86, 0x1e87, 0x1e88, 0x1e89, 0x1e8a, 0x1e8b, 0x1e8c, 0x1e8d,
+    0x1e8e, 0x1e8f, 0x1e90, 0x1e91, 0x1e92, 0x1e93, 0x1e94, 0x1e95, 0x1e96, 0x1e97, 0x1e98, 0x1e99,
+    0x1e9a, 0x1e9b, 0x1e9c, 0x1e9d, 0x1e9e, 0x1e9f, 0x1ea0, 0x1ea1, 0x1ea2, 0x1ea3, 0x1ea4, 0x1ea5,
+    0x1ea6, 0x1ea7, 0x1ea8, 0x1ea9, 0x1eaa, 0x1eab, 0x1eac, 0x1ead, 0x1eae, 0x1eaf, 0x1eb0, 0x1eb1,
+    0x1eb2, 0x1eb3, 0x1eb4, 0x1eb5, 0x1eb6, 0x1eb7, 0x1eb8, 0x1eb9, 0x1eba, 0x1ebb, 0x1ebc, 0x1ebd,
+    0x1ebe, 0x1ebf, 0x1ec0, 0x1ec1, 0x1ec2, 0x1ec3, 0x1ec4, 0x1ec5, 0x1ec6, 0x1ec7, 0x1ec8, 0x1ec9,
+    0x1eca, 0x1ecb, 0x1ecc, 0x1ecd, 0x1ece, 0x1ecf, 0x1ed0, 0x1ed1, 0x1ed2, 0x1ed3, 0x1ed4, 0x1ed5,
+    0x1ed6, 0x1ed7, 0x1ed8, 0x1ed9, 0x1eda, 0x1edb, 0x1edc, 0x1edd, 0x1ede, 0x1edf, 0x1ee0, 0x1ee1,
+    0x1ee2, 0x1ee3, 0x1ee4, 0x1ee5, 0x1ee6, 0x1ee7, 0x1ee8, 0x1ee9, 0x1eea, 0x1eeb, 0x1eec, 0x1eed,
+    0x1eee, 0x1eef, 0x1ef0, 0x1ef1, 0x1ef2, 0x1ef3, 0x1ef4, 0x1ef5, 0x1ef6, 0x1ef7, 0x1ef8, 0x1ef9,
+    0x1efa, 0x1efb, 0x1efc, 0x1efd, 0x1efe, 0x1eff, 0x1f00, 0x1f01, 0x1f02, 0x1f03, 0x1f04, 0x1f05,
+    0x1f06, 0x1f07, 0x1f08, 0x1f09, 0x1f0a, 0x1f0b, 0x1f0c, 0x1f0d, 0x1f0e, 0x1f0f, 0x1f10, 0x1f11,
+    0x1f12, 0x1f13, 0x1f14, 0x1f15, 0x1f18, 0x1f19, 0x1f1a, 0x1f1b, 0x1f1c, 0x1f1d, 0x1f20, 0x1f21,
+    0x1f22, 0x1f23, 0x1f24, 0x1f25, 0x1f26, 0x1f27, 0x1f28, 0x1f29, 0x1f2a, 0x1f2b, 0x1f2c, 0x1f2d,
+    0x1f2e, 0x1f2f, 0x1f30, 0x1f31, 0x1f32, 0x1f33, 0x1f34, 0x1f35, 0x1f36, 0x1f37, 0x1f38, 0x1f39,
+    0x1f3a, 0x1f3b, 0x1f3c, 0x1f3d, 0x1f3e, 0x1f3f, 0x1f40, 0x1f41, 0x1f42, 0x1f43, 0x1f44, 0x1f45,
+    0x1f48, 0x1f49, 0x1f4a, 0x1f4b, 0x1f4c, 0x1f4d, 0x1f50, 0x1f51, 0x1f52, 0x1f53, 0x1f54, 0x1f55,
+    0x1f56, 0x1f57, 0x1f59, 0x1f5b, 0x1f5d, 0x1f5f, 0x1f60, 0x1f61, 0x1f62, 0x1f63, 0x1f64, 0x1f65,
+    0x1f66, 0x1f67, 0x1f68, 0x1f69, 0x1f6a, 0x1f6b, 0x1f6c, 0x1f6d, 0x1f6e, 0x1f6f, 0x1f70, 0x1f71,
+    0x1f72, 0x1f73, 0x1f74, 0x1f75, 0x1f76, 0x1f77, 0x1f78, 0x1f79, 0x1f7a, 0x1f7b, 0x1f7c, 0x1f7d,
+    0x1f80, 0x1f81, 0x1f82, 0x1f83, 0x1f84, 0x1f85, 0x1f86, 0x1f87, 0x1f88, 0x1f89, 0x1f8a, 0x1f8b,
+    0x1f8c, 0x1f8d, 0x1f8e, 0x1f8f, 0x1f90, 0x1f91, 0x1f92, 0x1f93, 0x1f94, 0x1f95, 0x1f96, 0x1f97,
+    0x1f98, 0x1f99, 0x1f9a, 0x1f9b, 0x1f9c, 0x1f9d, 0x1f9e, 0x1f9f, 0x1fa0, 0x1fa1, 0x1fa2, 0x1fa3,
+    0x1fa4, 0x1fa5, 0x1fa6, 0x1fa7, 0x1fa8, 0x1fa9, 0x1faa, 0x1fab, 0x1fac, 0x1fad, 0x1fae, 0x1faf,
+    0x1fb0, 0x1fb1, 0x1fb2, 0x1fb3, 0x1fb4, 0x1fb6, 0x1fb7, 0x1fb8, 0x1fb9, 0x1fba, 0x1fbb, 0x1fbc,
+    0x1fbd, 0x1fbe, 0x1fbf, 0x1fc0, 0x1fc1, 0x1fc2, 0x1fc3, 0x1fc4, 0x1fc6, 0x1fc7, 0x1fc8, 0x1fc9,
+    0x1fca, 0x1fcb, 0x1fcc, 0x1fcd, 0x1fce, 0x1fcf, 0x1fd0, 0x1fd1, 0x1fd2, 0x1fd3, 0x1fd6, 0x1fd7,
+    0x1fd8, 0x1fd9, 0x1fda, 0x1fdb, 0x1fdd, 0x1fde, 0x1fdf, 0x1fe0, 0x1fe1, 0x1fe2, 0x1fe3, 0x1fe4,
+    0x1fe5, 0x1fe6, 0x1fe7, 0x1fe8, 0x1fe9, 0x1fea, 0x1feb, 0x1fec, 0x1fed, 0x1fee, 0x1fef, 0x1ff2,
+    0x1ff3, 0x1ff4, 0x1ff6, 0x1ff7, 0x1ff8, 0x1ff9, 0x1ffa, 0x1ffb, 0x1ffc, 0x1ffd, 0x1ffe, 0x2000,
+    0x2001, 0x2002, 0x2003, 0x2004, 0x2005, 0x2006, 0x2007, 0x2008, 0x2009, 0x200a, 0x200b, 0x200c,
+    0x200d, 0x200e, 0x200f, 0x2010, 0x2011, 0x2012, 0x2013, 0x2014, 0x2015, 0x2016, 0x2017, 0x2018,
+    0x2019, 0x201a, 0x201b, 0x201c, 0x201d, 0x201e, 0x201f, 0x2020, 0x2021, 0x2022, 0x2023, 0x2024,
+    0x2025, 0x2026, 0x2027, 0x2028, 0x2029, 0x202a, 0x202b, 0x202c, 0x202d, 0x202e, 0x202f, 0x2030,
+    0x2031, 0x2032, 0x2033, 0x2034, 0x2035, 0x2036, 0x2037, 0x2038, 0x2039, 0x203a, 0x203b, 0x203c,
+    0x203d, 0x203e, 0x203f, 0x2040, 0x2041, 0x2042, 0x2043, 0x2044, 0x2045, 0x2046, 0x2047, 0x2048,
+    0x2049, 0x204a, 0x204b, 0x204c, 0x204d, 0x204e, 0x204f, 0x2050, 0x2051, 0x2052, 0x2053, 0x2054,
+    0x2055, 0x2056, 0x2057, 0x2058, 0x2059, 0x205a, 0x205b, 0x205c, 0x205d, 0x205e, 0x205f, 0x2060,
+    0x2061, 0x2062, 0x2063, 0x2064, 0x2066, 0x2067, 0x2068, 0x2069, 0x206a, 0x206b, 0x206c, 0x206d,
+    0x206e, 0x206f, 0x2070, 0x2071, 0x2074, 0x2075, 0x2076, 0x2077, 0x2078, 0x2079, 0x207a, 0x207b,
+    0x207c, 0x207d, 0x207e, 0x207f, 0x2080, 0x2081, 0x2082, 0x2083, 0x2084, 0x2085, 0x2086, 0x2087,
+    0x2088, 0x2089, 0x208a, 0x208b, 0x208c, 0x208d, 0x208e, 0x2090, 0x2091, 0x2092, 0x2093, 0x2094,
+    0x2095, 0x2096, 0x2097, 0x2098, 0x2099, 0x209a, 0x209b, 0x209c, 0x20a0, 0x20a1, 0x20a2, 0x20a3,
+    0x20a4, 0x20a5, 0x20a6, 0x20a7, 0x20a8, 0x20a9, 0x20aa, 0x20ab, 0x20ac, 0x20ad, 0x20ae, 0x20af,
+    0x20b0, 0x20b1, 0x20b2, 0x20b3, 0x20b4, 0x20b5, 0x20b6, 0x20b7, 0x20b8, 0x20b9, 0x20ba, 0x20bb,
+    0x20bc, 0x20bd, 0x20be, 0x20bf, 0x20d0, 0x20d1, 0x20d2, 0x20d3, 0x20d4, 0x20d5, 0x20d6, 0x20d7,
+    0x20d8, 0x20d9, 0x20da, 0x20db, 0x20dc, 0x20dd, 0x20de, 0x20df, 0x20e0, 0x20e1, 0x20e2, 0x20e3,
+    0x20e4, 0x20e5, 0x20e6, 0x20e7, 0x20e8, 0x20e9, 0x20ea, 0x20eb, 0x20ec, 0x20ed, 0x20ee, 0x20ef,
+    0x20f0, 0x2100, 0x2101, 0x2102, 0x2103, 0x2104, 0x2105, 0x2106, 0x2107, 0x2108, 0x2109, 0x210a,
+    0x210b, 0x210c, 0x210d, 0x210e, 0x210f, 0x2110, 0x2111, 0x2112, 0x2113, 0x2114, 0x2115, 0x2116,
+    0x2117, 0x2118, 0x2119, 0x211a, 0x211b, 0x211c, 0x211d, 0x211e, 0x211f, 0x2120, 0x2121, 0x2122,
+    0x2123, 0x2124, 0x2125, 0x2126, 0x2127, 0x2128, 0x2129, 0x212a, 0x212b, 0x212c, 0x212d, 0x212e,
+    0x212f, 0x2130, 0x2131, 0x2132, 0x2133, 0x2134, 0x2135, 0x2136, 0x2137, 0x2138, 0x2139, 0x213a,
+    0x213b, 0x213c, 0x213d, 0x213e, 0x213f, 0x2140, 0x2141, 0x2142, 0x2143, 0x2144, 0x2145, 0x2146,
+    0x2147, 0x2148, 0x2149, 0x214a, 0x214b, 0x214c, 0x214d, 0x214e, 0x214f, 0x2150, 0x2151, 0x2152,
+    0x2153, 0x2154, 0x2155, 0x2156, 0x2157, 0x2158, 0x2159, 0x215a, 0x215b, 0x215c, 0x215d, 0x215e,
+    0x215f, 0x2160, 0x2161, 0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167, 0x2168, 0x2169, 0x216a,
+    0x216b, 0x216c, 0x216d, 0x216e, 0x216f, 0x2170, 0x2171, 0x2172, 0x2173, 0x2174, 0x2175, 0x2176,
+    0x2177, 0x2178, 0x2179, 0x217a, 0x217b, 0x217c, 0x217d, 0x217e, 0x217f, 0x2180, 0x2181, 0x2182,
+    0x2183, 0x2184, 0x2185, 0x2186, 0x2187, 0x2188, 0x2189, 0x218a, 0x218b, 0x2190, 0x2191, 0x2192,
+    0x2193, 0x2194, 0x2195, 0x2196, 0x2197, 0x2198, 0x2199, 0x219a, 0x219b, 0x219c, 0x219d, 0x219e,
+    0x219f, 0x21a0, 0x21a1, 0x21a2, 0x21a3, 0x21a4, 0x21a5, 0x21a6, 0x21a7, 0x21a8, 0x21a9, 0x21aa,
+    0x21ab, 0x21ac, 0x21ad, 0x21ae, 0x21af, 0x21b0, 0x21b1, 0x21b2, 0x21b3, 0x21b4, 0x21b5, 0x21b6,
+    0x21b7, 0x21b8, 0x21b9, 0x21ba, 0x21bb, 0x21bc, 0x21bd, 0x21be, 0x21bf, 0x21c0, 0x21c1, 0x21c2,
+    0x21c3, 0x21c4, 0x21c5, 0x21c6, 0x21c7, 0x21c8, 0x21c9, 0x21ca, 0x21cb, 0x21cc, 0x21cd, 0x21ce,
+    0x21cf, 0x21d0, 0x21d1, 0x21d2, 0x21d3, 0x21d4, 0x21d5, 0x21d6, 0x21d7, 0x21d8, 0x21d9, 0x21da,
+    0x21db, 0x21dc, 0x21dd, 0x21de, 0x21df, 0x21e0, 0x21e1, 0x21e2, 0x21e3, 0x21e4, 0x21e5, 0x21e6,
+    0x21e7, 0x21e8, 0x21e9, 0x21ea, 0x21eb, 0x21ec, 0x21ed, 0x21ee, 0x21ef, 0x21f0, 0x21f1, 0x21f2,
+    0x21f3, 0x21f4, 0x21f5, 0x21f6, 0x21f7, 0x21f8, 0x21f9, 0x21fa, 0x21fb, 0x21fc, 0x21fd, 0x21fe,
+    0x21ff, 0x2200, 0x2201, 0x2202, 0x2203, 0x2204, 0x2205, 0x2206, 0x2207, 0x2208, 0x2209, 0x220a,
+    0x220b, 0x220c, 0x220d, 0x220e, 0x220f, 0x2210, 0x2211, 0x2212, 0x2213, 0x2214, 0x2215, 0x2216,
+    0x2217, 0x2218, 0x2219, 0x221a, 0x221b, 0x221c, 0x221d, 0x221e, 0x221f, 0x2220, 0x2221, 0x2222,
+    0x2223, 0x2224, 0x2225, 0x2226, 0x2227, 0x2228, 0x2229, 0x222a, 0x222b, 0x222c, 0x222d, 0x222e,
+    0x222f, 0x2230, 0x2231, 0x2232, 0x2233, 0x2234, 0x2235, 0x2236, 0x2237, 0x2238, 0x2239, 0x223a,
+    0x223b, 0x223c, 0x223d, 0x223e, 0x223f, 0x2240, 0x2241, 0x2242, 0x2243, 0x2244, 0x2245, 0x2246,
+    0x2247, 0x2248, 0x2249, 0x224a, 0x224b, 0x224c, 0x224d, 0x224e, 0x224f, 0x2250, 0x2251, 0x2252,
+    0x2253, 0x2254, 0x2255, 0x2256, 0x2257, 0x2258, 0x2259, 0x225a, 0x225b, 0x225c, 0x225d, 0x225e,
+    0x225f, 0x2260, 0x2261, 0x2262, 0x2263, 0x2264, 0x2265, 0x2266, 0x2267, 0x2268, 0x2269, 0x226a,
+    0x226b, 0x226c, 0x226d, 0x226e, 0x226f, 0x2270, 0x2271, 0x2272, 0x2273, 0x2274, 0x2275, 0x2276,
+    0x2277, 0x2278, 0x2279, 0x227a, 0x227b, 0x227c, 0x227d, 0x227e, 0x227f, 0x2280, 0x2281, 0x2282,
+    0x2283, 0x2284, 0x2285, 0x2286, 0x2287, 0x2288, 0x2289, 0x228a, 0x228b, 0x228c, 0x228d, 0x228e,
+    0x228f, 0x2290, 0x2291, 0x2292, 0x2293, 0x2294, 0x2295, 0x2296, 0x2297, 0x2298, 0x2299, 0x229a,
+    0x229b, 0x229c, 0x229d, 0x229e, 0x229f, 0x22a0, 0x22a1, 0x22a2, 0x22a3, 0x22a4, 0x22a5, 0x22a6,
+    0x22a7, 0x22a8, 0x22a9, 0x22aa, 0x22ab, 0x22ac, 0x22ad, 0x22ae, 0x22af, 0x22b0, 0x22b1, 0x22b2,
+    0x22b3, 0x22b4, 0x22b5, 0x22b6, 0x22b7, 0x22b8, 0x22b9, 0x22ba, 0x22bb, 0x22bc, 0x22bd, 0x22be,
+    0x22bf, 0x22c0, 0x22c1, 0x22c2, 0x22c3, 0x22c4, 0x22c5, 0x22c6, 0x22c7, 0x22c8, 0x22c9, 0x22ca,
+    0x22cb, 0x22cc, 0x22cd, 0x22ce, 0x22cf, 0x22d0, 0x22d1, 0x22d2, 0x22d3, 0x22d4, 0x22d5, 0x22d6,
+    0x22d7, 0x22d8, 0x22d9, 0x22da, 0x22db, 0x22dc, 0x22dd, 0x22de, 0x22df, 0x22e0, 0x22e1, 0x22e2,
+    0x22e3, 0x22e4, 0x22e5, 0x22e6, 0x22e7, 0x22e8, 0x22e9, 0x22ea, 0x22eb, 0x22ec, 0x22ed, 0x22ee,
+    0x22ef, 0x22f0, 0x22f1, 0x22f2, 0x22f3, 0x22f4, 0x22f5, 0x22f6, 0x22f7, 0x22f8, 0x22f9, 0x22fa,
+    0x22fb, 0x22fc, 0x22fd, 0x22fe, 0x22ff, 0x2300, 0x2301, 0x2302, 0x2303, 0x2304, 0x2305, 0x2306,
+    0x2307, 0x2308, 0x2309, 0x230a, 0x230b, 0x230c, 0x230d, 0x230e, 0x230f, 0x2310, 0x2311, 0x2312,
+    0x2313, 0x2314, 0x2315, 0x2316, 0x2317, 0x2318, 0x2319, 0x231a, 0x231b, 0x231c, 0x231d, 0x231e,
+    0x231f, 0x2320, 0x2321, 0x2322, 0x2323, 0x2324, 0x2325, 0x2326, 0x2327, 0x2328, 0x2329, 0x232a,
+    0x232b, 0x232c, 0x232d, 0x232e, 0x232f, 0x2330, 0x2331, 0x2332, 0x2333, 0x2334, 0x2335, 0x2336,
+    0x2337, 0x2338, 0x2339, 0x233a, 0x233b, 0x233c, 0x233d, 0x233e, 0x233f, 0x2340, 0x2341, 0x2342,
+    0x2343, 0x2344, 0x2345, 0x2346, 0x2347, 0x2348, 0x2349, 0x234a, 0x234b, 0x234c, 0x234d, 0x234e,
+    0x234f, 0x2350, 0x2351, 0x2352, 0x2353, 0x2354, 0x2355, 0x2356, 0x2357, 0x2358, 0x2359, 0x235a,
+    0x235b, 0x235c, 0x235d, 0x235e, 0x235f, 0x2360, 0x2361, 0x2362, 0x2363, 0x2364, 0x2365, 0x2366,
+    0x2367, 0x2368, 0x2369, 0x236a, 0x236b, 0x236c, 0x236d, 0x236e, 0x236f, 0x2370, 0x2371, 0x2372,
+    0x2373, 0x2374, 0x2375, 0x2376, 0x2377, 0x2378, 0x2379, 0x237a, 0x237b, 0x237c, 0x237d, 0x237e,
+    0x237f, 0x2380, 0x2381, 0x2382, 0x2383, 0x2384, 0x2385, 0x2386, 0x2387, 0x2388, 0x2389, 0x238a,
+    0x238b, 0x238c, 0x238d, 0x238e, 0x238f, 0x2390, 0x2391, 0x2392, 0x2393, 0x2394, 0x2395, 0x2396,
+    0x2397, 0x2398, 0x2399, 0x239a, 0x239b, 0x239c, 0x239d, 0x239e, 0x239f, 0x23a0, 0x23a1, 0x23a2,
+    0x23a3, 0x23a4, 0x23a5, 0x23a6, 0x23a7, 0x23a8, 0x23a9, 0x23aa, 0x23ab, 0x23ac, 0x23ad, 0x23ae,
+    0x23af, 0x23b0, 0x23b1, 0x23b2, 0x23b3, 0x23b4, 0x23b5, 0x23b6, 0x23b7, 0x23b8, 0x23b9, 0x23ba,
+    0x23bb, 0x23bc, 0x23bd, 0x23be, 0x23bf, 0x23c0, 0x23c1, 0x23c2, 0x23c3, 0x23c4, 0x23c5, 0x23c6,
+    0x23c7, 0x23c8, 0x23c9, 0x23ca, 0x23cb, 0x23cc, 0x23cd, 0x23ce, 0x23cf, 0x23d0, 0x23d1, 0x23d2,
+    0x23d3, 0x23d4, 0x23d5, 0x23d6, 0x23d7, 0x23d8, 0x23d9, 0x23da, 0x23db, 0x23dc, 0x23dd, 0x23de,
+    0x23df, 0x23e0, 0x23e1, 0x23e2, 0x23e3, 0x23e4, 0x23e5, 0x23e6, 0x23e7, 0x23e8, 0x23e9, 0x23ea,
+    0x23eb, 0x23ec, 0x23ed, 0x23ee, 0x23ef, 0x23f0, 0x23f1, 0x23f2, 0x23f3, 0x23f4, 0x23f5, 0x23f6,
+    0x23f7, 0x23f8, 0x23f9, 0x23fa, 0x23fb, 0x23fc, 0x23fd, 0x23fe, 0x23ff, 0x2400, 0x2401, 0x2402,
+    0x2403, 0x2404, 0x2405, 0x2406, 0x2407, 0x2408, 0x2409, 0x240a, 0x240b, 0x240c, 0x240d, 0x240e,
+    0x240f, 0x2410, 0x2411, 0x2412, 0x2413, 0x2414, 0x2415, 0x2416, 0x2417, 0x2418, 0x2419, 0x241a,
+    0x241b, 0x241c, 0x241d, 0x241e, 0x241f, 0x2420, 0x2421, 0x2422, 0x2423, 0x2424, 0x2425, 0x2426,
+    0x2440, 0x2441, 0x2442, 0x2443, 0x2444, 0x2445, 0x2446, 0x2447, 0x2448, 0x2449, 0x244a, 0x2460,
+    0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468, 0x2469, 0x246a, 0x246b, 0x246c,
+    0x246d, 0x246e, 0x246f, 0x2470, 0x2471, 0x2472, 0x2473, 0x2474, 0x2475, 0x2476, 0x2477, 0x2478,
+    0x2479, 0x247a, 0x247b, 0x247c, 0x247d, 0x247e, 0x247f, 0x2480, 0x2481, 0x2482, 0x2483, 0x2484,
+    0x2485, 0x2486, 0x2487, 0x2488, 0x2489, 0x248a, 0x248b, 0x248c, 0x248d, 0x248e, 0x248f, 0x2490,
+    0x2491, 0x2492, 0x2493, 0x2494, 0x2495, 0x2496, 0x2497, 0x2498, 0x2499, 0x249a, 0x249b, 0x249c,
+    0x249d, 0x249e, 0x249f, 0x24a0, 0x24a1, 0x24a2, 0x24a3, 0x24a4, 0x24a5, 0x24a6, 0x24a7, 0x24a8,
+    0x24a9, 0x24aa, 0x24ab, 0x24ac, 0x24ad, 0x24ae, 0x24af, 0x24b0, 0x24b1, 0x24b2, 0x24b3, 0x24b4,
+    0x24b5, 0x24b6, 0x24b7, 0x24b8, 0x24b9, 0x24ba, 0x24bb, 0x24bc, 0x24bd, 0x24be, 0x24bf, 0x24c0,
+    0x24c1, 0x24c2, 0x24c3, 0x24c4, 0x24c5, 0x24c6, 0x24c7, 0x24c8, 0x24c9, 0x24ca, 0x24cb, 0x24cc,
+    0x24cd, 0x24ce, 0x24cf, 0x24d0, 0x24d1, 0x24d2, 0x24d3, 0x24d4, 0x24d5, 0x24d6, 0x24d7, 0x24d8,
+    0x24d9, 0x24da, 0x24db, 0x24dc, 0x24dd, 0x24de, 0x24df, 0x24e0, 0x24e1, 0x24e2, 0x24e3, 0x24e4,
+    0x24e5, 0x24e6, 0x24e7, 0x24e8, 0x24e9, 0x24ea, 0x24eb, 0x24ec, 0x24ed, 0x24ee, 0x24ef, 0x24f0,
+    0x24f1, 0x24f2, 0x24f3, 0x24f4, 0x24f5, 0x24f6, 0x24f7, 0x24f8, 0x24f9, 0x24fa, 0x24fb, 0x24fc,
+    0x24fd, 0x24fe, 0x24ff, 0x2500, 0x2501, 0x2502, 0x2503, 0x2504, 0x2505, 0x2506, 0x2507, 0x2508,
+    0x2509, 0x250a, 0x250b, 0x250c, 0x250d, 0x250e, 0x250f, 0x2510, 0x2511, 0x2512, 0x2513, 0x2514,
+    0x2515, 0x2516, 0x2517, 0x2518, 0x2519, 0x251a, 0x251b, 0x251c, 0x251d, 0x251e, 0x251f, 0x2520,
+    0x2521, 0x2522, 0x2523, 0x2524, 0x2525, 0x2526, 0x2527, 0x2528, 0x2529, 0x252a, 0x252b, 0x252c,
+    0x252d, 0x252e, 0x252f, 0x2530, 0x2531, 0x2532, 0x2533, 0x2534, 0x2535, 0x2536, 0x2537, 0x2538,
+    0x2539, 0x253a, 0x253b, 0x253c, 0x253d, 0x253e, 0x253f, 0x2540, 0x2541, 0x2542, 0x2543, 0x2544,
+    0x2545, 0x2546, 0x2547, 0x2548, 0x2549, 0x254a, 0x254b, 0x254c, 0x254d, 0x254e, 0x254f, 0x2550,
+    0x2551, 0x2552, 0x2553, 0x2554, 0x2555, 0x2556, 0x2557, 0x2558, 0x2559, 0x255a, 0x255b, 0x255c,
+    0x255d, 0x255e, 0x255f, 0x2560, 0x2561, 0x2562, 0x2563, 0x2564, 0x2565, 0x2566, 0x2567, 0x2568,
+    0x2569, 0x256a, 0x256b, 0x256c, 0x256d, 0x256e, 0x256f, 0x2570, 0x2571, 0x2572, 0x2573, 0x2574,
+    0x2575, 0x2576, 0x2577, 0x2578, 0x2579, 0x257a, 0x257b, 0x257c, 0x257d, 0x257e, 0x257f, 0x2580,
+    0x2581, 0x2582, 0x2583, 0x2584, 0x2585, 0x2586, 0x2587, 0x2588, 0x2589, 0x258a, 0x258b, 0x258c,
+    0x258d, 0x258e, 0x258f, 0x2590, 0x2591, 0x2592, 0x2593, 0x2594, 0x2595, 0x2596, 0x2597, 0x2598,
+    0x2599, 0x259a, 0x259b, 0x259c, 0x259d, 0x259e, 0x259f, 0x25a0, 0x25a1, 0x25a2, 0x25a3, 0x25a4,
+    0x25a5, 0x25a6, 0x25a7, 0x25a8, 0x25a9, 0x25aa, 0x25ab, 0x25ac, 0x25ad, 0x25ae, 0x25af, 0x25b0,
+    0x25b1, 0x25b2, 0x25b3, 0x25b4, 0x25b5, 0x25b6, 0x25b7, 0x25b8, 0x25b9, 0x25ba, 0x25bb, 0x25bc,
+    0x25bd, 0x25be, 0x25bf, 0x25c0, 0x25c1, 0x25c2, 0x25c3, 0x25c4, 0x25c5, 0x25c6, 0x25c7, 0x25c8,
+    0x25c9, 0x25ca, 0x25cb, 0x25cc, 0x25cd, 0x25ce, 0x25cf, 0x25d0, 0x25d1, 0x25d2, 0x25d3, 0x25d4,
+    0x25d5, 0x25d6, 0x25d7, 0x25d8, 0x25d9, 0x25da, 0x25db, 0x25dc, 0x25dd, 0x25de, 0x25df, 0x25e0,
+    0x25e1, 0x25e2, 0x25e3, 0x25e4, 0x25e5, 0x25e6, 0x25e7, 0x25e8, 0x25e9, 0x25ea, 0x25eb, 0x25ec,
+    0x25ed, 0x25ee, 0x25ef, 0x25f0, 0x25f1, 0x25f2, 0x25f3, 0x25f4, 0x25f5, 0x25f6, 0x25f7, 0x25f8,
+    0x25f9, 0x25fa, 0x25fb, 0x25fc, 0x25fd, 0x25fe, 0x25ff, 0x2600, 0x2601, 0x2602, 0x2603, 0x2604,
+    0x2605, 0x2606, 0x2607, 0x2608, 0x2609, 0x260a, 0x260b, 0x260c, 0x260d, 0x260e, 0x260f, 0x2610,
+    0x2611, 0x2612, 0x2613, 0x2614, 0x2615, 0x2616, 0x2617, 0x2618, 0x2619, 0x261a, 0x261b, 0x261c,
+    0x261d, 0x261e, 0x261f, 0x2620, 0x2621, 0x2622, 0x2623, 0x2624, 0x2625, 0x2626, 0x2627, 0x2628,
+    0x2629, 0x262a, 0x262b, 0x262c, 0x262d, 0x262e, 0x262f, 0x2630, 0x2631, 0x2632, 0x2633, 0x2634,
+    0x2635, 0x2636, 0x2637, 0x2638, 0x2639, 0x263a, 0x263b, 0x263c, 0x263d, 0x263e, 0x263f, 0x2640,
+    0x2641, 0x2642, 0x2643, 0x2644, 0x2645, 0x2646, 0x2647, 0x2648, 0x2649, 0x264a, 0x264b, 0x264c,
+    0x264d, 0x264e, 0x264f, 0x2650, 0x2651, 0x2652, 0x2653, 0x2654, 0x2655, 0x2656, 0x2657, 0x2658,
+    0x2659, 0x265a, 0x265b, 0x265c, 0x265d, 0x265e, 0x265f, 0x2660, 0x2661, 0x2662, 0x2663, 0x2664,
+    0x2665, 0x2666, 0x2667, 0x2668, 0x2669, 0x266a, 0x266b, 0x266c, 0x266d, 0x266e, 0x266f, 0x2670,
+    0x2671, 0x2672, 0x2673, 0x2674, 0x2675, 0x2676, 0x2677, 0x2678, 0x2679, 0x267a, 0x267b, 0x267c,
+    0x267d, 0x267e, 0x267f, 0x2680, 0x2681, 0x2682, 0x2683, 0x2684, 0x2685, 0x2686, 0x2687, 0x2688,
+    0x2689, 0x268a, 0x268b, 0x268c, 0x268d, 0x268e, 0x268f, 0x2690, 0x2691, 0x2692, 0x2693, 0x2694,
+    0x2695, 0x2696, 0x2697, 0x2698, 0x2699, 0x269a, 0x269b, 0x269c, 0x269d, 0x269e, 0x269f, 0x26a0,
+    0x26a1, 0x26a2, 0x26a3, 0x26a4, 0x26a5, 0x26a6, 0x26a7, 0x26a8, 0x26a9, 0x26aa, 0x26ab, 0x26ac,
+    0x26ad, 0x26ae, 0x26af, 0x26b0, 0x26b1, 0x26b2, 0x26b3, 0x26b4, 0x26b5, 0x26b6, 0x26b7, 0x26b8,
+    0x26b9, 0x26ba, 0x26bb, 0x26bc, 0x26bd, 0x26be, 0x26bf, 0x26c0, 0x26c1, 0x26c2, 0x26c3, 0x26c4,
+    0x26c5, 0x26c6, 0x26c7, 0x26c8, 0x26c9, 0x26ca, 0x26cb, 0x26cc, 0x26cd, 0x26ce, 0x26cf, 0x26d0,
+    0x26d1, 0x26d2, 0x26d3, 0x26d4, 0x26d5, 0x26d6, 0x26d7, 0x26d8, 0x26d9, 0x26da, 0x26db, 0x26dc,
+    0x26dd, 0x26de, 0x26df, 0x26e0, 0x26e1, 0x26e2, 0x26e3, 0x26e4, 0x26e5, 0x26e6, 0x26e7, 0x26e8,
+    0x26e9, 0x26ea, 0x26eb, 0x26ec, 0x26ed, 0x26ee, 0x26ef, 0x26f0, 0x26f1, 0x26f2, 0x26f3, 0x26f4,
+    0x26f5, 0x26f6, 0x26f7, 0x26f8, 0x26f9, 0x26fa, 0x26fb, 0x26fc, 0x26fd, 0x26fe, 0x26ff, 0x2700,
+    0x2701, 0x2702, 0x2703, 0x2704, 0x2705, 0x2706, 0x2707, 0x2708, 0x2709, 0x270a, 0x270b, 0x270c,
+    0x270d, 0x270e, 0x270f, 0x2710, 0x2711, 0x2712, 0x2713, 0x2714, 0x2715, 0x2716, 0x2717, 0x2718,
+    0x2719, 0x271a, 0x271b, 0x271c, 0x271d, 0x271e, 0x271f, 0x2720, 0x2721, 0x2722, 0x2723, 0x2724,
+    0x2725, 0x2726, 0x2727, 0x2728, 0x2729, 0x272a, 0x272b, 0x272c, 0x272d, 0x272e, 0x272f, 0x2730,
+    0x2731, 0x2732, 0x2733, 0x2734, 0x2735, 0x2736, 0x2737, 0x2738, 0x2739, 0x273a, 0x273b, 0x273c,
+    0x273d, 0x273e, 0x273f, 0x2740, 0x2741, 0x2742, 0x2743, 0x2744, 0x2745, 0x2746, 0x2747, 0x2748,
+    0x2749, 0x274a, 0x274b, 0x274c, 0x274d, 0x274e, 0x274f, 0x2750, 0x2751, 0x2752, 0x2753, 0x2754,
+    0x2755, 0x2756, 0x2757, 0x2758, 0x2759, 0x275a, 0x275b, 0x275c, 0x275d, 0x275e, 0x275f, 0x2760,
+    0x2761, 0x2762, 0x2763, 0x2764, 0x2765, 0x2766, 0x2767, 0x2768, 0x2769, 0x276a, 0x276b, 0x276c,
+    0x276d, 0x276e, 0x276f, 0x2770, 0x2771, 0x2772, 0x2773, 0x2774, 0x2775, 0x2776, 0x2777, 0x2778,
+    0x2779, 0x277a, 0x277b, 0x277c, 0x277d, 0x277e, 0x277f, 0x2780, 0x2781, 0x2782, 0x2783, 0x2784,
+    0x2785, 0x2786, 0x2787, 0x2788, 0x2789, 0x278a, 0x278b, 0x278c, 0x278d, 0x278e, 0x278f, 0x2790,
+    0x2791, 0x2792, 0x2793, 0x2794, 0x2795, 0x2796, 0x2797, 0x2798, 0x2799, 0x279a, 0x279b, 0x279c,
+    0x279d, 0x279e, 0x279f, 0x27a0, 0x27a1, 0x27a2, 0x27a3, 0x27a4, 0x27a5, 0x27a6, 0x27a7, 0x27a8,
+    0x27a9, 0x27aa, 0x27ab, 0x27ac, 0x27ad, 0x27ae, 0x27af, 0x27b0, 0x27b1, 0x27b2, 0x27b3, 0x27b4,
+    0x27b5, 0x27b6, 0x27b7, 0x27b8, 0x27b9, 0x27ba, 0x27bb, 0x27bc, 0x27bd, 0x27be, 0x27bf, 0x27c0,
+    0x27c1, 0x27c2, 0x27c3, 0x27c4, 0x27c5, 0x27c6, 0x27c7, 0x27c8, 0x27c9, 0x27ca, 0x27cb, 0x27cc,
+    0x27cd, 0x27ce, 0x27cf, 0x27d0, 0x27d1, 0x27d2, 0x27d3, 0x27d4, 0x27d5, 0x27d6, 0x27d7, 0x27d8,
+    0x27d9, 0x27da, 0x27db, 0x27dc, 0x27dd, 0x27de, 0x27df, 0x27e0, 0x27e1, 0x27e2, 0x27e3, 0x27e4,
+    0x27e5, 0x27e6, 0x27e7, 0x27e8, 0x27e9, 0x27ea, 0x27eb, 0x27ec, 0x27ed, 0x27ee, 0x27ef, 0x27f0,
+    0x27f1, 0x27f2, 0x27f3, 0x27f4, 0x27f5, 0x27f6, 0x27f7, 0x27f8, 0x27f9, 0x27fa, 0x27fb, 0x27fc,
+    0x27fd, 0x27fe, 0x27ff, 0x2800, 0x2801, 0x2802, 0x2803, 0x2804, 0x2805, 0x2806, 0x2807, 0x2808,
+    0x2809, 0x280a, 0x280b, 0x280c, 0x280d, 0x280e, 0x280f, 0x2810, 0x2811, 0x2812, 0x2813, 0x2814,
+    0x2815, 0x2816, 0x2817, 0x2818, 0x2819, 0x281a, 0x281b, 0x281c, 0x281d, 0x281e, 0x281f, 0x2820,
+    0x2821, 0x2822, 0x2823, 0x2824, 0x2825, 0x2826, 0x2827, 0x2828, 0x2829, 0x282a, 0x282b, 0x282c,
+    0x282d, 0x282e, 0x282f, 0x2830, 0x2831, 0x2832, 0x2833, 0x2834, 0x2835, 0x2836, 0x2837, 0x2838,
+    0x2839, 0x283a, 0x283b, 0x283c, 0x283d, 0x283e, 0x283f, 0x2840, 0x2841, 0x2842, 0x2843, 0x2844,
+    0x2845, 0x2846, 0x2847, 0x2848, 0x2849, 0x284a, 0x284b, 0x284c, 0x284d, 0x284e, 0x284f, 0x2850,
+    0x2851, 0x2852, 0x2853, 0x2854, 0x2855, 0x2856, 0x2857, 0x2858, 0x2859, 0x285a, 0x285b, 0x285c,
+    0x285d, 0x285e, 0x285f, 0x2860, 0x2861, 0x2862, 0x2863, 0x2864, 0x2865, 0x2866, 0x2867, 0x2868,
+    0x2869, 0x286a, 0x286b, 0x286c, 0x286d, 0x286e, 0x286f, 0x2870, 0x2871, 0x2872, 0x2873, 0x2874,
+    0x2875, 0x2876, 0x2877, 0x2878, 0x2879, 0x287a, 0x287b, 0x287c, 0x287d, 0x287e, 0x287f, 0x2880,
+    0x2881, 0x2882, 0x2883, 0x2884, 0x2885, 0x2886, 0x2887, 0x2888, 0x2889, 0x288a, 0x288b, 0x288c,
+    0x288d, 0x288e, 0x288f, 0x2890, 0x2891, 0x2892, 0x2893, 0x2894, 0x2895, 0x2896, 0x2897, 0x2898,
+    0x2899, 0x289a, 0x289b, 0x289c, 0x289d, 0x289e, 0x289f, 0x28a0, 0x28a1, 0x28a2, 0x28a3, 0x28a4,
+    0x28a5, 0x28a6, 0x28a7, 0x28a8, 0x28a9, 0x28aa, 0x28ab, 0x28ac, 0x28ad, 0x28ae, 0x28af, 0x28b0,
+    0x28b1, 0x28b2, 0x28b3, 0x28b4, 0x28b5, 0x28b6, 0x28b7, 0x28b8, 0x28b9, 0x28ba, 0x28bb, 0x28bc,
+    0x28bd, 0x28be, 0x28bf, 0x28c0, 0x28c1, 0x28c2, 0x28c3, 0x28c4, 0x28c5, 0x28c6, 0x28c7, 0x28c8,
+    0x28c9, 0x28ca, 0x28cb, 0x28cc, 0x28cd, 0x28ce, 0x28cf, 0x28d0, 0x28d1, 0x28d2, 0x28d3, 0x28d4,
+    0x28d5, 0x28d6, 0x28d7, 0x28d8, 0x28d9, 0x28da, 0x28db, 0x28dc, 0x28dd, 0x28de, 0x28df, 0x28e0,
+    0x28e1, 0x28e2, 0x28e3, 0x28e4, 0x28e5, 0x28e6, 0x28e7, 0x28e8, 0x28e9, 0x28ea, 0x28eb, 0x28ec,
+    0x28ed, 0x28ee, 0x28ef, 0x28f0, 0x28f1, 0x28f2, 0x28f3, 0x28f4, 0x28f5, 0x28f6, 0x28f7, 0x28f8,
+    0x28f9, 0x28fa, 0x28fb, 0x28fc, 0x28fd, 0x28fe, 0x28ff, 0x2900, 0x2901, 0x2902, 0x2903, 0x2904,
+    0x2905, 0x2906, 0x2907, 0x2908, 0x2909, 0x290a, 0x290b, 0x290c, 0x290d, 0x290e, 0x290f, 0x2910,
+    0x2911, 0x2912, 0x2913, 0x2914, 0x2915, 0x2916, 0x2917, 0x2918, 0x2919, 0x291a, 0x291b, 0x291c,
+    0x291d, 0x291e, 0x291f, 0x2920, 0x2921, 0x2922, 0x2923, 0x2924, 0x2925, 0x2926, 0x2927, 0x2928,
+    0x2929, 0x292a, 0x292b, 0x292c, 0x292d, 0x292e, 0x292f, 0x2930, 0x2931, 0x2932, 0x2933, 0x2934,
+    0x2935, 0x2936, 0x2937, 0x2938, 0x2939, 0x293a, 0x293b, 0x293c, 0x293d, 0x293e, 0x293f, 0x2940,
+    0x2941, 0x2942, 0x2943, 0x2944, 0x2945, 0x2946, 0x2947, 0x2948, 0x2949, 0x294a, 0x294b, 0x294c,
+    0x294d, 0x294e, 0x294f, 0x2950, 0x2951, 0x2952, 0x2953, 0x2954, 0x2955, 0x2956, 0x2957, 0x2958,
+    0x2959, 0x295a, 0x295b, 0x295c, 0x295d, 0x295e, 0x295f, 0x2960, 0x2961, 0x2962, 0x2963, 0x2964,
+    0x2965, 0x2966, 0x2967, 0x2968, 0x2969, 0x296a, 0x296b, 0x296c, 0x296d, 0x296e, 0x296f, 0x2970,
+    0x2971, 0x2972, 0x2973, 0x2974, 0x2975, 0x2976, 0x2977, 0x2978, 0x2979, 0x297a, 0x297b, 0x297c,
+    0x297d, 0x297e, 0x297f, 0x2980, 0x2981, 0x2982, 0x2983, 0x2984, 0x2985, 0x2986, 0x2987, 0x2988,
+    0x2989, 0x298a, 0x298b, 0x298c, 0x298d, 0x298e, 0x298f, 0x2990, 0x2991, 0x2992, 0x2993, 0x2994,
+    0x2995, 0x2996, 0x2997, 0x2998, 0x2999, 0x299a, 0x299b, 0x299c, 0x299d, 0x299e, 0x299f, 0x29a0,
+    0x29a1, 0x29a2, 0x29a3, 0x29a4, 0x29a5, 0x29a6, 0x29a7, 0x29a8, 0x29a9, 0x29aa, 0x29ab, 0x29ac,
+    0x29ad, 0x29ae, 0x29af, 0x29b0, 0x29b1, 0x29b2, 0x29b3, 0x29b4, 0x29b5, 0x29b6, 0x29b7, 0x29b8,
+    0x29b9, 0x29ba, 0x29bb, 0x29bc, 0x29bd, 0x29be, 0x29bf, 0x29c0, 0x29c1, 0x29c2, 0x29c3, 0x29c4,
+    0x29c5, 0x29c6, 0x29c7, 0x29c8, 0x29c9, 0x29ca, 0x29cb, 0x29cc, 0x29cd, 0x29ce, 0x29cf, 0x29d0,
+    0x29d1, 0x29d2, 0x29d3, 0x29d4, 0x29d5, 0x29d6, 0x29d7, 0x29d8, 0x29d9, 0x29da, 0x29db, 0x29dc,
+    0x29dd, 0x29de, 0x29df, 0x29e0, 0x29e1, 0x29e2, 0x29e3, 0x29e4, 0x29e5, 0x29e6, 0x29e7, 0x29e8,
+    0x29e9, 0x29ea, 0x29eb, 0x29ec, 0x29ed, 0x29ee, 0x29ef, 0x29f0, 0x29f1, 0x29f2, 0x29f3, 0x29f4,
+    0x29f5, 0x29f6, 0x29f7, 0x29f8, 0x29f9, 0x29fa, 0x29fb, 0x29fc, 0x29fd, 0x29fe, 0x29ff, 0x2a00,
+    0x2a01, 0x2a02, 0x2a03, 0x2a04, 0x2a05, 0x2a06, 0x2a07, 0x2a08, 0x2a09, 0x2a0a, 0x2a0b, 0x2a0c,
+    0x2a0d, 0x2a0e, 0x2a0f, 0x2a10, 0x2a11, 0x2a12, 0x2a13, 0x2a14, 0x2a15, 0x2a16, 0x2a17, 0x2a18,
+    0x2a19, 0x2a1a, 0x2a1b, 0x2a1c, 0x2a1d, 0x2a1e, 0x2a1f, 0x2a20, 0x2a21, 0x2a22, 0x2a23, 0x2a24,
+    0x2a25, 0x2a26, 0x2a27, 0x2a28, 0x2a29, 0x2a2a, 0x2a2b, 0x2a2c, 0x2a2d, 0x2a2e, 0x2a2f, 0x2a30,
+    0x2a31, 0x2a32, 0x2a33, 0x2a34, 0x2a35, 0x2a36, 0x2a37, 0x2a38, 0x2a39, 0x2a3a, 0x2a3b, 0x2a3c,
+    0x2a3d, 0x2a3e, 0x2a3f, 0x2a40, 0x2a41, 0x2a42, 0x2a43, 0x2a44, 0x2a45, 0x2a46, 0x2a47, 0x2a48,
+    0x2a49, 0x2a4a, 0x2a4b, 0x2a4c, 0x2a4d, 0x2a4e, 0x2a4f, 0x2a50, 0x2a51, 0x2a52, 0x2a53, 0x2a54,
+    0x2a55, 0x2a56, 0x2a57, 0x2a58, 0x2a59, 0x2a5a, 0x2a5b, 0x2a5c, 0x2a5d, 0x2a5e, 0x2a5f, 0x2a60,
+    0x2a61, 0x2a62, 0x2a63, 0x2a64, 0x2a65, 0x2a66, 0x2a67, 0x2a68, 0x2a69, 0x2a6a, 0x2a6b, 0x2a6c,
+    0x2a6d, 0x2a6e, 0x2a6f, 0x2a70, 0x2a71, 0x2a72, 0x2a73, 0x2a74, 0x2a75, 0x2a76, 0x2a77, 0x2a78,
+    0x2a79, 0x2a7a, 0x2a7b, 0x2a7c, 0x2a7d, 0x2a7e, 0x2a7f, 0x2a80, 0x2a81, 0x2a82, 0x2a83, 0x2a84,
+    0x2a85, 0x2a86, 0x2a87, 0x2a88, 0x2a89, 0x2a8a, 0x2a8b, 0x2a8c, 0x2a8d, 0x2a8e, 0x2a8f, 0x2a90,
+    0x2a91, 0x2a92, 0x2a93, 0x2a94, 0x2a95, 0x2a96, 0x2a97, 0x2a98, 0x2a99, 0x2a9a, 0x2a9b, 0x2a9c,
+    0x2a9d, 0x2a9e, 0x2a9f, 0x2aa0, 0x2aa1, 0x2aa2, 0x2aa3, 0x2aa4, 0x2aa5, 0x2aa6, 0x2aa7, 0x2aa8,
+    0x2aa9, 0x2aaa, 0x2aab, 0x2aac, 0x2aad, 0x2aae, 0x2aaf, 0x2ab0, 0x2ab1, 0x2ab2, 0x2ab3, 0x2ab4,
+    0x2ab5, 0x2ab6, 0x2ab7, 0x2ab8, 0x2ab9, 0x2aba, 0x2abb, 0x2abc, 0x2abd, 0x2abe, 0x2abf, 0x2ac0,
+    0x2ac1, 0x2ac2, 0x2ac3, 0x2ac4, 0x2ac5, 0x2ac6, 0x2ac7, 0x2ac8, 0x2ac9, 0x2aca, 0x2acb, 0x2acc,
+    0x2acd, 0x2ace, 0x2acf, 0x2ad0, 0x2ad1, 0x2ad2, 0x2ad3, 0x2ad4, 0x2ad5, 0x2ad6, 0x2ad7, 0x2ad8,
+    0x2ad9, 0x2ada, 0x2adb, 0x2adc, 0x2add, 0x2ade, 0x2adf, 0x2ae0, 0x2ae1, 0x2ae2, 0x2ae3, 0x2ae4,
+    0x2ae5, 0x2ae6, 0x2ae7, 0x2ae8, 0x2ae9, 0x2aea, 0x2aeb, 0x2aec, 0x2aed, 0x2aee, 0x2aef, 0x2af0,
+    0x2af1, 0x2af2, 0x2af3, 0x2af4, 0x2af5, 0x2af6, 0x2af7, 0x2af8, 0x2af9, 0x2afa, 0x2afb, 0x2afc,
+    0x2afd, 0x2afe, 0x2aff, 0x2b00, 0x2b01, 0x2b02, 0x2b03, 0x2b04, 0x2b05, 0x2b06, 0x2b07, 0x2b08,
+    0x2b09, 0x2b0a, 0x2b0b, 0x2b0c, 0x2b0d, 0x2b0e, 0x2b0f, 0x2b10, 0x2b11, 0x2b12, 0x2b13, 0x2b14,
+    0x2b15, 0x2b16, 0x2b17, 0x2b18, 0x2b19, 0x2b1a, 0x2b1b, 0x2b1c, 0x2b1d, 0x2b1e, 0x2b1f, 0x2b20,
+    0x2b21, 0x2b22, 0x2b23, 0x2b24, 0x2b25, 0x2b26, 0x2b27, 0x2b28, 0x2b29, 0x2b2a, 0x2b2b, 0x2b2c,
+    0x2b2d, 0x2b2e, 0x2b2f, 0x2b30, 0x2b31, 0x2b32, 0x2b33, 0x2b34, 0x2b35, 0x2b36, 0x2b37, 0x2b38,
+    0x2b39, 0x2b3a, 0x2b3b, 0x2b3c, 0x2b3d, 0x2b3e, 0x2b3f, 0x2b40, 0x2b41, 0x2b42, 0x2b43, 0x2b44,
+    0x2b45, 0x2b46, 0x2b47, 0x2b48, 0x2b49, 0x2b4a, 0x2b4b, 0x2b4c, 0x2b4d, 0x2b4e, 0x2b4f, 0x2b50,
+    0x2b51, 0x2b52, 0x2b53, 0x2b54, 0x2b55, 0x2b56, 0x2b57, 0x2b58, 0x2b59, 0x2b5a, 0x2b5b, 0x2b5c,
+    0x2b5d, 0x2b5e, 0x2b5f, 0x2b60, 0x2b61, 0x2b62, 0x2b63, 0x2b64, 0x2b65, 0x2b66, 0x2b67, 0x2b68,
+    0x2b69, 0x2b6a, 0x2b6b, 0x2b6c, 0x2b6d, 0x2b6e, 0x2b6f, 0x2b70, 0x2b71, 0x2b72, 0x2b73, 0x2b76,
+    0x2b77, 0x2b78, 0x2b79, 0x2b7a, 0x2b7b, 0x2b7c, 0x2b7d, 0x2b7e, 0x2b7f, 0x2b80, 0x2b81, 0x2b82,
+    0x2b83, 0x2b84, 0x2b85, 0x2b86, 0x2b87, 0x2b88, 0x2b89, 0x2b8a, 0x2b8b, 0x2b8c, 0x2b8d, 0x2b8e,
+    0x2b8f, 0x2b90, 0x2b91, 0x2b92, 0x2b93, 0x2b94, 0x2b95, 0x2b97, 0x2b98, 0x2b99, 0x2b9a, 0x2b9b,
+    0x2b9c, 0x2b9d, 0x2b9e, 0x2b9f, 0x2ba0, 0x2ba1, 0x2ba2, 0x2ba3, 0x2ba4, 0x2ba5, 0x2ba6, 0x2ba7,
+    0x2ba8, 0x2ba9, 0x2baa, 0x2bab, 0x2bac, 0x2bad, 0x2bae, 0x2baf, 0x2bb0, 0x2bb1, 0x2bb2, 0x2bb3,
+    0x2bb4, 0x2bb5, 0x2bb6, 0x2bb7, 0x2bb8, 0x2bb9, 0x2bba, 0x2bbb, 0x2bbc, 0x2bbd, 0x2bbe, 0x2bbf,
+    0x2bc0, 0x2bc1, 0x2bc2, 0x2bc3, 0x2bc4, 0x2bc5, 0x2bc6, 0x2bc7, 0x2bc8, 0x2bc9, 0x2bca, 0x2bcb,
+    0x2bcc, 0x2bcd, 0x2bce, 0x2bcf, 0x2bd0, 0x2bd1, 0x2bd2, 0x2bd3, 0x2bd4, 0x2bd5, 0x2bd6, 0x2bd7,
+    0x2bd8, 0x2bd9, 0x2bda, 0x2bdb, 0x2bdc, 0x2bdd, 0x2bde, 0x2bdf, 0x2be0, 0x2be1, 0x2be2, 0x2be3,
+    0x2be4, 0x2be5, 0x2be6, 0x2be7, 0x2be8, 0x2be9, 0x2bea, 0x2beb, 0x2bec, 0x2bed, 0x2bee, 0x2bef,
+    0x2bf0, 0x2bf1, 0x2bf2, 0x2bf3, 0x2bf4, 0x2bf5, 0x2bf6, 0x2bf7, 0x2bf8, 0x2bf9, 0x2bfa, 0x2bfb,
+    0x2bfc, 0x2bfd, 0x2bfe, 0x2bff, 0x2c00, 0x2c01, 0x2c02, 0x2c03, 0x2c04, 0x2c05, 0x2c06, 0x2c07,
+    0x2c08, 0x2c09, 0x2c0a, 0x2c0b, 0x2c0c, 0x2c0d, 0x2c0e, 0x2c0f, 0x2c10, 0x2c11, 0x2c12, 0x2c13,
+    0x2c14, 0x2c15, 0x2c16, 0x2c17, 0x2c18, 0x2c19, 0x2c1a, 0x2c1b, 0x2c1c, 0x2c1d, 0x2c1e, 0x2c1f,
+    0x2c20, 0x2c21, 0x2c22, 0x2c23, 0x2c24, 0x2c25, 0x2c26, 0x2c27, 0x2c28, 0x2c29, 0x2c2a, 0x2c2b,
+    0x2c2c, 0x2c2d, 0x2c2e, 0x2c30, 0x2c31, 0x2c32, 0x2c33, 0x2c34, 0x2c35, 0x2c36, 0x2c37, 0x2c38,
+    0x2c39, 0x2c3a, 0x2c3b, 0x2c3c, 0x2c3d, 0x2c3e, 0x2c3f, 0x2c40, 0x2c41, 0x2c42, 0x2c43, 0x2c44,
+    0x2c45, 0x2c46, 0x2c47, 0x2c48, 0x2c49, 0x2c4a, 0x2c4b, 0x2c4c, 0x2c4d, 0x2c4e, 0x2c4f, 0x2c50,
+    0x2c51, 0x2c52, 0x2c53, 0x2c54, 0x2c55, 0x2c56, 0x2c57, 0x2c58, 0x2c59, 0x2c5a, 0x2c5b, 0x2c5c,
+    0x2c5d, 0x2c5e, 0x2c60, 0x2c61, 0x2c62, 0x2c63, 0x2c64, 0x2c65, 0x2c66, 0x2c67, 0x2c68, 0x2c69,
+    0x2c6a, 0x2c6b, 0x2c6c, 0x2c6d, 0x2c6e, 0x2c6f, 0x2c70, 0x2c71, 0x2c72, 0x2c73, 0x2c74, 0x2c75,
+    0x2c76, 0x2c77, 0x2c78, 0x2c79, 0x2c7a, 0x2c7b, 0x2c7c, 0x2c7d, 0x2c7e, 0x2c7f, 0x2c80, 0x2c81,
+    0x2c82, 0x2c83, 0x2c84, 0x2c85, 0x2c86, 0x2c87, 0x2c88, 0x2c89, 0x2c8a, 0x2c8b, 0x2c8c, 0x2c8d,
+    0x2c8e, 0x2c8f, 0x2c90, 0x2c91, 0x2c92, 0x2c93, 0x2c94, 0x2c95, 0x2c96, 0x2c97, 0x2c98, 0x2c99,
+    0x2c9a, 0x2c9b, 0x2c9c, 0x2c9d, 0x2c9e, 0x2c9f, 0x2ca0, 0x2ca1, 0x2ca2, 0x2ca3, 0x2ca4, 0x2ca5,
+    0x2ca6, 0x2ca7, 0x2ca8, 0x2ca9, 0x2caa, 0x2cab, 0x2cac, 0x2cad, 0x2cae, 0x2caf, 0x2cb0, 0x2cb1,
+    0x2cb2, 0x2cb3, 0x2cb4, 0x2cb5, 0x2cb6, 0x2cb7, 0x2cb8, 0x2cb9, 0x2cba, 0x2cbb, 0x2cbc, 0x2cbd,
+    0x2cbe, 0x2cbf, 0x2cc0, 0x2cc1, 0x2cc2, 0x2cc3, 0x2cc4, 0x2cc5, 0x2cc6, 0x2cc7, 0x2cc8, 0x2cc9,
+    0x2cca, 0x2ccb, 0x2ccc, 0x2ccd, 0x2cce, 0x2ccf, 0x2cd0, 0x2cd1, 0x2cd2, 0x2cd3, 0x2cd4, 0x2cd5,
+    0x2cd6, 0x2cd7, 0x2cd8, 0x2cd9, 0x2cda, 0x2cdb, 0x2cdc, 0x2cdd, 0x2cde, 0x2cdf, 0x2ce0, 0x2ce1,
+    0x2ce2, 0x2ce3, 0x2ce4, 0x2ce5, 0x2ce6, 0x2ce7, 0x2ce8, 0x2ce9, 0x2cea, 0x2ceb, 0x2cec, 0x2ced,
+    0x2cee, 0x2cef, 0x2cf0, 0x2cf1, 0x2cf2, 0x2cf3, 0x2cf9, 0x2cfa, 0x2cfb, 0x2cfc, 0x2cfd, 0x2cfe,
+    0x2cff, 0x2d00, 0x2d01, 0x2d02, 0x2d03, 0x2d04, 0x2d05, 0x2d06, 0x2d07, 0x2d08, 0x2d09, 0x2d0a,
+    0x2d0b, 0x2d0c, 0x2d0d, 0x2d0e, 0x2d0f, 0x2d10, 0x2d11, 0x2d12, 0x2d13, 0x2d14, 0x2d15, 0x2d16,
+    0x2d17, 0x2d18, 0x2d19, 0x2d1a, 0x2d1b, 0x2d1c, 0x2d1d, 0x2d1e, 0x2d1f, 0x2d20, 0x2d21, 0x2d22,
+    0x2d23, 0x2d24, 0x2d25, 0x2d27, 0x2d2d, 0x2d30, 0x2d31, 0x2d32, 0x2d33, 0x2d34, 0x2d35, 0x2d36,
+    0x2d37, 0x2d38, 0x2d39, 0x2d3a, 0x2d3b, 0x2d3c, 0x2d3d, 0x2d3e, 0x2d3f, 0x2d40, 0x2d41, 0x2d42,
+    0x2d43, 0x2d44, 0x2d45, 0x2d46, 0x2d47, 0x2d48, 0x2d49, 0x2d4a, 0x2d4b, 0x2d4c, 0x2d4d, 0x2d4e,
+    0x2d4f, 0x2d50, 0x2d51, 0x2d52, 0x2d53, 0x2d54, 0x2d55, 0x2d56, 0x2d57, 0x2d58, 0x2d59, 0x2d5a,
+    0x2d5b, 0x2d5c, 0x2d5d, 0x2d5e, 0x2d5f, 0x2d60, 0x2d61, 0x2d62, 0x2d63, 0x2d64, 0x2d65, 0x2d66,
+    0x2d67, 0x2d6f, 0x2d70, 0x2d7f, 0x2d80, 0x2d81, 0x2d82, 0x2d83, 0x2d84, 0x2d85, 0x2d86, 0x2d87,
+    0x2d88, 0x2d89, 0x2d8a, 0x2d8b, 0x2d8c, 0x2d8d, 0x2d8e, 0x2d8f, 0x2d90, 0x2d91, 0x2d92, 0x2d93,
+    0x2d94, 0x2d95, 0x2d96, 0x2da0, 0x2da1, 0x2da2, 0x2da3, 0x2da4, 0x2da5, 0x2da6, 0x2da8, 0x2da9,
+    0x2daa, 0x2dab, 0x2dac, 0x2dad, 0x2dae, 0x2db0, 0x2db1, 0x2db2, 0x2db3, 0x2db4, 0x2db5, 0x2db6,
+    0x2db8, 0x2db9, 0x2dba, 0x2dbb, 0x2dbc, 0x2dbd, 0x2dbe, 0x2dc0, 0x2dc1, 0x2dc2, 0x2dc3, 0x2dc4,
+    0x2dc5, 0x2dc6, 0x2dc8, 0x2dc9, 0x2dca, 0x2dcb, 0x2dcc, 0x2dcd, 0x2dce, 0x2dd0, 0x2dd1, 0x2dd2,
+    0x2dd3, 0x2dd4, 0x2dd5, 0x2dd6, 0x2dd8, 0x2dd9, 0x2dda, 0x2ddb, 0x2ddc, 0x2ddd, 0x2dde, 0x2de0,
+    0x2de1, 0x2de2, 0x2de3, 0x2de4, 0x2de5, 0x2de6, 0x2de7, 0x2de8, 0x2de9, 0x2dea, 0x2deb, 0x2dec,
+    0x2ded, 0x2dee, 0x2def, 0x2df0, 0x2df1, 0x2df2, 0x2df3, 0x2df4, 0x2df5, 0x2df6, 0x2df7, 0x2df8,
+    0x2df9, 0x2dfa, 0x2dfb, 0x2dfc, 0x2dfd, 0x2dfe, 0x2dff, 0x2e00, 0x2e01, 0x2e02, 0x2e03, 0x2e04,
+    0x2e05, 0x2e06, 0x2e07, 0x2e08, 0x2e09, 0x2e0a, 0x2e0b, 0x2e0c, 0x2e0d, 0x2e0e, 0x2e0f, 0x2e10,
+    0x2e11, 0x2e12, 0x2e13, 0x2e14, 0x2e15, 0x2e16, 0x2e17, 0x2e18, 0x2e19, 0x2e1a, 0x2e1b, 0x2e1c,
+    0x2e1d, 0x2e1e, 0x2e1f, 0x2e20, 0x2e21, 0x2e22, 0x2e23, 0x2e24, 0x2e25, 0x2e26, 0x2e27, 0x2e28,
+    0x2e29, 0x2e2a, 0x2e2b, 0x2e2c, 0x2e2d, 0x2e2e, 0x2e2f, 0x2e30, 0x2e31, 0x2e32, 0x2e33, 0x2e34,
+    0x2e35, 0x2e36, 0x2e37, 0x2e38, 0x2e39, 0x2e3a, 0x2e3b, 0x2e3c, 0x2e3d, 0x2e3e, 0x2e3f, 0x2e40,
+    0x2e41, 0x2e42, 0x2e43, 0x2e44, 0x2e45, 0x2e46, 0x2e47, 0x2e48, 0x2e49, 0x2e4a, 0x2e4b, 0x2e4c,
+    0x2e4d, 0x2e4e, 0x2e4f, 0x2e50, 0x2e51, 0x2e52, 0x2e80, 0x2e81, 0x2e82, 0x2e83, 0x2e84, 0x2e85,
+    0x2e86, 0x2e87, 0x2e88, 0x2e89, 0x2e8a, 0x2e8b, 0x2e8c, 0x2e8d, 0x2e8e, 0x2e8f, 0x2e90, 0x2e91,
+    0x2e92, 0x2e93, 0x2e94, 0x2e95, 0x2e96, 0x2e97, 0x2e98, 0x2e99, 0x2e9b, 0x2e9c, 0x2e9d, 0x2e9e,
+    0x2e9f, 0x2ea0, 0x2ea1, 0x2ea2, 0x2ea3, 0x2ea4, 0x2ea5, 0x2ea6, 0x2ea7, 0x2ea8, 0x2ea9, 0x2eaa,
+    0x2eab, 0x2eac, 0x2ead, 0x2eae, 0x2eaf, 0x2eb0, 0x2eb1, 0x2eb2, 0x2eb3, 0x2eb4, 0x2eb5, 0x2eb6,
+    0x2eb7, 0x2eb8, 0x2eb9, 0x2eba, 0x2ebb, 0x2ebc, 0x2ebd, 0x2ebe, 0x2ebf, 0x2ec0, 0x2ec1, 0x2ec2,
+    0x2ec3, 0x2ec4, 0x2ec5, 0x2ec6, 0x2ec7, 0x2ec8, 0x2ec9, 0x2eca, 0x2ecb, 0x2ecc, 0x2ecd, 0x2ece,
+    0x2ecf, 0x2ed0, 0x2ed1, 0x2ed2, 0x2ed3, 0x2ed4, 0x2ed5, 0x2ed6, 0x2ed7, 0x2ed8, 0x2ed9, 0x2eda,
+    0x2edb, 0x2edc, 0x2edd, 0x2ede, 0x2edf, 0x2ee0, 0x2ee1, 0x2ee2, 0x2ee3, 0x2ee4, 0x2ee5, 0x2ee6,
+    0x2ee7, 0x2ee8, 0x2ee9, 0x2eea, 0x2eeb, 0x2eec, 0x2eed, 0x2eee, 0x2eef, 0x2ef0, 0x2ef1, 0x2ef2,
+    0x2ef3, 0x2f00, 0x2f01, 0x2f02, 0x2f03, 0x2f04, 0x2f05, 0x2f06, 0x2f07, 0x2f08, 0x2f09, 0x2f0a,
+    0x2f0b, 0x2f0c, 0x2f0d, 0x2f0e, 0x2f0f, 0x2f10, 0x2f11, 0x2f12, 0x2f13, 0x2f14, 0x2f15, 0x2f16,
+    0x2f17, 0x2f18, 0x2f19, 0x2f1a, 0x2f1b, 0x2f1c, 0x2f1d, 0x2f1e, 0x2f1f, 0x2f20, 0x2f21, 0x2f22,
+    0x2f23, 0x2f24, 0x2f25, 0x2f26, 0x2f27, 0x2f28, 0x2f29, 0x2f2a, 0x2f2b, 0x2f2c, 0x2f2d, 0x2f2e,
+    0x2f2f, 0x2f30, 0x2f31, 0x2f32, 0x2f33, 0x2f34, 0x2f35, 0x2f36, 0x2f37, 0x2f38, 0x2f39, 0x2f3a,
+    0x2f3b, 0x2f3c, 0x2f3d, 0x2f3e, 0x2f3f, 0x2f40, 0x2f41, 0x2f42, 0x2f43, 0x2f44, 0x2f45, 0x2f46,
+    0x2f47, 0x2f48, 0x2f49, 0x2f4a, 0x2f4b, 0x2f4c, 0x2f4d, 0x2f4e, 0x2f4f, 0x2f50, 0x2f51, 0x2f52,
+    0x2f53, 0x2f54, 0x2f55, 0x2f56, 0x2f57, 0x2f58, 0x2f59, 0x2f5a, 0x2f5b, 0x2f5c, 0x2f5d, 0x2f5e,
+    0x2f5f, 0x2f60, 0x2f61, 0x2f62, 0x2f63, 0x2f64, 0x2f65, 0x2f66, 0x2f67, 0x2f68, 0x2f69, 0x2f6a,
+    0x2f6b, 0x2f6c, 0x2f6d, 0x2f6e, 0x2f6f, 0x2f70, 0x2f71, 0x2f72, 0x2f73, 0x2f74, 0x2f75, 0x2f76,
+    0x2f77, 0x2f78, 0x2f79, 0x2f7a, 0x2f7b, 0x2f7c, 0x2f7d, 0x2f7e, 0x2f7f, 0x2f80, 0x2f81, 0x2f82,
+    0x2f83, 0x2f84, 0x2f85, 0x2f86, 0x2f87, 0x2f88, 0x2f89, 0x2f8a, 0x2f8b, 0x2f8c, 0x2f8d, 0x2f8e,
+    0x2f8f, 0x2f90, 0x2f91, 0x2f92, 0x2f93, 0x2f94, 0x2f95, 0x2f96, 0x2f97, 0x2f98, 0x2f99, 0x2f9a,
+    0x2f9b, 0x2f9c, 0x2f9d, 0x2f9e, 0x2f9f, 0x2fa0, 0x2fa1, 0x2fa2, 0x2fa3, 0x2fa4, 0x2fa5, 0x2fa6,
+    0x2fa7, 0x2fa8, 0x2fa9, 0x2faa, 0x2fab, 0x2fac, 0x2fad, 0x2fae, 0x2faf, 0x2fb0, 0x2fb1, 0x2fb2,
+    0x2fb3, 0x2fb4, 0x2fb5, 0x2fb6, 0x2fb7, 0x2fb8, 0x2fb9, 0x2fba, 0x2fbb, 0x2fbc, 0x2fbd, 0x2fbe,
+    0x2fbf, 0x2fc0, 0x2fc1, 0x2fc2, 0x2fc3, 0x2fc4, 0x2fc5, 0x2fc6, 0x2fc7, 0x2fc8, 0x2fc9, 0x2fca,
+    0x2fcb, 0x2fcc, 0x2fcd, 0x2fce, 0x2fcf, 0x2fd0, 0x2fd1, 0x2fd2, 0x2fd3, 0x2fd4, 0x2fd5, 0x2ff0,
+    0x2ff1, 0x2ff2, 0x2ff3, 0x2ff4, 0x2ff5, 0x2ff6, 0x2ff7, 0x2ff8, 0x2ff9, 0x2ffa, 0x2ffb, 0x3000,
+    0x3001, 0x3002, 0x3003, 0x3004, 0x3005, 0x3006, 0x3007, 0x3008, 0x3009, 0x300a, 0x300b, 0x300c,
+    0x300d, 0x300e, 0x300f, 0x3010, 0x3011, 0x3012, 0x3013, 0x3014, 0x3015, 0x3016, 0x3017, 0x3018,
+    0x3019, 0x301a, 0x301b, 0x301c, 0x301d, 0x301e, 0x301f, 0x3020, 0x3021, 0x3022, 0x3023, 0x3024,
+    0x3025, 0x3026, 0x3027, 0x3028, 0x3029, 0x302a, 0x302b, 0x302c, 0x302d, 0x302e, 0x302f, 0x3030,
+    0x3031, 0x3032, 0x3033, 0x3034, 0x3035, 0x3036, 0x3037, 0x3038, 0x3039, 0x303a, 0x303b, 0x303c,
+    0x303d, 0x303e, 0x303f, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045, 0x3046, 0x3047, 0x3048, 0x3049,
+    0x304a, 0x304b, 0x304c, 0x304d, 0x304e, 0x304f, 0x3050, 0x3051, 0x3052, 0x3053, 0x3054, 0x3055,
+    0x3056, 0x3057, 0x3058, 0x3059, 0x305a, 0x305b, 0x305c, 0x305d, 0x305e, 0x305f, 0x3060, 0x3061,
+    0x3062, 0x3063, 0x3064, 0x3065, 0x3066, 0x3067, 0x3068, 0x3069, 0x306a, 0x306b, 0x306c, 0x306d,
+    0x306e, 0x306f, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074, 0x3075, 0x3076, 0x3077, 0x3078, 0x3079,
+    0x307a, 0x307b, 0x307c, 0x307d, 0x307e, 0x307f, 0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085,
+    0x3086, 0x3087, 0x3088, 0x3089, 0x308a, 0x308b, 0x308c, 0x308d, 0x308e, 0x308f, 0x3090, 0x3091,
+    0x3092, 0x3093, 0x3094, 0x3095, 0x3096, 0x3099, 0x309a, 0x309b, 0x309c, 0x309d, 0x309e, 0x309f,
+    0x30a0, 0x30a1, 0x30a2, 0x30a3, 0x30a4, 0x30a5, 0x30a6, 0x30a7, 0x30a8, 0x30a9, 0x30aa, 0x30ab,
+    0x30ac, 0x30ad, 0x30ae, 0x30af, 0x30b0, 0x30b1, 0x30b2, 0x30b3, 0x30b4, 0x30b5, 0x30b6, 0x30b7,
+    0x30b8, 0x30b9, 0x30ba, 0x30bb, 0x30bc, 0x30bd, 0x30be, 0x30bf, 0x30c0, 0x30c1, 0x30c2, 0x30c3,
+    0x30c4, 0x30c5, 0x30c6, 0x30c7, 0x30c8, 0x30c9, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf,
+    0x30d0, 0x30d1, 0x30d2, 0x30d3, 0x30d4, 0x30d5, 0x30d6, 0x30d7, 0x30d8, 0x30d9, 0x30da, 0x30db,
+    0x30dc, 0x30dd, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2, 0x30e3, 0x30e4, 0x30e5, 0x30e6, 0x30e7,
+    0x30e8, 0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ee, 0x30ef, 0x30f0, 0x30f1, 0x30f2, 0x30f3,
+    0x30f4, 0x30f5, 0x30f6, 0x30f7, 0x30f8, 0x30f9, 0x30fa, 0x30fb, 0x30fc, 0x30fd, 0x30fe, 0x30ff,
+    0x3105, 0x3106, 0x3107, 0x3108, 0x3109, 0x310a, 0x310b, 0x310c, 0x310d, 0x310e, 0x310f, 0x3110,
+    0x3111, 0x3112, 0x3113, 0x3114, 0x3115, 0x3116, 0x3117, 0x3118, 0x3119, 0x311a, 0x311b, 0x311c,
+    0x311d, 0x311e, 0x311f, 0x3120, 0x3121, 0x3122, 0x3123, 0x3124, 0x3125, 0x3126, 0x3127, 0x3128,
+    0x3129, 0x312a, 0x312b, 0x312c, 0x312d, 0x312e, 0x312f, 0x3131, 0x3132, 0x3133, 0x3134, 0x3135,
+    0x3136, 0x3137, 0x3138, 0x3139, 0x313a, 0x313b, 0x313c, 0x313d, 0x313e, 0x313f, 0x3140, 0x3141,
+    0x3142, 0x3143, 0x3144, 0x3145, 0x3146, 0x3147, 0x3148, 0x3149, 0x314a, 0x314b, 0x314c, 0x314d,
+    0x314e, 0x314f, 0x3150, 0x3151, 0x3152, 0x3153, 0x3154, 0x3155, 0x3156, 0x3157, 0x3158, 0x3159,
+    0x315a, 0x315b, 0x315c, 0x315d, 0x315e, 0x315f, 0x3160, 0x3161, 0x3162, 0x3163, 0x3164, 0x3165,
+    0x3166, 0x3167, 0x3168, 0x3169, 0x316a, 0x316b, 0x316c, 0x316d, 0x316e, 0x316f, 0x3170, 0x3171,
+    0x3172, 0x3173, 0x3174, 0x3175, 0x3176, 0x3177, 0x3178, 0x3179, 0x317a, 0x317b, 0x317c, 0x317d,
+    0x317e, 0x317f, 0x3180, 0x3181, 0x3182, 0x3183, 0x3184, 0x3185, 0x3186, 0x3187, 0x3188, 0x3189,
+    0x318a, 0x318b, 0x318c, 0x318d, 0x318e, 0x3190, 0x3191, 0x3192, 0x3193, 0x3194, 0x3195, 0x3196,
+    0x3197, 0x3198, 0x3199, 0x319a, 0x319b, 0x319c, 0x319d, 0x319e, 0x319f, 0x31a0, 0x31a1, 0x31a2,
+    0x31a3, 0x31a4, 0x31a5, 0x31a6, 0x31a7, 0x31a8, 0x31a9, 0x31aa, 0x31ab, 0x31ac, 0x31ad, 0x31ae,
+    0x31af, 0x31b0, 0x31b1, 0x31b2, 0x31b3, 0x31b4, 0x31b5, 0x31b6, 0x31b7, 0x31b8, 0x31b9, 0x31ba,
+    0x31bb, 0x31bc, 0x31bd, 0x31be, 0x31bf, 0x31c0, 0x31c1, 0x31c2, 0x31c3, 0x31c4, 0x31c5, 0x31c6,
+    0x31c7, 0x31c8, 0x31c9, 0x31ca, 0x31cb, 0x31cc, 0x31cd, 0x31ce, 0x31cf, 0x31d0, 0x31d1, 0x31d2,
+    0x31d3, 0x31d4, 0x31d5, 0x31d6, 0x31d7, 0x31d8, 0x31d9, 0x31da, 0x31db, 0x31dc, 0x31dd, 0x31de,
+    0x31df, 0x31e0, 0x31e1, 0x31e2, 0x31e3, 0x31f0, 0x31f1, 0x31f2, 0x31f3, 0x31f4, 0x31f5, 0x31f6,
+    0x31f7, 0x31f8, 0x31f9, 0x31fa, 0x31fb, 0x31fc, 0x31fd, 0x31fe, 0x31ff, 0x3200, 0x3201, 0x3202,
+    0x3203, 0x3204, 0x3205, 0x3206, 0x3207, 0x3208, 0x3209, 0x320a, 0x320b, 0x320c, 0x320d, 0x320e,
+    0x320f, 0x3210, 0x3211, 0x3212, 0x3213, 0x3214, 0x3215, 0x3216, 0x3217, 0x3218, 0x3219, 0x321a,
+    0x321b, 0x321c, 0x321d, 0x321e, 0x3220, 0x3221, 0x3222, 0x3223, 0x3224, 0x3225, 0x3226, 0x3227,
+    0x3228, 0x3229, 0x322a, 0x322b, 0x322c, 0x322d, 0x322e, 0x322f, 0x3230, 0x3231, 0x3232, 0x3233,
+    0x3234, 0x3235, 0x3236, 0x3237, 0x3238, 0x3239, 0x323a, 0x323b, 0x323c, 0x323d, 0x323e, 0x323f,
+    0x3240, 0x3241, 0x3242, 0x3243, 0x3244, 0x3245, 0x3246, 0x3247, 0x3248, 0x3249, 0x324a, 0x324b,
+    0x324c, 0x324d, 0x324e, 0x324f, 0x3250, 0x3251, 0x3252, 0x3253, 0x3254, 0x3255, 0x3256, 0x3257,
+    0x3258, 0x3259, 0x325a, 0x325b, 0x325c, 0x325d, 0x325e, 0x325f, 0x3260, 0x3261, 0x3262, 0x3263,
+    0x3264, 0x3265, 0x3266, 0x3267, 0x3268, 0x3269, 0x326a, 0x326b, 0x326c, 0x326d, 0x326e, 0x326f,
+    0x3270, 0x3271, 0x3272, 0x3273, 0x3274, 0x3275, 0x3276, 0x3277, 0x3278, 0x3279, 0x327a, 0x327b,
+    0x327c, 0x327d, 0x327e, 0x327f, 0x3280, 0x3281, 0x3282, 0x3283, 0x3284, 0x3285, 0x3286, 0x3287,
+    0x3288, 0x3289, 0x328a, 0x328b, 0x328c, 0x328d, 0x328e, 0x328f, 0x3290, 0x3291, 0x3292, 0x3293,
+    0x3294, 0x3295, 0x3296, 0x3297, 0x3298, 0x3299, 0x329a, 0x329b, 0x329c, 0x329d, 0x329e, 0x329f,
+    0x32a0, 0x32a1, 0x32a2, 0x32a3, 0x32a4, 0x32a5, 0x32a6, 0x32a7, 0x32a8, 0x32a9, 0x32aa, 0x32ab,
+    0x32ac, 0x32ad, 0x32ae, 0x32af, 0x32b0, 0x32b1, 0x32b2, 0x32b3, 0x32b4, 0x32b5, 0x32b6, 0x32b7,
+    0x32b8, 0x32b9, 0x32ba, 0x32bb, 0x32bc, 0x32bd, 0x32be, 0x32bf, 0x32c0, 0x32c1, 0x32c2, 0x32c3,
+    0x32c4, 0x32c5, 0x32c6, 0x32c7, 0x32c8, 0x32c9, 0x32ca, 0x32cb, 0x32cc, 0x32cd, 0x32ce, 0x32cf,
+    0x32d0, 0x32d1, 0x32d2, 0x32d3, 0x32d4, 0x32d5, 0x32d6, 0x32d7, 0x32d8, 0x32d9, 0x32da, 0x32db,
+    0x32dc, 0x32dd, 0x32de, 0x32df, 0x32e0, 0x32e1, 0x32e2, 0x32e3, 0x32e4, 0x32e5, 0x32e6, 0x32e7,
+    0x32e8, 0x32e9, 0x32ea, 0x32eb, 0x32ec, 0x32ed, 0x32ee, 0x32ef, 0x32f0, 0x32f1, 0x32f2, 0x32f3,
+    0x32f4, 0x32f5, 0x32f6, 0x32f7, 0x32f8, 0x32f9, 0x32fa, 0x32fb, 0x32fc, 0x32fd, 0x32fe, 0x32ff,
+    0x3300, 0x3301, 0x3302, 0x3303, 0x3304, 0x3305, 0x3306, 0x3307, 0x3308, 0x3309, 0x330a, 0x330b,
+    0x330c, 0x330d, 0x330e, 0x330f, 0x3310, 0x3311, 0x3312, 0x3313, 0x3314, 0x3315, 0x3316, 0x3317,
+    0x3318, 0x3319, 0x331a, 0x331b, 0x331c, 0x331d, 0x331e, 0x331f, 0x3320, 0x3321, 0x3322, 0x3323,
+    0x3324, 0x3325, 0x3326, 0x3327, 0x3328, 0x3329, 0x332a, 0x332b, 0x332c, 0x332d, 0x332e, 0x332f,
+    0x3330, 0x3331, 0x3332, 0x3333, 0x3334, 0x3335, 0x3336, 0x3337, 0x3338, 0x3339, 0x333a, 0x333b,
+    0x333c, 0x333d, 0x333e, 0x333f, 0x3340, 0x3341, 0x3342, 0x3343, 0x3344, 0x3345, 0x3346, 0x3347,
+    0x3348, 0x3349, 0x334a, 0x334b, 0x334c, 0x334d, 0x334e, 0x334f, 0x3350, 0x3351, 0x3352, 0x3353,
+    0x3354, 0x3355, 0x3356, 0x3357, 0x3358, 0x3359, 0x335a, 0x335b, 0x335c, 0x335d, 0x335e, 0x335f,
+    0x3360, 0x3361, 0x3362, 0x3363, 0x3364, 0x3365, 0x3366, 0x3367, 0x3368, 0x3369, 0x336a, 0x336b,
+    0x336c, 0x336d, 0x336e, 0x336f, 0x3370, 0x3371, 0x3372, 0x3373, 0x3374, 0x3375, 0x3376, 0x3377,
+    0x3378, 0x3379, 0x337a, 0x337b, 0x337c, 0x337d, 0x337e, 0x337f, 0x3380, 0x3381, 0x3382, 0x3383,
+    0x3384, 0x3385, 0x3386, 0x3387, 0x3388, 0x3389, 0x338a, 0x338b, 0x338c, 0x338d, 0x338e, 0x338f,
+    0x3390, 0x3391, 0x3392, 0x3393, 0x3394, 0x3395, 0x3396, 0x3397, 0x3398, 0x3399, 0x339a, 0x339b,
+    0x339c, 0x339d, 0x339e, 0x339f, 0x33a0, 0x33a1, 0x33a2, 0x33a3, 0x33a4, 0x33a5, 0x33a6, 0x33a7,
+    0x33a8, 0x33a9, 0x33aa, 0x33ab, 0x33ac, 0x33ad, 0x33ae, 0x33af, 0x33b0, 0x33b1, 0x33b2, 0x33b3,
+    0x33b4, 0x33b5, 0x33b6, 0x33b7, 0x33b8, 0x33b9, 0x33ba, 0x33bb, 0x33bc, 0x33bd, 0x33be, 0x33bf,
+    0x33c0, 0x33c1, 0x33c2, 0x33c3, 0x33c4, 0x33c5, 0x33c6, 0x33c7, 0x33c8, 0x33c9, 0x33ca, 0x33cb,
+    0x33cc, 0x33cd, 0x33ce, 0x33cf, 0x33d0, 0x33d1, 0x33d2, 0x33d3, 0x33d4, 0x33d5, 0x33d6, 0x33d7,
+    0x33d8, 0x33d9, 0x33da, 0x33db, 0x33dc, 0x33dd, 0x33de, 0x33df, 0x33e0, 0x33e1, 0x33e2, 0x33e3,
+    0x33e4, 0x33e5, 0x33e6, 0x33e7, 0x33e8, 0x33e9, 0x33ea, 0x33eb, 0x33ec, 0x33ed, 0x33ee, 0x33ef,
+    0x33f0, 0x33f1, 0x33f2, 0x33f3, 0x33f4, 0x33f5, 0x33f6, 0x33f7, 0x33f8, 0x33f9, 0x33fa, 0x33fb,
+    0x33fc, 0x33fd, 0x33fe, 0x33ff, 0x4dc0, 0x4dc1, 0x4dc2, 0x4dc3, 0x4dc4, 0x4dc5, 0x4dc6, 0x4dc7,
+    0x4dc8, 0x4dc9, 0x4dca, 0x4dcb, 0x4dcc, 0x4dcd, 0x4dce, 0x4dcf, 0x4dd0, 0x4dd1, 0x4dd2, 0x4dd3,
+    0x4dd4, 0x4dd5, 0x4dd6, 0x4dd7, 0x4dd8, 0x4dd9, 0x4dda, 0x4ddb, 0x4ddc, 0x4ddd, 0x4dde, 0x4ddf,
+    0x4de0, 0x4de1, 0x4de2, 0x4de3, 0x4de4, 0x4de5, 0x4de6, 0x4de7, 0x4de8, 0x4de9, 0x4dea, 0x4deb,
+    0x4dec, 0x4ded, 0x4dee, 0x4def, 0x4df0, 0x4df1, 0x4df2, 0x4df3, 0x4df4, 0x4df5, 0x4df6, 0x4df7,
+    0x4df8, 0x4df9, 0x4dfa, 0x4dfb, 0x4dfc, 0x4dfd, 0x4dfe, 0x4dff, 0xa000, 0xa001, 0xa002, 0xa003,
+    0xa004, 0xa005, 0xa006, 0xa007, 0xa008, 0xa009, 0xa00a, 0xa00b, 0xa00c, 0xa00d, 0xa00e, 0xa00f,
+    0xa010, 0xa011, 0xa012, 0xa013, 0xa014, 0xa015, 0xa016, 0xa017, 0xa018, 0xa019, 0xa01a, 0xa01b,
+    0xa01c, 0xa01d, 0xa01e, 0xa01f, 0xa020, 0xa021, 0xa022, 0xa023, 0xa024, 0xa025, 0xa026, 0xa027,
+    0xa028, 0xa029, 0xa02a, 0xa02b, 0xa02c, 0xa02d, 0xa02e, 0xa02f, 0xa030, 0xa031, 0xa032, 0xa033,
+    0xa034, 0xa035, 0xa036, 0xa037, 0xa038, 0xa039, 0xa03a, 0xa03b, 0xa03c, 0xa03d, 0xa03e, 0xa03f,
+    0xa040, 0xa041, 0xa042, 0xa043, 0xa044, 0xa045, 0xa046, 0xa047, 0xa048, 0xa049, 0xa04a, 0xa04b,
+    0xa04c, 0xa04d, 0xa04e, 0xa04f, 0xa050, 0xa051, 0xa052, 0xa053, 0xa054, 0xa055, 0xa056, 0xa057,
+    0xa058, 0xa059, 0xa05a, 0xa05b, 0xa05c, 0xa05d, 0xa05e, 0xa05f, 0xa060, 0xa061, 0xa062, 0xa063,
+    0xa064, 0xa065, 0xa066, 0xa067, 0xa068, 0xa069, 0xa06a, 0xa06b, 0xa06c, 0xa06d, 0xa06e, 0xa06f,
+    0xa070, 0xa071, 0xa072, 0xa073, 0xa074, 0xa075, 0xa076, 0xa077, 0xa078, 0xa079, 0xa07a, 0xa07b,
+    0xa07c, 0xa07d, 0xa07e, 0xa07f, 0xa080, 0xa081, 0xa082, 0xa083, 0xa084, 0xa085, 0xa086, 0xa087,
+    0xa088, 0xa089, 0xa08a, 0xa08b, 0xa08c, 0xa08d, 0xa08e, 0xa08f, 0xa090, 0xa091, 0xa092, 0xa093,
+    0xa094, 0xa095, 0xa096, 0xa097, 0xa098, 0xa099, 0xa09a, 0xa09b, 0xa09c, 0xa09d, 0xa09e, 0xa09f,
+    0xa0a0, 0xa0a1, 0xa0a2, 0xa0a3, 0xa0a4, 0xa0a5, 0xa0a6, 0xa0a7, 0xa0a8, 0xa0a9, 0xa0aa, 0xa0ab,
+    0xa0ac, 0xa0ad, 0xa0ae, 0xa0af, 0xa0b0, 0xa0b1, 0xa0b2, 0xa0b3, 0xa0b4, 0xa0b5, 0xa0b6, 0xa0b7,
+    0xa0b8, 0xa0b9, 0xa0ba, 0xa0bb, 0xa0bc, 0xa0bd, 0xa0be, 0xa0bf, 0xa0c0, 0xa0c1, 0xa0c2, 0xa0c3,
+    0xa0c4, 0xa0c5, 0xa0c6, 0xa0c7, 0xa0c8, 0xa0c9, 0xa0ca, 0xa0cb, 0xa0cc, 0xa0cd, 0xa0ce, 0xa0cf,
+    0xa0d0, 0xa0d1, 0xa0d2, 0xa0d3, 0xa0d4, 0xa0d5, 0xa0d6, 0xa0d7, 0xa0d8, 0xa0d9, 0xa0da, 0xa0db,
+    0xa0dc, 0xa0dd, 0xa0de, 0xa0df, 0xa0e0, 0xa0e1, 0xa0e2, 0xa0e3, 0xa0e4, 0xa0e5, 0xa0e6, 0xa0e7,
+    0xa0e8, 0xa0e9, 0xa0ea, 0xa0eb, 0xa0ec, 0xa0ed, 0xa0ee, 0xa0ef, 0xa0f0, 0xa0f1, 0xa0f2, 0xa0f3,
+    0xa0f4, 0xa0f5, 0xa0f6, 0xa0f7, 0xa0f8, 0xa0f9, 0xa0fa, 0xa0fb, 0xa0fc, 0xa0fd, 0xa0fe, 0xa0ff,
+    0xa100, 0xa101, 0xa102, 0xa103, 0xa104, 0xa105, 0xa106, 0xa107, 0xa108, 0xa109, 0xa10a, 0xa10b,
+    0xa10c, 0xa10d, 0xa10e, 0xa10f, 0xa110, 0xa111, 0xa112, 0xa113, 0xa114, 0xa115, 0xa116, 0xa117,
+    0xa118, 0xa119, 0xa11a, 0xa11b, 0xa11c, 0xa11d, 0xa11e, 0xa11f, 0xa120, 0xa121, 0xa122, 0xa123,
+    0xa124, 0xa125, 0xa126, 0xa127, 0xa128, 0xa129, 0xa12a, 0xa12b, 0xa12c, 0xa12d, 0xa12e, 0xa12f,
+    0xa130, 0xa131, 0xa132, 0xa133, 0xa134, 0xa135, 0xa136, 0xa137, 0xa138, 0xa139, 0xa13a, 0xa13b,
+    0xa13c, 0xa13d, 0xa13e, 0xa13f, 0xa140, 0xa141, 0xa142, 0xa143, 0xa144, 0xa145, 0xa146, 0xa147,
+    0xa148, 0xa149, 0xa14a, 0xa14b, 0xa14c, 0xa14d, 0xa14e, 0xa14f, 0xa150, 0xa151, 0xa152, 0xa153,
+    0xa154, 0xa155, 0xa156, 0xa157, 0xa158, 0xa159, 0xa15a, 0xa15b, 0xa15c, 0xa15d, 0xa15e, 0xa15f,
+    0xa160, 0xa161, 0xa162, 0xa163, 0xa164, 0xa165, 0xa166, 0xa167, 0xa168, 0xa169, 0xa16a, 0xa16b,
+    0xa16c, 0xa16d, 0xa16e, 0xa16f, 0xa170, 0xa171, 0xa172, 0xa173, 0xa174, 0xa175, 0xa176, 0xa177,
+    0xa178, 0xa179, 0xa17a, 0xa17b, 0xa17c, 0xa17d, 0xa17e, 0xa17f, 0xa180, 0xa181, 0xa182, 0xa183,
+    0xa184, 0xa185, 0xa186, 0xa187, 0xa188, 0xa189, 0xa18a, 0xa18b, 0xa18c, 0xa18d, 0xa18e, 0xa18f,
+    0xa190, 0xa191, 0xa192, 0xa193, 0xa194, 0xa195, 0xa196, 0xa197, 0xa198, 0xa199, 0xa19a, 0xa19b,
+    0xa19c, 0xa19d, 0xa19e, 0xa19f, 0xa1a0, 0xa1a1, 0xa1a2, 0xa1a3, 0xa1a4, 0xa1a5, 0xa1a6, 0xa1a7,
+    0xa1a8, 0xa1a9, 0xa1aa, 0xa1ab, 0xa1ac, 0xa1ad, 0xa1ae, 0xa1af, 0xa1b0, 0xa1b1, 0xa1b2, 0xa1b3,
+    0xa1b4, 0xa1b5, 0xa1b6, 0xa1b7, 0xa1b8, 0xa1b9, 0xa1ba, 0xa1bb, 0xa1bc, 0xa1bd, 0xa1be, 0xa1bf,
+    0xa1c0, 0xa1c1, 0xa1c2, 0xa1c3, 0xa1c4, 0xa1c5, 0xa1c6, 0xa1c7, 0xa1c8, 0xa1c9, 0xa1ca, 0xa1cb,
+    0xa1cc, 0xa1cd, 0xa1ce, 0xa1cf, 0xa1d0, 0xa1d1, 0xa1d2, 0xa1d3, 0xa1d4, 0xa1d5, 0xa1d6, 0xa1d7,
+    0xa1d8, 0xa1d9, 0xa1da, 0xa1db, 0xa1dc, 0xa1dd, 0xa1de, 0xa1df, 0xa1e0, 0xa1e1, 0xa1e2, 0xa1e3,
+    0xa1e4, 0xa1e5, 0xa1e6, 0xa1e7, 0xa1e8, 0xa1e9, 0xa1ea, 0xa1eb, 0xa1ec, 0xa1ed, 0xa1ee, 0xa1ef,
+    0xa1f0, 0xa1f1, 0xa1f2, 0xa1f3, 0xa1f4, 0xa1f5, 0xa1f6, 0xa1f7, 0xa1f8, 0xa1f9, 0xa1fa, 0xa1fb,
+    0xa1fc, 0xa1fd, 0xa1fe, 0xa1ff, 0xa200, 0xa201, 0xa202, 0xa203, 0xa204, 0xa205, 0xa206, 0xa207,
+    0xa208, 0xa209, 0xa20a, 0xa20b, 0xa20c, 0xa20d, 0xa20e, 0xa20f, 0xa210, 0xa211, 0xa212, 0xa213,
+    0xa214, 0xa215, 0xa216, 0xa217, 0xa218, 0xa219, 0xa21a, 0xa21b, 0xa21c, 0xa21d, 0xa21e, 0xa21f,
+    0xa220, 0xa221, 0xa222, 0xa223, 0xa224, 0xa225, 0xa226, 0xa227, 0xa228, 0xa229, 0xa22a, 0xa22b,
+    0xa22c, 0xa22d, 0xa22e, 0xa22f, 0xa230, 0xa231, 0xa232, 0xa233, 0xa234, 0xa235, 0xa236, 0xa237,
+    0xa238, 0xa239, 0xa23a, 0xa23b, 0xa23c, 0xa23d, 0xa23e, 0xa23f, 0xa240, 0xa241, 0xa242, 0xa243,
+    0xa244, 0xa245, 0xa246, 0xa247, 0xa248, 0xa249, 0xa24a, 0xa24b, 0xa24c, 0xa24d, 0xa24e, 0xa24f,
+    0xa250, 0xa251, 0xa252, 0xa253, 0xa254, 0xa255, 0xa256, 0xa257, 0xa258, 0xa259, 0xa25a, 0xa25b,
+    0xa25c, 0xa25d, 0xa25e, 0xa25f, 0xa260, 0xa261, 0xa262, 0xa263, 0xa264, 0xa265, 0xa266, 0xa267,
+    0xa268, 0xa269, 0xa26a, 0xa26b, 0xa26c, 0xa26d, 0xa26e, 0xa26f, 0xa270, 0xa271, 0xa272, 0xa273,
+    0xa274, 0xa275, 0xa276, 0xa277, 0xa278, 0xa279, 0xa27a, 0xa27b, 0xa27c, 0xa27d, 0xa27e, 0xa27f,
+    0xa280, 0xa281, 0xa282, 0xa283, 0xa284, 0xa285, 0xa286, 0xa287, 0xa288, 0xa289, 0xa28a, 0xa28b,
+    0xa28c, 0xa28d, 0xa28e, 0xa28f, 0xa290, 0xa291, 0xa292, 0xa293, 0xa294, 0xa295, 0xa296, 0xa297,
+    0xa298, 0xa299, 0xa29a, 0xa29b, 0xa29c, 0xa29d, 0xa29e, 0xa29f, 0xa2a0, 0xa2a1, 0xa2a2, 0xa2a3,
+    0xa2a4, 0xa2a5, 0xa2a6, 0xa2a7, 0xa2a8, 0xa2a9, 0xa2aa, 0xa2ab, 0xa2ac, 0xa2ad, 0xa2ae, 0xa2af,
+    0xa2b0, 0xa2b1, 0xa2b2, 0xa2b3, 0xa2b4, 0xa2b5, 0xa2b6, 0xa2b7, 0xa2b8, 0xa2b9, 0xa2ba, 0xa2bb,
+    0xa2bc, 0xa2bd, 0xa2be, 0xa2bf, 0xa2c0, 0xa2c1, 0xa2c2, 0xa2c3, 0xa2c4, 0xa2c5, 0xa2c6, 0xa2c7,
+    0xa2c8, 0xa2c9, 0xa2ca, 0xa2cb, 0xa2cc, 0xa2cd, 0xa2ce, 0xa2cf, 0xa2d0, 0xa2d1, 0xa2d2, 0xa2d3,
+    0xa2d4, 0xa2d5, 0xa2d6, 0xa2d7, 0xa2d8, 0xa2d9, 0xa2da, 0xa2db, 0xa2dc, 0xa2dd, 0xa2de, 0xa2df,
+    0xa2e0, 0xa2e1, 0xa2e2, 0xa2e3, 0xa2e4, 0xa2e5, 0xa2e6, 0xa2e7, 0xa2e8, 0xa2e9, 0xa2ea, 0xa2eb,
+    0xa2ec, 0xa2ed, 0xa2ee, 0xa2ef, 0xa2f0, 0xa2f1, 0xa2f2, 0xa2f3, 0xa2f4, 0xa2f5, 0xa2f6, 0xa2f7,
+    0xa2f8, 0xa2f9, 0xa2fa, 0xa2fb, 0xa2fc, 0xa2fd, 0xa2fe, 0xa2ff, 0xa300, 0xa301, 0xa302, 0xa303,
+    0xa304, 0xa305, 0xa306, 0xa307, 0xa308, 0xa309, 0xa30a, 0xa30b, 0xa30c, 0xa30d, 0xa30e, 0xa30f,
+    0xa310, 0xa311, 0xa312, 0xa313, 0xa314, 0xa315, 0xa316, 0xa317, 0xa318, 0xa319, 0xa31a, 0xa31b,
+    0xa31c, 0xa31d, 0xa31e, 0xa31f, 0xa320, 0xa321, 0xa322, 0xa323, 0xa324, 0xa325, 0xa326, 0xa327,
+    0xa328, 0xa329, 0xa32a, 0xa32b, 0xa32c, 0xa32d, 0xa32e, 0xa32f, 0xa330, 0xa331, 0xa332, 0xa333,
+    0xa334, 0xa335, 0xa336, 0xa337, 0xa338, 0xa339, 0xa33a, 0xa33b, 0xa33c, 0xa33d, 0xa33e, 0xa33f,
+    0xa340, 0xa341, 0xa342, 0xa343, 0xa344, 0xa345, 0xa346, 0xa347, 0xa348, 0xa349, 0xa34a, 0xa34b,
+    0xa34c, 0xa34d, 0xa34e, 0xa34f, 0xa350, 0xa351, 0xa352, 0xa353, 0xa354, 0xa355, 0xa356, 0xa357,
+    0xa358, 0xa359, 0xa35a, 0xa35b, 0xa35c, 0xa35d, 0xa35e, 0xa35f, 0xa360, 0xa361, 0xa362, 0xa363,
+    0xa364, 0xa365, 0xa366, 0xa367, 0xa368, 0xa369, 0xa36a, 0xa36b, 0xa36c, 0xa36d, 0xa36e, 0xa36f,
+    0xa370, 0xa371, 0xa372, 0xa373, 0xa374, 0xa375, 0xa376, 0xa377, 0xa378, 0xa379, 0xa37a, 0xa37b,
+    0xa37c, 0xa37d, 0xa37e, 0xa37f, 0xa380, 0xa381, 0xa382, 0xa383, 0xa384, 0xa385, 0xa386, 0xa387,
+    0xa388, 0xa389, 0xa38a, 0xa38b, 0xa38c, 0xa38d, 0xa38e, 0xa38f, 0xa390, 0xa391, 0xa392, 0xa393,
+    0xa394, 0xa395, 0xa396, 0xa397, 0xa398, 0xa399, 0xa39a, 0xa39b, 0xa39c, 0xa39d, 0xa39e, 0xa39f,
+    0xa3a0, 0xa3a1, 0xa3a2, 0xa3a3, 0xa3a4, 0xa3a5, 0xa3a6, 0xa3a7, 0xa3a8, 0xa3a9, 0xa3aa, 0xa3ab,
+    0xa3ac, 0xa3ad, 0xa3ae, 0xa3af, 0xa3b0, 0xa3b1, 0xa3b2, 0xa3b3, 0xa3b4, 0xa3b5, 0xa3b6, 0xa3b7,
+    0xa3b8, 0xa3b9, 0xa3ba, 0xa3bb, 0xa3bc, 0xa3bd, 0xa3be, 0xa3bf, 0xa3c0, 0xa3c1, 0xa3c2, 0xa3c3,
+    0xa3c4, 0xa3c5, 0xa3c6, 0xa3c7, 0xa3c8, 0xa3c9, 0xa3ca, 0xa3cb, 0xa3cc, 0xa3cd, 0xa3ce, 0xa3cf,
+    0xa3d0, 0xa3d1, 0xa3d2, 0xa3d3, 0xa3d4, 0xa3d5, 0xa3d6, 0xa3d7, 0xa3d8, 0xa3d9, 0xa3da, 0xa3db,
+    0xa3dc, 0xa3dd, 0xa3de, 0xa3df, 0xa3e0, 0xa3e1, 0xa3e2, 0xa3e3, 0xa3e4, 0xa3e5, 0xa3e6, 0xa3e7,
+    0xa3e8, 0xa3e9, 0xa3ea, 0xa3eb, 0xa3ec, 0xa3ed, 0xa3ee, 0xa3ef, 0xa3f0, 0xa3f1, 0xa3f2, 0xa3f3,
+    0xa3f4, 0xa3f5, 0xa3f6, 0xa3f7, 0xa3f8, 0xa3f9, 0xa3fa, 0xa3fb, 0xa3fc, 0xa3fd, 0xa3fe, 0xa3ff,
+    0xa400, 0xa401, 0xa402, 0xa403, 0xa404, 0xa405, 0xa406, 0xa407, 0xa408, 0xa409, 0xa40a, 0xa40b,
+    0xa40c, 0xa40d, 0xa40e, 0xa40f, 0xa410, 0xa411, 0xa412, 0xa413, 0xa414, 0xa415, 0xa416, 0xa417,
+    0xa418, 0xa419, 0xa41a, 0xa41b, 0xa41c, 0xa41d, 0xa41e, 0xa41f, 0xa420, 0xa421, 0xa422, 0xa423,
+    0xa424, 0xa425, 0xa426, 0xa427, 0xa428, 0xa429, 0xa42a, 0xa42b, 0xa42c, 0xa42d, 0xa42e, 0xa42f,
+    0xa430, 0xa431, 0xa432, 0xa433, 0xa434, 0xa435, 0xa436, 0xa437, 0xa438, 0xa439, 0xa43a, 0xa43b,
+    0xa43c, 0xa43d, 0xa43e, 0xa43f, 0xa440, 0xa441, 0xa442, 0xa443, 0xa444, 0xa445, 0xa446, 0xa447,
+    0xa448, 0xa449, 0xa44a, 0xa44b, 0xa44c, 0xa44d, 0xa44e, 0xa44f, 0xa450, 0xa451, 0xa452, 0xa453,
+    0xa454, 0xa455, 0xa456, 0xa457, 0xa458, 0xa459, 0xa45a, 0xa45b, 0xa45c, 0xa45d, 0xa45e, 0xa45f,
+    0xa460, 0xa461, 0xa462, 0xa463, 0xa464, 0xa465, 0xa466, 0xa467, 0xa468, 0xa469, 0xa46a, 0xa46b,
+    0xa46c, 0xa46d, 0xa46e, 0xa46f, 0xa470, 0xa471, 0xa472, 0xa473, 0xa474, 0xa475, 0xa476, 0xa477,
+    0xa478, 0xa479, 0xa47a, 0xa47b, 0xa47c, 0xa47d, 0xa47e, 0xa47f, 0xa480, 0xa481, 0xa482, 0xa483,
+    0xa484, 0xa485, 0xa486, 0xa487, 0xa488, 0xa489, 0xa48a, 0xa48b, 0xa48c, 0xa490, 0xa491, 0xa492,
+    0xa493, 0xa494, 0xa495, 0xa496, 0xa497, 0xa498, 0xa499, 0xa49a, 0xa49b, 0xa49c, 0xa49d, 0xa49e,
+    0xa49f, 0xa4a0, 0xa4a1, 0xa4a2, 0xa4a3, 0xa4a4, 0xa4a5, 0xa4a6, 0xa4a7, 0xa4a8, 0xa4a9, 0xa4aa,
+    0xa4ab, 0xa4ac, 0xa4ad, 0xa4ae, 0xa4af, 0xa4b0, 0xa4b1, 0xa4b2, 0xa4b3, 0xa4b4, 0xa4b5, 0xa4b6,
+    0xa4b7, 0xa4b8, 0xa4b9, 0xa4ba, 0xa4bb, 0xa4bc, 0xa4bd, 0xa4be, 0xa4bf, 0xa4c0, 0xa4c1, 0xa4c2,
+    0xa4c3, 0xa4c4, 0xa4c5, 0xa4c6, 0xa4d0, 0xa4d1, 0xa4d2, 0xa4d3, 0xa4d4, 0xa4d5, 0xa4d6, 0xa4d7,
+    0xa4d8, 0xa4d9, 0xa4da, 0xa4db, 0xa4dc, 0xa4dd, 0xa4de, 0xa4df, 0xa4e0, 0xa4e1, 0xa4e2, 0xa4e3,
+    0xa4e4, 0xa4e5, 0xa4e6, 0xa4e7, 0xa4e8, 0xa4e9, 0xa4ea, 0xa4eb, 0xa4ec, 0xa4ed, 0xa4ee, 0xa4ef,
+    0xa4f0, 0xa4f1, 0xa4f2, 0xa4f3, 0xa4f4, 0xa4f5, 0xa4f6, 0xa4f7, 0xa4f8, 0xa4f9, 0xa4fa, 0xa4fb,
+    0xa4fc, 0xa4fd, 0xa4fe, 0xa4ff, 0xa500, 0xa501, 0xa502, 0xa503, 0xa504, 0xa505, 0xa506, 0xa507,
+    0xa508, 0xa509, 0xa50a, 0xa50b, 0xa50c, 0xa50d, 0xa50e, 0xa50f, 0xa510, 0xa511, 0xa512, 0xa513,
+    0xa514, 0xa515, 0xa516, 0xa517, 0xa518, 0xa519, 0xa51a, 0xa51b, 0xa51c, 0xa51d, 0xa51e, 0xa51f,
+    0xa520, 0xa521, 0xa522, 0xa523, 0xa524, 0xa525, 0xa526, 0xa527, 0xa528, 0xa529, 0xa52a, 0xa52b,
+    0xa52c, 0xa52d, 0xa52e, 0xa52f, 0xa530, 0xa531, 0xa532, 0xa533, 0xa534, 0xa535, 0xa536, 0xa537,
+    0xa538, 0xa539, 0xa53a, 0xa53b, 0xa53c, 0xa53d, 0xa53e, 0xa53f, 0xa540, 0xa541, 0xa542, 0xa543,
+    0xa544, 0xa545, 0xa546, 0xa547, 0xa548, 0xa549, 0xa54a, 0xa54b, 0xa54c, 0xa54d, 0xa54e, 0xa54f,
+    0xa550, 0xa551, 0xa552, 0xa553, 0xa554, 0xa555, 0xa556, 0xa557, 0xa558, 0xa559, 0xa55a, 0xa55b,
+    0xa55c, 0xa55d, 0xa55e, 0xa55f, 0xa560, 0xa561, 0xa562, 0xa563, 0xa564, 0xa565, 0xa566, 0xa567,
+    0xa568, 0xa569, 0xa56a, 0xa56b, 0xa56c, 0xa56d, 0xa56e, 0xa56f, 0xa570, 0xa571, 0xa572, 0xa573,
+    0xa574, 0xa575, 0xa576, 0xa577, 0xa578, 0xa579, 0xa57a, 0xa57b, 0xa57c, 0xa57d, 0xa57e, 0xa57f,
+    0xa580, 0xa581, 0xa582, 0xa583, 0xa584, 0xa585, 0xa586, 0xa587, 0xa588, 0xa589, 0xa58a, 0xa58b,
+    0xa58c, 0xa58d, 0xa58e, 0xa58f, 0xa590, 0xa591, 0xa592, 0xa593, 0xa594, 0xa595, 0xa596, 0xa597,
+    0xa598, 0xa599, 0xa59a, 0xa59b, 0xa59c, 0xa59d, 0xa59e, 0xa59f, 0xa5a0, 0xa5a1, 0xa5a2, 0xa5a3,
+    0xa5a4, 0xa5a5, 0xa5a6, 0xa5a7, 0xa5a8, 0xa5a9, 0xa5aa, 0xa5ab, 0xa5ac, 0xa5ad, 0xa5ae, 0xa5af,
+    0xa5b0, 0xa5b1, 0xa5b2, 0xa5b3, 0xa5b4, 0xa5b5, 0xa5b6, 0xa5b7, 0xa5b8, 0xa5b9, 0xa5ba, 0xa5bb,
+    0xa5bc, 0xa5bd, 0xa5be, 0xa5bf, 0xa5c0, 0xa5c1, 0xa5c2, 0xa5c3, 0xa5c4, 0xa5c5, 0xa5c6, 0xa5c7,
+    0xa5c8, 0xa5c9, 0xa5ca, 0xa5cb, 0xa5cc, 0xa5cd, 0xa5ce, 0xa5cf, 0xa5d0, 0xa5d1, 0xa5d2, 0xa5d3,
+    0xa5d4, 0xa5d5, 0xa5d6, 0xa5d7, 0xa5d8, 0xa5d9, 0xa5da, 0xa5db, 0xa5dc, 0xa5dd, 0xa5de, 0xa5df,
+    0xa5e0, 0xa5e1, 0xa5e2, 0xa5e3, 0xa5e4, 0xa5e5, 0xa5e6, 0xa5e7, 0xa5e8, 0xa5e9, 0xa5ea, 0xa5eb,
+    0xa5ec, 0xa5ed, 0xa5ee, 0xa5ef, 0xa5f0, 0xa5f1, 0xa5f2, 0xa5f3, 0xa5f4, 0xa5f5, 0xa5f6, 0xa5f7,
+    0xa5f8, 0xa5f9, 0xa5fa, 0xa5fb, 0xa5fc, 0xa5fd, 0xa5fe, 0xa5ff, 0xa600, 0xa601, 0xa602, 0xa603,
+    0xa604, 0xa605, 0xa606, 0xa607, 0xa608, 0xa609, 0xa60a, 0xa60b, 0xa60c, 0xa60d, 0xa60e, 0xa60f,
+    0xa610, 0xa611, 0xa612, 0xa613, 0xa614, 0xa615, 0xa616, 0xa617, 0xa618, 0xa619, 0xa61a, 0xa61b,
+    0xa61c, 0xa61d, 0xa61e, 0xa61f, 0xa620, 0xa621, 0xa622, 0xa623, 0xa624, 0xa625, 0xa626, 0xa627,
+    0xa628, 0xa629, 0xa62a, 0xa62b, 0xa640, 0xa641, 0xa642, 0xa643, 0xa644, 0xa645, 0xa646, 0xa647,
+    0xa648, 0xa649, 0xa64a, 0xa64b, 0xa64c, 0xa64d, 0xa64e, 0xa64f, 0xa650, 0xa651, 0xa652, 0xa653,
+    0xa654, 0xa655, 0xa656, 0xa657, 0xa658, 0xa659, 0xa65a, 0xa65b, 0xa65c, 0xa65d, 0xa65e, 0xa65f,
+    0xa660, 0xa661, 0xa662, 0xa663, 0xa664, 0xa665, 0xa666, 0xa667, 0xa668, 0xa669, 0xa66a, 0xa66b,
+    0xa66c, 0xa66d, 0xa66e, 0xa66f, 0xa670, 0xa671, 0xa672, 0xa673, 0xa674, 0xa675, 0xa676, 0xa677,
+    0xa678, 0xa679, 0xa67a, 0xa67b, 0xa67c, 0xa67d, 0xa67e, 0xa67f, 0xa680, 0xa681, 0xa682, 0xa683,
+    0xa684, 0xa685, 0xa686, 0xa687, 0xa688, 0xa689, 0xa68a, 0xa68b, 0xa68c, 0xa68d, 0xa68e, 0xa68f,
+    0xa690, 0xa691, 0xa692, 0xa693, 0xa694, 0xa695, 0xa696, 0xa697, 0xa698, 0xa699, 0xa69a, 0xa69b,
+    0xa69c, 0xa69d, 0xa69e, 0xa69f, 0xa6a0, 0xa6a1, 0xa6a2, 0xa6a3, 0xa6a4, 0xa6a5, 0xa6a6, 0xa6a7,
+    0xa6a8, 0xa6a9, 0xa6aa, 0xa6ab, 0xa6ac, 0xa6ad, 0xa6ae, 0xa6af, 0xa6b0, 0xa6b1, 0xa6b2, 0xa6b3,
+    0xa6b4, 0xa6b5, 0xa6b6, 0xa6b7, 0xa6b8, 0xa6b9, 0xa6ba, 0xa6bb, 0xa6bc, 0xa6bd, 0xa6be, 0xa6bf,
+    0xa6c0, 0xa6c1, 0xa6c2, 0xa6c3, 0xa6c4, 0xa6c5, 0xa6c6, 0xa6c7, 0xa6c8, 0xa6c9, 0xa6ca, 0xa6cb,
+    0xa6cc, 0xa6cd, 0xa6ce, 0xa6cf, 0xa6d0, 0xa6d1, 0xa6d2, 0xa6d3, 0xa6d4, 0xa6d5, 0xa6d6, 0xa6d7,
+    0xa6d8, 0xa6d9, 0xa6da, 0xa6db, 0xa6dc, 0xa6dd, 0xa6de, 0xa6df, 0xa6e0, 0xa6e1, 0xa6e2, 0xa6e3,
+    0xa6e4, 0xa6e5, 0xa6e6, 0xa6e7, 0xa6e8, 0xa6e9, 0xa6ea, 0xa6eb, 0xa6ec, 0xa6ed, 0xa6ee, 0xa6ef,
+    0xa6f0, 0xa6f1, 0xa6f2, 0xa6f3, 0xa6f4, 0xa6f5, 0xa6f6, 0xa6f7, 0xa700, 0xa701, 0xa702, 0xa703,
+    0xa704, 0xa705, 0xa706, 0xa707, 0xa708, 0xa709, 0xa70a, 0xa70b, 0xa70c, 0xa70d, 0xa70e, 0xa70f,
+    0xa710, 0xa711, 0xa712, 0xa713, 0xa714, 0xa715, 0xa716, 0xa717, 0xa718, 0xa719, 0xa71a, 0xa71b,
+    0xa71c, 0xa71d, 0xa71e, 0xa71f, 0xa720, 0xa721, 0xa722, 0xa723, 0xa724, 0xa725, 0xa726, 0xa727,
+    0xa728, 0xa729, 0xa72a, 0xa72b, 0xa72c, 0xa72d, 0xa72e, 0xa72f, 0xa730, 0xa731, 0xa732, 0xa733,
+    0xa734, 0xa735, 0xa736, 0xa737, 0xa738, 0xa739, 0xa73a, 0xa73b, 0xa73c, 0xa73d, 0xa73e, 0xa73f,
+    0xa740, 0xa741, 0xa742, 0xa743, 0xa744, 0xa745, 0xa746, 0xa747, 0xa748, 0xa749, 0xa74a, 0xa74b,
+    0xa74c, 0xa74d, 0xa74e, 0xa74f, 0xa750, 0xa751, 0xa752, 0xa753, 0xa754, 0xa755, 0xa756, 0xa757,
+    0xa758, 0xa759, 0xa75a, 0xa75b, 0xa75c, 0xa75d, 0xa75e, 0xa75f, 0xa760, 0xa761, 0xa762, 0xa763,
+    0xa764, 0xa765, 0xa766, 0xa767, 0xa768, 0xa769, 0xa76a, 0xa76b, 0xa76c, 0xa76d, 0xa76e, 0xa76f,
+    0xa770, 0xa771, 0xa772, 0xa773, 0xa774, 0xa775, 0xa776, 0xa777, 0xa778, 0xa779, 0xa77a, 0xa77b,
+    0xa77c, 0xa77d, 0xa77e, 0xa77f, 0xa780, 0xa781, 0xa782, 0xa783, 0xa784, 0xa785, 0xa786, 0xa787,
+    0xa788, 0xa789, 0xa78a, 0xa78b, 0xa78c, 0xa78d, 0xa78e, 0xa78f, 0xa790, 0xa791, 0xa792, 0xa793,
+    0xa794, 0xa795, 0xa796, 0xa797, 0xa798, 0xa799, 0xa79a, 0xa79b, 0xa79c, 0xa79d, 0xa79e, 0xa79f,
+    0xa7a0, 0xa7a1, 0xa7a2, 0xa7a3, 0xa7a4, 0xa7a5, 0xa7a6, 0xa7a7, 0xa7a8, 0xa7a9, 0xa7aa, 0xa7ab,
+    0xa7ac, 0xa7ad, 0xa7ae, 0xa7af, 0xa7b0, 0xa7b1, 0xa7b2, 0xa7b3, 0xa7b4, 0xa7b5, 0xa7b6, 0xa7b7,
+    0xa7b8, 0xa7b9, 0xa7ba, 0xa7bb, 0xa7bc, 0xa7bd, 0xa7be, 0xa7bf, 0xa7c2, 0xa7c3, 0xa7c4, 0xa7c5,
+    0xa7c6, 0xa7c7, 0xa7c8, 0xa7c9, 0xa7ca, 0xa7f5, 0xa7f6, 0xa7f7, 0xa7f8, 0xa7f9, 0xa7fa, 0xa7fb,
+    0xa7fc, 0xa7fd, 0xa7fe, 0xa7ff, 0xa800, 0xa801, 0xa802, 0xa803, 0xa804, 0xa805, 0xa806, 0xa807,
+    0xa808, 0xa809, 0xa80a, 0xa80b, 0xa80c, 0xa80d, 0xa80e, 0xa80f, 0xa810, 0xa811, 0xa812, 0xa813,
+    0xa814, 0xa815, 0xa816, 0xa817, 0xa818, 0xa819, 0xa81a, 0xa81b, 0xa81c, 0xa81d, 0xa81e, 0xa81f,
+    0xa820, 0xa821, 0xa822, 0xa823, 0xa824, 0xa825, 0xa826, 0xa827, 0xa828, 0xa829, 0xa82a, 0xa82b,
+    0xa82c, 0xa830, 0xa831, 0xa832, 0xa833, 0xa834, 0xa835, 0xa836, 0xa837, 0xa838, 0xa839, 0xa840,
+    0xa841, 0xa842, 0xa843, 0xa844, 0xa845, 0xa846, 0xa847, 0xa848, 0xa849, 0xa84a, 0xa84b, 0xa84c,
+    0xa84d, 0xa84e, 0xa84f, 0xa850, 0xa851, 0xa852, 0xa853, 0xa854, 0xa855, 0xa856, 0xa857, 0xa858,
+    0xa859, 0xa85a, 0xa85b, 0xa85c, 0xa85d, 0xa85e, 0xa85f, 0xa860, 0xa861, 0xa862, 0xa863, 0xa864,
+    0xa865, 0xa866, 0xa867, 0xa868, 0xa869, 0xa86a, 0xa86b, 0xa86c, 0xa86d, 0xa86e, 0xa86f, 0xa870,
+    0xa871, 0xa872, 0xa873, 0xa874, 0xa875, 0xa876, 0xa877, 0xa880, 0xa881, 0xa882, 0xa883, 0xa884,
+    0xa885, 0xa886, 0xa887, 0xa888, 0xa889, 0xa88a, 0xa88b, 0xa88c, 0xa88d, 0xa88e, 0xa88f, 0xa890,
+    0xa891, 0xa892, 0xa893, 0xa894, 0xa895, 0xa896, 0xa897, 0xa898, 0xa899, 0xa89a, 0xa89b, 0xa89c,
+    0xa89d, 0xa89e, 0xa89f, 0xa8a0, 0xa8a1, 0xa8a2, 0xa8a3, 0xa8a4, 0xa8a5, 0xa8a6, 0xa8a7, 0xa8a8,
+    0xa8a9, 0xa8aa, 0xa8ab, 0xa8ac, 0xa8ad, 0xa8ae, 0xa8af, 0xa8b0, 0xa8b1, 0xa8b2, 0xa8b3, 0xa8b4,
+    0xa8b5, 0xa8b6, 0xa8b7, 0xa8b8, 0xa8b9, 0xa8ba, 0xa8bb, 0xa8bc, 0xa8bd, 0xa8be, 0xa8bf, 0xa8c0,
+    0xa8c1, 0xa8c2, 0xa8c3, 0xa8c4, 0xa8c5, 0xa8ce, 0xa8cf, 0xa8d0, 0xa8d1, 0xa8d2, 0xa8d3, 0xa8d4,
+    0xa8d5, 0xa8d6, 0xa8d7, 0xa8d8, 0xa8d9, 0xa8e0, 0xa8e1, 0xa8e2, 0xa8e3, 0xa8e4, 0xa8e5, 0xa8e6,
+    0xa8e7, 0xa8e8, 0xa8e9, 0xa8ea, 0xa8eb, 0xa8ec, 0xa8ed, 0xa8ee, 0xa8ef, 0xa8f0, 0xa8f1, 0xa8f2,
+    0xa8f3, 0xa8f4, 0xa8f5, 0xa8f6, 0xa8f7, 0xa8f8, 0xa8f9, 0xa8fa, 0xa8fb, 0xa8fc, 0xa8fd, 0xa8fe,
+    0xa8ff, 0xa900, 0xa901, 0xa902, 0xa903, 0xa904, 0xa905, 0xa906, 0xa907, 0xa908, 0xa909, 0xa90a,
+    0xa90b, 0xa90c, 0xa90d, 0xa90e, 0xa90f, 0xa910, 0xa911, 0xa912, 0xa913, 0xa914, 0xa915, 0xa916,
+    0xa917, 0xa918, 0xa919, 0xa91a, 0xa91b, 0xa91c, 0xa91d, 0xa91e, 0xa91f, 0xa920, 0xa921, 0xa922,
+    0xa923, 0xa924, 0xa925, 0xa926, 0xa927, 0xa928, 0xa929, 0xa92a, 0xa92b, 0xa92c, 0xa92d, 0xa92e,
+    0xa92f, 0xa930, 0xa931, 0xa932, 0xa933, 0xa934, 0xa935, 0xa936, 0xa937, 0xa938, 0xa939, 0xa93a,
+    0xa93b, 0xa93c, 0xa93d, 0xa93e, 0xa93f, 0xa940, 0xa941, 0xa942, 0xa943, 0xa944, 0xa945, 0xa946,
+    0xa947, 0xa948, 0xa949, 0xa94a, 0xa94b, 0xa94c, 0xa94d, 0xa94e, 0xa94f, 0xa950, 0xa951, 0xa952,
+    0xa953, 0xa95f, 0xa960, 0xa961, 0xa962, 0xa963, 0xa964, 0xa965, 0xa966, 0xa967, 0xa968, 0xa969,
+    0xa96a, 0xa96b, 0xa96c, 0xa96d, 0xa96e, 0xa96f, 0xa970, 0xa971, 0xa972, 0xa973, 0xa974, 0xa975,
+    0xa976, 0xa977, 0xa978, 0xa979, 0xa97a, 0xa97b, 0xa97c, 0xa980, 0xa981, 0xa982, 0xa983, 0xa984,
+    0xa985, 0xa986, 0xa987, 0xa988, 0xa989, 0xa98a, 0xa98b, 0xa98c, 0xa98d, 0xa98e, 0xa98f, 0xa990,
+    0xa991, 0xa992, 0xa993, 0xa994, 0xa995, 0xa996, 0xa997, 0xa998, 0xa999, 0xa99a, 0xa99b, 0xa99c,
+    0xa99d, 0xa99e, 0xa99f, 0xa9a0, 0xa9a1, 0xa9a2, 0xa9a3, 0xa9a4, 0xa9a5, 0xa9a6, 0xa9a7, 0xa9a8,
+    0xa9a9, 0xa9aa, 0xa9ab, 0xa9ac, 0xa9ad, 0xa9ae, 0xa9af, 0xa9b0, 0xa9b1, 0xa9b2, 0xa9b3, 0xa9b4,
+    0xa9b5, 0xa9b6, 0xa9b7, 0xa9b8, 0xa9b9, 0xa9ba, 0xa9bb, 0xa9bc, 0xa9bd, 0xa9be, 0xa9bf, 0xa9c0,
+    0xa9c1, 0xa9c2, 0xa9c3, 0xa9c4, 0xa9c5, 0xa9c6, 0xa9c7, 0xa9c8, 0xa9c9, 0xa9ca, 0xa9cb, 0xa9cc,
+    0xa9cd, 0xa9cf, 0xa9d0, 0xa9d1, 0xa9d2, 0xa9d3, 0xa9d4, 0xa9d5, 0xa9d6, 0xa9d7, 0xa9d8, 0xa9d9,
+    0xa9de, 0xa9df, 0xa9e0, 0xa9e1, 0xa9e2, 0xa9e3, 0xa9e4, 0xa9e5, 0xa9e6, 0xa9e7, 0xa9e8, 0xa9e9,
+    0xa9ea, 0xa9eb, 0xa9ec, 0xa9ed, 0xa9ee, 0xa9ef, 0xa9f0, 0xa9f1, 0xa9f2, 0xa9f3, 0xa9f4, 0xa9f5,
+    0xa9f6, 0xa9f7, 0xa9f8, 0xa9f9, 0xa9fa, 0xa9fb, 0xa9fc, 0xa9fd, 0xa9fe, 0xaa00, 0xaa01, 0xaa02,
+    0xaa03, 0xaa04, 0xaa05, 0xaa06, 0xaa07, 0xaa08, 0xaa09, 0xaa0a, 0xaa0b, 0xaa0c, 0xaa0d, 0xaa0e,
+    0xaa0f, 0xaa10, 0xaa11, 0xaa12, 0xaa13, 0xaa14, 0xaa15, 0xaa16, 0xaa17, 0xaa18, 0xaa19, 0xaa1a,
+    0xaa1b, 0xaa1c, 0xaa1d, 0xaa1e, 0xaa1f, 0xaa20, 0xaa21, 0xaa22, 0xaa23, 0xaa24, 0xaa25, 0xaa26,
+    0xaa27, 0xaa28, 0xaa29, 0xaa2a, 0xaa2b, 0xaa2c, 0xaa2d, 0xaa2e, 0xaa2f, 0xaa30, 0xaa31, 0xaa32,
+    0xaa33, 0xaa34, 0xaa35, 0xaa36, 0xaa40, 0xaa41, 0xaa42, 0xaa43, 0xaa44, 0xaa45, 0xaa46, 0xaa47,
+    0xaa48, 0xaa49, 0xaa4a, 0xaa4b, 0xaa4c, 0xaa4d, 0xaa50, 0xaa51, 0xaa52, 0xaa53, 0xaa54, 0xaa55,
+    0xaa56, 0xaa57, 0xaa58, 0xaa59, 0xaa5c, 0xaa5d, 0xaa5e, 0xaa5f, 0xaa60, 0xaa61, 0xaa62, 0xaa63,
+    0xaa64, 0xaa65, 0xaa66, 0xaa67, 0xaa68, 0xaa69, 0xaa6a, 0xaa6b, 0xaa6c, 0xaa6d, 0xaa6e, 0xaa6f,
+    0xaa70, 0xaa71, 0xaa72, 0xaa73, 0xaa74, 0xaa75, 0xaa76, 0xaa77, 0xaa78, 0xaa79, 0xaa7a, 0xaa7b,
+    0xaa7c, 0xaa7d, 0xaa7e, 0xaa7f, 0xaa80, 0xaa81, 0xaa82, 0xaa83, 0xaa84, 0xaa85, 0xaa86, 0xaa87,
+    0xaa88, 0xaa89, 0xaa8a, 0xaa8b, 0xaa8c, 0xaa8d, 0xaa8e, 0xaa8f, 0xaa90, 0xaa91, 0xaa92, 0xaa93,
+    0xaa94, 0xaa95, 0xaa96, 0xaa97, 0xaa98, 0xaa99, 0xaa9a, 0xaa9b, 0xaa9c, 0xaa9d, 0xaa9e, 0xaa9f,
+    0xaaa0, 0xaaa1, 0xaaa2, 0xaaa3, 0xaaa4, 0xaaa5, 0xaaa6, 0xaaa7, 0xaaa8, 0xaaa9, 0xaaaa, 0xaaab,
+    0xaaac, 0xaaad, 0xaaae, 0xaaaf, 0xaab0, 0xaab1, 0xaab2, 0xaab3, 0xaab4, 0xaab5, 0xaab6, 0xaab7,
+    0xaab8, 0xaab9, 0xaaba, 0xaabb, 0xaabc, 0xaabd, 0xaabe, 0xaabf, 0xaac0, 0xaac1, 0xaac2, 0xaadb,
+    0xaadc, 0xaadd, 0xaade, 0xaadf, 0xaae0, 0xaae1, 0xaae2, 0xaae3, 0xaae4, 0xaae5, 0xaae6, 0xaae7,
+    0xaae8, 0xaae9, 0xaaea, 0xaaeb, 0xaaec, 0xaaed, 0xaaee, 0xaaef, 0xaaf0, 0xaaf1, 0xaaf2, 0xaaf3,
+    0xaaf4, 0xaaf5, 0xaaf6, 0xab01, 0xab02, 0xab03, 0xab04, 0xab05, 0xab06, 0xab09, 0xab0a, 0xab0b,
+    0xab0c, 0xab0d, 0xab0e, 0xab11, 0xab12, 0xab13, 0xab14, 0xab15, 0xab16, 0xab20, 0xab21, 0xab22,
+    0xab23, 0xab24, 0xab25, 0xab26, 0xab28, 0xab29, 0xab2a, 0xab2b, 0xab2c, 0xab2d, 0xab2e, 0xab30,
+    0xab31, 0xab32, 0xab33, 0xab34, 0xab35, 0xab36, 0xab37, 0xab38, 0xab39, 0xab3a, 0xab3b, 0xab3c,
+    0xab3d, 0xab3e, 0xab3f, 0xab40, 0xab41, 0xab42, 0xab43, 0xab44, 0xab45, 0xab46, 0xab47, 0xab48,
+    0xab49, 0xab4a, 0xab4b, 0xab4c, 0xab4d, 0xab4e, 0xab4f, 0xab50, 0xab51, 0xab52, 0xab53, 0xab54,
+    0xab55, 0xab56, 0xab57, 0xab58, 0xab59, 0xab5a, 0xab5b, 0xab5c, 0xab5d, 0xab5e, 0xab5f, 0xab60,
+    0xab61, 0xab62, 0xab63, 0xab64, 0xab65, 0xab66, 0xab67, 0xab68, 0xab69, 0xab6a, 0xab6b, 0xab70,
+    0xab71, 0xab72, 0xab73, 0xab74, 0xab75, 0xab76, 0xab77, 0xab78, 0xab79, 0xab7a, 0xab7b, 0xab7c,
+    0xab7d, 0xab7e, 0xab7f, 0xab80, 0xab81, 0xab82, 0xab83, 0xab84, 0xab85, 0xab86, 0xab87, 0xab88,
+    0xab89, 0xab8a, 0xab8b, 0xab8c, 0xab8d, 0xab8e, 0xab8f, 0xab90, 0xab91, 0xab92, 0xab93, 0xab94,
+    0xab95, 0xab96, 0xab97, 0xab98, 0xab99, 0xab9a, 0xab9b, 0xab9c, 0xab9d, 0xab9e, 0xab9f, 0xaba0,
+    0xaba1, 0xaba2, 0xaba3, 0xaba4, 0xaba5, 0xaba6, 0xaba7, 0xaba8, 0xaba9, 0xabaa, 0xabab, 0xabac,
+    0xabad, 0xabae, 0xabaf, 0xabb0, 0xabb1, 0xabb2, 0xabb3, 0xabb4, 0xabb5, 0xabb6, 0xabb7, 0xabb8,
+    0xabb9, 0xabba, 0xabbb, 0xabbc, 0xabbd, 0xabbe, 0xabbf, 0xabc0, 0xabc1, 0xabc2, 0xabc3, 0xabc4,
+    0xabc5, 0xabc6, 0xabc7, 0xabc8, 0xabc9, 0xabca, 0xabcb, 0xabcc, 0xabcd, 0xabce, 0xabcf, 0xabd0,
+    0xabd1, 0xabd2, 0xabd3, 0xabd4, 0xabd5, 0xabd6, 0xabd7, 0xabd8, 0xabd9, 0xabda, 0xabdb, 0xabdc,
+    0xabdd, 0xabde, 0xabdf, 0xabe0, 0xabe1, 0xabe2, 0xabe3, 0xabe4, 0xabe5, 0xabe6, 0xabe7, 0xabe8,
+    0xabe9, 0xabea, 0xabeb, 0xabec, 0xabed, 0xabf0, 0xabf1, 0xabf2, 0xabf3, 0xabf4, 0xabf5, 0xabf6,
+    0xabf7, 0xabf8, 0xabf9, 0xd7b0, 0xd7b1, 0xd7b2, 0xd7b3, 0xd7b4, 0xd7b5, 0xd7b6, 0xd7b7, 0xd7b8,
+    0xd7b9, 0xd7ba, 0xd7bb, 0xd7bc, 0xd7bd, 0xd7be, 0xd7bf, 0xd7c0, 0xd7c1, 0xd7c2, 0xd7c3, 0xd7c4,
+    0xd7c5, 0xd7c6, 0xd7cb, 0xd7cc, 0xd7cd, 0xd7ce, 0xd7cf, 0xd7d0, 0xd7d1, 0xd7d2, 0xd7d3, 0xd7d4,
+    0xd7d5, 0xd7d6, 0xd7d7, 0xd7d8, 0xd7d9, 0xd7da, 0xd7db, 0xd7dc, 0xd7dd, 0xd7de, 0xd7df, 0xd7e0,
+    0xd7e1, 0xd7e2, 0xd7e3, 0xd7e4, 0xd7e5, 0xd7e6, 0xd7e7, 0xd7e8, 0xd7e9, 0xd7ea, 0xd7eb, 0xd7ec,
+    0xd7ed, 0xd7ee, 0xd7ef, 0xd7f0, 0xd7f1, 0xd7f2, 0xd7f3, 0xd7f4, 0xd7f5, 0xd7f6, 0xd7f7, 0xd7f8,
+    0xd7f9, 0xd7fa, 0xd7fb, 0xf900, 0xf901, 0xf902, 0xf903, 0xf904, 0xf905, 0xf906, 0xf907, 0xf908,
+    0xf909, 0xf90a, 0xf90b, 0xf90c, 0xf90d, 0xf90e, 0xf90f, 0xf910, 0xf911, 0xf912, 0xf913, 0xf914,
+    0xf915, 0xf916, 0xf917, 0xf918, 0xf919, 0xf91a, 0xf91b, 0xf91c, 0xf91d, 0xf91e, 0xf91f, 0xf920,
+    0xf921, 0xf922, 0xf923, 0xf924, 0xf925, 0xf926, 0xf927, 0xf928, 0xf929, 0xf92a, 0xf92b, 0xf92c,
+    0xf92d, 0xf92e, 0xf92f, 0xf930, 0xf931, 0xf932, 0xf933, 0xf934, 0xf935, 0xf936, 0xf937, 0xf938,
+    0xf939, 0xf93a, 0xf93b, 0xf93c, 0xf93d, 0xf93e, 0xf93f, 0xf940, 0xf941, 0xf942, 0xf943, 0xf944,
+    0xf945, 0xf946, 0xf947, 0xf948, 0xf949, 0xf94a, 0xf94b, 0xf94c, 0xf94d, 0xf94e, 0xf94f, 0xf950,
+    0xf951, 0xf952, 0xf953, 0xf954, 0xf955, 0xf956, 0xf957, 0xf958, 0xf959, 0xf95a, 0xf95b, 0xf95c,
+    0xf95d, 0xf95e, 0xf95f, 0xf960, 0xf961, 0xf962, 0xf963, 0xf964, 0xf965, 0xf966, 0xf967, 0xf968,
+    0xf969, 0xf96a, 0xf96b, 0xf96c, 0xf96d, 0xf96e, 0xf96f, 0xf970, 0xf971, 0xf972, 0xf973, 0xf974,
+    0xf975, 0xf976, 0xf977, 0xf978, 0xf979, 0xf97a, 0xf97b, 0xf97c, 0xf97d, 0xf97e, 0xf97f, 0xf980,
+    0xf981, 0xf982, 0xf983, 0xf984, 0xf985, 0xf986, 0xf987, 0xf988, 0xf989, 0xf98a, 0xf98b, 0xf98c,
+    0xf98d, 0xf98e, 0xf98f, 0xf990, 0xf991, 0xf992, 0xf993, 0xf994, 0xf995, 0xf996, 0xf997, 0xf998,
+    0xf999, 0xf99a, 0xf99b, 0xf99c, 0xf99d, 0xf99e, 0xf99f, 0xf9a0, 0xf9a1, 0xf9a2, 0xf9a3, 0xf9a4,
+    0xf9a5, 0xf9a6, 0xf9a7, 0xf9a8, 0xf9a9, 0xf9aa, 0xf9ab, 0xf9ac, 0xf9ad, 0xf9ae, 0xf9af, 0xf9b0,
+    0xf9b1, 0xf9b2, 0xf9b3, 0xf9b4, 0xf9b5, 0xf9b6, 0xf9b7, 0xf9b8, 0xf9b9, 0xf9ba, 0xf9bb, 0xf9bc,
+    0xf9bd, 0xf9be, 0xf9bf, 0xf9c0, 0xf9c1, 0xf9c2, 0xf9c3, 0xf9c4, 0xf9c5, 0xf9c6, 0xf9c7, 0xf9c8,
+    0xf9c9, 0xf9ca, 0xf9cb, 0xf9cc, 0xf9cd, 0xf9ce, 0xf9cf, 0xf9d0, 0xf9d1, 0xf9d2, 0xf9d3, 0xf9d4,
+    0xf9d5, 0xf9d6, 0xf9d7, 0xf9d8, 0xf9d9, 0xf9da, 0xf9db, 0xf9dc, 0xf9dd, 0xf9de, 0xf9df, 0xf9e0,
+    0xf9e1, 0xf9e2, 0xf9e3, 0xf9e4, 0xf9e5, 0xf9e6, 0xf9e7, 0xf9e8, 0xf9e9, 0xf9ea, 0xf9eb, 0xf9ec,
+    0xf9ed, 0xf9ee, 0xf9ef, 0xf9f0, 0xf9f1, 0xf9f2, 0xf9f3, 0xf9f4, 0xf9f5, 0xf9f6, 0xf9f7, 0xf9f8,
+    0xf9f9, 0xf9fa, 0xf9fb, 0xf9fc, 0xf9fd, 0xf9fe, 0xf9ff, 0xfa00, 0xfa01, 0xfa02, 0xfa03, 0xfa04,
+    0xfa05, 0xfa06, 0xfa07, 0xfa08, 0xfa09, 0xfa0a, 0xfa0b, 0xfa0c, 0xfa0d, 0xfa0e, 0xfa0f, 0xfa10,
+    0xfa11, 0xfa12, 0xfa13, 0xfa14, 0xfa15, 0xfa16, 0xfa17, 0xfa18, 0xfa19, 0xfa1a, 0xfa1b, 0xfa1c,
+    0xfa1d, 0xfa1e, 0xfa1f, 0xfa20, 0xfa21, 0xfa22, 0xfa23, 0xfa24, 0xfa25, 0xfa26, 0xfa27, 0xfa28,
+    0xfa29, 0xfa2a, 0xfa2b, 0xfa2c, 0xfa2d, 0xfa2e, 0xfa2f, 0xfa30, 0xfa31, 0xfa32, 0xfa33, 0xfa34,
+    0xfa35, 0xfa36, 0xfa37, 0xfa38, 0xfa39, 0xfa3a, 0xfa3b, 0xfa3c, 0xfa3d, 0xfa3e, 0xfa3f, 0xfa40,
+    0xfa41, 0xfa42, 0xfa43, 0xfa44, 0xfa45, 0xfa46, 0xfa47, 0xfa48, 0xfa49, 0xfa4a, 0xfa4b, 0xfa4c,
+    0xfa4d, 0xfa4e, 0xfa4f, 0xfa50, 0xfa51, 0xfa52, 0xfa53, 0xfa54, 0xfa55, 0xfa56, 0xfa57, 0xfa58,
+    0xfa59, 0xfa5a, 0xfa5b, 0xfa5c, 0xfa5d, 0xfa5e, 0xfa5f, 0xfa60, 0xfa61, 0xfa62, 0xfa63, 0xfa64,
+    0xfa65, 0xfa66, 0xfa67, 0xfa68, 0xfa69, 0xfa6a, 0xfa6b, 0xfa6c, 0xfa6d, 0xfa70, 0xfa71, 0xfa72,
+    0xfa73, 0xfa74, 0xfa75, 0xfa76, 0xfa77, 0xfa78, 0xfa79, 0xfa7a, 0xfa7b, 0xfa7c, 0xfa7d, 0xfa7e,
+    0xfa7f, 0xfa80, 0xfa81, 0xfa82, 0xfa83, 0xfa84, 0xfa85, 0xfa86, 0xfa87, 0xfa88, 0xfa89, 0xfa8a,
+    0xfa8b, 0xfa8c, 0xfa8d, 0xfa8e, 0xfa8f, 0xfa90, 0xfa91, 0xfa92, 0xfa93, 0xfa94, 0xfa95, 0xfa96,
+    0xfa97, 0xfa98, 0xfa99, 0xfa9a, 0xfa9b, 0xfa9c, 0xfa9d, 0xfa9e, 0xfa9f, 0xfaa0, 0xfaa1, 0xfaa2,
+    0xfaa3, 0xfaa4, 0xfaa5, 0xfaa6, 0xfaa7, 0xfaa8, 0xfaa9, 0xfaaa, 0xfaab, 0xfaac, 0xfaad, 0xfaae,
+    0xfaaf, 0xfab0, 0xfab1, 0xfab2, 0xfab3, 0xfab4, 0xfab5, 0xfab6, 0xfab7, 0xfab8, 0xfab9, 0xfaba,
+    0xfabb, 0xfabc, 0xfabd, 0xfabe, 0xfabf, 0xfac0, 0xfac1, 0xfac2, 0xfac3, 0xfac4, 0xfac5, 0xfac6,
+    0xfac7, 0xfac8, 0xfac9, 0xfaca, 0xfacb, 0xfacc, 0xfacd, 0xface, 0xfacf, 0xfad0, 0xfad1, 0xfad2,
+    0xfad3, 0xfad4, 0xfad5, 0xfad6, 0xfad7, 0xfad8, 0xfad9, 0xfb00, 0xfb01, 0xfb02, 0xfb03, 0xfb04,
+    0xfb05, 0xfb06, 0xfb13, 0xfb14, 0xfb15, 0xfb16, 0xfb17, 0xfb1d, 0xfb1e, 0xfb1f, 0xfb20, 0xfb21,
+    0xfb22, 0xfb23, 0xfb24, 0xfb25, 0xfb26, 0xfb27, 0xfb28, 0xfb29, 0xfb2a, 0xfb2b, 0xfb2c, 0xfb2d,
+    0xfb2e, 0xfb2f, 0xfb30, 0xfb31, 0xfb32, 0xfb33, 0xfb34, 0xfb35, 0xfb36, 0xfb38, 0xfb39, 0xfb3a,
+    0xfb3b, 0xfb3c, 0xfb3e, 0xfb40, 0xfb41, 0xfb43, 0xfb44, 0xfb46, 0xfb47, 0xfb48, 0xfb49, 0xfb4a,
+    0xfb4b, 0xfb4c, 0xfb4d, 0xfb4e, 0xfb4f, 0xfb50, 0xfb51, 0xfb52, 0xfb53, 0xfb54, 0xfb55, 0xfb56,
+    0xfb57, 0xfb58, 0xfb59, 0xfb5a, 0xfb5b, 0xfb5c, 0xfb5d, 0xfb5e, 0xfb5f, 0xfb60, 0xfb61, 0xfb62,
+    0xfb63, 0xfb64, 0xfb65, 0xfb66, 0xfb67, 0xfb68, 0xfb69, 0xfb6a, 0xfb6b, 0xfb6c, 0xfb6d, 0xfb6e,
+    0xfb6f, 0xfb70, 0xfb71, 0xfb72, 0xfb73, 0xfb74, 0xfb75, 0xfb76, 0xfb77, 0xfb78, 0xfb79, 0xfb7a,
+    0xfb7b, 0xfb7c, 0xfb7d, 0xfb7e, 0xfb7f, 0xfb80, 0xfb81, 0xfb82, 0xfb83, 0xfb84, 0xfb85, 0xfb86,
+    0xfb87, 0xfb88, 0xfb89, 0xfb8a, 0xfb8b, 0xfb8c, 0xfb8d, 0xfb8e, 0xfb8f, 0xfb90, 0xfb91, 0xfb92,
+    0xfb93, 0xfb94, 0xfb95, 0xfb96, 0xfb97, 0xfb98, 0xfb99, 0xfb9a, 0xfb9b, 0xfb9c, 0xfb9d, 0xfb9e,
+    0xfb9f, 0xfba0, 0xfba1, 0xfba2, 0xfba3, 0xfba4, 0xfba5, 0xfba6, 0xfba7, 0xfba8, 0xfba9, 0xfbaa,
+    0xfbab, 0xfbac, 0xfbad, 0xfbae, 0xfbaf, 0xfbb0, 0xfbb1, 0xfbb2, 0xfbb3, 0xfbb4, 0xfbb5, 0xfbb6,
+    0xfbb7, 0xfbb8, 0xfbb9, 0xfbba, 0xfbbb, 0xfbbc, 0xfbbd, 0xfbbe, 0xfbbf, 0xfbc0, 0xfbc1, 0xfbd3,
+    0xfbd4, 0xfbd5, 0xfbd6, 0xfbd7, 0xfbd8, 0xfbd9, 0xfbda, 0xfbdb, 0xfbdc, 0xfbdd, 0xfbde, 0xfbdf,
+    0xfbe0, 0xfbe1, 0xfbe2, 0xfbe3, 0xfbe4, 0xfbe5, 0xfbe6, 0xfbe7, 0xfbe8, 0xfbe9, 0xfbea, 0xfbeb,
+    0xfbec, 0xfbed, 0xfbee, 0xfbef, 0xfbf0, 0xfbf1, 0xfbf2, 0xfbf3, 0xfbf4, 0xfbf5, 0xfbf6, 0xfbf7,
+    0xfbf8, 0xfbf9, 0xfbfa, 0xfbfb, 0xfbfc, 0xfbfd, 0xfbfe, 0xfbff, 0xfc00, 0xfc01, 0xfc02, 0xfc03,
+    0xfc04, 0xfc05, 0xfc06, 0xfc07, 0xfc08, 0xfc09, 0xfc0a, 0xfc0b, 0xfc0c, 0xfc0d, 0xfc0e, 0xfc0f,
+    0xfc10, 0xfc11, 0xfc12, 0xfc13, 0xfc14, 0xfc15, 0xfc16, 0xfc17, 0xfc18, 0xfc19, 0xfc1a, 0xfc1b,
+    0xfc1c, 0xfc1d, 0xfc1e, 0xfc1f, 0xfc20, 0xfc21, 0xfc22, 0xfc23, 0xfc24, 0xfc25, 0xfc26, 0xfc27,
+    0xfc28, 0xfc29, 0xfc2a, 0xfc2b, 0xfc2c, 0xfc2d, 0xfc2e, 0xfc2f, 0xfc30, 0xfc31, 0xfc32, 0xfc33,
+    0xfc34, 0xfc35, 0xfc36, 0xfc37, 0xfc38, 0xfc39, 0xfc3a, 0xfc3b, 0xfc3c, 0xfc3d, 0xfc3e, 0xfc3f,
+    0xfc40, 0xfc41, 0xfc42, 0xfc43, 0xfc44, 0xfc45, 0xfc46, 0xfc47, 0xfc48, 0xfc49, 0xfc4a, 0xfc4b,
+    0xfc4c, 0xfc4d, 0xfc4e, 0xfc4f, 0xfc50, 0xfc51, 0xfc52, 0xfc53, 0xfc54, 0xfc55, 0xfc56, 0xfc57,
+    0xfc58, 0xfc59, 0xfc5a, 0xfc5b, 0xfc5c, 0xfc5d, 0xfc5e, 0xfc5f, 0xfc60, 0xfc61, 0xfc62, 0xfc63,
+    0xfc64, 0xfc65, 0xfc66, 0xfc67, 0xfc68, 0xfc69, 0xfc6a, 0xfc6b, 0xfc6c, 0xfc6d, 0xfc6e, 0xfc6f,
+    0xfc70, 0xfc71, 0xfc72, 0xfc73, 0xfc74, 0xfc75, 0xfc76, 0xfc77, 0xfc78, 0xfc79, 0xfc7a, 0xfc7b,
+    0xfc7c, 0xfc7d, 0xfc7e, 0xfc7f, 0xfc80, 0xfc81, 0xfc82, 0xfc83, 0xfc84, 0xfc85, 0xfc86, 0xfc87,
+    0xfc88, 0xfc89, 0xfc8a, 0xfc8b, 0xfc8c, 0xfc8d, 0xfc8e, 0xfc8f, 0xfc90, 0xfc91, 0xfc92, 0xfc93,
+    0xfc94, 0xfc95, 0xfc96, 0xfc97, 0xfc98, 0xfc99, 0xfc9a, 0xfc9b, 0xfc9c, 0xfc9d, 0xfc9e, 0xfc9f,
+    0xfca0, 0xfca1, 0xfca2, 0xfca3, 0xfca4, 0xfca5, 0xfca6, 0xfca7, 0xfca8, 0xfca9, 0xfcaa, 0xfcab,
+    0xfcac, 0xfcad, 0xfcae, 0xfcaf, 0xfcb0, 0xfcb1, 0xfcb2, 0xfcb3, 0xfcb4, 0xfcb5, 0xfcb6, 0xfcb7,
+    0xfcb8, 0xfcb9, 0xfcba, 0xfcbb, 0xfcbc, 0xfcbd, 0xfcbe, 0xfcbf, 0xfcc0, 0xfcc1, 0xfcc2, 0xfcc3,
+    0xfcc4, 0xfcc5, 0xfcc6, 0xfcc7, 0xfcc8, 0xfcc9, 0xfcca, 0xfccb, 0xfccc, 0xfccd, 0xfcce, 0xfccf,
+    0xfcd0, 0xfcd1, 0xfcd2, 0xfcd3, 0xfcd4, 0xfcd5, 0xfcd6, 0xfcd7, 0xfcd8, 0xfcd9, 0xfcda, 0xfcdb,
+    0xfcdc, 0xfcdd, 0xfcde, 0xfcdf, 0xfce0, 0xfce1, 0xfce2, 0xfce3, 0xfce4, 0xfce5, 0xfce6, 0xfce7,
+    0xfce8, 0xfce9, 0xfcea, 0xfceb, 0xfcec, 0xfced, 0xfcee, 0xfcef, 0xfcf0, 0xfcf1, 0xfcf2, 0xfcf3,
+    0xfcf4, 0xfcf5, 0xfcf6, 0xfcf7, 0xfcf8, 0xfcf9, 0xfcfa, 0xfcfb, 0xfcfc, 0xfcfd, 0xfcfe, 0xfcff,
+    0xfd00, 0xfd01, 0xfd02, 0xfd03, 0xfd04, 0xfd05, 0xfd06, 0xfd07, 0xfd08, 0xfd09, 0xfd0a, 0xfd0b,
+    0xfd0c, 0xfd0d, 0xfd0e, 0xfd0f, 0xfd10, 0xfd11, 0xfd12, 0xfd13, 0xfd14, 0xfd15, 0xfd16, 0xfd17,
+    0xfd18, 0xfd19, 0xfd1a, 0xfd1b, 0xfd1c, 0xfd1d, 0xfd1e, 0xfd1f, 0xfd20, 0xfd21, 0xfd22, 0xfd23,
+    0xfd24, 0xfd25, 0xfd26, 0xfd27, 0xfd28, 0xfd29, 0xfd2a, 0xfd2b, 0xfd2c, 0xfd2d, 0xfd2e, 0xfd2f,
+    0xfd30, 0xfd31, 0xfd32, 0xfd33, 0xfd34, 0xfd35, 0xfd36, 0xfd37, 0xfd38, 0xfd39, 0xfd3a, 0xfd3b,
+    0xfd3c, 0xfd3d, 0xfd3e, 0xfd3f, 0xfd50, 0xfd51, 0xfd52, 0xfd53, 0xfd54, 0xfd55, 0xfd56, 0xfd57,
+    0xfd58, 0xfd59, 0xfd5a, 0xfd5b, 0xfd5c, 0xfd5d, 0xfd5e, 0xfd5f, 0xfd60, 0xfd61, 0xfd62, 0xfd63,
+    0xfd64, 0xfd65, 0xfd66, 0xfd67, 0xfd68, 0xfd69, 0xfd6a, 0xfd6b, 0xfd6c, 0xfd6d, 0xfd6e, 0xfd6f,
+    0xfd70, 0xfd71, 0xfd72, 0xfd73, 0xfd74, 0xfd75, 0xfd76, 0xfd77, 0xfd78, 0xfd79, 0xfd7a, 0xfd7b,
+    0xfd7c, 0xfd7d, 0xfd7e, 0xfd7f, 0xfd80, 0xfd81, 0xfd82, 0xfd83, 0xfd84, 0xfd85, 0xfd86, 0xfd87,
+    0xfd88, 0xfd89, 0xfd8a, 0xfd8b, 0xfd8c, 0xfd8d, 0xfd8e, 0xfd8f, 0xfd92, 0xfd93, 0xfd94, 0xfd95,
+    0xfd96, 0xfd97, 0xfd98, 0xfd99, 0xfd9a, 0xfd9b, 0xfd9c, 0xfd9d, 0xfd9e, 0xfd9f, 0xfda0, 0xfda1,
+    0xfda2, 0xfda3, 0xfda4, 0xfda5, 0xfda6, 0xfda7, 0xfda8, 0xfda9, 0xfdaa, 0xfdab, 0xfdac, 0xfdad,
+    0xfdae, 0xfdaf, 0xfdb0, 0xfdb1, 0xfdb2, 0xfdb3, 0xfdb4, 0xfdb5, 0xfdb6, 0xfdb7, 0xfdb8, 0xfdb9,
+    0xfdba, 0xfdbb, 0xfdbc, 0xfdbd, 0xfdbe, 0xfdbf, 0xfdc0, 0xfdc1, 0xfdc2, 0xfdc3, 0xfdc4, 0xfdc5,
+    0xfdc6, 0xfdc7, 0xfdf0, 0xfdf1, 0xfdf2, 0xfdf3, 0xfdf4, 0xfdf5, 0xfdf6, 0xfdf7, 0xfdf8, 0xfdf9,
+    0xfdfa, 0xfdfb, 0xfdfc, 0xfdfd, 0xfe00, 0xfe01, 0xfe02, 0xfe03, 0xfe04, 0xfe05, 0xfe06, 0xfe07,
+    0xfe08, 0xfe09, 0xfe0a, 0xfe0b, 0xfe0c, 0xfe0d, 0xfe0e, 0xfe0f, 0xfe10, 0xfe11, 0xfe12, 0xfe13,
+    0xfe14, 0xfe15, 0xfe16, 0xfe17, 0xfe18, 0xfe19, 0xfe20, 0xfe21, 0xfe22, 0xfe23, 0xfe24, 0xfe25,
+    0xfe26, 0xfe27, 0xfe28, 0xfe29, 0xfe2a, 0xfe2b, 0xfe2c, 0xfe2d, 0xfe2e, 0xfe2f, 0xfe30, 0xfe31,
+    0xfe32, 0xfe33, 0xfe34, 0xfe35, 0xfe36, 0xfe37, 0xfe38, 0xfe39, 0xfe3a, 0xfe3b, 0xfe3c, 0xfe3d,
+    0xfe3e, 0xfe3f, 0xfe40, 0xfe41, 0xfe42, 0xfe43, 0xfe44, 0xfe45, 0xfe46, 0xfe47, 0xfe48, 0xfe49,
+    0xfe4a, 0xfe4b, 0xfe4c, 0xfe4d, 0xfe4e, 0xfe4f, 0xfe50, 0xfe51, 0xfe52, 0xfe54, 0xfe55, 0xfe56,
+    0xfe57, 0xfe58, 0xfe59, 0xfe5a, 0xfe5b, 0xfe5c, 0xfe5d, 0xfe5e, 0xfe5f, 0xfe60, 0xfe61, 0xfe62,
+    0xfe63, 0xfe64, 0xfe65, 0xfe66, 0xfe68, 0xfe69, 0xfe6a, 0xfe6b, 0xfe70, 0xfe71, 0xfe72, 0xfe73,
+    0xfe74, 0xfe76, 0xfe77, 0xfe78, 0xfe79, 0xfe7a, 0xfe7b, 0xfe7c, 0xfe7d, 0xfe7e, 0xfe7f, 0xfe80,
+    0xfe81, 0xfe82, 0xfe83, 0xfe84, 0xfe85, 0xfe86, 0xfe87, 0xfe88, 0xfe89, 0xfe8a, 0xfe8b, 0xfe8c,
+    0xfe8d, 0xfe8e, 0xfe8f, 0xfe90, 0xfe91, 0xfe92, 0xfe93, 0xfe94, 0xfe95, 0xfe96, 0xfe97, 0xfe98,
+    0xfe99, 0xfe9a, 0xfe9b, 0xfe9c, 0xfe9d, 0xfe9e, 0xfe9f, 0xfea0, 0xfea1, 0xfea2, 0xfea3, 0xfea4,
+    0xfea5, 0xfea6, 0xfea7, 0xfea8, 0xfea9, 0xfeaa, 0xfeab, 0xfeac, 0xfead, 0xfeae, 0xfeaf, 0xfeb0,
+    0xfeb1, 0xfeb2, 0xfeb3, 0xfeb4, 0xfeb5, 0xfeb6, 0xfeb7, 0xfeb8, 0xfeb9, 0xfeba, 0xfebb, 0xfebc,
+    0xfebd, 0xfebe, 0xfebf, 0xfec0, 0xfec1, 0xfec2, 0xfec3, 0xfec4, 0xfec5, 0xfec6, 0xfec7, 0xfec8,
+    0xfec9, 0xfeca, 0xfecb, 0xfecc, 0xfecd, 0xfece, 0xfecf, 0xfed0, 0xfed1, 0xfed2, 0xfed3, 0xfed4,
+    0xfed5, 0xfed6, 0xfed7, 0xfed8, 0xfed9, 0xfeda, 0xfedb, 0xfedc, 0xfedd, 0xfede, 0xfedf, 0xfee0,
+    0xfee1, 0xfee2, 0xfee3, 0xfee4, 0xfee5, 0xfee6, 0xfee7, 0xfee8, 0xfee9, 0xfeea, 0xfeeb, 0xfeec,
+    0xfeed, 0xfeee, 0xfeef, 0xfef0, 0xfef1, 0xfef2, 0xfef3, 0xfef4, 0xfef5, 0xfef6, 0xfef7, 0xfef8,
+    0xfef9, 0xfefa, 0xfefb, 0xfefc, 0xfeff, 0xff01, 0xff02, 0xff03, 0xff04, 0xff05, 0xff06, 0xff07,
+    0xff08, 0xff09, 0xff0a, 0xff0b, 0xff0c, 0xff0d, 0xff0e, 0xff0f, 0xff10, 0xff11, 0xff12, 0xff13,
+    0xff14, 0xff15, 0xff16, 0xff17, 0xff18, 0xff19, 0xff1a, 0xff1b, 0xff1c, 0xff1d, 0xff1e, 0xff1f,
+    0xff20, 0xff21, 0xff22, 0xff23, 0xff24, 0xff25, 0xff26, 0xff27, 0xff28, 0xff29, 0xff2a, 0xff2b,
+    0xff2c, 0xff2d, 0xff2e, 0xff2f, 0xff30, 0xff31, 0xff32, 0xff33, 0xff34, 0xff35, 0xff36, 0xff37,
+    0xff38, 0xff39, 0xff3a, 0xff3b, 0xff3c, 0xff3d, 0xff3e, 0xff3f, 0xff40, 0xff41, 0xff42, 0xff43,
+    0xff44, 0xff45, 0xff46, 0xff47, 0xff48, 0xff49, 0xff4a, 0xff4b, 0xff4c, 0xff4d, 0xff4e, 0xff4f,
+    0xff50, 0xff51, 0xff52, 0xff53, 0xff54, 0xff55, 0xff56, 0xff57, 0xff58, 0xff59, 0xff5a, 0xff5b,
+    0xff5c, 0xff5d, 0xff5e, 0xff5f, 0xff60, 0xff61, 0xff62, 0xff63, 0xff64, 0xff65, 0xff66, 0xff67,
+    0xff68, 0xff69, 0xff6a, 0xff6b, 0xff6c, 0xff6d, 0xff6e, 0xff6f, 0xff70, 0xff71, 0xff72, 0xff73,
+    0xff74, 0xff75, 0xff76, 0xff77, 0xff78, 0xff79, 0xff7a, 0xff7b, 0xff7c, 0xff7d, 0xff7e, 0xff7f,
+    0xff80, 0xff81, 0xff82, 0xff83, 0xff84, 0xff85, 0xff86, 0xff87, 0xff88, 0xff89, 0xff8a, 0xff8b,
+    0xff8c, 0xff8d, 0xff8e, 0xff8f, 0xff90, 0xff91, 0xff92, 0xff93, 0xff94, 0xff95, 0xff96, 0xff97,
+    0xff98, 0xff99, 0xff9a, 0xff9b, 0xff9c, 0xff9d, 0xff9e, 0xff9f, 0xffa0, 0xffa1, 0xffa2, 0xffa3,
+    0xffa4, 0xffa5, 0xffa6, 0xffa7, 0xffa8, 0xffa9, 0xffaa, 0xffab, 0xffac, 0xffad, 0xffae, 0xffaf,
+    0xffb0, 0xffb1, 0xffb2, 0xffb3, 0xffb4, 0xffb5, 0xffb6, 0xffb7, 0xffb8, 0xffb9, 0xffba, 0xffbb,
+    0xffbc, 0xffbd, 0xffbe, 0xffc2, 0xffc3, 0xffc4, 0xffc5, 0xffc6, 0xffc7, 0xffca, 0xffcb, 0xffcc,
+    0xffcd, 0xffce, 0xffcf, 0xffd2, 0xffd3, 0xffd4, 0xffd5, 0xffd6, 0xffd7, 0xffda, 0xffdb, 0xffdc,
+    0xffe0, 0xffe1, 0xffe2, 0xffe3, 0xffe4, 0xffe5, 0xffe6, 0xffe8, 0xffe9, 0xffea, 0xffeb, 0xffec,
+    0xffed, 0xffee, 0xfff9, 0xfffa, 0xfffb, 0xfffc, 0xfffd, 0x10000, 0x10001, 0x10002, 0x10003,
+    0x10004, 0x10005, 0x10006, 0x10007, 0x10008, 0x10009, 0x1000a, 0x1000b, 0x1000d, 0x1000e,
+    0x1000f, 0x10010, 0x10011, 0x10012, 0x10013, 0x10014, 0x10015, 0x10016, 0x10017, 0x10018,
+    0x10019, 0x1001a, 0x1001b, 0x1001c, 0x1001d, 0x1001e, 0x1001f, 0x10020, 0x10021, 0x10022,
+    0x10023, 0x10024, 0x10025, 0x10026, 0x10028, 0x10029, 0x1002a, 0x1002b, 0x1002c, 0x1002d,
+    0x1002e, 0x1002f, 0x10030, 0x10031, 0x10032, 0x10033, 0x10034, 0x10035, 0x10036, 0x10037,
+    0x10038, 0x10039, 0x1003a, 0x1003c, 0x1003d, 0x1003f, 0x10040, 0x10041, 0x10042, 0x10043,
+    0x10044, 0x10045, 0x10046, 0x10047, 0x10048, 0x10049, 0x1004a, 0x1004b, 0x1004c, 0x1004d,
+    0x10050, 0x10051, 0x10052, 0x10053, 0x10054, 0x10055, 0x10056, 0x10057, 0x10058, 0x10059,
+    0x1005a, 0x1005b, 0x1005c, 0x1005d, 0x10080, 0x10081, 0x10082, 0x10083, 0x10084, 0x10085,
+    0x10086, 0x10087, 0x10088, 0x10089, 0x1008a, 0x1008b, 0x1008c, 0x1008d, 0x1008e, 0x1008f,
+    0x10090, 0x10091, 0x10092, 0x10093, 0x10094, 0x10095, 0x10096, 0x10097, 0x10098, 0x10099,
+    0x1009a, 0x1009b, 0x1009c, 0x1009d, 0x1009e, 0x1009f, 0x100a0, 0x100a1, 0x100a2, 0x100a3,
+    0x100a4, 0x100a5, 0x100a6, 0x100a7, 0x100a8, 0x100a9, 0x100aa, 0x100ab, 0x100ac, 0x100ad,
+    0x100ae, 0x100af, 0x100b0, 0x100b1, 0x100b2, 0x100b3, 0x100b4, 0x100b5, 0x100b6, 0x100b7,
+    0x100b8, 0x100b9, 0x100ba, 0x100bb, 0x100bc, 0x100bd, 0x100be, 0x100bf, 0x100c0, 0x100c1,
+    0x100c2, 0x100c3, 0x100c4, 0x100c5, 0x100c6, 0x100c7, 0x100c8, 0x100c9, 0x100ca, 0x100cb,
+    0x100cc, 0x100cd, 0x100ce, 0x100cf, 0x100d0, 0x100d1, 0x100d2, 0x100d3, 0x100d4, 0x100d5,
+    0x100d6, 0x100d7, 0x100d8, 0x100d9, 0x100da, 0x100db, 0x100dc, 0x100dd, 0x100de, 0x100df,
+    0x100e0, 0x100e1, 0x100e2, 0x100e3, 0x100e4, 0x100e5, 0x100e6, 0x100e7, 0x100e8, 0x100e9,
+    0x100ea, 0x100eb, 0x100ec, 0x100ed, 0x100ee, 0x100ef, 0x100f0, 0x100f1, 0x100f2, 0x100f3,
+    0x100f4, 0x100f5, 0x100f6, 0x100f7, 0x100f8, 0x100f9, 0x100fa, 0x10100, 0x10101, 0x10102,
+    0x10107, 0x10108, 0x10109, 0x1010a, 0x1010b, 0x1010c, 0x1010d, 0x1010e, 0x1010f, 0x10110,
+    0x10111, 0x10112, 0x10113, 0x10114, 0x10115, 0x10116, 0x10117, 0x10118, 0x10119, 0x1011a,
+    0x1011b, 0x1011c, 0x1011d, 0x1011e, 0x1011f, 0x10120, 0x10121, 0x10122, 0x10123, 0x10124,
+    0x10125, 0x10126, 0x10127, 0x10128, 0x10129, 0x1012a, 0x1012b, 0x1012c, 0x1012d, 0x1012e,
+    0x1012f, 0x10130, 0x10131, 0x10132, 0x10133, 0x10137, 0x10138, 0x10139, 0x1013a, 0x1013b,
+    0x1013c, 0x1013d, 0x1013e, 0x1013f, 0x10140, 0x10141, 0x10142, 0x10143, 0x10144, 0x10145,
+    0x10146, 0x10147, 0x10148, 0x10149, 0x1014a, 0x1014b, 0x1014c, 0x1014d, 0x1014e, 0x1014f,
+    0x10150, 0x10151, 0x10152, 0x10153, 0x10154, 0x10155, 0x10156, 0x10157, 0x10158, 0x10159,
+    0x1015a, 0x1015b, 0x1015c, 0x1015d, 0x1015e, 0x1015f, 0x10160, 0x10161, 0x10162, 0x10163,
+    0x10164, 0x10165, 0x10166, 0x10167, 0x10168, 0x10169, 0x1016a, 0x1016b, 0x1016c, 0x1016d,
+    0x1016e, 0x1016f, 0x10170, 0x10171, 0x10172, 0x10173, 0x10174, 0x10175, 0x10176, 0x10177,
+    0x10178, 0x10179, 0x1017a, 0x1017b, 0x1017c, 0x1017d, 0x1017e, 0x1017f, 0x10180, 0x10181,
+    0x10182, 0x10183, 0x10184, 0x10185, 0x10186, 0x10187, 0x10188, 0x10189, 0x1018a, 0x1018b,
+    0x1018c, 0x1018d, 0x1018e, 0x10190, 0x10191, 0x10192, 0x10193, 0x10194, 0x10195, 0x10196,
+    0x10197, 0x10198, 0x10199, 0x1019a, 0x1019b, 0x1019c, 0x101a0, 0x101d0, 0x101d1, 0x101d2,
+    0x101d3, 0x101d4, 0x101d5, 0x101d6, 0x101d7, 0x101d8, 0x101d9, 0x101da, 0x101db, 0x101dc,
+    0x101dd, 0x101de, 0x101df, 0x101e0, 0x101e1, 0x101e2, 0x101e3, 0x101e4, 0x101e5, 0x101e6,
+    0x101e7, 0x101e8, 0x101e9, 0x101ea, 0x101eb, 0x101ec, 0x101ed, 0x101ee, 0x101ef, 0x101f0,
+    0x101f1, 0x101f2, 0x101f3, 0x101f4, 0x101f5, 0x101f6, 0x101f7, 0x101f8, 0x101f9, 0x101fa,
+    0x101fb, 0x101fc, 0x101fd, 0x10280, 0x10281, 0x10282, 0x10283, 0x10284, 0x10285, 0x10286,
+    0x10287, 0x10288, 0x10289, 0x1028a, 0x1028b, 0x1028c, 0x1028d, 0x1028e, 0x1028f, 0x10290,
+    0x10291, 0x10292, 0x10293, 0x10294, 0x10295, 0x10296, 0x10297, 0x10298, 0x10299, 0x1029a,
+    0x1029b, 0x1029c, 0x102a0, 0x102a1, 0x102a2, 0x102a3, 0x102a4, 0x102a5, 0x102a6, 0x102a7,
+    0x102a8, 0x102a9, 0x102aa, 0x102ab, 0x102ac, 0x102ad, 0x102ae, 0x102af, 0x102b0, 0x102b1,
+    0x102b2, 0x102b3, 0x102b4, 0x102b5, 0x102b6, 0x102b7, 0x102b8, 0x102b9, 0x102ba, 0x102bb,
+    0x102bc, 0x102bd, 0x102be, 0x102bf, 0x102c0, 0x102c1, 0x102c2, 0x102c3, 0x102c4, 0x102c5,
+    0x102c6, 0x102c7, 0x102c8, 0x102c9, 0x102ca, 0x102cb, 0x102cc, 0x102cd, 0x102ce, 0x102cf,
+    0x102d0, 0x102e0, 0x102e1, 0x102e2, 0x102e3, 0x102e4, 0x102e5, 0x102e6, 0x102e7, 0x102e8,
+    0x102e9, 0x102ea, 0x102eb, 0x102ec, 0x102ed, 0x102ee, 0x102ef, 0x102f0, 0x102f1, 0x102f2,
+    0x102f3, 0x102f4, 0x102f5, 0x102f6, 0x102f7, 0x102f8, 0x102f9, 0x102fa, 0x102fb, 0x10300,
+    0x10301, 0x10302, 0x10303, 0x10304, 0x10305, 0x10306, 0x10307, 0x10308, 0x10309, 0x1030a,
+    0x1030b, 0x1030c, 0x1030d, 0x1030e, 0x1030f, 0x10310, 0x10311, 0x10312, 0x10313, 0x10314,
+    0x10315, 0x10316, 0x10317, 0x10318, 0x10319, 0x1031a, 0x1031b, 0x1031c, 0x1031d, 0x1031e,
+    0x1031f, 0x10320, 0x10321, 0x10322, 0x10323, 0x1032d, 0x1032e, 0x1032f, 0x10330, 0x10331,
+    0x10332, 0x10333, 0x10334, 0x10335, 0x10336, 0x10337, 0x10338, 0x10339, 0x1033a, 0x1033b,
+    0x1033c, 0x1033d, 0x1033e, 0x1033f, 0x10340, 0x10341, 0x10342, 0x10343, 0x10344, 0x10345,
+    0x10346, 0x10347, 0x10348, 0x10349, 0x1034a, 0x10350, 0x10351, 0x10352, 0x10353, 0x10354,
+    0x10355, 0x10356, 0x10357, 0x10358, 0x10359, 0x1035a, 0x1035b, 0x1035c, 0x1035d, 0x1035e,
+    0x1035f, 0x10360, 0x10361, 0x10362, 0x10363, 0x10364, 0x10365, 0x10366, 0x10367, 0x10368,
+    0x10369, 0x1036a, 0x1036b, 0x1036c, 0x1036d, 0x1036e, 0x1036f, 0x10370, 0x10371, 0x10372,
+    0x10373, 0x10374, 0x10375, 0x10376, 0x10377, 0x10378, 0x10379, 0x1037a, 0x10380, 0x10381,
+    0x10382, 0x10383, 0x10384, 0x10385, 0x10386, 0x10387, 0x10388, 0x10389, 0x1038a, 0x1038b,
+    0x1038c, 0x1038d, 0x1038e, 0x1038f, 0x10390, 0x10391, 0x10392, 0x10393, 0x10394, 0x10395,
+    0x10396, 0x10397, 0x10398, 0x10399, 0x1039a, 0x1039b, 0x1039c, 0x1039d, 0x1039f, 0x103a0,
+    0x103a1, 0x103a2, 0x103a3, 0x103a4, 0x103a5, 0x103a6, 0x103a7, 0x103a8, 0x103a9, 0x103aa,
+    0x103ab, 0x103ac, 0x103ad, 0x103ae, 0x103af, 0x103b0, 0x103b1, 0x103b2, 0x103b3, 0x103b4,
+    0x103b5, 0x103b6, 0x103b7, 0x103b8, 0x103b9, 0x103ba, 0x103bb, 0x103bc, 0x103bd, 0x103be,
+    0x103bf, 0x103c0, 0x103c1, 0x103c2, 0x103c3, 0x103c8, 0x103c9, 0x103ca, 0x103cb, 0x103cc,
+    0x103cd, 0x103ce, 0x103cf, 0x103d0, 0x103d1, 0x103d2, 0x103d3, 0x103d4, 0x103d5, 0x10400,
+    0x10401, 0x10402, 0x10403, 0x10404, 0x10405, 0x10406, 0x10407, 0x10408, 0x10409, 0x1040a,
+    0x1040b, 0x1040c, 0x1040d, 0x1040e, 0x1040f, 0x10410, 0x10411, 0x10412, 0x10413, 0x10414,
+    0x10415, 0x10416, 0x10417, 0x10418, 0x10419, 0x1041a, 0x1041b, 0x1041c, 0x1041d, 0x1041e,
+    0x1041f, 0x10420, 0x10421, 0x10422, 0x10423, 0x10424, 0x10425, 0x10426, 0x10427, 0x10428,
+    0x10429, 0x1042a, 0x1042b, 0x1042c, 0x1042d, 0x1042e, 0x1042f, 0x10430, 0x10431, 0x10432,
+    0x10433, 0x10434, 0x10435, 0x10436, 0x10437, 0x10438, 0x10439, 0x1043a, 0x1043b, 0x1043c,
+    0x1043d, 0x1043e, 0x1043f, 0x10440, 0x10441, 0x10442, 0x10443, 0x10444, 0x10445, 0x10446,
+    0x10447, 0x10448, 0x10449, 0x1044a, 0x1044b, 0x1044c, 0x1044d, 0x1044e, 0x1044f, 0x10450,
+    0x10451, 0x10452, 0x10453, 0x10454, 0x10455, 0x10456, 0x10457, 0x10458, 0x10459, 0x1045a,
+    0x1045b, 0x1045c, 0x1045d, 0x1045e, 0x1045f, 0x10460, 0x10461, 0x10462, 0x10463, 0x10464,
+    0x10465, 0x10466, 0x10467, 0x10468, 0x10469, 0x1046a, 0x1046b, 0x1046c, 0x1046d, 0x1046e,
+    0x1046f, 0x10470, 0x10471, 0x10472, 0x10473, 0x10474, 0x10475, 0x10476, 0x10477, 0x10478,
+    0x10479, 0x1047a, 0x1047b, 0x1047c, 0x1047d, 0x1047e, 0x1047f, 0x10480, 0x10481, 0x10482,
+    0x10483, 0x10484, 0x10485, 0x10486, 0x10487, 0x10488, 0x10489, 0x1048a, 0x1048b, 0x1048c,
+    0x1048d, 0x1048e, 0x1048f, 0x10490, 0x10491, 0x10492, 0x10493, 0x10494, 0x10495, 0x10496,
+    0x10497, 0x10498, 0x10499, 0x1049a, 0x1049b, 0x1049c, 0x1049d, 0x104a0, 0x104a1, 0x104a2,
+    0x104a3, 0x104a4, 0x104a5, 0x104a6, 0x104a7, 0x104a8, 0x104a9, 0x104b0, 0x104b1, 0x104b2,
+    0x104b3, 0x104b4, 0x104b5, 0x104b6, 0x104b7, 0x104b8, 0x104b9, 0x104ba, 0x104bb, 0x104bc,
+    0x104bd, 0x104be, 0x104bf, 0x104c0, 0x104c1, 0x104c2, 0x104c3, 0x104c4, 0x104c5, 0x104c6,
+    0x104c7, 0x104c8, 0x104c9, 0x104ca, 0x104cb, 0x104cc, 0x104cd, 0x104ce, 0x104cf, 0x104d0,
+    0x104d1, 0x104d2, 0x104d3, 0x104d8, 0x104d9, 0x104da, 0x104db, 0x104dc, 0x104dd, 0x104de,
+    0x104df, 0x104e0, 0x104e1, 0x104e2, 0x104e3, 0x104e4, 0x104e5, 0x104e6, 0x104e7, 0x104e8,
+    0x104e9, 0x104ea, 0x104eb, 0x104ec, 0x104ed, 0x104ee, 0x104ef, 0x104f0, 0x104f1, 0x104f2,
+    0x104f3, 0x104f4, 0x104f5, 0x104f6, 0x104f7, 0x104f8, 0x104f9, 0x104fa, 0x104fb, 0x10500,
+    0x10501, 0x10502, 0x10503, 0x10504, 0x10505, 0x10506, 0x10507, 0x10508, 0x10509, 0x1050a,
+    0x1050b, 0x1050c, 0x1050d, 0x1050e, 0x1050f, 0x10510, 0x10511, 0x10512, 0x10513, 0x10514,
+    0x10515, 0x10516, 0x10517, 0x10518, 0x10519, 0x1051a, 0x1051b, 0x1051c, 0x1051d, 0x1051e,
+    0x1051f, 0x10520, 0x10521, 0x10522, 0x10523, 0x10524, 0x10525, 0x10526, 0x10527, 0x10530,
+    0x10531, 0x10532, 0x10533, 0x10534, 0x10535, 0x10536, 0x10537, 0x10538, 0x10539, 0x1053a,
+    0x1053b, 0x1053c, 0x1053d, 0x1053e, 0x1053f, 0x10540, 0x10541, 0x10542, 0x10543, 0x10544,
+    0x10545, 0x10546, 0x10547, 0x10548, 0x10549, 0x1054a, 0x1054b, 0x1054c, 0x1054d, 0x1054e,
+    0x1054f, 0x10550, 0x10551, 0x10552, 0x10553, 0x10554, 0x10555, 0x10556, 0x10557, 0x10558,
+    0x10559, 0x1055a, 0x1055b, 0x1055c, 0x1055d, 0x1055e, 0x1055f, 0x10560, 0x10561, 0x10562,
+    0x10563, 0x1056f, 0x10600, 0x10601, 0x10602, 0x10603, 0x10604, 0x10605, 0x10606, 0x10607,
+    0x10608, 0x10609, 0x1060a, 0x1060b, 0x1060c, 0x1060d, 0x1060e, 0x1060f, 0x10610, 0x10611,
+    0x10612, 0x10613, 0x10614, 0x10615, 0x10616, 0x10617, 0x10618, 0x10619, 0x1061a, 0x1061b,
+    0x1061c, 0x1061d, 0x1061e, 0x1061f, 0x10620, 0x10621, 0x10622, 0x10623, 0x10624, 0x10625,
+    0x10626, 0x10627, 0x10628, 0x10629, 0x1062a, 0x1062b, 0x1062c, 0x1062d, 0x1062e, 0x1062f,
+    0x10630, 0x10631, 0x10632, 0x10633, 0x10634, 0x10635, 0x10636, 0x10637, 0x10638, 0x10639,
+    0x1063a, 0x1063b, 0x1063c, 0x1063d, 0x1063e, 0x1063f, 0x10640, 0x10641, 0x10642, 0x10643,
+    0x10644, 0x10645, 0x10646, 0x10647, 0x10648, 0x10649, 0x1064a, 0x1064b, 0x1064c, 0x1064d,
+    0x1064e, 0x1064f, 0x10650, 0x10651, 0x10652, 0x10653, 0x10654, 0x10655, 0x10656, 0x10657,
+    0x10658, 0x10659, 0x1065a, 0x1065b, 0x1065c, 0x1065d, 0x1065e, 0x1065f, 0x10660, 0x10661,
+    0x10662, 0x10663, 0x10664, 0x10665, 0x10666, 0x10667, 0x10668, 0x10669, 0x1066a, 0x1066b,
+    0x1066c, 0x1066d, 0x1066e, 0x1066f, 0x10670, 0x10671, 0x10672, 0x10673, 0x10674, 0x10675,
+    0x10676, 0x10677, 0x10678, 0x10679, 0x1067a, 0x1067b, 0x1067c, 0x1067d, 0x1067e, 0x1067f,
+    0x10680, 0x10681, 0x10682, 0x10683, 0x10684, 0x10685, 0x10686, 0x10687, 0x10688, 0x10689,
+    0x1068a, 0x1068b, 0x1068c, 0x1068d, 0x1068e, 0x1068f, 0x10690, 0x10691, 0x10692, 0x10693,
+    0x10694, 0x10695, 0x10696, 0x10697, 0x10698, 0x10699, 0x1069a, 0x1069b, 0x1069c, 0x1069d,
+    0x1069e, 0x1069f, 0x106a0, 0x106a1, 0x106a2, 0x106a3, 0x106a4, 0x106a5, 0x106a6, 0x106a7,
+    0x106a8, 0x106a9, 0x106aa, 0x106ab, 0x106ac, 0x106ad, 0x106ae, 0x106af, 0x106b0, 0x106b1,
+    0x106b2, 0x106b3, 0x106b4, 0x106b5, 0x106b6, 0x106b7, 0x106b8, 0x106b9, 0x106ba, 0x106bb,
+    0x106bc, 0x106bd, 0x106be, 0x106bf, 0x106c0, 0x106c1, 0x106c2, 0x106c3, 0x106c4, 0x106c5,
+    0x106c6, 0x106c7, 0x106c8, 0x106c9, 0x106ca, 0x106cb, 0x106cc, 0x106cd, 0x106ce, 0x106cf,
+    0x106d0, 0x106d1, 0x106d2, 0x106d3, 0x106d4, 0x106d5, 0x106d6, 0x106d7, 0x106d8, 0x106d9,
+    0x106da, 0x106db, 0x106dc, 0x106dd, 0x106de, 0x106df, 0x106e0, 0x106e1, 0x106e2, 0x106e3,
+    0x106e4, 0x106e5, 0x106e6, 0x106e7, 0x106e8, 0x106e9, 0x106ea, 0x106eb, 0x106ec, 0x106ed,
+    0x106ee, 0x106ef, 0x106f0, 0x106f1, 0x106f2, 0x106f3, 0x106f4, 0x106f5, 0x106f6, 0x106f7,
+    0x106f8, 0x106f9, 0x106fa, 0x106fb, 0x106fc, 0x106fd, 0x106fe, 0x106ff, 0x10700, 0x10701,
+    0x10702, 0x10703, 0x10704, 0x10705, 0x10706, 0x10707, 0x10708, 0x10709, 0x1070a, 0x1070b,
+    0x1070c, 0x1070d, 0x1070e, 0x1070f, 0x10710, 0x10711, 0x10712, 0x10713, 0x10714, 0x10715,
+    0x10716, 0x10717, 0x10718, 0x10719, 0x1071a, 0x1071b, 0x1071c, 0x1071d, 0x1071e, 0x1071f,
+    0x10720, 0x10721, 0x10722, 0x10723, 0x10724, 0x10725, 0x10726, 0x10727, 0x10728, 0x10729,
+    0x1072a, 0x1072b, 0x1072c, 0x1072d, 0x1072e, 0x1072f, 0x10730, 0x10731, 0x10732, 0x10733,
+    0x10734, 0x10735, 0x10736, 0x10740, 0x10741, 0x10742, 0x10743, 0x10744, 0x10745, 0x10746,
+    0x10747, 0x10748, 0x10749, 0x1074a, 0x1074b, 0x1074c, 0x1074d, 0x1074e, 0x1074f, 0x10750,
+    0x10751, 0x10752, 0x10753, 0x10754, 0x10755, 0x10760, 0x10761, 0x10762, 0x10763, 0x10764,
+    0x10765, 0x10766, 0x10767, 0x10800, 0x10801, 0x10802, 0x10803, 0x10804, 0x10805, 0x10808,
+    0x1080a, 0x1080b, 0x1080c, 0x1080d, 0x1080e, 0x1080f, 0x10810, 0x10811, 0x10812, 0x10813,
+    0x10814, 0x10815, 0x10816, 0x10817, 0x10818, 0x10819, 0x1081a, 0x1081b, 0x1081c, 0x1081d,
+    0x1081e, 0x1081f, 0x10820, 0x10821, 0x10822, 0x10823, 0x10824, 0x10825, 0x10826, 0x10827,
+    0x10828, 0x10829, 0x1082a, 0x1082b, 0x1082c, 0x1082d, 0x1082e, 0x1082f, 0x10830, 0x10831,
+    0x10832, 0x10833, 0x10834, 0x10835, 0x10837, 0x10838, 0x1083c, 0x1083f, 0x10840, 0x10841,
+    0x10842, 0x10843, 0x10844, 0x10845, 0x10846, 0x10847, 0x10848, 0x10849, 0x1084a, 0x1084b,
+    0x1084c, 0x1084d, 0x1084e, 0x1084f, 0x10850, 0x10851, 0x10852, 0x10853, 0x10854, 0x10855,
+    0x10857, 0x10858, 0x10859, 0x1085a, 0x1085b, 0x1085c, 0x1085d, 0x1085e, 0x1085f, 0x10860,
+    0x10861, 0x10862, 0x10863, 0x10864, 0x10865, 0x10866, 0x10867, 0x10868, 0x10869, 0x1086a,
+    0x1086b, 0x1086c, 0x1086d, 0x1086e, 0x1086f, 0x10870, 0x10871, 0x10872, 0x10873, 0x10874,
+    0x10875, 0x10876, 0x10877, 0x10878, 0x10879, 0x1087a, 0x1087b, 0x1087c, 0x1087d, 0x1087e,
+    0x1087f, 0x10880, 0x10881, 0x10882, 0x10883, 0x10884, 0x10885, 0x10886, 0x10887, 0x10888,
+    0x10889, 0x1088a, 0x1088b, 0x1088c, 0x1088d, 0x1088e, 0x1088f, 0x10890, 0x10891, 0x10892,
+    0x10893, 0x10894, 0x10895, 0x10896, 0x10897, 0x10898, 0x10899, 0x1089a, 0x1089b, 0x1089c,
+    0x1089d, 0x1089e, 0x108a7, 0x108a8, 0x108a9, 0x108aa, 0x108ab, 0x108ac, 0x108ad, 0x108ae,
+    0x108af, 0x108e0, 0x108e1, 0x108e2, 0x108e3, 0x108e4, 0x108e5, 0x108e6, 0x108e7, 0x108e8,
+    0x108e9, 0x108ea, 0x108eb, 0x108ec, 0x108ed, 0x108ee, 0x108ef, 0x108f0, 0x108f1, 0x108f2,
+    0x108f4, 0x108f5, 0x108fb, 0x108fc, 0x108fd, 0x108fe, 0x108ff, 0x10900, 0x10901, 0x10902,
+    0x10903, 0x10904, 0x10905, 0x10906, 0x10907, 0x10908, 0x10909, 0x1090a, 0x1090b, 0x1090c,
+    0x1090d, 0x1090e, 0x1090f, 0x10910, 0x10911, 0x10912, 0x10913, 0x10914, 0x10915, 0x10916,
+    0x10917, 0x10918, 0x10919, 0x1091a, 0x1091b, 0x1091f, 0x10920, 0x10921, 0x10922, 0x10923,
+    0x10924, 0x10925, 0x10926, 0x10927, 0x10928, 0x10929, 0x1092a, 0x1092b, 0x1092c, 0x1092d,
+    0x1092e, 0x1092f, 0x10930, 0x10931, 0x10932, 0x10933, 0x10934, 0x10935, 0x10936, 0x10937,
+    0x10938, 0x10939, 0x1093f, 0x10980, 0x10981, 0x10982, 0x10983, 0x10984, 0x10985, 0x10986,
+    0x10987, 0x10988, 0x10989, 0x1098a, 0x1098b, 0x1098c, 0x1098d, 0x1098e, 0x1098f, 0x10990,
+    0x10991, 0x10992, 0x10993, 0x10994, 0x10995, 0x10996, 0x10997, 0x10998, 0x10999, 0x1099a,
+    0x1099b, 0x1099c, 0x1099d, 0x1099e, 0x1099f, 0x109a0, 0x109a1, 0x109a2, 0x109a3, 0x109a4,
+    0x109a5, 0x109a6, 0x109a7, 0x109a8, 0x109a9, 0x109aa, 0x109ab, 0x109ac, 0x109ad, 0x109ae,
+    0x109af, 0x109b0, 0x109b1, 0x109b2, 0x109b3, 0x109b4, 0x109b5, 0x109b6, 0x109b7, 0x109bc,
+    0x109bd, 0x109be, 0x109bf, 0x109c0, 0x109c1, 0x109c2, 0x109c3, 0x109c4, 0x109c5, 0x109c6,
+    0x109c7, 0x109c8, 0x109c9, 0x109ca, 0x109cb, 0x109cc, 0x109cd, 0x109ce, 0x109cf, 0x109d2,
+    0x109d3, 0x109d4, 0x109d5, 0x109d6, 0x109d7, 0x109d8, 0x109d9, 0x109da, 0x109db, 0x109dc,
+    0x109dd, 0x109de, 0x109df, 0x109e0, 0x109e1, 0x109e2, 0x109e3, 0x109e4, 0x109e5, 0x109e6,
+    0x109e7, 0x109e8, 0x109e9, 0x109ea, 0x109eb, 0x109ec, 0x109ed, 0x109ee, 0x109ef, 0x109f0,
+    0x109f1, 0x109f2, 0x109f3, 0x109f4, 0x109f5, 0x109f6, 0x109f7, 0x109f8, 0x109f9, 0x109fa,
+    0x109fb, 0x109fc, 0x109fd, 0x109fe, 0x109ff, 0x10a00, 0x10a01, 0x10a02, 0x10a03, 0x10a05,
+    0x10a06, 0x10a0c, 0x10a0d, 0x10a0e, 0x10a0f, 0x10a10, 0x10a11, 0x10a12, 0x10a13, 0x10a15,
+    0x10a16, 0x10a17, 0x10a19, 0x10a1a, 0x10a1b, 0x10a1c, 0x10a1d, 0x10a1e, 0x10a1f, 0x10a20,
+    0x10a21, 0x10a22, 0x10a23, 0x10a24, 0x10a25, 0x10a26, 0x10a27, 0x10a28, 0x10a29, 0x10a2a,
+    0x10a2b, 0x10a2c, 0x10a2d, 0x10a2e, 0x10a2f, 0x10a30, 0x10a31, 0x10a32, 0x10a33, 0x10a34,
+    0x10a35, 0x10a38, 0x10a39, 0x10a3a, 0x10a3f, 0x10a40, 0x10a41, 0x10a42, 0x10a43, 0x10a44,
+    0x10a45, 0x10a46, 0x10a47, 0x10a48, 0x10a50, 0x10a51, 0x10a52, 0x10a53, 0x10a54, 0x10a55,
+    0x10a56, 0x10a57, 0x10a58, 0x10a60, 0x10a61, 0x10a62, 0x10a63, 0x10a64, 0x10a65, 0x10a66,
+    0x10a67, 0x10a68, 0x10a69, 0x10a6a, 0x10a6b, 0x10a6c, 0x10a6d, 0x10a6e, 0x10a6f, 0x10a70,
+    0x10a71, 0x10a72, 0x10a73, 0x10a74, 0x10a75, 0x10a76, 0x10a77, 0x10a78, 0x10a79, 0x10a7a,
+    0x10a7b, 0x10a7c, 0x10a7d, 0x10a7e, 0x10a7f, 0x10a80, 0x10a81, 0x10a82, 0x10a83, 0x10a84,
+    0x10a85, 0x10a86, 0x10a87, 0x10a88, 0x10a89, 0x10a8a, 0x10a8b, 0x10a8c, 0x10a8d, 0x10a8e,
+    0x10a8f, 0x10a90, 0x10a91, 0x10a92, 0x10a93, 0x10a94, 0x10a95, 0x10a96, 0x10a97, 0x10a98,
+    0x10a99, 0x10a9a, 0x10a9b, 0x10a9c, 0x10a9d, 0x10a9e, 0x10a9f, 0x10ac0, 0x10ac1, 0x10ac2,
+    0x10ac3, 0x10ac4, 0x10ac5, 0x10ac6, 0x10ac7, 0x10ac8, 0x10ac9, 0x10aca, 0x10acb, 0x10acc,
+    0x10acd, 0x10ace, 0x10acf, 0x10ad0, 0x10ad1, 0x10ad2, 0x10ad3, 0x10ad4, 0x10ad5, 0x10ad6,
+    0x10ad7, 0x10ad8, 0x10ad9, 0x10ada, 0x10adb, 0x10adc, 0x10add, 0x10ade, 0x10adf, 0x10ae0,
+    0x10ae1, 0x10ae2, 0x10ae3, 0x10ae4, 0x10ae5, 0x10ae6, 0x10aeb, 0x10aec, 0x10aed, 0x10aee,
+    0x10aef, 0x10af0, 0x10af1, 0x10af2, 0x10af3, 0x10af4, 0x10af5, 0x10af6, 0x10b00, 0x10b01,
+    0x10b02, 0x10b03, 0x10b04, 0x10b05, 0x10b06, 0x10b07, 0x10b08, 0x10b09, 0x10b0a, 0x10b0b,
+    0x10b0c, 0x10b0d, 0x10b0e, 0x10b0f, 0x10b10, 0x10b11, 0x10b12, 0x10b13, 0x10b14, 0x10b15,
+    0x10b16, 0x10b17, 0x10b18, 0x10b19, 0x10b1a, 0x10b1b, 0x10b1c, 0x10b1d, 0x10b1e, 0x10b1f,
+    0x10b20, 0x10b21, 0x10b22, 0x10b23, 0x10b24, 0x10b25, 0x10b26, 0x10b27, 0x10b28, 0x10b29,
+    0x10b2a, 0x10b2b, 0x10b2c, 0x10b2d, 0x10b2e, 0x10b2f, 0x10b30, 0x10b31, 0x10b32, 0x10b33,
+    0x10b34, 0x10b35, 0x10b39, 0x10b3a, 0x10b3b, 0x10b3c, 0x10b3d, 0x10b3e, 0x10b3f, 0x10b40,
+    0x10b41, 0x10b42, 0x10b43, 0x10b44, 0x10b45, 0x10b46, 0x10b47, 0x10b48, 0x10b49, 0x10b4a,
+    0x10b4b, 0x10b4c, 0x10b4d, 0x10b4e, 0x10b4f, 0x10b50, 0x10b51, 0x10b52, 0x10b53, 0x10b54,
+    0x10b55, 0x10b58, 0x10b59, 0x10b5a, 0x10b5b, 0x10b5c, 0x10b5d, 0x10b5e, 0x10b5f, 0x10b60,
+    0x10b61, 0x10b62, 0x10b63, 0x10b64, 0x10b65, 0x10b66, 0x10b67, 0x10b68, 0x10b69, 0x10b6a,
+    0x10b6b, 0x10b6c, 0x10b6d, 0x10b6e, 0x10b6f, 0x10b70, 0x10b71, 0x10b72, 0x10b78, 0x10b79,
+    0x10b7a, 0x10b7b, 0x10b7c, 0x10b7d, 0x10b7e, 0x10b7f, 0x10b80, 0x10b81, 0x10b82, 0x10b83,
+    0x10b84, 0x10b85, 0x10b86, 0x10b87, 0x10b88, 0x10b89, 0x10b8a, 0x10b8b, 0x10b8c, 0x10b8d,
+    0x10b8e, 0x10b8f, 0x10b90, 0x10b91, 0x10b99, 0x10b9a, 0x10b9b, 0x10b9c, 0x10ba9, 0x10baa,
+    0x10bab, 0x10bac, 0x10bad, 0x10bae, 0x10baf, 0x10c00, 0x10c01, 0x10c02, 0x10c03, 0x10c04,
+    0x10c05, 0x10c06, 0x10c07, 0x10c08, 0x10c09, 0x10c0a, 0x10c0b, 0x10c0c, 0x10c0d, 0x10c0e,
+    0x10c0f, 0x10c10, 0x10c11, 0x10c12, 0x10c13, 0x10c14, 0x10c15, 0x10c16, 0x10c17, 0x10c18,
+    0x10c19, 0x10c1a, 0x10c1b, 0x10c1c, 0x10c1d, 0x10c1e, 0x10c1f, 0x10c20, 0x10c21, 0x10c22,
+    0x10c23, 0x10c24, 0x10c25, 0x10c26, 0x10c27, 0x10c28, 0x10c29, 0x10c2a, 0x10c2b, 0x10c2c,
+    0x10c2d, 0x10c2e, 0x10c2f, 0x10c30, 0x10c31, 0x10c32, 0x10c33, 0x10c34, 0x10c35, 0x10c36,
+    0x10c37, 0x10c38, 0x10c39, 0x10c3a, 0x10c3b, 0x10c3c, 0x10c3d, 0x10c3e, 0x10c3f, 0x10c40,
+    0x10c41, 0x10c42, 0x10c43, 0x10c44, 0x10c45, 0x10c46, 0x10c47, 0x10c48, 0x10c80, 0x10c81,
+    0x10c82, 0x10c83, 0x10c84, 0x10c85, 0x10c86, 0x10c87, 0x10c88, 0x10c89, 0x10c8a, 0x10c8b,
+    0x10c8c, 0x10c8d, 0x10c8e, 0x10c8f, 0x10c90, 0x10c91, 0x10c92, 0x10c93, 0x10c94, 0x10c95,
+    0x10c96, 0x10c97, 0x10c98, 0x10c99, 0x10c9a, 0x10c9b, 0x10c9c, 0x10c9d, 0x10c9e, 0x10c9f,
+    0x10ca0, 0x10ca1, 0x10ca2, 0x10ca3, 0x10ca4, 0x10ca5, 0x10ca6, 0x10ca7, 0x10ca8, 0x10ca9,
+    0x10caa, 0x10cab, 0x10cac, 0x10cad, 0x10cae, 0x10caf, 0x10cb0, 0x10cb1, 0x10cb2, 0x10cc0,
+    0x10cc1, 0x10cc2, 0x10cc3, 0x10cc4, 0x10cc5, 0x10cc6, 0x10cc7, 0x10cc8, 0x10cc9, 0x10cca,
+    0x10ccb, 0x10ccc, 0x10ccd, 0x10cce, 0x10ccf, 0x10cd0, 0x10cd1, 0x10cd2, 0x10cd3, 0x10cd4,
+    0x10cd5, 0x10cd6, 0x10cd7, 0x10cd8, 0x10cd9, 0x10cda, 0x10cdb, 0x10cdc, 0x10cdd, 0x10cde,
+    0x10cdf, 0x10ce0, 0x10ce1, 0x10ce2, 0x10ce3, 0x10ce4, 0x10ce5, 0x10ce6, 0x10ce7, 0x10ce8,
+    0x10ce9, 0x10cea, 0x10ceb, 0x10cec, 0x10ced, 0x10cee, 0x10cef, 0x10cf0, 0x10cf1, 0x10cf2,
+    0x10cfa, 0x10cfb, 0x10cfc, 0x10cfd, 0x10cfe, 0x10cff, 0x10d00, 0x10d01, 0x10d02, 0x10d03,
+    0x10d04, 0x10d05, 0x10d06, 0x10d07, 0x10d08, 0x10d09, 0x10d0a, 0x10d0b, 0x10d0c, 0x10d0d,
+    0x10d0e, 0x10d0f, 0x10d10, 0x10d11, 0x10d12, 0x10d13, 0x10d14, 0x10d15, 0x10d16, 0x10d17,
+    0x10d18, 0x10d19, 0x10d1a, 0x10d1b, 0x10d1c, 0x10d1d, 0x10d1e, 0x10d1f, 0x10d20, 0x10d21,
+    0x10d22, 0x10d23, 0x10d24, 0x10d25, 0x10d26, 0x10d27, 0x10d30, 0x10d31, 0x10d32, 0x10d33,
+    0x10d34, 0x10d35, 0x10d36, 0x10d37, 0x10d38, 0x10d39, 0x10e60, 0x10e61, 0x10e62, 0x10e63,
+    0x10e64, 0x10e65, 0x10e66, 0x10e67, 0x10e68, 0x10e69, 0x10e6a, 0x10e6b, 0x10e6c, 0x10e6d,
+    0x10e6e, 0x10e6f, 0x10e70, 0x10e71, 0x10e72, 0x10e73, 0x10e74, 0x10e75, 0x10e76, 0x10e77,
+    0x10e78, 0x10e79, 0x10e7a, 0x10e7b, 0x10e7c, 0x10e7d, 0x10e7e, 0x10e80, 0x10e81, 0x10e82,
+    0x10e83, 0x10e84, 0x10e85, 0x10e86, 0x10e87, 0x10e88, 0x10e89, 0x10e8a, 0x10e8b, 0x10e8c,
+    0x10e8d, 0x10e8e, 0x10e8f, 0x10e90, 0x10e91, 0x10e92, 0x10e93, 0x10e94, 0x10e95, 0x10e96,
+    0x10e97, 0x10e98, 0x10e99, 0x10e9a, 0x10e9b, 0x10e9c, 0x10e9d, 0x10e9e, 0x10e9f, 0x10ea0,
+    0x10ea1, 0x10ea2, 0x10ea3, 0x10ea4, 0x10ea5, 0x10ea6, 0x10ea7, 0x10ea8, 0x10ea9, 0x10eab,
+    0x10eac, 0x10ead, 0x10eb0, 0x10eb1, 0x10f00, 0x10f01, 0x10f02, 0x10f03, 0x10f04, 0x10f05,
+    0x10f06, 0x10f07, 0x10f08, 0x10f09, 0x10f0a, 0x10f0b, 0x10f0c, 0x10f0d, 0x10f0e, 0x10f0f,
+    0x10f10, 0x10f11, 0x10f12, 0x10f13, 0x10f14, 0x10f15, 0x10f16, 0x10f17, 0x10f18, 0x10f19,
+    0x10f1a, 0x10f1b, 0x10f1c, 0x10f1d, 0x10f1e, 0x10f1f, 0x10f20, 0x10f21, 0x10f22, 0x10f23,
+    0x10f24, 0x10f25, 0x10f26, 0x10f27, 0x10f30, 0x10f31, 0x10f32, 0x10f33, 0x10f34, 0x10f35,
+    0x10f36, 0x10f37, 0x10f38, 0x10f39, 0x10f3a, 0x10f3b, 0x10f3c, 0x10f3d, 0x10f3e, 0x10f3f,
+    0x10f40, 0x10f41, 0x10f42, 0x10f43, 0x10f44, 0x10f45, 0x10f46, 0x10f47, 0x10f48, 0x10f49,
+    0x10f4a, 0x10f4b, 0x10f4c, 0x10f4d, 0x10f4e, 0x10f4f, 0x10f50, 0x10f51, 0x10f52, 0x10f53,
+    0x10f54, 0x10f55, 0x10f56, 0x10f57, 0x10f58, 0x10f59, 0x10fb0, 0x10fb1, 0x10fb2, 0x10fb3,
+    0x10fb4, 0x10fb5, 0x10fb6, 0x10fb7, 0x10fb8, 0x10fb9, 0x10fba, 0x10fbb, 0x10fbc, 0x10fbd,
+    0x10fbe, 0x10fbf, 0x10fc0, 0x10fc1, 0x10fc2, 0x10fc3, 0x10fc4, 0x10fc5, 0x10fc6, 0x10fc7,
+    0x10fc8, 0x10fc9, 0x10fca, 0x10fcb, 0x10fe0, 0x10fe1, 0x10fe2, 0x10fe3, 0x10fe4, 0x10fe5,
+    0x10fe6, 0x10fe7, 0x10fe8, 0x10fe9, 0x10fea, 0x10feb, 0x10fec, 0x10fed, 0x10fee, 0x10fef,
+    0x10ff0, 0x10ff1, 0x10ff2, 0x10ff3, 0x10ff4, 0x10ff5, 0x10ff6, 0x11000, 0x11001, 0x11002,
+    0x11003, 0x11004, 0x11005, 0x11006, 0x11007, 0x11008, 0x11009, 0x1100a, 0x1100b, 0x1100c,
+    0x1100d, 0x1100e, 0x1100f, 0x11010, 0x11011, 0x11012, 0x11013, 0x11014, 0x11015, 0x11016,
+    0x11017, 0x11018, 0x11019, 0x1101a, 0x1101b, 0x1101c, 0x1101d, 0x1101e, 0x1101f, 0x11020,
+    0x11021, 0x11022, 0x11023, 0x11024, 0x11025, 0x11026, 0x11027, 0x11028, 0x11029, 0x1102a,
+    0x1102b, 0x1102c, 0x1102d, 0x1102e, 0x1102f, 0x11030, 0x11031, 0x11032, 0x11033, 0x11034,
+    0x11035, 0x11036, 0x11037, 0x11038, 0x11039, 0x1103a, 0x1103b, 0x1103c, 0x1103d, 0x1103e,
+    0x1103f, 0x11040, 0x11041, 0x11042, 0x11043, 0x11044, 0x11045, 0x11046, 0x11047, 0x11048,
+    0x11049, 0x1104a, 0x1104b, 0x1104c, 0x1104d, 0x11052, 0x11053, 0x11054, 0x11055, 0x11056,
+    0x11057, 0x11058, 0x11059, 0x1105a, 0x1105b, 0x1105c, 0x1105d, 0x1105e, 0x1105f, 0x11060,
+    0x11061, 0x11062, 0x11063, 0x11064, 0x11065, 0x11066, 0x11067, 0x11068, 0x11069, 0x1106a,
+    0x1106b, 0x1106c, 0x1106d, 0x1106e, 0x1106f, 0x1107f, 0x11080, 0x11081, 0x11082, 0x11083,
+    0x11084, 0x11085, 0x11086, 0x11087, 0x11088, 0x11089, 0x1108a, 0x1108b, 0x1108c, 0x1108d,
+    0x1108e, 0x1108f, 0x11090, 0x11091, 0x11092, 0x11093, 0x11094, 0x11095, 0x11096, 0x11097,
+    0x11098, 0x11099, 0x1109a, 0x1109b, 0x1109c, 0x1109d, 0x1109e, 0x1109f, 0x110a0, 0x110a1,
+    0x110a2, 0x110a3, 0x110a4, 0x110a5, 0x110a6, 0x110a7, 0x110a8, 0x110a9, 0x110aa, 0x110ab,
+    0x110ac, 0x110ad, 0x110ae, 0x110af, 0x110b0, 0x110b1, 0x110b2, 0x110b3, 0x110b4, 0x110b5,
+    0x110b6, 0x110b7, 0x110b8, 0x110b9, 0x110ba, 0x110bb, 0x110bc, 0x110bd, 0x110be, 0x110bf,
+    0x110c0, 0x110c1, 0x110cd, 0x110d0, 0x110d1, 0x110d2, 0x110d3, 0x110d4, 0x110d5, 0x110d6,
+    0x110d7, 0x110d8, 0x110d9, 0x110da, 0x110db, 0x110dc, 0x110dd, 0x110de, 0x110df, 0x110e0,
+    0x110e1, 0x110e2, 0x110e3, 0x110e4, 0x110e5, 0x110e6, 0x110e7, 0x110e8, 0x110f0, 0x110f1,
+    0x110f2, 0x110f3, 0x110f4, 0x110f5, 0x110f6, 0x110f7, 0x110f8, 0x110f9, 0x11100, 0x11101,
+    0x11102, 0x11103, 0x11104, 0x11105, 0x11106, 0x11107, 0x11108, 0x11109, 0x1110a, 0x1110b,
+    0x1110c, 0x1110d, 0x1110e, 0x1110f, 0x11110, 0x11111, 0x11112, 0x11113, 0x11114, 0x11115,
+    0x11116, 0x11117, 0x11118, 0x11119, 0x1111a, 0x1111b, 0x1111c, 0x1111d, 0x1111e, 0x1111f,
+    0x11120, 0x11121, 0x11122, 0x11123, 0x11124, 0x11125, 0x11126, 0x11127, 0x11128, 0x11129,
+    0x1112a, 0x1112b, 0x1112c, 0x1112d, 0x1112e, 0x1112f, 0x11130, 0x11131, 0x11132, 0x11133,
+    0x11134, 0x11136, 0x11137, 0x11138, 0x11139, 0x1113a, 0x1113b, 0x1113c, 0x1113d, 0x1113e,
+    0x1113f, 0x11140, 0x11141, 0x11142, 0x11143, 0x11144, 0x11145, 0x11146, 0x11147, 0x11150,
+    0x11151, 0x11152, 0x11153, 0x11154, 0x11155, 0x11156, 0x11157, 0x11158, 0x11159, 0x1115a,
+    0x1115b, 0x1115c, 0x1115d, 0x1115e, 0x1115f, 0x11160, 0x11161, 0x11162, 0x11163, 0x11164,
+    0x11165, 0x11166, 0x11167, 0x11168, 0x11169, 0x1116a, 0x1116b, 0x1116c, 0x1116d, 0x1116e,
+    0x1116f, 0x11170, 0x11171, 0x11172, 0x11173, 0x11174, 0x11175, 0x11176, 0x11180, 0x11181,
+    0x11182, 0x11183, 0x11184, 0x11185, 0x11186, 0x11187, 0x11188, 0x11189, 0x1118a, 0x1118b,
+    0x1118c, 0x1118d, 0x1118e, 0x1118f, 0x11190, 0x11191, 0x11192, 0x11193, 0x11194, 0x11195,
+    0x11196, 0x11197, 0x11198, 0x11199, 0x1119a, 0x1119b, 0x1119c, 0x1119d, 0x1119e, 0x1119f,
+    0x111a0, 0x111a1, 0x111a2, 0x111a3, 0x111a4, 0x111a5, 0x111a6, 0x111a7, 0x111a8, 0x111a9,
+    0x111aa, 0x111ab, 0x111ac, 0x111ad, 0x111ae, 0x111af, 0x111b0, 0x111b1, 0x111b2, 0x111b3,
+    0x111b4, 0x111b5, 0x111b6, 0x111b7, 0x111b8, 0x111b9, 0x111ba, 0x111bb, 0x111bc, 0x111bd,
+    0x111be, 0x111bf, 0x111c0, 0x111c1, 0x111c2, 0x111c3, 0x111c4, 0x111c5, 0x111c6, 0x111c7,
+    0x111c8, 0x111c9, 0x111ca, 0x111cb, 0x111cc, 0x111cd, 0x111ce, 0x111cf, 0x111d0, 0x111d1,
+    0x111d2, 0x111d3, 0x111d4, 0x111d5, 0x111d6, 0x111d7, 0x111d8, 0x111d9, 0x111da, 0x111db,
+    0x111dc, 0x111dd, 0x111de, 0x111df, 0x111e1, 0x111e2, 0x111e3, 0x111e4, 0x111e5, 0x111e6,
+    0x111e7, 0x111e8, 0x111e9, 0x111ea, 0x111eb, 0x111ec, 0x111ed, 0x111ee, 0x111ef, 0x111f0,
+    0x111f1, 0x111f2, 0x111f3, 0x111f4, 0x11200, 0x11201, 0x11202, 0x11203, 0x11204, 0x11205,
+    0x11206, 0x11207, 0x11208, 0x11209, 0x1120a, 0x1120b, 0x1120c, 0x1120d, 0x1120e, 0x1120f,
+    0x11210, 0x11211, 0x11213, 0x11214, 0x11215, 0x11216, 0x11217, 0x11218, 0x11219, 0x1121a,
+    0x1121b, 0x1121c, 0x1121d, 0x1121e, 0x1121f, 0x11220, 0x11221, 0x11222, 0x11223, 0x11224,
+    0x11225, 0x11226, 0x11227, 0x11228, 0x11229, 0x1122a, 0x1122b, 0x1122c, 0x1122d, 0x1122e,
+    0x1122f, 0x11230, 0x11231, 0x11232, 0x11233, 0x11234, 0x11235, 0x11236, 0x11237, 0x11238,
+    0x11239, 0x1123a, 0x1123b, 0x1123c, 0x1123d, 0x1123e, 0x11280, 0x11281, 0x11282, 0x11283,
+    0x11284, 0x11285, 0x11286, 0x11288, 0x1128a, 0x1128b, 0x1128c, 0x1128d, 0x1128f, 0x11290,
+    0x11291, 0x11292, 0x11293, 0x11294, 0x11295, 0x11296, 0x11297, 0x11298, 0x11299, 0x1129a,
+    0x1129b, 0x1129c, 0x1129d, 0x1129f, 0x112a0, 0x112a1, 0x112a2, 0x112a3, 0x112a4, 0x112a5,
+    0x112a6, 0x112a7, 0x112a8, 0x112a9, 0x112b0, 0x112b1, 0x112b2, 0x112b3, 0x112b4, 0x112b5,
+    0x112b6, 0x112b7, 0x112b8, 0x112b9, 0x112ba, 0x112bb, 0x112bc, 0x112bd, 0x112be, 0x112bf,
+    0x112c0, 0x112c1, 0x112c2, 0x112c3, 0x112c4, 0x112c5, 0x112c6, 0x112c7, 0x112c8, 0x112c9,
+    0x112ca, 0x112cb, 0x112cc, 0x112cd, 0x112ce, 0x112cf, 0x112d0, 0x112d1, 0x112d2, 0x112d3,
+    0x112d4, 0x112d5, 0x112d6, 0x112d7, 0x112d8, 0x112d9, 0x112da, 0x112db, 0x112dc, 0x112dd,
+    0x112de, 0x112df, 0x112e0, 0x112e1, 0x112e2, 0x112e3, 0x112e4, 0x112e5, 0x112e6, 0x112e7,
+    0x112e8, 0x112e9, 0x112ea, 0x112f0, 0x112f1, 0x112f2, 0x112f3, 0x112f4, 0x112f5, 0x112f6,
+    0x112f7, 0x112f8, 0x112f9, 0x11300, 0x11301, 0x11302, 0x11303, 0x11305, 0x11306, 0x11307,
+    0x11308, 0x11309, 0x1130a, 0x1130b, 0x1130c, 0x1130f, 0x11310, 0x11313, 0x11314, 0x11315,
+    0x11316, 0x11317, 0x11318, 0x11319, 0x1131a, 0x1131b, 0x1131c, 0x1131d, 0x1131e, 0x1131f,
+    0x11320, 0x11321, 0x11322, 0x11323, 0x11324, 0x11325, 0x11326, 0x11327, 0x11328, 0x1132a,
+    0x1132b, 0x1132c, 0x1132d, 0x1132e, 0x1132f, 0x11330, 0x11332, 0x11333, 0x11335, 0x11336,
+    0x11337, 0x11338, 0x11339, 0x1133b, 0x1133c, 0x1133d, 0x1133e, 0x1133f, 0x11340, 0x11341,
+    0x11342, 0x11343, 0x11344, 0x11347, 0x11348, 0x1134b, 0x1134c, 0x1134d, 0x11350, 0x11357,
+    0x1135d, 0x1135e, 0x1135f, 0x11360, 0x11361, 0x11362, 0x11363, 0x11366, 0x11367, 0x11368,
+    0x11369, 0x1136a, 0x1136b, 0x1136c, 0x11370, 0x11371, 0x11372, 0x11373, 0x11374, 0x11400,
+    0x11401, 0x11402, 0x11403, 0x11404, 0x11405, 0x11406, 0x11407, 0x11408, 0x11409, 0x1140a,
+    0x1140b, 0x1140c, 0x1140d, 0x1140e, 0x1140f, 0x11410, 0x11411, 0x11412, 0x11413, 0x11414,
+    0x11415, 0x11416, 0x11417, 0x11418, 0x11419, 0x1141a, 0x1141b, 0x1141c, 0x1141d, 0x1141e,
+    0x1141f, 0x11420, 0x11421, 0x11422, 0x11423, 0x11424, 0x11425, 0x11426, 0x11427, 0x11428,
+    0x11429, 0x1142a, 0x1142b, 0x1142c, 0x1142d, 0x1142e, 0x1142f, 0x11430, 0x11431, 0x11432,
+    0x11433, 0x11434, 0x11435, 0x11436, 0x11437, 0x11438, 0x11439, 0x1143a, 0x1143b, 0x1143c,
+    0x1143d, 0x1143e, 0x1143f, 0x11440, 0x11441, 0x11442, 0x11443, 0x11444, 0x11445, 0x11446,
+    0x11447, 0x11448, 0x11449, 0x1144a, 0x1144b, 0x1144c, 0x1144d, 0x1144e, 0x1144f, 0x11450,
+    0x11451, 0x11452, 0x11453, 0x11454, 0x11455, 0x11456, 0x11457, 0x11458, 0x11459, 0x1145a,
+    0x1145b, 0x1145d, 0x1145e, 0x1145f, 0x11460, 0x11461, 0x11480, 0x11481, 0x11482, 0x11483,
+    0x11484, 0x11485, 0x11486, 0x11487, 0x11488, 0x11489, 0x1148a, 0x1148b, 0x1148c, 0x1148d,
+    0x1148e, 0x1148f, 0x11490, 0x11491, 0x11492, 0x11493, 0x11494, 0x11495, 0x11496, 0x11497,
+    0x11498, 0x11499, 0x1149a, 0x1149b, 0x1149c, 0x1149d, 0x1149e, 0x1149f, 0x114a0, 0x114a1,
+    0x114a2, 0x114a3, 0x114a4, 0x114a5, 0x114a6, 0x114a7, 0x114a8, 0x114a9, 0x114aa, 0x114ab,
+    0x114ac, 0x114ad, 0x114ae, 0x114af, 0x114b0, 0x114b1, 0x114b2, 0x114b3, 0x114b4, 0x114b5,
+    0x114b6, 0x114b7, 0x114b8, 0x114b9, 0x114ba, 0x114bb, 0x114bc, 0x114bd, 0x114be, 0x114bf,
+    0x114c0, 0x114c1, 0x114c2, 0x114c3, 0x114c4, 0x114c5, 0x114c6, 0x114c7, 0x114d0, 0x114d1,
+    0x114d2, 0x114d3, 0x114d4, 0x114d5, 0x114d6, 0x114d7, 0x114d8, 0x114d9, 0x11580, 0x11581,
+    0x11582, 0x11583, 0x11584, 0x11585, 0x11586, 0x11587, 0x11588, 0x11589, 0x1158a, 0x1158b,
+    0x1158c, 0x1158d, 0x1158e, 0x1158f, 0x11590, 0x11591, 0x11592, 0x11593, 0x11594, 0x11595,
+    0x11596, 0x11597, 0x11598, 0x11599, 0x1159a, 0x1159b, 0x1159c, 0x1159d, 0x1159e, 0x1159f,
+    0x115a0, 0x115a1, 0x115a2, 0x115a3, 0x115a4, 0x115a5, 0x115a6, 0x115a7, 0x115a8, 0x115a9,
+    0x115aa, 0x115ab, 0x115ac, 0x115ad, 0x115ae, 0x115af, 0x115b0, 0x115b1, 0x115b2, 0x115b3,
+    0x115b4, 0x115b5, 0x115b8, 0x115b9, 0x115ba, 0x115bb, 0x115bc, 0x115bd, 0x115be, 0x115bf,
+    0x115c0, 0x115c1, 0x115c2, 0x115c3, 0x115c4, 0x115c5, 0x115c6, 0x115c7, 0x115c8, 0x115c9,
+    0x115ca, 0x115cb, 0x115cc, 0x115cd, 0x115ce, 0x115cf, 0x115d0, 0x115d1, 0x115d2, 0x115d3,
+    0x115d4, 0x115d5, 0x115d6, 0x115d7, 0x115d8, 0x115d9, 0x115da, 0x115db, 0x115dc, 0x115dd,
+    0x11600, 0x11601, 0x11602, 0x11603, 0x11604, 0x11605, 0x11606, 0x11607, 0x11608, 0x11609,
+    0x1160a, 0x1160b, 0x1160c, 0x1160d, 0x1160e, 0x1160f, 0x11610, 0x11611, 0x11612, 0x11613,
+    0x11614, 0x11615, 0x11616, 0x11617, 0x11618, 0x11619, 0x1161a, 0x1161b, 0x1161c, 0x1161d,
+    0x1161e, 0x1161f, 0x11620, 0x11621, 0x11622, 0x11623, 0x11624, 0x11625, 0x11626, 0x11627,
+    0x11628, 0x11629, 0x1162a, 0x1162b, 0x1162c, 0x1162d, 0x1162e, 0x1162f, 0x11630, 0x11631,
+    0x11632, 0x11633, 0x11634, 0x11635, 0x11636, 0x11637, 0x11638, 0x11639, 0x1163a, 0x1163b,
+    0x1163c, 0x1163d, 0x1163e, 0x1163f, 0x11640, 0x11641, 0x11642, 0x11643, 0x11644, 0x11650,
+    0x11651, 0x11652, 0x11653, 0x11654, 0x11655, 0x11656, 0x11657, 0x11658, 0x11659, 0x11660,
+    0x11661, 0x11662, 0x11663, 0x11664, 0x11665, 0x11666, 0x11667, 0x11668, 0x11669, 0x1166a,
+    0x1166b, 0x1166c, 0x11680, 0x11681, 0x11682, 0x11683, 0x11684, 0x11685, 0x11686, 0x11687,
+    0x11688, 0x11689, 0x1168a, 0x1168b, 0x1168c, 0x1168d, 0x1168e, 0x1168f, 0x11690, 0x11691,
+    0x11692, 0x11693, 0x11694, 0x11695, 0x11696, 0x11697, 0x11698, 0x11699, 0x1169a, 0x1169b,
+    0x1169c, 0x1169d, 0x1169e, 0x1169f, 0x116a0, 0x116a1, 0x116a2, 0x116a3, 0x116a4, 0x116a5,
+    0x116a6, 0x116a7, 0x116a8, 0x116a9, 0x116aa, 0x116ab, 0x116ac, 0x116ad, 0x116ae, 0x116af,
+    0x116b0, 0x116b1, 0x116b2, 0x116b3, 0x116b4, 0x116b5, 0x116b6, 0x116b7, 0x116b8, 0x116c0,
+    0x116c1, 0x116c2, 0x116c3, 0x116c4, 0x116c5, 0x116c6, 0x116c7, 0x116c8, 0x116c9, 0x11700,
+    0x11701, 0x11702, 0x11703, 0x11704, 0x11705, 0x11706, 0x11707, 0x11708, 0x11709, 0x1170a,
+    0x1170b, 0x1170c, 0x1170d, 0x1170e, 0x1170f, 0x11710, 0x11711, 0x11712, 0x11713, 0x11714,
+    0x11715, 0x11716, 0x11717, 0x11718, 0x11719, 0x1171a, 0x1171d, 0x1171e, 0x1171f, 0x11720,
+    0x11721, 0x11722, 0x11723, 0x11724, 0x11725, 0x11726, 0x11727, 0x11728, 0x11729, 0x1172a,
+    0x1172b, 0x11730, 0x11731, 0x11732, 0x11733, 0x11734, 0x11735, 0x11736, 0x11737, 0x11738,
+    0x11739, 0x1173a, 0x1173b, 0x1173c, 0x1173d, 0x1173e, 0x1173f, 0x11800, 0x11801, 0x11802,
+    0x11803, 0x11804, 0x11805, 0x11806, 0x11807, 0x11808, 0x11809, 0x1180a, 0x1180b, 0x1180c,
+    0x1180d, 0x1180e, 0x1180f, 0x11810, 0x11811, 0x11812, 0x11813, 0x11814, 0x11815, 0x11816,
+    0x11817, 0x11818, 0x11819, 0x1181a, 0x1181b, 0x1181c, 0x1181d, 0x1181e, 0x1181f, 0x11820,
+    0x11821, 0x11822, 0x11823, 0x11824, 0x11825, 0x11826, 0x11827, 0x11828, 0x11829, 0x1182a,
+    0x1182b, 0x1182c, 0x1182d, 0x1182e, 0x1182f, 0x11830, 0x11831, 0x11832, 0x11833, 0x11834,
+    0x11835, 0x11836, 0x11837, 0x11838, 0x11839, 0x1183a, 0x1183b, 0x118a0, 0x118a1, 0x118a2,
+    0x118a3, 0x118a4, 0x118a5, 0x118a6, 0x118a7, 0x118a8, 0x118a9, 0x118aa, 0x118ab, 0x118ac,
+    0x118ad, 0x118ae, 0x118af, 0x118b0, 0x118b1, 0x118b2, 0x118b3, 0x118b4, 0x118b5, 0x118b6,
+    0x118b7, 0x118b8, 0x118b9, 0x118ba, 0x118bb, 0x118bc, 0x118bd, 0x118be, 0x118bf, 0x118c0,
+    0x118c1, 0x118c2, 0x118c3, 0x118c4, 0x118c5, 0x118c6, 0x118c7, 0x118c8, 0x118c9, 0x118ca,
+    0x118cb, 0x118cc, 0x118cd, 0x118ce, 0x118cf, 0x118d0, 0x118d1, 0x118d2, 0x118d3, 0x118d4,
+    0x118d5, 0x118d6, 0x118d7, 0x118d8, 0x118d9, 0x118da, 0x118db, 0x118dc, 0x118dd, 0x118de,
+    0x118df, 0x118e0, 0x118e1, 0x118e2, 0x118e3, 0x118e4, 0x118e5, 0x118e6, 0x118e7, 0x118e8,
+    0x118e9, 0x118ea, 0x118eb, 0x118ec, 0x118ed, 0x118ee, 0x118ef, 0x118f0, 0x118f1, 0x118f2,
+    0x118ff, 0x11900, 0x11901, 0x11902, 0x11903, 0x11904, 0x11905, 0x11906, 0x11909, 0x1190c,
+    0x1190d, 0x1190e, 0x1190f, 0x11910, 0x11911, 0x11912, 0x11913, 0x11915, 0x11916, 0x11918,
+    0x11919, 0x1191a, 0x1191b, 0x1191c, 0x1191d, 0x1191e, 0x1191f, 0x11920, 0x11921, 0x11922,
+    0x11923, 0x11924, 0x11925, 0x11926, 0x11927, 0x11928, 0x11929, 0x1192a, 0x1192b, 0x1192c,
+    0x1192d, 0x1192e, 0x1192f, 0x11930, 0x11931, 0x11932, 0x11933, 0x11934, 0x11935, 0x11937,
+    0x11938, 0x1193b, 0x1193c, 0x1193d, 0x1193e, 0x1193f, 0x11940, 0x11941, 0x11942, 0x11943,
+    0x11944, 0x11945, 0x11946, 0x11950, 0x11951, 0x11952, 0x11953, 0x11954, 0x11955, 0x11956,
+    0x11957, 0x11958, 0x11959, 0x119a0, 0x119a1, 0x119a2, 0x119a3, 0x119a4, 0x119a5, 0x119a6,
+    0x119a7, 0x119aa, 0x119ab, 0x119ac, 0x119ad, 0x119ae, 0x119af, 0x119b0, 0x119b1, 0x119b2,
+    0x119b3, 0x119b4, 0x119b5, 0x119b6, 0x119b7, 0x119b8, 0x119b9, 0x119ba, 0x119bb, 0x119bc,
+    0x119bd, 0x119be, 0x119bf, 0x119c0, 0x119c1, 0x119c2, 0x119c3, 0x119c4, 0x119c5, 0x119c6,
+    0x119c7, 0x119c8, 0x119c9, 0x119ca, 0x119cb, 0x119cc, 0x119cd, 0x119ce, 0x119cf, 0x119d0,
+    0x119d1, 0x119d2, 0x119d3, 0x119d4, 0x119d5, 0x119d6, 0x119d7, 0x119da, 0x119db, 0x119dc,
+    0x119dd, 0x119de, 0x119df, 0x119e0, 0x119e1, 0x119e2, 0x119e3, 0x119e4, 0x11a00, 0x11a01,
+    0x11a02, 0x11a03, 0x11a04, 0x11a05, 0x11a06, 0x11a07, 0x11a08, 0x11a09, 0x11a0a, 0x11a0b,
+    0x11a0c, 0x11a0d, 0x11a0e, 0x11a0f, 0x11a10, 0x11a11, 0x11a12, 0x11a13, 0x11a14, 0x11a15,
+    0x11a16, 0x11a17, 0x11a18, 0x11a19, 0x11a1a, 0x11a1b, 0x11a1c, 0x11a1d, 0x11a1e, 0x11a1f,
+    0x11a20, 0x11a21, 0x11a22, 0x11a23, 0x11a24, 0x11a25, 0x11a26, 0x11a27, 0x11a28, 0x11a29,
+    0x11a2a, 0x11a2b, 0x11a2c, 0x11a2d, 0x11a2e, 0x11a2f, 0x11a30, 0x11a31, 0x11a32, 0x11a33,
+    0x11a34, 0x11a35, 0x11a36, 0x11a37, 0x11a38, 0x11a39, 0x11a3a, 0x11a3b, 0x11a3c, 0x11a3d,
+    0x11a3e, 0x11a3f, 0x11a40, 0x11a41, 0x11a42, 0x11a43, 0x11a44, 0x11a45, 0x11a46, 0x11a47,
+    0x11a50, 0x11a51, 0x11a52, 0x11a53, 0x11a54, 0x11a55, 0x11a56, 0x11a57, 0x11a58, 0x11a59,
+    0x11a5a, 0x11a5b, 0x11a5c, 0x11a5d, 0x11a5e, 0x11a5f, 0x11a60, 0x11a61, 0x11a62, 0x11a63,
+    0x11a64, 0x11a65, 0x11a66, 0x11a67, 0x11a68, 0x11a69, 0x11a6a, 0x11a6b, 0x11a6c, 0x11a6d,
+    0x11a6e, 0x11a6f, 0x11a70, 0x11a71, 0x11a72, 0x11a73, 0x11a74, 0x11a75, 0x11a76, 0x11a77,
+    0x11a78, 0x11a79, 0x11a7a, 0x11a7b, 0x11a7c, 0x11a7d, 0x11a7e, 0x11a7f, 0x11a80, 0x11a81,
+    0x11a82, 0x11a83, 0x11a84, 0x11a85, 0x11a86, 0x11a87, 0x11a88, 0x11a89, 0x11a8a, 0x11a8b,
+    0x11a8c, 0x11a8d, 0x11a8e, 0x11a8f, 0x11a90, 0x11a91, 0x11a92, 0x11a93, 0x11a94, 0x11a95,
+    0x11a96, 0x11a97, 0x11a98, 0x11a99, 0x11a9a, 0x11a9b, 0x11a9c, 0x11a9d, 0x11a9e, 0x11a9f,
+    0x11aa0, 0x11aa1, 0x11aa2, 0x11ac0, 0x11ac1, 0x11ac2, 0x11ac3, 0x11ac4, 0x11ac5, 0x11ac6,
+    0x11ac7, 0x11ac8, 0x11ac9, 0x11aca, 0x11acb, 0x11acc, 0x11acd, 0x11ace, 0x11acf, 0x11ad0,
+    0x11ad1, 0x11ad2, 0x11ad3, 0x11ad4, 0x11ad5, 0x11ad6, 0x11ad7, 0x11ad8, 0x11ad9, 0x11ada,
+    0x11adb, 0x11adc, 0x11add, 0x11ade, 0x11adf, 0x11ae0, 0x11ae1, 0x11ae2, 0x11ae3, 0x11ae4,
+    0x11ae5, 0x11ae6, 0x11ae7, 0x11ae8, 0x11ae9, 0x11aea, 0x11aeb, 0x11aec, 0x11aed, 0x11aee,
+    0x11aef, 0x11af0, 0x11af1, 0x11af2, 0x11af3, 0x11af4, 0x11af5, 0x11af6, 0x11af7, 0x11af8,
+    0x11c00, 0x11c01, 0x11c02, 0x11c03, 0x11c04, 0x11c05, 0x11c06, 0x11c07, 0x11c08, 0x11c0a,
+    0x11c0b, 0x11c0c, 0x11c0d, 0x11c0e, 0x11c0f, 0x11c10, 0x11c11, 0x11c12, 0x11c13, 0x11c14,
+    0x11c15, 0x11c16, 0x11c17, 0x11c18, 0x11c19, 0x11c1a, 0x11c1b, 0x11c1c, 0x11c1d, 0x11c1e,
+    0x11c1f, 0x11c20, 0x11c21, 0x11c22, 0x11c23, 0x11c24, 0x11c25, 0x11c26, 0x11c27, 0x11c28,
+    0x11c29, 0x11c2a, 0x11c2b, 0x11c2c, 0x11c2d, 0x11c2e, 0x11c2f, 0x11c30, 0x11c31, 0x11c32,
+    0x11c33, 0x11c34, 0x11c35, 0x11c36, 0x11c38, 0x11c39, 0x11c3a, 0x11c3b, 0x11c3c, 0x11c3d,
+    0x11c3e, 0x11c3f, 0x11c40, 0x11c41, 0x11c42, 0x11c43, 0x11c44, 0x11c45, 0x11c50, 0x11c51,
+    0x11c52, 0x11c53, 0x11c54, 0x11c55, 0x11c56, 0x11c57, 0x11c58, 0x11c59, 0x11c5a, 0x11c5b,
+    0x11c5c, 0x11c5d, 0x11c5e, 0x11c5f, 0x11c60, 0x11c61, 0x11c62, 0x11c63, 0x11c64, 0x11c65,
+    0x11c66, 0x11c67, 0x11c68, 0x11c69, 0x11c6a, 0x11c6b, 0x11c6c, 0x11c70, 0x11c71, 0x11c72,
+    0x11c73, 0x11c74, 0x11c75, 0x11c76, 0x11c77, 0x11c78, 0x11c79, 0x11c7a, 0x11c7b, 0x11c7c,
+    0x11c7d, 0x11c7e, 0x11c7f, 0x11c80, 0x11c81, 0x11c82, 0x11c83, 0x11c84, 0x11c85, 0x11c86,
+    0x11c87, 0x11c88, 0x11c89, 0x11c8a, 0x11c8b, 0x11c8c, 0x11c8d, 0x11c8e, 0x11c8f, 0x11c92,
+    0x11c93, 0x11c94, 0x11c95, 0x11c96, 0x11c97, 0x11c98, 0x11c99, 0x11c9a, 0x11c9b, 0x11c9c,
+    0x11c9d, 0x11c9e, 0x11c9f, 0x11ca0, 0x11ca1, 0x11ca2, 0x11ca3, 0x11ca4, 0x11ca5, 0x11ca6,
+    0x11ca7, 0x11ca9, 0x11caa, 0x11cab, 0x11cac, 0x11cad, 0x11cae, 0x11caf, 0x11cb0, 0x11cb1,
+    0x11cb2, 0x11cb3, 0x11cb4, 0x11cb5, 0x11cb6, 0x11d00, 0x11d01, 0x11d02, 0x11d03, 0x11d04,
+    0x11d05, 0x11d06, 0x11d08, 0x11d09, 0x11d0b, 0x11d0c, 0x11d0d, 0x11d0e, 0x11d0f, 0x11d10,
+    0x11d11, 0x11d12, 0x11d13, 0x11d14, 0x11d15, 0x11d16, 0x11d17, 0x11d18, 0x11d19, 0x11d1a,
+    0x11d1b, 0x11d1c, 0x11d1d, 0x11d1e, 0x11d1f, 0x11d20, 0x11d21, 0x11d22, 0x11d23, 0x11d24,
+    0x11d25, 0x11d26, 0x11d27, 0x11d28, 0x11d29, 0x11d2a, 0x11d2b, 0x11d2c, 0x11d2d, 0x11d2e,
+    0x11d2f, 0x11d30, 0x11d31, 0x11d32, 0x11d33, 0x11d34, 0x11d35, 0x11d36, 0x11d3a, 0x11d3c,
+    0x11d3d, 0x11d3f, 0x11d40, 0x11d41, 0x11d42, 0x11d43, 0x11d44, 0x11d45, 0x11d46, 0x11d47,
+    0x11d50, 0x11d51, 0x11d52, 0x11d53, 0x11d54, 0x11d55, 0x11d56, 0x11d57, 0x11d58, 0x11d59,
+    0x11d60, 0x11d61, 0x11d62, 0x11d63, 0x11d64, 0x11d65, 0x11d67, 0x11d68, 0x11d6a, 0x11d6b,
+    0x11d6c, 0x11d6d, 0x11d6e, 0x11d6f, 0x11d70, 0x11d71, 0x11d72, 0x11d73, 0x11d74, 0x11d75,
+    0x11d76, 0x11d77, 0x11d78, 0x11d79, 0x11d7a, 0x11d7b, 0x11d7c, 0x11d7d, 0x11d7e, 0x11d7f,
+    0x11d80, 0x11d81, 0x11d82, 0x11d83, 0x11d84, 0x11d85, 0x11d86, 0x11d87, 0x11d88, 0x11d89,
+    0x11d8a, 0x11d8b, 0x11d8c, 0x11d8d, 0x11d8e, 0x11d90, 0x11d91, 0x11d93, 0x11d94, 0x11d95,
+    0x11d96, 0x11d97, 0x11d98, 0x11da0, 0x11da1, 0x11da2, 0x11da3, 0x11da4, 0x11da5, 0x11da6,
+    0x11da7, 0x11da8, 0x11da9, 0x11ee0, 0x11ee1, 0x11ee2, 0x11ee3, 0x11ee4, 0x11ee5, 0x11ee6,
+    0x11ee7, 0x11ee8, 0x11ee9, 0x11eea, 0x11eeb, 0x11eec, 0x11eed, 0x11eee, 0x11eef, 0x11ef0,
+    0x11ef1, 0x11ef2, 0x11ef3, 0x11ef4, 0x11ef5, 0x11ef6, 0x11ef7, 0x11ef8, 0x11fb0, 0x11fc0,
+    0x11fc1, 0x11fc2, 0x11fc3, 0x11fc4, 0x11fc5, 0x11fc6, 0x11fc7, 0x11fc8, 0x11fc9, 0x11fca,
+    0x11fcb, 0x11fcc, 0x11fcd, 0x11fce, 0x11fcf, 0x11fd0, 0x11fd1, 0x11fd2, 0x11fd3, 0x11fd4,
+    0x11fd5, 0x11fd6, 0x11fd7, 0x11fd8, 0x11fd9, 0x11fda, 0x11fdb, 0x11fdc, 0x11fdd, 0x11fde,
+    0x11fdf, 0x11fe0, 0x11fe1, 0x11fe2, 0x11fe3, 0x11fe4, 0x11fe5, 0x11fe6, 0x11fe7, 0x11fe8,
+    0x11fe9, 0x11fea, 0x11feb, 0x11fec, 0x11fed, 0x11fee, 0x11fef, 0x11ff0, 0x11ff1, 0x11fff,
+    0x12000, 0x12001, 0x12002, 0x12003, 0x12004, 0x12005, 0x12006, 0x12007, 0x12008, 0x12009,
+    0x1200a, 0x1200b, 0x1200c, 0x1200d, 0x1200e, 0x1200f, 0x12010, 0x12011, 0x12012, 0x12013,
+    0x12014, 0x12015, 0x12016, 0x12017, 0x12018, 0x12019, 0x1201a, 0x1201b, 0x1201c, 0x1201d,
+    0x1201e, 0x1201f, 0x12020, 0x12021, 0x12022, 0x12023, 0x12024, 0x12025, 0x12026, 0x12027,
+    0x12028, 0x12029, 0x1202a, 0x1202b, 0x1202c, 0x1202d, 0x1202e, 0x1202f, 0x12030, 0x12031,
+    0x12032, 0x12033, 0x12034, 0x12035, 0x12036, 0x12037, 0x12038, 0x12039, 0x1203a, 0x1203b,
+    0x1203c, 0x1203d, 0x1203e, 0x1203f, 0x12040, 0x12041, 0x12042, 0x12043, 0x12044, 0x12045,
+    0x12046, 0x12047, 0x12048, 0x12049, 0x1204a, 0x1204b, 0x1204c, 0x1204d, 0x1204e, 0x1204f,
+    0x12050, 0x12051, 0x12052, 0x12053, 0x12054, 0x12055, 0x12056, 0x12057, 0x12058, 0x12059,
+    0x1205a, 0x1205b, 0x1205c, 0x1205d, 0x1205e, 0x1205f, 0x12060, 0x12061, 0x12062, 0x12063,
+    0x12064, 0x12065, 0x12066, 0x12067, 0x12068, 0x12069, 0x1206a, 0x1206b, 0x1206c, 0x1206d,
+    0x1206e, 0x1206f, 0x12070, 0x12071, 0x12072, 0x12073, 0x12074, 0x12075, 0x12076, 0x12077,
+    0x12078, 0x12079, 0x1207a, 0x1207b, 0x1207c, 0x1207d, 0x1207e, 0x1207f, 0x12080, 0x12081,
+    0x12082, 0x12083, 0x12084, 0x12085, 0x12086, 0x12087, 0x12088, 0x12089, 0x1208a, 0x1208b,
+    0x1208c, 0x1208d, 0x1208e, 0x1208f, 0x12090, 0x12091, 0x12092, 0x12093, 0x12094, 0x12095,
+    0x12096, 0x12097, 0x12098, 0x12099, 0x1209a, 0x1209b, 0x1209c, 0x1209d, 0x1209e, 0x1209f,
+    0x120a0, 0x120a1, 0x120a2, 0x120a3, 0x120a4, 0x120a5, 0x120a6, 0x120a7, 0x120a8, 0x120a9,
+    0x120aa, 0x120ab, 0x120ac, 0x120ad, 0x120ae, 0x120af, 0x120b0, 0x120b1, 0x120b2, 0x120b3,
+    0x120b4, 0x120b5, 0x120b6, 0x120b7, 0x120b8, 0x120b9, 0x120ba, 0x120bb, 0x120bc, 0x120bd,
+    0x120be, 0x120bf, 0x120c0, 0x120c1, 0x120c2, 0x120c3, 0x120c4, 0x120c5, 0x120c6, 0x120c7,
+    0x120c8, 0x120c9, 0x120ca, 0x120cb, 0x120cc, 0x120cd, 0x120ce, 0x120cf, 0x120d0, 0x120d1,
+    0x120d2, 0x120d3, 0x120d4, 0x120d5, 0x120d6, 0x120d7, 0x120d8, 0x120d9, 0x120da, 0x120db,
+    0x120dc, 0x120dd, 0x120de, 0x120df, 0x120e0, 0x120e1, 0x120e2, 0x120e3, 0x120e4, 0x120e5,
+    0x120e6, 0x120e7, 0x120e8, 0x120e9, 0x120ea, 0x120eb, 0x120ec, 0x120ed, 0x120ee, 0x120ef,
+    0x120f0, 0x120f1, 0x120f2, 0x120f3, 0x120f4, 0x120f5, 0x120f6, 0x120f7, 0x120f8, 0x120f9,
+    0x120fa, 0x120fb, 0x120fc, 0x120fd, 0x120fe, 0x120ff, 0x12100, 0x12101, 0x12102, 0x12103,
+    0x12104, 0x12105, 0x12106, 0x12107, 0x12108, 0x12109, 0x1210a, 0x1210b, 0x1210c, 0x1210d,
+    0x1210e, 0x1210f, 0x12110, 0x12111, 0x12112, 0x12113, 0x12114, 0x12115, 0x12116, 0x12117,
+    0x12118, 0x12119, 0x1211a, 0x1211b, 0x1211c, 0x1211d, 0x1211e, 0x1211f, 0x12120, 0x12121,
+    0x12122, 0x12123, 0x12124, 0x12125, 0x12126, 0x12127, 0x12128, 0x12129, 0x1212a, 0x1212b,
+    0x1212c, 0x1212d, 0x1212e, 0x1212f, 0x12130, 0x12131, 0x12132, 0x12133, 0x12134, 0x12135,
+    0x12136, 0x12137, 0x12138, 0x12139, 0x1213a, 0x1213b, 0x1213c, 0x1213d, 0x1213e, 0x1213f,
+    0x12140, 0x12141, 0x12142, 0x12143, 0x12144, 0x12145, 0x12146, 0x12147, 0x12148, 0x12149,
+    0x1214a, 0x1214b, 0x1214c, 0x1214d, 0x1214e, 0x1214f, 0x12150, 0x12151, 0x12152, 0x12153,
+    0x12154, 0x12155, 0x12156, 0x12157, 0x12158, 0x12159, 0x1215a, 0x1215b, 0x1215c, 0x1215d,
+    0x1215e, 0x1215f, 0x12160, 0x12161, 0x12162, 0x12163, 0x12164, 0x12165, 0x12166, 0x12167,
+    0x12168, 0x12169, 0x1216a, 0x1216b, 0x1216c, 0x1216d, 0x1216e, 0x1216f, 0x12170, 0x12171,
+    0x12172, 0x12173, 0x12174, 0x12175, 0x12176, 0x12177, 0x12178, 0x12179, 0x1217a, 0x1217b,
+    0x1217c, 0x1217d, 0x1217e, 0x1217f, 0x12180, 0x12181, 0x12182, 0x12183, 0x12184, 0x12185,
+    0x12186, 0x12187, 0x12188, 0x12189, 0x1218a, 0x1218b, 0x1218c, 0x1218d, 0x1218e, 0x1218f,
+    0x12190, 0x12191, 0x12192, 0x12193, 0x12194, 0x12195, 0x12196, 0x12197, 0x12198, 0x12199,
+    0x1219a, 0x1219b, 0x1219c, 0x1219d, 0x1219e, 0x1219f, 0x121a0, 0x121a1, 0x121a2, 0x121a3,
+    0x121a4, 0x121a5, 0x121a6, 0x121a7, 0x121a8, 0x121a9, 0x121aa, 0x121ab, 0x121ac, 0x121ad,
+    0x121ae, 0x121af, 0x121b0, 0x121b1, 0x121b2, 0x121b3, 0x121b4, 0x121b5, 0x121b6, 0x121b7,
+    0x121b8, 0x121b9, 0x121ba, 0x121bb, 0x121bc, 0x121bd, 0x121be, 0x121bf, 0x121c0, 0x121c1,
+    0x121c2, 0x121c3, 0x121c4, 0x121c5, 0x121c6, 0x121c7, 0x121c8, 0x121c9, 0x121ca, 0x121cb,
+    0x121cc, 0x121cd, 0x121ce, 0x121cf, 0x121d0, 0x121d1, 0x121d2, 0x121d3, 0x121d4, 0x121d5,
+    0x121d6, 0x121d7, 0x121d8, 0x121d9, 0x121da, 0x121db, 0x121dc, 0x121dd, 0x121de, 0x121df,
+    0x121e0, 0x121e1, 0x121e2, 0x121e3, 0x121e4, 0x121e5, 0x121e6, 0x121e7, 0x121e8, 0x121e9,
+    0x121ea, 0x121eb, 0x121ec, 0x121ed, 0x121ee, 0x121ef, 0x121f0, 0x121f1, 0x121f2, 0x121f3,
+    0x121f4, 0x121f5, 0x121f6, 0x121f7, 0x121f8, 0x121f9, 0x121fa, 0x121fb, 0x121fc, 0x121fd,
+    0x121fe, 0x121ff, 0x12200, 0x12201, 0x12202, 0x12203, 0x12204, 0x12205, 0x12206, 0x12207,
+    0x12208, 0x12209, 0x1220a, 0x1220b, 0x1220c, 0x1220d, 0x1220e, 0x1220f, 0x12210, 0x12211,
+    0x12212, 0x12213, 0x12214, 0x12215, 0x12216, 0x12217, 0x12218, 0x12219, 0x1221a, 0x1221b,
+    0x1221c, 0x1221d, 0x1221e, 0x1221f, 0x12220, 0x12221, 0x12222, 0x12223, 0x12224, 0x12225,
+    0x12226, 0x12227, 0x12228, 0x12229, 0x1222a, 0x1222b, 0x1222c, 0x1222d, 0x1222e, 0x1222f,
+    0x12230, 0x12231, 0x12232, 0x12233, 0x12234, 0x12235, 0x12236, 0x12237, 0x12238, 0x12239,
+    0x1223a, 0x1223b, 0x1223c, 0x1223d, 0x1223e, 0x1223f, 0x12240, 0x12241, 0x12242, 0x12243,
+    0x12244, 0x12245, 0x12246, 0x12247, 0x12248, 0x12249, 0x1224a, 0x1224b, 0x1224c, 0x1224d,
+    0x1224e, 0x1224f, 0x12250, 0x12251, 0x12252, 0x12253, 0x12254, 0x12255, 0x12256, 0x12257,
+    0x12258, 0x12259, 0x1225a, 0x1225b, 0x1225c, 0x1225d, 0x1225e, 0x1225f, 0x12260, 0x12261,
+    0x12262, 0x12263, 0x12264, 0x12265, 0x12266, 0x12267, 0x12268, 0x12269, 0x1226a, 0x1226b,
+    0x1226c, 0x1226d, 0x1226e, 0x1226f, 0x12270, 0x12271, 0x12272, 0x12273, 0x12274, 0x12275,
+    0x12276, 0x12277, 0x12278, 0x12279, 0x1227a, 0x1227b, 0x1227c, 0x1227d, 0x1227e, 0x1227f,
+    0x12280, 0x12281, 0x12282, 0x12283, 0x12284, 0x12285, 0x12286, 0x12287, 0x12288, 0x12289,
+    0x1228a, 0x1228b, 0x1228c, 0x1228d, 0x1228e, 0x1228f, 0x12290, 0x12291, 0x12292, 0x12293,
+    0x12294, 0x12295, 0x12296, 0x12297, 0x12298, 0x12299, 0x1229a, 0x1229b, 0x1229c, 0x1229d,
+    0x1229e, 0x1229f, 0x122a0, 0x122a1, 0x122a2, 0x122a3, 0x122a4, 0x122a5, 0x122a6, 0x122a7,
+    0x122a8, 0x122a9, 0x122aa, 0x122ab, 0x122ac, 0x122ad, 0x122ae, 0x122af, 0x122b0, 0x122b1,
+    0x122b2, 0x122b3, 0x122b4, 0x122b5, 0x122b6, 0x122b7, 0x122b8, 0x122b9, 0x122ba, 0x122bb,
+    0x122bc, 0x122bd, 0x122be, 0x122bf, 0x122c0, 0x122c1, 0x122c2, 0x122c3, 0x122c4, 0x122c5,
+    0x122c6, 0x122c7, 0x122c8, 0x122c9, 0x122ca, 0x122cb, 0x122cc, 0x122cd, 0x122ce, 0x122cf,
+    0x122d0, 0x122d1, 0x122d2, 0x122d3, 0x122d4, 0x122d5, 0x122d6, 0x122d7, 0x122d8, 0x122d9,
+    0x122da, 0x122db, 0x122dc, 0x122dd, 0x122de, 0x122df, 0x122e0, 0x122e1, 0x122e2, 0x122e3,
+    0x122e4, 0x122e5, 0x122e6, 0x122e7, 0x122e8, 0x122e9, 0x122ea, 0x122eb, 0x122ec, 0x122ed,
+    0x122ee, 0x122ef, 0x122f0, 0x122f1, 0x122f2, 0x122f3, 0x122f4, 0x122f5, 0x122f6, 0x122f7,
+    0x122f8, 0x122f9, 0x122fa, 0x122fb, 0x122fc, 0x122fd, 0x122fe, 0x122ff, 0x12300, 0x12301,
+    0x12302, 0x12303, 0x12304, 0x12305, 0x12306, 0x12307, 0x12308, 0x12309, 0x1230a, 0x1230b,
+    0x1230c, 0x1230d, 0x1230e, 0x1230f, 0x12310, 0x12311, 0x12312, 0x12313, 0x12314, 0x12315,
+    0x12316, 0x12317, 0x12318, 0x12319, 0x1231a, 0x1231b, 0x1231c, 0x1231d, 0x1231e, 0x1231f,
+    0x12320, 0x12321, 0x12322, 0x12323, 0x12324, 0x12325, 0x12326, 0x12327, 0x12328, 0x12329,
+    0x1232a, 0x1232b, 0x1232c, 0x1232d, 0x1232e, 0x1232f, 0x12330, 0x12331, 0x12332, 0x12333,
+    0x12334, 0x12335, 0x12336, 0x12337, 0x12338, 0x12339, 0x1233a, 0x1233b, 0x1233c, 0x1233d,
+    0x1233e, 0x1233f, 0x12340, 0x12341, 0x12342, 0x12343, 0x12344, 0x12345, 0x12346, 0x12347,
+    0x12348, 0x12349, 0x1234a, 0x1234b, 0x1234c, 0x1234d, 0x1234e, 0x1234f, 0x12350, 0x12351,
+    0x12352, 0x12353, 0x12354, 0x12355, 0x12356, 0x12357, 0x12358, 0x12359, 0x1235a, 0x1235b,
+    0x1235c, 0x1235d, 0x1235e, 0x1235f, 0x12360, 0x12361, 0x12362, 0x12363, 0x12364, 0x12365,
+    0x12366, 0x12367, 0x12368, 0x12369, 0x1236a, 0x1236b, 0x1236c, 0x1236d, 0x1236e, 0x1236f,
+    0x12370, 0x12371, 0x12372, 0x12373, 0x12374, 0x12375, 0x12376, 0x12377, 0x12378, 0x12379,
+    0x1237a, 0x1237b, 0x1237c, 0x1237d, 0x1237e, 0x1237f, 0x12380, 0x12381, 0x12382, 0x12383,
+    0x12384, 0x12385, 0x12386, 0x12387, 0x12388, 0x12389, 0x1238a, 0x1238b, 0x1238c, 0x1238d,
+    0x1238e, 0x1238f, 0x12390, 0x12391, 0x12392, 0x12393, 0x12394, 0x12395, 0x12396, 0x12397,
+    0x12398, 0x12399, 0x12400, 0x12401, 0x12402, 0x12403, 0x12404, 0x12405, 0x12406, 0x12407,
+    0x12408, 0x12409, 0x1240a, 0x1240b, 0x1240c, 0x1240d, 0x1240e, 0x1240f, 0x12410, 0x12411,
+    0x12412, 0x12413, 0x12414, 0x12415, 0x12416, 0x12417, 0x12418, 0x12419, 0x1241a, 0x1241b,
+    0x1241c, 0x1241d, 0x1241e, 0x1241f, 0x12420, 0x12421, 0x12422, 0x12423, 0x12424, 0x12425,
+    0x12426, 0x12427, 0x12428, 0x12429, 0x1242a, 0x1242b, 0x1242c, 0x1242d, 0x1242e, 0x1242f,
+    0x12430, 0x12431, 0x12432, 0x12433, 0x12434, 0x12435, 0x12436, 0x12437, 0x12438, 0x12439,
+    0x1243a, 0x1243b, 0x1243c, 0x1243d, 0x1243e, 0x1243f, 0x12440, 0x12441, 0x12442, 0x12443,
+    0x12444, 0x12445, 0x12446, 0x12447, 0x12448, 0x12449, 0x1244a, 0x1244b, 0x1244c, 0x1244d,
+    0x1244e, 0x1244f, 0x12450, 0x12451, 0x12452, 0x12453, 0x12454, 0x12455, 0x12456, 0x12457,
+    0x12458, 0x12459, 0x1245a, 0x1245b, 0x1245c, 0x1245d, 0x1245e, 0x1245f, 0x12460, 0x12461,
+    0x12462, 0x12463, 0x12464, 0x12465, 0x12466, 0x12467, 0x12468, 0x12469, 0x1246a, 0x1246b,
+    0x1246c, 0x1246d, 0x1246e, 0x12470, 0x12471, 0x12472, 0x12473, 0x12474, 0x12480, 0x12481,
+    0x12482, 0x12483, 0x12484, 0x12485, 0x12486, 0x12487, 0x12488, 0x12489, 0x1248a, 0x1248b,
+    0x1248c, 0x1248d, 0x1248e, 0x1248f, 0x12490, 0x12491, 0x12492, 0x12493, 0x12494, 0x12495,
+    0x12496, 0x12497, 0x12498, 0x12499, 0x1249a, 0x1249b, 0x1249c, 0x1249d, 0x1249e, 0x1249f,
+    0x124a0, 0x124a1, 0x124a2, 0x124a3, 0x124a4, 0x124a5, 0x124a6, 0x124a7, 0x124a8, 0x124a9,
+    0x124aa, 0x124ab, 0x124ac, 0x124ad, 0x124ae, 0x124af, 0x124b0, 0x124b1, 0x124b2, 0x124b3,
+    0x124b4, 0x124b5, 0x124b6, 0x124b7, 0x124b8, 0x124b9, 0x124ba, 0x124bb, 0x124bc, 0x124bd,
+    0x124be, 0x124bf, 0x124c0, 0x124c1, 0x124c2, 0x124c3, 0x124c4, 0x124c5, 0x124c6, 0x124c7,
+    0x124c8, 0x124c9, 0x124ca, 0x124cb, 0x124cc, 0x124cd, 0x124ce, 0x124cf, 0x124d0, 0x124d1,
+    0x124d2, 0x124d3, 0x124d4, 0x124d5, 0x124d6, 0x124d7, 0x124d8, 0x124d9, 0x124da, 0x124db,
+    0x124dc, 0x124dd, 0x124de, 0x124df, 0x124e0, 0x124e1, 0x124e2, 0x124e3, 0x124e4, 0x124e5,
+    0x124e6, 0x124e7, 0x124e8, 0x124e9, 0x124ea, 0x124eb, 0x124ec, 0x124ed, 0x124ee, 0x124ef,
+    0x124f0, 0x124f1, 0x124f2, 0x124f3, 0x124f4, 0x124f5, 0x124f6, 0x124f7, 0x124f8, 0x124f9,
+    0x124fa, 0x124fb, 0x124fc, 0x124fd, 0x124fe, 0x124ff, 0x12500, 0x12501, 0x12502, 0x12503,
+    0x12504, 0x12505, 0x12506, 0x12507, 0x12508, 0x12509, 0x1250a, 0x1250b, 0x1250c, 0x1250d,
+    0x1250e, 0x1250f, 0x12510, 0x12511, 0x12512, 0x12513, 0x12514, 0x12515, 0x12516, 0x12517,
+    0x12518, 0x12519, 0x1251a, 0x1251b, 0x1251c, 0x1251d, 0x1251e, 0x1251f, 0x12520, 0x12521,
+    0x12522, 0x12523, 0x12524, 0x12525, 0x12526, 0x12527, 0x12528, 0x12529, 0x1252a, 0x1252b,
+    0x1252c, 0x1252d, 0x1252e, 0x1252f, 0x12530, 0x12531, 0x12532, 0x12533, 0x12534, 0x12535,
+    0x12536, 0x12537, 0x12538, 0x12539, 0x1253a, 0x1253b, 0x1253c, 0x1253d, 0x1253e, 0x1253f,
+    0x12540, 0x12541, 0x12542, 0x12543, 0x13000, 0x13001, 0x13002, 0x13003, 0x13004, 0x13005,
+    0x13006, 0x13007, 0x13008, 0x13009, 0x1300a, 0x1300b, 0x1300c, 0x1300d, 0x1300e, 0x1300f,
+    0x13010, 0x13011, 0x13012, 0x13013, 0x13014, 0x13015, 0x13016, 0x13017, 0x13018, 0x13019,
+    0x1301a, 0x1301b, 0x1301c, 0x1301d, 0x1301e, 0x1301f, 0x13020, 0x13021, 0x13022, 0x13023,
+    0x13024, 0x13025, 0x13026, 0x13027, 0x13028, 0x13029, 0x1302a, 0x1302b, 0x1302c, 0x1302d,
+    0x1302e, 0x1302f, 0x13030, 0x13031, 0x13032, 0x13033, 0x13034, 0x13035, 0x13036, 0x13037,
+    0x13038, 0x13039, 0x1303a, 0x1303b, 0x1303c, 0x1303d, 0x1303e, 0x1303f, 0x13040, 0x13041,
+    0x13042, 0x13043, 0x13044, 0x13045, 0x13046, 0x13047, 0x13048, 0x13049, 0x1304a, 0x1304b,
+    0x1304c, 0x1304d, 0x1304e, 0x1304f, 0x13050, 0x13051, 0x13052, 0x13053, 0x13054, 0x13055,
+    0x13056, 0x13057, 0x13058, 0x13059, 0x1305a, 0x1305b, 0x1305c, 0x1305d, 0x1305e, 0x1305f,
+    0x13060, 0x13061, 0x13062, 0x13063, 0x13064, 0x13065, 0x13066, 0x13067, 0x13068, 0x13069,
+    0x1306a, 0x1306b, 0x1306c, 0x1306d, 0x1306e, 0x1306f, 0x13070, 0x13071, 0x13072, 0x13073,
+    0x13074, 0x13075, 0x13076, 0x13077, 0x13078, 0x13079, 0x1307a, 0x1307b, 0x1307c, 0x1307d,
+    0x1307e, 0x1307f, 0x13080, 0x13081, 0x13082, 0x13083, 0x13084, 0x13085, 0x13086, 0x13087,
+    0x13088, 0x13089, 0x1308a, 0x1308b, 0x1308c, 0x1308d, 0x1308e, 0x1308f, 0x13090, 0x13091,
+    0x13092, 0x13093, 0x13094, 0x13095, 0x13096, 0x13097, 0x13098, 0x13099, 0x1309a, 0x1309b,
+    0x1309c, 0x1309d, 0x1309e, 0x1309f, 0x130a0, 0x130a1, 0x130a2, 0x130a3, 0x130a4, 0x130a5,
+    0x130a6, 0x130a7, 0x130a8, 0x130a9, 0x130aa, 0x130ab, 0x130ac, 0x130ad, 0x130ae, 0x130af,
+    0x130b0, 0x130b1, 0x130b2, 0x130b3, 0x130b4, 0x130b5, 0x130b6, 0x130b7, 0x130b8, 0x130b9,
+    0x130ba, 0x130bb, 0x130bc, 0x130bd, 0x130be, 0x130bf, 0x130c0, 0x130c1, 0x130c2, 0x130c3,
+    0x130c4, 0x130c5, 0x130c6, 0x130c7, 0x130c8, 0x130c9, 0x130ca, 0x130cb, 0x130cc, 0x130cd,
+    0x130ce, 0x130cf, 0x130d0, 0x130d1, 0x130d2, 0x130d3, 0x130d4, 0x130d5, 0x130d6, 0x130d7,
+    0x130d8, 0x130d9, 0x130da, 0x130db, 0x130dc, 0x130dd, 0x130de, 0x130df, 0x130e0, 0x130e1,
+    0x130e2, 0x130e3, 0x130e4, 0x130e5, 0x130e6, 0x130e7, 0x130e8, 0x130e9, 0x130ea, 0x130eb,
+    0x130ec, 0x130ed, 0x130ee, 0x130ef, 0x130f0, 0x130f1, 0x130f2, 0x130f3, 0x130f4, 0x130f5,
+    0x130f6, 0x130f7, 0x130f8, 0x130f9, 0x130fa, 0x130fb, 0x130fc, 0x130fd, 0x130fe, 0x130ff,
+    0x13100, 0x13101, 0x13102, 0x13103, 0x13104, 0x13105, 0x13106, 0x13107, 0x13108, 0x13109,
+    0x1310a, 0x1310b, 0x1310c, 0x1310d, 0x1310e, 0x1310f, 0x13110, 0x13111, 0x13112, 0x13113,
+    0x13114, 0x13115, 0x13116, 0x13117, 0x13118, 0x13119, 0x1311a, 0x1311b, 0x1311c, 0x1311d,
+    0x1311e, 0x1311f, 0x13120, 0x13121, 0x13122, 0x13123, 0x13124, 0x13125, 0x13126, 0x13127,
+    0x13128, 0x13129, 0x1312a, 0x1312b, 0x1312c, 0x1312d, 0x1312e, 0x1312f, 0x13130, 0x13131,
+    0x13132, 0x13133, 0x13134, 0x13135, 0x13136, 0x13137, 0x13138, 0x13139, 0x1313a, 0x1313b,
+    0x1313c, 0x1313d, 0x1313e, 0x1313f, 0x13140, 0x13141, 0x13142, 0x13143, 0x13144, 0x13145,
+    0x13146, 0x13147, 0x13148, 0x13149, 0x1314a, 0x1314b, 0x1314c, 0x1314d, 0x1314e, 0x1314f,
+    0x13150, 0x13151, 0x13152, 0x13153, 0x13154, 0x13155, 0x13156, 0x13157, 0x13158, 0x13159,
+    0x1315a, 0x1315b, 0x1315c, 0x1315d, 0x1315e, 0x1315f, 0x13160, 0x13161, 0x13162, 0x13163,
+    0x13164, 0x13165, 0x13166, 0x13167, 0x13168, 0x13169, 0x1316a, 0x1316b, 0x1316c, 0x1316d,
+    0x1316e, 0x1316f, 0x13170, 0x13171, 0x13172, 0x13173, 0x13174, 0x13175, 0x13176, 0x13177,
+    0x13178, 0x13179, 0x1317a, 0x1317b, 0x1317c, 0x1317d, 0x1317e, 0x1317f, 0x13180, 0x13181,
+    0x13182, 0x13183, 0x13184, 0x13185, 0x13186, 0x13187, 0x13188, 0x13189, 0x1318a, 0x1318b,
+    0x1318c, 0x1318d, 0x1318e, 0x1318f, 0x13190, 0x13191, 0x13192, 0x13193, 0x13194, 0x13195,
+    0x13196, 0x13197, 0x13198, 0x13199, 0x1319a, 0x1319b, 0x1319c, 0x1319d, 0x1319e, 0x1319f,
+    0x131a0, 0x131a1, 0x131a2, 0x131a3, 0x131a4, 0x131a5, 0x131a6, 0x131a7, 0x131a8, 0x131a9,
+    0x131aa, 0x131ab, 0x131ac, 0x131ad, 0x131ae, 0x131af, 0x131b0, 0x131b1, 0x131b2, 0x131b3,
+    0x131b4, 0x131b5, 0x131b6, 0x131b7, 0x131b8, 0x131b9, 0x131ba, 0x131bb, 0x131bc, 0x131bd,
+    0x131be, 0x131bf, 0x131c0, 0x131c1, 0x131c2, 0x131c3, 0x131c4, 0x131c5, 0x131c6, 0x131c7,
+    0x131c8, 0x131c9, 0x131ca, 0x131cb, 0x131cc, 0x131cd, 0x131ce, 0x131cf, 0x131d0, 0x131d1,
+    0x131d2, 0x131d3, 0x131d4, 0x131d5, 0x131d6, 0x131d7, 0x131d8, 0x131d9, 0x131da, 0x131db,
+    0x131dc, 0x131dd, 0x131de, 0x131df, 0x131e0, 0x131e1, 0x131e2, 0x131e3, 0x131e4, 0x131e5,
+    0x131e6, 0x131e7, 0x131e8, 0x131e9, 0x131ea, 0x131eb, 0x131ec, 0x131ed, 0x131ee, 0x131ef,
+    0x131f0, 0x131f1, 0x131f2, 0x131f3, 0x131f4, 0x131f5, 0x131f6, 0x131f7, 0x131f8, 0x131f9,
+    0x131fa, 0x131fb, 0x131fc, 0x131fd, 0x131fe, 0x131ff, 0x13200, 0x13201, 0x13202, 0x13203,
+    0x13204, 0x13205, 0x13206, 0x13207, 0x13208, 0x13209, 0x1320a, 0x1320b, 0x1320c, 0x1320d,
+    0x1320e, 0x1320f, 0x13210, 0x13211, 0x13212, 0x13213, 0x13214, 0x13215, 0x13216, 0x13217,
+    0x13218, 0x13219, 0x1321a, 0x1321b, 0x1321c, 0x1321d, 0x1321e, 0x1321f, 0x13220, 0x13221,
+    0x13222, 0x13223, 0x13224, 0x13225, 0x13226, 0x13227, 0x13228, 0x13229, 0x1322a, 0x1322b,
+    0x1322c, 0x1322d, 0x1322e, 0x1322f, 0x13230, 0x13231, 0x13232, 0x13233, 0x13234, 0x13235,
+    0x13236, 0x13237, 0x13238, 0x13239, 0x1323a, 0x1323b, 0x1323c, 0x1323d, 0x1323e, 0x1323f,
+    0x13240, 0x13241, 0x13242, 0x13243, 0x13244, 0x13245, 0x13246, 0x13247, 0x13248, 0x13249,
+    0x1324a, 0x1324b, 0x1324c, 0x1324d, 0x1324e, 0x1324f, 0x13250, 0x13251, 0x13252, 0x13253,
+    0x13254, 0x13255, 0x13256, 0x13257, 0x13258, 0x13259, 0x1325a, 0x1325b, 0x1325c, 0x1325d,
+    0x1325e, 0x1325f, 0x13260, 0x13261, 0x13262, 0x13263, 0x13264, 0x13265, 0x13266, 0x13267,
+    0x13268, 0x13269, 0x1326a, 0x1326b, 0x1326c, 0x1326d, 0x1326e, 0x1326f, 0x13270, 0x13271,
+    0x13272, 0x13273, 0x13274, 0x13275, 0x13276, 0x13277, 0x13278, 0x13279, 0x1327a, 0x1327b,
+    0x1327c, 0x1327d, 0x1327e, 0x1327f, 0x13280, 0x13281, 0x13282, 0x13283, 0x13284, 0x13285,
+    0x13286, 0x13287, 0x13288, 0x13289, 0x1328a, 0x1328b, 0x1328c, 0x1328d, 0x1328e, 0x1328f,
+    0x13290, 0x13291, 0x13292, 0x13293, 0x13294, 0x13295, 0x13296, 0x13297, 0x13298, 0x13299,
+    0x1329a, 0x1329b, 0x1329c, 0x1329d, 0x1329e, 0x1329f, 0x132a0, 0x132a1, 0x132a2, 0x132a3,
+    0x132a4, 0x132a5, 0x132a6, 0x132a7, 0x132a8, 0x132a9, 0x132aa, 0x132ab, 0x132ac, 0x132ad,
+    0x132ae, 0x132af, 0x132b0, 0x132b1, 0x132b2, 0x132b3, 0x132b4, 0x132b5, 0x132b6, 0x132b7,
+    0x132b8, 0x132b9, 0x132ba, 0x132bb, 0x132bc, 0x132bd, 0x132be, 0x132bf, 0x132c0, 0x132c1,
+    0x132c2, 0x132c3, 0x132c4, 0x132c5, 0x132c6, 0x132c7, 0x132c8, 0x132c9, 0x132ca, 0x132cb,
+    0x132cc, 0x132cd, 0x132ce, 0x132cf, 0x132d0, 0x132d1, 0x132d2, 0x132d3, 0x132d4, 0x132d5,
+    0x132d6, 0x132d7, 0x132d8, 0x132d9, 0x132da, 0x132db, 0x132dc, 0x132dd, 0x132de, 0x132df,
+    0x132e0, 0x132e1, 0x132e2, 0x132e3, 0x132e4, 0x132e5, 0x132e6, 0x132e7, 0x132e8, 0x132e9,
+    0x132ea, 0x132eb, 0x132ec, 0x132ed, 0x132ee, 0x132ef, 0x132f0, 0x132f1, 0x132f2, 0x132f3,
+    0x132f4, 0x132f5, 0x132f6, 0x132f7, 0x132f8, 0x132f9, 0x132fa, 0x132fb, 0x132fc, 0x132fd,
+    0x132fe, 0x132ff, 0x13300, 0x13301, 0x13302, 0x13303, 0x13304, 0x13305, 0x13306, 0x13307,
+    0x13308, 0x13309, 0x1330a, 0x1330b, 0x1330c, 0x1330d, 0x1330e, 0x1330f, 0x13310, 0x13311,
+    0x13312, 0x13313, 0x13314, 0x13315, 0x13316, 0x13317, 0x13318, 0x13319, 0x1331a, 0x1331b,
+    0x1331c, 0x1331d, 0x1331e, 0x1331f, 0x13320, 0x13321, 0x13322, 0x13323, 0x13324, 0x13325,
+    0x13326, 0x13327, 0x13328, 0x13329, 0x1332a, 0x1332b, 0x1332c, 0x1332d, 0x1332e, 0x1332f,
+    0x13330, 0x13331, 0x13332, 0x13333, 0x13334, 0x13335, 0x13336, 0x13337, 0x13338, 0x13339,
+    0x1333a, 0x1333b, 0x1333c, 0x1333d, 0x1333e, 0x1333f, 0x13340, 0x13341, 0x13342, 0x13343,
+    0x13344, 0x13345, 0x13346, 0x13347, 0x13348, 0x13349, 0x1334a, 0x1334b, 0x1334c, 0x1334d,
+    0x1334e, 0x1334f, 0x13350, 0x13351, 0x13352, 0x13353, 0x13354, 0x13355, 0x13356, 0x13357,
+    0x13358, 0x13359, 0x1335a, 0x1335b, 0x1335c, 0x1335d, 0x1335e, 0x1335f, 0x13360, 0x13361,
+    0x13362, 0x13363, 0x13364, 0x13365, 0x13366, 0x13367, 0x13368, 0x13369, 0x1336a, 0x1336b,
+    0x1336c, 0x1336d, 0x1336e, 0x1336f, 0x13370, 0x13371, 0x13372, 0x13373, 0x13374, 0x13375,
+    0x13376, 0x13377, 0x13378, 0x13379, 0x1337a, 0x1337b, 0x1337c, 0x1337d, 0x1337e, 0x1337f,
+    0x13380, 0x13381, 0x13382, 0x13383, 0x13384, 0x13385, 0x13386, 0x13387, 0x13388, 0x13389,
+    0x1338a, 0x1338b, 0x1338c, 0x1338d, 0x1338e, 0x1338f, 0x13390, 0x13391, 0x13392, 0x13393,
+    0x13394, 0x13395, 0x13396, 0x13397, 0x13398, 0x13399, 0x1339a, 0x1339b, 0x1339c, 0x1339d,
+    0x1339e, 0x1339f, 0x133a0, 0x133a1, 0x133a2, 0x133a3, 0x133a4, 0x133a5, 0x133a6, 0x133a7,
+    0x133a8, 0x133a9, 0x133aa, 0x133ab, 0x133ac, 0x133ad, 0x133ae, 0x133af, 0x133b0, 0x133b1,
+    0x133b2, 0x133b3, 0x133b4, 0x133b5, 0x133b6, 0x133b7, 0x133b8, 0x133b9, 0x133ba, 0x133bb,
+    0x133bc, 0x133bd, 0x133be, 0x133bf, 0x133c0, 0x133c1, 0x133c2, 0x133c3, 0x133c4, 0x133c5,
+    0x133c6, 0x133c7, 0x133c8, 0x133c9, 0x133ca, 0x133cb, 0x133cc, 0x133cd, 0x133ce, 0x133cf,
+    0x133d0, 0x133d1, 0x133d2, 0x133d3, 0x133d4, 0x133d5, 0x133d6, 0x133d7, 0x133d8, 0x133d9,
+    0x133da, 0x133db, 0x133dc, 0x133dd, 0x133de, 0x133df, 0x133e0, 0x133e1, 0x133e2, 0x133e3,
+    0x133e4, 0x133e5, 0x133e6, 0x133e7, 0x133e8, 0x133e9, 0x133ea, 0x133eb, 0x133ec, 0x133ed,
+    0x133ee, 0x133ef, 0x133f0, 0x133f1, 0x133f2, 0x133f3, 0x133f4, 0x133f5, 0x133f6, 0x133f7,
+    0x133f8, 0x133f9, 0x133fa, 0x133fb, 0x133fc, 0x133fd, 0x133fe, 0x133ff, 0x13400, 0x13401,
+    0x13402, 0x13403, 0x13404, 0x13405, 0x13406, 0x13407, 0x13408, 0x13409, 0x1340a, 0x1340b,
+    0x1340c, 0x1340d, 0x1340e, 0x1340f, 0x13410, 0x13411, 0x13412, 0x13413, 0x13414, 0x13415,
+    0x13416, 0x13417, 0x13418, 0x13419, 0x1341a, 0x1341b, 0x1341c, 0x1341d, 0x1341e, 0x1341f,
+    0x13420, 0x13421, 0x13422, 0x13423, 0x13424, 0x13425, 0x13426, 0x13427, 0x13428, 0x13429,
+    0x1342a, 0x1342b, 0x1342c, 0x1342d, 0x1342e, 0x13430, 0x13431, 0x13432, 0x13433, 0x13434,
+    0x13435, 0x13436, 0x13437, 0x13438, 0x14400, 0x14401, 0x14402, 0x14403, 0x14404, 0x14405,
+    0x14406, 0x14407, 0x14408, 0x14409, 0x1440a, 0x1440b, 0x1440c, 0x1440d, 0x1440e, 0x1440f,
+    0x14410, 0x14411, 0x14412, 0x14413, 0x14414, 0x14415, 0x14416, 0x14417, 0x14418, 0x14419,
+    0x1441a, 0x1441b, 0x1441c, 0x1441d, 0x1441e, 0x1441f, 0x14420, 0x14421, 0x14422, 0x14423,
+    0x14424, 0x14425, 0x14426, 0x14427, 0x14428, 0x14429, 0x1442a, 0x1442b, 0x1442c, 0x1442d,
+    0x1442e, 0x1442f, 0x14430, 0x14431, 0x14432, 0x14433, 0x14434, 0x14435, 0x14436, 0x14437,
+    0x14438, 0x14439, 0x1443a, 0x1443b, 0x1443c, 0x1443d, 0x1443e, 0x1443f, 0x14440, 0x14441,
+    0x14442, 0x14443, 0x14444, 0x14445, 0x14446, 0x14447, 0x14448, 0x14449, 0x1444a, 0x1444b,
+    0x1444c, 0x1444d, 0x1444e, 0x1444f, 0x14450, 0x14451, 0x14452, 0x14453, 0x14454, 0x14455,
+    0x14456, 0x14457, 0x14458, 0x14459, 0x1445a, 0x1445b, 0x1445c, 0x1445d, 0x1445e, 0x1445f,
+    0x14460, 0x14461, 0x14462, 0x14463, 0x14464, 0x14465, 0x14466, 0x14467, 0x14468, 0x14469,
+    0x1446a, 0x1446b, 0x1446c, 0x1446d, 0x1446e, 0x1446f, 0x14470, 0x14471, 0x14472, 0x14473,
+    0x14474, 0x14475, 0x14476, 0x14477, 0x14478, 0x14479, 0x1447a, 0x1447b, 0x1447c, 0x1447d,
+    0x1447e, 0x1447f, 0x14480, 0x14481, 0x14482, 0x14483, 0x14484, 0x14485, 0x14486, 0x14487,
+    0x14488, 0x14489, 0x1448a, 0x1448b, 0x1448c, 0x1448d, 0x1448e, 0x1448f, 0x14490, 0x14491,
+    0x14492, 0x14493, 0x14494, 0x14495, 0x14496, 0x14497, 0x14498, 0x14499, 0x1449a, 0x1449b,
+    0x1449c, 0x1449d, 0x1449e, 0x1449f, 0x144a0, 0x144a1, 0x144a2, 0x144a3, 0x144a4, 0x144a5,
+    0x144a6, 0x144a7, 0x144a8, 0x144a9, 0x144aa, 0x144ab, 0x144ac, 0x144ad, 0x144ae, 0x144af,
+    0x144b0, 0x144b1, 0x144b2, 0x144b3, 0x144b4, 0x144b5, 0x144b6, 0x144b7, 0x144b8, 0x144b9,
+    0x144ba, 0x144bb, 0x144bc, 0x144bd, 0x144be, 0x144bf, 0x144c0, 0x144c1, 0x144c2, 0x144c3,
+    0x144c4, 0x144c5, 0x144c6, 0x144c7, 0x144c8, 0x144c9, 0x144ca, 0x144cb, 0x144cc, 0x144cd,
+    0x144ce, 0x144cf, 0x144d0, 0x144d1, 0x144d2, 0x144d3, 0x144d4, 0x144d5, 0x144d6, 0x144d7,
+    0x144d8, 0x144d9, 0x144da, 0x144db, 0x144dc, 0x144dd, 0x144de, 0x144df, 0x144e0, 0x144e1,
+    0x144e2, 0x144e3, 0x144e4, 0x144e5, 0x144e6, 0x144e7, 0x144e8, 0x144e9, 0x144ea, 0x144eb,
+    0x144ec, 0x144ed, 0x144ee, 0x144ef, 0x144f0, 0x144f1, 0x144f2, 0x144f3, 0x144f4, 0x144f5,
+    0x144f6, 0x144f7, 0x144f8, 0x144f9, 0x144fa, 0x144fb, 0x144fc, 0x144fd, 0x144fe, 0x144ff,
+    0x14500, 0x14501, 0x14502, 0x14503, 0x14504, 0x14505, 0x14506, 0x14507, 0x14508, 0x14509,
+    0x1450a, 0x1450b, 0x1450c, 0x1450d, 0x1450e, 0x1450f, 0x14510, 0x14511, 0x14512, 0x14513,
+    0x14514, 0x14515, 0x14516, 0x14517, 0x14518, 0x14519, 0x1451a, 0x1451b, 0x1451c, 0x1451d,
+    0x1451e, 0x1451f, 0x14520, 0x14521, 0x14522, 0x14523, 0x14524, 0x14525, 0x14526, 0x14527,
+    0x14528, 0x14529, 0x1452a, 0x1452b, 0x1452c, 0x1452d, 0x1452e, 0x1452f, 0x14530, 0x14531,
+    0x14532, 0x14533, 0x14534, 0x14535, 0x14536, 0x14537, 0x14538, 0x14539, 0x1453a, 0x1453b,
+    0x1453c, 0x1453d, 0x1453e, 0x1453f, 0x14540, 0x14541, 0x14542, 0x14543, 0x14544, 0x14545,
+    0x14546, 0x14547, 0x14548, 0x14549, 0x1454a, 0x1454b, 0x1454c, 0x1454d, 0x1454e, 0x1454f,
+    0x14550, 0x14551, 0x14552, 0x14553, 0x14554, 0x14555, 0x14556, 0x14557, 0x14558, 0x14559,
+    0x1455a, 0x1455b, 0x1455c, 0x1455d, 0x1455e, 0x1455f, 0x14560, 0x14561, 0x14562, 0x14563,
+    0x14564, 0x14565, 0x14566, 0x14567, 0x14568, 0x14569, 0x1456a, 0x1456b, 0x1456c, 0x1456d,
+    0x1456e, 0x1456f, 0x14570, 0x14571, 0x14572, 0x14573, 0x14574, 0x14575, 0x14576, 0x14577,
+    0x14578, 0x14579, 0x1457a, 0x1457b, 0x1457c, 0x1457d, 0x1457e, 0x1457f, 0x14580, 0x14581,
+    0x14582, 0x14583, 0x14584, 0x14585, 0x14586, 0x14587, 0x14588, 0x14589, 0x1458a, 0x1458b,
+    0x1458c, 0x1458d, 0x1458e, 0x1458f, 0x14590, 0x14591, 0x14592, 0x14593, 0x14594, 0x14595,
+    0x14596, 0x14597, 0x14598, 0x14599, 0x1459a, 0x1459b, 0x1459c, 0x1459d, 0x1459e, 0x1459f,
+    0x145a0, 0x145a1, 0x145a2, 0x145a3, 0x145a4, 0x145a5, 0x145a6, 0x145a7, 0x145a8, 0x145a9,
+    0x145aa, 0x145ab, 0x145ac, 0x145ad, 0x145ae, 0x145af, 0x145b0, 0x145b1, 0x145b2, 0x145b3,
+    0x145b4, 0x145b5, 0x145b6, 0x145b7, 0x145b8, 0x145b9, 0x145ba, 0x145bb, 0x145bc, 0x145bd,
+    0x145be, 0x145bf, 0x145c0, 0x145c1, 0x145c2, 0x145c3, 0x145c4, 0x145c5, 0x145c6, 0x145c7,
+    0x145c8, 0x145c9, 0x145ca, 0x145cb, 0x145cc, 0x145cd, 0x145ce, 0x145cf, 0x145d0, 0x145d1,
+    0x145d2, 0x145d3, 0x145d4, 0x145d5, 0x145d6, 0x145d7, 0x145d8, 0x145d9, 0x145da, 0x145db,
+    0x145dc, 0x145dd, 0x145de, 0x145df, 0x145e0, 0x145e1, 0x145e2, 0x145e3, 0x145e4, 0x145e5,
+    0x145e6, 0x145e7, 0x145e8, 0x145e9, 0x145ea, 0x145eb, 0x145ec, 0x145ed, 0x145ee, 0x145ef,
+    0x145f0, 0x145f1, 0x145f2, 0x145f3, 0x145f4, 0x145f5, 0x145f6, 0x145f7, 0x145f8, 0x145f9,
+    0x145fa, 0x145fb, 0x145fc, 0x145fd, 0x145fe, 0x145ff, 0x14600, 0x14601, 0x14602, 0x14603,
+    0x14604, 0x14605, 0x14606, 0x14607, 0x14608, 0x14609, 0x1460a, 0x1460b, 0x1460c, 0x1460d,
+    0x1460e, 0x1460f, 0x14610, 0x14611, 0x14612, 0x14613, 0x14614, 0x14615, 0x14616, 0x14617,
+    0x14618, 0x14619, 0x1461a, 0x1461b, 0x1461c, 0x1461d, 0x1461e, 0x1461f, 0x14620, 0x14621,
+    0x14622, 0x14623, 0x14624, 0x14625, 0x14626, 0x14627, 0x14628, 0x14629, 0x1462a, 0x1462b,
+    0x1462c, 0x1462d, 0x1462e, 0x1462f, 0x14630, 0x14631, 0x14632, 0x14633, 0x14634, 0x14635,
+    0x14636, 0x14637, 0x14638, 0x14639, 0x1463a, 0x1463b, 0x1463c, 0x1463d, 0x1463e, 0x1463f,
+    0x14640, 0x14641, 0x14642, 0x14643, 0x14644, 0x14645, 0x14646, 0x16800, 0x16801, 0x16802,
+    0x16803, 0x16804, 0x16805, 0x16806, 0x16807, 0x16808, 0x16809, 0x1680a, 0x1680b, 0x1680c,
+    0x1680d, 0x1680e, 0x1680f, 0x16810, 0x16811, 0x16812, 0x16813, 0x16814, 0x16815, 0x16816,
+    0x16817, 0x16818, 0x16819, 0x1681a, 0x1681b, 0x1681c, 0x1681d, 0x1681e, 0x1681f, 0x16820,
+    0x16821, 0x16822, 0x16823, 0x16824, 0x16825, 0x16826, 0x16827, 0x16828, 0x16829, 0x1682a,
+    0x1682b, 0x1682c, 0x1682d, 0x1682e, 0x1682f, 0x16830, 0x16831, 0x16832, 0x16833, 0x16834,
+    0x16835, 0x16836, 0x16837, 0x16838, 0x16839, 0x1683a, 0x1683b, 0x1683c, 0x1683d, 0x1683e,
+    0x1683f, 0x16840, 0x16841, 0x16842, 0x16843, 0x16844, 0x16845, 0x16846, 0x16847, 0x16848,
+    0x16849, 0x1684a, 0x1684b, 0x1684c, 0x1684d, 0x1684e, 0x1684f, 0x16850, 0x16851, 0x16852,
+    0x16853, 0x16854, 0x16855, 0x16856, 0x16857, 0x16858, 0x16859, 0x1685a, 0x1685b, 0x1685c,
+    0x1685d, 0x1685e, 0x1685f, 0x16860, 0x16861, 0x16862, 0x16863, 0x16864, 0x16865, 0x16866,
+    0x16867, 0x16868, 0x16869, 0x1686a, 0x1686b, 0x1686c, 0x1686d, 0x1686e, 0x1686f, 0x16870,
+    0x16871, 0x16872, 0x16873, 0x16874, 0x16875, 0x16876, 0x16877, 0x16878, 0x16879, 0x1687a,
+    0x1687b, 0x1687c, 0x1687d, 0x1687e, 0x1687f, 0x16880, 0x16881, 0x16882, 0x16883, 0x16884,
+    0x16885, 0x16886, 0x16887, 0x16888, 0x16889, 0x1688a, 0x1688b, 0x1688c, 0x1688d, 0x1688e,
+    0x1688f, 0x16890, 0x16891, 0x16892, 0x16893, 0x16894, 0x16895, 0x16896, 0x16897, 0x16898,
+    0x16899, 0x1689a, 0x1689b, 0x1689c, 0x1689d, 0x1689e, 0x1689f, 0x168a0, 0x168a1, 0x168a2,
+    0x168a3, 0x168a4, 0x168a5, 0x168a6, 0x168a7, 0x168a8, 0x168a9, 0x168aa, 0x168ab, 0x168ac,
+    0x168ad, 0x168ae, 0x168af, 0x168b0, 0x168b1, 0x168b2, 0x168b3, 0x168b4, 0x168b5, 0x168b6,
+    0x168b7, 0x168b8, 0x168b9, 0x168ba, 0x168bb, 0x168bc, 0x168bd, 0x168be, 0x168bf, 0x168c0,
+    0x168c1, 0x168c2, 0x168c3, 0x168c4, 0x168c5, 0x168c6, 0x168c7, 0x168c8, 0x168c9, 0x168ca,
+    0x168cb, 0x168cc, 0x168cd, 0x168ce, 0x168cf, 0x168d0, 0x168d1, 0x168d2, 0x168d3, 0x168d4,
+    0x168d5, 0x168d6, 0x168d7, 0x168d8, 0x168d9, 0x168da, 0x168db, 0x168dc, 0x168dd, 0x168de,
+    0x168df, 0x168e0, 0x168e1, 0x168e2, 0x168e3, 0x168e4, 0x168e5, 0x168e6, 0x168e7, 0x168e8,
+    0x168e9, 0x168ea, 0x168eb, 0x168ec, 0x168ed, 0x168ee, 0x168ef, 0x168f0, 0x168f1, 0x168f2,
+    0x168f3, 0x168f4, 0x168f5, 0x168f6, 0x168f7, 0x168f8, 0x168f9, 0x168fa, 0x168fb, 0x168fc,
+    0x168fd, 0x168fe, 0x168ff, 0x16900, 0x16901, 0x16902, 0x16903, 0x16904, 0x16905, 0x16906,
+    0x16907, 0x16908, 0x16909, 0x1690a, 0x1690b, 0x1690c, 0x1690d, 0x1690e, 0x1690f, 0x16910,
+    0x16911, 0x16912, 0x16913, 0x16914, 0x16915, 0x16916, 0x16917, 0x16918, 0x16919, 0x1691a,
+    0x1691b, 0x1691c, 0x1691d, 0x1691e, 0x1691f, 0x16920, 0x16921, 0x16922, 0x16923, 0x16924,
+    0x16925, 0x16926, 0x16927, 0x16928, 0x16929, 0x1692a, 0x1692b, 0x1692c, 0x1692d, 0x1692e,
+    0x1692f, 0x16930, 0x16931, 0x16932, 0x16933, 0x16934, 0x16935, 0x16936, 0x16937, 0x16938,
+    0x16939, 0x1693a, 0x1693b, 0x1693c, 0x1693d, 0x1693e, 0x1693f, 0x16940, 0x16941, 0x16942,
+    0x16943, 0x16944, 0x16945, 0x16946, 0x16947, 0x16948, 0x16949, 0x1694a, 0x1694b, 0x1694c,
+    0x1694d, 0x1694e, 0x1694f, 0x16950, 0x16951, 0x16952, 0x16953, 0x16954, 0x16955, 0x16956,
+    0x16957, 0x16958, 0x16959, 0x1695a, 0x1695b, 0x1695c, 0x1695d, 0x1695e, 0x1695f, 0x16960,
+    0x16961, 0x16962, 0x16963, 0x16964, 0x16965, 0x16966, 0x16967, 0x16968, 0x16969, 0x1696a,
+    0x1696b, 0x1696c, 0x1696d, 0x1696e, 0x1696f, 0x16970, 0x16971, 0x16972, 0x16973, 0x16974,
+    0x16975, 0x16976, 0x16977, 0x16978, 0x16979, 0x1697a, 0x1697b, 0x1697c, 0x1697d, 0x1697e,
+    0x1697f, 0x16980, 0x16981, 0x16982, 0x16983, 0x16984, 0x16985, 0x16986, 0x16987, 0x16988,
+    0x16989, 0x1698a, 0x1698b, 0x1698c, 0x1698d, 0x1698e, 0x1698f, 0x16990, 0x16991, 0x16992,
+    0x16993, 0x16994, 0x16995, 0x16996, 0x16997, 0x16998, 0x16999, 0x1699a, 0x1699b, 0x1699c,
+    0x1699d, 0x1699e, 0x1699f, 0x169a0, 0x169a1, 0x169a2, 0x169a3, 0x169a4, 0x169a5, 0x169a6,
+    0x169a7, 0x169a8, 0x169a9, 0x169aa, 0x169ab, 0x169ac, 0x169ad, 0x169ae, 0x169af, 0x169b0,
+    0x169b1, 0x169b2, 0x169b3, 0x169b4, 0x169b5, 0x169b6, 0x169b7, 0x169b8, 0x169b9, 0x169ba,
+    0x169bb, 0x169bc, 0x169bd, 0x169be, 0x169bf, 0x169c0, 0x169c1, 0x169c2, 0x169c3, 0x169c4,
+    0x169c5, 0x169c6, 0x169c7, 0x169c8, 0x169c9, 0x169ca, 0x169cb, 0x169cc, 0x169cd, 0x169ce,
+    0x169cf, 0x169d0, 0x169d1, 0x169d2, 0x169d3, 0x169d4, 0x169d5, 0x169d6, 0x169d7, 0x169d8,
+    0x169d9, 0x169da, 0x169db, 0x169dc, 0x169dd, 0x169de, 0x169df, 0x169e0, 0x169e1, 0x169e2,
+    0x169e3, 0x169e4, 0x169e5, 0x169e6, 0x169e7, 0x169e8, 0x169e9, 0x169ea, 0x169eb, 0x169ec,
+    0x169ed, 0x169ee, 0x169ef, 0x169f0, 0x169f1, 0x169f2, 0x169f3, 0x169f4, 0x169f5, 0x169f6,
+    0x169f7, 0x169f8, 0x169f9, 0x169fa, 0x169fb, 0x169fc, 0x169fd, 0x169fe, 0x169ff, 0x16a00,
+    0x16a01, 0x16a02, 0x16a03, 0x16a04, 0x16a05, 0x16a06, 0x16a07, 0x16a08, 0x16a09, 0x16a0a,
+    0x16a0b, 0x16a0c, 0x16a0d, 0x16a0e, 0x16a0f, 0x16a10, 0x16a11, 0x16a12, 0x16a13, 0x16a14,
+    0x16a15, 0x16a16, 0x16a17, 0x16a18, 0x16a19, 0x16a1a, 0x16a1b, 0x16a1c, 0x16a1d, 0x16a1e,
+    0x16a1f, 0x16a20, 0x16a21, 0x16a22, 0x16a23, 0x16a24, 0x16a25, 0x16a26, 0x16a27, 0x16a28,
+    0x16a29, 0x16a2a, 0x16a2b, 0x16a2c, 0x16a2d, 0x16a2e, 0x16a2f, 0x16a30, 0x16a31, 0x16a32,
+    0x16a33, 0x16a34, 0x16a35, 0x16a36, 0x16a37, 0x16a38, 0x16a40, 0x16a41, 0x16a42, 0x16a43,
+    0x16a44, 0x16a45, 0x16a46, 0x16a47, 0x16a48, 0x16a49, 0x16a4a, 0x16a4b, 0x16a4c, 0x16a4d,
+    0x16a4e, 0x16a4f, 0x16a50, 0x16a51, 0x16a52, 0x16a53, 0x16a54, 0x16a55, 0x16a56, 0x16a57,
+    0x16a58, 0x16a59, 0x16a5a, 0x16a5b, 0x16a5c, 0x16a5d, 0x16a5e, 0x16a60, 0x16a61, 0x16a62,
+    0x16a63, 0x16a64, 0x16a65, 0x16a66, 0x16a67, 0x16a68, 0x16a69, 0x16a6e, 0x16a6f, 0x16ad0,
+    0x16ad1, 0x16ad2, 0x16ad3, 0x16ad4, 0x16ad5, 0x16ad6, 0x16ad7, 0x16ad8, 0x16ad9, 0x16ada,
+    0x16adb, 0x16adc, 0x16add, 0x16ade, 0x16adf, 0x16ae0, 0x16ae1, 0x16ae2, 0x16ae3, 0x16ae4,
+    0x16ae5, 0x16ae6, 0x16ae7, 0x16ae8, 0x16ae9, 0x16aea, 0x16aeb, 0x16aec, 0x16aed, 0x16af0,
+    0x16af1, 0x16af2, 0x16af3, 0x16af4, 0x16af5, 0x16b00, 0x16b01, 0x16b02, 0x16b03, 0x16b04,
+    0x16b05, 0x16b06, 0x16b07, 0x16b08, 0x16b09, 0x16b0a, 0x16b0b, 0x16b0c, 0x16b0d, 0x16b0e,
+    0x16b0f, 0x16b10, 0x16b11, 0x16b12, 0x16b13, 0x16b14, 0x16b15, 0x16b16, 0x16b17, 0x16b18,
+    0x16b19, 0x16b1a, 0x16b1b, 0x16b1c, 0x16b1d, 0x16b1e, 0x16b1f, 0x16b20, 0x16b21, 0x16b22,
+    0x16b23, 0x16b24, 0x16b25, 0x16b26, 0x16b27, 0x16b28, 0x16b29, 0x16b2a, 0x16b2b, 0x16b2c,
+    0x16b2d, 0x16b2e, 0x16b2f, 0x16b30, 0x16b31, 0x16b32, 0x16b33, 0x16b34, 0x16b35, 0x16b36,
+    0x16b37, 0x16b38, 0x16b39, 0x16b3a, 0x16b3b, 0x16b3c, 0x16b3d, 0x16b3e, 0x16b3f, 0x16b40,
+    0x16b41, 0x16b42, 0x16b43, 0x16b44, 0x16b45, 0x16b50, 0x16b51, 0x16b52, 0x16b53, 0x16b54,
+    0x16b55, 0x16b56, 0x16b57, 0x16b58, 0x16b59, 0x16b5b, 0x16b5c, 0x16b5d, 0x16b5e, 0x16b5f,
+    0x16b60, 0x16b61, 0x16b63, 0x16b64, 0x16b65, 0x16b66, 0x16b67, 0x16b68, 0x16b69, 0x16b6a,
+    0x16b6b, 0x16b6c, 0x16b6d, 0x16b6e, 0x16b6f, 0x16b70, 0x16b71, 0x16b72, 0x16b73, 0x16b74,
+    0x16b75, 0x16b76, 0x16b77, 0x16b7d, 0x16b7e, 0x16b7f, 0x16b80, 0x16b81, 0x16b82, 0x16b83,
+    0x16b84, 0x16b85, 0x16b86, 0x16b87, 0x16b88, 0x16b89, 0x16b8a, 0x16b8b, 0x16b8c, 0x16b8d,
+    0x16b8e, 0x16b8f, 0x16e40, 0x16e41, 0x16e42, 0x16e43, 0x16e44, 0x16e45, 0x16e46, 0x16e47,
+    0x16e48, 0x16e49, 0x16e4a, 0x16e4b, 0x16e4c, 0x16e4d, 0x16e4e, 0x16e4f, 0x16e50, 0x16e51,
+    0x16e52, 0x16e53, 0x16e54, 0x16e55, 0x16e56, 0x16e57, 0x16e58, 0x16e59, 0x16e5a, 0x16e5b,
+    0x16e5c, 0x16e5d, 0x16e5e, 0x16e5f, 0x16e60, 0x16e61, 0x16e62, 0x16e63, 0x16e64, 0x16e65,
+    0x16e66, 0x16e67, 0x16e68, 0x16e69, 0x16e6a, 0x16e6b, 0x16e6c, 0x16e6d, 0x16e6e, 0x16e6f,
+    0x16e70, 0x16e71, 0x16e72, 0x16e73, 0x16e74, 0x16e75, 0x16e76, 0x16e77, 0x16e78, 0x16e79,
+    0x16e7a, 0x16e7b, 0x16e7c, 0x16e7d, 0x16e7e, 0x16e7f, 0x16e80, 0x16e81, 0x16e82, 0x16e83,
+    0x16e84, 0x16e85, 0x16e86, 0x16e87, 0x16e88, 0x16e89, 0x16e8a, 0x16e8b, 0x16e8c, 0x16e8d,
+    0x16e8e, 0x16e8f, 0x16e90, 0x16e91, 0x16e92, 0x16e93, 0x16e94, 0x16e95, 0x16e96, 0x16e97,
+    0x16e98, 0x16e99, 0x16e9a, 0x16f00, 0x16f01, 0x16f02, 0x16f03, 0x16f04, 0x16f05, 0x16f06,
+    0x16f07, 0x16f08, 0x16f09, 0x16f0a, 0x16f0b, 0x16f0c, 0x16f0d, 0x16f0e, 0x16f0f, 0x16f10,
+    0x16f11, 0x16f12, 0x16f13, 0x16f14, 0x16f15, 0x16f16, 0x16f17, 0x16f18, 0x16f19, 0x16f1a,
+    0x16f1b, 0x16f1c, 0x16f1d, 0x16f1e, 0x16f1f, 0x16f20, 0x16f21, 0x16f22, 0x16f23, 0x16f24,
+    0x16f25, 0x16f26, 0x16f27, 0x16f28, 0x16f29, 0x16f2a, 0x16f2b, 0x16f2c, 0x16f2d, 0x16f2e,
+    0x16f2f, 0x16f30, 0x16f31, 0x16f32, 0x16f33, 0x16f34, 0x16f35, 0x16f36, 0x16f37, 0x16f38,
+    0x16f39, 0x16f3a, 0x16f3b, 0x16f3c, 0x16f3d, 0x16f3e, 0x16f3f, 0x16f40, 0x16f41, 0x16f42,
+    0x16f43, 0x16f44, 0x16f45, 0x16f46, 0x16f47, 0x16f48, 0x16f49, 0x16f4a, 0x16f4f, 0x16f50,
+    0x16f51, 0x16f52, 0x16f53, 0x16f54, 0x16f55, 0x16f56, 0x16f57, 0x16f58, 0x16f59, 0x16f5a,
+    0x16f5b, 0x16f5c, 0x16f5d, 0x16f5e, 0x16f5f, 0x16f60, 0x16f61, 0x16f62, 0x16f63, 0x16f64,
+    0x16f65, 0x16f66, 0x16f67, 0x16f68, 0x16f69, 0x16f6a, 0x16f6b, 0x16f6c, 0x16f6d, 0x16f6e,
+    0x16f6f, 0x16f70, 0x16f71, 0x16f72, 0x16f73, 0x16f74, 0x16f75, 0x16f76, 0x16f77, 0x16f78,
+    0x16f79, 0x16f7a, 0x16f7b, 0x16f7c, 0x16f7d, 0x16f7e, 0x16f7f, 0x16f80, 0x16f81, 0x16f82,
+    0x16f83, 0x16f84, 0x16f85, 0x16f86, 0x16f87, 0x16f8f, 0x16f90, 0x16f91, 0x16f92, 0x16f93,
+    0x16f94, 0x16f95, 0x16f96, 0x16f97, 0x16f98, 0x16f99, 0x16f9a, 0x16f9b, 0x16f9c, 0x16f9d,
+    0x16f9e, 0x16f9f, 0x16fe0, 0x16fe1, 0x16fe2, 0x16fe3, 0x16fe4, 0x16ff0, 0x16ff1, 0x1b000,
+    0x1b001, 0x1b002, 0x1b003, 0x1b004, 0x1b005, 0x1b006, 0x1b007, 0x1b008, 0x1b009, 0x1b00a,
+    0x1b00b, 0x1b00c, 0x1b00d, 0x1b00e, 0x1b00f, 0x1b010, 0x1b011, 0x1b012, 0x1b013, 0x1b014,
+    0x1b015, 0x1b016, 0x1b017, 0x1b018, 0x1b019, 0x1b01a, 0x1b01b, 0x1b01c, 0x1b01d, 0x1b01e,
+    0x1b01f, 0x1b020, 0x1b021, 0x1b022, 0x1b023, 0x1b024, 0x1b025, 0x1b026, 0x1b027, 0x1b028,
+    0x1b029, 0x1b02a, 0x1b02b, 0x1b02c, 0x1b02d, 0x1b02e, 0x1b02f, 0x1b030, 0x1b031, 0x1b032,
+    0x1b033, 0x1b034, 0x1b035, 0x1b036, 0x1b037, 0x1b038, 0x1b039, 0x1b03a, 0x1b03b, 0x1b03c,
+    0x1b03d, 0x1b03e, 0x1b03f, 0x1b040, 0x1b041, 0x1b042, 0x1b043, 0x1b044, 0x1b045, 0x1b046,
+    0x1b047, 0x1b048, 0x1b049, 0x1b04a, 0x1b04b, 0x1b04c, 0x1b04d, 0x1b04e, 0x1b04f, 0x1b050,
+    0x1b051, 0x1b052, 0x1b053, 0x1b054, 0x1b055, 0x1b056, 0x1b057, 0x1b058, 0x1b059, 0x1b05a,
+    0x1b05b, 0x1b05c, 0x1b05d, 0x1b05e, 0x1b05f, 0x1b060, 0x1b061, 0x1b062, 0x1b063, 0x1b064,
+    0x1b065, 0x1b066, 0x1b067, 0x1b068, 0x1b069, 0x1b06a, 0x1b06b, 0x1b06c, 0x1b06d, 0x1b06e,
+    0x1b06f, 0x1b070, 0x1b071, 0x1b072, 0x1b073, 0x1b074, 0x1b075, 0x1b076, 0x1b077, 0x1b078,
+    0x1b079, 0x1b07a, 0x1b07b, 0x1b07c, 0x1b07d, 0x1b07e, 0x1b07f, 0x1b080, 0x1b081, 0x1b082,
+    0x1b083, 0x1b084, 0x1b085, 0x1b086, 0x1b087, 0x1b088, 0x1b089, 0x1b08a, 0x1b08b, 0x1b08c,
+    0x1b08d, 0x1b08e, 0x1b08f, 0x1b090, 0x1b091, 0x1b092, 0x1b093, 0x1b094, 0x1b095, 0x1b096,
+    0x1b097, 0x1b098, 0x1b099, 0x1b09a, 0x1b09b, 0x1b09c, 0x1b09d, 0x1b09e, 0x1b09f, 0x1b0a0,
+    0x1b0a1, 0x1b0a2, 0x1b0a3, 0x1b0a4, 0x1b0a5, 0x1b0a6, 0x1b0a7, 0x1b0a8, 0x1b0a9, 0x1b0aa,
+    0x1b0ab, 0x1b0ac, 0x1b0ad, 0x1b0ae, 0x1b0af, 0x1b0b0, 0x1b0b1, 0x1b0b2, 0x1b0b3, 0x1b0b4,
+    0x1b0b5, 0x1b0b6, 0x1b0b7, 0x1b0b8, 0x1b0b9, 0x1b0ba, 0x1b0bb, 0x1b0bc, 0x1b0bd, 0x1b0be,
+    0x1b0bf, 0x1b0c0, 0x1b0c1, 0x1b0c2, 0x1b0c3, 0x1b0c4, 0x1b0c5, 0x1b0c6, 0x1b0c7, 0x1b0c8,
+    0x1b0c9, 0x1b0ca, 0x1b0cb, 0x1b0cc, 0x1b0cd, 0x1b0ce, 0x1b0cf, 0x1b0d0, 0x1b0d1, 0x1b0d2,
+    0x1b0d3, 0x1b0d4, 0x1b0d5, 0x1b0d6, 0x1b0d7, 0x1b0d8, 0x1b0d9, 0x1b0da, 0x1b0db, 0x1b0dc,
+    0x1b0dd, 0x1b0de, 0x1b0df, 0x1b0e0, 0x1b0e1, 0x1b0e2, 0x1b0e3, 0x1b0e4, 0x1b0e5, 0x1b0e6,
+    0x1b0e7, 0x1b0e8, 0x1b0e9, 0x1b0ea, 0x1b0eb, 0x1b0ec, 0x1b0ed, 0x1b0ee, 0x1b0ef, 0x1b0f0,
+    0x1b0f1, 0x1b0f2, 0x1b0f3, 0x1b0f4, 0x1b0f5, 0x1b0f6, 0x1b0f7, 0x1b0f8, 0x1b0f9, 0x1b0fa,
+    0x1b0fb, 0x1b0fc, 0x1b0fd, 0x1b0fe, 0x1b0ff, 0x1b100, 0x1b101, 0x1b102, 0x1b103, 0x1b104,
+    0x1b105, 0x1b106, 0x1b107, 0x1b108, 0x1b109, 0x1b10a, 0x1b10b, 0x1b10c, 0x1b10d, 0x1b10e,
+    0x1b10f, 0x1b110, 0x1b111, 0x1b112, 0x1b113, 0x1b114, 0x1b115, 0x1b116, 0x1b117, 0x1b118,
+    0x1b119, 0x1b11a, 0x1b11b, 0x1b11c, 0x1b11d, 0x1b11e, 0x1b150, 0x1b151, 0x1b152, 0x1b164,
+    0x1b165, 0x1b166, 0x1b167, 0x1bc00, 0x1bc01, 0x1bc02, 0x1bc03, 0x1bc04, 0x1bc05, 0x1bc06,
+    0x1bc07, 0x1bc08, 0x1bc09, 0x1bc0a, 0x1bc0b, 0x1bc0c, 0x1bc0d, 0x1bc0e, 0x1bc0f, 0x1bc10,
+    0x1bc11, 0x1bc12, 0x1bc13, 0x1bc14, 0x1bc15, 0x1bc16, 0x1bc17, 0x1bc18, 0x1bc19, 0x1bc1a,
+    0x1bc1b, 0x1bc1c, 0x1bc1d, 0x1bc1e, 0x1bc1f, 0x1bc20, 0x1bc21, 0x1bc22, 0x1bc23, 0x1bc24,
+    0x1bc25, 0x1bc26, 0x1bc27, 0x1bc28, 0x1bc29, 0x1bc2a, 0x1bc2b, 0x1bc2c, 0x1bc2d, 0x1bc2e,
+    0x1bc2f, 0x1bc30, 0x1bc31, 0x1bc32, 0x1bc33, 0x1bc34, 0x1bc35, 0x1bc36, 0x1bc37, 0x1bc38,
+    0x1bc39, 0x1bc3a, 0x1bc3b, 0x1bc3c, 0x1bc3d, 0x1bc3e, 0x1bc3f, 0x1bc40, 0x1bc41, 0x1bc42,
+    0x1bc43, 0x1bc44, 0x1bc45, 0x1bc46, 0x1bc47, 0x1bc48, 0x1bc49, 0x1bc4a, 0x1bc4b, 0x1bc4c,
+    0x1bc4d, 0x1bc4e, 0x1bc4f, 0x1bc50, 0x1bc51, 0x1bc52, 0x1bc53, 0x1bc54, 0x1bc55, 0x1bc56,
+    0x1bc57, 0x1bc58, 0x1bc59, 0x1bc5a, 0x1bc5b, 0x1bc5c, 0x1bc5d, 0x1bc5e, 0x1bc5f, 0x1bc60,
+    0x1bc61, 0x1bc62, 0x1bc63, 0x1bc64, 0x1bc65, 0x1bc66, 0x1bc67, 0x1bc68, 0x1bc69, 0x1bc6a,
+    0x1bc70, 0x1bc71, 0x1bc72, 0x1bc73, 0x1bc74, 0x1bc75, 0x1bc76, 0x1bc77, 0x1bc78, 0x1bc79,
+    0x1bc7a, 0x1bc7b, 0x1bc7c, 0x1bc80, 0x1bc81, 0x1bc82, 0x1bc83, 0x1bc84, 0x1bc85, 0x1bc86,
+    0x1bc87, 0x1bc88, 0x1bc90, 0x1bc91, 0x1bc92, 0x1bc93, 0x1bc94, 0x1bc95, 0x1bc96, 0x1bc97,
+    0x1bc98, 0x1bc99, 0x1bc9c, 0x1bc9d, 0x1bc9e, 0x1bc9f, 0x1bca0, 0x1bca1, 0x1bca2, 0x1bca3,
+    0x1d000, 0x1d001, 0x1d002, 0x1d003, 0x1d004, 0x1d005, 0x1d006, 0x1d007, 0x1d008, 0x1d009,
+    0x1d00a, 0x1d00b, 0x1d00c, 0x1d00d, 0x1d00e, 0x1d00f, 0x1d010, 0x1d011, 0x1d012, 0x1d013,
+    0x1d014, 0x1d015, 0x1d016, 0x1d017, 0x1d018, 0x1d019, 0x1d01a, 0x1d01b, 0x1d01c, 0x1d01d,
+    0x1d01e, 0x1d01f, 0x1d020, 0x1d021, 0x1d022, 0x1d023, 0x1d024, 0x1d025, 0x1d026, 0x1d027,
+    0x1d028, 0x1d029, 0x1d02a, 0x1d02b, 0x1d02c, 0x1d02d, 0x1d02e, 0x1d02f, 0x1d030, 0x1d031,
+    0x1d032, 0x1d033, 0x1d034, 0x1d035, 0x1d036, 0x1d037, 0x1d038, 0x1d039, 0x1d03a, 0x1d03b,
+    0x1d03c, 0x1d03d, 0x1d03e, 0x1d03f, 0x1d040, 0x1d041, 0x1d042, 0x1d043, 0x1d044, 0x1d045,
+    0x1d046, 0x1d047, 0x1d048, 0x1d049, 0x1d04a, 0x1d04b, 0x1d04c, 0x1d04d, 0x1d04e, 0x1d04f,
+    0x1d050, 0x1d051, 0x1d052, 0x1d053, 0x1d054, 0x1d055, 0x1d056, 0x1d057, 0x1d058, 0x1d059,
+    0x1d05a, 0x1d05b, 0x1d05c, 0x1d05d, 0x1d05e, 0x1d05f, 0x1d060, 0x1d061, 0x1d062, 0x1d063,
+    0x1d064, 0x1d065, 0x1d066, 0x1d067, 0x1d068, 0x1d069, 0x1d06a, 0x1d06b, 0x1d06c, 0x1d06d,
+    0x1d06e, 0x1d06f, 0x1d070, 0x1d071, 0x1d072, 0x1d073, 0x1d074, 0x1d075, 0x1d076, 0x1d077,
+    0x1d078, 0x1d079, 0x1d07a, 0x1d07b, 0x1d07c, 0x1d07d, 0x1d07e, 0x1d07f, 0x1d080, 0x1d081,
+    0x1d082, 0x1d083, 0x1d084, 0x1d085, 0x1d086, 0x1d087, 0x1d088, 0x1d089, 0x1d08a, 0x1d08b,
+    0x1d08c, 0x1d08d, 0x1d08e, 0x1d08f, 0x1d090, 0x1d091, 0x1d092, 0x1d093, 0x1d094, 0x1d095,
+    0x1d096, 0x1d097, 0x1d098, 0x1d099, 0x1d09a, 0x1d09b, 0x1d09c, 0x1d09d, 0x1d09e, 0x1d09f,
+    0x1d0a0, 0x1d0a1, 0x1d0a2, 0x1d0a3, 0x1d0a4, 0x1d0a5, 0x1d0a6, 0x1d0a7, 0x1d0a8, 0x1d0a9,
+    0x1d0aa, 0x1d0ab, 0x1d0ac, 0x1d0ad, 0x1d0ae, 0x1d0af, 0x1d0b0, 0x1d0b1, 0x1d0b2, 0x1d0b3,
+    0x1d0b4, 0x1d0b5, 0x1d0b6, 0x1d0b7, 0x1d0b8, 0x1d0b9, 0x1d0ba, 0x1d0bb, 0x1d0bc, 0x1d0bd,
+    0x1d0be, 0x1d0bf, 0x1d0c0, 0x1d0c1, 0x1d0c2, 0x1d0c3, 0x1d0c4, 0x1d0c5, 0x1d0c6, 0x1d0c7,
+    0x1d0c8, 0x1d0c9, 0x1d0ca, 0x1d0cb, 0x1d0cc, 0x1d0cd, 0x1d0ce, 0x1d0cf, 0x1d0d0, 0x1d0d1,
+    0x1d0d2, 0x1d0d3, 0x1d0d4, 0x1d0d5, 0x1d0d6, 0x1d0d7, 0x1d0d8, 0x1d0d9, 0x1d0da, 0x1d0db,
+    0x1d0dc, 0x1d0dd, 0x1d0de, 0x1d0df, 0x1d0e0, 0x1d0e1, 0x1d0e2, 0x1d0e3, 0x1d0e4, 0x1d0e5,
+    0x1d0e6, 0x1d0e7, 0x1d0e8, 0x1d0e9, 0x1d0ea, 0x1d0eb, 0x1d0ec, 0x1d0ed, 0x1d0ee, 0x1d0ef,
+    0x1d0f0, 0x1d0f1, 0x1d0f2, 0x1d0f3, 0x1d0f4, 0x1d0f5, 0x1d100, 0x1d101, 0x1d102, 0x1d103,
+    0x1d104, 0x1d105, 0x1d106, 0x1d107, 0x1d108, 0x1d109, 0x1d10a, 0x1d10b, 0x1d10c, 0x1d10d,
+    0x1d10e, 0x1d10f, 0x1d110, 0x1d111, 0x1d112, 0x1d113, 0x1d114, 0x1d115, 0x1d116, 0x1d117,
+    0x1d118, 0x1d119, 0x1d11a, 0x1d11b, 0x1d11c, 0x1d11d, 0x1d11e, 0x1d11f, 0x1d120, 0x1d121,
+    0x1d122, 0x1d123, 0x1d124, 0x1d125, 0x1d126, 0x1d129, 0x1d12a, 0x1d12b, 0x1d12c, 0x1d12d,
+    0x1d12e, 0x1d12f, 0x1d130, 0x1d131, 0x1d132, 0x1d133, 0x1d134, 0x1d135, 0x1d136, 0x1d137,
+    0x1d138, 0x1d139, 0x1d13a, 0x1d13b, 0x1d13c, 0x1d13d, 0x1d13e, 0x1d13f, 0x1d140, 0x1d141,
+    0x1d142, 0x1d143, 0x1d144, 0x1d145, 0x1d146, 0x1d147, 0x1d148, 0x1d149, 0x1d14a, 0x1d14b,
+    0x1d14c, 0x1d14d, 0x1d14e, 0x1d14f, 0x1d150, 0x1d151, 0x1d152, 0x1d153, 0x1d154, 0x1d155,
+    0x1d156, 0x1d157, 0x1d158, 0x1d159, 0x1d15a, 0x1d15b, 0x1d15c, 0x1d15d, 0x1d15e, 0x1d15f,
+    0x1d160, 0x1d161, 0x1d162, 0x1d163, 0x1d164, 0x1d165, 0x1d166, 0x1d167, 0x1d168, 0x1d169,
+    0x1d16a, 0x1d16b, 0x1d16c, 0x1d16d, 0x1d16e, 0x1d16f, 0x1d170, 0x1d171, 0x1d172, 0x1d173,
+    0x1d174, 0x1d175, 0x1d176, 0x1d177, 0x1d178, 0x1d179, 0x1d17a, 0x1d17b, 0x1d17c, 0x1d17d,
+    0x1d17e, 0x1d17f, 0x1d180, 0x1d181, 0x1d182, 0x1d183, 0x1d184, 0x1d185, 0x1d186, 0x1d187,
+    0x1d188, 0x1d189, 0x1d18a, 0x1d18b, 0x1d18c, 0x1d18d, 0x1d18e, 0x1d18f, 0x1d190, 0x1d191,
+    0x1d192, 0x1d193, 0x1d194, 0x1d195, 0x1d196, 0x1d197, 0x1d198, 0x1d199, 0x1d19a, 0x1d19b,
+    0x1d19c, 0x1d19d, 0x1d19e, 0x1d19f, 0x1d1a0, 0x1d1a1, 0x1d1a2, 0x1d1a3, 0x1d1a4, 0x1d1a5,
+    0x1d1a6, 0x1d1a7, 0x1d1a8, 0x1d1a9, 0x1d1aa, 0x1d1ab, 0x1d1ac, 0x1d1ad, 0x1d1ae, 0x1d1af,
+    0x1d1b0, 0x1d1b1, 0x1d1b2, 0x1d1b3, 0x1d1b4, 0x1d1b5, 0x1d1b6, 0x1d1b7, 0x1d1b8, 0x1d1b9,
+    0x1d1ba, 0x1d1bb, 0x1d1bc, 0x1d1bd, 0x1d1be, 0x1d1bf, 0x1d1c0, 0x1d1c1, 0x1d1c2, 0x1d1c3,
+    0x1d1c4, 0x1d1c5, 0x1d1c6, 0x1d1c7, 0x1d1c8, 0x1d1c9, 0x1d1ca, 0x1d1cb, 0x1d1cc, 0x1d1cd,
+    0x1d1ce, 0x1d1cf, 0x1d1d0, 0x1d1d1, 0x1d1d2, 0x1d1d3, 0x1d1d4, 0x1d1d5, 0x1d1d6, 0x1d1d7,
+    0x1d1d8, 0x1d1d9, 0x1d1da, 0x1d1db, 0x1d1dc, 0x1d1dd, 0x1d1de, 0x1d1df, 0x1d1e0, 0x1d1e1,
+    0x1d1e2, 0x1d1e3, 0x1d1e4, 0x1d1e5, 0x1d1e6, 0x1d1e7, 0x1d1e8, 0x1d200, 0x1d201, 0x1d202,
+    0x1d203, 0x1d204, 0x1d205, 0x1d206, 0x1d207, 0x1d208, 0x1d209, 0x1d20a, 0x1d20b, 0x1d20c,
+    0x1d20d, 0x1d20e, 0x1d20f, 0x1d210, 0x1d211, 0x1d212, 0x1d213, 0x1d214, 0x1d215, 0x1d216,
+    0x1d217, 0x1d218, 0x1d219, 0x1d21a, 0x1d21b, 0x1d21c, 0x1d21d, 0x1d21e, 0x1d21f, 0x1d220,
+    0x1d221, 0x1d222, 0x1d223, 0x1d224, 0x1d225, 0x1d226, 0x1d227, 0x1d228, 0x1d229, 0x1d22a,
+    0x1d22b, 0x1d22c, 0x1d22d, 0x1d22e, 0x1d22f, 0x1d230, 0x1d231, 0x1d232, 0x1d233, 0x1d234,
+    0x1d235, 0x1d236, 0x1d237, 0x1d238, 0x1d239, 0x1d23a, 0x1d23b, 0x1d23c, 0x1d23d, 0x1d23e,
+    0x1d23f, 0x1d240, 0x1d241, 0x1d242, 0x1d243, 0x1d244, 0x1d245, 0x1d2e0, 0x1d2e1, 0x1d2e2,
+    0x1d2e3, 0x1d2e4, 0x1d2e5, 0x1d2e6, 0x1d2e7, 0x1d2e8, 0x1d2e9, 0x1d2ea, 0x1d2eb, 0x1d2ec,
+    0x1d2ed, 0x1d2ee, 0x1d2ef, 0x1d2f0, 0x1d2f1, 0x1d2f2, 0x1d2f3, 0x1d300, 0x1d301, 0x1d302,
+    0x1d303, 0x1d304, 0x1d305, 0x1d306, 0x1d307, 0x1d308, 0x1d309, 0x1d30a, 0x1d30b, 0x1d30c,
+    0x1d30d, 0x1d30e, 0x1d30f, 0x1d310, 0x1d311, 0x1d312, 0x1d313, 0x1d314, 0x1d315, 0x1d316,
+    0x1d317, 0x1d318, 0x1d319, 0x1d31a, 0x1d31b, 0x1d31c, 0x1d31d, 0x1d31e, 0x1d31f, 0x1d320,
+    0x1d321, 0x1d322, 0x1d323, 0x1d324, 0x1d325, 0x1d326, 0x1d327, 0x1d328, 0x1d329, 0x1d32a,
+    0x1d32b, 0x1d32c, 0x1d32d, 0x1d32e, 0x1d32f, 0x1d330, 0x1d331, 0x1d332, 0x1d333, 0x1d334,
+    0x1d335, 0x1d336, 0x1d337, 0x1d338, 0x1d339, 0x1d33a, 0x1d33b, 0x1d33c, 0x1d33d, 0x1d33e,
+    0x1d33f, 0x1d340, 0x1d341, 0x1d342, 0x1d343, 0x1d344, 0x1d345, 0x1d346, 0x1d347, 0x1d348,
+    0x1d349, 0x1d34a, 0x1d34b, 0x1d34c, 0x1d34d, 0x1d34e, 0x1d34f, 0x1d350, 0x1d351, 0x1d352,
+    0x1d353, 0x1d354, 0x1d355, 0x1d356, 0x1d360, 0x1d361, 0x1d362, 0x1d363, 0x1d364, 0x1d365,
+    0x1d366, 0x1d367, 0x1d368, 0x1d369, 0x1d36a, 0x1d36b, 0x1d36c, 0x1d36d, 0x1d36e, 0x1d36f,
+    0x1d370, 0x1d371, 0x1d372, 0x1d373, 0x1d374, 0x1d375, 0x1d376, 0x1d377, 0x1d378, 0x1d400,
+    0x1d401, 0x1d402, 0x1d403, 0x1d404, 0x1d405, 0x1d406, 0x1d407, 0x1d408, 0x1d409, 0x1d40a,
+    0x1d40b, 0x1d40c, 0x1d40d, 0x1d40e, 0x1d40f, 0x1d410, 0x1d411, 0x1d412, 0x1d413, 0x1d414,
+    0x1d415, 0x1d416, 0x1d417, 0x1d418, 0x1d419, 0x1d41a, 0x1d41b, 0x1d41c, 0x1d41d, 0x1d41e,
+    0x1d41f, 0x1d420, 0x1d421, 0x1d422, 0x1d423, 0x1d424, 0x1d425, 0x1d426, 0x1d427, 0x1d428,
+    0x1d429, 0x1d42a, 0x1d42b, 0x1d42c, 0x1d42d, 0x1d42e, 0x1d42f, 0x1d430, 0x1d431, 0x1d432,
+    0x1d433, 0x1d434, 0x1d435, 0x1d436, 0x1d437, 0x1d438, 0x1d439, 0x1d43a, 0x1d43b, 0x1d43c,
+    0x1d43d, 0x1d43e, 0x1d43f, 0x1d440, 0x1d441, 0x1d442, 0x1d443, 0x1d444, 0x1d445, 0x1d446,
+    0x1d447, 0x1d448, 0x1d449, 0x1d44a, 0x1d44b, 0x1d44c, 0x1d44d, 0x1d44e, 0x1d44f, 0x1d450,
+    0x1d451, 0x1d452, 0x1d453, 0x1d454, 0x1d456, 0x1d457, 0x1d458, 0x1d459, 0x1d45a, 0x1d45b,
+    0x1d45c, 0x1d45d, 0x1d45e, 0x1d45f, 0x1d460, 0x1d461, 0x1d462, 0x1d463, 0x1d464, 0x1d465,
+    0x1d466, 0x1d467, 0x1d468, 0x1d469, 0x1d46a, 0x1d46b, 0x1d46c, 0x1d46d, 0x1d46e, 0x1d46f,
+    0x1d470, 0x1d471, 0x1d472, 0x1d473, 0x1d474, 0x1d475, 0x1d476, 0x1d477, 0x1d478, 0x1d479,
+    0x1d47a, 0x1d47b, 0x1d47c, 0x1d47d, 0x1d47e, 0x1d47f, 0x1d480, 0x1d481, 0x1d482, 0x1d483,
+    0x1d484, 0x1d485, 0x1d486, 0x1d487, 0x1d488, 0x1d489, 0x1d48a, 0x1d48b, 0x1d48c, 0x1d48d,
+    0x1d48e, 0x1d48f, 0x1d490, 0x1d491, 0x1d492, 0x1d493, 0x1d494, 0x1d495, 0x1d496, 0x1d497,
+    0x1d498, 0x1d499, 0x1d49a, 0x1d49b, 0x1d49c, 0x1d49e, 0x1d49f, 0x1d4a2, 0x1d4a5, 0x1d4a6,
+    0x1d4a9, 0x1d4aa, 0x1d4ab, 0x1d4ac, 0x1d4ae, 0x1d4af, 0x1d4b0, 0x1d4b1, 0x1d4b2, 0x1d4b3,
+    0x1d4b4, 0x1d4b5, 0x1d4b6, 0x1d4b7, 0x1d4b8, 0x1d4b9, 0x1d4bb, 0x1d4bd, 0x1d4be, 0x1d4bf,
+    0x1d4c0, 0x1d4c1, 0x1d4c2, 0x1d4c3, 0x1d4c5, 0x1d4c6, 0x1d4c7, 0x1d4c8, 0x1d4c9, 0x1d4ca,
+    0x1d4cb, 0x1d4cc, 0x1d4cd, 0x1d4ce, 0x1d4cf, 0x1d4d0, 0x1d4d1, 0x1d4d2, 0x1d4d3, 0x1d4d4,
+    0x1d4d5, 0x1d4d6, 0x1d4d7, 0x1d4d8, 0x1d4d9, 0x1d4da, 0x1d4db, 0x1d4dc, 0x1d4dd, 0x1d4de,
+    0x1d4df, 0x1d4e0, 0x1d4e1, 0x1d4e2, 0x1d4e3, 0x1d4e4, 0x1d4e5, 0x1d4e6, 0x1d4e7, 0x1d4e8,
+    0x1d4e9, 0x1d4ea, 0x1d4eb, 0x1d4ec, 0x1d4ed, 0x1d4ee, 0x1d4ef, 0x1d4f0, 0x1d4f1, 0x1d4f2,
+    0x1d4f3, 0x1d4f4, 0x1d4f5, 0x1d4f6, 0x1d4f7, 0x1d4f8, 0x1d4f9, 0x1d4fa, 0x1d4fb, 0x1d4fc,
+    0x1d4fd, 0x1d4fe, 0x1d4ff, 0x1d500, 0x1d501, 0x1d502, 0x1d503, 0x1d504, 0x1d505, 0x1d507,
+    0x1d508, 0x1d509, 0x1d50a, 0x1d50d, 0x1d50e, 0x1d50f, 0x1d510, 0x1d511, 0x1d512, 0x1d513,
+    0x1d514, 0x1d516, 0x1d517, 0x1d518, 0x1d519, 0x1d51a, 0x1d51b, 0x1d51c, 0x1d51e, 0x1d51f,
+    0x1d520, 0x1d521, 0x1d522, 0x1d523, 0x1d524, 0x1d525, 0x1d526, 0x1d527, 0x1d528, 0x1d529,
+    0x1d52a, 0x1d52b, 0x1d52c, 0x1d52d, 0x1d52e, 0x1d52f, 0x1d530, 0x1d531, 0x1d532, 0x1d533,
+    0x1d534, 0x1d535, 0x1d536, 0x1d537, 0x1d538, 0x1d539, 0x1d53b, 0x1d53c, 0x1d53d, 0x1d53e,
+    0x1d540, 0x1d541, 0x1d542, 0x1d543, 0x1d544, 0x1d546, 0x1d54a, 0x1d54b, 0x1d54c, 0x1d54d,
+    0x1d54e, 0x1d54f, 0x1d550, 0x1d552, 0x1d553, 0x1d554, 0x1d555, 0x1d556, 0x1d557, 0x1d558,
+    0x1d559, 0x1d55a, 0x1d55b, 0x1d55c, 0x1d55d, 0x1d55e, 0x1d55f, 0x1d560, 0x1d561, 0x1d562,
+    0x1d563, 0x1d564, 0x1d565, 0x1d566, 0x1d567, 0x1d568, 0x1d569, 0x1d56a, 0x1d56b, 0x1d56c,
+    0x1d56d, 0x1d56e, 0x1d56f, 0x1d570, 0x1d571, 0x1d572, 0x1d573, 0x1d574, 0x1d575, 0x1d576,
+    0x1d577, 0x1d578, 0x1d579, 0x1d57a, 0x1d57b, 0x1d57c, 0x1d57d, 0x1d57e, 0x1d57f, 0x1d580,
+    0x1d581, 0x1d582, 0x1d583, 0x1d584, 0x1d585, 0x1d586, 0x1d587, 0x1d588, 0x1d589, 0x1d58a,
+    0x1d58b, 0x1d58c, 0x1d58d, 0x1d58e, 0x1d58f, 0x1d590, 0x1d591, 0x1d592, 0x1d593, 0x1d594,
+    0x1d595, 0x1d596, 0x1d597, 0x1d598, 0x1d599, 0x1d59a, 0x1d59b, 0x1d59c, 0x1d59d, 0x1d59e,
+    0x1d59f, 0x1d5a0, 0x1d5a1, 0x1d5a2, 0x1d5a3, 0x1d5a4, 0x1d5a5, 0x1d5a6, 0x1d5a7, 0x1d5a8,
+    0x1d5a9, 0x1d5aa, 0x1d5ab, 0x1d5ac, 0x1d5ad, 0x1d5ae, 0x1d5af, 0x1d5b0, 0x1d5b1, 0x1d5b2,
+    0x1d5b3, 0x1d5b4, 0x1d5b5, 0x1d5b6, 0x1d5b7, 0x1d5b8, 0x1d5b9, 0x1d5ba, 0x1d5bb, 0x1d5bc,
+    0x1d5bd, 0x1d5be, 0x1d5bf, 0x1d5c0, 0x1d5c1, 0x1d5c2, 0x1d5c3, 0x1d5c4, 0x1d5c5, 0x1d5c6,
+    0x1d5c7, 0x1d5c8, 0x1d5c9, 0x1d5ca, 0x1d5cb, 0x1d5cc, 0x1d5cd, 0x1d5ce, 0x1d5cf, 0x1d5d0,
+    0x1d5d1, 0x1d5d2, 0x1d5d3, 0x1d5d4, 0x1d5d5, 0x1d5d6, 0x1d5d7, 0x1d5d8, 0x1d5d9, 0x1d5da,
+    0x1d5db, 0x1d5dc, 0x1d5dd, 0x1d5de, 0x1d5df, 0x1d5e0, 0x1d5e1, 0x1d5e2, 0x1d5e3, 0x1d5e4,
+    0x1d5e5, 0x1d5e6, 0x1d5e7, 0x1d5e8, 0x1d5e9, 0x1d5ea, 0x1d5eb, 0x1d5ec, 0x1d5ed, 0x1d5ee,
+    0x1d5ef, 0x1d5f0, 0x1d5f1, 0x1d5f2, 0x1d5f3, 0x1d5f4, 0x1d5f5, 0x1d5f6, 0x1d5f7, 0x1d5f8,
+    0x1d5f9, 0x1d5fa, 0x1d5fb, 0x1d5fc, 0x1d5fd, 0x1d5fe, 0x1d5ff, 0x1d600, 0x1d601, 0x1d602,
+    0x1d603, 0x1d604, 0x1d605, 0x1d606, 0x1d607, 0x1d608, 0x1d609, 0x1d60a, 0x1d60b, 0x1d60c,
+    0x1d60d, 0x1d60e, 0x1d60f, 0x1d610, 0x1d611, 0x1d612, 0x1d613, 0x1d614, 0x1d615, 0x1d616,
+    0x1d617, 0x1d618, 0x1d619, 0x1d61a, 0x1d61b, 0x1d61c, 0x1d61d, 0x1d61e, 0x1d61f, 0x1d620,
+    0x1d621, 0x1d622, 0x1d623, 0x1d624, 0x1d625, 0x1d626, 0x1d627, 0x1d628, 0x1d629, 0x1d62a,
+    0x1d62b, 0x1d62c, 0x1d62d, 0x1d62e, 0x1d62f, 0x1d630, 0x1d631, 0x1d632, 0x1d633, 0x1d634,
+    0x1d635, 0x1d636, 0x1d637, 0x1d638, 0x1d639, 0x1d63a, 0x1d63b, 0x1d63c, 0x1d63d, 0x1d63e,
+    0x1d63f, 0x1d640, 0x1d641, 0x1d642, 0x1d643, 0x1d644, 0x1d645, 0x1d646, 0x1d647, 0x1d648,
+    0x1d649, 0x1d64a, 0x1d64b, 0x1d64c, 0x1d64d, 0x1d64e, 0x1d64f, 0x1d650, 0x1d651, 0x1d652,
+    0x1d653, 0x1d654, 0x1d655, 0x1d656, 0x1d657, 0x1d658, 0x1d659, 0x1d65a, 0x1d65b, 0x1d65c,
+    0x1d65d, 0x1d65e, 0x1d65f, 0x1d660, 0x1d661, 0x1d662, 0x1d663, 0x1d664, 0x1d665, 0x1d666,
+    0x1d667, 0x1d668, 0x1d669, 0x1d66a, 0x1d66b, 0x1d66c, 0x1d66d, 0x1d66e, 0x1d66f, 0x1d670,
+    0x1d671, 0x1d672, 0x1d673, 0x1d674, 0x1d675, 0x1d676, 0x1d677, 0x1d678, 0x1d679, 0x1d67a,
+    0x1d67b, 0x1d67c, 0x1d67d, 0x1d67e, 0x1d67f, 0x1d680, 0x1d681, 0x1d682, 0x1d683, 0x1d684,
+    0x1d685, 0x1d686, 0x1d687, 0x1d688, 0x1d689, 0x1d68a, 0x1d68b, 0x1d68c, 0x1d68d, 0x1d68e,
+    0x1d68f, 0x1d690, 0x1d691, 0x1d692, 0x1d693, 0x1d694, 0x1d695, 0x1d696, 0x1d697, 0x1d698,
+    0x1d699, 0x1d69a, 0x1d69b, 0x1d69c, 0x1d69d, 0x1d69e, 0x1d69f, 0x1d6a0, 0x1d6a1, 0x1d6a2,
+    0x1d6a3, 0x1d6a4, 0x1d6a5, 0x1d6a8, 0x1d6a9, 0x1d6aa, 0x1d6ab, 0x1d6ac, 0x1d6ad, 0x1d6ae,
+    0x1d6af, 0x1d6b0, 0x1d6b1, 0x1d6b2, 0x1d6b3, 0x1d6b4, 0x1d6b5, 0x1d6b6, 0x1d6b7, 0x1d6b8,
+    0x1d6b9, 0x1d6ba, 0x1d6bb, 0x1d6bc, 0x1d6bd, 0x1d6be, 0x1d6bf, 0x1d6c0, 0x1d6c1, 0x1d6c2,
+    0x1d6c3, 0x1d6c4, 0x1d6c5, 0x1d6c6, 0x1d6c7, 0x1d6c8, 0x1d6c9, 0x1d6ca, 0x1d6cb, 0x1d6cc,
+    0x1d6cd, 0x1d6ce, 0x1d6cf, 0x1d6d0, 0x1d6d1, 0x1d6d2, 0x1d6d3, 0x1d6d4, 0x1d6d5, 0x1d6d6,
+    0x1d6d7, 0x1d6d8, 0x1d6d9, 0x1d6da, 0x1d6db, 0x1d6dc, 0x1d6dd, 0x1d6de, 0x1d6df, 0x1d6e0,
+    0x1d6e1, 0x1d6e2, 0x1d6e3, 0x1d6e4, 0x1d6e5, 0x1d6e6, 0x1d6e7, 0x1d6e8, 0x1d6e9, 0x1d6ea,
+    0x1d6eb, 0x1d6ec, 0x1d6ed, 0x1d6ee, 0x1d6ef, 0x1d6f0, 0x1d6f1, 0x1d6f2, 0x1d6f3, 0x1d6f4,
+    0x1d6f5, 0x1d6f6, 0x1d6f7, 0x1d6f8, 0x1d6f9, 0x1d6fa, 0x1d6fb, 0x1d6fc, 0x1d6fd, 0x1d6fe,
+    0x1d6ff, 0x1d700, 0x1d701, 0x1d702, 0x1d703, 0x1d704, 0x1d705, 0x1d706, 0x1d707, 0x1d708,
+    0x1d709, 0x1d70a, 0x1d70b, 0x1d70c, 0x1d70d, 0x1d70e, 0x1d70f, 0x1d710, 0x1d711, 0x1d712,
+    0x1d713, 0x1d714, 0x1d715, 0x1d716, 0x1d717, 0x1d718, 0x1d719, 0x1d71a, 0x1d71b, 0x1d71c,
+    0x1d71d, 0x1d71e, 0x1d71f, 0x1d720, 0x1d721, 0x1d722, 0x1d723, 0x1d724, 0x1d725, 0x1d726,
+    0x1d727, 0x1d728, 0x1d729, 0x1d72a, 0x1d72b, 0x1d72c, 0x1d72d, 0x1d72e, 0x1d72f, 0x1d730,
+    0x1d731, 0x1d732, 0x1d733, 0x1d734, 0x1d735, 0x1d736, 0x1d737, 0x1d738, 0x1d739, 0x1d73a,
+    0x1d73b, 0x1d73c, 0x1d73d, 0x1d73e, 0x1d73f, 0x1d740, 0x1d741, 0x1d742, 0x1d743, 0x1d744,
+    0x1d745, 0x1d746, 0x1d747, 0x1d748, 0x1d749, 0x1d74a, 0x1d74b, 0x1d74c, 0x1d74d, 0x1d74e,
+    0x1d74f, 0x1d750, 0x1d751, 0x1d752, 0x1d753, 0x1d754, 0x1d755, 0x1d756, 0x1d757, 0x1d758,
+    0x1d759, 0x1d75a, 0x1d75b, 0x1d75c, 0x1d75d, 0x1d75e, 0x1d75f, 0x1d760, 0x1d761, 0x1d762,
+    0x1d763, 0x1d764, 0x1d765, 0x1d766, 0x1d767, 0x1d768, 0x1d769, 0x1d76a, 0x1d76b, 0x1d76c,
+    0x1d76d, 0x1d76e, 0x1d76f, 0x1d770, 0x1d771, 0x1d772, 0x1d773, 0x1d774, 0x1d775, 0x1d776,
+    0x1d777, 0x1d778, 0x1d779, 0x1d77a, 0x1d77b, 0x1d77c, 0x1d77d, 0x1d77e, 0x1d77f, 0x1d780,
+    0x1d781, 0x1d782, 0x1d783, 0x1d784, 0x1d785, 0x1d786, 0x1d787, 0x1d788, 0x1d789, 0x1d78a,
+    0x1d78b, 0x1d78c, 0x1d78d, 0x1d78e, 0x1d78f, 0x1d790, 0x1d791, 0x1d792, 0x1d793, 0x1d794,
+    0x1d795, 0x1d796, 0x1d797, 0x1d798, 0x1d799, 0x1d79a, 0x1d79b, 0x1d79c, 0x1d79d, 0x1d79e,
+    0x1d79f, 0x1d7a0, 0x1d7a1, 0x1d7a2, 0x1d7a3, 0x1d7a4, 0x1d7a5, 0x1d7a6, 0x1d7a7, 0x1d7a8,
+    0x1d7a9, 0x1d7aa, 0x1d7ab, 0x1d7ac, 0x1d7ad, 0x1d7ae, 0x1d7af, 0x1d7b0, 0x1d7b1, 0x1d7b2,
+    0x1d7b3, 0x1d7b4, 0x1d7b5, 0x1d7b6, 0x1d7b7, 0x1d7b8, 0x1d7b9, 0x1d7ba, 0x1d7bb, 0x1d7bc,
+    0x1d7bd, 0x1d7be, 0x1d7bf, 0x1d7c0, 0x1d7c1, 0x1d7c2, 0x1d7c3, 0x1d7c4, 0x1d7c5, 0x1d7c6,
+    0x1d7c7, 0x1d7c8, 0x1d7c9, 0x1d7ca, 0x1d7cb, 0x1d7ce, 0x1d7cf, 0x1d7d0, 0x1d7d1, 0x1d7d2,
+    0x1d7d3, 0x1d7d4, 0x1d7d5, 0x1d7d6, 0x1d7d7, 0x1d7d8, 0x1d7d9, 0x1d7da, 0x1d7db, 0x1d7dc,
+    0x1d7dd, 0x1d7de, 0x1d7df, 0x1d7e0, 0x1d7e1, 0x1d7e2, 0x1d7e3, 0x1d7e4, 0x1d7e5, 0x1d7e6,
+    0x1d7e7, 0x1d7e8, 0x1d7e9, 0x1d7ea, 0x1d7eb, 0x1d7ec, 0x1d7ed, 0x1d7ee, 0x1d7ef, 0x1d7f0,
+    0x1d7f1, 0x1d7f2, 0x1d7f3, 0x1d7f4, 0x1d7f5, 0x1d7f6, 0x1d7f7, 0x1d7f8, 0x1d7f9, 0x1d7fa,
+    0x1d7fb, 0x1d7fc, 0x1d7fd, 0x1d7fe, 0x1d7ff, 0x1d800, 0x1d801, 0x1d802, 0x1d803, 0x1d804,
+    0x1d805, 0x1d806, 0x1d807, 0x1d808, 0x1d809, 0x1d80a, 0x1d80b, 0x1d80c, 0x1d80d, 0x1d80e,
+    0x1d80f, 0x1d810, 0x1d811, 0x1d812, 0x1d813, 0x1d814, 0x1d815, 0x1d816, 0x1d817, 0x1d818,
+    0x1d819, 0x1d81a, 0x1d81b, 0x1d81c, 0x1d81d, 0x1d81e, 0x1d81f, 0x1d820, 0x1d821, 0x1d822,
+    0x1d823, 0x1d824, 0x1d825, 0x1d826, 0x1d827, 0x1d828, 0x1d829, 0x1d82a, 0x1d82b, 0x1d82c,
+    0x1d82d, 0x1d82e, 0x1d82f, 0x1d830, 0x1d831, 0x1d832, 0x1d833, 0x1d834, 0x1d835, 0x1d836,
+    0x1d837, 0x1d838, 0x1d839, 0x1d83a, 0x1d83b, 0x1d83c, 0x1d83d, 0x1d83e, 0x1d83f, 0x1d840,
+    0x1d841, 0x1d842, 0x1d843, 0x1d844, 0x1d845, 0x1d846, 0x1d847, 0x1d848, 0x1d849, 0x1d84a,
+    0x1d84b, 0x1d84c, 0x1d84d, 0x1d84e, 0x1d84f, 0x1d850, 0x1d851, 0x1d852, 0x1d853, 0x1d854,
+    0x1d855, 0x1d856, 0x1d857, 0x1d858, 0x1d859, 0x1d85a, 0x1d85b, 0x1d85c, 0x1d85d, 0x1d85e,
+    0x1d85f, 0x1d860, 0x1d861, 0x1d862, 0x1d863, 0x1d864, 0x1d865, 0x1d866, 0x1d867, 0x1d868,
+    0x1d869, 0x1d86a, 0x1d86b, 0x1d86c, 0x1d86d, 0x1d86e, 0x1d86f, 0x1d870, 0x1d871, 0x1d872,
+    0x1d873, 0x1d874, 0x1d875, 0x1d876, 0x1d877, 0x1d878, 0x1d879, 0x1d87a, 0x1d87b, 0x1d87c,
+    0x1d87d, 0x1d87e, 0x1d87f, 0x1d880, 0x1d881, 0x1d882, 0x1d883, 0x1d884, 0x1d885, 0x1d886,
+    0x1d887, 0x1d888, 0x1d889, 0x1d88a, 0x1d88b, 0x1d88c, 0x1d88d, 0x1d88e, 0x1d88f, 0x1d890,
+    0x1d891, 0x1d892, 0x1d893, 0x1d894, 0x1d895, 0x1d896, 0x1d897, 0x1d898, 0x1d899, 0x1d89a,
+    0x1d89b, 0x1d89c, 0x1d89d, 0x1d89e, 0x1d89f, 0x1d8a0, 0x1d8a1, 0x1d8a2, 0x1d8a3, 0x1d8a4,
+    0x1d8a5, 0x1d8a6, 0x1d8a7, 0x1d8a8, 0x1d8a9, 0x1d8aa, 0x1d8ab, 0x1d8ac, 0x1d8ad, 0x1d8ae,
+    0x1d8af, 0x1d8b0, 0x1d8b1, 0x1d8b2, 0x1d8b3, 0x1d8b4, 0x1d8b5, 0x1d8b6, 0x1d8b7, 0x1d8b8,
+    0x1d8b9, 0x1d8ba, 0x1d8bb, 0x1d8bc, 0x1d8bd, 0x1d8be, 0x1d8bf, 0x1d8c0, 0x1d8c1, 0x1d8c2,
+    0x1d8c3, 0x1d8c4, 0x1d8c5, 0x1d8c6, 0x1d8c7, 0x1d8c8, 0x1d8c9, 0x1d8ca, 0x1d8cb, 0x1d8cc,
+    0x1d8cd, 0x1d8ce, 0x1d8cf, 0x1d8d0, 0x1d8d1, 0x1d8d2, 0x1d8d3, 0x1d8d4, 0x1d8d5, 0x1d8d6,
+    0x1d8d7, 0x1d8d8, 0x1d8d9, 0x1d8da, 0x1d8db, 0x1d8dc, 0x1d8dd, 0x1d8de, 0x1d8df, 0x1d8e0,
+    0x1d8e1, 0x1d8e2, 0x1d8e3, 0x1d8e4, 0x1d8e5, 0x1d8e6, 0x1d8e7, 0x1d8e8, 0x1d8e9, 0x1d8ea,
+    0x1d8eb, 0x1d8ec, 0x1d8ed, 0x1d8ee, 0x1d8ef, 0x1d8f0, 0x1d8f1, 0x1d8f2, 0x1d8f3, 0x1d8f4,
+    0x1d8f5, 0x1d8f6, 0x1d8f7, 0x1d8f8, 0x1d8f9, 0x1d8fa, 0x1d8fb, 0x1d8fc, 0x1d8fd, 0x1d8fe,
+    0x1d8ff, 0x1d900, 0x1d901, 0x1d902, 0x1d903, 0x1d904, 0x1d905, 0x1d906, 0x1d907, 0x1d908,
+    0x1d909, 0x1d90a, 0x1d90b, 0x1d90c, 0x1d90d, 0x1d90e, 0x1d90f, 0x1d910, 0x1d911, 0x1d912,
+    0x1d913, 0x1d914, 0x1d915, 0x1d916, 0x1d917, 0x1d918, 0x1d919, 0x1d91a, 0x1d91b, 0x1d91c,
+    0x1d91d, 0x1d91e, 0x1d91f, 0x1d920, 0x1d921, 0x1d922, 0x1d923, 0x1d924, 0x1d925, 0x1d926,
+    0x1d927, 0x1d928, 0x1d929, 0x1d92a, 0x1d92b, 0x1d92c, 0x1d92d, 0x1d92e, 0x1d92f, 0x1d930,
+    0x1d931, 0x1d932, 0x1d933, 0x1d934, 0x1d935, 0x1d936, 0x1d937, 0x1d938, 0x1d939, 0x1d93a,
+    0x1d93b, 0x1d93c, 0x1d93d, 0x1d93e, 0x1d93f, 0x1d940, 0x1d941, 0x1d942, 0x1d943, 0x1d944,
+    0x1d945, 0x1d946, 0x1d947, 0x1d948, 0x1d949, 0x1d94a, 0x1d94b, 0x1d94c, 0x1d94d, 0x1d94e,
+    0x1d94f, 0x1d950, 0x1d951, 0x1d952, 0x1d953, 0x1d954, 0x1d955, 0x1d956, 0x1d957, 0x1d958,
+    0x1d959, 0x1d95a, 0x1d95b, 0x1d95c, 0x1d95d, 0x1d95e, 0x1d95f, 0x1d960, 0x1d961, 0x1d962,
+    0x1d963, 0x1d964, 0x1d965, 0x1d966, 0x1d967, 0x1d968, 0x1d969, 0x1d96a, 0x1d96b, 0x1d96c,
+    0x1d96d, 0x1d96e, 0x1d96f, 0x1d970, 0x1d971, 0x1d972, 0x1d973, 0x1d974, 0x1d975, 0x1d976,
+    0x1d977, 0x1d978, 0x1d979, 0x1d97a, 0x1d97b, 0x1d97c, 0x1d97d, 0x1d97e, 0x1d97f, 0x1d980,
+    0x1d981, 0x1d982, 0x1d983, 0x1d984, 0x1d985, 0x1d986, 0x1d987, 0x1d988, 0x1d989, 0x1d98a,
+    0x1d98b, 0x1d98c, 0x1d98d, 0x1d98e, 0x1d98f, 0x1d990, 0x1d991, 0x1d992, 0x1d993, 0x1d994,
+    0x1d995, 0x1d996, 0x1d997, 0x1d998, 0x1d999, 0x1d99a, 0x1d99b, 0x1d99c, 0x1d99d, 0x1d99e,
+    0x1d99f, 0x1d9a0, 0x1d9a1, 0x1d9a2, 0x1d9a3, 0x1d9a4, 0x1d9a5, 0x1d9a6, 0x1d9a7, 0x1d9a8,
+    0x1d9a9, 0x1d9aa, 0x1d9ab, 0x1d9ac, 0x1d9ad, 0x1d9ae, 0x1d9af, 0x1d9b0, 0x1d9b1, 0x1d9b2,
+    0x1d9b3, 0x1d9b4, 0x1d9b5, 0x1d9b6, 0x1d9b7, 0x1d9b8, 0x1d9b9, 0x1d9ba, 0x1d9bb, 0x1d9bc,
+    0x1d9bd, 0x1d9be, 0x1d9bf, 0x1d9c0, 0x1d9c1, 0x1d9c2, 0x1d9c3, 0x1d9c4, 0x1d9c5, 0x1d9c6,
+    0x1d9c7, 0x1d9c8, 0x1d9c9, 0x1d9ca, 0x1d9cb, 0x1d9cc, 0x1d9cd, 0x1d9ce, 0x1d9cf, 0x1d9d0,
+    0x1d9d1, 0x1d9d2, 0x1d9d3, 0x1d9d4, 0x1d9d5, 0x1d9d6, 0x1d9d7, 0x1d9d8, 0x1d9d9, 0x1d9da,
+    0x1d9db, 0x1d9dc, 0x1d9dd, 0x1d9de, 0x1d9df, 0x1d9e0, 0x1d9e1, 0x1d9e2, 0x1d9e3, 0x1d9e4,
+    0x1d9e5, 0x1d9e6, 0x1d9e7, 0x1d9e8, 0x1d9e9, 0x1d9ea, 0x1d9eb, 0x1d9ec, 0x1d9ed, 0x1d9ee,
+    0x1d9ef, 0x1d9f0, 0x1d9f1, 0x1d9f2, 0x1d9f3, 0x1d9f4, 0x1d9f5, 0x1d9f6, 0x1d9f7, 0x1d9f8,
+    0x1d9f9, 0x1d9fa, 0x1d9fb, 0x1d9fc, 0x1d9fd, 0x1d9fe, 0x1d9ff, 0x1da00, 0x1da01, 0x1da02,
+    0x1da03, 0x1da04, 0x1da05, 0x1da06, 0x1da07, 0x1da08, 0x1da09, 0x1da0a, 0x1da0b, 0x1da0c,
+    0x1da0d, 0x1da0e, 0x1da0f, 0x1da10, 0x1da11, 0x1da12, 0x1da13, 0x1da14, 0x1da15, 0x1da16,
+    0x1da17, 0x1da18, 0x1da19, 0x1da1a, 0x1da1b, 0x1da1c, 0x1da1d, 0x1da1e, 0x1da1f, 0x1da20,
+    0x1da21, 0x1da22, 0x1da23, 0x1da24, 0x1da25, 0x1da26, 0x1da27, 0x1da28, 0x1da29, 0x1da2a,
+    0x1da2b, 0x1da2c, 0x1da2d, 0x1da2e, 0x1da2f, 0x1da30, 0x1da31, 0x1da32, 0x1da33, 0x1da34,
+    0x1da35, 0x1da36, 0x1da37, 0x1da38, 0x1da39, 0x1da3a, 0x1da3b, 0x1da3c, 0x1da3d, 0x1da3e,
+    0x1da3f, 0x1da40, 0x1da41, 0x1da42, 0x1da43, 0x1da44, 0x1da45, 0x1da46, 0x1da47, 0x1da48,
+    0x1da49, 0x1da4a, 0x1da4b, 0x1da4c, 0x1da4d, 0x1da4e, 0x1da4f, 0x1da50, 0x1da51, 0x1da52,
+    0x1da53, 0x1da54, 0x1da55, 0x1da56, 0x1da57, 0x1da58, 0x1da59, 0x1da5a, 0x1da5b, 0x1da5c,
+    0x1da5d, 0x1da5e, 0x1da5f, 0x1da60, 0x1da61, 0x1da62, 0x1da63, 0x1da64, 0x1da65, 0x1da66,
+    0x1da67, 0x1da68, 0x1da69, 0x1da6a, 0x1da6b, 0x1da6c, 0x1da6d, 0x1da6e, 0x1da6f, 0x1da70,
+    0x1da71, 0x1da72, 0x1da73, 0x1da74, 0x1da75, 0x1da76, 0x1da77, 0x1da78, 0x1da79, 0x1da7a,
+    0x1da7b, 0x1da7c, 0x1da7d, 0x1da7e, 0x1da7f, 0x1da80, 0x1da81, 0x1da82, 0x1da83, 0x1da84,
+    0x1da85, 0x1da86, 0x1da87, 0x1da88, 0x1da89, 0x1da8a, 0x1da8b, 0x1da9b, 0x1da9c, 0x1da9d,
+    0x1da9e, 0x1da9f, 0x1daa1, 0x1daa2, 0x1daa3, 0x1daa4, 0x1daa5, 0x1daa6, 0x1daa7, 0x1daa8,
+    0x1daa9, 0x1daaa, 0x1daab, 0x1daac, 0x1daad, 0x1daae, 0x1daaf, 0x1e000, 0x1e001, 0x1e002,
+    0x1e003, 0x1e004, 0x1e005, 0x1e006, 0x1e008, 0x1e009, 0x1e00a, 0x1e00b, 0x1e00c, 0x1e00d,
+    0x1e00e, 0x1e00f, 0x1e010, 0x1e011, 0x1e012, 0x1e013, 0x1e014, 0x1e015, 0x1e016, 0x1e017,
+    0x1e018, 0x1e01b, 0x1e01c, 0x1e01d, 0x1e01e, 0x1e01f, 0x1e020, 0x1e021, 0x1e023, 0x1e024,
+    0x1e026, 0x1e027, 0x1e028, 0x1e029, 0x1e02a, 0x1e100, 0x1e101, 0x1e102, 0x1e103, 0x1e104,
+    0x1e105, 0x1e106, 0x1e107, 0x1e108, 0x1e109, 0x1e10a, 0x1e10b, 0x1e10c, 0x1e10d, 0x1e10e,
+    0x1e10f, 0x1e110, 0x1e111, 0x1e112, 0x1e113, 0x1e114, 0x1e115, 0x1e116, 0x1e117, 0x1e118,
+    0x1e119, 0x1e11a, 0x1e11b, 0x1e11c, 0x1e11d, 0x1e11e, 0x1e11f, 0x1e120, 0x1e121, 0x1e122,
+    0x1e123, 0x1e124, 0x1e125, 0x1e126, 0x1e127, 0x1e128, 0x1e129, 0x1e12a, 0x1e12b, 0x1e12c,
+    0x1e130, 0x1e131, 0x1e132, 0x1e133, 0x1e134, 0x1e135, 0x1e136, 0x1e137, 0x1e138, 0x1e139,
+    0x1e13a, 0x1e13b, 0x1e13c, 0x1e13d, 0x1e140, 0x1e141, 0x1e142, 0x1e143, 0x1e144, 0x1e145,
+    0x1e146, 0x1e147, 0x1e148, 0x1e149, 0x1e14e, 0x1e14f, 0x1e2c0, 0x1e2c1, 0x1e2c2, 0x1e2c3,
+    0x1e2c4, 0x1e2c5, 0x1e2c6, 0x1e2c7, 0x1e2c8, 0x1e2c9, 0x1e2ca, 0x1e2cb, 0x1e2cc, 0x1e2cd,
+    0x1e2ce, 0x1e2cf, 0x1e2d0, 0x1e2d1, 0x1e2d2, 0x1e2d3, 0x1e2d4, 0x1e2d5, 0x1e2d6, 0x1e2d7,
+    0x1e2d8, 0x1e2d9, 0x1e2da, 0x1e2db, 0x1e2dc, 0x1e2dd, 0x1e2de, 0x1e2df, 0x1e2e0, 0x1e2e1,
+    0x1e2e2, 0x1e2e3, 0x1e2e4, 0x1e2e5, 0x1e2e6, 0x1e2e7, 0x1e2e8, 0x1e2e9, 0x1e2ea, 0x1e2eb,
+    0x1e2ec, 0x1e2ed, 0x1e2ee, 0x1e2ef, 0x1e2f0, 0x1e2f1, 0x1e2f2, 0x1e2f3, 0x1e2f4, 0x1e2f5,
+    0x1e2f6, 0x1e2f7, 0x1e2f8, 0x1e2f9, 0x1e2ff, 0x1e800, 0x1e801, 0x1e802, 0x1e803, 0x1e804,
+    0x1e805, 0x1e806, 0x1e807, 0x1e808, 0x1e809, 0x1e80a, 0x1e80b, 0x1e80c, 0x1e80d, 0x1e80e,
+    0x1e80f, 0x1e810, 0x1e811, 0x1e812, 0x1e813, 0x1e814, 0x1e815, 0x1e816, 0x1e817, 0x1e818,
+    0x1e819, 0x1e81a, 0x1e81b, 0x1e81c, 0x1e81d, 0x1e81e, 0x1e81f, 0x1e820, 0x1e821, 0x1e822,
+    0x1e823, 0x1e824, 0x1e825, 0x1e826, 0x1e827, 0x1e828, 0x1e829, 0x1e82a, 0x1e82b, 0x1e82c,
+    0x1e82d, 0x1e82e, 0x1e82f, 0x1e830, 0x1e831, 0x1e832, 0x1e833, 0x1e834, 0x1e835, 0x1e836,
+    0x1e837, 0x1e838, 0x1e839, 0x1e83a, 0x1e83b, 0x1e83c, 0x1e83d, 0x1e83e, 0x1e83f, 0x1e840,
+    0x1e841, 0x1e842, 0x1e843, 0x1e844, 0x1e845, 0x1e846, 0x1e847, 0x1e848, 0x1e849, 0x1e84a,
+    0x1e84b, 0x1e84c, 0x1e84d, 0x1e84e, 0x1e84f, 0x1e850, 0x1e851, 0x1e852, 0x1e853, 0x1e854,
+    0x1e855, 0x1e856, 0x1e857, 0x1e858, 0x1e859, 0x1e85a, 0x1e85b, 0x1e85c, 0x1e85d, 0x1e85e,
+    0x1e85f, 0x1e860, 0x1e861, 0x1e862, 0x1e863, 0x1e864, 0x1e865, 0x1e866, 0x1e867, 0x1e868,
+    0x1e869, 0x1e86a, 0x1e86b, 0x1e86c, 0x1e86d, 0x1e86e, 0x1e86f, 0x1e870, 0x1e871, 0x1e872,
+    0x1e873, 0x1e874, 0x1e875, 0x1e876, 0x1e877, 0x1e878, 0x1e879, 0x1e87a, 0x1e87b, 0x1e87c,
+    0x1e87d, 0x1e87e, 0x1e87f, 0x1e880, 0x1e881, 0x1e882, 0x1e883, 0x1e884, 0x1e885, 0x1e886,
+    0x1e887, 0x1e888, 0x1e889, 0x1e88a, 0x1e88b, 0x1e88c, 0x1e88d, 0x1e88e, 0x1e88f, 0x1e890,
+    0x1e891, 0x1e892, 0x1e893, 0x1e894, 0x1e895, 0x1e896, 0x1e897, 0x1e898, 0x1e899, 0x1e89a,
+    0x1e89b, 0x1e89c, 0x1e89d, 0x1e89e, 0x1e89f, 0x1e8a0, 0x1e8a1, 0x1e8a2, 0x1e8a3, 0x1e8a4,
+    0x1e8a5, 0x1e8a6, 0x1e8a7, 0x1e8a8, 0x1e8a9, 0x1e8aa, 0x1e8ab, 0x1e8ac, 0x1e8ad, 0x1e8ae,
+    0x1e8af, 0x1e8b0, 0x1e8b1, 0x1e8b2, 0x1e8b3, 0x1e8b4, 0x1e8b5, 0x1e8b6, 0x1e8b7, 0x1e8b8,
+    0x1e8b9, 0x1e8ba, 0x1e8bb, 0x1e8bc, 0x1e8bd, 0x1e8be, 0x1e8bf, 0x1e8c0, 0x1e8c1, 0x1e8c2,
+    0x1e8c3, 0x1e8c4, 0x1e8c7, 0x1e8c8, 0x1e8c9, 0x1e8ca, 0x1e8cb, 0x1e8cc, 0x1e8cd, 0x1e8ce,
+    0x1e8cf, 0x1e8d0, 0x1e8d1, 0x1e8d2, 0x1e8d3, 0x1e8d4, 0x1e8d5, 0x1e8d6, 0x1e900, 0x1e901,
+    0x1e902, 0x1e903, 0x1e904, 0x1e905, 0x1e906, 0x1e907, 0x1e908, 0x1e909, 0x1e90a, 0x1e90b,
+    0x1e90c, 0x1e90d, 0x1e90e, 0x1e90f, 0x1e910, 0x1e911, 0x1e912, 0x1e913, 0x1e914, 0x1e915,
+    0x1e916, 0x1e917, 0x1e918, 0x1e919, 0x1e91a, 0x1e91b, 0x1e91c, 0x1e91d, 0x1e91e, 0x1e91f,
+    0x1e920, 0x1e921, 0x1e922, 0x1e923, 0x1e924, 0x1e925, 0x1e926, 0x1e927, 0x1e928, 0x1e929,
+    0x1e92a, 0x1e92b, 0x1e92c, 0x1e92d, 0x1e92e, 0x1e92f, 0x1e930, 0x1e931, 0x1e932, 0x1e933,
+    0x1e934, 0x1e935, 0x1e936, 0x1e937, 0x1e938, 0x1e939, 0x1e93a, 0x1e93b, 0x1e93c, 0x1e93d,
+    0x1e93e, 0x1e93f, 0x1e940, 0x1e941, 0x1e942, 0x1e943, 0x1e944, 0x1e945, 0x1e946, 0x1e947,
+    0x1e948, 0x1e949, 0x1e94a, 0x1e94b, 0x1e950, 0x1e951, 0x1e952, 0x1e953, 0x1e954, 0x1e955,
+    0x1e956, 0x1e957, 0x1e958, 0x1e959, 0x1e95e, 0x1e95f, 0x1ec71, 0x1ec72, 0x1ec73, 0x1ec74,
+    0x1ec75, 0x1ec76, 0x1ec77, 0x1ec78, 0x1ec79, 0x1ec7a, 0x1ec7b, 0x1ec7c, 0x1ec7d, 0x1ec7e,
+    0x1ec7f, 0x1ec80, 0x1ec81, 0x1ec82, 0x1ec83, 0x1ec84, 0x1ec85, 0x1ec86, 0x1ec87, 0x1ec88,
+    0x1ec89, 0x1ec8a, 0x1ec8b, 0x1ec8c, 0x1ec8d, 0x1ec8e, 0x1ec8f, 0x1ec90, 0x1ec91, 0x1ec92,
+    0x1ec93, 0x1ec94, 0x1ec95, 0x1ec96, 0x1ec97, 0x1ec98, 0x1ec99, 0x1ec9a, 0x1ec9b, 0x1ec9c,
+    0x1ec9d, 0x1ec9e, 0x1ec9f, 0x1eca0, 0x1eca1, 0x1eca2, 0x1eca3, 0x1eca4, 0x1eca5, 0x1eca6,
+    0x1eca7, 0x1eca8, 0x1eca9, 0x1ecaa, 0x1ecab, 0x1ecac, 0x1ecad, 0x1ecae, 0x1ecaf, 0x1ecb0,
+    0x1ecb1, 0x1ecb2, 0x1ecb3, 0x1ecb4, 0x1ed01, 0x1ed02, 0x1ed03, 0x1ed04, 0x1ed05, 0x1ed06,
+    0x1ed07, 0x1ed08, 0x1ed09, 0x1ed0a, 0x1ed0b, 0x1ed0c, 0x1ed0d, 0x1ed0e, 0x1ed0f, 0x1ed10,
+    0x1ed11, 0x1ed12, 0x1ed13, 0x1ed14, 0x1ed15, 0x1ed16, 0x1ed17, 0x1ed18, 0x1ed19, 0x1ed1a,
+    0x1ed1b, 0x1ed1c, 0x1ed1d, 0x1ed1e, 0x1ed1f, 0x1ed20, 0x1ed21, 0x1ed22, 0x1ed23, 0x1ed24,
+    0x1ed25, 0x1ed26, 0x1ed27, 0x1ed28, 0x1ed29, 0x1ed2a, 0x1ed2b, 0x1ed2c, 0x1ed2d, 0x1ed2e,
+    0x1ed2f, 0x1ed30, 0x1ed31, 0x1ed32, 0x1ed33, 0x1ed34, 0x1ed35, 0x1ed36, 0x1ed37, 0x1ed38,
+    0x1ed39, 0x1ed3a, 0x1ed3b, 0x1ed3c, 0x1ed3d, 0x1ee00, 0x1ee01, 0x1ee02, 0x1ee03, 0x1ee05,
+    0x1ee06, 0x1ee07, 0x1ee08, 0x1ee09, 0x1ee0a, 0x1ee0b, 0x1ee0c, 0x1ee0d, 0x1ee0e, 0x1ee0f,
+    0x1ee10, 0x1ee11, 0x1ee12, 0x1ee13, 0x1ee14, 0x1ee15, 0x1ee16, 0x1ee17, 0x1ee18, 0x1ee19,
+    0x1ee1a, 0x1ee1b, 0x1ee1c, 0x1ee1d, 0x1ee1e, 0x1ee1f, 0x1ee21, 0x1ee22, 0x1ee24, 0x1ee27,
+    0x1ee29, 0x1ee2a, 0x1ee2b, 0x1ee2c, 0x1ee2d, 0x1ee2e, 0x1ee2f, 0x1ee30, 0x1ee31, 0x1ee32,
+    0x1ee34, 0x1ee35, 0x1ee36, 0x1ee37, 0x1ee39, 0x1ee3b, 0x1ee42, 0x1ee47, 0x1ee49, 0x1ee4b,
+    0x1ee4d, 0x1ee4e, 0x1ee4f, 0x1ee51, 0x1ee52, 0x1ee54, 0x1ee57, 0x1ee59, 0x1ee5b, 0x1ee5d,
+    0x1ee5f, 0x1ee61, 0x1ee62, 0x1ee64, 0x1ee67, 0x1ee68, 0x1ee69, 0x1ee6a, 0x1ee6c, 0x1ee6d,
+    0x1ee6e, 0x1ee6f, 0x1ee70, 0x1ee71, 0x1ee72, 0x1ee74, 0x1ee75, 0x1ee76, 0x1ee77, 0x1ee79,
+    0x1ee7a, 0x1ee7b, 0x1ee7c, 0x1ee7e, 0x1ee80, 0x1ee81, 0x1ee82, 0x1ee83, 0x1ee84, 0x1ee85,
+    0x1ee86, 0x1ee87, 0x1ee88, 0x1ee89, 0x1ee8b, 0x1ee8c, 0x1ee8d, 0x1ee8e, 0x1ee8f, 0x1ee90,
+    0x1ee91, 0x1ee92, 0x1ee93, 0x1ee94, 0x1ee95, 0x1ee96, 0x1ee97, 0x1ee98, 0x1ee99, 0x1ee9a,
+    0x1ee9b, 0x1eea1, 0x1eea2, 0x1eea3, 0x1eea5, 0x1eea6, 0x1eea7, 0x1eea8, 0x1eea9, 0x1eeab,
+    0x1eeac, 0x1eead, 0x1eeae, 0x1eeaf, 0x1eeb0, 0x1eeb1, 0x1eeb2, 0x1eeb3, 0x1eeb4, 0x1eeb5,
+    0x1eeb6, 0x1eeb7, 0x1eeb8, 0x1eeb9, 0x1eeba, 0x1eebb, 0x1eef0, 0x1eef1, 0x1f000, 0x1f001,
+    0x1f002, 0x1f003, 0x1f004, 0x1f005, 0x1f006, 0x1f007, 0x1f008, 0x1f009, 0x1f00a, 0x1f00b,
+    0x1f00c, 0x1f00d, 0x1f00e, 0x1f00f, 0x1f010, 0x1f011, 0x1f012, 0x1f013, 0x1f014, 0x1f015,
+    0x1f016, 0x1f017, 0x1f018, 0x1f019, 0x1f01a, 0x1f01b, 0x1f01c, 0x1f01d, 0x1f01e, 0x1f01f,
+    0x1f020, 0x1f021, 0x1f022, 0x1f023, 0x1f024, 0x1f025, 0x1f026, 0x1f027, 0x1f028, 0x1f029,
+    0x1f02a, 0x1f02b, 0x1f030, 0x1f031, 0x1f032, 0x1f033, 0x1f034, 0x1f035, 0x1f036, 0x1f037,
+    0x1f038, 0x1f039, 0x1f03a, 0x1f03b, 0x1f03c, 0x1f03d, 0x1f03e, 0x1f03f, 0x1f040, 0x1f041,
+    0x1f042, 0x1f043, 0x1f044, 0x1f045, 0x1f046, 0x1f047, 0x1f048, 0x1f049, 0x1f04a, 0x1f04b,
+    0x1f04c, 0x1f04d, 0x1f04e, 0x1f04f, 0x1f050, 0x1f051, 0x1f052, 0x1f053, 0x1f054, 0x1f055,
+    0x1f056, 0x1f057, 0x1f058, 0x1f059, 0x1f05a, 0x1f05b, 0x1f05c, 0x1f05d, 0x1f05e, 0x1f05f,
+    0x1f060, 0x1f061, 0x1f062, 0x1f063, 0x1f064, 0x1f065, 0x1f066, 0x1f067, 0x1f068, 0x1f069,
+    0x1f06a, 0x1f06b, 0x1f06c, 0x1f06d, 0x1f06e, 0x1f06f, 0x1f070, 0x1f071, 0x1f072, 0x1f073,
+    0x1f074, 0x1f075, 0x1f076, 0x1f077, 0x1f078, 0x1f079, 0x1f07a, 0x1f07b, 0x1f07c, 0x1f07d,
+    0x1f07e, 0x1f07f, 0x1f080, 0x1f081, 0x1f082, 0x1f083, 0x1f084, 0x1f085, 0x1f086, 0x1f087,
+    0x1f088, 0x1f089, 0x1f08a, 0x1f08b, 0x1f08c, 0x1f08d, 0x1f08e, 0x1f08f, 0x1f090, 0x1f091,
+    0x1f092, 0x1f093, 0x1f0a0, 0x1f0a1, 0x1f0a2, 0x1f0a3, 0x1f0a4, 0x1f0a5, 0x1f0a6, 0x1f0a7,
+    0x1f0a8, 0x1f0a9, 0x1f0aa, 0x1f0ab, 0x1f0ac, 0x1f0ad, 0x1f0ae, 0x1f0b1, 0x1f0b2, 0x1f0b3,
+    0x1f0b4, 0x1f0b5, 0x1f0b6, 0x1f0b7, 0x1f0b8, 0x1f0b9, 0x1f0ba, 0x1f0bb, 0x1f0bc, 0x1f0bd,
+    0x1f0be, 0x1f0bf, 0x1f0c1, 0x1f0c2, 0x1f0c3, 0x1f0c4, 0x1f0c5, 0x1f0c6, 0x1f0c7, 0x1f0c8,
+    0x1f0c9, 0x1f0ca, 0x1f0cb, 0x1f0cc, 0x1f0cd, 0x1f0ce, 0x1f0cf, 0x1f0d1, 0x1f0d2, 0x1f0d3,
+    0x1f0d4, 0x1f0d5, 0x1f0d6, 0x1f0d7, 0x1f0d8, 0x1f0d9, 0x1f0da, 0x1f0db, 0x1f0dc, 0x1f0dd,
+    0x1f0de, 0x1f0df, 0x1f0e0, 0x1f0e1, 0x1f0e2, 0x1f0e3, 0x1f0e4, 0x1f0e5, 0x1f0e6, 0x1f0e7,
+    0x1f0e8, 0x1f0e9, 0x1f0ea, 0x1f0eb, 0x1f0ec, 0x1f0ed, 0x1f0ee, 0x1f0ef, 0x1f0f0, 0x1f0f1,
+    0x1f0f2, 0x1f0f3, 0x1f0f4, 0x1f0f5, 0x1f100, 0x1f101, 0x1f102, 0x1f103, 0x1f104, 0x1f105,
+    0x1f106, 0x1f107, 0x1f108, 0x1f109, 0x1f10a, 0x1f10b, 0x1f10c, 0x1f10d, 0x1f10e, 0x1f10f,
+    0x1f110, 0x1f111, 0x1f112, 0x1f113, 0x1f114, 0x1f115, 0x1f116, 0x1f117, 0x1f118, 0x1f119,
+    0x1f11a, 0x1f11b, 0x1f11c, 0x1f11d, 0x1f11e, 0x1f11f, 0x1f120, 0x1f121, 0x1f122, 0x1f123,
+    0x1f124, 0x1f125, 0x1f126, 0x1f127, 0x1f128, 0x1f129, 0x1f12a, 0x1f12b, 0x1f12c, 0x1f12d,
+    0x1f12e, 0x1f12f, 0x1f130, 0x1f131, 0x1f132, 0x1f133, 0x1f134, 0x1f135, 0x1f136, 0x1f137,
+    0x1f138, 0x1f139, 0x1f13a, 0x1f13b, 0x1f13c, 0x1f13d, 0x1f13e, 0x1f13f, 0x1f140, 0x1f141,
+    0x1f142, 0x1f143, 0x1f144, 0x1f145, 0x1f146, 0x1f147, 0x1f148, 0x1f149, 0x1f14a, 0x1f14b,
+    0x1f14c, 0x1f14d, 0x1f14e, 0x1f14f, 0x1f150, 0x1f151, 0x1f152, 0x1f153, 0x1f154, 0x1f155,
+    0x1f156, 0x1f157, 0x1f158, 0x1f159, 0x1f15a, 0x1f15b, 0x1f15c, 0x1f15d, 0x1f15e, 0x1f15f,
+    0x1f160, 0x1f161, 0x1f162, 0x1f163, 0x1f164, 0x1f165, 0x1f166, 0x1f167, 0x1f168, 0x1f169,
+    0x1f16a, 0x1f16b, 0x1f16c, 0x1f16d, 0x1f16e, 0x1f16f, 0x1f170, 0x1f171, 0x1f172, 0x1f173,
+    0x1f174, 0x1f175, 0x1f176, 0x1f177, 0x1f178, 0x1f179, 0x1f17a, 0x1f17b, 0x1f17c, 0x1f17d,
+    0x1f17e, 0x1f17f, 0x1f180, 0x1f181, 0x1f182, 0x1f183, 0x1f184, 0x1f185, 0x1f186, 0x1f187,
+    0x1f188, 0x1f189, 0x1f18a, 0x1f18b, 0x1f18c, 0x1f18d, 0x1f18e, 0x1f18f, 0x1f190, 0x1f191,
+    0x1f192, 0x1f193, 0x1f194, 0x1f195, 0x1f196, 0x1f197, 0x1f198, 0x1f199, 0x1f19a, 0x1f19b,
+    0x1f19c, 0x1f19d, 0x1f19e, 0x1f19f, 0x1f1a0, 0x1f1a1, 0x1f1a2, 0x1f1a3, 0x1f1a4, 0x1f1a5,
+    0x1f1a6, 0x1f1a7, 0x1f1a8, 0x1f1a9, 0x1f1aa, 0x1f1ab, 0x1f1ac, 0x1f1ad, 0x1f1e6, 0x1f1e7,
+    0x1f1e8, 0x1f1e9, 0x1f1ea, 0x1f1eb, 0x1f1ec, 0x1f1ed, 0x1f1ee, 0x1f1ef, 0x1f1f0, 0x1f1f1,
+    0x1f1f2, 0x1f1f3, 0x1f1f4, 0x1f1f5, 0x1f1f6, 0x1f1f7, 0x1f1f8, 0x1f1f9, 0x1f1fa, 0x1f1fb,
+    0x1f1fc, 0x1f1fd, 0x1f1fe, 0x1f1ff, 0x1f200, 0x1f201, 0x1f202, 0x1f210, 0x1f211, 0x1f212,
+    0x1f213, 0x1f214, 0x1f215, 0x1f216, 0x1f217, 0x1f218, 0x1f219, 0x1f21a, 0x1f21b, 0x1f21c,
+    0x1f21d, 0x1f21e, 0x1f21f, 0x1f220, 0x1f221, 0x1f222, 0x1f223, 0x1f224, 0x1f225, 0x1f226,
+    0x1f227, 0x1f228, 0x1f229, 0x1f22a, 0x1f22b, 0x1f22c, 0x1f22d, 0x1f22e, 0x1f22f, 0x1f230,
+    0x1f231, 0x1f232, 0x1f233, 0x1f234, 0x1f235, 0x1f236, 0x1f237, 0x1f238, 0x1f239, 0x1f23a,
+    0x1f23b, 0x1f240, 0x1f241, 0x1f242, 0x1f243, 0x1f244, 0x1f245, 0x1f246, 0x1f247, 0x1f248,
+    0x1f250, 0x1f251, 0x1f260, 0x1f261, 0x1f262, 0x1f263, 0x1f264, 0x1f265, 0x1f300, 0x1f301,
+    0x1f302, 0x1f303, 0x1f304, 0x1f305, 0x1f306, 0x1f307, 0x1f308, 0x1f309, 0x1f30a, 0x1f30b,
+    0x1f30c, 0x1f30d, 0x1f30e, 0x1f30f, 0x1f310, 0x1f311, 0x1f312, 0x1f313, 0x1f314, 0x1f315,
+    0x1f316, 0x1f317, 0x1f318, 0x1f319, 0x1f31a, 0x1f31b, 0x1f31c, 0x1f31d, 0x1f31e, 0x1f31f,
+    0x1f320, 0x1f321, 0x1f322, 0x1f323, 0x1f324, 0x1f325, 0x1f326, 0x1f327, 0x1f328, 0x1f329,
+    0x1f32a, 0x1f32b, 0x1f32c, 0x1f32d, 0x1f32e, 0x1f32f, 0x1f330, 0x1f331, 0x1f332, 0x1f333,
+    0x1f334, 0x1f335, 0x1f336, 0x1f337, 0x1f338, 0x1f339, 0x1f33a, 0x1f33b, 0x1f33c, 0x1f33d,
+    0x1f33e, 0x1f33f, 0x1f340, 0x1f341, 0x1f342, 0x1f343, 0x1f344, 0x1f345, 0x1f346, 0x1f347,
+    0x1f348, 0x1f349, 0x1f34a, 0x1f34b, 0x1f34c, 0x1f34d, 0x1f34e, 0x1f34f, 0x1f350, 0x1f351,
+    0x1f352, 0x1f353, 0x1f354, 0x1f355, 0x1f356, 0x1f357, 0x1f358, 0x1f359, 0x1f35a, 0x1f35b,
+    0x1f35c, 0x1f35d, 0x1f35e, 0x1f35f, 0x1f360, 0x1f361, 0x1f362, 0x1f363, 0x1f364, 0x1f365,
+    0x1f366, 0x1f367, 0x1f368, 0x1f369, 0x1f36a, 0x1f36b, 0x1f36c, 0x1f36d, 0x1f36e, 0x1f36f,
+    0x1f370, 0x1f371, 0x1f372, 0x1f373, 0x1f374, 0x1f375, 0x1f376, 0x1f377, 0x1f378, 0x1f379,
+    0x1f37a, 0x1f37b, 0x1f37c, 0x1f37d, 0x1f37e, 0x1f37f, 0x1f380, 0x1f381, 0x1f382, 0x1f383,
+    0x1f384, 0x1f385, 0x1f386, 0x1f387, 0x1f388, 0x1f389, 0x1f38a, 0x1f38b, 0x1f38c, 0x1f38d,
+    0x1f38e, 0x1f38f, 0x1f390, 0x1f391, 0x1f392, 0x1f393, 0x1f394, 0x1f395, 0x1f396, 0x1f397,
+    0x1f398, 0x1f399, 0x1f39a, 0x1f39b, 0x1f39c, 0x1f39d, 0x1f39e, 0x1f39f, 0x1f3a0, 0x1f3a1,
+    0x1f3a2, 0x1f3a3, 0x1f3a4, 0x1f3a5, 0x1f3a6, 0x1f3a7, 0x1f3a8, 0x1f3a9, 0x1f3aa, 0x1f3ab,
+    0x1f3ac, 0x1f3ad, 0x1f3ae, 0x1f3af, 0x1f3b0, 0x1f3b1, 0x1f3b2, 0x1f3b3, 0x1f3b4, 0x1f3b5,
+    0x1f3b6, 0x1f3b7, 0x1f3b8, 0x1f3b9, 0x1f3ba, 0x1f3bb, 0x1f3bc, 0x1f3bd, 0x1f3be, 0x1f3bf,
+    0x1f3c0, 0x1f3c1, 0x1f3c2, 0x1f3c3, 0x1f3c4, 0x1f3c5, 0x1f3c6, 0x1f3c7, 0x1f3c8, 0x1f3c9,
+    0x1f3ca, 0x1f3cb, 0x1f3cc, 0x1f3cd, 0x1f3ce, 0x1f3cf, 0x1f3d0, 0x1f3d1, 0x1f3d2, 0x1f3d3,
+    0x1f3d4, 0x1f3d5, 0x1f3d6, 0x1f3d7, 0x1f3d8, 0x1f3d9, 0x1f3da, 0x1f3db, 0x1f3dc, 0x1f3dd,
+    0x1f3de, 0x1f3df, 0x1f3e0, 0x1f3e1, 0x1f3e2, 0x1f3e3, 0x1f3e4, 0x1f3e5, 0x1f3e6, 0x1f3e7,
+    0x1f3e8, 0x1f3e9, 0x1f3ea, 0x1f3eb, 0x1f3ec, 0x1f3ed, 0x1f3ee, 0x1f3ef, 0x1f3f0, 0x1f3f1,
+    0x1f3f2, 0x1f3f3, 0x1f3f4, 0x1f3f5, 0x1f3f6, 0x1f3f7, 0x1f3f8, 0x1f3f9, 0x1f3fa, 0x1f3fb,
+    0x1f3fc, 0x1f3fd, 0x1f3fe, 0x1f3ff, 0x1f400, 0x1f401, 0x1f402, 0x1f403, 0x1f404, 0x1f405,
+    0x1f406, 0x1f407, 0x1f408, 0x1f409, 0x1f40a, 0x1f40b, 0x1f40c, 0x1f40d, 0x1f40e, 0x1f40f,
+    0x1f410, 0x1f411, 0x1f412, 0x1f413, 0x1f414, 0x1f415, 0x1f416, 0x1f417, 0x1f418, 0x1f419,
+    0x1f41a, 0x1f41b, 0x1f41c, 0x1f41d, 0x1f41e, 0x1f41f, 0x1f420, 0x1f421, 0x1f422, 0x1f423,
+    0x1f424, 0x1f425, 0x1f426, 0x1f427, 0x1f428, 0x1f429, 0x1f42a, 0x1f42b, 0x1f42c, 0x1f42d,
+    0x1f42e, 0x1f42f, 0x1f430, 0x1f431, 0x1f432, 0x1f433, 0x1f434, 0x1f435, 0x1f436, 0x1f437,
+    0x1f438, 0x1f439, 0x1f43a, 0x1f43b, 0x1f43c, 0x1f43d, 0x1f43e, 0x1f43f, 0x1f440, 0x1f441,
+    0x1f442, 0x1f443, 0x1f444, 0x1f445, 0x1f446, 0x1f447, 0x1f448, 0x1f449, 0x1f44a, 0x1f44b,
+    0x1f44c, 0x1f44d, 0x1f44e, 0x1f44f, 0x1f450, 0x1f451, 0x1f452, 0x1f453, 0x1f454, 0x1f455,
+    0x1f456, 0x1f457, 0x1f458, 0x1f459, 0x1f45a, 0x1f45b, 0x1f45c, 0x1f45d, 0x1f45e, 0x1f45f,
+    0x1f460, 0x1f461, 0x1f462, 0x1f463, 0x1f464, 0x1f465, 0x1f466, 0x1f467, 0x1f468, 0x1f469,
+    0x1f46a, 0x1f46b, 0x1f46c, 0x1f46d, 0x1f46e, 0x1f46f, 0x1f470, 0x1f471, 0x1f472, 0x1f473,
+    0x1f474, 0x1f475, 0x1f476, 0x1f477, 0x1f478, 0x1f479, 0x1f47a, 0x1f47b, 0x1f47c, 0x1f47d,
+    0x1f47e, 0x1f47f, 0x1f480, 0x1f481, 0x1f482, 0x1f483, 0x1f484, 0x1f485, 0x1f486, 0x1f487,
+    0x1f488, 0x1f489, 0x1f48a, 0x1f48b, 0x1f48c, 0x1f48d, 0x1f48e, 0x1f48f, 0x1f490, 0x1f491,
+    0x1f492, 0x1f493, 0x1f494, 0x1f495, 0x1f496, 0x1f497, 0x1f498, 0x1f499, 0x1f49a, 0x1f49b,
+    0x1f49c, 0x1f49d, 0x1f49e, 0x1f49f, 0x1f4a0, 0x1f4a1, 0x1f4a2, 0x1f4a3, 0x1f4a4, 0x1f4a5,
+    0x1f4a6, 0x1f4a7, 0x1f4a8, 0x1f4a9, 0x1f4aa, 0x1f4ab, 0x1f4ac, 0x1f4ad, 0x1f4ae, 0x1f4af,
+    0x1f4b0, 0x1f4b1, 0x1f4b2, 0x1f4b3, 0x1f4b4, 0x1f4b5, 0x1f4b6, 0x1f4b7, 0x1f4b8, 0x1f4b9,
+    0x1f4ba, 0x1f4bb, 0x1f4bc, 0x1f4bd, 0x1f4be, 0x1f4bf, 0x1f4c0, 0x1f4c1, 0x1f4c2, 0x1f4c3,
+    0x1f4c4, 0x1f4c5, 0x1f4c6, 0x1f4c7, 0x1f4c8, 0x1f4c9, 0x1f4ca, 0x1f4cb, 0x1f4cc, 0x1f4cd,
+    0x1f4ce, 0x1f4cf, 0x1f4d0, 0x1f4d1, 0x1f4d2, 0x1f4d3, 0x1f4d4, 0x1f4d5, 0x1f4d6, 0x1f4d7,
+    0x1f4d8, 0x1f4d9, 0x1f4da, 0x1f4db, 0x1f4dc, 0x1f4dd, 0x1f4de, 0x1f4df, 0x1f4e0, 0x1f4e1,
+    0x1f4e2, 0x1f4e3, 0x1f4e4, 0x1f4e5, 0x1f4e6, 0x1f4e7, 0x1f4e8, 0x1f4e9, 0x1f4ea, 0x1f4eb,
+    0x1f4ec, 0x1f4ed, 0x1f4ee, 0x1f4ef, 0x1f4f0, 0x1f4f1, 0x1f4f2, 0x1f4f3, 0x1f4f4, 0x1f4f5,
+    0x1f4f6, 0x1f4f7, 0x1f4f8, 0x1f4f9, 0x1f4fa, 0x1f4fb, 0x1f4fc, 0x1f4fd, 0x1f4fe, 0x1f4ff,
+    0x1f500, 0x1f501, 0x1f502, 0x1f503, 0x1f504, 0x1f505, 0x1f506, 0x1f507, 0x1f508, 0x1f509,
+    0x1f50a, 0x1f50b, 0x1f50c, 0x1f50d, 0x1f50e, 0x1f50f, 0x1f510, 0x1f511, 0x1f512, 0x1f513,
+    0x1f514, 0x1f515, 0x1f516, 0x1f517, 0x1f518, 0x1f519, 0x1f51a, 0x1f51b, 0x1f51c, 0x1f51d,
+    0x1f51e, 0x1f51f, 0x1f520, 0x1f521, 0x1f522, 0x1f523, 0x1f524, 0x1f525, 0x1f526, 0x1f527,
+    0x1f528, 0x1f529, 0x1f52a, 0x1f52b, 0x1f52c, 0x1f52d, 0x1f52e, 0x1f52f, 0x1f530, 0x1f531,
+    0x1f532, 0x1f533, 0x1f534, 0x1f535, 0x1f536, 0x1f537, 0x1f538, 0x1f539, 0x1f53a, 0x1f53b,
+    0x1f53c, 0x1f53d, 0x1f53e, 0x1f53f, 0x1f540, 0x1f541, 0x1f542, 0x1f543, 0x1f544, 0x1f545,
+    0x1f546, 0x1f547, 0x1f548, 0x1f549, 0x1f54a, 0x1f54b, 0x1f54c, 0x1f54d, 0x1f54e, 0x1f54f,
+    0x1f550, 0x1f551, 0x1f552, 0x1f553, 0x1f554, 0x1f555, 0x1f556, 0x1f557, 0x1f558, 0x1f559,
+    0x1f55a, 0x1f55b, 0x1f55c, 0x1f55d, 0x1f55e, 0x1f55f, 0x1f560, 0x1f561, 0x1f562, 0x1f563,
+    0x1f564, 0x1f565, 0x1f566, 0x1f567, 0x1f568, 0x1f569, 0x1f56a, 0x1f56b, 0x1f56c, 0x1f56d,
+    0x1f56e, 0x1f56f, 0x1f570, 0x1f571, 0x1f572, 0x1f573, 0x1f574, 0x1f575, 0x1f576, 0x1f577,
+    0x1f578, 0x1f579, 0x1f57a, 0x1f57b, 0x1f57c, 0x1f57d, 0x1f57e, 0x1f57f, 0x1f580, 0x1f581,
+    0x1f582, 0x1f583, 0x1f584, 0x1f585, 0x1f586, 0x1f587, 0x1f588, 0x1f589, 0x1f58a, 0x1f58b,
+    0x1f58c, 0x1f58d, 0x1f58e, 0x1f58f, 0x1f590, 0x1f591, 0x1f592, 0x1f593, 0x1f594, 0x1f595,
+    0x1f596, 0x1f597, 0x1f598, 0x1f599, 0x1f59a, 0x1f59b, 0x1f59c, 0x1f59d, 0x1f59e, 0x1f59f,
+    0x1f5a0, 0x1f5a1, 0x1f5a2, 0x1f5a3, 0x1f5a4, 0x1f5a5, 0x1f5a6, 0x1f5a7, 0x1f5a8, 0x1f5a9,
+    0x1f5aa, 0x1f5ab, 0x1f5ac, 0x1f5ad, 0x1f5ae, 0x1f5af, 0x1f5b0, 0x1f5b1, 0x1f5b2, 0x1f5b3,
+    0x1f5b4, 0x1f5b5, 0x1f5b6, 0x1f5b7, 0x1f5b8, 0x1f5b9, 0x1f5ba, 0x1f5bb, 0x1f5bc, 0x1f5bd,
+    0x1f5be, 0x1f5bf, 0x1f5c0, 0x1f5c1, 0x1f5c2, 0x1f5c3, 0x1f5c4, 0x1f5c5, 0x1f5c6, 0x1f5c7,
+    0x1f5c8, 0x1f5c9, 0x1f5ca, 0x1f5cb, 0x1f5cc, 0x1f5cd, 0x1f5ce, 0x1f5cf, 0x1f5d0, 0x1f5d1,
+    0x1f5d2, 0x1f5d3, 0x1f5d4, 0x1f5d5, 0x1f5d6, 0x1f5d7, 0x1f5d8, 0x1f5d9, 0x1f5da, 0x1f5db,
+    0x1f5dc, 0x1f5dd, 0x1f5de, 0x1f5df, 0x1f5e0, 0x1f5e1, 0x1f5e2, 0x1f5e3, 0x1f5e4, 0x1f5e5,
+    0x1f5e6, 0x1f5e7, 0x1f5e8, 0x1f5e9, 0x1f5ea, 0x1f5eb, 0x1f5ec, 0x1f5ed, 0x1f5ee, 0x1f5ef,
+    0x1f5f0, 0x1f5f1, 0x1f5f2, 0x1f5f3, 0x1f5f4, 0x1f5f5, 0x1f5f6, 0x1f5f7, 0x1f5f8, 0x1f5f9,
+    0x1f5fa, 0x1f5fb, 0x1f5fc, 0x1f5fd, 0x1f5fe, 0x1f5ff, 0x1f600, 0x1f601, 0x1f602, 0x1f603,
+    0x1f604, 0x1f605, 0x1f606, 0x1f607, 0x1f608, 0x1f609, 0x1f60a, 0x1f60b, 0x1f60c, 0x1f60d,
+    0x1f60e, 0x1f60f, 0x1f610, 0x1f611, 0x1f612, 0x1f613, 0x1f614, 0x1f615, 0x1f616, 0x1f617,
+    0x1f618, 0x1f619, 0x1f61a, 0x1f61b, 0x1f61c, 0x1f61d, 0x1f61e, 0x1f61f, 0x1f620, 0x1f621,
+    0x1f622, 0x1f623, 0x1f624, 0x1f625, 0x1f626, 0x1f627, 0x1f628, 0x1f629, 0x1f62a, 0x1f62b,
+    0x1f62c, 0x1f62d, 0x1f62e, 0x1f62f, 0x1f630, 0x1f631, 0x1f632, 0x1f633, 0x1f634, 0x1f635,
+    0x1f636, 0x1f637, 0x1f638, 0x1f639, 0x1f63a, 0x1f63b, 0x1f63c, 0x1f63d, 0x1f63e, 0x1f63f,
+    0x1f640, 0x1f641, 0x1f642, 0x1f643, 0x1f644, 0x1f645, 0x1f646, 0x1f647, 0x1f648, 0x1f649,
+    0x1f64a, 0x1f64b, 0x1f64c, 0x1f64d, 0x1f64e, 0x1f64f, 0x1f650, 0x1f651, 0x1f652, 0x1f653,
+    0x1f654, 0x1f655, 0x1f656, 0x1f657, 0x1f658, 0x1f659, 0x1f65a, 0x1f65b, 0x1f65c, 0x1f65d,
+    0x1f65e, 0x1f65f, 0x1f660, 0x1f661, 0x1f662, 0x1f663, 0x1f664, 0x1f665, 0x1f666, 0x1f667,
+    0x1f668, 0x1f669, 0x1f66a, 0x1f66b, 0x1f66c, 0x1f66d, 0x1f66e, 0x1f66f, 0x1f670, 0x1f671,
+    0x1f672, 0x1f673, 0x1f674, 0x1f675, 0x1f676, 0x1f677, 0x1f678, 0x1f679, 0x1f67a, 0x1f67b,
+    0x1f67c, 0x1f67d, 0x1f67e, 0x1f67f, 0x1f680, 0x1f681, 0x1f682, 0x1f683, 0x1f684, 0x1f685,
+    0x1f686, 0x1f687, 0x1f688, 0x1f689, 0x1f68a, 0x1f68b, 0x1f68c, 0x1f68d, 0x1f68e, 0x1f68f,
+    0x1f690, 0x1f691, 0x1f692, 0x1f693, 0x1f694, 0x1f695, 0x1f696, 0x1f697, 0x1f698, 0x1f699,
+    0x1f69a, 0x1f69b, 0x1f69c, 0x1f69d, 0x1f69e, 0x1f69f, 0x1f6a0, 0x1f6a1, 0x1f6a2, 0x1f6a3,
+    0x1f6a4, 0x1f6a5, 0x1f6a6, 0x1f6a7, 0x1f6a8, 0x1f6a9, 0x1f6aa, 0x1f6ab, 0x1f6ac, 0x1f6ad,
+    0x1f6ae, 0x1f6af, 0x1f6b0, 0x1f6b1, 0x1f6b2, 0x1f6b3, 0x1f6b4, 0x1f6b5, 0x1f6b6, 0x1f6b7,
+    0x1f6b8, 0x1f6b9, 0x1f6ba, 0x1f6bb, 0x1f6bc, 0x1f6bd, 0x1f6be, 0x1f6bf, 0x1f6c0, 0x1f6c1,
+    0x1f6c2, 0x1f6c3, 0x1f6c4, 0x1f6c5, 0x1f6c6, 0x1f6c7, 0x1f6c8, 0x1f6c9, 0x1f6ca, 0x1f6cb,
+    0x1f6cc, 0x1f6cd, 0x1f6ce, 0x1f6cf, 0x1f6d0, 0x1f6d1, 0x1f6d2, 0x1f6d3, 0x1f6d4, 0x1f6d5,
+    0x1f6d6, 0x1f6d7, 0x1f6e0, 0x1f6e1, 0x1f6e2, 0x1f6e3, 0x1f6e4, 0x1f6e5, 0x1f6e6, 0x1f6e7,
+    0x1f6e8, 0x1f6e9, 0x1f6ea, 0x1f6eb, 0x1f6ec, 0x1f6f0, 0x1f6f1, 0x1f6f2, 0x1f6f3, 0x1f6f4,
+    0x1f6f5, 0x1f6f6, 0x1f6f7, 0x1f6f8, 0x1f6f9, 0x1f6fa, 0x1f6fb, 0x1f6fc, 0x1f700, 0x1f701,
+    0x1f702, 0x1f703, 0x1f704, 0x1f705, 0x1f706, 0x1f707, 0x1f708, 0x1f709, 0x1f70a, 0x1f70b,
+    0x1f70c, 0x1f70d, 0x1f70e, 0x1f70f, 0x1f710, 0x1f711, 0x1f712, 0x1f713, 0x1f714, 0x1f715,
+    0x1f716, 0x1f717, 0x1f718, 0x1f719, 0x1f71a, 0x1f71b, 0x1f71c, 0x1f71d, 0x1f71e, 0x1f71f,
+    0x1f720, 0x1f721, 0x1f722, 0x1f723, 0x1f724, 0x1f725, 0x1f726, 0x1f727, 0x1f728, 0x1f729,
+    0x1f72a, 0x1f72b, 0x1f72c, 0x1f72d, 0x1f72e, 0x1f72f, 0x1f730, 0x1f731, 0x1f732, 0x1f733,
+    0x1f734, 0x1f735, 0x1f736, 0x1f737, 0x1f738, 0x1f739, 0x1f73a, 0x1f73b, 0x1f73c, 0x1f73d,
+    0x1f73e, 0x1f73f, 0x1f740, 0x1f741, 0x1f742, 0x1f743, 0x1f744, 0x1f745, 0x1f746, 0x1f747,
+    0x1f748, 0x1f749, 0x1f74a, 0x1f74b, 0x1f74c, 0x1f74d, 0x1f74e, 0x1f74f, 0x1f750, 0x1f751,
+    0x1f752, 0x1f753, 0x1f754, 0x1f755, 0x1f756, 0x1f757, 0x1f758, 0x1f759, 0x1f75a, 0x1f75b,
+    0x1f75c, 0x1f75d, 0x1f75e, 0x1f75f, 0x1f760, 0x1f761, 0x1f762, 0x1f763, 0x1f764, 0x1f765,
+    0x1f766, 0x1f767, 0x1f768, 0x1f769, 0x1f76a, 0x1f76b, 0x1f76c, 0x1f76d, 0x1f76e, 0x1f76f,
+    0x1f770, 0x1f771, 0x1f772, 0x1f773, 0x1f780, 0x1f781, 0x1f782, 0x1f783, 0x1f784, 0x1f785,
+    0x1f786, 0x1f787, 0x1f788, 0x1f789, 0x1f78a, 0x1f78b, 0x1f78c, 0x1f78d, 0x1f78e, 0x1f78f,
+    0x1f790, 0x1f791, 0x1f792, 0x1f793, 0x1f794, 0x1f795, 0x1f796, 0x1f797, 0x1f798, 0x1f799,
+    0x1f79a, 0x1f79b, 0x1f79c, 0x1f79d, 0x1f79e, 0x1f79f, 0x1f7a0, 0x1f7a1, 0x1f7a2, 0x1f7a3,
+    0x1f7a4, 0x1f7a5, 0x1f7a6, 0x1f7a7, 0x1f7a8, 0x1f7a9, 0x1f7aa, 0x1f7ab, 0x1f7ac, 0x1f7ad,
+    0x1f7ae, 0x1f7af, 0x1f7b0, 0x1f7b1, 0x1f7b2, 0x1f7b3, 0x1f7b4, 0x1f7b5, 0x1f7b6, 0x1f7b7,
+    0x1f7b8, 0x1f7b9, 0x1f7ba, 0x1f7bb, 0x1f7bc, 0x1f7bd, 0x1f7be, 0x1f7bf, 0x1f7c0, 0x1f7c1,
+    0x1f7c2, 0x1f7c3, 0x1f7c4, 0x1f7c5, 0x1f7c6, 0x1f7c7, 0x1f7c8, 0x1f7c9, 0x1f7ca, 0x1f7cb,
+    0x1f7cc, 0x1f7cd, 0x1f7ce, 0x1f7cf, 0x1f7d0, 0x1f7d1, 0x1f7d2, 0x1f7d3, 0x1f7d4, 0x1f7d5,
+    0x1f7d6, 0x1f7d7, 0x1f7d8, 0x1f7e0, 0x1f7e1, 0x1f7e2, 0x1f7e3, 0x1f7e4, 0x1f7e5, 0x1f7e6,
+    0x1f7e7, 0x1f7e8, 0x1f7e9, 0x1f7ea, 0x1f7eb, 0x1f800, 0x1f801, 0x1f802, 0x1f803, 0x1f804,
+    0x1f805, 0x1f806, 0x1f807, 0x1f808, 0x1f809, 0x1f80a, 0x1f80b, 0x1f810, 0x1f811, 0x1f812,
+    0x1f813, 0x1f814, 0x1f815, 0x1f816, 0x1f817, 0x1f818, 0x1f819, 0x1f81a, 0x1f81b, 0x1f81c,
+    0x1f81d, 0x1f81e, 0x1f81f, 0x1f820, 0x1f821, 0x1f822, 0x1f823, 0x1f824, 0x1f825, 0x1f826,
+    0x1f827, 0x1f828, 0x1f829, 0x1f82a, 0x1f82b, 0x1f82c, 0x1f82d, 0x1f82e, 0x1f82f, 0x1f830,
+    0x1f831, 0x1f832, 0x1f833, 0x1f834, 0x1f835, 0x1f836, 0x1f837, 0x1f838, 0x1f839, 0x1f83a,
+    0x1f83b, 0x1f83c, 0x1f83d, 0x1f83e, 0x1f83f, 0x1f840, 0x1f841, 0x1f842, 0x1f843, 0x1f844,
+    0x1f845, 0x1f846, 0x1f847, 0x1f850, 0x1f851, 0x1f852, 0x1f853, 0x1f854, 0x1f855, 0x1f856,
+    0x1f857, 0x1f858, 0x1f859, 0x1f860, 0x1f861, 0x1f862, 0x1f863, 0x1f864, 0x1f865, 0x1f866,
+    0x1f867, 0x1f868, 0x1f869, 0x1f86a, 0x1f86b, 0x1f86c, 0x1f86d, 0x1f86e, 0x1f86f, 0x1f870,
+    0x1f871, 0x1f872, 0x1f873, 0x1f874, 0x1f875, 0x1f876, 0x1f877, 0x1f878, 0x1f879, 0x1f87a,
+    0x1f87b, 0x1f87c, 0x1f87d, 0x1f87e, 0x1f87f, 0x1f880, 0x1f881, 0x1f882, 0x1f883, 0x1f884,
+    0x1f885, 0x1f886, 0x1f887, 0x1f890, 0x1f891, 0x1f892, 0x1f893, 0x1f894, 0x1f895, 0x1f896,
+    0x1f897, 0x1f898, 0x1f899, 0x1f89a, 0x1f89b, 0x1f89c, 0x1f89d, 0x1f89e, 0x1f89f, 0x1f8a0,
+    0x1f8a1, 0x1f8a2, 0x1f8a3, 0x1f8a4, 0x1f8a5, 0x1f8a6, 0x1f8a7, 0x1f8a8, 0x1f8a9, 0x1f8aa,
+    0x1f8ab, 0x1f8ac, 0x1f8ad, 0x1f8b0, 0x1f8b1, 0x1f900, 0x1f901, 0x1f902, 0x1f903, 0x1f904,
+    0x1f905, 0x1f906, 0x1f907, 0x1f908, 0x1f909, 0x1f90a, 0x1f90b, 0x1f90c, 0x1f90d, 0x1f90e,
+    0x1f90f, 0x1f910, 0x1f911, 0x1f912, 0x1f913, 0x1f914, 0x1f915, 0x1f916, 0x1f917, 0x1f918,
+    0x1f919, 0x1f91a, 0x1f91b, 0x1f91c, 0x1f91d, 0x1f91e, 0x1f91f, 0x1f920, 0x1f921, 0x1f922,
+    0x1f923, 0x1f924, 0x1f925, 0x1f926, 0x1f927, 0x1f928, 0x1f929, 0x1f92a, 0x1f92b, 0x1f92c,
+    0x1f92d, 0x1f92e, 0x1f92f, 0x1f930, 0x1f931, 0x1f932, 0x1f933, 0x1f934, 0x1f935, 0x1f936,
+    0x1f937, 0x1f938, 0x1f939, 0x1f93a, 0x1f93b, 0x1f93c, 0x1f93d, 0x1f93e, 0x1f93f, 0x1f940,
+    0x1f941, 0x1f942, 0x1f943, 0x1f944, 0x1f945, 0x1f946, 0x1f947, 0x1f948, 0x1f949, 0x1f94a,
+    0x1f94b, 0x1f94c, 0x1f94d, 0x1f94e, 0x1f94f, 0x1f950, 0x1f951, 0x1f952, 0x1f953, 0x1f954,
+    0x1f955, 0x1f956, 0x1f957, 0x1f958, 0x1f959, 0x1f95a, 0x1f95b, 0x1f95c, 0x1f95d, 0x1f95e,
+    0x1f95f, 0x1f960, 0x1f961, 0x1f962, 0x1f963, 0x1f964, 0x1f965, 0x1f966, 0x1f967, 0x1f968,
+    0x1f969, 0x1f96a, 0x1f96b, 0x1f96c, 0x1f96d, 0x1f96e, 0x1f96f, 0x1f970, 0x1f971, 0x1f972,
+    0x1f973, 0x1f974, 0x1f975, 0x1f976, 0x1f977, 0x1f978, 0x1f97a, 0x1f97b, 0x1f97c, 0x1f97d,
+    0x1f97e, 0x1f97f, 0x1f980, 0x1f981, 0x1f982, 0x1f983, 0x1f984, 0x1f985, 0x1f986, 0x1f987,
+    0x1f988, 0x1f989, 0x1f98a, 0x1f98b, 0x1f98c, 0x1f98d, 0x1f98e, 0x1f98f, 0x1f990, 0x1f991,
+    0x1f992, 0x1f993, 0x1f994, 0x1f995, 0x1f996, 0x1f997, 0x1f998, 0x1f999, 0x1f99a, 0x1f99b,
+    0x1f99c, 0x1f99d, 0x1f99e, 0x1f99f, 0x1f9a0, 0x1f9a1, 0x1f9a2, 0x1f9a3, 0x1f9a4, 0x1f9a5,
+    0x1f9a6, 0x1f9a7, 0x1f9a8, 0x1f9a9, 0x1f9aa, 0x1f9ab, 0x1f9ac, 0x1f9ad, 0x1f9ae, 0x1f9af,
+    0x1f9b0, 0x1f9b1, 0x1f9b2, 0x1f9b3, 0x1f9b4, 0x1f9b5, 0x1f9b6, 0x1f9b7, 0x1f9b8, 0x1f9b9,
+    0x1f9ba, 0x1f9bb, 0x1f9bc, 0x1f9bd, 0x1f9be, 0x1f9bf, 0x1f9c0, 0x1f9c1, 0x1f9c2, 0x1f9c3,
+    0x1f9c4, 0x1f9c5, 0x1f9c6, 0x1f9c7, 0x1f9c8, 0x1f9c9, 0x1f9ca, 0x1f9cb, 0x1f9cd, 0x1f9ce,
+    0x1f9cf, 0x1f9d0, 0x1f9d1, 0x1f9d2, 0x1f9d3, 0x1f9d4, 0x1f9d5, 0x1f9d6, 0x1f9d7, 0x1f9d8,
+    0x1f9d9, 0x1f9da, 0x1f9db, 0x1f9dc, 0x1f9dd, 0x1f9de, 0x1f9df, 0x1f9e0, 0x1f9e1, 0x1f9e2,
+    0x1f9e3, 0x1f9e4, 0x1f9e5, 0x1f9e6, 0x1f9e7, 0x1f9e8, 0x1f9e9, 0x1f9ea, 0x1f9eb, 0x1f9ec,
+    0x1f9ed, 0x1f9ee, 0x1f9ef, 0x1f9f0, 0x1f9f1, 0x1f9f2, 0x1f9f3, 0x1f9f4, 0x1f9f5, 0x1f9f6,
+    0x1f9f7, 0x1f9f8, 0x1f9f9, 0x1f9fa, 0x1f9fb, 0x1f9fc, 0x1f9fd, 0x1f9fe, 0x1f9ff, 0x1fa00,
+    0x1fa01, 0x1fa02, 0x1fa03, 0x1fa04, 0x1fa05, 0x1fa06, 0x1fa07, 0x1fa08, 0x1fa09, 0x1fa0a,
+    0x1fa0b, 0x1fa0c, 0x1fa0d, 0x1fa0e, 0x1fa0f, 0x1fa10, 0x1fa11, 0x1fa12, 0x1fa13, 0x1fa14,
+    0x1fa15, 0x1fa16, 0x1fa17, 0x1fa18, 0x1fa19, 0x1fa1a, 0x1fa1b, 0x1fa1c, 0x1fa1d, 0x1fa1e,
+    0x1fa1f, 0x1fa20, 0x1fa21, 0x1fa22, 0x1fa23, 0x1fa24, 0x1fa25, 0x1fa26, 0x1fa27, 0x1fa28,
+    0x1fa29, 0x1fa2a, 0x1fa2b, 0x1fa2c, 0x1fa2d, 0x1fa2e, 0x1fa2f, 0x1fa30, 0x1fa31, 0x1fa32,
+    0x1fa33, 0x1fa34, 0x1fa35, 0x1fa36, 0x1fa37, 0x1fa38, 0x1fa39, 0x1fa3a, 0x1fa3b, 0x1fa3c,
+    0x1fa3d, 0x1fa3e, 0x1fa3f, 0x1fa40, 0x1fa41, 0x1fa42, 0x1fa43, 0x1fa44, 0x1fa45, 0x1fa46,
+    0x1fa47, 0x1fa48, 0x1fa49, 0x1fa4a, 0x1fa4b, 0x1fa4c, 0x1fa4d, 0x1fa4e, 0x1fa4f, 0x1fa50,
+    0x1fa51, 0x1fa52, 0x1fa53, 0x1fa60, 0x1fa61, 0x1fa62, 0x1fa63, 0x1fa64, 0x1fa65, 0x1fa66,
+    0x1fa67, 0x1fa68, 0x1fa69, 0x1fa6a, 0x1fa6b, 0x1fa6c, 0x1fa6d, 0x1fa70, 0x1fa71, 0x1fa72,
+    0x1fa73, 0x1fa74, 0x1fa78, 0x1fa79, 0x1fa7a, 0x1fa80, 0x1fa81, 0x1fa82, 0x1fa83, 0x1fa84,
+    0x1fa85, 0x1fa86, 0x1fa90, 0x1fa91, 0x1fa92, 0x1fa93, 0x1fa94, 0x1fa95, 0x1fa96, 0x1fa97,
+    0x1fa98, 0x1fa99, 0x1fa9a, 0x1fa9b, 0x1fa9c, 0x1fa9d, 0x1fa9e, 0x1fa9f, 0x1faa0, 0x1faa1,
+    0x1faa2, 0x1faa3, 0x1faa4, 0x1faa5, 0x1faa6, 0x1faa7, 0x1faa8, 0x1fab0, 0x1fab1, 0x1fab2,
+    0x1fab3, 0x1fab4, 0x1fab5, 0x1fab6, 0x1fac0, 0x1fac1, 0x1fac2, 0x1fad0, 0x1fad1, 0x1fad2,
+    0x1fad3, 0x1fad4, 0x1fad5, 0x1fad6, 0x1fb00, 0x1fb01, 0x1fb02, 0x1fb03, 0x1fb04, 0x1fb05,
+    0x1fb06, 0x1fb07, 0x1fb08, 0x1fb09, 0x1fb0a, 0x1fb0b, 0x1fb0c, 0x1fb0d, 0x1fb0e, 0x1fb0f,
+    0x1fb10, 0x1fb11, 0x1fb12, 0x1fb13, 0x1fb14, 0x1fb15, 0x1fb16, 0x1fb17, 0x1fb18, 0x1fb19,
+    0x1fb1a, 0x1fb1b, 0x1fb1c, 0x1fb1d, 0x1fb1e, 0x1fb1f, 0x1fb20, 0x1fb21, 0x1fb22, 0x1fb23,
+    0x1fb24, 0x1fb25, 0x1fb26, 0x1fb27, 0x1fb28, 0x1fb29, 0x1fb2a, 0x1fb2b, 0x1fb2c, 0x1fb2d,
+    0x1fb2e, 0x1fb2f, 0x1fb30, 0x1fb31, 0x1fb32, 0x1fb33, 0x1fb34, 0x1fb35, 0x1fb36, 0x1fb37,
+    0x1fb38, 0x1fb39, 0x1fb3a, 0x1fb3b, 0x1fb3c, 0x1fb3d, 0x1fb3e, 0x1fb3f, 0x1fb40, 0x1fb41,
+    0x1fb42, 0x1fb43, 0x1fb44, 0x1fb45, 0x1fb46, 0x1fb47, 0x1fb48, 0x1fb49, 0x1fb4a, 0x1fb4b,
+    0x1fb4c, 0x1fb4d, 0x1fb4e, 0x1fb4f, 0x1fb50, 0x1fb51, 0x1fb52, 0x1fb53, 0x1fb54, 0x1fb55,
+    0x1fb56, 0x1fb57, 0x1fb58, 0x1fb59, 0x1fb5a, 0x1fb5b, 0x1fb5c, 0x1fb5d, 0x1fb5e, 0x1fb5f,
+    0x1fb60, 0x1fb61, 0x1fb62, 0x1fb63, 0x1fb64, 0x1fb65, 0x1fb66, 0x1fb67, 0x1fb68, 0x1fb69,
+    0x1fb6a, 0x1fb6b, 0x1fb6c, 0x1fb6d, 0x1fb6e, 0x1fb6f, 0x1fb70, 0x1fb71, 0x1fb72, 0x1fb73,
+    0x1fb74, 0x1fb75, 0x1fb76, 0x1fb77, 0x1fb78, 0x1fb79, 0x1fb7a, 0x1fb7b, 0x1fb7c, 0x1fb7d,
+    0x1fb7e, 0x1fb7f, 0x1fb80, 0x1fb81, 0x1fb82, 0x1fb83, 0x1fb84, 0x1fb85, 0x1fb86, 0x1fb87,
+    0x1fb88, 0x1fb89, 0x1fb8a, 0x1fb8b, 0x1fb8c, 0x1fb8d, 0x1fb8e, 0x1fb8f, 0x1fb90, 0x1fb91,
+    0x1fb92, 0x1fb94, 0x1fb95, 0x1fb96, 0x1fb97, 0x1fb98, 0x1fb99, 0x1fb9a, 0x1fb9b, 0x1fb9c,
+    0x1fb9d, 0x1fb9e, 0x1fb9f, 0x1fba0, 0x1fba1, 0x1fba2, 0x1fba3, 0x1fba4, 0x1fba5, 0x1fba6,
+    0x1fba7, 0x1fba8, 0x1fba9, 0x1fbaa, 0x1fbab, 0x1fbac, 0x1fbad, 0x1fbae, 0x1fbaf, 0x1fbb0,
+    0x1fbb1, 0x1fbb2, 0x1fbb3, 0x1fbb4, 0x1fbb5, 0x1fbb6, 0x1fbb7, 0x1fbb8, 0x1fbb9, 0x1fbba,
+    0x1fbbb, 0x1fbbc, 0x1fbbd, 0x1fbbe, 0x1fbbf, 0x1fbc0, 0x1fbc1, 0x1fbc2, 0x1fbc3, 0x1fbc4,
+    0x1fbc5, 0x1fbc6, 0x1fbc7, 0x1fbc8, 0x1fbc9, 0x1fbca, 0x1fbf0, 0x1fbf1, 0x1fbf2, 0x1fbf3,
+    0x1fbf4, 0x1fbf5, 0x1fbf6, 0x1fbf7, 0x1fbf8, 0x1fbf9, 0x2f800, 0x2f801, 0x2f802, 0x2f803,
+    0x2f804, 0x2f805, 0x2f806, 0x2f807, 0x2f808, 0x2f809, 0x2f80a, 0x2f80b, 0x2f80c, 0x2f80d,
+    0x2f80e, 0x2f80f, 0x2f810, 0x2f811, 0x2f812, 0x2f813, 0x2f814, 0x2f815, 0x2f816, 0x2f817,
+    0x2f818, 0x2f819, 0x2f81a, 0x2f81b, 0x2f81c, 0x2f81d, 0x2f81e, 0x2f81f, 0x2f820, 0x2f821,
+    0x2f822, 0x2f823, 0x2f824, 0x2f825, 0x2f826, 0x2f827, 0x2f828, 0x2f829, 0x2f82a, 0x2f82b,
+    0x2f82c, 0x2f82d, 0x2f82e, 0x2f82f, 0x2f830, 0x2f831, 0x2f832, 0x2f833, 0x2f834, 0x2f835,
+    0x2f836, 0x2f837, 0x2f838, 0x2f839, 0x2f83a, 0x2f83b, 0x2f83c, 0x2f83d, 0x2f83e, 0x2f83f,
+    0x2f840, 0x2f841, 0x2f842, 0x2f843, 0x2f844, 0x2f845, 0x2f846, 0x2f847, 0x2f848, 0x2f849,
+    0x2f84a, 0x2f84b, 0x2f84c, 0x2f84d, 0x2f84e, 0x2f84f, 0x2f850, 0x2f851, 0x2f852, 0x2f853,
+    0x2f854, 0x2f855, 0x2f856, 0x2f857, 0x2f858, 0x2f859, 0x2f85a, 0x2f85b, 0x2f85c, 0x2f85d,
+    0x2f85e, 0x2f85f, 0x2f860, 0x2f861, 0x2f862, 0x2f863, 0x2f864, 0x2f865, 0x2f866, 0x2f867,
+    0x2f868, 0x2f869, 0x2f86a, 0x2f86b, 0x2f86c, 0x2f86d, 0x2f86e, 0x2f86f, 0x2f870, 0x2f871,
+    0x2f872, 0x2f873, 0x2f874, 0x2f875, 0x2f876, 0x2f877, 0x2f878, 0x2f879, 0x2f87a, 0x2f87b,
+    0x2f87c, 0x2f87d, 0x2f87e, 0x2f87f, 0x2f880, 0x2f881, 0x2f882, 0x2f883, 0x2f884, 0x2f885,
+    0x2f886, 0x2f887, 0x2f888, 0x2f889, 0x2f88a, 0x2f88b, 0x2f88c, 0x2f88d, 0x2f88e, 0x2f88f,
+    0x2f890, 0x2f891, 0x2f892, 0x2f893, 0x2f894, 0x2f895, 0x2f896, 0x2f897, 0x2f898, 0x2f899,
+    0x2f89a, 0x2f89b, 0x2f89c, 0x2f89d, 0x2f89e, 0x2f89f, 0x2f8a0, 0x2f8a1, 0x2f8a2, 0x2f8a3,
+    0x2f8a4, 0x2f8a5, 0x2f8a6, 0x2f8a7, 0x2f8a8, 0x2f8a9, 0x2f8aa, 0x2f8ab, 0x2f8ac, 0x2f8ad,
+    0x2f8ae, 0x2f8af, 0x2f8b0, 0x2f8b1, 0x2f8b2, 0x2f8b3, 0x2f8b4, 0x2f8b5, 0x2f8b6, 0x2f8b7,
+    0x2f8b8, 0x2f8b9, 0x2f8ba, 0x2f8bb, 0x2f8bc, 0x2f8bd, 0x2f8be, 0x2f8bf, 0x2f8c0, 0x2f8c1,
+    0x2f8c2, 0x2f8c3, 0x2f8c4, 0x2f8c5, 0x2f8c6, 0x2f8c7, 0x2f8c8, 0x2f8c9, 0x2f8ca, 0x2f8cb,
+    0x2f8cc, 0x2f8cd, 0x2f8ce, 0x2f8cf, 0x2f8d0, 0x2f8d1, 0x2f8d2, 0x2f8d3, 0x2f8d4, 0x2f8d5,
+    0x2f8d6, 0x2f8d7, 0x2f8d8, 0x2f8d9, 0x2f8da, 0x2f8db, 0x2f8dc, 0x2f8dd, 0x2f8de, 0x2f8df,
+    0x2f8e0, 0x2f8e1, 0x2f8e2, 0x2f8e3, 0x2f8e4, 0x2f8e5, 0x2f8e6, 0x2f8e7, 0x2f8e8, 0x2f8e9,
+    0x2f8ea, 0x2f8eb, 0x2f8ec, 0x2f8ed, 0x2f8ee, 0x2f8ef, 0x2f8f0, 0x2f8f1, 0x2f8f2, 0x2f8f3,
+    0x2f8f4, 0x2f8f5, 0x2f8f6, 0x2f8f7, 0x2f8f8, 0x2f8f9, 0x2f8fa, 0x2f8fb, 0x2f8fc, 0x2f8fd,
+    0x2f8fe, 0x2f8ff, 0x2f900, 0x2f901, 0x2f902, 0x2f903, 0x2f904, 0x2f905, 0x2f906, 0x2f907,
+    0x2f908, 0x2f909, 0x2f90a, 0x2f90b, 0x2f90c, 0x2f90d, 0x2f90e, 0x2f90f, 0x2f910, 0x2f911,
+    0x2f912, 0x2f913, 0x2f914, 0x2f915, 0x2f916, 0x2f917, 0x2f918, 0x2f919, 0x2f91a, 0x2f91b,
+    0x2f91c, 0x2f91d, 0x2f91e, 0x2f91f, 0x2f920, 0x2f921, 0x2f922, 0x2f923, 0x2f924, 0x2f925,
+    0x2f926, 0x2f927, 0x2f928, 0x2f929, 0x2f92a, 0x2f92b, 0x2f92c, 0x2f92d, 0x2f92e, 0x2f92f,
+    0x2f930, 0x2f931, 0x2f932, 0x2f933, 0x2f934, 0x2f935, 0x2f936, 0x2f937, 0x2f938, 0x2f939,
+    0x2f93a, 0x2f93b, 0x2f93c, 0x2f93d, 0x2f93e, 0x2f93f, 0x2f940, 0x2f941, 0x2f942, 0x2f943,
+    0x2f944, 0x2f945, 0x2f946, 0x2f947, 0x2f948, 0x2f949, 0x2f94a, 0x2f94b, 0x2f94c, 0x2f94d,
+    0x2f94e, 0x2f94f, 0x2f950, 0x2f951, 0x2f952, 0x2f953, 0x2f954, 0x2f955, 0x2f956, 0x2f957,
+    0x2f958, 0x2f959, 0x2f95a, 0x2f95b, 0x2f95c, 0x2f95d, 0x2f95e, 0x2f95f, 0x2f960, 0x2f961,
+    0x2f962, 0x2f963, 0x2f964, 0x2f965, 0x2f966, 0x2f967, 0x2f968, 0x2f969, 0x2f96a, 0x2f96b,
+    0x2f96c, 0x2f96d, 0x2f96e, 0x2f96f, 0x2f970, 0x2f971, 0x2f972, 0x2f973, 0x2f974, 0x2f975,
+    0x2f976, 0x2f977, 0x2f978, 0x2f979, 0x2f97a, 0x2f97b, 0x2f97c, 0x2f97d, 0x2f97e, 0x2f97f,
+    0x2f980, 0x2f981, 0x2f982, 0x2f983, 0x2f984, 0x2f985, 0x2f986, 0x2f987, 0x2f988, 0x2f989,
+    0x2f98a, 0x2f98b, 0x2f98c, 0x2f98d, 0x2f98e, 0x2f98f, 0x2f990, 0x2f991, 0x2f992, 0x2f993,
+    0x2f994, 0x2f995, 0x2f996, 0x2f997, 0x2f998, 0x2f999, 0x2f99a, 0x2f99b, 0x2f99c, 0x2f99d,
+    0x2f99e, 0x2f99f, 0x2f9a0, 0x2f9a1, 0x2f9a2, 0x2f9a3, 0x2f9a4, 0x2f9a5, 0x2f9a6, 0x2f9a7,
+    0x2f9a8, 0x2f9a9, 0x2f9aa, 0x2f9ab, 0x2f9ac, 0x2f9ad, 0x2f9ae, 0x2f9af, 0x2f9b0, 0x2f9b1,
+    0x2f9b2, 0x2f9b3, 0x2f9b4, 0x2f9b5, 0x2f9b6, 0x2f9b7, 0x2f9b8, 0x2f9b9, 0x2f9ba, 0x2f9bb,
+    0x2f9bc, 0x2f9bd, 0x2f9be, 0x2f9bf, 0x2f9c0, 0x2f9c1, 0x2f9c2, 0x2f9c3, 0x2f9c4, 0x2f9c5,
+    0x2f9c6, 0x2f9c7, 0x2f9c8, 0x2f9c9, 0x2f9ca, 0x2f9cb, 0x2f9cc, 0x2f9cd, 0x2f9ce, 0x2f9cf,
+    0x2f9d0, 0x2f9d1, 0x2f9d2, 0x2f9d3, 0x2f9d4, 0x2f9d5, 0x2f9d6, 0x2f9d7, 0x2f9d8, 0x2f9d9,
+    0x2f9da, 0x2f9db, 0x2f9dc, 0x2f9dd, 0x2f9de, 0x2f9df, 0x2f9e0, 0x2f9e1, 0x2f9e2, 0x2f9e3,
+    0x2f9e4, 0x2f9e5, 0x2f9e6, 0x2f9e7, 0x2f9e8, 0x2f9e9, 0x2f9ea, 0x2f9eb, 0x2f9ec, 0x2f9ed,
+    0x2f9ee, 0x2f9ef, 0x2f9f0, 0x2f9f1, 0x2f9f2, 0x2f9f3, 0x2f9f4, 0x2f9f5, 0x2f9f6, 0x2f9f7,
+    0x2f9f8, 0x2f9f9, 0x2f9fa, 0x2f9fb, 0x2f9fc, 0x2f9fd, 0x2f9fe, 0x2f9ff, 0x2fa00, 0x2fa01,
+    0x2fa02, 0x2fa03, 0x2fa04, 0x2fa05, 0x2fa06, 0x2fa07, 0x2fa08, 0x2fa09, 0x2fa0a, 0x2fa0b,
+    0x2fa0c, 0x2fa0d, 0x2fa0e, 0x2fa0f, 0x2fa10, 0x2fa11, 0x2fa12, 0x2fa13, 0x2fa14, 0x2fa15,
+    0x2fa16, 0x2fa17, 0x2fa18, 0x2fa19, 0x2fa1a, 0x2fa1b, 0x2fa1c, 0x2fa1d, 0xe0001, 0xe0020,
+    0xe0021, 0xe0022, 0xe0023, 0xe0024, 0xe0025, 0xe0026, 0xe0027, 0xe0028, 0xe0029, 0xe002a,
+    0xe002b, 0xe002c, 0xe002d, 0xe002e, 0xe002f, 0xe0030, 0xe0031, 0xe0032, 0xe0033, 0xe0034,
+    0xe0035, 0xe0036, 0xe0037, 0xe0038, 0xe0039, 0xe003a, 0xe003b, 0xe003c, 0xe003d, 0xe003e,
+    0xe003f, 0xe0040, 0xe0041, 0xe0042, 0xe0043, 0xe0044, 0xe0045, 0xe0046, 0xe0047, 0xe0048,
+    0xe0049, 0xe004a, 0xe004b, 0xe004c, 0xe004d, 0xe004e, 0xe004f, 0xe0050, 0xe0051, 0xe0052,
+    0xe0053, 0xe0054, 0xe0055, 0xe0056, 0xe0057, 0xe0058, 0xe0059, 0xe005a, 0xe005b, 0xe005c,
+    0xe005d, 0xe005e, 0xe005f, 0xe0060, 0xe0061, 0xe0062, 0xe0063, 0xe0064, 0xe0065, 0xe0066,
+    0xe0067, 0xe0068, 0xe0069, 0xe006a, 0xe006b, 0xe006c, 0xe006d, 0xe006e, 0xe006f, 0xe0070,
+    0xe0071, 0xe0072, 0xe0073, 0xe0074, 0xe0075, 0xe0076, 0xe0077, 0xe0078, 0xe0079, 0xe007a,
+    0xe007b, 0xe007c, 0xe007d, 0xe007e, 0xe007f, 0xe0100, 0xe0101, 0xe0102, 0xe0103, 0xe0104,
+    0xe0105, 0xe0106, 0xe0107, 0xe0108, 0xe0109, 0xe010a, 0xe010b, 0xe010c, 0xe010d, 0xe010e,
+    0xe010f, 0xe0110, 0xe0111, 0xe0112, 0xe0113, 0xe0114, 0xe0115, 0xe0116, 0xe0117, 0xe0118,
+    0xe0119, 0xe011a, 0xe011b, 0xe011c, 0xe011d, 0xe011e, 0xe011f, 0xe0120, 0xe0121, 0xe0122,
+    0xe0123, 0xe0124, 0xe0125, 0xe0126, 0xe0127, 0xe0128, 0xe0129, 0xe012a, 0xe012b, 0xe012c,
+    0xe012d, 0xe012e, 0xe012f, 0xe0130, 0xe0131, 0xe0132, 0xe0133, 0xe0134, 0xe0135, 0xe0136,
+    0xe0137, 0xe0138, 0xe0139, 0xe013a, 0xe013b, 0xe013c, 0xe013d, 0xe013e, 0xe013f, 0xe0140,
+    0xe0141, 0xe0142, 0xe0143, 0xe0144, 0xe0145, 0xe0146, 0xe0147, 0xe0148, 0xe0149, 0xe014a,
+    0xe014b, 0xe014c, 0xe014d, 0xe014e, 0xe014f, 0xe0150, 0xe0151, 0xe0152, 0xe0153, 0xe0154,
+    0xe0155, 0xe0156, 0xe0157, 0xe0158, 0xe0159, 0xe015a, 0xe015b, 0xe015c, 0xe015d, 0xe015e,
+    0xe015f, 0xe0160, 0xe0161, 0xe0162, 0xe0163, 0xe0164, 0xe0165, 0xe0166, 0xe0167, 0xe0168,
+    0xe0169, 0xe016a, 0xe016b, 0xe016c, 0xe016d, 0xe016e, 0xe016f, 0xe0170, 0xe0171, 0xe0172,
+    0xe0173, 0xe0174, 0xe0175, 0xe0176, 0xe0177, 0xe0178, 0xe0179, 0xe017a, 0xe017b, 0xe017c,
+    0xe017d, 0xe017e, 0xe017f, 0xe0180, 0xe0181, 0xe0182, 0xe0183, 0xe0184, 0xe0185, 0xe0186,
+    0xe0187, 0xe0188, 0xe0189, 0xe018a, 0xe018b, 0xe018c, 0xe018d, 0xe018e, 0xe018f, 0xe0190,
+    0xe0191, 0xe0192, 0xe0193, 0xe0194, 0xe0195, 0xe0196, 0xe0197, 0xe0198, 0xe0199, 0xe019a,
+    0xe019b, 0xe019c, 0xe019d, 0xe019e, 0xe019f, 0xe01a0, 0xe01a1, 0xe01a2, 0xe01a3, 0xe01a4,
+    0xe01a5, 0xe01a6, 0xe01a7, 0xe01a8, 0xe01a9, 0xe01aa, 0xe01ab, 0xe01ac, 0xe01ad, 0xe01ae,
+    0xe01af, 0xe01b0, 0xe01b1, 0xe01b2, 0xe01b3, 0xe01b4, 0xe01b5, 0xe01b6, 0xe01b7, 0xe01b8,
+    0xe01b9, 0xe01ba, 0xe01bb, 0xe01bc, 0xe01bd, 0xe01be, 0xe01bf, 0xe01c0, 0xe01c1, 0xe01c2,
+    0xe01c3, 0xe01c4, 0xe01c5, 0xe01c6, 0xe01c7, 0xe01c8, 0xe01c9, 0xe01ca, 0xe01cb, 0xe01cc,
+    0xe01cd, 0xe01ce, 0xe01cf, 0xe01d0, 0xe01d1, 0xe01d2, 0xe01d3, 0xe01d4, 0xe01d5, 0xe01d6,
+    0xe01d7, 0xe01d8, 0xe01d9, 0xe01da, 0xe01db, 0xe01dc, 0xe01dd, 0xe01de, 0xe01df, 0xe01e0,
+    0xe01e1, 0xe01e2, 0xe01e3, 0xe01e4, 0xe01e5, 0xe01e6, 0xe01e7, 0xe01e8, 0xe01e9, 0xe01ea,
+    0xe01eb, 0xe01ec, 0xe01ed, 0xe01ee, 0xe01ef,
+];
+
+/// The collation elements of each of `CHARS`: one packed element, or with the top bit
+/// set, the start and the number of elements in `EXPANSIONS`
+pub const ELEMENTS: [u32; 32129] = [
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1008805, 0x1010805, 0x1018805, 0x1020805,
+    0x1028805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x1048805, 0x1338805, 0x18e8805, 0x1d60805, 0xfb20804, 0x1d68805, 0x1d48805, 0x18b0805,
+    0x1940805, 0x1948805, 0x1d08805, 0x3330805, 0x1118805, 0x1068805, 0x13f0805, 0x1d30805,
+    0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804,
+    0xfd00804, 0xfd08804, 0x1200805, 0x11d0805, 0x3350805, 0x3358805, 0x3360805, 0x1368805,
+    0x1d00805, 0xfd10810, 0xfde0810, 0xfeb0810, 0xff58810, 0x10038810, 0x10210810, 0x10288810,
+    0x103a8810, 0x10480810, 0x10558810, 0x10620810, 0x106b0810, 0x10848810, 0x108c0810, 0x109e0810,
+    0x10b58810, 0x10c00810, 0x10c98810, 0x10e90810, 0x10fb8810, 0x110b8810, 0x11238810, 0x112c8810,
+    0x11320810, 0x11380810, 0x11430810, 0x1950805, 0x1d38805, 0x1958805, 0x25b8805, 0x1058805,
+    0x25a0805, 0xfd10804, 0xfde0804, 0xfeb0804, 0xff58804, 0x10038804, 0x10210804, 0x10288804,
+    0x103a8804, 0x10480804, 0x10558804, 0x10620804, 0x106b0804, 0x10848804, 0x108c0804, 0x109e0804,
+    0x10b58804, 0x10c00804, 0x10c98804, 0x10e90804, 0x10fb8804, 0x110b8804, 0x11238804, 0x112c8804,
+    0x11320804, 0x11380804, 0x11430804, 0x1960805, 0x3370805, 0x1968805, 0x3380805, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x1030805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1048837, 0x1340805, 0xfb18804,
+    0xfb28804, 0xfb10804, 0xfb30804, 0x3378805, 0x1cd0805, 0x25d8805, 0x2e90805, 0xfd10828,
+    0x1930805, 0x3368805, 0x0, 0x2ea0805, 0x25c0805, 0x2950805, 0x3338805, 0xfcd0828, 0xfcd8828,
+    0x25a8805, 0x11980808, 0x1ce0805, 0x1498805, 0x25f0805, 0xfcc8828, 0x109e0828, 0x1938805,
+    0x80000003, 0x80000063, 0x800000c3, 0x1370805, 0x80000122, 0x80000162, 0x800001a2, 0x800001e2,
+    0x80000222, 0x80000262, 0x800002a3, 0x80000302, 0x80000342, 0x80000382, 0x800003c2, 0x80000402,
+    0x80000442, 0x80000482, 0x800004c2, 0x80000502, 0x80000542, 0x80000582, 0x800005c2, 0x80000602,
+    0x80000642, 0x80000682, 0x800006c2, 0x3348805, 0x80000702, 0x80000742, 0x80000782, 0x800007c2,
+    0x80000802, 0x80000842, 0x115a8810, 0x80000883, 0x800008e2, 0x80000922, 0x80000962, 0x800009a2,
+    0x800009e2, 0x80000a22, 0x80000a63, 0x80000ac2, 0x80000b02, 0x80000b42, 0x80000b82, 0x80000bc2,
+    0x80000c02, 0x80000c42, 0x80000c82, 0x80000cc2, 0x80000d02, 0x80000d42, 0x80000d82, 0x80000dc2,
+    0x80000e02, 0x80000e42, 0x80000e82, 0x3340805, 0x80000ec2, 0x80000f02, 0x80000f42, 0x80000f82,
+    0x80000fc2, 0x80001002, 0x115a8804, 0x80001042, 0x80001082, 0x800010c2, 0x80001102, 0x80001142,
+    0x80001182, 0x800011c2, 0x80001202, 0x80001242, 0x80001282, 0x800012c2, 0x80001302, 0x80001342,
+    0x80001382, 0x800013c2, 0x80001402, 0x80001442, 0x80001482, 0x800014c2, 0x80001502, 0x80001542,
+    0x80001582, 0x800015c2, 0x80001602, 0x80001642, 0x80001682, 0x800016c2, 0x80001702, 0x80001742,
+    0x80001782, 0x800017c2, 0x80001802, 0x80001842, 0x80001882, 0x800018c2, 0x80001902, 0x80001942,
+    0x80001982, 0x800019c2, 0x80001a02, 0x80001a42, 0x80001a82, 0x80001ac2, 0x80001b02, 0x80001b42,
+    0x80001b82, 0x80001bc2, 0x80001c02, 0x80001c42, 0x80001c82, 0x104a0804, 0x80001cc2, 0x80001d02,
+    0x80001d42, 0x80001d82, 0x80001dc2, 0x80001e02, 0x10c78804, 0x80001e42, 0x80001e82, 0x80001ec2,
+    0x80001f02, 0x80001f42, 0x80001f82, 0x80001fc2, 0x80002002, 0x80002042, 0x80002082, 0x800020c2,
+    0x80002102, 0x80002142, 0x80002182, 0x800021c2, 0x80002202, 0x80002242, 0x109b8810, 0x109b8804,
+    0x80002282, 0x800022c2, 0x80002302, 0x80002342, 0x80002382, 0x800023c2, 0x80002403, 0x80002463,
+    0x800024c2, 0x80002502, 0x80002542, 0x80002582, 0x800025c2, 0x80002602, 0x80002642, 0x80002682,
+    0x800026c2, 0x80002702, 0x80002742, 0x80002782, 0x800027c2, 0x80002802, 0x80002842, 0x80002882,
+    0x800028c2, 0x80002902, 0x10fe0810, 0x10fe0804, 0x80002942, 0x80002982, 0x800029c2, 0x80002a02,
+    0x80002a42, 0x80002a82, 0x80002ac2, 0x80002b02, 0x80002b42, 0x80002b82, 0x80002bc2, 0x80002c02,
+    0x80002c42, 0x80002c82, 0x80002cc2, 0x80002d02, 0x80002d42, 0x80002d82, 0x80002dc2, 0x80002e02,
+    0x80002e42, 0x80002e82, 0x80002ec2, 0x80002f02, 0xfe20804, 0xfe68810, 0xfe88810, 0xfe88804,
+    0x116b0810, 0x116b0804, 0x10a78810, 0xff08810, 0xff08804, 0xffa0810, 0xffc0810, 0xffe8810,
+    0xffe8804, 0x80002f42, 0x100a8810, 0x100d0810, 0x100f8810, 0x10258810, 0x10258804, 0x10318810,
+    0x10368810, 0x103e8804, 0x10530810, 0x104f8810, 0x10650810, 0x10650804, 0x10708804, 0x10808804,
+    0x111c0810, 0x10918810, 0x10938804, 0x10ae0810, 0x80002f82, 0x80002fc2, 0x10388810, 0x10388804,
+    0x10ba0810, 0x10ba0804, 0x10cc0810, 0x11670810, 0x11670804, 0x10f20810, 0x10f50804, 0x11010804,
+    0x11030810, 0x11030804, 0x11050810, 0x80003002, 0x80003042, 0x11210810, 0x11270810, 0x113e0810,
+    0x113e0804, 0x11458810, 0x11458804, 0x11518810, 0x11540810, 0x11540804, 0x11568804, 0x11638804,
+    0x11690810, 0x11690804, 0x80003082, 0x115d8804, 0x117f0804, 0x11810804, 0x11830804, 0x11850804,
+    0x800030c3, 0x80003123, 0x80003183, 0x800031e2, 0x80003222, 0x80003262, 0x800032a2, 0x800032e2,
+    0x80003322, 0x80003362, 0x800033a2, 0x800033e2, 0x80003422, 0x80003462, 0x800034a2, 0x800034e2,
+    0x80003522, 0x80003563, 0x800035c3, 0x80003623, 0x80003683, 0x800036e3, 0x80003743, 0x800037a3,
+    0x80003803, 0x100a8804, 0x80003863, 0x800038c3, 0x80003923, 0x80003983, 0x800039e4, 0x80003a64,
+    0x102f0810, 0x102f0804, 0x80003ae2, 0x80003b22, 0x80003b62, 0x80003ba2, 0x80003be2, 0x80003c22,
+    0x80003c63, 0x80003cc3, 0x80003d22, 0x80003d62, 0x80003da2, 0x80003de2, 0x80003e22, 0x80003e62,
+    0x80003ea2, 0x80003ee2, 0x103e8810, 0x115d8810, 0x80003f22, 0x80003f62, 0x80003fa3, 0x80004003,
+    0x80004064, 0x800040e4, 0x80004163, 0x800041c3, 0x80004222, 0x80004262, 0x800042a2, 0x800042e2,
+    0x80004322, 0x80004362, 0x800043a2, 0x800043e2, 0x80004422, 0x80004462, 0x800044a2, 0x800044e2,
+    0x80004522, 0x80004562, 0x800045a2, 0x800045e2, 0x80004622, 0x80004662, 0x800046a2, 0x800046e2,
+    0x80004722, 0x80004762, 0x800047a2, 0x800047e2, 0x80004822, 0x80004862, 0x800048a2, 0x800048e2,
+    0x11410810, 0x11410804, 0x80004922, 0x80004962, 0x10938810, 0x10008804, 0x10b30810, 0x10b30804,
+    0x11488810, 0x11488804, 0x800049a2, 0x800049e2, 0x80004a22, 0x80004a62, 0x80004aa3, 0x80004b03,
+    0x80004b63, 0x80004bc3, 0x80004c22, 0x80004c62, 0x80004ca3, 0x80004d03, 0x80004d62, 0x80004da2,
+    0x107b8804, 0x10988804, 0x11070804, 0x10578804, 0x80004de2, 0x80004e22, 0xfd38810, 0xfed8810,
+    0xfed8804, 0x10708810, 0x11000810, 0x10ef0804, 0x114e8804, 0x116f0810, 0x116f0804, 0xfe20810,
+    0x11110810, 0x112a8810, 0x10070810, 0x10070804, 0x105a0810, 0x105a0804, 0x10c58810, 0x10c58804,
+    0x10cf8810, 0x10cf8804, 0x113c0810, 0x113c0804, 0xfd68804, 0xfd88804, 0xfdb8804, 0xfe68804,
+    0x10a78804, 0xff28804, 0xffa0804, 0xffc0804, 0x10120804, 0x100d0804, 0x10140804, 0x100f8804,
+    0x10160804, 0x10190804, 0x101b0804, 0x105e0804, 0x10318804, 0x102a8804, 0x102d0804, 0x10368804,
+    0x101f0804, 0x11160804, 0x10410804, 0x10450804, 0x104f8804, 0x10530804, 0x104c0804, 0x10730804,
+    0x10760804, 0x10790804, 0x107e0804, 0x111c0804, 0x111f0804, 0x10880804, 0x10918804, 0x10968804,
+    0x108e0804, 0x10ae0804, 0x10a18804, 0x10b08804, 0x10bd8804, 0x10d20804, 0x10d48804, 0x10d70804,
+    0x10d98804, 0x10db8804, 0x10de0804, 0x10e08804, 0x10cc0804, 0x10e50804, 0x10ed0804, 0x10f20804,
+    0x10600804, 0x10f70804, 0x10f98804, 0x11098804, 0x11050804, 0x11110804, 0x11210804, 0x11270804,
+    0x112a8804, 0x11300804, 0x10828804, 0x113a0804, 0x114a8804, 0x114c8804, 0x11518804, 0x11588804,
+    0x116d0804, 0x11748804, 0x117d0804, 0x11870804, 0x11890804, 0xfe00804, 0x101d0804, 0x10338804,
+    0x103c8804, 0x105c0804, 0x10690804, 0x106d0804, 0x10c38804, 0x11790804, 0x117b0804, 0x80004e62,
+    0x80004ea2, 0x80004ee2, 0x80004f22, 0x80004f62, 0x80004fa2, 0x80004fe2, 0x80005022, 0x80005062,
+    0x118b0804, 0x118d0804, 0x11180804, 0x111a0804, 0x103a8828, 0x10410828, 0x10558828, 0x10c98828,
+    0x10d20828, 0x10d70828, 0x10e50828, 0x112c8828, 0x11380828, 0x2628805, 0x2638805, 0x10470804,
+    0x11718804, 0x10478804, 0x11728804, 0x11768804, 0x11710804, 0x11770804, 0x2640805, 0x2648805,
+    0x2650805, 0x2658805, 0x2660805, 0x2668805, 0x2670805, 0x2678805, 0x2680805, 0x2688805,
+    0x2690805, 0x2698805, 0x26a0805, 0x26a8805, 0xfa30804, 0xfa38804, 0x26b0805, 0x26b8805,
+    0x26c0805, 0x26c8805, 0x26e0805, 0x26e8805, 0x25c8805, 0x25d0805, 0x25e0805, 0x25f8805,
+    0x25b0805, 0x25e8805, 0x26f0805, 0x26f8805, 0x10368828, 0x106b0828, 0x10e90828, 0x11320828,
+    0x11748828, 0x2700805, 0x2708805, 0x2710805, 0x2718805, 0x2720805, 0x2728805, 0x2730805,
+    0x2738805, 0x2740805, 0x11720804, 0x2748805, 0x2750805, 0x2758805, 0x2760805, 0x2768805,
+    0x2770805, 0x2778805, 0x2780805, 0x2788805, 0x2790805, 0x2798805, 0x27a0805, 0x27a8805,
+    0x27b0805, 0x27b8805, 0x27c0805, 0x27c8805, 0x944, 0x904, 0x9c4, 0xb44, 0xc84, 0xe84, 0x984,
+    0xb84, 0xac4, 0xec4, 0xa44, 0xb04, 0xa04, 0xcc4, 0xcc4, 0xf04, 0xf44, 0xf84, 0xcc4, 0x884,
+    0x8c4, 0xcc4, 0xd04, 0xd04, 0xd04, 0xd04, 0xcc4, 0xfc4, 0xd04, 0xd04, 0xd04, 0xd04, 0xd04,
+    0x1004, 0x1044, 0x1084, 0x10c4, 0x1104, 0x1144, 0xc04, 0xc44, 0xd04, 0xd04, 0xd04, 0xd04,
+    0x1184, 0x11c4, 0xd04, 0x1204, 0x1244, 0x844, 0xd04, 0x1284, 0xe44, 0xd44, 0xd44, 0xbc4,
+    0x12c4, 0xd04, 0xd04, 0xd04, 0xcc4, 0xcc4, 0xcc4, 0x944, 0x904, 0xa84, 0x884, 0x800050a2,
+    0x1304, 0xcc4, 0xd04, 0xd04, 0xd04, 0xcc4, 0xcc4, 0xcc4, 0xd04, 0xd04, 0x0, 0xcc4, 0xcc4,
+    0xcc4, 0xd04, 0xd04, 0xd04, 0xd04, 0xcc4, 0x1344, 0xd04, 0xd04, 0xcc4, 0xd04, 0xcc4, 0xcc4,
+    0xd04, 0x1384, 0x13c4, 0xd04, 0xfd10808, 0x10038808, 0x10480808, 0x109e0808, 0x110b8808,
+    0xfeb0808, 0xff58808, 0x103a8808, 0x10848808, 0x10c98808, 0x10fb8808, 0x11238808, 0x11320808,
+    0x11940810, 0x11940804, 0x11a48810, 0x11a48804, 0x2628805, 0x2630805, 0x11928810, 0x11928804,
+    0x11958808, 0x119f0804, 0x119e8804, 0x119f8804, 0x11d0805, 0x11960810, 0x25a8805, 0x800050e2,
+    0x80005122, 0x1498805, 0x80005162, 0x800051a2, 0x800051e2, 0x80005222, 0x80005262, 0x800052a2,
+    0x800052e3, 0x118f0810, 0x118f8810, 0x11900810, 0x11910810, 0x11918810, 0x11938810, 0x11948810,
+    0x11950810, 0x11958810, 0x11968810, 0x11970810, 0x11980810, 0x11988810, 0x11990810, 0x11998810,
+    0x119a0810, 0x119c8810, 0x119e0810, 0x11a00810, 0x11a08810, 0x11a10810, 0x11a18810, 0x11a20810,
+    0x11a30810, 0x80005342, 0x80005382, 0x800053c2, 0x80005402, 0x80005442, 0x80005482, 0x800054c3,
+    0x118f0804, 0x118f8804, 0x11900804, 0x11910804, 0x11918804, 0x11938804, 0x11948804, 0x11950804,
+    0x11958804, 0x11968804, 0x11970804, 0x11980804, 0x11988804, 0x11990804, 0x11998804, 0x119a0804,
+    0x119c8804, 0x119e0832, 0x119e0804, 0x11a00804, 0x11a08804, 0x11a10804, 0x11a18804, 0x11a20804,
+    0x11a30804, 0x80005522, 0x80005562, 0x800055a2, 0x800055e2, 0x80005622, 0x80005663, 0x118f8808,
+    0x11950808, 0x11a08814, 0x800056c2, 0x80005702, 0x11a10808, 0x119a0808, 0x80005743, 0x119c0810,
+    0x119c0804, 0x11930810, 0x11930804, 0x11920810, 0x11920804, 0x119b8810, 0x119b8804, 0x11a40810,
+    0x11a40804, 0x11b50810, 0x11b50804, 0x11b78810, 0x11b78804, 0x11b80810, 0x11b80804, 0x11b98810,
+    0x11b98804, 0x11bd0810, 0x11bd0804, 0x11be8810, 0x11be8804, 0x11c08810, 0x11c08804, 0x11968808,
+    0x119c8808, 0x119e0808, 0x11960804, 0x11950814, 0x11918808, 0x3308805, 0x11a50810, 0x11a50804,
+    0x119e0814, 0x119b0810, 0x119b0804, 0x119d8804, 0x119f0810, 0x119e8810, 0x119f8810, 0x800057a2,
+    0x800057e2, 0x11da8810, 0x80005822, 0x11e18810, 0x11ec8810, 0x11f68810, 0x80005862, 0x11fb0810,
+    0x12108810, 0x12238810, 0x123f0810, 0x800058a2, 0x800058e2, 0x80005922, 0x12720810, 0x11c38810,
+    0x11c98810, 0x11cb8810, 0x11cd8810, 0x11d78810, 0x11df8810, 0x11e38810, 0x11e88810, 0x11f28810,
+    0x11f90810, 0x11fd8810, 0x120a8810, 0x12140810, 0x12188810, 0x12260810, 0x122a0810, 0x12308810,
+    0x12350810, 0x12398810, 0x12410810, 0x12498810, 0x124b8810, 0x125f0810, 0x12648810, 0x12740810,
+    0x12768810, 0x127a0810, 0x127c8810, 0x127e8810, 0x12850810, 0x12870810, 0x128a0810, 0x11c38804,
+    0x11c98804, 0x11cb8804, 0x11cd8804, 0x11d78804, 0x11df8804, 0x11e38804, 0x11e88804, 0x11f28804,
+    0x11f90804, 0x11fd8804, 0x120a8804, 0x12140804, 0x12188804, 0x12260804, 0x122a0804, 0x12308804,
+    0x12350804, 0x12398804, 0x12410804, 0x12498804, 0x124b8804, 0x125f0804, 0x12648804, 0x12740804,
+    0x12768804, 0x127a0804, 0x127c8804, 0x127e8804, 0x12850804, 0x12870804, 0x128a0804, 0x80005962,
+    0x800059a2, 0x11da8804, 0x800059e2, 0x11e18804, 0x11ec8804, 0x11f68804, 0x80005a22, 0x11fb0804,
+    0x12108804, 0x12238804, 0x123f0804, 0x80005a62, 0x80005aa2, 0x80005ae2, 0x12720804, 0x12568810,
+    0x12568804, 0x12828810, 0x12828804, 0x128c8810, 0x128c8804, 0x128e8810, 0x128e8804, 0x12938810,
+    0x12938804, 0x12910810, 0x12910804, 0x12960810, 0x12960804, 0x12980810, 0x12980804, 0x129a0810,
+    0x129a0804, 0x129c0810, 0x129c0804, 0x129e0810, 0x129e0804, 0x80005b22, 0x80005b62, 0x12478810,
+    0x12478804, 0x125d0810, 0x125d0804, 0x125b0810, 0x125b0804, 0x12588810, 0x12588804, 0x122e8810,
+    0x122e8804, 0x2958805, 0x1404, 0xcc4, 0x8c4, 0x884, 0xcc4, 0x0, 0x0, 0x11f48810, 0x11f48804,
+    0x12808810, 0x12808804, 0x12328810, 0x12328804, 0x80005ba2, 0x80005be2, 0x11cf8810, 0x11cf8804,
+    0x11d38810, 0x11d38804, 0x11e68810, 0x11e68804, 0x11dd8810, 0x11dd8804, 0x11ff8810, 0x11ff8804,
+    0x12078810, 0x12078804, 0x12058810, 0x12058804, 0x12038810, 0x12038804, 0x121d0810, 0x121d0804,
+    0x12218810, 0x12218804, 0x122c8810, 0x122c8804, 0x12a08810, 0x12a08804, 0x12378810, 0x12378804,
+    0x123c8810, 0x123c8804, 0x12430810, 0x12430804, 0x12450810, 0x12450804, 0x12518810, 0x12518804,
+    0x12620810, 0x12620804, 0x12678810, 0x12678804, 0x126b8810, 0x126b8804, 0x12538810, 0x12538804,
+    0x126e0810, 0x126e0804, 0x12700810, 0x12700804, 0x12a30810, 0x80005c22, 0x80005c62, 0x12018810,
+    0x12018804, 0x120d0810, 0x120d0804, 0x121f0810, 0x121f0804, 0x121b0810, 0x121b0804, 0x12698810,
+    0x12698804, 0x12160810, 0x12160804, 0x12a30804, 0x80005ca2, 0x80005ce2, 0x80005d22, 0x80005d62,
+    0x11c78810, 0x11c78804, 0x80005da2, 0x80005de2, 0x11c58810, 0x11c58804, 0x80005e22, 0x80005e62,
+    0x80005ea2, 0x80005ee2, 0x80005f22, 0x80005f62, 0x11ef0810, 0x11ef0804, 0x80005fa2, 0x80005fe2,
+    0x80006022, 0x80006062, 0x800060a2, 0x800060e2, 0x12280810, 0x12280804, 0x80006122, 0x80006162,
+    0x800061a2, 0x800061e2, 0x80006222, 0x80006262, 0x800062a2, 0x800062e2, 0x80006322, 0x80006362,
+    0x800063a2, 0x800063e2, 0x11d58810, 0x11d58804, 0x80006422, 0x80006462, 0x11d18810, 0x11d18804,
+    0x124d8810, 0x124d8804, 0x124f8810, 0x124f8804, 0x11d98810, 0x11d98804, 0x11dd0810, 0x11dd0804,
+    0x11eb0810, 0x11eb0804, 0x11f18810, 0x11f18804, 0x12130810, 0x12130804, 0x12258810, 0x12258804,
+    0x12370810, 0x12370804, 0x123c0810, 0x123c0804, 0x11eb8810, 0x11eb8804, 0x120f8810, 0x120f8804,
+    0x12138810, 0x12138804, 0x12348810, 0x12348804, 0x128c0810, 0x128c0804, 0x120a0810, 0x120a0804,
+    0x12a28810, 0x12a28804, 0x12098810, 0x12098804, 0x12100810, 0x12100804, 0x12210810, 0x12210804,
+    0x122c0810, 0x122c0804, 0x12558810, 0x12558804, 0x121a8810, 0x121a8804, 0x11e58810, 0x11e58804,
+    0x12668810, 0x12668804, 0x120f0810, 0x120f0804, 0x12fa8810, 0x12fb8810, 0x12fc0810, 0x12fc8810,
+    0x12fd0810, 0x12fd8810, 0x12fe0810, 0x12fe8810, 0x12ff0810, 0x12ff8810, 0x13000810, 0x13008810,
+    0x13010810, 0x13018810, 0x13020810, 0x13028810, 0x13030810, 0x13038810, 0x13040810, 0x13048810,
+    0x13050810, 0x13060810, 0x13068810, 0x13070810, 0x13078810, 0x13080810, 0x13088810, 0x13090810,
+    0x13098810, 0x130a0810, 0x130a8810, 0x130b0810, 0x130b8810, 0x130c0810, 0x130c8810, 0x130d0810,
+    0x130d8810, 0x130e0810, 0x130e8804, 0x1f20805, 0x1f28805, 0x1348805, 0x1150805, 0x1380805,
+    0x1f30805, 0x12fb0804, 0x12fa8804, 0x12fb8804, 0x12fc0804, 0x12fc8804, 0x12fd0804, 0x12fd8804,
+    0x12fe0804, 0x12fe8804, 0x12ff0804, 0x12ff8804, 0x13000804, 0x13008804, 0x13010804, 0x13018804,
+    0x13020804, 0x13028804, 0x13030804, 0x13038804, 0x13040804, 0x13048804, 0x13050804, 0x13060804,
+    0x13068804, 0x13070804, 0x13078804, 0x13080804, 0x13088804, 0x13090804, 0x13098804, 0x130a0804,
+    0x130a8804, 0x130b0804, 0x130b8804, 0x130c0804, 0x130c8804, 0x130d0804, 0x130d8804, 0x130e0804,
+    0x800064a2, 0x13058804, 0x1208805, 0x1070805, 0x2960805, 0x2968805, 0xfb38804, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1484, 0x14c4, 0x1504, 0x1544, 0x1584, 0x15c4,
+    0x1604, 0x1644, 0x1684, 0x16c4, 0x16c4, 0x1704, 0x17c4, 0x0, 0x1f38805, 0x1804, 0x1f40805,
+    0x1784, 0x1744, 0x1f48805, 0x0, 0x0, 0x1f50805, 0x1684, 0x130f0804, 0x130f8804, 0x13100804,
+    0x13108804, 0x13110804, 0x13118804, 0x13120804, 0x13128804, 0x13130804, 0x13138804, 0x13140832,
+    0x13140804, 0x13148804, 0x13150832, 0x13150804, 0x13158832, 0x13158804, 0x13160804, 0x13168804,
+    0x13170832, 0x13170804, 0x13178832, 0x13178804, 0x13180804, 0x13188804, 0x13190804, 0x13198804,
+    0x800064e4, 0x80006562, 0x800065a2, 0x800065e2, 0x1f58805, 0x1f60805, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x33e8805, 0x33f8805, 0x2970805, 0x1d80805, 0x1d90805, 0xfb40804, 0x1158805, 0x1160805,
+    0x2988805, 0x2990805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x11d8805, 0x0,
+    0x1210805, 0x1388805, 0x13a00804, 0x13320804, 0x13328804, 0x13330804, 0x13348804, 0x13350804,
+    0x13370804, 0x13390804, 0x133a0804, 0x13420804, 0x13428804, 0x13430804, 0x13478804, 0x134c8804,
+    0x134d0804, 0x13520804, 0x13528804, 0x13598804, 0x135a0804, 0x13630804, 0x13638804, 0x13688804,
+    0x13690804, 0x136b8804, 0x136c0804, 0x136d8804, 0x136e0804, 0x13838804, 0x13840804, 0x139e8804,
+    0x139f0804, 0x139f8804, 0x0, 0x13720804, 0x13780804, 0x137b0804, 0x13860804, 0x138a0804,
+    0x138c0804, 0x13910804, 0x13940804, 0x139b0804, 0x139b8804, 0x1b44, 0x1c04, 0x1cc4, 0x1d84,
+    0x1e84, 0x1f44, 0x2004, 0x2044, 0x2084, 0x20c4, 0x2104, 0x2184, 0x21c4, 0x2204, 0x2284, 0x22c4,
+    0x2304, 0x2344, 0x2384, 0x23c4, 0x2144, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1d70805, 0x1168805, 0x1170805,
+    0x1d20805, 0x13398804, 0x13778804, 0x2604, 0x13340804, 0x13338804, 0x13358804, 0x13320808,
+    0x80006622, 0x80006662, 0x800066a2, 0x800066e2, 0x13438804, 0x13440804, 0x133a8804, 0x13448804,
+    0x13450804, 0x133b0804, 0x13458804, 0x133b8804, 0x134d8804, 0x134e0804, 0x13480804, 0x13488804,
+    0x134e8804, 0x13490804, 0x134a0804, 0x13530804, 0x13538804, 0x13540804, 0x13548804, 0x13550804,
+    0x13558804, 0x13568804, 0x13570804, 0x13578804, 0x135a8804, 0x135b0804, 0x135b8804, 0x135c0804,
+    0x135c8804, 0x135d0804, 0x135d8804, 0x135e0804, 0x135e8804, 0x13640804, 0x13648804, 0x13650804,
+    0x13698804, 0x136a8804, 0x136c8804, 0x136e8804, 0x13728804, 0x13730804, 0x13740804, 0x13748804,
+    0x13758804, 0x13760804, 0x13788804, 0x13798804, 0x137b8804, 0x137c0804, 0x137c8804, 0x137d0804,
+    0x137e0804, 0x137e8804, 0x137f8804, 0x13808804, 0x13810804, 0x13818804, 0x13820804, 0x13828804,
+    0x13868804, 0x13870804, 0x13878804, 0x13880804, 0x138f0804, 0x138c8804, 0x138d8804, 0x138e0804,
+    0x138e8804, 0x13918804, 0x13498804, 0x80006722, 0x13920804, 0x80006762, 0x13928804, 0x13948804,
+    0x13950804, 0x13958804, 0x13960804, 0x13968804, 0x13970804, 0x13978804, 0x13980804, 0x139c0804,
+    0x139c8804, 0x139d0804, 0x13990804, 0x139d8804, 0x139e0804, 0x13a28804, 0x800067a2, 0x1400805,
+    0x13938804, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2998805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x13940808, 0x139b8808, 0x0, 0x0, 0x29a0805, 0x0, 0x0, 0x0, 0x0, 0x13580804, 0x135f0804,
+    0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804,
+    0xfd00804, 0xfd08804, 0x13658804, 0x136b0804, 0x136f8804, 0x800067e2, 0x80006822, 0x13930804,
+    0x1668805, 0x1408805, 0x1410805, 0x1218805, 0x1220805, 0x1228805, 0x1230805, 0x1238805,
+    0x1240805, 0x1390805, 0x1f68805, 0x1f70805, 0x1f78805, 0x1f80805, 0x0, 0x13a40804, 0x2644,
+    0x13a48804, 0x13a50804, 0x80006862, 0x13a60804, 0x13a58804, 0x13a68804, 0x13a70804, 0x13a78804,
+    0x13a88804, 0x13a90804, 0x800068a2, 0x13a98804, 0x13aa0804, 0x13aa8804, 0x13ab8804, 0x13ac0804,
+    0x13ac8804, 0x13ad0804, 0x13ad0832, 0x13ad8804, 0x13ae0804, 0x800068e2, 0x13af0804, 0x13af8804,
+    0x13b00804, 0x13b08804, 0x13b10804, 0x80006922, 0x80006962, 0x800069a2, 0x2684, 0x26c4, 0x2704,
+    0x2744, 0x2784, 0x27c4, 0x2804, 0x2844, 0x2884, 0x28c4, 0x2904, 0x2944, 0x2984, 0x29c4, 0x2a04,
+    0x2a44, 0x0, 0xcc4, 0xd04, 0x0, 0x0, 0xcc4, 0xd04, 0x0, 0x0, 0x0, 0x0, 0x13a80804, 0x13ab0804,
+    0x13ae8804, 0x133c0804, 0x133c8804, 0x133d0804, 0x133d8804, 0x133e0804, 0x133e8804, 0x133f8804,
+    0x134f0804, 0x134f8804, 0x13588804, 0x13590804, 0x135f8804, 0x13660804, 0x13700804, 0x13708804,
+    0x13710804, 0x13768804, 0x13770804, 0x13830804, 0x13848804, 0x13850804, 0x138a8804, 0x138b0804,
+    0x138f8804, 0x13900804, 0x13908804, 0x13888804, 0x13600804, 0x13608804, 0x13668804, 0x13500804,
+    0x13508804, 0x13670804, 0x13610804, 0x13510804, 0x13360804, 0x13368804, 0x13a08804, 0x13a10804,
+    0x13a18804, 0x13998804, 0x139a0804, 0x13a30804, 0x13a38804, 0x13518804, 0x13678804, 0x13680804,
+    0x137d8804, 0x13c38804, 0x13c50804, 0x13c58804, 0x13c60804, 0x13c70804, 0x13c78804, 0x13c80804,
+    0x13c88804, 0x13ca0804, 0x13cb0804, 0x13cb8804, 0x13cc0804, 0x13cd0804, 0x13cf0804, 0x13cf8804,
+    0x13d08804, 0x13d10804, 0x13d30804, 0x13d38804, 0x13d40804, 0x13d48804, 0x13d50804, 0x13d58804,
+    0x13d60804, 0x13cd8804, 0x13c40804, 0x13c48804, 0x13cc8804, 0x13c68804, 0x13d18804, 0x13d20804,
+    0x13d28804, 0x13ce0804, 0x13ce8804, 0x13c90804, 0x13c98804, 0x13d00804, 0x13ca8804, 0x13d70804,
+    0x13d78804, 0x13d80804, 0x13d88804, 0x13d90804, 0x13d98804, 0x13da0804, 0x13da8804, 0x13db0804,
+    0x13db8804, 0x13dc0804, 0x13d68804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x13dc8804, 0x13dd0804, 0x13dd8804,
+    0x13de0804, 0x13de8804, 0x13df0804, 0x13df8804, 0x13e00804, 0x13e08804, 0x13e10804, 0x13e18804,
+    0x13e20804, 0x13e28804, 0x13e30804, 0x13e38804, 0x13e40804, 0x13e48804, 0x13e50804, 0x13e58804,
+    0x13e60804, 0x13e68804, 0x13e70804, 0x13e78804, 0x13e80804, 0x13e88804, 0x13e90804, 0x13e98804,
+    0x13ea0804, 0x13ea8804, 0x13eb0804, 0x800069e2, 0x80006a22, 0x80006a62, 0x2a84, 0x2ac4, 0x2b04,
+    0x2b44, 0x2b84, 0x2bc4, 0x2c04, 0x2c44, 0x2c84, 0x13eb8804, 0x13ec0804, 0x2a30805, 0x1670805,
+    0x1178805, 0x1350805, 0x0, 0xd04, 0xfb48804, 0xfb50804, 0x13250804, 0x13258804, 0x13260804,
+    0x13268804, 0x13270804, 0x13278804, 0x13280804, 0x13288804, 0x13290804, 0x13298804, 0x132a0804,
+    0x132a8804, 0x132b0804, 0x132b8804, 0x132c0804, 0x132c8804, 0x132d0804, 0x132d8804, 0x132e0804,
+    0x132e8804, 0x132f0804, 0x132f8804, 0x13300804, 0x13308804, 0x1a84, 0x1ac4, 0x13310804,
+    0x13318804, 0x1884, 0x1884, 0x18c4, 0x18c4, 0x18c4, 0x1904, 0x1904, 0x1904, 0x1944, 0x1944,
+    0x1984, 0x1984, 0x19c4, 0x19c4, 0x19c4, 0x1a04, 0x1a44, 0x1b04, 0x1248805, 0x1250805,
+    0x1258805, 0x1260805, 0x1268805, 0x1270805, 0x1278805, 0x1280805, 0x1288805, 0x1290805,
+    0x1298805, 0x12a0805, 0x12a8805, 0x12b0805, 0x12b8805, 0x13b70804, 0x13b78804, 0x13b80804,
+    0x13b88804, 0x13b90804, 0x13b98804, 0x13ba0804, 0x13ba8804, 0x13bb0804, 0x13bb8804, 0x13bc0804,
+    0x13bc8804, 0x13bd0804, 0x13bd8804, 0x13be0804, 0x13be8804, 0x13bf0804, 0x13bf8804, 0x13c00804,
+    0x13c08804, 0x13c10804, 0x13c18804, 0x13c20804, 0x13c28804, 0x13c30804, 0xd04, 0xd04, 0xd04,
+    0x1f88805, 0x13b18804, 0x13b20804, 0x13b28804, 0x13b30804, 0x13b38804, 0x13b40804, 0x13b48804,
+    0x13b50804, 0x13b58804, 0x13b60804, 0x13b68804, 0x133f0804, 0x13408804, 0x134a8804, 0x136d0804,
+    0x13750804, 0x137a8804, 0x13890804, 0x138b8804, 0x13378804, 0x13380804, 0x13618804, 0x139a8804,
+    0x13388804, 0x13390808, 0x13560804, 0x136a0804, 0x13800804, 0x13988804, 0x13620804, 0x13718804,
+    0x137f0804, 0x13410804, 0x13418804, 0x13460804, 0x13628804, 0x13a20804, 0x13738804, 0x13790804,
+    0x138d0804, 0x13400804, 0x13468804, 0x13470804, 0x134b0804, 0x13858804, 0x136f0804, 0x137a0804,
+    0x134b8804, 0x134c0804, 0x13898804, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x2404, 0x1dc4, 0x1ec4, 0x1f84, 0x1bc4, 0x1c84, 0x1d44, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x1b84, 0x1c44, 0x1d04, 0x0, 0x1e04, 0x1e44, 0x1fc4, 0x2444, 0x2484, 0x2584, 0x25c4,
+    0x2504, 0x2544, 0x24c4, 0x1f04, 0x2244, 0x30c4, 0x30c4, 0x3104, 0x3144, 0x14ed0804, 0x14ed8804,
+    0x14ee0804, 0x14f10804, 0x14f18804, 0x14f20804, 0x14f28804, 0x14f30804, 0x14f40804, 0x14f50804,
+    0x14f58804, 0x14f60804, 0x14f68804, 0x14f78804, 0x14f80804, 0x14f88804, 0x14f90804, 0x14f98804,
+    0x14fa0804, 0x14fa8804, 0x14fb8804, 0x14fc0804, 0x14fc8804, 0x14fd0804, 0x14fd8804, 0x14ff0804,
+    0x14ff8804, 0x15000804, 0x15008804, 0x15018804, 0x15028804, 0x15030804, 0x15038804, 0x15040804,
+    0x15048804, 0x15050804, 0x15058804, 0x80006aa2, 0x15060804, 0x15068804, 0x15070804, 0x15080804,
+    0x15088804, 0x15090804, 0x150a0804, 0x80006ae2, 0x150a8804, 0x150b0804, 0x80006b22, 0x150b8804,
+    0x150c0804, 0x150c8804, 0x150d0804, 0x150d8804, 0x15120804, 0x15128804, 0x3084, 0x150e0804,
+    0x15118804, 0x15148804, 0x15150804, 0x15158804, 0x15160804, 0x15168804, 0x15170804, 0x15188804,
+    0x15198804, 0x151a0804, 0x151b0804, 0x151c0804, 0x151c8804, 0x151d0804, 0x151d8804, 0x151e0804,
+    0x151a8804, 0x15130804, 0x14eb8804, 0x0, 0x0, 0x944, 0x904, 0x15190804, 0x15138804, 0x15140804,
+    0x80006b62, 0x80006ba2, 0x80006be2, 0x80006c22, 0x80006c62, 0x80006ca2, 0x80006ce2, 0x80006d22,
+    0x14f38804, 0x14f48804, 0x15178804, 0x15180804, 0x14b0805, 0x14b8805, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0x2000805, 0xfa40804, 0x14ec8804, 0x14ee8804, 0x14ef0804, 0x14ef8804, 0x14f00804, 0x14f08804,
+    0x15010804, 0x14fe0804, 0x15098804, 0x14fb0804, 0x14fe8804, 0x150e8804, 0x15020804, 0x15078804,
+    0x151e8804, 0x30c4, 0x3104, 0x3144, 0x151f0804, 0x151f8804, 0x15200804, 0x15208804, 0x15210804,
+    0x15218804, 0x15220804, 0x15230804, 0x15240804, 0x15248804, 0x15250804, 0x15258804, 0x15260804,
+    0x15268804, 0x15270804, 0x15278804, 0x15280804, 0x15288804, 0x15290804, 0x15298804, 0x152a0804,
+    0x152a8804, 0x152b0804, 0x152b8804, 0x152c0804, 0x152c8804, 0x152d0804, 0x152d8804, 0x152e0804,
+    0x152e8804, 0x152f0804, 0x152f8804, 0x15300804, 0x15308804, 0x15310804, 0x15318804, 0x15320804,
+    0x15328804, 0x15330804, 0x15340804, 0x15350804, 0x15358804, 0x15360804, 0x15368804, 0x3084,
+    0x15370804, 0x15378804, 0x15380804, 0x15388804, 0x15390804, 0x15398804, 0x153a0804, 0x153a8804,
+    0x153c0804, 0x153c8804, 0x153d0804, 0x153d8804, 0x153e0804, 0x80006d62, 0x153e8804, 0x80006da2,
+    0x80006de2, 0x80006e22, 0x15228804, 0x15238804, 0x153b0804, 0x153b8804, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0x15338804, 0x15348804, 0xfb58804, 0xfb60804, 0xeab0805, 0xeab8805, 0xeac0805, 0xeac8805,
+    0xead0805, 0xead8805, 0x2a38805, 0xfb68804, 0x153f0804, 0x2028805, 0x3184, 0x30c4, 0x3104,
+    0x3144, 0x15420804, 0x15428804, 0x15448804, 0x15450804, 0x15408804, 0x15410804, 0x15458804,
+    0x15430804, 0x15418804, 0x15438804, 0x15478804, 0x15480804, 0x15488804, 0x15490804, 0x15498804,
+    0x154a0804, 0x154a8804, 0x154b0804, 0x154b8804, 0x154c0804, 0x154c8804, 0x154d0804, 0x154d8804,
+    0x154e0804, 0x154e8804, 0x154f0804, 0x154f8804, 0x15500804, 0x15508804, 0x15510804, 0x15518804,
+    0x15520804, 0x15528804, 0x15530804, 0x15538804, 0x15540804, 0x15550804, 0x15558804, 0x80006e62,
+    0x15560804, 0x80006ea2, 0x15460804, 0x15468804, 0x3084, 0x15570804, 0x15578804, 0x15580804,
+    0x15588804, 0x15590804, 0x15598804, 0x155a0804, 0x155a8804, 0x155b0804, 0x155b8804, 0x15470804,
+    0x80006ee2, 0x80006f22, 0x80006f62, 0x15568804, 0x80006fa2, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x31c4, 0x3204,
+    0x15440804, 0x15400804, 0x153f8804, 0x15548804, 0x2030805, 0x30c4, 0x3104, 0x3144, 0x155c8804,
+    0x155d0804, 0x155d8804, 0x155e0804, 0x155e8804, 0x155f0804, 0x155f8804, 0x15608804, 0x15618804,
+    0x15620804, 0x15628804, 0x15630804, 0x15638804, 0x15640804, 0x15648804, 0x15650804, 0x15658804,
+    0x15660804, 0x15668804, 0x15670804, 0x15678804, 0x15680804, 0x15690804, 0x15698804, 0x156a0804,
+    0x156a8804, 0x156b0804, 0x156b8804, 0x156c0804, 0x156c8804, 0x156d0804, 0x156d8804, 0x156e0804,
+    0x156e8804, 0x156f0804, 0x156f8804, 0x15700804, 0x15708804, 0x15710804, 0x15718804, 0x15720804,
+    0x15728804, 0x15758804, 0x15730804, 0x15738804, 0x15740804, 0x15748804, 0x15750804, 0x3084,
+    0x15760804, 0x15768804, 0x15770804, 0x15778804, 0x15780804, 0x15788804, 0x15790804, 0x15798804,
+    0x157b0804, 0x157b8804, 0x157c0804, 0x157c8804, 0x157d0804, 0x157d8804, 0x157e0804, 0x155c0804,
+    0x15600804, 0x15610804, 0x157a0804, 0x157a8804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x2038805, 0xfb70804,
+    0x15688804, 0x2044, 0x2004, 0x2084, 0x3084, 0x3084, 0x3084, 0x30c4, 0x3104, 0x3144, 0x157e8804,
+    0x157f0804, 0x157f8804, 0x15800804, 0x15808804, 0x15810804, 0x15818804, 0x15828804, 0x15838804,
+    0x15840804, 0x15848804, 0x15850804, 0x15858804, 0x15860804, 0x15868804, 0x15870804, 0x15878804,
+    0x15880804, 0x15888804, 0x15890804, 0x15898804, 0x158a0804, 0x158a8804, 0x158b0804, 0x158b8804,
+    0x158c0804, 0x158c8804, 0x158d0804, 0x158d8804, 0x158e0804, 0x158e8804, 0x158f0804, 0x158f8804,
+    0x15900804, 0x15908804, 0x15910804, 0x15918804, 0x15920804, 0x15930804, 0x15938804, 0x15940804,
+    0x15948804, 0x15958804, 0x15960804, 0x15968804, 0x15970804, 0x3084, 0x15978804, 0x15980804,
+    0x15988804, 0x15990804, 0x15998804, 0x159a0804, 0x159a8804, 0x159b0804, 0x159c8804, 0x159d0804,
+    0x159d8804, 0x159e0804, 0x159e8804, 0xcc4, 0x159f0804, 0x159f8804, 0x80006fe2, 0x80007022,
+    0x15928804, 0x15820804, 0x15830804, 0x159b8804, 0x159c0804, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x2a40805,
+    0x15950804, 0xeae0805, 0xeae8805, 0xeaf0805, 0xeaf8805, 0xeb00805, 0xeb08805, 0x3104,
+    0x15a68804, 0x15a08804, 0x15a10804, 0x15a18804, 0x15a20804, 0x15a28804, 0x15a30804, 0x15a38804,
+    0x15a40804, 0x15a48804, 0x15a50804, 0x15a58804, 0x15a60804, 0x15a70804, 0x15a78804, 0x15a80804,
+    0x15b00804, 0x15a88804, 0x15a90804, 0x15a98804, 0x15aa0804, 0x15aa8804, 0x15af8804, 0x15ab0804,
+    0x15ab8804, 0x15ac0804, 0x15ac8804, 0x15af0804, 0x15ad0804, 0x15ae8804, 0x15ae0804, 0x15ad8804,
+    0x15b08804, 0x15b10804, 0x15b18804, 0x15b20804, 0x15b28804, 0x15b30804, 0x15b38804, 0x15b40804,
+    0x15b48804, 0x15b50804, 0x15b58804, 0x15b60804, 0x15b68804, 0x15b70804, 0x15b78804, 0x15b80804,
+    0x15a00804, 0x15b88804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xeb40805, 0xeb48805, 0xeb50805, 0x2a48805,
+    0x2a50805, 0x2a58805, 0x2a60805, 0x2a68805, 0x2a70805, 0xfb80804, 0x2a78805, 0x30c4, 0x30c4,
+    0x3104, 0x3144, 0x3104, 0x15b90804, 0x15b98804, 0x15ba0804, 0x15ba8804, 0x15bb0804, 0x15bb8804,
+    0x15bc0804, 0x15bd0804, 0x15be0804, 0x15be8804, 0x15bf0804, 0x15bf8804, 0x15c00804, 0x15c08804,
+    0x15c10804, 0x15c18804, 0x15c20804, 0x15c28804, 0x15c30804, 0x15c38804, 0x15c48804, 0x15c50804,
+    0x15c60804, 0x15c68804, 0x15c70804, 0x15c78804, 0x15c80804, 0x15c88804, 0x15c90804, 0x15c98804,
+    0x15ca0804, 0x15ca8804, 0x15cb0804, 0x15cb8804, 0x15cc0804, 0x15cc8804, 0x15cd0804, 0x15cd8804,
+    0x15ce0804, 0x15ce8804, 0x15cf0804, 0x15cf8804, 0x15d00804, 0x15d30804, 0x15d38804, 0x15d08804,
+    0x15d10804, 0x15d18804, 0x15d20804, 0x15d28804, 0x15d48804, 0x15d50804, 0x15d58804, 0x15d60804,
+    0x15d68804, 0x15d70804, 0x15d78804, 0x15d80804, 0x15d98804, 0x15da0804, 0x15da8804, 0x15db0804,
+    0x15db8804, 0x15dc0804, 0x15dc8804, 0x15dd0804, 0x15dd8804, 0x15c40804, 0x15c58804, 0x15d40804,
+    0x15bc8804, 0x15bd8804, 0x15d88804, 0x15d90804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x2040805, 0xfcc0804,
+    0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0x2b48805, 0x15f98804,
+    0x30c4, 0x3104, 0x3144, 0x2048805, 0x15de0804, 0x15de8804, 0x15df0804, 0x15df8804, 0x15e00804,
+    0x15e08804, 0x15e10804, 0x15e20804, 0x15e30804, 0x15e38804, 0x15e40804, 0x15e48804, 0x15e50804,
+    0x15e58804, 0x15e60804, 0x15e68804, 0x15e70804, 0x15e78804, 0x15e80804, 0x15e88804, 0x15e90804,
+    0x15e98804, 0x15ea0804, 0x15ea8804, 0x15eb0804, 0x15eb8804, 0x15ec0804, 0x15ec8804, 0x15ed0804,
+    0x15ed8804, 0x15ee0804, 0x15ee8804, 0x15ef0804, 0x15ef8804, 0x15f00804, 0x15f08804, 0x15f10804,
+    0x15f18804, 0x15f20804, 0x15f28804, 0x15f30804, 0x15f38804, 0x15f40804, 0x15f70804, 0x15f48804,
+    0x15f50804, 0x15f58804, 0x15f60804, 0x15f68804, 0x3084, 0x15f80804, 0x15fa0804, 0x15fa8804,
+    0x15fb0804, 0x15fb8804, 0x15fc0804, 0x15fc8804, 0x15fd0804, 0x15fe8804, 0x15ff0804, 0x15ff8804,
+    0x16000804, 0x16008804, 0x16010804, 0x16018804, 0x16020804, 0x16028804, 0x15f78804, 0x15e18804,
+    0x15e28804, 0x15fd8804, 0x15fe0804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x15f88804, 0x15f90804, 0x3104, 0x30c4,
+    0x3104, 0x3144, 0x161f0804, 0x16030804, 0x16038804, 0x16040804, 0x16048804, 0x16058804,
+    0x16060804, 0x16068804, 0x16078804, 0x16088804, 0x16090804, 0x16098804, 0x160a0804, 0x160a8804,
+    0x160b0804, 0x160b8804, 0x160c0804, 0x160c8804, 0x160d0804, 0x160d8804, 0x160e0804, 0x160e8804,
+    0x160f0804, 0x160f8804, 0x16100804, 0x16108804, 0x16110804, 0x16118804, 0x16120804, 0x16128804,
+    0x16130804, 0x16138804, 0x16140804, 0x16148804, 0x16150804, 0x16158804, 0x16160804, 0x16168804,
+    0x16170804, 0x16178804, 0x16180804, 0x16188804, 0x16190804, 0x161d8804, 0x16198804, 0x161c8804,
+    0x161d0804, 0x161a0804, 0x161a8804, 0x161b0804, 0x161b8804, 0x161c0804, 0x161e0804, 0x16278808,
+    0x16278808, 0x161e8804, 0x161f8804, 0x16200804, 0x16208804, 0x16210804, 0x16218804, 0x16220804,
+    0x16228804, 0x16240804, 0x16248804, 0x16250804, 0x16258804, 0x16260804, 0x16268804, 0x16278804,
+    0x80007062, 0x2b50805, 0x800070a2, 0x800070e2, 0x80007122, 0x16270804, 0xec00805, 0xec08805,
+    0xec10805, 0xec18805, 0xec20805, 0xec28805, 0xec30805, 0x16050804, 0x16070804, 0x16080804,
+    0x16230804, 0x16238804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xec38805, 0xec40805, 0xec48805, 0xec50805,
+    0xec58805, 0xec60805, 0xec68805, 0xec70805, 0xec78805, 0x2b58805, 0x80007162, 0x800071a2,
+    0x800071e2, 0x80007222, 0x80007262, 0x800072a2, 0x30c4, 0x3104, 0x3144, 0x16280804, 0x16288804,
+    0x16290804, 0x16298804, 0x162a0804, 0x162a8804, 0x162b0804, 0x162b8804, 0x162c0804, 0x162c8804,
+    0x162d0804, 0x162d8804, 0x162e0804, 0x162e8804, 0x162f0804, 0x162f8804, 0x16300804, 0x16308804,
+    0x16310804, 0x16318804, 0x16320804, 0x16328804, 0x16330804, 0x16338804, 0x16340804, 0x16348804,
+    0x16350804, 0x16358804, 0x16360804, 0x16368804, 0x16370804, 0x16378804, 0x16380804, 0x16388804,
+    0x16390804, 0x16398804, 0x163a0804, 0x163a8804, 0x163b0804, 0x163b8804, 0x163c0804, 0x163c8804,
+    0x163d0804, 0x163d8804, 0x163e0804, 0x163e8804, 0x163f0804, 0x163f8804, 0x16400804, 0x16408804,
+    0x16410804, 0x16418804, 0x16420804, 0x16428804, 0x16430804, 0x16438804, 0x16440804, 0x16448804,
+    0x16450804, 0x164e0804, 0x16458804, 0x16460804, 0x16468804, 0x16470804, 0x16478804, 0x16480804,
+    0x16488804, 0x16490804, 0x164b0804, 0x164b8804, 0x164c0804, 0x164c8804, 0x164d0804, 0x164d8804,
+    0x164a0804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804,
+    0xfcf8804, 0xfd00804, 0xfd08804, 0x16498804, 0x164a8804, 0x2050805, 0x190b8804, 0x190c0804,
+    0x190c8804, 0x190d0804, 0x190d8804, 0x190e0804, 0x190e8804, 0x190f0804, 0x190f8804, 0x19100804,
+    0x19108804, 0x19110804, 0x19118804, 0x19120804, 0x19128804, 0x19130804, 0x19138804, 0x19140804,
+    0x19148804, 0x19150804, 0x19158804, 0x19160804, 0x19168804, 0x19170804, 0x19178804, 0x19180804,
+    0x19188804, 0x19190804, 0x19198804, 0x191a0804, 0x191a8804, 0x191b0804, 0x191b8804, 0x191c0804,
+    0x191c8804, 0x191d0804, 0x191d8804, 0x191e0804, 0x191e8804, 0x191f0804, 0x191f8804, 0x19200804,
+    0x19208804, 0x19210804, 0x19218804, 0x19220804, 0x19228804, 0x19230804, 0x19238804, 0x19240804,
+    0x19248804, 0x19250804, 0x19258804, 0x19260804, 0x19268804, 0x19270804, 0x19278804, 0x19280804,
+    0xfba8804, 0x19288804, 0x19290804, 0x19298804, 0x192a0804, 0x192a8804, 0x192b0804, 0xfa48804,
+    0x3504, 0x3544, 0x3584, 0x35c4, 0x3604, 0x3644, 0x3684, 0x34c4, 0x2058805, 0xfcc0804,
+    0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804,
+    0xfd08804, 0x2060805, 0x2068805, 0x192c0804, 0x192c8804, 0x192d0804, 0x192d8804, 0x192e0804,
+    0x192e8804, 0x192f0804, 0x19300804, 0x19308804, 0x19320804, 0x19310804, 0x19328804, 0x19330804,
+    0x19338804, 0x19340804, 0x19348804, 0x19350804, 0x19358804, 0x19360804, 0x19368804, 0x19370804,
+    0x19378804, 0x19380804, 0x19388804, 0x19390804, 0x19398804, 0x193a0804, 0x193a8804, 0x193b0804,
+    0x193b8804, 0x193c0804, 0x193c8804, 0x193d0804, 0x193d8804, 0x193e0804, 0x193e8804, 0x192f8804,
+    0x193f0804, 0x193f8804, 0x19400804, 0x19408804, 0x19410804, 0x19418804, 0x19420804, 0x19428804,
+    0x19430804, 0x19438804, 0x19440804, 0x19448804, 0x19450804, 0x19458804, 0x19460804, 0x19468804,
+    0x19470804, 0x19478804, 0x19480804, 0x19488804, 0x19490804, 0x19498804, 0x194a0804, 0x194a8804,
+    0xfa50804, 0x36c4, 0x3704, 0x3744, 0x3784, 0x37c4, 0x3804, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x800072e2,
+    0x80007322, 0x192b8804, 0x19318804, 0x80007363, 0x2b98805, 0x2ba0805, 0x2ba8805, 0x2080805,
+    0x2088805, 0x2090805, 0x2098805, 0x20a0805, 0x20a8805, 0x20b0805, 0x20c8805, 0x20c8837,
+    0x20d0805, 0x20d8805, 0x20e0805, 0x20e8805, 0x20f0805, 0x20f8805, 0x2bb0805, 0x12f8805,
+    0x2bb8805, 0x2bc0805, 0x2bc8805, 0x0, 0x0, 0x2bd0805, 0x2bd8805, 0x2be0805, 0x2be8805,
+    0x2bf0805, 0x2bf8805, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xfcc8808, 0xfcd0808, 0xfcd8808, 0xfce0808,
+    0xfce8808, 0xfcf0808, 0xfcf8808, 0xfd00808, 0xfd08808, 0xfcc0808, 0x2c00805, 0x0, 0x2c08805,
+    0x0, 0x2c10805, 0x38c4, 0x1970805, 0x1978805, 0x1980805, 0x1988805, 0x0, 0x0, 0x196c8804,
+    0x196e0804, 0x196f0804, 0x800073c2, 0x19700804, 0x19710804, 0x19720804, 0x19730804, 0x19740804,
+    0x19750804, 0x19760804, 0x19770804, 0x80007402, 0x19780804, 0x19790804, 0x197a0804, 0x197b0804,
+    0x80007442, 0x197c0804, 0x197d0804, 0x197e0804, 0x197f0804, 0x80007482, 0x19800804, 0x19810804,
+    0x19820804, 0x19830804, 0x800074c2, 0x19840804, 0x19850804, 0x19860804, 0x19870804, 0x19880804,
+    0x19890804, 0x198a8804, 0x198b8804, 0x198c8804, 0x198d8804, 0x198e8804, 0x198f8804, 0x80007502,
+    0x80007542, 0x196d8804, 0x198a0804, 0x19948804, 0x19950804, 0x19958804, 0x19970804, 0x19978804,
+    0x19980804, 0x19988804, 0x19990804, 0x19998804, 0x199a0804, 0x199a8804, 0x199b0804, 0x199b8804,
+    0x3104, 0x3144, 0x19960804, 0x19968804, 0x0, 0x0, 0x199c0804, 0x2100805, 0x0, 0x0, 0x19908804,
+    0x19918804, 0x19938804, 0x19940804, 0x19928804, 0x19910804, 0x19920804, 0x19930804, 0x196d0804,
+    0x196e8804, 0x196f8804, 0x80007582, 0x19708804, 0x19718804, 0x19728804, 0x19738804, 0x19748804,
+    0x19758804, 0x19768804, 0x19778804, 0x800075c2, 0x19788804, 0x19798804, 0x197a8804, 0x197b8804,
+    0x80007602, 0x197c8804, 0x197d8804, 0x197e8804, 0x197f8804, 0x80007642, 0x19808804, 0x19818804,
+    0x19828804, 0x19838804, 0x80007682, 0x19848804, 0x19858804, 0x19868804, 0x19878804, 0x19888804,
+    0x19898804, 0x198b0804, 0x198c0804, 0x198d0804, 0x198e0804, 0x198f0804, 0x19900804, 0x800076c2,
+    0x80007702, 0x80007742, 0x80007782, 0x2c18805, 0x2c20805, 0x2c28805, 0x2c30805, 0x2c38805,
+    0x2c40805, 0x2c48805, 0x2c50805, 0x0, 0x2c58805, 0x2c60805, 0x2c68805, 0x2c70805, 0x2c78805,
+    0x2c80805, 0x2c88805, 0x2c90805, 0x20b8805, 0x20c0805, 0x2108805, 0x2110805, 0x2118805,
+    0x2c98805, 0x2ca0805, 0x2ca8805, 0x2cb0805, 0x2120805, 0x2128805, 0x1ab60804, 0x1ab70804,
+    0x1ab80804, 0x1aba0804, 0x1abb8804, 0x1abc8804, 0x1abe0804, 0x1ac00804, 0x1ac28804, 0x1ac60804,
+    0x1ac80804, 0x1ac88804, 0x1ac98804, 0x1aca8804, 0x1acc0804, 0x1acd8804, 0x1acf8804, 0x1ad00804,
+    0x1ad08804, 0x1ad20804, 0x1ad38804, 0x1ad58804, 0x1ad60804, 0x1ad90804, 0x1ada8804, 0x1adc0804,
+    0x1add0804, 0x1ade0804, 0x1ae00804, 0x1ae10804, 0x1ae48804, 0x1ae58804, 0x1ae88804, 0x1aec0804,
+    0x1aec8804, 0x1aed0804, 0x1aed8804, 0x1aee0804, 0x1aee8804, 0x1af10804, 0x1af18804, 0x1af20804,
+    0x1af28804, 0x1af30808, 0x1af30804, 0x1af50804, 0x1af60804, 0x1af70804, 0x1af88804, 0x1afb0804,
+    0x1afd0804, 0x1af68804, 0x1afe0804, 0x1afc0804, 0x3104, 0x39c4, 0x3144, 0x1b010804, 0x1b018804,
+    0x1add8804, 0x1adf8804, 0x1ae18804, 0x1ae70804, 0x800077c3, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1508805,
+    0x1510805, 0x21d8805, 0x21e0805, 0x21e8805, 0x21f0805, 0x1ae30804, 0x1ae38804, 0x1aef0804,
+    0x1aef8804, 0x1af00804, 0x1af08804, 0x1af90804, 0x1af98804, 0x1afa0804, 0x1afa8804, 0x1abc0804,
+    0x1ac30804, 0x1ae98804, 0x1aea0804, 0x1ad50804, 0x1adc8804, 0x1ae08804, 0x1ac50804, 0x1afe8804,
+    0x1b020804, 0x1b028804, 0x1ae40804, 0x1aeb8804, 0x1aff0804, 0x1aff8804, 0x1b030804, 0x1b038804,
+    0x1b040804, 0x1b048804, 0x1b050804, 0x1ace0804, 0x1aea8804, 0x1aeb0804, 0x1af58804, 0x1af40804,
+    0x1af78804, 0x1af80804, 0x1ab68804, 0x1ab78804, 0x1ab88804, 0x1abd0804, 0x1ac18804, 0x1ac68804,
+    0x1ad10804, 0x1ad40804, 0x1ad68804, 0x1ad70804, 0x1ad98804, 0x1ae28804, 0x1ae60804, 0x1ae20804,
+    0x1af38804, 0x1afb8804, 0x1afc8804, 0x1b008804, 0x1b058804, 0x1b068804, 0x1b080804, 0x1b088804,
+    0x1b060804, 0x1b070804, 0x1b078804, 0x1ad80804, 0x1b090804, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1b098804,
+    0x1b0a0804, 0x1af48804, 0x1afd8804, 0x2cc8805, 0x2cd0805, 0x12d00810, 0x12d10810, 0x12d20810,
+    0x12d30810, 0x12d40810, 0x12d50810, 0x12d60810, 0x12d80810, 0x12d90810, 0x12da0810, 0x12db0810,
+    0x12dc0810, 0x12dd0810, 0x12df0810, 0x12e00810, 0x12e10810, 0x12e20810, 0x12e30810, 0x12e40810,
+    0x12e60810, 0x12e70810, 0x12e80810, 0x12e90810, 0x12ea0810, 0x12eb0810, 0x12ec0810, 0x12ed0810,
+    0x12ee0810, 0x12ef0810, 0x12f00810, 0x12f10810, 0x12f30810, 0x12f40810, 0x12d70810, 0x12de0810,
+    0x12e50810, 0x12f20810, 0x12f50810, 0x12f68810, 0x12f90810, 0x12cf8804, 0x12d08804, 0x12d18804,
+    0x12d28804, 0x12d38804, 0x12d48804, 0x12d58804, 0x12d78804, 0x12d88804, 0x12d98804, 0x12da8804,
+    0x12db8804, 0x12dc8804, 0x12de8804, 0x12df8804, 0x12e08804, 0x12e18804, 0x12e28804, 0x12e38804,
+    0x12e58804, 0x12e68804, 0x12e78804, 0x12e88804, 0x12e98804, 0x12ea8804, 0x12eb8804, 0x12ec8804,
+    0x12ed8804, 0x12ee8804, 0x12ef8804, 0x12f08804, 0x12f28804, 0x12f38804, 0x12d68804, 0x12dd8804,
+    0x12e48804, 0x12f18804, 0x12f48804, 0x12f58804, 0x12f60804, 0x12f70804, 0x12f78804, 0x12f80804,
+    0x1678805, 0x12dc8828, 0x12f88804, 0x12f98804, 0x12fa0804, 0x20ba8804, 0x20bb0804, 0x20bb8804,
+    0x20bc0804, 0x20bc8804, 0x20bd0804, 0x20bd8804, 0x20be0804, 0x20be8804, 0x20bf0804, 0x20bf8804,
+    0x20c00804, 0x20c08804, 0x20c10804, 0x20c18804, 0x20c20804, 0x20c28804, 0x20c30804, 0x20c38804,
+    0x20c40804, 0x20c48804, 0x20c50804, 0x20c58804, 0x20c60804, 0x20c68804, 0x20c70804, 0x20c78804,
+    0x20c80804, 0x20c88804, 0x20c90804, 0x20c98804, 0x20ca0804, 0x20ca8804, 0x20cb0804, 0x20cb8804,
+    0x20cc0804, 0x20cc8804, 0x20cd0804, 0x20cd8804, 0x20ce0804, 0x20ce8804, 0x20cf0804, 0x20cf8804,
+    0x20d00804, 0x20d08804, 0x20d10804, 0x20d18804, 0x20d20804, 0x20d28804, 0x20d30804, 0x20d38804,
+    0x20d40804, 0x20d48804, 0x20d50804, 0x20d58804, 0x20d60804, 0x20d68804, 0x20d70804, 0x20d78804,
+    0x20d80804, 0x20d88804, 0x20d90804, 0x20d98804, 0x20da0804, 0x20da8804, 0x20db0804, 0x20db8804,
+    0x20dc0804, 0x20dc8804, 0x20dd0804, 0x20dd8804, 0x20de0804, 0x20de8804, 0x20df0804, 0x20df8804,
+    0x20e00804, 0x20e08804, 0x20e10804, 0x20e18804, 0x20e20804, 0x20e28804, 0x20e30804, 0x20e38804,
+    0x20e40804, 0x20e48804, 0x20e50804, 0x20e58804, 0x20e60804, 0x20e68804, 0x20e70804, 0x20e78804,
+    0x20e80804, 0x20e88804, 0x20e90804, 0x20e98804, 0x20f88804, 0x20f90804, 0x20f98804, 0x20fa0804,
+    0x20fa8804, 0x20fb0804, 0x20fb8804, 0x20fc0804, 0x20fc8804, 0x20fd0804, 0x20fd8804, 0x20fe0804,
+    0x20fe8804, 0x20ff0804, 0x20ff8804, 0x21000804, 0x21008804, 0x21010804, 0x21018804, 0x21020804,
+    0x21028804, 0x21030804, 0x21038804, 0x21040804, 0x21048804, 0x21050804, 0x21058804, 0x21060804,
+    0x21068804, 0x21070804, 0x21078804, 0x21080804, 0x21088804, 0x21090804, 0x21098804, 0x210a0804,
+    0x210a8804, 0x210b0804, 0x210b8804, 0x210c0804, 0x210c8804, 0x210d0804, 0x210d8804, 0x210e0804,
+    0x210e8804, 0x210f0804, 0x210f8804, 0x21100804, 0x21108804, 0x21110804, 0x21118804, 0x21120804,
+    0x21128804, 0x21130804, 0x21138804, 0x21140804, 0x21148804, 0x21150804, 0x21158804, 0x21160804,
+    0x21168804, 0x21170804, 0x21178804, 0x21180804, 0x21188804, 0x21190804, 0x21198804, 0x211a0804,
+    0x211a8804, 0x211b0804, 0x211b8804, 0x211c0804, 0x211c8804, 0x21288804, 0x21290804, 0x21298804,
+    0x212a0804, 0x212a8804, 0x212b0804, 0x212b8804, 0x212c0804, 0x212c8804, 0x212d0804, 0x212d8804,
+    0x212e0804, 0x212e8804, 0x212f0804, 0x212f8804, 0x21300804, 0x21308804, 0x21310804, 0x21318804,
+    0x21320804, 0x21328804, 0x21330804, 0x21338804, 0x21340804, 0x21348804, 0x21350804, 0x21358804,
+    0x21360804, 0x21368804, 0x21370804, 0x21378804, 0x21380804, 0x21388804, 0x21390804, 0x21398804,
+    0x213a0804, 0x213a8804, 0x213b0804, 0x213b8804, 0x213c0804, 0x213c8804, 0x213d0804, 0x213d8804,
+    0x213e0804, 0x213e8804, 0x213f0804, 0x213f8804, 0x21400804, 0x21408804, 0x21410804, 0x21418804,
+    0x21420804, 0x21428804, 0x21430804, 0x21438804, 0x21440804, 0x21448804, 0x21450804, 0x21458804,
+    0x21460804, 0x21468804, 0x21470804, 0x21478804, 0x21480804, 0x21488804, 0x21490804, 0x21498804,
+    0x214a0804, 0x214a8804, 0x214b0804, 0x214b8804, 0x214c0804, 0x214c8804, 0x214d0804, 0x214d8804,
+    0x214e0804, 0x214e8804, 0x214f0804, 0x214f8804, 0x21500804, 0x21508804, 0x21510804, 0x21518804,
+    0x21520804, 0x21528804, 0x21530804, 0x21538804, 0x21540804, 0x14090804, 0x14098804, 0x140a0804,
+    0x140a8804, 0x140b0804, 0x140b8804, 0x140c0804, 0x140c8804, 0x140d0804, 0x140d8804, 0x140e0804,
+    0x140e8804, 0x140f0804, 0x140f8804, 0x14100804, 0x14108804, 0x14118804, 0x14120804, 0x14128804,
+    0x14130804, 0x14138804, 0x14140804, 0x14148804, 0x14150804, 0x14158804, 0x14160804, 0x14168804,
+    0x14170804, 0x14178804, 0x14180804, 0x14188804, 0x14190804, 0x141c0804, 0x141c8804, 0x141d0804,
+    0x141d8804, 0x141e0804, 0x141e8804, 0x141f0804, 0x141f8804, 0x14200804, 0x14208804, 0x14210804,
+    0x14218804, 0x14220804, 0x14228804, 0x14230804, 0x14238804, 0x14248804, 0x14250804, 0x14258804,
+    0x14260804, 0x14268804, 0x14270804, 0x14278804, 0x14280804, 0x142c0804, 0x142c8804, 0x142d0804,
+    0x142d8804, 0x142e0804, 0x142e8804, 0x142f0804, 0x142f8804, 0x14308804, 0x14310804, 0x14318804,
+    0x14320804, 0x14328804, 0x14330804, 0x14338804, 0x14340804, 0x14348804, 0x14350804, 0x14358804,
+    0x14360804, 0x14368804, 0x14370804, 0x14378804, 0x14380804, 0x14388804, 0x14390804, 0x14398804,
+    0x143a0804, 0x143a8804, 0x143b0804, 0x143b8804, 0x143c0804, 0x143c8804, 0x143d0804, 0x143d8804,
+    0x143e0804, 0x143e8804, 0x143f0804, 0x143f8804, 0x14400804, 0x14408804, 0x14438804, 0x14440804,
+    0x14448804, 0x14450804, 0x14458804, 0x14460804, 0x14468804, 0x14470804, 0x14478804, 0x14480804,
+    0x14488804, 0x14490804, 0x14498804, 0x144a0804, 0x144a8804, 0x144b0804, 0x144c0804, 0x144c8804,
+    0x144d0804, 0x144d8804, 0x144e0804, 0x144e8804, 0x144f0804, 0x144f8804, 0x14508804, 0x14510804,
+    0x14518804, 0x14520804, 0x14528804, 0x14530804, 0x14538804, 0x14540804, 0x14548804, 0x14550804,
+    0x14558804, 0x14560804, 0x14568804, 0x14570804, 0x14578804, 0x14580804, 0x14588804, 0x14590804,
+    0x14598804, 0x145a0804, 0x145a8804, 0x145b8804, 0x145c0804, 0x145c8804, 0x145d0804, 0x145d8804,
+    0x145e0804, 0x145e8804, 0x145f0804, 0x14600804, 0x14608804, 0x14610804, 0x14618804, 0x14620804,
+    0x14628804, 0x14630804, 0x14638804, 0x14648804, 0x14650804, 0x14658804, 0x14660804, 0x14668804,
+    0x14670804, 0x14678804, 0x14680804, 0x14688804, 0x14690804, 0x14698804, 0x146a0804, 0x146a8804,
+    0x146b0804, 0x146b8804, 0x146c0804, 0x146c8804, 0x146d0804, 0x146d8804, 0x146e0804, 0x146e8804,
+    0x146f0804, 0x146f8804, 0x14700804, 0x14708804, 0x14710804, 0x14718804, 0x14720804, 0x14728804,
+    0x14730804, 0x14738804, 0x14740804, 0x14748804, 0x14750804, 0x14758804, 0x14760804, 0x14768804,
+    0x14770804, 0x14778804, 0x14780804, 0x14788804, 0x14790804, 0x14798804, 0x147a0804, 0x147a8804,
+    0x147b0804, 0x147b8804, 0x147c0804, 0x14800804, 0x14808804, 0x14810804, 0x14818804, 0x14820804,
+    0x14828804, 0x14830804, 0x14838804, 0x14840804, 0x14848804, 0x14850804, 0x14858804, 0x14860804,
+    0x14868804, 0x14870804, 0x14878804, 0x14880804, 0x14888804, 0x14890804, 0x14898804, 0x148a0804,
+    0x148a8804, 0x148b0804, 0x148b8804, 0x148f8804, 0x14900804, 0x14908804, 0x14910804, 0x14918804,
+    0x14920804, 0x14928804, 0x14930804, 0x14940804, 0x14948804, 0x14950804, 0x14958804, 0x14960804,
+    0x14968804, 0x14970804, 0x14978804, 0x14988804, 0x14990804, 0x14998804, 0x149a0804, 0x149a8804,
+    0x149b0804, 0x149b8804, 0x149c0804, 0x149c8804, 0x149d0804, 0x149d8804, 0x149e0804, 0x149e8804,
+    0x149f0804, 0x149f8804, 0x14a00804, 0x14a08804, 0x14a10804, 0x14a18804, 0x14a20804, 0x14a28804,
+    0x14a50804, 0x14a58804, 0x14a60804, 0x14a68804, 0x14a70804, 0x14a78804, 0x14a80804, 0x14a88804,
+    0x14a98804, 0x14aa0804, 0x14aa8804, 0x14ab0804, 0x14ab8804, 0x14ac0804, 0x14ac8804, 0x14ad0804,
+    0x14b18804, 0x14b20804, 0x14b28804, 0x14b30804, 0x14b38804, 0x14b40804, 0x14b48804, 0x14b50804,
+    0x14b60804, 0x14b68804, 0x14b70804, 0x14b78804, 0x14b80804, 0x14b88804, 0x14b90804, 0x14b98804,
+    0x14bd8804, 0x14be0804, 0x14be8804, 0x14bf0804, 0x14bf8804, 0x14c00804, 0x14c08804, 0x14c10804,
+    0x14c18804, 0x14c20804, 0x14c28804, 0x14c30804, 0x14c38804, 0x14c40804, 0x14c48804, 0x14c50804,
+    0x14c78804, 0x14c80804, 0x14c88804, 0x14c90804, 0x14c98804, 0x14ca0804, 0x14ca8804, 0x14cb0804,
+    0x14ce0804, 0x14ce8804, 0x14cf0804, 0x2d44, 0x2d04, 0x2cc4, 0x1680805, 0x12c0805, 0x1418805,
+    0x12c8805, 0x12d0805, 0x12d8805, 0x12e0805, 0x1398805, 0x1688805, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xec80805,
+    0xec88805, 0xec90805, 0xec98805, 0xeca0805, 0xeca8805, 0xecb0805, 0xecb8805, 0xecc0805,
+    0xecc8805, 0xecd0805, 0x14198804, 0x141a0804, 0x141a8804, 0x141b0804, 0x14410804, 0x14418804,
+    0x14420804, 0x14428804, 0x14c58804, 0x14c60804, 0x14c68804, 0x14c70804, 0x14cb8804, 0x14cc0804,
+    0x14cc8804, 0x14cd0804, 0x27d0805, 0x27d8805, 0x27e0805, 0x27e8805, 0x27f0805, 0x27f8805,
+    0x2800805, 0x2808805, 0x2810805, 0x2818805, 0x1c6f0810, 0x1c6f8810, 0x1c700810, 0x1c708810,
+    0x1c710810, 0x1c718810, 0x1c720810, 0x1c728810, 0x1c730810, 0x1c738810, 0x1c740810, 0x1c748810,
+    0x1c750810, 0x1c758810, 0x1c760810, 0x1c768810, 0x1c770810, 0x1c778810, 0x1c780810, 0x1c788810,
+    0x1c790810, 0x1c798810, 0x1c7a0810, 0x1c7a8810, 0x1c7b0810, 0x1c7b8810, 0x1c7c0810, 0x1c7c8810,
+    0x1c7d0810, 0x1c7d8810, 0x1c7e0810, 0x1c7e8810, 0x1c7f0810, 0x1c7f8810, 0x1c800810, 0x1c808810,
+    0x1c810810, 0x1c818810, 0x1c820810, 0x1c828810, 0x1c830810, 0x1c838810, 0x1c840810, 0x1c848810,
+    0x1c850810, 0x1c858810, 0x1c860810, 0x1c868810, 0x1c870810, 0x1c878810, 0x1c880810, 0x1c888810,
+    0x1c890810, 0x1c898810, 0x1c8a0810, 0x1c8a8810, 0x1c8b0810, 0x1c8b8810, 0x1c8c0810, 0x1c8c8810,
+    0x1c8d0810, 0x1c8d8810, 0x1c8e0810, 0x1c8e8810, 0x1c8f0810, 0x1c8f8810, 0x1c900810, 0x1c908810,
+    0x1c910810, 0x1c918810, 0x1c920810, 0x1c928810, 0x1c930810, 0x1c938810, 0x1c940810, 0x1c948810,
+    0x1c950810, 0x1c958810, 0x1c960810, 0x1c968810, 0x1c970810, 0x1c978810, 0x1c980810, 0x1c988810,
+    0x1c990810, 0x1c998810, 0x1c970804, 0x1c978804, 0x1c980804, 0x1c988804, 0x1c990804, 0x1c998804,
+    0x1078805, 0x1cac0804, 0x1cac8804, 0x1cad0804, 0x1cad8804, 0x1cae0804, 0x1cae8804, 0x1caf0804,
+    0x1caf8804, 0x1cb00804, 0x1cb08804, 0x1cb10804, 0x1cb18804, 0x1cb20804, 0x1cb28804, 0x1cb30804,
+    0x1cb38804, 0x1cb40804, 0x1cb48804, 0x1cb50804, 0x1cb58804, 0x1cb60804, 0x1cb68804, 0x1cb70804,
+    0x1cb78804, 0x1cb80804, 0x1cb88804, 0x1cb90804, 0x1cb98804, 0x1cba0804, 0x1cba8804, 0x1cbb0804,
+    0x1cbb8804, 0x1cbc0804, 0x1cbc8804, 0x1cbd0804, 0x1cbd8804, 0x1cbe0804, 0x1cbe8804, 0x1cbf0804,
+    0x1cbf8804, 0x1cc00804, 0x1cc08804, 0x1cc10804, 0x1cc18804, 0x1cc20804, 0x1cc28804, 0x1cc30804,
+    0x1cc38804, 0x1cc40804, 0x1cc48804, 0x1cc50804, 0x1cc58804, 0x1cc60804, 0x1cc68804, 0x1cc70804,
+    0x1cc78804, 0x1cc80804, 0x1cc88804, 0x1cc90804, 0x1cc98804, 0x1cca0804, 0x1cca8804, 0x1ccb0804,
+    0x1ccb8804, 0x1ccc0804, 0x1ccc8804, 0x1ccd0804, 0x1ccd8804, 0x1cce0804, 0x1cce8804, 0x1ccf0804,
+    0x1ccf8804, 0x1cd00804, 0x1cd08804, 0x1cd10804, 0x1cd18804, 0x1cd20804, 0x1cd28804, 0x1cd30804,
+    0x1cd38804, 0x1cd40804, 0x1cd48804, 0x1cd50804, 0x1cd58804, 0x1cd60804, 0x1cd68804, 0x1cd70804,
+    0x1cd78804, 0x1cd80804, 0x1cd88804, 0x1cd90804, 0x1cd98804, 0x1cda0804, 0x1cda8804, 0x1cdb0804,
+    0x1cdb8804, 0x1cdc0804, 0x1cdc8804, 0x1cdd0804, 0x1cdd8804, 0x1cde0804, 0x1cde8804, 0x1cdf0804,
+    0x1cdf8804, 0x1ce00804, 0x1ce08804, 0x1ce10804, 0x1ce18804, 0x1ce20804, 0x1ce28804, 0x1ce30804,
+    0x1ce38804, 0x1ce40804, 0x1ce48804, 0x1ce50804, 0x1ce58804, 0x1ce60804, 0x1ce68804, 0x1ce70804,
+    0x1ce78804, 0x1ce80804, 0x1ce88804, 0x1ce90804, 0x1ce98804, 0x1cea0804, 0x1cea8804, 0x1ceb0804,
+    0x1ceb8804, 0x1cec0804, 0x1cec8804, 0x1ced0804, 0x1ced8804, 0x1cee0804, 0x1cee8804, 0x1cef0804,
+    0x1cef8804, 0x1cf00804, 0x1cf08804, 0x1cf10804, 0x1cf18804, 0x1cf20804, 0x1cf28804, 0x1cf30804,
+    0x1cf38804, 0x1cf40804, 0x1cf48804, 0x1cf50804, 0x1cf58804, 0x1cf60804, 0x1cf68804, 0x1cf70804,
+    0x1cf78804, 0x1cf80804, 0x1cf88804, 0x1cf90804, 0x1cf98804, 0x1cfa0804, 0x1cfa8804, 0x1cfb0804,
+    0x1cfb8804, 0x1cfc0804, 0x1cfc8804, 0x1cfd0804, 0x1cfd8804, 0x1cfe0804, 0x1cfe8804, 0x1cff0804,
+    0x1cff8804, 0x1d000804, 0x1d008804, 0x1d010804, 0x1d018804, 0x1d020804, 0x1d028804, 0x1d030804,
+    0x1d038804, 0x1d040804, 0x1d048804, 0x1d050804, 0x1d058804, 0x1d060804, 0x1d068804, 0x1d070804,
+    0x1d078804, 0x1d080804, 0x1d088804, 0x1d090804, 0x1d098804, 0x1d0a0804, 0x1d0a8804, 0x1d0b0804,
+    0x1d0b8804, 0x1d0c0804, 0x1d0c8804, 0x1d0d0804, 0x1d0d8804, 0x1d0e0804, 0x1d0e8804, 0x1d0f0804,
+    0x1d0f8804, 0x1d100804, 0x1d108804, 0x1d110804, 0x1d118804, 0x1d120804, 0x1d128804, 0x1d130804,
+    0x1d138804, 0x1d140804, 0x1d148804, 0x1d150804, 0x1d158804, 0x1d160804, 0x1d168804, 0x1d170804,
+    0x1d178804, 0x1d180804, 0x1d188804, 0x1d190804, 0x1d198804, 0x1d1a0804, 0x1d1a8804, 0x1d1b0804,
+    0x1d1b8804, 0x1d1c0804, 0x1d1c8804, 0x1d1d0804, 0x1d1d8804, 0x1d1e0804, 0x1d1e8804, 0x1d1f0804,
+    0x1d1f8804, 0x1d200804, 0x1d208804, 0x1d210804, 0x1d218804, 0x1d220804, 0x1d228804, 0x1d230804,
+    0x1d238804, 0x1d240804, 0x1d248804, 0x1d250804, 0x1d258804, 0x1d260804, 0x1d268804, 0x1d270804,
+    0x1d278804, 0x1d280804, 0x1d288804, 0x1d290804, 0x1d298804, 0x1d2a0804, 0x1d2a8804, 0x1d2b0804,
+    0x1d2b8804, 0x1d2c0804, 0x1d2c8804, 0x1d2d0804, 0x1d2d8804, 0x1d2e0804, 0x1d2e8804, 0x1d2f0804,
+    0x1d2f8804, 0x1d300804, 0x1d308804, 0x1d310804, 0x1d318804, 0x1d320804, 0x1d328804, 0x1d330804,
+    0x1d338804, 0x1d340804, 0x1d348804, 0x1d350804, 0x1d358804, 0x1d360804, 0x1d368804, 0x1d370804,
+    0x1d378804, 0x1d380804, 0x1d388804, 0x1d390804, 0x1d398804, 0x1d3a0804, 0x1d3a8804, 0x1d3b0804,
+    0x1d3b8804, 0x1d3c0804, 0x1d3c8804, 0x1d3d0804, 0x1d3d8804, 0x1d3e0804, 0x1d3e8804, 0x1d3f0804,
+    0x1d3f8804, 0x1d400804, 0x1d408804, 0x1d410804, 0x1d418804, 0x1d420804, 0x1d428804, 0x1d430804,
+    0x1d438804, 0x1d440804, 0x1d448804, 0x1d450804, 0x1d458804, 0x1d460804, 0x1d468804, 0x1d470804,
+    0x1d478804, 0x1d480804, 0x1d488804, 0x1d490804, 0x1d498804, 0x1d4a0804, 0x1d4a8804, 0x1d4b0804,
+    0x1d4b8804, 0x1d4c0804, 0x1d4c8804, 0x1d4d0804, 0x1d4d8804, 0x1d4e0804, 0x1d4e8804, 0x1d4f0804,
+    0x1d4f8804, 0x1d500804, 0x1d508804, 0x1d510804, 0x1d518804, 0x1d520804, 0x1d528804, 0x1d530804,
+    0x1d538804, 0x1d540804, 0x1d548804, 0x1d550804, 0x1d558804, 0x1d560804, 0x1d568804, 0x1d570804,
+    0x1d578804, 0x1d580804, 0x1d588804, 0x1d590804, 0x1d598804, 0x1d5a0804, 0x1d5a8804, 0x1d5b0804,
+    0x1d5b8804, 0x1d5c0804, 0x1d5c8804, 0x1d5d0804, 0x1d5d8804, 0x1d5e0804, 0x1d5e8804, 0x1d5f0804,
+    0x1d5f8804, 0x1d600804, 0x1d608804, 0x1d610804, 0x1d618804, 0x1d620804, 0x1d628804, 0x1d630804,
+    0x1d638804, 0x1d640804, 0x1d648804, 0x1d650804, 0x1d658804, 0x1d660804, 0x1d668804, 0x1d670804,
+    0x1d678804, 0x1d680804, 0x1d688804, 0x1d690804, 0x1d828804, 0x1d698804, 0x1d6a8804, 0x1d6b0804,
+    0x1d6b8804, 0x1d6c0804, 0x1d6c8804, 0x1d6d0804, 0x1d6d8804, 0x1d6e0804, 0x1d6e8804, 0x1d6f0804,
+    0x1d6f8804, 0x1d700804, 0x1d708804, 0x1d710804, 0x1d718804, 0x1d720804, 0x1d730804, 0x1d738804,
+    0x1d740804, 0x1d748804, 0x1d750804, 0x1d758804, 0x1d760804, 0x1d768804, 0x1d7a0804, 0x1d7a8804,
+    0x1d7b0804, 0x1d7b8804, 0x1d7c0804, 0x1d7c8804, 0x1d7d0804, 0x1d7d8804, 0x1d7e0804, 0x1d7e8804,
+    0x1d7f0804, 0x1d7f8804, 0x1d800804, 0x1d808804, 0x1d810804, 0x1d818804, 0x1d820804, 0x1d830804,
+    0x1d838804, 0x1d840804, 0x1d848804, 0x1d850804, 0x1d858804, 0x1d860804, 0x1d868804, 0x1d870804,
+    0x1d878804, 0x1d880804, 0x1d888804, 0x1d890804, 0x1d898804, 0x1d8a0804, 0x1d8a8804, 0x1d8b0804,
+    0x1d8b8804, 0x1d8c0804, 0x1d8c8804, 0x1d8d0804, 0x1d8d8804, 0x1d8e0804, 0x1d8e8804, 0x1d8f0804,
+    0x1d8f8804, 0x1d900804, 0x1d908804, 0x1d910804, 0x1d918804, 0x1d920804, 0x1d928804, 0x1d930804,
+    0x1d938804, 0x1d940804, 0x1d948804, 0x1d950804, 0x1d958804, 0x1d960804, 0x1d968804, 0x1d970804,
+    0x1d978804, 0x1d980804, 0x1d988804, 0x1d990804, 0x1d998804, 0x1d9a0804, 0x1d9a8804, 0x1d9b0804,
+    0x1d9b8804, 0x1d9c0804, 0x1d9c8804, 0x1d9d0804, 0x1d9d8804, 0x1d9e0804, 0x1d9e8804, 0x1d9f0804,
+    0x1d9f8804, 0x1da00804, 0x1da08804, 0x1da10804, 0x1da18804, 0x1da20804, 0x1da28804, 0x1da30804,
+    0x1da38804, 0x1da40804, 0x1da48804, 0x1da50804, 0x1da58804, 0x1da60804, 0x1da68804, 0x1da70804,
+    0x1da78804, 0x1da80804, 0x1da88804, 0x1da90804, 0x1da98804, 0x1daa0804, 0x1daa8804, 0x1dab0804,
+    0x1dab8804, 0x1dac0804, 0x1dac8804, 0x1dad0804, 0x1dad8804, 0x1dae0804, 0x1dae8804, 0x1daf0804,
+    0x1daf8804, 0x1db00804, 0x1db08804, 0x1db10804, 0x1db18804, 0x1db20804, 0x1db28804, 0x1db30804,
+    0x1db38804, 0x1db40804, 0x1db48804, 0x1db50804, 0x1db58804, 0x1db60804, 0x1db68804, 0x1db70804,
+    0x1db78804, 0x1db80804, 0x1db88804, 0x1db90804, 0x1db98804, 0x1dba0804, 0x1dba8804, 0x1dbb0804,
+    0x1dbb8804, 0x1dbc0804, 0x1dbc8804, 0x1dbd0804, 0x1dbd8804, 0x1dbe0804, 0x1dbe8804, 0x1dbf0804,
+    0x1dbf8804, 0x1dc00804, 0x1dc08804, 0x1dc10804, 0x1dc18804, 0x1dc20804, 0x1dc28804, 0x1dc30804,
+    0x1dc38804, 0x1dc40804, 0x1dc48804, 0x1dc50804, 0x1dc58804, 0x1dc60804, 0x1dc68804, 0x1dc70804,
+    0x1dc78804, 0x1dc80804, 0x1dc88804, 0x1dc90804, 0x1dc98804, 0x1dca0804, 0x1dca8804, 0x1dcb0804,
+    0x1dcb8804, 0x1dcc0804, 0x1dcc8804, 0x1dcd0804, 0x1dcd8804, 0x1dce0804, 0x1dce8804, 0x1dcf0804,
+    0x1dcf8804, 0x1dd00804, 0x1dd08804, 0x1dd10804, 0x1dd18804, 0x1dd20804, 0x1dd28804, 0x1dd30804,
+    0x1dd38804, 0x1dd40804, 0x1dd48804, 0x1dd50804, 0x1dd58804, 0x1dd60804, 0x1dd68804, 0x1dd70804,
+    0x1dd78804, 0x1dd80804, 0x1dd88804, 0x1dd90804, 0x1dd98804, 0x1dda0804, 0x1dda8804, 0x1ddb0804,
+    0x1ddb8804, 0x1ddc0804, 0x1ddc8804, 0x1ddd0804, 0x1ddd8804, 0x1dde0804, 0x1dde8804, 0x1ddf0804,
+    0x1ddf8804, 0x1de00804, 0x1de08804, 0x1de10804, 0x1de18804, 0x1de20804, 0x1de28804, 0x1de30804,
+    0x1de38804, 0x1de40804, 0x1de48804, 0x1de50804, 0x1de58804, 0x2cb8805, 0x1430805, 0x1d6a0804,
+    0x1d728804, 0x1d770804, 0x1d778804, 0x1d780804, 0x1d788804, 0x1d790804, 0x1d798804, 0x1de60804,
+    0x1de68804, 0x1de70804, 0x1de78804, 0x1de80804, 0x1de88804, 0x1de90804, 0x1de98804, 0x1dea0804,
+    0x1048809, 0x1e0d8804, 0x1e0e0804, 0x1e0e8804, 0x1e0f0804, 0x1e0f8804, 0x1e100804, 0x1e108804,
+    0x1e110804, 0x1e118804, 0x1e120804, 0x1e128804, 0x1e130804, 0x1e138804, 0x1e140804, 0x1e148804,
+    0x1e150804, 0x1e158804, 0x1e160804, 0x1e168804, 0x1e170804, 0x1e178804, 0x1e180804, 0x1e188804,
+    0x1e190804, 0x1e198804, 0x1e1a0804, 0x1990805, 0x1998805, 0x1e1a8804, 0x80007822, 0x1e1b0804,
+    0x1e2d0804, 0x80007862, 0x800078a2, 0x1e1b8804, 0x800078e2, 0x1e1c0804, 0x80007922, 0x1e2b0804,
+    0x1e2c0804, 0x80007962, 0x800079a2, 0x800079e2, 0x1e1d0804, 0x1e1d8804, 0x1e1e0804, 0x1e1e8804,
+    0x80007a22, 0x80007a62, 0x80007aa2, 0x80007ae2, 0x1e1f8804, 0x1e2e8804, 0x1e200804, 0x1e208804,
+    0x80007b22, 0x80007b62, 0x80007ba2, 0x1e210804, 0x80007be2, 0x80007c22, 0x1e218804, 0x80007c62,
+    0x1e228804, 0x80007ca2, 0x1e230804, 0x80007ce2, 0x1e238804, 0x1e240804, 0x1e248804, 0x1e250804,
+    0x80007d22, 0x80007d62, 0x80007da2, 0x80007de2, 0x1e260804, 0x80007e22, 0x80007e62, 0x1e268804,
+    0x80007ea2, 0x80007ee2, 0x80007f22, 0x1e270804, 0x1e280804, 0x80007f62, 0x80007fa2, 0x1e288804,
+    0x80007fe2, 0x1e290804, 0x80008022, 0x1e298804, 0x1e2a0804, 0x1e2d8804, 0x1e2f8804, 0x1e300804,
+    0x1e2e0804, 0x1e2f0804, 0x1e308804, 0x1e310804, 0x80008062, 0x800080a2, 0x800080e2, 0x80008122,
+    0x1318805, 0x1320805, 0x1328805, 0x80008162, 0x800081a4, 0x80008222, 0x1e1f0804, 0x1e258804,
+    0x1e2a8804, 0x1e1c8804, 0x1e220804, 0x1e278804, 0x1e2b8804, 0x1e2c8804, 0x1a430804, 0x1a438804,
+    0x1a440804, 0x1a448804, 0x1a450804, 0x1a458804, 0x1a460804, 0x1a468804, 0x1a470804, 0x1a478804,
+    0x1a480804, 0x1a488804, 0x1a490804, 0x1a498804, 0x1a4a0804, 0x1a4a8804, 0x1a4b0804, 0x1a4b8804,
+    0x1a4c0804, 0x1a4c8804, 0x1a4d0804, 0x1a4d8804, 0x1a4e0804, 0x1a4e8804, 0x1a4f0804, 0x1a4f8804,
+    0x1a500804, 0x1a508804, 0x1a510804, 0x1a518804, 0x1a520804, 0x1a528804, 0x1a530804, 0x1a538804,
+    0x1a540804, 0x1a548804, 0x1a550804, 0x1a558804, 0x1a560804, 0x1a568804, 0x1a570804, 0x14f0805,
+    0x14f8805, 0x1a578804, 0x1a580804, 0x1a588804, 0x1a590804, 0x1a598804, 0x1a5a0804, 0x1a5a8804,
+    0x1a5b0804, 0x1a5b8804, 0x1a5c0804, 0x1a5c8804, 0x1a5d0804, 0x1a5d8804, 0x1a5e0804, 0x1a5e8804,
+    0x1a5f0804, 0x1a5f8804, 0x1a600804, 0x1a608804, 0x1a610804, 0x1a618804, 0x1a620804, 0x1a628804,
+    0x1a630804, 0x1a638804, 0x1a640804, 0x1a648804, 0x1a650804, 0x1a658804, 0x1a660804, 0x1a668804,
+    0x1a670804, 0x1a678804, 0x1a680804, 0x1a688804, 0x1a690804, 0x1a698804, 0x1a6a0804, 0x1b3a8804,
+    0x1b3b0804, 0x1b3b8804, 0x1b3c0804, 0x1b3c8804, 0x1b3d0804, 0x1b3d8804, 0x1b3e0804, 0x1b3e8804,
+    0x1b3f0804, 0x1b3f8804, 0x1b400804, 0x1b408804, 0x1b410804, 0x1b418804, 0x1b420804, 0x1b428804,
+    0x1b430804, 0x1b438804, 0x1b440804, 0x1b448804, 0x1b450804, 0x1b458804, 0x1b460804, 0x1b468804,
+    0x1b470804, 0x1b478804, 0x1b480804, 0x1b488804, 0x1b490804, 0x1b498804, 0x1b4a0804, 0x1b4a8804,
+    0x1b4b0804, 0x1b4b8804, 0x1b4c8804, 0x1b4d0804, 0x1b4d8804, 0x1b4e0804, 0x1b4e8804, 0x1b4f0804,
+    0x1b4f8804, 0x1b500804, 0x1b508804, 0x1b510804, 0x1b518804, 0x1b520804, 0x1b528804, 0x1b530804,
+    0x1b538804, 0x1b540804, 0x1b548804, 0x0, 0x0, 0x1b550804, 0x1b558804, 0x1b560804, 0x1b568804,
+    0x1b570804, 0x1b578804, 0x1b580804, 0x1b588804, 0x1b590804, 0x1b598804, 0x1b5a0804, 0x1b5a8804,
+    0x1b5b0804, 0x1b5b8804, 0x1b5c0804, 0x1b5c8804, 0x3104, 0x3144, 0x3a04, 0x3a44, 0x3a84, 0xcc4,
+    0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0x1b5d0804, 0x0, 0x1518805, 0x1520805, 0x1300805,
+    0xfa58804, 0x21f8805, 0x2200805, 0x2208805, 0xfbb0804, 0x1b4c0804, 0xcc4, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804,
+    0xfd00804, 0xfd08804, 0x1f90805, 0x13f8805, 0x1180805, 0x1420805, 0x12e8805, 0x12f0805,
+    0x1088805, 0x1090805, 0x1188805, 0x1428805, 0x0, 0x0, 0x0, 0x0, 0x0, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0x1c1f0804, 0x1c200804, 0x1c218804, 0x1c248804, 0x1c258804, 0x1c270804, 0x1c280804, 0x1c298804,
+    0x1c2a0804, 0x1c2a8804, 0x1c2d0804, 0x1c2e0804, 0x1c2f8804, 0x1c308804, 0x1c330804, 0x1c340804,
+    0x1c348804, 0x1c350804, 0x1c388804, 0x1c3a0804, 0x1c3b8804, 0x1c3e8804, 0x1c408804, 0x1c420804,
+    0x1c430804, 0x1c440804, 0x1c458804, 0x1c480804, 0x1c488804, 0x1c4a0804, 0x1c4c0804, 0x1c4d8804,
+    0x1c4e0804, 0x1c4e8804, 0x1c4f0804, 0x1c1e8804, 0x1c208804, 0x1c220804, 0x1c250804, 0x1c260804,
+    0x1c278804, 0x1c288804, 0x1c2b0804, 0x1c2d8804, 0x1c2e8804, 0x1c300804, 0x1c310804, 0x1c338804,
+    0x1c390804, 0x1c3a8804, 0x1c3c0804, 0x1c3f0804, 0x1c490804, 0x1c410804, 0x1c438804, 0x1c460804,
+    0x1c4b0804, 0x1c4c8804, 0x1c4f8804, 0x1c500804, 0x1c3d8804, 0x1c210804, 0x1c228804, 0x1c240804,
+    0x1c290804, 0x1c268804, 0x1c2b8804, 0x1c468804, 0x1c318804, 0x1c328804, 0x1c2f0804, 0x1c358804,
+    0x1c398804, 0x1c3b0804, 0x1c3f8804, 0x1c448804, 0x1c4b8804, 0x1c4d0804, 0x1c498804, 0x1c4a8804,
+    0x1c508804, 0x1c3c8804, 0x1c418804, 0x1c230804, 0x1c470804, 0x1c428804, 0x1c450804, 0x1c400804,
+    0x1c3d0804, 0x1c1b0804, 0x1c1b8804, 0x1c1c0804, 0x1c1c8804, 0x1c1d0804, 0x1c1d8804, 0x1c1e0804,
+    0x1c1f8804, 0x1c238804, 0x1c478804, 0x1c2c0804, 0x1c3e0804, 0x1c510804, 0x1c520804, 0x1c528804,
+    0x1c538804, 0x1c540804, 0x1c558804, 0x1c568804, 0x1c570804, 0x1c580804, 0x1c590804, 0x1c5a0804,
+    0x1c5a8804, 0x1c548804, 0x1c598804, 0x1c320804, 0x1c2c8804, 0x1c360804, 0x1c368804, 0x1c518804,
+    0x1c530804, 0x1c550804, 0x1c560804, 0x1c370804, 0x1c588804, 0x1c378804, 0x1c380804, 0x1c5b0804,
+    0x1c5b8804, 0x1c578804, 0x1c5c8804, 0x1c5c0804, 0x1dea8804, 0x1deb0804, 0x1deb8804, 0x1dec0804,
+    0x1dec8804, 0x1ded0804, 0x1ded8804, 0x1dee0804, 0x1dee8804, 0x1def0804, 0x1def8804, 0x1df00804,
+    0x1df08804, 0x1df10804, 0x1df18804, 0x1df20804, 0x1df28804, 0x1df30804, 0x1df38804, 0x1df40804,
+    0x1df48804, 0x1df50804, 0x1df58804, 0x1df60804, 0x1df68804, 0x1df70804, 0x1df78804, 0x1df80804,
+    0x1df88804, 0x1df90804, 0x1df98804, 0x1dfa0804, 0x1dfa8804, 0x1dfb0804, 0x1dfb8804, 0x1dfc0804,
+    0x1dfc8804, 0x1dfd0804, 0x1dfd8804, 0x1dfe0804, 0x1dfe8804, 0x1dff0804, 0x1dff8804, 0x1e000804,
+    0x1e008804, 0x1e010804, 0x1e018804, 0x1e020804, 0x1e028804, 0x1e030804, 0x1e038804, 0x1e040804,
+    0x1e048804, 0x1e050804, 0x1e058804, 0x1e060804, 0x1e068804, 0x1e070804, 0x1e078804, 0x1e080804,
+    0x1e088804, 0x1e090804, 0x1e098804, 0x1e0a0804, 0x1e0a8804, 0x1e0b0804, 0x1e0b8804, 0x1e0c0804,
+    0x1e0c8804, 0x1e0d0804, 0x1a2a0804, 0x1a2a8804, 0x1a2b0804, 0x1a2b8804, 0x1a2c0804, 0x1a2c8804,
+    0x1a2d0804, 0x1a2d8804, 0x1a2e0804, 0x1a2e8804, 0x1a2f0804, 0x1a2f8804, 0x1a300804, 0x1a308804,
+    0x1a310804, 0x1a318804, 0x1a320804, 0x1a328804, 0x1a330804, 0x1a338804, 0x1a340804, 0x1a348804,
+    0x1a350804, 0x1a358804, 0x1a360804, 0x1a368804, 0x1a370804, 0x1a378804, 0x1a380804, 0x80008262,
+    0x800082a2, 0x1a388804, 0x1a390804, 0x1a398804, 0x1a3a0804, 0x1a3a8804, 0x1a3b0804, 0x1a3b8804,
+    0x1a3c0804, 0x1a3c8804, 0x1a3d0804, 0x1a3d8804, 0x1a3e0804, 0x1a3e8804, 0x1a3f0804, 0x1a3f8804,
+    0x1a400804, 0x1a408804, 0x1a410804, 0x1a418804, 0x1a420804, 0x1a428804, 0x3cc4, 0x3d04, 0x3d44,
+    0x2cc0805, 0x1358805, 0x13a0805, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1b5d8804, 0x1b5e0804, 0x1b5e8804,
+    0x1b5f0804, 0x1b5f8804, 0x1b600804, 0x1b608804, 0x1b610804, 0x1b618804, 0x1b620804, 0x1b628804,
+    0x1b630804, 0x1b638804, 0x1b640804, 0x1b648804, 0x1b650804, 0x1b658804, 0x1b660804, 0x1b668804,
+    0x1b670804, 0x1b678804, 0x1b680804, 0x1b688804, 0x1b690804, 0x1b698804, 0x1b6a0804, 0x1b6a8804,
+    0x1b6b0804, 0x1b6b8804, 0x1b6c0804, 0x1b6c8804, 0x1b6d0804, 0x1b6d8804, 0x1b6e0804, 0x1b6e8804,
+    0x1b6f0804, 0x1b6f8804, 0x1b700804, 0x1b708804, 0x1b710804, 0x1b718804, 0x1b720804, 0x1b728804,
+    0x1b730804, 0x1b738804, 0x1b740804, 0x1b748804, 0x1b750804, 0x1b758804, 0x1b760804, 0x1b768804,
+    0x1b770804, 0x1b778804, 0x1b780804, 0x1b788804, 0x1b790804, 0x1b798804, 0x1b7a0804, 0x1b7a8804,
+    0x1b7b0804, 0x1b7b8804, 0x1b7c0804, 0x1b7c8804, 0x1b7d0804, 0x1b7d8804, 0x1b7e0804, 0x1b7e8804,
+    0x1b7f0804, 0x1b7f8804, 0x1b800804, 0x1b808804, 0x1b810804, 0x1b818804, 0x1b820804, 0x1b828804,
+    0x1b830804, 0x1b838804, 0x1b840804, 0x1b848804, 0x1b850804, 0x1b858804, 0x1b860804, 0x1b868804,
+    0x1b870804, 0x1b878804, 0x1b880804, 0x1b888804, 0x1b890804, 0x1b898804, 0x1b8a0804, 0x1b8a8804,
+    0x1b8b0804, 0x1b8b8804, 0x1b8c0804, 0x1b8c8804, 0x1b8d0804, 0x1b8d8804, 0x1b8e0804, 0x1b8e8804,
+    0x1b8f0804, 0x1b8f8804, 0x1b900804, 0x1b908804, 0x1b910804, 0x1b918804, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0xfcc8804, 0x800082e2, 0x80008323, 0x2cf8805, 0x2d00805, 0x2d08805, 0x2d10805, 0x2d18805,
+    0x2d20805, 0x2d28805, 0x2d30805, 0x2d38805, 0x2d40805, 0x2d48805, 0x2d50805, 0x2d58805,
+    0x2d60805, 0x2d68805, 0x2d70805, 0x2d78805, 0x2d80805, 0x2d88805, 0x2d90805, 0x2d98805,
+    0x2da0805, 0x2da8805, 0x2db0805, 0x2db8805, 0x2dc0805, 0x2dc8805, 0x2dd0805, 0x2dd8805,
+    0x2de0805, 0x2de8805, 0x2df0805, 0x1a6a8804, 0x1a6b0804, 0x1a6b8804, 0x1a6c0804, 0x1a6c8804,
+    0x1a6d0804, 0x1a6d8804, 0x1a6e0804, 0x1a6e8804, 0x1a6f0804, 0x1a6f8804, 0x1a700804, 0x1a708804,
+    0x1a710804, 0x1a718804, 0x1a720804, 0x1a728804, 0x1a730804, 0x1a738804, 0x1a740804, 0x1a748804,
+    0x1a750804, 0x1a758804, 0x1a760804, 0x1a768804, 0x1a770804, 0x1a778804, 0x1a780804, 0x1690805,
+    0x1698805, 0x1b920804, 0x1b928804, 0x1b930804, 0x1b938804, 0x1b940804, 0x1b948804, 0x1b950804,
+    0x1b958804, 0x1b960804, 0x1b968804, 0x1b970804, 0x1b978804, 0x1b980804, 0x1b988804, 0x1b990804,
+    0x1b998804, 0x1b9a0804, 0x1b9a8804, 0x1b9b0804, 0x1b9b8804, 0x1b9c0804, 0x1b9c8804, 0x1b9d0804,
+    0x1b9d8804, 0x1b9e0804, 0x1b9e8804, 0x1b9f0804, 0x1b9f8804, 0x1ba00804, 0x1ba08804, 0x1ba10804,
+    0x1ba18804, 0x1ba20804, 0x1ba28804, 0x1ba30804, 0x1ba38804, 0x1ba40804, 0x1ba48804, 0x1ba50804,
+    0x1ba58804, 0x1ba60804, 0x1ba68804, 0x1ba70804, 0x1ba78804, 0x1ba80804, 0x1bac8804, 0x1bad0804,
+    0x1bad8804, 0x1bae0804, 0x1bae8804, 0x1baf0804, 0x1ba88804, 0x80008383, 0x1ba98804, 0x1baa0804,
+    0x1baa8804, 0x1b950808, 0x1b950808, 0x1b9f8808, 0x1b9f8808, 0x1bab0804, 0x1bab8804, 0x1bac0804,
+    0x1bb80804, 0x1baf8804, 0x1bb08804, 0x1bb10804, 0x1bb10808, 0x1bb18804, 0x1bb20804, 0x1bb28804,
+    0x1bb30804, 0x1bb38804, 0x1bb40804, 0x1ba90804, 0x1bb00804, 0x1bb78804, 0x1bb48804, 0x1bb50804,
+    0x1bb60804, 0x1bb68804, 0x1bb70804, 0x1bb58804, 0x3104, 0x3ac4, 0x3b04, 0x3b44, 0x3b84, 0x3bc4,
+    0x3c04, 0x3c44, 0x3c84, 0x0, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x2210805, 0x2218805,
+    0x2220805, 0x2228805, 0x2230805, 0x2238805, 0x2240805, 0xfa60804, 0x1528805, 0x1530805,
+    0x1538805, 0x1540805, 0x2248805, 0x2250805, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xd04, 0xd04,
+    0xd04, 0xd04, 0xd04, 0xd04, 0xcc4, 0xcc4, 0xd04, 0xd84, 0x112c8808, 0x11300808, 0x30c4, 0x30c4,
+    0x3104, 0x3244, 0x3144, 0x1bdb0804, 0x1bdb8804, 0x1bdc0804, 0x1bdc8804, 0x1bdd0804, 0x1bdd8804,
+    0x1bde0804, 0x1bde8804, 0x1bdf0804, 0x1bdf8804, 0x1be00804, 0x1be08804, 0x1be10804, 0x1be18804,
+    0x1be20804, 0x1be38804, 0x1be40804, 0x1be48804, 0x1be50804, 0x1be58804, 0x1be60804, 0x1be68804,
+    0x1be70804, 0x1be78804, 0x1be80804, 0x1be88804, 0x1be90804, 0x1be98804, 0x1bea0804, 0x1bea8804,
+    0x1beb8804, 0x1bec0804, 0x1bec8804, 0x1bed0804, 0x1bed8804, 0x1bee8804, 0x1bef0804, 0x1bef8804,
+    0x1bf00804, 0x1bf08804, 0x1bf10804, 0x1bf18804, 0x1bf20804, 0x1bf30804, 0x1bf38804, 0x1bf40804,
+    0x1bf58804, 0x3084, 0x1bf60804, 0x1bf68804, 0x1bf70804, 0x1bf78804, 0x1bf80804, 0x1bf88804,
+    0x1bf90804, 0x1bf98804, 0x1bfa0804, 0x1bfa8804, 0x1bfb0804, 0x1bfb8804, 0x1bfc0804, 0x1bfc8804,
+    0x1bfd0804, 0x1bfd8804, 0x1be28804, 0x1be30804, 0x1beb0804, 0x1bee0804, 0x1bf28804, 0x1bf48804,
+    0x1bf50804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804,
+    0xfcf8804, 0xfd00804, 0xfd08804, 0x16a0805, 0x16a8805, 0x1438805, 0x1308805, 0x1548805,
+    0x1550805, 0x1080805, 0x2df8805, 0x2e00805, 0x2e08805, 0x2e10805, 0x2e18805, 0x2e20805,
+    0x2e28805, 0x2e30805, 0x2e38805, 0x2e40805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x2e48805, 0x2e50805, 0x2e58805, 0x2e60805, 0x2e68805, 0x2e70805, 0x2e78805, 0x2e80805,
+    0x2e88805, 0x3104, 0x32c4, 0x3144, 0x189d0804, 0x189d8804, 0x189e0804, 0x189e8804, 0x189f0804,
+    0x189f8804, 0x18a00804, 0x18a08804, 0x18a18804, 0x18a20804, 0x18a28804, 0x18a30804, 0x18a38804,
+    0x18a40804, 0x18a48804, 0x18a50804, 0x18a58804, 0x18a60804, 0x18a68804, 0x18a70804, 0x18a78804,
+    0x18a80804, 0x18a90804, 0x18aa0804, 0x18ab0804, 0x18ac8804, 0x18ae0804, 0x18af0804, 0x18af8804,
+    0x18b08804, 0x18aa8804, 0x18ab8804, 0x18ad0804, 0x18b10804, 0x18b18804, 0x18b20804, 0x18b28804,
+    0x18b30804, 0x18b38804, 0x18b40804, 0x18b48804, 0x18a98804, 0x18ae8804, 0x18a10804, 0x18b00804,
+    0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804,
+    0xfd00804, 0xfd08804, 0x189d0808, 0x18ac0804, 0x18ad8804, 0x18a88804, 0x18a08832, 0x18a90832,
+    0x1a840804, 0x1a840808, 0x1a848804, 0x1a848808, 0x1a848808, 0x1a850804, 0x1a850808, 0x1a858804,
+    0x1a858808, 0x1a860804, 0x1a860808, 0x1a868804, 0x1a868808, 0x1a868808, 0x1a870804, 0x1a870808,
+    0x1a878804, 0x1a880804, 0x1a888804, 0x1a888808, 0x1a890804, 0x1a890808, 0x1a898804, 0x1a898808,
+    0x1a8a0804, 0x1a8a0808, 0x1a8a0808, 0x1a8a8804, 0x1a8a8808, 0x1a8b0804, 0x1a8b8804, 0x1a8b8808,
+    0x1a8c0804, 0x1a8c8804, 0x1a8d0804, 0x1a8d8804, 0x1a8e0804, 0x1a8e8804, 0x3084, 0x1a8f0804,
+    0x1a8f0808, 0x1a8f8804, 0x1a900804, 0x1a900808, 0x1a908804, 0x1a908808, 0x1a910804, 0x1a910808,
+    0x1a918804, 0x1a920804, 0x1a928804, 0x1a930804, 0x22b0805, 0x22b8805, 0x22c0805, 0x22c8805,
+    0x19f30804, 0x19f38804, 0x19f40804, 0x19f48804, 0x19f50804, 0x19f58804, 0x19f60804, 0x19f68804,
+    0x19f70804, 0x19f78804, 0x19f98804, 0x19fa0804, 0x19fa8804, 0x19fb0804, 0x19fb8804, 0x19fc0804,
+    0x19fc8804, 0x19fd0804, 0x19fd8804, 0x19fe0804, 0x19fe8804, 0x19ff0804, 0x19ff8804, 0x1a000804,
+    0x1a008804, 0x1a010804, 0x1a018804, 0x1a028804, 0x1a038804, 0x1a040804, 0x1a048804, 0x1a050804,
+    0x1a058804, 0x1a060804, 0x1a068804, 0x1a070804, 0x1a020804, 0x1a030804, 0x1a080804, 0x1a088804,
+    0x1a090804, 0x1a098804, 0x1a0a0804, 0x1a0a8804, 0x1a0b0804, 0x1a0b8804, 0x1a0c0804, 0x1a0c8804,
+    0x1a0d0804, 0x1a0d8804, 0x1a0e0804, 0x1a0e8804, 0x1a0f0804, 0x1a0f8804, 0x1a078804, 0x3084,
+    0x14d0805, 0x14d8805, 0x21c0805, 0x21c8805, 0x21d0805, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x19f80804,
+    0x19f88804, 0x19f90804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1c5d0804, 0x1c5d8804, 0x1c5e0804, 0x1c5e8804,
+    0x1c5f0804, 0x1c5f8804, 0x1c600804, 0x1c608804, 0x1c610804, 0x1c618804, 0x1c620804, 0x1c628804,
+    0x1c630804, 0x1c638804, 0x1c640804, 0x1c648804, 0x1c650804, 0x1c658804, 0x1c660804, 0x1c668804,
+    0x1c670804, 0x1c678804, 0x1c680804, 0x1c688804, 0x1c690804, 0x1c698804, 0x1c6a0804, 0x1c6a8804,
+    0x1c6b0804, 0x1c6b8804, 0x1c6c0804, 0x1c6c8804, 0x1c6d0804, 0x1c6d8804, 0x1c6e0804, 0x1c6e8804,
+    0x1658805, 0x1660805, 0x11cb8808, 0x11d78808, 0x12260808, 0x12350808, 0x12398808, 0x12398808,
+    0x127a0808, 0x12828808, 0x12470808, 0x12cf8810, 0x12d08810, 0x12d18810, 0x12d28810, 0x12d38810,
+    0x12d48810, 0x12d58810, 0x12d78810, 0x12d88810, 0x12d98810, 0x12da8810, 0x12db8810, 0x12dc8810,
+    0x12de8810, 0x12df8810, 0x12e08810, 0x12e18810, 0x12e28810, 0x12e38810, 0x12e58810, 0x12e68810,
+    0x12e78810, 0x12e88810, 0x12e98810, 0x12ea8810, 0x12eb8810, 0x12ec8810, 0x12ed8810, 0x12ee8810,
+    0x12ef8810, 0x12f08810, 0x12f28810, 0x12f38810, 0x12d68810, 0x12dd8810, 0x12e48810, 0x12f18810,
+    0x12f48810, 0x12f58810, 0x12f60810, 0x12f70810, 0x12f78810, 0x12f80810, 0x12f88810, 0x12f98810,
+    0x12fa0810, 0x2258805, 0x2260805, 0x2268805, 0x2270805, 0x2278805, 0x2280805, 0x2288805,
+    0x2290805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x150f0804, 0x150f0808, 0x150f0808, 0x150f0808, 0x3104,
+    0x150f0808, 0x150f0808, 0x150f0808, 0x150f0808, 0x3144, 0x3144, 0x0, 0x150f8804, 0x15100804,
+    0x0, 0x0, 0x0, 0x150f0808, 0xfd30804, 0xfd50804, 0xfd58804, 0xfe48804, 0xfed0804, 0xff78804,
+    0xff80804, 0x10058804, 0x10188804, 0x104f0804, 0x10598804, 0x10640804, 0x106f8804, 0x10868804,
+    0x10908804, 0x10a00804, 0x10a98804, 0x10a08804, 0x10aa0804, 0x10a68804, 0x10a38804, 0x10b50804,
+    0x10ac8804, 0x10ad0804, 0x10b78804, 0x10cf0804, 0x10d40804, 0x10fd8804, 0x110d8804, 0x110e8804,
+    0x110f0804, 0x111e8804, 0x11258804, 0x112e8804, 0x11450804, 0x11538804, 0x11778804, 0x11780804,
+    0x11908804, 0x11978804, 0x119a8804, 0x119d0804, 0x11a28804, 0x120c8804, 0xfd1083a, 0x800083e3,
+    0xfde083a, 0xfe40804, 0xff5883a, 0x1003883a, 0x100a883a, 0x1028883a, 0x103a883a, 0x1048083a,
+    0x1055883a, 0x1062083a, 0x106b083a, 0x1084883a, 0x108c083a, 0x10900804, 0x109e083a, 0x10b3083a,
+    0x10b5883a, 0x10c9883a, 0x10fb883a, 0x110b883a, 0x112c883a, 0xfd10828, 0xfd68828, 0xfd88828,
+    0xfd58828, 0xfde0828, 0xff58828, 0x10038828, 0x100d0828, 0x100f8828, 0x10188828, 0x10288828,
+    0x104f0828, 0x10620828, 0x10848828, 0x109b8828, 0x109e0828, 0x10a78828, 0x10ac8828, 0x10ad0828,
+    0x10b58828, 0x10fb8828, 0x110b8828, 0x110e8828, 0x111c0828, 0x11238828, 0x11780828, 0x118f8828,
+    0x11900828, 0x11910828, 0x11a10828, 0x11a18828, 0x1048082a, 0x10c9882a, 0x110b882a, 0x1123882a,
+    0x118f882a, 0x1190082a, 0x119c882a, 0x11a1082a, 0x11a1882a, 0x110f8804, 0xfe50804, 0xff90804,
+    0x10248804, 0x10870804, 0x10910804, 0x10b90804, 0x10d18804, 0x10e00804, 0x10ec0804, 0x11008804,
+    0x11478804, 0x10358804, 0x12188828, 0x80008442, 0x80008483, 0x10518804, 0x10550804, 0x10b80804,
+    0x11140804, 0x11230804, 0xfe60804, 0xff98804, 0x10250804, 0x10310804, 0x10648804, 0x10788804,
+    0x10878804, 0x10960804, 0x10b98804, 0x10d68804, 0x10ec8804, 0x10f48804, 0x11268804, 0x11340804,
+    0x11480804, 0xfd40804, 0xfdb0804, 0xffe0804, 0x10090804, 0x10118804, 0x10180804, 0x100f0804,
+    0x10520804, 0x10ab0804, 0x10f90804, 0x11148804, 0x11560804, 0xfdb8828, 0xfeb0828, 0xff28828,
+    0x800084e2, 0x10160828, 0x10210828, 0x105e0828, 0x102a8828, 0x11160828, 0x104f8828, 0x10530828,
+    0x104c0828, 0x10518828, 0x105c0828, 0x10790828, 0x10788828, 0x106d0828, 0x10880828, 0x111f0828,
+    0x10918828, 0x10968828, 0x108e0828, 0x10ae0828, 0x10bd8828, 0x10ed0828, 0x10f20828, 0x11010828,
+    0x11110828, 0x11210828, 0x110d8828, 0x11270828, 0x112a8828, 0x11430828, 0x114a8828, 0x114c8828,
+    0x11518828, 0x11950828, 0xcc4, 0xcc4, 0xd04, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xcc4,
+    0x10c98808, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xd04, 0xd04, 0xcc4, 0x11628808, 0x80008522,
+    0x80008563, 0x800085c2, 0x80008602, 0x80008642, 0x80008682, 0x800086c2, 0x10288808, 0x102d0808,
+    0x10620808, 0x106b0808, 0x106d0808, 0x10868808, 0x108c0808, 0x108e0808, 0x10cc0808, 0x10ce8808,
+    0x10e90808, 0x80008702, 0x11430808, 0xfd88808, 0xfde0808, 0xfea8808, 0x100d0808, 0x10210808,
+    0x10750808, 0x80008742, 0x10b58808, 0x10f20808, 0x80008782, 0x112c8808, 0x800087c2, 0x80008802,
+    0x80008842, 0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xd04, 0xcc4, 0xd04, 0xd04, 0xcc4, 0xd04, 0x80008882,
+    0x800088c2, 0x80008902, 0x80008942, 0x80008982, 0x800089c2, 0x80008a02, 0x80008a42, 0x80008a83,
+    0x80008ae3, 0x80008b42, 0x80008b82, 0x80008bc2, 0x80008c02, 0x80008c42, 0x80008c82, 0x80008cc2,
+    0x80008d02, 0x80008d42, 0x80008d82, 0x80008dc3, 0x80008e23, 0x80008e83, 0x80008ee3, 0x80008f42,
+    0x80008f82, 0x80008fc2, 0x80009002, 0x80009043, 0x800090a3, 0x80009102, 0x80009142, 0x80009182,
+    0x800091c2, 0x80009202, 0x80009242, 0x80009282, 0x800092c2, 0x80009302, 0x80009342, 0x80009382,
+    0x800093c2, 0x80009402, 0x80009442, 0x80009482, 0x800094c2, 0x80009503, 0x80009563, 0x800095c2,
+    0x80009602, 0x80009642, 0x80009682, 0x800096c2, 0x80009702, 0x80009742, 0x80009782, 0x800097c3,
+    0x80009823, 0x80009882, 0x800098c2, 0x80009902, 0x80009942, 0x80009982, 0x800099c2, 0x80009a02,
+    0x80009a42, 0x80009a82, 0x80009ac2, 0x80009b02, 0x80009b42, 0x80009b82, 0x80009bc2, 0x80009c02,
+    0x80009c42, 0x80009c82, 0x80009cc2, 0x80009d03, 0x80009d63, 0x80009dc3, 0x80009e23, 0x80009e83,
+    0x80009ee3, 0x80009f43, 0x80009fa3, 0x8000a002, 0x8000a042, 0x8000a082, 0x8000a0c2, 0x8000a102,
+    0x8000a142, 0x8000a182, 0x8000a1c2, 0x8000a203, 0x8000a263, 0x8000a2c2, 0x8000a302, 0x8000a342,
+    0x8000a382, 0x8000a3c2, 0x8000a402, 0x8000a443, 0x8000a4a3, 0x8000a503, 0x8000a563, 0x8000a5c3,
+    0x8000a623, 0x8000a682, 0x8000a6c2, 0x8000a702, 0x8000a742, 0x8000a782, 0x8000a7c2, 0x8000a802,
+    0x8000a842, 0x8000a882, 0x8000a8c2, 0x8000a902, 0x8000a942, 0x8000a982, 0x8000a9c2, 0x8000aa03,
+    0x8000aa63, 0x8000aac3, 0x8000ab23, 0x8000ab82, 0x8000abc2, 0x8000ac02, 0x8000ac42, 0x8000ac82,
+    0x8000acc2, 0x8000ad02, 0x8000ad42, 0x8000ad82, 0x8000adc2, 0x8000ae02, 0x8000ae42, 0x8000ae82,
+    0x8000aec2, 0x8000af02, 0x8000af42, 0x8000af82, 0x8000afc2, 0x8000b002, 0x8000b042, 0x8000b082,
+    0x8000b0c2, 0x8000b102, 0x8000b142, 0x8000b182, 0x8000b1c2, 0x8000b202, 0x8000b242, 0x8000b282,
+    0x8000b2c2, 0x8000b302, 0x8000b343, 0x10f10804, 0x10f18804, 0x8000b3a3, 0x10030804, 0x8000b402,
+    0x8000b442, 0x8000b482, 0x8000b4c2, 0x8000b503, 0x8000b563, 0x8000b5c3, 0x8000b623, 0x8000b683,
+    0x8000b6e3, 0x8000b743, 0x8000b7a3, 0x8000b803, 0x8000b863, 0x8000b8c3, 0x8000b923, 0x8000b983,
+    0x8000b9e3, 0x8000ba43, 0x8000baa3, 0x8000bb03, 0x8000bb63, 0x8000bbc3, 0x8000bc23, 0x8000bc82,
+    0x8000bcc2, 0x8000bd02, 0x8000bd42, 0x8000bd82, 0x8000bdc2, 0x8000be03, 0x8000be63, 0x8000bec3,
+    0x8000bf23, 0x8000bf83, 0x8000bfe3, 0x8000c043, 0x8000c0a3, 0x8000c103, 0x8000c163, 0x8000c1c2,
+    0x8000c202, 0x8000c242, 0x8000c282, 0x8000c2c2, 0x8000c302, 0x8000c342, 0x8000c382, 0x8000c3c3,
+    0x8000c423, 0x8000c483, 0x8000c4e3, 0x8000c543, 0x8000c5a3, 0x8000c603, 0x8000c663, 0x8000c6c3,
+    0x8000c723, 0x8000c783, 0x8000c7e3, 0x8000c843, 0x8000c8a3, 0x8000c903, 0x8000c963, 0x8000c9c3,
+    0x8000ca23, 0x8000ca83, 0x8000cae3, 0x8000cb42, 0x8000cb82, 0x8000cbc2, 0x8000cc02, 0x8000cc43,
+    0x8000cca3, 0x8000cd03, 0x8000cd63, 0x8000cdc3, 0x8000ce23, 0x8000ce83, 0x8000cee3, 0x8000cf43,
+    0x8000cfa3, 0x8000d002, 0x8000d042, 0x8000d082, 0x8000d0c2, 0x8000d102, 0x8000d142, 0x8000d182,
+    0x8000d1c2, 0x8000d202, 0x8000d242, 0x112a0810, 0x112a0804, 0x11400810, 0x11400804, 0x8000d282,
+    0x8000d2c2, 0x8000d303, 0x8000d363, 0x8000d3c3, 0x8000d423, 0x8000d483, 0x8000d4e3, 0x8000d542,
+    0x8000d582, 0x8000d5c3, 0x8000d623, 0x8000d683, 0x8000d6e3, 0x8000d743, 0x8000d7a3, 0x8000d802,
+    0x8000d842, 0x8000d883, 0x8000d8e3, 0x8000d943, 0x8000d9a3, 0x8000da02, 0x8000da42, 0x8000da83,
+    0x8000dae3, 0x8000db43, 0x8000dba3, 0x8000dc02, 0x8000dc42, 0x8000dc83, 0x8000dce3, 0x8000dd43,
+    0x8000dda3, 0x8000de03, 0x8000de63, 0x8000dec2, 0x8000df02, 0x8000df43, 0x8000dfa3, 0x8000e003,
+    0x8000e063, 0x8000e0c3, 0x8000e123, 0x8000e182, 0x8000e1c2, 0x8000e203, 0x8000e263, 0x8000e2c3,
+    0x8000e323, 0x8000e383, 0x8000e3e3, 0x8000e442, 0x8000e482, 0x8000e4c3, 0x8000e523, 0x8000e583,
+    0x8000e5e3, 0x8000e643, 0x8000e6a3, 0x8000e702, 0x8000e742, 0x8000e783, 0x8000e7e3, 0x8000e843,
+    0x8000e8a3, 0x8000e902, 0x8000e942, 0x8000e983, 0x8000e9e3, 0x8000ea43, 0x8000eaa3, 0x8000eb02,
+    0x8000eb42, 0x8000eb83, 0x8000ebe3, 0x8000ec43, 0x8000eca3, 0x8000ed03, 0x8000ed63, 0x8000edc2,
+    0x8000ee03, 0x8000ee63, 0x8000eec3, 0x8000ef22, 0x8000ef62, 0x8000efa3, 0x8000f003, 0x8000f063,
+    0x8000f0c3, 0x8000f123, 0x8000f183, 0x8000f1e2, 0x8000f222, 0x8000f263, 0x8000f2c3, 0x8000f323,
+    0x8000f383, 0x8000f3e3, 0x8000f443, 0x8000f4a2, 0x8000f4e2, 0x8000f522, 0x8000f562, 0x8000f5a2,
+    0x8000f5e2, 0x8000f622, 0x8000f662, 0x8000f6a2, 0x8000f6e2, 0x8000f722, 0x8000f762, 0x8000f7a2,
+    0x8000f7e2, 0x8000f823, 0x8000f883, 0x8000f8e4, 0x8000f964, 0x8000f9e4, 0x8000fa64, 0x8000fae4,
+    0x8000fb64, 0x8000fbe3, 0x8000fc43, 0x8000fca4, 0x8000fd24, 0x8000fda4, 0x8000fe24, 0x8000fea4,
+    0x8000ff24, 0x8000ffa3, 0x80010003, 0x80010064, 0x800100e4, 0x80010164, 0x800101e4, 0x80010264,
+    0x800102e4, 0x80010363, 0x800103c3, 0x80010424, 0x800104a4, 0x80010524, 0x800105a4, 0x80010624,
+    0x800106a4, 0x80010723, 0x80010783, 0x800107e4, 0x80010864, 0x800108e4, 0x80010964, 0x800109e4,
+    0x80010a64, 0x80010ae3, 0x80010b43, 0x80010ba4, 0x80010c24, 0x80010ca4, 0x80010d24, 0x80010da4,
+    0x80010e24, 0x80010ea2, 0x80010ee2, 0x80010f23, 0x80010f82, 0x80010fc3, 0x80011022, 0x80011063,
+    0x800110c2, 0x80011102, 0x80011142, 0x80011182, 0x800111c2, 0x2600805, 0x11958804, 0x2600805,
+    0x2610805, 0x80011202, 0x80011243, 0x800112a2, 0x800112e3, 0x80011342, 0x80011383, 0x800113e2,
+    0x80011422, 0x80011462, 0x800114a2, 0x800114e2, 0x80011522, 0x80011562, 0x800115a2, 0x800115e2,
+    0x80011622, 0x80011663, 0x800116c3, 0x80011722, 0x80011763, 0x800117c2, 0x80011802, 0x80011842,
+    0x80011882, 0x800118c2, 0x80011902, 0x80011942, 0x80011982, 0x800119c2, 0x80011a03, 0x80011a63,
+    0x80011ac2, 0x80011b02, 0x80011b42, 0x80011b83, 0x80011be2, 0x80011c22, 0x80011c62, 0x80011ca2,
+    0x80011ce2, 0x80011d22, 0x80011d62, 0x25a0805, 0x80011da3, 0x80011e02, 0x80011e43, 0x80011ea2,
+    0x80011ee3, 0x80011f42, 0x80011f82, 0x80011fc2, 0x80012002, 0x80012042, 0x25a8805, 0x2608805,
+    0x1048809, 0x1048809, 0x1048809, 0x1048809, 0x1048809, 0x1048809, 0x1048809, 0x1048837,
+    0x1048809, 0x1048809, 0x1048809, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1098805, 0x1098837, 0x10a0805,
+    0x10a8805, 0x10b0805, 0x10b8805, 0x1ca0805, 0x1060805, 0x18b8805, 0x18c0805, 0x18c8805,
+    0x18d0805, 0x18f0805, 0x18f8805, 0x1900805, 0x1908805, 0x1d98805, 0x1da0805, 0x1dc8805,
+    0x1dd0805, 0x13f0809, 0x80012082, 0x800120c3, 0x1dd8805, 0x1038805, 0x1040805, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x1048837, 0x1d78805, 0x1d88805, 0x1df8805, 0x80012122, 0x80012163, 0x1e00805,
+    0x800121c2, 0x80012203, 0x1e18805, 0x18d8805, 0x18e0805, 0x1e20805, 0x80012262, 0x13e0805,
+    0x1050805, 0x1e28805, 0x1e38805, 0x1e48805, 0x1e50805, 0x1de0805, 0x33b0805, 0x19a0805,
+    0x19a8805, 0x800122a2, 0x800122e2, 0x80012322, 0x1d50805, 0x1ce8805, 0x1de8805, 0x1df0805,
+    0x1d10805, 0x11e0805, 0x1e40805, 0x1d18805, 0x3390805, 0x10d0805, 0x1e30805, 0x17c8805,
+    0x17d0805, 0x80012364, 0x17d8805, 0x17e0805, 0x17e8805, 0x17f0805, 0x17f8805, 0x1800805,
+    0x1808805, 0x1048809, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0xfcc0828, 0x10480828, 0xfce0828, 0xfce8828, 0xfcf0828, 0xfcf8828, 0xfd00828, 0xfd08828,
+    0x3330829, 0x3388829, 0x3358829, 0x1940829, 0x1948829, 0x108c0828, 0xfcc082a, 0xfcc882a,
+    0xfcd082a, 0xfcd882a, 0xfce082a, 0xfce882a, 0xfcf082a, 0xfcf882a, 0xfd0082a, 0xfd0882a,
+    0x333082b, 0x338882b, 0x335882b, 0x194082b, 0x194882b, 0xfd1082a, 0x1003882a, 0x109e082a,
+    0x1132082a, 0x100d082a, 0x103a882a, 0x1062082a, 0x106b082a, 0x1084882a, 0x108c082a, 0x10b5882a,
+    0x10e9082a, 0x10fb882a, 0xfbc0804, 0xfbc8804, 0xfbd0804, 0xfbd8804, 0xfbe0804, 0xfbe8804,
+    0xfbf0804, 0xfbf8804, 0x800123e2, 0xfc00804, 0xfc08804, 0xfc10804, 0xfc18804, 0xfc20804,
+    0xfc28804, 0xfc30804, 0xfc38804, 0xfc40804, 0xfc48804, 0xfc50804, 0xfc58804, 0xfc60804,
+    0xfc68804, 0xfc70804, 0xfc78804, 0xfc80804, 0xfc90804, 0xfc98804, 0xfca0804, 0xfca8804,
+    0xfcb0804, 0xfcb8804, 0x4244, 0x4284, 0x42c4, 0x42c4, 0x4304, 0x4344, 0x4384, 0x43c4, 0xd44,
+    0xd44, 0xd44, 0x4404, 0x4444, 0xd84, 0xd84, 0xd84, 0xd84, 0x4484, 0xd84, 0xd84, 0xd84, 0xd44,
+    0x44c4, 0x4504, 0x4544, 0x4584, 0xd44, 0xd44, 0xd04, 0xd04, 0xd04, 0xd04, 0xcc4, 0x80012423,
+    0x80012483, 0xfeb0816, 0x800124e2, 0x2eb0805, 0x80012523, 0x80012583, 0x100f8814, 0x2eb8805,
+    0x800125e2, 0x1028880a, 0x103a8816, 0x103a8816, 0x103a8816, 0x103a880a, 0x80012622, 0x10480816,
+    0x10480816, 0x106b0816, 0x106b080a, 0x2ec0805, 0x108c0816, 0x80012662, 0x2ec8805, 0x2ed0805,
+    0x10b58816, 0x10c00816, 0x10c98816, 0x10c98816, 0x10c98816, 0x2ed8805, 0x2ee0805, 0x800126a2,
+    0x800126e3, 0x80012742, 0x2ee8805, 0x11430816, 0x2ef0805, 0x11a30810, 0x2ef8805, 0x11430816,
+    0x2f00805, 0x10620810, 0x80012782, 0xfde0816, 0xfeb0816, 0x2f08805, 0x1003880a, 0x10038816,
+    0x10210816, 0x10278810, 0x10848816, 0x109e080a, 0x130f0808, 0x130f8808, 0x13100808, 0x13108808,
+    0x1048080a, 0x2f10805, 0x800127c3, 0x119a080a, 0x1190080a, 0x11900816, 0x119a0816, 0x332880b,
+    0x2f18805, 0x2f20805, 0x2f28805, 0x2f30805, 0xff58816, 0xff5880a, 0x1003880a, 0x1048080a,
+    0x1055880a, 0x2f38805, 0x3808805, 0x2f40805, 0x80012823, 0x10278804, 0x2f48805, 0x80012883,
+    0x800128e3, 0x80012944, 0x800129c3, 0x80012a23, 0x80012a83, 0x80012ae3, 0x80012b43, 0x80012ba3,
+    0x80012c03, 0x80012c63, 0x80012cc3, 0x80012d23, 0x80012d83, 0x80012de3, 0x80012e42, 0x10480814,
+    0x80012e82, 0x80012ec3, 0x80012f22, 0x11238814, 0x80012f62, 0x80012fa3, 0x80013004, 0x80013082,
+    0x11320814, 0x800130c2, 0x80013103, 0x106b0814, 0xfeb0814, 0xff58814, 0x10848814, 0x10480808,
+    0x80013162, 0x800131a3, 0x80013202, 0x11238808, 0x80013242, 0x80013283, 0x800132e4, 0x80013362,
+    0x11320808, 0x800133a2, 0x800133e3, 0x106b0808, 0xfeb0808, 0xff58808, 0x10848808, 0xecd8805,
+    0xece0805, 0xece8805, 0xff48810, 0xff48804, 0xfcf0804, 0xecf0805, 0xecf8805, 0xed00805,
+    0x80013443, 0x2f50805, 0x2f58805, 0x2f60805, 0x2f70805, 0x2f68805, 0x2f78805, 0x2f80805,
+    0x2f88805, 0x2f90805, 0x2f98805, 0x2fa0805, 0x2fa8805, 0x800134a2, 0x800134e2, 0x2fb0805,
+    0x2fb8805, 0x2fc0805, 0x2fc8805, 0x2fd0805, 0x2fd8805, 0x2fe0805, 0x2fe8805, 0x2ff0805,
+    0x2ff8805, 0x3000805, 0x3008805, 0x3010805, 0x3018805, 0x3020805, 0x3028805, 0x3030805,
+    0x3038805, 0x80013522, 0x3040805, 0x3048805, 0x3050805, 0x3058805, 0x3060805, 0x3068805,
+    0x3070805, 0x3078805, 0x3080805, 0x3088805, 0x3090805, 0x3098805, 0x30a0805, 0x30a8805,
+    0x30b0805, 0x30b8805, 0x30c0805, 0x30c8805, 0x30d0805, 0x30d8805, 0x30e0805, 0x30e8805,
+    0x30f0805, 0x30f8805, 0x3100805, 0x3108805, 0x3110805, 0x3118805, 0x3120805, 0x3128805,
+    0x80013562, 0x800135a2, 0x800135e2, 0x3130805, 0x3138805, 0x3140805, 0x3148805, 0x3150805,
+    0x3158805, 0x3160805, 0x3168805, 0x3170805, 0x3178805, 0x3180805, 0x3188805, 0x3190805,
+    0x3198805, 0x31a0805, 0x31a8805, 0x31b0805, 0x31b8805, 0x31c0805, 0x31c8805, 0x31d0805,
+    0x31d8805, 0x31e0805, 0x31e8805, 0x31f0805, 0x31f8805, 0x3200805, 0x3208805, 0x3210805,
+    0x3218805, 0x3220805, 0x3228805, 0x3230805, 0x3238805, 0x3240805, 0x3248805, 0x3250805,
+    0x3258805, 0x3260805, 0x3268805, 0x3270805, 0x3278805, 0x3280805, 0x3288805, 0x3290805,
+    0x3298805, 0x32a0805, 0x32a8805, 0x32b0805, 0x32b8805, 0x32c0805, 0x32c8805, 0x80013622,
+    0x32d0805, 0x32d8805, 0x32e0805, 0x32e8805, 0x80013662, 0x32f0805, 0x32f8805, 0x800136a2,
+    0x3300805, 0x3310805, 0x3318805, 0x3320805, 0x3328805, 0x3388805, 0x3398805, 0x33a0805,
+    0x33a8805, 0x33b8805, 0x33c0805, 0x33c8805, 0x33d0805, 0x33d8805, 0x33e0805, 0x33f0805,
+    0x3400805, 0x3408805, 0x3410805, 0x3418805, 0x3420805, 0x3428805, 0x3430805, 0x800136e2,
+    0x3438805, 0x80013722, 0x3440805, 0x3448805, 0x3450805, 0x3458805, 0x3460805, 0x80013762,
+    0x800137a3, 0x3468805, 0x80013802, 0x80013843, 0x3470805, 0x3478805, 0x3480805, 0x3488805,
+    0x3490805, 0x3498805, 0x34a0805, 0x34a8805, 0x34b0805, 0x34b8805, 0x34c0805, 0x34c8805,
+    0x34d0805, 0x34d8805, 0x34e0805, 0x34e8805, 0x800138a2, 0x34f0805, 0x34f8805, 0x800138e2,
+    0x3500805, 0x3508805, 0x80013922, 0x3510805, 0x80013962, 0x3518805, 0x3520805, 0x3528805,
+    0x3530805, 0x3538805, 0x3540805, 0x3548805, 0x3550805, 0x3558805, 0x3560805, 0x3568805,
+    0x3570805, 0x3578805, 0x3580805, 0x3588805, 0x3590805, 0x3598805, 0x35a0805, 0x35a8805,
+    0x35b0805, 0x35b8805, 0x35c0805, 0x800139a2, 0x35c8805, 0x800139e2, 0x35d0805, 0x35d8805,
+    0x35e0805, 0x35e8805, 0x35f0805, 0x35f8805, 0x3600805, 0x3608805, 0x3610805, 0x3618805,
+    0x80013a22, 0x80013a62, 0x80013aa2, 0x80013ae2, 0x80013b22, 0x3620805, 0x3628805, 0x80013b62,
+    0x80013ba2, 0x3630805, 0x3638805, 0x80013be2, 0x80013c22, 0x3640805, 0x3648805, 0x3650805,
+    0x3658805, 0x3660805, 0x3668805, 0x80013c62, 0x80013ca2, 0x3670805, 0x3678805, 0x80013ce2,
+    0x80013d22, 0x3680805, 0x3688805, 0x80013d62, 0x80013da2, 0x3690805, 0x3698805, 0x36a0805,
+    0x36a8805, 0x36b0805, 0x36b8805, 0x36c0805, 0x36c8805, 0x36d0805, 0x36d8805, 0x36e0805,
+    0x36e8805, 0x36f0805, 0x36f8805, 0x3700805, 0x3708805, 0x3710805, 0x3718805, 0x3720805,
+    0x3728805, 0x3730805, 0x3738805, 0x3740805, 0x3748805, 0x3750805, 0x3758805, 0x3760805,
+    0x3768805, 0x3770805, 0x3778805, 0x3780805, 0x3788805, 0x3790805, 0x3798805, 0x80013de2,
+    0x80013e22, 0x80013e62, 0x80013ea2, 0x37a0805, 0x37a8805, 0x37b0805, 0x37b8805, 0x37c0805,
+    0x37c8805, 0x37d0805, 0x37d8805, 0x37e0805, 0x37e8805, 0x37f0805, 0x37f8805, 0x3800805,
+    0x3810805, 0x3818805, 0x3820805, 0x3828805, 0x3830805, 0x3838805, 0x3840805, 0x3848805,
+    0x3850805, 0x3858805, 0x3860805, 0x3868805, 0x3870805, 0x3878805, 0x3880805, 0x3888805,
+    0x3890805, 0x3898805, 0x38a0805, 0x38a8805, 0x38b0805, 0x38b8805, 0x38c0805, 0x38c8805,
+    0x38d0805, 0x38d8805, 0x38e0805, 0x38e8805, 0x38f0805, 0x38f8805, 0x3900805, 0x3908805,
+    0x3910805, 0x3918805, 0x3920805, 0x80013ee2, 0x80013f22, 0x80013f62, 0x80013fa2, 0x3928805,
+    0x3930805, 0x3938805, 0x3940805, 0x3948805, 0x3950805, 0x80013fe2, 0x80014022, 0x80014062,
+    0x800140a2, 0x3958805, 0x3960805, 0x3968805, 0x3970805, 0x3978805, 0x3980805, 0x3988805,
+    0x3990805, 0x3998805, 0x39a0805, 0x39a8805, 0x39b0805, 0x39b8805, 0x39c0805, 0x39c8805,
+    0x39d0805, 0x39d8805, 0x39e0805, 0x39e8805, 0x39f0805, 0x39f8805, 0x3a00805, 0x3a08805,
+    0x3a10805, 0x3a18805, 0x3a20805, 0x19b0805, 0x19b8805, 0x19c0805, 0x19c8805, 0x3a28805,
+    0x3a30805, 0x3a38805, 0x3a40805, 0x3a48805, 0x3a50805, 0x3a58805, 0x3a60805, 0x3a68805,
+    0x3a70805, 0x3a78805, 0x3a80805, 0x3a88805, 0x3a90805, 0x3a98805, 0x3aa0805, 0x3aa8805,
+    0x3ab0805, 0x3ab8805, 0x3ac0805, 0x3ac8805, 0x3ad0805, 0x3ad8805, 0x3ae0805, 0x3ae8805,
+    0x3af0805, 0x3af8805, 0x3b00805, 0x3b08805, 0x1c00805, 0x1c08805, 0x3b10805, 0x3b18805,
+    0x3b20805, 0x3b28805, 0x3b30805, 0x3b38805, 0x3b40805, 0x3b48805, 0x3b50805, 0x3b58805,
+    0x3b60805, 0x3b68805, 0x3b70805, 0x3b78805, 0x3b80805, 0x3b88805, 0x3b90805, 0x3b98805,
+    0x3ba0805, 0x3ba8805, 0x3bb0805, 0x3bb8805, 0x3bc0805, 0x3bc8805, 0x3bd0805, 0x3bd8805,
+    0x3be0805, 0x3be8805, 0x3bf0805, 0x3bf8805, 0x3c00805, 0x3c08805, 0x3c10805, 0x3c18805,
+    0x3c20805, 0x3c28805, 0x3c30805, 0x3c38805, 0x3c40805, 0x3c48805, 0x3c50805, 0x3c58805,
+    0x3c60805, 0x3c68805, 0x3c70805, 0x3c78805, 0x3c80805, 0x3c88805, 0x3c90805, 0x3c98805,
+    0x3ca0805, 0x3ca8805, 0x3cb0805, 0x3cb8805, 0x3cc0805, 0x3cc8805, 0x3cd0805, 0x3cd8805,
+    0x3ce0805, 0x3ce8805, 0x3cf0805, 0x3cf8805, 0x3d00805, 0x3d08805, 0x3d10805, 0x3d18805,
+    0x3d20805, 0x3d28805, 0x3d30805, 0x3d38805, 0x3d40805, 0x3d48805, 0x3d50805, 0x3d58805,
+    0x3d60805, 0x3d68805, 0x3d70805, 0x3d78805, 0x3d80805, 0x3d88805, 0x3d90805, 0x3d98805,
+    0x3da0805, 0x3da8805, 0x3db0805, 0x3db8805, 0x3dc0805, 0x3dc8805, 0x3dd0805, 0x3dd8805,
+    0x3de0805, 0x3de8805, 0x3df0805, 0x3df8805, 0x3e00805, 0x3e08805, 0x3e10805, 0x3e18805,
+    0x3e20805, 0x3e28805, 0x3e30805, 0x3e38805, 0x3e40805, 0x3e48805, 0x3e50805, 0x3e58805,
+    0x3e60805, 0x3e68805, 0x3e70805, 0x3e78805, 0x3e80805, 0x3e88805, 0x3e90805, 0x3e98805,
+    0x3ea0805, 0x3ea8805, 0x3eb0805, 0x3eb8805, 0x3ec0805, 0x3ec8805, 0x3ed0805, 0x3ed8805,
+    0x3ee0805, 0x3ee8805, 0x3ef0805, 0x3ef8805, 0x3f00805, 0x3f08805, 0x3f10805, 0x3f18805,
+    0x3f20805, 0x3f28805, 0x3f30805, 0x3f38805, 0x3f40805, 0x3f48805, 0x3f50805, 0x3f58805,
+    0x3f60805, 0x3f68805, 0x3f70805, 0x3f78805, 0x3f80805, 0x3f88805, 0x3f90805, 0x3f98805,
+    0x3fa0805, 0x3fa8805, 0x3fb0805, 0x3fb8805, 0x3fc0805, 0x3fc8805, 0x3fd0805, 0x3fd8805,
+    0x3fe0805, 0x3fe8805, 0x3ff0805, 0x3ff8805, 0x4000805, 0x4008805, 0x4010805, 0x4018805,
+    0x4020805, 0x4028805, 0x4030805, 0x4038805, 0x4040805, 0x4048805, 0x4050805, 0x4058805,
+    0x4060805, 0x4068805, 0x4070805, 0x4078805, 0x4080805, 0x4088805, 0x4090805, 0x4098805,
+    0x40a0805, 0x40a8805, 0x40b0805, 0x40b8805, 0x40c0805, 0x40c8805, 0x40d0805, 0x40d8805,
+    0x40e0805, 0x40e8805, 0x40f0805, 0x40f8805, 0x4100805, 0x4108805, 0x4110805, 0x4118805,
+    0x4120805, 0x4128805, 0x4130805, 0x4138805, 0x4140805, 0x4148805, 0x4150805, 0x4158805,
+    0x4160805, 0x4168805, 0x4170805, 0x4178805, 0x4180805, 0x4188805, 0x4190805, 0x4198805,
+    0x41a0805, 0x41a8805, 0x41b0805, 0x41b8805, 0x41c0805, 0x41c8805, 0x41d0805, 0x41d8805,
+    0x41e0805, 0x41e8805, 0x41f0805, 0x41f8805, 0x4200805, 0x4208805, 0x4210805, 0x4218805,
+    0x4220805, 0x4228805, 0x4230805, 0x4238805, 0x4240805, 0x4248805, 0x4250805, 0x4258805,
+    0x4260805, 0x4268805, 0x4270805, 0x4278805, 0x4280805, 0x4288805, 0x4290805, 0x4298805,
+    0x42a0805, 0x42a8805, 0x42b0805, 0x42b8805, 0x42c0805, 0x42c8805, 0x42d0805, 0x42d8805,
+    0x42e0805, 0x42e8805, 0x42f0805, 0x42f8805, 0x4300805, 0x4308805, 0x4310805, 0x4318805,
+    0x4320805, 0x4328805, 0x4330805, 0x4338805, 0x4340805, 0xfcc880c, 0xfcd080c, 0xfcd880c,
+    0xfce080c, 0xfce880c, 0xfcf080c, 0xfcf880c, 0xfd0080c, 0xfd0880c, 0x800140e2, 0x80014122,
+    0x80014162, 0x800141a2, 0x800141e2, 0x80014222, 0x80014262, 0x800142a2, 0x800142e2, 0x80014322,
+    0x80014362, 0x800143a3, 0x80014403, 0x80014463, 0x800144c3, 0x80014523, 0x80014583, 0x800145e3,
+    0x80014643, 0x800146a3, 0x80014704, 0x80014784, 0x80014804, 0x80014884, 0x80014904, 0x80014984,
+    0x80014a04, 0x80014a84, 0x80014b04, 0x80014b84, 0x80014c04, 0x80014c82, 0x80014cc2, 0x80014d02,
+    0x80014d42, 0x80014d82, 0x80014dc2, 0x80014e02, 0x80014e42, 0x80014e82, 0x80014ec3, 0x80014f23,
+    0x80014f83, 0x80014fe3, 0x80015043, 0x800150a3, 0x80015103, 0x80015163, 0x800151c3, 0x80015223,
+    0x80015283, 0x800152e3, 0x80015343, 0x800153a3, 0x80015403, 0x80015463, 0x800154c3, 0x80015523,
+    0x80015583, 0x800155e3, 0x80015643, 0x800156a3, 0x80015703, 0x80015763, 0x800157c3, 0x80015823,
+    0x80015883, 0x800158e3, 0x80015943, 0x800159a3, 0x80015a03, 0x80015a63, 0x80015ac3, 0x80015b23,
+    0x80015b83, 0x80015be3, 0x80015c43, 0xfd10818, 0xfde0818, 0xfeb0818, 0xff58818, 0x10038818,
+    0x10210818, 0x10288818, 0x103a8818, 0x10480818, 0x10558818, 0x10620818, 0x106b0818, 0x10848818,
+    0x108c0818, 0x109e0818, 0x10b58818, 0x10c00818, 0x10c98818, 0x10e90818, 0x10fb8818, 0x110b8818,
+    0x11238818, 0x112c8818, 0x11320818, 0x11380818, 0x11430818, 0xfd1080c, 0xfde080c, 0xfeb080c,
+    0xff5880c, 0x1003880c, 0x1021080c, 0x1028880c, 0x103a880c, 0x1048080c, 0x1055880c, 0x1062080c,
+    0x106b080c, 0x1084880c, 0x108c080c, 0x109e080c, 0x10b5880c, 0x10c0080c, 0x10c9880c, 0x10e9080c,
+    0x10fb880c, 0x110b880c, 0x1123880c, 0x112c880c, 0x1132080c, 0x1138080c, 0x1143080c, 0xfcc080c,
+    0x80015ca2, 0x80015ce2, 0x80015d22, 0x80015d62, 0x80015da2, 0x80015de2, 0x80015e22, 0x80015e62,
+    0x80015ea2, 0x80015ee2, 0xfcc880c, 0xfcd080c, 0xfcd880c, 0xfce080c, 0xfce880c, 0xfcf080c,
+    0xfcf880c, 0xfd0080c, 0xfd0880c, 0x80015f22, 0xfcc080c, 0x4348805, 0x4350805, 0x4358805,
+    0x4360805, 0x4368805, 0x4370805, 0x4378805, 0x4380805, 0x4388805, 0x4390805, 0x4398805,
+    0x43a0805, 0x43a8805, 0x43b0805, 0x43b8805, 0x43c0805, 0x43c8805, 0x43d0805, 0x43d8805,
+    0x43e0805, 0x43e8805, 0x43f0805, 0x43f8805, 0x4400805, 0x4408805, 0x4410805, 0x4418805,
+    0x4420805, 0x4428805, 0x4430805, 0x4438805, 0x4440805, 0x4448805, 0x4450805, 0x4458805,
+    0x4460805, 0x4468805, 0x4470805, 0x4478805, 0x4480805, 0x4488805, 0x4490805, 0x4498805,
+    0x44a0805, 0x44a8805, 0x44b0805, 0x44b8805, 0x44c0805, 0x44c8805, 0x44d0805, 0x44d8805,
+    0x44e0805, 0x44e8805, 0x44f0805, 0x44f8805, 0x4500805, 0x4508805, 0x4510805, 0x4518805,
+    0x4520805, 0x4528805, 0x4530805, 0x4538805, 0x4540805, 0x4548805, 0x4550805, 0x4558805,
+    0x4560805, 0x4568805, 0x4570805, 0x4578805, 0x4580805, 0x4588805, 0x4590805, 0x4598805,
+    0x45a0805, 0x45a8805, 0x45b0805, 0x45b8805, 0x45c0805, 0x45c8805, 0x45d0805, 0x45d8805,
+    0x45e0805, 0x45e8805, 0x45f0805, 0x45f8805, 0x4600805, 0x4608805, 0x4610805, 0x4618805,
+    0x4620805, 0x4628805, 0x4630805, 0x4638805, 0x4640805, 0x4648805, 0x4650805, 0x4658805,
+    0x4660805, 0x4668805, 0x4670805, 0x4678805, 0x4680805, 0x4688805, 0x4690805, 0x4698805,
+    0x46a0805, 0x46a8805, 0x46b0805, 0x46b8805, 0x46c0805, 0x46c8805, 0x46d0805, 0x46d8805,
+    0x46e0805, 0x46e8805, 0x46f0805, 0x46f8805, 0x4700805, 0x4708805, 0x4710805, 0x4718805,
+    0x4720805, 0x4728805, 0x4730805, 0x4738805, 0x4740805, 0x4748805, 0x4750805, 0x4758805,
+    0x4760805, 0x4768805, 0x4770805, 0x4778805, 0x4780805, 0x4788805, 0x4790805, 0x4798805,
+    0x47a0805, 0x47a8805, 0x47b0805, 0x47b8805, 0x47c0805, 0x47c8805, 0x47d0805, 0x47d8805,
+    0x47e0805, 0x47e8805, 0x47f0805, 0x47f8805, 0x4800805, 0x4808805, 0x4810805, 0x4818805,
+    0x4820805, 0x4828805, 0x4830805, 0x4838805, 0x4840805, 0x4848805, 0x4850805, 0x4858805,
+    0x4860805, 0x4868805, 0x4870805, 0x4878805, 0x4880805, 0x4888805, 0x4890805, 0x4898805,
+    0x48a0805, 0x48a8805, 0x48b0805, 0x48b8805, 0x48c0805, 0x48c8805, 0x48d0805, 0x48d8805,
+    0x48e0805, 0x48e8805, 0x48f0805, 0x48f8805, 0x4900805, 0x4908805, 0x4910805, 0x4918805,
+    0x4920805, 0x4928805, 0x4930805, 0x4938805, 0x4940805, 0x4948805, 0x4950805, 0x4958805,
+    0x4960805, 0x4968805, 0x4970805, 0x4978805, 0x4980805, 0x4988805, 0x4990805, 0x4998805,
+    0x49a0805, 0x49a8805, 0x49b0805, 0x49b8805, 0x49c0805, 0x49c8805, 0x49d0805, 0x49d8805,
+    0x49e0805, 0x49e8805, 0x49f0805, 0x49f8805, 0x4a00805, 0x4a08805, 0x4a10805, 0x4a18805,
+    0x4a20805, 0x4a28805, 0x4a30805, 0x4a38805, 0x4a40805, 0x4a48805, 0x4a50805, 0x4a58805,
+    0x4a60805, 0x4a68805, 0x4a70805, 0x4a78805, 0x4a80805, 0x4a88805, 0x4a90805, 0x4a98805,
+    0x4aa0805, 0x4aa8805, 0x4ab0805, 0x4ab8805, 0x4ac0805, 0x4ac8805, 0x4ad0805, 0x4ad8805,
+    0x4ae0805, 0x4ae8805, 0x4af0805, 0x4af8805, 0x4b00805, 0x4b08805, 0x4b10805, 0x4b18805,
+    0x4b20805, 0x4b28805, 0x4b30805, 0x4b38805, 0x4b40805, 0x5198805, 0x51a0805, 0x51a8805,
+    0x51b0805, 0x51b8805, 0x51c0805, 0x51c8805, 0x51d0805, 0x51d8805, 0x51e0805, 0x51e8805,
+    0x51f0805, 0x51f8805, 0x5200805, 0x5208805, 0x5210805, 0x5218805, 0x5220805, 0x5228805,
+    0x5230805, 0x5238805, 0x5240805, 0x5248805, 0x5250805, 0x5258805, 0x5260805, 0x5268805,
+    0x5270805, 0x5278805, 0x5280805, 0x5288805, 0x5290805, 0x5298805, 0x52a0805, 0x52a8805,
+    0x52b0805, 0x52b8805, 0x52c0805, 0x52c8805, 0x52d0805, 0x52d8805, 0x52e0805, 0x52e8805,
+    0x52f0805, 0x52f8805, 0x5300805, 0x5308805, 0x5310805, 0x7f70805, 0x7f78805, 0x7f80805,
+    0x7f88805, 0x7f90805, 0x7f98805, 0x7fa0805, 0x7fa8805, 0x5318805, 0x5320805, 0x5328805,
+    0x5330805, 0x5338805, 0x5340805, 0x5348805, 0x5350805, 0x5358805, 0x5360805, 0x5368805,
+    0x5370805, 0x5378805, 0x5380805, 0x5388805, 0x5390805, 0x5398805, 0x53a0805, 0x53a8805,
+    0x53b0805, 0x53b8805, 0x53c0805, 0x53c8805, 0x53d0805, 0x53d8805, 0x53e0805, 0x53e8805,
+    0x53f0805, 0x53f8805, 0x5400805, 0x5408805, 0x5410805, 0x5418805, 0x5420805, 0x5428805,
+    0x5430805, 0x5438805, 0x5440805, 0x5448805, 0x5450805, 0x5458805, 0x5460805, 0x5468805,
+    0x5470805, 0x5478805, 0x5480805, 0x5488805, 0x5490805, 0x5498805, 0x54a0805, 0x54a8805,
+    0x54b0805, 0x54b8805, 0x9208805, 0x9210805, 0x9218805, 0x54c0805, 0x54c8805, 0x54d0805,
+    0x54d8805, 0x54e0805, 0x54e8805, 0x54f0805, 0x54f8805, 0x5500805, 0x5508805, 0x5510805,
+    0x5518805, 0x5520805, 0x5528805, 0x5530805, 0x5538805, 0x5540805, 0x5548805, 0x5550805,
+    0x5558805, 0x5560805, 0x5568805, 0x5570805, 0x5578805, 0x5580805, 0x5588805, 0x7f40805,
+    0x7f48805, 0x7f50805, 0x7f58805, 0x7f60805, 0x7f68805, 0x5590805, 0x5598805, 0x55a0805,
+    0x55a8805, 0x55b0805, 0x55b8805, 0x55c0805, 0x55c8805, 0x55d0805, 0x55d8805, 0x55e0805,
+    0x55e8805, 0x55f0805, 0x55f8805, 0x5600805, 0x5608805, 0x5610805, 0x5618805, 0x5620805,
+    0x5628805, 0x5630805, 0x5638805, 0x5640805, 0x5648805, 0x5650805, 0x5658805, 0x5660805,
+    0x5668805, 0x5670805, 0x5678805, 0x5680805, 0x5688805, 0x5690805, 0x5698805, 0x56a0805,
+    0x56a8805, 0x56b0805, 0x56b8805, 0x56c0805, 0x56c8805, 0x56d0805, 0x56d8805, 0x56e0805,
+    0x56e8805, 0x56f0805, 0x56f8805, 0x5700805, 0x5708805, 0x5710805, 0x5718805, 0x5720805,
+    0x5728805, 0x5730805, 0x5738805, 0x5740805, 0x5748805, 0x5750805, 0x5758805, 0x5760805,
+    0x5768805, 0x5770805, 0x5778805, 0x5780805, 0x5788805, 0x5790805, 0x5798805, 0x57a0805,
+    0x57a8805, 0x57b0805, 0x57b8805, 0x57c0805, 0x57c8805, 0x57d0805, 0x57d8805, 0x57e0805,
+    0x57e8805, 0x57f0805, 0x57f8805, 0x5800805, 0x5808805, 0x5810805, 0x5818805, 0x5820805,
+    0x5828805, 0x5830805, 0x5838805, 0x5840805, 0x5848805, 0x5850805, 0x5858805, 0x5860805,
+    0x5868805, 0x5870805, 0x5878805, 0x5880805, 0x5888805, 0x5890805, 0x5898805, 0x58a0805,
+    0x58a8805, 0x58b0805, 0x58b8805, 0x58c0805, 0x58c8805, 0x58d0805, 0x58d8805, 0x58e0805,
+    0x58e8805, 0x58f0805, 0x58f8805, 0x5900805, 0x5908805, 0x59e0805, 0x59e8805, 0x59f0805,
+    0x59f8805, 0x5a00805, 0x5a08805, 0x5a10805, 0x5a18805, 0x5a20805, 0x5a28805, 0x5a30805,
+    0x5a38805, 0x5a40805, 0x5a48805, 0x5a50805, 0x5a58805, 0x5a60805, 0x5a68805, 0x5a70805,
+    0x5a78805, 0x5a80805, 0x5a88805, 0x5a90805, 0x5a98805, 0x5aa0805, 0x5aa8805, 0x5ab0805,
+    0x5ab8805, 0x5ac0805, 0x5ac8805, 0x5ad0805, 0x5ad8805, 0x5ae0805, 0x5af8805, 0x5b00805,
+    0x5b08805, 0x5b10805, 0x5b18805, 0x5b20805, 0x5b28805, 0x5b30805, 0x5b38805, 0x5b40805,
+    0x5b48805, 0x5b50805, 0x5b58805, 0x5b60805, 0x5b68805, 0x5b70805, 0x5b78805, 0x5b80805,
+    0x5b88805, 0x5b90805, 0x5b98805, 0x5ba0805, 0x5ba8805, 0x5bb0805, 0x5bb8805, 0x5bc0805,
+    0x5bc8805, 0x5bd0805, 0x5bd8805, 0x5be0805, 0x5be8805, 0x5bf0805, 0x5bf8805, 0x5c00805,
+    0x5c08805, 0x5c10805, 0x5c18805, 0x5c20805, 0x5c28805, 0x5c30805, 0x5c38805, 0x5c40805,
+    0x5c48805, 0x5c50805, 0x5c58805, 0x5c60805, 0x5c68805, 0x5c70805, 0x5c78805, 0x5c80805,
+    0x5c88805, 0x5c90805, 0x5c98805, 0x5ca0805, 0x5ca8805, 0x5cb0805, 0x5cb8805, 0x5cc0805,
+    0x5cc8805, 0x5cd0805, 0x5cd8805, 0x5ce0805, 0x5ce8805, 0x5cf0805, 0x5cf8805, 0x5d00805,
+    0x5d08805, 0x5d10805, 0x5d18805, 0x5d20805, 0x5d28805, 0x1af0805, 0x1af8805, 0x1b00805,
+    0x1b08805, 0x1b10805, 0x1b18805, 0x1b20805, 0x1b28805, 0x1b30805, 0x1b38805, 0x1b40805,
+    0x1b48805, 0x1b50805, 0x1b58805, 0xfcc880c, 0xfcd080c, 0xfcd880c, 0xfce080c, 0xfce880c,
+    0xfcf080c, 0xfcf880c, 0xfd0080c, 0xfd0880c, 0x80015f62, 0xfcc880c, 0xfcd080c, 0xfcd880c,
+    0xfce080c, 0xfce880c, 0xfcf080c, 0xfcf880c, 0xfd0080c, 0xfd0880c, 0x80015fa2, 0xfcc880c,
+    0xfcd080c, 0xfcd880c, 0xfce080c, 0xfce880c, 0xfcf080c, 0xfcf880c, 0xfd0080c, 0xfd0880c,
+    0x80015fe2, 0x5d30805, 0x5d38805, 0x5d40805, 0x5d48805, 0x5d50805, 0x5d58805, 0x5d60805,
+    0x5d68805, 0x5d70805, 0x5d78805, 0x5d80805, 0x5d88805, 0x5d90805, 0x5d98805, 0x5da0805,
+    0x5da8805, 0x5db0805, 0x5db8805, 0x5dc0805, 0x5dc8805, 0x5dd0805, 0x5dd8805, 0x5de0805,
+    0x5de8805, 0x5df0805, 0x5df8805, 0x5e00805, 0x5e08805, 0x5e10805, 0x5e18805, 0x5e20805,
+    0x5e28805, 0x5e30805, 0x5e38805, 0x5e40805, 0x5e48805, 0x5e50805, 0x5e58805, 0x5e60805,
+    0x5e68805, 0x5e70805, 0x5e78805, 0x5e80805, 0x5e88805, 0x5e90805, 0x5e98805, 0x5ea0805,
+    0x5ea8805, 0x5eb0805, 0x1a90805, 0x1a98805, 0x5eb8805, 0x5ec0805, 0x5ec8805, 0x5ed0805,
+    0x5ed8805, 0x5ee0805, 0x5ee8805, 0x5ef0805, 0x5ef8805, 0x5f00805, 0x5f08805, 0x5f10805,
+    0x5f18805, 0x5f20805, 0x5f28805, 0x5f30805, 0x5f38805, 0x5f40805, 0x5f48805, 0x5f50805,
+    0x5f58805, 0x5f60805, 0x5f68805, 0x5f70805, 0x5f78805, 0x5f80805, 0x5f88805, 0x5f90805,
+    0x5f98805, 0x5fa0805, 0x5fa8805, 0x1aa0805, 0x1aa8805, 0x1ab0805, 0x1ab8805, 0x1ac0805,
+    0x1ac8805, 0x1ad0805, 0x1ad8805, 0x1ae0805, 0x1ae8805, 0x5fb0805, 0x5fb8805, 0x5fc0805,
+    0x5fc8805, 0x5fd0805, 0x5fd8805, 0x5fe0805, 0x5fe8805, 0x5ff0805, 0x5ff8805, 0x6000805,
+    0x6008805, 0x6010805, 0x6018805, 0x6020805, 0x6028805, 0x7740805, 0x7748805, 0x7750805,
+    0x7758805, 0x7760805, 0x7768805, 0x7770805, 0x7778805, 0x7780805, 0x7788805, 0x7790805,
+    0x7798805, 0x77a0805, 0x77a8805, 0x77b0805, 0x77b8805, 0x77c0805, 0x77c8805, 0x77d0805,
+    0x77d8805, 0x77e0805, 0x77e8805, 0x77f0805, 0x77f8805, 0x7800805, 0x7808805, 0x7810805,
+    0x7818805, 0x7820805, 0x7828805, 0x7830805, 0x7838805, 0x7840805, 0x7848805, 0x7850805,
+    0x7858805, 0x7860805, 0x7868805, 0x7870805, 0x7878805, 0x7880805, 0x7888805, 0x7890805,
+    0x7898805, 0x78a0805, 0x78a8805, 0x78b0805, 0x78b8805, 0x78c0805, 0x78c8805, 0x78d0805,
+    0x78d8805, 0x78e0805, 0x78e8805, 0x78f0805, 0x78f8805, 0x7900805, 0x7908805, 0x7910805,
+    0x7918805, 0x7920805, 0x7928805, 0x7930805, 0x7938805, 0x7940805, 0x7948805, 0x7950805,
+    0x7958805, 0x7960805, 0x7968805, 0x7970805, 0x7978805, 0x7980805, 0x7988805, 0x7990805,
+    0x7998805, 0x79a0805, 0x79a8805, 0x79b0805, 0x79b8805, 0x79c0805, 0x79c8805, 0x79d0805,
+    0x79d8805, 0x79e0805, 0x79e8805, 0x79f0805, 0x79f8805, 0x7a00805, 0x7a08805, 0x7a10805,
+    0x7a18805, 0x7a20805, 0x7a28805, 0x7a30805, 0x7a38805, 0x7a40805, 0x7a48805, 0x7a50805,
+    0x7a58805, 0x7a60805, 0x7a68805, 0x7a70805, 0x7a78805, 0x7a80805, 0x7a88805, 0x7a90805,
+    0x7a98805, 0x7aa0805, 0x7aa8805, 0x7ab0805, 0x7ab8805, 0x7ac0805, 0x7ac8805, 0x7ad0805,
+    0x7ad8805, 0x7ae0805, 0x7ae8805, 0x7af0805, 0x7af8805, 0x7b00805, 0x7b08805, 0x7b10805,
+    0x7b18805, 0x7b20805, 0x7b28805, 0x7b30805, 0x7b38805, 0x7b40805, 0x7b48805, 0x7b50805,
+    0x7b58805, 0x7b60805, 0x7b68805, 0x7b70805, 0x7b78805, 0x7b80805, 0x7b88805, 0x7b90805,
+    0x7b98805, 0x7ba0805, 0x7ba8805, 0x7bb0805, 0x7bb8805, 0x7bc0805, 0x7bc8805, 0x7bd0805,
+    0x7bd8805, 0x7be0805, 0x7be8805, 0x7bf0805, 0x7bf8805, 0x7c00805, 0x7c08805, 0x7c10805,
+    0x7c18805, 0x7c20805, 0x7c28805, 0x7c30805, 0x7c38805, 0x7c40805, 0x7c48805, 0x7c50805,
+    0x7c58805, 0x7c60805, 0x7c68805, 0x7c70805, 0x7c78805, 0x7c80805, 0x7c88805, 0x7c90805,
+    0x7c98805, 0x7ca0805, 0x7ca8805, 0x7cb0805, 0x7cb8805, 0x7cc0805, 0x7cc8805, 0x7cd0805,
+    0x7cd8805, 0x7ce0805, 0x7ce8805, 0x7cf0805, 0x7cf8805, 0x7d00805, 0x7d08805, 0x7d10805,
+    0x7d18805, 0x7d20805, 0x7d28805, 0x7d30805, 0x7d38805, 0x7d40805, 0x7d48805, 0x7d50805,
+    0x7d58805, 0x7d60805, 0x7d68805, 0x7d70805, 0x7d78805, 0x7d80805, 0x7d88805, 0x7d90805,
+    0x7d98805, 0x7da0805, 0x7da8805, 0x7db0805, 0x7db8805, 0x7dc0805, 0x7dc8805, 0x7dd0805,
+    0x7dd8805, 0x7de0805, 0x7de8805, 0x7df0805, 0x7df8805, 0x7e00805, 0x7e08805, 0x7e10805,
+    0x7e18805, 0x7e20805, 0x7e28805, 0x7e30805, 0x7e38805, 0x7e40805, 0x7e48805, 0x7e50805,
+    0x7e58805, 0x7e60805, 0x7e68805, 0x7e70805, 0x7e78805, 0x7e80805, 0x7e88805, 0x7e90805,
+    0x7e98805, 0x7ea0805, 0x7ea8805, 0x7eb0805, 0x7eb8805, 0x7ec0805, 0x7ec8805, 0x7ed0805,
+    0x7ed8805, 0x7ee0805, 0x7ee8805, 0x7ef0805, 0x7ef8805, 0x7f00805, 0x7f08805, 0x7f10805,
+    0x7f18805, 0x7f20805, 0x7f28805, 0x7f30805, 0x7f38805, 0x6030805, 0x6038805, 0x6040805,
+    0x6048805, 0x6050805, 0x6058805, 0x6060805, 0x6068805, 0x6070805, 0x6078805, 0x6080805,
+    0x6088805, 0x6090805, 0x6098805, 0x60a0805, 0x60a8805, 0x60b0805, 0x60b8805, 0x60c0805,
+    0x60c8805, 0x60d0805, 0x60d8805, 0x60e0805, 0x60e8805, 0x60f0805, 0x60f8805, 0x6100805,
+    0x6108805, 0x6110805, 0x6118805, 0x6120805, 0x6128805, 0x6130805, 0x6138805, 0x6140805,
+    0x6148805, 0x6150805, 0x6158805, 0x6160805, 0x6168805, 0x6170805, 0x6178805, 0x6180805,
+    0x6188805, 0x6190805, 0x6198805, 0x61a0805, 0x61a8805, 0x61b0805, 0x61b8805, 0x61c0805,
+    0x61c8805, 0x61d0805, 0x61d8805, 0x61e0805, 0x61e8805, 0x61f0805, 0x61f8805, 0x6200805,
+    0x6208805, 0x6210805, 0x6218805, 0x6220805, 0x6228805, 0x6230805, 0x6238805, 0x6240805,
+    0x6248805, 0x6250805, 0x6258805, 0x6260805, 0x6268805, 0x6270805, 0x6278805, 0x6280805,
+    0x6288805, 0x6290805, 0x6298805, 0x62a0805, 0x62a8805, 0x62b0805, 0x62b8805, 0x62c0805,
+    0x62c8805, 0x62d0805, 0x62d8805, 0x62e0805, 0x62e8805, 0x62f0805, 0x62f8805, 0x6300805,
+    0x6308805, 0x6310805, 0x6318805, 0x6320805, 0x6328805, 0x6330805, 0x6338805, 0x6340805,
+    0x6348805, 0x6350805, 0x6358805, 0x6360805, 0x6368805, 0x6370805, 0x6378805, 0x6380805,
+    0x6388805, 0x6390805, 0x6398805, 0x63a0805, 0x63a8805, 0x63b0805, 0x63b8805, 0x63c0805,
+    0x63c8805, 0x63d0805, 0x63d8805, 0x63e0805, 0x63e8805, 0x63f0805, 0x63f8805, 0x6400805,
+    0x6408805, 0x6410805, 0x6418805, 0x6420805, 0x6428805, 0x6430805, 0x6438805, 0x6440805,
+    0x19e0805, 0x19e8805, 0x19f0805, 0x19f8805, 0x1a00805, 0x1a08805, 0x1a10805, 0x1a18805,
+    0x1a20805, 0x1a28805, 0x1a30805, 0x1a38805, 0x1a40805, 0x1a48805, 0x1a50805, 0x1a58805,
+    0x1a60805, 0x1a68805, 0x1a70805, 0x1a78805, 0x1a80805, 0x1a88805, 0x6448805, 0x6450805,
+    0x6458805, 0x6460805, 0x6468805, 0x6470805, 0x6478805, 0x6480805, 0x6488805, 0x6490805,
+    0x6498805, 0x64a0805, 0x64a8805, 0x64b0805, 0x64b8805, 0x64c0805, 0x64c8805, 0x64d0805,
+    0x64d8805, 0x64e0805, 0x64e8805, 0x64f0805, 0x64f8805, 0x6500805, 0x6508805, 0x6510805,
+    0x6518805, 0x6520805, 0x6528805, 0x6530805, 0x6538805, 0x6540805, 0x6548805, 0x6550805,
+    0x6558805, 0x6560805, 0x6568805, 0x6570805, 0x6578805, 0x6580805, 0x6588805, 0x6590805,
+    0x6598805, 0x65a0805, 0x65a8805, 0x65b0805, 0x65b8805, 0x65c0805, 0x65c8805, 0x65d0805,
+    0x65d8805, 0x65e0805, 0x65e8805, 0x65f0805, 0x65f8805, 0x6600805, 0x6608805, 0x6610805,
+    0x6618805, 0x6620805, 0x6628805, 0x6630805, 0x6638805, 0x1cb0805, 0x1cb8805, 0x1cc0805,
+    0x1cc8805, 0x6640805, 0x6648805, 0x6650805, 0x6658805, 0x6660805, 0x6668805, 0x6670805,
+    0x6678805, 0x6680805, 0x6688805, 0x6690805, 0x6698805, 0x66a0805, 0x66a8805, 0x66b0805,
+    0x66b8805, 0x66c0805, 0x66c8805, 0x66d0805, 0x66d8805, 0x66e0805, 0x66e8805, 0x66f0805,
+    0x66f8805, 0x6700805, 0x6708805, 0x6710805, 0x6718805, 0x6720805, 0x6728805, 0x6730805,
+    0x6738805, 0x19d0805, 0x19d8805, 0x6740805, 0x6748805, 0x6750805, 0x6758805, 0x6760805,
+    0x6768805, 0x6770805, 0x6778805, 0x6780805, 0x6788805, 0x6790805, 0x6798805, 0x67a0805,
+    0x67a8805, 0x80016024, 0x67b0805, 0x67b8805, 0x67c0805, 0x67c8805, 0x67d0805, 0x67d8805,
+    0x67e0805, 0x67e8805, 0x67f0805, 0x67f8805, 0x6800805, 0x6808805, 0x6810805, 0x6818805,
+    0x6820805, 0x6828805, 0x6830805, 0x6838805, 0x6840805, 0x6848805, 0x6850805, 0x6858805,
+    0x6860805, 0x6868805, 0x6870805, 0x6878805, 0x6880805, 0x6888805, 0x6890805, 0x6898805,
+    0x68a0805, 0x68a8805, 0x68b0805, 0x68b8805, 0x68c0805, 0x68c8805, 0x68d0805, 0x68d8805,
+    0x68e0805, 0x68e8805, 0x68f0805, 0x68f8805, 0x6900805, 0x6908805, 0x6910805, 0x6918805,
+    0x6920805, 0x6928805, 0x6930805, 0x6938805, 0x6940805, 0x6948805, 0x6950805, 0x6958805,
+    0x6960805, 0x6968805, 0x6970805, 0x6978805, 0x6980805, 0x6988805, 0x6990805, 0x6998805,
+    0x69a0805, 0x69a8805, 0x69b0805, 0x69b8805, 0x69c0805, 0x69c8805, 0x69d0805, 0x69d8805,
+    0x69e0805, 0x69e8805, 0x69f0805, 0x69f8805, 0x6a00805, 0x6a08805, 0x6a10805, 0x6a18805,
+    0x6a20805, 0x6a28805, 0x6a30805, 0x6a38805, 0x6a40805, 0x6a48805, 0x6a50805, 0x6a58805,
+    0x6a60805, 0x6a68805, 0x6a70805, 0x6a78805, 0x6a80805, 0x6a88805, 0x6a90805, 0x6a98805,
+    0x6aa0805, 0x6aa8805, 0x6ab0805, 0x6ab8805, 0x6ac0805, 0x6ac8805, 0x6ad0805, 0x6ad8805,
+    0x6ae0805, 0x800160a3, 0x80016102, 0x80016143, 0x6ae8805, 0x6af0805, 0x6af8805, 0x6b00805,
+    0x6b08805, 0x6b10805, 0x6b18805, 0x6b20805, 0x6b28805, 0x6b30805, 0x6b38805, 0x6b40805,
+    0x6b48805, 0x6b50805, 0x6b58805, 0x6b60805, 0x6b68805, 0x6b70805, 0x6b78805, 0x6b80805,
+    0x6b88805, 0x6b90805, 0x6b98805, 0x6ba0805, 0x6ba8805, 0x6bb0805, 0x6bb8805, 0x6bc0805,
+    0x6bc8805, 0x6bd0805, 0x6bd8805, 0x6be0805, 0x6be8805, 0x6bf0805, 0x6bf8805, 0x6c00805,
+    0x6c08805, 0x6c10805, 0x6c18805, 0x6c20805, 0x6c28805, 0x6c30805, 0x6c38805, 0x6c40805,
+    0x6c48805, 0x6c50805, 0x6c58805, 0x6c60805, 0x6c68805, 0x6c70805, 0x6c78805, 0x6c80805,
+    0x6c88805, 0x6c90805, 0x6c98805, 0x6ca0805, 0x6ca8805, 0x6cb0805, 0x6cb8805, 0x6cc0805,
+    0x6cc8805, 0x6cd0805, 0x6cd8805, 0x6ce0805, 0x6ce8805, 0x6cf0805, 0x6cf8805, 0x6d00805,
+    0x6d08805, 0x6d10805, 0x6d18805, 0x6d20805, 0x6d28805, 0x6d30805, 0x6d38805, 0x6d40805,
+    0x6d48805, 0x6d50805, 0x6d58805, 0x6d60805, 0x6d68805, 0x6d70805, 0x6d78805, 0x6d80805,
+    0x6d88805, 0x6d90805, 0x6d98805, 0x6da0805, 0x6da8805, 0x6db0805, 0x6db8805, 0x6dc0805,
+    0x6dc8805, 0x6dd0805, 0x6dd8805, 0x6de0805, 0x6de8805, 0x6df0805, 0x6df8805, 0x6e00805,
+    0x6e08805, 0x800161a2, 0x6e10805, 0x6e18805, 0x6e20805, 0x6e28805, 0x6e30805, 0x6e38805,
+    0x6e40805, 0x6e48805, 0x6e50805, 0x6e58805, 0x6e60805, 0x6e68805, 0x6e70805, 0x6e78805,
+    0x6e80805, 0x6e88805, 0x6e90805, 0x6e98805, 0x6ea0805, 0x6ea8805, 0x6eb0805, 0x6eb8805,
+    0x6ec0805, 0x6ec8805, 0x6ed0805, 0x6ed8805, 0x6ee0805, 0x6ee8805, 0x6ef0805, 0x6ef8805,
+    0x6f00805, 0x6f08805, 0x6f10805, 0x6f18805, 0x6f20805, 0x6f28805, 0x6f30805, 0x6f38805,
+    0x6f40805, 0x6f48805, 0x6f50805, 0x6f58805, 0x6f60805, 0x6f68805, 0x6f70805, 0x6f78805,
+    0x6f80805, 0x6f88805, 0x6f90805, 0x6f98805, 0x6fa0805, 0x6fa8805, 0x6fb0805, 0x6fb8805,
+    0x6fc0805, 0x6fc8805, 0x6fd0805, 0x6fd8805, 0x6fe0805, 0x6fe8805, 0x6ff0805, 0x6ff8805,
+    0x7000805, 0x7008805, 0x7010805, 0x7018805, 0x7020805, 0x7028805, 0x7030805, 0x7038805,
+    0x7040805, 0x7048805, 0x7050805, 0x7058805, 0x7060805, 0x7068805, 0x7070805, 0x7078805,
+    0x7080805, 0x7088805, 0x7090805, 0x7098805, 0x70a0805, 0x70a8805, 0x70b0805, 0x70b8805,
+    0x70c0805, 0x70c8805, 0x70d0805, 0x70d8805, 0x70e0805, 0x70e8805, 0x70f0805, 0x70f8805,
+    0x7100805, 0x7108805, 0x7110805, 0x7118805, 0x7120805, 0x7128805, 0x7130805, 0x7138805,
+    0x7140805, 0x7148805, 0x7150805, 0x7158805, 0x7160805, 0x7168805, 0x7170805, 0x7178805,
+    0x7180805, 0x7188805, 0x7190805, 0x7198805, 0x71a0805, 0x71a8805, 0x71b0805, 0x71b8805,
+    0x71c0805, 0x71c8805, 0x71d0805, 0x71d8805, 0x71e0805, 0x71e8805, 0x71f0805, 0x71f8805,
+    0x7200805, 0x7208805, 0x7210805, 0x7218805, 0x7220805, 0x7228805, 0x7230805, 0x7238805,
+    0x7240805, 0x7248805, 0x7250805, 0x7258805, 0x7260805, 0x7268805, 0x7270805, 0x7278805,
+    0x7280805, 0x7288805, 0x7290805, 0x7298805, 0x72a0805, 0x72a8805, 0x72b0805, 0x72b8805,
+    0x72c0805, 0x72c8805, 0x72d0805, 0x72d8805, 0x72e0805, 0x72e8805, 0x72f0805, 0x72f8805,
+    0x7300805, 0x7308805, 0x7310805, 0x7318805, 0x7320805, 0x7328805, 0x7330805, 0x7338805,
+    0x7340805, 0x7348805, 0x7350805, 0x7358805, 0x7360805, 0x7368805, 0x7370805, 0x7378805,
+    0x7380805, 0x7388805, 0x7390805, 0x7398805, 0x73a0805, 0x73a8805, 0x73b0805, 0x73b8805,
+    0x73c0805, 0x73c8805, 0x73d0805, 0x73d8805, 0x73e0805, 0x73e8805, 0x73f0805, 0x73f8805,
+    0x7400805, 0x7408805, 0x7410805, 0x7418805, 0x7420805, 0x7428805, 0x7430805, 0x7438805,
+    0x7440805, 0x7448805, 0x7450805, 0x7458805, 0x7460805, 0x7468805, 0x7470805, 0x7478805,
+    0x7480805, 0x7488805, 0x7490805, 0x7498805, 0x74a0805, 0x74a8805, 0x74b0805, 0x74b8805,
+    0x74c0805, 0x74c8805, 0x74d0805, 0x74d8805, 0x74e0805, 0x74e8805, 0x74f0805, 0x74f8805,
+    0x7500805, 0x7508805, 0x7510805, 0x7518805, 0x7520805, 0x7528805, 0x7530805, 0x7538805,
+    0x7540805, 0x7548805, 0x7550805, 0x7558805, 0x7560805, 0x7568805, 0x7570805, 0x7578805,
+    0x7580805, 0x7588805, 0x7590805, 0x7598805, 0x75a0805, 0x75a8805, 0x75b0805, 0x75b8805,
+    0x75c0805, 0x75c8805, 0x75d0805, 0x75d8805, 0x75e0805, 0x75e8805, 0x75f0805, 0x75f8805,
+    0x7600805, 0x7608805, 0x7610805, 0x7618805, 0x7620805, 0x7628805, 0x7630805, 0x7638805,
+    0x7640805, 0x7648805, 0x7650805, 0x7658805, 0x7660805, 0x7668805, 0x7670805, 0x7678805,
+    0x7680805, 0x7688805, 0x7690805, 0x7698805, 0x76a0805, 0x76a8805, 0x76b0805, 0x76b8805,
+    0x76c0805, 0x76c8805, 0x76d0805, 0x76d8805, 0x76e0805, 0x76e8805, 0x76f0805, 0x76f8805,
+    0x7700805, 0x7708805, 0x12a50810, 0x12a58810, 0x12a60810, 0x12a68810, 0x12a70810, 0x12a78810,
+    0x12a80810, 0x12a88810, 0x12a90810, 0x12a98810, 0x12aa0810, 0x12aa8810, 0x12ab0810, 0x12ab8810,
+    0x12ac0810, 0x12ac8810, 0x12ad0810, 0x12ad8810, 0x12ae0810, 0x12ae8810, 0x12af0810, 0x12af8810,
+    0x12b00810, 0x12b08810, 0x12b10810, 0x12b18810, 0x12b20810, 0x12b28810, 0x12b30810, 0x12b38810,
+    0x12b40810, 0x12b48810, 0x12b50810, 0x12b58810, 0x12b60810, 0x12b68810, 0x12b70810, 0x12b78810,
+    0x12b80810, 0x12b88810, 0x12b90810, 0x12b98810, 0x12ba0810, 0x12ba8810, 0x12bb0810, 0x12bb8810,
+    0x12bc0810, 0x12a50804, 0x12a58804, 0x12a60804, 0x12a68804, 0x12a70804, 0x12a78804, 0x12a80804,
+    0x12a88804, 0x12a90804, 0x12a98804, 0x12aa0804, 0x12aa8804, 0x12ab0804, 0x12ab8804, 0x12ac0804,
+    0x12ac8804, 0x12ad0804, 0x12ad8804, 0x12ae0804, 0x12ae8804, 0x12af0804, 0x12af8804, 0x12b00804,
+    0x12b08804, 0x12b10804, 0x12b18804, 0x12b20804, 0x12b28804, 0x12b30804, 0x12b38804, 0x12b40804,
+    0x12b48804, 0x12b50804, 0x12b58804, 0x12b60804, 0x12b68804, 0x12b70804, 0x12b78804, 0x12b80804,
+    0x12b88804, 0x12b90804, 0x12b98804, 0x12ba0804, 0x12ba8804, 0x12bb0804, 0x12bb8804, 0x12bc0804,
+    0x10728810, 0x10728804, 0x10730810, 0x10b80810, 0x10db8810, 0xfd38804, 0x11000804, 0x10430810,
+    0x10430804, 0x10670810, 0x10670804, 0x11508810, 0x11508804, 0xfd88810, 0x10880810, 0xfd68810,
+    0xfdb8810, 0x11290804, 0x112f8810, 0x112f8804, 0x11298804, 0x10438810, 0x10438804, 0x10bf8804,
+    0x100a0804, 0x10d90804, 0x10ad8804, 0x100c8804, 0x1055882a, 0x1123883a, 0x10ef0810, 0x114e8810,
+    0x11a58810, 0x11a58804, 0x11a60810, 0x11a60804, 0x11a68810, 0x11a68804, 0x11a70810, 0x11a70804,
+    0x11a78810, 0x11a78804, 0x11a88810, 0x11a88804, 0x11a90810, 0x11a90804, 0x11a98810, 0x11a98804,
+    0x11aa0810, 0x11aa0804, 0x11aa8810, 0x11aa8804, 0x11ab0810, 0x11ab0804, 0x11ac0810, 0x11ac0804,
+    0x11ac8810, 0x11ac8804, 0x11ad0810, 0x11ad0804, 0x11ae8810, 0x11ae8804, 0x11af0810, 0x11af0804,
+    0x11af8810, 0x11af8804, 0x11b00810, 0x11b00804, 0x11b08810, 0x11b08804, 0x11b10810, 0x11b10804,
+    0x11b18810, 0x11b18804, 0x11b20810, 0x11b20804, 0x11b28810, 0x11b28804, 0x11b30810, 0x11b30804,
+    0x11b38810, 0x11b38804, 0x11c10810, 0x11c10804, 0x11c18810, 0x11c18804, 0x11a80810, 0x11a80804,
+    0x11ab8810, 0x11ab8804, 0x11ad8810, 0x11ad8804, 0x11ae0810, 0x11ae0804, 0x11b40810, 0x11b40804,
+    0x11b48810, 0x11b48804, 0x11b60810, 0x11b60804, 0x11b68810, 0x11b68804, 0x11b70810, 0x11b70804,
+    0x11b90810, 0x11b90804, 0x11ba0810, 0x11ba0804, 0x11ba8810, 0x11ba8804, 0x11bb0810, 0x11bb0804,
+    0x11bb8810, 0x11bb8804, 0x11bc0810, 0x11bc0804, 0x11bc8810, 0x11bc8804, 0x11be0810, 0x11be0804,
+    0x11bf0810, 0x11bf0804, 0x11bf8810, 0x11bf8804, 0x11c00810, 0x11c00804, 0x11c20810, 0x11c20804,
+    0x11c28810, 0x11c28804, 0x11c30810, 0x11c30804, 0x800161e3, 0x7710805, 0x7718805, 0x7720805,
+    0x7728805, 0x7730805, 0x7738805, 0x11b58810, 0x11b58804, 0x11bd8810, 0x11bd8804, 0xcc4, 0x8c4,
+    0x884, 0x11b88810, 0x11b88804, 0x1440805, 0x13a8805, 0x13b0805, 0x1838805, 0xedb8805,
+    0x1448805, 0x1840805, 0x12d00804, 0x12d10804, 0x12d20804, 0x12d30804, 0x12d40804, 0x12d50804,
+    0x12d60804, 0x12d80804, 0x12d90804, 0x12da0804, 0x12db0804, 0x12dc0804, 0x12dd0804, 0x12df0804,
+    0x12e00804, 0x12e10804, 0x12e20804, 0x12e30804, 0x12e40804, 0x12e60804, 0x12e70804, 0x12e80804,
+    0x12e90804, 0x12ea0804, 0x12eb0804, 0x12ec0804, 0x12ed0804, 0x12ee0804, 0x12ef0804, 0x12f00804,
+    0x12f10804, 0x12f30804, 0x12f40804, 0x12d70804, 0x12de0804, 0x12e50804, 0x12f20804, 0x12f50804,
+    0x12f68804, 0x12f90804, 0x13ec8804, 0x13ed0804, 0x13ed8804, 0x13ee0804, 0x13ee8804, 0x13ef0804,
+    0x13ef8804, 0x13f00804, 0x13f08804, 0x13f10804, 0x13f18804, 0x13f20804, 0x13f30804, 0x13f38804,
+    0x13f40804, 0x13f48804, 0x13f50804, 0x13f58804, 0x13f60804, 0x13f68804, 0x13f70804, 0x13f78804,
+    0x13f80804, 0x13f88804, 0x13f90804, 0x13f98804, 0x13fa0804, 0x13fa8804, 0x13fb0804, 0x13fb8804,
+    0x13fc0804, 0x13fc8804, 0x13fd0804, 0x13fd8804, 0x13fe0804, 0x13fe8804, 0x13ff8804, 0x14000804,
+    0x14008804, 0x14010804, 0x14018804, 0x14020804, 0x14028804, 0x14030804, 0x14038804, 0x14040804,
+    0x14048804, 0x14050804, 0x14058804, 0x14060804, 0x14068804, 0x14070804, 0x14078804, 0x14080804,
+    0x13f28804, 0x13ff0804, 0x14088804, 0x2298805, 0x0, 0x14110804, 0x141b8804, 0x14240804,
+    0x14288804, 0x14300804, 0x14430804, 0x144b8804, 0x14500804, 0x145b0804, 0x145f8804, 0x14640804,
+    0x147c8804, 0x148c0804, 0x14938804, 0x14980804, 0x14a90804, 0x14ad8804, 0x14b58804, 0x14cd8804,
+    0x14a30804, 0x14a38804, 0x14a40804, 0x14a48804, 0x14cf8804, 0x14d00804, 0x14d08804, 0x14d10804,
+    0x14d18804, 0x14d20804, 0x14d28804, 0x14d30804, 0x14d38804, 0x14d40804, 0x14d48804, 0x14d50804,
+    0x14d58804, 0x14d60804, 0x14d68804, 0x14d70804, 0x14d78804, 0x14d80804, 0x14d88804, 0x14d90804,
+    0x14d98804, 0x14da0804, 0x14da8804, 0x14db0804, 0x14db8804, 0x14dc0804, 0x14dc8804, 0x14dd0804,
+    0x14dd8804, 0x14de0804, 0x14de8804, 0x14df0804, 0x14df8804, 0x14e00804, 0x14e08804, 0x14e10804,
+    0x14e18804, 0x14e20804, 0x14e28804, 0x14e30804, 0x14e38804, 0x14e40804, 0x14e48804, 0x14e50804,
+    0x14e58804, 0x14e60804, 0x14e68804, 0x14e70804, 0x14e78804, 0x14e80804, 0x14e88804, 0x14e90804,
+    0x14e98804, 0x14ea0804, 0x14ea8804, 0x14eb0804, 0x11c98808, 0x11cb8808, 0x11cd8808, 0x11d78808,
+    0x11e38808, 0x11e88808, 0x11fd8808, 0x120a8808, 0x12140808, 0x12188808, 0x12260808, 0x122a0808,
+    0x12308808, 0x12350808, 0x12398808, 0x124b8808, 0x125f0808, 0x12648808, 0x12740808, 0x12768808,
+    0x129c0808, 0x80016242, 0x11c38808, 0x11df8808, 0x11fd0808, 0x12470808, 0x12828808, 0x12870808,
+    0x12898808, 0x128e8808, 0x12910808, 0x12960808, 0x1e58805, 0x1e60805, 0x1b60805, 0x1b68805,
+    0x1b70805, 0x1b78805, 0x1e68805, 0x1e70805, 0x1e78805, 0x1b80805, 0x1b88805, 0x1e80805,
+    0x1b90805, 0x1b98805, 0x1e88805, 0x1e90805, 0x1e98805, 0x1ea0805, 0x1ea8805, 0x1eb0805,
+    0x1eb8805, 0x1ec0805, 0x1ec8805, 0x10e0805, 0x13e8805, 0x1848805, 0x1ed0805, 0x1ed8805,
+    0x1ba0805, 0x1ba8805, 0x1ee0805, 0x1ee8805, 0x1bb0805, 0x1bb8805, 0x1bc0805, 0x1bc8805,
+    0x1bd0805, 0x1bd8805, 0x1be0805, 0x1be8805, 0x1bf0805, 0x1bf8805, 0x1810805, 0x1818805,
+    0x1820805, 0x1828805, 0x1378805, 0x12790804, 0x1450805, 0x14a0805, 0x1128805, 0x14a8805,
+    0x1120805, 0x11e8805, 0x1da8805, 0x1db0805, 0x1db8805, 0x1cd8805, 0x10c0805, 0x10c8805,
+    0x1458805, 0x1830805, 0x1ca8805, 0x1cf8805, 0x10e8805, 0x1130805, 0x1910805, 0x10d8805,
+    0x1ef0805, 0x1ef8805, 0x1f00805, 0x1f08805, 0x1f10805, 0x11f8805, 0x1d40805, 0x1dc0805,
+    0x1138805, 0x1cf0805, 0x1140805, 0x1148805, 0x5ae8805, 0x5af0805, 0x1d58805, 0x80016283,
+    0x800162e3, 0x80016342, 0x80016382, 0x800163c3, 0x80016422, 0x80016463, 0x800164c3, 0x80016523,
+    0x80016582, 0x800165c3, 0x80016623, 0x80016683, 0x800166e3, 0x80016743, 0x800167a2, 0x800167e2,
+    0x80016823, 0x80016882, 0x800168c2, 0x80016902, 0x80016943, 0x800169a2, 0x800169e3, 0x80016a42,
+    0x80016a82, 0x80016ac2, 0x80016b03, 0x80016b63, 0x80016bc3, 0x80016c22, 0x80016c62, 0x80016ca2,
+    0x80016ce2, 0x80016d22, 0x80016d62, 0x80016da3, 0x80016e02, 0x80016e43, 0x80016ea2, 0x80016ee3,
+    0x80016f43, 0x80016fa3, 0x80017003, 0x80017062, 0x800170a3, 0x80017102, 0x80017142, 0x80017182,
+    0x800171c2, 0x80017203, 0x80017263, 0x800172c3, 0x80017323, 0x80017383, 0x800173e2, 0x80017422,
+    0x80017462, 0x800174a3, 0x80017503, 0x80017563, 0x800175c2, 0x80017603, 0x80017663, 0x800176c2,
+    0x80017702, 0x80017742, 0x80017782, 0x800177c2, 0x80017802, 0x80017843, 0x800178a2, 0x800178e2,
+    0x80017923, 0x80017982, 0x800179c2, 0x80017a03, 0x80017a63, 0x80017ac3, 0x80017b22, 0x80017b62,
+    0x80017ba2, 0x80017be2, 0x80017c22, 0x80017c63, 0x80017cc2, 0x80017d03, 0x80017d62, 0x80017da2,
+    0x80017de2, 0x80017e22, 0x80017e62, 0x80017ea2, 0x80017ee3, 0x80017f42, 0x80017f82, 0x80017fc3,
+    0x80018022, 0x80018063, 0x800180c3, 0x80018122, 0x80018162, 0x800181a3, 0x80018202, 0x80018242,
+    0x80018282, 0x800182c3, 0x80018322, 0x80018363, 0x800183c2, 0x80018403, 0x80018462, 0x800184a3,
+    0x80018503, 0x80018562, 0x800185a2, 0x800185e2, 0x80018622, 0x80018662, 0x800186a2, 0x800186e2,
+    0x80018722, 0x80018762, 0x800187a2, 0x800187e2, 0x80018822, 0x80018862, 0x800188a2, 0x800188e2,
+    0x80018922, 0x80018962, 0x800189a2, 0x800189e2, 0x80018a22, 0x80018a62, 0x80018aa2, 0x80018ae2,
+    0x80018b22, 0x80018b62, 0x80018ba2, 0x80018be2, 0x80018c22, 0x80018c62, 0x80018ca2, 0x80018ce2,
+    0x80018d22, 0x80018d62, 0x80018da2, 0x80018de2, 0x80018e22, 0x80018e62, 0x80018ea2, 0x80018ee2,
+    0x80018f22, 0x80018f62, 0x80018fa2, 0x80018fe2, 0x80019022, 0x80019062, 0x800190a2, 0x800190e2,
+    0x80019122, 0x80019162, 0x800191a2, 0x800191e2, 0x80019222, 0x80019262, 0x800192a2, 0x800192e2,
+    0x80019322, 0x80019362, 0x800193a2, 0x800193e2, 0x80019422, 0x80019462, 0x800194a2, 0x800194e2,
+    0x80019522, 0x80019562, 0x800195a2, 0x800195e2, 0x80019622, 0x80019662, 0x800196a2, 0x800196e2,
+    0x80019722, 0x80019762, 0x800197a2, 0x800197e2, 0x80019822, 0x80019862, 0x800198a2, 0x800198e2,
+    0x80019922, 0x80019962, 0x800199a2, 0x800199e2, 0x80019a22, 0x80019a62, 0x80019aa2, 0x80019ae2,
+    0x80019b22, 0x80019b62, 0x80019ba2, 0x80019be2, 0x80019c22, 0x80019c62, 0x80019ca2, 0x80019ce2,
+    0x80019d22, 0x80019d62, 0x80019da2, 0x80019de2, 0x80019e22, 0x80019e62, 0x80019ea2, 0x80019ee2,
+    0x80019f22, 0x80019f62, 0x80019fa2, 0x80019fe2, 0x8001a022, 0x8001a062, 0x8001a0a2, 0x8001a0e2,
+    0x8001a122, 0x8001a162, 0x8001a1a2, 0x8001a1e2, 0x8001a222, 0x8001a262, 0x8001a2a2, 0x8001a2e2,
+    0x8001a322, 0x8001a362, 0x8001a3a2, 0x8001a3e2, 0x8001a422, 0x8001a462, 0x8001a4a2, 0x8001a4e2,
+    0x8001a522, 0x8001a562, 0x8001a5a2, 0x8001a5e2, 0x8001a622, 0x8001a662, 0x8001a6a2, 0x8001a6e2,
+    0x8001a722, 0x8001a762, 0x8001a7a2, 0x8001a7e2, 0x8001a822, 0x8001a862, 0x8001a8a2, 0x8001a8e2,
+    0x8001a922, 0x8001a962, 0x8001a9a2, 0x8001a9e2, 0x8001aa22, 0x8001aa62, 0x8001aaa2, 0x8001aae2,
+    0x8001ab22, 0x8001ab62, 0x8001aba2, 0x8001abe2, 0x8001ac22, 0x8001ac62, 0x8001aca2, 0x8001ace2,
+    0x8001ad22, 0x8001ad62, 0x8001ada2, 0x8001ade2, 0x8001ae22, 0x8001ae62, 0x8001aea2, 0x8001aee2,
+    0x8001af22, 0x8001af62, 0x8001afa2, 0x8001afe2, 0x8001b022, 0x8001b062, 0x8001b0a2, 0x8001b0e2,
+    0x8001b122, 0x8001b162, 0x8001b1a2, 0x8001b1e2, 0x8001b222, 0x8001b262, 0x8001b2a2, 0x8001b2e2,
+    0x8001b322, 0x8001b362, 0x8001b3a2, 0x8001b3e2, 0x8001b422, 0x8001b462, 0x8001b4a2, 0x8001b4e2,
+    0x8001b522, 0x8001b562, 0x8001b5a2, 0x8001b5e2, 0x8001b622, 0x8001b662, 0x8001b6a2, 0x8001b6e2,
+    0x8001b722, 0x8001b762, 0x8001b7a2, 0x8001b7e2, 0x8001b822, 0x8001b862, 0x8001b8a2, 0x8001b8e2,
+    0x8001b922, 0x8001b962, 0x8001b9a2, 0x8001b9e2, 0x8001ba22, 0x8001ba62, 0x8001baa2, 0x8001bae2,
+    0xe8d8805, 0xe8e0805, 0xe8e8805, 0xe8f0805, 0xe8f8805, 0xe900805, 0xe908805, 0xe910805,
+    0xe918805, 0xe920805, 0xe928805, 0xe930805, 0x1048807, 0x11b0805, 0x1490805, 0x1e08805,
+    0xea58805, 0xfab8804, 0x8001bb22, 0xfcc0804, 0x1c00805, 0x1c08805, 0x1c10805, 0x1c18805,
+    0x1c20805, 0x1c28805, 0x1c30805, 0x1c38805, 0x1c40805, 0x1c48805, 0xea60805, 0xea68805,
+    0x1c50805, 0x1c58805, 0x1c60805, 0x1c68805, 0x1c70805, 0x1c78805, 0x1c80805, 0x1c88805,
+    0x10f0805, 0x1918805, 0x1920805, 0x1928805, 0xea70805, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x4044, 0x4084, 0x40c4,
+    0x4104, 0x4144, 0x4184, 0x10f8805, 0xfae0804, 0x8001bb62, 0xfae8804, 0x8001bba2, 0xfaf0804,
+    0xea60809, 0xea78805, 0x8001bbe2, 0x8001bc22, 0x8001bc62, 0xfac0804, 0x8001bca2, 0x1e10805,
+    0xea80805, 0xea88805, 0x216d081a, 0x216d081c, 0x216d881a, 0x216d881c, 0x216e081a, 0x216e081c,
+    0x216f081a, 0x216f081c, 0x216f881a, 0x216f881c, 0x2170081c, 0x8001bce2, 0x2170881c, 0x8001bd22,
+    0x2171081c, 0x8001bd62, 0x2171881c, 0x8001bda2, 0x2172081c, 0x8001bde2, 0x2172881c, 0x8001be22,
+    0x2173081c, 0x8001be62, 0x2173881c, 0x8001bea2, 0x2174081c, 0x8001bee2, 0x2174881c, 0x8001bf22,
+    0x2175081c, 0x8001bf62, 0x2175881c, 0x8001bfa2, 0x2176081a, 0x2176081c, 0x8001bfe2, 0x2176881c,
+    0x8001c022, 0x2177081c, 0x8001c062, 0x2177881c, 0x2178081c, 0x2178881c, 0x2179081c, 0x2179881c,
+    0x217a081c, 0x8001c0a2, 0x8001c0e2, 0x217a881c, 0x8001c122, 0x8001c162, 0x217b081c, 0x8001c1a2,
+    0x8001c1e2, 0x217b881c, 0x8001c222, 0x8001c262, 0x217c081c, 0x8001c2a2, 0x8001c2e2, 0x217c881c,
+    0x217d081c, 0x217d881c, 0x217e081c, 0x217e881c, 0x217f081a, 0x217f081c, 0x217f881a, 0x217f881c,
+    0x2180081a, 0x2180081c, 0x2180881c, 0x2181081c, 0x2181881c, 0x2182081c, 0x2182881c, 0x2183081a,
+    0x2183081c, 0x2183881c, 0x2184081c, 0x2184881c, 0x2185081c, 0x8001c322, 0x2170081a, 0x2171881a,
+    0xdc4, 0xe04, 0x2618805, 0x2620805, 0xfaf8804, 0x8001c362, 0x8001c3a2, 0x1100805, 0x216d081e,
+    0x216d0822, 0x216d881e, 0x216d8822, 0x216e081e, 0x216e0822, 0x216f081e, 0x216f0822, 0x216f881e,
+    0x216f8822, 0x21700822, 0x8001c3e2, 0x21708822, 0x8001c422, 0x21710822, 0x8001c462, 0x21718822,
+    0x8001c4a2, 0x21720822, 0x8001c4e2, 0x21728822, 0x8001c522, 0x21730822, 0x8001c562, 0x21738822,
+    0x8001c5a2, 0x21740822, 0x8001c5e2, 0x21748822, 0x8001c622, 0x21750822, 0x8001c662, 0x21758822,
+    0x8001c6a2, 0x2176081e, 0x21760822, 0x8001c6e2, 0x21768822, 0x8001c722, 0x21770822, 0x8001c762,
+    0x21778822, 0x21780822, 0x21788822, 0x21790822, 0x21798822, 0x217a0822, 0x8001c7a2, 0x8001c7e2,
+    0x217a8822, 0x8001c822, 0x8001c862, 0x217b0822, 0x8001c8a2, 0x8001c8e2, 0x217b8822, 0x8001c922,
+    0x8001c962, 0x217c0822, 0x8001c9a2, 0x8001c9e2, 0x217c8822, 0x217d0822, 0x217d8822, 0x217e0822,
+    0x217e8822, 0x217f081e, 0x217f0822, 0x217f881e, 0x217f8822, 0x2180081e, 0x21800822, 0x21808822,
+    0x21810822, 0x21818822, 0x21820822, 0x21828822, 0x2183081e, 0x21830822, 0x21838822, 0x21840822,
+    0x21848822, 0x21850822, 0x8001ca22, 0x2170081e, 0x2171881e, 0x8001ca62, 0x8001caa2, 0x8001cae2,
+    0x8001cb22, 0x1108805, 0xfb00804, 0xfb08804, 0x8001cb62, 0x8001cba2, 0x22148804, 0x22150804,
+    0x22158804, 0x22168804, 0x22178804, 0x22180804, 0x22188804, 0x22198804, 0x221a0804, 0x221a8804,
+    0x221c0804, 0x221c8804, 0x221d0804, 0x221d8804, 0x221f8804, 0x22200804, 0x22208804, 0x22210804,
+    0x22218804, 0x22220804, 0x22228804, 0x22248804, 0x22250804, 0x22260804, 0x22268804, 0x22278804,
+    0x22280804, 0x22288804, 0x22290804, 0x22298804, 0x222a0804, 0x222a8804, 0x222b8804, 0x222d0804,
+    0x222d8804, 0x222e0804, 0x222e8804, 0x22170804, 0x221b0804, 0x221e0804, 0x222f0804, 0x22260808,
+    0x22190804, 0x20ba8808, 0x20bb0808, 0x21298808, 0x20bb8808, 0x212a8808, 0x212b0808, 0x20bc0808,
+    0x20bc8808, 0x20bd0808, 0x212c8808, 0x212d0808, 0x212d8808, 0x212e0808, 0x212e8808, 0x212f0808,
+    0x20c78808, 0x20bd8808, 0x20be0808, 0x20be8808, 0x20cb0808, 0x20bf0808, 0x20bf8808, 0x20c00808,
+    0x20c08808, 0x20c10808, 0x20c18808, 0x20c20808, 0x20c28808, 0x20c30808, 0x20c38808, 0x20f98808,
+    0x20fa0808, 0x20fa8808, 0x20fb0808, 0x20fb8808, 0x20fc0808, 0x20fc8808, 0x20fd0808, 0x20fd8808,
+    0x20fe0808, 0x20fe8808, 0x20ff0808, 0x20ff8808, 0x21000808, 0x21008808, 0x21010808, 0x21018808,
+    0x21020808, 0x21028808, 0x21030808, 0x21038808, 0x20f90808, 0x20c48808, 0x20c50808, 0x21380808,
+    0x21388808, 0x213a8808, 0x213b8808, 0x213e0808, 0x21400808, 0x21410808, 0x20c88808, 0x21430808,
+    0x21440808, 0x20c90808, 0x20c98808, 0x20ca8808, 0x20cb8808, 0x20cc0808, 0x20ce0808, 0x20cf0808,
+    0x20d00808, 0x20d08808, 0x20d10808, 0x20d18808, 0x20d20808, 0x20d38808, 0x20d58808, 0x20da8808,
+    0x20de0808, 0x20e08808, 0x214d0808, 0x214d8808, 0x20e60808, 0x20e68808, 0x20e70808, 0x210b0808,
+    0x210b8808, 0x210d0808, 0x21118808, 0x21120808, 0x21130808, 0x21180808, 0x21198808, 0xea90805,
+    0xea98805, 0x8001cbe2, 0x8001cc22, 0x8001cc62, 0x8001cca2, 0x8001cce2, 0x8001cd22, 0x8001cd62,
+    0x8001cda2, 0x8001cde2, 0x8001ce22, 0x8001ce62, 0x8001cea2, 0x8001cee2, 0x8001cf22, 0x8001cf62,
+    0x8001cfa2, 0x8001cfe2, 0x8001d022, 0x22270804, 0x8001d062, 0x22258804, 0x8001d0a2, 0x8001d0e2,
+    0x8001d122, 0x8001d162, 0x8001d1a2, 0x22160804, 0x221b8804, 0x8001d1e2, 0x8001d222, 0x222c0804,
+    0x222c8804, 0x222b0804, 0x8001d262, 0x22150832, 0x22180832, 0x221a8832, 0x221c0832, 0x22230804,
+    0x22238804, 0x22240804, 0x221a0832, 0x221e8804, 0x221f0804, 0x222f8804, 0x22300804, 0xe938805,
+    0xe940805, 0xe948805, 0xe950805, 0xe958805, 0xe960805, 0xe968805, 0xe970805, 0xe978805,
+    0xe980805, 0xe988805, 0xe990805, 0xe998805, 0xe9a0805, 0xe9a8805, 0xe9b0805, 0xe9b8805,
+    0xe9c0805, 0xe9c8805, 0xe9d0805, 0xe9d8805, 0xe9e0805, 0xe9e8805, 0xe9f0805, 0xe9f8805,
+    0xea00805, 0xea08805, 0xea10805, 0xea18805, 0xea20805, 0xea28805, 0xea30805, 0xea38805,
+    0xea40805, 0xea48805, 0xea50805, 0x2171081e, 0x2173081e, 0x2173881e, 0x2177081e, 0x2178881e,
+    0x217a081e, 0x217a881e, 0x217b081e, 0x217b881e, 0x217c081e, 0x217d881e, 0x2180881e, 0x2181081e,
+    0x2181881e, 0x2182081e, 0x2182881e, 0x8001d2a3, 0x8001d303, 0x8001d363, 0x8001d3c3, 0x8001d423,
+    0x8001d483, 0x8001d4e3, 0x8001d543, 0x8001d5a3, 0x8001d603, 0x8001d663, 0x8001d6c3, 0x8001d723,
+    0x8001d783, 0x8001d7e4, 0x8001d864, 0x8001d8e4, 0x8001d964, 0x8001d9e4, 0x8001da64, 0x8001dae4,
+    0x8001db64, 0x8001dbe4, 0x8001dc64, 0x8001dce4, 0x8001dd64, 0x8001dde4, 0x8001de64, 0x8001dee4,
+    0x8001df67, 0x8001e046, 0x8001e104, 0x8001e184, 0x8001e204, 0x8001e284, 0x8001e304, 0x8001e384,
+    0x8001e404, 0x8001e484, 0x8001e504, 0x8001e584, 0x8001e604, 0x8001e684, 0x8001e704, 0x8001e784,
+    0x8001e804, 0x8001e884, 0x8001e904, 0x8001e984, 0x8001ea04, 0x8001ea84, 0x8001eb04, 0x8001eb84,
+    0x8001ec04, 0x8001ec84, 0x8001ed04, 0x8001ed84, 0x8001ee04, 0x8001ee84, 0x8001ef04, 0x8001ef84,
+    0x8001f004, 0x8001f084, 0x8001f104, 0x8001f184, 0x8001f204, 0x8001f284, 0x8001f302, 0x8001f342,
+    0x8001f382, 0x8001f3c2, 0x8001f402, 0x8001f442, 0x8001f482, 0x8001f4c2, 0x8001f502, 0x8001f542,
+    0x8001f582, 0x8001f5c2, 0x8001f603, 0x8001f662, 0x8001f6a2, 0x8001f6e2, 0x8001f722, 0x8001f762,
+    0x8001f7a2, 0x8001f7e2, 0x8001f822, 0x8001f862, 0x8001f8a2, 0x8001f8e2, 0x8001f922, 0x8001f962,
+    0x8001f9a2, 0x8001f9e2, 0x20ba880c, 0x20bb880c, 0x20bc080c, 0x20bd080c, 0x20bd880c, 0x20be080c,
+    0x20bf080c, 0x20c0080c, 0x20c0880c, 0x20c1880c, 0x20c2080c, 0x20c2880c, 0x20c3080c, 0x20c3880c,
+    0x8001fa22, 0x8001fa62, 0x8001faa2, 0x8001fae2, 0x8001fb22, 0x8001fb62, 0x8001fba2, 0x8001fbe2,
+    0x8001fc22, 0x8001fc62, 0x8001fca2, 0x8001fce2, 0x8001fd22, 0x8001fd62, 0x8001fda5, 0x8001fe44,
+    0x8001fec2, 0xeaa0805, 0x8001ff02, 0x8001ff42, 0x8001ff82, 0x8001ffc2, 0x80020002, 0x80020042,
+    0x80020082, 0x800200c2, 0x80020102, 0x80020142, 0x80020182, 0x800201c2, 0x80020202, 0x80020242,
+    0x80020282, 0x800202c2, 0x80020302, 0x80020342, 0x80020382, 0x800203c2, 0x80020402, 0x80020442,
+    0x80020482, 0x800204c2, 0x80020502, 0x80020542, 0x80020582, 0x800205c2, 0x80020602, 0x80020642,
+    0x80020682, 0x800206c2, 0x80020702, 0x80020742, 0x80020782, 0x800207c2, 0x80020802, 0x80020842,
+    0x80020882, 0x800208c2, 0x80020902, 0x80020942, 0x80020982, 0x800209c2, 0x80020a02, 0x80020a42,
+    0x80020a82, 0x80020ac2, 0x80020b02, 0x80020b42, 0x80020b82, 0x80020bc2, 0x80020c02, 0x80020c42,
+    0x80020c82, 0x80020cc2, 0x80020d02, 0x80020d42, 0x80020d82, 0x80020dc2, 0x80020e02, 0x80020e42,
+    0x80020e82, 0x80020ec2, 0x80020f03, 0x80020f63, 0x80020fc3, 0x80021023, 0x80021083, 0x800210e3,
+    0x80021143, 0x800211a3, 0x80021203, 0x80021264, 0x800212e4, 0x80021364, 0x800213e2, 0x80021423,
+    0x80021482, 0x800214c3, 0x216d0826, 0x216d8826, 0x216e0826, 0x216f0826, 0x216f8826, 0x21700826,
+    0x21708826, 0x21710826, 0x21718826, 0x21720826, 0x21728826, 0x21730826, 0x21738826, 0x21740826,
+    0x21748826, 0x21750826, 0x21758826, 0x21760826, 0x21768826, 0x21770826, 0x21778826, 0x21780826,
+    0x21788826, 0x21790826, 0x21798826, 0x217a0826, 0x217a8826, 0x217b0826, 0x217b8826, 0x217c0826,
+    0x217c8826, 0x217d0826, 0x217d8826, 0x217e0826, 0x217e8826, 0x217f0826, 0x217f8826, 0x21800826,
+    0x21808826, 0x21810826, 0x21818826, 0x21820826, 0x21828826, 0x21830826, 0x21838826, 0x21840826,
+    0x21848826, 0x80021524, 0x800215a5, 0x80021644, 0x800216c5, 0x80021763, 0x800217c5, 0x80021863,
+    0x800218c3, 0x80021926, 0x800219e4, 0x80021a63, 0x80021ac3, 0x80021b23, 0x80021b84, 0x80021c04,
+    0x80021c84, 0x80021d04, 0x80021d84, 0x80021e04, 0x80021e84, 0x80021f06, 0x80021fc2, 0x80022006,
+    0x800220c6, 0x80022185, 0x80022224, 0x800222a6, 0x80022366, 0x80022424, 0x800224a3, 0x80022503,
+    0x80022564, 0x800225e4, 0x80022665, 0x80022705, 0x800227a3, 0x80022803, 0x80022864, 0x800228e3,
+    0x80022943, 0x800229a2, 0x800229e2, 0x80022a23, 0x80022a83, 0x80022ae6, 0x80022ba4, 0x80022c25,
+    0x80022cc6, 0x80022d84, 0x80022e03, 0x80022e63, 0x80022ec6, 0x80022f84, 0x80023006, 0x800230c3,
+    0x80023125, 0x800231c3, 0x80023224, 0x800232a3, 0x80023304, 0x80023385, 0x80023424, 0x800234a5,
+    0x80023544, 0x800235c2, 0x80023605, 0x800236a3, 0x80023703, 0x80023764, 0x800237e3, 0x80023843,
+    0x800238a3, 0x80023905, 0x800239a4, 0x80023a22, 0x80023a66, 0x80023b23, 0x80023b85, 0x80023c24,
+    0x80023ca4, 0x80023d23, 0x80023d83, 0x80023de4, 0x80023e62, 0x80023ea4, 0x80023f25, 0x80023fc2,
+    0x80024006, 0x800240c3, 0x80024123, 0x80024183, 0x800241e3, 0x80024243, 0x800242a3, 0x80024303,
+    0x80024363, 0x800243c3, 0x80024423, 0x80024483, 0x800244e4, 0x80024564, 0x800245e4, 0x80024664,
+    0x800246e4, 0x80024764, 0x800247e4, 0x80024864, 0x800248e4, 0x80024964, 0x800249e4, 0x80024a64,
+    0x80024ae4, 0x80024b64, 0x80024be4, 0x80024c63, 0x80024cc2, 0x80024d02, 0x80024d43, 0x80024da2,
+    0x80024de2, 0x80024e22, 0x80024e63, 0x80024ec3, 0x80024f22, 0x80024f64, 0x80024fe4, 0x80025064,
+    0x800250e4, 0x80025168, 0x80025262, 0x800252a2, 0x800252e2, 0x80025322, 0x80025362, 0x800253a2,
+    0x800253e2, 0x80025422, 0x80025463, 0x800254c4, 0x80025542, 0x80025582, 0x800255c2, 0x80025602,
+    0x80025642, 0x80025682, 0x800256c2, 0x80025703, 0x80025763, 0x800257c3, 0x80025823, 0x80025882,
+    0x800258c2, 0x80025902, 0x80025942, 0x80025982, 0x800259c2, 0x80025a02, 0x80025a42, 0x80025a82,
+    0x80025ac2, 0x80025b03, 0x80025b63, 0x80025bc2, 0x80025c03, 0x80025c63, 0x80025cc3, 0x80025d22,
+    0x80025d63, 0x80025dc3, 0x80025e24, 0x80025ea2, 0x80025ee3, 0x80025f43, 0x80025fa3, 0x80026003,
+    0x80026065, 0x80026106, 0x800261c2, 0x80026202, 0x80026242, 0x80026282, 0x800262c2, 0x80026302,
+    0x80026342, 0x80026382, 0x800263c2, 0x80026402, 0x80026442, 0x80026482, 0x800264c2, 0x80026502,
+    0x80026542, 0x80026582, 0x800265c2, 0x80026602, 0x80026644, 0x800266c2, 0x80026702, 0x80026742,
+    0x80026784, 0x80026803, 0x80026862, 0x800268a2, 0x800268e2, 0x80026922, 0x80026962, 0x800269a2,
+    0x800269e2, 0x80026a22, 0x80026a62, 0x80026aa2, 0x80026ae3, 0x80026b42, 0x80026b82, 0x80026bc3,
+    0x80026c23, 0x80026c82, 0x80026cc4, 0x80026d43, 0x80026da2, 0x80026de2, 0x80026e22, 0x80026e62,
+    0x80026ea3, 0x80026f03, 0x80026f63, 0x80026fc3, 0x80027023, 0x80027083, 0x800270e3, 0x80027143,
+    0x800271a3, 0x80027203, 0x80027263, 0x800272c4, 0x80027344, 0x800273c4, 0x80027444, 0x800274c4,
+    0x80027544, 0x800275c4, 0x80027644, 0x800276c4, 0x80027744, 0x800277c4, 0x80027844, 0x800278c4,
+    0x80027944, 0x800279c4, 0x80027a44, 0x80027ac4, 0x80027b44, 0x80027bc4, 0x80027c44, 0x80027cc4,
+    0x80027d44, 0x80027dc3, 0x7fb0805, 0x7fb8805, 0x7fc0805, 0x7fc8805, 0x7fd0805, 0x7fd8805,
+    0x7fe0805, 0x7fe8805, 0x7ff0805, 0x7ff8805, 0x8000805, 0x8008805, 0x8010805, 0x8018805,
+    0x8020805, 0x8028805, 0x8030805, 0x8038805, 0x8040805, 0x8048805, 0x8050805, 0x8058805,
+    0x8060805, 0x8068805, 0x8070805, 0x8078805, 0x8080805, 0x8088805, 0x8090805, 0x8098805,
+    0x80a0805, 0x80a8805, 0x80b0805, 0x80b8805, 0x80c0805, 0x80c8805, 0x80d0805, 0x80d8805,
+    0x80e0805, 0x80e8805, 0x80f0805, 0x80f8805, 0x8100805, 0x8108805, 0x8110805, 0x8118805,
+    0x8120805, 0x8128805, 0x8130805, 0x8138805, 0x8140805, 0x8148805, 0x8150805, 0x8158805,
+    0x8160805, 0x8168805, 0x8170805, 0x8178805, 0x8180805, 0x8188805, 0x8190805, 0x8198805,
+    0x81a0805, 0x81a8805, 0x22308804, 0x22310804, 0x22318804, 0x22320804, 0x22328804, 0x22330804,
+    0x22338804, 0x22340804, 0x22348804, 0x22350804, 0x22358804, 0x22360804, 0x22368804, 0x22370804,
+    0x22378804, 0x22380804, 0x22388804, 0x22390804, 0x22398804, 0x223a0804, 0x223a8804, 0x223b0804,
+    0x223b8804, 0x223c0804, 0x223c8804, 0x223d0804, 0x223d8804, 0x223e0804, 0x223e8804, 0x223f0804,
+    0x223f8804, 0x22400804, 0x22408804, 0x22410804, 0x22418804, 0x22420804, 0x22428804, 0x22430804,
+    0x22438804, 0x22440804, 0x22448804, 0x22450804, 0x22458804, 0x22460804, 0x22468804, 0x22470804,
+    0x22478804, 0x22480804, 0x22488804, 0x22490804, 0x22498804, 0x224a0804, 0x224a8804, 0x224b0804,
+    0x224b8804, 0x224c0804, 0x224c8804, 0x224d0804, 0x224d8804, 0x224e0804, 0x224e8804, 0x224f0804,
+    0x224f8804, 0x22500804, 0x22508804, 0x22510804, 0x22518804, 0x22520804, 0x22528804, 0x22530804,
+    0x22538804, 0x22540804, 0x22548804, 0x22550804, 0x22558804, 0x22560804, 0x22568804, 0x22570804,
+    0x22578804, 0x22580804, 0x22588804, 0x22590804, 0x22598804, 0x225a0804, 0x225a8804, 0x225b0804,
+    0x225b8804, 0x225c0804, 0x225c8804, 0x225d0804, 0x225d8804, 0x225e0804, 0x225e8804, 0x225f0804,
+    0x225f8804, 0x22600804, 0x22608804, 0x22610804, 0x22618804, 0x22620804, 0x22628804, 0x22630804,
+    0x22638804, 0x22640804, 0x22648804, 0x22650804, 0x22658804, 0x22660804, 0x22668804, 0x22670804,
+    0x22678804, 0x22680804, 0x22688804, 0x22690804, 0x22698804, 0x226a0804, 0x226a8804, 0x226b0804,
+    0x226b8804, 0x226c0804, 0x226c8804, 0x226d0804, 0x226d8804, 0x226e0804, 0x226e8804, 0x226f0804,
+    0x226f8804, 0x22700804, 0x22708804, 0x22710804, 0x22718804, 0x22720804, 0x22728804, 0x22730804,
+    0x22738804, 0x22740804, 0x22748804, 0x22750804, 0x22758804, 0x22760804, 0x22768804, 0x22770804,
+    0x22778804, 0x22780804, 0x22788804, 0x22790804, 0x22798804, 0x227a0804, 0x227a8804, 0x227b0804,
+    0x227b8804, 0x227c0804, 0x227c8804, 0x227d0804, 0x227d8804, 0x227e0804, 0x227e8804, 0x227f0804,
+    0x227f8804, 0x22800804, 0x22808804, 0x22810804, 0x22818804, 0x22820804, 0x22828804, 0x22830804,
+    0x22838804, 0x22840804, 0x22848804, 0x22850804, 0x22858804, 0x22860804, 0x22868804, 0x22870804,
+    0x22878804, 0x22880804, 0x22888804, 0x22890804, 0x22898804, 0x228a0804, 0x228a8804, 0x228b0804,
+    0x228b8804, 0x228c0804, 0x228c8804, 0x228d0804, 0x228d8804, 0x228e0804, 0x228e8804, 0x228f0804,
+    0x228f8804, 0x22900804, 0x22908804, 0x22910804, 0x22918804, 0x22920804, 0x22928804, 0x22930804,
+    0x22938804, 0x22940804, 0x22948804, 0x22950804, 0x22958804, 0x22960804, 0x22968804, 0x22970804,
+    0x22978804, 0x22980804, 0x22988804, 0x22990804, 0x22998804, 0x229a0804, 0x229a8804, 0x229b0804,
+    0x229b8804, 0x229c0804, 0x229c8804, 0x229d0804, 0x229d8804, 0x229e0804, 0x229e8804, 0x229f0804,
+    0x229f8804, 0x22a00804, 0x22a08804, 0x22a10804, 0x22a18804, 0x22a20804, 0x22a28804, 0x22a30804,
+    0x22a38804, 0x22a40804, 0x22a48804, 0x22a50804, 0x22a58804, 0x22a60804, 0x22a68804, 0x22a70804,
+    0x22a78804, 0x22a80804, 0x22a88804, 0x22a90804, 0x22a98804, 0x22aa0804, 0x22aa8804, 0x22ab0804,
+    0x22ab8804, 0x22ac0804, 0x22ac8804, 0x22ad0804, 0x22ad8804, 0x22ae0804, 0x22ae8804, 0x22af0804,
+    0x22af8804, 0x22b00804, 0x22b08804, 0x22b10804, 0x22b18804, 0x22b20804, 0x22b28804, 0x22b30804,
+    0x22b38804, 0x22b40804, 0x22b48804, 0x22b50804, 0x22b58804, 0x22b60804, 0x22b68804, 0x22b70804,
+    0x22b78804, 0x22b80804, 0x22b88804, 0x22b90804, 0x22b98804, 0x22ba0804, 0x22ba8804, 0x22bb0804,
+    0x22bb8804, 0x22bc0804, 0x22bc8804, 0x22bd0804, 0x22bd8804, 0x22be0804, 0x22be8804, 0x22bf0804,
+    0x22bf8804, 0x22c00804, 0x22c08804, 0x22c10804, 0x22c18804, 0x22c20804, 0x22c28804, 0x22c30804,
+    0x22c38804, 0x22c40804, 0x22c48804, 0x22c50804, 0x22c58804, 0x22c60804, 0x22c68804, 0x22c70804,
+    0x22c78804, 0x22c80804, 0x22c88804, 0x22c90804, 0x22c98804, 0x22ca0804, 0x22ca8804, 0x22cb0804,
+    0x22cb8804, 0x22cc0804, 0x22cc8804, 0x22cd0804, 0x22cd8804, 0x22ce0804, 0x22ce8804, 0x22cf0804,
+    0x22cf8804, 0x22d00804, 0x22d08804, 0x22d10804, 0x22d18804, 0x22d20804, 0x22d28804, 0x22d30804,
+    0x22d38804, 0x22d40804, 0x22d48804, 0x22d50804, 0x22d58804, 0x22d60804, 0x22d68804, 0x22d70804,
+    0x22d78804, 0x22d80804, 0x22d88804, 0x22d90804, 0x22d98804, 0x22da0804, 0x22da8804, 0x22db0804,
+    0x22db8804, 0x22dc0804, 0x22dc8804, 0x22dd0804, 0x22dd8804, 0x22de0804, 0x22de8804, 0x22df0804,
+    0x22df8804, 0x22e00804, 0x22e08804, 0x22e10804, 0x22e18804, 0x22e20804, 0x22e28804, 0x22e30804,
+    0x22e38804, 0x22e40804, 0x22e48804, 0x22e50804, 0x22e58804, 0x22e60804, 0x22e68804, 0x22e70804,
+    0x22e78804, 0x22e80804, 0x22e88804, 0x22e90804, 0x22e98804, 0x22ea0804, 0x22ea8804, 0x22eb0804,
+    0x22eb8804, 0x22ec0804, 0x22ec8804, 0x22ed0804, 0x22ed8804, 0x22ee0804, 0x22ee8804, 0x22ef0804,
+    0x22ef8804, 0x22f00804, 0x22f08804, 0x22f10804, 0x22f18804, 0x22f20804, 0x22f28804, 0x22f30804,
+    0x22f38804, 0x22f40804, 0x22f48804, 0x22f50804, 0x22f58804, 0x22f60804, 0x22f68804, 0x22f70804,
+    0x22f78804, 0x22f80804, 0x22f88804, 0x22f90804, 0x22f98804, 0x22fa0804, 0x22fa8804, 0x22fb0804,
+    0x22fb8804, 0x22fc0804, 0x22fc8804, 0x22fd0804, 0x22fd8804, 0x22fe0804, 0x22fe8804, 0x22ff0804,
+    0x22ff8804, 0x23000804, 0x23008804, 0x23010804, 0x23018804, 0x23020804, 0x23028804, 0x23030804,
+    0x23038804, 0x23040804, 0x23048804, 0x23050804, 0x23058804, 0x23060804, 0x23068804, 0x23070804,
+    0x23078804, 0x23080804, 0x23088804, 0x23090804, 0x23098804, 0x230a0804, 0x230a8804, 0x230b0804,
+    0x230b8804, 0x230c0804, 0x230c8804, 0x230d0804, 0x230d8804, 0x230e0804, 0x230e8804, 0x230f0804,
+    0x230f8804, 0x23100804, 0x23108804, 0x23110804, 0x23118804, 0x23120804, 0x23128804, 0x23130804,
+    0x23138804, 0x23140804, 0x23148804, 0x23150804, 0x23158804, 0x23160804, 0x23168804, 0x23170804,
+    0x23178804, 0x23180804, 0x23188804, 0x23190804, 0x23198804, 0x231a0804, 0x231a8804, 0x231b0804,
+    0x231b8804, 0x231c0804, 0x231c8804, 0x231d0804, 0x231d8804, 0x231e0804, 0x231e8804, 0x231f0804,
+    0x231f8804, 0x23200804, 0x23208804, 0x23210804, 0x23218804, 0x23220804, 0x23228804, 0x23230804,
+    0x23238804, 0x23240804, 0x23248804, 0x23250804, 0x23258804, 0x23260804, 0x23268804, 0x23270804,
+    0x23278804, 0x23280804, 0x23288804, 0x23290804, 0x23298804, 0x232a0804, 0x232a8804, 0x232b0804,
+    0x232b8804, 0x232c0804, 0x232c8804, 0x232d0804, 0x232d8804, 0x232e0804, 0x232e8804, 0x232f0804,
+    0x232f8804, 0x23300804, 0x23308804, 0x23310804, 0x23318804, 0x23320804, 0x23328804, 0x23330804,
+    0x23338804, 0x23340804, 0x23348804, 0x23350804, 0x23358804, 0x23360804, 0x23368804, 0x23370804,
+    0x23378804, 0x23380804, 0x23388804, 0x23390804, 0x23398804, 0x233a0804, 0x233a8804, 0x233b0804,
+    0x233b8804, 0x233c0804, 0x233c8804, 0x233d0804, 0x233d8804, 0x233e0804, 0x233e8804, 0x233f0804,
+    0x233f8804, 0x23400804, 0x23408804, 0x23410804, 0x23418804, 0x23420804, 0x23428804, 0x23430804,
+    0x23438804, 0x23440804, 0x23448804, 0x23450804, 0x23458804, 0x23460804, 0x23468804, 0x23470804,
+    0x23478804, 0x23480804, 0x23488804, 0x23490804, 0x23498804, 0x234a0804, 0x234a8804, 0x234b0804,
+    0x234b8804, 0x234c0804, 0x234c8804, 0x234d0804, 0x234d8804, 0x234e0804, 0x234e8804, 0x234f0804,
+    0x234f8804, 0x23500804, 0x23508804, 0x23510804, 0x23518804, 0x23520804, 0x23528804, 0x23530804,
+    0x23538804, 0x23540804, 0x23548804, 0x23550804, 0x23558804, 0x23560804, 0x23568804, 0x23570804,
+    0x23578804, 0x23580804, 0x23588804, 0x23590804, 0x23598804, 0x235a0804, 0x235a8804, 0x235b0804,
+    0x235b8804, 0x235c0804, 0x235c8804, 0x235d0804, 0x235d8804, 0x235e0804, 0x235e8804, 0x235f0804,
+    0x235f8804, 0x23600804, 0x23608804, 0x23610804, 0x23618804, 0x23620804, 0x23628804, 0x23630804,
+    0x23638804, 0x23640804, 0x23648804, 0x23650804, 0x23658804, 0x23660804, 0x23668804, 0x23670804,
+    0x23678804, 0x23680804, 0x23688804, 0x23690804, 0x23698804, 0x236a0804, 0x236a8804, 0x236b0804,
+    0x236b8804, 0x236c0804, 0x236c8804, 0x236d0804, 0x236d8804, 0x236e0804, 0x236e8804, 0x236f0804,
+    0x236f8804, 0x23700804, 0x23708804, 0x23710804, 0x23718804, 0x23720804, 0x23728804, 0x23730804,
+    0x23738804, 0x23740804, 0x23748804, 0x23750804, 0x23758804, 0x23760804, 0x23768804, 0x23770804,
+    0x23778804, 0x23780804, 0x23788804, 0x23790804, 0x23798804, 0x237a0804, 0x237a8804, 0x237b0804,
+    0x237b8804, 0x237c0804, 0x237c8804, 0x237d0804, 0x237d8804, 0x237e0804, 0x237e8804, 0x237f0804,
+    0x237f8804, 0x23800804, 0x23808804, 0x23810804, 0x23818804, 0x23820804, 0x23828804, 0x23830804,
+    0x23838804, 0x23840804, 0x23848804, 0x23850804, 0x23858804, 0x23860804, 0x23868804, 0x23870804,
+    0x23878804, 0x23880804, 0x23888804, 0x23890804, 0x23898804, 0x238a0804, 0x238a8804, 0x238b0804,
+    0x238b8804, 0x238c0804, 0x238c8804, 0x238d0804, 0x238d8804, 0x238e0804, 0x238e8804, 0x238f0804,
+    0x238f8804, 0x23900804, 0x23908804, 0x23910804, 0x23918804, 0x23920804, 0x23928804, 0x23930804,
+    0x23938804, 0x23940804, 0x23948804, 0x23950804, 0x23958804, 0x23960804, 0x23968804, 0x23970804,
+    0x23978804, 0x23980804, 0x23988804, 0x23990804, 0x23998804, 0x239a0804, 0x239a8804, 0x239b0804,
+    0x239b8804, 0x239c0804, 0x239c8804, 0x239d0804, 0x239d8804, 0x239e0804, 0x239e8804, 0x239f0804,
+    0x239f8804, 0x23a00804, 0x23a08804, 0x23a10804, 0x23a18804, 0x23a20804, 0x23a28804, 0x23a30804,
+    0x23a38804, 0x23a40804, 0x23a48804, 0x23a50804, 0x23a58804, 0x23a60804, 0x23a68804, 0x23a70804,
+    0x23a78804, 0x23a80804, 0x23a88804, 0x23a90804, 0x23a98804, 0x23aa0804, 0x23aa8804, 0x23ab0804,
+    0x23ab8804, 0x23ac0804, 0x23ac8804, 0x23ad0804, 0x23ad8804, 0x23ae0804, 0x23ae8804, 0x23af0804,
+    0x23af8804, 0x23b00804, 0x23b08804, 0x23b10804, 0x23b18804, 0x23b20804, 0x23b28804, 0x23b30804,
+    0x23b38804, 0x23b40804, 0x23b48804, 0x23b50804, 0x23b58804, 0x23b60804, 0x23b68804, 0x23b70804,
+    0x23b78804, 0x23b80804, 0x23b88804, 0x23b90804, 0x23b98804, 0x23ba0804, 0x23ba8804, 0x23bb0804,
+    0x23bb8804, 0x23bc0804, 0x23bc8804, 0x23bd0804, 0x23bd8804, 0x23be0804, 0x23be8804, 0x23bf0804,
+    0x23bf8804, 0x23c00804, 0x23c08804, 0x23c10804, 0x23c18804, 0x23c20804, 0x23c28804, 0x23c30804,
+    0x23c38804, 0x23c40804, 0x23c48804, 0x23c50804, 0x23c58804, 0x23c60804, 0x23c68804, 0x23c70804,
+    0x23c78804, 0x23c80804, 0x23c88804, 0x23c90804, 0x23c98804, 0x23ca0804, 0x23ca8804, 0x23cb0804,
+    0x23cb8804, 0x23cc0804, 0x23cc8804, 0x23cd0804, 0x23cd8804, 0x23ce0804, 0x23ce8804, 0x23cf0804,
+    0x23cf8804, 0x23d00804, 0x23d08804, 0x23d10804, 0x23d18804, 0x23d20804, 0x23d28804, 0x23d30804,
+    0x23d38804, 0x23d40804, 0x23d48804, 0x23d50804, 0x23d58804, 0x23d60804, 0x23d68804, 0x23d70804,
+    0x23d78804, 0x23d80804, 0x23d88804, 0x23d90804, 0x23d98804, 0x23da0804, 0x23da8804, 0x23db0804,
+    0x23db8804, 0x23dc0804, 0x23dc8804, 0x23dd0804, 0x23dd8804, 0x23de0804, 0x23de8804, 0x23df0804,
+    0x23df8804, 0x23e00804, 0x23e08804, 0x23e10804, 0x23e18804, 0x23e20804, 0x23e28804, 0x23e30804,
+    0x23e38804, 0x23e40804, 0x23e48804, 0x23e50804, 0x23e58804, 0x23e60804, 0x23e68804, 0x23e70804,
+    0x23e78804, 0x23e80804, 0x23e88804, 0x23e90804, 0x23e98804, 0x23ea0804, 0x23ea8804, 0x23eb0804,
+    0x23eb8804, 0x23ec0804, 0x23ec8804, 0x23ed0804, 0x23ed8804, 0x23ee0804, 0x23ee8804, 0x23ef0804,
+    0x23ef8804, 0x23f00804, 0x23f08804, 0x23f10804, 0x23f18804, 0x23f20804, 0x23f28804, 0x23f30804,
+    0x23f38804, 0x23f40804, 0x23f48804, 0x23f50804, 0x23f58804, 0x23f60804, 0x23f68804, 0x23f70804,
+    0x23f78804, 0x23f80804, 0x23f88804, 0x23f90804, 0x23f98804, 0x23fa0804, 0x23fa8804, 0x23fb0804,
+    0x23fb8804, 0x23fc0804, 0x23fc8804, 0x23fd0804, 0x23fd8804, 0x23fe0804, 0x23fe8804, 0x23ff0804,
+    0x23ff8804, 0x24000804, 0x24008804, 0x24010804, 0x24018804, 0x24020804, 0x24028804, 0x24030804,
+    0x24038804, 0x24040804, 0x24048804, 0x24050804, 0x24058804, 0x24060804, 0x24068804, 0x24070804,
+    0x24078804, 0x24080804, 0x24088804, 0x24090804, 0x24098804, 0x240a0804, 0x240a8804, 0x240b0804,
+    0x240b8804, 0x240c0804, 0x240c8804, 0x240d0804, 0x240d8804, 0x240e0804, 0x240e8804, 0x240f0804,
+    0x240f8804, 0x24100804, 0x24108804, 0x24110804, 0x24118804, 0x24120804, 0x24128804, 0x24130804,
+    0x24138804, 0x24140804, 0x24148804, 0x24150804, 0x24158804, 0x24160804, 0x24168804, 0x24170804,
+    0x24178804, 0x24180804, 0x24188804, 0x24190804, 0x24198804, 0x241a0804, 0x241a8804, 0x241b0804,
+    0x241b8804, 0x241c0804, 0x241c8804, 0x241d0804, 0x241d8804, 0x241e0804, 0x241e8804, 0x241f0804,
+    0x241f8804, 0x24200804, 0x24208804, 0x24210804, 0x24218804, 0x24220804, 0x24228804, 0x24230804,
+    0x24238804, 0x24240804, 0x24248804, 0x24250804, 0x24258804, 0x24260804, 0x24268804, 0x24270804,
+    0x24278804, 0x24280804, 0x24288804, 0x24290804, 0x24298804, 0x242a0804, 0x242a8804, 0x242b0804,
+    0x242b8804, 0x242c0804, 0x242c8804, 0x242d0804, 0x242d8804, 0x242e0804, 0x242e8804, 0x242f0804,
+    0x242f8804, 0x24300804, 0x24308804, 0x24310804, 0x24318804, 0x24320804, 0x24328804, 0x24330804,
+    0x24338804, 0x24340804, 0x24348804, 0x24350804, 0x24358804, 0x24360804, 0x24368804, 0x24370804,
+    0x24378804, 0x24380804, 0x24388804, 0x24390804, 0x24398804, 0x243a0804, 0x243a8804, 0x243b0804,
+    0x243b8804, 0x243c0804, 0x243c8804, 0x243d0804, 0x243d8804, 0x243e0804, 0x243e8804, 0x243f0804,
+    0x243f8804, 0x24400804, 0x24408804, 0x24410804, 0x24418804, 0x24420804, 0x24428804, 0x24430804,
+    0x24438804, 0x24440804, 0x24448804, 0x24450804, 0x24458804, 0x24460804, 0x24468804, 0x24470804,
+    0x24478804, 0x24480804, 0x24488804, 0x24490804, 0x24498804, 0x244a0804, 0x244a8804, 0x244b0804,
+    0x244b8804, 0x244c0804, 0x244c8804, 0x244d0804, 0x244d8804, 0x244e0804, 0x244e8804, 0x244f0804,
+    0x244f8804, 0x24500804, 0x24508804, 0x24510804, 0x24518804, 0x24520804, 0x24528804, 0x24530804,
+    0x24538804, 0x24540804, 0x24548804, 0x24550804, 0x24558804, 0x24560804, 0x24568804, 0x24570804,
+    0x24578804, 0x24580804, 0x24588804, 0x24590804, 0x24598804, 0x245a0804, 0x245a8804, 0x245b0804,
+    0x245b8804, 0x245c0804, 0x245c8804, 0x245d0804, 0x245d8804, 0x245e0804, 0x245e8804, 0x245f0804,
+    0x245f8804, 0x24600804, 0x24608804, 0x24610804, 0x24618804, 0x24620804, 0x24628804, 0x24630804,
+    0x24638804, 0x24640804, 0x24648804, 0x24650804, 0x24658804, 0x24660804, 0x24668804, 0x24670804,
+    0x24678804, 0x24680804, 0x24688804, 0x24690804, 0x24698804, 0x246a0804, 0x246a8804, 0x246b0804,
+    0x246b8804, 0x246c0804, 0x246c8804, 0x246d0804, 0x246d8804, 0x246e0804, 0x246e8804, 0x246f0804,
+    0x246f8804, 0x24700804, 0x24708804, 0x24710804, 0x24718804, 0x24720804, 0x24728804, 0x24730804,
+    0x24738804, 0x24740804, 0x24748804, 0x24750804, 0x24758804, 0x24760804, 0x24768804, 0x8468805,
+    0x8470805, 0x8478805, 0x8480805, 0x8488805, 0x8490805, 0x8498805, 0x84a0805, 0x84a8805,
+    0x84b0805, 0x84b8805, 0x84c0805, 0x84c8805, 0x84d0805, 0x84d8805, 0x84e0805, 0x84e8805,
+    0x84f0805, 0x84f8805, 0x8500805, 0x8508805, 0x8510805, 0x8518805, 0x8520805, 0x8528805,
+    0x8530805, 0x8538805, 0x8540805, 0x8548805, 0x8550805, 0x8558805, 0x8560805, 0x8568805,
+    0x8570805, 0x8578805, 0x8580805, 0x8588805, 0x8590805, 0x8598805, 0x85a0805, 0x85a8805,
+    0x85b0805, 0x85b8805, 0x85c0805, 0x85c8805, 0x85d0805, 0x85d8805, 0x85e0805, 0x85e8805,
+    0x85f0805, 0x85f8805, 0x8600805, 0x8608805, 0x8610805, 0x8618805, 0x247a0804, 0x247a8804,
+    0x247b0804, 0x247b8804, 0x247c0804, 0x247c8804, 0x247d0804, 0x247d8804, 0x247e0804, 0x247e8804,
+    0x247f0804, 0x247f8804, 0x24800804, 0x24808804, 0x24810804, 0x24818804, 0x24820804, 0x24828804,
+    0x24830804, 0x24838804, 0x24840804, 0x24848804, 0x24850804, 0x24858804, 0x24860804, 0x24868804,
+    0x24880804, 0x24870804, 0x24888804, 0x24878804, 0x24898804, 0x248a0804, 0x248a8804, 0x248b0804,
+    0x248b8804, 0x248c0804, 0x248c8804, 0x248d0804, 0x248d8804, 0x248e0804, 0x24770804, 0x24778804,
+    0x24780804, 0x24788804, 0x24798804, 0x24790804, 0x1190805, 0x1460805, 0x1e5c8804, 0x1e5d0804,
+    0x1e5d8804, 0x1e5e0804, 0x1e5e8804, 0x1e5f0804, 0x1e5f8804, 0x1e600804, 0x1e608804, 0x1e610804,
+    0x1e618804, 0x1e620804, 0x1e628804, 0x1e630804, 0x1e638804, 0x1e640804, 0x1e648804, 0x1e650804,
+    0x1e658804, 0x1e660804, 0x1e668804, 0x1e670804, 0x1e678804, 0x1e680804, 0x1e688804, 0x1e690804,
+    0x1e698804, 0x1e6a0804, 0x1e6a8804, 0x1e6b0804, 0x1e6b8804, 0x1e6c0804, 0x1e6c8804, 0x1e6d0804,
+    0x1e6d8804, 0x1e6e0804, 0x1e6e8804, 0x1e6f0804, 0x1e6f8804, 0x1e700804, 0x1e708804, 0x1e710804,
+    0x1e718804, 0x1e720804, 0x1e728804, 0x1e730804, 0x1e738804, 0x1e740804, 0x1e748804, 0x1e750804,
+    0x1e758804, 0x1e760804, 0x1e768804, 0x1e770804, 0x1e778804, 0x1e780804, 0x1e788804, 0x1e790804,
+    0x1e798804, 0x1e7a0804, 0x1e7a8804, 0x1e7b0804, 0x1e7b8804, 0x1e7c0804, 0x1e7c8804, 0x1e7d0804,
+    0x1e7d8804, 0x1e7e0804, 0x1e7e8804, 0x1e7f0804, 0x1e7f8804, 0x1e800804, 0x1e808804, 0x1e810804,
+    0x1e818804, 0x1e820804, 0x1e828804, 0x1e830804, 0x1e838804, 0x1e840804, 0x1e848804, 0x1e850804,
+    0x1e858804, 0x1e860804, 0x1e868804, 0x1e870804, 0x1e878804, 0x1e880804, 0x1e888804, 0x1e890804,
+    0x1e898804, 0x1e8a0804, 0x1e8a8804, 0x1e8b0804, 0x1e8b8804, 0x1e8c0804, 0x1e8c8804, 0x1e8d0804,
+    0x1e8d8804, 0x1e8e0804, 0x1e8e8804, 0x1e8f0804, 0x1e8f8804, 0x1e900804, 0x1e908804, 0x1e910804,
+    0x1e918804, 0x1e920804, 0x1e928804, 0x1e930804, 0x1e938804, 0x1e940804, 0x1e948804, 0x1e950804,
+    0x1e958804, 0x1e960804, 0x1e968804, 0x1e970804, 0x1e978804, 0x1e980804, 0x1e988804, 0x1e990804,
+    0x1e998804, 0x1e9a0804, 0x1e9a8804, 0x1e9b0804, 0x1e9b8804, 0x1e9c0804, 0x1e9c8804, 0x1e9d0804,
+    0x1e9d8804, 0x1e9e0804, 0x1e9e8804, 0x1e9f0804, 0x1e9f8804, 0x1ea00804, 0x1ea08804, 0x1ea10804,
+    0x1ea18804, 0x1ea20804, 0x1ea28804, 0x1ea30804, 0x1ea38804, 0x1ea40804, 0x1ea48804, 0x1ea50804,
+    0x1ea58804, 0x1ea60804, 0x1ea68804, 0x1ea70804, 0x1ea78804, 0x1ea80804, 0x1ea88804, 0x1ea90804,
+    0x1ea98804, 0x1eaa0804, 0x1eaa8804, 0x1eab0804, 0x1eab8804, 0x1eac0804, 0x1eac8804, 0x1ead0804,
+    0x1ead8804, 0x1eae0804, 0x1eae8804, 0x1eaf0804, 0x1eaf8804, 0x1eb00804, 0x1eb08804, 0x1eb10804,
+    0x1eb18804, 0x1eb20804, 0x1eb28804, 0x1eb30804, 0x1eb38804, 0x1eb40804, 0x1eb48804, 0x1eb50804,
+    0x1eb58804, 0x1eb60804, 0x1eb68804, 0x1eb70804, 0x1eb78804, 0x1eb80804, 0x1eb88804, 0x1eb90804,
+    0x1eb98804, 0x1eba0804, 0x1eba8804, 0x1ebb0804, 0x1ebb8804, 0x1ebc0804, 0x1ebc8804, 0x1ebd0804,
+    0x1ebd8804, 0x1ebe0804, 0x1ebe8804, 0x1ebf0804, 0x1ebf8804, 0x1ec00804, 0x1ec08804, 0x1ec10804,
+    0x1ec18804, 0x1ec20804, 0x1ec28804, 0x1ec30804, 0x1ec38804, 0x1ec40804, 0x1ec48804, 0x1ec50804,
+    0x1ec58804, 0x1ec60804, 0x1ec68804, 0x1ec70804, 0x1ec78804, 0x1ec80804, 0x1ec88804, 0x1ec90804,
+    0x1ec98804, 0x1eca0804, 0x1eca8804, 0x1ecb0804, 0x1ecb8804, 0x1ecc0804, 0x1ecc8804, 0x1ecd0804,
+    0x1ecd8804, 0x1ece0804, 0x1ece8804, 0x1ecf0804, 0x1ecf8804, 0x1ed00804, 0x1ed08804, 0x1ed10804,
+    0x1ed18804, 0x1ed20804, 0x1ed28804, 0x1ed30804, 0x1ed38804, 0x1ed40804, 0x1ed48804, 0x1ed50804,
+    0x1ed58804, 0x1ed60804, 0x1ed68804, 0x1ed70804, 0x1ed78804, 0x1ed80804, 0x1ed88804, 0x1ed90804,
+    0x1ed98804, 0x1eda0804, 0x1eda8804, 0x1edb0804, 0x1edb8804, 0x1edc0804, 0x1edc8804, 0x1edd0804,
+    0x1edd8804, 0x1ede0804, 0x1ede8804, 0x1edf0804, 0x1edf8804, 0x1ee00804, 0x1ee08804, 0x1ee10804,
+    0x1ee18804, 0x1ee20804, 0x1ee28804, 0x1198805, 0x1468805, 0x13b8805, 0x1e888808, 0x1e918808,
+    0x1ea00808, 0x80027e22, 0x80027e62, 0x80027ea2, 0x80027ee2, 0x80027f22, 0x80027f62, 0x80027fa2,
+    0x80027fe2, 0x80028022, 0x80028062, 0x800280a2, 0x800280e2, 0x80028122, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0x1e938808, 0x1ec50808, 0x11ea8810, 0x11ea8804, 0x11ec0810, 0x11ec0804, 0x11ee8810, 0x11ee8804,
+    0x11f88810, 0x11f88804, 0x11fd0810, 0x11fd0804, 0x12470810, 0x12470804, 0x125a8810, 0x125a8804,
+    0x12788810, 0x12788804, 0x127c0810, 0x127c0804, 0x12848810, 0x12848804, 0x12890810, 0x12890804,
+    0x12898810, 0x12898804, 0x12908810, 0x12908804, 0x12930810, 0x12930804, 0x12958810, 0x12958804,
+    0x12a00810, 0x12a00804, 0x12610810, 0x12610804, 0x11dc8810, 0x11dc8804, 0x12128810, 0x12128804,
+    0x12180810, 0x12180804, 0x12260814, 0x12260808, 0x12260814, 0x12260808, 0x12260814, 0x12260808,
+    0x12260808, 0x1444, 0x0, 0x0, 0x0, 0x1d28805, 0x11e18808, 0x11f28808, 0x80028162, 0x12410808,
+    0x127a0808, 0x127c8808, 0x127e8808, 0x12568808, 0xcc4, 0xcc4, 0x1f18805, 0x12798804,
+    0x11da0810, 0x11da0804, 0x11f20810, 0x11f20804, 0x11e60810, 0x11e60804, 0x126d8810, 0x126d8804,
+    0x11f10810, 0x11f10804, 0x123e8810, 0x123e8804, 0x123b8810, 0x123b8804, 0x12618810, 0x12618804,
+    0x12640810, 0x12640804, 0x12670810, 0x12670804, 0x12560810, 0x12560804, 0x12760810, 0x12760804,
+    0x12260814, 0x12260808, 0x12260814, 0x12260808, 0x127a0828, 0x127e8828, 0x12498808, 0x128c8808,
+    0x1ee30804, 0x1ee38804, 0x1ee40804, 0x1ee48804, 0x1ee50804, 0x1ee58804, 0x1ee60804, 0x1ee68804,
+    0x1ee70804, 0x1ee78804, 0x1ee80804, 0x1ee88804, 0x1ee90804, 0x1ee98804, 0x1eea0804, 0x1eea8804,
+    0x1eeb0804, 0x1eeb8804, 0x1eec0804, 0x1eec8804, 0x1eed0804, 0x1eed8804, 0x1eee0804, 0x1eee8804,
+    0x1eef0804, 0x1eef8804, 0x1ef00804, 0x1ef08804, 0x1ef10804, 0x1ef18804, 0x1ef20804, 0x1ef28804,
+    0x1ef30804, 0x1ef38804, 0x1ef40804, 0x1ef48804, 0x1ef50804, 0x1ef58804, 0x1ef60804, 0x1ef68804,
+    0x1ef70804, 0x1ef78804, 0x1ef80804, 0x1ef88804, 0x1ef90804, 0x1ef98804, 0x1efa0804, 0x1efa8804,
+    0x1efb0804, 0x1efb8804, 0x1efc0804, 0x1efc8804, 0x1efd0804, 0x1efd8804, 0x1efe0804, 0x1efe8804,
+    0x1eff0804, 0x1eff8804, 0x1f000804, 0x1f008804, 0x1f010804, 0x1f018804, 0x1f020804, 0x1f028804,
+    0x1f030804, 0x1f038804, 0x1f040804, 0x1f048804, 0x1f050804, 0x1f058804, 0x1f060804, 0x1f068804,
+    0x1f070804, 0x1f078804, 0x1f080804, 0x1f088804, 0x1f090804, 0x1f098804, 0x1f0a0804, 0x1f0a8804,
+    0x2d84, 0x2dc4, 0x1700805, 0x1470805, 0x1330805, 0x11a0805, 0x11f0805, 0x13c0805, 0x2820805,
+    0x2828805, 0x2830805, 0x2838805, 0x2840805, 0x2848805, 0x2850805, 0x2858805, 0x2860805,
+    0x2868805, 0x2870805, 0x2878805, 0x2880805, 0x2888805, 0x2890805, 0x2898805, 0x28a0805,
+    0x28a8805, 0x28b0805, 0x28b8805, 0x28c0805, 0x28c8805, 0x28d0805, 0x28d8805, 0x28e0805,
+    0x28e8805, 0x28f0805, 0x28f8805, 0x2900805, 0x2908805, 0x2910805, 0x2918805, 0x2920805,
+    0x2928805, 0x11730810, 0x11730804, 0x11788810, 0x11788804, 0x10448810, 0x10448804, 0x800281a2,
+    0x800281e2, 0x11658810, 0x11658804, 0x11660810, 0x11660804, 0x11668810, 0x11668804, 0x10230804,
+    0x10eb0804, 0x80028222, 0x80028262, 0x800282a2, 0x800282e2, 0x80028322, 0x80028362, 0x800283a2,
+    0x800283e2, 0x80028423, 0x80028483, 0x800284e2, 0x80028522, 0xff50810, 0xff50804, 0x10678810,
+    0x10678804, 0x10680810, 0x10680804, 0x10688810, 0x10688804, 0x106f0810, 0x106f0804, 0x10700810,
+    0x10700804, 0x10b00810, 0x10b00804, 0x10ac0810, 0x10ac0804, 0x80028562, 0x800285a2, 0x10b88810,
+    0x10b88804, 0x10bc0810, 0x10bc0804, 0x10bc8810, 0x10bc8804, 0x10c28810, 0x10c28804, 0x10c30810,
+    0x10c30804, 0x10ce8810, 0x10ce8804, 0x10e88810, 0x10e88804, 0x11260810, 0x11260804, 0x800285e2,
+    0x80028622, 0x11510810, 0x11510804, 0x115c8810, 0x115c8804, 0x115d0810, 0x115d0804, 0x115f8810,
+    0x115f8804, 0x11618810, 0x11618804, 0x11620810, 0x11620804, 0x11628810, 0x11628804, 0x11628828,
+    0x10028804, 0x107d8804, 0x108b8804, 0x109b0804, 0x10e78804, 0x10e80804, 0x11090804, 0x11630804,
+    0x80028662, 0x800286a2, 0x800286e2, 0x80028722, 0x80028762, 0x10360810, 0x10360804, 0x10800810,
+    0x10800804, 0x800287a2, 0x800287e2, 0x80028822, 0x80028862, 0x800288a2, 0x800288e2, 0x2930805,
+    0x2938805, 0x2940805, 0x11738810, 0x11738804, 0x11160810, 0x107b0804, 0x11740804, 0x10958810,
+    0x10958804, 0xfef8810, 0xfef8804, 0xff00804, 0x10408804, 0xfe58810, 0xfe58804, 0x10240810,
+    0x10240804, 0x80028922, 0x80028962, 0x800289a2, 0x800289e2, 0x80028a22, 0x80028a62, 0x80028aa2,
+    0x80028ae2, 0x80028b22, 0x80028b62, 0x80028ba2, 0x80028be2, 0x80028c22, 0x80028c62, 0x80028ca2,
+    0x80028ce2, 0x10410810, 0x10160810, 0x102a8810, 0x10760810, 0x104c0810, 0x10c20804, 0x10690810,
+    0x11098810, 0x105c0810, 0x11368810, 0xfea8810, 0xfea8804, 0x10b28810, 0x10b28804, 0x11138810,
+    0x11138804, 0xfd48810, 0xfd48804, 0x10528810, 0x10528804, 0x11158810, 0x11158804, 0x112f0810,
+    0x112f0804, 0xff00810, 0x10ed0810, 0x11480810, 0xff88810, 0xff88804, 0x10eb8810, 0x10eb8804,
+    0x10440810, 0x10440804, 0x104e8804, 0x80028d22, 0x80028d63, 0x111e0804, 0x10280804, 0x10bd0804,
+    0x108a8804, 0x104e0804, 0x108b0804, 0x166e0804, 0x166e8804, 0x166f0804, 0x166f8804, 0x16700804,
+    0x16708804, 0x16710804, 0x16720804, 0x16728804, 0x16730804, 0x16738804, 0x3104, 0x16740804,
+    0x16748804, 0x16750804, 0x16758804, 0x16760804, 0x16768804, 0x16770804, 0x16778804, 0x16780804,
+    0x16788804, 0x16790804, 0x16798804, 0x167a0804, 0x167a8804, 0x167b0804, 0x167b8804, 0x167c0804,
+    0x167c8804, 0x167d0804, 0x167d8804, 0x167e0804, 0x167e8804, 0x167f0804, 0x167f8804, 0x16800804,
+    0x16808804, 0x16810804, 0x16818804, 0x2b60805, 0x2b68805, 0x2b70805, 0x2b78805, 0x16718804,
+    0xeb10805, 0xeb18805, 0xeb20805, 0xeb28805, 0xeb30805, 0xeb38805, 0x2b80805, 0x2b88805,
+    0xfb78804, 0x2b90805, 0x1a100804, 0x1a108804, 0x1a110804, 0x1a118804, 0x1a120804, 0x1a128804,
+    0x1a130804, 0x1a138804, 0x1a160804, 0x1a168804, 0x1a170804, 0x1a178804, 0x1a180804, 0x1a188804,
+    0x1a190804, 0x1a198804, 0x1a1a0804, 0x1a1a8804, 0x1a1b0804, 0x1a1b8804, 0x1a1c8804, 0x1a1d0804,
+    0x1a1d8804, 0x1a1e0804, 0x1a1f8804, 0x1a210804, 0x1a218804, 0x1a228804, 0x1a230804, 0x1a248804,
+    0x1a270804, 0x1a278804, 0x1a280804, 0x1a288804, 0x1a250804, 0x1a258804, 0x1a260804, 0x1a268804,
+    0x1a290804, 0x1a1c0804, 0x1a1e8804, 0x1a140804, 0x1a148804, 0x1a150804, 0x1a158804, 0x1a1f0804,
+    0x1a220804, 0x1a238804, 0x1a240804, 0x1a200804, 0x1a208804, 0x1a298804, 0x22a0805, 0x22a8805,
+    0x14e0805, 0x14e8805, 0x3104, 0x3144, 0x16820804, 0x16828804, 0x16830804, 0x16838804,
+    0x16840804, 0x16848804, 0x16850804, 0x16858804, 0x16860804, 0x16868804, 0x16870804, 0x16878804,
+    0x16880804, 0x16888804, 0x16890804, 0x16898804, 0x168a0804, 0x168a8804, 0x168b0804, 0x168b8804,
+    0x168c0804, 0x168c8804, 0x168d0804, 0x168d8804, 0x168e0804, 0x168e8804, 0x168f0804, 0x168f8804,
+    0x16900804, 0x16908804, 0x16910804, 0x16918804, 0x16920804, 0x16928804, 0x16930804, 0x16938804,
+    0x16940804, 0x16948804, 0x16950804, 0x16958804, 0x16960804, 0x16968804, 0x16970804, 0x16978804,
+    0x16980804, 0x16988804, 0x16990804, 0x16998804, 0x169a0804, 0x169a8804, 0x169b0804, 0x169b8804,
+    0x169c0804, 0x169c8804, 0x169d0804, 0x169d8804, 0x169e0804, 0x169e8804, 0x169f0804, 0x169f8804,
+    0x16a00804, 0x16a08804, 0x16a10804, 0x16a18804, 0x16a20804, 0x16a28804, 0x16a30804, 0x30c4,
+    0x14c0805, 0x14c8805, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x15108804, 0x15108808, 0x15108808, 0x15108808,
+    0x15108808, 0x15108808, 0x2008805, 0x2010805, 0x2018805, 0x15110804, 0x2020805, 0x14ec0804,
+    0x14f70804, 0x151b8804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1aa58804, 0x1aa60804, 0x1aa68804, 0x1aa70804,
+    0x1aa78804, 0x1aa80804, 0x1aa88804, 0x1aa90804, 0x1aa98804, 0x1aaa0804, 0x1aaa8804, 0x1aab0804,
+    0x1aab8804, 0x1aac0804, 0x1aac8804, 0x1aad0804, 0x1aad8804, 0x1aae0804, 0x1aae8804, 0x1aaf0804,
+    0x1aaf8804, 0x1ab00804, 0x1ab08804, 0x1ab10804, 0x1ab18804, 0x1ab20804, 0x1ab28804, 0x1ab30804,
+    0x1ab38804, 0x1ab40804, 0x1ab48804, 0x1ab50804, 0x1ab58804, 0x3904, 0x3944, 0x3984, 0x22d0805,
+    0x1500805, 0x1a938804, 0x1a940804, 0x1a948804, 0x1a950804, 0x1a958804, 0x1a960804, 0x1a968804,
+    0x1a970804, 0x1a978804, 0x1a980804, 0x1a988804, 0x1a990804, 0x1a998804, 0x1a9a0804, 0x1a9a8804,
+    0x1a9b0804, 0x1a9b8804, 0x1a9c0804, 0x1a9c8804, 0x1a9d0804, 0x1a9d8804, 0x1a9e0804, 0x1a9e8804,
+    0x1a9f0804, 0x1a9f8804, 0x1aa00804, 0x1aa08804, 0x1aa10804, 0x1aa18804, 0x1aa20804, 0x1aa28804,
+    0x1aa30804, 0x1aa38804, 0x1aa40804, 0x1aa48804, 0x1aa50804, 0x1708805, 0x20ea0804, 0x20ea8804,
+    0x20eb0804, 0x20eb8804, 0x20ec0804, 0x20ec8804, 0x20ed0804, 0x20ed8804, 0x20ee0804, 0x20ee8804,
+    0x20ef0804, 0x20ef8804, 0x20f00804, 0x20f08804, 0x20f10804, 0x20f18804, 0x20f20804, 0x20f28804,
+    0x20f30804, 0x20f38804, 0x20f40804, 0x20f48804, 0x20f50804, 0x20f58804, 0x20f60804, 0x20f68804,
+    0x20f70804, 0x20f78804, 0x20f80804, 0x30c4, 0x3104, 0x3284, 0x3144, 0x1bfe0804, 0x1bfe8804,
+    0x1bff0804, 0x1bff8804, 0x1c000804, 0x1c008804, 0x1c010804, 0x1c018804, 0x1c020804, 0x1c028804,
+    0x1c030804, 0x1c038804, 0x1c040804, 0x1c048804, 0x1c050804, 0x1c058804, 0x1c060804, 0x1c068804,
+    0x1c070804, 0x1c078804, 0x1c080804, 0x1c088804, 0x1c090804, 0x1c098804, 0x1c0a0804, 0x1c0a8804,
+    0x1c0b0804, 0x1c0b8804, 0x1c0c0804, 0x1c0c8804, 0x1c0d0804, 0x1c0d8804, 0x1c0e0804, 0x1c0e8804,
+    0x1c0f0804, 0x1c0f8804, 0x1c100804, 0x1c108804, 0x1c110804, 0x1c120804, 0x1c120808, 0x1c130804,
+    0x1c138804, 0x1c140804, 0x1c148804, 0x1c150804, 0x1c158804, 0x3084, 0x1c160804, 0x1c160808,
+    0x1c170804, 0x1c178804, 0x1c180804, 0x1c188804, 0x1c198804, 0x1c1a0804, 0x1c168804, 0x1c190804,
+    0x1c118804, 0x1c128804, 0x1c1a8804, 0x16b0805, 0x16b8805, 0x16c0805, 0x16c8805, 0x16d0805,
+    0x16d8805, 0x1310805, 0x1558805, 0x1560805, 0x16e0805, 0x16e8805, 0x16f0805, 0x16f8805,
+    0xfa68804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804,
+    0xfcf8804, 0xfd00804, 0xfd08804, 0x22d8805, 0x22e0805, 0x1aba8804, 0x1abe8804, 0x1ac38804,
+    0x1ace8804, 0x1adb0804, 0x1b000804, 0xfa70804, 0x1ac78804, 0x1ad88804, 0x1ab98804, 0x1abb0804,
+    0x1ac10804, 0x1ac48804, 0x1acb8804, 0x1acd0804, 0x1acf0804, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1ae90804,
+    0x1ad18804, 0x1ad30804, 0x1ada0804, 0x1adb8804, 0x1bb88804, 0x1bb90804, 0x1bb98804, 0x1bba0804,
+    0x1bba8804, 0x1bbb0804, 0x1bbb8804, 0x1bbc0804, 0x1bbc8804, 0x1bbd0804, 0x1bbd8804, 0x1bbe0804,
+    0x1bbe8804, 0x1bbf0804, 0x1bbf8804, 0x1bc00804, 0x1bc08804, 0x1bc10804, 0x1bc18804, 0x1bc20804,
+    0x1bc28804, 0x1bc30804, 0x1bc38804, 0x1bc40804, 0x1bc48804, 0x1bc50804, 0x1bc58804, 0x1bc60804,
+    0x1bc68804, 0x1bc70804, 0x1bc78804, 0x1bc80804, 0x1bc88804, 0x1bc90804, 0x1bc98804, 0x1bca0804,
+    0x1bca8804, 0x1bcb0804, 0x1bcb8804, 0x1bcc0804, 0x1bcc8804, 0x1bcf0804, 0x1bcf8804, 0x1bd00804,
+    0x1bd08804, 0x1bd10804, 0x1bd18804, 0x1bd20804, 0x1bd28804, 0x1bd30804, 0x1bd38804, 0x1bcd0804,
+    0x1bcd8804, 0x1bce0804, 0x1bce8804, 0x1bd40804, 0x1bd48804, 0x1bd50804, 0x1bd58804, 0x1bd60804,
+    0x1bd68804, 0x1bd70804, 0x1bd78804, 0x1bd80804, 0x1bd88804, 0x1bd90804, 0x1bd98804, 0x1bda0804,
+    0x1bda8804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804,
+    0xfcf8804, 0xfd00804, 0xfd08804, 0x22e8805, 0x1568805, 0x1570805, 0x1578805, 0x1ab90804,
+    0x1abd8804, 0x1abf0804, 0x1ac08804, 0x1ac40804, 0x1ac70804, 0x1ac90804, 0x1aca0804, 0x1acb0804,
+    0x1acc8804, 0x1ad28804, 0x1ad48804, 0x1ae50804, 0x1ae68804, 0x1ae78804, 0x1ad78804, 0xfa78804,
+    0x1ae80804, 0x1ac20804, 0x1ade8804, 0x1b0c0804, 0x1b0c8804, 0x1b0d0804, 0x2cd8805, 0x2ce0805,
+    0x2ce8805, 0x1adf0804, 0x1b0a8804, 0x1b0b0804, 0x1b0b8804, 0x1abf8804, 0x1ac58804, 0x194b0804,
+    0x194b8804, 0x194c0804, 0x194c8804, 0x194d0804, 0x194d8804, 0x194e0804, 0x194e8804, 0x194f0804,
+    0x194f8804, 0x19500804, 0x19508804, 0x19510804, 0x19518804, 0x19520804, 0x19528804, 0x19530804,
+    0x19538804, 0x19540804, 0x19548804, 0x19550804, 0x19558804, 0x19560804, 0x19568804, 0x19570804,
+    0x19578804, 0x19580804, 0x19588804, 0x19590804, 0x19598804, 0x195a0804, 0x195a8804, 0x195b0804,
+    0x195b8804, 0x195c0804, 0x195c8804, 0x195d0804, 0x195d8804, 0x195e0804, 0x195e8804, 0x195f0804,
+    0x195f8804, 0x19600804, 0x19608804, 0x19610804, 0x19618804, 0x19620804, 0x19628804, 0x19630804,
+    0x19638804, 0x19640804, 0x19648804, 0x19650804, 0x19658804, 0x19660804, 0x19668804, 0x19670804,
+    0x19678804, 0x19680804, 0x19688804, 0x19690804, 0x19698804, 0x196a0804, 0x3844, 0x196a8804,
+    0x3884, 0x196b0804, 0x196b8804, 0x196c0804, 0xfa80804, 0x2070805, 0x2078805, 0x165c8804,
+    0x165d0804, 0x165d8804, 0x165e0804, 0x165e8804, 0x165f0804, 0x165f8804, 0x16600804, 0x16608804,
+    0x16610804, 0x16618804, 0x16660804, 0x16668804, 0x16670804, 0x16678804, 0x16680804, 0x1580805,
+    0x13c8805, 0x164e8804, 0xfa88804, 0xfa90804, 0x16688804, 0x166d8804, 0x14290804, 0x14298804,
+    0x142a0804, 0x142a8804, 0x142b0804, 0x142b8804, 0x148c8804, 0x148d0804, 0x148d8804, 0x148e0804,
+    0x148e8804, 0x148f0804, 0x147d0804, 0x147d8804, 0x147e0804, 0x147e8804, 0x147f0804, 0x147f8804,
+    0x14ae0804, 0x14ae8804, 0x14af0804, 0x14af8804, 0x14b00804, 0x14b08804, 0x14b10804, 0x14ba0804,
+    0x14ba8804, 0x14bb0804, 0x14bb8804, 0x14bc0804, 0x14bc8804, 0x14bd0804, 0xfda8804, 0xfd60804,
+    0x10060804, 0x10068804, 0x10098804, 0x10238804, 0x102c8804, 0x10780804, 0x10750804, 0x10758804,
+    0x108a0804, 0x109a8804, 0x109d8804, 0x10a10804, 0x10a70804, 0x10aa8804, 0x10a50804, 0x10a40804,
+    0x10a48804, 0x10a58804, 0x10a60804, 0x10cb8804, 0x10ce0804, 0x10e28804, 0x10e30804, 0x10dd8804,
+    0x10e38804, 0x10e40804, 0x10e48804, 0x10f40804, 0x110e0804, 0x11130804, 0x11100804, 0x11108804,
+    0x11150804, 0x11368804, 0x11370804, 0x11378804, 0x11348804, 0x11350804, 0x11358804, 0x11360804,
+    0x11408804, 0x2948805, 0x10448828, 0x10780828, 0x10730828, 0x11150828, 0x11600804, 0x11608804,
+    0x10ab8804, 0x11610804, 0xfdd8804, 0x11a38804, 0x80028dc2, 0x80028e02, 0x10e70804, 0x11300828,
+    0x26d0805, 0x26d8805, 0x1c6f0804, 0x1c6f8804, 0x1c700804, 0x1c708804, 0x1c710804, 0x1c718804,
+    0x1c720804, 0x1c728804, 0x1c730804, 0x1c738804, 0x1c740804, 0x1c748804, 0x1c750804, 0x1c758804,
+    0x1c760804, 0x1c768804, 0x1c770804, 0x1c778804, 0x1c780804, 0x1c788804, 0x1c790804, 0x1c798804,
+    0x1c7a0804, 0x1c7a8804, 0x1c7b0804, 0x1c7b8804, 0x1c7c0804, 0x1c7c8804, 0x1c7d0804, 0x1c7d8804,
+    0x1c7e0804, 0x1c7e8804, 0x1c7f0804, 0x1c7f8804, 0x1c800804, 0x1c808804, 0x1c810804, 0x1c818804,
+    0x1c820804, 0x1c828804, 0x1c830804, 0x1c838804, 0x1c840804, 0x1c848804, 0x1c850804, 0x1c858804,
+    0x1c860804, 0x1c868804, 0x1c870804, 0x1c878804, 0x1c880804, 0x1c888804, 0x1c890804, 0x1c898804,
+    0x1c8a0804, 0x1c8a8804, 0x1c8b0804, 0x1c8b8804, 0x1c8c0804, 0x1c8c8804, 0x1c8d0804, 0x1c8d8804,
+    0x1c8e0804, 0x1c8e8804, 0x1c8f0804, 0x1c8f8804, 0x1c900804, 0x1c908804, 0x1c910804, 0x1c918804,
+    0x1c920804, 0x1c928804, 0x1c930804, 0x1c938804, 0x1c940804, 0x1c948804, 0x1c950804, 0x1c958804,
+    0x1c960804, 0x1c968804, 0x164f0804, 0x164f8804, 0x16500804, 0x16508804, 0x16510804, 0x16518804,
+    0x16520804, 0x16528804, 0x16530804, 0x16538804, 0x16540804, 0x16548804, 0x16550804, 0x16558804,
+    0x16560804, 0x16568804, 0x16570804, 0x16578804, 0x16580804, 0x16588804, 0x16590804, 0x16598804,
+    0x165a0804, 0x165a8804, 0x165b0804, 0x165b8804, 0x165c0804, 0x16690804, 0x16698804, 0x166a0804,
+    0x166a8804, 0x166b0804, 0x166b8804, 0x166c0804, 0x166c8804, 0x16620804, 0x16628804, 0x16630804,
+    0x16638804, 0x16640804, 0x16648804, 0x16650804, 0x16658804, 0x1588805, 0x3304, 0x166d0804,
+    0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804,
+    0xfd00804, 0xfd08804, 0x211d0804, 0x211d8804, 0x211e0804, 0x211e8804, 0x211f0804, 0x211f8804,
+    0x21200804, 0x21208804, 0x21210804, 0x21218804, 0x21220804, 0x21228804, 0x21230804, 0x21238804,
+    0x21240804, 0x21248804, 0x21250804, 0x21258804, 0x21260804, 0x21268804, 0x21270804, 0x21278804,
+    0x21280804, 0x21548804, 0x21550804, 0x21558804, 0x21560804, 0x21568804, 0x21570804, 0x21578804,
+    0x21580804, 0x21588804, 0x21590804, 0x21598804, 0x215a0804, 0x215a8804, 0x215b0804, 0x215b8804,
+    0x215c0804, 0x215c8804, 0x215d0804, 0x215d8804, 0x215e0804, 0x215e8804, 0x215f0804, 0x215f8804,
+    0x21600804, 0x21608804, 0x21610804, 0x21618804, 0x21620804, 0x21628804, 0x21630804, 0x21638804,
+    0x21640804, 0x21648804, 0x21650804, 0x21658804, 0x21660804, 0x21668804, 0x21670804, 0x21678804,
+    0x21680804, 0x21688804, 0x21690804, 0x21698804, 0x216a0804, 0x216a8804, 0x216b0804, 0x216b8804,
+    0x216c0804, 0x216c8804, 0x80028e42, 0x80028e82, 0x80028ec2, 0x80028f02, 0x80028f42, 0x80028f82,
+    0x80028fc2, 0x80029002, 0x80029042, 0x80029082, 0x800290c2, 0x80029102, 0x80029142, 0x80029182,
+    0x800291c2, 0x80029202, 0x80029242, 0x80029282, 0x800292c2, 0x80029302, 0x80029342, 0x80029382,
+    0x800293c2, 0x80029402, 0x80029442, 0x80029482, 0x800294c2, 0x80029502, 0x80029542, 0x80029582,
+    0x800295c2, 0x80029602, 0x80029642, 0x80029682, 0x800296c2, 0x80029702, 0x80029742, 0x80029782,
+    0x800297c2, 0x80029802, 0x80029842, 0x80029882, 0x800298c2, 0x80029902, 0x80029942, 0x80029982,
+    0x800299c2, 0x80029a02, 0x80029a42, 0x80029a82, 0x80029ac2, 0x80029b02, 0x80029b42, 0x80029b82,
+    0x80029bc2, 0x80029c02, 0x80029c42, 0x80029c82, 0x80029cc2, 0x80029d02, 0x80029d42, 0x80029d82,
+    0x80029dc2, 0x80029e02, 0x80029e42, 0x80029e82, 0x80029ec2, 0x80029f02, 0x80029f42, 0x80029f82,
+    0x80029fc2, 0x8002a002, 0x8002a042, 0x8002a082, 0x8002a0c2, 0x8002a102, 0x8002a142, 0x8002a182,
+    0x8002a1c2, 0x8002a202, 0x8002a242, 0x8002a282, 0x8002a2c2, 0x8002a302, 0x8002a342, 0x8002a382,
+    0x8002a3c2, 0x8002a402, 0x8002a442, 0x8002a482, 0x8002a4c2, 0x8002a502, 0x8002a542, 0x8002a582,
+    0x8002a5c2, 0x8002a602, 0x8002a642, 0x8002a682, 0x8002a6c2, 0x8002a702, 0x8002a742, 0x8002a782,
+    0x8002a7c2, 0x8002a802, 0x8002a842, 0x8002a882, 0x8002a8c2, 0x8002a902, 0x8002a942, 0x8002a982,
+    0x8002a9c2, 0x8002aa02, 0x8002aa42, 0x8002aa82, 0x8002aac2, 0x8002ab02, 0x8002ab42, 0x8002ab82,
+    0x8002abc2, 0x8002ac02, 0x8002ac42, 0x8002ac82, 0x8002acc2, 0x8002ad02, 0x8002ad42, 0x8002ad82,
+    0x8002adc2, 0x8002ae02, 0x8002ae42, 0x8002ae82, 0x8002aec2, 0x8002af02, 0x8002af42, 0x8002af82,
+    0x8002afc2, 0x8002b002, 0x8002b042, 0x8002b082, 0x8002b0c2, 0x8002b102, 0x8002b142, 0x8002b182,
+    0x8002b1c2, 0x8002b202, 0x8002b242, 0x8002b282, 0x8002b2c2, 0x8002b302, 0x8002b342, 0x8002b382,
+    0x8002b3c2, 0x8002b402, 0x8002b442, 0x8002b482, 0x8002b4c2, 0x8002b502, 0x8002b542, 0x8002b582,
+    0x8002b5c2, 0x8002b602, 0x8002b642, 0x8002b682, 0x8002b6c2, 0x8002b702, 0x8002b742, 0x8002b782,
+    0x8002b7c2, 0x8002b802, 0x8002b842, 0x8002b882, 0x8002b8c2, 0x8002b902, 0x8002b942, 0x8002b982,
+    0x8002b9c2, 0x8002ba02, 0x8002ba42, 0x8002ba82, 0x8002bac2, 0x8002bb02, 0x8002bb42, 0x8002bb82,
+    0x8002bbc2, 0x8002bc02, 0x8002bc42, 0x8002bc82, 0x8002bcc2, 0x8002bd02, 0x8002bd42, 0x8002bd82,
+    0x8002bdc2, 0x8002be02, 0x8002be42, 0x8002be82, 0x8002bec2, 0x8002bf02, 0x8002bf42, 0x8002bf82,
+    0x8002bfc2, 0x8002c002, 0x8002c042, 0x8002c082, 0x8002c0c2, 0x8002c102, 0x8002c142, 0x8002c182,
+    0x8002c1c2, 0x8002c202, 0x8002c242, 0x8002c282, 0x8002c2c2, 0x8002c302, 0x8002c342, 0x8002c382,
+    0x8002c3c2, 0x8002c402, 0x8002c442, 0x8002c482, 0x8002c4c2, 0x8002c502, 0x8002c542, 0x8002c582,
+    0x8002c5c2, 0x8002c602, 0x8002c642, 0x8002c682, 0x8002c6c2, 0x8002c702, 0x8002c742, 0x8002c782,
+    0x8002c7c2, 0x8002c802, 0x8002c842, 0x8002c882, 0x8002c8c2, 0x8002c902, 0x8002c942, 0x8002c982,
+    0x8002c9c2, 0x8002ca02, 0x8002ca42, 0x8002ca82, 0x8002cac2, 0x8002cb02, 0x8002cb42, 0x8002cb82,
+    0x8002cbc2, 0x8002cc02, 0x8002cc42, 0x8002cc82, 0x8002ccc2, 0x8002cd02, 0x8002cd42, 0x8002cd82,
+    0x8002cdc2, 0x8002ce02, 0x8002ce42, 0x8002ce82, 0x8002cec2, 0x8002cf02, 0x8002cf42, 0x8002cf82,
+    0x8002cfc2, 0x8002d002, 0x8002d042, 0x8002d082, 0x8002d0c2, 0x8002d102, 0x8002d142, 0x8002d182,
+    0x8002d1c2, 0x8002d202, 0x8002d242, 0x8002d282, 0x8002d2c2, 0x8002d302, 0x8002d342, 0x8002d382,
+    0x8002d3c2, 0x8002d402, 0x8002d442, 0x8002d482, 0x8002d4c2, 0x8002d502, 0x8002d542, 0x8002d582,
+    0x8002d5c2, 0x8002d602, 0x8002d642, 0x8002d682, 0x8002d6c2, 0x8002d702, 0x8002d742, 0x8002d782,
+    0x8002d7c2, 0x8002d802, 0x8002d842, 0x8002d882, 0x8002d8c2, 0x8002d902, 0x8002d942, 0x8002d982,
+    0x8002d9c2, 0x8002da02, 0x8002da42, 0x8002da82, 0x8002dac2, 0x8002db02, 0x8002db42, 0x8002db82,
+    0x8002dbc2, 0x8002dc02, 0x8002dc42, 0x8002dc82, 0x8002dcc2, 0x8002dd02, 0x8002dd42, 0x8002dd82,
+    0x8002ddc2, 0x8002de02, 0x8002de42, 0x8002de82, 0x8002dec2, 0x8002df02, 0x8002df42, 0x8002df82,
+    0x8002dfc2, 0x8002e002, 0x8002e042, 0x8002e082, 0x8002e0c2, 0x8002e102, 0x8002e142, 0x8002e182,
+    0x8002e1c2, 0x8002e202, 0x8002e242, 0x8002e282, 0x8002e2c2, 0x8002e302, 0x8002e342, 0x8002e382,
+    0x8002e3c2, 0x8002e402, 0x8002e442, 0x8002e482, 0x8002e4c2, 0x8002e502, 0x8002e542, 0x8002e582,
+    0x8002e5c2, 0x8002e602, 0x8002e642, 0x8002e682, 0x8002e6c2, 0x8002e702, 0x8002e742, 0x8002e782,
+    0x8002e7c2, 0x8002e802, 0x8002e842, 0x8002e882, 0x8002e8c2, 0x8002e902, 0x8002e942, 0x8002e982,
+    0x8002e9c2, 0x8002ea02, 0x8002ea42, 0x8002ea82, 0x8002eac2, 0x8002eb02, 0x8002eb42, 0x8002eb82,
+    0x8002ebc2, 0x8002ec02, 0x8002ec42, 0x8002ec82, 0x8002ecc2, 0x8002ed02, 0x8002ed42, 0x8002ed82,
+    0x8002edc2, 0x8002ee02, 0x8002ee42, 0x8002ee82, 0x8002eec2, 0x8002ef02, 0x8002ef42, 0x8002ef82,
+    0x8002efc2, 0x8002f002, 0x8002f042, 0x8002f082, 0x8002f0c2, 0x8002f102, 0x8002f142, 0x8002f182,
+    0x8002f1c2, 0x8002f202, 0x8002f242, 0x8002f282, 0x8002f2c2, 0x8002f302, 0x8002f342, 0x8002f382,
+    0x8002f3c2, 0x8002f402, 0x8002f442, 0x8002f482, 0x8002f4c2, 0x8002f502, 0x8002f542, 0x8002f582,
+    0x8002f5c2, 0x8002f602, 0x8002f642, 0x8002f682, 0x8002f6c2, 0x8002f702, 0x8002f742, 0x8002f782,
+    0x8002f7c2, 0x8002f802, 0x8002f842, 0x8002f882, 0x8002f8c2, 0x8002f902, 0x8002f942, 0x8002f982,
+    0x8002f9c2, 0x8002fa02, 0x8002fa42, 0x8002fa82, 0x8002fac2, 0x8002fb02, 0x8002fb42, 0x8002fb82,
+    0x8002fbc2, 0x8002fc02, 0x8002fc42, 0x8002fc82, 0x8002fcc2, 0x8002fd02, 0x8002fd42, 0x8002fd82,
+    0x8002fdc2, 0x8002fe02, 0x8002fe42, 0x8002fe82, 0x8002fec2, 0x8002ff02, 0x8002ff42, 0x8002ff82,
+    0x8002ffc2, 0x80030002, 0x80030042, 0x80030082, 0x800300c2, 0x80030102, 0x80030142, 0x80030182,
+    0x800301c2, 0x80030202, 0x80030242, 0x80030282, 0x800302c2, 0x80030302, 0x80030342, 0x80030382,
+    0x800303c2, 0x80030402, 0x80030442, 0x80030482, 0x800304c2, 0x80030503, 0x80030563, 0x800305c3,
+    0x80030622, 0x80030662, 0x800306a2, 0x800306e2, 0x80030722, 0x80030762, 0x800307a2, 0x1844,
+    0x800307e3, 0x1316880a, 0x130f080a, 0x1310880a, 0x1311080a, 0x1314080a, 0x1314880a, 0x1315080a,
+    0x1318880a, 0x1319880a, 0x333080b, 0x80030842, 0x80030882, 0x800308c3, 0x80030923, 0x80030982,
+    0x800309c2, 0x80030a02, 0x80030a42, 0x80030a82, 0x80030ac2, 0x80030b02, 0x80030b42, 0x80030b82,
+    0x80030bc2, 0x80030c02, 0x80030c42, 0x80030c82, 0x80030cc2, 0x80030d02, 0x80030d42, 0x80030d82,
+    0x80030dc2, 0x80030e02, 0x80030e42, 0x80030e82, 0x80030ec2, 0x80030f02, 0x80030f42, 0x80030f82,
+    0x80030fc2, 0x80031002, 0x80031042, 0x80031082, 0x13340834, 0x13340832, 0x133a8834, 0x133a8832,
+    0x133a882e, 0x133a8830, 0x133b0834, 0x133b0832, 0x133b082e, 0x133b0830, 0x133b8834, 0x133b8832,
+    0x133b882e, 0x133b8830, 0x13440834, 0x13440832, 0x1344082e, 0x13440830, 0x13458834, 0x13458832,
+    0x1345882e, 0x13458830, 0x13438834, 0x13438832, 0x1343882e, 0x13438830, 0x13748834, 0x13748832,
+    0x1374882e, 0x13748830, 0x13760834, 0x13760832, 0x1376082e, 0x13760830, 0x13488834, 0x13488832,
+    0x1348882e, 0x13488830, 0x13480834, 0x13480832, 0x1348082e, 0x13480830, 0x13490834, 0x13490832,
+    0x1349082e, 0x13490830, 0x134a0834, 0x134a0832, 0x134a082e, 0x134a0830, 0x13558834, 0x13558832,
+    0x13550834, 0x13550832, 0x13568834, 0x13568832, 0x13530834, 0x13530832, 0x135e0834, 0x135e0832,
+    0x135a8834, 0x135a8832, 0x137b8834, 0x137b8832, 0x137b882e, 0x137b8830, 0x137f8834, 0x137f8832,
+    0x137f882e, 0x137f8830, 0x13820834, 0x13820832, 0x1382082e, 0x13820830, 0x13810834, 0x13810832,
+    0x1381082e, 0x13810830, 0x138c8834, 0x138c8832, 0x138d8834, 0x138d8832, 0x138d882e, 0x138d8830,
+    0x800310c2, 0x80031102, 0x13920834, 0x13920832, 0x1392082e, 0x13920830, 0x13918834, 0x13918832,
+    0x1391882e, 0x13918830, 0x13a28834, 0x13a28832, 0x80031142, 0x80031182, 0x29b0805, 0x29b8805,
+    0x29c0805, 0x29c8805, 0x29d0805, 0x29d8805, 0x29e0805, 0x29e8805, 0x29f0805, 0x29f8805,
+    0x2a00805, 0x2a08805, 0x2a10805, 0x2a18805, 0x2a20805, 0x2a28805, 0x137e0834, 0x137e0832,
+    0x137e082e, 0x137e0830, 0x13960834, 0x13960832, 0x13958834, 0x13958832, 0x13968834, 0x13968832,
+    0x800311c2, 0x13980834, 0x13980832, 0x13950834, 0x13950832, 0x13970834, 0x13970832, 0x139d8834,
+    0x139d8832, 0x139d882e, 0x139d8830, 0x139b082e, 0x139b0830, 0x80031202, 0x80031242, 0x80031282,
+    0x800312c2, 0x80031302, 0x80031342, 0x80031382, 0x800313c2, 0x80031402, 0x80031442, 0x80031482,
+    0x800314c2, 0x80031502, 0x80031542, 0x80031582, 0x800315c2, 0x80031602, 0x80031642, 0x139c0834,
+    0x139c0832, 0x139c082e, 0x139c0830, 0x80031682, 0x800316c2, 0x80031702, 0x80031742, 0x80031782,
+    0x800317c2, 0x80031802, 0x80031842, 0x80031882, 0x800318c2, 0x80031902, 0x80031942, 0x80031982,
+    0x800319c2, 0x80031a02, 0x80031a42, 0x80031a82, 0x80031ac2, 0x80031b02, 0x80031b42, 0x80031b82,
+    0x80031bc2, 0x80031c02, 0x80031c42, 0x80031c82, 0x80031cc2, 0x80031d02, 0x80031d42, 0x80031d82,
+    0x80031dc2, 0x80031e02, 0x80031e42, 0x80031e82, 0x80031ec2, 0x80031f02, 0x80031f42, 0x80031f82,
+    0x80031fc2, 0x80032002, 0x80032042, 0x80032082, 0x800320c2, 0x80032102, 0x80032142, 0x80032182,
+    0x800321c2, 0x80032202, 0x80032242, 0x80032282, 0x800322c2, 0x80032302, 0x80032342, 0x80032382,
+    0x800323c2, 0x80032402, 0x80032442, 0x80032482, 0x800324c2, 0x80032502, 0x80032542, 0x80032582,
+    0x800325c2, 0x80032602, 0x80032642, 0x80032682, 0x800326c2, 0x80032702, 0x80032742, 0x80032782,
+    0x800327c2, 0x80032802, 0x80032842, 0x80032882, 0x800328c2, 0x80032902, 0x80032942, 0x80032982,
+    0x800329c2, 0x80032a02, 0x80032a42, 0x80032a82, 0x80032ac2, 0x80032b02, 0x80032b42, 0x80032b82,
+    0x80032bc2, 0x80032c02, 0x80032c42, 0x80032c82, 0x80032cc2, 0x80032d02, 0x80032d42, 0x80032d82,
+    0x80032dc2, 0x80032e02, 0x80032e42, 0x80032e82, 0x80032ec2, 0x80032f02, 0x80032f42, 0x80032f82,
+    0x80032fc2, 0x80033002, 0x80033042, 0x80033082, 0x800330c2, 0x80033102, 0x80033142, 0x80033182,
+    0x800331c2, 0x80033202, 0x80033242, 0x80033282, 0x800332c2, 0x80033302, 0x80033342, 0x80033382,
+    0x800333c2, 0x80033402, 0x80033442, 0x80033482, 0x800334c2, 0x80033502, 0x80033542, 0x80033582,
+    0x800335c2, 0x80033602, 0x80033642, 0x80033682, 0x800336c2, 0x80033702, 0x80033742, 0x80033782,
+    0x800337c2, 0x80033802, 0x80033842, 0x80033882, 0x800338c2, 0x80033902, 0x80033942, 0x80033982,
+    0x800339c2, 0x80033a02, 0x80033a42, 0x80033a82, 0x80033ac2, 0x80033b02, 0x80033b42, 0x80033b82,
+    0x80033bc2, 0x80033c02, 0x80033c42, 0x80033c82, 0x80033cc2, 0x80033d02, 0x80033d42, 0x80033d82,
+    0x80033dc2, 0x80033e02, 0x80033e42, 0x80033e82, 0x80033ec2, 0x80033f02, 0x80033f42, 0x80033f82,
+    0x80033fc2, 0x80034002, 0x80034042, 0x80034082, 0x800340c2, 0x80034102, 0x80034142, 0x80034182,
+    0x800341c2, 0x80034202, 0x80034242, 0x80034282, 0x800342c2, 0x80034302, 0x80034342, 0x80034382,
+    0x800343c2, 0x80034402, 0x80034442, 0x80034482, 0x800344c2, 0x80034502, 0x80034542, 0x80034582,
+    0x800345c2, 0x80034602, 0x80034642, 0x80034682, 0x800346c2, 0x80034702, 0x80034742, 0x80034782,
+    0x800347c2, 0x80034802, 0x80034842, 0x80034882, 0x800348c2, 0x80034902, 0x80034942, 0x80034982,
+    0x800349c2, 0x80034a02, 0x80034a42, 0x80034a82, 0x80034ac2, 0x80034b02, 0x80034b42, 0x80034b82,
+    0x80034bc2, 0x80034c02, 0x80034c42, 0x80034c82, 0x80034cc2, 0x80034d02, 0x80034d42, 0x80034d82,
+    0x80034dc2, 0x80034e02, 0x80034e42, 0x80034e82, 0x80034ec2, 0x80034f02, 0x80034f42, 0x80034f82,
+    0x80034fc2, 0x80035002, 0x80035042, 0x80035082, 0x800350c2, 0x80035102, 0x80035142, 0x80035182,
+    0x800351c2, 0x80035202, 0x80035242, 0x80035282, 0x800352c2, 0x80035302, 0x80035342, 0x80035382,
+    0x800353c2, 0x80035402, 0x80035442, 0x80035482, 0x800354c2, 0x80035502, 0x80035542, 0x80035582,
+    0x800355c2, 0x80035602, 0x80035642, 0x80035682, 0x800356c2, 0x80035702, 0x80035742, 0x80035782,
+    0x800357c2, 0x80035802, 0x80035842, 0x80035882, 0x800358c2, 0x80035902, 0x80035942, 0x80035982,
+    0x800359c2, 0x80035a02, 0x80035a42, 0x80035a82, 0x80035ac2, 0x80035b02, 0x80035b42, 0x80035b82,
+    0x80035bc2, 0x80035c02, 0x80035c42, 0x80035c82, 0x80035cc2, 0x80035d02, 0x80035d42, 0x80035d82,
+    0x80035dc2, 0x80035e02, 0x80035e42, 0x80035e82, 0x80035ec2, 0x80035f02, 0x80035f42, 0x80035f82,
+    0x80035fc2, 0x80036002, 0x80036042, 0x80036082, 0x800360c2, 0x80036102, 0x80036142, 0x80036182,
+    0x800361c2, 0x80036202, 0x80036242, 0x80036282, 0x800362c2, 0x80036302, 0x80036342, 0x80036382,
+    0x800363c2, 0x80036402, 0x80036442, 0x80036482, 0x800364c2, 0x80036502, 0x80036542, 0x80036582,
+    0x800365c2, 0x1c90805, 0x1c98805, 0x80036603, 0x80036663, 0x800366c3, 0x80036723, 0x80036783,
+    0x800367e3, 0x80036843, 0x800368a3, 0x80036903, 0x80036963, 0x800369c3, 0x80036a23, 0x80036a83,
+    0x80036ae3, 0x80036b43, 0x80036ba3, 0x80036c03, 0x80036c63, 0x80036cc3, 0x80036d23, 0x80036d83,
+    0x80036de3, 0x80036e43, 0x80036ea3, 0x80036f03, 0x80036f63, 0x80036fc3, 0x80037023, 0x80037083,
+    0x800370e3, 0x80037143, 0x800371a3, 0x80037203, 0x80037263, 0x800372c3, 0x80037323, 0x80037383,
+    0x800373e3, 0x80037443, 0x800374a3, 0x80037503, 0x80037563, 0x800375c3, 0x80037623, 0x80037683,
+    0x800376e3, 0x80037743, 0x800377a3, 0x80037803, 0x80037863, 0x800378c3, 0x80037923, 0x80037983,
+    0x800379e3, 0x80037a43, 0x80037aa3, 0x80037b03, 0x80037b63, 0x80037bc3, 0x80037c23, 0x80037c83,
+    0x80037ce3, 0x80037d43, 0x80037da3, 0x80037e03, 0x80037e63, 0x80037ec3, 0x80037f23, 0x80037f83,
+    0x80037fe3, 0x80038043, 0x800380a3, 0x80038103, 0x80038163, 0x800381c3, 0x80038223, 0x80038283,
+    0x800382e3, 0x80038343, 0x800383a3, 0x80038403, 0x80038463, 0x800384c3, 0x80038523, 0x80038583,
+    0x800385e3, 0x80038643, 0x800386a3, 0x80038703, 0x80038763, 0x800387c3, 0x80038823, 0x80038883,
+    0x800388e3, 0x80038943, 0x800389a3, 0x80038a03, 0x80038a63, 0x80038ac3, 0x80038b23, 0x80038b83,
+    0x80038be3, 0x80038c43, 0x80038ca3, 0x80038d03, 0x80038d63, 0x80038dc3, 0x80038e23, 0x80038e83,
+    0x80038ee3, 0x80038f43, 0x80038fa3, 0x80039003, 0x80039063, 0x800390c3, 0x80039123, 0x80039183,
+    0x800391e3, 0x80039243, 0x800392a3, 0x80039304, 0x80039384, 0x80039404, 0x80039484, 0x80039504,
+    0x80039584, 0x80039604, 0x80039683, 0x800396f2, 0x80039928, 0x80039a24, 0x29a8805, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x111882d, 0x11b082d,
+    0x149082d, 0x120082d, 0x11d082d, 0x133882d, 0x136882d, 0x1c6082d, 0x1c6882d, 0x80039aa3,
+    0x13c4, 0x0, 0x1384, 0x0, 0x0, 0x0, 0x0, 0xd04, 0x0, 0x1384, 0x0, 0x0, 0x0, 0x0, 0x1404, 0x0,
+    0x80039b02, 0x10b082d, 0x10a882d, 0x105882d, 0x105882d, 0x194082d, 0x194882d, 0x196082d,
+    0x196882d, 0x1c5082d, 0x1c5882d, 0x1c4082d, 0x1c4882d, 0x1c1082d, 0x1c1882d, 0x1c0082d,
+    0x1c0882d, 0x1c2082d, 0x1c2882d, 0x1c3082d, 0x1c3882d, 0x11b8805, 0x11c0805, 0x195082d,
+    0x195882d, 0x1050809, 0x1050809, 0x1050809, 0x1050809, 0x1058809, 0x1058809, 0x1058809,
+    0x111881f, 0x11b081f, 0x13f081f, 0x11d081f, 0x120081f, 0x136881f, 0x133881f, 0x10b081f,
+    0x194081f, 0x194881f, 0x196081f, 0x196881f, 0x1c5081f, 0x1c5881f, 0x1d6081f, 0x1d4881f,
+    0x1d0881f, 0x333081f, 0x106881f, 0x335081f, 0x336081f, 0x335881f, 0x1d3881f, 0xfb2081e,
+    0x1d6881f, 0x1d0081f, 0x1b74, 0x1b70, 0x1c34, 0x0, 0x1cf4, 0x1db4, 0x1db0, 0x1eb4, 0x1eb0,
+    0x1f74, 0x1f70, 0x2034, 0x2030, 0x2074, 0x2070, 0x13320834, 0x13328834, 0x13328832, 0x13330834,
+    0x13330832, 0x13348834, 0x13348832, 0x13350834, 0x13350832, 0x13370834, 0x13370832, 0x1337082e,
+    0x13370830, 0x13390834, 0x13390832, 0x133a0834, 0x133a0832, 0x133a082e, 0x133a0830, 0x13420834,
+    0x13420832, 0x13428834, 0x13428832, 0x1342882e, 0x13428830, 0x13430834, 0x13430832, 0x1343082e,
+    0x13430830, 0x13478834, 0x13478832, 0x1347882e, 0x13478830, 0x134c8834, 0x134c8832, 0x134c882e,
+    0x134c8830, 0x134d0834, 0x134d0832, 0x134d082e, 0x134d0830, 0x13520834, 0x13520832, 0x13528834,
+    0x13528832, 0x13598834, 0x13598832, 0x135a0834, 0x135a0832, 0x13630834, 0x13630832, 0x1363082e,
+    0x13630830, 0x13638834, 0x13638832, 0x1363882e, 0x13638830, 0x13688834, 0x13688832, 0x1368882e,
+    0x13688830, 0x13690834, 0x13690832, 0x1369082e, 0x13690830, 0x136b8834, 0x136b8832, 0x136b882e,
+    0x136b8830, 0x136c0834, 0x136c0832, 0x136c082e, 0x136c0830, 0x136d8834, 0x136d8832, 0x136d882e,
+    0x136d8830, 0x136e0834, 0x136e0832, 0x136e082e, 0x136e0830, 0x13720834, 0x13720832, 0x1372082e,
+    0x13720830, 0x13780834, 0x13780832, 0x1378082e, 0x13780830, 0x137b0834, 0x137b0832, 0x137b082e,
+    0x137b0830, 0x13860834, 0x13860832, 0x1386082e, 0x13860830, 0x138a0834, 0x138a0832, 0x138a082e,
+    0x138a0830, 0x138c0834, 0x138c0832, 0x138c082e, 0x138c0830, 0x13910834, 0x13910832, 0x1391082e,
+    0x13910830, 0x13940834, 0x13940832, 0x139b0834, 0x139b0832, 0x139b8834, 0x139b8832, 0x139b882e,
+    0x139b8830, 0x80039b42, 0x80039b82, 0x80039bc2, 0x80039c02, 0x80039c42, 0x80039c82, 0x80039cc2,
+    0x80039d02, 0x0, 0x1338807, 0x18e8807, 0x1d60807, 0xfb20806, 0x1d68807, 0x1d48807, 0x18b0807,
+    0x1940807, 0x1948807, 0x1d08807, 0x3330807, 0x1118807, 0x1068807, 0x13f0807, 0x1d30807,
+    0xfcc0806, 0xfcc8806, 0xfcd0806, 0xfcd8806, 0xfce0806, 0xfce8806, 0xfcf0806, 0xfcf8806,
+    0xfd00806, 0xfd08806, 0x1200807, 0x11d0807, 0x3350807, 0x3358807, 0x3360807, 0x1368807,
+    0x1d00807, 0xfd10812, 0xfde0812, 0xfeb0812, 0xff58812, 0x10038812, 0x10210812, 0x10288812,
+    0x103a8812, 0x10480812, 0x10558812, 0x10620812, 0x106b0812, 0x10848812, 0x108c0812, 0x109e0812,
+    0x10b58812, 0x10c00812, 0x10c98812, 0x10e90812, 0x10fb8812, 0x110b8812, 0x11238812, 0x112c8812,
+    0x11320812, 0x11380812, 0x11430812, 0x1950807, 0x1d38807, 0x1958807, 0x25b8807, 0x1058807,
+    0x25a0807, 0xfd10806, 0xfde0806, 0xfeb0806, 0xff58806, 0x10038806, 0x10210806, 0x10288806,
+    0x103a8806, 0x10480806, 0x10558806, 0x10620806, 0x106b0806, 0x10848806, 0x108c0806, 0x109e0806,
+    0x10b58806, 0x10c00806, 0x10c98806, 0x10e90806, 0x10fb8806, 0x110b8806, 0x11238806, 0x112c8806,
+    0x11320806, 0x11380806, 0x11430806, 0x1960807, 0x3370807, 0x1968807, 0x3380807, 0x19f0807,
+    0x19f8807, 0x1490825, 0x1c20825, 0x1c28825, 0x11b0825, 0x1108825, 0x21848824, 0x216d0820,
+    0x216d8820, 0x216e0820, 0x216f0820, 0x216f8820, 0x217f0820, 0x217f8820, 0x21800820, 0x21760820,
+    0xfb00824, 0x216d0824, 0x216d8824, 0x216e0824, 0x216f0824, 0x216f8824, 0x21700824, 0x21708824,
+    0x21710824, 0x21718824, 0x21720824, 0x21728824, 0x21730824, 0x21738824, 0x21740824, 0x21748824,
+    0x21750824, 0x21758824, 0x21760824, 0x21768824, 0x21770824, 0x21778824, 0x21780824, 0x21788824,
+    0x21790824, 0x21798824, 0x217a0824, 0x217a8824, 0x217b0824, 0x217b8824, 0x217c0824, 0x217c8824,
+    0x217d0824, 0x217d8824, 0x217e0824, 0x217e8824, 0x217f0824, 0x217f8824, 0x21800824, 0x21808824,
+    0x21810824, 0x21818824, 0x21820824, 0x21828824, 0x21830824, 0x21850824, 0xde4, 0xe24,
+    0x20f90824, 0x20ba8824, 0x20bb0824, 0x21298824, 0x20bb8824, 0x212a8824, 0x212b0824, 0x20bc0824,
+    0x20bc8824, 0x20bd0824, 0x212c8824, 0x212d0824, 0x212d8824, 0x212e0824, 0x212e8824, 0x212f0824,
+    0x20c78824, 0x20bd8824, 0x20be0824, 0x20be8824, 0x20cb0824, 0x20bf0824, 0x20bf8824, 0x20c00824,
+    0x20c08824, 0x20c10824, 0x20c18824, 0x20c20824, 0x20c28824, 0x20c30824, 0x20c38824, 0x20f98824,
+    0x20fa0824, 0x20fa8824, 0x20fb0824, 0x20fb8824, 0x20fc0824, 0x20fc8824, 0x20fd0824, 0x20fd8824,
+    0x20fe0824, 0x20fe8824, 0x20ff0824, 0x20ff8824, 0x21000824, 0x21008824, 0x21010824, 0x21018824,
+    0x21020824, 0x21028824, 0x21030824, 0x21038824, 0xfb18806, 0xfb28806, 0x3368807, 0x25c0807,
+    0x3378807, 0xfb30806, 0xfc00806, 0x4358825, 0x2f60825, 0x2f70825, 0x2f68825, 0x2f78825,
+    0x4848825, 0x49a0825, 0x0, 0x0, 0x0, 0xeaa8805, 0x7ffe8804, 0x267e0804, 0x267e8804, 0x267f0804,
+    0x267f8804, 0x26800804, 0x26808804, 0x26810804, 0x26818804, 0x26820804, 0x26828804, 0x26830804,
+    0x26838804, 0x26840804, 0x26848804, 0x26850804, 0x26858804, 0x26860804, 0x26868804, 0x26870804,
+    0x26878804, 0x26880804, 0x26888804, 0x26890804, 0x26898804, 0x268a0804, 0x268a8804, 0x268b0804,
+    0x268b8804, 0x268c0804, 0x268c8804, 0x268d0804, 0x268d8804, 0x268e0804, 0x268e8804, 0x268f0804,
+    0x268f8804, 0x26900804, 0x26908804, 0x26910804, 0x26918804, 0x26920804, 0x26928804, 0x26930804,
+    0x26938804, 0x26940804, 0x26948804, 0x26950804, 0x26958804, 0x26960804, 0x26968804, 0x26970804,
+    0x26978804, 0x26980804, 0x26988804, 0x26990804, 0x26998804, 0x269a0804, 0x269a8804, 0x269b0804,
+    0x269b8804, 0x269c0804, 0x269c8804, 0x269d0804, 0x269d8804, 0x269e0804, 0x269e8804, 0x269f0804,
+    0x269f8804, 0x26a00804, 0x26a08804, 0x26a10804, 0x26a18804, 0x26a20804, 0x26a28804, 0x26a30804,
+    0x26a38804, 0x26a40804, 0x26a48804, 0x26a50804, 0x26a58804, 0x26a60804, 0x26a68804, 0x26a70804,
+    0x26a78804, 0x26a80804, 0x26a88804, 0x26a90804, 0x26a98804, 0x26aa0804, 0x26aa8804, 0x26ab0804,
+    0x26ab8804, 0x26ac0804, 0x26ac8804, 0x26ad0804, 0x26ad8804, 0x26ae0804, 0x26ae8804, 0x26af0804,
+    0x26af8804, 0x26b00804, 0x26b08804, 0x26b10804, 0x26b18804, 0x26b20804, 0x26b28804, 0x26b30804,
+    0x26b38804, 0x26b40804, 0x26b48804, 0x26b50804, 0x26b58804, 0x26b60804, 0x26b68804, 0x26b70804,
+    0x26b78804, 0x26b80804, 0x26b88804, 0x26b90804, 0x26b98804, 0x26ba0804, 0x26ba8804, 0x26bb0804,
+    0x26bb8804, 0x26bc0804, 0x26bc8804, 0x26bd0804, 0x26bd8804, 0x26be0804, 0x26be8804, 0x26bf0804,
+    0x26bf8804, 0x26c00804, 0x26c08804, 0x26c10804, 0x26c18804, 0x26c20804, 0x26c28804, 0x26c30804,
+    0x26c38804, 0x26c40804, 0x26c48804, 0x26c50804, 0x26c58804, 0x26c60804, 0x26c68804, 0x26c70804,
+    0x26c78804, 0x26c80804, 0x26c88804, 0x26c90804, 0x26c98804, 0x26ca0804, 0x26ca8804, 0x26cb0804,
+    0x26cb8804, 0x26cc0804, 0x26cc8804, 0x26cd0804, 0x26cd8804, 0x26ce0804, 0x26ce8804, 0x26cf0804,
+    0x26cf8804, 0x26d00804, 0x26d08804, 0x26d10804, 0x26d18804, 0x26d20804, 0x26d28804, 0x26d30804,
+    0x26d38804, 0x26d40804, 0x26d48804, 0x26d50804, 0x26d58804, 0x26d60804, 0x26d68804, 0x26d70804,
+    0x26d78804, 0x26d80804, 0x26d88804, 0x26d90804, 0x26d98804, 0x26da0804, 0x26da8804, 0x26db0804,
+    0x26db8804, 0x26dc0804, 0x26dc8804, 0x26dd0804, 0x26dd8804, 0x26de0804, 0x26de8804, 0x26df0804,
+    0x26df8804, 0x26e00804, 0x26e08804, 0x26e10804, 0x26e18804, 0x26e20804, 0x26e28804, 0x26e30804,
+    0x26e38804, 0x26e40804, 0x26e48804, 0x26e50804, 0x26e58804, 0x26e60804, 0x26e68804, 0x26e70804,
+    0x1858805, 0x1860805, 0x1868805, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xedf0805, 0xedf8805, 0xee00805, 0xee08805,
+    0xee10805, 0xee18805, 0xee20805, 0xee28805, 0xee30805, 0xee38805, 0xee40805, 0xee48805,
+    0xee50805, 0xee58805, 0xee60805, 0xee68805, 0xee70805, 0xee78805, 0xee80805, 0xee88805,
+    0xee90805, 0xee98805, 0xeea0805, 0xeea8805, 0xeeb0805, 0xeeb8805, 0xeec0805, 0xeec8805,
+    0xeed0805, 0xeed8805, 0xeee0805, 0xeee8805, 0xeef0805, 0xeef8805, 0xef00805, 0xef08805,
+    0x8620805, 0x8628805, 0x8630805, 0x8638805, 0x8640805, 0x8648805, 0x8650805, 0x8658805,
+    0x8660805, 0xef10805, 0xef18805, 0xfcc8804, 0xfce8804, 0xef20805, 0xef28805, 0xef30805,
+    0xef38805, 0xfce8804, 0xef40805, 0xef48805, 0xef50805, 0xef58805, 0xef60805, 0xef68805,
+    0xfce8804, 0xef70805, 0xef78805, 0xef80805, 0xef88805, 0xef90805, 0xef98805, 0xefa0805,
+    0xefa8805, 0xfcc8804, 0xfcc8804, 0xfcc8804, 0xfcd0804, 0xfcd0804, 0xfcd0804, 0xfcd0804,
+    0xfce8804, 0xefb0805, 0xefb8805, 0xefc0805, 0xefc8805, 0xefd0805, 0xefd8805, 0xefe0805,
+    0xefe8805, 0xeff0805, 0xeff8805, 0xf000805, 0xf008805, 0xf010805, 0xf018805, 0xf020805,
+    0xf028805, 0xf030805, 0xf038805, 0xf040805, 0xfce8804, 0xf048805, 0xf050805, 0xf058805,
+    0xf060805, 0xf068805, 0x8668805, 0x8670805, 0x8678805, 0x8680805, 0x8688805, 0x8690805,
+    0x8698805, 0x86a0805, 0x86a8805, 0x86b0805, 0x86b8805, 0x86c0805, 0x86c8805, 0x86d0805,
+    0x86d8805, 0x86e0805, 0x86e8805, 0xfcc0804, 0xf070805, 0x86f0805, 0x86f8805, 0x8700805,
+    0x8708805, 0x8710805, 0x8718805, 0x8720805, 0x8728805, 0x8730805, 0x8738805, 0x8740805,
+    0x8748805, 0x8750805, 0x8758805, 0x8760805, 0x8768805, 0x8770805, 0x8778805, 0x8780805,
+    0x8788805, 0x8790805, 0x8798805, 0x87a0805, 0x87a8805, 0x87b0805, 0x87b8805, 0x87c0805,
+    0x87c8805, 0x87d0805, 0x87d8805, 0x87e0805, 0x87e8805, 0x87f0805, 0x87f8805, 0x8800805,
+    0x8808805, 0x8810805, 0x8818805, 0x8820805, 0x8828805, 0x8830805, 0x8838805, 0x8840805,
+    0x8848805, 0x8850805, 0x8858805, 0x8860805, 0x8868805, 0x8870805, 0x8878805, 0x8880805,
+    0x8888805, 0x8890805, 0x8898805, 0x88a0805, 0x88a8805, 0x88b0805, 0x88b8805, 0x88c0805,
+    0x88c8805, 0x88d0805, 0x88d8805, 0x45c4, 0x25608804, 0x25610804, 0x25618804, 0x25620804,
+    0x25628804, 0x25630804, 0x25638804, 0x25640804, 0x25648804, 0x25650804, 0x25658804, 0x25660804,
+    0x25668804, 0x25670804, 0x25678804, 0x25680804, 0x25688804, 0x25690804, 0x25698804, 0x256a0804,
+    0x256a8804, 0x256b0804, 0x256b8804, 0x256c0804, 0x256c8804, 0x256d0804, 0x256d8804, 0x256e0804,
+    0x256e8804, 0x256f0804, 0x256f8804, 0x25700804, 0x25708804, 0x25710804, 0x25718804, 0x25720804,
+    0x25728804, 0x25730804, 0x25738804, 0x25740804, 0x25748804, 0x25750804, 0x25758804, 0x25760804,
+    0x25768804, 0x25770804, 0x25778804, 0x25780804, 0x25788804, 0x25790804, 0x25798804, 0x257a0804,
+    0x257a8804, 0x257b0804, 0x257b8804, 0x257c0804, 0x257c8804, 0x257d0804, 0x257d8804, 0x257e0804,
+    0x257e8804, 0x257f0804, 0x257f8804, 0x25800804, 0x25808804, 0x25810804, 0x25818804, 0x25820804,
+    0x25828804, 0x25830804, 0x25838804, 0x25840804, 0x25848804, 0x25850804, 0x25858804, 0x25860804,
+    0x25868804, 0x25870804, 0x0, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804,
+    0xfcf8804, 0xfd00804, 0xfd08804, 0xf078805, 0xf080805, 0xf088805, 0xf090805, 0xf098805,
+    0xf0a0805, 0xf0a8805, 0xf0b0805, 0xf0b8805, 0xf0c0805, 0xf0c8805, 0xf0d0805, 0xf0d8805,
+    0xf0e0805, 0xf0e8805, 0xf0f0805, 0xf0f8805, 0xf100805, 0x25948804, 0x25950804, 0x25958804,
+    0x25960804, 0x25968804, 0x25970804, 0x25978804, 0x25980804, 0x25988804, 0x25990804, 0x25998804,
+    0x259a0804, 0x259a8804, 0x259b0804, 0x259b8804, 0x259c8804, 0x259d0804, 0x259d8804, 0x259e0804,
+    0x259e8804, 0x259f0804, 0x259f8804, 0x25a00804, 0x25a08804, 0x25a10804, 0x25a18804, 0x25a20804,
+    0x25a28804, 0x25a30804, 0x25a38804, 0x25a40804, 0x259c0804, 0xfcc8804, 0xfce8804, 0xedc0805,
+    0xedc8805, 0x25a48804, 0x25a50804, 0x25a58804, 0x25a60804, 0x25a68804, 0x25a70804, 0x25a78804,
+    0x25a80804, 0x25a88804, 0x25a90804, 0x25a98804, 0x25aa0804, 0x25aa8804, 0x25ab0804, 0x25ab8804,
+    0x25ac0804, 0x25ac8804, 0x25ad0804, 0x25ad8804, 0x25ae0804, 0x25ae8804, 0x25af0804, 0x25af8804,
+    0x25b00804, 0x25b08804, 0x25b10804, 0x25b18804, 0x25b20804, 0x25b28804, 0x25b30804, 0x12bc8804,
+    0x12bd0804, 0x12bd8804, 0x12be0804, 0x12be8804, 0x12bf0804, 0x12bf8804, 0x12c00804, 0x12c08804,
+    0x12c10804, 0x12c18804, 0x12c20804, 0x12c28804, 0x12c30804, 0x12c38804, 0x12c40804, 0x12c48804,
+    0x12c50804, 0x12c58804, 0x12c60804, 0x12c68804, 0x12c70804, 0x12c78804, 0x12c80804, 0x12c88804,
+    0x12c90804, 0x12c98804, 0x12ca0804, 0x12ca8804, 0x12cb0804, 0x12cb8804, 0x12cc0804, 0x12cc8804,
+    0x12cd0804, 0x12cd8804, 0x12ce0804, 0x12ce8804, 0x12cf0804, 0x12bc8808, 0x12be0808, 0x12c00808,
+    0x12c30808, 0x12c50808, 0x287f8804, 0x28800804, 0x28808804, 0x28810804, 0x28818804, 0x28820804,
+    0x28828804, 0x28830804, 0x28838804, 0x28840804, 0x28848804, 0x28850804, 0x28858804, 0x28860804,
+    0x28868804, 0x28870804, 0x28878804, 0x28880804, 0x28888804, 0x28890804, 0x28898804, 0x288a0804,
+    0x288a8804, 0x288b0804, 0x288b8804, 0x288c0804, 0x288c8804, 0x288d0804, 0x288d8804, 0x288e0804,
+    0x1870805, 0x288e8804, 0x288f0804, 0x288f8804, 0x28900804, 0x28908804, 0x28910804, 0x28918804,
+    0x28920804, 0x28928804, 0x28930804, 0x28938804, 0x28940804, 0x28948804, 0x28950804, 0x28958804,
+    0x28960804, 0x28968804, 0x28970804, 0x28978804, 0x28980804, 0x28988804, 0x28990804, 0x28998804,
+    0x289a0804, 0x289a8804, 0x289b0804, 0x289b8804, 0x289c0804, 0x289c8804, 0x289d0804, 0x289d8804,
+    0x289e0804, 0x289e8804, 0x289f0804, 0x289f8804, 0x28a00804, 0x28a08804, 0x28a10804, 0x28a18804,
+    0x28a20804, 0x28a28804, 0x28a30804, 0x28a38804, 0x28a40804, 0x1878805, 0xfcc8804, 0xfcd0804,
+    0xf108805, 0xf110805, 0xf118805, 0x25b38810, 0x25b40810, 0x25b48810, 0x25b50810, 0x25b58810,
+    0x25b60810, 0x25b68810, 0x25b70810, 0x25b78810, 0x25b80810, 0x25b88810, 0x25b90810, 0x25b98810,
+    0x25ba0810, 0x25ba8810, 0x25bb0810, 0x25bb8810, 0x25bc0810, 0x25bc8810, 0x25bd0810, 0x25bd8810,
+    0x25be0810, 0x25be8810, 0x25bf0810, 0x25bf8810, 0x25c00810, 0x25c08810, 0x25c10810, 0x25c18810,
+    0x25c20810, 0x25c28810, 0x25c30810, 0x25c38810, 0x25c40810, 0x25c48810, 0x25c50810, 0x25c58810,
+    0x25c60810, 0x25c68810, 0x25c70810, 0x25b38804, 0x25b40804, 0x25b48804, 0x25b50804, 0x25b58804,
+    0x25b60804, 0x25b68804, 0x25b70804, 0x25b78804, 0x25b80804, 0x25b88804, 0x25b90804, 0x25b98804,
+    0x25ba0804, 0x25ba8804, 0x25bb0804, 0x25bb8804, 0x25bc0804, 0x25bc8804, 0x25bd0804, 0x25bd8804,
+    0x25be0804, 0x25be8804, 0x25bf0804, 0x25bf8804, 0x25c00804, 0x25c08804, 0x25c10804, 0x25c18804,
+    0x25c20804, 0x25c28804, 0x25c30804, 0x25c38804, 0x25c40804, 0x25c48804, 0x25c50804, 0x25c58804,
+    0x25c60804, 0x25c68804, 0x25c70804, 0x25c78804, 0x25c80804, 0x25c88804, 0x25c90804, 0x25c98804,
+    0x25ca0804, 0x25ca8804, 0x25cb0804, 0x25cb8804, 0x25cc0804, 0x25cc8804, 0x25cd0804, 0x25cd8804,
+    0x25ce0804, 0x25ce8804, 0x25cf0804, 0x25cf8804, 0x25d00804, 0x25d08804, 0x25d10804, 0x25d18804,
+    0x25d20804, 0x25d28804, 0x25d30804, 0x25d38804, 0x25d40804, 0x25d48804, 0x25d50804, 0x25d58804,
+    0x25d60804, 0x25d68804, 0x25d70804, 0x25d78804, 0x25d80804, 0x25d88804, 0x25d90804, 0x25d98804,
+    0x25da0804, 0x25da8804, 0x25db0804, 0x25db8804, 0x25dc0804, 0x25dc8804, 0x25dd0804, 0x25dd8804,
+    0x25de0804, 0x25de8804, 0x25df0804, 0x26250804, 0x26258804, 0x26260804, 0x26268804, 0x26270804,
+    0x26278804, 0x26280804, 0x26288804, 0x26290804, 0x26298804, 0x262a0804, 0x262a8804, 0x262b0804,
+    0x262b8804, 0x262c0804, 0x262c8804, 0x262d0804, 0x262d8804, 0x262e0804, 0x262e8804, 0x262f0804,
+    0x262f8804, 0x26300804, 0x26308804, 0x26310804, 0x26318804, 0x26320804, 0x26328804, 0x26330804,
+    0x26338804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804,
+    0xfcf8804, 0xfd00804, 0xfd08804, 0x1c9a0810, 0x1c9a8810, 0x1c9b0810, 0x1c9b8810, 0x1c9c0810,
+    0x1c9c8810, 0x1c9d0810, 0x1c9d8810, 0x1c9e0810, 0x1c9e8810, 0x1c9f0810, 0x1c9f8810, 0x1ca00810,
+    0x1ca08810, 0x1ca10810, 0x1ca18810, 0x1ca20810, 0x1ca28810, 0x1ca30810, 0x1ca38810, 0x1ca40810,
+    0x1ca48810, 0x1ca50810, 0x1ca58810, 0x1ca60810, 0x1ca68810, 0x1ca70810, 0x1ca78810, 0x1ca80810,
+    0x1ca88810, 0x1ca90810, 0x1ca98810, 0x1caa0810, 0x1caa8810, 0x1cab0810, 0x1cab8810, 0x1c9a0804,
+    0x1c9a8804, 0x1c9b0804, 0x1c9b8804, 0x1c9c0804, 0x1c9c8804, 0x1c9d0804, 0x1c9d8804, 0x1c9e0804,
+    0x1c9e8804, 0x1c9f0804, 0x1c9f8804, 0x1ca00804, 0x1ca08804, 0x1ca10804, 0x1ca18804, 0x1ca20804,
+    0x1ca28804, 0x1ca30804, 0x1ca38804, 0x1ca40804, 0x1ca48804, 0x1ca50804, 0x1ca58804, 0x1ca60804,
+    0x1ca68804, 0x1ca70804, 0x1ca78804, 0x1ca80804, 0x1ca88804, 0x1ca90804, 0x1ca98804, 0x1caa0804,
+    0x1caa8804, 0x1cab0804, 0x1cab8804, 0x26340804, 0x26348804, 0x26350804, 0x26358804, 0x26360804,
+    0x26368804, 0x26370804, 0x26378804, 0x26380804, 0x26388804, 0x26390804, 0x26398804, 0x263a0804,
+    0x263a8804, 0x263b0804, 0x263b8804, 0x263c0804, 0x263c8804, 0x263d0804, 0x263d8804, 0x263e0804,
+    0x263e8804, 0x263f0804, 0x263f8804, 0x26400804, 0x26408804, 0x26410804, 0x26418804, 0x26420804,
+    0x26428804, 0x26430804, 0x26438804, 0x26440804, 0x26448804, 0x26450804, 0x26458804, 0x26460804,
+    0x26468804, 0x26470804, 0x26478804, 0x26480804, 0x26488804, 0x26490804, 0x26498804, 0x264a0804,
+    0x264a8804, 0x264b0804, 0x264b8804, 0x264c0804, 0x264c8804, 0x264d0804, 0x264d8804, 0x264e0804,
+    0x264e8804, 0x264f0804, 0x264f8804, 0x26500804, 0x26508804, 0x26510804, 0x26518804, 0x26520804,
+    0x26528804, 0x26530804, 0x26538804, 0x26540804, 0x26548804, 0x26550804, 0x26558804, 0x26560804,
+    0x26568804, 0x26570804, 0x26578804, 0x26580804, 0x26588804, 0x26590804, 0x26598804, 0x265a0804,
+    0x265a8804, 0x265b0804, 0x265b8804, 0x265c0804, 0x265c8804, 0x265d0804, 0x265d8804, 0x265e0804,
+    0x265e8804, 0x265f0804, 0x265f8804, 0x26600804, 0x26608804, 0x26610804, 0x26618804, 0x22f0805,
+    0x26e78804, 0x26e80804, 0x26e88804, 0x26e90804, 0x26e98804, 0x26ea0804, 0x26ea8804, 0x26eb0804,
+    0x26eb8804, 0x26ec0804, 0x26ec8804, 0x26ed0804, 0x26ed8804, 0x26ee0804, 0x26ee8804, 0x26ef0804,
+    0x26ef8804, 0x26f00804, 0x26f08804, 0x26f10804, 0x26f18804, 0x26f20804, 0x26f28804, 0x26f30804,
+    0x26f38804, 0x26f40804, 0x26f48804, 0x26f50804, 0x26f58804, 0x26f60804, 0x26f68804, 0x26f70804,
+    0x26f78804, 0x26f80804, 0x26f88804, 0x26f90804, 0x26f98804, 0x26fa0804, 0x26fa8804, 0x26fb0804,
+    0x26fb8804, 0x26fc0804, 0x26fc8804, 0x26fd0804, 0x26fd8804, 0x26fe0804, 0x26fe8804, 0x26ff0804,
+    0x26ff8804, 0x27000804, 0x27008804, 0x27010804, 0x27018804, 0x27020804, 0x27028804, 0x27030804,
+    0x27038804, 0x27040804, 0x27048804, 0x27050804, 0x27058804, 0x27060804, 0x27068804, 0x27070804,
+    0x27078804, 0x27080804, 0x27088804, 0x27090804, 0x27098804, 0x270a0804, 0x270a8804, 0x270b0804,
+    0x270b8804, 0x270c0804, 0x270c8804, 0x270d0804, 0x270d8804, 0x270e0804, 0x270e8804, 0x270f0804,
+    0x270f8804, 0x27100804, 0x27108804, 0x27110804, 0x27118804, 0x27120804, 0x27128804, 0x27130804,
+    0x27138804, 0x27140804, 0x27148804, 0x27150804, 0x27158804, 0x27160804, 0x27168804, 0x27170804,
+    0x27178804, 0x27180804, 0x27188804, 0x27190804, 0x27198804, 0x271a0804, 0x271a8804, 0x271b0804,
+    0x271b8804, 0x271c0804, 0x271c8804, 0x271d0804, 0x271d8804, 0x271e0804, 0x271e8804, 0x271f0804,
+    0x271f8804, 0x27200804, 0x27208804, 0x27210804, 0x27218804, 0x27220804, 0x27228804, 0x27230804,
+    0x27238804, 0x27240804, 0x27248804, 0x27250804, 0x27258804, 0x27260804, 0x27268804, 0x27270804,
+    0x27278804, 0x27280804, 0x27288804, 0x27290804, 0x27298804, 0x272a0804, 0x272a8804, 0x272b0804,
+    0x272b8804, 0x272c0804, 0x272c8804, 0x272d0804, 0x272d8804, 0x272e0804, 0x272e8804, 0x272f0804,
+    0x272f8804, 0x27300804, 0x27308804, 0x27310804, 0x27318804, 0x27320804, 0x27328804, 0x27330804,
+    0x27338804, 0x27340804, 0x27348804, 0x27350804, 0x27358804, 0x27360804, 0x27368804, 0x27370804,
+    0x27378804, 0x27380804, 0x27388804, 0x27390804, 0x27398804, 0x273a0804, 0x273a8804, 0x273b0804,
+    0x273b8804, 0x273c0804, 0x273c8804, 0x273d0804, 0x273d8804, 0x273e0804, 0x273e8804, 0x273f0804,
+    0x273f8804, 0x27400804, 0x27408804, 0x27410804, 0x27418804, 0x27420804, 0x27428804, 0x27430804,
+    0x27438804, 0x27440804, 0x27448804, 0x27450804, 0x27458804, 0x27460804, 0x27468804, 0x27470804,
+    0x27478804, 0x27480804, 0x27488804, 0x27490804, 0x27498804, 0x274a0804, 0x274a8804, 0x274b0804,
+    0x274b8804, 0x274c0804, 0x274c8804, 0x274d0804, 0x274d8804, 0x274e0804, 0x274e8804, 0x274f0804,
+    0x274f8804, 0x27500804, 0x27508804, 0x27510804, 0x27518804, 0x27520804, 0x27528804, 0x27530804,
+    0x27538804, 0x27540804, 0x27548804, 0x27550804, 0x27558804, 0x27560804, 0x27568804, 0x27570804,
+    0x27578804, 0x27580804, 0x27588804, 0x27590804, 0x27598804, 0x275a0804, 0x275a8804, 0x275b0804,
+    0x275b8804, 0x275c0804, 0x275c8804, 0x275d0804, 0x275d8804, 0x275e0804, 0x275e8804, 0x275f0804,
+    0x275f8804, 0x27600804, 0x27608804, 0x27610804, 0x27618804, 0x27620804, 0x27628804, 0x27630804,
+    0x27638804, 0x27640804, 0x27648804, 0x27650804, 0x27658804, 0x27660804, 0x27668804, 0x27670804,
+    0x27678804, 0x27680804, 0x27688804, 0x27690804, 0x27698804, 0x276a0804, 0x276a8804, 0x276b0804,
+    0x276b8804, 0x276c0804, 0x276c8804, 0x276d0804, 0x276d8804, 0x276e0804, 0x276e8804, 0x276f0804,
+    0x276f8804, 0x27700804, 0x27708804, 0x27710804, 0x27718804, 0x27720804, 0x27728804, 0x27730804,
+    0x27738804, 0x27740804, 0x27748804, 0x27750804, 0x27758804, 0x27760804, 0x27768804, 0x27770804,
+    0x27778804, 0x27780804, 0x27788804, 0x27790804, 0x27798804, 0x277a0804, 0x277a8804, 0x277b0804,
+    0x277b8804, 0x277c0804, 0x277c8804, 0x277d0804, 0x277d8804, 0x277e0804, 0x277e8804, 0x277f0804,
+    0x277f8804, 0x27800804, 0x27808804, 0x27810804, 0x27818804, 0x27820804, 0x27828804, 0x27830804,
+    0x27838804, 0x27840804, 0x27848804, 0x27850804, 0x27858804, 0x27860804, 0x27868804, 0x27870804,
+    0x27878804, 0x27880804, 0x27888804, 0x27890804, 0x27898804, 0x278a0804, 0x278a8804, 0x278b0804,
+    0x278b8804, 0x278c0804, 0x278c8804, 0x278d0804, 0x278d8804, 0x278e0804, 0x278e8804, 0x278f0804,
+    0x278f8804, 0x27900804, 0x27908804, 0x27910804, 0x27918804, 0x27920804, 0x27928804, 0x27930804,
+    0x27938804, 0x27940804, 0x27948804, 0x27950804, 0x27958804, 0x27960804, 0x27968804, 0x27970804,
+    0x27978804, 0x27980804, 0x27988804, 0x27990804, 0x27998804, 0x279a0804, 0x279a8804, 0x279b0804,
+    0x279b8804, 0x279c0804, 0x279c8804, 0x279d0804, 0x279d8804, 0x279e0804, 0x279e8804, 0x279f0804,
+    0x279f8804, 0x27a00804, 0x27a08804, 0x27a10804, 0x27a18804, 0x27a20804, 0x27a28804, 0x27a30804,
+    0x27a38804, 0x27a40804, 0x27a48804, 0x27a50804, 0x27a58804, 0x27a60804, 0x27a68804, 0x27a70804,
+    0x27a78804, 0x27a80804, 0x27a88804, 0x27a90804, 0x27a98804, 0x27aa0804, 0x27aa8804, 0x27ab0804,
+    0x27ab8804, 0x27ac0804, 0x27ac8804, 0x27ad0804, 0x28058804, 0x28060804, 0x28068804, 0x28070804,
+    0x28078804, 0x28080804, 0x28088804, 0x28090804, 0x28098804, 0x280a0804, 0x280a8804, 0x280b0804,
+    0x280b8804, 0x280c0804, 0x280c8804, 0x280d0804, 0x280d8804, 0x280e0804, 0x280e8804, 0x280f0804,
+    0x280f8804, 0x28100804, 0x1710805, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xf198805, 0xf1a0805,
+    0xf1a8805, 0xf1b0805, 0xf1b8805, 0x27e50804, 0x27e58804, 0x27e60804, 0x27e68804, 0x27e70804,
+    0x27e78804, 0x27e80804, 0x27e88804, 0x27e90804, 0x27e98804, 0x27ea0804, 0x27ea8804, 0x27eb0804,
+    0x27eb8832, 0x27eb8804, 0x27ec0804, 0x27ec8804, 0x27ed0804, 0x27ed8804, 0x27ee0804, 0x27ee8804,
+    0x27ef0804, 0x27ef8804, 0x88e0805, 0x88e8805, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xf120805, 0xf128805, 0x27f00832, 0x27f00804, 0x27f08832, 0x27f08804, 0x27f10804,
+    0x27f18804, 0x27f20832, 0x27f20804, 0x27f28804, 0x27f30804, 0x27f38804, 0x27f40804, 0x27f48832,
+    0x27f48804, 0x27f50832, 0x27f50804, 0x27f58832, 0x27f58804, 0x27f60832, 0x27f60804, 0x27f68832,
+    0x27f68804, 0x27f70804, 0x27f78804, 0x27f80804, 0x27f88804, 0x27f90804, 0x27f98804, 0x27fa0832,
+    0x27fa0804, 0x27fa8804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce0804, 0xfce8804,
+    0xf130805, 0xf138805, 0xf140805, 0x27fb0804, 0x27fb8804, 0x27fc0804, 0x27fc8804, 0x27fd0804,
+    0x27fd8804, 0x27fe0804, 0x27fe8804, 0x27ff0804, 0x27ff8804, 0x28000804, 0x28008804, 0x28010804,
+    0x28018804, 0x28020804, 0x28028804, 0x28030804, 0x28038804, 0x28040804, 0x28048804, 0x28050804,
+    0xfcc8804, 0xfce8804, 0xf148805, 0xf150805, 0xf158805, 0x131a0804, 0x131a8804, 0x131b0804,
+    0x131b8804, 0x131c0804, 0x131c8804, 0x131d0804, 0x131d8804, 0x131e0804, 0x131e8804, 0x131f0804,
+    0x131f8804, 0x13200804, 0x13208804, 0x13210804, 0x13218804, 0x13220804, 0x13228804, 0x13230804,
+    0x13238804, 0x13240804, 0x13248804, 0xfcc8804, 0xf180805, 0xf188805, 0xf190805, 0xfcd0804,
+    0xfcd8804, 0x1880805, 0x25878804, 0x25880804, 0x25888804, 0x25890804, 0x25898804, 0x258a0804,
+    0x258a8804, 0x258b0804, 0x258b8804, 0x258c0804, 0x258c8804, 0x258d0804, 0x258d8804, 0x258e0804,
+    0x258e8804, 0x258f0804, 0x258f8804, 0x25900804, 0x25908804, 0x25910804, 0x25918804, 0x25920804,
+    0x25928804, 0x25930804, 0x25938804, 0x25940804, 0x1850805, 0x80039d42, 0x80039d82, 0x80039dc2,
+    0x80039e02, 0x80039e42, 0x80039e82, 0x80039ec2, 0x80039f02, 0x80039f42, 0x80039f82, 0x80039fc2,
+    0x8003a002, 0x8003a042, 0x8003a082, 0x8003a0c2, 0x8003a102, 0x8003a142, 0x8003a182, 0x8003a1c2,
+    0x8003a202, 0x8003a242, 0x8003a282, 0x8003a2c2, 0x8003a302, 0x8003a342, 0x8003a382, 0x8003a3c2,
+    0x8003a402, 0x8003a442, 0x8003a482, 0x2cf78804, 0x2cf80804, 0x2ceb0804, 0x2ceb8804, 0x2cec0804,
+    0x2cec8804, 0x2ced0804, 0x2ced8804, 0x2cee0804, 0x2cee8804, 0x2cef0804, 0x2cef8804, 0x2cf00804,
+    0x2cf08804, 0x2cf10804, 0x2cf18804, 0x2cf20804, 0x2cf28804, 0x8003a4c2, 0x2cf30804, 0x2cf38804,
+    0x2cf40804, 0x2cf48804, 0x2cf50804, 0x2cf58804, 0x2cf60804, 0xf958805, 0xf900805, 0x2cf68804,
+    0x2cf70804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804,
+    0xfd00804, 0xfd08804, 0xf7a8805, 0xf7b0805, 0xf7b8805, 0xf7c0805, 0xf7c8805, 0xf7d0805,
+    0xf7d8805, 0xf7e0805, 0xf7e8805, 0xf7f0805, 0xf7f8805, 0xf800805, 0xf808805, 0xf810805,
+    0xf818805, 0xf820805, 0xf828805, 0xf830805, 0xf838805, 0xf840805, 0xf848805, 0xf850805,
+    0xf858805, 0xf860805, 0xf868805, 0xf870805, 0xf878805, 0xf880805, 0xf888805, 0xf890805,
+    0xf898805, 0xf8a0805, 0xf8a8805, 0xf8b0805, 0xf8b8805, 0xf8c0805, 0xf8c8805, 0xf8d0805,
+    0xf8d8805, 0xf8e0805, 0xf8e8805, 0xf8f0805, 0xf8f8805, 0xf908805, 0xf910805, 0xf918805,
+    0xf920805, 0xf928805, 0xf930805, 0xf938805, 0xf940805, 0xf948805, 0xf950805, 0x18d78804,
+    0x18d80804, 0x18d88804, 0x18d90804, 0x18d98804, 0x18da0804, 0x18da8804, 0xd04, 0x3104, 0x3144,
+    0x18db0804, 0x18dc0804, 0x18dc8804, 0x18dd0804, 0x18dd8804, 0x18de0804, 0x18de8804, 0x18df0804,
+    0x18df8804, 0x18e08804, 0x18e18804, 0x18e20804, 0x18e28804, 0x18e30804, 0x18e38804, 0x18e40804,
+    0x18e48804, 0x18e50804, 0x18e58804, 0x18e60804, 0x18e68804, 0x18e70804, 0x18e78804, 0x18e80804,
+    0x18e88804, 0x18e90804, 0x18e98804, 0x18ea8804, 0x18eb0804, 0x18eb8804, 0x18ec0804, 0x18ec8804,
+    0x18db8804, 0x18e10804, 0x18e00804, 0x18ea0804, 0x3344, 0x3384, 0x33c4, 0x18ed0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xf2f0805, 0xf2f8805, 0xf300805, 0xf308805, 0xf310805,
+    0x2320805, 0x2328805, 0x2330805, 0x2338805, 0x2340805, 0x2348805, 0x1590805, 0x1598805,
+    0x2350805, 0x27ad8804, 0x27ae0804, 0x27ae8804, 0x27af0804, 0x27af8804, 0x27b00804, 0x27b08804,
+    0x27b10804, 0x27b18804, 0x27b20804, 0x27b28804, 0x27b30804, 0x27b38804, 0x27b40804, 0x27b48804,
+    0x27b50804, 0x27b58804, 0x27b60804, 0x27b68804, 0x27b70804, 0x27b78804, 0x27b80804, 0x27b88804,
+    0x27b90804, 0x27b98804, 0x27ba0804, 0x27ba8804, 0x27bb0804, 0x27bb8804, 0xfcc8804, 0xf160805,
+    0xf168805, 0x27bc0804, 0x27bc8804, 0x27bd0804, 0x27bd8804, 0x27be0804, 0x27be8804, 0x27bf0804,
+    0x27bf8804, 0x27c00804, 0x27c08804, 0x27c10804, 0x27c18804, 0x27c20804, 0x27c28804, 0x27c30804,
+    0x27c38804, 0x27c40804, 0x27c48804, 0x27c50804, 0x27c58804, 0x27c60804, 0x27c68804, 0x27c70804,
+    0x27c78804, 0x27c80804, 0x27c88804, 0x27c90804, 0x27c98804, 0x27ca0804, 0xfcc8804, 0xf170805,
+    0xf178805, 0x28390804, 0x28398804, 0x283a0804, 0x283a8804, 0x283b0804, 0x283b8804, 0x283c0804,
+    0x283c8804, 0x8003a502, 0x283d0804, 0x283d8804, 0x283e0804, 0x283e8804, 0x283f0804, 0x283f8804,
+    0x28400804, 0x28408804, 0x28410804, 0x28418804, 0x28420804, 0x28428804, 0x28430804, 0x28438804,
+    0x28440804, 0x28448804, 0x28450804, 0x28458804, 0x28460804, 0x28468804, 0x28470804, 0x28478804,
+    0x28480804, 0x28488804, 0x28490804, 0x28498804, 0x284a0804, 0x284a8804, 0xcc4, 0xd04,
+    0xfcc8804, 0xfce8804, 0xf1c0805, 0xf1c8805, 0xf1d0805, 0x2378805, 0x2380805, 0x2388805,
+    0x2390805, 0x2398805, 0x23a0805, 0x23a8805, 0x27ca8804, 0x27cb0804, 0x27cb8804, 0x27cc0804,
+    0x27cc8804, 0x27cd0804, 0x27cd8804, 0x27ce0804, 0x27ce8804, 0x27cf0804, 0x27cf8804, 0x27d00804,
+    0x27d08804, 0x27d10804, 0x27d18804, 0x27d20804, 0x27d28804, 0x27d30804, 0x27d38804, 0x27d40804,
+    0x27d48804, 0x27d50804, 0x27d58804, 0x27d60804, 0x27d68804, 0x27d70804, 0x27d78804, 0x27d80804,
+    0x27d88804, 0x27d90804, 0x27d98804, 0x27da0804, 0x27da8804, 0x27db0804, 0x27db8804, 0x27dc0804,
+    0x27dc8804, 0x27dd0804, 0x27dd8804, 0x27de0804, 0x27de8804, 0x27df0804, 0x27df8804, 0x27e00804,
+    0x27e08804, 0x27e10804, 0x8003a542, 0x27e18804, 0x27e20804, 0x27e28804, 0x27e30804, 0x27e38804,
+    0x27e40804, 0x27e48804, 0x2370805, 0x1718805, 0x1720805, 0x1728805, 0x1730805, 0x1738805,
+    0x1740805, 0x28108804, 0x28110804, 0x28118804, 0x28120804, 0x28128804, 0x28130804, 0x28138804,
+    0x28140804, 0x28148804, 0x28150804, 0x28158804, 0x28160804, 0x28168804, 0x28170804, 0x28178804,
+    0x28180804, 0x28188804, 0x28190804, 0x28198804, 0x281a0804, 0x281a8804, 0x281b0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xf1d8805, 0xf1e0805, 0xf1e8805, 0xf1f0805, 0x281b8804,
+    0x281c0804, 0x281c8804, 0x281d0804, 0x281d8804, 0x281e0804, 0x281e8804, 0x281f0804, 0x281f8804,
+    0x28200804, 0x28208804, 0x28210804, 0x28218804, 0x28220804, 0x28228804, 0x28230804, 0x28238804,
+    0x28240804, 0x28248804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xf1f8805, 0xf200805,
+    0xf208805, 0xf210805, 0x28250804, 0x28258804, 0x28260804, 0x28268804, 0x28270804, 0x28278804,
+    0x28280804, 0x28288804, 0x28290804, 0x28298804, 0x282a0804, 0x282a8804, 0x282b0804, 0x282b8804,
+    0x282c0804, 0x282c8804, 0x282d0804, 0x282d8804, 0x23b0805, 0x23b8805, 0x23c0805, 0x23c8805,
+    0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xf218805, 0xf220805, 0xf228805, 0x1e460804,
+    0x8003a582, 0x1e468804, 0x1e470804, 0x8003a5c2, 0x1e478804, 0x1e480804, 0x1e488804, 0x8003a602,
+    0x1e490804, 0x8003a642, 0x1e498804, 0x8003a682, 0x1e4a0804, 0x8003a6c2, 0x1e4a8804, 0x8003a702,
+    0x1e4b0804, 0x8003a742, 0x1e4b8804, 0x1e4c0804, 0x8003a782, 0x1e4c8804, 0x8003a7c2, 0x1e4d0804,
+    0x8003a802, 0x1e4d8804, 0x8003a842, 0x1e4e0804, 0x8003a882, 0x1e4e8804, 0x8003a8c2, 0x1e4f0804,
+    0x1e4f8804, 0x1e500804, 0x1e508804, 0x1e510804, 0x8003a902, 0x1e518804, 0x8003a942, 0x1e520804,
+    0x8003a982, 0x1e528804, 0x8003a9c2, 0x1e530804, 0x1e538804, 0x8003aa02, 0x1e540804, 0x1e548804,
+    0x1e550804, 0x1e558804, 0x8003aa42, 0x1e560804, 0x8003aa82, 0x1e568804, 0x8003aac2, 0x1e570804,
+    0x8003ab02, 0x1e578804, 0x8003ab42, 0x1e580804, 0x1e588804, 0x1e590804, 0x1e598804, 0x8003ab82,
+    0x1e5a0804, 0x8003abc2, 0x1e5a8804, 0x8003ac02, 0x1e5b0804, 0x8003ac42, 0x1e5b8804, 0x1e5c0804,
+    0x1e318810, 0x8003ac82, 0x1e320810, 0x1e328810, 0x1e330810, 0x1e338810, 0x1e340810, 0x1e348810,
+    0x1e350810, 0x1e358810, 0x8003acc2, 0x8003ad02, 0x1e360810, 0x1e368810, 0x1e370810, 0x1e378810,
+    0x1e380810, 0x8003ad42, 0x1e388810, 0x1e390810, 0x1e398810, 0x1e3a0810, 0x1e3a8810, 0x1e3b0810,
+    0x1e3b8810, 0x1e3c0810, 0x1e3c8810, 0x1e3d0810, 0x8003ad82, 0x1e3d8810, 0x8003adc2, 0x8003ae02,
+    0x1e3e0810, 0x1e3e8810, 0x1e3f0810, 0x8003ae42, 0x1e3f8810, 0x1e400810, 0x1e408810, 0x1e410810,
+    0x1e418810, 0x1e420810, 0x1e428810, 0x8003ae82, 0x1e430810, 0x8003aec2, 0x1e438810, 0x1e440810,
+    0x1e448810, 0x1e450810, 0x1e458810, 0x1e318804, 0x8003af02, 0x1e320804, 0x1e328804, 0x1e330804,
+    0x1e338804, 0x1e340804, 0x1e348804, 0x1e350804, 0x1e358804, 0x8003af42, 0x8003af82, 0x1e360804,
+    0x1e368804, 0x1e370804, 0x1e378804, 0x1e380804, 0x8003afc2, 0x1e388804, 0x1e390804, 0x1e398804,
+    0x1e3a0804, 0x1e3a8804, 0x1e3b0804, 0x1e3b8804, 0x1e3c0804, 0x1e3c8804, 0x1e3d0804, 0x8003b002,
+    0x1e3d8804, 0x8003b042, 0x8003b082, 0x1e3e0804, 0x1e3e8804, 0x1e3f0804, 0x8003b0c2, 0x1e3f8804,
+    0x1e400804, 0x1e408804, 0x1e410804, 0x1e418804, 0x1e420804, 0x1e428804, 0x8003b102, 0x1e430804,
+    0x8003b142, 0x1e438804, 0x1e440804, 0x1e448804, 0x1e450804, 0x1e458804, 0xfcc8804, 0xfce8804,
+    0xedd0805, 0xedd8805, 0xede0805, 0xede8805, 0x1b0d8804, 0x1b108804, 0x1b110804, 0x1b118804,
+    0x1b120804, 0x1b128804, 0x1b130804, 0x1b138804, 0x1b140804, 0x1b148804, 0x1b150804, 0x1b158804,
+    0x1b160804, 0x1b168804, 0x1b170804, 0x1b178804, 0x1b180804, 0x1b188804, 0x1b190804, 0x1b198804,
+    0x1b1a0804, 0x1b1a8804, 0x1b1b0804, 0x1b1b8804, 0x1b1c0804, 0x1b1c8804, 0x1b1d0804, 0x1b1d8804,
+    0x1b1e0804, 0x1b0e0804, 0x1b0e8804, 0x1b0f0804, 0x1b0f8804, 0x1b100804, 0x1b1e8804, 0x1b1f0804,
+    0xcc4, 0xcc4, 0xcc4, 0xcc4, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xed08805, 0xed10805, 0xed18805,
+    0xed20805, 0xed28805, 0xed30805, 0xed38805, 0xed40805, 0xed48805, 0xed50805, 0xed58805,
+    0xed60805, 0xed68805, 0xed70805, 0xed78805, 0xed80805, 0xed88805, 0xed90805, 0xed98805,
+    0xeda0805, 0xeda8805, 0xedb0805, 0x285f0804, 0x285f8804, 0x28600804, 0x28608804, 0x28610804,
+    0x28618804, 0x28620804, 0x28628804, 0x28630804, 0x28638804, 0x28640804, 0x28648804, 0x28650804,
+    0x28658804, 0x28660804, 0x28668804, 0x28670804, 0x28678804, 0x28680804, 0x28688804, 0x28690804,
+    0x28698804, 0x286a0804, 0x286a8804, 0x286b0804, 0x286b8804, 0x286c0804, 0x286c8804, 0x286d0804,
+    0x286d8804, 0x286e0804, 0x286e8804, 0x286f0804, 0x28700804, 0x28708804, 0x28710804, 0x28718804,
+    0x28720804, 0x28728804, 0x28730804, 0x28738804, 0x28748804, 0x20c4, 0x2084, 0x1110805,
+    0x286f8804, 0x28740804, 0x284b0804, 0x284b0832, 0x284b8804, 0x284b8832, 0x284c0804, 0x284c8804,
+    0x284c8832, 0x284d0804, 0x284d8804, 0x284e0804, 0x284e8804, 0x284f0804, 0x284f8804, 0x28500804,
+    0x28508804, 0x28508832, 0x8003b182, 0x28510804, 0x28518804, 0x8003b1c2, 0x28520804, 0x28528804,
+    0x28528832, 0x8003b202, 0x28530804, 0x28538804, 0x28540804, 0x28540832, 0x8003b242, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xf230805, 0xf238805, 0xf240805, 0xf248805,
+    0xf250805, 0x8003b282, 0x28548804, 0x28550804, 0x28558804, 0x28560804, 0x28568804, 0x28570804,
+    0x28578804, 0x28580804, 0x28588804, 0x28590804, 0x28598804, 0x285a0804, 0x285a8804, 0x285b0804,
+    0x285b8804, 0x285c0804, 0x285c8804, 0x285d0804, 0x285d8804, 0x285e0804, 0x285e8804, 0x8003b2c2,
+    0xd04, 0xd04, 0xcc4, 0xcc4, 0xcc4, 0xd04, 0xcc4, 0xd04, 0xd04, 0xd04, 0xd04, 0xfcc8804,
+    0xf258805, 0xf260805, 0xf268805, 0x1748805, 0x1750805, 0x1758805, 0x1760805, 0x1768805,
+    0x28750804, 0x28758804, 0x28760804, 0x28768804, 0x28770804, 0x28778804, 0x28780804, 0x28788804,
+    0x28790804, 0x28798804, 0x287a0804, 0x287a8804, 0x287b0804, 0x287b8804, 0x287c0804, 0x287c8804,
+    0x287d0804, 0x287d8804, 0x287e0804, 0x287e8804, 0x287f0804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xf270805, 0xf278805, 0xf280805, 0x282e0804, 0x282e8804, 0x282f0804, 0x282f8804,
+    0x28300804, 0x28308804, 0x28310804, 0x28318804, 0x28320804, 0x28328804, 0x28330804, 0x28338804,
+    0x28340804, 0x28348804, 0x28350804, 0x28358804, 0x28360804, 0x28368804, 0x28370804, 0x28378804,
+    0x28380804, 0x28388804, 0x8003b302, 0x30c4, 0x3104, 0x3144, 0x18cc8804, 0x18cd0804, 0x18b50804,
+    0x18b58804, 0x18b60804, 0x18b68804, 0x18b70804, 0x18b78804, 0x18b80804, 0x18b88804, 0x18b90804,
+    0x18b98804, 0x18ba0804, 0x18ba8804, 0x18bb0804, 0x18bb8804, 0x18bc0804, 0x18bc8804, 0x18bd0804,
+    0x18bd8804, 0x18be0804, 0x18be8804, 0x18bf0804, 0x18bf8804, 0x18c00804, 0x18c08804, 0x18c10804,
+    0x18c18804, 0x18c20804, 0x18c28804, 0x18c30804, 0x18c38804, 0x18c40804, 0x18c48804, 0x18c50804,
+    0x18c58804, 0x18c60804, 0x18c68804, 0x18c70804, 0x18c78804, 0x18c80804, 0x18c88804, 0x18c90804,
+    0x18c98804, 0x18ca0804, 0x18ca8804, 0x18cb0804, 0x18cb8804, 0x18cc0804, 0x18cd8804, 0x18ce0804,
+    0x18ce8804, 0x18cf0804, 0x18cf8804, 0x18d00804, 0x18d08804, 0x18d10804, 0x18d18804, 0x18d20804,
+    0x18d28804, 0x18d30804, 0x18d38804, 0x18d40804, 0x18d48804, 0x18d50804, 0x18d58804, 0x18d60804,
+    0x18d68804, 0x15a0805, 0x15a8805, 0x22f8805, 0x2300805, 0x2308805, 0x2310805, 0x2318805,
+    0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804,
+    0xfd08804, 0xf298805, 0xf2a0805, 0xf2a8805, 0xf2b0805, 0xf2b8805, 0xf2c0805, 0xf2c8805,
+    0xf2d0805, 0xf2d8805, 0xf2e0805, 0xf2e8805, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x18d70804, 0x30c4, 0x3104,
+    0x3144, 0x16a38804, 0x16a40804, 0x16a48804, 0x16a50804, 0x16a58804, 0x16a60804, 0x16a68804,
+    0x16a70804, 0x16a78804, 0x16a80804, 0x16a88804, 0x16a90804, 0x16a98804, 0x16aa0804, 0x16aa8804,
+    0x16ab0804, 0x16ab8804, 0x16ac0804, 0x16ac8804, 0x16ad0804, 0x16ad8804, 0x16ae0804, 0x16ae8804,
+    0x8003b342, 0x16af0804, 0x8003b382, 0x16af8804, 0x16b00804, 0x16b08804, 0x16b10804, 0x16b18804,
+    0x16b20804, 0x16b28804, 0x16b30804, 0x16b38804, 0x16b40804, 0x16b48804, 0x16b50804, 0x16b58804,
+    0x16b60804, 0x8003b3c2, 0x16b68804, 0x16b70804, 0x16b78804, 0x16b80804, 0x16b88804, 0x16b90804,
+    0x16b98804, 0x16ba0804, 0x16ba8804, 0x16bb0804, 0x16bb8804, 0x16bc0804, 0x16bc8804, 0x16bd0804,
+    0x3084, 0x23d0805, 0x23d8805, 0x0, 0x1770805, 0x1778805, 0x15b0805, 0x15b8805, 0x0, 0x26620804,
+    0x26628804, 0x26630804, 0x26638804, 0x26640804, 0x26648804, 0x26650804, 0x26658804, 0x26660804,
+    0x26668804, 0x26670804, 0x26678804, 0x26680804, 0x26688804, 0x26690804, 0x26698804, 0x266a0804,
+    0x266a8804, 0x266b0804, 0x266b8804, 0x266c0804, 0x266c8804, 0x266d0804, 0x266d8804, 0x266e0804,
+    0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804,
+    0xfd00804, 0xfd08804, 0x30c4, 0x3104, 0x3144, 0x1b1f8804, 0x1b200804, 0x1b208804, 0x1b210804,
+    0x1b218804, 0x1b220804, 0x1b228804, 0x1b230804, 0x1b238804, 0x1b240804, 0x1b248804, 0x1b250804,
+    0x1b258804, 0x1b260804, 0x1b268804, 0x1b270804, 0x1b278804, 0x1b280804, 0x1b288804, 0x1b290804,
+    0x1b298804, 0x1b2a0804, 0x1b2a8804, 0x1b2b0804, 0x1b2b8804, 0x1b2c0804, 0x1b2c8804, 0x1b2d0804,
+    0x1b2d8804, 0x1b2e0804, 0x1b2e8804, 0x1b2f0804, 0x1b2f8804, 0x1b308804, 0x1b310804, 0x1b318804,
+    0x1b328804, 0x1b330804, 0x1b338804, 0x1b340804, 0x1b348804, 0x1b350804, 0x1b358804, 0x1b360804,
+    0x1b368804, 0x1b370804, 0x1b378804, 0x1b380804, 0x1b398804, 0x1b3a0804, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0x1780805, 0x15c0805, 0x15c8805, 0x13d0805, 0x1b320804, 0x1b388804, 0x1b390804, 0x1b300804,
+    0x16bd8804, 0x16be0804, 0x16be8804, 0x16bf0804, 0x16bf8804, 0x16c00804, 0x16c08804, 0x16c10804,
+    0x16c18804, 0x16c20804, 0x16c28804, 0x16c30804, 0x16c38804, 0x16c40804, 0x16c48804, 0x16c50804,
+    0x16c58804, 0x16c60804, 0x16c68804, 0x16c70804, 0x16c78804, 0x16c80804, 0x16c88804, 0x16c90804,
+    0x16c98804, 0x16ca0804, 0x16ca8804, 0x16cb0804, 0x16cb8804, 0x16cc0804, 0x16cc8804, 0x16cd0804,
+    0x16ce0804, 0x16ce8804, 0x16cf0804, 0x3084, 0x23e0805, 0x23e8805, 0x16cd8804, 0x30c4, 0x3104,
+    0x3144, 0x16d08804, 0x16d10804, 0x16d18804, 0x16d20804, 0x16d28804, 0x16d30804, 0x16d38804,
+    0x16d40804, 0x16d48804, 0x16d50804, 0x16d58804, 0x16d60804, 0x16d68804, 0x16d70804, 0x16d78804,
+    0x16d80804, 0x16d88804, 0x16d90804, 0x16d98804, 0x16da0804, 0x16da8804, 0x16db0804, 0x16db8804,
+    0x16dc0804, 0x16dc8804, 0x16dd0804, 0x16dd8804, 0x16de0804, 0x16de8804, 0x16df0804, 0x16df8804,
+    0x16e00804, 0x16e08804, 0x16e10804, 0x16e18804, 0x16e20804, 0x16e28804, 0x16e30804, 0x16e38804,
+    0x16e40804, 0x16e48804, 0x16e50804, 0x16e58804, 0x16e60804, 0x16e68804, 0x16e70804, 0x16e78804,
+    0x16e80804, 0x16ea8804, 0x16eb0804, 0x16eb8804, 0x16ec0804, 0x16ec8804, 0x16ed0804, 0x16ed8804,
+    0x16ee0804, 0x16ee8804, 0x16ef0804, 0x16f00804, 0x16f08804, 0x16f10804, 0x16f18804, 0x16e88804,
+    0x16e90804, 0x16e98804, 0x16cf8804, 0x15d0805, 0x15d8805, 0x23f8805, 0x1788805, 0x3184, 0x3084,
+    0x3404, 0x3444, 0x23f0805, 0x16ef8804, 0x30c4, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x16d00804, 0x2400805,
+    0x16ea0804, 0x2408805, 0x1790805, 0x1798805, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xf368805, 0xf370805, 0xf378805,
+    0xf380805, 0xf388805, 0xf390805, 0xf398805, 0xf3a0805, 0xf3a8805, 0xf3b0805, 0xf3b8805,
+    0x16f20804, 0x16f28804, 0x16f30804, 0x16f38804, 0x16f40804, 0x16f48804, 0x16f50804, 0x16f58804,
+    0x16f60804, 0x16f68804, 0x16f70804, 0x16f78804, 0x16f80804, 0x16f88804, 0x16f90804, 0x16f98804,
+    0x16fa0804, 0x16fa8804, 0x16fb0804, 0x16fb8804, 0x16fc0804, 0x16fc8804, 0x16fd0804, 0x16fd8804,
+    0x16fe0804, 0x16fe8804, 0x16ff0804, 0x16ff8804, 0x17000804, 0x17008804, 0x17010804, 0x17018804,
+    0x17020804, 0x17028804, 0x17030804, 0x17038804, 0x17040804, 0x17048804, 0x17050804, 0x17058804,
+    0x17060804, 0x17068804, 0x17070804, 0x17078804, 0x17080804, 0x17088804, 0x17090804, 0x17098804,
+    0x170a0804, 0x170a8804, 0x170b0804, 0x3104, 0x170b8804, 0x3084, 0x2004, 0x15e0805, 0x15e8805,
+    0x2410805, 0x2418805, 0x2420805, 0x2428805, 0x2044, 0x17288804, 0x17290804, 0x17298804,
+    0x172a0804, 0x172b8804, 0x172c0804, 0x172c8804, 0x172d0804, 0x172d8804, 0x172e0804, 0x172e8804,
+    0x172f0804, 0x172f8804, 0x17300804, 0x17308804, 0x17310804, 0x17318804, 0x17320804, 0x17328804,
+    0x17330804, 0x17338804, 0x17340804, 0x17348804, 0x17350804, 0x17358804, 0x17360804, 0x17368804,
+    0x17370804, 0x17378804, 0x17380804, 0x17388804, 0x17390804, 0x17398804, 0x172a8804, 0x172b0804,
+    0x173a0804, 0x173a8804, 0x17a0805, 0x170c0804, 0x170c8804, 0x170d0804, 0x170d8804, 0x170e0804,
+    0x170e8804, 0x170f0804, 0x170f8804, 0x17100804, 0x17108804, 0x17110804, 0x17118804, 0x17120804,
+    0x17128804, 0x17130804, 0x17138804, 0x17140804, 0x17148804, 0x17150804, 0x17158804, 0x17160804,
+    0x17168804, 0x17170804, 0x17178804, 0x17180804, 0x17188804, 0x17190804, 0x17198804, 0x171a0804,
+    0x171a8804, 0x171b0804, 0x171b8804, 0x171c0804, 0x171c8804, 0x171d0804, 0x171d8804, 0x171e0804,
+    0x171e8804, 0x171f0804, 0x171f8804, 0x17200804, 0x17208804, 0x17210804, 0x17218804, 0x17220804,
+    0x17228804, 0x17230804, 0x3104, 0x17238804, 0x17240804, 0x17248804, 0x17250804, 0x17258804,
+    0x17260804, 0x17268804, 0x17270804, 0x17278804, 0x3084, 0x17280804, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x3104,
+    0x30c4, 0x3104, 0x3144, 0x173b8804, 0x173c0804, 0x173c8804, 0x173d0804, 0x173d8804, 0x173e0804,
+    0x173e8804, 0x173f8804, 0x17408804, 0x17410804, 0x17418804, 0x17420804, 0x17428804, 0x17430804,
+    0x17438804, 0x17440804, 0x17448804, 0x17450804, 0x17458804, 0x17460804, 0x17468804, 0x17470804,
+    0x17478804, 0x17480804, 0x17488804, 0x17490804, 0x17498804, 0x174a0804, 0x174a8804, 0x174b0804,
+    0x174b8804, 0x174c0804, 0x174c8804, 0x174d0804, 0x174d8804, 0x174e0804, 0x174e8804, 0x174f0804,
+    0x174f8804, 0x17500804, 0x17508804, 0x17510804, 0x17518804, 0x17520804, 0x17528804, 0x17530804,
+    0x3084, 0x3084, 0x17538804, 0x17550804, 0x17558804, 0x17560804, 0x17568804, 0x17570804,
+    0x17578804, 0x17580804, 0x17598804, 0x175a0804, 0x175a8804, 0x175b0804, 0x175b8804, 0x173b0804,
+    0x175c0804, 0x175c8804, 0x17540804, 0x17548804, 0x173f0804, 0x17400804, 0x17588804, 0x17590804,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x175e0804, 0x175e8804, 0x175f0804,
+    0x175f8804, 0x17600804, 0x17608804, 0x17610804, 0x17618804, 0x17620804, 0x17628804, 0x17630804,
+    0x17638804, 0x17640804, 0x17648804, 0x17650804, 0x17658804, 0x17660804, 0x17668804, 0x17670804,
+    0x17678804, 0x17680804, 0x17688804, 0x17690804, 0x17698804, 0x176a0804, 0x176a8804, 0x176b0804,
+    0x176b8804, 0x176c0804, 0x176c8804, 0x176d0804, 0x176d8804, 0x176e0804, 0x176e8804, 0x176f0804,
+    0x176f8804, 0x17700804, 0x17708804, 0x17710804, 0x17718804, 0x17720804, 0x17728804, 0x17730804,
+    0x17738804, 0x17740804, 0x17748804, 0x17750804, 0x17758804, 0x17760804, 0x17768804, 0x17770804,
+    0x17778804, 0x17780804, 0x177b0804, 0x177b8804, 0x177c0804, 0x177c8804, 0x177d0804, 0x177d8804,
+    0x177e0804, 0x177e8804, 0x177f0804, 0x177f8804, 0x17800804, 0x17808804, 0x17810804, 0x17818804,
+    0x30c4, 0x3104, 0x3144, 0x3084, 0x17788804, 0x177a0804, 0x175d0804, 0x175d8804, 0x15f0805,
+    0x15f8805, 0x2430805, 0x2440805, 0x2448805, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x2438805, 0x2450805,
+    0x2458805, 0x3184, 0x177a8804, 0x17790804, 0x17798804, 0x17828804, 0x17830804, 0x17838804,
+    0x17840804, 0x17848804, 0x17850804, 0x17858804, 0x17860804, 0x17868804, 0x17870804, 0x17878804,
+    0x17880804, 0x17888804, 0x17890804, 0x17898804, 0x178a0804, 0x178a8804, 0x178b0804, 0x178b8804,
+    0x178c0804, 0x178c8804, 0x178d0804, 0x178d8804, 0x178e0804, 0x178e8804, 0x178f0804, 0x178f8804,
+    0x17900804, 0x17908804, 0x17910804, 0x17918804, 0x17920804, 0x17928804, 0x17930804, 0x17938804,
+    0x17940804, 0x17948804, 0x17950804, 0x17958804, 0x17960804, 0x17968804, 0x17970804, 0x17978804,
+    0x17980804, 0x17988804, 0x17990804, 0x17998804, 0x179a0804, 0x179b8804, 0x179c0804, 0x179c8804,
+    0x179d0804, 0x179d8804, 0x179e0804, 0x179e8804, 0x179f0804, 0x179f8804, 0x17a00804, 0x17a08804,
+    0x17a10804, 0x17a18804, 0x17a20804, 0x17a28804, 0x30c4, 0x3104, 0x3144, 0x17a30804, 0x3084,
+    0x179a8804, 0x179b0804, 0x2460805, 0x17820804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x17a38804, 0x17a40804,
+    0x17a48804, 0x17a50804, 0x17a58804, 0x17a60804, 0x17a68804, 0x17a70804, 0x17a78804, 0x17a80804,
+    0x17a88804, 0x17a90804, 0x17a98804, 0x17aa0804, 0x17aa8804, 0x17ab0804, 0x17ab8804, 0x17ac0804,
+    0x17ac8804, 0x17ad0804, 0x17ad8804, 0x17ae0804, 0x17ae8804, 0x17af0804, 0x17af8804, 0x17b00804,
+    0x17b08804, 0x17b10804, 0x17b18804, 0x17b20804, 0x17b28804, 0x17b30804, 0x17b38804, 0x17b40804,
+    0x17b48804, 0x17b50804, 0x17b58804, 0x17b60804, 0x17b68804, 0x17b70804, 0x17b78804, 0x17b80804,
+    0x17b88804, 0x17b90804, 0x17b98804, 0x17ba0804, 0x17ba8804, 0x17bb0804, 0x17bb8804, 0x17bc0804,
+    0x17bc8804, 0x17bd0804, 0x17bd8804, 0x17be0804, 0x17be8804, 0x17bf0804, 0x17bf8804, 0x17c00804,
+    0x30c4, 0x3104, 0x3144, 0x17c08804, 0x3084, 0x2468805, 0x1600805, 0x1608805, 0x2470805,
+    0x2478805, 0x2480805, 0x2488805, 0x2490805, 0x2498805, 0x24a0805, 0x24a8805, 0x24b0805,
+    0x24b8805, 0x24c0805, 0x24c8805, 0x24d0805, 0x24d8805, 0x24e0805, 0x24e8805, 0x24f0805,
+    0x24f8805, 0x2500805, 0x2508805, 0x8003b402, 0x8003b442, 0x8003b482, 0x8003b4c2, 0x8003b502,
+    0x8003b542, 0x17c10804, 0x17c18804, 0x17c20804, 0x17c28804, 0x17c30804, 0x17c38804, 0x17c40804,
+    0x17c48804, 0x17c50804, 0x17c58804, 0x17c60804, 0x17c68804, 0x17c70804, 0x17c78804, 0x17c80804,
+    0x17c88804, 0x17c90804, 0x17c98804, 0x17ca0804, 0x17ca8804, 0x17cb0804, 0x17cb8804, 0x17cc0804,
+    0x17cc8804, 0x17cd0804, 0x17cd8804, 0x17ce0804, 0x17ce8804, 0x17cf0804, 0x17cf8804, 0x17d00804,
+    0x17d08804, 0x17d10804, 0x17d18804, 0x17d20804, 0x17d28804, 0x17d30804, 0x17d38804, 0x17d40804,
+    0x17d48804, 0x17d50804, 0x17d58804, 0x17d60804, 0x17d68804, 0x17d70804, 0x17d78804, 0x17d80804,
+    0x17d88804, 0x17d90804, 0x17d98804, 0x17da0804, 0x17da8804, 0x17db0804, 0x17db8804, 0x17dc0804,
+    0x17dc8804, 0x17dd0804, 0x17dd8804, 0x17de0804, 0x17de8804, 0x17df0804, 0x3104, 0x3144,
+    0x17df8804, 0x30c4, 0x1610805, 0x1618805, 0x2510805, 0x17e00804, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0x1f98805, 0x1fa0805, 0x1fa8805, 0x1fb0805, 0x1fb8805, 0x1fc0805, 0x1fc8805, 0x1fd0805,
+    0x1fd8805, 0x1fe0805, 0x1fe8805, 0x1ff0805, 0x1ff8805, 0x17e08804, 0x17e10804, 0x17e18804,
+    0x17e20804, 0x17e28804, 0x17e30804, 0x17e38804, 0x17e40804, 0x17e48804, 0x17e50804, 0x17e70804,
+    0x17e78804, 0x17e88804, 0x17e90804, 0x17e98804, 0x17ea0804, 0x17ea8804, 0x17eb0804, 0x17eb8804,
+    0x17ec0804, 0x17ec8804, 0x17ed0804, 0x17ed8804, 0x17ee0804, 0x17ee8804, 0x17ef0804, 0x17ef8804,
+    0x17f00804, 0x17f08804, 0x17f10804, 0x17f18804, 0x17f20804, 0x17f28804, 0x17f30804, 0x17f38804,
+    0x17f40804, 0x17f48804, 0x17f50804, 0x17f58804, 0x17e60804, 0x17e58804, 0x17e68804, 0x17f60804,
+    0x3104, 0x3144, 0x17f68804, 0x17f70804, 0x17f78804, 0x17f80804, 0x17f88804, 0x17f90804,
+    0x17f98804, 0x17fa0804, 0x17fa8804, 0x17fb0804, 0x3084, 0x17e80804, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0x18528804, 0x18530804, 0x18538804, 0x18540804, 0x18548804, 0x8003b582, 0x18550804, 0x18558804,
+    0x18560804, 0x18568804, 0x18570804, 0x18578804, 0x18580804, 0x18588804, 0x18590804, 0x18598804,
+    0x185a0804, 0x185a8804, 0x185b0804, 0x185b8804, 0x185c0804, 0x185c8804, 0x8003b5c2, 0x185d0804,
+    0x185d8804, 0x185e0804, 0x8003b602, 0x18648804, 0x18650804, 0x18658804, 0x185e8804, 0x185f0804,
+    0x185f8804, 0x18600804, 0x18608804, 0x18610804, 0x18618804, 0x18620804, 0x18628804, 0x18630804,
+    0x18638804, 0x18640804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xf288805, 0xf290805, 0x1620805, 0x1628805,
+    0x17a8805, 0x2cf0805, 0x18368804, 0x18370804, 0x18378804, 0x18380804, 0x18388804, 0x18390804,
+    0x18398804, 0x183a0804, 0x183a8804, 0x183b0804, 0x183b8804, 0x183c0804, 0x183c8804, 0x183d0804,
+    0x183d8804, 0x183e0804, 0x183e8804, 0x183f0804, 0x183f8804, 0x18400804, 0x18408804, 0x18410804,
+    0x18418804, 0x18420804, 0x18428804, 0x18430804, 0x18438804, 0x18440804, 0x18448804, 0x18450804,
+    0x18458804, 0x18460804, 0x18468804, 0x18470804, 0x18478804, 0x18480804, 0x18488804, 0x18490804,
+    0x18498804, 0x184a0804, 0x184a8804, 0x184b0804, 0x184b8804, 0x184c0804, 0x184c8804, 0x184d0804,
+    0x184d8804, 0x184e0804, 0x184e8804, 0x184f0804, 0x184f8804, 0x18500804, 0x18508804, 0x18510804,
+    0x18518804, 0x3104, 0x3144, 0x18520804, 0x3084, 0x2518805, 0x24d78810, 0x24d80810, 0x24d88810,
+    0x24d90810, 0x24d98810, 0x24da0810, 0x24da8810, 0x24db0810, 0x24db8810, 0x24dc0810, 0x24dc8810,
+    0x24dd0810, 0x24dd8810, 0x24de0810, 0x24de8810, 0x24df0810, 0x24df8810, 0x24e00810, 0x24e08810,
+    0x24e10810, 0x24e18810, 0x24e20810, 0x24e28810, 0x24e30810, 0x24e38810, 0x24e40810, 0x24e48810,
+    0x24e50810, 0x24e58810, 0x24e60810, 0x24e68810, 0x24e70810, 0x24d78804, 0x24d80804, 0x24d88804,
+    0x24d90804, 0x24d98804, 0x24da0804, 0x24da8804, 0x24db0804, 0x24db8804, 0x24dc0804, 0x24dc8804,
+    0x24dd0804, 0x24dd8804, 0x24de0804, 0x24de8804, 0x24df0804, 0x24df8804, 0x24e00804, 0x24e08804,
+    0x24e10804, 0x24e18804, 0x24e20804, 0x24e28804, 0x24e30804, 0x24e38804, 0x24e40804, 0x24e48804,
+    0x24e50804, 0x24e58804, 0x24e60804, 0x24e68804, 0x24e70804, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xf3c0805,
+    0xf3c8805, 0xf3d0805, 0xf3d8805, 0xf3e0805, 0xf3e8805, 0xf3f0805, 0xf3f8805, 0xf400805,
+    0x24d70804, 0x17fb8804, 0x17fc0804, 0x17fc8804, 0x17fd0804, 0x17fd8804, 0x17fe0804, 0x17fe8804,
+    0x17ff0804, 0x17ff8804, 0x18000804, 0x18008804, 0x18010804, 0x18018804, 0x18028804, 0x18030804,
+    0x18038804, 0x18040804, 0x18048804, 0x18050804, 0x18058804, 0x18060804, 0x18068804, 0x18070804,
+    0x18078804, 0x18080804, 0x18088804, 0x18090804, 0x18098804, 0x180a0804, 0x180a8804, 0x180b0804,
+    0x180b8804, 0x180c8804, 0x180d8804, 0x180e8804, 0x180f0804, 0x180f8804, 0x18100804, 0x18108804,
+    0x18110804, 0x18118804, 0x18120804, 0x18128804, 0x18130804, 0x18138804, 0x18140804, 0x18148804,
+    0x18150804, 0x18158804, 0x18160804, 0x3104, 0x30c4, 0x18168804, 0x18170804, 0x18020804,
+    0x180c0804, 0x180d0804, 0x180e0804, 0x3084, 0x1630805, 0x2520805, 0x17b0805, 0xfcc0804,
+    0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804,
+    0xfd08804, 0x18178804, 0x18180804, 0x18188804, 0x18190804, 0x18198804, 0x181a0804, 0x181a8804,
+    0x181b0804, 0x181b8804, 0x181c0804, 0x181c8804, 0x181d0804, 0x181d8804, 0x181e0804, 0x181e8804,
+    0x181f0804, 0x181f8804, 0x18200804, 0x18208804, 0x18210804, 0x18218804, 0x18220804, 0x18228804,
+    0x18230804, 0x18238804, 0x18240804, 0x18248804, 0x18250804, 0x18258804, 0x18260804, 0x18268804,
+    0x18270804, 0x18278804, 0x18280804, 0x18288804, 0x18290804, 0x18298804, 0x182a0804, 0x182a8804,
+    0x182b0804, 0x182b8804, 0x182c0804, 0x182c8804, 0x182d0804, 0x182d8804, 0x182e0804, 0x182e8804,
+    0x18300804, 0x18308804, 0x18310804, 0x18318804, 0x18320804, 0x18328804, 0x18330804, 0x18338804,
+    0x18348804, 0x18350804, 0x18358804, 0x3104, 0x3144, 0x18360804, 0x182f0804, 0x2528805,
+    0x182f8804, 0x18340804, 0x19b08804, 0x19b10804, 0x19b18804, 0x19b20804, 0x19b28804, 0x19b30804,
+    0x19b38804, 0x19b40804, 0x19b48804, 0x19b50804, 0x19b58804, 0x199c8804, 0x199d8804, 0x199e0804,
+    0x199e8804, 0x199f0804, 0x199f8804, 0x19a00804, 0x19a08804, 0x19a10804, 0x19a18804, 0x19a20804,
+    0x19a28804, 0x19a30804, 0x19a38804, 0x19a40804, 0x19a48804, 0x19a50804, 0x19a58804, 0x19a60804,
+    0x19a68804, 0x19a70804, 0x19a78804, 0x19a80804, 0x19a88804, 0x19a90804, 0x19a98804, 0x19aa0804,
+    0x19aa8804, 0x19ab0804, 0x19ab8804, 0x19ac0804, 0x19ac8804, 0x19ad0804, 0x19ad8804, 0x19ae0804,
+    0x19ae8804, 0x19af0804, 0x19af8804, 0x19b00804, 0x199d0804, 0x3084, 0x19b60804, 0x30c4, 0x30c4,
+    0x30c4, 0x3104, 0x3144, 0x19ad082e, 0x19ac8832, 0x19ad0832, 0x19ad8832, 0x19ae0832, 0x2130805,
+    0x2138805, 0x2140805, 0x2148805, 0x2150805, 0x2158805, 0x2160805, 0x2168805, 0x19b68804,
+    0x19b70804, 0x19b78804, 0x19b80804, 0x19b88804, 0x19ba0804, 0x19bb0804, 0x19ba8804, 0x19bb8804,
+    0x19bc0804, 0x19b90804, 0x19b98804, 0x19bc8804, 0x19bd0804, 0x19be0804, 0x19be8804, 0x19bf0804,
+    0x19bf8804, 0x19c00804, 0x19c08804, 0x19c10804, 0x19c18804, 0x19c20804, 0x19c28804, 0x19c30804,
+    0x19c38804, 0x19c40804, 0x19c48804, 0x19c50804, 0x19c58804, 0x19c60804, 0x19c68804, 0x19c70804,
+    0x19c78804, 0x19c80804, 0x19c88804, 0x19c90804, 0x19c98804, 0x19ca0804, 0x19ca8804, 0x19cb0804,
+    0x19cb8804, 0x19cc0804, 0x19cc8804, 0x19cd0804, 0x19cd8804, 0x19ce0804, 0x19ce8804, 0x19cf0804,
+    0x19cf8804, 0x19d00804, 0x19d08804, 0x19bd8804, 0x19d10804, 0x19d18804, 0x19cd882e, 0x19ce082e,
+    0x19cf082e, 0x19d0082e, 0x19bd0832, 0x19be0832, 0x19bf8832, 0x19c50832, 0x19c70832, 0x19c78832,
+    0x19c98832, 0x19cd8832, 0x19ce0832, 0x19cf0832, 0x19d00832, 0x19cc8832, 0x3104, 0x3144, 0x3484,
+    0x19d20804, 0x2170805, 0x2178805, 0x2180805, 0x19d28804, 0x2188805, 0x2190805, 0x2198805,
+    0x21a0805, 0x21a8805, 0x24eb0804, 0x24eb8804, 0x24ec0804, 0x24ec8804, 0x24ed0804, 0x24ed8804,
+    0x24ee0804, 0x24ee8804, 0x24ef0804, 0x24ef8804, 0x24f00804, 0x24f08804, 0x24f10804, 0x24f18804,
+    0x24f20804, 0x24f28804, 0x24f30804, 0x24f38804, 0x24f40804, 0x24f48804, 0x24f50804, 0x24e78804,
+    0x24e80804, 0x24e88804, 0x24e90804, 0x24e98804, 0x24ea0804, 0x24ea8804, 0x24f58804, 0x24f60804,
+    0x24f90804, 0x24f68804, 0x24f70804, 0x24f78804, 0x24f80804, 0x24f88804, 0x24f98804, 0x24fc8804,
+    0x24fb8804, 0x25028804, 0x24fd0804, 0x24fc0804, 0x25030804, 0x25008804, 0x24ff8804, 0x25010804,
+    0x25000804, 0x24fa0804, 0x25038804, 0x24fb0804, 0x24fa8804, 0x24fe8804, 0x24fd8804, 0x25018804,
+    0x24ff0804, 0x24fe0804, 0x25020804, 0x18ed8804, 0x18ee0804, 0x18ee8804, 0x18ef0804, 0x18ef8804,
+    0x18f00804, 0x18f08804, 0x18f10804, 0x18f18804, 0x18f20804, 0x18f28804, 0x18f30804, 0x18f38804,
+    0x18f40804, 0x18f48804, 0x18f50804, 0x18f58804, 0x18f60804, 0x18f68804, 0x18f70804, 0x18f78804,
+    0x18f80804, 0x18f88804, 0x18f90804, 0x18f98804, 0x18fa0804, 0x18fa8804, 0x18fb0804, 0x18fb8804,
+    0x18fc0804, 0x18fc8804, 0x18fd0804, 0x18fd8804, 0x18fe0804, 0x18fe8804, 0x18ff0804, 0x18ff8804,
+    0x19000804, 0x19008804, 0x19010804, 0x19018804, 0x19020804, 0x19028804, 0x19030804, 0x19038804,
+    0x19040804, 0x19050804, 0x19058804, 0x19060804, 0x19068804, 0x19070804, 0x19078804, 0x19080804,
+    0x19088804, 0x19090804, 0x19098804, 0x190a0804, 0x190a8804, 0x30c4, 0x3104, 0x3144, 0x190b0804,
+    0x19048804, 0x1638805, 0x1640805, 0x2358805, 0x2360805, 0x2368805, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804,
+    0xfd08804, 0xf318805, 0xf320805, 0xf328805, 0xf330805, 0xf338805, 0xf340805, 0xf348805,
+    0xf350805, 0xf358805, 0xf360805, 0x21b0805, 0x21b8805, 0x19d30804, 0x19d40804, 0x19d50804,
+    0x19d60804, 0x19d70804, 0x19d80804, 0x19d90804, 0x19da0804, 0x19db0804, 0x19dc0804, 0x19dd0804,
+    0x19de0804, 0x19df0804, 0x19e00804, 0x19e10804, 0x19e20804, 0x19e30804, 0x19e40804, 0x19e50804,
+    0x19e60804, 0x19e70804, 0x19e80804, 0x19e90804, 0x19e98804, 0x19ea8804, 0x19eb8804, 0x19ec8804,
+    0x19ed8804, 0x19ee8804, 0x19ef8804, 0x19d38804, 0x19d48804, 0x19d58804, 0x19d68804, 0x19d78804,
+    0x19d88804, 0x19d98804, 0x19da8804, 0x19db8804, 0x19dc8804, 0x19dd8804, 0x19de8804, 0x19df8804,
+    0x19e08804, 0x19e18804, 0x19e28804, 0x19e38804, 0x19e48804, 0x19e58804, 0x19e68804, 0x19e78804,
+    0x19e88804, 0x19ea0804, 0x19eb0804, 0x19ec0804, 0x19ed0804, 0x19ee0804, 0x19ef0804, 0x19f00804,
+    0x19f08804, 0x19f10804, 0x19f18804, 0x19f20804, 0x19f28804, 0x3104, 0x30c4, 0x18660804,
+    0x18668804, 0x18670804, 0x18678804, 0x18680804, 0x18688804, 0x18690804, 0x18698804, 0x186a0804,
+    0x186a8804, 0x186b0804, 0x186b8804, 0x186c0804, 0x186c8804, 0x186d0804, 0x186d8804, 0x186e0804,
+    0x186e8804, 0x186f0804, 0x186f8804, 0x18700804, 0x18708804, 0x18710804, 0x18718804, 0x18720804,
+    0x18728804, 0x18730804, 0x18738804, 0x18740804, 0x18748804, 0x18750804, 0x18758804, 0x18760804,
+    0x18768804, 0x18770804, 0x18778804, 0x18780804, 0x18788804, 0x18790804, 0x18798804, 0x187a0804,
+    0x187a8804, 0x187b0804, 0x187b8804, 0x187c0804, 0x187c8804, 0x187d0804, 0x187d8804, 0x187e0804,
+    0x187e8804, 0x187f0804, 0x187f8804, 0x18800804, 0x18808804, 0x18810804, 0x18818804, 0x18820804,
+    0x3104, 0x3144, 0x3084, 0x30c4, 0x18828804, 0x18830804, 0x1878082e, 0x18780832, 0xfcc0804,
+    0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804,
+    0xfd08804, 0x18840804, 0x18848804, 0x18850804, 0x18858804, 0x18860804, 0x18868804, 0x18870804,
+    0x18878804, 0x18880804, 0x18888804, 0x18890804, 0x18898804, 0x188a0804, 0x188a8804, 0x188b0804,
+    0x188b8804, 0x188c0804, 0x188c8804, 0x188d0804, 0x188d8804, 0x188e0804, 0x188e8804, 0x188f0804,
+    0x188f8804, 0x18900804, 0x18908804, 0x18910804, 0x18918804, 0x18920804, 0x18928804, 0x18930804,
+    0x18938804, 0x18940804, 0x18948804, 0x18950804, 0x18958804, 0x18960804, 0x18968804, 0x18970804,
+    0x18978804, 0x18980804, 0x18988804, 0x18990804, 0x18998804, 0x189a0804, 0x189a8804, 0x189b0804,
+    0x189b8804, 0x189c0804, 0x3104, 0x3144, 0x189c8804, 0x18838804, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0x1a788804, 0x1a790804, 0x1a798804, 0x1a7a0804, 0x1a7a8804, 0x1a7b0804, 0x1a7b8804, 0x1a7c0804,
+    0x1a7c8804, 0x1a7d0804, 0x1a7d8804, 0x1a7e0804, 0x1a7e8804, 0x1a7f0804, 0x1a7f8804, 0x1a800804,
+    0x1a808804, 0x1a810804, 0x1a818804, 0x1a820804, 0x1a828804, 0x1a830804, 0x1a838804, 0x17b8805,
+    0x17c0805, 0x24890804, 0xeb58805, 0xeb60805, 0xeb68805, 0xeb70805, 0xeb78805, 0xeb80805,
+    0xeb88805, 0xeb90805, 0xeb98805, 0xeba0805, 0xeba8805, 0xebb0805, 0xebb8805, 0xebc0805,
+    0xebc8805, 0xebd0805, 0xebd8805, 0xebe0805, 0xebe8805, 0xebf0805, 0xebf8805, 0x2a80805,
+    0x2a88805, 0x2a90805, 0x2a98805, 0x2aa0805, 0x2aa8805, 0x2ab0805, 0x2ab8805, 0xfb88804,
+    0xfb90804, 0xfb98804, 0xfba0804, 0x2ac0805, 0x2ac8805, 0x2ad0805, 0x2ad8805, 0x2ae0805,
+    0x2ae8805, 0x2af0805, 0x2af8805, 0x2b00805, 0x2b08805, 0x2b10805, 0x2b18805, 0x2b20805,
+    0x2b28805, 0x2b30805, 0x2b38805, 0x2b40805, 0x2530805, 0x28a48804, 0x28a50804, 0x28a58804,
+    0x28a60804, 0x28a68804, 0x28a70804, 0x28a78804, 0x28a80804, 0x28a88804, 0x28a90804, 0x28a98804,
+    0x28aa0804, 0x28aa8804, 0x28ab0804, 0x28ab8804, 0x28ac0804, 0x28ac8804, 0x28ad0804, 0x28ad8804,
+    0x28ae0804, 0x28ae8804, 0x28af0804, 0x28af8804, 0x28b00804, 0x28b08804, 0x28b10804, 0x28b18804,
+    0x28b20804, 0x28b28804, 0x28b30804, 0x28b38804, 0x28b40804, 0x28b48804, 0x28b50804, 0x28b58804,
+    0x28b60804, 0x28b68804, 0x28b70804, 0x28b78804, 0x28b80804, 0x28b88804, 0x28b90804, 0x28b98804,
+    0x28ba0804, 0x28ba8804, 0x28bb0804, 0x28bb8804, 0x28bc0804, 0x28bc8804, 0x28bd0804, 0x28bd8804,
+    0x28be0804, 0x28be8804, 0x28bf0804, 0x28bf8804, 0x28c00804, 0x28c08804, 0x28c10804, 0x28c18804,
+    0x28c20804, 0x28c28804, 0x28c30804, 0x28c38804, 0x28c40804, 0x28c48804, 0x28c50804, 0x28c58804,
+    0x28c60804, 0x28c68804, 0x28c70804, 0x28c78804, 0x28c80804, 0x28c88804, 0x28c90804, 0x28c98804,
+    0x28ca0804, 0x28ca8804, 0x28cb0804, 0x28cb8804, 0x28cc0804, 0x28cc8804, 0x28cd0804, 0x28cd8804,
+    0x28ce0804, 0x28ce8804, 0x28cf0804, 0x28cf8804, 0x28d00804, 0x28d08804, 0x28d10804, 0x28d18804,
+    0x28d20804, 0x28d28804, 0x28d30804, 0x28d38804, 0x28d40804, 0x28d48804, 0x28d50804, 0x28d58804,
+    0x28d60804, 0x28d68804, 0x28d70804, 0x28d78804, 0x28d80804, 0x28d88804, 0x28d90804, 0x28d98804,
+    0x28da0804, 0x28da8804, 0x28db0804, 0x28db8804, 0x28dc0804, 0x28dc8804, 0x28dd0804, 0x28dd8804,
+    0x28de0804, 0x28de8804, 0x28df0804, 0x28df8804, 0x28e00804, 0x28e08804, 0x28e10804, 0x28e18804,
+    0x28e20804, 0x28e28804, 0x28e30804, 0x28e38804, 0x28e40804, 0x28e48804, 0x28e50804, 0x28e58804,
+    0x28e60804, 0x28e68804, 0x28e70804, 0x28e78804, 0x28e80804, 0x28e88804, 0x28e90804, 0x28e98804,
+    0x28ea0804, 0x28ea8804, 0x28eb0804, 0x28eb8804, 0x28ec0804, 0x28ec8804, 0x28ed0804, 0x28ed8804,
+    0x28ee0804, 0x28ee8804, 0x28ef0804, 0x28ef8804, 0x28f00804, 0x28f08804, 0x28f10804, 0x28f18804,
+    0x28f20804, 0x28f28804, 0x28f30804, 0x28f38804, 0x28f40804, 0x28f48804, 0x28f50804, 0x28f58804,
+    0x28f60804, 0x28f68804, 0x28f70804, 0x28f78804, 0x28f80804, 0x28f88804, 0x28f90804, 0x28f98804,
+    0x28fa0804, 0x28fa8804, 0x28fb0804, 0x28fb8804, 0x28fc0804, 0x28fc8804, 0x28fd0804, 0x28fd8804,
+    0x28fe0804, 0x28fe8804, 0x28ff0804, 0x28ff8804, 0x29000804, 0x29008804, 0x29010804, 0x29018804,
+    0x29020804, 0x29028804, 0x29030804, 0x29038804, 0x29040804, 0x29048804, 0x29050804, 0x29058804,
+    0x29060804, 0x29068804, 0x29070804, 0x29078804, 0x29080804, 0x29088804, 0x29090804, 0x29098804,
+    0x290a0804, 0x290a8804, 0x290b0804, 0x290b8804, 0x290c0804, 0x290c8804, 0x290d0804, 0x290d8804,
+    0x290e0804, 0x290e8804, 0x290f0804, 0x290f8804, 0x29100804, 0x29108804, 0x29110804, 0x29118804,
+    0x29120804, 0x29128804, 0x29130804, 0x29138804, 0x29140804, 0x29148804, 0x29150804, 0x29158804,
+    0x29160804, 0x29168804, 0x29170804, 0x29178804, 0x29180804, 0x29188804, 0x29190804, 0x29198804,
+    0x291a0804, 0x291a8804, 0x291b0804, 0x291b8804, 0x291c0804, 0x291c8804, 0x291d0804, 0x291d8804,
+    0x291e0804, 0x291e8804, 0x291f0804, 0x291f8804, 0x29200804, 0x29208804, 0x29210804, 0x29218804,
+    0x29220804, 0x29228804, 0x29230804, 0x29238804, 0x29240804, 0x29248804, 0x29250804, 0x29258804,
+    0x29260804, 0x29268804, 0x29270804, 0x29278804, 0x29280804, 0x29288804, 0x29290804, 0x29298804,
+    0x292a0804, 0x292a8804, 0x292b0804, 0x292b8804, 0x292c0804, 0x292c8804, 0x292d0804, 0x292d8804,
+    0x292e0804, 0x292e8804, 0x292f0804, 0x292f8804, 0x29300804, 0x29308804, 0x29310804, 0x29318804,
+    0x29320804, 0x29328804, 0x29330804, 0x29338804, 0x29340804, 0x29348804, 0x29350804, 0x29358804,
+    0x29360804, 0x29368804, 0x29370804, 0x29378804, 0x29380804, 0x29388804, 0x29390804, 0x29398804,
+    0x293a0804, 0x293a8804, 0x293b0804, 0x293b8804, 0x293c0804, 0x293c8804, 0x293d0804, 0x293d8804,
+    0x293e0804, 0x293e8804, 0x293f0804, 0x293f8804, 0x29400804, 0x29408804, 0x29410804, 0x29418804,
+    0x29420804, 0x29428804, 0x29430804, 0x29438804, 0x29440804, 0x29448804, 0x29450804, 0x29458804,
+    0x29460804, 0x29468804, 0x29470804, 0x29478804, 0x29480804, 0x29488804, 0x29490804, 0x29498804,
+    0x294a0804, 0x294a8804, 0x294b0804, 0x294b8804, 0x294c0804, 0x294c8804, 0x294d0804, 0x294d8804,
+    0x294e0804, 0x294e8804, 0x294f0804, 0x294f8804, 0x29500804, 0x29508804, 0x29510804, 0x29518804,
+    0x29520804, 0x29528804, 0x29530804, 0x29538804, 0x29540804, 0x29548804, 0x29550804, 0x29558804,
+    0x29560804, 0x29568804, 0x29570804, 0x29578804, 0x29580804, 0x29588804, 0x29590804, 0x29598804,
+    0x295a0804, 0x295a8804, 0x295b0804, 0x295b8804, 0x295c0804, 0x295c8804, 0x295d0804, 0x295d8804,
+    0x295e0804, 0x295e8804, 0x295f0804, 0x295f8804, 0x29600804, 0x29608804, 0x29610804, 0x29618804,
+    0x29620804, 0x29628804, 0x29630804, 0x29638804, 0x29640804, 0x29648804, 0x29650804, 0x29658804,
+    0x29660804, 0x29668804, 0x29670804, 0x29678804, 0x29680804, 0x29688804, 0x29690804, 0x29698804,
+    0x296a0804, 0x296a8804, 0x296b0804, 0x296b8804, 0x296c0804, 0x296c8804, 0x296d0804, 0x296d8804,
+    0x296e0804, 0x296e8804, 0x296f0804, 0x296f8804, 0x29700804, 0x29708804, 0x29710804, 0x29718804,
+    0x29720804, 0x29728804, 0x29730804, 0x29738804, 0x29740804, 0x29748804, 0x29750804, 0x29758804,
+    0x29760804, 0x29768804, 0x29770804, 0x29778804, 0x29780804, 0x29788804, 0x29790804, 0x29798804,
+    0x297a0804, 0x297a8804, 0x297b0804, 0x297b8804, 0x297c0804, 0x297c8804, 0x297d0804, 0x297d8804,
+    0x297e0804, 0x297e8804, 0x297f0804, 0x297f8804, 0x29800804, 0x29808804, 0x29810804, 0x29818804,
+    0x29820804, 0x29828804, 0x29830804, 0x29838804, 0x29840804, 0x29848804, 0x29850804, 0x29858804,
+    0x29860804, 0x29868804, 0x29870804, 0x29878804, 0x29880804, 0x29888804, 0x29890804, 0x29898804,
+    0x298a0804, 0x298a8804, 0x298b0804, 0x298b8804, 0x298c0804, 0x298c8804, 0x298d0804, 0x298d8804,
+    0x298e0804, 0x298e8804, 0x298f0804, 0x298f8804, 0x29900804, 0x29908804, 0x29910804, 0x29918804,
+    0x29920804, 0x29928804, 0x29930804, 0x29938804, 0x29940804, 0x29948804, 0x29950804, 0x29958804,
+    0x29960804, 0x29968804, 0x29970804, 0x29978804, 0x29980804, 0x29988804, 0x29990804, 0x29998804,
+    0x299a0804, 0x299a8804, 0x299b0804, 0x299b8804, 0x299c0804, 0x299c8804, 0x299d0804, 0x299d8804,
+    0x299e0804, 0x299e8804, 0x299f0804, 0x299f8804, 0x29a00804, 0x29a08804, 0x29a10804, 0x29a18804,
+    0x29a20804, 0x29a28804, 0x29a30804, 0x29a38804, 0x29a40804, 0x29a48804, 0x29a50804, 0x29a58804,
+    0x29a60804, 0x29a68804, 0x29a70804, 0x29a78804, 0x29a80804, 0x29a88804, 0x29a90804, 0x29a98804,
+    0x29aa0804, 0x29aa8804, 0x29ab0804, 0x29ab8804, 0x29ac0804, 0x29ac8804, 0x29ad0804, 0x29ad8804,
+    0x29ae0804, 0x29ae8804, 0x29af0804, 0x29af8804, 0x29b00804, 0x29b08804, 0x29b10804, 0x29b18804,
+    0x29b20804, 0x29b28804, 0x29b30804, 0x29b38804, 0x29b40804, 0x29b48804, 0x29b50804, 0x29b58804,
+    0x29b60804, 0x29b68804, 0x29b70804, 0x29b78804, 0x29b80804, 0x29b88804, 0x29b90804, 0x29b98804,
+    0x29ba0804, 0x29ba8804, 0x29bb0804, 0x29bb8804, 0x29bc0804, 0x29bc8804, 0x29bd0804, 0x29bd8804,
+    0x29be0804, 0x29be8804, 0x29bf0804, 0x29bf8804, 0x29c00804, 0x29c08804, 0x29c10804, 0x29c18804,
+    0x29c20804, 0x29c28804, 0x29c30804, 0x29c38804, 0x29c40804, 0x29c48804, 0x29c50804, 0x29c58804,
+    0x29c60804, 0x29c68804, 0x29c70804, 0x29c78804, 0x29c80804, 0x29c88804, 0x29c90804, 0x29c98804,
+    0x29ca0804, 0x29ca8804, 0x29cb0804, 0x29cb8804, 0x29cc0804, 0x29cc8804, 0x29cd0804, 0x29cd8804,
+    0x29ce0804, 0x29ce8804, 0x29cf0804, 0x29cf8804, 0x29d00804, 0x29d08804, 0x29d10804, 0x29d18804,
+    0x29d20804, 0x29d28804, 0x29d30804, 0x29d38804, 0x29d40804, 0x29d48804, 0x29d50804, 0x29d58804,
+    0x29d70804, 0x29d78804, 0x29d80804, 0x29d88804, 0x29d90804, 0x29d98804, 0x29da0804, 0x29da8804,
+    0x29db0804, 0x29db8804, 0x29dc0804, 0x29dc8804, 0x29dd0804, 0x29dd8804, 0x29de0804, 0x29de8804,
+    0x29df0804, 0x29df8804, 0x29e00804, 0x29e08804, 0x29e10804, 0x29e18804, 0x29e20804, 0x29e28804,
+    0x29e30804, 0x29e38804, 0x29e40804, 0x29e48804, 0x29e50804, 0x29e58804, 0x29e60804, 0x29e68804,
+    0x29e70804, 0x29e78804, 0x29e80804, 0x29e88804, 0x29e90804, 0x29e98804, 0x29ea0804, 0x29ea8804,
+    0x29eb0804, 0x29eb8804, 0x29ec0804, 0x29ec8804, 0x29ed0804, 0x29ed8804, 0x29ee0804, 0x29ee8804,
+    0x29ef0804, 0x29ef8804, 0x29f00804, 0x29f08804, 0x29f10804, 0x29f18804, 0x29f20804, 0x29f28804,
+    0x29f30804, 0x29f38804, 0x29f40804, 0x29f48804, 0x29f50804, 0x29f58804, 0x29f60804, 0x29f68804,
+    0x29f70804, 0x29f78804, 0x29f80804, 0x29f88804, 0x29f90804, 0x29f98804, 0x29fa0804, 0x29fa8804,
+    0x29fb0804, 0x29fb8804, 0x29fc0804, 0x29fc8804, 0x29fd0804, 0x29fd8804, 0x29fe0804, 0x29fe8804,
+    0x29ff0804, 0x29ff8804, 0x2a000804, 0x2a008804, 0x2a010804, 0x2a018804, 0x2a020804, 0x2a028804,
+    0x2a030804, 0x2a038804, 0x2a040804, 0x2a048804, 0x2a050804, 0x2a058804, 0x2a060804, 0x2a068804,
+    0x2a070804, 0x2a078804, 0x2a080804, 0x2a088804, 0x2a090804, 0x2a098804, 0x2a0a0804, 0x2a0a8804,
+    0x2a0b0804, 0x2a0b8804, 0x2a0c0804, 0x2a0c8804, 0x2a0d0804, 0x2a0d8804, 0x2a0e0804, 0x2a0e8804,
+    0x2a0f0804, 0x29d60804, 0x29d68804, 0x2a0f8804, 0x2a100804, 0x2a108804, 0x2a110804, 0x2a118804,
+    0x2a120804, 0x2a128804, 0x2a130804, 0x2a138804, 0x2a140804, 0x2a148804, 0x2a150804, 0x2a158804,
+    0x2a160804, 0x2a168804, 0x2a170804, 0x2a178804, 0x2a180804, 0x2a188804, 0x2a190804, 0x2a198804,
+    0x2a1a0804, 0x2a1a8804, 0x2a1b0804, 0x2a1b8804, 0x2a1c0804, 0x2a1c8804, 0x2a1d0804, 0x2a1d8804,
+    0x2a1e0804, 0x2a1e8804, 0x2a1f0804, 0x2a1f8804, 0x2a200804, 0x2a208804, 0x2a210804, 0x2a218804,
+    0x2a220804, 0x2a228804, 0x2a230804, 0x2a238804, 0x2a240804, 0x2a248804, 0x2a250804, 0x2a258804,
+    0x2a260804, 0x2a268804, 0x2a270804, 0x2a278804, 0x2a280804, 0x2a288804, 0x2a290804, 0x2a298804,
+    0x2a2a0804, 0x2a2a8804, 0x2a2b0804, 0x2a2b8804, 0x2a2c0804, 0x2a2c8804, 0x2a2d0804, 0x2a2d8804,
+    0x2a2e0804, 0x2a2e8804, 0x2a2f0804, 0x2a2f8804, 0x2a300804, 0x2a308804, 0x2a310804, 0x2a318804,
+    0x2a320804, 0x2a328804, 0x2a330804, 0x2a338804, 0x2a340804, 0x2a348804, 0x2a350804, 0x2a358804,
+    0x2a360804, 0x2a368804, 0x2a370804, 0x2a378804, 0x2a380804, 0x2a388804, 0x2a390804, 0x2a398804,
+    0x2a3a0804, 0x2a3a8804, 0x2a3b0804, 0x2a3b8804, 0x2a3c0804, 0x2a3c8804, 0x2a3d0804, 0x2a3d8804,
+    0x2a3e0804, 0x2a3e8804, 0x2a3f0804, 0x2a3f8804, 0x2a400804, 0x2a408804, 0x2a410804, 0x2a418804,
+    0x2a420804, 0x2a428804, 0x2a430804, 0x2a438804, 0x2a440804, 0x2a448804, 0x2a450804, 0x2a458804,
+    0x2a460804, 0x2a468804, 0x2a470804, 0x2a478804, 0x2a480804, 0x2a488804, 0x2a490804, 0x2a498804,
+    0x2a4a0804, 0x2a4a8804, 0x2a4b0804, 0x2a4b8804, 0x2a4c0804, 0x2a4c8804, 0x2a4d0804, 0x2a4d8804,
+    0x2a4e0804, 0x2a4e8804, 0x2a4f0804, 0x2a4f8804, 0x2a500804, 0x2a508804, 0x2a510804, 0x2a518804,
+    0x2a520804, 0x2a528804, 0x2a530804, 0x2a538804, 0x2a540804, 0x2a548804, 0x2a550804, 0x2a558804,
+    0x2a560804, 0x2a568804, 0x2a570804, 0x2a578804, 0x2a580804, 0x2a588804, 0x2a590804, 0x2a598804,
+    0x2a5a0804, 0x2a5a8804, 0x2a5b0804, 0x2a5b8804, 0x2a5c0804, 0x2a5c8804, 0x2a5d0804, 0x2a5d8804,
+    0x2a5e0804, 0x2a5e8804, 0x2a5f0804, 0x2a5f8804, 0x2a600804, 0x2a608804, 0x2a610804, 0x2a618804,
+    0x2a620804, 0x2a628804, 0x2a630804, 0x2a638804, 0x2a640804, 0x2a648804, 0x2a650804, 0x2a658804,
+    0x2a660804, 0x2a668804, 0x2a670804, 0x2a678804, 0x2a680804, 0x2a688804, 0x2a690804, 0x2a698804,
+    0x2a6a0804, 0x2a6a8804, 0x2a6b0804, 0x2a6b8804, 0x2a6c0804, 0x2a6c8804, 0x2a6d0804, 0x2a6d8804,
+    0x2a6e0804, 0x2a6e8804, 0x2a6f0804, 0x2a6f8804, 0x2a700804, 0x2a708804, 0x2a710804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcd0804, 0xfcd8804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804,
+    0xfcf8804, 0xfd00804, 0xfd08804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xf960805, 0xf968805, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcd8804, 0xfcd8804, 0xfce0804, 0xfce0804, 0xfce0804, 0xfce0804, 0xfcf0804,
+    0xfcf8804, 0xfcf8804, 0xfcf8804, 0xfd00804, 0xfd00804, 0xfd08804, 0xfd08804, 0xfd08804,
+    0xfd08804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce0804, 0xfce8804, 0xfce8804, 0xfcd0804, 0xfcd8804, 0xfcc8804,
+    0xfcd0804, 0xf970805, 0xf978805, 0xf980805, 0xf988805, 0xf990805, 0xf998805, 0xf9a0805,
+    0xf9a8805, 0xf9b0805, 0xf9b8805, 0xf9c0805, 0xf9c8805, 0xf9d0805, 0xf9d8805, 0xf9e0805,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1888805, 0x1890805,
+    0x1898805, 0x18a0805, 0x18a8805, 0x2a718804, 0x2a720804, 0x2a728804, 0x2a730804, 0x2a738804,
+    0x2a740804, 0x2a748804, 0x2a750804, 0x2a758804, 0x2a760804, 0x2a768804, 0x2a770804, 0x2a778804,
+    0x2a780804, 0x2a788804, 0x2a790804, 0x2a798804, 0x2a7a0804, 0x2a7a8804, 0x2a7b0804, 0x2a7b8804,
+    0x2a7c0804, 0x2a7c8804, 0x2a7d0804, 0x2a7d8804, 0x2a7e0804, 0x2a7e8804, 0x2a7f0804, 0x2a7f8804,
+    0x2a800804, 0x2a808804, 0x2a810804, 0x2a818804, 0x2a820804, 0x2a828804, 0x2a830804, 0x2a838804,
+    0x2a840804, 0x2a848804, 0x2a850804, 0x2a858804, 0x2a860804, 0x2a868804, 0x2a870804, 0x2a878804,
+    0x2a880804, 0x2a888804, 0x2a890804, 0x2a898804, 0x2a8a0804, 0x2a8a8804, 0x2a8b0804, 0x2a8b8804,
+    0x2a8c0804, 0x2a8c8804, 0x2a8d0804, 0x2a8d8804, 0x2a8e0804, 0x2a8e8804, 0x2a8f0804, 0x2a8f8804,
+    0x2a900804, 0x2a908804, 0x2a910804, 0x2a918804, 0x2a920804, 0x2a928804, 0x2a930804, 0x2a938804,
+    0x2a940804, 0x2a948804, 0x2a950804, 0x2a958804, 0x2a960804, 0x2a968804, 0x2a970804, 0x2a978804,
+    0x2a980804, 0x2a988804, 0x2a990804, 0x2a998804, 0x2a9a0804, 0x2a9a8804, 0x2a9b0804, 0x2a9b8804,
+    0x2a9c0804, 0x2a9c8804, 0x2a9d0804, 0x2a9d8804, 0x2a9e0804, 0x2a9e8804, 0x2a9f0804, 0x2a9f8804,
+    0x2aa00804, 0x2aa08804, 0x2aa10804, 0x2aa18804, 0x2aa20804, 0x2aa28804, 0x2aa30804, 0x2aa38804,
+    0x2aa40804, 0x2aa48804, 0x2aa50804, 0x2aa58804, 0x2aa60804, 0x2aa68804, 0x2aa70804, 0x2aa78804,
+    0x2aa80804, 0x2aa88804, 0x2aa90804, 0x2aa98804, 0x2aaa0804, 0x2aaa8804, 0x2aab0804, 0x2aab8804,
+    0x2aac0804, 0x2aac8804, 0x2aad0804, 0x2aad8804, 0x2aae0804, 0x2aae8804, 0x2aaf0804, 0x2aaf8804,
+    0x2ab00804, 0x2ab08804, 0x2ab10804, 0x2ab18804, 0x2ab20804, 0x2ab28804, 0x2ab30804, 0x2ab38804,
+    0x2ab40804, 0x2ab48804, 0x2ab50804, 0x2ab58804, 0x2ab60804, 0x2ab68804, 0x2ab70804, 0x2ab78804,
+    0x2ab80804, 0x2ab88804, 0x2ab90804, 0x2ab98804, 0x2aba0804, 0x2aba8804, 0x2abb0804, 0x2abb8804,
+    0x2abc0804, 0x2abc8804, 0x2abd0804, 0x2abd8804, 0x2abe0804, 0x2abe8804, 0x2abf0804, 0x2abf8804,
+    0x2ac00804, 0x2ac08804, 0x2ac10804, 0x2ac18804, 0x2ac20804, 0x2ac28804, 0x2ac30804, 0x2ac38804,
+    0x2ac40804, 0x2ac48804, 0x2ac50804, 0x2ac58804, 0x2ac60804, 0x2ac68804, 0x2ac70804, 0x2ac78804,
+    0x2ac80804, 0x2ac88804, 0x2ac90804, 0x2ac98804, 0x2aca0804, 0x2aca8804, 0x2acb0804, 0x2acb8804,
+    0x2acc0804, 0x2acc8804, 0x2acd0804, 0x2acd8804, 0x2ace0804, 0x2ace8804, 0x2acf0804, 0x2acf8804,
+    0x2ad00804, 0x2ad08804, 0x2ad10804, 0x2ad18804, 0x2ad20804, 0x2ad28804, 0x2ad30804, 0x2ad38804,
+    0x2ad40804, 0x2ad48804, 0x2ad50804, 0x2ad58804, 0x2ad60804, 0x2ad68804, 0x2ad70804, 0x2ad78804,
+    0x2ad80804, 0x2ad88804, 0x2ad90804, 0x2ad98804, 0x2ada0804, 0x2ada8804, 0x2adb0804, 0x2adb8804,
+    0x2adc0804, 0x2adc8804, 0x2add0804, 0x2add8804, 0x2ade0804, 0x2ade8804, 0x2adf0804, 0x2adf8804,
+    0x2ae00804, 0x2ae08804, 0x2ae10804, 0x2ae18804, 0x2ae20804, 0x2ae28804, 0x2ae30804, 0x2ae38804,
+    0x2ae40804, 0x2ae48804, 0x2ae50804, 0x2ae58804, 0x2ae60804, 0x2ae68804, 0x2ae70804, 0x2ae78804,
+    0x2ae80804, 0x2ae88804, 0x2ae90804, 0x2ae98804, 0x2aea0804, 0x2aea8804, 0x2aeb0804, 0x2aeb8804,
+    0x2aec0804, 0x2aec8804, 0x2aed0804, 0x2aed8804, 0x2aee0804, 0x2aee8804, 0x2aef0804, 0x2aef8804,
+    0x2af00804, 0x2af08804, 0x2af10804, 0x2af18804, 0x2af20804, 0x2af28804, 0x2af30804, 0x2af38804,
+    0x2af40804, 0x2af48804, 0x2af50804, 0x2af58804, 0x2af60804, 0x2af68804, 0x2af70804, 0x2af78804,
+    0x2af80804, 0x2af88804, 0x2af90804, 0x2af98804, 0x2afa0804, 0x2afa8804, 0x2afb0804, 0x2afb8804,
+    0x2afc0804, 0x2afc8804, 0x2afd0804, 0x2afd8804, 0x2afe0804, 0x2afe8804, 0x2aff0804, 0x2aff8804,
+    0x2b000804, 0x2b008804, 0x2b010804, 0x2b018804, 0x2b020804, 0x2b028804, 0x2b030804, 0x2b038804,
+    0x2b040804, 0x2b048804, 0x2b050804, 0x2b058804, 0x2b060804, 0x2b068804, 0x2b070804, 0x2b078804,
+    0x2b080804, 0x2b088804, 0x2b090804, 0x2b098804, 0x2b0a0804, 0x2b0a8804, 0x2b0b0804, 0x2b0b8804,
+    0x2b0c0804, 0x2b0c8804, 0x2b0d0804, 0x2b0d8804, 0x2b0e0804, 0x2b0e8804, 0x2b0f0804, 0x2b0f8804,
+    0x2b100804, 0x2b108804, 0x2b110804, 0x2b118804, 0x2b120804, 0x2b128804, 0x2b130804, 0x2b138804,
+    0x2b140804, 0x2b148804, 0x2b150804, 0x2b158804, 0x2b160804, 0x2b168804, 0x2b170804, 0x2b178804,
+    0x2b180804, 0x2b188804, 0x2b190804, 0x2b198804, 0x2b1a0804, 0x2b1a8804, 0x2b1b0804, 0x2b1b8804,
+    0x2b1c0804, 0x2b1c8804, 0x2b1d0804, 0x2b1d8804, 0x2b1e0804, 0x2b1e8804, 0x2b1f0804, 0x2b1f8804,
+    0x2b200804, 0x2b208804, 0x2b210804, 0x2b218804, 0x2b220804, 0x2b228804, 0x2b230804, 0x2b238804,
+    0x2b240804, 0x2b248804, 0x2b250804, 0x2b258804, 0x2b260804, 0x2b268804, 0x2b270804, 0x2b278804,
+    0x2b280804, 0x2b288804, 0x2b290804, 0x2b298804, 0x2b2a0804, 0x2b2a8804, 0x2b2b0804, 0x2b2b8804,
+    0x2b2c0804, 0x2b2c8804, 0x2b2d0804, 0x2b2d8804, 0x2b2e0804, 0x2b2e8804, 0x2b2f0804, 0x2b2f8804,
+    0x2b300804, 0x2b308804, 0x2b310804, 0x2b318804, 0x2b320804, 0x2b328804, 0x2b330804, 0x2b338804,
+    0x2b340804, 0x2b348804, 0x2b350804, 0x2b358804, 0x2b360804, 0x2b368804, 0x2b370804, 0x2b378804,
+    0x2b380804, 0x2b388804, 0x2b390804, 0x2b398804, 0x2b3a0804, 0x2b3a8804, 0x2b3b0804, 0x2b3b8804,
+    0x2b3c0804, 0x2b3c8804, 0x2b3d0804, 0x2b3d8804, 0x2b3e0804, 0x2b3e8804, 0x2b3f0804, 0x2b3f8804,
+    0x2b400804, 0x2b408804, 0x2b410804, 0x2b418804, 0x2b420804, 0x2b428804, 0x2b430804, 0x2b438804,
+    0x2b440804, 0x2b448804, 0x2b450804, 0x2b458804, 0x2b460804, 0x2b468804, 0x2b470804, 0x2b478804,
+    0x2b480804, 0x2b488804, 0x2b490804, 0x2b498804, 0x2b4a0804, 0x2b4a8804, 0x2b4b0804, 0x2b4b8804,
+    0x2b4c0804, 0x2b4c8804, 0x2b4d0804, 0x2b4d8804, 0x2b4e0804, 0x2b4e8804, 0x2b4f0804, 0x2b4f8804,
+    0x2b500804, 0x2b508804, 0x2b510804, 0x2b518804, 0x2b520804, 0x2b528804, 0x2b530804, 0x2b538804,
+    0x2b540804, 0x2b548804, 0x2b550804, 0x2b558804, 0x2b560804, 0x2b568804, 0x2b570804, 0x2b578804,
+    0x2b580804, 0x2b588804, 0x2b590804, 0x2b598804, 0x2b5a0804, 0x2b5a8804, 0x2b5b0804, 0x2b5b8804,
+    0x2b5c0804, 0x2b5c8804, 0x2b5d0804, 0x2b5d8804, 0x2b5e0804, 0x2b5e8804, 0x2b5f0804, 0x2b5f8804,
+    0x2b600804, 0x2b608804, 0x2b610804, 0x2b618804, 0x2b620804, 0x2b628804, 0x2b630804, 0x2b638804,
+    0x2b640804, 0x2b648804, 0x2b650804, 0x2b658804, 0x2b660804, 0x2b668804, 0x2b670804, 0x2b678804,
+    0x2b680804, 0x2b688804, 0x2b690804, 0x2b698804, 0x2b6a0804, 0x2b6a8804, 0x2b6b0804, 0x2b6b8804,
+    0x2b6c0804, 0x2b6c8804, 0x2b6d0804, 0x2b6d8804, 0x2b6e0804, 0x2b6e8804, 0x2b6f0804, 0x2b6f8804,
+    0x2b700804, 0x2b708804, 0x2b710804, 0x2b718804, 0x2b720804, 0x2b728804, 0x2b730804, 0x2b738804,
+    0x2b740804, 0x2b748804, 0x2b750804, 0x2b758804, 0x2b760804, 0x2b768804, 0x2b770804, 0x2b778804,
+    0x2b780804, 0x2b788804, 0x2b790804, 0x2b798804, 0x2b7a0804, 0x2b7a8804, 0x2b7b0804, 0x2b7b8804,
+    0x2b7c0804, 0x2b7c8804, 0x2b7d0804, 0x2b7d8804, 0x2b7e0804, 0x2b7e8804, 0x2b7f0804, 0x2b7f8804,
+    0x2b800804, 0x2b808804, 0x2b810804, 0x2b818804, 0x2b820804, 0x2b828804, 0x2b830804, 0x2b838804,
+    0x2b840804, 0x2b848804, 0x2b850804, 0x2b858804, 0x2b860804, 0x2b868804, 0x2b870804, 0x2b878804,
+    0x2b880804, 0x2b888804, 0x2b890804, 0x2b898804, 0x2b8a0804, 0x2b8a8804, 0x2b8b0804, 0x2b8b8804,
+    0x2b8c0804, 0x2b8c8804, 0x2b8d0804, 0x2b8d8804, 0x2b8e0804, 0x2b8e8804, 0x2b8f0804, 0x2b8f8804,
+    0x2b900804, 0x2b908804, 0x2b910804, 0x2b918804, 0x2b920804, 0x2b928804, 0x2b930804, 0x2b938804,
+    0x2b940804, 0x2b948804, 0x2b950804, 0x2b958804, 0x2b960804, 0x2b968804, 0x2b970804, 0x2b978804,
+    0x2b980804, 0x2b988804, 0x2b990804, 0x2b998804, 0x2b9a0804, 0x2b9a8804, 0x2b9b0804, 0x2b9b8804,
+    0x2b9c0804, 0x2b9c8804, 0x2b9d0804, 0x2b9d8804, 0x2b9e0804, 0x2b9e8804, 0x2b9f0804, 0x2b9f8804,
+    0x2ba00804, 0x2ba08804, 0x2ba10804, 0x2ba18804, 0x2ba20804, 0x2ba28804, 0x2ba30804, 0x2ba38804,
+    0x2ba40804, 0x2ba48804, 0x2ba50804, 0x2ba58804, 0x2ba60804, 0x2ba68804, 0x2ba70804, 0x2ba78804,
+    0x2ba80804, 0x2ba88804, 0x2ba90804, 0x2ba98804, 0x2baa0804, 0x2baa8804, 0x2bab0804, 0x2bab8804,
+    0x2bac0804, 0x2bac8804, 0x2bad0804, 0x2bad8804, 0x2bae0804, 0x2bae8804, 0x2baf0804, 0x2baf8804,
+    0x2bb00804, 0x2bb08804, 0x2bb10804, 0x2bb18804, 0x2bb20804, 0x2bb28804, 0x2bb30804, 0x2bb38804,
+    0x2bb40804, 0x2bb48804, 0x2bb50804, 0x2bb58804, 0x2bb60804, 0x2bb68804, 0x2bb70804, 0x2bb78804,
+    0x2bb80804, 0x2bb88804, 0x2bb90804, 0x2bb98804, 0x2bba0804, 0x2bba8804, 0x2bbb0804, 0x2bbb8804,
+    0x2bbc0804, 0x2bbc8804, 0x2bbd0804, 0x2bbd8804, 0x2bbe0804, 0x2bbe8804, 0x2bbf0804, 0x2bbf8804,
+    0x2bc00804, 0x2bc08804, 0x2bc10804, 0x2bc18804, 0x2bc20804, 0x2bc28804, 0x2bc30804, 0x2bc38804,
+    0x2bc40804, 0x2bc48804, 0x2bc50804, 0x2bc58804, 0x2bc60804, 0x2bc68804, 0x2bc70804, 0x2bc78804,
+    0x2bc80804, 0x2bc88804, 0x2bc90804, 0x2bc98804, 0x2bca0804, 0x2bca8804, 0x2bcb0804, 0x2bcb8804,
+    0x2bcc0804, 0x2bcc8804, 0x2bcd0804, 0x2bcd8804, 0x2bce0804, 0x2bce8804, 0x2bcf0804, 0x2bcf8804,
+    0x2bd00804, 0x2bd08804, 0x2bd10804, 0x2bd18804, 0x2bd20804, 0x2bd28804, 0x2bd30804, 0x2bd38804,
+    0x2bd40804, 0x2bd48804, 0x2bd50804, 0x2bd58804, 0x2bd60804, 0x2bd68804, 0x2bd70804, 0x2bd78804,
+    0x2bd80804, 0x2bd88804, 0x2bd90804, 0x2bd98804, 0x2bda0804, 0x2bda8804, 0x2bdb0804, 0x2bdb8804,
+    0x2bdc0804, 0x2bdc8804, 0x2bdd0804, 0x2bdd8804, 0x2bde0804, 0x2bde8804, 0x2bdf0804, 0x2bdf8804,
+    0x2be00804, 0x2be08804, 0x2be10804, 0x2be18804, 0x2be20804, 0x2be28804, 0x2be30804, 0x2be38804,
+    0x2be40804, 0x2be48804, 0x2be50804, 0x2be58804, 0x2be60804, 0x2be68804, 0x2be70804, 0x2be78804,
+    0x2be80804, 0x2be88804, 0x2be90804, 0x2be98804, 0x2bea0804, 0x2bea8804, 0x2beb0804, 0x2beb8804,
+    0x2bec0804, 0x2bec8804, 0x2bed0804, 0x2bed8804, 0x2bee0804, 0x2bee8804, 0x2bef0804, 0x2bef8804,
+    0x2bf00804, 0x2bf08804, 0x2bf10804, 0x2bf18804, 0x2bf20804, 0x2bf28804, 0x2bf30804, 0x2bf38804,
+    0x2bf40804, 0x2bf48804, 0x2bf50804, 0x2bf58804, 0x2bf60804, 0x2bf68804, 0x2bf70804, 0x2bf78804,
+    0x2bf80804, 0x2bf88804, 0x2bf90804, 0x2bf98804, 0x2bfa0804, 0x2bfa8804, 0x2bfb0804, 0x2bfb8804,
+    0x2bfc0804, 0x2bfc8804, 0x2bfd0804, 0x2bfd8804, 0x2bfe0804, 0x2bfe8804, 0x2bff0804, 0x2bff8804,
+    0x2c000804, 0x2c008804, 0x2c010804, 0x2c018804, 0x2c020804, 0x2c028804, 0x2c030804, 0x2c038804,
+    0x2c040804, 0x2c048804, 0x2c050804, 0x2c058804, 0x2c060804, 0x2c068804, 0x2c070804, 0x2c078804,
+    0x2c080804, 0x2c088804, 0x2c090804, 0x2c098804, 0x2c0a0804, 0x2c0a8804, 0x2c0b0804, 0x2c0b8804,
+    0x2c0c0804, 0x2c0c8804, 0x2c0d0804, 0x2c0d8804, 0x2c0e0804, 0x2c0e8804, 0x2c0f0804, 0x2c0f8804,
+    0x2c100804, 0x2c108804, 0x2c110804, 0x2c118804, 0x2c120804, 0x2c128804, 0x2c130804, 0x2c138804,
+    0x2c140804, 0x2c148804, 0x2c150804, 0x2c158804, 0x2c160804, 0x2c168804, 0x2c170804, 0x2c178804,
+    0x2c180804, 0x2c188804, 0x2c190804, 0x2c198804, 0x2c1a0804, 0x2c1a8804, 0x2c1b0804, 0x2c1b8804,
+    0x2c1c0804, 0x2c1c8804, 0x2c1d0804, 0x2c1d8804, 0x2c1e0804, 0x2c1e8804, 0x2c1f0804, 0x2c1f8804,
+    0x2c200804, 0x2c208804, 0x2c210804, 0x2c218804, 0x2c220804, 0x2c228804, 0x2c230804, 0x2c238804,
+    0x2c240804, 0x2c248804, 0x2c250804, 0x2c258804, 0x2c260804, 0x2c268804, 0x2c270804, 0x2c278804,
+    0x2c280804, 0x2c288804, 0x2c290804, 0x2c298804, 0x2c2a0804, 0x2c2a8804, 0x2c2b0804, 0x2c2b8804,
+    0x2c2c0804, 0x2c2c8804, 0x2c2d0804, 0x2c2d8804, 0x2c2e0804, 0x2c2e8804, 0x2c2f0804, 0x2c2f8804,
+    0x2c300804, 0x2c308804, 0x2c310804, 0x2c318804, 0x2c320804, 0x2c328804, 0x2c330804, 0x2c338804,
+    0x2c340804, 0x2c348804, 0x2c350804, 0x2c358804, 0x2c360804, 0x2c368804, 0x2c370804, 0x2c378804,
+    0x2c380804, 0x2c388804, 0x2c390804, 0x2c398804, 0x2c3a0804, 0x2c3a8804, 0x2c3b0804, 0x2c3b8804,
+    0x2c3c0804, 0x2c3c8804, 0x2c3d0804, 0x2c3d8804, 0x2c3e0804, 0x2c3e8804, 0x2c3f0804, 0x2c3f8804,
+    0x2c400804, 0x2c408804, 0x2c410804, 0x2c418804, 0x2c420804, 0x2c428804, 0x2c430804, 0x2c438804,
+    0x2c440804, 0x2c448804, 0x2c450804, 0x2c458804, 0x2c460804, 0x2c468804, 0x2c470804, 0x2c478804,
+    0x2c480804, 0x2c488804, 0x2c490804, 0x2c498804, 0x2c4a0804, 0x2c4a8804, 0x2c4b0804, 0x2c4b8804,
+    0x2c4c0804, 0x2c4c8804, 0x2c4d0804, 0x2c4d8804, 0x2c4e0804, 0x2c4e8804, 0x2c4f0804, 0x2c4f8804,
+    0x2c500804, 0x2c508804, 0x2c510804, 0x2c518804, 0x2c520804, 0x2c528804, 0x2c530804, 0x2c538804,
+    0x2c540804, 0x2c548804, 0x2c550804, 0x2c558804, 0x2c560804, 0x2c568804, 0x2c570804, 0x2c578804,
+    0x2c580804, 0x2c588804, 0x2c590804, 0x2c598804, 0x2c5a0804, 0x2c5a8804, 0x2c5b0804, 0x2c5b8804,
+    0x2c5c0804, 0x2c5c8804, 0x2c5d0804, 0x2c5d8804, 0x2c5e0804, 0x2c5e8804, 0x2c5f0804, 0x2c5f8804,
+    0x2c600804, 0x2c608804, 0x2c610804, 0x2c618804, 0x2c620804, 0x2c628804, 0x2c630804, 0x2c638804,
+    0x2c640804, 0x2c648804, 0x2c650804, 0x2c658804, 0x2c660804, 0x2c668804, 0x2c670804, 0x2c678804,
+    0x2c680804, 0x2c688804, 0x2c690804, 0x2c698804, 0x2c6a0804, 0x2c6a8804, 0x2c6b0804, 0x2c6b8804,
+    0x2c6c0804, 0x2c6c8804, 0x2c6d0804, 0x2c6d8804, 0x2c6e0804, 0x2c6e8804, 0x2c6f0804, 0x2c6f8804,
+    0x2c700804, 0x2c708804, 0x2c710804, 0x2c718804, 0x2c720804, 0x2c728804, 0x2c730804, 0x2c738804,
+    0x2c740804, 0x2c748804, 0x2c750804, 0x2c758804, 0x2c760804, 0x2c768804, 0x2c770804, 0x2c778804,
+    0x2c780804, 0x2c788804, 0x2c790804, 0x2c798804, 0x2c7a0804, 0x2c7a8804, 0x2c7b0804, 0x2c7b8804,
+    0x2c7c0804, 0x2c7c8804, 0x2c7d0804, 0x2c7d8804, 0x2c7e0804, 0x2c7e8804, 0x2c7f0804, 0x2c7f8804,
+    0x2c800804, 0x2c808804, 0x2c810804, 0x2c818804, 0x2c820804, 0x2c828804, 0x2c830804, 0x2c838804,
+    0x2c840804, 0x2c848804, 0x2c850804, 0x2c858804, 0x2c860804, 0x2c868804, 0x2c870804, 0x2c878804,
+    0x2c880804, 0x2c888804, 0x2c890804, 0x2c898804, 0x2c8a0804, 0x2c8a8804, 0x2c8b0804, 0x2c8b8804,
+    0x2c8c0804, 0x2c8c8804, 0x2c8d0804, 0x2c8d8804, 0x2c8e0804, 0x2c8e8804, 0x2c8f0804, 0x2c8f8804,
+    0x2c900804, 0x2c908804, 0x2c910804, 0x2c918804, 0x2c920804, 0x2c928804, 0x2c930804, 0x2c938804,
+    0x2c940804, 0x2c948804, 0x2c950804, 0x2c958804, 0x2c960804, 0x2c968804, 0x2c970804, 0x2c978804,
+    0x2c980804, 0x2c988804, 0x2c990804, 0x2c998804, 0x2c9a0804, 0x2c9a8804, 0x2c9b0804, 0x2c9b8804,
+    0x2c9c0804, 0x2c9c8804, 0x2c9d0804, 0x2c9d8804, 0x2c9e0804, 0x2c9e8804, 0x2c9f0804, 0x2c9f8804,
+    0x2ca00804, 0x2ca08804, 0x2ca10804, 0x2ca18804, 0x2ca20804, 0x2ca28804, 0x2ca30804, 0x2ca38804,
+    0x2ca40804, 0x2ca48804, 0x2ca50804, 0x2ca58804, 0x2ca60804, 0x2ca68804, 0x2ca70804, 0x2ca78804,
+    0x2ca80804, 0x2ca88804, 0x2ca90804, 0x2ca98804, 0x2caa0804, 0x2caa8804, 0x2cab0804, 0x2cab8804,
+    0x2cac0804, 0x2cac8804, 0x2cad0804, 0x2cad8804, 0x2cae0804, 0x2cae8804, 0x2caf0804, 0x2caf8804,
+    0x2cb00804, 0x2cb08804, 0x2cb10804, 0x2cb18804, 0x2cb20804, 0x2cb28804, 0x2cb30804, 0x2cb38804,
+    0x2cb40804, 0x2cb48804, 0x2cb50804, 0x2cb58804, 0x2cb60804, 0x2cb68804, 0x2cb70804, 0x2cb78804,
+    0x2cb80804, 0x2cb88804, 0x2cb90804, 0x2cb98804, 0x2cba0804, 0x2cba8804, 0x2cbb0804, 0x2cbb8804,
+    0x2cbc0804, 0x2cbc8804, 0x2cbd0804, 0x2cbd8804, 0x2cbe0804, 0x2cbe8804, 0x2cbf0804, 0x2cbf8804,
+    0x2cc00804, 0x2cc08804, 0x2cc10804, 0x2cc18804, 0x2cc20804, 0x2cc28804, 0x2cc30804, 0x2cc38804,
+    0x2cc40804, 0x2cc48804, 0x2cc50804, 0x2cc58804, 0x2cc60804, 0x2cc68804, 0x2cc70804, 0x2cc78804,
+    0x2cc80804, 0x2cc88804, 0x2cc90804, 0x2cc98804, 0x2cca0804, 0x2cca8804, 0x2ccb0804, 0x2ccb8804,
+    0x2ccc0804, 0x2ccc8804, 0x2ccd0804, 0x2ccd8804, 0x2cce0804, 0x2cce8804, 0x2ccf0804, 0x2ccf8804,
+    0x2cd00804, 0x2cd08804, 0x2cd10804, 0x2cd18804, 0x2cd20804, 0x2cd28804, 0x2cd30804, 0x2cd38804,
+    0x2cd40804, 0x2cd48804, 0x2cd50804, 0x2cd58804, 0x2cd60804, 0x2cd68804, 0x2cd70804, 0x2cd78804,
+    0x2cd80804, 0x2cd88804, 0x2cd90804, 0x2cd98804, 0x2cda0804, 0x2cda8804, 0x2cdb0804, 0x2cdb8804,
+    0x2cdc0804, 0x2cdc8804, 0x2cdd0804, 0x2cdd8804, 0x2cde0804, 0x2cde8804, 0x2cdf0804, 0x2cdf8804,
+    0x2ce00804, 0x2ce08804, 0x2ce10804, 0x2ce18804, 0x2ce20804, 0x2ce28804, 0x2ce30804, 0x2ce38804,
+    0x2ce40804, 0x2ce48804, 0x2ce50804, 0x2ce58804, 0x2ce60804, 0x2ce68804, 0x2ce70804, 0x2ce78804,
+    0x2ce80804, 0x2ce88804, 0x2ce90804, 0x2ce98804, 0x2cea0804, 0x2cea8804, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x2cf88804, 0x2cf90804, 0x2cf98804, 0x2cfa0804, 0x2cfa8804,
+    0x2cfb0804, 0x2cfb8804, 0x2cfc0804, 0x2cfc8804, 0x2cfd0804, 0x2cfd8804, 0x2cfe0804, 0x2cfe8804,
+    0x2cff0804, 0x2cff8804, 0x2d000804, 0x2d008804, 0x2d010804, 0x2d018804, 0x2d020804, 0x2d028804,
+    0x2d030804, 0x2d038804, 0x2d040804, 0x2d048804, 0x2d050804, 0x2d058804, 0x2d060804, 0x2d068804,
+    0x2d070804, 0x2d078804, 0x2d080804, 0x2d088804, 0x2d090804, 0x2d098804, 0x2d0a0804, 0x2d0a8804,
+    0x2d0b0804, 0x2d0b8804, 0x2d0c0804, 0x2d0c8804, 0x2d0d0804, 0x2d0d8804, 0x2d0e0804, 0x2d0e8804,
+    0x2d0f0804, 0x2d0f8804, 0x2d100804, 0x2d108804, 0x2d110804, 0x2d118804, 0x2d120804, 0x2d128804,
+    0x2d130804, 0x2d138804, 0x2d140804, 0x2d148804, 0x2d150804, 0x2d158804, 0x2d160804, 0x2d168804,
+    0x2d170804, 0x2d178804, 0x2d180804, 0x2d188804, 0x2d190804, 0x2d198804, 0x2d1a0804, 0x2d1a8804,
+    0x2d1b0804, 0x2d1b8804, 0x2d1c0804, 0x2d1c8804, 0x2d1d0804, 0x2d1d8804, 0x2d1e0804, 0x2d1e8804,
+    0x2d1f0804, 0x2d1f8804, 0x2d200804, 0x2d208804, 0x2d210804, 0x2d218804, 0x2d220804, 0x2d228804,
+    0x2d230804, 0x2d238804, 0x2d240804, 0x2d248804, 0x2d250804, 0x2d258804, 0x2d260804, 0x2d268804,
+    0x2d270804, 0x2d278804, 0x2d280804, 0x2d288804, 0x2d290804, 0x2d298804, 0x2d2a0804, 0x2d2a8804,
+    0x2d2b0804, 0x2d2b8804, 0x2d2c0804, 0x2d2c8804, 0x2d2d0804, 0x2d2d8804, 0x2d2e0804, 0x2d2e8804,
+    0x2d2f0804, 0x2d2f8804, 0x2d300804, 0x2d308804, 0x2d310804, 0x2d318804, 0x2d320804, 0x2d328804,
+    0x2d330804, 0x2d338804, 0x2d340804, 0x2d348804, 0x2d350804, 0x2d358804, 0x2d360804, 0x2d368804,
+    0x2d370804, 0x2d378804, 0x2d380804, 0x2d388804, 0x2d390804, 0x2d398804, 0x2d3a0804, 0x2d3a8804,
+    0x2d3b0804, 0x2d3b8804, 0x2d3c0804, 0x2d3c8804, 0x2d3d0804, 0x2d3d8804, 0x2d3e0804, 0x2d3e8804,
+    0x2d3f0804, 0x2d3f8804, 0x2d400804, 0x2d408804, 0x2d410804, 0x2d418804, 0x2d420804, 0x2d428804,
+    0x2d430804, 0x2d438804, 0x2d440804, 0x2d448804, 0x2d450804, 0x2d458804, 0x2d460804, 0x2d468804,
+    0x2d470804, 0x2d478804, 0x2d480804, 0x2d488804, 0x2d490804, 0x2d498804, 0x2d4a0804, 0x2d4a8804,
+    0x2d4b0804, 0x2d4b8804, 0x2d4c0804, 0x2d4c8804, 0x2d4d0804, 0x2d4d8804, 0x2d4e0804, 0x2d4e8804,
+    0x2d4f0804, 0x2d4f8804, 0x2d500804, 0x2d508804, 0x2d510804, 0x2d518804, 0x2d520804, 0x2d528804,
+    0x2d530804, 0x2d538804, 0x2d540804, 0x2d548804, 0x2d550804, 0x2d558804, 0x2d560804, 0x2d568804,
+    0x2d570804, 0x2d578804, 0x2d580804, 0x2d588804, 0x2d590804, 0x2d598804, 0x2d5a0804, 0x2d5a8804,
+    0x2d5b0804, 0x2d5b8804, 0x2d5c0804, 0x2d5c8804, 0x2d5d0804, 0x2d5d8804, 0x2d5e0804, 0x2d5e8804,
+    0x2d5f0804, 0x2d5f8804, 0x2d600804, 0x2d608804, 0x2d610804, 0x2d618804, 0x2d620804, 0x2d628804,
+    0x2d630804, 0x2d638804, 0x2d640804, 0x2d648804, 0x2d650804, 0x2d658804, 0x2d660804, 0x2d668804,
+    0x2d670804, 0x2d678804, 0x2d680804, 0x2d688804, 0x2d690804, 0x2d698804, 0x2d6a0804, 0x2d6a8804,
+    0x2d6b0804, 0x2d6b8804, 0x2d6c0804, 0x2d6c8804, 0x2d6d0804, 0x2d6d8804, 0x2d6e0804, 0x2d6e8804,
+    0x2d6f0804, 0x2d6f8804, 0x2d700804, 0x2d708804, 0x2d710804, 0x2d718804, 0x2d720804, 0x2d728804,
+    0x2d730804, 0x2d738804, 0x2d740804, 0x2d748804, 0x2d750804, 0x2d758804, 0x2d760804, 0x2d768804,
+    0x2d770804, 0x2d778804, 0x2d780804, 0x2d788804, 0x2d790804, 0x2d798804, 0x2d7a0804, 0x2d7a8804,
+    0x2d7b0804, 0x2d7b8804, 0x2d7c0804, 0x2d7c8804, 0x2d7d0804, 0x2d7d8804, 0x2d7e0804, 0x2d7e8804,
+    0x2d7f0804, 0x2d7f8804, 0x2d800804, 0x2d808804, 0x2d810804, 0x2d818804, 0x2d820804, 0x2d828804,
+    0x2d830804, 0x2d838804, 0x2d840804, 0x2d848804, 0x2d850804, 0x2d858804, 0x2d860804, 0x2d868804,
+    0x2d870804, 0x2d878804, 0x2d880804, 0x2d888804, 0x2d890804, 0x2d898804, 0x2d8a0804, 0x2d8a8804,
+    0x2d8b0804, 0x2d8b8804, 0x2d8c0804, 0x2d8c8804, 0x2d8d0804, 0x2d8d8804, 0x2d8e0804, 0x2d8e8804,
+    0x2d8f0804, 0x2d8f8804, 0x2d900804, 0x2d908804, 0x2d910804, 0x2d918804, 0x2d920804, 0x2d928804,
+    0x2d930804, 0x2d938804, 0x2d940804, 0x2d948804, 0x2d950804, 0x2d958804, 0x2d960804, 0x2d968804,
+    0x2d970804, 0x2d978804, 0x2d980804, 0x2d988804, 0x2d990804, 0x2d998804, 0x2d9a0804, 0x2d9a8804,
+    0x2d9b0804, 0x2d9b8804, 0x2d9c0804, 0x2d9c8804, 0x2d9d0804, 0x2d9d8804, 0x2d9e0804, 0x2d9e8804,
+    0x2d9f0804, 0x2d9f8804, 0x2da00804, 0x2da08804, 0x2da10804, 0x2da18804, 0x2da20804, 0x2da28804,
+    0x2da30804, 0x2da38804, 0x2da40804, 0x2da48804, 0x2da50804, 0x2da58804, 0x2da60804, 0x2da68804,
+    0x2da70804, 0x2da78804, 0x2da80804, 0x2da88804, 0x2da90804, 0x2da98804, 0x2daa0804, 0x2daa8804,
+    0x2dab0804, 0x2dab8804, 0x2dac0804, 0x2dac8804, 0x2dad0804, 0x2dad8804, 0x2dae0804, 0x2dae8804,
+    0x2daf0804, 0x2daf8804, 0x2db00804, 0x2db08804, 0x2db10804, 0x2db18804, 0x2db20804, 0x2db28804,
+    0x2db30804, 0x2db38804, 0x2db40804, 0x2db48804, 0x2db50804, 0x2db58804, 0x2db60804, 0x2db68804,
+    0x2db70804, 0x2db78804, 0x2db80804, 0x2db88804, 0x2db90804, 0x2db98804, 0x2dba0804, 0x2dba8804,
+    0x2dbb0804, 0x2dbb8804, 0x2dbc0804, 0x2dbc8804, 0x2dbd0804, 0x2dbd8804, 0x2dbe0804, 0x2dbe8804,
+    0x2dbf0804, 0x2dbf8804, 0x2dc00804, 0x2dc08804, 0x2dc10804, 0x2dc18804, 0x2dc20804, 0x2dc28804,
+    0x2dc30804, 0x2dc38804, 0x2dc40804, 0x2dc48804, 0x2dc50804, 0x2dc58804, 0x2dc60804, 0x2dc68804,
+    0x2dc70804, 0x2dc78804, 0x2dc80804, 0x2dc88804, 0x2dc90804, 0x2dc98804, 0x2dca0804, 0x2dca8804,
+    0x2dcb0804, 0x2dcb8804, 0x2dcc0804, 0x2dcc8804, 0x2dcd0804, 0x2dcd8804, 0x2dce0804, 0x2dce8804,
+    0x2dcf0804, 0x2dcf8804, 0x2dd00804, 0x2dd08804, 0x2dd10804, 0x2dd18804, 0x2dd20804, 0x2dd28804,
+    0x2dd30804, 0x2dd38804, 0x2dd40804, 0x2dd48804, 0x2dd50804, 0x2dd58804, 0x2dd60804, 0x2dd68804,
+    0x2dd70804, 0x2dd78804, 0x2dd80804, 0x2dd88804, 0x2dd90804, 0x2dd98804, 0x2dda0804, 0x2dda8804,
+    0x2ddb0804, 0x2ddb8804, 0x2ddc0804, 0x2ddc8804, 0x2ddd0804, 0x2ddd8804, 0x2dde0804, 0x2dde8804,
+    0x2ddf0804, 0x2ddf8804, 0x2de00804, 0x2de08804, 0x2de10804, 0x2de18804, 0x2de20804, 0x2de28804,
+    0x2de30804, 0x2de38804, 0x2de40804, 0x2de48804, 0x2de50804, 0x2de58804, 0x2de60804, 0x2de68804,
+    0x2de70804, 0x2de78804, 0x2de80804, 0x2de88804, 0x2de90804, 0x2de98804, 0x2dea0804, 0x2dea8804,
+    0x2deb0804, 0x2deb8804, 0x2dec0804, 0x2dec8804, 0x2ded0804, 0x2ded8804, 0x2dee0804, 0x2dee8804,
+    0x2def0804, 0x2def8804, 0x2df00804, 0x2df08804, 0x2df10804, 0x2df18804, 0x2df20804, 0x2df28804,
+    0x2df30804, 0x2df38804, 0x2df40804, 0x2df48804, 0x2df50804, 0x2df58804, 0x2df60804, 0x2df68804,
+    0x2df70804, 0x2df78804, 0x2df80804, 0x2df88804, 0x2df90804, 0x2df98804, 0x2dfa0804, 0x2dfa8804,
+    0x2dfb0804, 0x2dfb8804, 0x2dfc0804, 0x2dfc8804, 0x2dfd0804, 0x2dfd8804, 0x2dfe0804, 0x2dfe8804,
+    0x2dff0804, 0x2dff8804, 0x2e000804, 0x2e008804, 0x2e010804, 0x2e018804, 0x2e020804, 0x2e028804,
+    0x2e030804, 0x2e038804, 0x2e040804, 0x2e048804, 0x2e050804, 0x2e058804, 0x2e060804, 0x2e068804,
+    0x2e070804, 0x2e078804, 0x2e080804, 0x2e088804, 0x2e090804, 0x2e098804, 0x2e0a0804, 0x2e0a8804,
+    0x2e0b0804, 0x2e0b8804, 0x2e0c0804, 0x2e0c8804, 0x2e0d0804, 0x2e0d8804, 0x2e0e0804, 0x2e0e8804,
+    0x2e0f0804, 0x2e0f8804, 0x2e100804, 0x2e108804, 0x2e110804, 0x2e118804, 0x2e120804, 0x2e128804,
+    0x2e130804, 0x2e138804, 0x2e140804, 0x2e148804, 0x2e150804, 0x2e158804, 0x2e160804, 0x2e168804,
+    0x2e170804, 0x2e178804, 0x2e180804, 0x2e188804, 0x2e190804, 0x2e198804, 0x2e1a0804, 0x2e1a8804,
+    0x2e1b0804, 0x2e1b8804, 0x1f0b0804, 0x1f0b8804, 0x1f0c0804, 0x1f0c8804, 0x1f0d0804, 0x1f0d8804,
+    0x1f0e0804, 0x1f0e8804, 0x1f0f0804, 0x1f0f8804, 0x1f100804, 0x1f108804, 0x1f110804, 0x1f118804,
+    0x1f120804, 0x1f128804, 0x1f130804, 0x1f138804, 0x1f140804, 0x1f148804, 0x1f150804, 0x1f158804,
+    0x1f160804, 0x1f168804, 0x1f170804, 0x1f178804, 0x1f180804, 0x1f188804, 0x1f190804, 0x1f198804,
+    0x1f1a0804, 0x1f1a8804, 0x1f1b0804, 0x1f1b8804, 0x1f1c0804, 0x1f1c8804, 0x1f1d0804, 0x1f1d8804,
+    0x1f1e0804, 0x1f1e8804, 0x1f1f0804, 0x1f1f8804, 0x1f200804, 0x1f208804, 0x1f210804, 0x1f218804,
+    0x1f220804, 0x1f228804, 0x1f230804, 0x1f238804, 0x1f240804, 0x1f248804, 0x1f250804, 0x1f258804,
+    0x1f260804, 0x1f268804, 0x1f270804, 0x1f278804, 0x1f280804, 0x1f288804, 0x1f290804, 0x1f298804,
+    0x1f2a0804, 0x1f2a8804, 0x1f2b0804, 0x1f2b8804, 0x1f2c0804, 0x1f2c8804, 0x1f2d0804, 0x1f2d8804,
+    0x1f2e0804, 0x1f2e8804, 0x1f2f0804, 0x1f2f8804, 0x1f300804, 0x1f308804, 0x1f310804, 0x1f318804,
+    0x1f320804, 0x1f328804, 0x1f330804, 0x1f338804, 0x1f340804, 0x1f348804, 0x1f350804, 0x1f358804,
+    0x1f360804, 0x1f368804, 0x1f370804, 0x1f378804, 0x1f380804, 0x1f388804, 0x1f390804, 0x1f398804,
+    0x1f3a0804, 0x1f3a8804, 0x1f3b0804, 0x1f3b8804, 0x1f3c0804, 0x1f3c8804, 0x1f3d0804, 0x1f3d8804,
+    0x1f3e0804, 0x1f3e8804, 0x1f3f0804, 0x1f3f8804, 0x1f400804, 0x1f408804, 0x1f410804, 0x1f418804,
+    0x1f420804, 0x1f428804, 0x1f430804, 0x1f438804, 0x1f440804, 0x1f448804, 0x1f450804, 0x1f458804,
+    0x1f460804, 0x1f468804, 0x1f470804, 0x1f478804, 0x1f480804, 0x1f488804, 0x1f490804, 0x1f498804,
+    0x1f4a0804, 0x1f4a8804, 0x1f4b0804, 0x1f4b8804, 0x1f4c0804, 0x1f4c8804, 0x1f4d0804, 0x1f4d8804,
+    0x1f4e0804, 0x1f4e8804, 0x1f4f0804, 0x1f4f8804, 0x1f500804, 0x1f508804, 0x1f510804, 0x1f518804,
+    0x1f520804, 0x1f528804, 0x1f530804, 0x1f538804, 0x1f540804, 0x1f548804, 0x1f550804, 0x1f558804,
+    0x1f560804, 0x1f568804, 0x1f570804, 0x1f578804, 0x1f580804, 0x1f588804, 0x1f590804, 0x1f598804,
+    0x1f5a0804, 0x1f5a8804, 0x1f5b0804, 0x1f5b8804, 0x1f5c0804, 0x1f5c8804, 0x1f5d0804, 0x1f5d8804,
+    0x1f5e0804, 0x1f5e8804, 0x1f5f0804, 0x1f5f8804, 0x1f600804, 0x1f608804, 0x1f610804, 0x1f618804,
+    0x1f620804, 0x1f628804, 0x1f630804, 0x1f638804, 0x1f640804, 0x1f648804, 0x1f650804, 0x1f658804,
+    0x1f660804, 0x1f668804, 0x1f670804, 0x1f678804, 0x1f680804, 0x1f688804, 0x1f690804, 0x1f698804,
+    0x1f6a0804, 0x1f6a8804, 0x1f6b0804, 0x1f6b8804, 0x1f6c0804, 0x1f6c8804, 0x1f6d0804, 0x1f6d8804,
+    0x1f6e0804, 0x1f6e8804, 0x1f6f0804, 0x1f6f8804, 0x1f700804, 0x1f708804, 0x1f710804, 0x1f718804,
+    0x1f720804, 0x1f728804, 0x1f730804, 0x1f738804, 0x1f740804, 0x1f748804, 0x1f750804, 0x1f758804,
+    0x1f760804, 0x1f768804, 0x1f770804, 0x1f778804, 0x1f780804, 0x1f788804, 0x1f790804, 0x1f798804,
+    0x1f7a0804, 0x1f7a8804, 0x1f7b0804, 0x1f7b8804, 0x1f7c0804, 0x1f7c8804, 0x1f7d0804, 0x1f7d8804,
+    0x1f7e0804, 0x1f7e8804, 0x1f7f0804, 0x1f7f8804, 0x1f800804, 0x1f808804, 0x1f810804, 0x1f818804,
+    0x1f820804, 0x1f828804, 0x1f830804, 0x1f838804, 0x1f840804, 0x1f848804, 0x1f850804, 0x1f858804,
+    0x1f860804, 0x1f868804, 0x1f870804, 0x1f878804, 0x1f880804, 0x1f888804, 0x1f890804, 0x1f898804,
+    0x1f8a0804, 0x1f8a8804, 0x1f8b0804, 0x1f8b8804, 0x1f8c0804, 0x1f8c8804, 0x1f8d0804, 0x1f8d8804,
+    0x1f8e0804, 0x1f8e8804, 0x1f8f0804, 0x1f8f8804, 0x1f900804, 0x1f908804, 0x1f910804, 0x1f918804,
+    0x1f920804, 0x1f928804, 0x1f930804, 0x1f938804, 0x1f940804, 0x1f948804, 0x1f950804, 0x1f958804,
+    0x1f960804, 0x1f968804, 0x1f970804, 0x1f978804, 0x1f980804, 0x1f988804, 0x1f990804, 0x1f998804,
+    0x1f9a0804, 0x1f9a8804, 0x1f9b0804, 0x1f9b8804, 0x1f9c0804, 0x1f9c8804, 0x1f9d0804, 0x1f9d8804,
+    0x1f9e0804, 0x1f9e8804, 0x1f9f0804, 0x1f9f8804, 0x1fa00804, 0x1fa08804, 0x1fa10804, 0x1fa18804,
+    0x1fa20804, 0x1fa28804, 0x1fa30804, 0x1fa38804, 0x1fa40804, 0x1fa48804, 0x1fa50804, 0x1fa58804,
+    0x1fa60804, 0x1fa68804, 0x1fa70804, 0x1fa78804, 0x1fa80804, 0x1fa88804, 0x1fa90804, 0x1fa98804,
+    0x1faa0804, 0x1faa8804, 0x1fab0804, 0x1fab8804, 0x1fac0804, 0x1fac8804, 0x1fad0804, 0x1fad8804,
+    0x1fae0804, 0x1fae8804, 0x1faf0804, 0x1faf8804, 0x1fb00804, 0x1fb08804, 0x1fb10804, 0x1fb18804,
+    0x1fb20804, 0x1fb28804, 0x1fb30804, 0x1fb38804, 0x1fb40804, 0x1fb48804, 0x1fb50804, 0x1fb58804,
+    0x1fb60804, 0x1fb68804, 0x1fb70804, 0x1fb78804, 0x1fb80804, 0x1fb88804, 0x1fb90804, 0x1fb98804,
+    0x1fba0804, 0x1fba8804, 0x1fbb0804, 0x1fbb8804, 0x1fbc0804, 0x1fbc8804, 0x1fbd0804, 0x1fbd8804,
+    0x1fbe0804, 0x1fbe8804, 0x1fbf0804, 0x1fbf8804, 0x1fc00804, 0x1fc08804, 0x1fc10804, 0x1fc18804,
+    0x1fc20804, 0x1fc28804, 0x1fc30804, 0x1fc38804, 0x1fc40804, 0x1fc48804, 0x1fc50804, 0x1fc58804,
+    0x1fc60804, 0x1fc68804, 0x1fc70804, 0x1fc78804, 0x1fc80804, 0x1fc88804, 0x1fc90804, 0x1fc98804,
+    0x1fca0804, 0x1fca8804, 0x1fcb0804, 0x1fcb8804, 0x1fcc0804, 0x1fcc8804, 0x1fcd0804, 0x1fcd8804,
+    0x1fce0804, 0x1fce8804, 0x1fcf0804, 0x1fcf8804, 0x1fd00804, 0x1fd08804, 0x1fd10804, 0x1fd18804,
+    0x1fd20804, 0x1fd28804, 0x1fd30804, 0x1fd38804, 0x1fd40804, 0x1fd48804, 0x1fd50804, 0x1fd58804,
+    0x1fd60804, 0x1fd68804, 0x1fd70804, 0x1fd78804, 0x1fd80804, 0x1fd88804, 0x1fd90804, 0x1fd98804,
+    0x1fda0804, 0x1fda8804, 0x1fdb0804, 0x1fdb8804, 0x1fdc0804, 0x1fdc8804, 0x1fdd0804, 0x1fdd8804,
+    0x1fde0804, 0x1fde8804, 0x1fdf0804, 0x1fdf8804, 0x1fe00804, 0x1fe08804, 0x1fe10804, 0x1fe18804,
+    0x1fe20804, 0x1fe28804, 0x1fe30804, 0x1fe38804, 0x1fe40804, 0x1fe48804, 0x1fe50804, 0x1fe58804,
+    0x1fe60804, 0x1fe68804, 0x1fe70804, 0x1fe78804, 0x1fe80804, 0x1fe88804, 0x1fe90804, 0x1fe98804,
+    0x1fea0804, 0x1fea8804, 0x1feb0804, 0x1feb8804, 0x1fec0804, 0x1fec8804, 0x1fed0804, 0x1fed8804,
+    0x1fee0804, 0x1fee8804, 0x1fef0804, 0x1fef8804, 0x1ff00804, 0x1ff08804, 0x1ff10804, 0x1ff18804,
+    0x1ff20804, 0x1ff28804, 0x1ff30804, 0x1ff38804, 0x1ff40804, 0x1ff48804, 0x1ff50804, 0x1ff58804,
+    0x1ff60804, 0x1ff68804, 0x1ff70804, 0x1ff78804, 0x1ff80804, 0x1ff88804, 0x1ff90804, 0x1ff98804,
+    0x1ffa0804, 0x1ffa8804, 0x1ffb0804, 0x1ffb8804, 0x1ffc0804, 0x1ffc8804, 0x1ffd0804, 0x1ffd8804,
+    0x1ffe0804, 0x1ffe8804, 0x1fff0804, 0x1fff8804, 0x20000804, 0x20008804, 0x20010804, 0x20018804,
+    0x20020804, 0x20028804, 0x20030804, 0x20038804, 0x20040804, 0x20048804, 0x20050804, 0x20058804,
+    0x20060804, 0x20068804, 0x20070804, 0x20078804, 0x20080804, 0x20088804, 0x20090804, 0x20098804,
+    0x200a0804, 0x200a8804, 0x200b0804, 0x200b8804, 0x200c0804, 0x200c8804, 0x200d0804, 0x200d8804,
+    0x200e0804, 0x200e8804, 0x200f0804, 0x200f8804, 0x20100804, 0x20108804, 0x20110804, 0x20118804,
+    0x20120804, 0x20128804, 0x20130804, 0x20138804, 0x20140804, 0x20148804, 0x20150804, 0x20158804,
+    0x20160804, 0x20168804, 0x20170804, 0x20178804, 0x20180804, 0x20188804, 0x20190804, 0x20198804,
+    0x201a0804, 0x201a8804, 0x201b0804, 0x201b8804, 0x201c0804, 0x201c8804, 0x201d0804, 0x201d8804,
+    0x201e0804, 0x201e8804, 0x201f0804, 0x201f8804, 0x20200804, 0x20208804, 0x20210804, 0x20218804,
+    0x20220804, 0x20228804, 0x20230804, 0x20238804, 0x20240804, 0x20248804, 0x20250804, 0x20258804,
+    0x20260804, 0x20268804, 0x20270804, 0x266e8804, 0x266f0804, 0x266f8804, 0x26700804, 0x26708804,
+    0x26710804, 0x26718804, 0x26720804, 0x26728804, 0x26730804, 0x26738804, 0x26740804, 0x26748804,
+    0x26750804, 0x26758804, 0x26760804, 0x26768804, 0x26770804, 0x26778804, 0x26780804, 0x26788804,
+    0x26790804, 0x26798804, 0x267a0804, 0x267a8804, 0x267b0804, 0x267b8804, 0x267c0804, 0x267c8804,
+    0x267d0804, 0x267d8804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1648805, 0x1650805, 0x20278804, 0x20280804,
+    0x20288804, 0x20290804, 0x20298804, 0x202a0804, 0x202a8804, 0x202b0804, 0x202b8804, 0x202c0804,
+    0x202c8804, 0x202d0804, 0x202d8804, 0x202e0804, 0x202e8804, 0x202f0804, 0x202f8804, 0x20300804,
+    0x20308804, 0x20310804, 0x20318804, 0x20320804, 0x20328804, 0x20330804, 0x20338804, 0x20340804,
+    0x20348804, 0x20350804, 0x20358804, 0x20360804, 0x2e04, 0x2e44, 0x2e84, 0x2ec4, 0x2f04,
+    0x1478805, 0x25040804, 0x25048804, 0x25050804, 0x25058804, 0x25060804, 0x25068804, 0x25070804,
+    0x25078804, 0x25080804, 0x25088804, 0x25090804, 0x25098804, 0x250a0804, 0x250a8804, 0x250b0804,
+    0x250b8804, 0x250c0804, 0x250c8804, 0x250d0804, 0x250d8804, 0x250e0804, 0x250e8804, 0x250f0804,
+    0x250f8804, 0x25100804, 0x25108804, 0x25110804, 0x25118804, 0x25120804, 0x25128804, 0x25130804,
+    0x25138804, 0x25140804, 0x25148804, 0x25150804, 0x25158804, 0x25160804, 0x25168804, 0x25170804,
+    0x25178804, 0x25180804, 0x25188804, 0x25190804, 0x25198804, 0x251a0804, 0x251a8804, 0x251b0804,
+    0x251b8804, 0x3d84, 0x3dc4, 0x3e04, 0x3e44, 0x3e84, 0x3ec4, 0x3f04, 0x2538805, 0x2540805,
+    0x2548805, 0x2550805, 0x2558805, 0x88f0805, 0x88f8805, 0x8900805, 0x8908805, 0x251c0804,
+    0x251c8804, 0xfa98804, 0xfaa0804, 0x2560805, 0x8910805, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xf408805,
+    0xf410805, 0xf418805, 0xf420805, 0xf428805, 0xf430805, 0xf438805, 0x251d0804, 0x251d8804,
+    0x251e0804, 0x251e8804, 0x251f0804, 0x251f8804, 0x25200804, 0x25208804, 0x25210804, 0x25218804,
+    0x25220804, 0x25228804, 0x25230804, 0x25238804, 0x25240804, 0x25248804, 0x25250804, 0x25258804,
+    0x25260804, 0x25268804, 0x25270804, 0x25278804, 0x25280804, 0x25288804, 0x25290804, 0x25298804,
+    0x252a0804, 0x252a8804, 0x252b0804, 0x252b8804, 0x252c0804, 0x252c8804, 0x252d0804, 0x252d8804,
+    0x252e0804, 0x252e8804, 0x252f0804, 0x252f8804, 0x25300804, 0x25308804, 0x20990810, 0x20998810,
+    0x209a0810, 0x209a8810, 0x209b0810, 0x209b8810, 0x209c0810, 0x209c8810, 0x209d0810, 0x209d8810,
+    0x209e0810, 0x209e8810, 0x209f0810, 0x209f8810, 0x20a00810, 0x20a08810, 0x20a10810, 0x20a18810,
+    0x20a20810, 0x20a28810, 0x20a30810, 0x20a38810, 0x20a40810, 0x20a48810, 0x20a50810, 0x20a58810,
+    0x20a60810, 0x20a68810, 0x20a70810, 0x20a78810, 0x20a80810, 0x20a88810, 0x20990804, 0x20998804,
+    0x209a0804, 0x209a8804, 0x209b0804, 0x209b8804, 0x209c0804, 0x209c8804, 0x209d0804, 0x209d8804,
+    0x209e0804, 0x209e8804, 0x209f0804, 0x209f8804, 0x20a00804, 0x20a08804, 0x20a10804, 0x20a18804,
+    0x20a20804, 0x20a28804, 0x20a30804, 0x20a38804, 0x20a40804, 0x20a48804, 0x20a50804, 0x20a58804,
+    0x20a60804, 0x20a68804, 0x20a70804, 0x20a78804, 0x20a80804, 0x20a88804, 0xfcc0804, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0xf440805, 0xf448805, 0xf450805, 0xf458805, 0xf460805, 0xf468805, 0xf470805, 0xf478805,
+    0xf480805, 0xf488805, 0x8003b642, 0x8003b682, 0x8003b6c2, 0x11a8805, 0x1480805, 0x2568805,
+    0x2570805, 0x248e8804, 0x248f0804, 0x248f8804, 0x24900804, 0x24910804, 0x24918804, 0x24910808,
+    0x24920804, 0x24928804, 0x24930804, 0x24938804, 0x24940804, 0x24948804, 0x24950804, 0x24960804,
+    0x24968804, 0x24978804, 0x24980804, 0x24988804, 0x24978808, 0x24990804, 0x24998804, 0x249a0804,
+    0x249a8804, 0x249b0804, 0x249b8804, 0x249c0804, 0x249c8804, 0x249d0804, 0x249d8804, 0x249e0804,
+    0x249e8804, 0x249f0804, 0x249f8804, 0x24a00804, 0x24a08804, 0x24a10804, 0x24a08808, 0x24a18804,
+    0x24a20804, 0x24a28804, 0x24a30804, 0x24a38804, 0x24a40804, 0x24a48804, 0x24a50804, 0x24a58804,
+    0x24a60804, 0x24a68804, 0x24a70804, 0x24a78804, 0x24a88804, 0x24a98804, 0x24aa0804, 0x24aa8804,
+    0x24ab0804, 0x24ab8804, 0x24ac0804, 0x24ad0804, 0x24ad8804, 0x24ae0804, 0x24ae8804, 0x24af0804,
+    0x24ae8808, 0x24af8804, 0x24b00804, 0x24b08804, 0x24b10804, 0x24b18804, 0x24908804, 0x24a90804,
+    0x24a80804, 0x24958804, 0x24ac8804, 0x24970804, 0x24b40804, 0x24b20804, 0x24b28804, 0x24b30804,
+    0x24b38804, 0x24b48804, 0x24b50804, 0x24b58804, 0x24b60804, 0x24b68804, 0x24b78804, 0x24b80804,
+    0x24b88804, 0x24b90804, 0x24ba0804, 0x24ba8804, 0x24bb0804, 0x24bb8804, 0x24bc8804, 0x24bd0804,
+    0x24bd8804, 0x24be0804, 0x24be8804, 0x24bf8804, 0x24c00804, 0x24c08804, 0x24c10804, 0x24c18804,
+    0x24c20804, 0x24c28804, 0x24c30804, 0x24c48804, 0x24c50804, 0x24c58804, 0x24c60804, 0x24c68804,
+    0x24c78804, 0x24c80804, 0x24c98804, 0x24ca0804, 0x24ca8804, 0x24cb0804, 0x24cb8804, 0x24cc0804,
+    0x24cc8804, 0x24cd0804, 0x24cd8804, 0x24ce0804, 0x24c38804, 0x24c70804, 0x24b70804, 0x24c90804,
+    0x24b98804, 0x24bc0804, 0x24c88804, 0x24bf0804, 0x24c40804, 0x24ce8804, 0x24cf0804, 0x24cf8804,
+    0x24d00804, 0x24d08804, 0x24d10804, 0x24d18804, 0x24d20804, 0x24d28804, 0x24d30804, 0x24d38804,
+    0x24d40804, 0x24d48804, 0x24d50804, 0x24d58804, 0x24d60804, 0x24d68804, 0xfac8804, 0xfad0804,
+    0x11c8805, 0xfad8804, 0x0, 0x41c4, 0x4204, 0x216e8822, 0x218c0804, 0x21858804, 0x21860804,
+    0x21868804, 0x21870804, 0x21878804, 0x21880804, 0x21888804, 0x21890804, 0x21898804, 0x218a0804,
+    0x218a8804, 0x218b0804, 0x218b8804, 0x218c8804, 0x218d0804, 0x218d8804, 0x218e0804, 0x218e8804,
+    0x218f0804, 0x218f8804, 0x21900804, 0x21908804, 0x21910804, 0x21918804, 0x21920804, 0x21928804,
+    0x21930804, 0x21938804, 0x21940804, 0x21948804, 0x21950804, 0x21958804, 0x21960804, 0x21968804,
+    0x21970804, 0x21978804, 0x21980804, 0x21988804, 0x21990804, 0x21998804, 0x219a0804, 0x219a8804,
+    0x219b0804, 0x219b8804, 0x219c0804, 0x219c8804, 0x219d0804, 0x219d8804, 0x219e0804, 0x219e8804,
+    0x219f0804, 0x219f8804, 0x21a00804, 0x21a08804, 0x21a10804, 0x21a18804, 0x21a20804, 0x21a28804,
+    0x21a30804, 0x21a38804, 0x21a40804, 0x21a48804, 0x21a50804, 0x21a58804, 0x21a60804, 0x21a68804,
+    0x21a70804, 0x21a78804, 0x21a80804, 0x21a88804, 0x21a90804, 0x21a98804, 0x21aa0804, 0x21aa8804,
+    0x21ab0804, 0x21ab8804, 0x21ac0804, 0x21ac8804, 0x21ad0804, 0x21ad8804, 0x21ae0804, 0x21ae8804,
+    0x21af0804, 0x21af8804, 0x21b00804, 0x21b08804, 0x21b10804, 0x21b18804, 0x21b20804, 0x21b28804,
+    0x21b30804, 0x21b38804, 0x21b40804, 0x21b48804, 0x21b50804, 0x21b58804, 0x21b60804, 0x21b68804,
+    0x21b70804, 0x21b78804, 0x21b80804, 0x21b88804, 0x21b90804, 0x21b98804, 0x21ba0804, 0x21ba8804,
+    0x21bb0804, 0x21bb8804, 0x21bc0804, 0x21bc8804, 0x21bd0804, 0x21bd8804, 0x21be0804, 0x21be8804,
+    0x21bf0804, 0x21bf8804, 0x21c00804, 0x21c08804, 0x21c10804, 0x21c18804, 0x21c20804, 0x21c28804,
+    0x21c30804, 0x21c38804, 0x21c40804, 0x21c48804, 0x21c50804, 0x21c58804, 0x21c60804, 0x21c68804,
+    0x21c70804, 0x21c78804, 0x21c80804, 0x21c88804, 0x21c90804, 0x21c98804, 0x21ca0804, 0x21ca8804,
+    0x21cb0804, 0x21cb8804, 0x21cc0804, 0x21cc8804, 0x21cd0804, 0x21cd8804, 0x21ce0804, 0x21ce8804,
+    0x21cf0804, 0x21cf8804, 0x21d00804, 0x21d08804, 0x21d10804, 0x21d18804, 0x21d20804, 0x21d28804,
+    0x21d30804, 0x21d38804, 0x21d40804, 0x21d48804, 0x21d50804, 0x21d58804, 0x21d60804, 0x21d68804,
+    0x21d70804, 0x21d78804, 0x21d80804, 0x21d88804, 0x21d90804, 0x21d98804, 0x21da0804, 0x21da8804,
+    0x21db0804, 0x21db8804, 0x21dc0804, 0x21dc8804, 0x21dd0804, 0x21dd8804, 0x21de0804, 0x21de8804,
+    0x21df0804, 0x21df8804, 0x21e00804, 0x21e08804, 0x21e10804, 0x21e18804, 0x21e20804, 0x21e28804,
+    0x21e30804, 0x21e38804, 0x21e40804, 0x21e48804, 0x21e50804, 0x21e58804, 0x21e60804, 0x21e68804,
+    0x21e70804, 0x21e78804, 0x21e80804, 0x21e88804, 0x21e90804, 0x21e98804, 0x21ea0804, 0x21ea8804,
+    0x21eb0804, 0x21eb8804, 0x21ec0804, 0x21ec8804, 0x21ed0804, 0x21ed8804, 0x21ee0804, 0x21ee8804,
+    0x21ef0804, 0x21ef8804, 0x21f00804, 0x21f08804, 0x21f10804, 0x21f18804, 0x21f20804, 0x21f28804,
+    0x21f30804, 0x21f38804, 0x21f40804, 0x21f48804, 0x21f50804, 0x21f58804, 0x21f60804, 0x21f68804,
+    0x21f70804, 0x21f78804, 0x21f80804, 0x21f88804, 0x21f90804, 0x21f98804, 0x21fa0804, 0x21fa8804,
+    0x21fb0804, 0x21fb8804, 0x21fc0804, 0x21fc8804, 0x21fd0804, 0x21fd8804, 0x21fe0804, 0x21fe8804,
+    0x21ff0804, 0x21ff8804, 0x22000804, 0x22008804, 0x22010804, 0x22018804, 0x22020804, 0x22028804,
+    0x22030804, 0x22038804, 0x22040804, 0x22048804, 0x22050804, 0x22058804, 0x22060804, 0x22068804,
+    0x22070804, 0x22078804, 0x22080804, 0x22088804, 0x22090804, 0x22098804, 0x220a0804, 0x220a8804,
+    0x220b0804, 0x220b8804, 0x220c0804, 0x220c8804, 0x220d0804, 0x220d8804, 0x220e0804, 0x220e8804,
+    0x220f0804, 0x220f8804, 0x22100804, 0x22108804, 0x22110804, 0x22118804, 0x22120804, 0x22128804,
+    0x22130804, 0x22138804, 0x22140804, 0x2183881a, 0x2184081a, 0x2184881a, 0x2183881e, 0x2184081e,
+    0x2184881e, 0x2185081e, 0x25df8804, 0x25e00804, 0x25e08804, 0x25e10804, 0x25e18804, 0x25e20804,
+    0x25e28804, 0x25e30804, 0x25e38804, 0x25e40804, 0x25e48804, 0x25e50804, 0x25e58804, 0x25e60804,
+    0x25e68804, 0x25e70804, 0x25e78804, 0x25e80804, 0x25e88804, 0x25e90804, 0x25e98804, 0x25ea0804,
+    0x25ea8804, 0x25eb0804, 0x25eb8804, 0x25ec0804, 0x25ec8804, 0x25ed0804, 0x25ed8804, 0x25ee0804,
+    0x25ee8804, 0x25ef0804, 0x25ef8804, 0x25f00804, 0x25f08804, 0x25f10804, 0x25f18804, 0x25f20804,
+    0x25f28804, 0x25f30804, 0x25f38804, 0x25f40804, 0x25f48804, 0x25f50804, 0x25f58804, 0x25f60804,
+    0x25f68804, 0x25f70804, 0x25f78804, 0x25f80804, 0x25f88804, 0x25f90804, 0x25f98804, 0x25fa0804,
+    0x25fa8804, 0x25fb0804, 0x25fb8804, 0x25fc0804, 0x25fc8804, 0x25fd0804, 0x25fd8804, 0x25fe0804,
+    0x25fe8804, 0x25ff0804, 0x25ff8804, 0x26000804, 0x26008804, 0x26010804, 0x26018804, 0x26020804,
+    0x26028804, 0x26030804, 0x26038804, 0x26040804, 0x26048804, 0x26050804, 0x26058804, 0x26060804,
+    0x26068804, 0x26070804, 0x26078804, 0x26080804, 0x26088804, 0x26090804, 0x26098804, 0x260a0804,
+    0x260a8804, 0x260b0804, 0x260b8804, 0x260c0804, 0x260c8804, 0x260d0804, 0x260d8804, 0x260e0804,
+    0x260e8804, 0x260f0804, 0x260f8804, 0x26100804, 0x26108804, 0x26110804, 0x26118804, 0x26120804,
+    0x26128804, 0x26130804, 0x26138804, 0x26140804, 0x26148804, 0x26150804, 0x26158804, 0x26160804,
+    0x26168804, 0x26170804, 0x26178804, 0x26180804, 0x26188804, 0x26190804, 0x26198804, 0x261a0804,
+    0x261a8804, 0x261b0804, 0x261b8804, 0x261c0804, 0x261c8804, 0x261d0804, 0x261d8804, 0x261e0804,
+    0x261e8804, 0x261f0804, 0x261f8804, 0x26200804, 0x26208804, 0x26210804, 0x26218804, 0x26220804,
+    0x26228804, 0x26230804, 0x26238804, 0x26240804, 0x26248804, 0x98a0805, 0xcc4, 0xd44, 0x1488805,
+    0x0, 0x0, 0x0, 0x0, 0x8920805, 0x8928805, 0x8930805, 0x8938805, 0x8940805, 0x8948805,
+    0x8950805, 0x8958805, 0x8960805, 0x8968805, 0x8970805, 0x8978805, 0x8980805, 0x8988805,
+    0x8990805, 0x8998805, 0x89a0805, 0x89a8805, 0x89b0805, 0x89b8805, 0x89c0805, 0x89c8805,
+    0x89d0805, 0x89d8805, 0x89e0805, 0x89e8805, 0x89f0805, 0x89f8805, 0x8a00805, 0x8a08805,
+    0x8a10805, 0x8a18805, 0x8a20805, 0x8a28805, 0x8a30805, 0x8a38805, 0x8a40805, 0x8a48805,
+    0x8a50805, 0x8a58805, 0x8a60805, 0x8a68805, 0x8a70805, 0x8a78805, 0x8a80805, 0x8a88805,
+    0x8a90805, 0x8a98805, 0x8aa0805, 0x8aa8805, 0x8ab0805, 0x8ab8805, 0x8ac0805, 0x8ac8805,
+    0x8ad0805, 0x8ad8805, 0x8ae0805, 0x8ae8805, 0x8af0805, 0x8af8805, 0x8b00805, 0x8b08805,
+    0x8b10805, 0x8b18805, 0x8b20805, 0x8b28805, 0x8b30805, 0x8b38805, 0x8b40805, 0x8b48805,
+    0x8b50805, 0x8b58805, 0x8b60805, 0x8b68805, 0x8b70805, 0x8b78805, 0x8b80805, 0x8b88805,
+    0x8b90805, 0x8b98805, 0x8ba0805, 0x8ba8805, 0x8bb0805, 0x8bb8805, 0x8bc0805, 0x8bc8805,
+    0x8bd0805, 0x8bd8805, 0x8be0805, 0x8be8805, 0x8bf0805, 0x8bf8805, 0x8c00805, 0x8c08805,
+    0x8c10805, 0x8c18805, 0x8c20805, 0x8c28805, 0x8c30805, 0x8c38805, 0x8c40805, 0x8c48805,
+    0x8c50805, 0x8c58805, 0x8c60805, 0x8c68805, 0x8c70805, 0x8c78805, 0x8c80805, 0x8c88805,
+    0x8c90805, 0x8c98805, 0x8ca0805, 0x8ca8805, 0x8cb0805, 0x8cb8805, 0x8cc0805, 0x8cc8805,
+    0x8cd0805, 0x8cd8805, 0x8ce0805, 0x8ce8805, 0x8cf0805, 0x8cf8805, 0x8d00805, 0x8d08805,
+    0x8d10805, 0x8d18805, 0x8d20805, 0x8d28805, 0x8d30805, 0x8d38805, 0x8d40805, 0x8d48805,
+    0x8d50805, 0x8d58805, 0x8d60805, 0x8d68805, 0x8d70805, 0x8d78805, 0x8d80805, 0x8d88805,
+    0x8d90805, 0x8d98805, 0x8da0805, 0x8da8805, 0x8db0805, 0x8db8805, 0x8dc0805, 0x8dc8805,
+    0x8dd0805, 0x8dd8805, 0x8de0805, 0x8de8805, 0x8df0805, 0x8df8805, 0x8e00805, 0x8e08805,
+    0x8e10805, 0x8e18805, 0x8e20805, 0x8e28805, 0x8e30805, 0x8e38805, 0x8e40805, 0x8e48805,
+    0x8e50805, 0x8e58805, 0x8e60805, 0x8e68805, 0x8e70805, 0x8e78805, 0x8e80805, 0x8e88805,
+    0x8e90805, 0x8e98805, 0x8ea0805, 0x8ea8805, 0x8eb0805, 0x8eb8805, 0x8ec0805, 0x8ec8805,
+    0x8ed0805, 0x8ed8805, 0x8ee0805, 0x8ee8805, 0x8ef0805, 0x8ef8805, 0x8f00805, 0x8f08805,
+    0x8f10805, 0x8f18805, 0x8f20805, 0x8f28805, 0x8f30805, 0x8f38805, 0x8f40805, 0x8f48805,
+    0x8f50805, 0x8f58805, 0x8f60805, 0x8f68805, 0x8f70805, 0x8f78805, 0x8f80805, 0x8f88805,
+    0x8f90805, 0x8f98805, 0x8fa0805, 0x8fa8805, 0x8fb0805, 0x8fb8805, 0x8fc0805, 0x8fc8805,
+    0x8fd0805, 0x8fd8805, 0x8fe0805, 0x8fe8805, 0x8ff0805, 0x8ff8805, 0x9000805, 0x9008805,
+    0x9010805, 0x9018805, 0x9020805, 0x9028805, 0x9030805, 0x9038805, 0x9040805, 0x9048805,
+    0x9050805, 0x9058805, 0x9060805, 0x9068805, 0x9070805, 0x9078805, 0x9080805, 0x9088805,
+    0x9090805, 0x9098805, 0x90a0805, 0x90a8805, 0x90b0805, 0x90b8805, 0x90c0805, 0x90c8805,
+    0x90d0805, 0x90d8805, 0x90e0805, 0x90e8805, 0x90f0805, 0x90f8805, 0x9100805, 0x9108805,
+    0x9110805, 0x9118805, 0x9120805, 0x9128805, 0x9130805, 0x9138805, 0x9140805, 0x9148805,
+    0x9150805, 0x9158805, 0x9160805, 0x9168805, 0x9170805, 0x9178805, 0x9180805, 0x9188805,
+    0x9190805, 0x9198805, 0x91a0805, 0x91a8805, 0x91b0805, 0x91b8805, 0x91c0805, 0x91c8805,
+    0x91d0805, 0x91d8805, 0x91e0805, 0x91e8805, 0x91f0805, 0x91f8805, 0x9200805, 0x92a0805,
+    0x9220805, 0x9228805, 0x9230805, 0x9238805, 0x9240805, 0x9248805, 0x9250805, 0x9258805,
+    0x9260805, 0x9268805, 0x9270805, 0x9278805, 0x9280805, 0x9288805, 0x9290805, 0x9298805,
+    0x92a8805, 0x92b0805, 0x92b8805, 0x92c0805, 0x92c8805, 0x92d0805, 0x92d8805, 0x92e0805,
+    0x92e8805, 0x92f0805, 0x92f8805, 0x9300805, 0x9308805, 0x9310805, 0x9318805, 0x9320805,
+    0x9328805, 0x9330805, 0x9338805, 0x9340805, 0x9348805, 0x9350805, 0x9358805, 0x9360805,
+    0x9368805, 0x9370805, 0x9378805, 0x9380805, 0x9388805, 0x9390805, 0x9398805, 0x93a0805,
+    0x93a8805, 0x93b0805, 0x93b8805, 0x93c0805, 0x8003b702, 0x8003b742, 0x8003b783, 0x8003b7e3,
+    0x8003b843, 0x8003b8a3, 0x8003b903, 0x0, 0x0, 0x0, 0x0, 0x0, 0x93c8805, 0x93d0805, 0x93d8805,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x93e0805, 0x93e8805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x93f0805, 0x93f8805,
+    0x9400805, 0x9408805, 0x9410805, 0x9418805, 0x9420805, 0x9428805, 0x9430805, 0x9438805,
+    0x9440805, 0x9448805, 0x9450805, 0x9458805, 0x9460805, 0x9468805, 0x9470805, 0x9478805,
+    0x9480805, 0x9488805, 0x9490805, 0x9498805, 0x94a0805, 0x94a8805, 0x94b0805, 0x94b8805,
+    0x94c0805, 0x94c8805, 0x94d0805, 0x94d8805, 0x0, 0x0, 0x0, 0x0, 0x94e0805, 0x94e8805,
+    0x94f0805, 0x94f8805, 0x9500805, 0x9508805, 0x9510805, 0x9518805, 0x9520805, 0x9528805,
+    0x9530805, 0x9538805, 0x9540805, 0x8003b962, 0x8003b9a2, 0x8003b9e3, 0x8003ba43, 0x8003baa3,
+    0x8003bb03, 0x9548805, 0x9550805, 0x9558805, 0x9560805, 0x9568805, 0x9570805, 0x9578805,
+    0x9580805, 0x9588805, 0x9590805, 0x9598805, 0x95a0805, 0x95a8805, 0x95b0805, 0x95b8805,
+    0x95c0805, 0x95c8805, 0x95d0805, 0x95d8805, 0x95e0805, 0x95e8805, 0x95f0805, 0x95f8805,
+    0x9600805, 0x9608805, 0x9610805, 0x9618805, 0x9620805, 0x9628805, 0x9630805, 0x9638805,
+    0x9640805, 0x9648805, 0x9650805, 0x9658805, 0x9660805, 0x9668805, 0x9670805, 0x9678805,
+    0x9680805, 0x9688805, 0x9690805, 0x9698805, 0x96a0805, 0x96a8805, 0x96b0805, 0x96b8805,
+    0x96c0805, 0x96c8805, 0x96d0805, 0x96d8805, 0x96e0805, 0x96e8805, 0x96f0805, 0x96f8805,
+    0x9700805, 0x9708805, 0x9710805, 0x9718805, 0x9720805, 0x9728805, 0x9730805, 0x9738805,
+    0x9740805, 0x9748805, 0x9750805, 0x9758805, 0x9760805, 0x9768805, 0x9770805, 0x9778805,
+    0x9780805, 0x9788805, 0x9790805, 0x9798805, 0x97a0805, 0x97a8805, 0x97b0805, 0x97b8805,
+    0x97c0805, 0x97c8805, 0x97d0805, 0x97d8805, 0x97e0805, 0x97e8805, 0x97f0805, 0x97f8805,
+    0x9800805, 0x9808805, 0x9810805, 0x9818805, 0x9820805, 0x9828805, 0x9830805, 0x9838805,
+    0x9840805, 0x9848805, 0x9850805, 0x9858805, 0x9860805, 0x9868805, 0x9870805, 0x9878805,
+    0x9880805, 0x9888805, 0x9890805, 0x0, 0x0, 0x0, 0x9898805, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xf490805,
+    0xf498805, 0xf4a0805, 0xf4a8805, 0xf4b0805, 0xf4b8805, 0xf4c0805, 0xf4c8805, 0xf4d0805,
+    0xf4d8805, 0x81b0805, 0x81b8805, 0x81c0805, 0x81c8805, 0x81d0805, 0x81d8805, 0x81e0805,
+    0x81e8805, 0x81f0805, 0x81f8805, 0x8200805, 0x8208805, 0x8210805, 0x8218805, 0x8220805,
+    0x8228805, 0x8230805, 0x8238805, 0x8240805, 0x8248805, 0x8250805, 0x8258805, 0x8260805,
+    0x8268805, 0x8270805, 0x8278805, 0x8280805, 0x8288805, 0x8290805, 0x8298805, 0x82a0805,
+    0x82a8805, 0x82b0805, 0x82b8805, 0x82c0805, 0x82c8805, 0x82d0805, 0x82d8805, 0x82e0805,
+    0x82e8805, 0x82f0805, 0x82f8805, 0x8300805, 0x8308805, 0x8310805, 0x8318805, 0x8320805,
+    0x8328805, 0x8330805, 0x8338805, 0x8340805, 0x8348805, 0x8350805, 0x8358805, 0x8360805,
+    0x8368805, 0x8370805, 0x8378805, 0x8380805, 0x8388805, 0x8390805, 0x8398805, 0x83a0805,
+    0x83a8805, 0x83b0805, 0x83b8805, 0x83c0805, 0x83c8805, 0x83d0805, 0x83d8805, 0x83e0805,
+    0x83e8805, 0x83f0805, 0x83f8805, 0x8400805, 0x8408805, 0x8410805, 0x8418805, 0x8420805,
+    0x8428805, 0x8430805, 0x8438805, 0x8440805, 0x8448805, 0x8450805, 0x8458805, 0x8460805,
+    0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804,
+    0xfd08804, 0xf9e8805, 0xf9f0805, 0xf9f8805, 0xfa00805, 0xfa08805, 0xfa10805, 0xfa18805,
+    0xfa20805, 0xfa28805, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcc8804,
+    0xfce8804, 0xfd10816, 0xfde0816, 0xfeb0816, 0xff58816, 0x10038816, 0x10210816, 0x10288816,
+    0x103a8816, 0x10480816, 0x10558816, 0x10620816, 0x106b0816, 0x10848816, 0x108c0816, 0x109e0816,
+    0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816, 0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816,
+    0x11320816, 0x11380816, 0x11430816, 0xfd1080a, 0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a,
+    0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a, 0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a,
+    0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a, 0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a,
+    0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a, 0xfd10816, 0xfde0816, 0xfeb0816,
+    0xff58816, 0x10038816, 0x10210816, 0x10288816, 0x103a8816, 0x10480816, 0x10558816, 0x10620816,
+    0x106b0816, 0x10848816, 0x108c0816, 0x109e0816, 0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816,
+    0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816, 0x11320816, 0x11380816, 0x11430816, 0xfd1080a,
+    0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a, 0x1021080a, 0x1028880a, 0x1048080a, 0x1055880a,
+    0x1062080a, 0x106b080a, 0x1084880a, 0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a, 0x10c9880a,
+    0x10e9080a, 0x10fb880a, 0x110b880a, 0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a,
+    0xfd10816, 0xfde0816, 0xfeb0816, 0xff58816, 0x10038816, 0x10210816, 0x10288816, 0x103a8816,
+    0x10480816, 0x10558816, 0x10620816, 0x106b0816, 0x10848816, 0x108c0816, 0x109e0816, 0x10b58816,
+    0x10c00816, 0x10c98816, 0x10e90816, 0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816, 0x11320816,
+    0x11380816, 0x11430816, 0xfd1080a, 0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a, 0x1021080a,
+    0x1028880a, 0x103a880a, 0x1048080a, 0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a, 0x108c080a,
+    0x109e080a, 0x10b5880a, 0x10c0080a, 0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a, 0x1123880a,
+    0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a, 0xfd10816, 0xfeb0816, 0xff58816, 0x10288816,
+    0x10558816, 0x10620816, 0x108c0816, 0x109e0816, 0x10b58816, 0x10c00816, 0x10e90816, 0x10fb8816,
+    0x110b8816, 0x11238816, 0x112c8816, 0x11320816, 0x11380816, 0x11430816, 0xfd1080a, 0xfde080a,
+    0xfeb080a, 0xff5880a, 0x1021080a, 0x103a880a, 0x1048080a, 0x1055880a, 0x1062080a, 0x106b080a,
+    0x1084880a, 0x108c080a, 0x10b5880a, 0x10c0080a, 0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a,
+    0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a, 0xfd10816, 0xfde0816, 0xfeb0816,
+    0xff58816, 0x10038816, 0x10210816, 0x10288816, 0x103a8816, 0x10480816, 0x10558816, 0x10620816,
+    0x106b0816, 0x10848816, 0x108c0816, 0x109e0816, 0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816,
+    0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816, 0x11320816, 0x11380816, 0x11430816, 0xfd1080a,
+    0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a, 0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a,
+    0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a, 0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a,
+    0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a, 0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a,
+    0x1143080a, 0xfd10816, 0xfde0816, 0xff58816, 0x10038816, 0x10210816, 0x10288816, 0x10558816,
+    0x10620816, 0x106b0816, 0x10848816, 0x108c0816, 0x109e0816, 0x10b58816, 0x10c00816, 0x10e90816,
+    0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816, 0x11320816, 0x11380816, 0xfd1080a, 0xfde080a,
+    0xfeb080a, 0xff5880a, 0x1003880a, 0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a, 0x1055880a,
+    0x1062080a, 0x106b080a, 0x1084880a, 0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a, 0x10c9880a,
+    0x10e9080a, 0x10fb880a, 0x110b880a, 0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a,
+    0xfd10816, 0xfde0816, 0xff58816, 0x10038816, 0x10210816, 0x10288816, 0x10480816, 0x10558816,
+    0x10620816, 0x106b0816, 0x10848816, 0x109e0816, 0x10e90816, 0x10fb8816, 0x110b8816, 0x11238816,
+    0x112c8816, 0x11320816, 0x11380816, 0xfd1080a, 0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a,
+    0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a, 0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a,
+    0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a, 0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a,
+    0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a, 0xfd10816, 0xfde0816, 0xfeb0816,
+    0xff58816, 0x10038816, 0x10210816, 0x10288816, 0x103a8816, 0x10480816, 0x10558816, 0x10620816,
+    0x106b0816, 0x10848816, 0x108c0816, 0x109e0816, 0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816,
+    0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816, 0x11320816, 0x11380816, 0x11430816, 0xfd1080a,
+    0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a, 0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a,
+    0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a, 0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a,
+    0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a, 0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a,
+    0x1143080a, 0xfd10816, 0xfde0816, 0xfeb0816, 0xff58816, 0x10038816, 0x10210816, 0x10288816,
+    0x103a8816, 0x10480816, 0x10558816, 0x10620816, 0x106b0816, 0x10848816, 0x108c0816, 0x109e0816,
+    0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816, 0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816,
+    0x11320816, 0x11380816, 0x11430816, 0xfd1080a, 0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a,
+    0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a, 0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a,
+    0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a, 0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a,
+    0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a, 0xfd10816, 0xfde0816, 0xfeb0816,
+    0xff58816, 0x10038816, 0x10210816, 0x10288816, 0x103a8816, 0x10480816, 0x10558816, 0x10620816,
+    0x106b0816, 0x10848816, 0x108c0816, 0x109e0816, 0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816,
+    0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816, 0x11320816, 0x11380816, 0x11430816, 0xfd1080a,
+    0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a, 0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a,
+    0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a, 0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a,
+    0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a, 0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a,
+    0x1143080a, 0xfd10816, 0xfde0816, 0xfeb0816, 0xff58816, 0x10038816, 0x10210816, 0x10288816,
+    0x103a8816, 0x10480816, 0x10558816, 0x10620816, 0x106b0816, 0x10848816, 0x108c0816, 0x109e0816,
+    0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816, 0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816,
+    0x11320816, 0x11380816, 0x11430816, 0xfd1080a, 0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a,
+    0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a, 0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a,
+    0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a, 0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a,
+    0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a, 0xfd10816, 0xfde0816, 0xfeb0816,
+    0xff58816, 0x10038816, 0x10210816, 0x10288816, 0x103a8816, 0x10480816, 0x10558816, 0x10620816,
+    0x106b0816, 0x10848816, 0x108c0816, 0x109e0816, 0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816,
+    0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816, 0x11320816, 0x11380816, 0x11430816, 0xfd1080a,
+    0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a, 0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a,
+    0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a, 0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a,
+    0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a, 0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a,
+    0x1143080a, 0xfd10816, 0xfde0816, 0xfeb0816, 0xff58816, 0x10038816, 0x10210816, 0x10288816,
+    0x103a8816, 0x10480816, 0x10558816, 0x10620816, 0x106b0816, 0x10848816, 0x108c0816, 0x109e0816,
+    0x10b58816, 0x10c00816, 0x10c98816, 0x10e90816, 0x10fb8816, 0x110b8816, 0x11238816, 0x112c8816,
+    0x11320816, 0x11380816, 0x11430816, 0xfd1080a, 0xfde080a, 0xfeb080a, 0xff5880a, 0x1003880a,
+    0x1021080a, 0x1028880a, 0x103a880a, 0x1048080a, 0x1055880a, 0x1062080a, 0x106b080a, 0x1084880a,
+    0x108c080a, 0x109e080a, 0x10b5880a, 0x10c0080a, 0x10c9880a, 0x10e9080a, 0x10fb880a, 0x110b880a,
+    0x1123880a, 0x112c880a, 0x1132080a, 0x1138080a, 0x1143080a, 0x104a080a, 0x1057880a, 0x118f0816,
+    0x118f8816, 0x11900816, 0x11910816, 0x11918816, 0x11938816, 0x11948816, 0x11950816, 0x11958816,
+    0x11968816, 0x11970816, 0x11980816, 0x11988816, 0x11990816, 0x11998816, 0x119a0816, 0x119c8816,
+    0x11950816, 0x119e0816, 0x11a00816, 0x11a08816, 0x11a10816, 0x11a18816, 0x11a20816, 0x11a30816,
+    0x32e080b, 0x118f080a, 0x118f880a, 0x1190080a, 0x1191080a, 0x1191880a, 0x1193880a, 0x1194880a,
+    0x1195080a, 0x1195880a, 0x1196880a, 0x1197080a, 0x1198080a, 0x1198880a, 0x1199080a, 0x1199880a,
+    0x119a080a, 0x119c880a, 0x119e080a, 0x119e080a, 0x11a0080a, 0x11a0880a, 0x11a1080a, 0x11a1880a,
+    0x11a2080a, 0x11a3080a, 0x32c080b, 0x1191880a, 0x1195080a, 0x1196880a, 0x11a1080a, 0x119c880a,
+    0x119a080a, 0x118f0816, 0x118f8816, 0x11900816, 0x11910816, 0x11918816, 0x11938816, 0x11948816,
+    0x11950816, 0x11958816, 0x11968816, 0x11970816, 0x11980816, 0x11988816, 0x11990816, 0x11998816,
+    0x119a0816, 0x119c8816, 0x11950816, 0x119e0816, 0x11a00816, 0x11a08816, 0x11a10816, 0x11a18816,
+    0x11a20816, 0x11a30816, 0x32e080b, 0x118f080a, 0x118f880a, 0x1190080a, 0x1191080a, 0x1191880a,
+    0x1193880a, 0x1194880a, 0x1195080a, 0x1195880a, 0x1196880a, 0x1197080a, 0x1198080a, 0x1198880a,
+    0x1199080a, 0x1199880a, 0x119a080a, 0x119c880a, 0x119e080a, 0x119e080a, 0x11a0080a, 0x11a0880a,
+    0x11a1080a, 0x11a1880a, 0x11a2080a, 0x11a3080a, 0x32c080b, 0x1191880a, 0x1195080a, 0x1196880a,
+    0x11a1080a, 0x119c880a, 0x119a080a, 0x118f0816, 0x118f8816, 0x11900816, 0x11910816, 0x11918816,
+    0x11938816, 0x11948816, 0x11950816, 0x11958816, 0x11968816, 0x11970816, 0x11980816, 0x11988816,
+    0x11990816, 0x11998816, 0x119a0816, 0x119c8816, 0x11950816, 0x119e0816, 0x11a00816, 0x11a08816,
+    0x11a10816, 0x11a18816, 0x11a20816, 0x11a30816, 0x32e080b, 0x118f080a, 0x118f880a, 0x1190080a,
+    0x1191080a, 0x1191880a, 0x1193880a, 0x1194880a, 0x1195080a, 0x1195880a, 0x1196880a, 0x1197080a,
+    0x1198080a, 0x1198880a, 0x1199080a, 0x1199880a, 0x119a080a, 0x119c880a, 0x119e080a, 0x119e080a,
+    0x11a0080a, 0x11a0880a, 0x11a1080a, 0x11a1880a, 0x11a2080a, 0x11a3080a, 0x32c080b, 0x1191880a,
+    0x1195080a, 0x1196880a, 0x11a1080a, 0x119c880a, 0x119a080a, 0x118f0816, 0x118f8816, 0x11900816,
+    0x11910816, 0x11918816, 0x11938816, 0x11948816, 0x11950816, 0x11958816, 0x11968816, 0x11970816,
+    0x11980816, 0x11988816, 0x11990816, 0x11998816, 0x119a0816, 0x119c8816, 0x11950816, 0x119e0816,
+    0x11a00816, 0x11a08816, 0x11a10816, 0x11a18816, 0x11a20816, 0x11a30816, 0x32e080b, 0x118f080a,
+    0x118f880a, 0x1190080a, 0x1191080a, 0x1191880a, 0x1193880a, 0x1194880a, 0x1195080a, 0x1195880a,
+    0x1196880a, 0x1197080a, 0x1198080a, 0x1198880a, 0x1199080a, 0x1199880a, 0x119a080a, 0x119c880a,
+    0x119e080a, 0x119e080a, 0x11a0080a, 0x11a0880a, 0x11a1080a, 0x11a1880a, 0x11a2080a, 0x11a3080a,
+    0x32c080b, 0x1191880a, 0x1195080a, 0x1196880a, 0x11a1080a, 0x119c880a, 0x119a080a, 0x118f0816,
+    0x118f8816, 0x11900816, 0x11910816, 0x11918816, 0x11938816, 0x11948816, 0x11950816, 0x11958816,
+    0x11968816, 0x11970816, 0x11980816, 0x11988816, 0x11990816, 0x11998816, 0x119a0816, 0x119c8816,
+    0x11950816, 0x119e0816, 0x11a00816, 0x11a08816, 0x11a10816, 0x11a18816, 0x11a20816, 0x11a30816,
+    0x32e080b, 0x118f080a, 0x118f880a, 0x1190080a, 0x1191080a, 0x1191880a, 0x1193880a, 0x1194880a,
+    0x1195080a, 0x1195880a, 0x1196880a, 0x1197080a, 0x1198080a, 0x1198880a, 0x1199080a, 0x1199880a,
+    0x119a080a, 0x119c880a, 0x119e080a, 0x119e080a, 0x11a0080a, 0x11a0880a, 0x11a1080a, 0x11a1880a,
+    0x11a2080a, 0x11a3080a, 0x32c080b, 0x1191880a, 0x1195080a, 0x1196880a, 0x11a1080a, 0x119c880a,
+    0x119a080a, 0x11920816, 0x1192080a, 0xfcc080a, 0xfcc880a, 0xfcd080a, 0xfcd880a, 0xfce080a,
+    0xfce880a, 0xfcf080a, 0xfcf880a, 0xfd0080a, 0xfd0880a, 0xfcc080a, 0xfcc880a, 0xfcd080a,
+    0xfcd880a, 0xfce080a, 0xfce880a, 0xfcf080a, 0xfcf880a, 0xfd0080a, 0xfd0880a, 0xfcc080a,
+    0xfcc880a, 0xfcd080a, 0xfcd880a, 0xfce080a, 0xfce880a, 0xfcf080a, 0xfcf880a, 0xfd0080a,
+    0xfd0880a, 0xfcc080a, 0xfcc880a, 0xfcd080a, 0xfcd880a, 0xfce080a, 0xfce880a, 0xfcf080a,
+    0xfcf880a, 0xfd0080a, 0xfd0880a, 0xfcc080a, 0xfcc880a, 0xfcd080a, 0xfcd880a, 0xfce080a,
+    0xfce880a, 0xfcf080a, 0xfcf880a, 0xfd0080a, 0xfd0880a, 0xd7f8805, 0xd800805, 0xd808805,
+    0xd810805, 0xd818805, 0xd820805, 0xd828805, 0xd830805, 0xd838805, 0xd840805, 0xd848805,
+    0xd850805, 0xd858805, 0xd860805, 0xd868805, 0xd870805, 0xd878805, 0xd880805, 0xd888805,
+    0xd890805, 0xd898805, 0xd8a0805, 0xd8a8805, 0xd8b0805, 0xd8b8805, 0xd8c0805, 0xd8c8805,
+    0xd8d0805, 0xd8d8805, 0xd8e0805, 0xd8e8805, 0xd8f0805, 0xd8f8805, 0xd900805, 0xd908805,
+    0xd910805, 0xd918805, 0xd920805, 0xd928805, 0xd930805, 0xd938805, 0xd940805, 0xd948805,
+    0xd950805, 0xd958805, 0xd960805, 0xd968805, 0xd970805, 0xd978805, 0xd980805, 0xd988805,
+    0xd990805, 0xd998805, 0xd9a0805, 0xd9a8805, 0xd9b0805, 0xd9b8805, 0xd9c0805, 0xd9c8805,
+    0xd9d0805, 0xd9d8805, 0xd9e0805, 0xd9e8805, 0xd9f0805, 0xd9f8805, 0xda00805, 0xda08805,
+    0xda10805, 0xda18805, 0xda20805, 0xda28805, 0xda30805, 0xda38805, 0xda40805, 0xda48805,
+    0xda50805, 0xda58805, 0xda60805, 0xda68805, 0xda70805, 0xda78805, 0xda80805, 0xda88805,
+    0xda90805, 0xda98805, 0xdaa0805, 0xdaa8805, 0xdab0805, 0xdab8805, 0xdac0805, 0xdac8805,
+    0xdad0805, 0xdad8805, 0xdae0805, 0xdae8805, 0xdaf0805, 0xdaf8805, 0xdb00805, 0xdb08805,
+    0xdb10805, 0xdb18805, 0xdb20805, 0xdb28805, 0xdb30805, 0xdb38805, 0xdb40805, 0xdb48805,
+    0xdb50805, 0xdb58805, 0xdb60805, 0xdb68805, 0xdb70805, 0xdb78805, 0xdb80805, 0xdb88805,
+    0xdb90805, 0xdb98805, 0xdba0805, 0xdba8805, 0xdbb0805, 0xdbb8805, 0xdbc0805, 0xdbc8805,
+    0xdbd0805, 0xdbd8805, 0xdbe0805, 0xdbe8805, 0xdbf0805, 0xdbf8805, 0xdc00805, 0xdc08805,
+    0xdc10805, 0xdc18805, 0xdc20805, 0xdc28805, 0xdc30805, 0xdc38805, 0xdc40805, 0xdc48805,
+    0xdc50805, 0xdc58805, 0xdc60805, 0xdc68805, 0xdc70805, 0xdc78805, 0xdc80805, 0xdc88805,
+    0xdc90805, 0xdc98805, 0xdca0805, 0xdca8805, 0xdcb0805, 0xdcb8805, 0xdcc0805, 0xdcc8805,
+    0xdcd0805, 0xdcd8805, 0xdce0805, 0xdce8805, 0xdcf0805, 0xdcf8805, 0xdd00805, 0xdd08805,
+    0xdd10805, 0xdd18805, 0xdd20805, 0xdd28805, 0xdd30805, 0xdd38805, 0xdd40805, 0xdd48805,
+    0xdd50805, 0xdd58805, 0xdd60805, 0xdd68805, 0xdd70805, 0xdd78805, 0xdd80805, 0xdd88805,
+    0xdd90805, 0xdd98805, 0xdda0805, 0xdda8805, 0xddb0805, 0xddb8805, 0xddc0805, 0xddc8805,
+    0xddd0805, 0xddd8805, 0xdde0805, 0xdde8805, 0xddf0805, 0xddf8805, 0xde00805, 0xde08805,
+    0xde10805, 0xde18805, 0xde20805, 0xde28805, 0xde30805, 0xde38805, 0xde40805, 0xde48805,
+    0xde50805, 0xde58805, 0xde60805, 0xde68805, 0xde70805, 0xde78805, 0xde80805, 0xde88805,
+    0xde90805, 0xde98805, 0xdea0805, 0xdea8805, 0xdeb0805, 0xdeb8805, 0xdec0805, 0xdec8805,
+    0xded0805, 0xded8805, 0xdee0805, 0xdee8805, 0xdef0805, 0xdef8805, 0xdf00805, 0xdf08805,
+    0xdf10805, 0xdf18805, 0xdf20805, 0xdf28805, 0xdf30805, 0xdf38805, 0xdf40805, 0xdf48805,
+    0xdf50805, 0xdf58805, 0xdf60805, 0xdf68805, 0xdf70805, 0xdf78805, 0xdf80805, 0xdf88805,
+    0xdf90805, 0xdf98805, 0xdfa0805, 0xdfa8805, 0xdfb0805, 0xdfb8805, 0xdfc0805, 0xdfc8805,
+    0xdfd0805, 0xdfd8805, 0xdfe0805, 0xdfe8805, 0xdff0805, 0xdff8805, 0xe000805, 0xe008805,
+    0xe010805, 0xe018805, 0xe020805, 0xe028805, 0xe030805, 0xe038805, 0xe040805, 0xe048805,
+    0xe050805, 0xe058805, 0xe060805, 0xe068805, 0xe070805, 0xe078805, 0xe080805, 0xe088805,
+    0xe090805, 0xe098805, 0xe0a0805, 0xe0a8805, 0xe0b0805, 0xe0b8805, 0xe0c0805, 0xe0c8805,
+    0xe0d0805, 0xe0d8805, 0xe0e0805, 0xe0e8805, 0xe0f0805, 0xe0f8805, 0xe100805, 0xe108805,
+    0xe110805, 0xe118805, 0xe120805, 0xe128805, 0xe130805, 0xe138805, 0xe140805, 0xe148805,
+    0xe150805, 0xe158805, 0xe160805, 0xe168805, 0xe170805, 0xe178805, 0xe180805, 0xe188805,
+    0xe190805, 0xe198805, 0xe1a0805, 0xe1a8805, 0xe1b0805, 0xe1b8805, 0xe1c0805, 0xe1c8805,
+    0xe1d0805, 0xe1d8805, 0xe1e0805, 0xe1e8805, 0xe1f0805, 0xe1f8805, 0xe200805, 0xe208805,
+    0xe210805, 0xe218805, 0xe220805, 0xe228805, 0xe230805, 0xe238805, 0xe240805, 0xe248805,
+    0xe250805, 0xe258805, 0xe260805, 0xe268805, 0xe270805, 0xe278805, 0xe280805, 0xe288805,
+    0xe290805, 0xe298805, 0xe2a0805, 0xe2a8805, 0xe2b0805, 0xe2b8805, 0xe2c0805, 0xe2c8805,
+    0xe2d0805, 0xe2d8805, 0xe2e0805, 0xe2e8805, 0xe2f0805, 0xe2f8805, 0xe300805, 0xe308805,
+    0xe310805, 0xe318805, 0xe320805, 0xe328805, 0xe330805, 0xe338805, 0xe340805, 0xe348805,
+    0xe350805, 0xe358805, 0xe360805, 0xe368805, 0xe370805, 0xe378805, 0xe380805, 0xe388805,
+    0xe390805, 0xe398805, 0xe3a0805, 0xe3a8805, 0xe3b0805, 0xe3b8805, 0xe3c0805, 0xe3c8805,
+    0xe3d0805, 0xe3d8805, 0xe3e0805, 0xe3e8805, 0xe3f0805, 0xe3f8805, 0xe400805, 0xe408805,
+    0xe410805, 0xe418805, 0xe420805, 0xe428805, 0xe430805, 0xe438805, 0xe440805, 0xe448805,
+    0xe450805, 0xe458805, 0xe460805, 0xe468805, 0xe470805, 0xe478805, 0xe480805, 0xe488805,
+    0xe490805, 0xe498805, 0xe4a0805, 0xe4a8805, 0xe4b0805, 0xe4b8805, 0xe4c0805, 0xe4c8805,
+    0xe4d0805, 0xe4d8805, 0xe4e0805, 0xe4e8805, 0xe4f0805, 0xe4f8805, 0xe500805, 0xe508805,
+    0xe510805, 0xe518805, 0xe520805, 0xe528805, 0xe530805, 0xe538805, 0xe540805, 0xe548805,
+    0xe550805, 0xe558805, 0xe560805, 0xe568805, 0xe570805, 0xe578805, 0xe580805, 0xe588805,
+    0xe590805, 0xe598805, 0xe5a0805, 0xe5a8805, 0xe5b0805, 0xe5b8805, 0xe5c0805, 0xe5c8805,
+    0xe5d0805, 0xe5d8805, 0xe5e0805, 0xe5e8805, 0xe5f0805, 0xe5f8805, 0xe600805, 0xe608805,
+    0xe610805, 0xe618805, 0xe620805, 0xe628805, 0xe630805, 0xe638805, 0xe640805, 0xe648805,
+    0xe650805, 0xe658805, 0xe660805, 0xe668805, 0xe670805, 0xe678805, 0xe680805, 0xe688805,
+    0xe690805, 0xe698805, 0xe6a0805, 0xe6a8805, 0xe6b0805, 0xe6b8805, 0xe6c0805, 0xe6c8805,
+    0xe6d0805, 0xe6d8805, 0xe6e0805, 0xe6e8805, 0xe6f0805, 0xe6f8805, 0xe700805, 0xe708805,
+    0xe710805, 0xe718805, 0xe720805, 0xe728805, 0xe730805, 0xe738805, 0xe740805, 0xe748805,
+    0xe750805, 0xe758805, 0xe760805, 0xe768805, 0xe770805, 0xe778805, 0xe780805, 0xe788805,
+    0xe790805, 0xe798805, 0xe7a0805, 0xe7a8805, 0xe7b0805, 0xe7b8805, 0xe7c0805, 0xe7c8805,
+    0xe7d0805, 0xe7d8805, 0xe7e0805, 0xe7e8805, 0xe7f0805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xe7f8805, 0xe800805, 0xe808805, 0xe810805, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xe818805, 0xe820805, 0xe828805,
+    0xe830805, 0xe838805, 0xe840805, 0xe848805, 0xe850805, 0x0, 0xe858805, 0xe860805, 0xe868805,
+    0xe870805, 0xe878805, 0xe880805, 0xe888805, 0xe890805, 0xe898805, 0xe8a0805, 0xe8a8805,
+    0xe8b0805, 0xe8b8805, 0xe8c0805, 0x0, 0xe8c8805, 0xe8d0805, 0x2578805, 0x2580805, 0x2588805,
+    0x2590805, 0x2598805, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x12a50808, 0x12a58808, 0x12a60808, 0x12a68808, 0x12a70808,
+    0x12a78808, 0x12a80808, 0x12a90808, 0x12a98808, 0x12aa0808, 0x12aa8808, 0x12ab0808, 0x12ab8808,
+    0x12ac0808, 0x12ac8808, 0x12ad0808, 0x12ad8808, 0x12ae0808, 0x12ae8808, 0x12af0808, 0x12af8808,
+    0x12b00808, 0x12b08808, 0x12b10808, 0x12b28808, 0x12b30808, 0x12b38808, 0x12b40808, 0x12b48808,
+    0x12b50808, 0x12b58808, 0x12b68808, 0x12b70808, 0x12b80808, 0x12b88808, 0x12b90808, 0x12b98808,
+    0x12ba0808, 0x25310804, 0x25318804, 0x25320804, 0x25328804, 0x25330804, 0x25338804, 0x25340804,
+    0x25348804, 0x25350804, 0x25358804, 0x25360804, 0x25368804, 0x25370804, 0x25378804, 0x25380804,
+    0x25388804, 0x25390804, 0x25398804, 0x253a0804, 0x253a8804, 0x253b0804, 0x253b8804, 0x253c0804,
+    0x253c8804, 0x253d0804, 0x253d8804, 0x253e0804, 0x253e8804, 0x253f0804, 0x253f8804, 0x25400804,
+    0x25408804, 0x25410804, 0x25418804, 0x25420804, 0x25428804, 0x25430804, 0x25438804, 0x25440804,
+    0x25448804, 0x25450804, 0x25458804, 0x25460804, 0x25468804, 0x25470804, 0x3e84, 0x3d84, 0x3e04,
+    0x3e44, 0x3ec4, 0x3f04, 0x3dc4, 0x25478804, 0x25480804, 0x25488804, 0x25490804, 0x25498804,
+    0xfaa8804, 0xfab0804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x254a0804, 0x8918805, 0x254a8804, 0x254b0804,
+    0x254b8804, 0x254c0804, 0x254c8804, 0x254d0804, 0x254d8804, 0x254e0804, 0x254e8804, 0x254f0804,
+    0x254f8804, 0x25500804, 0x25508804, 0x25510804, 0x25518804, 0x25520804, 0x25528804, 0x25530804,
+    0x25538804, 0x25540804, 0x25548804, 0x25550804, 0x25558804, 0x25560804, 0x25568804, 0x25570804,
+    0x25578804, 0x25580804, 0x25588804, 0x25590804, 0x25598804, 0x255a0804, 0x255a8804, 0x255b0804,
+    0x255b8804, 0x255c0804, 0x255c8804, 0x255d0804, 0x255d8804, 0x255e0804, 0x255e8804, 0x255f0804,
+    0x255f8804, 0x25600804, 0x3f44, 0x3f84, 0x3fc4, 0x4004, 0xfcc0804, 0xfcc8804, 0xfcd0804,
+    0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xfbb8804,
+    0x20368804, 0x20370804, 0x20378804, 0x20380804, 0x20388804, 0x20390804, 0x20398804, 0x203a0804,
+    0x203a8804, 0x203b0804, 0x203b8804, 0x203c0804, 0x203c8804, 0x203d0804, 0x203d8804, 0x203e0804,
+    0x203e8804, 0x203f0804, 0x203f8804, 0x20400804, 0x20408804, 0x20410804, 0x20418804, 0x20420804,
+    0x20428804, 0x20430804, 0x20438804, 0x20440804, 0x20448804, 0x20450804, 0x20458804, 0x20460804,
+    0x20468804, 0x20470804, 0x20478804, 0x20480804, 0x20488804, 0x20490804, 0x20498804, 0x204a0804,
+    0x204a8804, 0x204b0804, 0x204b8804, 0x204c0804, 0x204c8804, 0x204d0804, 0x204d8804, 0x204e0804,
+    0x204e8804, 0x204f0804, 0x204f8804, 0x20500804, 0x20508804, 0x20510804, 0x20518804, 0x20520804,
+    0x20528804, 0x20530804, 0x20538804, 0x20540804, 0x20548804, 0x20550804, 0x20558804, 0x20560804,
+    0x20568804, 0x20570804, 0x20578804, 0x20580804, 0x20588804, 0x20590804, 0x20598804, 0x205a0804,
+    0x205a8804, 0x205b0804, 0x205b8804, 0x205c0804, 0x205c8804, 0x205d0804, 0x205d8804, 0x205e0804,
+    0x205e8804, 0x205f0804, 0x205f8804, 0x20600804, 0x20608804, 0x20610804, 0x20618804, 0x20620804,
+    0x20628804, 0x20630804, 0x20638804, 0x20640804, 0x20648804, 0x20650804, 0x20658804, 0x20660804,
+    0x20668804, 0x20670804, 0x20678804, 0x20680804, 0x20688804, 0x20690804, 0x20698804, 0x206a0804,
+    0x206a8804, 0x206b0804, 0x206b8804, 0x206c0804, 0x206c8804, 0x206d0804, 0x206d8804, 0x206e0804,
+    0x206e8804, 0x206f0804, 0x206f8804, 0x20700804, 0x20708804, 0x20710804, 0x20718804, 0x20720804,
+    0x20728804, 0x20730804, 0x20738804, 0x20740804, 0x20748804, 0x20750804, 0x20758804, 0x20760804,
+    0x20768804, 0x20770804, 0x20778804, 0x20780804, 0x20788804, 0x20790804, 0x20798804, 0x207a0804,
+    0x207a8804, 0x207b0804, 0x207b8804, 0x207c0804, 0x207c8804, 0x207d0804, 0x207d8804, 0x207e0804,
+    0x207e8804, 0x207f0804, 0x207f8804, 0x20800804, 0x20808804, 0x20810804, 0x20818804, 0x20820804,
+    0x20828804, 0x20830804, 0x20838804, 0x20840804, 0x20848804, 0x20850804, 0x20858804, 0x20860804,
+    0x20868804, 0x20870804, 0x20878804, 0x20880804, 0x20888804, 0x20890804, 0x20898804, 0x208a0804,
+    0x208a8804, 0x208b0804, 0x208b8804, 0x208c0804, 0x208c8804, 0x208d0804, 0x208d8804, 0x208e0804,
+    0x208e8804, 0x208f0804, 0x208f8804, 0x20900804, 0x20908804, 0x20910804, 0x20918804, 0x20920804,
+    0x20928804, 0x20930804, 0x20938804, 0x20940804, 0x20948804, 0x20950804, 0x20958804, 0x20960804,
+    0x20968804, 0x20970804, 0x20978804, 0x20980804, 0x20988804, 0xfcc8804, 0xfcd0804, 0xfcd8804,
+    0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x20a90810, 0x20a98810, 0x20aa0810, 0x20aa8810, 0x20ab0810, 0x20ab8810, 0x20ac0810,
+    0x20ac8810, 0x20ad0810, 0x20ad8810, 0x20ae0810, 0x20ae8810, 0x20af0810, 0x20af8810, 0x20b00810,
+    0x20b08810, 0x20b10810, 0x20b18810, 0x20b20810, 0x20b28810, 0x20b30810, 0x20b38810, 0x20b40810,
+    0x20b48810, 0x20b50810, 0x20b58810, 0x20b60810, 0x20b68810, 0x20b70810, 0x20b78810, 0x20b80810,
+    0x20b88810, 0x20b90810, 0x20b98810, 0x20a90804, 0x20a98804, 0x20aa0804, 0x20aa8804, 0x20ab0804,
+    0x20ab8804, 0x20ac0804, 0x20ac8804, 0x20ad0804, 0x20ad8804, 0x20ae0804, 0x20ae8804, 0x20af0804,
+    0x20af8804, 0x20b00804, 0x20b08804, 0x20b10804, 0x20b18804, 0x20b20804, 0x20b28804, 0x20b30804,
+    0x20b38804, 0x20b40804, 0x20b48804, 0x20b50804, 0x20b58804, 0x20b60804, 0x20b68804, 0x20b70804,
+    0x20b78804, 0x20b80804, 0x20b88804, 0x20b90804, 0x20b98804, 0x2f44, 0x2f44, 0x2f44, 0x2fc4,
+    0x3004, 0x3044, 0x2f84, 0x20ba0804, 0xfcc0804, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804,
+    0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0x1360805, 0x13d8805, 0xfcc8804,
+    0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804,
+    0xf4e0805, 0xf4e8805, 0xf4f0805, 0xf4f8805, 0xf500805, 0xf508805, 0xf510805, 0xf518805,
+    0xf520805, 0xf528805, 0xf530805, 0xf538805, 0xf540805, 0xf548805, 0xf550805, 0xf558805,
+    0xf560805, 0xf568805, 0xf570805, 0xf578805, 0xf580805, 0xf588805, 0xf590805, 0xf598805,
+    0xf5a0805, 0xf5a8805, 0xf5b0805, 0xf5b8805, 0xf5c0805, 0xf5c8805, 0xf5d0805, 0xf5d8805,
+    0xf5e0805, 0xf5e8805, 0xf5f0805, 0xf5f8805, 0xf600805, 0xf608805, 0xf610805, 0xf618805,
+    0xf620805, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804, 0xfcf0804, 0xfcf8804,
+    0xfd00804, 0xfd08804, 0x98a8805, 0xf628805, 0xf630805, 0xf638805, 0xfc88804, 0x8003bb62,
+    0x8003bba2, 0xf640805, 0xf648805, 0xfcc8804, 0xfcd0804, 0xfcd8804, 0xfce0804, 0xfce8804,
+    0xfcf0804, 0xfcf8804, 0xfd00804, 0xfd08804, 0xf650805, 0xf658805, 0xf660805, 0xf668805,
+    0xf670805, 0xf678805, 0xf680805, 0xf688805, 0xf690805, 0xf698805, 0xf6a0805, 0xf6a8805,
+    0xf6b0805, 0xf6b8805, 0xf6c0805, 0xf6c8805, 0xf6d0805, 0xf6d8805, 0xf6e0805, 0xf6e8805,
+    0xf6f0805, 0xf6f8805, 0xf700805, 0xf708805, 0xf710805, 0xf718805, 0xf720805, 0xf728805,
+    0xf730805, 0xf738805, 0xf740805, 0xf748805, 0xf750805, 0xf758805, 0xf760805, 0xf768805,
+    0x98b0805, 0x8003bbe2, 0x8003bc22, 0x8003bc62, 0x8003bca2, 0x8003bce2, 0x8003bd22, 0x8003bd62,
+    0x8003bda2, 0xf770805, 0xf778805, 0xf780805, 0xf788805, 0xf790805, 0xf798805, 0xf7a0805,
+    0x1339080a, 0x133a080a, 0x1347880a, 0x1352080a, 0x1394080a, 0x135a080a, 0x134c880a, 0x136b880a,
+    0x139b880a, 0x137b080a, 0x1386080a, 0x138a080a, 0x138c080a, 0x1363080a, 0x136d880a, 0x1372080a,
+    0x1368880a, 0x1378080a, 0x1359880a, 0x1363880a, 0x1342880a, 0x1343080a, 0x134d080a, 0x1352880a,
+    0x1369080a, 0x136c080a, 0x136e080a, 0x1339880a, 0x138c880a, 0x1372880a, 0x1377880a, 0x133a080a,
+    0x1347880a, 0x1391080a, 0x134c880a, 0x139b880a, 0x137b080a, 0x1386080a, 0x138a080a, 0x138c080a,
+    0x1363080a, 0x136d880a, 0x1372080a, 0x1368880a, 0x1378080a, 0x1363880a, 0x1342880a, 0x1343080a,
+    0x134d080a, 0x1369080a, 0x136e080a, 0x1347880a, 0x134c880a, 0x139b880a, 0x1386080a, 0x138c080a,
+    0x1363080a, 0x136d880a, 0x1368880a, 0x1378080a, 0x1363880a, 0x134d080a, 0x1369080a, 0x136e080a,
+    0x138c880a, 0x1377880a, 0x133a080a, 0x1347880a, 0x1391080a, 0x134c880a, 0x136b880a, 0x139b880a,
+    0x137b080a, 0x138a080a, 0x138c080a, 0x1363080a, 0x136d880a, 0x1372080a, 0x1368880a, 0x1378080a,
+    0x1363880a, 0x1342880a, 0x1343080a, 0x134d080a, 0x1369080a, 0x136c080a, 0x136e080a, 0x1339880a,
+    0x1372880a, 0x1339080a, 0x133a080a, 0x1347880a, 0x1352080a, 0x1391080a, 0x1394080a, 0x135a080a,
+    0x134c880a, 0x136b880a, 0x139b880a, 0x1386080a, 0x138a080a, 0x138c080a, 0x1363080a, 0x136d880a,
+    0x1372080a, 0x1368880a, 0x1378080a, 0x1359880a, 0x1363880a, 0x1342880a, 0x1343080a, 0x134d080a,
+    0x1352880a, 0x1369080a, 0x136c080a, 0x136e080a, 0x133a080a, 0x1347880a, 0x1352080a, 0x1394080a,
+    0x135a080a, 0x134c880a, 0x136b880a, 0x139b880a, 0x1386080a, 0x138a080a, 0x138c080a, 0x1363080a,
+    0x136d880a, 0x1372080a, 0x1368880a, 0x1378080a, 0x1359880a, 0x1363880a, 0x1342880a, 0x1343080a,
+    0x134d080a, 0x1352880a, 0x1369080a, 0x136c080a, 0x136e080a, 0x2978805, 0x2980805, 0x98b8805,
+    0x98c0805, 0x98c8805, 0x98d0805, 0x98d8805, 0x98e0805, 0x98e8805, 0x98f0805, 0x98f8805,
+    0x9900805, 0x9908805, 0x9910805, 0x9918805, 0x9920805, 0x9928805, 0x9930805, 0x9938805,
+    0x9940805, 0x9948805, 0x9950805, 0x9958805, 0x9960805, 0x9968805, 0x9970805, 0x9978805,
+    0x9980805, 0x9988805, 0x9990805, 0x9998805, 0x99a0805, 0x99a8805, 0x99b0805, 0x99b8805,
+    0x99c0805, 0x99c8805, 0x99d0805, 0x99d8805, 0x99e0805, 0x99e8805, 0x99f0805, 0x99f8805,
+    0x9a00805, 0x9a08805, 0x9a10805, 0x9a18805, 0x9a20805, 0x9a28805, 0x9a30805, 0x9a38805,
+    0x9a40805, 0x9a48805, 0x9a50805, 0x9a58805, 0x9a60805, 0x9a68805, 0x9a70805, 0x9a78805,
+    0x9a80805, 0x9a88805, 0x9a90805, 0x9a98805, 0x9aa0805, 0x9aa8805, 0x9ab0805, 0x9ab8805,
+    0x9ac0805, 0x9ac8805, 0x9ad0805, 0x9ad8805, 0x9ae0805, 0x9ae8805, 0x9af0805, 0x9af8805,
+    0x9b00805, 0x9b08805, 0x9b10805, 0x9b18805, 0x9b20805, 0x9b28805, 0x9b30805, 0x9b38805,
+    0x9b40805, 0x9b48805, 0x9b50805, 0x9b58805, 0x9b60805, 0x9b68805, 0x9b70805, 0x9b78805,
+    0x9b80805, 0x9b88805, 0x9b90805, 0x9b98805, 0x9ba0805, 0x9ba8805, 0x9bb0805, 0x9bb8805,
+    0x9bc0805, 0x9bc8805, 0x9bd0805, 0x9bd8805, 0x9be0805, 0x9be8805, 0x9bf0805, 0x9bf8805,
+    0x9c00805, 0x9c08805, 0x9c10805, 0x9c18805, 0x9c20805, 0x9c28805, 0x9c30805, 0x9c38805,
+    0x9c40805, 0x9c48805, 0x9c50805, 0x9c58805, 0x9c60805, 0x9c68805, 0x9c70805, 0x9c78805,
+    0x9c80805, 0x9c88805, 0x9c90805, 0x9c98805, 0x9ca0805, 0x9ca8805, 0x9cb0805, 0x9cb8805,
+    0x9cc0805, 0x9cc8805, 0x9cd0805, 0x9cd8805, 0x9ce0805, 0x9ce8805, 0x9cf0805, 0x9cf8805,
+    0x9d00805, 0x9d08805, 0x9d10805, 0x9d18805, 0x9d20805, 0x9d28805, 0x9d30805, 0x9d38805,
+    0x9d40805, 0x9d48805, 0x9d50805, 0x9d58805, 0x9d60805, 0x9d68805, 0x9d70805, 0x9d78805,
+    0x9d80805, 0x9d88805, 0x9d90805, 0x9d98805, 0x9da0805, 0x9da8805, 0x9db0805, 0x9db8805,
+    0x9dc0805, 0x9dc8805, 0x9dd0805, 0x9dd8805, 0x9de0805, 0x9de8805, 0x9df0805, 0x9df8805,
+    0x9e00805, 0x9e08805, 0x9e10805, 0x9e18805, 0x9e20805, 0x9e28805, 0x9e30805, 0x9e38805,
+    0x9e40805, 0x9e48805, 0x9e50805, 0x9e58805, 0x9e60805, 0x9e68805, 0x9e70805, 0x9e78805,
+    0x9e80805, 0x9e88805, 0x9e90805, 0x9e98805, 0x9ea0805, 0x9ea8805, 0x9eb0805, 0x9eb8805,
+    0x9ec0805, 0x9ec8805, 0x9ed0805, 0x9ed8805, 0x9ee0805, 0x9ee8805, 0x9ef0805, 0x9ef8805,
+    0x9f00805, 0x9f08805, 0x9f10805, 0x9f18805, 0x9f20805, 0x9f28805, 0x9f30805, 0x9f38805,
+    0x9f40805, 0x9f48805, 0x9f50805, 0x9f58805, 0x9f60805, 0x9f68805, 0x9f70805, 0x9f78805,
+    0x9f80805, 0x9f88805, 0x9f90805, 0x9f98805, 0x9fa0805, 0x9fa8805, 0x9fb0805, 0x9fb8805,
+    0x9fc0805, 0x8003bde2, 0x8003be22, 0x8003be62, 0x8003bea2, 0x8003bee2, 0x8003bf22, 0x8003bf62,
+    0x8003bfa2, 0x8003bfe2, 0x8003c022, 0x8003c062, 0xfcc080c, 0xfcc080c, 0xa2d8805, 0xa2e0805,
+    0xa2e8805, 0x8003c0a3, 0x8003c103, 0x8003c163, 0x8003c1c3, 0x8003c223, 0x8003c283, 0x8003c2e3,
+    0x8003c343, 0x8003c3a3, 0x8003c403, 0x8003c463, 0x8003c4c3, 0x8003c523, 0x8003c583, 0x8003c5e3,
+    0x8003c643, 0x8003c6a3, 0x8003c703, 0x8003c763, 0x8003c7c3, 0x8003c823, 0x8003c883, 0x8003c8e3,
+    0x8003c943, 0x8003c9a3, 0x8003ca03, 0x8003ca63, 0xfeb0818, 0x10c98818, 0x8003cac2, 0x8003cb02,
+    0x2e98805, 0xfd1083a, 0xfde083a, 0xfeb083a, 0xff5883a, 0x1003883a, 0x1021083a, 0x1028883a,
+    0x103a883a, 0x1048083a, 0x1055883a, 0x1062083a, 0x106b083a, 0x1084883a, 0x108c083a, 0x109e083a,
+    0x10b5883a, 0x10c0083a, 0x10c9883a, 0x10e9083a, 0x10fb883a, 0x110b883a, 0x1123883a, 0x112c883a,
+    0x1132083a, 0x1138083a, 0x1143083a, 0x8003cb42, 0x8003cb82, 0x8003cbc2, 0x8003cc02, 0x8003cc43,
+    0x8003cca2, 0xfd10818, 0xfde0818, 0xfeb0818, 0xff58818, 0x10038818, 0x10210818, 0x10288818,
+    0x103a8818, 0x10480818, 0x10558818, 0x10620818, 0x106b0818, 0x10848818, 0x108c0818, 0x109e0818,
+    0x10b58818, 0x10c00818, 0x10c98818, 0x10e90818, 0x10fb8818, 0x110b8818, 0x11238818, 0x112c8818,
+    0x11320818, 0x11380818, 0x11430818, 0x8003cce2, 0x8003cd22, 0x8003cd62, 0xa2f0805, 0xa2f8805,
+    0xa300805, 0xfd1083a, 0xfde083a, 0xfeb083a, 0xff5883a, 0x1003883a, 0x1021083a, 0x1028883a,
+    0x103a883a, 0x1048083a, 0x1055883a, 0x1062083a, 0x106b083a, 0x1084883a, 0x108c083a, 0x109e083a,
+    0x10b5883a, 0x10c0083a, 0x10c9883a, 0x10e9083a, 0x10fb883a, 0x110b883a, 0x1123883a, 0x112c883a,
+    0x1132083a, 0x1138083a, 0x1143083a, 0x10b5883a, 0x8003cda2, 0x8003cde2, 0x8003ce22, 0x8003ce62,
+    0x8003cea2, 0x8003cee2, 0x8003cf22, 0x8003cf64, 0x8003cfe4, 0x8003d062, 0x8003d0a3, 0x8003d102,
+    0x8003d142, 0x8003d183, 0x8003d1e3, 0x8003d242, 0x8003d282, 0x8003d2c7, 0x8003d3a2, 0x8003d3e2,
+    0x8003d422, 0x8003d463, 0x8003d4c3, 0x8003d524, 0x8003d5a3, 0x8003d604, 0xff58838, 0x8003d682,
+    0x8003d6c3, 0x8003d726, 0x8003d7e8, 0x8003d8e3, 0x8003d943, 0x8003d9a3, 0x2ea8805, 0x5910805,
+    0x5918805, 0x5920805, 0x5928805, 0x5930805, 0x5938805, 0x5940805, 0x5948805, 0x5950805,
+    0x5958805, 0x5960805, 0x5968805, 0x5970805, 0x5978805, 0x5980805, 0x5988805, 0x5990805,
+    0x5998805, 0x59a0805, 0x59a8805, 0x59b0805, 0x59b8805, 0x59c0805, 0x59c8805, 0x59d0805,
+    0x59d8805, 0x8003da02, 0x8003da42, 0x21728838, 0x8003da82, 0x8003dac2, 0x8003db02, 0x8003db42,
+    0x8003db82, 0x8003dbc2, 0x8003dc02, 0x8003dc42, 0x8003dc82, 0x8003dcc2, 0x8003dd02, 0x8003dd42,
+    0x8003dd82, 0x8003ddc2, 0x8003de02, 0x8003de42, 0x8003de82, 0x8003dec2, 0x8003df02, 0x8003df42,
+    0x8003df82, 0x8003dfc2, 0x8003e002, 0x8003e042, 0x8003e082, 0x8003e0c2, 0x8003e102, 0x8003e142,
+    0x8003e182, 0x8003e1c2, 0x8003e202, 0x8003e242, 0x8003e282, 0x8003e2c2, 0x8003e302, 0x8003e342,
+    0x8003e382, 0x8003e3c2, 0x8003e402, 0x8003e442, 0x8003e482, 0x8003e4c2, 0x8003e502, 0x8003e542,
+    0x8003e584, 0x8003e604, 0x8003e684, 0x8003e704, 0x8003e784, 0x8003e804, 0x8003e884, 0x8003e904,
+    0x8003e984, 0x8003ea02, 0x8003ea42, 0xa308805, 0xa310805, 0xa318805, 0xa320805, 0xa328805,
+    0xa330805, 0xa338805, 0xa340805, 0xa348805, 0xa350805, 0xa358805, 0xa360805, 0xa368805,
+    0xa370805, 0xa378805, 0xa380805, 0xa388805, 0xa390805, 0xa398805, 0xa3a0805, 0xa3a8805,
+    0xa3b0805, 0xa3b8805, 0xa3c0805, 0xa3c8805, 0xa3d0805, 0xa3d8805, 0xa3e0805, 0xa3e8805,
+    0xa3f0805, 0xa3f8805, 0xa400805, 0xa408805, 0xa410805, 0xa418805, 0xa420805, 0xa428805,
+    0xa430805, 0xa438805, 0xa440805, 0xa448805, 0xa450805, 0xa458805, 0xa460805, 0xa468805,
+    0xa470805, 0xa478805, 0xa480805, 0xa488805, 0xa490805, 0xa498805, 0xa4a0805, 0xa4a8805,
+    0xa4b0805, 0xa4b8805, 0xa4c0805, 0xa4c8805, 0xa4d0805, 0xa4d8805, 0xa4e0805, 0xa4e8805,
+    0xa4f0805, 0xa4f8805, 0xa500805, 0xa508805, 0xa510805, 0xa518805, 0xa520805, 0xa528805,
+    0xa530805, 0xa538805, 0xa540805, 0xa548805, 0xa550805, 0xa558805, 0xa560805, 0xa568805,
+    0xa570805, 0xa578805, 0xa580805, 0xa588805, 0xa590805, 0xa598805, 0xa5a0805, 0xa5a8805,
+    0xa5b0805, 0xa5b8805, 0xa5c0805, 0xa5c8805, 0xa5d0805, 0xa5d8805, 0xa5e0805, 0xa5e8805,
+    0xa5f0805, 0xa5f8805, 0xa600805, 0xa608805, 0xa610805, 0xa618805, 0xa620805, 0xa628805,
+    0xa630805, 0xa638805, 0xa640805, 0xa648805, 0xa650805, 0xa658805, 0xa660805, 0xa668805,
+    0xa670805, 0xa678805, 0xa680805, 0xa688805, 0xa690805, 0xa698805, 0xa6a0805, 0xa6a8805,
+    0xa6b0805, 0xa6b8805, 0xa6c0805, 0xa6c8805, 0xa6d0805, 0xa6d8805, 0xa6e0805, 0xa6e8805,
+    0xa6f0805, 0xa6f8805, 0xa700805, 0xa708805, 0xa710805, 0xa718805, 0xa720805, 0xa728805,
+    0xa730805, 0xa738805, 0xa740805, 0xa748805, 0xa750805, 0xa758805, 0xa760805, 0xa768805,
+    0xa770805, 0xa778805, 0xa780805, 0xa788805, 0xa790805, 0xa798805, 0xa7a0805, 0xa7a8805,
+    0xa7b0805, 0xa7b8805, 0xa7c0805, 0xa7c8805, 0xa7d0805, 0xa7d8805, 0xa7e0805, 0xa7e8805,
+    0xa7f0805, 0xa7f8805, 0xa800805, 0xa808805, 0xa810805, 0xa818805, 0xa820805, 0xa828805,
+    0xa830805, 0xa838805, 0xa840805, 0xa848805, 0xa850805, 0xa858805, 0xa860805, 0xa868805,
+    0xa870805, 0xa878805, 0xa880805, 0xa888805, 0xa890805, 0xa898805, 0xa8a0805, 0xa8a8805,
+    0xa8b0805, 0xa8b8805, 0xa8c0805, 0xa8c8805, 0xa8d0805, 0xa8d8805, 0xa8e0805, 0xa8e8805,
+    0xa8f0805, 0xa8f8805, 0xa900805, 0xa908805, 0xa910805, 0xa918805, 0xa920805, 0xa928805,
+    0xa930805, 0xa938805, 0xa940805, 0xa948805, 0xa950805, 0xa958805, 0xa960805, 0xa968805,
+    0xa970805, 0xa978805, 0xa980805, 0xa988805, 0xa990805, 0xa998805, 0xa9a0805, 0xa9a8805,
+    0xa9b0805, 0xa9b8805, 0xa9c0805, 0xa9c8805, 0xa9d0805, 0xa9d8805, 0xa9e0805, 0xa9e8805,
+    0xa9f0805, 0xa9f8805, 0xaa00805, 0xaa08805, 0xaa10805, 0xaa18805, 0xaa20805, 0xaa28805,
+    0xaa30805, 0xaa38805, 0xaa40805, 0xaa48805, 0xaa50805, 0xaa58805, 0xaa60805, 0xaa68805,
+    0xaa70805, 0xaa78805, 0xaa80805, 0xaa88805, 0xaa90805, 0xaa98805, 0xaaa0805, 0xaaa8805,
+    0xaab0805, 0xaab8805, 0xaac0805, 0xaac8805, 0xaad0805, 0xaad8805, 0xaae0805, 0xaae8805,
+    0xaaf0805, 0xaaf8805, 0xab00805, 0xab08805, 0xab10805, 0xab18805, 0xab20805, 0xab28805,
+    0xab30805, 0xab38805, 0xab40805, 0xab48805, 0xab50805, 0xab58805, 0xab60805, 0xab68805,
+    0xab70805, 0xab78805, 0xab80805, 0xab88805, 0xab90805, 0xab98805, 0xaba0805, 0xaba8805,
+    0xabb0805, 0xabb8805, 0xabc0805, 0xabc8805, 0xabd0805, 0xabd8805, 0xabe0805, 0xabe8805,
+    0xabf0805, 0xabf8805, 0xac00805, 0xac08805, 0xac10805, 0xac18805, 0xac20805, 0xac28805,
+    0xac30805, 0xac38805, 0xac40805, 0xac48805, 0xac50805, 0xac58805, 0xac60805, 0xac68805,
+    0xac70805, 0xac78805, 0xac80805, 0xac88805, 0xac90805, 0xac98805, 0xaca0805, 0xaca8805,
+    0xacb0805, 0xacb8805, 0xacc0805, 0xacc8805, 0xacd0805, 0xacd8805, 0xace0805, 0xace8805,
+    0xacf0805, 0xacf8805, 0xad00805, 0xad08805, 0xad10805, 0xad18805, 0xad20805, 0xad28805,
+    0xad30805, 0xad38805, 0xad40805, 0xad48805, 0xad50805, 0xad58805, 0xad60805, 0xad68805,
+    0xad70805, 0xad78805, 0xad80805, 0xad88805, 0xad90805, 0xad98805, 0xada0805, 0xada8805,
+    0xadb0805, 0xadb8805, 0xadc0805, 0xadc8805, 0xadd0805, 0xadd8805, 0xade0805, 0xade8805,
+    0xadf0805, 0xadf8805, 0xae00805, 0xae08805, 0xae10805, 0xae18805, 0xae20805, 0xae28805,
+    0xae30805, 0xae38805, 0xae40805, 0xae48805, 0xae50805, 0xae58805, 0xae60805, 0xae68805,
+    0xae70805, 0xae78805, 0xae80805, 0xae88805, 0xae90805, 0xae98805, 0xaea0805, 0xaea8805,
+    0xaeb0805, 0xaeb8805, 0xaec0805, 0xaec8805, 0xaed0805, 0xaed8805, 0xaee0805, 0xaee8805,
+    0xaef0805, 0xaef8805, 0xaf00805, 0xaf08805, 0xaf10805, 0xaf18805, 0xaf20805, 0xaf28805,
+    0xaf30805, 0xaf38805, 0xaf40805, 0xaf48805, 0xaf50805, 0xaf58805, 0xaf60805, 0xaf68805,
+    0xaf70805, 0xaf78805, 0xaf80805, 0xaf88805, 0xaf90805, 0xaf98805, 0xafa0805, 0xafa8805,
+    0xafb0805, 0xafb8805, 0xafc0805, 0xafc8805, 0xafd0805, 0xafd8805, 0xafe0805, 0xafe8805,
+    0xaff0805, 0xaff8805, 0xb000805, 0xb008805, 0xb010805, 0xb018805, 0xb020805, 0xb028805,
+    0xb030805, 0xb038805, 0xb040805, 0xb048805, 0xb050805, 0xb058805, 0xb060805, 0xb068805,
+    0xb070805, 0xb078805, 0xb080805, 0xb088805, 0xb090805, 0xb098805, 0xb0a0805, 0xb0a8805,
+    0xb0b0805, 0xb0b8805, 0xb0c0805, 0xb0c8805, 0xb0d0805, 0xb0d8805, 0xb0e0805, 0xb0e8805,
+    0xb0f0805, 0xb0f8805, 0xb100805, 0xb108805, 0xb110805, 0xb118805, 0xb120805, 0xb128805,
+    0xb130805, 0xb138805, 0xb140805, 0xb148805, 0xb150805, 0xb158805, 0xb160805, 0xb168805,
+    0xb170805, 0xb178805, 0xb180805, 0xb188805, 0xb190805, 0xb198805, 0xb1a0805, 0xb1a8805,
+    0xb1b0805, 0xb1b8805, 0xb1c0805, 0xb1c8805, 0xb1d0805, 0xb1d8805, 0xb1e0805, 0xb1e8805,
+    0xb1f0805, 0xb1f8805, 0xb200805, 0xb208805, 0xb210805, 0xb218805, 0xb220805, 0xb228805,
+    0xb230805, 0xb238805, 0xb240805, 0xb248805, 0xb250805, 0xb258805, 0xb260805, 0xb268805,
+    0xb270805, 0xb278805, 0xb280805, 0xb288805, 0xb290805, 0xb298805, 0xb2a0805, 0xb2a8805,
+    0xb2b0805, 0xb2b8805, 0xb2c0805, 0xb2c8805, 0xb2d0805, 0xb2d8805, 0xb2e0805, 0xb2e8805,
+    0xb2f0805, 0xb2f8805, 0xb300805, 0xb308805, 0xb310805, 0xb318805, 0xb320805, 0xb328805,
+    0xb330805, 0xb338805, 0xb340805, 0xb348805, 0xb350805, 0xb358805, 0xb360805, 0xb368805,
+    0xb370805, 0xb378805, 0xb380805, 0xb388805, 0xb390805, 0xb398805, 0xb3a0805, 0xb3a8805,
+    0xb3b0805, 0xb3b8805, 0xb3c0805, 0xb3c8805, 0xb3d0805, 0xb3d8805, 0xb3e0805, 0xb3e8805,
+    0xb3f0805, 0xb3f8805, 0xb400805, 0xb408805, 0xb410805, 0xb418805, 0xb420805, 0xb428805,
+    0xb430805, 0xb438805, 0xb440805, 0xb448805, 0xb450805, 0xb458805, 0xb460805, 0xb468805,
+    0xb470805, 0xb478805, 0xb480805, 0xb488805, 0xb490805, 0xb498805, 0xb4a0805, 0xb4a8805,
+    0xb4b0805, 0xb4b8805, 0xb4c0805, 0xb4c8805, 0xb4d0805, 0xb4d8805, 0xb4e0805, 0xb4e8805,
+    0xb4f0805, 0xb4f8805, 0xb500805, 0xb508805, 0xb510805, 0xb518805, 0xb520805, 0xb528805,
+    0xb530805, 0xb538805, 0xb540805, 0xb548805, 0xb550805, 0xb558805, 0xb560805, 0xb568805,
+    0xb570805, 0xb578805, 0xb580805, 0xb588805, 0xb590805, 0xb598805, 0xb5a0805, 0xb5a8805,
+    0xb5b0805, 0xb5b8805, 0xb5c0805, 0xb5c8805, 0xb5d0805, 0xb5d8805, 0xb5e0805, 0xb5e8805,
+    0xb5f0805, 0xb5f8805, 0xb600805, 0xb608805, 0xb610805, 0xb618805, 0xb620805, 0xb628805,
+    0xb630805, 0xb638805, 0xb640805, 0xb648805, 0xb650805, 0xb658805, 0xb660805, 0xb668805,
+    0xb670805, 0xb678805, 0xb680805, 0xb688805, 0xb690805, 0xb698805, 0xb6a0805, 0xb6a8805,
+    0xb6b0805, 0xb6b8805, 0xb6c0805, 0xb6c8805, 0xb6d0805, 0xb6d8805, 0xb6e0805, 0xb6e8805,
+    0xb6f0805, 0xb6f8805, 0xb700805, 0xb708805, 0xb710805, 0xb718805, 0xb720805, 0xb728805,
+    0xb730805, 0xb738805, 0xb740805, 0xb748805, 0xb750805, 0xb758805, 0xb760805, 0xb768805,
+    0xb770805, 0xb778805, 0xb780805, 0xb788805, 0xb790805, 0xb798805, 0xb7a0805, 0xb7a8805,
+    0xb7b0805, 0xb7b8805, 0xb7c0805, 0xb7c8805, 0xb7d0805, 0xb7d8805, 0xb7e0805, 0xb7e8805,
+    0xb7f0805, 0xb7f8805, 0xb800805, 0xb808805, 0xb810805, 0xb818805, 0xb820805, 0xb828805,
+    0xb830805, 0xb838805, 0xb840805, 0xb848805, 0xb850805, 0xb858805, 0xb860805, 0xb868805,
+    0xb870805, 0xb878805, 0xb880805, 0xb888805, 0xb890805, 0xb898805, 0xb8a0805, 0xb8a8805,
+    0xb8b0805, 0xb8b8805, 0xb8c0805, 0xb8c8805, 0xb8d0805, 0xb8d8805, 0xb8e0805, 0xb8e8805,
+    0xb8f0805, 0xb8f8805, 0xb900805, 0xb908805, 0xb910805, 0xb918805, 0xb920805, 0xb928805,
+    0xb930805, 0xb938805, 0xb940805, 0xb948805, 0xb950805, 0xb958805, 0xb960805, 0xb968805,
+    0xb970805, 0xb978805, 0xb980805, 0xb988805, 0xb990805, 0xb998805, 0xb9a0805, 0xb9a8805,
+    0xb9b0805, 0xb9b8805, 0xb9c0805, 0xb9c8805, 0xb9d0805, 0xb9d8805, 0xb9e0805, 0xb9e8805,
+    0xb9f0805, 0xb9f8805, 0xba00805, 0xba08805, 0xba10805, 0xba18805, 0xba20805, 0xba28805,
+    0xba30805, 0xba38805, 0xba40805, 0xba48805, 0xba50805, 0xba58805, 0xba60805, 0xba68805,
+    0xba70805, 0xba78805, 0xba80805, 0xba88805, 0xba90805, 0xba98805, 0xbaa0805, 0xbaa8805,
+    0xbab0805, 0xbab8805, 0xbac0805, 0xbac8805, 0xbad0805, 0xbad8805, 0xbae0805, 0xbae8805,
+    0xbaf0805, 0xbaf8805, 0xbb00805, 0xbb08805, 0xbb10805, 0xbb18805, 0xbb20805, 0xbb28805,
+    0xbb30805, 0xc4f0805, 0xc4f8805, 0xc500805, 0xc508805, 0xc510805, 0xc518805, 0xc520805,
+    0xc528805, 0xc530805, 0xc538805, 0xc540805, 0xc548805, 0xc550805, 0xc558805, 0xc560805,
+    0xc568805, 0xc570805, 0xc578805, 0xc580805, 0xc588805, 0xc590805, 0xc598805, 0xc5a0805,
+    0xc5a8805, 0xc5b0805, 0xc5b8805, 0xc5c0805, 0xc5c8805, 0xc5d0805, 0xc5d8805, 0xc5e0805,
+    0xc5e8805, 0xc5f0805, 0xc5f8805, 0xc600805, 0xc608805, 0xc610805, 0xc618805, 0xc620805,
+    0xc628805, 0xc630805, 0xc638805, 0xc640805, 0xc648805, 0xc650805, 0xc658805, 0xc660805,
+    0xc668805, 0xc670805, 0xc678805, 0xc680805, 0xc688805, 0xc690805, 0xc698805, 0xc6a0805,
+    0xc6a8805, 0xc6b0805, 0xc6b8805, 0xc6c0805, 0xc6c8805, 0xc6d0805, 0xc6d8805, 0xc6e0805,
+    0xc6e8805, 0xc6f0805, 0xc6f8805, 0xc700805, 0xc708805, 0xc710805, 0xc718805, 0xc720805,
+    0xc728805, 0xc730805, 0xc738805, 0xc740805, 0xc748805, 0xc750805, 0xc758805, 0xc760805,
+    0xc768805, 0xc770805, 0xc778805, 0xc780805, 0xc788805, 0xc790805, 0xc798805, 0xc7a0805,
+    0xc7a8805, 0xc7b0805, 0xc7b8805, 0xc7c0805, 0xc7c8805, 0xc7d0805, 0xc7d8805, 0xc7e0805,
+    0xc7e8805, 0xc7f0805, 0xc7f8805, 0xc800805, 0xc808805, 0xc810805, 0xc818805, 0xc820805,
+    0xc828805, 0xc830805, 0xc838805, 0xc840805, 0xc848805, 0xc850805, 0xc858805, 0xc860805,
+    0xc868805, 0xc870805, 0xc878805, 0xc880805, 0xc888805, 0xc890805, 0xc898805, 0xc8a0805,
+    0xc8a8805, 0xc8b0805, 0xc8b8805, 0xc8c0805, 0xc8c8805, 0xc8d0805, 0xc8d8805, 0xc8e0805,
+    0xc8e8805, 0xc8f0805, 0xc8f8805, 0xc900805, 0xc908805, 0xc910805, 0xc918805, 0xc920805,
+    0xc928805, 0xc930805, 0xc938805, 0xc940805, 0xc948805, 0xc950805, 0xc958805, 0xc960805,
+    0xc968805, 0xc970805, 0xc978805, 0xc980805, 0xc988805, 0xc990805, 0xc998805, 0xc9a0805,
+    0xc9a8805, 0xc9b0805, 0xc9b8805, 0xc9c0805, 0xc9c8805, 0xc9d0805, 0xc9d8805, 0xc9e0805,
+    0xc9e8805, 0xc9f0805, 0xc9f8805, 0xca00805, 0xca08805, 0xca10805, 0xca18805, 0xca20805,
+    0xca28805, 0xca30805, 0xca38805, 0xca40805, 0xca48805, 0xca50805, 0xca58805, 0xca60805,
+    0xca68805, 0xca70805, 0xca78805, 0xca80805, 0xca88805, 0xca90805, 0xca98805, 0xcaa0805,
+    0xcaa8805, 0xcab0805, 0xcab8805, 0xcac0805, 0xcac8805, 0xcad0805, 0xcad8805, 0xcae0805,
+    0xcae8805, 0xcaf0805, 0xcaf8805, 0xcb00805, 0xcb08805, 0xcb10805, 0xcb18805, 0xcb20805,
+    0xcb28805, 0xcb30805, 0xcb38805, 0xcb40805, 0xcb48805, 0xcb50805, 0xcb58805, 0xcb60805,
+    0xcb68805, 0xcb70805, 0xcb78805, 0xcb80805, 0xcb88805, 0xcb90805, 0xcb98805, 0xcba0805,
+    0xcba8805, 0xcbb0805, 0xcbb8805, 0xcbc0805, 0xcbc8805, 0xcbd0805, 0xcbd8805, 0xcbe0805,
+    0xcbe8805, 0xcbf0805, 0xcbf8805, 0xcc00805, 0xcc08805, 0xcc10805, 0xcc18805, 0xcc20805,
+    0xcc28805, 0xcc30805, 0xcc38805, 0xcc40805, 0xcc48805, 0xcc50805, 0xcc58805, 0xcc60805,
+    0xcc68805, 0xcc70805, 0xcc78805, 0xcc80805, 0xcc88805, 0xcc90805, 0xcc98805, 0xcca0805,
+    0xcca8805, 0xccb0805, 0xccb8805, 0xccc0805, 0xccc8805, 0xccd0805, 0xccd8805, 0xcce0805,
+    0xcce8805, 0xccf0805, 0xccf8805, 0xcd00805, 0xcd08805, 0xcd10805, 0xcd18805, 0xcd20805,
+    0xcd28805, 0xcd30805, 0xcd38805, 0xcd40805, 0xcd48805, 0xcd50805, 0xcd58805, 0xcd60805,
+    0xcd68805, 0xcd70805, 0xcd78805, 0xcd80805, 0xcd88805, 0xcd90805, 0xcd98805, 0xcda0805,
+    0xcda8805, 0xcdb0805, 0xcdb8805, 0xcdc0805, 0xcdc8805, 0xcdd0805, 0xcdd8805, 0xcde0805,
+    0xcde8805, 0xcdf0805, 0xcdf8805, 0xce00805, 0xce08805, 0xce10805, 0xce18805, 0xce20805,
+    0xce28805, 0xce30805, 0xce38805, 0xce40805, 0xce48805, 0xce50805, 0xce58805, 0xce60805,
+    0xce68805, 0xce70805, 0xce78805, 0xce80805, 0xce88805, 0xce90805, 0xce98805, 0xcea0805,
+    0xcea8805, 0xceb0805, 0xceb8805, 0xcec0805, 0xcec8805, 0xced0805, 0xced8805, 0xcee0805,
+    0xcee8805, 0xcef0805, 0xcef8805, 0xcf00805, 0xcf08805, 0xcf10805, 0xcf18805, 0xcf20805,
+    0xcf28805, 0xcf30805, 0xcf38805, 0xcf40805, 0xcf48805, 0xcf50805, 0xcf58805, 0xcf60805,
+    0xcf68805, 0xcf70805, 0xcf78805, 0xcf80805, 0xcf88805, 0xcf90805, 0xcf98805, 0xcfa0805,
+    0xcfa8805, 0xcfb0805, 0xcfb8805, 0xcfc0805, 0xcfc8805, 0xcfd0805, 0xcfd8805, 0xcfe0805,
+    0xcfe8805, 0xcff0805, 0xcff8805, 0xd000805, 0xd008805, 0xd010805, 0xd018805, 0xd020805,
+    0xd028805, 0xd030805, 0xd038805, 0xd040805, 0xd048805, 0xd050805, 0xd058805, 0xd060805,
+    0xd068805, 0xd070805, 0xd078805, 0xd080805, 0xd088805, 0xd090805, 0xd098805, 0xd0a0805,
+    0xd0a8805, 0xd0b0805, 0xd0b8805, 0xd0c0805, 0xd0c8805, 0xd0d0805, 0xd0d8805, 0xd0e0805,
+    0xd0e8805, 0xd0f0805, 0xd0f8805, 0xd100805, 0xd108805, 0xd110805, 0xd118805, 0xd120805,
+    0xd128805, 0xd130805, 0xd138805, 0xd140805, 0xd148805, 0xd150805, 0xd158805, 0xd160805,
+    0xd168805, 0xd170805, 0xd178805, 0xd180805, 0xd188805, 0xd190805, 0xd198805, 0xd1a0805,
+    0xd1a8805, 0xd1b0805, 0xd1b8805, 0xd1c0805, 0xd1c8805, 0xd1d0805, 0xd1d8805, 0xd1e0805,
+    0xd1e8805, 0xd1f0805, 0xd1f8805, 0xd200805, 0xd208805, 0xd210805, 0xd218805, 0xd220805,
+    0xd228805, 0xd230805, 0xd238805, 0xd240805, 0xd248805, 0xd250805, 0xd258805, 0xd260805,
+    0xd268805, 0xd270805, 0xd278805, 0xd280805, 0xd288805, 0xd290805, 0xd298805, 0xd2a0805,
+    0xd2a8805, 0xd2b0805, 0xd2b8805, 0xd2c0805, 0xd2c8805, 0xd2d0805, 0xd2d8805, 0xd2e0805,
+    0xd2e8805, 0xd2f0805, 0xd2f8805, 0xd300805, 0xd308805, 0xd310805, 0xd318805, 0xd320805,
+    0xd328805, 0xd330805, 0xd338805, 0xd340805, 0xd348805, 0xd350805, 0xd358805, 0xd360805,
+    0xd368805, 0xd370805, 0xd378805, 0xd380805, 0xd388805, 0xd390805, 0xd398805, 0xd3a0805,
+    0xd3a8805, 0xd3b0805, 0xd3b8805, 0xd3c0805, 0xd3c8805, 0xd3d0805, 0xd3d8805, 0xd3e0805,
+    0xd3e8805, 0xd3f0805, 0xd3f8805, 0xd400805, 0xd408805, 0xd410805, 0xd418805, 0xd420805,
+    0xd428805, 0xd430805, 0xd438805, 0xd440805, 0xd448805, 0xd450805, 0xd458805, 0xd460805,
+    0xd468805, 0xd470805, 0xd478805, 0xd480805, 0xd488805, 0xd490805, 0xd498805, 0xd4a0805,
+    0xd4a8805, 0xd4b0805, 0xd4b8805, 0xd4c0805, 0xd4c8805, 0xd4d0805, 0xd4d8805, 0xd4e0805,
+    0xd4e8805, 0xd4f0805, 0xd4f8805, 0xd500805, 0xd508805, 0xd510805, 0xd518805, 0xd520805,
+    0xd528805, 0xd530805, 0xd538805, 0xd540805, 0xd548805, 0xd550805, 0xd558805, 0xd560805,
+    0xd568805, 0xd570805, 0xd578805, 0xd580805, 0xd588805, 0xd590805, 0xd598805, 0xd5a0805,
+    0xd5a8805, 0xd5b0805, 0xd5b8805, 0xd5c0805, 0xd5c8805, 0xd5d0805, 0xd5d8805, 0xd5e0805,
+    0xd5e8805, 0xd5f0805, 0xd5f8805, 0xd600805, 0xd608805, 0xd610805, 0xd618805, 0xd620805,
+    0xd628805, 0xd630805, 0xd638805, 0xd640805, 0xd648805, 0xd650805, 0xd658805, 0xd660805,
+    0xd668805, 0xd670805, 0xd678805, 0xd680805, 0xd688805, 0xd690805, 0xd698805, 0xd6a0805,
+    0xd6a8805, 0xd6b0805, 0xd6b8805, 0xd6c0805, 0xd6c8805, 0xd6d0805, 0xd6d8805, 0xd6e0805,
+    0xd6e8805, 0xd6f0805, 0xd6f8805, 0xd700805, 0xd708805, 0xd710805, 0xd718805, 0xd720805,
+    0xd728805, 0xd730805, 0xd738805, 0xd740805, 0xd748805, 0xd750805, 0xd758805, 0xd760805,
+    0xd768805, 0xd770805, 0xd778805, 0xd780805, 0xd788805, 0xd790805, 0xd798805, 0xd7a0805,
+    0xd7a8805, 0xd7b0805, 0xd7b8805, 0xd7c0805, 0xd7c8805, 0xd7d0805, 0xd7d8805, 0xd7e0805,
+    0xd7e8805, 0xd7f0805, 0xbb38805, 0xbb40805, 0xbb48805, 0xbb50805, 0xbb58805, 0xbb60805,
+    0xbb68805, 0xbb70805, 0xbb78805, 0xbb80805, 0xbb88805, 0xbb90805, 0xbb98805, 0xbba0805,
+    0xbba8805, 0xbbb0805, 0xbbb8805, 0xbbc0805, 0xbbc8805, 0xbbd0805, 0xbbd8805, 0xbbe0805,
+    0xbbe8805, 0xbbf0805, 0xbbf8805, 0xbc00805, 0xbc08805, 0xbc10805, 0xbc18805, 0xbc20805,
+    0xbc28805, 0xbc30805, 0xbc38805, 0xbc40805, 0xbc48805, 0xbc50805, 0xbc58805, 0xbc60805,
+    0xbc68805, 0xbc70805, 0xbc78805, 0xbc80805, 0xbc88805, 0xbc90805, 0xbc98805, 0xbca0805,
+    0xbca8805, 0xbcb0805, 0xbcb8805, 0xbcc0805, 0xbcc8805, 0xbcd0805, 0xbcd8805, 0xbce0805,
+    0xbce8805, 0xbcf0805, 0xbcf8805, 0xbd00805, 0xbd08805, 0xbd10805, 0xbd18805, 0xbd20805,
+    0xbd28805, 0xbd30805, 0xbd38805, 0xbd40805, 0xbd48805, 0xbd50805, 0xbd58805, 0xbd60805,
+    0xbd68805, 0xbd70805, 0xbd78805, 0xbd80805, 0xbd88805, 0xbd90805, 0xbd98805, 0xbda0805,
+    0xbda8805, 0xbdb0805, 0xbdb8805, 0xbdc0805, 0xbdc8805, 0xbdd0805, 0xbdd8805, 0xbde0805,
+    0xbde8805, 0xbdf0805, 0xbdf8805, 0xbe00805, 0xbe08805, 0xbe10805, 0xbe18805, 0xbe20805,
+    0xbe28805, 0xbe30805, 0xbe38805, 0xbe40805, 0xbe48805, 0xbe50805, 0xbe58805, 0xbe60805,
+    0xbe68805, 0xbe70805, 0xbe78805, 0xbe80805, 0xbe88805, 0xbe90805, 0xbe98805, 0xbea0805,
+    0xbea8805, 0xbeb0805, 0xbeb8805, 0xbec0805, 0xbec8805, 0xbed0805, 0xbed8805, 0xbee0805,
+    0xbee8805, 0xbef0805, 0xbef8805, 0xbf00805, 0xbf08805, 0xbf10805, 0xbf18805, 0xbf20805,
+    0xbf28805, 0xbf30805, 0xbf38805, 0xbf40805, 0xbf48805, 0xbf50805, 0xbf58805, 0xbf60805,
+    0xbf68805, 0xbf70805, 0xbf78805, 0xbf80805, 0xbf88805, 0xbf90805, 0xbf98805, 0xbfa0805,
+    0xbfa8805, 0xbfb0805, 0xbfb8805, 0xbfc0805, 0xbfc8805, 0xbfd0805, 0xbfd8805, 0xbfe0805,
+    0xbfe8805, 0xbff0805, 0xbff8805, 0xc000805, 0xc008805, 0xc010805, 0xc018805, 0xc020805,
+    0xc028805, 0xc030805, 0xc038805, 0xc040805, 0xc048805, 0xc050805, 0xc058805, 0xc060805,
+    0xc068805, 0xc070805, 0xc078805, 0xc080805, 0xc088805, 0xc090805, 0xc098805, 0xc0a0805,
+    0xc0a8805, 0xc0b0805, 0xc0b8805, 0xc0c0805, 0xc0c8805, 0xc0d0805, 0xc0d8805, 0xc0e0805,
+    0xc0e8805, 0xc0f0805, 0xc0f8805, 0xc100805, 0xc108805, 0xc110805, 0xc118805, 0xc120805,
+    0xc128805, 0xc130805, 0xc138805, 0xc140805, 0xc148805, 0xc150805, 0xc158805, 0xc160805,
+    0xc168805, 0xc170805, 0xc178805, 0xc180805, 0xc188805, 0xc190805, 0xc198805, 0xc1a0805,
+    0xc1a8805, 0xc1b0805, 0xc1b8805, 0xc1c0805, 0xc1c8805, 0xc1d0805, 0xc1d8805, 0xc1e0805,
+    0xc1e8805, 0xc1f0805, 0xc1f8805, 0xc200805, 0xc208805, 0xc210805, 0xc218805, 0xc220805,
+    0xc228805, 0xc230805, 0xc238805, 0xc240805, 0xc248805, 0xc250805, 0xc258805, 0xc260805,
+    0xc268805, 0xc270805, 0xc278805, 0xc280805, 0xc288805, 0xc290805, 0xc298805, 0xc2a0805,
+    0xc2a8805, 0xc2b0805, 0xc2b8805, 0xc2c0805, 0xc2c8805, 0xc2d0805, 0xc2d8805, 0xc2e0805,
+    0xc2e8805, 0xc2f0805, 0xc2f8805, 0xc300805, 0xc308805, 0xc310805, 0xc318805, 0xc320805,
+    0x9fc8805, 0x9fd0805, 0x9fd8805, 0x9fe0805, 0x9fe8805, 0x9ff0805, 0x9ff8805, 0xa000805,
+    0xa008805, 0xa010805, 0xa018805, 0xa020805, 0xa028805, 0xa030805, 0xa038805, 0xa040805,
+    0xa048805, 0xa050805, 0xa058805, 0xa060805, 0xa068805, 0xa070805, 0xa078805, 0xa080805,
+    0xa088805, 0xa090805, 0xa098805, 0xa0a0805, 0xa0a8805, 0xa0b0805, 0xa0b8805, 0xa0c0805,
+    0xa0c8805, 0xa0d0805, 0xa0d8805, 0xa0e0805, 0xa0e8805, 0xa0f0805, 0xa0f8805, 0xa100805,
+    0xa108805, 0xa110805, 0xa118805, 0xa120805, 0xa128805, 0xa130805, 0xa138805, 0xa140805,
+    0xa148805, 0xa150805, 0xa158805, 0xa160805, 0xa168805, 0xa170805, 0xa178805, 0xa180805,
+    0xa188805, 0xa190805, 0xa198805, 0xa1a0805, 0xa1a8805, 0xa1b0805, 0xa1b8805, 0xa1c0805,
+    0xa1c8805, 0xa1d0805, 0xa1d8805, 0xa1e0805, 0xa1e8805, 0xa1f0805, 0xa1f8805, 0xa200805,
+    0xa208805, 0xa210805, 0xa218805, 0xa220805, 0xa228805, 0xa230805, 0xa238805, 0xa240805,
+    0xa248805, 0xa250805, 0xa258805, 0xa260805, 0xa268805, 0xa270805, 0xa278805, 0xa280805,
+    0xa288805, 0xa290805, 0xa298805, 0xa2a0805, 0xa2a8805, 0xa2b0805, 0xa2b8805, 0xa2c0805,
+    0xa2c8805, 0xa2d0805, 0xc328805, 0xc330805, 0xc338805, 0xc340805, 0xc348805, 0xc350805,
+    0xc358805, 0xc360805, 0xc368805, 0xc370805, 0xc378805, 0xc380805, 0xc388805, 0xc390805,
+    0xc398805, 0xc3a0805, 0xc3a8805, 0xc3b0805, 0xc3b8805, 0xc3c0805, 0xc3c8805, 0xc3d0805,
+    0xc3d8805, 0xc3e0805, 0xc3e8805, 0xc3f0805, 0xc3f8805, 0xc400805, 0xc408805, 0xc410805,
+    0xc418805, 0xc420805, 0xc428805, 0xc430805, 0xc438805, 0xc440805, 0xc448805, 0xc450805,
+    0xc458805, 0xc460805, 0xc468805, 0xc470805, 0xc478805, 0xc480805, 0xc488805, 0xc490805,
+    0xc498805, 0xc4a0805, 0xc4a8805, 0xc4b0805, 0xc4b8805, 0xc4c0805, 0xc4c8805, 0xc4d0805,
+    0xc4d8805, 0xc4e0805, 0xc4e8805, 0x4b48805, 0x4b50805, 0x4b58805, 0x4b60805, 0x4b68805,
+    0x4b70805, 0x4b78805, 0x4b80805, 0x4b88805, 0x4b90805, 0x4b98805, 0x4ba0805, 0x4ba8805,
+    0x4bb0805, 0x4bb8805, 0x4bc0805, 0x4bc8805, 0x4bd0805, 0x4bd8805, 0x4be0805, 0x4be8805,
+    0x4bf0805, 0x4bf8805, 0x4c00805, 0x4c08805, 0x4c10805, 0x4c18805, 0x4c20805, 0x4c28805,
+    0x4c30805, 0x4c38805, 0x4c40805, 0x4c48805, 0x4c50805, 0x4c58805, 0x4c60805, 0x4c68805,
+    0x4c70805, 0x4c78805, 0x4c80805, 0x4c88805, 0x4c90805, 0x4c98805, 0x4ca0805, 0x4ca8805,
+    0x4cb0805, 0x4cb8805, 0x4cc0805, 0x4cc8805, 0x4cd0805, 0x4cd8805, 0x4ce0805, 0x4ce8805,
+    0x4cf0805, 0x4cf8805, 0x4d00805, 0x4d08805, 0x4d10805, 0x4d18805, 0x4d20805, 0x4d28805,
+    0x4d30805, 0x4d38805, 0x4d40805, 0x4d48805, 0x4d50805, 0x4d58805, 0x4d60805, 0x4d68805,
+    0x4d70805, 0x4d78805, 0x4d80805, 0x4d88805, 0x4d90805, 0x4d98805, 0x4da0805, 0x4da8805,
+    0x4db0805, 0x4db8805, 0x4dc0805, 0x4dc8805, 0x4dd0805, 0x4dd8805, 0x4de0805, 0x4de8805,
+    0x4df0805, 0x4df8805, 0x4e00805, 0x4e08805, 0x4e10805, 0x4e18805, 0x4e20805, 0x4e28805,
+    0x4e30805, 0x4e38805, 0x4e40805, 0x4e48805, 0x4e50805, 0x4e58805, 0x4e60805, 0x4e68805,
+    0x4e70805, 0x4e78805, 0x4e80805, 0x4e88805, 0x4e90805, 0x4e98805, 0x4ea0805, 0x4ea8805,
+    0x4eb0805, 0x4eb8805, 0x4ec0805, 0x4ec8805, 0x4ed0805, 0x4ed8805, 0x4ee0805, 0x4ee8805,
+    0x4ef0805, 0x4ef8805, 0x4f00805, 0x4f08805, 0x4f10805, 0x4f18805, 0x4f20805, 0x4f28805,
+    0x4f30805, 0x4f38805, 0x4f40805, 0x4f48805, 0x4f50805, 0x4f58805, 0x4f60805, 0x4f68805,
+    0x4f70805, 0x4f78805, 0x4f80805, 0x4f88805, 0x4f90805, 0x4f98805, 0x4fa0805, 0x4fa8805,
+    0x4fb0805, 0x4fb8805, 0x4fc0805, 0x4fc8805, 0x4fd0805, 0x4fd8805, 0x4fe0805, 0x4fe8805,
+    0x4ff0805, 0x4ff8805, 0x5000805, 0x5008805, 0x5010805, 0x5018805, 0x5020805, 0x5028805,
+    0x5030805, 0x5038805, 0x5040805, 0x5048805, 0x5050805, 0x5058805, 0x5060805, 0x5068805,
+    0x5070805, 0x5078805, 0x5080805, 0x5088805, 0x5090805, 0x5098805, 0x50a0805, 0x50a8805,
+    0x50b0805, 0x50b8805, 0x50c0805, 0x50c8805, 0x50d0805, 0x50d8805, 0x50e0805, 0x50e8805,
+    0x50f0805, 0x50f8805, 0x5100805, 0x5108805, 0x5110805, 0x5118805, 0x5120805, 0x5128805,
+    0x5130805, 0x5138805, 0x5140805, 0x5148805, 0x5150805, 0x5158805, 0x5160805, 0x5168805,
+    0x5170805, 0x5178805, 0x5180805, 0x5188805, 0x5190805, 0xfcc080a, 0xfcc880a, 0xfcd080a,
+    0xfcd880a, 0xfce080a, 0xfce880a, 0xfcf080a, 0xfcf880a, 0xfd0080a, 0xfd0880a, 0x8003ea82,
+    0x8003eac2, 0x8003eb02, 0x8003eb42, 0x8003eb82, 0x8003ebc2, 0x8003ec02, 0x8003ec42, 0x8003ec82,
+    0x8003ecc2, 0x8003ed02, 0x8003ed42, 0x8003ed82, 0x8003edc2, 0x8003ee02, 0x8003ee42, 0x8003ee82,
+    0x8003eec2, 0x8003ef02, 0x8003ef42, 0x8003ef82, 0x8003efc2, 0x8003f002, 0x8003f042, 0x8003f082,
+    0x8003f0c2, 0x8003f102, 0x8003f142, 0x8003f182, 0x8003f1c2, 0x8003f202, 0x8003f242, 0x8003f282,
+    0x8003f2c2, 0x8003f302, 0x8003f342, 0x8003f382, 0x8003f3c2, 0x8003f402, 0x8003f442, 0x8003f482,
+    0x8003f4c2, 0x8003f502, 0x8003f542, 0x8003f582, 0x8003f5c2, 0x8003f602, 0x8003f642, 0x8003f682,
+    0x8003f6c2, 0x8003f702, 0x8003f742, 0x8003f782, 0x8003f7c2, 0x8003f802, 0x8003f842, 0x8003f882,
+    0x8003f8c2, 0x8003f902, 0x8003f942, 0x8003f982, 0x8003f9c2, 0x8003fa02, 0x8003fa42, 0x8003fa82,
+    0x8003fac2, 0x8003fb02, 0x8003fb42, 0x8003fb82, 0x8003fbc2, 0x8003fc02, 0x8003fc42, 0x8003fc82,
+    0x8003fcc2, 0x8003fd02, 0x8003fd42, 0x8003fd82, 0x8003fdc2, 0x8003fe02, 0x8003fe42, 0x8003fe82,
+    0x8003fec2, 0x8003ff02, 0x8003ff42, 0x8003ff82, 0x8003ffc2, 0x80040002, 0x80040042, 0x80040082,
+    0x800400c2, 0x80040102, 0x80040142, 0x80040182, 0x800401c2, 0x80040202, 0x80040242, 0x80040282,
+    0x800402c2, 0x80040302, 0x80040342, 0x80040382, 0x800403c2, 0x80040402, 0x80040442, 0x80040482,
+    0x800404c2, 0x80040502, 0x80040542, 0x80040582, 0x800405c2, 0x80040602, 0x80040642, 0x80040682,
+    0x800406c2, 0x80040702, 0x80040742, 0x80040782, 0x800407c2, 0x80040802, 0x80040842, 0x80040882,
+    0x800408c2, 0x80040902, 0x80040942, 0x80040982, 0x800409c2, 0x80040a02, 0x80040a42, 0x80040a82,
+    0x80040ac2, 0x80040b02, 0x80040b42, 0x80040b82, 0x80040bc2, 0x80040c02, 0x80040c42, 0x80040c82,
+    0x80040cc2, 0x80040d02, 0x80040d42, 0x80040d82, 0x80040dc2, 0x80040e02, 0x80040e42, 0x80040e82,
+    0x80040ec2, 0x80040f02, 0x80040f42, 0x80040f82, 0x80040fc2, 0x80041002, 0x80041042, 0x80041082,
+    0x800410c2, 0x80041102, 0x80041142, 0x80041182, 0x800411c2, 0x80041202, 0x80041242, 0x80041282,
+    0x800412c2, 0x80041302, 0x80041342, 0x80041382, 0x800413c2, 0x80041402, 0x80041442, 0x80041482,
+    0x800414c2, 0x80041502, 0x80041542, 0x80041582, 0x800415c2, 0x80041602, 0x80041642, 0x80041682,
+    0x800416c2, 0x80041702, 0x80041742, 0x80041782, 0x800417c2, 0x80041802, 0x80041842, 0x80041882,
+    0x800418c2, 0x80041902, 0x80041942, 0x80041982, 0x800419c2, 0x80041a02, 0x80041a42, 0x80041a82,
+    0x80041ac2, 0x80041b02, 0x80041b42, 0x80041b82, 0x80041bc2, 0x80041c02, 0x80041c42, 0x80041c82,
+    0x80041cc2, 0x80041d02, 0x80041d42, 0x80041d82, 0x80041dc2, 0x80041e02, 0x80041e42, 0x80041e82,
+    0x80041ec2, 0x80041f02, 0x80041f42, 0x80041f82, 0x80041fc2, 0x80042002, 0x80042042, 0x80042082,
+    0x800420c2, 0x80042102, 0x80042142, 0x80042182, 0x800421c2, 0x80042202, 0x80042242, 0x80042282,
+    0x800422c2, 0x80042302, 0x80042342, 0x80042382, 0x800423c2, 0x80042402, 0x80042442, 0x80042482,
+    0x800424c2, 0x80042502, 0x80042542, 0x80042582, 0x800425c2, 0x80042602, 0x80042642, 0x80042682,
+    0x800426c2, 0x80042702, 0x80042742, 0x80042782, 0x800427c2, 0x80042802, 0x80042842, 0x80042882,
+    0x800428c2, 0x80042902, 0x80042942, 0x80042982, 0x800429c2, 0x80042a02, 0x80042a42, 0x80042a82,
+    0x80042ac2, 0x80042b02, 0x80042b42, 0x80042b82, 0x80042bc2, 0x80042c02, 0x80042c42, 0x80042c82,
+    0x80042cc2, 0x80042d02, 0x80042d42, 0x80042d82, 0x80042dc2, 0x80042e02, 0x80042e42, 0x80042e82,
+    0x80042ec2, 0x80042f02, 0x80042f42, 0x80042f82, 0x80042fc2, 0x80043002, 0x80043042, 0x80043082,
+    0x800430c2, 0x80043102, 0x80043142, 0x80043182, 0x800431c2, 0x80043202, 0x80043242, 0x80043282,
+    0x800432c2, 0x80043302, 0x80043342, 0x80043382, 0x800433c2, 0x80043402, 0x80043442, 0x80043482,
+    0x800434c2, 0x80043502, 0x80043542, 0x80043582, 0x800435c2, 0x80043602, 0x80043642, 0x80043682,
+    0x800436c2, 0x80043702, 0x80043742, 0x80043782, 0x800437c2, 0x80043802, 0x80043842, 0x80043882,
+    0x800438c2, 0x80043902, 0x80043942, 0x80043982, 0x800439c2, 0x80043a02, 0x80043a42, 0x80043a82,
+    0x80043ac2, 0x80043b02, 0x80043b42, 0x80043b82, 0x80043bc2, 0x80043c02, 0x80043c42, 0x80043c82,
+    0x80043cc2, 0x80043d02, 0x80043d42, 0x80043d82, 0x80043dc2, 0x80043e02, 0x80043e42, 0x80043e82,
+    0x80043ec2, 0x80043f02, 0x80043f42, 0x80043f82, 0x80043fc2, 0x80044002, 0x80044042, 0x80044082,
+    0x800440c2, 0x80044102, 0x80044142, 0x80044182, 0x800441c2, 0x80044202, 0x80044242, 0x80044282,
+    0x800442c2, 0x80044302, 0x80044342, 0x80044382, 0x800443c2, 0x80044402, 0x80044442, 0x80044482,
+    0x800444c2, 0x80044502, 0x80044542, 0x80044582, 0x800445c2, 0x80044602, 0x80044642, 0x80044682,
+    0x800446c2, 0x80044702, 0x80044742, 0x80044782, 0x800447c2, 0x80044802, 0x80044842, 0x80044882,
+    0x800448c2, 0x80044902, 0x80044942, 0x80044982, 0x800449c2, 0x80044a02, 0x80044a42, 0x80044a82,
+    0x80044ac2, 0x80044b02, 0x80044b42, 0x80044b82, 0x80044bc2, 0x80044c02, 0x80044c42, 0x80044c82,
+    0x80044cc2, 0x80044d02, 0x80044d42, 0x80044d82, 0x80044dc2, 0x80044e02, 0x80044e42, 0x80044e82,
+    0x80044ec2, 0x80044f02, 0x80044f42, 0x80044f82, 0x80044fc2, 0x80045002, 0x80045042, 0x80045082,
+    0x800450c2, 0x80045102, 0x80045142, 0x80045182, 0x800451c2, 0x80045202, 0x80045242, 0x80045282,
+    0x800452c2, 0x80045302, 0x80045342, 0x80045382, 0x800453c2, 0x80045402, 0x80045442, 0x80045482,
+    0x800454c2, 0x80045502, 0x80045542, 0x80045582, 0x800455c2, 0x80045602, 0x80045642, 0x80045682,
+    0x800456c2, 0x80045702, 0x80045742, 0x80045782, 0x800457c2, 0x80045802, 0x80045842, 0x80045882,
+    0x800458c2, 0x80045902, 0x80045942, 0x80045982, 0x800459c2, 0x80045a02, 0x80045a42, 0x80045a82,
+    0x80045ac2, 0x80045b02, 0x80045b42, 0x80045b82, 0x80045bc2, 0x80045c02, 0x80045c42, 0x80045c82,
+    0x80045cc2, 0x80045d02, 0x80045d42, 0x80045d82, 0x80045dc2, 0x80045e02, 0x80045e42, 0x80045e82,
+    0x80045ec2, 0x80045f02, 0x80045f42, 0x80045f82, 0x80045fc2, 0x80046002, 0x80046042, 0x80046082,
+    0x800460c2, 0x80046102, 0x80046142, 0x80046182, 0x800461c2, 0x80046202, 0x80046242, 0x80046282,
+    0x800462c2, 0x80046302, 0x80046342, 0x80046382, 0x800463c2, 0x80046402, 0x80046442, 0x80046482,
+    0x800464c2, 0x80046502, 0x80046542, 0x80046582, 0x800465c2, 0x80046602, 0x80046642, 0x80046682,
+    0x800466c2, 0x80046702, 0x80046742, 0x80046782, 0x800467c2, 0x80046802, 0x80046842, 0x80046882,
+    0x800468c2, 0x80046902, 0x80046942, 0x80046982, 0x800469c2, 0x80046a02, 0x80046a42, 0x80046a82,
+    0x80046ac2, 0x80046b02, 0x80046b42, 0x80046b82, 0x80046bc2, 0x80046c02, 0x80046c42, 0x80046c82,
+    0x80046cc2, 0x80046d02, 0x80046d42, 0x80046d82, 0x80046dc2, 0x80046e02, 0x80046e42, 0x80046e82,
+    0x80046ec2, 0x80046f02, 0x80046f42, 0x80046f82, 0x80046fc2, 0x80047002, 0x80047042, 0x80047082,
+    0x800470c2, 0x80047102, 0x80047142, 0x80047182, 0x800471c2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+];
+
+/// Collation elements of characters that have more than one
+pub const EXPANSIONS: [u32; 10864] = [
+    0xfcc883c, 0x33b083d, 0xfce083c, 0xfcc883c, 0x33b083d, 0xfcd083c, 0xfcd883c, 0x33b083d,
+    0xfce083c, 0xfd10810, 0x944, 0xfd10810, 0x904, 0xfd10810, 0x9c4, 0xfd10810, 0xb44, 0xfd10810,
+    0xac4, 0xfd10810, 0xa44, 0xfd10814, 0x4608, 0x10038814, 0xfeb0810, 0xc04, 0x10038810, 0x944,
+    0x10038810, 0x904, 0x10038810, 0x9c4, 0x10038810, 0xac4, 0x10480810, 0x944, 0x10480810, 0x904,
+    0x10480810, 0x9c4, 0x10480810, 0xac4, 0xff58814, 0x4608, 0x108c0810, 0xb44, 0x109e0810, 0x944,
+    0x109e0810, 0x904, 0x109e0810, 0x9c4, 0x109e0810, 0xb44, 0x109e0810, 0xac4, 0x109e0810, 0xbc4,
+    0x110b8810, 0x944, 0x110b8810, 0x904, 0x110b8810, 0x9c4, 0x110b8810, 0xac4, 0x11380810, 0x904,
+    0x10e90808, 0x4608, 0x10e90808, 0xfd10804, 0x944, 0xfd10804, 0x904, 0xfd10804, 0x9c4,
+    0xfd10804, 0xb44, 0xfd10804, 0xac4, 0xfd10804, 0xa44, 0xfd10808, 0x4608, 0x10038808, 0xfeb0804,
+    0xc04, 0x10038804, 0x944, 0x10038804, 0x904, 0x10038804, 0x9c4, 0x10038804, 0xac4, 0x10480804,
+    0x944, 0x10480804, 0x904, 0x10480804, 0x9c4, 0x10480804, 0xac4, 0xff58808, 0x4608, 0x108c0804,
+    0xb44, 0x109e0804, 0x944, 0x109e0804, 0x904, 0x109e0804, 0x9c4, 0x109e0804, 0xb44, 0x109e0804,
+    0xac4, 0x109e0804, 0xbc4, 0x110b8804, 0x944, 0x110b8804, 0x904, 0x110b8804, 0x9c4, 0x110b8804,
+    0xac4, 0x11380804, 0x904, 0x11380804, 0xac4, 0xfd10810, 0xc84, 0xfd10804, 0xc84, 0xfd10810,
+    0x984, 0xfd10804, 0x984, 0xfd10810, 0xc44, 0xfd10804, 0xc44, 0xfeb0810, 0x904, 0xfeb0804,
+    0x904, 0xfeb0810, 0x9c4, 0xfeb0804, 0x9c4, 0xfeb0810, 0xb84, 0xfeb0804, 0xb84, 0xfeb0810,
+    0xa04, 0xfeb0804, 0xa04, 0xff58810, 0xa04, 0xff58804, 0xa04, 0xff58810, 0xe44, 0xff58804,
+    0xe44, 0x10038810, 0xc84, 0x10038804, 0xc84, 0x10038810, 0x984, 0x10038804, 0x984, 0x10038810,
+    0xb84, 0x10038804, 0xb84, 0x10038810, 0xc44, 0x10038804, 0xc44, 0x10038810, 0xa04, 0x10038804,
+    0xa04, 0x10288810, 0x9c4, 0x10288804, 0x9c4, 0x10288810, 0x984, 0x10288804, 0x984, 0x10288810,
+    0xb84, 0x10288804, 0xb84, 0x10288810, 0xc04, 0x10288804, 0xc04, 0x103a8810, 0x9c4, 0x103a8804,
+    0x9c4, 0x103a8810, 0xe44, 0x103a8804, 0xe44, 0x10480810, 0xb44, 0x10480804, 0xb44, 0x10480810,
+    0xc84, 0x10480804, 0xc84, 0x10480810, 0x984, 0x10480804, 0x984, 0x10480810, 0xc44, 0x10480804,
+    0xc44, 0x10480810, 0xb84, 0x10480814, 0x10558814, 0x10480808, 0x10558808, 0x10558810, 0x9c4,
+    0x10558804, 0x9c4, 0x10620810, 0xc04, 0x10620804, 0xc04, 0x106b0810, 0x904, 0x106b0804, 0x904,
+    0x106b0810, 0xc04, 0x106b0804, 0xc04, 0x106b0810, 0xa04, 0x106b0804, 0xa04, 0x106b0810, 0x4604,
+    0x106b0804, 0x4604, 0x106b0810, 0xe44, 0x106b0804, 0xe44, 0x108c0810, 0x904, 0x108c0804, 0x904,
+    0x108c0810, 0xc04, 0x108c0804, 0xc04, 0x108c0810, 0xa04, 0x108c0804, 0xa04, 0x11718808,
+    0x108c0808, 0x109e0810, 0xc84, 0x109e0804, 0xc84, 0x109e0810, 0x984, 0x109e0804, 0x984,
+    0x109e0810, 0xb04, 0x109e0804, 0xb04, 0x109e0814, 0x4608, 0x10038814, 0x109e0808, 0x4608,
+    0x10038808, 0x10c98810, 0x904, 0x10c98804, 0x904, 0x10c98810, 0xc04, 0x10c98804, 0xc04,
+    0x10c98810, 0xa04, 0x10c98804, 0xa04, 0x10e90810, 0x904, 0x10e90804, 0x904, 0x10e90810, 0x9c4,
+    0x10e90804, 0x9c4, 0x10e90810, 0xc04, 0x10e90804, 0xc04, 0x10e90810, 0xa04, 0x10e90804, 0xa04,
+    0x10fb8810, 0xc04, 0x10fb8804, 0xc04, 0x10fb8810, 0xa04, 0x10fb8804, 0xa04, 0x110b8810, 0xb44,
+    0x110b8804, 0xb44, 0x110b8810, 0xc84, 0x110b8804, 0xc84, 0x110b8810, 0x984, 0x110b8804, 0x984,
+    0x110b8810, 0xa44, 0x110b8804, 0xa44, 0x110b8810, 0xb04, 0x110b8804, 0xb04, 0x110b8810, 0xc44,
+    0x110b8804, 0xc44, 0x112c8810, 0x9c4, 0x112c8804, 0x9c4, 0x11380810, 0x9c4, 0x11380804, 0x9c4,
+    0x11380810, 0xac4, 0x11430810, 0x904, 0x11430804, 0x904, 0x11430810, 0xb84, 0x11430804, 0xb84,
+    0x11430810, 0xa04, 0x11430804, 0xa04, 0x10e90808, 0x4648, 0x11430808, 0x112c8808, 0x109e0810,
+    0xfc4, 0x109e0804, 0xfc4, 0x110b8810, 0xfc4, 0x110b8804, 0xfc4, 0x10fb8808, 0x10e90808,
+    0xff58814, 0x11430814, 0xa08, 0xff58814, 0x11430808, 0xa08, 0xff58808, 0x11430808, 0xa08,
+    0x106b0814, 0x10558814, 0x106b0814, 0x10558808, 0x106b0808, 0x10558808, 0x108c0814, 0x10558814,
+    0x108c0814, 0x10558808, 0x108c0808, 0x10558808, 0xfd10810, 0xa04, 0xfd10804, 0xa04, 0x10480810,
+    0xa04, 0x10480804, 0xa04, 0x109e0810, 0xa04, 0x109e0804, 0xa04, 0x110b8810, 0xa04, 0x110b8804,
+    0xa04, 0x110b8810, 0xac4, 0xc84, 0x110b8804, 0xac4, 0xc84, 0x110b8810, 0xac4, 0x904,
+    0x110b8804, 0xac4, 0x904, 0x110b8810, 0xac4, 0xa04, 0x110b8804, 0xac4, 0xa04, 0x110b8810,
+    0xac4, 0x944, 0x110b8804, 0xac4, 0x944, 0xfd10810, 0xac4, 0xc84, 0xfd10804, 0xac4, 0xc84,
+    0xfd10810, 0xb84, 0xc84, 0xfd10804, 0xb84, 0xc84, 0xfd10814, 0x4608, 0x10038814, 0xc84,
+    0xfd10808, 0x4608, 0x10038808, 0xc84, 0x10288810, 0xa04, 0x10288804, 0xa04, 0x10620810, 0xa04,
+    0x10620804, 0xa04, 0x109e0810, 0xc44, 0x109e0804, 0xc44, 0x109e0810, 0xc44, 0xc84, 0x109e0804,
+    0xc44, 0xc84, 0x11518810, 0xa04, 0x11518804, 0xa04, 0x10558804, 0xa04, 0xff58814, 0x11430814,
+    0xff58814, 0x11430808, 0xff58808, 0x11430808, 0x10288810, 0x904, 0x10288804, 0x904, 0x108c0810,
+    0x944, 0x108c0804, 0x944, 0xfd10810, 0xa44, 0x904, 0xfd10804, 0xa44, 0x904, 0xfd10814, 0x4608,
+    0x10038814, 0x904, 0xfd10808, 0x4608, 0x10038808, 0x904, 0x109e0810, 0xbc4, 0x904, 0x109e0804,
+    0xbc4, 0x904, 0xfd10810, 0xf04, 0xfd10804, 0xf04, 0xfd10810, 0xf84, 0xfd10804, 0xf84,
+    0x10038810, 0xf04, 0x10038804, 0xf04, 0x10038810, 0xf84, 0x10038804, 0xf84, 0x10480810, 0xf04,
+    0x10480804, 0xf04, 0x10480810, 0xf84, 0x10480804, 0xf84, 0x109e0810, 0xf04, 0x109e0804, 0xf04,
+    0x109e0810, 0xf84, 0x109e0804, 0xf84, 0x10c98810, 0xf04, 0x10c98804, 0xf04, 0x10c98810, 0xf84,
+    0x10c98804, 0xf84, 0x110b8810, 0xf04, 0x110b8804, 0xf04, 0x110b8810, 0xf84, 0x110b8804, 0xf84,
+    0x10e90810, 0x1144, 0x10e90804, 0x1144, 0x10fb8810, 0x1144, 0x10fb8804, 0x1144, 0x103a8810,
+    0xa04, 0x103a8804, 0xa04, 0xfd10810, 0xb84, 0xfd10804, 0xb84, 0x10038810, 0xc04, 0x10038804,
+    0xc04, 0x109e0810, 0xac4, 0xc84, 0x109e0804, 0xac4, 0xc84, 0x109e0810, 0xb44, 0xc84,
+    0x109e0804, 0xb44, 0xc84, 0x109e0810, 0xb84, 0x109e0804, 0xb84, 0x109e0810, 0xb84, 0xc84,
+    0x109e0804, 0xb84, 0xc84, 0x11380810, 0xc84, 0x11380804, 0xc84, 0xff58808, 0xfde0808,
+    0x10c00808, 0x10b58808, 0xff58808, 0x11430808, 0xff58808, 0x11518808, 0xff58808, 0x114c8808,
+    0x10fb8808, 0x10e90808, 0x10fb8808, 0x10f20808, 0x10fb8808, 0xff28808, 0x10210808, 0x109b8808,
+    0x106b0808, 0x10e90808, 0x106b0808, 0x11430808, 0xac4, 0x904, 0x25d8805, 0x904, 0x118f0810,
+    0x904, 0x11918810, 0x904, 0x11948810, 0x904, 0x11958810, 0x904, 0x11998810, 0x904, 0x11a08810,
+    0x904, 0x11a30810, 0x904, 0x11958804, 0xac4, 0x904, 0x11958810, 0xac4, 0x11a08810, 0xac4,
+    0x118f0804, 0x904, 0x11918804, 0x904, 0x11948804, 0x904, 0x11958804, 0x904, 0x11a08804, 0xac4,
+    0x904, 0x11958804, 0xac4, 0x11a08804, 0xac4, 0x11998804, 0x904, 0x11a08804, 0x904, 0x11a30804,
+    0x904, 0x11968814, 0x118f0808, 0x11958808, 0x11a08814, 0x904, 0x11a08814, 0xac4, 0x11968808,
+    0x118f0808, 0x11958808, 0x11df8810, 0x944, 0x11df8810, 0xac4, 0x11cd8810, 0x904, 0x11f68810,
+    0xac4, 0x11fd8810, 0x904, 0x11f28810, 0x944, 0x12410810, 0x984, 0x11df8804, 0x944, 0x11df8804,
+    0xac4, 0x11cd8804, 0x904, 0x11f68804, 0xac4, 0x11fd8804, 0x904, 0x11f28804, 0x944, 0x12410804,
+    0x984, 0x129e0810, 0xf04, 0x129e0804, 0xf04, 0x11cd8814, 0x4648, 0x11cd8808, 0x4648,
+    0x11e38810, 0x984, 0x11e38804, 0x984, 0x11c38810, 0x984, 0x11c38804, 0x984, 0x11c38810, 0xac4,
+    0x11c38804, 0xac4, 0x11df8810, 0x984, 0x11df8804, 0x984, 0x11c58810, 0xac4, 0x11c58804, 0xac4,
+    0x11e38810, 0xac4, 0x11e38804, 0xac4, 0x11e88810, 0xac4, 0x11e88804, 0xac4, 0x11f28810, 0xc84,
+    0x11f28804, 0xc84, 0x11f28810, 0xac4, 0x11f28804, 0xac4, 0x12260810, 0xac4, 0x12260804, 0xac4,
+    0x12280810, 0xac4, 0x12280804, 0xac4, 0x12850810, 0xac4, 0x12850804, 0xac4, 0x12410810, 0xc84,
+    0x12410804, 0xc84, 0x12410810, 0xac4, 0x12410804, 0xac4, 0x12410810, 0xb04, 0x12410804, 0xb04,
+    0x12648810, 0xac4, 0x12648804, 0xac4, 0x127c8810, 0xac4, 0x127c8804, 0xac4, 0x12fd0808,
+    0x130c0808, 0x13138808, 0x13110808, 0x13118808, 0x13110808, 0x13118808, 0x13118808, 0x13118808,
+    0x13138808, 0x13138808, 0x13138808, 0x13390808, 0x13320808, 0x13940808, 0x13320808, 0x13960808,
+    0x13320808, 0x139b8808, 0x13320808, 0x13938804, 0x20c4, 0x13920804, 0x20c4, 0x13a28804, 0x20c4,
+    0x13320808, 0x4648, 0x138a0808, 0x4648, 0x13a50808, 0x4648, 0x13a90808, 0x4648, 0x13ae0808,
+    0x4648, 0x13a48808, 0x4688, 0x13a50808, 0x4688, 0x13a60808, 0x4688, 0x13e28808, 0x4608,
+    0x13e30808, 0x4608, 0x13e40808, 0x4608, 0x15058804, 0x3084, 0x150a0804, 0x3084, 0x150b0804,
+    0x3084, 0x14f98804, 0x3084, 0x14fa0804, 0x3084, 0x14fa8804, 0x3084, 0x14fd8804, 0x3084,
+    0x15018804, 0x3084, 0x15028804, 0x3084, 0x15068804, 0x3084, 0x15090804, 0x3084, 0x152d8808,
+    0x153e0808, 0x152c0804, 0x3084, 0x152c8804, 0x3084, 0x15328804, 0x3084, 0x15558804, 0x3084,
+    0x15460804, 0x3084, 0x15480804, 0x3084, 0x15488804, 0x3084, 0x154b0804, 0x3084, 0x15520804,
+    0x3084, 0x158b8804, 0x3084, 0x158c0804, 0x3084, 0x16190808, 0x16278808, 0x16180808, 0x16278808,
+    0x16188808, 0x16278808, 0x161d0808, 0x16278808, 0x16128808, 0x16278808, 0x16150808, 0x16278808,
+    0x16190808, 0x16278808, 0x16198808, 0x16278808, 0x161c8808, 0x16278808, 0x160b8808, 0x16278808,
+    0x193f0808, 0x19378808, 0x193f0808, 0x193b8808, 0x198f8808, 0x199b0808, 0x3108, 0x196f0804,
+    0x198f0804, 0x19770804, 0x198f0804, 0x197b0804, 0x198f0804, 0x197f0804, 0x198f0804, 0x19830804,
+    0x198f0804, 0x196c8804, 0x198d0804, 0x19890808, 0x4648, 0x196f8804, 0x198f0804, 0x19778804,
+    0x198f0804, 0x197b8804, 0x198f0804, 0x197f8804, 0x198f0804, 0x19838804, 0x198f0804, 0x196d0804,
+    0x198d0804, 0x19848808, 0x4648, 0x19888808, 0x4648, 0x19898808, 0x4648, 0x1ae48808, 0x1b010808,
+    0x1ae48808, 0x1e1a8808, 0x4608, 0x1e1b0808, 0x4608, 0x1e1b0808, 0x4648, 0x1e1b8808, 0x4608,
+    0x1e1c0808, 0x4608, 0x1e1c0808, 0x4648, 0x1e1c0808, 0x4688, 0x1e1c0808, 0x46c8, 0x1e1e8808,
+    0x4608, 0x1e1e8808, 0x4648, 0x1e1e8808, 0x4688, 0x1e1e8808, 0x46c8, 0x1e208808, 0x4608,
+    0x1e208808, 0x4648, 0x1e208808, 0x4688, 0x1e210808, 0x4608, 0x1e210808, 0x4648, 0x1e218808,
+    0x4608, 0x1e228808, 0x4608, 0x1e230808, 0x4608, 0x1e250808, 0x4608, 0x1e250808, 0x4688,
+    0x1e250808, 0x46c8, 0x1e250808, 0x4708, 0x1e260808, 0x4608, 0x1e260808, 0x4648, 0x1e268808,
+    0x4608, 0x1e268808, 0x4648, 0x1e240808, 0x4608, 0x1e280808, 0x4608, 0x1e280808, 0x4648,
+    0x1e288808, 0x4608, 0x1e290808, 0x4608, 0x1e310808, 0x4608, 0x1e310808, 0x4648, 0x1e200808,
+    0x4608, 0x1e250808, 0x4648, 0x1e230808, 0x1e288808, 0x1e280808, 0x4608, 0x1e280808, 0x4608,
+    0x1e1b8808, 0x1e1b8808, 0x1a2e0808, 0x1a3d0808, 0x1a2f8808, 0x1a3d8808, 0x1b7d0808, 0x1b880808,
+    0x1b7d0808, 0x1b880808, 0x1b8d8808, 0x1ba50808, 0x1bb80808, 0x1ba50808, 0xfd10828, 0x4628,
+    0x10038828, 0x10288808, 0x4648, 0x10fb8808, 0x4608, 0x103a8808, 0xff58828, 0x4628, 0xfd10808,
+    0x4608, 0xfd10808, 0x4608, 0x10038808, 0xfd10808, 0x109e0808, 0xfd10808, 0x11238808, 0xfeb0808,
+    0xc08, 0xff58808, 0x4648, 0xff58808, 0x4608, 0x10e90808, 0x4648, 0x109e0808, 0xd08, 0x110b8808,
+    0xd08, 0xfd10808, 0xac8, 0x109e0808, 0xac8, 0x110b8808, 0xac8, 0xfd10810, 0x1104, 0xfd10804,
+    0x1104, 0xfde0810, 0xb84, 0xfde0804, 0xb84, 0xfde0810, 0x1084, 0xfde0804, 0x1084, 0xfde0810,
+    0x1244, 0xfde0804, 0x1244, 0xfeb0810, 0xc04, 0x904, 0xfeb0804, 0xc04, 0x904, 0xff58810, 0xb84,
+    0xff58804, 0xb84, 0xff58810, 0x1084, 0xff58804, 0x1084, 0xff58810, 0x1244, 0xff58804, 0x1244,
+    0xff58810, 0xc04, 0xff58804, 0xc04, 0xff58810, 0x1184, 0xff58804, 0x1184, 0x10038810, 0xc84,
+    0x944, 0x10038804, 0xc84, 0x944, 0x10038810, 0xc84, 0x904, 0x10038804, 0xc84, 0x904,
+    0x10038810, 0x1184, 0x10038804, 0x1184, 0x10038810, 0x1204, 0x10038804, 0x1204, 0x10038810,
+    0xc04, 0x984, 0x10038804, 0xc04, 0x984, 0x10210810, 0xb84, 0x10210804, 0xb84, 0x10288810,
+    0xc84, 0x10288804, 0xc84, 0x103a8810, 0xb84, 0x103a8804, 0xb84, 0x103a8810, 0x1084, 0x103a8804,
+    0x1084, 0x103a8810, 0xac4, 0x103a8804, 0xac4, 0x103a8810, 0xc04, 0x103a8804, 0xc04, 0x103a8810,
+    0x11c4, 0x103a8804, 0x11c4, 0x10480810, 0x1204, 0x10480804, 0x1204, 0x10480810, 0xac4, 0x904,
+    0x10480804, 0xac4, 0x904, 0x10620810, 0x904, 0x10620804, 0x904, 0x10620810, 0x1084, 0x10620804,
+    0x1084, 0x10620810, 0x1244, 0x10620804, 0x1244, 0x106b0810, 0x1084, 0x106b0804, 0x1084,
+    0x106b0810, 0x1084, 0xc84, 0x106b0804, 0x1084, 0xc84, 0x106b0810, 0x1244, 0x106b0804, 0x1244,
+    0x106b0810, 0x1184, 0x106b0804, 0x1184, 0x10848810, 0x904, 0x10848804, 0x904, 0x10848810,
+    0xb84, 0x10848804, 0xb84, 0x10848810, 0x1084, 0x10848804, 0x1084, 0x108c0810, 0xb84,
+    0x108c0804, 0xb84, 0x108c0810, 0x1084, 0x108c0804, 0x1084, 0x108c0810, 0x1244, 0x108c0804,
+    0x1244, 0x108c0810, 0x1184, 0x108c0804, 0x1184, 0x109e0810, 0xb44, 0x904, 0x109e0804, 0xb44,
+    0x904, 0x109e0810, 0xb44, 0xac4, 0x109e0804, 0xb44, 0xac4, 0x109e0810, 0xc84, 0x944,
+    0x109e0804, 0xc84, 0x944, 0x109e0810, 0xc84, 0x904, 0x109e0804, 0xc84, 0x904, 0x10b58810,
+    0x904, 0x10b58804, 0x904, 0x10b58810, 0xb84, 0x10b58804, 0xb84, 0x10c98810, 0xb84, 0x10c98804,
+    0xb84, 0x10c98810, 0x1084, 0x10c98804, 0x1084, 0x10c98810, 0x1084, 0xc84, 0x10c98804, 0x1084,
+    0xc84, 0x10c98810, 0x1244, 0x10c98804, 0x1244, 0x10e90810, 0xb84, 0x10e90804, 0xb84,
+    0x10e90810, 0x1084, 0x10e90804, 0x1084, 0x10e90810, 0x904, 0xb84, 0x10e90804, 0x904, 0xb84,
+    0x10e90810, 0xa04, 0xb84, 0x10e90804, 0xa04, 0xb84, 0x10e90810, 0x1084, 0xb84, 0x10e90804,
+    0x1084, 0xb84, 0x10fb8810, 0xb84, 0x10fb8804, 0xb84, 0x10fb8810, 0x1084, 0x10fb8804, 0x1084,
+    0x10fb8810, 0x1244, 0x10fb8804, 0x1244, 0x10fb8810, 0x1184, 0x10fb8804, 0x1184, 0x110b8810,
+    0x10c4, 0x110b8804, 0x10c4, 0x110b8810, 0x1204, 0x110b8804, 0x1204, 0x110b8810, 0x1184,
+    0x110b8804, 0x1184, 0x110b8810, 0xb44, 0x904, 0x110b8804, 0xb44, 0x904, 0x110b8810, 0xc84,
+    0xac4, 0x110b8804, 0xc84, 0xac4, 0x11238810, 0xb44, 0x11238804, 0xb44, 0x11238810, 0x1084,
+    0x11238804, 0x1084, 0x112c8810, 0x944, 0x112c8804, 0x944, 0x112c8810, 0x904, 0x112c8804, 0x904,
+    0x112c8810, 0xac4, 0x112c8804, 0xac4, 0x112c8810, 0xb84, 0x112c8804, 0xb84, 0x112c8810, 0x1084,
+    0x112c8804, 0x1084, 0x11320810, 0xb84, 0x11320804, 0xb84, 0x11320810, 0xac4, 0x11320804, 0xac4,
+    0x11380810, 0xb84, 0x11380804, 0xb84, 0x11430810, 0x9c4, 0x11430804, 0x9c4, 0x11430810, 0x1084,
+    0x11430804, 0x1084, 0x11430810, 0x1244, 0x11430804, 0x1244, 0x103a8804, 0x1244, 0x10fb8804,
+    0xac4, 0x112c8804, 0xa44, 0x11380804, 0xa44, 0xfd10808, 0x11728808, 0x10e90808, 0x4648, 0xb84,
+    0x10e90814, 0x4608, 0x10e90814, 0xfd10810, 0x1084, 0xfd10804, 0x1084, 0xfd10810, 0xec4,
+    0xfd10804, 0xec4, 0xfd10810, 0x9c4, 0x904, 0xfd10804, 0x9c4, 0x904, 0xfd10810, 0x9c4, 0x944,
+    0xfd10804, 0x9c4, 0x944, 0xfd10810, 0x9c4, 0xec4, 0xfd10804, 0x9c4, 0xec4, 0xfd10810, 0x9c4,
+    0xb44, 0xfd10804, 0x9c4, 0xb44, 0xfd10810, 0x1084, 0x9c4, 0xfd10804, 0x1084, 0x9c4, 0xfd10810,
+    0x984, 0x904, 0xfd10804, 0x984, 0x904, 0xfd10810, 0x984, 0x944, 0xfd10804, 0x984, 0x944,
+    0xfd10810, 0x984, 0xec4, 0xfd10804, 0x984, 0xec4, 0xfd10810, 0x984, 0xb44, 0xfd10804, 0x984,
+    0xb44, 0xfd10810, 0x1084, 0x984, 0xfd10804, 0x1084, 0x984, 0x10038810, 0x1084, 0x10038804,
+    0x1084, 0x10038810, 0xec4, 0x10038804, 0xec4, 0x10038810, 0xb44, 0x10038804, 0xb44, 0x10038810,
+    0x9c4, 0x904, 0x10038804, 0x9c4, 0x904, 0x10038810, 0x9c4, 0x944, 0x10038804, 0x9c4, 0x944,
+    0x10038810, 0x9c4, 0xec4, 0x10038804, 0x9c4, 0xec4, 0x10038810, 0x9c4, 0xb44, 0x10038804,
+    0x9c4, 0xb44, 0x10038810, 0x1084, 0x9c4, 0x10038804, 0x1084, 0x9c4, 0x10480810, 0xec4,
+    0x10480804, 0xec4, 0x10480810, 0x1084, 0x10480804, 0x1084, 0x109e0810, 0x1084, 0x109e0804,
+    0x1084, 0x109e0810, 0xec4, 0x109e0804, 0xec4, 0x109e0810, 0x9c4, 0x904, 0x109e0804, 0x9c4,
+    0x904, 0x109e0810, 0x9c4, 0x944, 0x109e0804, 0x9c4, 0x944, 0x109e0810, 0x9c4, 0xec4,
+    0x109e0804, 0x9c4, 0xec4, 0x109e0810, 0x9c4, 0xb44, 0x109e0804, 0x9c4, 0xb44, 0x109e0810,
+    0x1084, 0x9c4, 0x109e0804, 0x1084, 0x9c4, 0x109e0810, 0xfc4, 0x904, 0x109e0804, 0xfc4, 0x904,
+    0x109e0810, 0xfc4, 0x944, 0x109e0804, 0xfc4, 0x944, 0x109e0810, 0xfc4, 0xec4, 0x109e0804,
+    0xfc4, 0xec4, 0x109e0810, 0xfc4, 0xb44, 0x109e0804, 0xfc4, 0xb44, 0x109e0810, 0xfc4, 0x1084,
+    0x109e0804, 0xfc4, 0x1084, 0x110b8810, 0x1084, 0x110b8804, 0x1084, 0x110b8810, 0xec4,
+    0x110b8804, 0xec4, 0x110b8810, 0xfc4, 0x904, 0x110b8804, 0xfc4, 0x904, 0x110b8810, 0xfc4,
+    0x944, 0x110b8804, 0xfc4, 0x944, 0x110b8810, 0xfc4, 0xec4, 0x110b8804, 0xfc4, 0xec4,
+    0x110b8810, 0xfc4, 0xb44, 0x110b8804, 0xfc4, 0xb44, 0x110b8810, 0xfc4, 0x1084, 0x110b8804,
+    0xfc4, 0x1084, 0x11380810, 0x944, 0x11380804, 0x944, 0x11380810, 0x1084, 0x11380804, 0x1084,
+    0x11380810, 0xec4, 0x11380804, 0xec4, 0x11380810, 0xb44, 0x11380804, 0xb44, 0x106b0814,
+    0x106b0814, 0x106b0808, 0x106b0808, 0x118f0804, 0x884, 0x118f0804, 0x8c4, 0x118f0804, 0x884,
+    0x944, 0x118f0804, 0x8c4, 0x944, 0x118f0804, 0x884, 0x904, 0x118f0804, 0x8c4, 0x904,
+    0x118f0804, 0x884, 0xa84, 0x118f0804, 0x8c4, 0xa84, 0x118f0810, 0x884, 0x118f0810, 0x8c4,
+    0x118f0810, 0x884, 0x944, 0x118f0810, 0x8c4, 0x944, 0x118f0810, 0x884, 0x904, 0x118f0810,
+    0x8c4, 0x904, 0x118f0810, 0x884, 0xa84, 0x118f0810, 0x8c4, 0xa84, 0x11918804, 0x884,
+    0x11918804, 0x8c4, 0x11918804, 0x884, 0x944, 0x11918804, 0x8c4, 0x944, 0x11918804, 0x884,
+    0x904, 0x11918804, 0x8c4, 0x904, 0x11918810, 0x884, 0x11918810, 0x8c4, 0x11918810, 0x884,
+    0x944, 0x11918810, 0x8c4, 0x944, 0x11918810, 0x884, 0x904, 0x11918810, 0x8c4, 0x904,
+    0x11948804, 0x884, 0x11948804, 0x8c4, 0x11948804, 0x884, 0x944, 0x11948804, 0x8c4, 0x944,
+    0x11948804, 0x884, 0x904, 0x11948804, 0x8c4, 0x904, 0x11948804, 0x884, 0xa84, 0x11948804,
+    0x8c4, 0xa84, 0x11948810, 0x884, 0x11948810, 0x8c4, 0x11948810, 0x884, 0x944, 0x11948810,
+    0x8c4, 0x944, 0x11948810, 0x884, 0x904, 0x11948810, 0x8c4, 0x904, 0x11948810, 0x884, 0xa84,
+    0x11948810, 0x8c4, 0xa84, 0x11958804, 0x884, 0x11958804, 0x8c4, 0x11958804, 0x884, 0x944,
+    0x11958804, 0x8c4, 0x944, 0x11958804, 0x884, 0x904, 0x11958804, 0x8c4, 0x904, 0x11958804,
+    0x884, 0xa84, 0x11958804, 0x8c4, 0xa84, 0x11958810, 0x884, 0x11958810, 0x8c4, 0x11958810,
+    0x884, 0x944, 0x11958810, 0x8c4, 0x944, 0x11958810, 0x884, 0x904, 0x11958810, 0x8c4, 0x904,
+    0x11958810, 0x884, 0xa84, 0x11958810, 0x8c4, 0xa84, 0x11998804, 0x884, 0x11998804, 0x8c4,
+    0x11998804, 0x884, 0x944, 0x11998804, 0x8c4, 0x944, 0x11998804, 0x884, 0x904, 0x11998804,
+    0x8c4, 0x904, 0x11998810, 0x884, 0x11998810, 0x8c4, 0x11998810, 0x884, 0x944, 0x11998810,
+    0x8c4, 0x944, 0x11998810, 0x884, 0x904, 0x11998810, 0x8c4, 0x904, 0x11a08804, 0x884,
+    0x11a08804, 0x8c4, 0x11a08804, 0x884, 0x944, 0x11a08804, 0x8c4, 0x944, 0x11a08804, 0x884,
+    0x904, 0x11a08804, 0x8c4, 0x904, 0x11a08804, 0x884, 0xa84, 0x11a08804, 0x8c4, 0xa84,
+    0x11a08810, 0x8c4, 0x11a08810, 0x8c4, 0x944, 0x11a08810, 0x8c4, 0x904, 0x11a08810, 0x8c4,
+    0xa84, 0x11a30804, 0x884, 0x11a30804, 0x8c4, 0x11a30804, 0x884, 0x944, 0x11a30804, 0x8c4,
+    0x944, 0x11a30804, 0x884, 0x904, 0x11a30804, 0x8c4, 0x904, 0x11a30804, 0x884, 0xa84,
+    0x11a30804, 0x8c4, 0xa84, 0x11a30810, 0x884, 0x11a30810, 0x8c4, 0x11a30810, 0x884, 0x944,
+    0x11a30810, 0x8c4, 0x944, 0x11a30810, 0x884, 0x904, 0x11a30810, 0x8c4, 0x904, 0x11a30810,
+    0x884, 0xa84, 0x11a30810, 0x8c4, 0xa84, 0x118f0804, 0x944, 0x118f0804, 0x904, 0x11918804,
+    0x944, 0x11918804, 0x904, 0x11948804, 0x944, 0x11948804, 0x904, 0x11958804, 0x944, 0x11958804,
+    0x904, 0x11998804, 0x944, 0x11998804, 0x904, 0x11a08804, 0x944, 0x11a08804, 0x904, 0x11a30804,
+    0x944, 0x11a30804, 0x904, 0x118f0804, 0x884, 0x1304, 0x118f0804, 0x8c4, 0x1304, 0x118f0804,
+    0x884, 0x944, 0x1304, 0x118f0804, 0x8c4, 0x944, 0x1304, 0x118f0804, 0x884, 0x904, 0x1304,
+    0x118f0804, 0x8c4, 0x904, 0x1304, 0x118f0804, 0x884, 0xa84, 0x1304, 0x118f0804, 0x8c4, 0xa84,
+    0x1304, 0x118f0810, 0x884, 0x1304, 0x118f0810, 0x8c4, 0x1304, 0x118f0810, 0x884, 0x944, 0x1304,
+    0x118f0810, 0x8c4, 0x944, 0x1304, 0x118f0810, 0x884, 0x904, 0x1304, 0x118f0810, 0x8c4, 0x904,
+    0x1304, 0x118f0810, 0x884, 0xa84, 0x1304, 0x118f0810, 0x8c4, 0xa84, 0x1304, 0x11948804, 0x884,
+    0x1304, 0x11948804, 0x8c4, 0x1304, 0x11948804, 0x884, 0x944, 0x1304, 0x11948804, 0x8c4, 0x944,
+    0x1304, 0x11948804, 0x884, 0x904, 0x1304, 0x11948804, 0x8c4, 0x904, 0x1304, 0x11948804, 0x884,
+    0xa84, 0x1304, 0x11948804, 0x8c4, 0xa84, 0x1304, 0x11948810, 0x884, 0x1304, 0x11948810, 0x8c4,
+    0x1304, 0x11948810, 0x884, 0x944, 0x1304, 0x11948810, 0x8c4, 0x944, 0x1304, 0x11948810, 0x884,
+    0x904, 0x1304, 0x11948810, 0x8c4, 0x904, 0x1304, 0x11948810, 0x884, 0xa84, 0x1304, 0x11948810,
+    0x8c4, 0xa84, 0x1304, 0x11a30804, 0x884, 0x1304, 0x11a30804, 0x8c4, 0x1304, 0x11a30804, 0x884,
+    0x944, 0x1304, 0x11a30804, 0x8c4, 0x944, 0x1304, 0x11a30804, 0x884, 0x904, 0x1304, 0x11a30804,
+    0x8c4, 0x904, 0x1304, 0x11a30804, 0x884, 0xa84, 0x1304, 0x11a30804, 0x8c4, 0xa84, 0x1304,
+    0x11a30810, 0x884, 0x1304, 0x11a30810, 0x8c4, 0x1304, 0x11a30810, 0x884, 0x944, 0x1304,
+    0x11a30810, 0x8c4, 0x944, 0x1304, 0x11a30810, 0x884, 0x904, 0x1304, 0x11a30810, 0x8c4, 0x904,
+    0x1304, 0x11a30810, 0x884, 0xa84, 0x1304, 0x11a30810, 0x8c4, 0xa84, 0x1304, 0x118f0804, 0x984,
+    0x118f0804, 0xc84, 0x118f0804, 0x944, 0x1304, 0x118f0804, 0x1304, 0x118f0804, 0x904, 0x1304,
+    0x118f0804, 0xa84, 0x118f0804, 0xa84, 0x1304, 0x118f0810, 0x984, 0x118f0810, 0xc84, 0x118f0810,
+    0x944, 0x118f0810, 0x904, 0x118f0810, 0x1304, 0x25d8805, 0xa84, 0x11948804, 0x944, 0x1304,
+    0x11948804, 0x1304, 0x11948804, 0x904, 0x1304, 0x11948804, 0xa84, 0x11948804, 0xa84, 0x1304,
+    0x11918810, 0x944, 0x11918810, 0x904, 0x11948810, 0x944, 0x11948810, 0x904, 0x11948810, 0x1304,
+    0x2600805, 0x944, 0x2600805, 0x904, 0x2600805, 0xa84, 0x11958804, 0x984, 0x11958804, 0xc84,
+    0x11958804, 0xac4, 0x944, 0x11958804, 0xac4, 0x904, 0x11958804, 0xa84, 0x11958804, 0xac4,
+    0xa84, 0x11958810, 0x984, 0x11958810, 0xc84, 0x11958810, 0x944, 0x11958810, 0x904, 0x2608805,
+    0x944, 0x2608805, 0x904, 0x2608805, 0xa84, 0x11a08804, 0x984, 0x11a08804, 0xc84, 0x11a08804,
+    0xac4, 0x944, 0x11a08804, 0xac4, 0x904, 0x119c8804, 0x884, 0x119c8804, 0x8c4, 0x11a08804,
+    0xa84, 0x11a08804, 0xac4, 0xa84, 0x11a08810, 0x984, 0x11a08810, 0xc84, 0x11a08810, 0x944,
+    0x11a08810, 0x904, 0x119c8810, 0x8c4, 0x25d8805, 0x944, 0x25d8805, 0x904, 0x11a30804, 0x944,
+    0x1304, 0x11a30804, 0x1304, 0x11a30804, 0x904, 0x1304, 0x11a30804, 0xa84, 0x11a30804, 0xa84,
+    0x1304, 0x11998810, 0x944, 0x11998810, 0x904, 0x11a30810, 0x944, 0x11a30810, 0x904, 0x11a30810,
+    0x1304, 0x13f0809, 0x13f0809, 0x13f0809, 0x13f0809, 0x13f0809, 0x1df8809, 0x1df8809, 0x1df8809,
+    0x1df8809, 0x1df8809, 0x1e00809, 0x1e00809, 0x1e00809, 0x1e00809, 0x1e00809, 0x1338809,
+    0x1338809, 0x1368809, 0x1368809, 0x1368809, 0x1338809, 0x1338809, 0x1368809, 0x1df8809,
+    0x1df8809, 0x1df8809, 0x1df8809, 0x10c98814, 0x10e90808, 0xfd10808, 0x1d30809, 0xfeb0808,
+    0xfd10808, 0x1d30809, 0x10e90808, 0x2950809, 0xfeb0814, 0xfeb0808, 0x1d30809, 0x109e0808,
+    0xfeb0808, 0x1d30809, 0x110b8808, 0x2950809, 0x10210814, 0x103a8804, 0xe44, 0x108c0814,
+    0x109e0808, 0x10e90828, 0x10848828, 0x10fb8814, 0x10038814, 0x106b0814, 0x10fb8828, 0x10848828,
+    0xfd10810, 0xa44, 0x10210814, 0xfd10814, 0x11320814, 0xfd10814, 0x1d30809, 0x10e90814,
+    0xfcc883c, 0x33b083d, 0xfcf883c, 0xfcc883c, 0x33b083d, 0xfd0883c, 0xfcc883c, 0x33b083d,
+    0xfcc883c, 0xfcc083c, 0xfcc883c, 0x33b083d, 0xfcd883c, 0xfcd083c, 0x33b083d, 0xfcd883c,
+    0xfcc883c, 0x33b083d, 0xfce883c, 0xfcd083c, 0x33b083d, 0xfce883c, 0xfcd883c, 0x33b083d,
+    0xfce883c, 0xfce083c, 0x33b083d, 0xfce883c, 0xfcc883c, 0x33b083d, 0xfcf083c, 0xfce883c,
+    0x33b083d, 0xfcf083c, 0xfcc883c, 0x33b083d, 0xfd0083c, 0xfcd883c, 0x33b083d, 0xfd0083c,
+    0xfce883c, 0x33b083d, 0xfd0083c, 0xfcf883c, 0x33b083d, 0xfd0083c, 0xfcc883c, 0x33b083d,
+    0x10480814, 0x10480814, 0x10480814, 0x10480814, 0x10480814, 0x10480814, 0x11238814, 0x11238814,
+    0x10480814, 0x11238814, 0x10480814, 0x10480814, 0x11238814, 0x10480814, 0x10480814, 0x10480814,
+    0x10480814, 0x11320814, 0x11320814, 0x10480814, 0x11320814, 0x10480814, 0x10480814, 0x10480808,
+    0x10480808, 0x10480808, 0x10480808, 0x10480808, 0x10480808, 0x11238808, 0x11238808, 0x10480808,
+    0x11238808, 0x10480808, 0x10480808, 0x11238808, 0x10480808, 0x10480808, 0x10480808, 0x10480808,
+    0x11320808, 0x11320808, 0x10480808, 0x11320808, 0x10480808, 0x10480808, 0xfcc083c, 0x33b083d,
+    0xfcd883c, 0x2f60805, 0xbc4, 0x2f68805, 0xbc4, 0x2f80805, 0xbc4, 0x3130805, 0xbc4, 0x3150805,
+    0xbc4, 0x3140805, 0xbc4, 0x32c8805, 0xbc4, 0x32e8805, 0xbc4, 0x32f8805, 0xbc4, 0x3430805,
+    0xbc4, 0x3438805, 0xbc4, 0x3460809, 0x3460809, 0x3460809, 0x3460809, 0x3460809, 0x3468809,
+    0x3468809, 0x3468809, 0x3468809, 0x3468809, 0x34c8805, 0xbc4, 0x34f8805, 0xbc4, 0x3500805,
+    0xbc4, 0x3510805, 0xbc4, 0x3358805, 0xbc4, 0x35c8805, 0xbc4, 0x3530805, 0xbc4, 0x3350805,
+    0xbc4, 0x3360805, 0xbc4, 0x35d8805, 0xbc4, 0x35e0805, 0xbc4, 0x3620805, 0xbc4, 0x3628805,
+    0xbc4, 0x3630805, 0xbc4, 0x3638805, 0xbc4, 0x3640805, 0xbc4, 0x3648805, 0xbc4, 0x3670805,
+    0xbc4, 0x3678805, 0xbc4, 0x3680805, 0xbc4, 0x3688805, 0xbc4, 0x3750805, 0xbc4, 0x3780805,
+    0xbc4, 0x3788805, 0xbc4, 0x3798805, 0xbc4, 0x3650805, 0xbc4, 0x3658805, 0xbc4, 0x36c8805,
+    0xbc4, 0x36d0805, 0xbc4, 0x37b0805, 0xbc4, 0x37b8805, 0xbc4, 0x37c0805, 0xbc4, 0x37c8805,
+    0xbc4, 0xfcc880c, 0xfcc080c, 0xfcc880c, 0xfcc880c, 0xfcc880c, 0xfcd080c, 0xfcc880c, 0xfcd880c,
+    0xfcc880c, 0xfce080c, 0xfcc880c, 0xfce880c, 0xfcc880c, 0xfcf080c, 0xfcc880c, 0xfcf880c,
+    0xfcc880c, 0xfd0080c, 0xfcc880c, 0xfd0880c, 0xfcd080c, 0xfcc080c, 0x1940809, 0xfcc8808,
+    0x1948809, 0x1940809, 0xfcd0808, 0x1948809, 0x1940809, 0xfcd8808, 0x1948809, 0x1940809,
+    0xfce0808, 0x1948809, 0x1940809, 0xfce8808, 0x1948809, 0x1940809, 0xfcf0808, 0x1948809,
+    0x1940809, 0xfcf8808, 0x1948809, 0x1940809, 0xfd00808, 0x1948809, 0x1940809, 0xfd08808,
+    0x1948809, 0x1940809, 0xfcc8808, 0xfcc0808, 0x1948809, 0x1940809, 0xfcc8808, 0xfcc8808,
+    0x1948809, 0x1940809, 0xfcc8808, 0xfcd0808, 0x1948809, 0x1940809, 0xfcc8808, 0xfcd8808,
+    0x1948809, 0x1940809, 0xfcc8808, 0xfce0808, 0x1948809, 0x1940809, 0xfcc8808, 0xfce8808,
+    0x1948809, 0x1940809, 0xfcc8808, 0xfcf0808, 0x1948809, 0x1940809, 0xfcc8808, 0xfcf8808,
+    0x1948809, 0x1940809, 0xfcc8808, 0xfd00808, 0x1948809, 0x1940809, 0xfcc8808, 0xfd08808,
+    0x1948809, 0x1940809, 0xfcd0808, 0xfcc0808, 0x1948809, 0xfcc8808, 0x13f0809, 0xfcd0808,
+    0x13f0809, 0xfcd8808, 0x13f0809, 0xfce0808, 0x13f0809, 0xfce8808, 0x13f0809, 0xfcf0808,
+    0x13f0809, 0xfcf8808, 0x13f0809, 0xfd00808, 0x13f0809, 0xfd08808, 0x13f0809, 0xfcc8808,
+    0xfcc0808, 0x13f0809, 0xfcc8808, 0xfcc8808, 0x13f0809, 0xfcc8808, 0xfcd0808, 0x13f0809,
+    0xfcc8808, 0xfcd8808, 0x13f0809, 0xfcc8808, 0xfce0808, 0x13f0809, 0xfcc8808, 0xfce8808,
+    0x13f0809, 0xfcc8808, 0xfcf0808, 0x13f0809, 0xfcc8808, 0xfcf8808, 0x13f0809, 0xfcc8808,
+    0xfd00808, 0x13f0809, 0xfcc8808, 0xfd08808, 0x13f0809, 0xfcd0808, 0xfcc0808, 0x13f0809,
+    0x1940809, 0xfd10808, 0x1948809, 0x1940809, 0xfde0808, 0x1948809, 0x1940809, 0xfeb0808,
+    0x1948809, 0x1940809, 0xff58808, 0x1948809, 0x1940809, 0x10038808, 0x1948809, 0x1940809,
+    0x10210808, 0x1948809, 0x1940809, 0x10288808, 0x1948809, 0x1940809, 0x103a8808, 0x1948809,
+    0x1940809, 0x10480808, 0x1948809, 0x1940809, 0x10558808, 0x1948809, 0x1940809, 0x10620808,
+    0x1948809, 0x1940809, 0x106b0808, 0x1948809, 0x1940809, 0x10848808, 0x1948809, 0x1940809,
+    0x108c0808, 0x1948809, 0x1940809, 0x109e0808, 0x1948809, 0x1940809, 0x10b58808, 0x1948809,
+    0x1940809, 0x10c00808, 0x1948809, 0x1940809, 0x10c98808, 0x1948809, 0x1940809, 0x10e90808,
+    0x1948809, 0x1940809, 0x10fb8808, 0x1948809, 0x1940809, 0x110b8808, 0x1948809, 0x1940809,
+    0x11238808, 0x1948809, 0x1940809, 0x112c8808, 0x1948809, 0x1940809, 0x11320808, 0x1948809,
+    0x1940809, 0x11380808, 0x1948809, 0x1940809, 0x11430808, 0x1948809, 0xfcc880c, 0xfcc880c,
+    0xfcc880c, 0xfcd080c, 0xfcc880c, 0xfcd880c, 0xfcc880c, 0xfce080c, 0xfcc880c, 0xfce880c,
+    0xfcc880c, 0xfcf080c, 0xfcc880c, 0xfcf880c, 0xfcc880c, 0xfd0080c, 0xfcc880c, 0xfd0880c,
+    0xfcd080c, 0xfcc080c, 0xfcc880c, 0xfcc080c, 0xfcc880c, 0xfcc080c, 0xfcc880c, 0xfcc080c,
+    0xfcc880c, 0xfcc080c, 0x3460809, 0x3460809, 0x3460809, 0x3460809, 0x1200809, 0x1200809,
+    0x3358809, 0x3358809, 0x3358809, 0x3358809, 0x3358809, 0x3358809, 0x6e10805, 0xbc4, 0x11ab0808,
+    0x11a58808, 0x11aa8808, 0x12350808, 0x12398808, 0x7da00808, 0x671b0000, 0x4608, 0x7da00808,
+    0x69c10000, 0x4608, 0x7da00808, 0x672d8000, 0x7da00808, 0x672d0000, 0x7da00808, 0x672c8000,
+    0x4608, 0x7da00808, 0x675d8000, 0x7da00808, 0x68c10000, 0x4608, 0x7da00808, 0x68f00000, 0x4608,
+    0x7da00808, 0x69000000, 0x4608, 0x7da00808, 0x69010000, 0x7da00808, 0x69ae0000, 0x4608,
+    0x7da00808, 0x69b48000, 0x4608, 0x7da00808, 0x6e078000, 0x4608, 0x7da00808, 0x6e078000, 0x4648,
+    0x7da00808, 0x6e110000, 0x4608, 0x7da00808, 0x6e118000, 0x7da00808, 0x6e110000, 0x7da00808,
+    0x6e118000, 0x4608, 0x7da00808, 0x6ef98000, 0x7da00808, 0x6f3d0000, 0x7da00808, 0x6fa88000,
+    0x7da00808, 0x6fa80000, 0x4608, 0x7da00808, 0x6fe20000, 0x7da00808, 0x6fe18000, 0x4608,
+    0x7da00808, 0x71260000, 0x7da00808, 0x729a8000, 0x7da00808, 0x72f08000, 0x7da00808, 0x72f28000,
+    0x4608, 0x7da00808, 0x73840000, 0x4608, 0x7da00808, 0x75bd0000, 0x4608, 0x7da00808, 0x75e68000,
+    0x7da00808, 0x76088000, 0x7da00808, 0x761a8000, 0x7da00808, 0x761d0000, 0x7da00808, 0x78360000,
+    0x7da00808, 0x79158000, 0x7da00808, 0x79158000, 0x4608, 0x7da00808, 0x67160000, 0x7da00808,
+    0x792d8000, 0x4608, 0x7da00808, 0x79568000, 0x7da00808, 0x79c58000, 0x4608, 0x7da00808,
+    0x7ac58000, 0x4608, 0x7da00808, 0x7b770000, 0x4608, 0x7da00808, 0x7c9d0000, 0x4608, 0x7da00808,
+    0x7c9d8000, 0x7da00808, 0x7d7c8000, 0x4608, 0x7da00808, 0x7e7c8000, 0x7da00808, 0x7f4f8000,
+    0x7da00808, 0x7fa98000, 0x7da00808, 0x7fa90000, 0x7da00808, 0x7fa98000, 0x4608, 0x7da00808,
+    0x7fa98000, 0x4648, 0x7da00808, 0x7fa90000, 0x4608, 0x7da00808, 0x7fc50000, 0x4608, 0x7da00808,
+    0x7fc50000, 0x4648, 0x7da00808, 0x7fc58000, 0x7da08808, 0x40010000, 0x7da08808, 0x40400000,
+    0x7da08808, 0x403f8000, 0x4608, 0x7da08808, 0x40448000, 0x4608, 0x7da08808, 0x40fe0000, 0x4608,
+    0x7da08808, 0x413c8000, 0x7da08808, 0x413c8000, 0x4608, 0x7da08808, 0x413c8000, 0x4648,
+    0x7da08808, 0x43270000, 0x7da08808, 0x44320000, 0x7da08808, 0x44c00000, 0x7da08808, 0x44bf8000,
+    0x7da08808, 0x44e08000, 0x7da08808, 0x44e90000, 0x7da08808, 0x44e90000, 0x4608, 0x7da08808,
+    0x45d00000, 0x7da08808, 0x468e8000, 0x7da08808, 0x46d98000, 0x4608, 0x7da08808, 0x47b30000,
+    0x7da08808, 0x47db0000, 0x7da08808, 0x47db0000, 0x4608, 0x7da08808, 0x47db0000, 0x4648,
+    0x7da08808, 0x48488000, 0x4608, 0x7da08808, 0x4a428000, 0x7da08808, 0x4abb8000, 0x7da08808,
+    0x4abc0000, 0x7da08808, 0x4abf8000, 0x7da08808, 0x4af40000, 0x7da08808, 0x4b0e0000, 0x4608,
+    0x7da08808, 0x4b0e8000, 0x7da08808, 0x4b740000, 0x4608, 0x7da08808, 0x4ba90000, 0x7da08808,
+    0x4bf30000, 0x7da08808, 0x4c3a8000, 0x7da08808, 0x4c670000, 0x7da08808, 0x4c6f0000, 0x7da08808,
+    0x4c6f8000, 0x7da08808, 0x4c700000, 0x4608, 0x7da08808, 0x4c700000, 0x7da08808, 0x4cb18000,
+    0x7da08808, 0x4ccb0000, 0x4608, 0x7da08808, 0x4d360000, 0x7da08808, 0x4d540000, 0x4608,
+    0x7da08808, 0x4d9e0000, 0x4608, 0x7da08808, 0x4e3e0000, 0x7da08808, 0x4f0f8000, 0x7da08808,
+    0x4f3a8000, 0x4608, 0x7da08808, 0x4f530000, 0x7da08808, 0x4f620000, 0x7da08808, 0x4f7f0000,
+    0x7da08808, 0x4fa50000, 0x4608, 0x7da08808, 0x4fa80000, 0x7da08808, 0x4fa90000, 0x4608,
+    0x7da08808, 0x4fbf8000, 0x7da08808, 0x4fc68000, 0x4608, 0x7da08808, 0x4fcc8000, 0x7da08808,
+    0x4fce0000, 0x4608, 0x7da08808, 0x4fce0000, 0x4648, 0x7da08808, 0x4fcf8000, 0x7da00808,
+    0x67000000, 0x7da00808, 0x67140000, 0x7da00808, 0x671b0000, 0x7da00808, 0x671f8000, 0x7da00808,
+    0x672c8000, 0x7da00808, 0x67428000, 0x7da00808, 0x67460000, 0x7da00808, 0x67500000, 0x7da00808,
+    0x675d0000, 0x7da00808, 0x689f8000, 0x7da00808, 0x68b28000, 0x7da00808, 0x68b58000, 0x7da00808,
+    0x68c10000, 0x7da00808, 0x68cb0000, 0x7da00808, 0x68d58000, 0x7da00808, 0x68f00000, 0x7da00808,
+    0x68fa8000, 0x7da00808, 0x69000000, 0x7da00808, 0x694d8000, 0x7da00808, 0x697c8000, 0x7da00808,
+    0x698a8000, 0x7da00808, 0x698d0000, 0x7da00808, 0x699c0000, 0x7da00808, 0x69a08000, 0x7da00808,
+    0x69ae0000, 0x7da00808, 0x69b48000, 0x7da00808, 0x69c10000, 0x7da00808, 0x69db0000, 0x7da00808,
+    0x69e40000, 0x7da00808, 0x69f18000, 0x7da00808, 0x6b6b8000, 0x7da00808, 0x6b8f8000, 0x7da00808,
+    0x6c758000, 0x7da00808, 0x6c810000, 0x7da00808, 0x6c850000, 0x7da00808, 0x6c8a8000, 0x7da00808,
+    0x6c938000, 0x7da00808, 0x6cb98000, 0x7da00808, 0x6da80000, 0x7da00808, 0x6dc00000, 0x7da00808,
+    0x6dfc0000, 0x7da00808, 0x6e078000, 0x7da00808, 0x6e110000, 0x7da00808, 0x6e1c0000, 0x7da00808,
+    0x6e370000, 0x7da00808, 0x6e388000, 0x7da00808, 0x6eed8000, 0x7da00808, 0x6ef28000, 0x7da00808,
+    0x6ef88000, 0x7da00808, 0x6eff0000, 0x7da00808, 0x6f390000, 0x7da00808, 0x6f3d0000, 0x7da00808,
+    0x6f3f8000, 0x7da00808, 0x6f7a0000, 0x7da00808, 0x6f7f0000, 0x7da00808, 0x6f858000, 0x7da00808,
+    0x6f898000, 0x7da00808, 0x6fa80000, 0x7da00808, 0x6fb08000, 0x7da00808, 0x6fb98000, 0x7da00808,
+    0x6fe18000, 0x7da00808, 0x71040000, 0x7da00808, 0x711b0000, 0x7da00808, 0x71258000, 0x7da00808,
+    0x72978000, 0x7da00808, 0x729a0000, 0x7da00808, 0x72c38000, 0x7da00808, 0x72cb8000, 0x7da00808,
+    0x72d20000, 0x7da00808, 0x72dc8000, 0x7da00808, 0x72f00000, 0x7da00808, 0x72f28000, 0x7da00808,
+    0x73780000, 0x7da00808, 0x73840000, 0x7da00808, 0x73940000, 0x7da00808, 0x75900000, 0x7da00808,
+    0x75b10000, 0x7da00808, 0x75bc8000, 0x7da00808, 0x75d98000, 0x7da00808, 0x75e58000, 0x7da00808,
+    0x75ea0000, 0x7da00808, 0x75ed8000, 0x7da00808, 0x76078000, 0x7da00808, 0x760a0000, 0x7da00808,
+    0x761a0000, 0x7da00808, 0x78358000, 0x7da00808, 0x79150000, 0x7da00808, 0x791b0000, 0x7da00808,
+    0x791d8000, 0x7da00808, 0x791f8000, 0x7da00808, 0x79238000, 0x7da00808, 0x792c8000, 0x7da00808,
+    0x792d8000, 0x7da00808, 0x79560000, 0x7da00808, 0x79c20000, 0x7da00808, 0x79c48000, 0x7da00808,
+    0x7a6e0000, 0x7da00808, 0x7a730000, 0x7da00808, 0x7a8c0000, 0x7da00808, 0x7a8f8000, 0x7da00808,
+    0x7a940000, 0x7da00808, 0x7a980000, 0x7da00808, 0x7ac58000, 0x7da00808, 0x7ac90000, 0x7da00808,
+    0x7b3b0000, 0x7da00808, 0x7b3e8000, 0x7da00808, 0x7b570000, 0x7da00808, 0x7b5f8000, 0x7da00808,
+    0x7b770000, 0x7da00808, 0x7bed8000, 0x7da00808, 0x7bf10000, 0x7da00808, 0x7bf98000, 0x7da00808,
+    0x7c9d0000, 0x7da00808, 0x7cdc0000, 0x7da00808, 0x7cdf0000, 0x7da00808, 0x7d3a0000, 0x7da00808,
+    0x7d658000, 0x7da00808, 0x7d7c8000, 0x7da00808, 0x7e398000, 0x7da00808, 0x7e7c0000, 0x7da00808,
+    0x7f9b0000, 0x7da00808, 0x7fa88000, 0x7da00808, 0x7fc50000, 0x7da00808, 0x7fde8000, 0x7da08808,
+    0x40008000, 0x7da08808, 0x40060000, 0x7da08808, 0x40090000, 0x7da08808, 0x40198000, 0x7da08808,
+    0x403f8000, 0x7da08808, 0x40448000, 0x7da08808, 0x40f18000, 0x7da08808, 0x40f50000, 0x7da08808,
+    0x40f98000, 0x7da08808, 0x40fe0000, 0x7da08808, 0x41060000, 0x7da08808, 0x410d8000, 0x7da08808,
+    0x410f8000, 0x7da08808, 0x41370000, 0x7da08808, 0x41390000, 0x7da08808, 0x413c0000, 0x7da08808,
+    0x43268000, 0x7da08808, 0x43358000, 0x7da08808, 0x44200000, 0x7da08808, 0x44260000, 0x7da08808,
+    0x44318000, 0x7da08808, 0x44bf0000, 0x7da08808, 0x44c58000, 0x7da08808, 0x44e90000, 0x7da08808,
+    0x45000000, 0x7da08808, 0x461b8000, 0x7da08808, 0x46230000, 0x7da08808, 0x462a8000, 0x7da08808,
+    0x463c0000, 0x7da08808, 0x464e8000, 0x7da08808, 0x46b20000, 0x7da08808, 0x46b80000, 0x7da08808,
+    0x46d98000, 0x7da08808, 0x47558000, 0x7da08808, 0x47650000, 0x7da08808, 0x47cd8000, 0x7da08808,
+    0x47d80000, 0x7da08808, 0x47da8000, 0x7da08808, 0x48488000, 0x7da08808, 0x48a48000, 0x7da08808,
+    0x48e30000, 0x7da08808, 0x48e60000, 0x7da08808, 0x48e88000, 0x7da08808, 0x4abb8000, 0x7da08808,
+    0x4ac00000, 0x7da08808, 0x4b0e0000, 0x7da08808, 0x4b5b0000, 0x7da08808, 0x4b5c8000, 0x7da08808,
+    0x4b740000, 0x7da08808, 0x4ba88000, 0x7da08808, 0x4baf0000, 0x7da08808, 0x4bb10000, 0x7da08808,
+    0x4bb48000, 0x7da08808, 0x4be58000, 0x7da08808, 0x4bf68000, 0x7da08808, 0x4bf98000, 0x7da08808,
+    0x4c008000, 0x7da08808, 0x4c540000, 0x7da08808, 0x4c6d8000, 0x7da08808, 0x4c6f8000, 0x7da08808,
+    0x4ccb0000, 0x7da08808, 0x4ccc8000, 0x7da08808, 0x4cd60000, 0x7da08808, 0x4d540000, 0x7da08808,
+    0x4d6c0000, 0x7da08808, 0x4d6f8000, 0x7da08808, 0x4d928000, 0x7da08808, 0x4d978000, 0x7da08808,
+    0x4d990000, 0x7da08808, 0x4d9e0000, 0x7da08808, 0x4dad0000, 0x7da08808, 0x4e728000, 0x7da08808,
+    0x4f3a8000, 0x7da08808, 0x4f3f8000, 0x7da08808, 0x4f528000, 0x7da08808, 0x4f5d8000, 0x7da08808,
+    0x4f618000, 0x7da08808, 0x4f668000, 0x7da08808, 0x4f688000, 0x7da08808, 0x4f7c8000, 0x7da08808,
+    0x4f7e8000, 0x7da08808, 0x4f870000, 0x7da08808, 0x4f898000, 0x7da08808, 0x4f900000, 0x7da08808,
+    0x4f9d8000, 0x7da08808, 0x4fa50000, 0x7da08808, 0x4fa90000, 0x7da08808, 0x4fc68000, 0x7da08808,
+    0x4fce0000, 0x7da08808, 0x4fd00000, 0x21730808, 0x217e0808, 0xfae0804, 0xdc4, 0xfae8804, 0xdc4,
+    0x7da00808, 0x69a08000, 0x7da00808, 0x69a20000, 0x7da00808, 0x69a28000, 0x217c8808, 0x21738808,
+    0x2170081c, 0xdc4, 0x2170881c, 0xdc4, 0x2171081c, 0xdc4, 0x2171881c, 0xdc4, 0x2172081c, 0xdc4,
+    0x2172881c, 0xdc4, 0x2173081c, 0xdc4, 0x2173881c, 0xdc4, 0x2174081c, 0xdc4, 0x2174881c, 0xdc4,
+    0x2175081c, 0xdc4, 0x2175881c, 0xdc4, 0x2176081c, 0xdc4, 0x2176881c, 0xdc4, 0x2177081c, 0xdc4,
+    0x217a081c, 0xdc4, 0x217a081c, 0xe04, 0x217a881c, 0xdc4, 0x217a881c, 0xe04, 0x217b081c, 0xdc4,
+    0x217b081c, 0xe04, 0x217b881c, 0xdc4, 0x217b881c, 0xe04, 0x217c081c, 0xdc4, 0x217c081c, 0xe04,
+    0x216e081c, 0xdc4, 0xfaf8804, 0xdc4, 0x2180082c, 0x2181082c, 0x21700822, 0xdc4, 0x21708822,
+    0xdc4, 0x21710822, 0xdc4, 0x21718822, 0xdc4, 0x21720822, 0xdc4, 0x21728822, 0xdc4, 0x21730822,
+    0xdc4, 0x21738822, 0xdc4, 0x21740822, 0xdc4, 0x21748822, 0xdc4, 0x21750822, 0xdc4, 0x21758822,
+    0xdc4, 0x21760822, 0xdc4, 0x21768822, 0xdc4, 0x21770822, 0xdc4, 0x217a0822, 0xdc4, 0x217a0822,
+    0xe04, 0x217a8822, 0xdc4, 0x217a8822, 0xe04, 0x217b0822, 0xdc4, 0x217b0822, 0xe04, 0x217b8822,
+    0xdc4, 0x217b8822, 0xe04, 0x217c0822, 0xdc4, 0x217c0822, 0xe04, 0x216e0822, 0xdc4, 0x21830822,
+    0xdc4, 0x21838822, 0xdc4, 0x21840822, 0xdc4, 0x21848822, 0xdc4, 0xfb08804, 0xdc4, 0x2172082c,
+    0x2177082c, 0x7da00828, 0x67000000, 0x7da00828, 0x67460000, 0x7da00828, 0x67048000, 0x7da00828,
+    0x6b6d8000, 0x7da00828, 0x67050000, 0x7da00828, 0x67168000, 0x7da00828, 0x67058000, 0x7da00828,
+    0x7a990000, 0x7da00828, 0x672c8000, 0x7da00828, 0x670c8000, 0x7da00828, 0x67008000, 0x7da00828,
+    0x6c948000, 0x7da00828, 0x6b980000, 0x7da00828, 0x675d0000, 0x22148808, 0x4648, 0x22218808,
+    0x4648, 0x221c8808, 0x4648, 0x221a0808, 0x4648, 0x22270808, 0x4648, 0x22250808, 0x4648,
+    0x222e0808, 0x4688, 0x22248808, 0x4648, 0x222d8808, 0x4648, 0x222e0808, 0x4648, 0x22278808,
+    0x4648, 0x22288808, 0x4648, 0x222d882c, 0x466c, 0x1940809, 0x20ba8808, 0x1948809, 0x1940809,
+    0x20bb8808, 0x1948809, 0x1940809, 0x20bc0808, 0x1948809, 0x1940809, 0x20bd0808, 0x1948809,
+    0x1940809, 0x20bd8808, 0x1948809, 0x1940809, 0x20be0808, 0x1948809, 0x1940809, 0x20bf0808,
+    0x1948809, 0x1940809, 0x20c00808, 0x1948809, 0x1940809, 0x20c08808, 0x1948809, 0x1940809,
+    0x20c18808, 0x1948809, 0x1940809, 0x20c20808, 0x1948809, 0x1940809, 0x20c28808, 0x1948809,
+    0x1940809, 0x20c30808, 0x1948809, 0x1940809, 0x20c38808, 0x1948809, 0x1940809, 0x20ba8808,
+    0x20f98808, 0x1948809, 0x1940809, 0x20bb8808, 0x20f98808, 0x1948809, 0x1940809, 0x20bc0808,
+    0x20f98808, 0x1948809, 0x1940809, 0x20bd0808, 0x20f98808, 0x1948809, 0x1940809, 0x20bd8808,
+    0x20f98808, 0x1948809, 0x1940809, 0x20be0808, 0x20f98808, 0x1948809, 0x1940809, 0x20bf0808,
+    0x20f98808, 0x1948809, 0x1940809, 0x20c00808, 0x20f98808, 0x1948809, 0x1940809, 0x20c08808,
+    0x20f98808, 0x1948809, 0x1940809, 0x20c18808, 0x20f98808, 0x1948809, 0x1940809, 0x20c20808,
+    0x20f98808, 0x1948809, 0x1940809, 0x20c28808, 0x20f98808, 0x1948809, 0x1940809, 0x20c30808,
+    0x20f98808, 0x1948809, 0x1940809, 0x20c38808, 0x20f98808, 0x1948809, 0x1940809, 0x20c08808,
+    0x21000808, 0x1948809, 0x1940809, 0x20c00808, 0x20fd8808, 0x20c08808, 0x20fb8808, 0x212a0808,
+    0x1948809, 0x1940809, 0x20c00808, 0x20fd8808, 0x20c38808, 0x21000808, 0x1948809, 0x1940809,
+    0x7da00808, 0x67000000, 0x1948809, 0x1940809, 0x7da00808, 0x67460000, 0x1948809, 0x1940809,
+    0x7da00808, 0x67048000, 0x1948809, 0x1940809, 0x7da00808, 0x6b6d8000, 0x1948809, 0x1940809,
+    0x7da00808, 0x674a0000, 0x1948809, 0x1940809, 0x7da00808, 0x68b68000, 0x1948809, 0x1940809,
+    0x7da00808, 0x67018000, 0x1948809, 0x1940809, 0x7da00808, 0x68b58000, 0x1948809, 0x1940809,
+    0x7da00808, 0x672e8000, 0x1948809, 0x1940809, 0x7da00808, 0x69a08000, 0x1948809, 0x1940809,
+    0x7da00808, 0x73840000, 0x1948809, 0x1940809, 0x7da00808, 0x78358000, 0x1948809, 0x1940809,
+    0x7da00808, 0x761a0000, 0x1948809, 0x1940809, 0x7da00808, 0x73940000, 0x1948809, 0x1940809,
+    0x7da08808, 0x48e88000, 0x1948809, 0x1940809, 0x7da00808, 0x6b8f8000, 0x1948809, 0x1940809,
+    0x7da00808, 0x72f28000, 0x1948809, 0x1940809, 0x7da00808, 0x74150000, 0x1948809, 0x1940809,
+    0x7da00808, 0x73848000, 0x1948809, 0x1940809, 0x7da00808, 0x7c9f0000, 0x1948809, 0x1940809,
+    0x7da00808, 0x6a068000, 0x1948809, 0x1940809, 0x7da00808, 0x793c8000, 0x1948809, 0x1940809,
+    0x7da08808, 0x46508000, 0x1948809, 0x1940809, 0x7da00808, 0x7cae8000, 0x1948809, 0x1940809,
+    0x7da00808, 0x695a0000, 0x1948809, 0x1940809, 0x7da00808, 0x67718000, 0x1948809, 0x1940809,
+    0x7da00808, 0x6a3e0000, 0x1948809, 0x1940809, 0x7da00808, 0x6db30000, 0x1948809, 0x1940809,
+    0x7da00808, 0x7b718000, 0x1948809, 0x1940809, 0x7da00808, 0x67808000, 0x1948809, 0x1940809,
+    0x7da08808, 0x46638000, 0x1948809, 0x1940809, 0x7da00808, 0x69aa0000, 0x1948809, 0x1940809,
+    0x7da00808, 0x7cb68000, 0x1948809, 0x1940809, 0x7da00808, 0x67888000, 0x1948809, 0x1940809,
+    0x7da08808, 0x40f50000, 0x1948809, 0x1940809, 0x7da08808, 0x40f98000, 0x1948809, 0x7da0080c,
+    0x6aa78000, 0x7da0080c, 0x6f3e0000, 0x7da0080c, 0x72c38000, 0x7da0080c, 0x7dc78000, 0xfcc880c,
+    0xfcc080c, 0xfcd080c, 0xfcc080c, 0xfcd880c, 0xfcc080c, 0xfce080c, 0xfcc080c, 0xfce880c,
+    0xfcc080c, 0xfcf080c, 0xfcc080c, 0xfcf880c, 0xfcc080c, 0xfd0080c, 0xfcc080c, 0x10b5883a,
+    0x10fb883a, 0x1003883a, 0xfcd080c, 0xfcc880c, 0xfcd080c, 0xfcd080c, 0xfcd080c, 0xfcd880c,
+    0xfcd080c, 0xfce080c, 0xfcd080c, 0xfce880c, 0xfcd080c, 0xfcf080c, 0xfcd080c, 0xfcf880c,
+    0xfcd080c, 0xfd0080c, 0xfcd080c, 0xfd0880c, 0xfcd880c, 0xfcc080c, 0xfcd880c, 0xfcc880c,
+    0xfcd880c, 0xfcd080c, 0xfcd880c, 0xfcd880c, 0xfcd880c, 0xfce080c, 0xfcd880c, 0xfce880c,
+    0x20ba880c, 0x20f9880c, 0x20bb880c, 0x20f9880c, 0x20bc080c, 0x20f9880c, 0x20bd080c, 0x20f9880c,
+    0x20bd880c, 0x20f9880c, 0x20be080c, 0x20f9880c, 0x20bf080c, 0x20f9880c, 0x20c0080c, 0x20f9880c,
+    0x20c0880c, 0x20f9880c, 0x20c1880c, 0x20f9880c, 0x20c2080c, 0x20f9880c, 0x20c2880c, 0x20f9880c,
+    0x20c3080c, 0x20f9880c, 0x20c3880c, 0x20f9880c, 0x20c1880c, 0x20f9880c, 0x2130080c, 0x20ba880c,
+    0x20fd880c, 0x20c0880c, 0x2100080c, 0x20c0080c, 0x2103080c, 0x20c0080c, 0x2100080c, 0x7da0080c,
+    0x67000000, 0x7da0080c, 0x67460000, 0x7da0080c, 0x67048000, 0x7da0080c, 0x6b6d8000, 0x7da0080c,
+    0x674a0000, 0x7da0080c, 0x68b68000, 0x7da0080c, 0x67018000, 0x7da0080c, 0x68b58000, 0x7da0080c,
+    0x672e8000, 0x7da0080c, 0x69a08000, 0x7da0080c, 0x73840000, 0x7da0080c, 0x78358000, 0x7da0080c,
+    0x761a0000, 0x7da0080c, 0x73940000, 0x7da0880c, 0x48e88000, 0x7da0080c, 0x6b8f8000, 0x7da0080c,
+    0x72f28000, 0x7da0080c, 0x74150000, 0x7da0080c, 0x73848000, 0x7da0080c, 0x7c9f0000, 0x7da0080c,
+    0x6a068000, 0x7da0080c, 0x793c8000, 0x7da0880c, 0x46508000, 0x7da0080c, 0x7cae8000, 0x7da0080c,
+    0x695a0000, 0x7da0080c, 0x7cec0000, 0x7da0080c, 0x7a9b8000, 0x7da0080c, 0x6cb98000, 0x7da0880c,
+    0x48348000, 0x7da0080c, 0x68950000, 0x7da0080c, 0x69b80000, 0x7da0080c, 0x76740000, 0x7da0880c,
+    0x4c028000, 0x7da0080c, 0x67888000, 0x7da0080c, 0x68cc8000, 0x7da0080c, 0x75b18000, 0x7da0080c,
+    0x67050000, 0x7da0080c, 0x67168000, 0x7da0080c, 0x67058000, 0x7da0080c, 0x6ef30000, 0x7da0080c,
+    0x69f98000, 0x7da0080c, 0x699d8000, 0x7da0080c, 0x6dcb8000, 0x7da0080c, 0x6db30000, 0x7da0080c,
+    0x7b718000, 0x7da0080c, 0x67808000, 0x7da0880c, 0x46638000, 0x7da0080c, 0x69aa0000, 0x7da0080c,
+    0x6c8e0000, 0xfcd880c, 0xfcf080c, 0xfcd880c, 0xfcf880c, 0xfcd880c, 0xfd0080c, 0xfcd880c,
+    0xfd0880c, 0xfce080c, 0xfcc080c, 0xfce080c, 0xfcc880c, 0xfce080c, 0xfcd080c, 0xfce080c,
+    0xfcd880c, 0xfce080c, 0xfce080c, 0xfce080c, 0xfce880c, 0xfce080c, 0xfcf080c, 0xfce080c,
+    0xfcf880c, 0xfce080c, 0xfd0080c, 0xfce080c, 0xfd0880c, 0xfce880c, 0xfcc080c, 0xfcc8808,
+    0x7da00808, 0x73840000, 0xfcd0808, 0x7da00808, 0x73840000, 0xfcd8808, 0x7da00808, 0x73840000,
+    0xfce0808, 0x7da00808, 0x73840000, 0xfce8808, 0x7da00808, 0x73840000, 0xfcf0808, 0x7da00808,
+    0x73840000, 0xfcf8808, 0x7da00808, 0x73840000, 0xfd00808, 0x7da00808, 0x73840000, 0xfd08808,
+    0x7da00808, 0x73840000, 0xfcc8808, 0xfcc0808, 0x7da00808, 0x73840000, 0xfcc8808, 0xfcc8808,
+    0x7da00808, 0x73840000, 0xfcc8808, 0xfcd0808, 0x7da00808, 0x73840000, 0x103a883a, 0x10288838,
+    0x10038838, 0x10c98838, 0x10288838, 0x10038838, 0x1123883a, 0x106b083a, 0x10fb883a, 0xff5883a,
+    0x7da00838, 0x67720000, 0x7da00838, 0x6a460000, 0x216d0838, 0x217a0838, 0xe38, 0xfb00838,
+    0x21770838, 0x216d0838, 0x21818838, 0x217b0838, 0x216d0838, 0x216d0838, 0x21850838, 0x217b8838,
+    0xe38, 0x216d0838, 0x216d0838, 0xfb00838, 0x21818838, 0x216d8838, 0x21780838, 0x21850838,
+    0x21710838, 0xdf8, 0x216d8838, 0x21850838, 0x21758838, 0x216e0838, 0x216f8838, 0x21850838,
+    0x216f0838, 0x21738838, 0x21710838, 0xfb00838, 0x21770838, 0xdf8, 0x216f0838, 0xfb00838,
+    0x21700838, 0xfb00838, 0x216f8838, 0x21850838, 0x21738838, 0x216f8838, 0xfb00838, 0x217d8838,
+    0x21700838, 0x216d8838, 0x21810838, 0x21700838, 0x21808838, 0x21760838, 0x21770838, 0x21700838,
+    0x21828838, 0x21810838, 0xfb00838, 0x21700838, 0xdf8, 0x21828838, 0x21850838, 0x21700838,
+    0xdf8, 0x21850838, 0x217c8838, 0x21708838, 0xdf8, 0x21700838, 0xdf8, 0x21708838, 0xdf8,
+    0x21780838, 0xfb00838, 0x21708838, 0x217f8838, 0x21810838, 0xfb00838, 0x21708838, 0xdf8,
+    0x21818838, 0x21750838, 0xdf8, 0xfb00838, 0x21708838, 0x21828838, 0x21708838, 0x21828838,
+    0x21710838, 0xdf8, 0x21808838, 0x217d8838, 0x21708838, 0x21828838, 0x217e0838, 0xfb00838,
+    0x21770838, 0x21818838, 0x21708838, 0x21828838, 0x21830838, 0x21760838, 0x21770838, 0x21710838,
+    0xdf8, 0x21808838, 0x217d8838, 0x21710838, 0xdf8, 0x21808838, 0x217d8838, 0x21770838,
+    0x21850838, 0x21710838, 0x21818838, 0x21740838, 0xdf8, 0x216d8838, 0x21828838, 0x21710838,
+    0x21828838, 0xfb00838, 0x21790838, 0x21718838, 0xfb00838, 0x21738838, 0x21720838, 0x21818838,
+    0x21778838, 0x21720838, 0xfb00838, 0x217c0838, 0xe38, 0x21728838, 0x216d8838, 0x21710838,
+    0x21818838, 0x21728838, 0x21850838, 0x21758838, 0xfb00838, 0x217d8838, 0x21730838, 0x21810838,
+    0x21850838, 0x21710838, 0xdf8, 0x21740838, 0x21850838, 0x21758838, 0x21740838, 0x21850838,
+    0x21770838, 0x21750838, 0xdf8, 0xfb00838, 0x21738838, 0x21768838, 0xdf8, 0x21730838,
+    0x21770838, 0xdf8, 0x21818838, 0x21770838, 0x21850838, 0x21778838, 0x21798838, 0x21798838,
+    0x21760838, 0x21770838, 0x217a0838, 0x216d8838, 0x21760838, 0x217a0838, 0xe38, 0xfb00838,
+    0x21740838, 0x21850838, 0x21770838, 0x217a0838, 0xe38, 0xfb00838, 0x21760838, 0x217a0838,
+    0xdf8, 0xfb00838, 0x21820838, 0x21818838, 0x217a8838, 0xe38, 0x216d0838, 0x21738838,
+    0x21770838, 0x21818838, 0x217a8838, 0xe38, 0x21710838, 0x21818838, 0x217a8838, 0xe38,
+    0x21720838, 0x217a8838, 0xdf8, 0x21818838, 0x217b0838, 0x216d0838, 0x21808838, 0x21760838,
+    0x21770838, 0xdf8, 0x217b0838, 0x216d8838, 0xfb00838, 0x21770838, 0x217b0838, 0xdf8,
+    0x21760838, 0x21730838, 0x216f0838, 0x21818838, 0x217b0838, 0x21808838, 0x21850838, 0x217b8838,
+    0x21710838, 0x21750838, 0xfb00838, 0x21818838, 0x217b8838, 0xe38, 0x21748838, 0x217b8838,
+    0xe38, 0x21780838, 0x217a8838, 0x217b8838, 0x21818838, 0x21760838, 0x217b8838, 0xe38,
+    0x21850838, 0x21738838, 0x217b8838, 0xe38, 0xfb00838, 0x21730838, 0xdf8, 0x217b8838, 0xdf8,
+    0xfb00838, 0x21750838, 0x217c0838, 0xe38, 0x216d8838, 0x21850838, 0x21770838, 0x217c0838,
+    0xdf8, 0x21818838, 0x21770838, 0x217c0838, 0x21850838, 0x217c0838, 0xe38, 0x21850838,
+    0x21770838, 0xdf8, 0x217c0838, 0xfb00838, 0x21818838, 0x217c0838, 0xfb00838, 0x21850838,
+    0x217c8838, 0x216d8838, 0x21710838, 0x21828838, 0x217c8838, 0x216d8838, 0x21818838, 0x217c8838,
+    0x21760838, 0x217a0838, 0x217c8838, 0x21818838, 0x21710838, 0x217c8838, 0x21850838, 0x21730838,
+    0x21800838, 0x21850838, 0x217d0838, 0x21710838, 0x21828838, 0x21850838, 0x217d0838, 0x21810838,
+    0x217d0838, 0x21810838, 0x217a0838, 0xdf8, 0xfb00838, 0x21818838, 0x217e0838, 0x21700838,
+    0xdf8, 0x217e0838, 0x21700838, 0xdf8, 0x21770838, 0x21850838, 0x217e0838, 0xfb00838,
+    0x21770838, 0x21818838, 0x217f0838, 0xfb00838, 0x21770838, 0xdf8, 0x217f0838, 0xfb00838,
+    0x21818838, 0x217f8838, 0x216d0838, 0x21850838, 0x21810838, 0x21760838, 0x21770838, 0x21818838,
+    0x21810838, 0x21808838, 0x21818838, 0x217a8838, 0xe38, 0xfb00838, 0x21818838, 0xfb00838,
+    0x217b0838, 0xdf8, 0x21818838, 0x21820838, 0x217d8838, 0x21820838, 0x21850838, 0x21770838,
+    0x21718838, 0xdf8, 0x21850838, 0x21830838, 0x21760838, 0x21770838, 0xfcc0808, 0x7da00808,
+    0x785c8000, 0xfcc8808, 0x7da00808, 0x785c8000, 0xfcd0808, 0x7da00808, 0x785c8000, 0xfcd8808,
+    0x7da00808, 0x785c8000, 0xfce0808, 0x7da00808, 0x785c8000, 0xfce8808, 0x7da00808, 0x785c8000,
+    0xfcf0808, 0x7da00808, 0x785c8000, 0xfcf8808, 0x7da00808, 0x785c8000, 0xfd00808, 0x7da00808,
+    0x785c8000, 0xfd08808, 0x7da00808, 0x785c8000, 0xfcc8808, 0xfcc0808, 0x7da00808, 0x785c8000,
+    0xfcc8808, 0xfcc8808, 0x7da00808, 0x785c8000, 0xfcc8808, 0xfcd0808, 0x7da00808, 0x785c8000,
+    0xfcc8808, 0xfcd8808, 0x7da00808, 0x785c8000, 0xfcc8808, 0xfce0808, 0x7da00808, 0x785c8000,
+    0xfcc8808, 0xfce8808, 0x7da00808, 0x785c8000, 0xfcc8808, 0xfcf0808, 0x7da00808, 0x785c8000,
+    0xfcc8808, 0xfcf8808, 0x7da00808, 0x785c8000, 0xfcc8808, 0xfd00808, 0x7da00808, 0x785c8000,
+    0xfcc8808, 0xfd08808, 0x7da00808, 0x785c8000, 0xfcd0808, 0xfcc0808, 0x7da00808, 0x785c8000,
+    0xfcd0808, 0xfcc8808, 0x7da00808, 0x785c8000, 0xfcd0808, 0xfcd0808, 0x7da00808, 0x785c8000,
+    0xfcd0808, 0xfcd8808, 0x7da00808, 0x785c8000, 0xfcd0808, 0xfce0808, 0x7da00808, 0x785c8000,
+    0x103a8838, 0x10b5883a, 0xfd10838, 0xff58838, 0xfd10838, 0xfd1083a, 0x110b883a, 0xfde0838,
+    0xfd10838, 0x10c98838, 0x109e0838, 0x1123883a, 0x10b58838, 0xfeb0838, 0xff58838, 0x10848838,
+    0xff58838, 0x10848838, 0xfcd0838, 0xff58838, 0x10848838, 0xfcd8838, 0x1048083a, 0x110b883a,
+    0x7da00838, 0x6f398000, 0x7da00838, 0x71080000, 0x7da00838, 0x73168000, 0x7da00838, 0x6a460000,
+    0x7da00838, 0x6c938000, 0x7da00838, 0x75b18000, 0x7da00838, 0x73070000, 0x7da00838, 0x765d8000,
+    0x7da00838, 0x74150000, 0x7da00838, 0x6f878000, 0x7da00838, 0x678d0000, 0x7da00838, 0x7c9f0000,
+    0x10b58838, 0xfd1083a, 0x108c0838, 0xfd1083a, 0x11980838, 0xfd1083a, 0x10848838, 0xfd1083a,
+    0x10620838, 0xfd1083a, 0x1062083a, 0xfde083a, 0x1084883a, 0xfde083a, 0x1028883a, 0xfde083a,
+    0xfeb0838, 0xfd10838, 0x106b0838, 0x10620838, 0xfeb0838, 0xfd10838, 0x106b0838, 0x10b58838,
+    0x1021083a, 0x108c0838, 0x1021083a, 0x11980838, 0x1021083a, 0x11980838, 0x10288838, 0x10848838,
+    0x10288838, 0x10620838, 0x10288838, 0x103a883a, 0x11430838, 0x10620838, 0x103a883a, 0x11430838,
+    0x1084883a, 0x103a883a, 0x11430838, 0x1028883a, 0x103a883a, 0x11430838, 0x10fb883a, 0x103a883a,
+    0x11430838, 0x11980838, 0x106b0838, 0x10848838, 0x106b0838, 0xff58838, 0x106b0838, 0x10620838,
+    0x106b0838, 0x10210838, 0x10848838, 0x108c0838, 0x10848838, 0x11980838, 0x10848838, 0x10848838,
+    0x10848838, 0xfeb0838, 0x10848838, 0x10620838, 0x10848838, 0x10848838, 0x10848838, 0xfcd0838,
+    0xfeb0838, 0x10848838, 0xfcd0838, 0x10848838, 0xfcd0838, 0x10620838, 0x10848838, 0xfcd0838,
+    0x10848838, 0x10848838, 0xfcd8838, 0xfeb0838, 0x10848838, 0xfcd8838, 0x10848838, 0xfcd8838,
+    0x10620838, 0x10848838, 0xfcd8838, 0x10848838, 0x33a8839, 0x10e90838, 0x10848838, 0x33a8839,
+    0x10e90838, 0xfcd0838, 0x10b5883a, 0xfd10838, 0x10620838, 0x10b5883a, 0xfd10838, 0x1084883a,
+    0x10b5883a, 0xfd10838, 0x1028883a, 0x10b5883a, 0xfd10838, 0x10c98838, 0xfd10838, 0xff58838,
+    0x10c98838, 0xfd10838, 0xff58838, 0x33a8839, 0x10e90838, 0x10c98838, 0xfd10838, 0xff58838,
+    0x33a8839, 0x10e90838, 0xfcd0838, 0x10b58838, 0x10e90838, 0x108c0838, 0x10e90838, 0x11980838,
+    0x10e90838, 0x10848838, 0x10e90838, 0x10b58838, 0x1123883a, 0x108c0838, 0x1123883a, 0x11980838,
+    0x1123883a, 0x10848838, 0x1123883a, 0x10620838, 0x1123883a, 0x1084883a, 0x1123883a, 0x10b58838,
+    0x112c883a, 0x108c0838, 0x112c883a, 0x11980838, 0x112c883a, 0x10848838, 0x112c883a, 0x10620838,
+    0x112c883a, 0x1084883a, 0x112c883a, 0x10620838, 0x11a3083a, 0x1084883a, 0x11a3083a, 0xfd10838,
+    0x13f0839, 0x10848838, 0x13f0839, 0xfde083a, 0x10c00838, 0xfeb0838, 0xfeb0838, 0xfeb0838,
+    0xff58838, 0xfeb083a, 0x33a8839, 0x10620838, 0x10288838, 0xfeb083a, 0x109e0838, 0x13f0839,
+    0xff58838, 0xfde083a, 0x1028883a, 0x11380838, 0x103a8838, 0xfd10838, 0x103a883a, 0x10b5883a,
+    0x10480838, 0x108c0838, 0x1062083a, 0x1062083a, 0x1062083a, 0x1084883a, 0x10620838, 0x10fb8838,
+    0x106b0838, 0x10848838, 0x106b0838, 0x108c0838, 0x106b0838, 0x109e0838, 0x10288838, 0x106b0838,
+    0x11320838, 0x10848838, 0xfde0838, 0x10848838, 0x10480838, 0x106b0838, 0x10848838, 0x109e0838,
+    0x106b0838, 0x10b5883a, 0x103a883a, 0x10b58838, 0x13f0839, 0x10848838, 0x13f0839, 0x10b5883a,
+    0x10b5883a, 0x1084883a, 0x10b5883a, 0x10c9883a, 0x10e90838, 0x10c98838, 0x10e9083a, 0x11238838,
+    0x112c883a, 0xfde0838, 0x1123883a, 0x33a8839, 0x10848838, 0xfd1083a, 0x33a8839, 0x10848838,
+    0xfcc8808, 0x7da00808, 0x72f28000, 0xfcd0808, 0x7da00808, 0x72f28000, 0xfcd8808, 0x7da00808,
+    0x72f28000, 0xfce0808, 0x7da00808, 0x72f28000, 0xfce8808, 0x7da00808, 0x72f28000, 0xfcf0808,
+    0x7da00808, 0x72f28000, 0xfcf8808, 0x7da00808, 0x72f28000, 0xfd00808, 0x7da00808, 0x72f28000,
+    0xfd08808, 0x7da00808, 0x72f28000, 0xfcc8808, 0xfcc0808, 0x7da00808, 0x72f28000, 0xfcc8808,
+    0xfcc8808, 0x7da00808, 0x72f28000, 0xfcc8808, 0xfcd0808, 0x7da00808, 0x72f28000, 0xfcc8808,
+    0xfcd8808, 0x7da00808, 0x72f28000, 0xfcc8808, 0xfce0808, 0x7da00808, 0x72f28000, 0xfcc8808,
+    0xfce8808, 0x7da00808, 0x72f28000, 0xfcc8808, 0xfcf0808, 0x7da00808, 0x72f28000, 0xfcc8808,
+    0xfcf8808, 0x7da00808, 0x72f28000, 0xfcc8808, 0xfd00808, 0x7da00808, 0x72f28000, 0xfcc8808,
+    0xfd08808, 0x7da00808, 0x72f28000, 0xfcd0808, 0xfcc0808, 0x7da00808, 0x72f28000, 0xfcd0808,
+    0xfcc8808, 0x7da00808, 0x72f28000, 0xfcd0808, 0xfcd0808, 0x7da00808, 0x72f28000, 0xfcd0808,
+    0xfcd8808, 0x7da00808, 0x72f28000, 0xfcd0808, 0xfce0808, 0x7da00808, 0x72f28000, 0xfcd0808,
+    0xfce8808, 0x7da00808, 0x72f28000, 0xfcd0808, 0xfcf0808, 0x7da00808, 0x72f28000, 0xfcd0808,
+    0xfcf8808, 0x7da00808, 0x72f28000, 0xfcd0808, 0xfd00808, 0x7da00808, 0x72f28000, 0xfcd0808,
+    0xfd08808, 0x7da00808, 0x72f28000, 0xfcd8808, 0xfcc0808, 0x7da00808, 0x72f28000, 0xfcd8808,
+    0xfcc8808, 0x7da00808, 0x72f28000, 0x10288838, 0xfd10838, 0x106b0838, 0x1e628808, 0x1ee20808,
+    0x1e6b8808, 0x1ee20808, 0x1e760808, 0x1ee20808, 0x1e800808, 0x1ee28808, 0x1e858808, 0x1ee20808,
+    0x1e888808, 0x1ee28808, 0x1e898808, 0x1ee28808, 0x1e8c8808, 0x1ee20808, 0x1e9f0808, 0x1ee20808,
+    0x1eb68808, 0x1ee20808, 0x1ec20808, 0x1ee20808, 0x1ec50808, 0x1ee28808, 0x1ec88808, 0x1ee20808,
+    0x11f68808, 0xac8, 0x10fb8814, 0x11430808, 0x10fb8808, 0x11430808, 0xfd10814, 0xfd10814,
+    0xfd10808, 0xfd10808, 0xfd10814, 0x109e0814, 0xfd10808, 0x109e0808, 0xfd10814, 0x110b8814,
+    0xfd10808, 0x110b8808, 0xfd10814, 0x11238814, 0xfd10808, 0x11238808, 0xfd10814, 0x4608,
+    0x11238814, 0xfd10808, 0x4608, 0x11238808, 0xfd10814, 0x11380814, 0xfd10808, 0x11380808,
+    0x109e0814, 0x109e0814, 0x109e0808, 0x109e0808, 0x11238814, 0x11380814, 0x11238808, 0x11380808,
+    0xff58814, 0x4648, 0xff58808, 0x4648, 0x10210814, 0x4648, 0x10210808, 0x4648, 0x10288814,
+    0x4648, 0x10c98814, 0x4648, 0x10c98808, 0x4648, 0x10e90814, 0x4648, 0x10e90808, 0x4648,
+    0x10fb8814, 0x4648, 0x10fb8808, 0x4648, 0xfd10814, 0xac8, 0xfd10808, 0xac8, 0x109e0814, 0xac8,
+    0x109e0808, 0xac8, 0x110b8814, 0xac8, 0x110b8808, 0xac8, 0x10288814, 0xd48, 0x10288808, 0xd48,
+    0x10620814, 0xd48, 0x10620808, 0xd48, 0x108c0814, 0xd48, 0x108c0808, 0xd48, 0x10c98814, 0xd48,
+    0x10c98808, 0xd48, 0x10e90814, 0xd48, 0x10e90808, 0xd48, 0x103a8828, 0xe68, 0x109e0828, 0x4628,
+    0x10038828, 0xff58808, 0x114a8808, 0x10fb8808, 0x10ed0808, 0x7da08804, 0x46240000, 0x7da00804,
+    0x737a0000, 0x7da08804, 0x47650000, 0x7da08804, 0x46640000, 0x7da00804, 0x77688000, 0x7da00804,
+    0x67190000, 0x7da00804, 0x69f28000, 0x7da08804, 0x4fce0000, 0x7da08804, 0x4fce0000, 0x7da00804,
+    0x6ca88000, 0x7da08804, 0x48e88000, 0x7da00804, 0x6ac38000, 0x7da00804, 0x6ca40000, 0x7da00804,
+    0x70fb0000, 0x7da00804, 0x7b348000, 0x7da00804, 0x7fc28000, 0x7da08804, 0x431f8000, 0x7da08804,
+    0x43dd0000, 0x7da08804, 0x447c0000, 0x7da08804, 0x48478000, 0x7da00804, 0x75010000, 0x7da00804,
+    0x768d8000, 0x7da00804, 0x786c8000, 0x7da00804, 0x79ef0000, 0x7da08804, 0x421e8000, 0x7da08804,
+    0x48b50000, 0x7da08804, 0x4cf88000, 0x7da00804, 0x67410000, 0x7da00804, 0x69ba8000, 0x7da00804,
+    0x75820000, 0x7da00804, 0x790d8000, 0x7da08804, 0x43168000, 0x7da08804, 0x4f0f0000, 0x7da00804,
+    0x6ea80000, 0x7da00804, 0x77f58000, 0x7da08804, 0x42e68000, 0x7da08804, 0x44b20000, 0x7da00804,
+    0x71648000, 0x7da08804, 0x40ec0000, 0x7da08804, 0x440f8000, 0x7da00804, 0x6f650000, 0x7da00804,
+    0x738b8000, 0x7da00804, 0x76b50000, 0x7da00804, 0x797e0000, 0x7da08804, 0x48670000, 0x7da00804,
+    0x67c30000, 0x7da00804, 0x68db8000, 0x7da00804, 0x696f0000, 0x7da00804, 0x72620000, 0x7da00804,
+    0x75698000, 0x7da00804, 0x79080000, 0x7da00804, 0x7b738000, 0x7da08804, 0x40008000, 0x7da08804,
+    0x43030000, 0x7da08804, 0x432e0000, 0x7da08804, 0x46f78000, 0x7da08804, 0x4b990000, 0x7da08804,
+    0x4db78000, 0x7da08804, 0x4efd0000, 0x7da00804, 0x7c460000, 0x7da00804, 0x7cbf8000, 0x7da00804,
+    0x7ed00000, 0x7da08804, 0x41e48000, 0x7da08804, 0x49820000, 0x7da08804, 0x4f3f8000, 0x7da08804,
+    0x456b0000, 0x7da00804, 0x6c6f8000, 0x7da00804, 0x6f820000, 0x7da00804, 0x7e300000, 0x7da08804,
+    0x403f0000, 0x7da00804, 0x79310000, 0x7da00804, 0x7c650000, 0x7da08804, 0x46610000, 0x7da08804,
+    0x4b7b8000, 0x7da00804, 0x6c6c0000, 0x7da00804, 0x6e310000, 0x7da00804, 0x75098000, 0x7da00804,
+    0x76ed0000, 0x7da00804, 0x77878000, 0x7da00804, 0x7e978000, 0x7da00804, 0x7f1b8000, 0x7da08804,
+    0x4b258000, 0x7da00804, 0x69690000, 0x7da08804, 0x40458000, 0x7da00804, 0x68ee0000, 0x7da00804,
+    0x68e60000, 0x7da00804, 0x7d0e0000, 0x7da00804, 0x7edf0000, 0x7da08804, 0x41f88000, 0x7da08804,
+    0x4b3a8000, 0x7da08804, 0x45c00000, 0x7da00804, 0x71678000, 0x7da00804, 0x75010000, 0x7da08804,
+    0x457f0000, 0x7da00804, 0x671c8000, 0x7da00804, 0x6df38000, 0x7da00804, 0x70090000, 0x7da00804,
+    0x79c38000, 0x7da00804, 0x7ab80000, 0x7da00804, 0x698b8000, 0x7da00804, 0x7c7d8000, 0x7da00804,
+    0x67df8000, 0x7da00804, 0x6fd48000, 0x7da00804, 0x67068000, 0x7da00804, 0x76660000, 0x7da00804,
+    0x72bc0000, 0x7da00804, 0x7e910000, 0x7da00804, 0x69e18000, 0x7da00804, 0x6c2f0000, 0x7da00804,
+    0x7b808000, 0x7da08804, 0x42248000, 0x7da08804, 0x45550000, 0x7da00804, 0x75dd0000, 0x7da08804,
+    0x47d80000, 0x7da00804, 0x76440000, 0x7da00804, 0x717f0000, 0x7da08804, 0x41728000, 0x7da00804,
+    0x71d00000, 0x7da00804, 0x7ab28000, 0x7da00804, 0x67570000, 0x7da00804, 0x68b48000, 0x7da00804,
+    0x68e48000, 0x7da00804, 0x74408000, 0x7da00804, 0x7e738000, 0x7da08804, 0x41378000, 0x7da08804,
+    0x45690000, 0x7da08804, 0x48e78000, 0x7da00804, 0x697a8000, 0x7da00804, 0x6a210000, 0x7da00804,
+    0x6cb98000, 0x7da00804, 0x6f760000, 0x7da00804, 0x72e28000, 0x7da00804, 0x77ff0000, 0x7da00804,
+    0x7c950000, 0x7da08804, 0x4ad68000, 0x7da08804, 0x4d350000, 0x7da08804, 0x4f4b8000, 0x7da08804,
+    0x4f670000, 0x7da00804, 0x694d8000, 0x7da00804, 0x73630000, 0x7da00804, 0x75bb8000, 0x7da08804,
+    0x47b10000, 0x7da00804, 0x6f3a0000, 0x7da00804, 0x70c80000, 0x7da00804, 0x71000000, 0x7da00804,
+    0x724d0000, 0x7da00804, 0x77918000, 0x7da00804, 0x78a48000, 0x7da00804, 0x7a448000, 0x7da00804,
+    0x7ce50000, 0x7da00804, 0x7efa0000, 0x7da08804, 0x40378000, 0x7da08804, 0x47930000, 0x7da08804,
+    0x42770000, 0x7da08804, 0x48118000, 0x7da08804, 0x49a50000, 0x7da00804, 0x690b8000, 0x7da00804,
+    0x69518000, 0x7da00804, 0x6a5e8000, 0x7da00804, 0x78640000, 0x7da08804, 0x44610000, 0x7da08804,
+    0x45550000, 0x7da00804, 0x6f648000, 0x7da00804, 0x6ffa8000, 0x7da00804, 0x71bd8000, 0x7da00804,
+    0x75d70000, 0x7da00804, 0x7e1f0000, 0x7da00804, 0x79ba8000, 0x7da00804, 0x67720000, 0x7da00804,
+    0x6b7c8000, 0x7da00804, 0x6df38000, 0x7da00804, 0x6edd0000, 0x7da00804, 0x700e0000, 0x7da00804,
+    0x79d90000, 0x7da00804, 0x7a348000, 0x7da00804, 0x7fcd0000, 0x7da08804, 0x40230000, 0x7da08804,
+    0x491a0000, 0x7da08804, 0x4b7b0000, 0x7da08804, 0x4ba40000, 0x7da08804, 0x4c0c0000, 0x7da00804,
+    0x67c58000, 0x7da00804, 0x7cd70000, 0x7da08804, 0x48da0000, 0x7da08804, 0x4b5c0000, 0x7da00804,
+    0x70708000, 0x7da00804, 0x67430000, 0x7da00804, 0x686d0000, 0x7da00804, 0x6df70000, 0x7da00804,
+    0x6e1f8000, 0x7da00804, 0x72cc8000, 0x7da00804, 0x75010000, 0x7da00804, 0x78e70000, 0x7da00804,
+    0x7b210000, 0x7da08804, 0x427e0000, 0x7da08804, 0x483e0000, 0x7da08804, 0x4fc68000, 0x7da00804,
+    0x73440000, 0x7da08804, 0x4b170000, 0x7da00804, 0x69448000, 0x7da00804, 0x73bd8000, 0x7da00804,
+    0x73f98000, 0x7da00804, 0x76a08000, 0x7da00804, 0x774e0000, 0x7da00804, 0x7a048000, 0x7da00804,
+    0x7aac8000, 0x7da00804, 0x7c358000, 0x7da00804, 0x7e880000, 0x7da08804, 0x4c2f0000, 0x7da00804,
+    0x68b68000, 0x7da00804, 0x71170000, 0x7da08804, 0x4b3c0000, 0x7da00804, 0x68158000, 0x7da00804,
+    0x6e8c8000, 0x7da00804, 0x76f50000, 0x7da08804, 0x47950000, 0x7da00804, 0x6fc58000, 0x7da00804,
+    0x70a20000, 0x7da00804, 0x740b8000, 0x7da00804, 0x79c38000, 0x7da08804, 0x4b430000, 0x7da00804,
+    0x69148000, 0x7da00804, 0x6a078000, 0x7da00804, 0x6e328000, 0x7da00804, 0x73098000, 0x7da00804,
+    0x73a70000, 0x7da00804, 0x74540000, 0x7da00804, 0x76728000, 0x7da00804, 0x7a030000, 0x7da00804,
+    0x7af10000, 0x7da00804, 0x7fbc8000, 0x7da08804, 0x44678000, 0x7da08804, 0x44708000, 0x7da08804,
+    0x48e60000, 0x7da08804, 0x4b710000, 0x7da00804, 0x699f8000, 0x7da00804, 0x775d0000, 0x7da00804,
+    0x6a0e8000, 0x7da00804, 0x78e80000, 0x7da00804, 0x7a4c0000, 0x7da08804, 0x42fd0000, 0x7da08804,
+    0x4b518000, 0x7da08804, 0x4e2b8000, 0x7da08804, 0x4f4f8000, 0x7da00804, 0x73cb8000, 0x7da00804,
+    0x76e58000, 0x7da08804, 0x40f40000, 0x7da00804, 0x7d658000, 0x7da00804, 0x7d900000, 0x7da00804,
+    0x7e490000, 0x7da00804, 0x79600000, 0x7da00804, 0x784c8000, 0x7da08804, 0x45ac0000, 0x7da00804,
+    0x67600000, 0x7da08804, 0x419b0000, 0x7da00804, 0x691d0000, 0x7da00804, 0x69038000, 0x7da00804,
+    0x6f530000, 0x7da00804, 0x71698000, 0x7da00804, 0x7e6b0000, 0x7da00804, 0x6dc28000, 0x7da00804,
+    0x768f0000, 0x7da00804, 0x735a0000, 0x7da08804, 0x479d8000, 0x7da08804, 0x44260000, 0x7da08804,
+    0x4b268000, 0x7da08804, 0x44c58000, 0x7da00804, 0x6f698000, 0x7da00804, 0x68a00000, 0x7da00804,
+    0x6ae00000, 0x7da08804, 0x7d070000, 0x7da08804, 0x7d078000, 0x7da00804, 0x6c2d0000, 0x7da08804,
+    0x7d088000, 0x7da00804, 0x733a0000, 0x7da08804, 0x7d098000, 0x7da08804, 0x7d0a0000, 0x7da00804,
+    0x68ef0000, 0x7da00804, 0x79950000, 0x7da00804, 0x7b650000, 0x7da00804, 0x7c9e0000, 0x7da00804,
+    0x7caf0000, 0x7da00804, 0x7cb28000, 0x7da00804, 0x7cc78000, 0x7da08804, 0x4bab0000, 0x7da00804,
+    0x7e5f0000, 0x7da00804, 0x7fde8000, 0x7da08804, 0x7d0f8000, 0x7da08804, 0x43090000, 0x7da08804,
+    0x7d108000, 0x7da08804, 0x457c0000, 0x7da08804, 0x7d118000, 0x7da08804, 0x7d120000, 0x7da08804,
+    0x481c0000, 0x7da08804, 0x487e8000, 0x7da08804, 0x7d138000, 0x7da08804, 0x7d140000, 0x7da08804,
+    0x7d148000, 0x7da08804, 0x4c778000, 0x7da08804, 0x4c7e0000, 0x7da08804, 0x4c940000, 0x7da08804,
+    0x4eda0000, 0x7da08804, 0x486f0000, 0x7da08804, 0x4b5b8000, 0x7da00804, 0x67d70000, 0x7da00804,
+    0x68738000, 0x7da00804, 0x68a68000, 0x7da00804, 0x69648000, 0x7da00804, 0x69720000, 0x7da00804,
+    0x69a88000, 0x7da00804, 0x6ace8000, 0x7da00804, 0x6b030000, 0x7da00804, 0x6b340000, 0x7da00804,
+    0x6c200000, 0x7da00804, 0x6c540000, 0x7da00804, 0x6e320000, 0x7da00804, 0x6e370000, 0x7da00804,
+    0x704a0000, 0x7da00804, 0x70b40000, 0x7da00804, 0x70c70000, 0x7da00804, 0x70f90000, 0x7da00804,
+    0x72a78000, 0x7da00804, 0x72f10000, 0x7da00804, 0x73488000, 0x7da00804, 0x74428000, 0x7da00804,
+    0x76bb8000, 0x7da00804, 0x770d0000, 0x7da00804, 0x77910000, 0x7da00804, 0x78b70000, 0x7da00804,
+    0x79158000, 0x7da00804, 0x7a110000, 0x7da00804, 0x7c488000, 0x7da00804, 0x7c9f0000, 0x7da00804,
+    0x7ca48000, 0x7da00804, 0x7ca40000, 0x7da00804, 0x7ca80000, 0x7da00804, 0x7cab0000, 0x7da00804,
+    0x7cae8000, 0x7da00804, 0x7cc68000, 0x7da00804, 0x7cc70000, 0x7da00804, 0x7d200000, 0x7da00804,
+    0x7d408000, 0x7da00804, 0x7de00000, 0x7da00804, 0x7efa0000, 0x7da00804, 0x7f048000, 0x7da00804,
+    0x7f208000, 0x7da00804, 0x7fb90000, 0x7da08804, 0x40028000, 0x7da08804, 0x40f68000, 0x7da08804,
+    0x413c8000, 0x7da08804, 0x413c8000, 0x7da08804, 0x422b8000, 0x7da08804, 0x44880000, 0x7da08804,
+    0x44cb0000, 0x7da08804, 0x45808000, 0x7da08804, 0x459c8000, 0x7da08804, 0x46698000, 0x7da08804,
+    0x46840000, 0x7da08804, 0x47db0000, 0x7da08804, 0x481c0000, 0x7da08804, 0x4b718000, 0x7da08804,
+    0x4bff8000, 0x7da08804, 0x4c1d8000, 0x7da00804, 0x703a8000, 0x7dc20804, 0x61770000, 0x7da08804,
+    0x410c0000, 0x7da00804, 0x67130000, 0x7da00804, 0x68da8000, 0x7da00804, 0x68b40000, 0x7da00804,
+    0x67c00000, 0x7da00804, 0x68a28000, 0x7da00804, 0x68c00000, 0x7da00804, 0x69638000, 0x7da00804,
+    0x697d0000, 0x7da00804, 0x6ace8000, 0x7da00804, 0x6aaa8000, 0x7da00804, 0x6acc8000, 0x7da00804,
+    0x6af10000, 0x7da00804, 0x6c2d0000, 0x7da00804, 0x6c598000, 0x7da00804, 0x6ca20000, 0x7da00804,
+    0x6caa0000, 0x7da00804, 0x6d310000, 0x7da00804, 0x6d940000, 0x7da00804, 0x6f690000, 0x7da00804,
+    0x6f6c8000, 0x7da00804, 0x6fb48000, 0x7da00804, 0x6fd68000, 0x7da00804, 0x706c0000, 0x7da00804,
+    0x70a70000, 0x7da00804, 0x70840000, 0x7da00804, 0x70c70000, 0x7da00804, 0x70b00000, 0x7da00804,
+    0x70f90000, 0x7da00804, 0x711a0000, 0x7da00804, 0x71e20000, 0x7da00804, 0x720e0000, 0x7da00804,
+    0x72290000, 0x7da00804, 0x72ab0000, 0x7da00804, 0x733a0000, 0x7da00804, 0x738b8000, 0x7da00804,
+    0x738d8000, 0x7da00804, 0x73ab0000, 0x7da00804, 0x75bc8000, 0x7da00804, 0x75dd0000, 0x7da00804,
+    0x76a08000, 0x7da00804, 0x776d8000, 0x7da00804, 0x77658000, 0x7da00804, 0x77910000, 0x7da00804,
+    0x780f0000, 0x7da00804, 0x78b70000, 0x7da00804, 0x7bd38000, 0x7da00804, 0x791a8000, 0x7da00804,
+    0x79578000, 0x7da00804, 0x79950000, 0x7da00804, 0x7a388000, 0x7da00804, 0x7a830000, 0x7da00804,
+    0x7a9d8000, 0x7da00804, 0x7b0e8000, 0x7da00804, 0x7b0f8000, 0x7da00804, 0x7b650000, 0x7da00804,
+    0x7b6d8000, 0x7da00804, 0x7b7a0000, 0x7da00804, 0x7ba50000, 0x7da00804, 0x7ba00000, 0x7da00804,
+    0x7c660000, 0x7da00804, 0x7d588000, 0x7da00804, 0x7de00000, 0x7da00804, 0x7e3d8000, 0x7da00804,
+    0x7ead8000, 0x7da00804, 0x7efa0000, 0x7da00804, 0x7f9f0000, 0x7da08804, 0x40028000, 0x7da08804,
+    0x41a90000, 0x7da08804, 0x41f78000, 0x7da08804, 0x43bc8000, 0x7da08804, 0x44a08000, 0x7da08804,
+    0x44c30000, 0x7da08804, 0x44cb0000, 0x7da08804, 0x455f8000, 0x7da08804, 0x457c0000, 0x7da08804,
+    0x45658000, 0x7da08804, 0x45808000, 0x7da08804, 0x457f0000, 0x7da08804, 0x45768000, 0x7da08804,
+    0x459c8000, 0x7da08804, 0x45c50000, 0x7da08804, 0x46840000, 0x7da08804, 0x479c0000, 0x7da08804,
+    0x48390000, 0x7da08804, 0x48cc8000, 0x7da08804, 0x493b0000, 0x7da08804, 0x4b3e0000, 0x7da08804,
+    0x4b718000, 0x7da08804, 0x4bab0000, 0x7da08804, 0x4bed8000, 0x7da08804, 0x4bff8000, 0x7da08804,
+    0x4c058000, 0x7da08804, 0x4c1d8000, 0x7da08804, 0x4d890000, 0x7da08804, 0x4fce0000, 0x7dc20804,
+    0x54250000, 0x7dc20804, 0x54220000, 0x7dc20804, 0x59ea8000, 0x7dc00804, 0x5dce8000, 0x7dc00804,
+    0x600c0000, 0x7dc00804, 0x601c8000, 0x7dc20804, 0x69248000, 0x7dc20804, 0x6e680000, 0x7dc20804,
+    0x7f698000, 0x7da08804, 0x4fa18000, 0x7da08804, 0x4fc70000, 0x10210808, 0x10210808, 0x10210808,
+    0x10480808, 0x10210808, 0x106b0808, 0x10210808, 0x10210808, 0x10480808, 0x10210808, 0x10210808,
+    0x106b0808, 0x10e90808, 0x4648, 0x10fb8808, 0x10e90808, 0x10fb8808, 0x13048808, 0x13060808,
+    0x13048808, 0x12fd0808, 0x13048808, 0x13000808, 0x130a0808, 0x13060808, 0x13048808, 0x13010808,
+    0x13138804, 0x1584, 0x13138808, 0x13138808, 0x1644, 0x13190804, 0x1784, 0x13190804, 0x1744,
+    0x13190804, 0x17c4, 0x1784, 0x13190804, 0x17c4, 0x1744, 0x130f0804, 0x1644, 0x130f0804, 0x1684,
+    0x130f0804, 0x17c4, 0x130f8804, 0x17c4, 0x13100804, 0x17c4, 0x13108804, 0x17c4, 0x13110804,
+    0x17c4, 0x13118804, 0x17c4, 0x13120804, 0x17c4, 0x13130804, 0x17c4, 0x13138804, 0x17c4,
+    0x13140832, 0x17c4, 0x13140804, 0x17c4, 0x13148804, 0x17c4, 0x13150804, 0x17c4, 0x13158804,
+    0x17c4, 0x13160804, 0x17c4, 0x13170832, 0x17c4, 0x13170804, 0x17c4, 0x13178804, 0x17c4,
+    0x13180804, 0x17c4, 0x13188804, 0x17c4, 0x13190804, 0x17c4, 0x13198804, 0x17c4, 0x13118804,
+    0x16c4, 0x130f8804, 0x1804, 0x13140804, 0x1804, 0x13170804, 0x1804, 0x130f0808, 0x13148808,
+    0x13938834, 0x20f4, 0x13938832, 0x20f2, 0x13a28834, 0x20f4, 0x13a28832, 0x20f2, 0x13960834,
+    0x13320834, 0x13370834, 0x13390834, 0x13370832, 0x13390832, 0x13370834, 0x13938834, 0x13370832,
+    0x13938832, 0x13370834, 0x13940834, 0x13370832, 0x13940832, 0x13370834, 0x13960834, 0x13370832,
+    0x13960832, 0x13370834, 0x13958834, 0x13370832, 0x13958832, 0x13370834, 0x13968834, 0x13370832,
+    0x13968832, 0x13370834, 0x139d8834, 0x13370832, 0x139d8832, 0x1337082e, 0x139d882e, 0x13370834,
+    0x139b0834, 0x13370832, 0x139b0832, 0x1337082e, 0x139b082e, 0x13370834, 0x13478834, 0x13370834,
+    0x134c8834, 0x13370834, 0x138a0834, 0x13370834, 0x139b0834, 0x13370834, 0x139b8834, 0x133a0834,
+    0x13478834, 0x133a0834, 0x134c8834, 0x133a0834, 0x134d0834, 0x133a0834, 0x138a0834, 0x133a0834,
+    0x139b0834, 0x133a0834, 0x139b8834, 0x13428834, 0x13478834, 0x13428834, 0x134c8834, 0x13428834,
+    0x134d0834, 0x13428834, 0x138a0834, 0x13428834, 0x139b0834, 0x13428834, 0x139b8834, 0x13430834,
+    0x13478834, 0x13430834, 0x138a0834, 0x13430834, 0x139b0834, 0x13430834, 0x139b8834, 0x13478834,
+    0x134c8834, 0x13478834, 0x138a0834, 0x134c8834, 0x13478834, 0x134c8834, 0x138a0834, 0x134d0834,
+    0x13478834, 0x134d0834, 0x134c8834, 0x134d0834, 0x138a0834, 0x13630834, 0x13478834, 0x13630834,
+    0x134c8834, 0x13630834, 0x134d0834, 0x13630834, 0x138a0834, 0x13688834, 0x134c8834, 0x13688834,
+    0x138a0834, 0x13690834, 0x13478834, 0x13690834, 0x134c8834, 0x13690834, 0x134d0834, 0x13690834,
+    0x138a0834, 0x136b8834, 0x134c8834, 0x136b8834, 0x138a0834, 0x136c0834, 0x138a0834, 0x136d8834,
+    0x13478834, 0x136d8834, 0x138a0834, 0x136e0834, 0x13478834, 0x136e0834, 0x138a0834, 0x13720834,
+    0x13478834, 0x13720834, 0x134c8834, 0x13720834, 0x134d0834, 0x13720834, 0x138a0834, 0x13720834,
+    0x139b0834, 0x13720834, 0x139b8834, 0x13780834, 0x134c8834, 0x13780834, 0x138a0834, 0x13780834,
+    0x139b0834, 0x13780834, 0x139b8834, 0x137b0834, 0x13390834, 0x137b0834, 0x13478834, 0x137b0834,
+    0x134c8834, 0x137b0834, 0x134d0834, 0x137b0834, 0x13860834, 0x137b0834, 0x138a0834, 0x137b0834,
+    0x139b0834, 0x137b0834, 0x139b8834, 0x13860834, 0x13478834, 0x13860834, 0x134c8834, 0x13860834,
+    0x134d0834, 0x13860834, 0x138a0834, 0x13860834, 0x139b0834, 0x13860834, 0x139b8834, 0x138a0834,
+    0x13478834, 0x138a0834, 0x134c8834, 0x138a0834, 0x134d0834, 0x138a0834, 0x138a0834, 0x138a0834,
+    0x139b0834, 0x138a0834, 0x139b8834, 0x138c0834, 0x13478834, 0x138c0834, 0x134c8834, 0x138c0834,
+    0x134d0834, 0x138c0834, 0x138a0834, 0x138c0834, 0x139b0834, 0x138c0834, 0x139b8834, 0x13910834,
+    0x13478834, 0x13910834, 0x138a0834, 0x13910834, 0x139b0834, 0x13910834, 0x139b8834, 0x139b8834,
+    0x13478834, 0x139b8834, 0x134c8834, 0x139b8834, 0x134d0834, 0x139b8834, 0x138a0834, 0x139b8834,
+    0x139b0834, 0x139b8834, 0x139b8834, 0x13528834, 0x2634, 0x13598834, 0x2634, 0x139b0834, 0x2634,
+    0x1c34, 0x2034, 0x1cf4, 0x2034, 0x1db4, 0x2034, 0x1eb4, 0x2034, 0x1f74, 0x2034, 0x2034, 0x2634,
+    0x13370832, 0x13598832, 0x13370832, 0x135a0832, 0x13370832, 0x138a0832, 0x13370832, 0x138c0832,
+    0x13370832, 0x139b0832, 0x13370832, 0x139b8832, 0x133a0832, 0x13598832, 0x133a0832, 0x135a0832,
+    0x133a0832, 0x138a0832, 0x133a0832, 0x138c0832, 0x133a0832, 0x139b0832, 0x133a0832, 0x139b8832,
+    0x13428832, 0x13598832, 0x13428832, 0x135a0832, 0x13428832, 0x138a0832, 0x13428832, 0x138c0832,
+    0x13428832, 0x139b0832, 0x13428832, 0x139b8832, 0x13430832, 0x13598832, 0x13430832, 0x135a0832,
+    0x13430832, 0x138a0832, 0x13430832, 0x138c0832, 0x13430832, 0x139b0832, 0x13430832, 0x139b8832,
+    0x13720832, 0x139b0832, 0x13720832, 0x139b8832, 0x13780832, 0x139b0832, 0x13780832, 0x139b8832,
+    0x137b0832, 0x13390832, 0x137b0832, 0x13860832, 0x137b0832, 0x138a0832, 0x137b0832, 0x139b0832,
+    0x137b0832, 0x139b8832, 0x13860832, 0x138a0832, 0x13860832, 0x139b0832, 0x13860832, 0x139b8832,
+    0x138a0832, 0x13390832, 0x138a0832, 0x138a0832, 0x138c0832, 0x13598832, 0x138c0832, 0x135a0832,
+    0x138c0832, 0x138a0832, 0x138c0832, 0x138c0832, 0x138c0832, 0x139b0832, 0x138c0832, 0x139b8832,
+    0x139b0832, 0x2632, 0x139b8832, 0x13598832, 0x139b8832, 0x135a0832, 0x139b8832, 0x138a0832,
+    0x139b8832, 0x138c0832, 0x139b8832, 0x139b0832, 0x139b8832, 0x139b8832, 0x1337082e, 0x1347882e,
+    0x1337082e, 0x134c882e, 0x1337082e, 0x134d082e, 0x1337082e, 0x138a082e, 0x1337082e, 0x1391082e,
+    0x133a082e, 0x1347882e, 0x133a082e, 0x134c882e, 0x133a082e, 0x134d082e, 0x133a082e, 0x138a082e,
+    0x133a082e, 0x1391082e, 0x1342882e, 0x1347882e, 0x1342882e, 0x134c882e, 0x1342882e, 0x134d082e,
+    0x1342882e, 0x138a082e, 0x1342882e, 0x1391082e, 0x1343082e, 0x138a082e, 0x1347882e, 0x134c882e,
+    0x1347882e, 0x138a082e, 0x134c882e, 0x1347882e, 0x134c882e, 0x138a082e, 0x134d082e, 0x1347882e,
+    0x134d082e, 0x138a082e, 0x1363082e, 0x1347882e, 0x1363082e, 0x134c882e, 0x1363082e, 0x134d082e,
+    0x1363082e, 0x138a082e, 0x1368882e, 0x134c882e, 0x1368882e, 0x134d082e, 0x1368882e, 0x138a082e,
+    0x1369082e, 0x1347882e, 0x1369082e, 0x134c882e, 0x1369082e, 0x134d082e, 0x1369082e, 0x138a082e,
+    0x136b882e, 0x134c882e, 0x136c082e, 0x138a082e, 0x136d882e, 0x1347882e, 0x136d882e, 0x138a082e,
+    0x136e082e, 0x1347882e, 0x136e082e, 0x138a082e, 0x1372082e, 0x1347882e, 0x1372082e, 0x134c882e,
+    0x1372082e, 0x134d082e, 0x1372082e, 0x138a082e, 0x1378082e, 0x134c882e, 0x1378082e, 0x138a082e,
+    0x137b082e, 0x1347882e, 0x137b082e, 0x134c882e, 0x137b082e, 0x134d082e, 0x137b082e, 0x1386082e,
+    0x137b082e, 0x138a082e, 0x1386082e, 0x1347882e, 0x1386082e, 0x134c882e, 0x1386082e, 0x134d082e,
+    0x1386082e, 0x138a082e, 0x1386082e, 0x1391082e, 0x138a082e, 0x1347882e, 0x138a082e, 0x134c882e,
+    0x138a082e, 0x134d082e, 0x138a082e, 0x138a082e, 0x138c082e, 0x1347882e, 0x138c082e, 0x134c882e,
+    0x138c082e, 0x134d082e, 0x138c082e, 0x138a082e, 0x138c082e, 0x1391082e, 0x1391082e, 0x1347882e,
+    0x1391082e, 0x138a082e, 0x1391082e, 0x262e, 0x139b882e, 0x1347882e, 0x139b882e, 0x134c882e,
+    0x139b882e, 0x134d082e, 0x139b882e, 0x138a082e, 0x139b882e, 0x1391082e, 0x13370830, 0x138a0830,
+    0x13370830, 0x13910830, 0x133a0830, 0x138a0830, 0x133a0830, 0x13910830, 0x13428830, 0x138a0830,
+    0x13428830, 0x13910830, 0x13430830, 0x138a0830, 0x13430830, 0x13910830, 0x13630830, 0x138a0830,
+    0x13630830, 0x13910830, 0x13638830, 0x138a0830, 0x13638830, 0x13910830, 0x137b0830, 0x13860830,
+    0x137b0830, 0x138a0830, 0x13860830, 0x138a0830, 0x138c0830, 0x138a0830, 0x138c0830, 0x13910830,
+    0x139b8830, 0x138a0830, 0x139b8830, 0x13910830, 0x1db0, 0x2030, 0x1eb0, 0x2030, 0x1f70, 0x2030,
+    0x136b8834, 0x139b0834, 0x136b8834, 0x139b8834, 0x136d8834, 0x139b0834, 0x136d8834, 0x139b8834,
+    0x136e0834, 0x139b0834, 0x136e0834, 0x139b8834, 0x13630834, 0x139b0834, 0x13630834, 0x139b8834,
+    0x13638834, 0x139b0834, 0x13638834, 0x139b8834, 0x134c8834, 0x139b0834, 0x134c8834, 0x139b8834,
+    0x13478834, 0x139b0834, 0x13478834, 0x139b8834, 0x134d0834, 0x139b0834, 0x134d0834, 0x139b8834,
+    0x13688834, 0x139b0834, 0x13688834, 0x139b8834, 0x13690834, 0x139b0834, 0x13690834, 0x139b8834,
+    0x13638834, 0x13478834, 0x13638834, 0x134c8834, 0x13638834, 0x134d0834, 0x13638834, 0x138a0834,
+    0x13638834, 0x13598834, 0x13630834, 0x13598834, 0x13688834, 0x13598834, 0x13690834, 0x13598834,
+    0x136b8832, 0x139b0832, 0x136b8832, 0x139b8832, 0x136d8832, 0x139b0832, 0x136d8832, 0x139b8832,
+    0x136e0832, 0x139b0832, 0x136e0832, 0x139b8832, 0x13630832, 0x139b0832, 0x13630832, 0x139b8832,
+    0x13638832, 0x139b0832, 0x13638832, 0x139b8832, 0x134c8832, 0x139b0832, 0x134c8832, 0x139b8832,
+    0x13478832, 0x139b0832, 0x13478832, 0x139b8832, 0x134d0832, 0x139b0832, 0x134d0832, 0x139b8832,
+    0x13688832, 0x139b0832, 0x13688832, 0x139b8832, 0x13690832, 0x139b0832, 0x13690832, 0x139b8832,
+    0x13638832, 0x13478832, 0x13638832, 0x134c8832, 0x13638832, 0x134d0832, 0x13638832, 0x138a0832,
+    0x13638832, 0x13598832, 0x13630832, 0x13598832, 0x13688832, 0x13598832, 0x13690832, 0x13598832,
+    0x1363882e, 0x1347882e, 0x1363882e, 0x134c882e, 0x1363882e, 0x134d082e, 0x1363882e, 0x138a082e,
+    0x1363082e, 0x1391082e, 0x1363882e, 0x1391082e, 0x136b882e, 0x138a082e, 0x13630830, 0x13478830,
+    0x13630830, 0x134c8830, 0x13630830, 0x134d0830, 0x13638830, 0x13478830, 0x13638830, 0x134c8830,
+    0x13638830, 0x134d0830, 0x136b8830, 0x138a0830, 0x136c0830, 0x138a0830, 0x13390832, 0x1b72,
+    0x13390834, 0x1b74, 0x1342882e, 0x1347882e, 0x138a082e, 0x13428832, 0x134c8832, 0x13478832,
+    0x1342882e, 0x134c882e, 0x1347882e, 0x1342882e, 0x134c882e, 0x138a082e, 0x1342882e, 0x134d082e,
+    0x138a082e, 0x1342882e, 0x138a082e, 0x1347882e, 0x1342882e, 0x138a082e, 0x134c882e, 0x1342882e,
+    0x138a082e, 0x134d082e, 0x13478832, 0x138a0832, 0x134c8832, 0x1347882e, 0x138a082e, 0x134c882e,
+    0x134c8832, 0x138a0832, 0x139b8832, 0x134c8832, 0x138a0832, 0x139b0832, 0x1363082e, 0x134c882e,
+    0x1347882e, 0x1363082e, 0x1347882e, 0x134c882e, 0x13630832, 0x13478832, 0x139b0832, 0x13630832,
+    0x138a0832, 0x134c8832, 0x1363082e, 0x138a082e, 0x134c882e, 0x1363082e, 0x138a082e, 0x1347882e,
+    0x13630832, 0x138a0832, 0x138a0832, 0x1363082e, 0x138a082e, 0x138a082e, 0x13688832, 0x134c8832,
+    0x134c8832, 0x1368882e, 0x134c882e, 0x134c882e, 0x13688832, 0x138a0832, 0x138a0832, 0x13638832,
+    0x134c8832, 0x138a0832, 0x1363882e, 0x134c882e, 0x138a082e, 0x13638832, 0x13478832, 0x139b8832,
+    0x13638832, 0x138a0832, 0x134d0832, 0x1363882e, 0x138a082e, 0x134d082e, 0x13638832, 0x138a0832,
+    0x138a0832, 0x1363882e, 0x138a082e, 0x138a082e, 0x13690832, 0x134c8832, 0x139b0832, 0x13690832,
+    0x134d0832, 0x138a0832, 0x1369082e, 0x134d082e, 0x138a082e, 0x136b8832, 0x138a0832, 0x134c8832,
+    0x136b882e, 0x138a082e, 0x134c882e, 0x136b882e, 0x138a082e, 0x138a082e, 0x136b8832, 0x138a0832,
+    0x139b8832, 0x136d8832, 0x13478832, 0x138a0832, 0x136d8832, 0x138a0832, 0x138a0832, 0x136d882e,
+    0x138a082e, 0x138a082e, 0x136d8832, 0x138a0832, 0x139b0832, 0x136e0832, 0x138a0832, 0x138a0832,
+    0x136e0832, 0x138a0832, 0x139b8832, 0x136e0832, 0x138a0832, 0x139b0832, 0x13720832, 0x134d0832,
+    0x138a0832, 0x1372082e, 0x134d082e, 0x138a082e, 0x13780832, 0x138a0832, 0x134c8832, 0x13780832,
+    0x138a0832, 0x138a0832, 0x13860832, 0x134c8832, 0x138a0832, 0x13860832, 0x134c8832, 0x139b8832,
+    0x13860832, 0x134c8832, 0x139b0832, 0x1386082e, 0x1347882e, 0x1347882e, 0x13860832, 0x13478832,
+    0x13478832, 0x13860832, 0x134d0832, 0x138a0832, 0x1386082e, 0x134d082e, 0x138a082e, 0x13860832,
+    0x138a0832, 0x134c8832, 0x1386082e, 0x138a082e, 0x134c882e, 0x138a082e, 0x134c882e, 0x1347882e,
+    0x138a082e, 0x134c882e, 0x138a082e, 0x138a0832, 0x134c8832, 0x139b8832, 0x138a082e, 0x1347882e,
+    0x134c882e, 0x138a082e, 0x1347882e, 0x138a082e, 0x138a082e, 0x134d082e, 0x1347882e, 0x138a082e,
+    0x134d082e, 0x138a082e, 0x138a082e, 0x1347882e, 0x134d082e, 0x1391082e, 0x138a082e, 0x1347882e,
+    0x1391082e, 0x138a082e, 0x138a082e, 0x138c082e, 0x134c882e, 0x138a082e, 0x138c0832, 0x134c8832,
+    0x139b0832, 0x138c0832, 0x13478832, 0x138a0832, 0x138c082e, 0x1347882e, 0x138a082e, 0x138c0832,
+    0x13478832, 0x139b0832, 0x138c0832, 0x138a0832, 0x139b8832, 0x138c0832, 0x138a0832, 0x139b0832,
+    0x139b8832, 0x138a0832, 0x138a0832, 0x139b882e, 0x138a082e, 0x138a082e, 0x133a0832, 0x134d0832,
+    0x139b8832, 0x13428832, 0x13478832, 0x139b8832, 0x13428832, 0x13478832, 0x139b0832, 0x13428832,
+    0x134d0832, 0x139b8832, 0x13428832, 0x134d0832, 0x139b0832, 0x13428832, 0x138a0832, 0x139b8832,
+    0x13428832, 0x138a0832, 0x139b0832, 0x13478832, 0x138a0832, 0x139b8832, 0x13478832, 0x134c8832,
+    0x139b0832, 0x13478832, 0x138a0832, 0x139b0832, 0x13630832, 0x134d0832, 0x139b0832, 0x13688832,
+    0x134c8832, 0x139b8832, 0x13638832, 0x134c8832, 0x139b8832, 0x13690832, 0x134c8832, 0x139b8832,
+    0x13860832, 0x13478832, 0x139b8832, 0x13860832, 0x138a0832, 0x139b8832, 0x139b8832, 0x134c8832,
+    0x139b8832, 0x139b8832, 0x13478832, 0x139b8832, 0x139b8832, 0x138a0832, 0x139b8832, 0x138a0832,
+    0x138a0832, 0x139b8832, 0x13780832, 0x138a0832, 0x139b8832, 0x138c0832, 0x134c8832, 0x139b8832,
+    0x1378082e, 0x138a082e, 0x134c882e, 0x1386082e, 0x134c882e, 0x138a082e, 0x136d8832, 0x138a0832,
+    0x139b8832, 0x137b0832, 0x138a0832, 0x139b8832, 0x138c082e, 0x1347882e, 0x134c882e, 0x138a0832,
+    0x134d0832, 0x139b8832, 0x1386082e, 0x1347882e, 0x138a082e, 0x137b0832, 0x138a0832, 0x138a0832,
+    0x13860832, 0x13478832, 0x138a0832, 0x138c0832, 0x13478832, 0x134c8832, 0x13478832, 0x134c8832,
+    0x139b8832, 0x134c8832, 0x13478832, 0x139b8832, 0x138a0832, 0x13478832, 0x139b8832, 0x13720832,
+    0x138a0832, 0x139b8832, 0x133a0832, 0x134c8832, 0x139b8832, 0x137b082e, 0x138a082e, 0x138a082e,
+    0x136d882e, 0x1347882e, 0x138a082e, 0x1368882e, 0x138a082e, 0x138a082e, 0x13630832, 0x134d0832,
+    0x139b8832, 0x138c0832, 0x13478832, 0x139b8832, 0x13688834, 0x13860834, 0x13a28834, 0x13780834,
+    0x13860834, 0x13a28834, 0x13390834, 0x13860834, 0x13860834, 0x13910834, 0x13390834, 0x137b0834,
+    0x133a0834, 0x13598834, 0x138a0834, 0x134c8834, 0x138a0834, 0x13520834, 0x13688834, 0x13860834,
+    0x136d8834, 0x138a0834, 0x13598834, 0x13630834, 0x13940834, 0x13860834, 0x136d8834, 0x13860834,
+    0x139b8834, 0x13910834, 0x13940834, 0x13630834, 0x13860834, 0x138a0834, 0x13688834, 0x13860834,
+    0x139b0834, 0x13688834, 0x13860834, 0x139b0834, 0x1048835, 0x13390834, 0x13860834, 0x13860834,
+    0x13910834, 0x1048835, 0x136d8834, 0x13860834, 0x139b8834, 0x13910834, 0x1048835, 0x13940834,
+    0x13630834, 0x13860834, 0x138a0834, 0x13478834, 0x13860834, 0x1048835, 0x13478834, 0x13860834,
+    0x13390834, 0x13860834, 0x13910834, 0x13598834, 0x139c0834, 0x13390834, 0x13860834, 0x13f082d,
+    0x13f082d, 0x13f082d, 0x13f082d, 0x13f082d, 0x13860834, 0x13328834, 0x13860832, 0x13328832,
+    0x13860834, 0x13330834, 0x13860832, 0x13330832, 0x13860834, 0x13350834, 0x13860832, 0x13350832,
+    0x13860834, 0x13390834, 0x13860832, 0x13390832, 0x2ceb0808, 0x4608, 0x2ceb8808, 0x4608,
+    0x2cec0808, 0x4608, 0x2cec8808, 0x4608, 0x2ced0808, 0x4608, 0x2ced8808, 0x4608, 0x2cee0808,
+    0x4608, 0x2cee0808, 0x4648, 0x2cee8808, 0x4608, 0x2cef0808, 0x4608, 0x2cef8808, 0x4608,
+    0x2cef8808, 0x4648, 0x2cf00808, 0x4608, 0x2cf00808, 0x4648, 0x2cf08808, 0x4608, 0x2cf08808,
+    0x4648, 0x2cf10808, 0x4608, 0x2cf18808, 0x4608, 0x2cf20808, 0x4608, 0x2cf28808, 0x4648,
+    0x2cf28808, 0x4688, 0x2cf30808, 0x4608, 0x2cf38808, 0x4608, 0x2cf40808, 0x4608, 0x2cf48808,
+    0x4608, 0x2cf48808, 0x4648, 0x2cf50808, 0x4608, 0x2cf50808, 0x4648, 0x2cf58808, 0x4608,
+    0x2cf60808, 0x4608, 0x2cf28808, 0x4608, 0x283c8808, 0x4648, 0x27e10808, 0x4608, 0x1e460808,
+    0x4608, 0x1e470808, 0x4608, 0x1e488808, 0x4608, 0x1e490808, 0x4608, 0x1e498808, 0x4608,
+    0x1e4a0808, 0x4608, 0x1e4a8808, 0x4608, 0x1e4b0808, 0x4608, 0x1e4c0808, 0x4608, 0x1e4c8808,
+    0x4608, 0x1e4d0808, 0x4608, 0x1e4d8808, 0x4608, 0x1e4e0808, 0x4608, 0x1e4e8808, 0x4608,
+    0x1e510808, 0x4608, 0x1e518808, 0x4608, 0x1e520808, 0x4608, 0x1e528808, 0x4608, 0x1e538808,
+    0x4608, 0x1e558808, 0x4608, 0x1e560808, 0x4608, 0x1e568808, 0x4608, 0x1e570808, 0x4608,
+    0x1e578808, 0x4608, 0x1e598808, 0x4608, 0x1e5a0808, 0x4608, 0x1e5a8808, 0x4608, 0x1e5b0808,
+    0x4608, 0x1e318814, 0x4608, 0x1e358814, 0x4608, 0x1e358814, 0x4648, 0x1e380814, 0x4608,
+    0x1e3d0814, 0x4608, 0x1e3d8814, 0x4608, 0x1e3d8814, 0x4648, 0x1e3f0814, 0x4608, 0x1e428814,
+    0x4608, 0x1e430814, 0x4608, 0x1e318808, 0x4608, 0x1e358808, 0x4608, 0x1e358808, 0x4648,
+    0x1e380808, 0x4608, 0x1e3d0808, 0x4608, 0x1e3d8808, 0x4608, 0x1e3d8808, 0x4648, 0x1e3f0808,
+    0x4608, 0x1e428808, 0x4608, 0x1e430808, 0x4608, 0x28508808, 0x4648, 0x28518808, 0x4648,
+    0x28528808, 0x4648, 0x28540808, 0x4648, 0x28518808, 0x28530808, 0x285d0808, 0x4648, 0x28310808,
+    0x28328808, 0x16ae8804, 0x3084, 0x16af0804, 0x3084, 0x16b38804, 0x3084, 0x17a48808, 0x4608,
+    0x17a48808, 0x4648, 0x17a50808, 0x4608, 0x17a58808, 0x4608, 0x17bc8808, 0x4608, 0x17bd0808,
+    0x4608, 0x18548808, 0x4608, 0x185c8808, 0x4608, 0x18560808, 0x4608, 0xfcc8808, 0x4608,
+    0xfcd0808, 0x4608, 0xfcd8804, 0x4604, 0x9390805, 0x0, 0x9398805, 0x0, 0x9398805, 0x0, 0x0,
+    0x9398805, 0x0, 0x0, 0x9398805, 0x0, 0x0, 0x9398805, 0x0, 0x0, 0x9398805, 0x0, 0x0, 0x9538805,
+    0x0, 0x9540805, 0x0, 0x9538805, 0x0, 0x0, 0x9540805, 0x0, 0x0, 0x9538805, 0x0, 0x0, 0x9540805,
+    0x0, 0x0, 0xfcc8808, 0x4608, 0xfcd0808, 0x4608, 0xfcd0808, 0x4608, 0xfcd8808, 0x4608,
+    0xfce0808, 0x4608, 0xfce8808, 0x4608, 0xfcf0808, 0x4608, 0xfcf8808, 0x4608, 0xfd00808, 0x4608,
+    0xfd08808, 0x4608, 0xfcc0808, 0x13f0809, 0xfcc0808, 0x1118809, 0xfcc8808, 0x1118809, 0xfcd0808,
+    0x1118809, 0xfcd8808, 0x1118809, 0xfce0808, 0x1118809, 0xfce8808, 0x1118809, 0xfcf0808,
+    0x1118809, 0xfcf8808, 0x1118809, 0xfd00808, 0x1118809, 0xfd08808, 0x1118809, 0x1940809,
+    0xfd10814, 0x1948809, 0x1940809, 0xfde0814, 0x1948809, 0x1940809, 0xfeb0814, 0x1948809,
+    0x1940809, 0xff58814, 0x1948809, 0x1940809, 0x10038814, 0x1948809, 0x1940809, 0x10210814,
+    0x1948809, 0x1940809, 0x10288814, 0x1948809, 0x1940809, 0x103a8814, 0x1948809, 0x1940809,
+    0x10480814, 0x1948809, 0x1940809, 0x10558814, 0x1948809, 0x1940809, 0x10620814, 0x1948809,
+    0x1940809, 0x106b0814, 0x1948809, 0x1940809, 0x10848814, 0x1948809, 0x1940809, 0x108c0814,
+    0x1948809, 0x1940809, 0x109e0814, 0x1948809, 0x1940809, 0x10b58814, 0x1948809, 0x1940809,
+    0x10c00814, 0x1948809, 0x1940809, 0x10c98814, 0x1948809, 0x1940809, 0x10e90814, 0x1948809,
+    0x1940809, 0x10fb8814, 0x1948809, 0x1940809, 0x110b8814, 0x1948809, 0x1940809, 0x11238814,
+    0x1948809, 0x1940809, 0x112c8814, 0x1948809, 0x1940809, 0x11320814, 0x1948809, 0x1940809,
+    0x11380814, 0x1948809, 0x1940809, 0x11430814, 0x1948809, 0x1c50809, 0x10e90814, 0x1c58809,
+    0xfeb0818, 0xff58818, 0x112c8818, 0x11430818, 0x103a883a, 0x1123883a, 0x1084883a, 0x1123883a,
+    0x10e9083a, 0xff5883a, 0x10e9083a, 0x10e9083a, 0x10b5883a, 0x10b5883a, 0x1123883a, 0x112c883a,
+    0xfeb083a, 0x10848828, 0xfeb0828, 0x10848828, 0xff58828, 0x10848828, 0x10c98828, 0x1048083a,
+    0xfeb083a, 0x10b5883a, 0xfd1083a, 0x10e9083a, 0xfd1083a, 0xfd1083a, 0xfde083a, 0x112c883a,
+    0xfeb083a, 0xff5883a, 0x1055883a, 0xfeb083a, 0x106b083a, 0xfeb083a, 0x109e083a, 0x109e083a,
+    0x106b083a, 0x1021083a, 0x10c9883a, 0x1003883a, 0x1003883a, 0x1048083a, 0xff5883a, 0x108c083a,
+    0x1003883a, 0x112c883a, 0x108c083a, 0x1028883a, 0x109e083a, 0x1062083a, 0x10e9083a, 0x109e083a,
+    0x10e9083a, 0x110b883a, 0x10b5883a, 0x1338839, 0x1123883a, 0x10e9083a, 0xfcd8838, 0xff5883a,
+    0xfcd0838, 0x108c0838, 0xff58838, 0x1048839, 0x10e9083a, 0xfeb0838, 0x10c98838, 0xfcd0838,
+    0x1062083a, 0xfce0838, 0x1062083a, 0xfd00838, 0x1062083a, 0xfce8838, 0x13f0839, 0xfcc8838,
+    0xfcf8838, 0x13f0839, 0xfcc8838, 0xfcd0838, 0xfcd0838, 0x13f0839, 0xfcd0838, 0xfcf0838,
+    0xfcc0838, 0x10b5883a, 0xfcc8838, 0xfcd0838, 0xfcc0838, 0x10b5883a, 0x103a883a, 0xfeb0838,
+    0x103a883a, 0xff58838, 0x10c9883a, 0x103a883a, 0x10480838, 0x1048839, 0x10c9883a, 0x10038838,
+    0x10e90838, 0x106b083a, 0x109e0838, 0x10e90838, 0x10e90838, 0x106b0838, 0x10038838, 0x10e90838,
+    0x10e90838, 0x10e9083a, 0x103a883a, 0x1123883a, 0x110b883a, 0x103a883a, 0xff5883a, 0x1123883a,
+    0x109e083a, 0xff5883a, 0x217c0838, 0x21700838, 0x21720838, 0x21720838, 0x7da00838, 0x71258000,
+    0x7da00838, 0x6dab8000, 0x7da00838, 0x69e60000, 0x21768838, 0xdf8, 0x7da00838, 0x67460000,
+    0x7da00838, 0x6c8d0000, 0x7da08838, 0x44f18000, 0x7da00838, 0x6c948000, 0x7da00838, 0x67520000,
+    0x7da00838, 0x73100000, 0x7da00838, 0x78908000, 0x7da00838, 0x72cc8000, 0x7da00838, 0x69268000,
+    0x7da00838, 0x6fc60000, 0x7da00838, 0x68c68000, 0x7da00838, 0x72d80000, 0x7da00838, 0x690e8000,
+    0x7da00838, 0x7ea10000, 0x7da00838, 0x7a8f8000, 0x7da08838, 0x46548000, 0x7da00838, 0x6c780000,
+    0x7da00838, 0x6a1c8000, 0x7da00838, 0x778a0000, 0x7da00838, 0x714a8000, 0x7da00838, 0x71aa8000,
+    0x7da00838, 0x67000000, 0x7da00838, 0x67048000, 0x7da08838, 0x48250000, 0x7da00838, 0x6ef30000,
+    0x7da00838, 0x67168000, 0x7da00838, 0x69f98000, 0x7da00838, 0x71838000, 0x7da08838, 0x46b80000,
+    0x7da00838, 0x71298000, 0x7da00838, 0x7cc08000, 0x7da00838, 0x7d3d0000, 0x7da00838, 0x6a040000,
+    0x7da00838, 0x77400000, 0x7da00838, 0x73848000, 0x7da00838, 0x73840000, 0x7da00838, 0x7a998000,
+    0x7da00838, 0x69390000, 0x7da00838, 0x6adb0000, 0x7da08838, 0x48a68000, 0x1c50809, 0x7da00808,
+    0x73960000, 0x1c58809, 0x1c50809, 0x7da00808, 0x67048000, 0x1c58809, 0x1c50809, 0x7da00808,
+    0x67460000, 0x1c58809, 0x1c50809, 0x7da00808, 0x6dc48000, 0x1c58809, 0x1c50809, 0x7da00808,
+    0x785c8000, 0x1c58809, 0x1c50809, 0x7da00808, 0x71298000, 0x1c58809, 0x1c50809, 0x7da00808,
+    0x7b6b8000, 0x1c58809, 0x1c50809, 0x7da00808, 0x696e8000, 0x1c58809, 0x1c50809, 0x7da00808,
+    0x72ab8000, 0x1c58809, 0x7da0080c, 0x6fcb8000, 0x7da0080c, 0x69f78000, 0x7da00804, 0x671e8000,
+    0x7da00804, 0x671c0000, 0x7da00804, 0x67208000, 0x7dc20804, 0x40910000, 0x7da00804, 0x67b00000,
+    0x7da00804, 0x67d70000, 0x7da00804, 0x67dd8000, 0x7da00804, 0x68010000, 0x7da00804, 0x683d0000,
+    0x7da00804, 0x684c8000, 0x7da00804, 0x68738000, 0x7da00804, 0x68678000, 0x7dc00804, 0x5a4f0000,
+    0x7dc20804, 0x431d0000, 0x7da00804, 0x68a68000, 0x7da00804, 0x68aa0000, 0x7da00804, 0x68b20000,
+    0x7da00804, 0x68bb8000, 0x7dc20804, 0x428e0000, 0x7dc00804, 0x5a5c8000, 0x7da00804, 0x68b38000,
+    0x7da00804, 0x68c68000, 0x7dc20804, 0x42a58000, 0x7da00804, 0x68cb8000, 0x7da00804, 0x68d20000,
+    0x7da00804, 0x67660000, 0x7da00804, 0x68d60000, 0x7da00804, 0x68da8000, 0x7dc28804, 0x48ef8000,
+    0x7da00804, 0x68fa8000, 0x7da00804, 0x69018000, 0x7dc00804, 0x5a6f8000, 0x7da00804, 0x691d8000,
+    0x7da00804, 0x69230000, 0x7da00804, 0x69390000, 0x7da00804, 0x693b8000, 0x7dc00804, 0x5a8a8000,
+    0x7da00804, 0x69638000, 0x7da00804, 0x69648000, 0x7da00804, 0x69720000, 0x7da00804, 0x697d0000,
+    0x7da00804, 0x69828000, 0x7da00804, 0x69830000, 0x7da00804, 0x698b8000, 0x7da00804, 0x69a48000,
+    0x7da00804, 0x69a88000, 0x7da00804, 0x69ad0000, 0x7da00804, 0x69b98000, 0x7da00804, 0x69be8000,
+    0x7da00804, 0x69bf8000, 0x7da00804, 0x69bf8000, 0x7da00804, 0x69bf8000, 0x7dc20804, 0x45160000,
+    0x7da00804, 0x78380000, 0x7da00804, 0x69e50000, 0x7da00804, 0x69ef8000, 0x7dc20804, 0x45b18000,
+    0x7da00804, 0x69f58000, 0x7da00804, 0x69f88000, 0x7da00804, 0x6a030000, 0x7da00804, 0x6a4f0000,
+    0x7da00804, 0x6a1c0000, 0x7da00804, 0x6a240000, 0x7da00804, 0x6a340000, 0x7da00804, 0x6a510000,
+    0x7da00804, 0x6a7b0000, 0x7da00804, 0x6a880000, 0x7da00804, 0x6aa98000, 0x7da00804, 0x6ab18000,
+    0x7da00804, 0x6ac20000, 0x7da00804, 0x6ac20000, 0x7da00804, 0x6acc8000, 0x7da00804, 0x6ad58000,
+    0x7da00804, 0x6ad98000, 0x7da00804, 0x6ae10000, 0x7da00804, 0x6b8b0000, 0x7da00804, 0x6b030000,
+    0x7da00804, 0x6b8b8000, 0x7da00804, 0x6b288000, 0x7da00804, 0x6b3a0000, 0x7da00804, 0x69038000,
+    0x7da00804, 0x6c770000, 0x7da00804, 0x6be70000, 0x7da00804, 0x6bfa0000, 0x7da00804, 0x6c068000,
+    0x7da00804, 0x6bc58000, 0x7da00804, 0x6c190000, 0x7da00804, 0x6c188000, 0x7da00804, 0x6c560000,
+    0x7dc20804, 0x4a720000, 0x7da00804, 0x6c790000, 0x7da00804, 0x6c7b8000, 0x7da00804, 0x6c830000,
+    0x7da00804, 0x6c8d0000, 0x7da00804, 0x6c910000, 0x7da00804, 0x6cb10000, 0x7dc20804, 0x4b540000,
+    0x7dc20804, 0x4b750000, 0x7da00804, 0x6cf60000, 0x7da00804, 0x6d0d8000, 0x7da00804, 0x6d138000,
+    0x7da00804, 0x6cec0000, 0x7da00804, 0x6d330000, 0x7dc00804, 0x5b770000, 0x7dc00804, 0x5b7e0000,
+    0x7da00804, 0x6d840000, 0x7da00804, 0x6d9f0000, 0x7da00804, 0x6d9f0000, 0x7dc20804, 0x4ce40000,
+    0x7da00804, 0x6de18000, 0x7da00804, 0x6dec0000, 0x7da00804, 0x6df38000, 0x7da00804, 0x6df98000,
+    0x7dc20804, 0x4d8c0000, 0x7da00804, 0x6dff8000, 0x7da00804, 0x6e030000, 0x7da00804, 0x6fa98000,
+    0x7da00804, 0x6e110000, 0x7dc00804, 0x5bc08000, 0x7da00804, 0x6e300000, 0x7da00804, 0x6e370000,
+    0x7da00804, 0x6e600000, 0x7da00804, 0x6e468000, 0x7dc20804, 0x4ef20000, 0x7da00804, 0x6ea18000,
+    0x7dc20804, 0x4ef30000, 0x7da00804, 0x6eb70000, 0x7da00804, 0x6eb58000, 0x7da00804, 0x6ebe0000,
+    0x7da00804, 0x6ef08000, 0x7da00804, 0x6ef10000, 0x7dc00804, 0x5c178000, 0x7da00804, 0x6efe8000,
+    0x7da00804, 0x6f140000, 0x7da00804, 0x6f1e8000, 0x7da00804, 0x6f348000, 0x7dc00804, 0x5c310000,
+    0x7dc20804, 0x50c18000, 0x7dc00804, 0x5c3e0000, 0x7da00804, 0x6f580000, 0x7da00804, 0x6f598000,
+    0x7da00804, 0x6f5b0000, 0x7da00804, 0x6f650000, 0x7dc28804, 0x51c90000, 0x7da00804, 0x6f7f0000,
+    0x7dc20804, 0x51988000, 0x7dc20804, 0x51988000, 0x7da08804, 0x41008000, 0x7da00804, 0x6f910000,
+    0x7da00804, 0x6f910000, 0x7dc00804, 0x5c638000, 0x7dc20804, 0x595c0000, 0x7dc20804, 0x70ed0000,
+    0x7da00804, 0x6fb10000, 0x7da00804, 0x6fb58000, 0x7dc00804, 0x5c718000, 0x7da00804, 0x6fcd0000,
+    0x7da00804, 0x6fe68000, 0x7da00804, 0x6feb8000, 0x7da00804, 0x6ffc8000, 0x7da00804, 0x70408000,
+    0x7dc00804, 0x5c9d0000, 0x7dc00804, 0x5c8e0000, 0x7da00804, 0x704a0000, 0x7dc20804, 0x536a0000,
+    0x7da00804, 0x70638000, 0x7da00804, 0x70a40000, 0x7da00804, 0x70a60000, 0x7da00804, 0x70a70000,
+    0x7da00804, 0x70a60000, 0x7da00804, 0x70bd0000, 0x7da00804, 0x70c70000, 0x7da00804, 0x70d90000,
+    0x7da00804, 0x70d20000, 0x7da00804, 0x70d78000, 0x7da00804, 0x70ef0000, 0x7da00804, 0x70f90000,
+    0x7da00804, 0x70fb0000, 0x7da00804, 0x71080000, 0x7da00804, 0x710d8000, 0x7da00804, 0x712e8000,
+    0x7da00804, 0x71588000, 0x7da00804, 0x716a0000, 0x7da00804, 0x71a80000, 0x7dc20804, 0x55860000,
+    0x7da00804, 0x719e8000, 0x7da00804, 0x717e0000, 0x7da00804, 0x71b40000, 0x7da00804, 0x71c18000,
+    0x7da00804, 0x71f20000, 0x7dc20804, 0x55f88000, 0x7da00804, 0x72110000, 0x7da00804, 0x71e28000,
+    0x7da00804, 0x71d48000, 0x7dc00804, 0x5d170000, 0x7da00804, 0x72348000, 0x7da00804, 0x723f0000,
+    0x7da00804, 0x724e8000, 0x7da00804, 0x723b8000, 0x7dc00804, 0x5d360000, 0x7da00804, 0x72a78000,
+    0x7da00804, 0x72b60000, 0x7dc20804, 0x58050000, 0x7da00804, 0x72f18000, 0x7da00804, 0x737c0000,
+    0x7da00804, 0x73248000, 0x7dc00804, 0x5d8c8000, 0x7da00804, 0x73488000, 0x7dc00804, 0x5d840000,
+    0x7dc00804, 0x5d720000, 0x7da00804, 0x68c90000, 0x7da00804, 0x68ca8000, 0x7da00804, 0x73800000,
+    0x7da00804, 0x734e0000, 0x7da08804, 0x40568000, 0x7dc00804, 0x61ec8000, 0x7da00804, 0x738b8000,
+    0x7da00804, 0x738d8000, 0x7da00804, 0x73908000, 0x7da00804, 0x73af0000, 0x7da00804, 0x73a98000,
+    0x7dc20804, 0x59e18000, 0x7dc00804, 0x5da48000, 0x7da00804, 0x73fd0000, 0x7da00804, 0x73c28000,
+    0x7da00804, 0x74290000, 0x7da00804, 0x74428000, 0x7dc20804, 0x5a368000, 0x7da00804, 0x74470000,
+    0x7da00804, 0x740f8000, 0x7da00804, 0x748a0000, 0x7dc00804, 0x5dce8000, 0x7da00804, 0x74a10000,
+    0x7da00804, 0x74d18000, 0x7da00804, 0x74f50000, 0x7da00804, 0x75540000, 0x7dc20804, 0x5b518000,
+    0x7da00804, 0x756d8000, 0x7dc00804, 0x5e0c0000, 0x7da00804, 0x75908000, 0x7dc20804, 0x5c538000,
+    0x7da00804, 0x75aa0000, 0x7dc00804, 0x5e270000, 0x7da00804, 0x75b90000, 0x7da00804, 0x75cf8000,
+    0x7da00804, 0x75dd0000, 0x7da00804, 0x75dd8000, 0x7dc20804, 0x5d468000, 0x7dc20804, 0x4e858000,
+    0x7dc20804, 0x5d7d0000, 0x7da00804, 0x76270000, 0x7dc20804, 0x5e5e0000, 0x7da00804, 0x765f8000,
+    0x7da00804, 0x76668000, 0x7da00804, 0x76338000, 0x7da00804, 0x768b0000, 0x7da00804, 0x769f0000,
+    0x7da00804, 0x76bb8000, 0x7da00804, 0x76a08000, 0x7da00804, 0x76b48000, 0x7da00804, 0x76bc0000,
+    0x7da00804, 0x76c28000, 0x7dc20804, 0x5e8f0000, 0x7da00804, 0x769a0000, 0x7da00804, 0x77178000,
+    0x7da00804, 0x77370000, 0x7dc00804, 0x5e998000, 0x7da00804, 0x77658000, 0x7da00804, 0x77638000,
+    0x7dc20804, 0x5f688000, 0x7da00804, 0x76fc8000, 0x7da00804, 0x77b70000, 0x7dc20804, 0x5faf0000,
+    0x7dc20804, 0x5fc70000, 0x7da00804, 0x77e30000, 0x7da00804, 0x781c8000, 0x7da00804, 0x780f0000,
+    0x7da00804, 0x780d8000, 0x7dc00804, 0x5ecb0000, 0x7da00804, 0x78250000, 0x7da00804, 0x783e8000,
+    0x7da00804, 0x783b8000, 0x7da00804, 0x78568000, 0x7dc20804, 0x42928000, 0x7da00804, 0x78a28000,
+    0x7dc20804, 0x61318000, 0x7da00804, 0x78ce0000, 0x7dc20804, 0x61d58000, 0x7da00804, 0x79140000,
+    0x7da00804, 0x791a8000, 0x7da00804, 0x79280000, 0x7dc20804, 0x63040000, 0x7da00804, 0x79400000,
+    0x7da00804, 0x794a8000, 0x7dc20804, 0x639a8000, 0x7dc20804, 0x640a0000, 0x7da00804, 0x79bd0000,
+    0x7da00804, 0x79c58000, 0x7dc00804, 0x5f560000, 0x7da00804, 0x79d28000, 0x7dc00804, 0x5f5c0000,
+    0x7dc00804, 0x5f5c0000, 0x7da00804, 0x7a238000, 0x7da00804, 0x7a2e0000, 0x7da00804, 0x7a388000,
+    0x7da00804, 0x7a428000, 0x7da00804, 0x7a650000, 0x7dc00804, 0x5f8d8000, 0x7da00804, 0x7a920000,
+    0x7dc20804, 0x661b0000, 0x7da00804, 0x7a9f0000, 0x7dc20804, 0x66490000, 0x7da00804, 0x7ab80000,
+    0x7dc20804, 0x50cf8000, 0x7da00804, 0x7b080000, 0x7dc20804, 0x67d08000, 0x7dc20804, 0x67dc0000,
+    0x7dc20804, 0x68220000, 0x7dc00804, 0x5ffe0000, 0x7dc00804, 0x60040000, 0x7da00804, 0x7b7a0000,
+    0x7dc20804, 0x68798000, 0x7dc20804, 0x68790000, 0x7dc20804, 0x688c8000, 0x7dc20804, 0x68998000,
+    0x7da00804, 0x7b8f0000, 0x7da00804, 0x7b8f8000, 0x7da00804, 0x7b8f8000, 0x7da00804, 0x7ba50000,
+    0x7dc00804, 0x601c8000, 0x7da00804, 0x7bc58000, 0x7dc00804, 0x60230000, 0x7dc00804, 0x604b0000,
+    0x7dc20804, 0x6a0e8000, 0x7da00804, 0x7c270000, 0x7da00804, 0x7c460000, 0x7da00804, 0x7c660000,
+    0x7dc00804, 0x60718000, 0x7dc20804, 0x6b130000, 0x7da00804, 0x7cab0000, 0x7dc20804, 0x6b4d0000,
+    0x7dc20804, 0x6b628000, 0x7da00804, 0x7cc78000, 0x7da00804, 0x7cf58000, 0x7dc00804, 0x60978000,
+    0x7da00804, 0x7d200000, 0x7da00804, 0x7d250000, 0x7da00804, 0x7d278000, 0x7dc20804, 0x6cbe0000,
+    0x7dc20804, 0x6d538000, 0x7dc20804, 0x6d538000, 0x7da00804, 0x7d770000, 0x7dc00804, 0x61010000,
+    0x7dc20804, 0x6dd58000, 0x7da00804, 0x7de30000, 0x7da00804, 0x7de48000, 0x7dc00804, 0x61138000,
+    0x7dc20804, 0x6e400000, 0x7da00804, 0x7e690000, 0x7dc00804, 0x61500000, 0x7da00804, 0x7e740000,
+    0x7da00804, 0x7e718000, 0x7da00804, 0x7e800000, 0x7dc20804, 0x6fc30000, 0x7da00804, 0x7eb18000,
+    0x7dc00804, 0x61808000, 0x7da00804, 0x7ee38000, 0x7da00804, 0x7f010000, 0x7da00804, 0x7f228000,
+    0x7dc00804, 0x619a0000, 0x7dc20804, 0x71140000, 0x7dc20804, 0x71238000, 0x7dc00804, 0x61ac8000,
+    0x7dc20804, 0x716c8000, 0x7da00804, 0x7fbd0000, 0x7dc20804, 0x719f0000, 0x7da00804, 0x7fca8000,
+    0x7da00804, 0x7ffd0000, 0x7da08804, 0x40028000, 0x7dc20804, 0x726d0000, 0x7dc20804, 0x72918000,
+    0x7da08804, 0x40300000, 0x7dc20804, 0x72d40000, 0x7da08804, 0x40380000, 0x7dc20804, 0x59af8000,
+    0x7dc00804, 0x61ea8000, 0x7da08804, 0x40590000, 0x7da08804, 0x40818000, 0x7dc00804, 0x62058000,
+    0x7da08804, 0x409f0000, 0x7da00804, 0x6d5a8000, 0x7dc20804, 0x73d38000, 0x7dc20804, 0x73da8000,
+    0x7dc20804, 0x59c98000, 0x7dc20804, 0x59ce0000, 0x7da08804, 0x41008000, 0x7da08804, 0x41020000,
+    0x7da08804, 0x47cf0000, 0x7dc00804, 0x62358000, 0x7da08804, 0x41488000, 0x7da08804, 0x41458000,
+    0x7da08804, 0x414e8000, 0x7da00804, 0x69598000, 0x7da08804, 0x41588000, 0x7da08804, 0x41598000,
+    0x7da08804, 0x415e8000, 0x7da08804, 0x41730000, 0x7dc20804, 0x759e0000, 0x7da08804, 0x41728000,
+    0x7da08804, 0x418e8000, 0x7da08804, 0x41b18000, 0x7da08804, 0x41d68000, 0x7da08804, 0x41918000,
+    0x7da08804, 0x41de8000, 0x7da08804, 0x41f38000, 0x7da08804, 0x422b8000, 0x7da08804, 0x41a98000,
+    0x7da08804, 0x41e50000, 0x7da08804, 0x41e60000, 0x7da08804, 0x41ee0000, 0x7dc20804, 0x761b0000,
+    0x7dc20804, 0x76b58000, 0x7dc20804, 0x766a8000, 0x7dc00804, 0x62958000, 0x7da08804, 0x42788000,
+    0x7da08804, 0x42798000, 0x7da08804, 0x428b0000, 0x7dc20804, 0x79e50000, 0x7da08804, 0x42b20000,
+    0x7dc20804, 0x77960000, 0x7dc00804, 0x62ae8000, 0x7dc00804, 0x62b08000, 0x7dc20804, 0x77d88000,
+    0x7dc20804, 0x78690000, 0x7dc00804, 0x62b58000, 0x7da08804, 0x43280000, 0x7da08804, 0x432e0000,
+    0x7da08804, 0x43338000, 0x7da08804, 0x43348000, 0x7da08804, 0x43548000, 0x7da08804, 0x43440000,
+    0x7da08804, 0x43870000, 0x7da08804, 0x43710000, 0x7da08804, 0x43bc8000, 0x7da08804, 0x43940000,
+    0x7da08804, 0x43b58000, 0x7da08804, 0x43c30000, 0x7dc00804, 0x62eb8000, 0x7da08804, 0x43f08000,
+    0x7da08804, 0x44008000, 0x7dc00804, 0x62fc8000, 0x7da08804, 0x44300000, 0x7da08804, 0x44318000,
+    0x7dc20804, 0x7b338000, 0x7da08804, 0x446b8000, 0x7da08804, 0x446f0000, 0x7dc00804, 0x631a8000,
+    0x7da08804, 0x447d0000, 0x7dc00804, 0x5a5d8000, 0x7dc20804, 0x7c570000, 0x7dc20804, 0x7cb30000,
+    0x7dc00804, 0x635f0000, 0x7dc00804, 0x63638000, 0x7da08804, 0x45500000, 0x7da08804, 0x45768000,
+    0x7da08804, 0x45c50000, 0x7da08804, 0x462a8000, 0x7dc20804, 0x7e540000, 0x7da08804, 0x46558000,
+    0x7da08804, 0x46608000, 0x7da08804, 0x468d8000, 0x7da08804, 0x46bb8000, 0x7dc20804, 0x7f978000,
+    0x7dc20804, 0x44020000, 0x7da08804, 0x46e58000, 0x7da08804, 0x46de0000, 0x7da08804, 0x46f80000,
+    0x7dc20804, 0x446f0000, 0x7da08804, 0x476a0000, 0x7da08804, 0x479c0000, 0x7dc28804, 0x42e90000,
+    0x7dc28804, 0x42f68000, 0x7da08804, 0x484a0000, 0x7da08804, 0x48788000, 0x7da08804, 0x48888000,
+    0x7dc28804, 0x43970000, 0x7da08804, 0x488d8000, 0x7da08804, 0x491c0000, 0x7da08804, 0x496b8000,
+    0x7da08804, 0x496c0000, 0x7da08804, 0x493e0000, 0x7da08804, 0x49fc8000, 0x7da08804, 0x4a0a8000,
+    0x7dc28804, 0x45fd0000, 0x7da08804, 0x4ac58000, 0x7dc00804, 0x64ca8000, 0x7da08804, 0x4adb8000,
+    0x7dc28804, 0x46bb8000, 0x7dc00804, 0x64f30000, 0x7da08804, 0x4b618000, 0x7da00804, 0x6ed90000,
+    0x7da08804, 0x4b918000, 0x7dc28804, 0x48a28000, 0x7dc28804, 0x490d0000, 0x7dc00804, 0x65370000,
+    0x7dc00804, 0x653b0000, 0x7da08804, 0x4bf00000, 0x7dc28804, 0x4a050000, 0x7dc00804, 0x65590000,
+    0x7dc28804, 0x4a4b0000, 0x7da08804, 0x4c058000, 0x7da08804, 0x4c058000, 0x7da08804, 0x4c148000,
+    0x7dc28804, 0x4adb0000, 0x7da08804, 0x4c710000, 0x7dc00804, 0x65998000, 0x7da08804, 0x4c948000,
+    0x7da08804, 0x4cd38000, 0x7da08804, 0x4ce10000, 0x7da08804, 0x4cff0000, 0x7dc00804, 0x65e70000,
+    0x7dc28804, 0x4d980000, 0x7da08804, 0x4d890000, 0x7da08804, 0x4e200000, 0x7da08804, 0x4e7e8000,
+    0x7dc00804, 0x66670000, 0x7dc00804, 0x66768000, 0x7da08804, 0x4eb38000, 0x7dc28804, 0x50670000,
+    0x7dc00804, 0x667c0000, 0x7dc28804, 0x50828000, 0x7dc28804, 0x51070000, 0x7dc28804, 0x51488000,
+    0x7da08804, 0x4f5d8000, 0x7dc00804, 0x66ab0000, 0x7da08804, 0x4f7c8000, 0x7da08804, 0x4f7f0000,
+    0x7da08804, 0x4f828000, 0x7da08804, 0x4f878000, 0x7da08804, 0x4f8b0000, 0x7da08804, 0x4f9d8000,
+    0x7dc28804, 0x53000000, 0x106b0810, 0x4604, 0x106b0810, 0x4604, 0x106b0804, 0x4604, 0x106b0804,
+    0x4604, 0x190b8804, 0x19288804, 0x190c0804, 0x19288804, 0x190c8804, 0x19288804, 0x190d0804,
+    0x19288804, 0x190d8804, 0x19288804, 0x190e0804, 0x19288804, 0x190e8804, 0x19288804, 0x190f0804,
+    0x19288804, 0x190f8804, 0x19288804, 0x19100804, 0x19288804, 0x19108804, 0x19288804, 0x19110804,
+    0x19288804, 0x19118804, 0x19288804, 0x19120804, 0x19288804, 0x19128804, 0x19288804, 0x19130804,
+    0x19288804, 0x19138804, 0x19288804, 0x19140804, 0x19288804, 0x19148804, 0x19288804, 0x19150804,
+    0x19288804, 0x19158804, 0x19288804, 0x19160804, 0x19288804, 0x19168804, 0x19288804, 0x19170804,
+    0x19288804, 0x19178804, 0x19288804, 0x19180804, 0x19288804, 0x19188804, 0x19288804, 0x19190804,
+    0x19288804, 0x19198804, 0x19288804, 0x191a0804, 0x19288804, 0x191a8804, 0x19288804, 0x191b0804,
+    0x19288804, 0x191b8804, 0x19288804, 0x191c0804, 0x19288804, 0x191c8804, 0x19288804, 0x191d0804,
+    0x19288804, 0x191d8804, 0x19288804, 0x191e0804, 0x19288804, 0x191e8804, 0x19288804, 0x191f0804,
+    0x19288804, 0x191f8804, 0x19288804, 0x19200804, 0x19288804, 0x19208804, 0x19288804, 0x19210804,
+    0x19288804, 0x19218804, 0x19288804, 0x19220804, 0x19288804, 0x190b8804, 0x19290804, 0x190c0804,
+    0x19290804, 0x190c8804, 0x19290804, 0x190d0804, 0x19290804, 0x190d8804, 0x19290804, 0x190e0804,
+    0x19290804, 0x190e8804, 0x19290804, 0x190f0804, 0x19290804, 0x190f8804, 0x19290804, 0x19100804,
+    0x19290804, 0x19108804, 0x19290804, 0x19110804, 0x19290804, 0x19118804, 0x19290804, 0x19120804,
+    0x19290804, 0x19128804, 0x19290804, 0x19130804, 0x19290804, 0x19138804, 0x19290804, 0x19140804,
+    0x19290804, 0x19148804, 0x19290804, 0x19150804, 0x19290804, 0x19158804, 0x19290804, 0x19160804,
+    0x19290804, 0x19168804, 0x19290804, 0x19170804, 0x19290804, 0x19178804, 0x19290804, 0x19180804,
+    0x19290804, 0x19188804, 0x19290804, 0x19190804, 0x19290804, 0x19198804, 0x19290804, 0x191a0804,
+    0x19290804, 0x191a8804, 0x19290804, 0x191b0804, 0x19290804, 0x191b8804, 0x19290804, 0x191c0804,
+    0x19290804, 0x191c8804, 0x19290804, 0x191d0804, 0x19290804, 0x191d8804, 0x19290804, 0x191e0804,
+    0x19290804, 0x191e8804, 0x19290804, 0x191f0804, 0x19290804, 0x191f8804, 0x19290804, 0x19200804,
+    0x19290804, 0x19208804, 0x19290804, 0x19210804, 0x19290804, 0x19218804, 0x19290804, 0x19220804,
+    0x19290804, 0x190b8804, 0x19298804, 0x190c0804, 0x19298804, 0x190c8804, 0x19298804, 0x190d0804,
+    0x19298804, 0x190d8804, 0x19298804, 0x190e0804, 0x19298804, 0x190e8804, 0x19298804, 0x190f0804,
+    0x19298804, 0x190f8804, 0x19298804, 0x19100804, 0x19298804, 0x19108804, 0x19298804, 0x19110804,
+    0x19298804, 0x19118804, 0x19298804, 0x19120804, 0x19298804, 0x19128804, 0x19298804, 0x19130804,
+    0x19298804, 0x19138804, 0x19298804, 0x19140804, 0x19298804, 0x19148804, 0x19298804, 0x19150804,
+    0x19298804, 0x19158804, 0x19298804, 0x19160804, 0x19298804, 0x19168804, 0x19298804, 0x19170804,
+    0x19298804, 0x19178804, 0x19298804, 0x19180804, 0x19298804, 0x19188804, 0x19298804, 0x19190804,
+    0x19298804, 0x19198804, 0x19298804, 0x191a0804, 0x19298804, 0x191a8804, 0x19298804, 0x191b0804,
+    0x19298804, 0x191b8804, 0x19298804, 0x191c0804, 0x19298804, 0x191c8804, 0x19298804, 0x191d0804,
+    0x19298804, 0x191d8804, 0x19298804, 0x191e0804, 0x19298804, 0x191e8804, 0x19298804, 0x191f0804,
+    0x19298804, 0x191f8804, 0x19298804, 0x19200804, 0x19298804, 0x19208804, 0x19298804, 0x19210804,
+    0x19298804, 0x19218804, 0x19298804, 0x19220804, 0x19298804, 0x190b8804, 0x192a0804, 0x190c0804,
+    0x192a0804, 0x190c8804, 0x192a0804, 0x190d0804, 0x192a0804, 0x190d8804, 0x192a0804, 0x190e0804,
+    0x192a0804, 0x190e8804, 0x192a0804, 0x190f0804, 0x192a0804, 0x190f8804, 0x192a0804, 0x19100804,
+    0x192a0804, 0x19108804, 0x192a0804, 0x19110804, 0x192a0804, 0x19118804, 0x192a0804, 0x19120804,
+    0x192a0804, 0x19128804, 0x192a0804, 0x19130804, 0x192a0804, 0x19138804, 0x192a0804, 0x19140804,
+    0x192a0804, 0x19148804, 0x192a0804, 0x19150804, 0x192a0804, 0x19158804, 0x192a0804, 0x19160804,
+    0x192a0804, 0x19168804, 0x192a0804, 0x19170804, 0x192a0804, 0x19178804, 0x192a0804, 0x19180804,
+    0x192a0804, 0x19188804, 0x192a0804, 0x19190804, 0x192a0804, 0x19198804, 0x192a0804, 0x191a0804,
+    0x192a0804, 0x191a8804, 0x192a0804, 0x191b0804, 0x192a0804, 0x191b8804, 0x192a0804, 0x191c0804,
+    0x192a0804, 0x191c8804, 0x192a0804, 0x191d0804, 0x192a0804, 0x191d8804, 0x192a0804, 0x191e0804,
+    0x192a0804, 0x191e8804, 0x192a0804, 0x191f0804, 0x192a0804, 0x191f8804, 0x192a0804, 0x19200804,
+    0x192a0804, 0x19208804, 0x192a0804, 0x19210804, 0x192a0804, 0x19218804, 0x192a0804, 0x19220804,
+    0x192a0804, 0x190b8804, 0x192a8804, 0x190c0804, 0x192a8804, 0x190c8804, 0x192a8804, 0x190d0804,
+    0x192a8804, 0x190d8804, 0x192a8804, 0x190e0804, 0x192a8804, 0x190e8804, 0x192a8804, 0x190f0804,
+    0x192a8804, 0x190f8804, 0x192a8804, 0x19100804, 0x192a8804, 0x19108804, 0x192a8804, 0x19110804,
+    0x192a8804, 0x19118804, 0x192a8804, 0x19120804, 0x192a8804, 0x19128804, 0x192a8804, 0x19130804,
+    0x192a8804, 0x19138804, 0x192a8804, 0x19140804, 0x192a8804, 0x19148804, 0x192a8804, 0x19150804,
+    0x192a8804, 0x19158804, 0x192a8804, 0x19160804, 0x192a8804, 0x19168804, 0x192a8804, 0x19170804,
+    0x192a8804, 0x19178804, 0x192a8804, 0x19180804, 0x192a8804, 0x19188804, 0x192a8804, 0x19190804,
+    0x192a8804, 0x19198804, 0x192a8804, 0x191a0804, 0x192a8804, 0x191a8804, 0x192a8804, 0x191b0804,
+    0x192a8804, 0x191b8804, 0x192a8804, 0x191c0804, 0x192a8804, 0x191c8804, 0x192a8804, 0x191d0804,
+    0x192a8804, 0x191d8804, 0x192a8804, 0x191e0804, 0x192a8804, 0x191e8804, 0x192a8804, 0x191f0804,
+    0x192a8804, 0x191f8804, 0x192a8804, 0x19200804, 0x192a8804, 0x19208804, 0x192a8804, 0x19210804,
+    0x192a8804, 0x19218804, 0x192a8804, 0x19220804, 0x192a8804, 0x192c0804, 0x19488804, 0x192c8804,
+    0x19488804, 0x192d0804, 0x19488804, 0x192d8804, 0x19488804, 0x192e0804, 0x19488804, 0x192e8804,
+    0x19488804, 0x192f0804, 0x19488804, 0x19300804, 0x19488804, 0x19308804, 0x19488804, 0x19320804,
+    0x19488804, 0x19310804, 0x19488804, 0x19328804, 0x19488804, 0x19330804, 0x19488804, 0x19338804,
+    0x19488804, 0x19340804, 0x19488804, 0x19348804, 0x19488804, 0x19350804, 0x19488804, 0x19358804,
+    0x19488804, 0x19360804, 0x19488804, 0x19368804, 0x19488804, 0x19370804, 0x19488804, 0x19378804,
+    0x19488804, 0x19380804, 0x19488804, 0x19388804, 0x19488804, 0x19390804, 0x19488804, 0x19398804,
+    0x19488804, 0x193a0804, 0x19488804, 0x193a8804, 0x19488804, 0x193b0804, 0x19488804, 0x193b8804,
+    0x19488804, 0x193c0804, 0x19488804, 0x193c8804, 0x19488804, 0x193d0804, 0x19488804, 0x193d8804,
+    0x19488804, 0x193e0804, 0x19488804, 0x193e8804, 0x19488804, 0x192f8804, 0x19488804, 0x193f0804,
+    0x19488804, 0x193f8804, 0x19488804, 0x19400804, 0x19488804, 0x19408804, 0x19488804, 0x193f0808,
+    0x19378808, 0x19488804, 0x193f0808, 0x193b8808, 0x19488804, 0x192b8804, 0x19488804, 0x19318804,
+    0x19488804, 0x192c0804, 0x19490804, 0x192c8804, 0x19490804, 0x192d0804, 0x19490804, 0x192d8804,
+    0x19490804, 0x192e0804, 0x19490804, 0x192e8804, 0x19490804, 0x192f0804, 0x19490804, 0x19300804,
+    0x19490804, 0x19308804, 0x19490804, 0x19320804, 0x19490804, 0x19310804, 0x19490804, 0x19328804,
+    0x19490804, 0x19330804, 0x19490804, 0x19338804, 0x19490804, 0x19340804, 0x19490804, 0x19348804,
+    0x19490804, 0x19350804, 0x19490804, 0x19358804, 0x19490804, 0x19360804, 0x19490804, 0x19368804,
+    0x19490804, 0x19370804, 0x19490804, 0x19378804, 0x19490804, 0x19380804, 0x19490804, 0x19388804,
+    0x19490804, 0x19390804, 0x19490804, 0x19398804, 0x19490804, 0x193a0804, 0x19490804, 0x193a8804,
+    0x19490804, 0x193b0804, 0x19490804, 0x193b8804, 0x19490804, 0x193c0804, 0x19490804, 0x193c8804,
+    0x19490804, 0x193d0804, 0x19490804, 0x193d8804, 0x19490804, 0x193e0804, 0x19490804, 0x193e8804,
+    0x19490804, 0x192f8804, 0x19490804, 0x193f0804, 0x19490804, 0x193f8804, 0x19490804, 0x19400804,
+    0x19490804, 0x19408804, 0x19490804, 0x193f0808, 0x19378808, 0x19490804, 0x193f0808, 0x193b8808,
+    0x19490804, 0x192b8804, 0x19490804, 0x19318804, 0x19490804, 0x192c0804, 0x19498804, 0x192c8804,
+    0x19498804, 0x192d0804, 0x19498804, 0x192d8804, 0x19498804, 0x192e0804, 0x19498804, 0x192e8804,
+    0x19498804, 0x192f0804, 0x19498804, 0x19300804, 0x19498804, 0x19308804, 0x19498804, 0x19320804,
+    0x19498804, 0x19310804, 0x19498804, 0x19328804, 0x19498804, 0x19330804, 0x19498804, 0x19338804,
+    0x19498804, 0x19340804, 0x19498804, 0x19348804, 0x19498804, 0x19350804, 0x19498804, 0x19358804,
+    0x19498804, 0x19360804, 0x19498804, 0x19368804, 0x19498804, 0x19370804, 0x19498804, 0x19378804,
+    0x19498804, 0x19380804, 0x19498804, 0x19388804, 0x19498804, 0x19390804, 0x19498804, 0x19398804,
+    0x19498804, 0x193a0804, 0x19498804, 0x193a8804, 0x19498804, 0x193b0804, 0x19498804, 0x193b8804,
+    0x19498804, 0x193c0804, 0x19498804, 0x193c8804, 0x19498804, 0x193d0804, 0x19498804, 0x193d8804,
+    0x19498804, 0x193e0804, 0x19498804, 0x193e8804, 0x19498804, 0x192f8804, 0x19498804, 0x193f0804,
+    0x19498804, 0x193f8804, 0x19498804, 0x19400804, 0x19498804, 0x19408804, 0x19498804, 0x193f0808,
+    0x19378808, 0x19498804, 0x193f0808, 0x193b8808, 0x19498804, 0x192b8804, 0x19498804, 0x19318804,
+    0x19498804, 0x192c0804, 0x194a0804, 0x192c8804, 0x194a0804, 0x192d0804, 0x194a0804, 0x192d8804,
+    0x194a0804, 0x192e0804, 0x194a0804, 0x192e8804, 0x194a0804, 0x192f0804, 0x194a0804, 0x19300804,
+    0x194a0804, 0x19308804, 0x194a0804, 0x19320804, 0x194a0804, 0x19310804, 0x194a0804, 0x19328804,
+    0x194a0804, 0x19330804, 0x194a0804, 0x19338804, 0x194a0804, 0x19340804, 0x194a0804, 0x19348804,
+    0x194a0804, 0x19350804, 0x194a0804, 0x19358804, 0x194a0804, 0x19360804, 0x194a0804, 0x19368804,
+    0x194a0804, 0x19370804, 0x194a0804, 0x19378804, 0x194a0804, 0x19380804, 0x194a0804, 0x19388804,
+    0x194a0804, 0x19390804, 0x194a0804, 0x19398804, 0x194a0804, 0x193a0804, 0x194a0804, 0x193a8804,
+    0x194a0804, 0x193b0804, 0x194a0804, 0x193b8804, 0x194a0804, 0x193c0804, 0x194a0804, 0x193c8804,
+    0x194a0804, 0x193d0804, 0x194a0804, 0x193d8804, 0x194a0804, 0x193e0804, 0x194a0804, 0x193e8804,
+    0x194a0804, 0x192f8804, 0x194a0804, 0x193f0804, 0x194a0804, 0x193f8804, 0x194a0804, 0x19400804,
+    0x194a0804, 0x19408804, 0x194a0804, 0x193f0808, 0x19378808, 0x194a0804, 0x193f0808, 0x193b8808,
+    0x194a0804, 0x192b8804, 0x194a0804, 0x19318804, 0x194a0804, 0x192c0804, 0x194a8804, 0x192c8804,
+    0x194a8804, 0x192d0804, 0x194a8804, 0x192d8804, 0x194a8804, 0x192e0804, 0x194a8804, 0x192e8804,
+    0x194a8804, 0x192f0804, 0x194a8804, 0x19300804, 0x194a8804, 0x19308804, 0x194a8804, 0x19320804,
+    0x194a8804, 0x19310804, 0x194a8804, 0x19328804, 0x194a8804, 0x19330804, 0x194a8804, 0x19338804,
+    0x194a8804, 0x19340804, 0x194a8804, 0x19348804, 0x194a8804, 0x19350804, 0x194a8804, 0x19358804,
+    0x194a8804, 0x19360804, 0x194a8804, 0x19368804, 0x194a8804, 0x19370804, 0x194a8804, 0x19378804,
+    0x194a8804, 0x19380804, 0x194a8804, 0x19388804, 0x194a8804, 0x19390804, 0x194a8804, 0x19398804,
+    0x194a8804, 0x193a0804, 0x194a8804, 0x193a8804, 0x194a8804, 0x193b0804, 0x194a8804, 0x193b8804,
+    0x194a8804, 0x193c0804, 0x194a8804, 0x193c8804, 0x194a8804, 0x193d0804, 0x194a8804, 0x193d8804,
+    0x194a8804, 0x193e0804, 0x194a8804, 0x193e8804, 0x194a8804, 0x192f8804, 0x194a8804, 0x193f0804,
+    0x194a8804, 0x193f8804, 0x194a8804, 0x19400804, 0x194a8804, 0x19408804, 0x194a8804, 0x193f0808,
+    0x19378808, 0x194a8804, 0x193f0808, 0x193b8808, 0x194a8804, 0x192b8804, 0x194a8804, 0x19318804,
+    0x194a8804, 0x1b6f0804, 0x1b878804, 0x1b6f8804, 0x1b878804, 0x1b700804, 0x1b878804, 0x1b708804,
+    0x1b878804, 0x1b710804, 0x1b878804, 0x1b718804, 0x1b878804, 0x1b720804, 0x1b878804, 0x1b728804,
+    0x1b878804, 0x1b730804, 0x1b878804, 0x1b738804, 0x1b878804, 0x1b740804, 0x1b878804, 0x1b748804,
+    0x1b878804, 0x1b750804, 0x1b878804, 0x1b758804, 0x1b878804, 0x1b760804, 0x1b878804, 0x1b768804,
+    0x1b878804, 0x1b770804, 0x1b878804, 0x1b778804, 0x1b878804, 0x1b780804, 0x1b878804, 0x1b788804,
+    0x1b878804, 0x1b790804, 0x1b878804, 0x1b798804, 0x1b878804, 0x1b7a0804, 0x1b878804, 0x1b7a8804,
+    0x1b878804, 0x1b7b0804, 0x1b878804, 0x1b7b8804, 0x1b878804, 0x1b7c0804, 0x1b878804, 0x1b7c8804,
+    0x1b878804, 0x1b7d0804, 0x1b878804, 0x1b7d8804, 0x1b878804, 0x1b7e0804, 0x1b878804, 0x1b7e8804,
+    0x1b878804, 0x1b7f0804, 0x1b878804, 0x1b7f8804, 0x1b878804, 0x1b800804, 0x1b878804, 0x1b808804,
+    0x1b878804, 0x1b810804, 0x1b878804, 0x1b818804, 0x1b878804, 0x1b820804, 0x1b878804, 0x1b828804,
+    0x1b878804, 0x1b830804, 0x1b878804, 0x1b838804, 0x1b878804, 0x1b840804, 0x1b878804, 0x1b848804,
+    0x1b878804, 0x1b6f0804, 0x1b880804, 0x1b6f8804, 0x1b880804, 0x1b700804, 0x1b880804, 0x1b708804,
+    0x1b880804, 0x1b710804, 0x1b880804, 0x1b718804, 0x1b880804, 0x1b720804, 0x1b880804, 0x1b728804,
+    0x1b880804, 0x1b730804, 0x1b880804, 0x1b738804, 0x1b880804, 0x1b740804, 0x1b880804, 0x1b748804,
+    0x1b880804, 0x1b750804, 0x1b880804, 0x1b758804, 0x1b880804, 0x1b760804, 0x1b880804, 0x1b768804,
+    0x1b880804, 0x1b770804, 0x1b880804, 0x1b778804, 0x1b880804, 0x1b780804, 0x1b880804, 0x1b788804,
+    0x1b880804, 0x1b790804, 0x1b880804, 0x1b798804, 0x1b880804, 0x1b7a0804, 0x1b880804, 0x1b7a8804,
+    0x1b880804, 0x1b7b0804, 0x1b880804, 0x1b7b8804, 0x1b880804, 0x1b7c0804, 0x1b880804, 0x1b7c8804,
+    0x1b880804, 0x1b7d0804, 0x1b880804, 0x1b7d8804, 0x1b880804, 0x1b7e0804, 0x1b880804, 0x1b7e8804,
+    0x1b880804, 0x1b7f0804, 0x1b880804, 0x1b7f8804, 0x1b880804, 0x1b800804, 0x1b880804, 0x1b808804,
+    0x1b880804, 0x1b810804, 0x1b880804, 0x1b818804, 0x1b880804, 0x1b820804, 0x1b880804, 0x1b828804,
+    0x1b880804, 0x1b830804, 0x1b880804, 0x1b838804, 0x1b880804, 0x1b840804, 0x1b880804, 0x1b848804,
+    0x1b880804, 0x1b6f0804, 0x1b888804, 0x1b6f8804, 0x1b888804, 0x1b700804, 0x1b888804, 0x1b708804,
+    0x1b888804, 0x1b710804, 0x1b888804, 0x1b718804, 0x1b888804, 0x1b720804, 0x1b888804, 0x1b728804,
+    0x1b888804, 0x1b730804, 0x1b888804, 0x1b738804, 0x1b888804, 0x1b740804, 0x1b888804, 0x1b748804,
+    0x1b888804, 0x1b750804, 0x1b888804, 0x1b758804, 0x1b888804, 0x1b760804, 0x1b888804, 0x1b768804,
+    0x1b888804, 0x1b770804, 0x1b888804, 0x1b778804, 0x1b888804, 0x1b780804, 0x1b888804, 0x1b788804,
+    0x1b888804, 0x1b790804, 0x1b888804, 0x1b798804, 0x1b888804, 0x1b7a0804, 0x1b888804, 0x1b7a8804,
+    0x1b888804, 0x1b7b0804, 0x1b888804, 0x1b7b8804, 0x1b888804, 0x1b7c0804, 0x1b888804, 0x1b7c8804,
+    0x1b888804, 0x1b7d0804, 0x1b888804, 0x1b7d8804, 0x1b888804, 0x1b7e0804, 0x1b888804, 0x1b7e8804,
+    0x1b888804, 0x1b7f0804, 0x1b888804, 0x1b7f8804, 0x1b888804, 0x1b800804, 0x1b888804, 0x1b808804,
+    0x1b888804, 0x1b810804, 0x1b888804, 0x1b818804, 0x1b888804, 0x1b820804, 0x1b888804, 0x1b828804,
+    0x1b888804, 0x1b830804, 0x1b888804, 0x1b838804, 0x1b888804, 0x1b840804, 0x1b888804, 0x1b848804,
+    0x1b888804, 0x1b6f0804, 0x1b8a0804, 0x1b6f8804, 0x1b8a0804, 0x1b700804, 0x1b8a0804, 0x1b708804,
+    0x1b8a0804, 0x1b710804, 0x1b8a0804, 0x1b718804, 0x1b8a0804, 0x1b720804, 0x1b8a0804, 0x1b728804,
+    0x1b8a0804, 0x1b730804, 0x1b8a0804, 0x1b738804, 0x1b8a0804, 0x1b740804, 0x1b8a0804, 0x1b748804,
+    0x1b8a0804, 0x1b750804, 0x1b8a0804, 0x1b758804, 0x1b8a0804, 0x1b760804, 0x1b8a0804, 0x1b768804,
+    0x1b8a0804, 0x1b770804, 0x1b8a0804, 0x1b778804, 0x1b8a0804, 0x1b780804, 0x1b8a0804, 0x1b788804,
+    0x1b8a0804, 0x1b790804, 0x1b8a0804, 0x1b798804, 0x1b8a0804, 0x1b7a0804, 0x1b8a0804, 0x1b7a8804,
+    0x1b8a0804, 0x1b7b0804, 0x1b8a0804, 0x1b7b8804, 0x1b8a0804, 0x1b7c0804, 0x1b8a0804, 0x1b7c8804,
+    0x1b8a0804, 0x1b7d0804, 0x1b8a0804, 0x1b7d8804, 0x1b8a0804, 0x1b7e0804, 0x1b8a0804, 0x1b7e8804,
+    0x1b8a0804, 0x1b7f0804, 0x1b8a0804, 0x1b7f8804, 0x1b8a0804, 0x1b800804, 0x1b8a0804, 0x1b808804,
+    0x1b8a0804, 0x1b810804, 0x1b8a0804, 0x1b818804, 0x1b8a0804, 0x1b820804, 0x1b8a0804, 0x1b828804,
+    0x1b8a0804, 0x1b830804, 0x1b8a0804, 0x1b838804, 0x1b8a0804, 0x1b840804, 0x1b8a0804, 0x1b848804,
+    0x1b8a0804, 0x194b0804, 0x19658804, 0x194b8804, 0x19658804, 0x194c0804, 0x19658804, 0x194c8804,
+    0x19658804, 0x194d0804, 0x19658804, 0x194d8804, 0x19658804, 0x194e0804, 0x19658804, 0x194e8804,
+    0x19658804, 0x194f0804, 0x19658804, 0x194f8804, 0x19658804, 0x19500804, 0x19658804, 0x19508804,
+    0x19658804, 0x19510804, 0x19658804, 0x19518804, 0x19658804, 0x19520804, 0x19658804, 0x19528804,
+    0x19658804, 0x19530804, 0x19658804, 0x19538804, 0x19658804, 0x19540804, 0x19658804, 0x19548804,
+    0x19658804, 0x19550804, 0x19658804, 0x19558804, 0x19658804, 0x19560804, 0x19658804, 0x19568804,
+    0x19658804, 0x19570804, 0x19658804, 0x19578804, 0x19658804, 0x19580804, 0x19658804, 0x19588804,
+    0x19658804, 0x19590804, 0x19658804, 0x19598804, 0x19658804, 0x195a0804, 0x19658804, 0x195a8804,
+    0x19658804, 0x195b0804, 0x19658804, 0x195b8804, 0x19658804, 0x195c0804, 0x19658804, 0x195c8804,
+    0x19658804, 0x195d0804, 0x19658804, 0x195d8804, 0x19658804, 0x195e0804, 0x19658804, 0x195e8804,
+    0x19658804, 0x195f0804, 0x19658804, 0x195f8804, 0x19658804, 0x19600804, 0x19658804, 0x19608804,
+    0x19658804, 0x19610804, 0x19658804, 0x19618804, 0x19658804, 0x19620804, 0x19658804, 0x19628804,
+    0x19658804, 0x194b0804, 0x19660804, 0x194b8804, 0x19660804, 0x194c0804, 0x19660804, 0x194c8804,
+    0x19660804, 0x194d0804, 0x19660804, 0x194d8804, 0x19660804, 0x194e0804, 0x19660804, 0x194e8804,
+    0x19660804, 0x194f0804, 0x19660804, 0x194f8804, 0x19660804, 0x19500804, 0x19660804, 0x19508804,
+    0x19660804, 0x19510804, 0x19660804, 0x19518804, 0x19660804, 0x19520804, 0x19660804, 0x19528804,
+    0x19660804, 0x19530804, 0x19660804, 0x19538804, 0x19660804, 0x19540804, 0x19660804, 0x19548804,
+    0x19660804, 0x19550804, 0x19660804, 0x19558804, 0x19660804, 0x19560804, 0x19660804, 0x19568804,
+    0x19660804, 0x19570804, 0x19660804, 0x19578804, 0x19660804, 0x19580804, 0x19660804, 0x19588804,
+    0x19660804, 0x19590804, 0x19660804, 0x19598804, 0x19660804, 0x195a0804, 0x19660804, 0x195a8804,
+    0x19660804, 0x195b0804, 0x19660804, 0x195b8804, 0x19660804, 0x195c0804, 0x19660804, 0x195c8804,
+    0x19660804, 0x195d0804, 0x19660804, 0x195d8804, 0x19660804, 0x195e0804, 0x19660804, 0x195e8804,
+    0x19660804, 0x195f0804, 0x19660804, 0x195f8804, 0x19660804, 0x19600804, 0x19660804, 0x19608804,
+    0x19660804, 0x19610804, 0x19660804, 0x19618804, 0x19660804, 0x19620804, 0x19660804, 0x19628804,
+    0x19660804, 0x194b0804, 0x19678804, 0x194b8804, 0x19678804, 0x194c0804, 0x19678804, 0x194c8804,
+    0x19678804, 0x194d0804, 0x19678804, 0x194d8804, 0x19678804, 0x194e0804, 0x19678804, 0x194e8804,
+    0x19678804, 0x194f0804, 0x19678804, 0x194f8804, 0x19678804, 0x19500804, 0x19678804, 0x19508804,
+    0x19678804, 0x19510804, 0x19678804, 0x19518804, 0x19678804, 0x19520804, 0x19678804, 0x19528804,
+    0x19678804, 0x19530804, 0x19678804, 0x19538804, 0x19678804, 0x19540804, 0x19678804, 0x19548804,
+    0x19678804, 0x19550804, 0x19678804, 0x19558804, 0x19678804, 0x19560804, 0x19678804, 0x19568804,
+    0x19678804, 0x19570804, 0x19678804, 0x19578804, 0x19678804, 0x19580804, 0x19678804, 0x19588804,
+    0x19678804, 0x19590804, 0x19678804, 0x19598804, 0x19678804, 0x195a0804, 0x19678804, 0x195a8804,
+    0x19678804, 0x195b0804, 0x19678804, 0x195b8804, 0x19678804, 0x195c0804, 0x19678804, 0x195c8804,
+    0x19678804, 0x195d0804, 0x19678804, 0x195d8804, 0x19678804, 0x195e0804, 0x19678804, 0x195e8804,
+    0x19678804, 0x195f0804, 0x19678804, 0x195f8804, 0x19678804, 0x19600804, 0x19678804, 0x19608804,
+    0x19678804, 0x19610804, 0x19678804, 0x19618804, 0x19678804, 0x19620804, 0x19678804, 0x19628804,
+    0x19678804, 0x194b0804, 0x19688804, 0x194b8804, 0x19688804, 0x194c0804, 0x19688804, 0x194c8804,
+    0x19688804, 0x194d0804, 0x19688804, 0x194d8804, 0x19688804, 0x194e0804, 0x19688804, 0x194e8804,
+    0x19688804, 0x194f0804, 0x19688804, 0x194f8804, 0x19688804, 0x19500804, 0x19688804, 0x19508804,
+    0x19688804, 0x19510804, 0x19688804, 0x19518804, 0x19688804, 0x19520804, 0x19688804, 0x19528804,
+    0x19688804, 0x19530804, 0x19688804, 0x19538804, 0x19688804, 0x19540804, 0x19688804, 0x19548804,
+    0x19688804, 0x19550804, 0x19688804, 0x19558804, 0x19688804, 0x19560804, 0x19688804, 0x19568804,
+    0x19688804, 0x19570804, 0x19688804, 0x19578804, 0x19688804, 0x19580804, 0x19688804, 0x19588804,
+    0x19688804, 0x19590804, 0x19688804, 0x19598804, 0x19688804, 0x195a0804, 0x19688804, 0x195a8804,
+    0x19688804, 0x195b0804, 0x19688804, 0x195b8804, 0x19688804, 0x195c0804, 0x19688804, 0x195c8804,
+    0x19688804, 0x195d0804, 0x19688804, 0x195d8804, 0x19688804, 0x195e0804, 0x19688804, 0x195e8804,
+    0x19688804, 0x195f0804, 0x19688804, 0x195f8804, 0x19688804, 0x19600804, 0x19688804, 0x19608804,
+    0x19688804, 0x19610804, 0x19688804, 0x19618804, 0x19688804, 0x19620804, 0x19688804, 0x19628804,
+    0x19688804, 0x194b0804, 0x19690804, 0x194b8804, 0x19690804, 0x194c0804, 0x19690804, 0x194c8804,
+    0x19690804, 0x194d0804, 0x19690804, 0x194d8804, 0x19690804, 0x194e0804, 0x19690804, 0x194e8804,
+    0x19690804, 0x194f0804, 0x19690804, 0x194f8804, 0x19690804, 0x19500804, 0x19690804, 0x19508804,
+    0x19690804, 0x19510804, 0x19690804, 0x19518804, 0x19690804, 0x19520804, 0x19690804, 0x19528804,
+    0x19690804, 0x19530804, 0x19690804, 0x19538804, 0x19690804, 0x19540804, 0x19690804, 0x19548804,
+    0x19690804, 0x19550804, 0x19690804, 0x19558804, 0x19690804, 0x19560804, 0x19690804, 0x19568804,
+    0x19690804, 0x19570804, 0x19690804, 0x19578804, 0x19690804, 0x19580804, 0x19690804, 0x19588804,
+    0x19690804, 0x19590804, 0x19690804, 0x19598804, 0x19690804, 0x195a0804, 0x19690804, 0x195a8804,
+    0x19690804, 0x195b0804, 0x19690804, 0x195b8804, 0x19690804, 0x195c0804, 0x19690804, 0x195c8804,
+    0x19690804, 0x195d0804, 0x19690804, 0x195d8804, 0x19690804, 0x195e0804, 0x19690804, 0x195e8804,
+    0x19690804, 0x195f0804, 0x19690804, 0x195f8804, 0x19690804, 0x19600804, 0x19690804, 0x19608804,
+    0x19690804, 0x19610804, 0x19690804, 0x19618804, 0x19690804, 0x19620804, 0x19690804, 0x19628804,
+    0x19690804,
+];
+
+/// Sequences of two or three characters that collate as one, in order, with pairs ending
+/// in 0
+pub const CONTRACTIONS: [[u32; 3]; 939] = [
+    [0x4c, 0xb7, 0x0],
+    [0x4c, 0x387, 0x0],
+    [0x6c, 0xb7, 0x0],
+    [0x6c, 0x387, 0x0],
+    [0x418, 0x306, 0x0],
+    [0x438, 0x306, 0x0],
+    [0x627, 0x653, 0x0],
+    [0x627, 0x654, 0x0],
+    [0x627, 0x655, 0x0],
+    [0x648, 0x654, 0x0],
+    [0x64a, 0x654, 0x0],
+    [0x9c7, 0x9be, 0x0],
+    [0x9c7, 0x9d7, 0x0],
+    [0xb47, 0xb3e, 0x0],
+    [0xb47, 0xb56, 0x0],
+    [0xb47, 0xb57, 0x0],
+    [0xb92, 0xbd7, 0x0],
+    [0xbc6, 0xbbe, 0x0],
+    [0xbc6, 0xbd7, 0x0],
+    [0xbc7, 0xbbe, 0x0],
+    [0xc46, 0xc56, 0x0],
+    [0xcbf, 0xcd5, 0x0],
+    [0xcc6, 0xcc2, 0x0],
+    [0xcc6, 0xcc2, 0xcd5],
+    [0xcc6, 0xcd5, 0x0],
+    [0xcc6, 0xcd6, 0x0],
+    [0xcca, 0xcd5, 0x0],
+    [0xd46, 0xd3e, 0x0],
+    [0xd46, 0xd57, 0x0],
+    [0xd47, 0xd3e, 0x0],
+    [0xdd9, 0xdca, 0x0],
+    [0xdd9, 0xdcf, 0x0],
+    [0xdd9, 0xdcf, 0xdca],
+    [0xdd9, 0xddf, 0x0],
+    [0xddc, 0xdca, 0x0],
+    [0xe40, 0xe01, 0x0],
+    [0xe40, 0xe02, 0x0],
+    [0xe40, 0xe03, 0x0],
+    [0xe40, 0xe04, 0x0],
+    [0xe40, 0xe05, 0x0],
+    [0xe40, 0xe06, 0x0],
+    [0xe40, 0xe07, 0x0],
+    [0xe40, 0xe08, 0x0],
+    [0xe40, 0xe09, 0x0],
+    [0xe40, 0xe0a, 0x0],
+    [0xe40, 0xe0b, 0x0],
+    [0xe40, 0xe0c, 0x0],
+    [0xe40, 0xe0d, 0x0],
+    [0xe40, 0xe0e, 0x0],
+    [0xe40, 0xe0f, 0x0],
+    [0xe40, 0xe10, 0x0],
+    [0xe40, 0xe11, 0x0],
+    [0xe40, 0xe12, 0x0],
+    [0xe40, 0xe13, 0x0],
+    [0xe40, 0xe14, 0x0],
+    [0xe40, 0xe15, 0x0],
+    [0xe40, 0xe16, 0x0],
+    [0xe40, 0xe17, 0x0],
+    [0xe40, 0xe18, 0x0],
+    [0xe40, 0xe19, 0x0],
+    [0xe40, 0xe1a, 0x0],
+    [0xe40, 0xe1b, 0x0],
+    [0xe40, 0xe1c, 0x0],
+    [0xe40, 0xe1d, 0x0],
+    [0xe40, 0xe1e, 0x0],
+    [0xe40, 0xe1f, 0x0],
+    [0xe40, 0xe20, 0x0],
+    [0xe40, 0xe21, 0x0],
+    [0xe40, 0xe22, 0x0],
+    [0xe40, 0xe23, 0x0],
+    [0xe40, 0xe24, 0x0],
+    [0xe40, 0xe25, 0x0],
+    [0xe40, 0xe26, 0x0],
+    [0xe40, 0xe27, 0x0],
+    [0xe40, 0xe28, 0x0],
+    [0xe40, 0xe29, 0x0],
+    [0xe40, 0xe2a, 0x0],
+    [0xe40, 0xe2b, 0x0],
+    [0xe40, 0xe2c, 0x0],
+    [0xe40, 0xe2d, 0x0],
+    [0xe40, 0xe2e, 0x0],
+    [0xe41, 0xe01, 0x0],
+    [0xe41, 0xe02, 0x0],
+    [0xe41, 0xe03, 0x0],
+    [0xe41, 0xe04, 0x0],
+    [0xe41, 0xe05, 0x0],
+    [0xe41, 0xe06, 0x0],
+    [0xe41, 0xe07, 0x0],
+    [0xe41, 0xe08, 0x0],
+    [0xe41, 0xe09, 0x0],
+    [0xe41, 0xe0a, 0x0],
+    [0xe41, 0xe0b, 0x0],
+    [0xe41, 0xe0c, 0x0],
+    [0xe41, 0xe0d, 0x0],
+    [0xe41, 0xe0e, 0x0],
+    [0xe41, 0xe0f, 0x0],
+    [0xe41, 0xe10, 0x0],
+    [0xe41, 0xe11, 0x0],
+    [0xe41, 0xe12, 0x0],
+    [0xe41, 0xe13, 0x0],
+    [0xe41, 0xe14, 0x0],
+    [0xe41, 0xe15, 0x0],
+    [0xe41, 0xe16, 0x0],
+    [0xe41, 0xe17, 0x0],
+    [0xe41, 0xe18, 0x0],
+    [0xe41, 0xe19, 0x0],
+    [0xe41, 0xe1a, 0x0],
+    [0xe41, 0xe1b, 0x0],
+    [0xe41, 0xe1c, 0x0],
+    [0xe41, 0xe1d, 0x0],
+    [0xe41, 0xe1e, 0x0],
+    [0xe41, 0xe1f, 0x0],
+    [0xe41, 0xe20, 0x0],
+    [0xe41, 0xe21, 0x0],
+    [0xe41, 0xe22, 0x0],
+    [0xe41, 0xe23, 0x0],
+    [0xe41, 0xe24, 0x0],
+    [0xe41, 0xe25, 0x0],
+    [0xe41, 0xe26, 0x0],
+    [0xe41, 0xe27, 0x0],
+    [0xe41, 0xe28, 0x0],
+    [0xe41, 0xe29, 0x0],
+    [0xe41, 0xe2a, 0x0],
+    [0xe41, 0xe2b, 0x0],
+    [0xe41, 0xe2c, 0x0],
+    [0xe41, 0xe2d, 0x0],
+    [0xe41, 0xe2e, 0x0],
+    [0xe42, 0xe01, 0x0],
+    [0xe42, 0xe02, 0x0],
+    [0xe42, 0xe03, 0x0],
+    [0xe42, 0xe04, 0x0],
+    [0xe42, 0xe05, 0x0],
+    [0xe42, 0xe06, 0x0],
+    [0xe42, 0xe07, 0x0],
+    [0xe42, 0xe08, 0x0],
+    [0xe42, 0xe09, 0x0],
+    [0xe42, 0xe0a, 0x0],
+    [0xe42, 0xe0b, 0x0],
+    [0xe42, 0xe0c, 0x0],
+    [0xe42, 0xe0d, 0x0],
+    [0xe42, 0xe0e, 0x0],
+    [0xe42, 0xe0f, 0x0],
+    [0xe42, 0xe10, 0x0],
+    [0xe42, 0xe11, 0x0],
+    [0xe42, 0xe12, 0x0],
+    [0xe42, 0xe13, 0x0],
+    [0xe42, 0xe14, 0x0],
+    [0xe42, 0xe15, 0x0],
+    [0xe42, 0xe16, 0x0],
+    [0xe42, 0xe17, 0x0],
+    [0xe42, 0xe18, 0x0],
+    [0xe42, 0xe19, 0x0],
+    [0xe42, 0xe1a, 0x0],
+    [0xe42, 0xe1b, 0x0],
+    [0xe42, 0xe1c, 0x0],
+    [0xe42, 0xe1d, 0x0],
+    [0xe42, 0xe1e, 0x0],
+    [0xe42, 0xe1f, 0x0],
+    [0xe42, 0xe20, 0x0],
+    [0xe42, 0xe21, 0x0],
+    [0xe42, 0xe22, 0x0],
+    [0xe42, 0xe23, 0x0],
+    [0xe42, 0xe24, 0x0],
+    [0xe42, 0xe25, 0x0],
+    [0xe42, 0xe26, 0x0],
+    [0xe42, 0xe27, 0x0],
+    [0xe42, 0xe28, 0x0],
+    [0xe42, 0xe29, 0x0],
+    [0xe42, 0xe2a, 0x0],
+    [0xe42, 0xe2b, 0x0],
+    [0xe42, 0xe2c, 0x0],
+    [0xe42, 0xe2d, 0x0],
+    [0xe42, 0xe2e, 0x0],
+    [0xe43, 0xe01, 0x0],
+    [0xe43, 0xe02, 0x0],
+    [0xe43, 0xe03, 0x0],
+    [0xe43, 0xe04, 0x0],
+    [0xe43, 0xe05, 0x0],
+    [0xe43, 0xe06, 0x0],
+    [0xe43, 0xe07, 0x0],
+    [0xe43, 0xe08, 0x0],
+    [0xe43, 0xe09, 0x0],
+    [0xe43, 0xe0a, 0x0],
+    [0xe43, 0xe0b, 0x0],
+    [0xe43, 0xe0c, 0x0],
+    [0xe43, 0xe0d, 0x0],
+    [0xe43, 0xe0e, 0x0],
+    [0xe43, 0xe0f, 0x0],
+    [0xe43, 0xe10, 0x0],
+    [0xe43, 0xe11, 0x0],
+    [0xe43, 0xe12, 0x0],
+    [0xe43, 0xe13, 0x0],
+    [0xe43, 0xe14, 0x0],
+    [0xe43, 0xe15, 0x0],
+    [0xe43, 0xe16, 0x0],
+    [0xe43, 0xe17, 0x0],
+    [0xe43, 0xe18, 0x0],
+    [0xe43, 0xe19, 0x0],
+    [0xe43, 0xe1a, 0x0],
+    [0xe43, 0xe1b, 0x0],
+    [0xe43, 0xe1c, 0x0],
+    [0xe43, 0xe1d, 0x0],
+    [0xe43, 0xe1e, 0x0],
+    [0xe43, 0xe1f, 0x0],
+    [0xe43, 0xe20, 0x0],
+    [0xe43, 0xe21, 0x0],
+    [0xe43, 0xe22, 0x0],
+    [0xe43, 0xe23, 0x0],
+    [0xe43, 0xe24, 0x0],
+    [0xe43, 0xe25, 0x0],
+    [0xe43, 0xe26, 0x0],
+    [0xe43, 0xe27, 0x0],
+    [0xe43, 0xe28, 0x0],
+    [0xe43, 0xe29, 0x0],
+    [0xe43, 0xe2a, 0x0],
+    [0xe43, 0xe2b, 0x0],
+    [0xe43, 0xe2c, 0x0],
+    [0xe43, 0xe2d, 0x0],
+    [0xe43, 0xe2e, 0x0],
+    [0xe44, 0xe01, 0x0],
+    [0xe44, 0xe02, 0x0],
+    [0xe44, 0xe03, 0x0],
+    [0xe44, 0xe04, 0x0],
+    [0xe44, 0xe05, 0x0],
+    [0xe44, 0xe06, 0x0],
+    [0xe44, 0xe07, 0x0],
+    [0xe44, 0xe08, 0x0],
+    [0xe44, 0xe09, 0x0],
+    [0xe44, 0xe0a, 0x0],
+    [0xe44, 0xe0b, 0x0],
+    [0xe44, 0xe0c, 0x0],
+    [0xe44, 0xe0d, 0x0],
+    [0xe44, 0xe0e, 0x0],
+    [0xe44, 0xe0f, 0x0],
+    [0xe44, 0xe10, 0x0],
+    [0xe44, 0xe11, 0x0],
+    [0xe44, 0xe12, 0x0],
+    [0xe44, 0xe13, 0x0],
+    [0xe44, 0xe14, 0x0],
+    [0xe44, 0xe15, 0x0],
+    [0xe44, 0xe16, 0x0],
+    [0xe44, 0xe17, 0x0],
+    [0xe44, 0xe18, 0x0],
+    [0xe44, 0xe19, 0x0],
+    [0xe44, 0xe1a, 0x0],
+    [0xe44, 0xe1b, 0x0],
+    [0xe44, 0xe1c, 0x0],
+    [0xe44, 0xe1d, 0x0],
+    [0xe44, 0xe1e, 0x0],
+    [0xe44, 0xe1f, 0x0],
+    [0xe44, 0xe20, 0x0],
+    [0xe44, 0xe21, 0x0],
+    [0xe44, 0xe22, 0x0],
+    [0xe44, 0xe23, 0x0],
+    [0xe44, 0xe24, 0x0],
+    [0xe44, 0xe25, 0x0],
+    [0xe44, 0xe26, 0x0],
+    [0xe44, 0xe27, 0x0],
+    [0xe44, 0xe28, 0x0],
+    [0xe44, 0xe29, 0x0],
+    [0xe44, 0xe2a, 0x0],
+    [0xe44, 0xe2b, 0x0],
+    [0xe44, 0xe2c, 0x0],
+    [0xe44, 0xe2d, 0x0],
+    [0xe44, 0xe2e, 0x0],
+    [0xe4d, 0xe32, 0x0],
+    [0xec0, 0xe81, 0x0],
+    [0xec0, 0xe82, 0x0],
+    [0xec0, 0xe84, 0x0],
+    [0xec0, 0xe86, 0x0],
+    [0xec0, 0xe87, 0x0],
+    [0xec0, 0xe88, 0x0],
+    [0xec0, 0xe89, 0x0],
+    [0xec0, 0xe8a, 0x0],
+    [0xec0, 0xe8c, 0x0],
+    [0xec0, 0xe8d, 0x0],
+    [0xec0, 0xe8e, 0x0],
+    [0xec0, 0xe8f, 0x0],
+    [0xec0, 0xe90, 0x0],
+    [0xec0, 0xe91, 0x0],
+    [0xec0, 0xe92, 0x0],
+    [0xec0, 0xe93, 0x0],
+    [0xec0, 0xe94, 0x0],
+    [0xec0, 0xe95, 0x0],
+    [0xec0, 0xe96, 0x0],
+    [0xec0, 0xe97, 0x0],
+    [0xec0, 0xe98, 0x0],
+    [0xec0, 0xe99, 0x0],
+    [0xec0, 0xe9a, 0x0],
+    [0xec0, 0xe9b, 0x0],
+    [0xec0, 0xe9c, 0x0],
+    [0xec0, 0xe9d, 0x0],
+    [0xec0, 0xe9e, 0x0],
+    [0xec0, 0xe9f, 0x0],
+    [0xec0, 0xea0, 0x0],
+    [0xec0, 0xea1, 0x0],
+    [0xec0, 0xea2, 0x0],
+    [0xec0, 0xea3, 0x0],
+    [0xec0, 0xea5, 0x0],
+    [0xec0, 0xea7, 0x0],
+    [0xec0, 0xea8, 0x0],
+    [0xec0, 0xea9, 0x0],
+    [0xec0, 0xeaa, 0x0],
+    [0xec0, 0xeab, 0x0],
+    [0xec0, 0xeac, 0x0],
+    [0xec0, 0xead, 0x0],
+    [0xec0, 0xeae, 0x0],
+    [0xec0, 0xedc, 0x0],
+    [0xec0, 0xedd, 0x0],
+    [0xec0, 0xede, 0x0],
+    [0xec0, 0xedf, 0x0],
+    [0xec1, 0xe81, 0x0],
+    [0xec1, 0xe82, 0x0],
+    [0xec1, 0xe84, 0x0],
+    [0xec1, 0xe86, 0x0],
+    [0xec1, 0xe87, 0x0],
+    [0xec1, 0xe88, 0x0],
+    [0xec1, 0xe89, 0x0],
+    [0xec1, 0xe8a, 0x0],
+    [0xec1, 0xe8c, 0x0],
+    [0xec1, 0xe8d, 0x0],
+    [0xec1, 0xe8e, 0x0],
+    [0xec1, 0xe8f, 0x0],
+    [0xec1, 0xe90, 0x0],
+    [0xec1, 0xe91, 0x0],
+    [0xec1, 0xe92, 0x0],
+    [0xec1, 0xe93, 0x0],
+    [0xec1, 0xe94, 0x0],
+    [0xec1, 0xe95, 0x0],
+    [0xec1, 0xe96, 0x0],
+    [0xec1, 0xe97, 0x0],
+    [0xec1, 0xe98, 0x0],
+    [0xec1, 0xe99, 0x0],
+    [0xec1, 0xe9a, 0x0],
+    [0xec1, 0xe9b, 0x0],
+    [0xec1, 0xe9c, 0x0],
+    [0xec1, 0xe9d, 0x0],
+    [0xec1, 0xe9e, 0x0],
+    [0xec1, 0xe9f, 0x0],
+    [0xec1, 0xea0, 0x0],
+    [0xec1, 0xea1, 0x0],
+    [0xec1, 0xea2, 0x0],
+    [0xec1, 0xea3, 0x0],
+    [0xec1, 0xea5, 0x0],
+    [0xec1, 0xea7, 0x0],
+    [0xec1, 0xea8, 0x0],
+    [0xec1, 0xea9, 0x0],
+    [0xec1, 0xeaa, 0x0],
+    [0xec1, 0xeab, 0x0],
+    [0xec1, 0xeac, 0x0],
+    [0xec1, 0xead, 0x0],
+    [0xec1, 0xeae, 0x0],
+    [0xec1, 0xedc, 0x0],
+    [0xec1, 0xedd, 0x0],
+    [0xec1, 0xede, 0x0],
+    [0xec1, 0xedf, 0x0],
+    [0xec2, 0xe81, 0x0],
+    [0xec2, 0xe82, 0x0],
+    [0xec2, 0xe84, 0x0],
+    [0xec2, 0xe86, 0x0],
+    [0xec2, 0xe87, 0x0],
+    [0xec2, 0xe88, 0x0],
+    [0xec2, 0xe89, 0x0],
+    [0xec2, 0xe8a, 0x0],
+    [0xec2, 0xe8c, 0x0],
+    [0xec2, 0xe8d, 0x0],
+    [0xec2, 0xe8e, 0x0],
+    [0xec2, 0xe8f, 0x0],
+    [0xec2, 0xe90, 0x0],
+    [0xec2, 0xe91, 0x0],
+    [0xec2, 0xe92, 0x0],
+    [0xec2, 0xe93, 0x0],
+    [0xec2, 0xe94, 0x0],
+    [0xec2, 0xe95, 0x0],
+    [0xec2, 0xe96, 0x0],
+    [0xec2, 0xe97, 0x0],
+    [0xec2, 0xe98, 0x0],
+    [0xec2, 0xe99, 0x0],
+    [0xec2, 0xe9a, 0x0],
+    [0xec2, 0xe9b, 0x0],
+    [0xec2, 0xe9c, 0x0],
+    [0xec2, 0xe9d, 0x0],
+    [0xec2, 0xe9e, 0x0],
+    [0xec2, 0xe9f, 0x0],
+    [0xec2, 0xea0, 0x0],
+    [0xec2, 0xea1, 0x0],
+    [0xec2, 0xea2, 0x0],
+    [0xec2, 0xea3, 0x0],
+    [0xec2, 0xea5, 0x0],
+    [0xec2, 0xea7, 0x0],
+    [0xec2, 0xea8, 0x0],
+    [0xec2, 0xea9, 0x0],
+    [0xec2, 0xeaa, 0x0],
+    [0xec2, 0xeab, 0x0],
+    [0xec2, 0xeac, 0x0],
+    [0xec2, 0xead, 0x0],
+    [0xec2, 0xeae, 0x0],
+    [0xec2, 0xedc, 0x0],
+    [0xec2, 0xedd, 0x0],
+    [0xec2, 0xede, 0x0],
+    [0xec2, 0xedf, 0x0],
+    [0xec3, 0xe81, 0x0],
+    [0xec3, 0xe82, 0x0],
+    [0xec3, 0xe84, 0x0],
+    [0xec3, 0xe86, 0x0],
+    [0xec3, 0xe87, 0x0],
+    [0xec3, 0xe88, 0x0],
+    [0xec3, 0xe89, 0x0],
+    [0xec3, 0xe8a, 0x0],
+    [0xec3, 0xe8c, 0x0],
+    [0xec3, 0xe8d, 0x0],
+    [0xec3, 0xe8e, 0x0],
+    [0xec3, 0xe8f, 0x0],
+    [0xec3, 0xe90, 0x0],
+    [0xec3, 0xe91, 0x0],
+    [0xec3, 0xe92, 0x0],
+    [0xec3, 0xe93, 0x0],
+    [0xec3, 0xe94, 0x0],
+    [0xec3, 0xe95, 0x0],
+    [0xec3, 0xe96, 0x0],
+    [0xec3, 0xe97, 0x0],
+    [0xec3, 0xe98, 0x0],
+    [0xec3, 0xe99, 0x0],
+    [0xec3, 0xe9a, 0x0],
+    [0xec3, 0xe9b, 0x0],
+    [0xec3, 0xe9c, 0x0],
+    [0xec3, 0xe9d, 0x0],
+    [0xec3, 0xe9e, 0x0],
+    [0xec3, 0xe9f, 0x0],
+    [0xec3, 0xea0, 0x0],
+    [0xec3, 0xea1, 0x0],
+    [0xec3, 0xea2, 0x0],
+    [0xec3, 0xea3, 0x0],
+    [0xec3, 0xea5, 0x0],
+    [0xec3, 0xea7, 0x0],
+    [0xec3, 0xea8, 0x0],
+    [0xec3, 0xea9, 0x0],
+    [0xec3, 0xeaa, 0x0],
+    [0xec3, 0xeab, 0x0],
+    [0xec3, 0xeac, 0x0],
+    [0xec3, 0xead, 0x0],
+    [0xec3, 0xeae, 0x0],
+    [0xec3, 0xedc, 0x0],
+    [0xec3, 0xedd, 0x0],
+    [0xec3, 0xede, 0x0],
+    [0xec3, 0xedf, 0x0],
+    [0xec4, 0xe81, 0x0],
+    [0xec4, 0xe82, 0x0],
+    [0xec4, 0xe84, 0x0],
+    [0xec4, 0xe86, 0x0],
+    [0xec4, 0xe87, 0x0],
+    [0xec4, 0xe88, 0x0],
+    [0xec4, 0xe89, 0x0],
+    [0xec4, 0xe8a, 0x0],
+    [0xec4, 0xe8c, 0x0],
+    [0xec4, 0xe8d, 0x0],
+    [0xec4, 0xe8e, 0x0],
+    [0xec4, 0xe8f, 0x0],
+    [0xec4, 0xe90, 0x0],
+    [0xec4, 0xe91, 0x0],
+    [0xec4, 0xe92, 0x0],
+    [0xec4, 0xe93, 0x0],
+    [0xec4, 0xe94, 0x0],
+    [0xec4, 0xe95, 0x0],
+    [0xec4, 0xe96, 0x0],
+    [0xec4, 0xe97, 0x0],
+    [0xec4, 0xe98, 0x0],
+    [0xec4, 0xe99, 0x0],
+    [0xec4, 0xe9a, 0x0],
+    [0xec4, 0xe9b, 0x0],
+    [0xec4, 0xe9c, 0x0],
+    [0xec4, 0xe9d, 0x0],
+    [0xec4, 0xe9e, 0x0],
+    [0xec4, 0xe9f, 0x0],
+    [0xec4, 0xea0, 0x0],
+    [0xec4, 0xea1, 0x0],
+    [0xec4, 0xea2, 0x0],
+    [0xec4, 0xea3, 0x0],
+    [0xec4, 0xea5, 0x0],
+    [0xec4, 0xea7, 0x0],
+    [0xec4, 0xea8, 0x0],
+    [0xec4, 0xea9, 0x0],
+    [0xec4, 0xeaa, 0x0],
+    [0xec4, 0xeab, 0x0],
+    [0xec4, 0xeac, 0x0],
+    [0xec4, 0xead, 0x0],
+    [0xec4, 0xeae, 0x0],
+    [0xec4, 0xedc, 0x0],
+    [0xec4, 0xedd, 0x0],
+    [0xec4, 0xede, 0x0],
+    [0xec4, 0xedf, 0x0],
+    [0xecd, 0xeb2, 0x0],
+    [0xf71, 0xf72, 0x0],
+    [0xf71, 0xf74, 0x0],
+    [0xf71, 0xf80, 0x0],
+    [0xfb2, 0xf71, 0xf80],
+    [0xfb2, 0xf80, 0x0],
+    [0xfb2, 0xf81, 0x0],
+    [0xfb3, 0xf71, 0xf80],
+    [0xfb3, 0xf80, 0x0],
+    [0xfb3, 0xf81, 0x0],
+    [0x1025, 0x102e, 0x0],
+    [0x19b5, 0x1980, 0x0],
+    [0x19b5, 0x1981, 0x0],
+    [0x19b5, 0x1982, 0x0],
+    [0x19b5, 0x1983, 0x0],
+    [0x19b5, 0x1984, 0x0],
+    [0x19b5, 0x1985, 0x0],
+    [0x19b5, 0x1986, 0x0],
+    [0x19b5, 0x1987, 0x0],
+    [0x19b5, 0x1988, 0x0],
+    [0x19b5, 0x1989, 0x0],
+    [0x19b5, 0x198a, 0x0],
+    [0x19b5, 0x198b, 0x0],
+    [0x19b5, 0x198c, 0x0],
+    [0x19b5, 0x198d, 0x0],
+    [0x19b5, 0x198e, 0x0],
+    [0x19b5, 0x198f, 0x0],
+    [0x19b5, 0x1990, 0x0],
+    [0x19b5, 0x1991, 0x0],
+    [0x19b5, 0x1992, 0x0],
+    [0x19b5, 0x1993, 0x0],
+    [0x19b5, 0x1994, 0x0],
+    [0x19b5, 0x1995, 0x0],
+    [0x19b5, 0x1996, 0x0],
+    [0x19b5, 0x1997, 0x0],
+    [0x19b5, 0x1998, 0x0],
+    [0x19b5, 0x1999, 0x0],
+    [0x19b5, 0x199a, 0x0],
+    [0x19b5, 0x199b, 0x0],
+    [0x19b5, 0x199c, 0x0],
+    [0x19b5, 0x199d, 0x0],
+    [0x19b5, 0x199e, 0x0],
+    [0x19b5, 0x199f, 0x0],
+    [0x19b5, 0x19a0, 0x0],
+    [0x19b5, 0x19a1, 0x0],
+    [0x19b5, 0x19a2, 0x0],
+    [0x19b5, 0x19a3, 0x0],
+    [0x19b5, 0x19a4, 0x0],
+    [0x19b5, 0x19a5, 0x0],
+    [0x19b5, 0x19a6, 0x0],
+    [0x19b5, 0x19a7, 0x0],
+    [0x19b5, 0x19a8, 0x0],
+    [0x19b5, 0x19a9, 0x0],
+    [0x19b5, 0x19aa, 0x0],
+    [0x19b5, 0x19ab, 0x0],
+    [0x19b6, 0x1980, 0x0],
+    [0x19b6, 0x1981, 0x0],
+    [0x19b6, 0x1982, 0x0],
+    [0x19b6, 0x1983, 0x0],
+    [0x19b6, 0x1984, 0x0],
+    [0x19b6, 0x1985, 0x0],
+    [0x19b6, 0x1986, 0x0],
+    [0x19b6, 0x1987, 0x0],
+    [0x19b6, 0x1988, 0x0],
+    [0x19b6, 0x1989, 0x0],
+    [0x19b6, 0x198a, 0x0],
+    [0x19b6, 0x198b, 0x0],
+    [0x19b6, 0x198c, 0x0],
+    [0x19b6, 0x198d, 0x0],
+    [0x19b6, 0x198e, 0x0],
+    [0x19b6, 0x198f, 0x0],
+    [0x19b6, 0x1990, 0x0],
+    [0x19b6, 0x1991, 0x0],
+    [0x19b6, 0x1992, 0x0],
+    [0x19b6, 0x1993, 0x0],
+    [0x19b6, 0x1994, 0x0],
+    [0x19b6, 0x1995, 0x0],
+    [0x19b6, 0x1996, 0x0],
+    [0x19b6, 0x1997, 0x0],
+    [0x19b6, 0x1998, 0x0],
+    [0x19b6, 0x1999, 0x0],
+    [0x19b6, 0x199a, 0x0],
+    [0x19b6, 0x199b, 0x0],
+    [0x19b6, 0x199c, 0x0],
+    [0x19b6, 0x199d, 0x0],
+    [0x19b6, 0x199e, 0x0],
+    [0x19b6, 0x199f, 0x0],
+    [0x19b6, 0x19a0, 0x0],
+    [0x19b6, 0x19a1, 0x0],
+    [0x19b6, 0x19a2, 0x0],
+    [0x19b6, 0x19a3, 0x0],
+    [0x19b6, 0x19a4, 0x0],
+    [0x19b6, 0x19a5, 0x0],
+    [0x19b6, 0x19a6, 0x0],
+    [0x19b6, 0x19a7, 0x0],
+    [0x19b6, 0x19a8, 0x0],
+    [0x19b6, 0x19a9, 0x0],
+    [0x19b6, 0x19aa, 0x0],
+    [0x19b6, 0x19ab, 0x0],
+    [0x19b7, 0x1980, 0x0],
+    [0x19b7, 0x1981, 0x0],
+    [0x19b7, 0x1982, 0x0],
+    [0x19b7, 0x1983, 0x0],
+    [0x19b7, 0x1984, 0x0],
+    [0x19b7, 0x1985, 0x0],
+    [0x19b7, 0x1986, 0x0],
+    [0x19b7, 0x1987, 0x0],
+    [0x19b7, 0x1988, 0x0],
+    [0x19b7, 0x1989, 0x0],
+    [0x19b7, 0x198a, 0x0],
+    [0x19b7, 0x198b, 0x0],
+    [0x19b7, 0x198c, 0x0],
+    [0x19b7, 0x198d, 0x0],
+    [0x19b7, 0x198e, 0x0],
+    [0x19b7, 0x198f, 0x0],
+    [0x19b7, 0x1990, 0x0],
+    [0x19b7, 0x1991, 0x0],
+    [0x19b7, 0x1992, 0x0],
+    [0x19b7, 0x1993, 0x0],
+    [0x19b7, 0x1994, 0x0],
+    [0x19b7, 0x1995, 0x0],
+    [0x19b7, 0x1996, 0x0],
+    [0x19b7, 0x1997, 0x0],
+    [0x19b7, 0x1998, 0x0],
+    [0x19b7, 0x1999, 0x0],
+    [0x19b7, 0x199a, 0x0],
+    [0x19b7, 0x199b, 0x0],
+    [0x19b7, 0x199c, 0x0],
+    [0x19b7, 0x199d, 0x0],
+    [0x19b7, 0x199e, 0x0],
+    [0x19b7, 0x199f, 0x0],
+    [0x19b7, 0x19a0, 0x0],
+    [0x19b7, 0x19a1, 0x0],
+    [0x19b7, 0x19a2, 0x0],
+    [0x19b7, 0x19a3, 0x0],
+    [0x19b7, 0x19a4, 0x0],
+    [0x19b7, 0x19a5, 0x0],
+    [0x19b7, 0x19a6, 0x0],
+    [0x19b7, 0x19a7, 0x0],
+    [0x19b7, 0x19a8, 0x0],
+    [0x19b7, 0x19a9, 0x0],
+    [0x19b7, 0x19aa, 0x0],
+    [0x19b7, 0x19ab, 0x0],
+    [0x19ba, 0x1980, 0x0],
+    [0x19ba, 0x1981, 0x0],
+    [0x19ba, 0x1982, 0x0],
+    [0x19ba, 0x1983, 0x0],
+    [0x19ba, 0x1984, 0x0],
+    [0x19ba, 0x1985, 0x0],
+    [0x19ba, 0x1986, 0x0],
+    [0x19ba, 0x1987, 0x0],
+    [0x19ba, 0x1988, 0x0],
+    [0x19ba, 0x1989, 0x0],
+    [0x19ba, 0x198a, 0x0],
+    [0x19ba, 0x198b, 0x0],
+    [0x19ba, 0x198c, 0x0],
+    [0x19ba, 0x198d, 0x0],
+    [0x19ba, 0x198e, 0x0],
+    [0x19ba, 0x198f, 0x0],
+    [0x19ba, 0x1990, 0x0],
+    [0x19ba, 0x1991, 0x0],
+    [0x19ba, 0x1992, 0x0],
+    [0x19ba, 0x1993, 0x0],
+    [0x19ba, 0x1994, 0x0],
+    [0x19ba, 0x1995, 0x0],
+    [0x19ba, 0x1996, 0x0],
+    [0x19ba, 0x1997, 0x0],
+    [0x19ba, 0x1998, 0x0],
+    [0x19ba, 0x1999, 0x0],
+    [0x19ba, 0x199a, 0x0],
+    [0x19ba, 0x199b, 0x0],
+    [0x19ba, 0x199c, 0x0],
+    [0x19ba, 0x199d, 0x0],
+    [0x19ba, 0x199e, 0x0],
+    [0x19ba, 0x199f, 0x0],
+    [0x19ba, 0x19a0, 0x0],
+    [0x19ba, 0x19a1, 0x0],
+    [0x19ba, 0x19a2, 0x0],
+    [0x19ba, 0x19a3, 0x0],
+    [0x19ba, 0x19a4, 0x0],
+    [0x19ba, 0x19a5, 0x0],
+    [0x19ba, 0x19a6, 0x0],
+    [0x19ba, 0x19a7, 0x0],
+    [0x19ba, 0x19a8, 0x0],
+    [0x19ba, 0x19a9, 0x0],
+    [0x19ba, 0x19aa, 0x0],
+    [0x19ba, 0x19ab, 0x0],
+    [0x1b05, 0x1b35, 0x0],
+    [0x1b07, 0x1b35, 0x0],
+    [0x1b09, 0x1b35, 0x0],
+    [0x1b0b, 0x1b35, 0x0],
+    [0x1b0d, 0x1b35, 0x0],
+    [0x1b11, 0x1b35, 0x0],
+    [0x1b3a, 0x1b35, 0x0],
+    [0x1b3c, 0x1b35, 0x0],
+    [0x1b3e, 0x1b35, 0x0],
+    [0x1b3f, 0x1b35, 0x0],
+    [0x1b42, 0x1b35, 0x0],
+    [0xaab5, 0xaa80, 0x0],
+    [0xaab5, 0xaa81, 0x0],
+    [0xaab5, 0xaa82, 0x0],
+    [0xaab5, 0xaa83, 0x0],
+    [0xaab5, 0xaa84, 0x0],
+    [0xaab5, 0xaa85, 0x0],
+    [0xaab5, 0xaa86, 0x0],
+    [0xaab5, 0xaa87, 0x0],
+    [0xaab5, 0xaa88, 0x0],
+    [0xaab5, 0xaa89, 0x0],
+    [0xaab5, 0xaa8a, 0x0],
+    [0xaab5, 0xaa8b, 0x0],
+    [0xaab5, 0xaa8c, 0x0],
+    [0xaab5, 0xaa8d, 0x0],
+    [0xaab5, 0xaa8e, 0x0],
+    [0xaab5, 0xaa8f, 0x0],
+    [0xaab5, 0xaa90, 0x0],
+    [0xaab5, 0xaa91, 0x0],
+    [0xaab5, 0xaa92, 0x0],
+    [0xaab5, 0xaa93, 0x0],
+    [0xaab5, 0xaa94, 0x0],
+    [0xaab5, 0xaa95, 0x0],
+    [0xaab5, 0xaa96, 0x0],
+    [0xaab5, 0xaa97, 0x0],
+    [0xaab5, 0xaa98, 0x0],
+    [0xaab5, 0xaa99, 0x0],
+    [0xaab5, 0xaa9a, 0x0],
+    [0xaab5, 0xaa9b, 0x0],
+    [0xaab5, 0xaa9c, 0x0],
+    [0xaab5, 0xaa9d, 0x0],
+    [0xaab5, 0xaa9e, 0x0],
+    [0xaab5, 0xaa9f, 0x0],
+    [0xaab5, 0xaaa0, 0x0],
+    [0xaab5, 0xaaa1, 0x0],
+    [0xaab5, 0xaaa2, 0x0],
+    [0xaab5, 0xaaa3, 0x0],
+    [0xaab5, 0xaaa4, 0x0],
+    [0xaab5, 0xaaa5, 0x0],
+    [0xaab5, 0xaaa6, 0x0],
+    [0xaab5, 0xaaa7, 0x0],
+    [0xaab5, 0xaaa8, 0x0],
+    [0xaab5, 0xaaa9, 0x0],
+    [0xaab5, 0xaaaa, 0x0],
+    [0xaab5, 0xaaab, 0x0],
+    [0xaab5, 0xaaac, 0x0],
+    [0xaab5, 0xaaad, 0x0],
+    [0xaab5, 0xaaae, 0x0],
+    [0xaab5, 0xaaaf, 0x0],
+    [0xaab6, 0xaa80, 0x0],
+    [0xaab6, 0xaa81, 0x0],
+    [0xaab6, 0xaa82, 0x0],
+    [0xaab6, 0xaa83, 0x0],
+    [0xaab6, 0xaa84, 0x0],
+    [0xaab6, 0xaa85, 0x0],
+    [0xaab6, 0xaa86, 0x0],
+    [0xaab6, 0xaa87, 0x0],
+    [0xaab6, 0xaa88, 0x0],
+    [0xaab6, 0xaa89, 0x0],
+    [0xaab6, 0xaa8a, 0x0],
+    [0xaab6, 0xaa8b, 0x0],
+    [0xaab6, 0xaa8c, 0x0],
+    [0xaab6, 0xaa8d, 0x0],
+    [0xaab6, 0xaa8e, 0x0],
+    [0xaab6, 0xaa8f, 0x0],
+    [0xaab6, 0xaa90, 0x0],
+    [0xaab6, 0xaa91, 0x0],
+    [0xaab6, 0xaa92, 0x0],
+    [0xaab6, 0xaa93, 0x0],
+    [0xaab6, 0xaa94, 0x0],
+    [0xaab6, 0xaa95, 0x0],
+    [0xaab6, 0xaa96, 0x0],
+    [0xaab6, 0xaa97, 0x0],
+    [0xaab6, 0xaa98, 0x0],
+    [0xaab6, 0xaa99, 0x0],
+    [0xaab6, 0xaa9a, 0x0],
+    [0xaab6, 0xaa9b, 0x0],
+    [0xaab6, 0xaa9c, 0x0],
+    [0xaab6, 0xaa9d, 0x0],
+    [0xaab6, 0xaa9e, 0x0],
+    [0xaab6, 0xaa9f, 0x0],
+    [0xaab6, 0xaaa0, 0x0],
+    [0xaab6, 0xaaa1, 0x0],
+    [0xaab6, 0xaaa2, 0x0],
+    [0xaab6, 0xaaa3, 0x0],
+    [0xaab6, 0xaaa4, 0x0],
+    [0xaab6, 0xaaa5, 0x0],
+    [0xaab6, 0xaaa6, 0x0],
+    [0xaab6, 0xaaa7, 0x0],
+    [0xaab6, 0xaaa8, 0x0],
+    [0xaab6, 0xaaa9, 0x0],
+    [0xaab6, 0xaaaa, 0x0],
+    [0xaab6, 0xaaab, 0x0],
+    [0xaab6, 0xaaac, 0x0],
+    [0xaab6, 0xaaad, 0x0],
+    [0xaab6, 0xaaae, 0x0],
+    [0xaab6, 0xaaaf, 0x0],
+    [0xaab9, 0xaa80, 0x0],
+    [0xaab9, 0xaa81, 0x0],
+    [0xaab9, 0xaa82, 0x0],
+    [0xaab9, 0xaa83, 0x0],
+    [0xaab9, 0xaa84, 0x0],
+    [0xaab9, 0xaa85, 0x0],
+    [0xaab9, 0xaa86, 0x0],
+    [0xaab9, 0xaa87, 0x0],
+    [0xaab9, 0xaa88, 0x0],
+    [0xaab9, 0xaa89, 0x0],
+    [0xaab9, 0xaa8a, 0x0],
+    [0xaab9, 0xaa8b, 0x0],
+    [0xaab9, 0xaa8c, 0x0],
+    [0xaab9, 0xaa8d, 0x0],
+    [0xaab9, 0xaa8e, 0x0],
+    [0xaab9, 0xaa8f, 0x0],
+    [0xaab9, 0xaa90, 0x0],
+    [0xaab9, 0xaa91, 0x0],
+    [0xaab9, 0xaa92, 0x0],
+    [0xaab9, 0xaa93, 0x0],
+    [0xaab9, 0xaa94, 0x0],
+    [0xaab9, 0xaa95, 0x0],
+    [0xaab9, 0xaa96, 0x0],
+    [0xaab9, 0xaa97, 0x0],
+    [0xaab9, 0xaa98, 0x0],
+    [0xaab9, 0xaa99, 0x0],
+    [0xaab9, 0xaa9a, 0x0],
+    [0xaab9, 0xaa9b, 0x0],
+    [0xaab9, 0xaa9c, 0x0],
+    [0xaab9, 0xaa9d, 0x0],
+    [0xaab9, 0xaa9e, 0x0],
+    [0xaab9, 0xaa9f, 0x0],
+    [0xaab9, 0xaaa0, 0x0],
+    [0xaab9, 0xaaa1, 0x0],
+    [0xaab9, 0xaaa2, 0x0],
+    [0xaab9, 0xaaa3, 0x0],
+    [0xaab9, 0xaaa4, 0x0],
+    [0xaab9, 0xaaa5, 0x0],
+    [0xaab9, 0xaaa6, 0x0],
+    [0xaab9, 0xaaa7, 0x0],
+    [0xaab9, 0xaaa8, 0x0],
+    [0xaab9, 0xaaa9, 0x0],
+    [0xaab9, 0xaaaa, 0x0],
+    [0xaab9, 0xaaab, 0x0],
+    [0xaab9, 0xaaac, 0x0],
+    [0xaab9, 0xaaad, 0x0],
+    [0xaab9, 0xaaae, 0x0],
+    [0xaab9, 0xaaaf, 0x0],
+    [0xaabb, 0xaa80, 0x0],
+    [0xaabb, 0xaa81, 0x0],
+    [0xaabb, 0xaa82, 0x0],
+    [0xaabb, 0xaa83, 0x0],
+    [0xaabb, 0xaa84, 0x0],
+    [0xaabb, 0xaa85, 0x0],
+    [0xaabb, 0xaa86, 0x0],
+    [0xaabb, 0xaa87, 0x0],
+    [0xaabb, 0xaa88, 0x0],
+    [0xaabb, 0xaa89, 0x0],
+    [0xaabb, 0xaa8a, 0x0],
+    [0xaabb, 0xaa8b, 0x0],
+    [0xaabb, 0xaa8c, 0x0],
+    [0xaabb, 0xaa8d, 0x0],
+    [0xaabb, 0xaa8e, 0x0],
+    [0xaabb, 0xaa8f, 0x0],
+    [0xaabb, 0xaa90, 0x0],
+    [0xaabb, 0xaa91, 0x0],
+    [0xaabb, 0xaa92, 0x0],
+    [0xaabb, 0xaa93, 0x0],
+    [0xaabb, 0xaa94, 0x0],
+    [0xaabb, 0xaa95, 0x0],
+    [0xaabb, 0xaa96, 0x0],
+    [0xaabb, 0xaa97, 0x0],
+    [0xaabb, 0xaa98, 0x0],
+    [0xaabb, 0xaa99, 0x0],
+    [0xaabb, 0xaa9a, 0x0],
+    [0xaabb, 0xaa9b, 0x0],
+    [0xaabb, 0xaa9c, 0x0],
+    [0xaabb, 0xaa9d, 0x0],
+    [0xaabb, 0xaa9e, 0x0],
+    [0xaabb, 0xaa9f, 0x0],
+    [0xaabb, 0xaaa0, 0x0],
+    [0xaabb, 0xaaa1, 0x0],
+    [0xaabb, 0xaaa2, 0x0],
+    [0xaabb, 0xaaa3, 0x0],
+    [0xaabb, 0xaaa4, 0x0],
+    [0xaabb, 0xaaa5, 0x0],
+    [0xaabb, 0xaaa6, 0x0],
+    [0xaabb, 0xaaa7, 0x0],
+    [0xaabb, 0xaaa8, 0x0],
+    [0xaabb, 0xaaa9, 0x0],
+    [0xaabb, 0xaaaa, 0x0],
+    [0xaabb, 0xaaab, 0x0],
+    [0xaabb, 0xaaac, 0x0],
+    [0xaabb, 0xaaad, 0x0],
+    [0xaabb, 0xaaae, 0x0],
+    [0xaabb, 0xaaaf, 0x0],
+    [0xaabc, 0xaa80, 0x0],
+    [0xaabc, 0xaa81, 0x0],
+    [0xaabc, 0xaa82, 0x0],
+    [0xaabc, 0xaa83, 0x0],
+    [0xaabc, 0xaa84, 0x0],
+    [0xaabc, 0xaa85, 0x0],
+    [0xaabc, 0xaa86, 0x0],
+    [0xaabc, 0xaa87, 0x0],
+    [0xaabc, 0xaa88, 0x0],
+    [0xaabc, 0xaa89, 0x0],
+    [0xaabc, 0xaa8a, 0x0],
+    [0xaabc, 0xaa8b, 0x0],
+    [0xaabc, 0xaa8c, 0x0],
+    [0xaabc, 0xaa8d, 0x0],
+    [0xaabc, 0xaa8e, 0x0],
+    [0xaabc, 0xaa8f, 0x0],
+    [0xaabc, 0xaa90, 0x0],
+    [0xaabc, 0xaa91, 0x0],
+    [0xaabc, 0xaa92, 0x0],
+    [0xaabc, 0xaa93, 0x0],
+    [0xaabc, 0xaa94, 0x0],
+    [0xaabc, 0xaa95, 0x0],
+    [0xaabc, 0xaa96, 0x0],
+    [0xaabc, 0xaa97, 0x0],
+    [0xaabc, 0xaa98, 0x0],
+    [0xaabc, 0xaa99, 0x0],
+    [0xaabc, 0xaa9a, 0x0],
+    [0xaabc, 0xaa9b, 0x0],
+    [0xaabc, 0xaa9c, 0x0],
+    [0xaabc, 0xaa9d, 0x0],
+    [0xaabc, 0xaa9e, 0x0],
+    [0xaabc, 0xaa9f, 0x0],
+    [0xaabc, 0xaaa0, 0x0],
+    [0xaabc, 0xaaa1, 0x0],
+    [0xaabc, 0xaaa2, 0x0],
+    [0xaabc, 0xaaa3, 0x0],
+    [0xaabc, 0xaaa4, 0x0],
+    [0xaabc, 0xaaa5, 0x0],
+    [0xaabc, 0xaaa6, 0x0],
+    [0xaabc, 0xaaa7, 0x0],
+    [0xaabc, 0xaaa8, 0x0],
+    [0xaabc, 0xaaa9, 0x0],
+    [0xaabc, 0xaaaa, 0x0],
+    [0xaabc, 0xaaab, 0x0],
+    [0xaabc, 0xaaac, 0x0],
+    [0xaabc, 0xaaad, 0x0],
+    [0xaabc, 0xaaae, 0x0],
+    [0xaabc, 0xaaaf, 0x0],
+    [0x11131, 0x11127, 0x0],
+    [0x11132, 0x11127, 0x0],
+    [0x11347, 0x1133e, 0x0],
+    [0x11347, 0x11357, 0x0],
+    [0x114b9, 0x114b0, 0x0],
+    [0x114b9, 0x114ba, 0x0],
+    [0x114b9, 0x114bd, 0x0],
+    [0x115b8, 0x115af, 0x0],
+    [0x115b9, 0x115af, 0x0],
+    [0x11935, 0x11930, 0x0],
+];
+
+/// The collation elements of each of `CONTRACTIONS`, like `ELEMENTS`
+pub const CONTRACTION_ELEMENTS: [u32; 939] = [
+    0x80047202, 0x80047242, 0x80047282, 0x800472c2, 0x11f90810, 0x11f90804, 0x13328804, 0x13330804,
+    0x13350804, 0x13348804, 0x13370804, 0x153d0804, 0x153d8804, 0x159d8804, 0x159d0804, 0x159e0804,
+    0x15a60804, 0x15b68804, 0x15b78804, 0x15b70804, 0x15da8804, 0x15fb0804, 0x16000804, 0x16008804,
+    0x15ff0804, 0x15ff8804, 0x16008804, 0x16258804, 0x16268804, 0x16260804, 0x164b8804, 0x164c8804,
+    0x164d0804, 0x164d8804, 0x164d0804, 0x80047302, 0x80047342, 0x80047382, 0x800473c2, 0x80047402,
+    0x80047442, 0x80047482, 0x800474c2, 0x80047502, 0x80047542, 0x80047582, 0x800475c2, 0x80047602,
+    0x80047642, 0x80047682, 0x800476c2, 0x80047702, 0x80047742, 0x80047782, 0x800477c2, 0x80047802,
+    0x80047842, 0x80047882, 0x800478c2, 0x80047902, 0x80047942, 0x80047982, 0x800479c2, 0x80047a02,
+    0x80047a42, 0x80047a82, 0x80047ac2, 0x80047b02, 0x80047b42, 0x80047b82, 0x80047bc2, 0x80047c02,
+    0x80047c42, 0x80047c82, 0x80047cc2, 0x80047d02, 0x80047d42, 0x80047d82, 0x80047dc2, 0x80047e02,
+    0x80047e42, 0x80047e82, 0x80047ec2, 0x80047f02, 0x80047f42, 0x80047f82, 0x80047fc2, 0x80048002,
+    0x80048042, 0x80048082, 0x800480c2, 0x80048102, 0x80048142, 0x80048182, 0x800481c2, 0x80048202,
+    0x80048242, 0x80048282, 0x800482c2, 0x80048302, 0x80048342, 0x80048382, 0x800483c2, 0x80048402,
+    0x80048442, 0x80048482, 0x800484c2, 0x80048502, 0x80048542, 0x80048582, 0x800485c2, 0x80048602,
+    0x80048642, 0x80048682, 0x800486c2, 0x80048702, 0x80048742, 0x80048782, 0x800487c2, 0x80048802,
+    0x80048842, 0x80048882, 0x800488c2, 0x80048902, 0x80048942, 0x80048982, 0x800489c2, 0x80048a02,
+    0x80048a42, 0x80048a82, 0x80048ac2, 0x80048b02, 0x80048b42, 0x80048b82, 0x80048bc2, 0x80048c02,
+    0x80048c42, 0x80048c82, 0x80048cc2, 0x80048d02, 0x80048d42, 0x80048d82, 0x80048dc2, 0x80048e02,
+    0x80048e42, 0x80048e82, 0x80048ec2, 0x80048f02, 0x80048f42, 0x80048f82, 0x80048fc2, 0x80049002,
+    0x80049042, 0x80049082, 0x800490c2, 0x80049102, 0x80049142, 0x80049182, 0x800491c2, 0x80049202,
+    0x80049242, 0x80049282, 0x800492c2, 0x80049302, 0x80049342, 0x80049382, 0x800493c2, 0x80049402,
+    0x80049442, 0x80049482, 0x800494c2, 0x80049502, 0x80049542, 0x80049582, 0x800495c2, 0x80049602,
+    0x80049642, 0x80049682, 0x800496c2, 0x80049702, 0x80049742, 0x80049782, 0x800497c2, 0x80049802,
+    0x80049842, 0x80049882, 0x800498c2, 0x80049902, 0x80049942, 0x80049982, 0x800499c2, 0x80049a02,
+    0x80049a42, 0x80049a82, 0x80049ac2, 0x80049b02, 0x80049b42, 0x80049b82, 0x80049bc2, 0x80049c02,
+    0x80049c42, 0x80049c82, 0x80049cc2, 0x80049d02, 0x80049d42, 0x80049d82, 0x80049dc2, 0x80049e02,
+    0x80049e42, 0x80049e82, 0x80049ec2, 0x80049f02, 0x80049f42, 0x80049f82, 0x80049fc2, 0x8004a002,
+    0x8004a042, 0x8004a082, 0x8004a0c2, 0x8004a102, 0x8004a142, 0x8004a182, 0x8004a1c2, 0x8004a202,
+    0x8004a242, 0x8004a282, 0x8004a2c2, 0x8004a302, 0x8004a342, 0x8004a382, 0x8004a3c2, 0x8004a402,
+    0x8004a442, 0x8004a482, 0x8004a4c2, 0x8004a502, 0x8004a542, 0x8004a582, 0x8004a5c2, 0x8004a602,
+    0x8004a642, 0x8004a682, 0x8004a6c2, 0x8004a702, 0x8004a742, 0x8004a782, 0x8004a7c2, 0x8004a802,
+    0x8004a842, 0x8004a882, 0x8004a8c2, 0x8004a902, 0x8004a942, 0x8004a982, 0x8004a9c2, 0x8004aa02,
+    0x8004aa42, 0x8004aa82, 0x8004aac2, 0x8004ab02, 0x8004ab42, 0x8004ab82, 0x8004abc2, 0x8004ac02,
+    0x8004ac42, 0x19248804, 0x8004ac82, 0x8004acc2, 0x8004ad02, 0x8004ad42, 0x8004ad82, 0x8004adc2,
+    0x8004ae02, 0x8004ae42, 0x8004ae82, 0x8004aec2, 0x8004af02, 0x8004af42, 0x8004af82, 0x8004afc2,
+    0x8004b002, 0x8004b042, 0x8004b082, 0x8004b0c2, 0x8004b102, 0x8004b142, 0x8004b182, 0x8004b1c2,
+    0x8004b202, 0x8004b242, 0x8004b282, 0x8004b2c2, 0x8004b302, 0x8004b342, 0x8004b382, 0x8004b3c2,
+    0x8004b402, 0x8004b442, 0x8004b482, 0x8004b4c2, 0x8004b502, 0x8004b542, 0x8004b582, 0x8004b5c2,
+    0x8004b602, 0x8004b642, 0x8004b682, 0x8004b6c3, 0x8004b723, 0x8004b782, 0x8004b7c2, 0x8004b802,
+    0x8004b842, 0x8004b882, 0x8004b8c2, 0x8004b902, 0x8004b942, 0x8004b982, 0x8004b9c2, 0x8004ba02,
+    0x8004ba42, 0x8004ba82, 0x8004bac2, 0x8004bb02, 0x8004bb42, 0x8004bb82, 0x8004bbc2, 0x8004bc02,
+    0x8004bc42, 0x8004bc82, 0x8004bcc2, 0x8004bd02, 0x8004bd42, 0x8004bd82, 0x8004bdc2, 0x8004be02,
+    0x8004be42, 0x8004be82, 0x8004bec2, 0x8004bf02, 0x8004bf42, 0x8004bf82, 0x8004bfc2, 0x8004c002,
+    0x8004c042, 0x8004c082, 0x8004c0c2, 0x8004c102, 0x8004c142, 0x8004c182, 0x8004c1c2, 0x8004c202,
+    0x8004c243, 0x8004c2a3, 0x8004c302, 0x8004c342, 0x8004c382, 0x8004c3c2, 0x8004c402, 0x8004c442,
+    0x8004c482, 0x8004c4c2, 0x8004c502, 0x8004c542, 0x8004c582, 0x8004c5c2, 0x8004c602, 0x8004c642,
+    0x8004c682, 0x8004c6c2, 0x8004c702, 0x8004c742, 0x8004c782, 0x8004c7c2, 0x8004c802, 0x8004c842,
+    0x8004c882, 0x8004c8c2, 0x8004c902, 0x8004c942, 0x8004c982, 0x8004c9c2, 0x8004ca02, 0x8004ca42,
+    0x8004ca82, 0x8004cac2, 0x8004cb02, 0x8004cb42, 0x8004cb82, 0x8004cbc2, 0x8004cc02, 0x8004cc42,
+    0x8004cc82, 0x8004ccc2, 0x8004cd02, 0x8004cd42, 0x8004cd82, 0x8004cdc3, 0x8004ce23, 0x8004ce82,
+    0x8004cec2, 0x8004cf02, 0x8004cf42, 0x8004cf82, 0x8004cfc2, 0x8004d002, 0x8004d042, 0x8004d082,
+    0x8004d0c2, 0x8004d102, 0x8004d142, 0x8004d182, 0x8004d1c2, 0x8004d202, 0x8004d242, 0x8004d282,
+    0x8004d2c2, 0x8004d302, 0x8004d342, 0x8004d382, 0x8004d3c2, 0x8004d402, 0x8004d442, 0x8004d482,
+    0x8004d4c2, 0x8004d502, 0x8004d542, 0x8004d582, 0x8004d5c2, 0x8004d602, 0x8004d642, 0x8004d682,
+    0x8004d6c2, 0x8004d702, 0x8004d742, 0x8004d782, 0x8004d7c2, 0x8004d802, 0x8004d842, 0x8004d882,
+    0x8004d8c2, 0x8004d902, 0x8004d943, 0x8004d9a3, 0x8004da02, 0x8004da42, 0x8004da82, 0x8004dac2,
+    0x8004db02, 0x8004db42, 0x8004db82, 0x8004dbc2, 0x8004dc02, 0x8004dc42, 0x8004dc82, 0x8004dcc2,
+    0x8004dd02, 0x8004dd42, 0x8004dd82, 0x8004ddc2, 0x8004de02, 0x8004de42, 0x8004de82, 0x8004dec2,
+    0x8004df02, 0x8004df42, 0x8004df82, 0x8004dfc2, 0x8004e002, 0x8004e042, 0x8004e082, 0x8004e0c2,
+    0x8004e102, 0x8004e142, 0x8004e182, 0x8004e1c2, 0x8004e202, 0x8004e242, 0x8004e282, 0x8004e2c2,
+    0x8004e302, 0x8004e342, 0x8004e382, 0x8004e3c2, 0x8004e402, 0x8004e442, 0x8004e482, 0x8004e4c3,
+    0x8004e523, 0x8004e582, 0x8004e5c2, 0x19430804, 0x19958804, 0x19978804, 0x19968804, 0x19988804,
+    0x19980804, 0x19988804, 0x19998804, 0x19990804, 0x19998804, 0x1aee8804, 0x8004e602, 0x8004e642,
+    0x8004e682, 0x8004e6c2, 0x8004e702, 0x8004e742, 0x8004e782, 0x8004e7c2, 0x8004e802, 0x8004e842,
+    0x8004e882, 0x8004e8c2, 0x8004e902, 0x8004e942, 0x8004e982, 0x8004e9c2, 0x8004ea02, 0x8004ea42,
+    0x8004ea82, 0x8004eac2, 0x8004eb02, 0x8004eb42, 0x8004eb82, 0x8004ebc2, 0x8004ec02, 0x8004ec42,
+    0x8004ec82, 0x8004ecc2, 0x8004ed02, 0x8004ed42, 0x8004ed82, 0x8004edc2, 0x8004ee02, 0x8004ee42,
+    0x8004ee82, 0x8004eec2, 0x8004ef02, 0x8004ef42, 0x8004ef82, 0x8004efc2, 0x8004f002, 0x8004f042,
+    0x8004f082, 0x8004f0c2, 0x8004f102, 0x8004f142, 0x8004f182, 0x8004f1c2, 0x8004f202, 0x8004f242,
+    0x8004f282, 0x8004f2c2, 0x8004f302, 0x8004f342, 0x8004f382, 0x8004f3c2, 0x8004f402, 0x8004f442,
+    0x8004f482, 0x8004f4c2, 0x8004f502, 0x8004f542, 0x8004f582, 0x8004f5c2, 0x8004f602, 0x8004f642,
+    0x8004f682, 0x8004f6c2, 0x8004f702, 0x8004f742, 0x8004f782, 0x8004f7c2, 0x8004f802, 0x8004f842,
+    0x8004f882, 0x8004f8c2, 0x8004f902, 0x8004f942, 0x8004f982, 0x8004f9c2, 0x8004fa02, 0x8004fa42,
+    0x8004fa82, 0x8004fac2, 0x8004fb02, 0x8004fb42, 0x8004fb82, 0x8004fbc2, 0x8004fc02, 0x8004fc42,
+    0x8004fc82, 0x8004fcc2, 0x8004fd02, 0x8004fd42, 0x8004fd82, 0x8004fdc2, 0x8004fe02, 0x8004fe42,
+    0x8004fe82, 0x8004fec2, 0x8004ff02, 0x8004ff42, 0x8004ff82, 0x8004ffc2, 0x80050002, 0x80050042,
+    0x80050082, 0x800500c2, 0x80050102, 0x80050142, 0x80050182, 0x800501c2, 0x80050202, 0x80050242,
+    0x80050282, 0x800502c2, 0x80050302, 0x80050342, 0x80050382, 0x800503c2, 0x80050402, 0x80050442,
+    0x80050482, 0x800504c2, 0x80050502, 0x80050542, 0x80050582, 0x800505c2, 0x80050602, 0x80050642,
+    0x80050682, 0x800506c2, 0x80050702, 0x80050742, 0x80050782, 0x800507c2, 0x80050802, 0x80050842,
+    0x80050882, 0x800508c2, 0x80050902, 0x80050942, 0x80050982, 0x800509c2, 0x80050a02, 0x80050a42,
+    0x80050a82, 0x80050ac2, 0x80050b02, 0x80050b42, 0x80050b82, 0x80050bc2, 0x80050c02, 0x80050c42,
+    0x80050c82, 0x80050cc2, 0x80050d02, 0x80050d42, 0x80050d82, 0x80050dc2, 0x80050e02, 0x80050e42,
+    0x80050e82, 0x80050ec2, 0x80050f02, 0x80050f42, 0x80050f82, 0x80050fc2, 0x80051002, 0x80051042,
+    0x80051082, 0x800510c2, 0x80051102, 0x80051142, 0x80051182, 0x800511c2, 0x1bdb8804, 0x1bdc8804,
+    0x1bdd8804, 0x1bde8804, 0x1bdf8804, 0x1be18804, 0x1bf90804, 0x1bfa0804, 0x1bfb8804, 0x1bfc0804,
+    0x1bfd0804, 0x80051202, 0x80051242, 0x80051282, 0x800512c2, 0x80051302, 0x80051342, 0x80051382,
+    0x800513c2, 0x80051402, 0x80051442, 0x80051482, 0x800514c2, 0x80051502, 0x80051542, 0x80051582,
+    0x800515c2, 0x80051602, 0x80051642, 0x80051682, 0x800516c2, 0x80051702, 0x80051742, 0x80051782,
+    0x800517c2, 0x80051802, 0x80051842, 0x80051882, 0x800518c2, 0x80051902, 0x80051942, 0x80051982,
+    0x800519c2, 0x80051a02, 0x80051a42, 0x80051a82, 0x80051ac2, 0x80051b02, 0x80051b42, 0x80051b82,
+    0x80051bc2, 0x80051c02, 0x80051c42, 0x80051c82, 0x80051cc2, 0x80051d02, 0x80051d42, 0x80051d82,
+    0x80051dc2, 0x80051e02, 0x80051e42, 0x80051e82, 0x80051ec2, 0x80051f02, 0x80051f42, 0x80051f82,
+    0x80051fc2, 0x80052002, 0x80052042, 0x80052082, 0x800520c2, 0x80052102, 0x80052142, 0x80052182,
+    0x800521c2, 0x80052202, 0x80052242, 0x80052282, 0x800522c2, 0x80052302, 0x80052342, 0x80052382,
+    0x800523c2, 0x80052402, 0x80052442, 0x80052482, 0x800524c2, 0x80052502, 0x80052542, 0x80052582,
+    0x800525c2, 0x80052602, 0x80052642, 0x80052682, 0x800526c2, 0x80052702, 0x80052742, 0x80052782,
+    0x800527c2, 0x80052802, 0x80052842, 0x80052882, 0x800528c2, 0x80052902, 0x80052942, 0x80052982,
+    0x800529c2, 0x80052a02, 0x80052a42, 0x80052a82, 0x80052ac2, 0x80052b02, 0x80052b42, 0x80052b82,
+    0x80052bc2, 0x80052c02, 0x80052c42, 0x80052c82, 0x80052cc2, 0x80052d02, 0x80052d42, 0x80052d82,
+    0x80052dc2, 0x80052e02, 0x80052e42, 0x80052e82, 0x80052ec2, 0x80052f02, 0x80052f42, 0x80052f82,
+    0x80052fc2, 0x80053002, 0x80053042, 0x80053082, 0x800530c2, 0x80053102, 0x80053142, 0x80053182,
+    0x800531c2, 0x80053202, 0x80053242, 0x80053282, 0x800532c2, 0x80053302, 0x80053342, 0x80053382,
+    0x800533c2, 0x80053402, 0x80053442, 0x80053482, 0x800534c2, 0x80053502, 0x80053542, 0x80053582,
+    0x800535c2, 0x80053602, 0x80053642, 0x80053682, 0x800536c2, 0x80053702, 0x80053742, 0x80053782,
+    0x800537c2, 0x80053802, 0x80053842, 0x80053882, 0x800538c2, 0x80053902, 0x80053942, 0x80053982,
+    0x800539c2, 0x80053a02, 0x80053a42, 0x80053a82, 0x80053ac2, 0x80053b02, 0x80053b42, 0x80053b82,
+    0x80053bc2, 0x80053c02, 0x80053c42, 0x80053c82, 0x80053cc2, 0x80053d02, 0x80053d42, 0x80053d82,
+    0x80053dc2, 0x80053e02, 0x80053e42, 0x80053e82, 0x80053ec2, 0x80053f02, 0x80053f42, 0x80053f82,
+    0x80053fc2, 0x80054002, 0x80054042, 0x80054082, 0x800540c2, 0x80054102, 0x80054142, 0x80054182,
+    0x800541c2, 0x80054202, 0x80054242, 0x80054282, 0x800542c2, 0x80054302, 0x80054342, 0x80054382,
+    0x800543c2, 0x80054402, 0x80054442, 0x80054482, 0x800544c2, 0x80054502, 0x80054542, 0x80054582,
+    0x800545c2, 0x80054602, 0x80054642, 0x80054682, 0x800546c2, 0x80054702, 0x80054742, 0x80054782,
+    0x800547c2, 0x80054802, 0x80054842, 0x80054882, 0x800548c2, 0x80054902, 0x80054942, 0x80054982,
+    0x800549c2, 0x80054a02, 0x80054a42, 0x80054a82, 0x80054ac2, 0x80054b02, 0x80054b42, 0x80054b82,
+    0x80054bc2, 0x80054c02, 0x80054c42, 0x80054c82, 0x80054cc2, 0x80054d02, 0x80054d42, 0x80054d82,
+    0x80054dc2, 0x1b360804, 0x1b368804, 0x175a8804, 0x175b0804, 0x17a18804, 0x17a10804, 0x17a28804,
+    0x17bf8804, 0x17c00804, 0x18160804,
+];
+
+/// Ranges of characters whose implicit weights have a base other than the one for
+/// unassigned characters: the first and last character, and the base
+pub const IMPLICIT: [(u32, u32, u32); 19] = [
+    (0x3400, 0x4dbf, 0xfb80),
+    (0x4e00, 0x9ffc, 0xfb40),
+    (0xfa0e, 0xfa0f, 0xfb40),
+    (0xfa11, 0xfa11, 0xfb40),
+    (0xfa13, 0xfa14, 0xfb40),
+    (0xfa1f, 0xfa1f, 0xfb40),
+    (0xfa21, 0xfa21, 0xfb40),
+    (0xfa23, 0xfa24, 0xfb40),
+    (0xfa27, 0xfa29, 0xfb40),
+    (0x17000, 0x18aff, 0xfb00),
+    (0x18b00, 0x18cff, 0xfb02),
+    (0x18d00, 0x18d8f, 0xfb00),
+    (0x1b170, 0x1b2ff, 0xfb01),
+    (0x20000, 0x2a6dd, 0xfb80),
+    (0x2a700, 0x2b734, 0xfb80),
+    (0x2b740, 0x2b81d, 0xfb80),
+    (0x2b820, 0x2cea1, 0xfb80),
+    (0x2ceb0, 0x2ebe0, 0xfb80),
+    (0x30000, 0x3134a, 0xfb80),
+];
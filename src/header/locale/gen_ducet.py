@@ -0,0 +1,180 @@
+#!/usr/bin/env python3
+"""Generates ducet.rs, the Default Unicode Collation Element Table that collate.rs sorts with.
+
+Each collation element is packed into 31 bits: the primary weight in the top 16, then the
+secondary in 9 bits, the tertiary in 5 bits and whether the element is variable (punctuation and
+symbols, which "shifted" collation only looks at on the fourth level) in the lowest bit.
+
+The table is read from allkeys.txt, which comes from https://www.unicode.org/Public/UCA/ and is
+also installed with Perl's Unicode::Collate. Characters that are not in it get implicit weights
+computed from the code point; which base weight a CJK ideograph uses depends on the
+Unified_Ideograph property, which is taken from the character names in the unicodedata module.
+Both allkeys.txt and unicodedata have to be of UNICODE_VERSION; Perl 5.36 installs that
+allkeys.txt, and Python 3.9 has that unicodedata:
+
+    python3.9 src/header/locale/gen_ducet.py [path/to/allkeys.txt]
+"""
+
+import os
+import re
+import sys
+import unicodedata
+
+DEFAULT_ALLKEYS = "/usr/share/perl/5.36.0/Unicode/Collate/allkeys.txt"
+
+# Also the version of the wctype tables, which wctype/gen_ctype.py and wctype/gen_width.py make.
+# Characters the collation order does not know about must not be letters to iswalpha().
+UNICODE_VERSION = "13.0.0"
+
+# The top bit of an entry in ELEMENTS says that it points into EXPANSIONS instead
+EXPANSION_FLAG = 1 << 31
+
+# Compatibility ideographs that are unified ideographs all the same, as listed in PropList.txt
+UNIFIED_COMPATIBILITY = {
+    0xFA0E, 0xFA0F, 0xFA11, 0xFA13, 0xFA14, 0xFA1F,
+    0xFA21, 0xFA23, 0xFA24, 0xFA27, 0xFA28, 0xFA29,
+}
+
+
+def pack(element):
+    variable, primary, secondary, tertiary = element
+    if primary > 0xFFFF or secondary > 0x1FF or tertiary > 0x1F:
+        raise ValueError("weight out of range: {}".format(element))
+    return primary << 15 | secondary << 6 | tertiary << 1 | variable
+
+
+def parse(path):
+    version = None
+    implicit = []
+    entries = {}
+    element = re.compile(r"\[([.*])([0-9A-F]{4})\.([0-9A-F]{4})\.([0-9A-F]{4})\]")
+    with open(path) as f:
+        for line in f:
+            line = line.split("#")[0].strip()
+            if not line:
+                continue
+            if line.startswith("@version"):
+                version = line.split()[1]
+            elif line.startswith("@implicitweights"):
+                first, last, base = re.match(
+                    r"@implicitweights ([0-9A-F]+)\.\.([0-9A-F]+); ([0-9A-F]+)", line
+                ).groups()
+                implicit.append((int(first, 16), int(last, 16), int(base, 16)))
+            elif not line.startswith("@"):
+                chars, weights = line.split(";")
+                chars = tuple(int(c, 16) for c in chars.split())
+                entries[chars] = [
+                    (int(v == "*"), int(p, 16), int(s, 16), int(t, 16))
+                    for v, p, s, t in element.findall(weights)
+                ]
+    return version, implicit, entries
+
+
+def ideographs():
+    """Ranges of unified ideographs with their implicit base weights"""
+    ranges = []
+    for c in range(0x110000):
+        if 0xD800 <= c <= 0xDFFF:
+            continue
+        name = unicodedata.name(chr(c), "")
+        if not name.startswith("CJK UNIFIED IDEOGRAPH-") and c not in UNIFIED_COMPATIBILITY:
+            continue
+        core = 0x4E00 <= c <= 0x9FFF or 0xF900 <= c <= 0xFAFF
+        base = 0xFB40 if core else 0xFB80
+        if ranges and ranges[-1][1] == c - 1 and ranges[-1][2] == base:
+            ranges[-1][1] = c
+        else:
+            ranges.append([c, c, base])
+    return [tuple(r) for r in ranges]
+
+
+def items(values, fmt, indent="   "):
+    """Fills lines with numbers, the way rustfmt lays them out"""
+    lines = []
+    line = indent
+    for value in values:
+        item = " " + fmt(value) + ","
+        if len(line) + len(item) > 99:
+            lines.append(line)
+            line = indent
+        line += item
+    lines.append(line)
+    return "\n".join(lines)
+
+
+def rows(values, fmt):
+    """One item per line, which is how rustfmt lays out arrays and tuples"""
+    return "\n".join("    " + fmt(value) + "," for value in values)
+
+
+def main():
+    path = sys.argv[1] if len(sys.argv) > 1 else DEFAULT_ALLKEYS
+    version, implicit, entries = parse(path)
+    for name, found in (("allkeys.txt", version), ("Python", unicodedata.unidata_version)):
+        if found != UNICODE_VERSION:
+            raise SystemExit("{} has Unicode {}, not {}".format(name, found, UNICODE_VERSION))
+
+    expansions = []
+
+    def encode(elements):
+        if len(elements) == 1:
+            return pack(elements[0])
+        start = len(expansions)
+        expansions.extend(pack(e) for e in elements)
+        if start >= 1 << 16 or len(elements) >= 1 << 5:
+            raise ValueError("expansion out of range")
+        return EXPANSION_FLAG | start << 5 | len(elements)
+
+    singles = sorted((k[0], v) for k, v in entries.items() if len(k) == 1)
+    chars = [c for c, _ in singles]
+    elements = [encode(v) for _, v in singles]
+
+    contractions = sorted((k, v) for k, v in entries.items() if len(k) > 1)
+    if max(len(k) for k, _ in contractions) > 3:
+        raise ValueError("contractions of more than three characters")
+    sequences = [k + (0,) * (3 - len(k)) for k, _ in contractions]
+    contraction_elements = [encode(v) for _, v in contractions]
+
+    implicit = sorted(implicit + ideographs())
+
+    out = os.path.join(os.path.dirname(os.path.abspath(__file__)), "ducet.rs")
+    with open(out, "w") as f:
+        f.write("// Generated by gen_ducet.py from allkeys.txt {}, do not edit\n\n".format(version))
+        f.write("/// Characters that have collation elements of their own, in order\n")
+        f.write("pub const CHARS: [u32; {}] = [\n".format(len(chars)))
+        f.write(items(chars, "0x{:x}".format))
+        f.write("\n];\n\n")
+        f.write(
+            "/// The collation elements of each of `CHARS`: one packed element, or with the top bit\n"
+            "/// set, the start and the number of elements in `EXPANSIONS`\n"
+        )
+        f.write("pub const ELEMENTS: [u32; {}] = [\n".format(len(elements)))
+        f.write(items(elements, "0x{:x}".format))
+        f.write("\n];\n\n")
+        f.write("/// Collation elements of characters that have more than one\n")
+        f.write("pub const EXPANSIONS: [u32; {}] = [\n".format(len(expansions)))
+        f.write(items(expansions, "0x{:x}".format))
+        f.write("\n];\n\n")
+        f.write(
+            "/// Sequences of two or three characters that collate as one, in order, with pairs ending\n"
+            "/// in 0\n"
+        )
+        f.write("pub const CONTRACTIONS: [[u32; 3]; {}] = [\n".format(len(sequences)))
+        f.write(rows(sequences, lambda s: "[{}]".format(", ".join("0x{:x}".format(c) for c in s))))
+        f.write("\n];\n\n")
+        f.write("/// The collation elements of each of `CONTRACTIONS`, like `ELEMENTS`\n")
+        f.write(
+            "pub const CONTRACTION_ELEMENTS: [u32; {}] = [\n".format(len(contraction_elements))
+        )
+        f.write(items(contraction_elements, "0x{:x}".format))
+        f.write("\n];\n\n")
+        f.write(
+            "/// Ranges of characters whose implicit weights have a base other than the one for\n"
+            "/// unassigned characters: the first and last character, and the base\n"
+        )
+        f.write("pub const IMPLICIT: [(u32, u32, u32); {}] = [\n".format(len(implicit)))
+        f.write(rows(implicit, lambda r: "(0x{:x}, 0x{:x}, 0x{:x})".format(*r)))
+        f.write("\n];\n")
+
+
+main()
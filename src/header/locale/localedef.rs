@@ -0,0 +1,696 @@
+//! Reader for the subset of the POSIX localedef source format that relibc uses, following
+//! https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap07.html#tag_07_03
+//!
+//! Unknown categories and keywords are skipped, so sources written for other C libraries (such
+//! as the ones in /usr/share/i18n/locales) can be read as well. The character set is taken from
+//! the locale name. In LC_CTYPE, the standard classes always follow Unicode, and only the case
+//! mappings, the classes and mappings of the locale's own, and its output digits are read. An LC_COLLATE section
+//! turns on Unicode collation, with the changes to the DUCET order described in
+//! `collate::Tailoring`.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{char, convert::TryFrom, str};
+
+use crate::{c_str::CStr, platform::types::*};
+
+use super::{
+    collate::{Tailored, Tailoring},
+    data::{self, CharClass, CharMap, Codeset, CtypeData, LocaleData},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Category {
+    Ctype,
+    Collate,
+    Messages,
+    Monetary,
+    Numeric,
+    Time,
+    Other,
+}
+
+impl Category {
+    fn from_name(name: &[u8]) -> Option<Self> {
+        Some(match name {
+            b"LC_CTYPE" => Category::Ctype,
+            b"LC_COLLATE" => Category::Collate,
+            b"LC_MESSAGES" => Category::Messages,
+            b"LC_MONETARY" => Category::Monetary,
+            b"LC_NUMERIC" => Category::Numeric,
+            b"LC_TIME" => Category::Time,
+            // glibc extensions such as LC_ADDRESS or LC_PAPER
+            _ if name.starts_with(b"LC_") => Category::Other,
+            _ => return None,
+        })
+    }
+}
+
+enum Operand {
+    Str(Vec<u8>),
+    Int(c_int),
+}
+
+/// What the LC_CTYPE section of a source defines on top of the built-in Unicode data.
+#[derive(Default)]
+struct Ctype {
+    toupper: Vec<(u32, u32)>,
+    tolower: Vec<(u32, u32)>,
+    totitle: Vec<(u32, u32)>,
+    classes: Vec<CharClass>,
+    maps: Vec<CharMap>,
+    outdigits: Vec<u32>,
+    /// Names declared by `charclass` and `charconv`, which are then used as keywords
+    charclass: Vec<Vec<u8>>,
+    charconv: Vec<Vec<u8>>,
+}
+
+impl Ctype {
+    fn class(&mut self, name: &[u8], ranges: Vec<(u32, u32)>) {
+        self.classes.retain(|class| class.name != name);
+        self.classes.push(CharClass {
+            name: name.to_vec(),
+            ranges: sorted(ranges),
+        });
+    }
+
+    fn map(&mut self, name: &[u8], pairs: Vec<(u32, u32)>) {
+        let list = match name {
+            b"toupper" => &mut self.toupper,
+            b"tolower" => &mut self.tolower,
+            b"totitle" => &mut self.totitle,
+            _ => {
+                self.maps.retain(|map| map.name != name);
+                self.maps.push(CharMap {
+                    name: name.to_vec(),
+                    pairs: sorted(pairs),
+                });
+                return;
+            }
+        };
+        list.extend(pairs);
+    }
+
+    /// Adds the definitions to those of `old`, which come from a copied locale.
+    fn finish(self, old: CtypeData) -> CtypeData {
+        fn merge(old: &'static [(u32, u32)], new: Vec<(u32, u32)>) -> &'static [(u32, u32)] {
+            if new.is_empty() {
+                return old;
+            }
+            // The later of two pairs for the same character wins
+            let mut all: Vec<(u32, u32)> = old.iter().copied().chain(new).rev().collect();
+            all.sort_by_key(|&(from, _)| from);
+            all.dedup_by_key(|&mut (from, _)| from);
+            Box::leak(all.into_boxed_slice())
+        }
+
+        let Ctype {
+            toupper,
+            tolower,
+            totitle,
+            classes,
+            maps,
+            outdigits,
+            ..
+        } = self;
+        let mut data = CtypeData {
+            toupper: merge(old.toupper, toupper),
+            tolower: merge(old.tolower, tolower),
+            totitle: merge(old.totitle, totitle),
+            ..old
+        };
+        if !outdigits.is_empty() {
+            data.outdigits = Box::leak(outdigits.into_boxed_slice());
+        }
+        // Classes and mappings of this source replace those of the same name
+        if !classes.is_empty() {
+            let mut all: Vec<CharClass> = old
+                .classes
+                .iter()
+                .filter(|old| classes.iter().all(|new| new.name != old.name))
+                .cloned()
+                .collect();
+            all.extend(classes);
+            data.classes = Box::leak(all.into_boxed_slice());
+        }
+        if !maps.is_empty() {
+            let mut all: Vec<CharMap> = old
+                .maps
+                .iter()
+                .filter(|old| maps.iter().all(|new| new.name != old.name))
+                .cloned()
+                .collect();
+            all.extend(maps);
+            data.maps = Box::leak(all.into_boxed_slice());
+        }
+        data
+    }
+}
+
+struct Parser {
+    codeset: Codeset,
+    escape_char: u8,
+    depth: usize,
+}
+
+/// Parses the locale source `source`, starting from the "C" locale for everything it does not
+/// define. Strings are converted to `codeset`.
+pub fn parse(source: &[u8], codeset: Codeset, depth: usize) -> LocaleData {
+    let mut data = data::c_with_codeset(codeset);
+    let mut parser = Parser {
+        codeset,
+        escape_char: b'\\',
+        depth,
+    };
+    let mut comment_char = b'#';
+    let mut category = None;
+    let mut ctype = Ctype::default();
+    let mut tailoring = Tailoring::default();
+
+    let mut lines = source.split(|&b| b == b'\n');
+    while let Some(first) = lines.next() {
+        if trim(first).first() == Some(&comment_char) {
+            continue;
+        }
+
+        // Join lines ending with the escape character
+        let mut line = first.to_vec();
+        while line.last() == Some(&parser.escape_char) {
+            line.pop();
+            match lines.next() {
+                Some(next) => line.extend_from_slice(next),
+                None => break,
+            }
+        }
+
+        let line = trim(&line);
+        if line.is_empty() {
+            continue;
+        }
+
+        let (keyword, operands) = match line.iter().position(|b| b.is_ascii_whitespace()) {
+            Some(i) => (&line[..i], trim(&line[i..])),
+            None => (line, &[][..]),
+        };
+
+        match (keyword, category) {
+            (b"comment_char", None) => {
+                if let Some(&c) = operands.first() {
+                    comment_char = c;
+                }
+            }
+            (b"escape_char", None) => {
+                if let Some(&c) = operands.first() {
+                    parser.escape_char = c;
+                }
+            }
+            (b"END", Some(_)) => category = None,
+            (_, None) => {
+                category = Category::from_name(keyword);
+                if category == Some(Category::Collate) {
+                    data.collate.unicode = true;
+                }
+            }
+            (b"copy", Some(current @ (Category::Ctype | Category::Collate))) => {
+                if let Some(operands) = parser.operands(operands) {
+                    parser.keyword(&mut data, current, keyword, operands);
+                }
+            }
+            (_, Some(Category::Ctype)) => parser.ctype(&mut ctype, keyword, operands),
+            (_, Some(Category::Collate)) => parser.collate(&mut tailoring, keyword, operands),
+            (_, Some(Category::Other)) => (),
+            (_, Some(current)) => {
+                if let Some(operands) = parser.operands(operands) {
+                    parser.keyword(&mut data, current, keyword, operands);
+                }
+            }
+        }
+    }
+
+    data.ctype = ctype.finish(data.ctype);
+
+    let tailored = tailoring.finish();
+    if !tailored.is_empty() {
+        // The lines of this source take precedence over those of a copied locale
+        let mut all: Vec<Tailored> = data
+            .collate
+            .tailoring
+            .iter()
+            .filter(|old| tailored.iter().all(|new| new.chars != old.chars))
+            .cloned()
+            .collect();
+        all.extend(tailored);
+        data.collate.tailoring = Box::leak(all.into_boxed_slice());
+    }
+
+    data
+}
+
+impl Parser {
+    fn keyword(
+        &self,
+        data: &mut LocaleData,
+        category: Category,
+        keyword: &[u8],
+        ops: Vec<Operand>,
+    ) {
+        if keyword == b"copy" {
+            let name = match self.string(ops) {
+                Some(name) => name,
+                None => return,
+            };
+            // glibc's rendering of the Unicode classes and mappings, which are built in, and of
+            // ISO 14651, which has the same order as the DUCET
+            match (category, name.to_bytes()) {
+                (Category::Ctype, b"i18n" | b"i18n_ctype") => return,
+                (Category::Collate, b"iso14651_t1") => return,
+                _ => (),
+            }
+            if let Some(other) = data::load_copy(name.to_bytes(), self.codeset, self.depth) {
+                match category {
+                    Category::Ctype => data.ctype = other.ctype,
+                    Category::Collate => data.collate = other.collate,
+                    Category::Messages => data.messages = other.messages,
+                    Category::Monetary => data.monetary = other.monetary,
+                    Category::Numeric => data.numeric = other.numeric,
+                    Category::Time => data.time = other.time,
+                    Category::Other => (),
+                }
+            }
+            return;
+        }
+
+        macro_rules! fields {
+            ($($category:ident => $section:ident { $($field:ident: $kind:ident),* $(,)? })*) => {
+                match (category, keyword) {
+                    $($((Category::$category, kw) if kw == stringify!($field).as_bytes() => {
+                        if let Some(value) = self.$kind(ops) {
+                            data.$section.$field = value;
+                        }
+                    })*)*
+                    _ => (),
+                }
+            };
+        }
+
+        fields! {
+            Messages => messages {
+                yesexpr: string,
+                noexpr: string,
+                yesstr: string,
+                nostr: string,
+            }
+            Monetary => monetary {
+                int_curr_symbol: string,
+                currency_symbol: string,
+                mon_decimal_point: string,
+                mon_thousands_sep: string,
+                mon_grouping: grouping,
+                positive_sign: string,
+                negative_sign: string,
+                int_frac_digits: int,
+                frac_digits: int,
+                p_cs_precedes: int,
+                p_sep_by_space: int,
+                n_cs_precedes: int,
+                n_sep_by_space: int,
+                p_sign_posn: int,
+                n_sign_posn: int,
+                int_p_cs_precedes: int,
+                int_p_sep_by_space: int,
+                int_n_cs_precedes: int,
+                int_n_sep_by_space: int,
+                int_p_sign_posn: int,
+                int_n_sign_posn: int,
+            }
+            Numeric => numeric {
+                decimal_point: string,
+                thousands_sep: string,
+                grouping: grouping,
+            }
+            Time => time {
+                abday: strings,
+                day: strings,
+                abmon: strings,
+                mon: strings,
+                am_pm: strings,
+                d_t_fmt: string,
+                d_fmt: string,
+                t_fmt: string,
+                t_fmt_ampm: string,
+            }
+        }
+    }
+
+    /// Reads the case mappings and the classes and mappings a locale defines itself from a line of
+    /// the LC_CTYPE section. The standard classes are skipped, as they are built in.
+    fn ctype(&self, ctype: &mut Ctype, keyword: &[u8], operands: &[u8]) {
+        match keyword {
+            b"toupper" | b"tolower" => {
+                if let Some(pairs) = pairs(operands) {
+                    ctype.map(keyword, pairs);
+                }
+            }
+            b"class" | b"map" => {
+                // glibc sources also leave the name unquoted
+                let (name, rest) = match operands.strip_prefix(b"\"") {
+                    Some(operands) => match self.quoted(operands) {
+                        Some((Operand::Str(name), rest)) => (name, trim(rest)),
+                        _ => return,
+                    },
+                    None => {
+                        let end = operands
+                            .iter()
+                            .position(|&b| b == b';')
+                            .unwrap_or(operands.len());
+                        (trim(&operands[..end]).to_vec(), &operands[end..])
+                    }
+                };
+                let rest = trim(rest.strip_prefix(b";").unwrap_or(rest));
+                if keyword == b"class" {
+                    if let Some(ranges) = char_ranges(rest) {
+                        ctype.class(&name, ranges);
+                    }
+                } else if let Some(pairs) = pairs(rest) {
+                    ctype.map(&name, pairs);
+                }
+            }
+            b"outdigit" => {
+                let ranges = match char_ranges(operands) {
+                    Some(ranges) => ranges,
+                    None => return,
+                };
+                let digits: Vec<u32> = ranges
+                    .into_iter()
+                    .flat_map(|(first, last)| first..=last)
+                    .take(11)
+                    .collect();
+                if digits.len() == 10 {
+                    ctype.outdigits = digits;
+                }
+            }
+            b"charclass" => ctype.charclass.extend(names(operands)),
+            b"charconv" => ctype.charconv.extend(names(operands)),
+            _ if ctype.charclass.iter().any(|name| name == keyword) => {
+                if let Some(ranges) = char_ranges(operands) {
+                    ctype.class(keyword, ranges);
+                }
+            }
+            _ if ctype.charconv.iter().any(|name| name == keyword) => {
+                if let Some(pairs) = pairs(operands) {
+                    ctype.map(keyword, pairs);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Passes a line of the LC_COLLATE section on to `tailoring`.
+    fn collate(&self, tailoring: &mut Tailoring, keyword: &[u8], operands: &[u8]) {
+        match keyword {
+            b"collating-element" => {
+                let (name, rest) = match symbol(operands) {
+                    Some(symbol) => symbol,
+                    None => return,
+                };
+                let rest = trim(rest);
+                let chars = match rest.strip_prefix(b"from") {
+                    Some(chars) => trim(chars),
+                    None => return,
+                };
+                if let Some((Operand::Str(chars), _)) = chars
+                    .strip_prefix(b"\"")
+                    .and_then(|chars| self.quoted(chars))
+                {
+                    let chars = String::from_utf8_lossy(&chars);
+                    tailoring.collating_element(name, chars.chars().map(|c| c as u32).collect());
+                }
+            }
+            b"reorder-after" => match symbol(operands) {
+                Some((anchor, _)) => tailoring.reorder_after(anchor),
+                None => tailoring.reorder_end(),
+            },
+            b"reorder-end" => tailoring.reorder_end(),
+            _ => {
+                let name = match symbol(keyword) {
+                    Some((name, b"")) => name,
+                    _ => return,
+                };
+                // The weights end at the first blank, as comments can follow them
+                let weights = operands
+                    .split(|b| b.is_ascii_whitespace())
+                    .next()
+                    .unwrap_or(&[]);
+                if weights.is_empty() {
+                    tailoring.line(name, None);
+                } else if let Some(primaries) = primaries(weights) {
+                    tailoring.line(name, Some(primaries));
+                }
+            }
+        }
+    }
+
+    /// Splits the operands of a keyword, which are separated by semicolons.
+    fn operands(&self, mut line: &[u8]) -> Option<Vec<Operand>> {
+        let mut operands = Vec::new();
+        while !line.is_empty() {
+            let (operand, rest) = if line[0] == b'"' {
+                self.quoted(&line[1..])?
+            } else {
+                let end = line
+                    .iter()
+                    .position(|&b| b == b';' || b.is_ascii_whitespace())
+                    .unwrap_or(line.len());
+                let value = str::from_utf8(&line[..end]).ok()?.parse().ok()?;
+                (Operand::Int(value), &line[end..])
+            };
+            operands.push(operand);
+
+            line = trim(rest);
+            match line.first() {
+                Some(b';') => line = trim(&line[1..]),
+                Some(_) => return None,
+                None => (),
+            }
+        }
+        Some(operands)
+    }
+
+    /// Reads a string up to its closing quote, returning it as UTF-8 along with what follows.
+    fn quoted<'a>(&self, line: &'a [u8]) -> Option<(Operand, &'a [u8])> {
+        let mut value = Vec::new();
+        let mut i = 0;
+        loop {
+            match *line.get(i)? {
+                b'"' => return Some((Operand::Str(value), &line[i + 1..])),
+                b'<' => {
+                    let len = line[i + 1..].iter().position(|&b| b == b'>')?;
+                    let c = symbolic_char(&line[i + 1..i + 1 + len])?;
+                    let mut buf = [0; 4];
+                    value.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                    i += len + 2;
+                }
+                b if b == self.escape_char => {
+                    let (byte, len) = escaped_byte(&line[i + 1..])?;
+                    value.push(byte);
+                    i += len + 1;
+                }
+                b => {
+                    value.push(b);
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    /// Converts a string from the source, which is UTF-8, to the locale's character set.
+    fn convert(&self, value: Vec<u8>) -> &'static CStr {
+        match self.codeset {
+            Codeset::Utf8 | Codeset::Ascii => data::leak_c_str(value),
+            Codeset::Latin1 => data::leak_c_str(
+                String::from_utf8_lossy(&value)
+                    .chars()
+                    .map(|c| u8::try_from(c as u32).unwrap_or(b'?'))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn string(&self, ops: Vec<Operand>) -> Option<&'static CStr> {
+        match <[Operand; 1]>::try_from(ops).ok()? {
+            [Operand::Str(value)] => Some(self.convert(value)),
+            _ => None,
+        }
+    }
+
+    fn strings<const N: usize>(&self, ops: Vec<Operand>) -> Option<[&'static CStr; N]> {
+        let mut values = Vec::with_capacity(N);
+        for op in ops {
+            match op {
+                Operand::Str(value) => values.push(self.convert(value)),
+                Operand::Int(_) => return None,
+            }
+        }
+        <[&'static CStr; N]>::try_from(values).ok()
+    }
+
+    /// A single number, where -1 stands for a value that is not available in the locale.
+    fn int(&self, ops: Vec<Operand>) -> Option<c_char> {
+        match <[Operand; 1]>::try_from(ops).ok()? {
+            [Operand::Int(-1)] => Some(c_char::max_value()),
+            [Operand::Int(value)] => c_char::try_from(value).ok(),
+            _ => None,
+        }
+    }
+
+    /// A list of group sizes, encoded the way struct lconv expects them.
+    fn grouping(&self, ops: Vec<Operand>) -> Option<&'static CStr> {
+        let mut grouping = Vec::with_capacity(ops.len());
+        for op in ops {
+            match op {
+                Operand::Int(-1) => grouping.push(c_char::max_value() as u8),
+                Operand::Int(value @ 1..=126) => grouping.push(value as u8),
+                _ => return None,
+            }
+        }
+        Some(data::leak_c_str(grouping))
+    }
+}
+
+/// Resolves `<Uxxxx>` names. Other symbolic names would need a charmap, which is not supported.
+fn symbolic_char(name: &[u8]) -> Option<char> {
+    match name.split_first() {
+        Some((b'U', hex)) if !hex.is_empty() => {
+            char::from_u32(u32::from_str_radix(str::from_utf8(hex).ok()?, 16).ok()?)
+        }
+        _ => None,
+    }
+}
+
+/// Reads a list of characters and ranges of them, like `<U0041>..<U005A>;<U00C0>`.
+fn char_ranges(list: &[u8]) -> Option<Vec<(u32, u32)>> {
+    let mut ranges = Vec::new();
+    for item in list
+        .split(|&b| b == b';')
+        .map(trim)
+        .filter(|item| !item.is_empty())
+    {
+        let (first, rest) = symbol(item)?;
+        let first = symbolic_char(first)? as u32;
+        // glibc sources also have ranges with three dots
+        let rest = rest
+            .strip_prefix(b"..")
+            .map(|rest| rest.strip_prefix(b".").unwrap_or(rest));
+        let last = match rest {
+            Some(rest) => match symbol(rest)? {
+                (last, b"") => symbolic_char(last)? as u32,
+                _ => return None,
+            },
+            None => first,
+        };
+        ranges.push((first, last));
+    }
+    Some(ranges)
+}
+
+/// Reads a list of mappings from one character to another, like `(<U0061>,<U0041>);(<U0062>,
+/// <U0042>)`.
+fn pairs(list: &[u8]) -> Option<Vec<(u32, u32)>> {
+    let mut pairs = Vec::new();
+    for item in list
+        .split(|&b| b == b';')
+        .map(trim)
+        .filter(|item| !item.is_empty())
+    {
+        let item = item.strip_prefix(b"(")?.strip_suffix(b")")?;
+        let comma = item.iter().position(|&b| b == b',')?;
+        let from = match symbol(trim(&item[..comma]))? {
+            (from, b"") => symbolic_char(from)?,
+            _ => return None,
+        };
+        let to = match symbol(trim(&item[comma + 1..]))? {
+            (to, b"") => symbolic_char(to)?,
+            _ => return None,
+        };
+        pairs.push((from as u32, to as u32));
+    }
+    Some(pairs)
+}
+
+/// The names declared by a `charclass` or `charconv` line.
+fn names(list: &[u8]) -> impl Iterator<Item = Vec<u8>> + '_ {
+    list.split(|&b| b == b';')
+        .map(trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_vec())
+}
+
+/// Sorts ranges or pairs by their first character.
+fn sorted(mut list: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    list.sort_unstable();
+    list
+}
+
+/// Splits a `<name>` off the start of `s`.
+fn symbol(s: &[u8]) -> Option<(&[u8], &[u8])> {
+    let s = s.strip_prefix(b"<")?;
+    let end = s.iter().position(|&b| b == b'>')?;
+    Some((&s[..end], &s[end + 1..]))
+}
+
+/// The names of the first-level weights in the weights of an LC_COLLATE line, which are either
+/// one `<name>`, several in quotes, or IGNORE for none.
+fn primaries(weights: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let mut field = weights.split(|&b| b == b';').next()?;
+    if field == b"IGNORE" {
+        return Some(Vec::new());
+    }
+    if let Some(quoted) = field.strip_prefix(b"\"") {
+        field = quoted.strip_suffix(b"\"")?;
+    }
+    let mut names = Vec::new();
+    while !field.is_empty() {
+        let (name, rest) = symbol(field)?;
+        names.push(name.to_vec());
+        field = rest;
+    }
+    if names.is_empty() {
+        None
+    } else {
+        Some(names)
+    }
+}
+
+/// Decodes what follows an escape character: `d` with decimal digits, `x` with hexadecimal
+/// digits, octal digits, or any character standing for itself.
+fn escaped_byte(s: &[u8]) -> Option<(u8, usize)> {
+    let (radix, start) = match *s.first()? {
+        b'd' => (10, 1),
+        b'x' => (16, 1),
+        b'0'..=b'7' => (8, 0),
+        b => return Some((b, 1)),
+    };
+    let len = s[start..]
+        .iter()
+        .take(3)
+        .take_while(|b| (**b as char).is_digit(radix))
+        .count();
+    let digits = str::from_utf8(&s[start..start + len]).ok()?;
+    let byte = u8::from_str_radix(digits, radix).ok()?;
+    Some((byte, start + len))
+}
+
+fn trim(mut s: &[u8]) -> &[u8] {
+    while let Some((first, rest)) = s.split_first() {
+        if !first.is_ascii_whitespace() {
+            break;
+        }
+        s = rest;
+    }
+    while let Some((last, rest)) = s.split_last() {
+        if !last.is_ascii_whitespace() {
+            break;
+        }
+        s = rest;
+    }
+    s
+}
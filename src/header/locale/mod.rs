@@ -1,15 +1,69 @@
-//! locale implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/locale.h.html
+//! locale implementation for Redox, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/locale.h.html
 
-use core::ptr;
+use alloc::{boxed::Box, vec::Vec};
+use core::{cell::Cell, ptr};
 
-use crate::platform::types::*;
+use crate::{
+    c_str::CStr,
+    header::{
+        errno::{EINVAL, ENOENT},
+        stdlib::getenv,
+    },
+    platform::{self, types::*},
+};
+
+pub(crate) use self::data::{Codeset, CtypeData};
+use self::data::{
+    CollateData, LocaleData, MessagesData, MonetaryData, NumericData, TimeData, C_LOCALE,
+};
+
+pub(crate) mod collate;
+pub(crate) mod data;
+mod ducet;
+mod localedef;
+
+pub const LC_ALL: c_int = 0;
+pub const LC_COLLATE: c_int = 1;
+pub const LC_CTYPE: c_int = 2;
+pub const LC_MESSAGES: c_int = 3;
+pub const LC_MONETARY: c_int = 4;
+pub const LC_NUMERIC: c_int = 5;
+pub const LC_TIME: c_int = 6;
+
+pub const LC_COLLATE_MASK: c_int = 1 << 1;
+pub const LC_CTYPE_MASK: c_int = 1 << 2;
+pub const LC_MESSAGES_MASK: c_int = 1 << 3;
+pub const LC_MONETARY_MASK: c_int = 1 << 4;
+pub const LC_NUMERIC_MASK: c_int = 1 << 5;
+pub const LC_TIME_MASK: c_int = 1 << 6;
+pub const LC_ALL_MASK: c_int = 0x7e;
+
+const CATEGORIES: usize = 6;
+const CATEGORY_NAMES: [&str; CATEGORIES] = [
+    "LC_COLLATE",
+    "LC_CTYPE",
+    "LC_MESSAGES",
+    "LC_MONETARY",
+    "LC_NUMERIC",
+    "LC_TIME",
+];
+
+/// The name of a category other than LC_ALL, which is also the directory that message catalogs
+/// for it are in
+pub(crate) fn category_name(category: c_int) -> Option<&'static str> {
+    CATEGORY_NAMES
+        .get((category as usize).checked_sub(1)?)
+        .copied()
+}
+
+// Defined in bits/locale_t.h, so that every header using it can include it
+pub type locale_t = *mut c_void;
 
-const EMPTY_PTR: *const c_char = "\0" as *const _ as *const c_char;
-// Can't use &str because of the mutability
-static mut C_LOCALE: [c_char; 2] = [b'C' as c_char, 0];
+// Defined in bits/locale.h as a pointer, which cbindgen cannot express
+pub(crate) const LC_GLOBAL_LOCALE: locale_t = usize::max_value() as locale_t;
 
 #[repr(C)]
-#[no_mangle]
+#[derive(Clone, Copy)]
 pub struct lconv {
     currency_symbol: *const c_char,
     decimal_point: *const c_char,
@@ -29,40 +83,300 @@ pub struct lconv {
     p_sep_by_space: c_char,
     p_sign_posn: c_char,
     thousands_sep: *const c_char,
+    int_n_cs_precedes: c_char,
+    int_n_sep_by_space: c_char,
+    int_n_sign_posn: c_char,
+    int_p_cs_precedes: c_char,
+    int_p_sep_by_space: c_char,
+    int_p_sign_posn: c_char,
+}
+
+impl lconv {
+    const fn new(numeric: &'static NumericData, monetary: &'static MonetaryData) -> Self {
+        Self {
+            currency_symbol: monetary.currency_symbol.as_ptr(),
+            decimal_point: numeric.decimal_point.as_ptr(),
+            frac_digits: monetary.frac_digits,
+            grouping: numeric.grouping.as_ptr(),
+            int_curr_symbol: monetary.int_curr_symbol.as_ptr(),
+            int_frac_digits: monetary.int_frac_digits,
+            mon_decimal_point: monetary.mon_decimal_point.as_ptr(),
+            mon_grouping: monetary.mon_grouping.as_ptr(),
+            mon_thousands_sep: monetary.mon_thousands_sep.as_ptr(),
+            negative_sign: monetary.negative_sign.as_ptr(),
+            n_cs_precedes: monetary.n_cs_precedes,
+            n_sep_by_space: monetary.n_sep_by_space,
+            n_sign_posn: monetary.n_sign_posn,
+            positive_sign: monetary.positive_sign.as_ptr(),
+            p_cs_precedes: monetary.p_cs_precedes,
+            p_sep_by_space: monetary.p_sep_by_space,
+            p_sign_posn: monetary.p_sign_posn,
+            thousands_sep: numeric.thousands_sep.as_ptr(),
+            int_n_cs_precedes: monetary.int_n_cs_precedes,
+            int_n_sep_by_space: monetary.int_n_sep_by_space,
+            int_n_sign_posn: monetary.int_n_sign_posn,
+            int_p_cs_precedes: monetary.int_p_cs_precedes,
+            int_p_sep_by_space: monetary.int_p_sep_by_space,
+            int_p_sign_posn: monetary.int_p_sign_posn,
+        }
+    }
+}
+
+/// What a locale_t points to. Every category refers to the data of the locale it was last set
+/// to, which is what setlocale() reports the name of.
+#[derive(Clone)]
+pub(crate) struct Locale {
+    categories: [&'static LocaleData; CATEGORIES],
+    lconv: lconv,
+}
+
+impl Locale {
+    fn c() -> Self {
+        Self {
+            categories: [&C_LOCALE; CATEGORIES],
+            lconv: lconv::new(&C_LOCALE.numeric, &C_LOCALE.monetary),
+        }
+    }
+
+    fn data(&self, category: c_int) -> &'static LocaleData {
+        self.categories[category as usize - 1]
+    }
+
+    pub fn collate(&self) -> &'static CollateData {
+        &self.data(LC_COLLATE).collate
+    }
+
+    pub fn ctype(&self) -> &'static CtypeData {
+        &self.data(LC_CTYPE).ctype
+    }
+
+    /// The name of the locale a category is set to
+    pub fn name(&self, category: c_int) -> &'static CStr {
+        self.data(category).name
+    }
+
+    pub fn messages(&self) -> &'static MessagesData {
+        &self.data(LC_MESSAGES).messages
+    }
+
+    pub fn monetary(&self) -> &'static MonetaryData {
+        &self.data(LC_MONETARY).monetary
+    }
+
+    pub fn numeric(&self) -> &'static NumericData {
+        &self.data(LC_NUMERIC).numeric
+    }
+
+    pub fn time(&self) -> &'static TimeData {
+        &self.data(LC_TIME).time
+    }
+
+    /// Switches the categories that have data in `new` over to it.
+    fn set(&mut self, new: &[Option<&'static LocaleData>; CATEGORIES]) {
+        for (category, data) in self.categories.iter_mut().zip(new.iter()) {
+            if let Some(data) = *data {
+                *category = data;
+            }
+        }
+        self.lconv = lconv::new(self.numeric(), self.monetary());
+    }
 }
-unsafe impl Sync for lconv {}
-
-static CURRENT_LOCALE: lconv = lconv {
-    currency_symbol: EMPTY_PTR,
-    decimal_point: ".\0" as *const _ as *const c_char,
-    frac_digits: c_char::max_value(),
-    grouping: EMPTY_PTR,
-    int_curr_symbol: EMPTY_PTR,
-    int_frac_digits: c_char::max_value(),
-    mon_decimal_point: EMPTY_PTR,
-    mon_grouping: EMPTY_PTR,
-    mon_thousands_sep: EMPTY_PTR,
-    negative_sign: EMPTY_PTR,
-    n_cs_precedes: c_char::max_value(),
-    n_sep_by_space: c_char::max_value(),
-    n_sign_posn: c_char::max_value(),
-    positive_sign: EMPTY_PTR,
-    p_cs_precedes: c_char::max_value(),
-    p_sep_by_space: c_char::max_value(),
-    p_sign_posn: c_char::max_value(),
-    thousands_sep: EMPTY_PTR,
+
+static mut GLOBAL_LOCALE: Locale = Locale {
+    categories: [&C_LOCALE; CATEGORIES],
+    lconv: lconv::new(&C_LOCALE.numeric, &C_LOCALE.monetary),
 };
 
+#[thread_local]
+static THREAD_LOCALE: Cell<*mut Locale> = Cell::new(ptr::null_mut());
+
+/// The buffer that setlocale() returns composite names in.
+static mut LOCALE_NAME: Vec<u8> = Vec::new();
+
+/// The locale of the calling thread, as set by uselocale(), or the global locale.
+pub(crate) fn current() -> &'static Locale {
+    let locale = THREAD_LOCALE.get();
+    unsafe {
+        if locale.is_null() {
+            &GLOBAL_LOCALE
+        } else {
+            &*locale
+        }
+    }
+}
+
+/// The locale behind a locale_t passed to one of the `_l` functions.
+pub(crate) unsafe fn get(locale: locale_t) -> &'static Locale {
+    if locale == LC_GLOBAL_LOCALE {
+        &GLOBAL_LOCALE
+    } else {
+        &*locale.cast::<Locale>()
+    }
+}
+
+/// The name of the locale that `category` takes its data from when it is set to "", as told by
+/// the environment.
+unsafe fn name_from_env(category: usize) -> &'static CStr {
+    let mut var = Vec::with_capacity(12);
+    var.extend_from_slice(CATEGORY_NAMES[category].as_bytes());
+    var.push(0);
+
+    for name in [
+        c_str!("LC_ALL"),
+        CStr::from_bytes_with_nul_unchecked(&var),
+        c_str!("LANG"),
+    ]
+    .iter()
+    {
+        let value = getenv(name.as_ptr());
+        if !value.is_null() && *value != 0 {
+            return CStr::from_ptr(value);
+        }
+    }
+    c_str!("C")
+}
+
+/// Looks up the data for every category in `mask`. `name` is either the name of a single
+/// locale, "" for the one configured in the environment, or a composite name as returned by
+/// setlocale(LC_ALL, NULL).
+unsafe fn resolve(mask: c_int, name: &CStr) -> Option<[Option<&'static LocaleData>; CATEGORIES]> {
+    let mut new = [None; CATEGORIES];
+    let bytes = name.to_bytes();
+
+    if bytes.contains(&b'=') {
+        for part in bytes.split(|&b| b == b';') {
+            let eq = part.iter().position(|&b| b == b'=')?;
+            let category = CATEGORY_NAMES
+                .iter()
+                .position(|cat| cat.as_bytes() == &part[..eq])?;
+            if mask & (1 << (category + 1)) != 0 {
+                let mut name = part[eq + 1..].to_vec();
+                name.push(0);
+                new[category] = Some(data::find(CStr::from_bytes_with_nul_unchecked(&name))?);
+            }
+        }
+        // Every category being set has to be named
+        for (category, data) in new.iter().enumerate() {
+            if mask & (1 << (category + 1)) != 0 && data.is_none() {
+                return None;
+            }
+        }
+    } else {
+        for (category, data) in new.iter_mut().enumerate() {
+            if mask & (1 << (category + 1)) != 0 {
+                let name = if bytes.is_empty() {
+                    name_from_env(category)
+                } else {
+                    name
+                };
+                *data = Some(data::find(name)?);
+            }
+        }
+    }
+
+    Some(new)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn duplocale(locobj: locale_t) -> locale_t {
+    Box::into_raw(Box::new(get(locobj).clone())).cast()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn freelocale(locobj: locale_t) {
+    drop(Box::from_raw(locobj.cast::<Locale>()));
+}
+
+#[no_mangle]
+pub extern "C" fn localeconv() -> *mut lconv {
+    &current().lconv as *const lconv as *mut lconv
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn newlocale(
+    category_mask: c_int,
+    locale: *const c_char,
+    base: locale_t,
+) -> locale_t {
+    if category_mask & !LC_ALL_MASK != 0 || locale.is_null() {
+        platform::errno = EINVAL;
+        return ptr::null_mut();
+    }
+
+    let new = match resolve(category_mask, CStr::from_ptr(locale)) {
+        Some(new) => new,
+        None => {
+            platform::errno = ENOENT;
+            return ptr::null_mut();
+        }
+    };
+
+    // The base locale is reused for the new one, as POSIX allows
+    let locobj = if base.is_null() {
+        Box::into_raw(Box::new(Locale::c()))
+    } else {
+        base.cast::<Locale>()
+    };
+    (*locobj).set(&new);
+    locobj.cast()
+}
+
 #[no_mangle]
-pub extern "C" fn localeconv() -> *const lconv {
-    &CURRENT_LOCALE as *const _
+pub unsafe extern "C" fn setlocale(category: c_int, locale: *const c_char) -> *mut c_char {
+    let mask = match category {
+        LC_ALL => LC_ALL_MASK,
+        LC_COLLATE..=LC_TIME => 1 << category,
+        _ => return ptr::null_mut(),
+    };
+
+    if !locale.is_null() {
+        match resolve(mask, CStr::from_ptr(locale)) {
+            Some(new) => GLOBAL_LOCALE.set(&new),
+            None => return ptr::null_mut(),
+        }
+    }
+
+    if category != LC_ALL {
+        return GLOBAL_LOCALE.data(category).name.as_ptr() as *mut c_char;
+    }
+
+    let first = GLOBAL_LOCALE.categories[0];
+    if GLOBAL_LOCALE
+        .categories
+        .iter()
+        .all(|data| data.name == first.name)
+    {
+        return first.name.as_ptr() as *mut c_char;
+    }
+
+    // The categories differ, so describe each of them in a way that setlocale() accepts back
+    LOCALE_NAME.clear();
+    for (i, data) in GLOBAL_LOCALE.categories.iter().enumerate() {
+        if i > 0 {
+            LOCALE_NAME.push(b';');
+        }
+        LOCALE_NAME.extend_from_slice(CATEGORY_NAMES[i].as_bytes());
+        LOCALE_NAME.push(b'=');
+        LOCALE_NAME.extend_from_slice(data.name.to_bytes());
+    }
+    LOCALE_NAME.push(0);
+    LOCALE_NAME.as_mut_ptr().cast()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn setlocale(_option: c_int, val: *const c_char) -> *mut c_char {
-    if val.is_null() {
-        return C_LOCALE.as_mut_ptr() as *mut c_char;
+pub unsafe extern "C" fn uselocale(newloc: locale_t) -> locale_t {
+    let old = THREAD_LOCALE.get();
+    if !newloc.is_null() {
+        THREAD_LOCALE.set(if newloc == LC_GLOBAL_LOCALE {
+            ptr::null_mut()
+        } else {
+            newloc.cast()
+        });
+    }
+
+    if old.is_null() {
+        LC_GLOBAL_LOCALE
+    } else {
+        old.cast()
     }
-    // TODO actually implement
-    ptr::null_mut()
 }
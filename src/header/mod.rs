@@ -14,21 +14,27 @@ pub mod float;
 pub mod fnmatch;
 pub mod getopt;
 pub mod grp;
+pub mod iconv;
 pub mod inttypes;
 pub mod libgen;
+pub mod libintl;
 pub mod limits;
 pub mod locale;
 pub mod netdb;
 pub mod netinet_in;
 pub mod netinet_ip;
 pub mod netinet_tcp;
+pub mod nl_types;
 pub mod poll;
+pub mod pthread;
 pub mod pwd;
 pub mod regex;
+pub mod sched;
 pub mod semaphore;
 pub mod setjmp;
 pub mod sgtty;
 pub mod signal;
+pub mod spawn;
 pub mod stdio;
 pub mod stdlib;
 pub mod string;
@@ -37,10 +43,14 @@ pub mod sys_auxv;
 pub mod sys_epoll;
 pub mod sys_file;
 pub mod sys_ioctl;
+pub mod sys_ipc;
 pub mod sys_mman;
+pub mod sys_msg;
 pub mod sys_ptrace;
 pub mod sys_resource;
 pub mod sys_select;
+pub mod sys_sem;
+pub mod sys_shm;
 pub mod sys_socket;
 pub mod sys_stat;
 pub mod sys_statvfs;
@@ -59,6 +69,7 @@ pub mod sys_utsname;
 pub mod sys_wait;
 pub mod termios;
 pub mod time;
+pub mod uchar;
 pub mod unistd;
 pub mod utime;
 pub mod wchar;
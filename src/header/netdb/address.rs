@@ -0,0 +1,556 @@
+//! The addresses and services that getaddrinfo() combines into its results, and the addresses
+//! getnameinfo() is given, which can be of either IP version.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::{self, Vec},
+};
+use core::{fmt, mem, str, task::Poll};
+
+use crate::{
+    c_str::CStr,
+    header::{
+        arpa_inet::{format_in4, format_in6, htonl, htons, ntohs, parse_in4, parse_in6},
+        errno::EAGAIN,
+        netinet_in::{
+            in6_addr, in6addr_any, in6addr_loopback, in_addr, sockaddr_in, sockaddr_in6,
+            INADDR_ANY, INADDR_LOOPBACK, IPPROTO_TCP, IPPROTO_UDP,
+        },
+        sys_socket::{
+            constants::{AF_INET, AF_INET6, SOCK_CLOEXEC, SOCK_DGRAM, SOCK_RAW, SOCK_STREAM},
+            sa_family_t, sockaddr, socklen_t,
+        },
+    },
+    platform::{self, types::*, Pal, PalSocket, Sys},
+};
+
+use super::{
+    getservbyname, hosts,
+    lookup::{addrs_v4, addrs_v6, TYPE_A, TYPE_AAAA},
+    nsswitch::{self, Source},
+    resolv::Lookup,
+    AI_ADDRCONFIG, AI_ALL, AI_NUMERICHOST, AI_NUMERICSERV, AI_PASSIVE, AI_V4MAPPED, EAI_ADDRFAMILY,
+    EAI_AGAIN, EAI_NONAME, EAI_SERVICE, EAI_SOCKTYPE, EAI_SYSTEM,
+};
+
+#[derive(Clone, Copy)]
+pub enum Address {
+    V4(in_addr),
+    /// An IPv6 address with its scope ID, which is the interface link-local addresses are on
+    V6(in6_addr, u32),
+}
+
+impl Address {
+    /// Parses an address in numeric form. IPv6 addresses can be followed by "%" and a numeric
+    /// scope ID.
+    pub fn parse(s: &[u8]) -> Option<Address> {
+        if let Some(addr) = parse_in4(s) {
+            return Some(Address::V4(in_addr {
+                s_addr: u32::from_ne_bytes(addr),
+            }));
+        }
+        let (addr, scope_id) = match s.iter().position(|&b| b == b'%') {
+            Some(i) => {
+                let scope_id = &s[i + 1..];
+                if scope_id.is_empty() || !scope_id.iter().all(u8::is_ascii_digit) {
+                    return None;
+                }
+                (&s[..i], str::from_utf8(scope_id).ok()?.parse().ok()?)
+            }
+            None => (s, 0),
+        };
+        let s6_addr = parse_in6(addr)?;
+        Some(Address::V6(in6_addr { s6_addr }, scope_id))
+    }
+
+    /// Reads the address and the port from a socket address
+    pub unsafe fn from_sockaddr(sa: *const sockaddr, len: socklen_t) -> Option<(Address, u16)> {
+        if sa.is_null() {
+            return None;
+        }
+        match (*sa).sa_family as c_int {
+            AF_INET if len as usize >= mem::size_of::<sockaddr_in>() => {
+                let sa = &*(sa as *const sockaddr_in);
+                Some((Address::V4(sa.sin_addr), ntohs(sa.sin_port)))
+            }
+            AF_INET6 if len as usize >= mem::size_of::<sockaddr_in6>() => {
+                let sa = &*(sa as *const sockaddr_in6);
+                Some((
+                    Address::V6(sa.sin6_addr, sa.sin6_scope_id),
+                    ntohs(sa.sin6_port),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn family(&self) -> c_int {
+        match self {
+            Address::V4(_) => AF_INET,
+            Address::V6(..) => AF_INET6,
+        }
+    }
+
+    /// The address as an IPv6 one, where IPv4 addresses are mapped into ::ffff:0:0/96
+    pub fn to_v6(self) -> [u8; 16] {
+        match self {
+            Address::V4(addr) => mapped(&addr),
+            Address::V6(addr, _) => addr.s6_addr,
+        }
+    }
+
+    /// The IPv4 address that an IPv4-mapped IPv6 address stands for
+    pub fn unmapped(&self) -> Option<in_addr> {
+        match self {
+            Address::V4(addr) => Some(*addr),
+            Address::V6(addr, _) => {
+                let addr = &addr.s6_addr;
+                if addr[..12] != [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff] {
+                    return None;
+                }
+                Some(in_addr {
+                    s_addr: u32::from_ne_bytes([addr[12], addr[13], addr[14], addr[15]]),
+                })
+            }
+        }
+    }
+
+    /// Calls `f` with a socket address for the address and `port`
+    pub fn with_sockaddr<T>(
+        &self,
+        port: u16,
+        f: impl FnOnce(*const sockaddr, socklen_t) -> T,
+    ) -> T {
+        match self {
+            Address::V4(addr) => {
+                let sa = sockaddr_in {
+                    sin_family: AF_INET as sa_family_t,
+                    sin_port: htons(port),
+                    sin_addr: *addr,
+                    ..Default::default()
+                };
+                f(
+                    &sa as *const _ as *const sockaddr,
+                    mem::size_of::<sockaddr_in>() as socklen_t,
+                )
+            }
+            Address::V6(addr, scope_id) => {
+                let sa = sockaddr_in6 {
+                    sin6_family: AF_INET6 as sa_family_t,
+                    sin6_port: htons(port),
+                    sin6_addr: *addr,
+                    sin6_scope_id: *scope_id,
+                    ..Default::default()
+                };
+                f(
+                    &sa as *const _ as *const sockaddr,
+                    mem::size_of::<sockaddr_in6>() as socklen_t,
+                )
+            }
+        }
+    }
+
+    /// A socket address for the address and `port` allocated for an addrinfo, which
+    /// freeaddrinfo() tells apart by its length
+    pub fn to_sockaddr(self, port: u16) -> (*mut sockaddr, size_t) {
+        match self {
+            Address::V4(addr) => (
+                Box::into_raw(Box::new(sockaddr_in {
+                    sin_family: AF_INET as sa_family_t,
+                    sin_port: htons(port),
+                    sin_addr: addr,
+                    ..Default::default()
+                })) as *mut sockaddr,
+                mem::size_of::<sockaddr_in>(),
+            ),
+            Address::V6(addr, scope_id) => (
+                Box::into_raw(Box::new(sockaddr_in6 {
+                    sin6_family: AF_INET6 as sa_family_t,
+                    sin6_port: htons(port),
+                    sin6_addr: addr,
+                    sin6_scope_id: scope_id,
+                    ..Default::default()
+                })) as *mut sockaddr,
+                mem::size_of::<sockaddr_in6>(),
+            ),
+        }
+    }
+}
+
+/// The numeric form of the address, with the scope ID if there is one
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Address::V4(addr) => f.write_str(&format_in4(addr.s_addr.to_ne_bytes())),
+            Address::V6(addr, scope_id) => {
+                f.write_str(&format_in6(&addr.s6_addr))?;
+                if *scope_id != 0 {
+                    write!(f, "%{}", scope_id)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn mapped(addr: &in_addr) -> [u8; 16] {
+    let mut v6 = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0, 0, 0, 0];
+    v6[12..].copy_from_slice(&addr.s_addr.to_ne_bytes());
+    v6
+}
+
+/// Whether the system has addresses of `family`, for AI_ADDRCONFIG. Like in musl, this is
+/// whether a UDP socket can be connected to the loopback address of the family, which sends
+/// nothing and works without a network.
+fn configured(family: c_int) -> bool {
+    let loopback = if family == AF_INET {
+        Address::V4(in_addr {
+            s_addr: htonl(INADDR_LOOPBACK),
+        })
+    } else {
+        Address::V6(in6addr_loopback, 0)
+    };
+    unsafe {
+        let fd = Sys::socket(family, SOCK_DGRAM | SOCK_CLOEXEC, IPPROTO_UDP as c_int);
+        if fd < 0 {
+            return false;
+        }
+        let connected = loopback.with_sockaddr(0xffff, |sa, len| Sys::connect(fd, sa, len)) == 0;
+        Sys::close(fd);
+        connected
+    }
+}
+
+/// Finding the addresses of `node` for getaddrinfo(), in the order they were found. Only the
+/// name servers are ever waited for, through the sockets of lookups in an epoll instance.
+pub enum Addresses {
+    /// The result, when it was known without looking anything up, until poll() takes it
+    Known(Option<Result<Vec<Address>, c_int>>),
+    Search(Search),
+}
+
+impl Addresses {
+    pub fn new(node: Option<&CStr>, flags: c_int, family: c_int, epoll: c_int) -> Addresses {
+        let node = match node {
+            Some(node) => node.to_bytes(),
+            None => {
+                // Without a node, the addresses are the ones to listen on, or this host's own
+                let (v4, v6) = if flags & AI_PASSIVE != 0 {
+                    (INADDR_ANY, in6addr_any)
+                } else {
+                    (INADDR_LOOPBACK, in6addr_loopback)
+                };
+                let mut addrs = Vec::new();
+                if family != AF_INET {
+                    addrs.push(Address::V6(v6, 0));
+                }
+                if family != AF_INET6 {
+                    addrs.push(Address::V4(in_addr { s_addr: htonl(v4) }));
+                }
+                return Addresses::Known(Some(Ok(addrs)));
+            }
+        };
+
+        if let Some(addr) = Address::parse(node) {
+            let known = match (family, addr) {
+                (AF_INET6, Address::V4(v4)) if flags & AI_V4MAPPED != 0 => {
+                    Ok(vec![as_family(v4, family)])
+                }
+                (AF_INET6, Address::V4(_)) => Err(EAI_ADDRFAMILY),
+                (AF_INET, Address::V6(..)) => match addr.unmapped() {
+                    Some(v4) => Ok(vec![Address::V4(v4)]),
+                    None => Err(EAI_ADDRFAMILY),
+                },
+                _ => Ok(vec![addr]),
+            };
+            return Addresses::Known(Some(known));
+        }
+        if flags & AI_NUMERICHOST != 0 || node.is_empty() {
+            return Addresses::Known(Some(Err(EAI_NONAME)));
+        }
+        let name = match str::from_utf8(node) {
+            Ok(name) => name.to_string(),
+            Err(_) => return Addresses::Known(Some(Err(EAI_NONAME))),
+        };
+
+        // With AI_V4MAPPED, IPv4 addresses are looked up for IPv6 too
+        let mut v6 = family != AF_INET;
+        let mut v4 = family != AF_INET6 || flags & AI_V4MAPPED != 0;
+        if flags & AI_ADDRCONFIG != 0 {
+            v6 &= configured(AF_INET6);
+            v4 &= configured(AF_INET);
+        }
+        Addresses::Search(Search {
+            name,
+            flags,
+            family,
+            v6,
+            v4,
+            sources: nsswitch::hosts().into_iter(),
+            asking: None,
+            error: None,
+            epoll,
+        })
+    }
+
+    /// When poll() has to be called even if the epoll instance isn't ready
+    pub fn deadline(&self) -> Option<i64> {
+        match self {
+            Addresses::Known(_) => None,
+            Addresses::Search(search) => search.deadline(),
+        }
+    }
+
+    /// Goes on finding the addresses as far as it can without waiting, and returns them once
+    /// it has finished
+    pub fn poll(&mut self) -> Poll<Result<Vec<Address>, c_int>> {
+        match self {
+            Addresses::Known(known) => Poll::Ready(known.take().expect("addresses already taken")),
+            Addresses::Search(search) => search.poll(),
+        }
+    }
+}
+
+/// Looking a name up in the sources that nsswitch.conf gives, in turn until one knows it
+pub struct Search {
+    name: String,
+    flags: c_int,
+    family: c_int,
+    /// Whether addresses of each version are looked for at all
+    v6: bool,
+    v4: bool,
+    sources: vec::IntoIter<Source>,
+    /// The lookups of the name servers, while they are being asked
+    asking: Option<Asking>,
+    error: Option<c_int>,
+    epoll: c_int,
+}
+
+/// The name servers being asked for both versions of addresses at once
+struct Asking {
+    v6: Option<Lookup>,
+    v4: Option<Lookup>,
+    /// Whether IPv4 addresses are still to be looked for if there turn out to be no IPv6 ones
+    v4_fallback: bool,
+    found_v6: Vec<Address>,
+    found_v4: Vec<Address>,
+}
+
+impl Search {
+    /// Whether to look for IPv4 addresses, given whether there are IPv6 ones. With
+    /// AI_V4MAPPED, they are only wanted for IPv6 if there are none, unless AI_ALL is given.
+    fn want_v4(&self, found_v6: bool) -> bool {
+        self.v4 && (self.family != AF_INET6 || !found_v6 || self.flags & AI_ALL != 0)
+    }
+
+    fn deadline(&self) -> Option<i64> {
+        let asking = self.asking.as_ref()?;
+        let v6 = asking.v6.as_ref().and_then(Lookup::deadline);
+        let v4 = asking.v4.as_ref().and_then(Lookup::deadline);
+        v6.into_iter().chain(v4).min()
+    }
+
+    fn poll(&mut self) -> Poll<Result<Vec<Address>, c_int>> {
+        loop {
+            let addrs = if self.asking.is_some() {
+                match self.poll_asking() {
+                    Poll::Ready(addrs) => {
+                        self.asking = None;
+                        addrs
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            } else {
+                match self.sources.next() {
+                    Some(Source::Files) => self.files(),
+                    Some(Source::Dns) => {
+                        self.asking = Some(self.ask());
+                        continue;
+                    }
+                    None => return Poll::Ready(Err(self.failure())),
+                }
+            };
+            if !addrs.is_empty() {
+                return Poll::Ready(Ok(addrs));
+            }
+        }
+    }
+
+    fn files(&self) -> Vec<Address> {
+        let hosts = hosts::by_name(&self.name);
+        let mut addrs: Vec<Address> = hosts
+            .iter()
+            .filter(|host| self.v6 && host.addr.family() == AF_INET6)
+            .map(|host| host.addr)
+            .collect();
+        if self.want_v4(!addrs.is_empty()) {
+            addrs.extend(hosts.iter().filter_map(|host| match host.addr {
+                Address::V4(addr) => Some(as_family(addr, self.family)),
+                Address::V6(..) => None,
+            }));
+        }
+        addrs
+    }
+
+    /// Starts asking the name servers, about IPv4 addresses only after IPv6 ones if whether
+    /// they are wanted depends on those
+    fn ask(&self) -> Asking {
+        let v4_now = self.want_v4(self.v6);
+        Asking {
+            v6: if self.v6 {
+                Some(Lookup::new(&self.name, TYPE_AAAA, self.epoll))
+            } else {
+                None
+            },
+            v4: if v4_now {
+                Some(Lookup::new(&self.name, TYPE_A, self.epoll))
+            } else {
+                None
+            },
+            v4_fallback: !v4_now && self.want_v4(false),
+            found_v6: Vec::new(),
+            found_v4: Vec::new(),
+        }
+    }
+
+    /// Goes on asking the name servers, returning the addresses once all lookups finished
+    fn poll_asking(&mut self) -> Poll<Vec<Address>> {
+        let asking = self.asking.as_mut().unwrap();
+        if let Some(Poll::Ready(result)) = asking.v6.as_mut().map(Lookup::poll) {
+            asking.v6 = None;
+            match result {
+                Ok(records) => {
+                    asking.found_v6 = addrs_v6(records)
+                        .into_iter()
+                        .map(|addr| Address::V6(addr, 0))
+                        .collect()
+                }
+                Err(err) => self.error = Some(err),
+            }
+            if asking.v4_fallback && asking.found_v6.is_empty() {
+                asking.v4 = Some(Lookup::new(&self.name, TYPE_A, self.epoll));
+            }
+        }
+        if let Some(Poll::Ready(result)) = asking.v4.as_mut().map(Lookup::poll) {
+            asking.v4 = None;
+            match result {
+                Ok(records) => {
+                    let family = self.family;
+                    asking.found_v4 = addrs_v4(records)
+                        .into_iter()
+                        .map(|addr| as_family(addr, family))
+                        .collect();
+                }
+                Err(err) => self.error = Some(err),
+            }
+        }
+
+        if asking.v6.is_some() || asking.v4.is_some() {
+            return Poll::Pending;
+        }
+        let mut addrs = mem::take(&mut asking.found_v6);
+        addrs.append(&mut asking.found_v4);
+        Poll::Ready(addrs)
+    }
+
+    /// The error once no source knew the name
+    fn failure(&self) -> c_int {
+        match self.error {
+            // The name servers didn't answer, or failed to
+            Some(EAGAIN) => EAI_AGAIN,
+            Some(err) => {
+                unsafe { platform::errno = err };
+                EAI_SYSTEM
+            }
+            None => EAI_NONAME,
+        }
+    }
+}
+
+/// An IPv4 address for results of `family`, mapped into IPv6 for AF_INET6
+fn as_family(addr: in_addr, family: c_int) -> Address {
+    if family == AF_INET6 {
+        Address::V6(
+            in6_addr {
+                s6_addr: mapped(&addr),
+            },
+            0,
+        )
+    } else {
+        Address::V4(addr)
+    }
+}
+
+/// The socket types, protocols and ports that getaddrinfo() gives each address with, for
+/// `service` and the socket type and protocol asked for
+pub unsafe fn services(
+    service: Option<&CStr>,
+    flags: c_int,
+    socktype: c_int,
+    protocol: c_int,
+) -> Result<Vec<(c_int, c_int, u16)>, c_int> {
+    let kinds = [
+        (SOCK_STREAM, IPPROTO_TCP as c_int, c_str!("tcp")),
+        (SOCK_DGRAM, IPPROTO_UDP as c_int, c_str!("udp")),
+        // Raw sockets can be of any protocol
+        (SOCK_RAW, protocol, c_str!("")),
+    ];
+    let mut kinds: Vec<_> = kinds
+        .iter()
+        .filter(|&&(kind, kind_protocol, _)| {
+            (socktype == 0 || socktype == kind) && (protocol == 0 || protocol == kind_protocol)
+        })
+        .collect();
+    // Like in glibc, a protocol without a socket type only gives raw sockets if no other type
+    // has it
+    if socktype == 0 && protocol != 0 && kinds.len() > 1 {
+        kinds.retain(|&&(kind, _, _)| kind != SOCK_RAW);
+    }
+    if kinds.is_empty() {
+        return Err(EAI_SOCKTYPE);
+    }
+
+    let service = match service {
+        Some(service) => service,
+        None => {
+            return Ok(kinds
+                .iter()
+                .map(|&&(kind, protocol, _)| (kind, protocol, 0))
+                .collect())
+        }
+    };
+    let name = service.to_bytes();
+    if !name.is_empty() && name.iter().all(u8::is_ascii_digit) {
+        let port = str::from_utf8(name)
+            .ok()
+            .and_then(|port| port.parse::<u16>().ok())
+            .ok_or(EAI_SERVICE)?;
+        // Raw sockets have no ports, but they are only left out when asked for
+        if socktype == SOCK_RAW {
+            return Err(EAI_SERVICE);
+        }
+        return Ok(kinds
+            .iter()
+            .map(|&&(kind, protocol, _)| (kind, protocol, port))
+            .collect());
+    }
+    if flags & AI_NUMERICSERV != 0 {
+        return Err(EAI_NONAME);
+    }
+
+    let mut services = Vec::new();
+    for &&(kind, protocol, proto_name) in kinds.iter() {
+        if kind == SOCK_RAW {
+            continue;
+        }
+        let entry = getservbyname(service.as_ptr(), proto_name.as_ptr());
+        if !entry.is_null() {
+            services.push((kind, protocol, ntohs((*entry).s_port as u16)));
+        }
+    }
+    if services.is_empty() {
+        return Err(EAI_SERVICE);
+    }
+    Ok(services)
+}
@@ -0,0 +1,143 @@
+//! The space that the entries netdb returns point into. The reentrant functions put the names,
+//! aliases and addresses of an entry in a buffer the caller gives them, and the others put them
+//! in a buffer of the calling thread's own.
+
+use alloc::vec::Vec;
+use core::{mem, ptr};
+
+use crate::{
+    header::{errno::ERANGE, netinet_in::in_addr},
+    platform::types::*,
+};
+
+/// A caller's buffer, filled from the start
+pub struct Buffer {
+    buf: *mut u8,
+    len: usize,
+    used: usize,
+}
+
+impl Buffer {
+    pub unsafe fn new(buf: *mut c_char, len: size_t) -> Buffer {
+        Buffer {
+            buf: buf as *mut u8,
+            len,
+            used: 0,
+        }
+    }
+
+    fn alloc(&mut self, size: usize, align: usize) -> Result<*mut u8, c_int> {
+        let start = self.buf as usize + self.used;
+        let padding = (align - start % align) % align;
+        if self.len - self.used < padding + size {
+            return Err(ERANGE);
+        }
+        let p = unsafe { self.buf.add(self.used + padding) };
+        self.used += padding + size;
+        Ok(p)
+    }
+
+    /// Copies an address, aligned so that it can be read as an in_addr
+    pub fn address(&mut self, addr: &[u8]) -> Result<*mut c_char, c_int> {
+        let p = self.alloc(addr.len(), mem::align_of::<in_addr>())?;
+        unsafe { ptr::copy_nonoverlapping(addr.as_ptr(), p, addr.len()) };
+        Ok(p as *mut c_char)
+    }
+
+    /// Copies a string and a nul after it
+    pub fn string(&mut self, s: &[u8]) -> Result<*mut c_char, c_int> {
+        let p = self.alloc(s.len() + 1, 1)?;
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), p, s.len());
+            *p.add(s.len()) = 0;
+        }
+        Ok(p as *mut c_char)
+    }
+
+    /// Copies a list of pointers and a null pointer after them
+    pub fn list(&mut self, items: &[*mut c_char]) -> Result<*mut *mut c_char, c_int> {
+        let p = self.alloc(
+            (items.len() + 1) * mem::size_of::<*mut c_char>(),
+            mem::align_of::<*mut c_char>(),
+        )? as *mut *mut c_char;
+        unsafe {
+            ptr::copy_nonoverlapping(items.as_ptr(), p, items.len());
+            *p.add(items.len()) = ptr::null_mut();
+        }
+        Ok(p)
+    }
+
+    /// Copies strings and a list of pointers to them
+    pub fn strings<S: AsRef<[u8]>>(&mut self, strings: &[S]) -> Result<*mut *mut c_char, c_int> {
+        let strings = strings
+            .iter()
+            .map(|s| self.string(s.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.list(&strings)
+    }
+}
+
+/// An entry that netdb returns as the C struct `Struct`
+pub trait Record {
+    type Struct;
+
+    /// Fills in `entry`, with what it points to in `buf`. Fails with ERANGE if `buf` is too
+    /// small.
+    fn write(&self, entry: &mut Self::Struct, buf: &mut Buffer) -> Result<(), c_int>;
+}
+
+/// The result of a reentrant function, which is written to the caller's `entry` and `buf` with
+/// `*result` pointing to it. Without a record, `*result` is NULL and `not_found` is returned.
+pub unsafe fn reentrant<R: Record>(
+    record: Option<&R>,
+    not_found: c_int,
+    entry: *mut R::Struct,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut R::Struct,
+) -> c_int {
+    *result = ptr::null_mut();
+    let record = match record {
+        Some(record) => record,
+        None => return not_found,
+    };
+    match record.write(&mut *entry, &mut Buffer::new(buf, buflen)) {
+        Ok(()) => {
+            *result = entry;
+            0
+        }
+        Err(err) => err,
+    }
+}
+
+/// The entry that a function which isn't reentrant returns a pointer to. Each thread has its
+/// own, which the next call on the thread overwrites.
+pub struct Local<T> {
+    entry: T,
+    buf: Vec<u8>,
+}
+
+impl<T> Local<T> {
+    pub const fn new(entry: T) -> Local<T> {
+        Local {
+            entry,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Writes `record` to the entry, with a buffer grown until it fits
+    pub fn store<R: Record<Struct = T>>(&mut self, record: &R) -> *mut T {
+        if self.buf.is_empty() {
+            self.buf.resize(256, 0);
+        }
+        loop {
+            let mut buf =
+                unsafe { Buffer::new(self.buf.as_mut_ptr() as *mut c_char, self.buf.len()) };
+            if record.write(&mut self.entry, &mut buf).is_ok() {
+                return &mut self.entry;
+            }
+            let len = self.buf.len() * 2;
+            self.buf.resize(len, 0);
+        }
+    }
+}
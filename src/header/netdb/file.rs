@@ -0,0 +1,162 @@
+//! The files that hosts, services, protocols and networks are listed in, with an entry on each
+//! line as words separated by whitespace.
+
+use alloc::{string::String, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::{
+    c_str::{CStr, CString},
+    db::{FileDb, Separator},
+    header::errno::{ENOENT, ERANGE},
+    platform::types::*,
+    sync::Mutex,
+};
+
+use super::buffer::{reentrant, Record};
+
+/// Files read in place of the configuration files in /etc that they are paired with
+static REDIRECTS: Mutex<Vec<(CString, CString)>> = Mutex::new(Vec::new());
+
+/// The path to read `file`, a configuration file in /etc, from
+pub fn config_path(file: &CStr) -> CString {
+    let redirects = REDIRECTS.lock();
+    let path = match redirects.iter().find(|(from, _)| **from == *file) {
+        Some((_, to)) => to.as_c_str(),
+        None => file,
+    };
+    CString::new(path.to_bytes()).unwrap()
+}
+
+/// Makes the netdb functions read `path` in place of `file`, like /etc/hosts or
+/// /etc/resolv.conf, or read `file` itself again if `path` is null.
+///
+/// This is only for the tests, which cannot change the files in /etc. It is not an environment
+/// variable, which anyone could set to change the lookups of a setuid program, or that could be
+/// left set by accident.
+#[no_mangle]
+pub unsafe extern "C" fn __relibc_internal_netdb_redirect(
+    file: *const c_char,
+    path: *const c_char,
+) {
+    let file = CStr::from_ptr(file);
+    let mut redirects = REDIRECTS.lock();
+    redirects.retain(|(from, _)| **from != *file);
+    if !path.is_null() {
+        redirects.push((
+            CString::new(file.to_bytes()).unwrap(),
+            CString::new(CStr::from_ptr(path).to_bytes()).unwrap(),
+        ));
+    }
+}
+
+pub trait Entry: Sized {
+    fn path() -> CString;
+
+    /// Reads an entry from the words of a line, if they make one
+    fn parse(words: Vec<String>) -> Option<Self>;
+}
+
+/// The entries of a file, in order. Lines that aren't entries are skipped.
+pub struct Entries<T> {
+    db: FileDb,
+    entry: PhantomData<T>,
+}
+
+impl<T: Entry> Entries<T> {
+    pub fn open() -> Option<Entries<T>> {
+        let db = FileDb::open(&T::path(), Separator::Whitespace).ok()?;
+        Some(Entries {
+            db,
+            entry: PhantomData,
+        })
+    }
+}
+
+impl<T: Entry> Iterator for Entries<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if let Some(entry) = T::parse(self.db.read().ok()??) {
+                return Some(entry);
+            }
+        }
+    }
+}
+
+/// How far the get*ent() functions have gone through a file, which the set*ent() functions
+/// rewind and the end*ent() ones close
+pub struct Cursor<T> {
+    entries: Option<Entries<T>>,
+    /// An entry that didn't fit in the buffer a get*ent_r() function was given, to be returned
+    /// again
+    pending: Option<T>,
+    stayopen: bool,
+}
+
+impl<T> Cursor<T> {
+    pub const fn new() -> Cursor<T> {
+        Cursor {
+            entries: None,
+            pending: None,
+            stayopen: false,
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.entries = None;
+        self.pending = None;
+    }
+
+    pub fn put_back(&mut self, entry: T) {
+        self.pending = Some(entry);
+    }
+}
+
+impl<T: Entry> Cursor<T> {
+    pub fn rewind(&mut self, stayopen: c_int) {
+        self.stayopen = stayopen != 0;
+        self.entries = Entries::open();
+        self.pending = None;
+    }
+
+    /// The next entry, where the file is closed after the last unless it should stay open
+    pub fn next_entry(&mut self) -> Option<T> {
+        if let Some(entry) = self.pending.take() {
+            return Some(entry);
+        }
+        if self.entries.is_none() {
+            self.entries = Entries::open();
+        }
+        let entry = self.entries.as_mut().and_then(Iterator::next);
+        if entry.is_none() && !self.stayopen {
+            self.close();
+        }
+        entry
+    }
+}
+
+impl<T: Entry + Record> Cursor<T> {
+    /// The next entry for a get*ent_r() function, or ENOENT after the last. An entry that
+    /// doesn't fit in the buffer is given again by the next call.
+    pub unsafe fn next_reentrant(
+        &mut self,
+        entry: *mut T::Struct,
+        buf: *mut c_char,
+        buflen: size_t,
+        result: *mut *mut T::Struct,
+    ) -> c_int {
+        let next = self.next_entry();
+        let status = reentrant(next.as_ref(), ENOENT, entry, buf, buflen, result);
+        if let (ERANGE, Some(next)) = (status, next) {
+            self.put_back(next);
+        }
+        status
+    }
+}
+
+/// Whether `name` is `canonical` or one of `aliases`, ignoring case
+pub fn is_named(name: &str, canonical: &str, aliases: &[String]) -> bool {
+    canonical.eq_ignore_ascii_case(name)
+        || aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name))
+}
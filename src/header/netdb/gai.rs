@@ -0,0 +1,443 @@
+//! getaddrinfo_a() and the functions to wait for and cancel its requests, like in glibc.
+//!
+//! With GAI_WAIT, the caller looks its requests up itself, all at once. Requests with
+//! GAI_NOWAIT go to a thread that goes on with whichever of them can whenever one can, and that
+//! exits once there are none left.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    ptr, slice,
+    sync::atomic::{AtomicI32, Ordering},
+    task::Poll,
+};
+
+use crate::{
+    header::{
+        errno::{EINTR, EINVAL},
+        fcntl::{O_CLOEXEC, O_NONBLOCK},
+        poll::{poll_epoll, pollfd, POLLIN},
+        pthread::PTHREAD_CREATE_DETACHED,
+        signal::{
+            sigevent, sigset_t, sigval, SIGEV_NONE, SIGEV_SIGNAL, SIGEV_THREAD, SIG_BLOCK,
+            SIG_SETMASK,
+        },
+        time::{timespec, CLOCK_MONOTONIC},
+    },
+    platform::{self, types::*, Pal, PalSignal, Sys},
+    pthread::{self, RlctAttr},
+    sync::{relative_timeout, Mutex},
+};
+
+use super::{
+    addrinfo, gai_query, resolv, EAI_AGAIN, EAI_ALLDONE, EAI_CANCELED, EAI_INPROGRESS, EAI_INTR,
+    EAI_NOTCANCELED, EAI_SYSTEM,
+};
+
+pub const GAI_WAIT: c_int = 0;
+pub const GAI_NOWAIT: c_int = 1;
+
+const FUTEX_WAIT: c_int = 0;
+const FUTEX_WAKE: c_int = 1;
+
+#[repr(C)]
+pub struct gaicb {
+    ar_name: *const c_char,
+    ar_service: *const c_char,
+    ar_request: *const addrinfo,
+    ar_result: *mut addrinfo,
+    /// What gai_error() returns, which EAI_INPROGRESS until the request has finished
+    __relibc_internal_status: c_int,
+    __relibc_internal_pad: [c_int; 5],
+}
+
+static PENDING: Mutex<Pending> = Mutex::new(Pending {
+    requests: Vec::new(),
+    batches: Vec::new(),
+    next_batch: 0,
+    worker: false,
+    wake: None,
+});
+
+/// Counts the requests that have finished, for gai_suspend() to wait on
+static FINISHED: AtomicI32 = AtomicI32::new(0);
+
+/// The requests that the thread looks after
+struct Pending {
+    requests: Vec<Request>,
+    batches: Vec<Batch>,
+    next_batch: usize,
+    /// Whether the thread is running
+    worker: bool,
+    /// The pipe that wakes the thread up when there are new requests
+    wake: Option<[c_int; 2]>,
+}
+
+// The gaicbs and notifications of requests are only ever used with the lock held, or by the
+// thread that made them
+unsafe impl Send for Pending {}
+
+struct Request {
+    cb: *mut gaicb,
+    query: gai_query,
+    /// The batch that is notified once all of its requests have finished
+    batch: Option<usize>,
+}
+
+/// The requests of a getaddrinfo_a() call that asked to be notified
+struct Batch {
+    id: usize,
+    remaining: usize,
+    event: sigevent,
+}
+
+impl Pending {
+    /// Takes out `request` with its result, returning the notification to send if it was the
+    /// last of its batch
+    unsafe fn finish(
+        &mut self,
+        request: Request,
+        result: Result<*mut addrinfo, c_int>,
+    ) -> Option<sigevent> {
+        finished(request.cb, result);
+        let id = request.batch?;
+        let index = self.batches.iter().position(|batch| batch.id == id)?;
+        self.batches[index].remaining -= 1;
+        if self.batches[index].remaining == 0 {
+            Some(self.batches.swap_remove(index).event)
+        } else {
+            None
+        }
+    }
+
+    /// Makes sure that the thread is running, which fails with an errno value if it can't be
+    unsafe fn run_worker(&mut self) -> Result<(), c_int> {
+        if self.wake.is_none() {
+            let mut wake = [0; 2];
+            if Sys::pipe2(&mut wake, O_CLOEXEC | O_NONBLOCK) < 0 {
+                return Err(platform::errno);
+            }
+            self.wake = Some(wake);
+        }
+        if self.worker {
+            return Ok(());
+        }
+
+        let attrs = RlctAttr {
+            detachstate: PTHREAD_CREATE_DETACHED as c_uchar,
+            ..RlctAttr::default()
+        };
+        // Like in glibc, signals are for the threads of the program and never go to this one
+        let all: sigset_t = !0;
+        let mut old: sigset_t = 0;
+        Sys::sigprocmask(SIG_BLOCK, &all, &mut old);
+        let result = pthread::create(Some(&attrs), worker, ptr::null_mut());
+        Sys::sigprocmask(SIG_SETMASK, &old, ptr::null_mut());
+
+        result.map_err(|pthread::Errno(err)| err)?;
+        self.worker = true;
+        Ok(())
+    }
+}
+
+/// The status of the request, which finishing it changes from EAI_INPROGRESS
+unsafe fn status<'a>(cb: *const gaicb) -> &'a AtomicI32 {
+    &*(ptr::addr_of!((*cb).__relibc_internal_status) as *const AtomicI32)
+}
+
+/// Stores the result of a request, and wakes up whoever waits for one to finish
+unsafe fn finished(cb: *mut gaicb, result: Result<*mut addrinfo, c_int>) {
+    let code = match result {
+        Ok(res) => {
+            (*cb).ar_result = res;
+            0
+        }
+        Err(err) => err,
+    };
+    status(cb).store(code, Ordering::Release);
+
+    FINISHED.fetch_add(1, Ordering::SeqCst);
+    Sys::futex(
+        &FINISHED as *const AtomicI32 as *mut c_int,
+        FUTEX_WAKE,
+        c_int::max_value(),
+        0,
+    );
+}
+
+/// Goes on with the requests as far as they can without waiting, taking out the ones that
+/// have finished with their results
+fn process(requests: &mut Vec<Request>) -> Vec<(Request, Result<*mut addrinfo, c_int>)> {
+    let mut done = Vec::new();
+    let mut i = 0;
+    while i < requests.len() {
+        match requests[i].query.poll() {
+            Poll::Ready(result) => done.push((requests.swap_remove(i), result)),
+            Poll::Pending => i += 1,
+        }
+    }
+    done
+}
+
+/// The file descriptors to wait on for `requests`, and how long to wait at most
+fn waits(requests: &[Request]) -> (Vec<pollfd>, c_int) {
+    let fds = requests
+        .iter()
+        .map(|request| pollfd {
+            fd: request.query.fd(),
+            events: POLLIN,
+            revents: 0,
+        })
+        .collect();
+    let deadline = requests
+        .iter()
+        .filter_map(|request| request.query.deadline())
+        .min();
+    (fds, resolv::remaining(deadline))
+}
+
+extern "C" fn worker(_arg: *mut c_void) -> *mut c_void {
+    loop {
+        let mut notifications = Vec::new();
+        let mut pending = PENDING.lock();
+        for (request, result) in process(&mut pending.requests) {
+            notifications.extend(unsafe { pending.finish(request, result) });
+        }
+
+        let wake = pending.wake.unwrap()[0];
+        let exit = pending.requests.is_empty();
+        let (mut fds, timeout) = waits(&pending.requests);
+        if exit {
+            pending.worker = false;
+        }
+        drop(pending);
+
+        for event in notifications.iter() {
+            unsafe { notify(event) };
+        }
+        if exit {
+            return ptr::null_mut();
+        }
+
+        fds.push(pollfd {
+            fd: wake,
+            events: POLLIN,
+            revents: 0,
+        });
+        poll_epoll(&mut fds, timeout);
+        let mut buf = [0; 64];
+        while Sys::read(wake, &mut buf) > 0 {}
+    }
+}
+
+/// Lets the program know that the requests of a batch have finished
+unsafe fn notify(event: &sigevent) {
+    match event.sigev_notify {
+        SIGEV_SIGNAL => {
+            Sys::sigqueue(Sys::getpid(), event.sigev_signo, event.sigev_value);
+        }
+        SIGEV_THREAD => {
+            let function = match event.sigev_notify_function {
+                Some(function) => function,
+                None => return,
+            };
+            let mut attrs = event
+                .sigev_notify_attributes
+                .cast::<RlctAttr>()
+                .as_ref()
+                .copied()
+                .unwrap_or_default();
+            attrs.detachstate = PTHREAD_CREATE_DETACHED as c_uchar;
+            let arg = Box::into_raw(Box::new((function, event.sigev_value)));
+            if pthread::create(Some(&attrs), notify_thread, arg.cast()).is_err() {
+                drop(Box::from_raw(arg));
+            }
+        }
+        _ => (),
+    }
+}
+
+extern "C" fn notify_thread(arg: *mut c_void) -> *mut c_void {
+    let (function, value) =
+        *unsafe { Box::from_raw(arg.cast::<(extern "C" fn(sigval), sigval)>()) };
+    function(value);
+    ptr::null_mut()
+}
+
+/// Starts the requests, of which the ones whose arguments are wrong finish at once
+unsafe fn start(list: &[*mut gaicb]) -> Vec<Request> {
+    let mut requests = Vec::new();
+    for &cb in list.iter().filter(|cb| !cb.is_null()) {
+        (*cb).ar_result = ptr::null_mut();
+        match gai_query::new((*cb).ar_name, (*cb).ar_service, (*cb).ar_request) {
+            Ok(query) => {
+                status(cb).store(EAI_INPROGRESS, Ordering::Release);
+                requests.push(Request {
+                    cb,
+                    query,
+                    batch: None,
+                });
+            }
+            Err(err) => finished(cb, Err(err)),
+        }
+    }
+    requests
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getaddrinfo_a(
+    mode: c_int,
+    list: *mut *mut gaicb,
+    nitems: c_int,
+    sevp: *mut sigevent,
+) -> c_int {
+    let list = if nitems > 0 {
+        slice::from_raw_parts(list, nitems as usize)
+    } else {
+        &[]
+    };
+
+    match mode {
+        GAI_WAIT => {
+            let mut requests = start(list);
+            loop {
+                for (request, result) in process(&mut requests) {
+                    finished(request.cb, result);
+                }
+                if requests.is_empty() {
+                    return 0;
+                }
+                let (mut fds, timeout) = waits(&requests);
+                poll_epoll(&mut fds, timeout);
+            }
+        }
+        GAI_NOWAIT => {
+            let event = sevp
+                .as_ref()
+                .filter(|event| event.sigev_notify != SIGEV_NONE)
+                .copied();
+
+            let mut pending = PENDING.lock();
+            if let Err(err) = pending.run_worker() {
+                platform::errno = err;
+                return EAI_AGAIN;
+            }
+            let mut requests = start(list);
+            if let Some(event) = event {
+                if requests.is_empty() {
+                    drop(pending);
+                    notify(&event);
+                    return 0;
+                }
+                let id = pending.next_batch;
+                pending.next_batch += 1;
+                pending.batches.push(Batch {
+                    id,
+                    remaining: requests.len(),
+                    event,
+                });
+                for request in requests.iter_mut() {
+                    request.batch = Some(id);
+                }
+            }
+            pending.requests.append(&mut requests);
+            let wake = pending.wake.unwrap()[1];
+            drop(pending);
+
+            Sys::write(wake, &[0]);
+            0
+        }
+        _ => {
+            platform::errno = EINVAL;
+            EAI_SYSTEM
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gai_suspend(
+    list: *const *const gaicb,
+    nitems: c_int,
+    timeout: *const timespec,
+) -> c_int {
+    let list = if nitems > 0 {
+        slice::from_raw_parts(list, nitems as usize)
+    } else {
+        &[]
+    };
+    // The timeout is relative, so it is kept as a deadline on the monotonic clock
+    let deadline = timeout.as_ref().map(|timeout| {
+        let mut deadline = timespec::default();
+        Sys::clock_gettime(CLOCK_MONOTONIC, &mut deadline);
+        deadline.tv_sec += timeout.tv_sec;
+        deadline.tv_nsec += timeout.tv_nsec;
+        if deadline.tv_nsec >= 1_000_000_000 {
+            deadline.tv_sec += 1;
+            deadline.tv_nsec -= 1_000_000_000;
+        }
+        deadline
+    });
+
+    loop {
+        let finished = FINISHED.load(Ordering::SeqCst);
+        let mut requests = list.iter().filter(|cb| !cb.is_null()).peekable();
+        if requests.peek().is_none() {
+            return EAI_ALLDONE;
+        }
+        if requests.any(|&cb| status(cb).load(Ordering::Acquire) != EAI_INPROGRESS) {
+            return 0;
+        }
+
+        let timeout = match deadline
+            .as_ref()
+            .map(|deadline| relative_timeout(CLOCK_MONOTONIC, deadline))
+        {
+            Some(Ok(timeout)) => Some(timeout),
+            Some(Err(_)) => return EAI_AGAIN,
+            None => None,
+        };
+        let result = Sys::futex(
+            &FINISHED as *const AtomicI32 as *mut c_int,
+            FUTEX_WAIT,
+            finished,
+            timeout
+                .as_ref()
+                .map_or(0, |timeout| timeout as *const timespec as usize),
+        );
+        if result == -EINTR {
+            return EAI_INTR;
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gai_error(req: *mut gaicb) -> c_int {
+    status(req).load(Ordering::Acquire)
+}
+
+/// Cancels a request that the thread looks after. Cancelled requests count as finished for
+/// the notification of their batch.
+#[no_mangle]
+pub unsafe extern "C" fn gai_cancel(req: *mut gaicb) -> c_int {
+    let mut pending = PENDING.lock();
+    let index = match pending
+        .requests
+        .iter()
+        .position(|request| request.cb == req)
+    {
+        Some(index) => index,
+        // A request being waited for with GAI_WAIT can't be cancelled
+        None if status(req).load(Ordering::Acquire) == EAI_INPROGRESS => return EAI_NOTCANCELED,
+        None => return EAI_ALLDONE,
+    };
+    let request = pending.requests.swap_remove(index);
+    let event = pending.finish(request, Err(EAI_CANCELED));
+    // The thread stops waiting on the request, and exits if it was the last one
+    let wake = pending.wake.unwrap()[1];
+    drop(pending);
+
+    Sys::write(wake, &[0]);
+    if let Some(event) = event {
+        notify(&event);
+    }
+    EAI_CANCELED
+}
@@ -1,127 +1,357 @@
-use alloc::{boxed::Box, str::SplitWhitespace, vec::Vec};
-use core::{mem, ptr};
+use alloc::{string::String, vec::Vec};
+use core::{ptr, str};
 
 use crate::{
-    c_str::CString,
+    c_str::CStr,
     header::{
-        arpa_inet::inet_aton, fcntl::O_RDONLY, netinet_in::in_addr, sys_socket::constants::AF_INET,
-        unistd::SEEK_SET,
-    },
-    platform::{
-        rlb::{Line, RawLineBuffer},
-        types::*,
-        Pal, Sys,
+        errno::{EAFNOSUPPORT, EAGAIN, ENOENT, ERANGE},
+        netinet_in::{in6_addr, in_addr},
+        sys_socket::{
+            constants::{AF_INET, AF_INET6},
+            socklen_t,
+        },
     },
+    platform::{self, types::*},
+    sync::Mutex,
+};
+
+use super::{
+    address::Address,
+    buffer::{reentrant, Buffer, Local, Record},
+    file::Cursor,
+    h_errno, hostent,
+    hosts::{self, Host},
+    lookup_addr, lookup_addr6, lookup_host, lookup_host6,
+    nsswitch::{self, Source},
+    HOST_NOT_FOUND, NETDB_INTERNAL, NETDB_SUCCESS, TRY_AGAIN,
 };
 
-use super::{bytes_to_box_str, hostent};
+static HOSTS: Mutex<Cursor<Host>> = Mutex::new(Cursor::new());
 
-static mut HOSTDB: c_int = -1;
-pub static mut HOST_ENTRY: hostent = hostent {
+#[thread_local]
+static mut HOST_ENTRY: Local<hostent> = Local::new(hostent {
     h_name: ptr::null_mut(),
     h_aliases: ptr::null_mut(),
     h_addrtype: 0,
     h_length: 0,
     h_addr_list: ptr::null_mut(),
-};
-pub static mut HOST_NAME: Option<Vec<u8>> = None;
-pub static mut HOST_ALIASES: Option<Vec<Vec<u8>>> = None;
-static mut _HOST_ALIASES: Option<Vec<*mut i8>> = None;
-pub static mut HOST_ADDR: Option<in_addr> = None;
-pub static mut HOST_ADDR_LIST: [*mut c_char; 2] = [ptr::null_mut(); 2];
-pub static mut _HOST_ADDR_LIST: [u8; 4] = [0u8; 4];
-static mut H_POS: usize = 0;
-pub static mut HOST_STAYOPEN: c_int = 0;
+});
 
-#[no_mangle]
-pub unsafe extern "C" fn endhostent() {
-    if HOSTDB >= 0 {
-        Sys::close(HOSTDB);
+/// A host with all of the addresses it was found with, of one family
+pub struct HostEntry {
+    pub name: Vec<u8>,
+    pub aliases: Vec<String>,
+    pub family: c_int,
+    pub addrs: Vec<Address>,
+}
+
+impl From<&Host> for HostEntry {
+    fn from(host: &Host) -> HostEntry {
+        HostEntry {
+            name: host.name.as_bytes().to_vec(),
+            aliases: host.aliases.clone(),
+            family: host.addr.family(),
+            addrs: vec![host.addr],
+        }
     }
-    HOSTDB = -1;
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn sethostent(stayopen: c_int) {
-    HOST_STAYOPEN = stayopen;
-    if HOSTDB < 0 {
-        HOSTDB = Sys::open(&CString::new("/etc/hosts").unwrap(), O_RDONLY, 0)
-    } else {
-        Sys::lseek(HOSTDB, 0, SEEK_SET);
+impl Record for HostEntry {
+    type Struct = hostent;
+
+    fn write(&self, entry: &mut hostent, buf: &mut Buffer) -> Result<(), c_int> {
+        let addrs = self
+            .addrs
+            .iter()
+            .map(|&addr| match addr.unmapped() {
+                Some(v4) if self.family == AF_INET => buf.address(&v4.s_addr.to_ne_bytes()),
+                _ => buf.address(&addr.to_v6()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        *entry = hostent {
+            h_addr_list: buf.list(&addrs)?,
+            h_aliases: buf.strings(&self.aliases)?,
+            h_name: buf.string(&self.name)?,
+            h_addrtype: self.family,
+            h_length: if self.family == AF_INET { 4 } else { 16 },
+        };
+        Ok(())
     }
-    H_POS = 0;
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn gethostent() -> *mut hostent {
-    if HOSTDB < 0 {
-        HOSTDB = Sys::open(&CString::new("/etc/hosts").unwrap(), O_RDONLY, 0);
+/// The names of `addr` from the name servers
+fn lookup_reverse(addr: Address) -> Result<Vec<Vec<u8>>, c_int> {
+    match addr.unmapped() {
+        Some(v4) => lookup_addr(v4),
+        None => match addr {
+            Address::V6(v6, _) => lookup_addr6(&v6),
+            Address::V4(_) => unreachable!(),
+        },
+    }
+}
+
+/// The addresses of `family` that `name` has in the first source that knows it. Fails with
+/// ENOENT if no source does, or with the error from the name servers if they failed.
+pub fn by_name(name: &[u8], family: c_int) -> Result<HostEntry, c_int> {
+    if family != AF_INET && family != AF_INET6 {
+        return Err(EAFNOSUPPORT);
     }
-    let mut rlb = RawLineBuffer::new(HOSTDB);
-    rlb.seek(H_POS);
-
-    let mut r: Box<str> = Box::default();
-    while r.is_empty() || r.split_whitespace().next() == None || r.starts_with('#') {
-        r = match rlb.next() {
-            Line::Some(s) => bytes_to_box_str(s),
-            _ => {
-                if HOST_STAYOPEN == 0 {
-                    endhostent();
+
+    // An address names itself, without looking anything up
+    if let Some(addr) = Address::parse(name) {
+        return match addr {
+            Address::V6(addr, _) if family == AF_INET6 => Ok(HostEntry {
+                name: name.to_vec(),
+                aliases: Vec::new(),
+                family,
+                addrs: vec![Address::V6(addr, 0)],
+            }),
+            Address::V4(_) if family == AF_INET => Ok(HostEntry {
+                name: name.to_vec(),
+                aliases: Vec::new(),
+                family,
+                addrs: vec![addr],
+            }),
+            _ => Err(ENOENT),
+        };
+    }
+    let name = str::from_utf8(name).map_err(|_| ENOENT)?;
+
+    let mut error = None;
+    for source in nsswitch::hosts() {
+        match source {
+            Source::Files => {
+                // The hosts file can give a name several addresses on separate lines, but the
+                // canonical name and aliases are those of the first
+                let hosts: Vec<Host> = hosts::by_name(name)
+                    .into_iter()
+                    .filter(|host| host.addr.family() == family)
+                    .collect();
+                if let Some(host) = hosts.first() {
+                    return Ok(HostEntry {
+                        addrs: hosts.iter().map(|host| host.addr).collect(),
+                        ..HostEntry::from(host)
+                    });
                 }
-                return ptr::null_mut();
             }
-        };
+            Source::Dns => {
+                let found: Result<Vec<Address>, c_int> = if family == AF_INET {
+                    lookup_host(name).map(|found| found.map(Address::V4).collect())
+                } else {
+                    lookup_host6(name)
+                        .map(|found| found.into_iter().map(|v6| Address::V6(v6, 0)).collect())
+                };
+                match found {
+                    Ok(addrs) => {
+                        if !addrs.is_empty() {
+                            return Ok(HostEntry {
+                                name: name.as_bytes().to_vec(),
+                                aliases: Vec::new(),
+                                family,
+                                addrs,
+                            });
+                        }
+                    }
+                    Err(err) => error = Some(err),
+                }
+            }
+        }
     }
-    rlb.next();
-    H_POS = rlb.line_pos();
+    Err(error.unwrap_or(ENOENT))
+}
 
-    let mut iter: SplitWhitespace = r.split_whitespace();
+/// The name of `addr` in the first source that knows it, with failures like by_name()
+pub fn by_addr(addr: Address, family: c_int) -> Result<HostEntry, c_int> {
+    let mut error = None;
+    for source in nsswitch::hosts() {
+        match source {
+            Source::Files => {
+                if let Some(host) = hosts::by_addr(addr) {
+                    return Ok(HostEntry {
+                        family,
+                        addrs: vec![addr],
+                        ..HostEntry::from(&host)
+                    });
+                }
+            }
+            Source::Dns => match lookup_reverse(addr) {
+                Ok(names) => {
+                    if let Some(name) = names.into_iter().next() {
+                        return Ok(HostEntry {
+                            name,
+                            aliases: Vec::new(),
+                            family,
+                            addrs: vec![addr],
+                        });
+                    }
+                }
+                Err(err) => error = Some(err),
+            },
+        }
+    }
+    Err(error.unwrap_or(ENOENT))
+}
 
-    let mut addr_vec = iter.next().unwrap().as_bytes().to_vec();
-    addr_vec.push(b'\0');
-    let addr_cstr = addr_vec.as_slice().as_ptr() as *const i8;
-    let mut addr = mem::MaybeUninit::uninit();
-    inet_aton(addr_cstr, addr.as_mut_ptr());
-    let addr = addr.assume_init();
+/// The address that gethostbyaddr() is given
+unsafe fn address(addr: *const c_void, len: socklen_t, family: c_int) -> Result<Address, c_int> {
+    match (family, len) {
+        (AF_INET, 4) => Ok(Address::V4(ptr::read_unaligned(addr as *const in_addr))),
+        (AF_INET6, 16) => Ok(Address::V6(ptr::read_unaligned(addr as *const in6_addr), 0)),
+        _ => Err(EAFNOSUPPORT),
+    }
+}
 
-    _HOST_ADDR_LIST = mem::transmute::<u32, [u8; 4]>(addr.s_addr);
-    HOST_ADDR_LIST = [_HOST_ADDR_LIST.as_mut_ptr() as *mut c_char, ptr::null_mut()];
+/// The h_errno for a failure of by_name() or by_addr()
+fn h_error(err: c_int) -> c_int {
+    match err {
+        ENOENT => HOST_NOT_FOUND,
+        EAGAIN => TRY_AGAIN,
+        _ => NETDB_INTERNAL,
+    }
+}
 
-    HOST_ADDR = Some(addr);
+/// The result of a function that isn't reentrant, which is in the thread's own entry
+unsafe fn local_host(found: Result<HostEntry, c_int>) -> *mut hostent {
+    match found {
+        Ok(host) => HOST_ENTRY.store(&host),
+        Err(err) => {
+            h_errno = h_error(err);
+            platform::errno = err;
+            ptr::null_mut()
+        }
+    }
+}
 
-    let mut host_name = iter.next().unwrap().as_bytes().to_vec();
-    host_name.push(b'\0');
+/// The result of a reentrant function. Not finding the host isn't an error, but leaves
+/// `*result` NULL.
+unsafe fn reentrant_host(
+    found: Result<HostEntry, c_int>,
+    ret: *mut hostent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut hostent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    let status = match found {
+        Ok(ref host) => reentrant(Some(host), 0, ret, buf, buflen, result),
+        Err(err) => {
+            *result = ptr::null_mut();
+            *h_errnop = h_error(err);
+            return if err == ENOENT { 0 } else { err };
+        }
+    };
+    *h_errnop = if status == ERANGE {
+        NETDB_INTERNAL
+    } else {
+        NETDB_SUCCESS
+    };
+    status
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn endhostent() {
+    HOSTS.lock().close();
+}
 
-    let mut _host_aliases: Vec<Vec<u8>> = Vec::new();
+#[no_mangle]
+pub unsafe extern "C" fn sethostent(stayopen: c_int) {
+    HOSTS.lock().rewind(stayopen);
+}
 
-    for s in iter {
-        let mut alias = s.as_bytes().to_vec();
-        alias.push(b'\0');
-        _host_aliases.push(alias);
+#[no_mangle]
+pub unsafe extern "C" fn gethostent() -> *mut hostent {
+    let host = HOSTS.lock().next_entry();
+    match host {
+        Some(host) => HOST_ENTRY.store(&HostEntry::from(&host)),
+        None => ptr::null_mut(),
     }
-    HOST_ALIASES = Some(_host_aliases);
-
-    let mut host_aliases: Vec<*mut i8> = HOST_ALIASES
-        .as_mut()
-        .unwrap()
-        .iter_mut()
-        .map(|x| x.as_mut_ptr() as *mut i8)
-        .collect();
-    host_aliases.push(ptr::null_mut());
-    host_aliases.push(ptr::null_mut());
-
-    HOST_NAME = Some(host_name);
-
-    HOST_ENTRY = hostent {
-        h_name: HOST_NAME.as_mut().unwrap().as_mut_ptr() as *mut c_char,
-        h_aliases: host_aliases.as_mut_slice().as_mut_ptr() as *mut *mut i8,
-        h_addrtype: AF_INET,
-        h_length: 4,
-        h_addr_list: HOST_ADDR_LIST.as_mut_ptr(),
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gethostent_r(
+    ret: *mut hostent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut hostent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    let mut hosts = HOSTS.lock();
+    let host = hosts.next_entry();
+    let status = reentrant(
+        host.as_ref().map(HostEntry::from).as_ref(),
+        ENOENT,
+        ret,
+        buf,
+        buflen,
+        result,
+    );
+    *h_errnop = match status {
+        0 => NETDB_SUCCESS,
+        ENOENT => HOST_NOT_FOUND,
+        _ => NETDB_INTERNAL,
     };
-    _HOST_ALIASES = Some(host_aliases);
-    if HOST_STAYOPEN == 0 {
-        endhostent();
+    if let (ERANGE, Some(host)) = (status, host) {
+        hosts.put_back(host);
     }
-    &mut HOST_ENTRY as *mut hostent
+    status
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gethostbyaddr(
+    v: *const c_void,
+    length: socklen_t,
+    format: c_int,
+) -> *mut hostent {
+    local_host(address(v, length, format).and_then(|addr| by_addr(addr, format)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gethostbyaddr_r(
+    v: *const c_void,
+    length: socklen_t,
+    format: c_int,
+    ret: *mut hostent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut hostent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    let found = address(v, length, format).and_then(|addr| by_addr(addr, format));
+    reentrant_host(found, ret, buf, buflen, result, h_errnop)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gethostbyname(name: *const c_char) -> *mut hostent {
+    gethostbyname2(name, AF_INET)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gethostbyname2(name: *const c_char, af: c_int) -> *mut hostent {
+    local_host(by_name(CStr::from_ptr(name).to_bytes(), af))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gethostbyname_r(
+    name: *const c_char,
+    ret: *mut hostent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut hostent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    gethostbyname2_r(name, AF_INET, ret, buf, buflen, result, h_errnop)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gethostbyname2_r(
+    name: *const c_char,
+    af: c_int,
+    ret: *mut hostent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut hostent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    let found = by_name(CStr::from_ptr(name).to_bytes(), af);
+    reentrant_host(found, ret, buf, buflen, result, h_errnop)
 }
@@ -0,0 +1,49 @@
+//! The hosts file, which gives names to addresses without asking a name server. Each line has
+//! an address, IPv4 or IPv6, followed by the canonical name of the host and any aliases.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::c_str::CString;
+
+use super::{
+    address::Address,
+    file::{config_path, is_named, Entries, Entry},
+};
+
+pub struct Host {
+    pub addr: Address,
+    pub name: String,
+    pub aliases: Vec<String>,
+}
+
+impl Entry for Host {
+    fn path() -> CString {
+        config_path(c_str!("/etc/hosts"))
+    }
+
+    fn parse(words: Vec<String>) -> Option<Host> {
+        let mut words = words.into_iter();
+        let addr = Address::parse(words.next()?.as_bytes())?;
+        let name = words.next()?;
+        Some(Host {
+            addr,
+            name,
+            aliases: words.collect(),
+        })
+    }
+}
+
+/// The hosts that `name` names, which can be on several lines with different addresses
+pub fn by_name(name: &str) -> Vec<Host> {
+    match Entries::<Host>::open() {
+        Some(hosts) => hosts
+            .filter(|host| is_named(name, &host.name, &host.aliases))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// The first host with `addr`, where IPv4 addresses also match their IPv4-mapped IPv6 form
+pub fn by_addr(addr: Address) -> Option<Host> {
+    Entries::<Host>::open()?.find(|host| host.addr.to_v6() == addr.to_v6())
+}
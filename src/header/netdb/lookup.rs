@@ -1,28 +1,19 @@
 use alloc::{
-    boxed::Box,
-    string::{String, ToString},
+    string::String,
     vec::{IntoIter, Vec},
 };
-use core::mem;
+use core::fmt::Write;
 
-use crate::platform::{types::*, Pal, Sys};
-
-use crate::header::{
-    arpa_inet::htons,
-    errno::*,
-    netinet_in::{in_addr, sockaddr_in, IPPROTO_UDP},
-    sys_socket::{
-        self,
-        constants::{AF_INET, SOCK_DGRAM},
-        sockaddr, socklen_t,
-    },
-    time::{self, timespec},
+use crate::{
+    header::netinet_in::{in6_addr, in_addr},
+    platform::types::*,
 };
 
-use super::{
-    dns::{Dns, DnsQuery},
-    sys::get_dns_server,
-};
+use super::resolv::query;
+
+pub(super) const TYPE_A: u16 = 0x0001;
+const TYPE_PTR: u16 = 0x000C;
+pub(super) const TYPE_AAAA: u16 = 0x001C;
 
 pub struct LookupHost(IntoIter<in_addr>);
 
@@ -34,212 +25,67 @@ impl Iterator for LookupHost {
 }
 
 pub fn lookup_host(host: &str) -> Result<LookupHost, c_int> {
-    let dns_string = get_dns_server();
-
-    let dns_vec: Vec<u8> = dns_string
-        .trim()
-        .split('.')
-        .map(|octet| octet.parse::<u8>().unwrap_or(0))
-        .collect();
-
-    if dns_vec.len() == 4 {
-        let mut dns_arr = [0u8; 4];
-        for (i, octet) in dns_vec.iter().enumerate() {
-            dns_arr[i] = *octet;
-        }
-        let dns_addr = unsafe { mem::transmute::<[u8; 4], u32>(dns_arr) };
-
-        let mut timespec = timespec::default();
-        Sys::clock_gettime(time::constants::CLOCK_REALTIME, &mut timespec);
-        let tid = (timespec.tv_nsec >> 16) as u16;
-
-        let packet = Dns {
-            transaction_id: tid,
-            flags: 0x0100,
-            queries: vec![DnsQuery {
-                name: host.to_string(),
-                q_type: 0x0001,
-                q_class: 0x0001,
-            }],
-            answers: vec![],
-        };
-
-        let packet_data = packet.compile();
-        let packet_data_len = packet_data.len();
-
-        let packet_data_box = packet_data.into_boxed_slice();
-        let packet_data_ptr = Box::into_raw(packet_data_box) as *mut _ as *mut c_void;
-
-        let dest = sockaddr_in {
-            sin_family: AF_INET as u16,
-            sin_port: htons(53),
-            sin_addr: in_addr { s_addr: dns_addr },
-            ..Default::default()
-        };
-        let dest_ptr = &dest as *const _ as *const sockaddr;
-
-        let sock = unsafe {
-            let sock = sys_socket::socket(AF_INET, SOCK_DGRAM, IPPROTO_UDP as i32);
-            if sys_socket::connect(sock, dest_ptr, mem::size_of_val(&dest) as socklen_t) < 0 {
-                return Err(EIO);
-            }
-            if sys_socket::send(sock, packet_data_ptr, packet_data_len, 0) < 0 {
-                Box::from_raw(packet_data_ptr);
-                return Err(EIO);
-            }
-            sock
-        };
-
-        unsafe {
-            Box::from_raw(packet_data_ptr);
-        }
+    Ok(LookupHost(addrs_v4(query(host, TYPE_A)?).into_iter()))
+}
 
-        let i = 0 as socklen_t;
-        let mut buf = vec![0u8; 65536];
-        let buf_ptr = buf.as_mut_ptr() as *mut c_void;
+pub fn lookup_host6(host: &str) -> Result<Vec<in6_addr>, c_int> {
+    Ok(addrs_v6(query(host, TYPE_AAAA)?))
+}
 
-        let count = unsafe { sys_socket::recv(sock, buf_ptr, 65536, 0) };
-        if count < 0 {
-            return Err(EIO);
-        }
+/// The addresses in the data of A records
+pub(super) fn addrs_v4(records: Vec<Vec<u8>>) -> Vec<in_addr> {
+    records
+        .into_iter()
+        .filter(|data| data.len() == 4)
+        .map(|data| in_addr {
+            s_addr: u32::from_ne_bytes([data[0], data[1], data[2], data[3]]),
+        })
+        .collect()
+}
 
-        match Dns::parse(&buf[..count as usize]) {
-            Ok(response) => {
-                let mut addrs = vec![];
-                for answer in response.answers.iter() {
-                    if answer.a_type == 0x0001 && answer.a_class == 0x0001 && answer.data.len() == 4
-                    {
-                        let addr = in_addr {
-                            s_addr: unsafe {
-                                mem::transmute::<[u8; 4], u32>([
-                                    answer.data[0],
-                                    answer.data[1],
-                                    answer.data[2],
-                                    answer.data[3],
-                                ])
-                            },
-                        };
-                        addrs.push(addr);
-                    }
-                }
-                Ok(LookupHost(addrs.into_iter()))
-            }
-            Err(_err) => Err(EINVAL),
-        }
-    } else {
-        Err(EINVAL)
-    }
+/// The addresses in the data of AAAA records
+pub(super) fn addrs_v6(records: Vec<Vec<u8>>) -> Vec<in6_addr> {
+    records
+        .into_iter()
+        .filter(|data| data.len() == 16)
+        .map(|data| {
+            let mut addr = in6_addr::default();
+            addr.s6_addr.copy_from_slice(&data);
+            addr
+        })
+        .collect()
 }
 
 pub fn lookup_addr(addr: in_addr) -> Result<Vec<Vec<u8>>, c_int> {
-    let dns_string = get_dns_server();
-
-    let dns_vec: Vec<u8> = dns_string
-        .trim()
-        .split('.')
-        .map(|octet| octet.parse::<u8>().unwrap_or(0))
-        .collect();
-
-    let mut dns_arr = [0u8; 4];
-
-    for (i, octet) in dns_vec.iter().enumerate() {
-        dns_arr[i] = *octet;
+    let octets = addr.s_addr.to_ne_bytes();
+    let mut name = String::new();
+    for octet in octets.iter().rev() {
+        write!(name, "{}.", octet).unwrap();
     }
+    name.push_str("IN-ADDR.ARPA.");
+    lookup_ptr(name)
+}
 
-    let mut addr_vec: Vec<u8> = unsafe { mem::transmute::<u32, [u8; 4]>(addr.s_addr).to_vec() };
-    addr_vec.reverse();
-    let mut name: Vec<u8> = vec![];
-    for octet in addr_vec {
-        for ch in format!("{}", octet).as_bytes() {
-            name.push(*ch);
-        }
-        name.push(b"."[0]);
-    }
-    name.pop();
-    for ch in b".IN-ADDR.ARPA" {
-        name.push(*ch);
+pub fn lookup_addr6(addr: &in6_addr) -> Result<Vec<Vec<u8>>, c_int> {
+    // Each nibble is a label, starting from the end
+    let mut name = String::new();
+    for byte in addr.s6_addr.iter().rev() {
+        write!(name, "{:x}.{:x}.", byte & 0xf, byte >> 4).unwrap();
     }
+    name.push_str("IP6.ARPA.");
+    lookup_ptr(name)
+}
 
-    if dns_vec.len() == 4 {
-        let mut timespec = timespec::default();
-        Sys::clock_gettime(time::constants::CLOCK_REALTIME, &mut timespec);
-        let tid = (timespec.tv_nsec >> 16) as u16;
-
-        let packet = Dns {
-            transaction_id: tid,
-            flags: 0x0100,
-            queries: vec![DnsQuery {
-                name: String::from_utf8(name).unwrap(),
-                q_type: 0x000C,
-                q_class: 0x0001,
-            }],
-            answers: vec![],
-        };
-
-        let packet_data = packet.compile();
-        let packet_data_len = packet_data.len();
-        let packet_data_box = packet_data.into_boxed_slice();
-        let packet_data_ptr = Box::into_raw(packet_data_box) as *mut _ as *mut c_void;
-
-        let dest = sockaddr_in {
-            sin_family: AF_INET as u16,
-            sin_port: htons(53),
-            sin_addr: in_addr {
-                s_addr: unsafe { mem::transmute::<[u8; 4], u32>(dns_arr) },
-            },
-            ..Default::default()
-        };
-
-        let dest_ptr = &dest as *const _ as *const sockaddr;
-
-        let sock = unsafe {
-            let sock = sys_socket::socket(AF_INET, SOCK_DGRAM, IPPROTO_UDP as i32);
-            if sys_socket::connect(sock, dest_ptr, mem::size_of_val(&dest) as socklen_t) < 0 {
-                return Err(EIO);
-            }
-            sock
-        };
-
-        unsafe {
-            if sys_socket::send(sock, packet_data_ptr, packet_data_len, 0) < 0 {
-                return Err(EIO);
-            }
-        }
-
-        unsafe {
-            Box::from_raw(packet_data_ptr);
-        }
-
-        let i = mem::size_of::<sockaddr_in>() as socklen_t;
-        let mut buf = [0u8; 65536];
-        let buf_ptr = buf.as_mut_ptr() as *mut c_void;
-
-        let count = unsafe { sys_socket::recv(sock, buf_ptr, 65536, 0) };
-        if count < 0 {
-            return Err(EIO);
-        }
-
-        match Dns::parse(&buf[..count as usize]) {
-            Ok(response) => {
-                let mut names = vec![];
-                for answer in response.answers.iter() {
-                    if answer.a_type == 0x000C && answer.a_class == 0x0001 {
-                        // answer.data is encoded kinda weird.
-                        // Basically length-prefixed strings for each
-                        // subsection of the domain.
-                        // We need to parse this to insert periods where
-                        // they belong (ie at the end of each string)
-                        let data = parse_revdns_answer(&answer.data);
-                        names.push(data);
-                    }
-                }
-                Ok(names)
-            }
-            Err(_err) => Err(EINVAL),
-        }
-    } else {
-        Err(EINVAL)
-    }
+fn lookup_ptr(name: String) -> Result<Vec<Vec<u8>>, c_int> {
+    // answer.data is encoded kinda weird.
+    // Basically length-prefixed strings for each
+    // subsection of the domain.
+    // We need to parse this to insert periods where
+    // they belong (ie at the end of each string)
+    Ok(query(&name, TYPE_PTR)?
+        .iter()
+        .map(|data| parse_revdns_answer(data))
+        .collect())
 }
 
 fn parse_revdns_answer(data: &[u8]) -> Vec<u8> {
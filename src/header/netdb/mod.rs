@@ -1,32 +1,27 @@
 //! netdb implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xns/netdb.h.html
 
+mod address;
+mod buffer;
 mod dns;
+mod file;
+mod hosts;
+mod nsswitch;
+mod resolv;
+mod sort;
 
-use core::{
-    mem, ptr, slice,
-    str::{self, FromStr},
-};
+use core::{mem, ptr, str, task::Poll};
 
-use alloc::{borrow::ToOwned, boxed::Box, str::SplitWhitespace, vec::Vec};
+use alloc::{boxed::Box, string::ToString, vec::Vec};
 
 use crate::{
     c_str::{CStr, CString},
     header::{
-        arpa_inet::{htons, inet_aton, ntohl},
-        errno::*,
-        fcntl::O_RDONLY,
-        netinet_in::{in_addr, sockaddr_in, sockaddr_in6},
-        stdlib::atoi,
-        strings::strcasecmp,
-        sys_socket::{constants::AF_INET, sa_family_t, sockaddr, socklen_t},
-        unistd::SEEK_SET,
-    },
-    platform::{
-        self,
-        rlb::{Line, RawLineBuffer},
-        types::*,
-        Pal, Sys,
+        arpa_inet::htons,
+        netinet_in::{sockaddr_in, sockaddr_in6},
+        sys_socket::{sockaddr, socklen_t},
+        unistd::gethostname,
     },
+    platform::types::*,
 };
 
 #[cfg(target_os = "linux")]
@@ -37,12 +32,29 @@ pub mod sys;
 #[path = "redox.rs"]
 pub mod sys;
 
+pub use self::gai::*;
+pub mod gai;
+
 pub use self::host::*;
 pub mod host;
 
 pub use self::lookup::*;
 pub mod lookup;
 
+pub use self::network::*;
+pub mod network;
+
+pub use self::protocol::*;
+pub mod protocol;
+
+pub use self::query::*;
+pub mod query;
+
+pub use self::service::*;
+pub mod service;
+
+use self::address::Address;
+
 #[repr(C)]
 pub struct hostent {
     h_name: *mut c_char,
@@ -108,6 +120,11 @@ pub const EAI_ADDRFAMILY: c_int = -9;
 pub const EAI_MEMORY: c_int = -10;
 pub const EAI_SYSTEM: c_int = -11;
 pub const EAI_OVERFLOW: c_int = -12;
+pub const EAI_INPROGRESS: c_int = -100;
+pub const EAI_CANCELED: c_int = -101;
+pub const EAI_NOTCANCELED: c_int = -102;
+pub const EAI_ALLDONE: c_int = -103;
+pub const EAI_INTR: c_int = -104;
 
 pub const NI_MAXHOST: c_int = 1025;
 pub const NI_MAXSERV: c_int = 32;
@@ -118,605 +135,19 @@ pub const NI_NOFQDN: c_int = 0x0004;
 pub const NI_NAMEREQD: c_int = 0x0008;
 pub const NI_DGRAM: c_int = 0x0010;
 
-static mut NETDB: c_int = 0;
-pub static mut NET_ENTRY: netent = netent {
-    n_name: ptr::null_mut(),
-    n_aliases: ptr::null_mut(),
-    n_addrtype: 0,
-    n_net: 0,
-};
-pub static mut NET_NAME: Option<Vec<u8>> = None;
-pub static mut NET_ALIASES: Option<Vec<Vec<u8>>> = None;
-pub static mut NET_ADDR: Option<u32> = None;
-static mut N_POS: usize = 0;
-static mut NET_STAYOPEN: c_int = 0;
-
 #[allow(non_upper_case_globals)]
-#[no_mangle]
+#[thread_local]
 pub static mut h_errno: c_int = 0;
+pub const NETDB_INTERNAL: c_int = -1;
+pub const NETDB_SUCCESS: c_int = 0;
 pub const HOST_NOT_FOUND: c_int = 1;
 pub const NO_DATA: c_int = 2;
 pub const NO_RECOVERY: c_int = 3;
 pub const TRY_AGAIN: c_int = 4;
 
-static mut PROTODB: c_int = 0;
-static mut PROTO_ENTRY: protoent = protoent {
-    p_name: ptr::null_mut(),
-    p_aliases: ptr::null_mut(),
-    p_proto: 0 as c_int,
-};
-static mut PROTO_NAME: Option<Vec<u8>> = None;
-static mut PROTO_ALIASES: Option<Vec<Vec<u8>>> = None;
-static mut PROTO_NUM: Option<c_int> = None;
-static mut P_POS: usize = 0;
-static mut PROTO_STAYOPEN: c_int = 0;
-
-static mut SERVDB: c_int = 0;
-static mut SERV_ENTRY: servent = servent {
-    s_name: ptr::null_mut(),
-    s_aliases: ptr::null_mut(),
-    s_port: 0 as c_int,
-    s_proto: ptr::null_mut(),
-};
-static mut SERV_NAME: Option<Vec<u8>> = None;
-static mut SERV_ALIASES: Option<Vec<Vec<u8>>> = None;
-static mut SERV_PORT: Option<c_int> = None;
-static mut SERV_PROTO: Option<Vec<u8>> = None;
-static mut S_POS: usize = 0;
-static mut SERV_STAYOPEN: c_int = 0;
-
-fn bytes_to_box_str(bytes: &[u8]) -> Box<str> {
-    Box::from(core::str::from_utf8(bytes).unwrap_or(""))
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn endnetent() {
-    Sys::close(NETDB);
-    NETDB = 0;
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn endprotoent() {
-    Sys::close(PROTODB);
-    PROTODB = 0;
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn endservent() {
-    Sys::close(SERVDB);
-    SERVDB = 0;
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn gethostbyaddr(
-    v: *const c_void,
-    length: socklen_t,
-    format: c_int,
-) -> *mut hostent {
-    let addr: in_addr = *(v as *mut in_addr);
-
-    // check the hosts file first
-    let mut p: *mut hostent;
-    sethostent(HOST_STAYOPEN);
-    while {
-        p = gethostent();
-        !p.is_null()
-    } {
-        let mut cp = (*p).h_addr_list;
-        loop {
-            if cp.is_null() {
-                break;
-            }
-            if (*cp).is_null() {
-                break;
-            }
-            let mut cp_slice: [i8; 4] = [0i8; 4];
-            (*cp).copy_to(cp_slice.as_mut_ptr(), 4);
-            let cp_s_addr = mem::transmute::<[i8; 4], u32>(cp_slice);
-            if cp_s_addr == addr.s_addr {
-                sethostent(HOST_STAYOPEN);
-                return p;
-            }
-            cp = cp.offset(1);
-        }
-    }
-
-    //TODO actually get aliases
-    let mut _host_aliases: Vec<Vec<u8>> = Vec::new();
-    _host_aliases.push(vec![b'\0']);
-    let mut host_aliases: Vec<*mut i8> = Vec::new();
-    host_aliases.push(ptr::null_mut());
-    HOST_ALIASES = Some(_host_aliases);
-
-    match lookup_addr(addr) {
-        Ok(s) => {
-            _HOST_ADDR_LIST = mem::transmute::<u32, [u8; 4]>(addr.s_addr);
-            HOST_ADDR_LIST = [_HOST_ADDR_LIST.as_mut_ptr() as *mut c_char, ptr::null_mut()];
-            let host_name = s[0].to_vec();
-            HOST_NAME = Some(host_name);
-            HOST_ENTRY = hostent {
-                h_name: HOST_NAME.as_mut().unwrap().as_mut_ptr() as *mut c_char,
-                h_aliases: host_aliases.as_mut_slice().as_mut_ptr() as *mut *mut i8,
-                h_addrtype: format,
-                h_length: length as i32,
-                h_addr_list: HOST_ADDR_LIST.as_mut_ptr(),
-            };
-            &mut HOST_ENTRY
-        }
-        Err(e) => {
-            platform::errno = e;
-            ptr::null_mut()
-        }
-    }
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn gethostbyname(name: *const c_char) -> *mut hostent {
-    // check if some idiot gave us an address instead of a name
-    let name_cstr = CStr::from_ptr(name);
-    let mut octets = str::from_utf8_unchecked(name_cstr.to_bytes()).split('.');
-    let mut s_addr = [0u8; 4];
-    let mut is_addr = true;
-    for item in &mut s_addr {
-        if let Some(n) = octets.next().and_then(|x| u8::from_str(x).ok()) {
-            *item = n;
-        } else {
-            is_addr = false;
-        }
-    }
-    if octets.next() != None {
-        is_addr = false;
-    }
-
-    if is_addr {
-        let addr = in_addr {
-            s_addr: mem::transmute::<[u8; 4], u32>(s_addr),
-        };
-        return gethostbyaddr(&addr as *const _ as *const c_void, 4, AF_INET);
-    }
-
-    // check the hosts file first
-    let mut p: *mut hostent;
-    sethostent(HOST_STAYOPEN);
-    while {
-        p = gethostent();
-        !p.is_null()
-    } {
-        if strcasecmp((*p).h_name, name) == 0 {
-            sethostent(HOST_STAYOPEN);
-            return p;
-        }
-        let mut cp = (*p).h_aliases;
-        loop {
-            if cp.is_null() {
-                break;
-            }
-            if (*cp).is_null() {
-                break;
-            }
-            if strcasecmp(*cp, name) == 0 {
-                sethostent(HOST_STAYOPEN);
-                return p;
-            }
-            cp = cp.offset(1);
-        }
-    }
-
-    let name_cstr = CStr::from_ptr(name);
-
-    let mut host = match lookup_host(str::from_utf8_unchecked(name_cstr.to_bytes())) {
-        Ok(lookuphost) => lookuphost,
-        Err(e) => {
-            platform::errno = e;
-            return ptr::null_mut();
-        }
-    };
-    let host_addr = match host.next() {
-        Some(result) => result,
-        None => {
-            platform::errno = ENOENT;
-            return ptr::null_mut();
-        }
-    };
-
-    let host_name: Vec<u8> = name_cstr.to_bytes().to_vec();
-    HOST_NAME = Some(host_name);
-    _HOST_ADDR_LIST = mem::transmute::<u32, [u8; 4]>(host_addr.s_addr);
-    HOST_ADDR_LIST = [_HOST_ADDR_LIST.as_mut_ptr() as *mut c_char, ptr::null_mut()];
-    HOST_ADDR = Some(host_addr);
-
-    //TODO actually get aliases
-    let mut _host_aliases: Vec<Vec<u8>> = Vec::new();
-    _host_aliases.push(vec![b'\0']);
-    let mut host_aliases: Vec<*mut i8> = Vec::new();
-    host_aliases.push(ptr::null_mut());
-    host_aliases.push(ptr::null_mut());
-    HOST_ALIASES = Some(_host_aliases);
-
-    HOST_ENTRY = hostent {
-        h_name: HOST_NAME.as_mut().unwrap().as_mut_ptr() as *mut c_char,
-        h_aliases: host_aliases.as_mut_slice().as_mut_ptr() as *mut *mut i8,
-        h_addrtype: AF_INET,
-        h_length: 4,
-        h_addr_list: HOST_ADDR_LIST.as_mut_ptr(),
-    };
-    sethostent(HOST_STAYOPEN);
-    &mut HOST_ENTRY as *mut hostent
-}
-
-pub unsafe extern "C" fn getnetbyaddr(net: u32, net_type: c_int) -> *mut netent {
-    unimplemented!();
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn getnetbyname(name: *const c_char) -> *mut netent {
-    let mut n: *mut netent;
-    setnetent(NET_STAYOPEN);
-    while {
-        n = getnetent();
-        !n.is_null()
-    } {
-        if strcasecmp((*n).n_name, name) == 0 {
-            setnetent(NET_STAYOPEN);
-            return n;
-        }
-    }
-    setnetent(NET_STAYOPEN);
-
-    platform::errno = ENOENT;
-    ptr::null_mut() as *mut netent
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn getnetent() -> *mut netent {
-    if NETDB == 0 {
-        NETDB = Sys::open(&CString::new("/etc/networks").unwrap(), O_RDONLY, 0);
-    }
-
-    let mut rlb = RawLineBuffer::new(NETDB);
-    rlb.seek(N_POS);
-
-    let mut r: Box<str> = Box::default();
-    while r.is_empty() || r.split_whitespace().next() == None || r.starts_with('#') {
-        r = match rlb.next() {
-            Line::Some(s) => bytes_to_box_str(s),
-            _ => {
-                if NET_STAYOPEN == 0 {
-                    endnetent();
-                }
-                return ptr::null_mut();
-            }
-        };
-    }
-    rlb.next();
-    N_POS = rlb.line_pos();
-
-    let mut iter: SplitWhitespace = r.split_whitespace();
-
-    let mut net_name = iter.next().unwrap().as_bytes().to_vec();
-    net_name.push(b'\0');
-    NET_NAME = Some(net_name);
-
-    let mut addr_vec = iter.next().unwrap().as_bytes().to_vec();
-    addr_vec.push(b'\0');
-    let addr_cstr = addr_vec.as_slice().as_ptr() as *const i8;
-    let mut addr = mem::MaybeUninit::uninit();
-    inet_aton(addr_cstr, addr.as_mut_ptr());
-    let addr = addr.assume_init();
-    NET_ADDR = Some(ntohl(addr.s_addr));
-
-    let mut _net_aliases: Vec<Vec<u8>> = Vec::new();
-    for s in iter {
-        let mut alias = s.as_bytes().to_vec();
-        alias.push(b'\0');
-        _net_aliases.push(alias);
-    }
-    let mut net_aliases: Vec<*mut i8> = _net_aliases
-        .iter_mut()
-        .map(|x| x.as_mut_ptr() as *mut i8)
-        .collect();
-    net_aliases.push(ptr::null_mut());
-    NET_ALIASES = Some(_net_aliases);
-
-    NET_ENTRY = netent {
-        n_name: NET_NAME.as_mut().unwrap().as_mut_ptr() as *mut c_char,
-        n_aliases: net_aliases.as_mut_slice().as_mut_ptr() as *mut *mut i8,
-        n_addrtype: AF_INET,
-        n_net: NET_ADDR.unwrap() as u64,
-    };
-    &mut NET_ENTRY as *mut netent
-}
-
 #[no_mangle]
-pub unsafe extern "C" fn getprotobyname(name: *const c_char) -> *mut protoent {
-    let mut p: *mut protoent;
-    setprotoent(PROTO_STAYOPEN);
-    while {
-        p = getprotoent();
-        !p.is_null()
-    } {
-        if strcasecmp((*p).p_name, name) == 0 {
-            setprotoent(PROTO_STAYOPEN);
-            return p;
-        }
-
-        let mut cp = (*p).p_aliases;
-        loop {
-            if cp.is_null() {
-                setprotoent(PROTO_STAYOPEN);
-                break;
-            }
-            if (*cp).is_null() {
-                setprotoent(PROTO_STAYOPEN);
-                break;
-            }
-            if strcasecmp(*cp, name) == 0 {
-                setprotoent(PROTO_STAYOPEN);
-                return p;
-            }
-            cp = cp.offset(1);
-        }
-    }
-    setprotoent(PROTO_STAYOPEN);
-
-    platform::errno = ENOENT;
-    ptr::null_mut() as *mut protoent
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn getprotobynumber(number: c_int) -> *mut protoent {
-    setprotoent(PROTO_STAYOPEN);
-    let mut p: *mut protoent;
-    while {
-        p = getprotoent();
-        !p.is_null()
-    } {
-        if (*p).p_proto == number {
-            setprotoent(PROTO_STAYOPEN);
-            return p;
-        }
-    }
-    setprotoent(PROTO_STAYOPEN);
-    platform::errno = ENOENT;
-    ptr::null_mut() as *mut protoent
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn getprotoent() -> *mut protoent {
-    if PROTODB == 0 {
-        PROTODB = Sys::open(&CString::new("/etc/protocols").unwrap(), O_RDONLY, 0);
-    }
-
-    let mut rlb = RawLineBuffer::new(PROTODB);
-    rlb.seek(P_POS);
-
-    let mut r: Box<str> = Box::default();
-    while r.is_empty() || r.split_whitespace().next() == None || r.starts_with('#') {
-        r = match rlb.next() {
-            Line::Some(s) => bytes_to_box_str(s),
-            _ => {
-                if PROTO_STAYOPEN == 0 {
-                    endprotoent();
-                }
-                return ptr::null_mut();
-            }
-        };
-    }
-    rlb.next();
-    P_POS = rlb.line_pos();
-
-    let mut iter: SplitWhitespace = r.split_whitespace();
-
-    let mut proto_name: Vec<u8> = iter.next().unwrap().as_bytes().to_vec();
-    proto_name.push(b'\0');
-
-    let mut num = iter.next().unwrap().as_bytes().to_vec();
-    num.push(b'\0');
-    PROTO_NUM = Some(atoi(num.as_mut_slice().as_mut_ptr() as *mut i8));
-
-    let mut _proto_aliases: Vec<Vec<u8>> = Vec::new();
-    for s in iter {
-        let mut alias = s.as_bytes().to_vec();
-        alias.push(b'\0');
-        _proto_aliases.push(alias);
-    }
-    let mut proto_aliases: Vec<*mut i8> = _proto_aliases
-        .iter_mut()
-        .map(|x| x.as_mut_ptr() as *mut i8)
-        .collect();
-    proto_aliases.push(ptr::null_mut());
-
-    PROTO_ALIASES = Some(_proto_aliases);
-    PROTO_NAME = Some(proto_name);
-
-    PROTO_ENTRY = protoent {
-        p_name: PROTO_NAME.as_mut().unwrap().as_mut_slice().as_mut_ptr() as *mut c_char,
-        p_aliases: proto_aliases.as_mut_slice().as_mut_ptr() as *mut *mut i8,
-        p_proto: PROTO_NUM.unwrap(),
-    };
-    if PROTO_STAYOPEN == 0 {
-        endprotoent();
-    }
-    &mut PROTO_ENTRY as *mut protoent
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn getservbyname(name: *const c_char, proto: *const c_char) -> *mut servent {
-    setservent(SERV_STAYOPEN);
-    let mut p: *mut servent;
-    if proto.is_null() {
-        while {
-            p = getservent();
-            !p.is_null()
-        } {
-            if strcasecmp((*p).s_name, name) == 0 {
-                setservent(SERV_STAYOPEN);
-                return p;
-            }
-        }
-    } else {
-        while {
-            p = getservent();
-            !p.is_null()
-        } {
-            if strcasecmp((*p).s_name, name) == 0 && strcasecmp((*p).s_proto, proto) == 0 {
-                setservent(SERV_STAYOPEN);
-                return p;
-            }
-        }
-    }
-    setservent(SERV_STAYOPEN);
-    platform::errno = ENOENT;
-    ptr::null_mut() as *mut servent
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn getservbyport(port: c_int, proto: *const c_char) -> *mut servent {
-    setservent(SERV_STAYOPEN);
-    let mut p: *mut servent;
-    if proto.is_null() {
-        while {
-            p = getservent();
-            !p.is_null()
-        } {
-            if (*p).s_port == port {
-                setservent(SERV_STAYOPEN);
-                return p;
-            }
-        }
-    } else {
-        while {
-            p = getservent();
-            !p.is_null()
-        } {
-            if (*p).s_port == port && strcasecmp((*p).s_proto, proto) == 0 {
-                setservent(SERV_STAYOPEN);
-                return p;
-            }
-        }
-    }
-    setservent(SERV_STAYOPEN);
-    platform::errno = ENOENT;
-    ptr::null_mut()
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn getservent() -> *mut servent {
-    if SERVDB == 0 {
-        SERVDB = Sys::open(&CString::new("/etc/services").unwrap(), O_RDONLY, 0);
-    }
-    let mut rlb = RawLineBuffer::new(SERVDB);
-    rlb.seek(S_POS);
-
-    let r: Box<str> = Box::default();
-
-    loop {
-        let r = match rlb.next() {
-            Line::Some(s) => bytes_to_box_str(s),
-            _ => {
-                if SERV_STAYOPEN == 0 {
-                    endservent();
-                }
-                return ptr::null_mut();
-            }
-        };
-
-        let mut iter = r.split_whitespace();
-        let mut serv_name = match iter.next() {
-            Some(serv_name) => serv_name.as_bytes().to_vec(),
-            None => continue,
-        };
-        serv_name.push(b'\0');
-        let port_proto = match iter.next() {
-            Some(port_proto) => port_proto,
-            None => continue,
-        };
-        let mut split = port_proto.split('/');
-        let mut port = match split.next() {
-            Some(port) => port.as_bytes().to_vec(),
-            None => continue,
-        };
-        port.push(b'\0');
-        SERV_PORT =
-            Some(htons(atoi(port.as_mut_slice().as_mut_ptr() as *mut i8) as u16) as u32 as i32);
-        let mut proto = match split.next() {
-            Some(proto) => proto.as_bytes().to_vec(),
-            None => continue,
-        };
-        proto.push(b'\0');
-
-        rlb.next();
-        S_POS = rlb.line_pos();
-
-        /*
-         *let mut _serv_aliases: Vec<Vec<u8>> = Vec::new();
-         *loop {
-         *    let mut alias = match iter.next() {
-         *        Some(s) => s.as_bytes().to_vec(),
-         *        _ => break
-         *    };
-         *    alias.push(b'\0');
-         *    _serv_aliases.push(alias);
-         *}
-         *let mut serv_aliases: Vec<*mut i8> = _serv_aliases.iter_mut().map(|x| x.as_mut_ptr() as *mut i8).collect();
-         *serv_aliases.push(ptr::null_mut());
-         *
-         */
-        let mut _serv_aliases: Vec<Vec<u8>> = Vec::new();
-        _serv_aliases.push(vec![b'\0']);
-        let mut serv_aliases: Vec<*mut i8> = Vec::new();
-        serv_aliases.push(ptr::null_mut());
-        serv_aliases.push(ptr::null_mut());
-
-        SERV_ALIASES = Some(_serv_aliases);
-        SERV_NAME = Some(serv_name);
-        SERV_PROTO = Some(proto);
-
-        SERV_ENTRY = servent {
-            s_name: SERV_NAME.as_mut().unwrap().as_mut_slice().as_mut_ptr() as *mut c_char,
-            s_aliases: serv_aliases.as_mut_slice().as_mut_ptr() as *mut *mut i8,
-            s_port: SERV_PORT.unwrap(),
-            s_proto: SERV_PROTO.as_mut().unwrap().as_mut_slice().as_mut_ptr() as *mut c_char,
-        };
-
-        if SERV_STAYOPEN == 0 {
-            endservent();
-        }
-        break &mut SERV_ENTRY as *mut servent;
-    }
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn setnetent(stayopen: c_int) {
-    NET_STAYOPEN = stayopen;
-    if NETDB == 0 {
-        NETDB = Sys::open(&CString::new("/etc/networks").unwrap(), O_RDONLY, 0)
-    } else {
-        Sys::lseek(NETDB, 0, SEEK_SET);
-        N_POS = 0;
-    }
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn setprotoent(stayopen: c_int) {
-    PROTO_STAYOPEN = stayopen;
-    if PROTODB == 0 {
-        PROTODB = Sys::open(&CString::new("/etc/protocols").unwrap(), O_RDONLY, 0)
-    } else {
-        Sys::lseek(PROTODB, 0, SEEK_SET);
-        P_POS = 0;
-    }
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn setservent(stayopen: c_int) {
-    SERV_STAYOPEN = stayopen;
-    if SERVDB == 0 {
-        SERVDB = Sys::open(&CString::new("/etc/services").unwrap(), O_RDONLY, 0)
-    } else {
-        Sys::lseek(SERVDB, 0, SEEK_SET);
-        S_POS = 0;
-    }
+pub unsafe extern "C" fn __h_errno_location() -> *mut c_int {
+    &mut h_errno
 }
 
 #[no_mangle]
@@ -726,96 +157,63 @@ pub unsafe extern "C" fn getaddrinfo(
     hints: *const addrinfo,
     res: *mut *mut addrinfo,
 ) -> c_int {
-    //TODO: getaddrinfo
-    let node_opt = if node.is_null() {
-        None
-    } else {
-        Some(CStr::from_ptr(node))
-    };
-
-    let service_opt = if service.is_null() {
-        None
-    } else {
-        Some(CStr::from_ptr(service))
-    };
-
-    let hints_opt = if hints.is_null() { None } else { Some(&*hints) };
-
     trace!(
         "getaddrinfo({:?}, {:?}, {:?})",
-        node_opt.map(|c| str::from_utf8_unchecked(c.to_bytes())),
-        service_opt.map(|c| str::from_utf8_unchecked(c.to_bytes())),
-        hints_opt
+        node.as_ref()
+            .map(|_| str::from_utf8_unchecked(CStr::from_ptr(node).to_bytes())),
+        service
+            .as_ref()
+            .map(|_| str::from_utf8_unchecked(CStr::from_ptr(service).to_bytes())),
+        hints.as_ref()
     );
 
-    //TODO: Use hints
-    let mut ai_flags = hints_opt.map_or(0, |hints| hints.ai_flags);
-    let mut ai_family; // = hints_opt.map_or(AF_UNSPEC, |hints| hints.ai_family);
-    let ai_socktype = hints_opt.map_or(0, |hints| hints.ai_socktype);
-    let mut ai_protocol; // = hints_opt.map_or(0, |hints| hints.ai_protocol);
-
     *res = ptr::null_mut();
 
-    let mut port = 0;
-    if let Some(service) = service_opt {
-        //TODO: Support other service definitions as well as AI_NUMERICSERV
-        match str::from_utf8_unchecked(service.to_bytes()).parse::<u16>() {
-            Ok(ok) => port = ok,
-            Err(_err) => (),
+    let mut query = match gai_query::new(node, service, hints) {
+        Ok(query) => query,
+        Err(err) => return err,
+    };
+    loop {
+        match query.poll() {
+            Poll::Ready(Ok(results)) => {
+                *res = results;
+                return 0;
+            }
+            Poll::Ready(Err(err)) => return err,
+            Poll::Pending => query.wait(),
         }
     }
+}
 
-    //TODO: Check hosts file
-    if let Some(node) = node_opt {
-        //TODO: Support AI_NUMERICHOST
-        let lookuphost = match lookup_host(str::from_utf8_unchecked(node.to_bytes())) {
-            Ok(lookuphost) => lookuphost,
-            Err(e) => {
-                platform::errno = e;
-                return EAI_SYSTEM;
-            }
-        };
-
-        for in_addr in lookuphost {
-            ai_family = AF_INET;
-            ai_protocol = 0;
-
-            let ai_addr = Box::into_raw(Box::new(sockaddr_in {
-                sin_family: ai_family as sa_family_t,
-                sin_port: htons(port),
-                sin_addr: in_addr,
-                sin_zero: [0; 8],
-            })) as *mut sockaddr;
-
-            let ai_addrlen = mem::size_of::<sockaddr_in>();
-
-            let ai_canonname = if ai_flags & AI_CANONNAME > 0 {
-                ai_flags &= !AI_CANONNAME;
-                node.to_owned().into_raw()
-            } else {
-                ptr::null_mut()
-            };
+/// Copies `s` with a null byte into `buf`, if it fits in `len` bytes
+unsafe fn copy_name(s: &[u8], buf: *mut c_char, len: socklen_t) -> Result<(), c_int> {
+    if s.len() >= len as usize {
+        return Err(EAI_OVERFLOW);
+    }
+    ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, buf, s.len());
+    *buf.add(s.len()) = 0;
+    Ok(())
+}
 
-            let addrinfo = Box::new(addrinfo {
-                ai_flags: 0,
-                ai_family,
-                ai_socktype,
-                ai_protocol,
-                ai_addrlen,
-                ai_canonname,
-                ai_addr,
-                ai_next: ptr::null_mut(),
-            });
-
-            let mut indirect = res;
-            while !(*indirect).is_null() {
-                indirect = &mut (**indirect).ai_next;
+/// The name of `addr` in the DNS, without the domain of this host for NI_NOFQDN
+fn host_name(addr: &Address, flags: c_int) -> Option<Vec<u8>> {
+    let mut name = host::by_addr(*addr, addr.family()).ok()?.name;
+    if flags & NI_NOFQDN != 0 {
+        let mut hostname = [0; 256];
+        let hostname = unsafe {
+            gethostname(hostname.as_mut_ptr(), hostname.len() - 1);
+            CStr::from_ptr(hostname.as_ptr()).to_bytes()
+        };
+        if let (Some(dot), Some(own_dot)) = (
+            name.iter().position(|&b| b == b'.'),
+            hostname.iter().position(|&b| b == b'.'),
+        ) {
+            if name[dot..].eq_ignore_ascii_case(&hostname[own_dot..]) {
+                name.truncate(dot);
             }
-            *indirect = Box::into_raw(addrinfo);
         }
     }
-
-    0
+    Some(name)
 }
 
 #[no_mangle]
@@ -828,29 +226,55 @@ pub unsafe extern "C" fn getnameinfo(
     servlen: socklen_t,
     flags: c_int,
 ) -> c_int {
-    //TODO: getnameinfo
-    if addrlen as usize != mem::size_of::<sockaddr_in>() {
-        return EAI_FAMILY;
-    }
-
-    let addr = &*(addr as *const sockaddr_in);
-
-    let host_opt = if host.is_null() {
-        None
-    } else {
-        Some(slice::from_raw_parts_mut(host, hostlen as usize))
+    let (addr, port) = match Address::from_sockaddr(addr, addrlen) {
+        Some(found) => found,
+        None => return EAI_FAMILY,
     };
 
-    let serv_opt = if serv.is_null() {
-        None
-    } else {
-        Some(slice::from_raw_parts_mut(serv, servlen as usize))
-    };
+    let want_host = !host.is_null() && hostlen != 0;
+    let want_serv = !serv.is_null() && servlen != 0;
+    if !want_host && !want_serv {
+        return EAI_NONAME;
+    }
+
+    if want_host {
+        let name = if flags & NI_NUMERICHOST != 0 {
+            None
+        } else {
+            host_name(&addr, flags)
+        };
+        let name = match name {
+            Some(name) => name,
+            None if flags & NI_NAMEREQD != 0 => return EAI_NONAME,
+            None => addr.to_string().into_bytes(),
+        };
+        if let Err(err) = copy_name(&name, host, hostlen) {
+            return err;
+        }
+    }
 
-    eprintln!("getnameinfo({:p}, {}, {:#x})", addr, addrlen, flags);
+    if want_serv {
+        let entry = if flags & NI_NUMERICSERV != 0 {
+            ptr::null_mut()
+        } else {
+            let proto = if flags & NI_DGRAM != 0 {
+                c_str!("udp")
+            } else {
+                c_str!("tcp")
+            };
+            getservbyport(htons(port) as c_int, proto.as_ptr())
+        };
+        let result = if entry.is_null() {
+            copy_name(port.to_string().as_bytes(), serv, servlen)
+        } else {
+            copy_name(CStr::from_ptr((*entry).s_name).to_bytes(), serv, servlen)
+        };
+        if let Err(err) = result {
+            return err;
+        }
+    }
 
-    platform::errno = ENOSYS;
-    EAI_SYSTEM
+    0
 }
 
 #[no_mangle]
@@ -889,6 +313,11 @@ pub extern "C" fn gai_strerror(errcode: c_int) -> *const c_char {
         EAI_MEMORY => c_str!("Out of memory"),
         EAI_SYSTEM => c_str!("System error"),
         EAI_OVERFLOW => c_str!("Overflow"),
+        EAI_INPROGRESS => c_str!("Processing request in progress"),
+        EAI_CANCELED => c_str!("Request canceled"),
+        EAI_NOTCANCELED => c_str!("Request not canceled"),
+        EAI_ALLDONE => c_str!("All requests done"),
+        EAI_INTR => c_str!("Interrupted by a signal"),
         _ => c_str!("Unknown error"),
     }
     .as_ptr()
@@ -0,0 +1,196 @@
+use alloc::{string::String, vec::Vec};
+use core::ptr;
+
+use crate::{
+    c_str::{CStr, CString},
+    header::{
+        errno::{ENOENT, ERANGE},
+        sys_socket::constants::AF_INET,
+    },
+    platform::{self, types::*},
+    sync::Mutex,
+};
+
+use super::{
+    buffer::{reentrant, Buffer, Local, Record},
+    file::{is_named, Cursor, Entries, Entry},
+    netent, HOST_NOT_FOUND, NETDB_INTERNAL, NETDB_SUCCESS,
+};
+
+static NETWORKS: Mutex<Cursor<Network>> = Mutex::new(Cursor::new());
+
+#[thread_local]
+static mut NET_ENTRY: Local<netent> = Local::new(netent {
+    n_name: ptr::null_mut(),
+    n_aliases: ptr::null_mut(),
+    n_addrtype: 0,
+    n_net: 0,
+});
+
+/// A line of /etc/networks, like `link-local 169.254.0.0`
+struct Network {
+    name: String,
+    aliases: Vec<String>,
+    /// The network number in host byte order
+    net: u32,
+}
+
+/// Reads a network number like inet_network(), where each of up to four parts is a byte of
+/// the number and the last is the lowest, so "10" is 10 and "192.168" is 0xc0a8
+fn parse_net(s: &str) -> Option<u32> {
+    let parts: Vec<&str> = s.split('.').collect();
+    if parts.len() > 4 {
+        return None;
+    }
+    parts.iter().try_fold(0u32, |net, part| {
+        Some(net << 8 | part.parse::<u8>().ok()? as u32)
+    })
+}
+
+impl Entry for Network {
+    fn path() -> CString {
+        CString::new("/etc/networks").unwrap()
+    }
+
+    fn parse(words: Vec<String>) -> Option<Network> {
+        let mut words = words.into_iter();
+        let name = words.next()?;
+        let net = parse_net(&words.next()?)?;
+        Some(Network {
+            name,
+            aliases: words.collect(),
+            net,
+        })
+    }
+}
+
+impl Record for Network {
+    type Struct = netent;
+
+    fn write(&self, entry: &mut netent, buf: &mut Buffer) -> Result<(), c_int> {
+        *entry = netent {
+            n_aliases: buf.strings(&self.aliases)?,
+            n_name: buf.string(self.name.as_bytes())?,
+            n_addrtype: AF_INET,
+            n_net: self.net as c_ulong,
+        };
+        Ok(())
+    }
+}
+
+unsafe fn by_name(name: *const c_char) -> Option<Network> {
+    let name = CStr::from_ptr(name).to_str().ok()?;
+    Entries::<Network>::open()?.find(|network| is_named(name, &network.name, &network.aliases))
+}
+
+fn by_addr(net: u32, net_type: c_int) -> Option<Network> {
+    if net_type != AF_INET {
+        return None;
+    }
+    Entries::<Network>::open()?.find(|network| network.net == net)
+}
+
+unsafe fn local_network(found: Option<Network>) -> *mut netent {
+    match found {
+        Some(network) => NET_ENTRY.store(&network),
+        None => {
+            platform::errno = ENOENT;
+            ptr::null_mut()
+        }
+    }
+}
+
+/// The result of a reentrant function, with h_errno like for hosts
+unsafe fn reentrant_network(
+    found: Option<Network>,
+    result_buf: *mut netent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut netent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    let status = reentrant(found.as_ref(), 0, result_buf, buf, buflen, result);
+    *h_errnop = if found.is_none() {
+        HOST_NOT_FOUND
+    } else if status == ERANGE {
+        NETDB_INTERNAL
+    } else {
+        NETDB_SUCCESS
+    };
+    status
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn endnetent() {
+    NETWORKS.lock().close();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn setnetent(stayopen: c_int) {
+    NETWORKS.lock().rewind(stayopen);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getnetent() -> *mut netent {
+    let network = NETWORKS.lock().next_entry();
+    match network {
+        Some(network) => NET_ENTRY.store(&network),
+        None => ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getnetent_r(
+    result_buf: *mut netent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut netent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    let status = NETWORKS
+        .lock()
+        .next_reentrant(result_buf, buf, buflen, result);
+    *h_errnop = match status {
+        0 => NETDB_SUCCESS,
+        ENOENT => HOST_NOT_FOUND,
+        _ => NETDB_INTERNAL,
+    };
+    status
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getnetbyaddr(net: u32, net_type: c_int) -> *mut netent {
+    local_network(by_addr(net, net_type))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getnetbyaddr_r(
+    net: u32,
+    net_type: c_int,
+    result_buf: *mut netent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut netent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    let network = by_addr(net, net_type);
+    reentrant_network(network, result_buf, buf, buflen, result, h_errnop)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getnetbyname(name: *const c_char) -> *mut netent {
+    local_network(by_name(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getnetbyname_r(
+    name: *const c_char,
+    result_buf: *mut netent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut netent,
+    h_errnop: *mut c_int,
+) -> c_int {
+    let network = by_name(name);
+    reentrant_network(network, result_buf, buf, buflen, result, h_errnop)
+}
@@ -0,0 +1,53 @@
+//! The order that host names are looked up in, from the `hosts` line of nsswitch.conf, like
+//! `hosts: files dns`. Sources other than `files` and `dns`, and actions like
+//! `[NOTFOUND=return]`, are ignored; each source is tried until one finds the name.
+
+use alloc::vec::Vec;
+
+use crate::db::{FileDb, Separator};
+
+use super::file::config_path;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Source {
+    /// The hosts file
+    Files,
+    /// The name servers from resolv.conf
+    Dns,
+}
+
+/// The sources to look hosts up in. Without a `hosts` line naming any known source, these are
+/// the hosts file and then DNS, so that names like localhost resolve without a network.
+pub fn hosts() -> Vec<Source> {
+    configured().unwrap_or_else(|| vec![Source::Files, Source::Dns])
+}
+
+fn configured() -> Option<Vec<Source>> {
+    let path = config_path(c_str!("/etc/nsswitch.conf"));
+    let mut db = FileDb::open(&path, Separator::Whitespace).ok()?;
+    while let Some(words) = db.read().ok()? {
+        // The colon after the database name doesn't need a space after it
+        let line = words.join(" ");
+        let colon = match line.find(':') {
+            Some(colon) => colon,
+            None => continue,
+        };
+        if line[..colon].trim() != "hosts" {
+            continue;
+        }
+        let sources: Vec<Source> = line[colon + 1..]
+            .split_whitespace()
+            .filter_map(|source| match source {
+                "files" => Some(Source::Files),
+                "dns" => Some(Source::Dns),
+                _ => None,
+            })
+            .collect();
+        return if sources.is_empty() {
+            None
+        } else {
+            Some(sources)
+        };
+    }
+    None
+}
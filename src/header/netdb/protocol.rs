@@ -0,0 +1,145 @@
+use alloc::{string::String, vec::Vec};
+use core::ptr;
+
+use crate::{
+    c_str::{CStr, CString},
+    header::errno::ENOENT,
+    platform::{self, types::*},
+    sync::Mutex,
+};
+
+use super::{
+    buffer::{reentrant, Buffer, Local, Record},
+    file::{is_named, Cursor, Entries, Entry},
+    protoent,
+};
+
+static PROTOCOLS: Mutex<Cursor<Protocol>> = Mutex::new(Cursor::new());
+
+#[thread_local]
+static mut PROTO_ENTRY: Local<protoent> = Local::new(protoent {
+    p_name: ptr::null_mut(),
+    p_aliases: ptr::null_mut(),
+    p_proto: 0,
+});
+
+/// A line of /etc/protocols, like `tcp 6 TCP`
+struct Protocol {
+    name: String,
+    aliases: Vec<String>,
+    number: c_int,
+}
+
+impl Entry for Protocol {
+    fn path() -> CString {
+        CString::new("/etc/protocols").unwrap()
+    }
+
+    fn parse(words: Vec<String>) -> Option<Protocol> {
+        let mut words = words.into_iter();
+        let name = words.next()?;
+        let number = words.next()?.parse().ok()?;
+        Some(Protocol {
+            name,
+            aliases: words.collect(),
+            number,
+        })
+    }
+}
+
+impl Record for Protocol {
+    type Struct = protoent;
+
+    fn write(&self, entry: &mut protoent, buf: &mut Buffer) -> Result<(), c_int> {
+        *entry = protoent {
+            p_aliases: buf.strings(&self.aliases)?,
+            p_name: buf.string(self.name.as_bytes())?,
+            p_proto: self.number,
+        };
+        Ok(())
+    }
+}
+
+unsafe fn by_name(name: *const c_char) -> Option<Protocol> {
+    let name = CStr::from_ptr(name).to_str().ok()?;
+    Entries::<Protocol>::open()?.find(|protocol| is_named(name, &protocol.name, &protocol.aliases))
+}
+
+fn by_number(number: c_int) -> Option<Protocol> {
+    Entries::<Protocol>::open()?.find(|protocol| protocol.number == number)
+}
+
+unsafe fn local_protocol(found: Option<Protocol>) -> *mut protoent {
+    match found {
+        Some(protocol) => PROTO_ENTRY.store(&protocol),
+        None => {
+            platform::errno = ENOENT;
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn endprotoent() {
+    PROTOCOLS.lock().close();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn setprotoent(stayopen: c_int) {
+    PROTOCOLS.lock().rewind(stayopen);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getprotoent() -> *mut protoent {
+    let protocol = PROTOCOLS.lock().next_entry();
+    match protocol {
+        Some(protocol) => PROTO_ENTRY.store(&protocol),
+        None => ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getprotoent_r(
+    result_buf: *mut protoent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut protoent,
+) -> c_int {
+    PROTOCOLS
+        .lock()
+        .next_reentrant(result_buf, buf, buflen, result)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getprotobyname(name: *const c_char) -> *mut protoent {
+    local_protocol(by_name(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getprotobyname_r(
+    name: *const c_char,
+    result_buf: *mut protoent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut protoent,
+) -> c_int {
+    let protocol = by_name(name);
+    reentrant(protocol.as_ref(), 0, result_buf, buf, buflen, result)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getprotobynumber(number: c_int) -> *mut protoent {
+    local_protocol(by_number(number))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getprotobynumber_r(
+    number: c_int,
+    result_buf: *mut protoent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut protoent,
+) -> c_int {
+    let protocol = by_number(number);
+    reentrant(protocol.as_ref(), 0, result_buf, buf, buflen, result)
+}
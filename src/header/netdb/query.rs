@@ -0,0 +1,223 @@
+//! getaddrinfo() as a query that never blocks, for event loops. A query has a file descriptor
+//! that is readable whenever gai_query_process() can make progress, which can be polled or
+//! added to an epoll instance, and a timeout after which it has to be processed anyway.
+
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
+use core::{ptr, task::Poll};
+
+use crate::{
+    c_str::{CStr, CString},
+    header::sys_socket::constants::{AF_INET, AF_INET6, AF_UNSPEC},
+    platform::{self, types::*},
+};
+
+use super::{
+    address::{self, Address, Addresses},
+    addrinfo,
+    resolv::{self, Epoll},
+    sort, AI_ADDRCONFIG, AI_ALL, AI_CANONNAME, AI_NUMERICHOST, AI_NUMERICSERV, AI_PASSIVE,
+    AI_V4MAPPED, EAI_ALLDONE, EAI_BADFLAGS, EAI_FAMILY, EAI_INPROGRESS, EAI_NONAME, EAI_SYSTEM,
+};
+
+pub struct gai_query {
+    node: Option<CString>,
+    flags: c_int,
+    /// The socket types, protocols and ports that each address comes with
+    services: Vec<(c_int, c_int, u16)>,
+    /// Whether poll() has been called, which is what starts the lookups
+    started: bool,
+    /// None once the query has finished. It is dropped before the epoll instance its sockets
+    /// are in.
+    addresses: Option<Addresses>,
+    epoll: Epoll,
+}
+
+impl gai_query {
+    /// Starts looking up `node` and `service` like getaddrinfo(), failing with its error if
+    /// the arguments are wrong
+    pub unsafe fn new(
+        node: *const c_char,
+        service: *const c_char,
+        hints: *const addrinfo,
+    ) -> Result<gai_query, c_int> {
+        let node = if node.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(node))
+        };
+        let service = if service.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(service))
+        };
+        let hints = hints.as_ref();
+
+        let ai_flags = hints.map_or(0, |hints| hints.ai_flags);
+        let ai_family = hints.map_or(AF_UNSPEC, |hints| hints.ai_family);
+        let ai_socktype = hints.map_or(0, |hints| hints.ai_socktype);
+        let ai_protocol = hints.map_or(0, |hints| hints.ai_protocol);
+
+        let known_flags = AI_PASSIVE
+            | AI_CANONNAME
+            | AI_NUMERICHOST
+            | AI_V4MAPPED
+            | AI_ALL
+            | AI_ADDRCONFIG
+            | AI_NUMERICSERV;
+        if ai_flags & !known_flags != 0 || (ai_flags & AI_CANONNAME != 0 && node.is_none()) {
+            return Err(EAI_BADFLAGS);
+        }
+        if node.is_none() && service.is_none() {
+            return Err(EAI_NONAME);
+        }
+        if ai_family != AF_UNSPEC && ai_family != AF_INET && ai_family != AF_INET6 {
+            return Err(EAI_FAMILY);
+        }
+
+        let services = address::services(service, ai_flags, ai_socktype, ai_protocol)?;
+        let epoll = Epoll::new().map_err(|err| {
+            platform::errno = err;
+            EAI_SYSTEM
+        })?;
+        Ok(gai_query {
+            node: node.map(ToOwned::to_owned),
+            flags: ai_flags,
+            services,
+            started: false,
+            addresses: Some(Addresses::new(node, ai_flags, ai_family, epoll.fd())),
+            epoll,
+        })
+    }
+
+    pub fn fd(&self) -> c_int {
+        self.epoll.fd()
+    }
+
+    /// When poll() has to be called even if the file descriptor isn't readable, which is right
+    /// away if it hasn't been yet, in milliseconds on the monotonic clock
+    pub fn deadline(&self) -> Option<i64> {
+        if !self.started {
+            return Some(resolv::now());
+        }
+        self.addresses.as_ref().and_then(Addresses::deadline)
+    }
+
+    /// Waits until the query can go on
+    pub fn wait(&self) {
+        self.epoll.wait(self.deadline());
+    }
+
+    /// Goes on with the query as far as it can without waiting, returning the results once it
+    /// has finished, or EAI_ALLDONE after that
+    pub fn poll(&mut self) -> Poll<Result<*mut addrinfo, c_int>> {
+        self.started = true;
+        let addresses = match self.addresses {
+            Some(ref mut addresses) => addresses.poll(),
+            None => return Poll::Ready(Err(EAI_ALLDONE)),
+        };
+        let found = match addresses {
+            Poll::Ready(found) => found,
+            Poll::Pending => return Poll::Pending,
+        };
+        self.addresses = None;
+        Poll::Ready(found.map(|mut addrs| {
+            sort::sort(&mut addrs);
+            self.results(&addrs)
+        }))
+    }
+
+    /// The list of results, where each address comes with every socket type, the first also
+    /// with the name
+    fn results(&self, addrs: &[Address]) -> *mut addrinfo {
+        let mut res = ptr::null_mut();
+        let mut indirect: *mut *mut addrinfo = &mut res;
+        for addr in addrs.iter() {
+            for &(ai_socktype, ai_protocol, port) in self.services.iter() {
+                let (ai_addr, ai_addrlen) = addr.to_sockaddr(port);
+
+                let ai_canonname = match self.node {
+                    Some(ref node) if self.flags & AI_CANONNAME != 0 && res.is_null() => {
+                        node.clone().into_raw()
+                    }
+                    _ => ptr::null_mut(),
+                };
+
+                let addrinfo = Box::new(addrinfo {
+                    ai_flags: self.flags,
+                    ai_family: addr.family(),
+                    ai_socktype,
+                    ai_protocol,
+                    ai_addrlen,
+                    ai_canonname,
+                    ai_addr,
+                    ai_next: ptr::null_mut(),
+                });
+
+                unsafe {
+                    *indirect = Box::into_raw(addrinfo);
+                    indirect = &mut (**indirect).ai_next;
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Starts a query for `node` and `service` like getaddrinfo() would look them up. Returns 0
+/// with the query in `*query`, or the error getaddrinfo() would give for wrong arguments.
+#[no_mangle]
+pub unsafe extern "C" fn gai_query_start(
+    node: *const c_char,
+    service: *const c_char,
+    hints: *const addrinfo,
+    query: *mut *mut gai_query,
+) -> c_int {
+    match gai_query::new(node, service, hints) {
+        Ok(new) => {
+            *query = Box::into_raw(Box::new(new));
+            0
+        }
+        Err(err) => err,
+    }
+}
+
+/// The file descriptor that is readable whenever the query can go on, once it has been
+/// processed for the first time
+#[no_mangle]
+pub unsafe extern "C" fn gai_query_fd(query: *const gai_query) -> c_int {
+    (*query).fd()
+}
+
+/// How many milliseconds are left until the query has to be processed even if its file
+/// descriptor isn't readable, or -1 if there is no such time. It is 0 until the query has been
+/// processed for the first time, which is what sends its first questions.
+#[no_mangle]
+pub unsafe extern "C" fn gai_query_timeout(query: *const gai_query) -> c_int {
+    resolv::remaining((*query).deadline())
+}
+
+/// Goes on with the query as far as it can without blocking. Returns EAI_INPROGRESS until it
+/// has finished, and then what getaddrinfo() would, with the results in `*res`.
+#[no_mangle]
+pub unsafe extern "C" fn gai_query_process(
+    query: *mut gai_query,
+    res: *mut *mut addrinfo,
+) -> c_int {
+    *res = ptr::null_mut();
+    match (*query).poll() {
+        Poll::Ready(Ok(results)) => {
+            *res = results;
+            0
+        }
+        Poll::Ready(Err(err)) => err,
+        Poll::Pending => EAI_INPROGRESS,
+    }
+}
+
+/// Frees the query, stopping it if it hasn't finished
+#[no_mangle]
+pub unsafe extern "C" fn gai_query_free(query: *mut gai_query) {
+    if !query.is_null() {
+        drop(Box::from_raw(query));
+    }
+}
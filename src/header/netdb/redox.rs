@@ -1,9 +1,10 @@
 use crate::{c_str::CString, fs::File, header::fcntl, io::Read};
 use alloc::string::String;
 
-pub fn get_dns_server() -> String {
+/// The name server the network stack was set up with, which is in /etc/net/dns
+pub fn get_dns_server() -> Option<String> {
     let mut string = String::new();
-    let mut file = File::open(&CString::new("/etc/net/dns").unwrap(), fcntl::O_RDONLY).unwrap(); // TODO: error handling
-    file.read_to_string(&mut string).unwrap(); // TODO: error handling
-    string
+    let mut file = File::open(&CString::new("/etc/net/dns").unwrap(), fcntl::O_RDONLY).ok()?;
+    file.read_to_string(&mut string).ok()?;
+    Some(string)
 }
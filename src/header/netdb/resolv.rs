@@ -0,0 +1,736 @@
+//! The stub resolver, which asks the name servers from resolv.conf about names.
+//!
+//! Queries go to each name server in turn over UDP, for as many rounds as the `attempts` option
+//! says, and again over TCP when an answer was truncated. Answers are only taken from the server
+//! a query went to, and only if they answer that query, so that spoofed ones are left out.
+//!
+//! The LOCALDOMAIN and RES_OPTIONS environment variables override the search list and add
+//! options like in glibc, except in programs that run setuid or setgid. Name servers can be
+//! given with a port as `[address]:port`, like in OpenBSD.
+//!
+//! Lookups never block: their sockets are in an epoll instance that whoever drives them waits
+//! on, so that query() can wait for one and getaddrinfo_a() for many at once.
+
+use alloc::{
+    string::{String, ToString},
+    vec::{self, Vec},
+};
+use core::{
+    mem, ptr, str,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::Poll,
+};
+
+use crate::{
+    c_str::CStr,
+    fs::File,
+    header::{
+        arpa_inet::htonl,
+        errno::{EAGAIN, EINPROGRESS},
+        fcntl::{O_CLOEXEC, O_RDONLY},
+        netinet_in::{in_addr, sockaddr_in6, INADDR_LOOPBACK, IPPROTO_TCP, IPPROTO_UDP},
+        stdlib::getenv,
+        sys_epoll::{
+            epoll_event, EPOLLIN, EPOLLOUT, EPOLL_CLOEXEC, EPOLL_CTL_ADD, EPOLL_CTL_DEL,
+            EPOLL_CTL_MOD,
+        },
+        sys_random::GRND_NONBLOCK,
+        sys_socket::{
+            constants::{SOCK_CLOEXEC, SOCK_DGRAM, SOCK_NONBLOCK, SOCK_STREAM},
+            sockaddr, socklen_t,
+        },
+        time::{timespec, CLOCK_MONOTONIC, CLOCK_REALTIME},
+        unistd::gethostname,
+    },
+    io::Read,
+    platform::{self, types::*, Pal, PalEpoll, PalSocket, Sys},
+};
+
+use super::{
+    address::Address,
+    dns::{Dns, DnsQuery},
+    file::config_path,
+    sys::get_dns_server,
+};
+
+const CLASS_IN: u16 = 0x0001;
+
+const PORT: u16 = 53;
+
+// The limits of glibc
+const MAXNS: usize = 3;
+const MAXDNSRCH: usize = 6;
+const NDOTS_MAX: usize = 15;
+const TIMEOUT_MAX: c_int = 30;
+const ATTEMPTS_MAX: c_int = 5;
+
+const FLAG_RESPONSE: u16 = 0x8000;
+const FLAG_TRUNCATED: u16 = 0x0200;
+const FLAG_RECURSION_DESIRED: u16 = 0x0100;
+const RCODE_MASK: u16 = 0x000f;
+const RCODE_NOERROR: u16 = 0;
+const RCODE_NXDOMAIN: u16 = 3;
+
+/// Where rotation has got to, for the `rotate` option
+static ROTATION: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Config {
+    /// The addresses and ports of the name servers
+    pub nameservers: Vec<(Address, u16)>,
+    /// The domains that names with fewer than `ndots` dots are looked for in first
+    pub search: Vec<String>,
+    pub ndots: usize,
+    /// How long to wait for the first answer, in seconds
+    pub timeout: c_int,
+    /// How many rounds of queries to send to the name servers
+    pub attempts: c_int,
+    /// Whether to spread queries over the name servers instead of starting with the first
+    pub rotate: bool,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            nameservers: Vec::new(),
+            search: Vec::new(),
+            ndots: 1,
+            timeout: 5,
+            attempts: 2,
+            rotate: false,
+        }
+    }
+}
+
+impl Config {
+    /// Reads the configuration from resolv.conf and the environment
+    pub fn load() -> Config {
+        let mut config = Config::default();
+        let path = config_path(c_str!("/etc/resolv.conf"));
+        let mut search = read_file(&path).and_then(|data| config.parse(&data));
+
+        if config.nameservers.len() < MAXNS {
+            if let Some(nameserver) = get_dns_server().and_then(|s| parse_nameserver(s.as_bytes()))
+            {
+                config.nameservers.push(nameserver);
+            }
+        }
+        // Like in glibc, the server on this host is asked when there is no other
+        if config.nameservers.is_empty() {
+            let loopback = in_addr {
+                s_addr: htonl(INADDR_LOOPBACK),
+            };
+            config.nameservers.push((Address::V4(loopback), PORT));
+        }
+
+        if let Some(domains) = unsafe { env(c_str!("LOCALDOMAIN")) } {
+            search = Some(words(domains).take(MAXDNSRCH).filter_map(domain).collect());
+        }
+        // Without a search list, names are looked for in the domain of this host
+        config.search = search.unwrap_or_else(|| {
+            let mut hostname = [0; 256];
+            let hostname = unsafe {
+                gethostname(hostname.as_mut_ptr(), hostname.len() - 1);
+                CStr::from_ptr(hostname.as_ptr()).to_bytes()
+            };
+            match hostname.iter().position(|&b| b == b'.') {
+                Some(dot) => domain(&hostname[dot + 1..]).into_iter().collect(),
+                None => Vec::new(),
+            }
+        });
+
+        if let Some(options) = unsafe { env(c_str!("RES_OPTIONS")) } {
+            words(options).for_each(|option| config.option(option));
+        }
+        config
+    }
+
+    /// Reads the lines of a resolv.conf into the configuration, returning the search list if
+    /// there was one
+    fn parse(&mut self, data: &[u8]) -> Option<Vec<String>> {
+        let mut search = None;
+        for line in data.split(|&b| b == b'\n') {
+            let mut words = words(line);
+            match words.next() {
+                Some(b"nameserver") => {
+                    let nameserver = words.next().and_then(parse_nameserver);
+                    if let Some(nameserver) = nameserver {
+                        if self.nameservers.len() < MAXNS {
+                            self.nameservers.push(nameserver);
+                        }
+                    }
+                }
+                // The last of domain and search is the one that counts
+                Some(b"domain") => search = Some(words.take(1).filter_map(domain).collect()),
+                Some(b"search") => {
+                    search = Some(words.take(MAXDNSRCH).filter_map(domain).collect())
+                }
+                Some(b"options") => words.for_each(|option| self.option(option)),
+                _ => (),
+            }
+        }
+        search
+    }
+
+    /// Sets an option like "ndots:2" or "rotate", leaving out ones that aren't known
+    fn option(&mut self, option: &[u8]) {
+        let (name, value) = match option.iter().position(|&b| b == b':') {
+            Some(colon) => (&option[..colon], Some(&option[colon + 1..])),
+            None => (option, None),
+        };
+        let value = value
+            .and_then(|value| str::from_utf8(value).ok())
+            .and_then(|value| value.parse::<c_int>().ok())
+            .filter(|&value| value >= 0);
+        match (name, value) {
+            (b"ndots", Some(ndots)) => self.ndots = (ndots as usize).min(NDOTS_MAX),
+            (b"timeout", Some(timeout)) => self.timeout = timeout.max(1).min(TIMEOUT_MAX),
+            (b"attempts", Some(attempts)) => self.attempts = attempts.max(1).min(ATTEMPTS_MAX),
+            (b"rotate", None) => self.rotate = true,
+            _ => (),
+        }
+    }
+
+    /// The names to look for in turn for `name`, which are it and it in each search domain.
+    /// Names with a dot at the end are only looked for as they are.
+    fn candidates(&self, name: &str) -> Vec<String> {
+        if let Some(name) = name.strip_suffix('.') {
+            return vec![name.to_string()];
+        }
+        let searched = self
+            .search
+            .iter()
+            .map(|domain| format!("{}.{}", name, domain));
+        if name.matches('.').count() >= self.ndots {
+            Some(name.to_string()).into_iter().chain(searched).collect()
+        } else {
+            searched.chain(Some(name.to_string())).collect()
+        }
+    }
+}
+
+/// Looks up the records of type `q_type` for `name`, trying the names the search list gives
+/// until one has some. Returns the data of the records.
+pub fn query(name: &str, q_type: u16) -> Result<Vec<Vec<u8>>, c_int> {
+    let epoll = Epoll::new()?;
+    let mut lookup = Lookup::new(name, q_type, epoll.fd());
+    loop {
+        if let Poll::Ready(result) = lookup.poll() {
+            return result;
+        }
+        epoll.wait(lookup.deadline());
+    }
+}
+
+/// An epoll instance that the sockets of lookups are added to, so that one file descriptor
+/// shows when any of them can go on
+pub struct Epoll(c_int);
+
+impl Epoll {
+    pub fn new() -> Result<Epoll, c_int> {
+        match Sys::epoll_create1(EPOLL_CLOEXEC) {
+            fd if fd < 0 => Err(unsafe { platform::errno }),
+            fd => Ok(Epoll(fd)),
+        }
+    }
+
+    pub fn fd(&self) -> c_int {
+        self.0
+    }
+
+    /// Waits until a socket is ready, or until `deadline` has passed
+    pub fn wait(&self, deadline: Option<i64>) {
+        let mut event = epoll_event::default();
+        Sys::epoll_pwait(self.0, &mut event, 1, remaining(deadline), ptr::null());
+    }
+}
+
+impl Drop for Epoll {
+    fn drop(&mut self) {
+        Sys::close(self.0);
+    }
+}
+
+/// Looking up the records of one type for a name, which never blocks. Its sockets are in an
+/// epoll instance, which is ready whenever poll() can make progress, and poll() also has to be
+/// called once the deadline has passed.
+pub struct Lookup {
+    config: Config,
+    q_type: u16,
+    epoll: c_int,
+    /// The names that are left to look for
+    candidates: vec::IntoIter<String>,
+    exchange: Option<Exchange>,
+    error: Option<c_int>,
+}
+
+impl Lookup {
+    pub fn new(name: &str, q_type: u16, epoll: c_int) -> Lookup {
+        let config = Config::load();
+        let candidates: Vec<String> = config
+            .candidates(name)
+            .into_iter()
+            .filter(|candidate| valid_name(candidate))
+            .collect();
+        Lookup {
+            config,
+            q_type,
+            epoll,
+            candidates: candidates.into_iter(),
+            exchange: None,
+            error: None,
+        }
+    }
+
+    /// When poll() has to be called even if no socket is ready, in milliseconds on the
+    /// monotonic clock
+    pub fn deadline(&self) -> Option<i64> {
+        self.exchange.as_ref().map(|exchange| exchange.deadline)
+    }
+
+    /// Goes on with the lookup as far as it can without waiting. Returns the data of the
+    /// records once it has finished, after which it must not be polled again.
+    pub fn poll(&mut self) -> Poll<Result<Vec<Vec<u8>>, c_int>> {
+        loop {
+            let exchange = match self.exchange {
+                Some(ref mut exchange) => exchange,
+                None => match self.candidates.next() {
+                    Some(name) => {
+                        self.exchange
+                            .insert(Exchange::new(name, self.q_type, &self.config))
+                    }
+                    None => {
+                        return Poll::Ready(match self.error {
+                            Some(err) => Err(err),
+                            None => Ok(Vec::new()),
+                        })
+                    }
+                },
+            };
+            let response = match exchange.poll(&self.config, self.epoll) {
+                Poll::Ready(response) => response,
+                Poll::Pending => return Poll::Pending,
+            };
+            self.exchange = None;
+
+            match response {
+                Ok(response) => {
+                    let q_type = self.q_type;
+                    let answers: Vec<Vec<u8>> = response
+                        .answers
+                        .into_iter()
+                        .filter(|answer| answer.a_type == q_type && answer.a_class == CLASS_IN)
+                        .map(|answer| answer.data)
+                        .collect();
+                    if !answers.is_empty() {
+                        return Poll::Ready(Ok(answers));
+                    }
+                }
+                // Keep looking, in case another name has records
+                Err(err) => self.error = Some(err),
+            }
+        }
+    }
+}
+
+/// Asking the name servers about one name, each in turn for as many rounds as the `attempts`
+/// option says
+struct Exchange {
+    question: Question,
+    /// The server that each round starts with
+    first: usize,
+    /// How many servers have been asked, counting every round
+    tries: usize,
+    server: Option<Server>,
+    /// When the server being asked is given up on
+    deadline: i64,
+}
+
+impl Exchange {
+    fn new(name: String, q_type: u16, config: &Config) -> Exchange {
+        let id = random_id();
+        let packet = Dns {
+            transaction_id: id,
+            flags: FLAG_RECURSION_DESIRED,
+            queries: vec![DnsQuery {
+                name: name.clone(),
+                q_type,
+                q_class: CLASS_IN,
+            }],
+            answers: vec![],
+        }
+        .compile();
+        let first = if config.rotate {
+            ROTATION.fetch_add(1, Ordering::Relaxed)
+        } else {
+            0
+        };
+        Exchange {
+            question: Question {
+                id,
+                name,
+                q_type,
+                packet,
+            },
+            first,
+            tries: 0,
+            server: None,
+            deadline: 0,
+        }
+    }
+
+    /// Goes on asking, returning the response once there is one, or EAGAIN if none of the
+    /// servers gave one
+    fn poll(&mut self, config: &Config, epoll: c_int) -> Poll<Result<Dns, c_int>> {
+        let count = config.nameservers.len();
+        loop {
+            let server = match self.server {
+                Some(ref mut server) => server,
+                None => {
+                    if self.tries == count * config.attempts as usize {
+                        return Poll::Ready(Err(EAGAIN));
+                    }
+                    // Like in glibc, the time is split between the servers and doubles each
+                    // round
+                    let attempt = self.tries / count;
+                    let timeout = ((config.timeout * 1000) << attempt) / count as c_int;
+                    let (addr, port) = config.nameservers[(self.first + self.tries) % count];
+                    self.tries += 1;
+                    self.deadline = now() + timeout.max(1000) as i64;
+                    self.server = Server::udp(addr, port, &self.question, epoll);
+                    continue;
+                }
+            };
+
+            let response = match server.poll(&self.question) {
+                Poll::Ready(Some(response)) => response,
+                Poll::Pending if now() < self.deadline => return Poll::Pending,
+                // The server failed or took too long, so on to the next one
+                _ => {
+                    self.server = None;
+                    continue;
+                }
+            };
+            if response.flags & FLAG_TRUNCATED != 0 && server.tcp.is_none() {
+                let (addr, port) = (server.addr, server.port);
+                self.server = Server::tcp(addr, port, &self.question, epoll);
+                continue;
+            }
+            match response.flags & RCODE_MASK {
+                RCODE_NOERROR | RCODE_NXDOMAIN => return Poll::Ready(Ok(response)),
+                // Other servers might do better than one that failed or refused
+                _ => self.server = None,
+            }
+        }
+    }
+}
+
+/// A query sent to name servers
+struct Question {
+    id: u16,
+    name: String,
+    q_type: u16,
+    packet: Vec<u8>,
+}
+
+impl Question {
+    /// Whether `response` is the answer to this query
+    fn answered_by(&self, response: &Dns) -> bool {
+        response.transaction_id == self.id
+            && response.flags & FLAG_RESPONSE != 0
+            && match response.queries.as_slice() {
+                [query] => {
+                    query.name.eq_ignore_ascii_case(&self.name)
+                        && query.q_type == self.q_type
+                        && query.q_class == CLASS_IN
+                }
+                _ => false,
+            }
+    }
+}
+
+/// A name server being asked over UDP, or over TCP once its answer over UDP was truncated
+struct Server {
+    addr: Address,
+    port: u16,
+    socket: Socket,
+    tcp: Option<Tcp>,
+}
+
+/// Where an exchange over TCP has got to. Messages are preceded by their length both ways.
+struct Tcp {
+    message: Vec<u8>,
+    written: usize,
+    read: Vec<u8>,
+}
+
+impl Server {
+    fn udp(addr: Address, port: u16, question: &Question, epoll: c_int) -> Option<Server> {
+        let socket = Socket::new(addr.family(), SOCK_DGRAM, IPPROTO_UDP as c_int, epoll)?;
+        // Connecting makes errors from unreachable servers show up right away
+        if addr.with_sockaddr(port, |sa, len| unsafe { Sys::connect(socket.fd, sa, len) }) < 0
+            || Sys::write(socket.fd, &question.packet) < 0
+        {
+            return None;
+        }
+        Some(Server {
+            addr,
+            port,
+            socket,
+            tcp: None,
+        })
+    }
+
+    fn tcp(addr: Address, port: u16, question: &Question, epoll: c_int) -> Option<Server> {
+        let socket = Socket::new(addr.family(), SOCK_STREAM, IPPROTO_TCP as c_int, epoll)?;
+        let connected =
+            addr.with_sockaddr(port, |sa, len| unsafe { Sys::connect(socket.fd, sa, len) });
+        if connected < 0 && unsafe { platform::errno } != EINPROGRESS {
+            return None;
+        }
+        socket.wait_for(EPOLLOUT)?;
+
+        let mut message = (question.packet.len() as u16).to_be_bytes().to_vec();
+        message.extend_from_slice(&question.packet);
+        Some(Server {
+            addr,
+            port,
+            socket,
+            tcp: Some(Tcp {
+                message,
+                written: 0,
+                read: Vec::new(),
+            }),
+        })
+    }
+
+    /// Reads what the server has sent, returning its answer once there is one, or None if the
+    /// server failed
+    fn poll(&mut self, question: &Question) -> Poll<Option<Dns>> {
+        match self.tcp {
+            Some(ref mut tcp) => Server::poll_tcp(&self.socket, tcp, question),
+            None => self.poll_udp(question),
+        }
+    }
+
+    fn poll_udp(&self, question: &Question) -> Poll<Option<Dns>> {
+        let mut buf = [0; 4096];
+        loop {
+            let mut from = sockaddr_in6::default();
+            let mut from_len = mem::size_of::<sockaddr_in6>() as socklen_t;
+            let len = unsafe {
+                Sys::recvfrom(
+                    self.socket.fd,
+                    buf.as_mut_ptr() as *mut c_void,
+                    buf.len(),
+                    0,
+                    &mut from as *mut _ as *mut sockaddr,
+                    &mut from_len,
+                )
+            };
+            if len < 0 {
+                return would_block();
+            }
+
+            let from =
+                unsafe { Address::from_sockaddr(&from as *const _ as *const sockaddr, from_len) };
+            match from {
+                Some((from, from_port))
+                    if from.to_v6() == self.addr.to_v6() && from_port == self.port => {}
+                _ => continue,
+            }
+            match Dns::parse(&buf[..len as usize]) {
+                Ok(response) if question.answered_by(&response) => {
+                    return Poll::Ready(Some(response))
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    fn poll_tcp(socket: &Socket, tcp: &mut Tcp, question: &Question) -> Poll<Option<Dns>> {
+        while tcp.written < tcp.message.len() {
+            match Sys::write(socket.fd, &tcp.message[tcp.written..]) {
+                len if len > 0 => tcp.written += len as usize,
+                _ => return would_block(),
+            }
+            if tcp.written == tcp.message.len() && socket.wait_for(EPOLLIN).is_none() {
+                return Poll::Ready(None);
+            }
+        }
+
+        let mut buf = [0; 4096];
+        loop {
+            if let [high, low, ref response @ ..] = *tcp.read.as_slice() {
+                let len = u16::from_be_bytes([high, low]) as usize;
+                if response.len() >= len {
+                    return Poll::Ready(match Dns::parse(&response[..len]) {
+                        Ok(response) if question.answered_by(&response) => Some(response),
+                        _ => None,
+                    });
+                }
+            }
+            match Sys::read(socket.fd, &mut buf) {
+                len if len > 0 => tcp.read.extend_from_slice(&buf[..len as usize]),
+                // The server closed the connection before it answered
+                0 => return Poll::Ready(None),
+                _ => return would_block(),
+            }
+        }
+    }
+}
+
+/// What to make of a read or write that failed: nothing yet if it would have blocked, and
+/// otherwise that the server failed
+fn would_block<T>() -> Poll<Option<T>> {
+    match unsafe { platform::errno } {
+        EAGAIN => Poll::Pending,
+        _ => Poll::Ready(None),
+    }
+}
+
+/// A non-blocking socket of a lookup, which is in its epoll instance until it is dropped and
+/// closed
+struct Socket {
+    fd: c_int,
+    epoll: c_int,
+}
+
+impl Socket {
+    fn new(domain: c_int, kind: c_int, protocol: c_int, epoll: c_int) -> Option<Socket> {
+        let kind = kind | SOCK_NONBLOCK | SOCK_CLOEXEC;
+        let socket = match unsafe { Sys::socket(domain, kind, protocol) } {
+            fd if fd < 0 => return None,
+            fd => Socket { fd, epoll },
+        };
+        let mut event = epoll_event {
+            events: EPOLLIN,
+            ..epoll_event::default()
+        };
+        if Sys::epoll_ctl(epoll, EPOLL_CTL_ADD, socket.fd, &mut event) < 0 {
+            return None;
+        }
+        Some(socket)
+    }
+
+    /// Makes the epoll instance wait for `events` on the socket, in place of reading
+    fn wait_for(&self, events: c_uint) -> Option<()> {
+        let mut event = epoll_event {
+            events,
+            ..epoll_event::default()
+        };
+        if Sys::epoll_ctl(self.epoll, EPOLL_CTL_MOD, self.fd, &mut event) < 0 {
+            None
+        } else {
+            Some(())
+        }
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        Sys::epoll_ctl(self.epoll, EPOLL_CTL_DEL, self.fd, ptr::null_mut());
+        Sys::close(self.fd);
+    }
+}
+
+/// Parses the address of a name server, which can be followed by a port if it is in brackets
+fn parse_nameserver(s: &[u8]) -> Option<(Address, u16)> {
+    let s = trim(s);
+    if s.first() != Some(&b'[') {
+        return Some((Address::parse(s)?, PORT));
+    }
+    let end = s.iter().position(|&b| b == b']')?;
+    let addr = Address::parse(&s[1..end])?;
+    let port = match &s[end + 1..] {
+        [] => PORT,
+        [b':', port @ ..] => str::from_utf8(port).ok()?.parse().ok()?,
+        _ => return None,
+    };
+    Some((addr, port))
+}
+
+/// Whether `name` can be put in a query: labels of 1 to 63 bytes, making up 253 at most
+fn valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 253
+        && name
+            .split('.')
+            .all(|label| !label.is_empty() && label.len() <= 63)
+}
+
+/// A search domain, without the dot it can end in
+fn domain(s: &[u8]) -> Option<String> {
+    let s = str::from_utf8(s).ok()?.trim_end_matches('.');
+    if s.is_empty() {
+        None
+    } else {
+        Some(s.to_string())
+    }
+}
+
+/// An ID for a query that is hard for others to guess
+fn random_id() -> u16 {
+    let mut id = [0; 2];
+    if Sys::getrandom(&mut id, GRND_NONBLOCK) == 2 {
+        return u16::from_ne_bytes(id);
+    }
+    let mut time = timespec::default();
+    Sys::clock_gettime(CLOCK_REALTIME, &mut time);
+    (time.tv_nsec >> 4) as u16
+}
+
+/// The time on the monotonic clock, in milliseconds
+pub fn now() -> i64 {
+    let mut time = timespec::default();
+    Sys::clock_gettime(CLOCK_MONOTONIC, &mut time);
+    time.tv_sec as i64 * 1000 + time.tv_nsec as i64 / 1_000_000
+}
+
+/// How many milliseconds are left until `deadline`, or -1 to wait without one
+pub fn remaining(deadline: Option<i64>) -> c_int {
+    match deadline {
+        Some(deadline) => (deadline - now()).max(0).min(c_int::max_value() as i64) as c_int,
+        None => -1,
+    }
+}
+
+fn words(line: &[u8]) -> impl Iterator<Item = &[u8]> {
+    // Comments start with "#" or ";"
+    let end = line
+        .iter()
+        .position(|&b| b == b'#' || b == b';')
+        .unwrap_or(line.len());
+    line[..end]
+        .split(|b| b.is_ascii_whitespace())
+        .filter(|word| !word.is_empty())
+}
+
+fn trim(s: &[u8]) -> &[u8] {
+    let start = s.iter().position(|b| !b.is_ascii_whitespace());
+    let end = s.iter().rposition(|b| !b.is_ascii_whitespace());
+    match (start, end) {
+        (Some(start), Some(end)) => &s[start..=end],
+        _ => &[],
+    }
+}
+
+fn read_file(path: &CStr) -> Option<Vec<u8>> {
+    let mut file = File::open(path, O_RDONLY | O_CLOEXEC).ok()?;
+    let mut data = Vec::new();
+    file.read_to_end(&mut data).ok()?;
+    Some(data)
+}
+
+/// The value of an environment variable, which is ignored where the process runs setuid or
+/// setgid, so that whoever starts it cannot change the lookups of a more privileged program
+unsafe fn env(name: &CStr) -> Option<&'static [u8]> {
+    if Sys::getuid() != Sys::geteuid() || Sys::getgid() != Sys::getegid() {
+        return None;
+    }
+    let value = getenv(name.as_ptr());
+    if value.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(value).to_bytes())
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::{string::String, vec::Vec};
+use core::ptr;
+
+use crate::{
+    c_str::{CStr, CString},
+    header::{arpa_inet::htons, errno::ENOENT},
+    platform::{self, types::*},
+    sync::Mutex,
+};
+
+use super::{
+    buffer::{reentrant, Buffer, Local, Record},
+    file::{is_named, Cursor, Entries, Entry},
+    servent,
+};
+
+static SERVICES: Mutex<Cursor<Service>> = Mutex::new(Cursor::new());
+
+#[thread_local]
+static mut SERV_ENTRY: Local<servent> = Local::new(servent {
+    s_name: ptr::null_mut(),
+    s_aliases: ptr::null_mut(),
+    s_port: 0,
+    s_proto: ptr::null_mut(),
+});
+
+/// A line of /etc/services, like `http 80/tcp www`
+struct Service {
+    name: String,
+    aliases: Vec<String>,
+    port: u16,
+    proto: String,
+}
+
+impl Service {
+    /// Whether the service is for `proto`, where NULL is any protocol
+    unsafe fn is_for(&self, proto: *const c_char) -> bool {
+        proto.is_null()
+            || CStr::from_ptr(proto)
+                .to_bytes()
+                .eq_ignore_ascii_case(self.proto.as_bytes())
+    }
+}
+
+impl Entry for Service {
+    fn path() -> CString {
+        CString::new("/etc/services").unwrap()
+    }
+
+    fn parse(words: Vec<String>) -> Option<Service> {
+        let mut words = words.into_iter();
+        let name = words.next()?;
+        let port_proto = words.next()?;
+        let mut port_proto = port_proto.splitn(2, '/');
+        let port = port_proto.next()?.parse().ok()?;
+        let proto = port_proto.next()?.into();
+        Some(Service {
+            name,
+            aliases: words.collect(),
+            port,
+            proto,
+        })
+    }
+}
+
+impl Record for Service {
+    type Struct = servent;
+
+    fn write(&self, entry: &mut servent, buf: &mut Buffer) -> Result<(), c_int> {
+        *entry = servent {
+            s_aliases: buf.strings(&self.aliases)?,
+            s_name: buf.string(self.name.as_bytes())?,
+            s_port: htons(self.port) as c_int,
+            s_proto: buf.string(self.proto.as_bytes())?,
+        };
+        Ok(())
+    }
+}
+
+unsafe fn by_name(name: *const c_char, proto: *const c_char) -> Option<Service> {
+    let name = CStr::from_ptr(name).to_str().ok()?;
+    Entries::<Service>::open()?
+        .find(|service| is_named(name, &service.name, &service.aliases) && service.is_for(proto))
+}
+
+/// The service with `port`, which is in network byte order
+unsafe fn by_port(port: c_int, proto: *const c_char) -> Option<Service> {
+    Entries::<Service>::open()?
+        .find(|service| htons(service.port) as c_int == port && service.is_for(proto))
+}
+
+unsafe fn local_service(found: Option<Service>) -> *mut servent {
+    match found {
+        Some(service) => SERV_ENTRY.store(&service),
+        None => {
+            platform::errno = ENOENT;
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn endservent() {
+    SERVICES.lock().close();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn setservent(stayopen: c_int) {
+    SERVICES.lock().rewind(stayopen);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getservent() -> *mut servent {
+    let service = SERVICES.lock().next_entry();
+    match service {
+        Some(service) => SERV_ENTRY.store(&service),
+        None => ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getservent_r(
+    result_buf: *mut servent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut servent,
+) -> c_int {
+    SERVICES
+        .lock()
+        .next_reentrant(result_buf, buf, buflen, result)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getservbyname(name: *const c_char, proto: *const c_char) -> *mut servent {
+    local_service(by_name(name, proto))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getservbyname_r(
+    name: *const c_char,
+    proto: *const c_char,
+    result_buf: *mut servent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut servent,
+) -> c_int {
+    let service = by_name(name, proto);
+    reentrant(service.as_ref(), 0, result_buf, buf, buflen, result)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getservbyport(port: c_int, proto: *const c_char) -> *mut servent {
+    local_service(by_port(port, proto))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getservbyport_r(
+    port: c_int,
+    proto: *const c_char,
+    result_buf: *mut servent,
+    buf: *mut c_char,
+    buflen: size_t,
+    result: *mut *mut servent,
+) -> c_int {
+    let service = by_port(port, proto);
+    reentrant(service.as_ref(), 0, result_buf, buf, buflen, result)
+}
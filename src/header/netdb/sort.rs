@@ -0,0 +1,174 @@
+//! Destination address selection from RFC 6724, which orders the addresses of a host so that
+//! the ones most likely to work from this host come first.
+//!
+//! The source address the system would use for each destination is found by connecting a UDP
+//! socket to it, which sends nothing. Of the rules, those about deprecated and home addresses
+//! and native transport are left out, as there is no portable way to know about them.
+
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::{
+    header::{
+        netinet_in::{in6addr_loopback, sockaddr_in6, IPPROTO_UDP},
+        sys_socket::{
+            constants::{SOCK_CLOEXEC, SOCK_DGRAM},
+            sockaddr, socklen_t,
+        },
+    },
+    platform::{types::*, Pal, PalSocket, Sys},
+};
+
+use super::address::Address;
+
+/// The default policy table: prefix, prefix length, precedence and label
+const POLICY: [([u8; 16], usize, u8, u8); 9] = [
+    ([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], 128, 50, 0),
+    (
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0, 0, 0, 0],
+        96,
+        35,
+        4,
+    ),
+    ([0; 16], 96, 1, 3),
+    (
+        [0x20, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        32,
+        5,
+        5,
+    ),
+    (
+        [0x20, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        16,
+        30,
+        2,
+    ),
+    (
+        [0x3f, 0xfe, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        16,
+        1,
+        12,
+    ),
+    (
+        [0xfe, 0xc0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        10,
+        1,
+        11,
+    ),
+    (
+        [0xfc, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        7,
+        3,
+        13,
+    ),
+    ([0; 16], 0, 40, 1),
+];
+
+/// How many leading bits `a` and `b` have in common
+fn common_prefix(a: &[u8; 16], b: &[u8; 16]) -> usize {
+    let mut bits = 0;
+    for (a, b) in a.iter().zip(b.iter()) {
+        let differ = a ^ b;
+        bits += differ.leading_zeros() as usize;
+        if differ != 0 {
+            break;
+        }
+    }
+    bits
+}
+
+/// The precedence and label of an address
+fn policy(addr: &[u8; 16]) -> (u8, u8) {
+    POLICY
+        .iter()
+        .find(|(prefix, len, _, _)| common_prefix(prefix, addr) >= *len)
+        .map(|&(_, _, precedence, label)| (precedence, label))
+        .unwrap()
+}
+
+/// The scope of an address, where smaller ones are more local: 2 for link-local and 14 for
+/// global addresses. IPv4 loopback and link-local addresses count as link-local.
+fn scope(addr: &[u8; 16]) -> u8 {
+    if addr[0] == 0xff {
+        addr[1] & 0xf
+    } else if (addr[0] == 0xfe && addr[1] & 0xc0 == 0x80) || *addr == in6addr_loopback.s6_addr {
+        2
+    } else if addr[0] == 0xfe && addr[1] & 0xc0 == 0xc0 {
+        5
+    } else if addr[..12] == [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff]
+        && (addr[12] == 127 || (addr[12] == 169 && addr[13] == 254))
+    {
+        2
+    } else {
+        14
+    }
+}
+
+/// The source address the system would send to `dest` from, as an IPv6 address
+fn source(dest: &Address) -> Option<[u8; 16]> {
+    unsafe {
+        let fd = Sys::socket(
+            dest.family(),
+            SOCK_DGRAM | SOCK_CLOEXEC,
+            IPPROTO_UDP as c_int,
+        );
+        if fd < 0 {
+            return None;
+        }
+        // Big enough for addresses of either family
+        let mut sa = sockaddr_in6::default();
+        let mut len = mem::size_of::<sockaddr_in6>() as socklen_t;
+        let source = if dest.with_sockaddr(0xffff, |dest, len| Sys::connect(fd, dest, len)) == 0
+            && Sys::getsockname(fd, &mut sa as *mut _ as *mut sockaddr, &mut len) == 0
+        {
+            Address::from_sockaddr(&sa as *const _ as *const sockaddr, len)
+                .map(|(source, _)| source.to_v6())
+        } else {
+            None
+        };
+        Sys::close(fd);
+        source
+    }
+}
+
+/// Orders `addrs` by the rules of RFC 6724, keeping the order they came in where the rules don't
+/// prefer any of them.
+pub fn sort(addrs: &mut Vec<Address>) {
+    if addrs.len() < 2 {
+        return;
+    }
+
+    // The rules in the order they are applied, each as a part of a key that the sort is by
+    let mut keyed: Vec<_> = mem::take(addrs)
+        .into_iter()
+        .map(|addr| {
+            let dest = addr.to_v6();
+            let (precedence, label) = policy(&dest);
+            let dest_scope = scope(&dest);
+            let key = match source(&addr) {
+                Some(source) => (
+                    // Rule 1: avoid unusable destinations
+                    true,
+                    // Rule 2: prefer matching scope
+                    scope(&source) == dest_scope,
+                    // Rule 5: prefer matching label
+                    policy(&source).1 == label,
+                    // Rule 6: prefer higher precedence
+                    precedence,
+                    // Rule 8: prefer smaller scope
+                    u8::MAX - dest_scope,
+                    // Rule 9: use longest matching prefix, between IPv6 addresses
+                    match addr {
+                        Address::V6(..) => common_prefix(&source, &dest),
+                        Address::V4(_) => 0,
+                    },
+                ),
+                None => (false, false, false, precedence, u8::MAX - dest_scope, 0),
+            };
+            (key, addr)
+        })
+        .collect();
+    // Rule 10: otherwise, leave the order unchanged, which the sort being stable does
+    keyed.sort_by(|(a, _), (b, _)| b.cmp(a));
+    addrs.extend(keyed.into_iter().map(|(_, addr)| addr));
+}
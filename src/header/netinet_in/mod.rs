@@ -12,6 +12,7 @@ pub struct in_addr {
 }
 
 #[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct in6_addr {
     pub s6_addr: [u8; 16],
 }
@@ -26,6 +27,7 @@ pub struct sockaddr_in {
 }
 
 #[repr(C)]
+#[derive(Debug, Default)]
 pub struct sockaddr_in6 {
     pub sin6_family: sa_family_t,
     pub sin6_port: in_port_t,
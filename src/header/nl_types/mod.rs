@@ -0,0 +1,213 @@
+//! nl_types implementation for Redox, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/nl_types.h.html
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    c_str::{CStr, CString},
+    fs::{File, Mapping},
+    header::{
+        errno::{EBADF, ENOENT, ENOMSG},
+        fcntl::{O_CLOEXEC, O_RDONLY},
+        locale::{self, LC_MESSAGES},
+        stdlib::getenv,
+    },
+    platform::{self, types::*},
+};
+
+pub type nl_catd = *mut c_void;
+pub type nl_item = c_int;
+
+pub const NL_SETD: c_int = 1;
+pub const NL_CAT_LOCALE: c_int = 1;
+
+/// Where catalogs are looked for without NLSPATH, which are the places glibc looks in
+const DEFAULT_NLSPATH: &[u8] = b"/usr/share/locale/%L/%N:/usr/share/locale/%L/LC_MESSAGES/%N:\
+/usr/share/locale/%l/%N:/usr/share/locale/%l/LC_MESSAGES/%N";
+
+const MAGIC: u32 = 0x960408de;
+
+/// A catalog as glibc's gencat writes it. A header of the magic number and the size and depth
+/// of a hash table is followed by the table, whose entries are the set, the message and the
+/// offset of its string, and then by the table again in the other byte order, and the strings.
+/// Set numbers are stored one higher than they are passed to catgets().
+struct Catalog {
+    data: Mapping,
+    /// Whether the catalog was written in the other byte order
+    swapped: bool,
+    size: usize,
+    depth: usize,
+}
+
+impl Catalog {
+    fn open(path: &[u8]) -> Option<Catalog> {
+        let path = CString::new(path).ok()?;
+        let data = File::open(&path, O_RDONLY | O_CLOEXEC).ok()?.map().ok()?;
+
+        let mut catalog = Catalog {
+            data,
+            swapped: false,
+            size: 0,
+            depth: 0,
+        };
+        match catalog.word(0)? {
+            MAGIC => (),
+            magic if magic.swap_bytes() == MAGIC => catalog.swapped = true,
+            _ => return None,
+        }
+        catalog.size = catalog.word(1)? as usize;
+        catalog.depth = catalog.word(2)? as usize;
+        if catalog.size == 0 || catalog.strings()? > catalog.data.len() {
+            return None;
+        }
+        Some(catalog)
+    }
+
+    /// The `i`th 32-bit word of the file
+    fn word(&self, i: usize) -> Option<u32> {
+        let bytes = self.data.get(i * 4..i * 4 + 4)?;
+        let word = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        Some(if self.swapped {
+            word.swap_bytes()
+        } else {
+            word
+        })
+    }
+
+    /// Where the strings start
+    fn strings(&self) -> Option<usize> {
+        let entries = self.size.checked_mul(self.depth)?.checked_mul(3)?;
+        entries.checked_mul(2)?.checked_add(3)?.checked_mul(4)
+    }
+
+    fn get(&self, set_id: c_int, msg_id: c_int) -> Option<&[u8]> {
+        if set_id < 0 || msg_id < 0 {
+            return None;
+        }
+        let set = set_id as u32 + 1;
+        let msg = msg_id as u32;
+
+        // Messages that hash to the same place are in the next plane
+        let mut entry = (set as usize * msg as usize) % self.size;
+        for _ in 0..self.depth {
+            let word = 3 + entry * 3;
+            if self.word(word)? == set && self.word(word + 1)? == msg {
+                let start = self.strings()? + self.word(word + 2)? as usize;
+                let string = self.data.get(start..)?;
+                // The string must end within the file
+                let len = string.iter().position(|&b| b == 0)?;
+                return Some(&string[..=len]);
+            }
+            entry += self.size;
+        }
+        None
+    }
+}
+
+/// Fills in a template from NLSPATH: %N is the name of the catalog, %L is the locale, and %l,
+/// %t and %c are the language, territory and codeset in it
+fn expand(template: &[u8], name: &[u8], locale: &[u8]) -> Vec<u8> {
+    // The part of the locale name after `start` up to one of `ends`
+    let field = |start: Option<u8>, ends: &[u8]| -> &[u8] {
+        let rest = match start {
+            Some(start) => match locale.iter().position(|&b| b == start) {
+                Some(i) => &locale[i + 1..],
+                None => return &[],
+            },
+            None => locale,
+        };
+        let end = rest
+            .iter()
+            .position(|b| ends.contains(b))
+            .unwrap_or(rest.len());
+        &rest[..end]
+    };
+
+    let mut path = Vec::new();
+    let mut bytes = template.iter();
+    while let Some(&b) = bytes.next() {
+        if b != b'%' {
+            path.push(b);
+            continue;
+        }
+        match bytes.next() {
+            Some(b'N') => path.extend_from_slice(name),
+            Some(b'L') => path.extend_from_slice(locale),
+            Some(b'l') => path.extend_from_slice(field(None, b"_.@")),
+            Some(b't') => path.extend_from_slice(field(Some(b'_'), b".@")),
+            Some(b'c') => path.extend_from_slice(field(Some(b'.'), b"@")),
+            Some(b'%') => path.push(b'%'),
+            Some(&other) => path.extend_from_slice(&[b'%', other]),
+            None => path.push(b'%'),
+        }
+    }
+    path
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn catclose(catd: nl_catd) -> c_int {
+    if catd.is_null() || catd == -1isize as nl_catd {
+        platform::errno = EBADF;
+        return -1;
+    }
+    drop(Box::from_raw(catd.cast::<Catalog>()));
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn catgets(
+    catd: nl_catd,
+    set_id: c_int,
+    msg_id: c_int,
+    s: *const c_char,
+) -> *mut c_char {
+    if catd.is_null() || catd == -1isize as nl_catd {
+        platform::errno = EBADF;
+        return s as *mut c_char;
+    }
+    match (*catd.cast::<Catalog>()).get(set_id, msg_id) {
+        Some(message) => message.as_ptr() as *mut c_char,
+        None => {
+            platform::errno = ENOMSG;
+            s as *mut c_char
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn catopen(name: *const c_char, oflag: c_int) -> nl_catd {
+    let name = CStr::from_ptr(name).to_bytes();
+
+    let catalog = if name.contains(&b'/') {
+        Catalog::open(name)
+    } else {
+        let nlspath = getenv(c_str!("NLSPATH").as_ptr());
+        let nlspath = if nlspath.is_null() {
+            DEFAULT_NLSPATH
+        } else {
+            CStr::from_ptr(nlspath).to_bytes()
+        };
+
+        // The locale is the one of LC_MESSAGES if asked for, as POSIX says, and LANG otherwise
+        let lang = getenv(c_str!("LANG").as_ptr());
+        let locale = if oflag == NL_CAT_LOCALE {
+            locale::current().name(LC_MESSAGES).to_bytes()
+        } else if lang.is_null() {
+            b"C"
+        } else {
+            CStr::from_ptr(lang).to_bytes()
+        };
+
+        nlspath
+            .split(|&b| b == b':')
+            .filter(|template| !template.is_empty())
+            .find_map(|template| Catalog::open(&expand(template, name, locale)))
+    };
+
+    match catalog {
+        Some(catalog) => Box::into_raw(Box::new(catalog)).cast(),
+        None => {
+            platform::errno = ENOENT;
+            -1isize as nl_catd
+        }
+    }
+}
@@ -8,7 +8,8 @@ use crate::{
         epoll_create1, epoll_ctl, epoll_data, epoll_event, epoll_wait, EPOLLERR, EPOLLHUP, EPOLLIN,
         EPOLLNVAL, EPOLLOUT, EPOLLPRI, EPOLL_CLOEXEC, EPOLL_CTL_ADD,
     },
-    platform::types::*,
+    platform::{types::*, Pal, Sys},
+    pthread,
 };
 
 pub const POLLIN: c_short = 0x001;
@@ -68,7 +69,13 @@ pub fn poll_epoll(fds: &mut [pollfd], timeout: c_int) -> c_int {
     }
 
     let mut events: [epoll_event; 32] = unsafe { mem::zeroed() };
-    let res = epoll_wait(*ep, events.as_mut_ptr(), events.len() as c_int, timeout);
+    // Only the wait is a cancellation point, and the epoll file is closed if it is cancelled
+    let res = pthread::cancellation_point_cleanup(
+        || epoll_wait(*ep, events.as_mut_ptr(), events.len() as c_int, timeout),
+        || {
+            Sys::close(*ep);
+        },
+    );
     if res < 0 {
         return -1;
     }
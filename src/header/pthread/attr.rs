@@ -0,0 +1,189 @@
+use super::*;
+
+use crate::header::errno::ENOTSUP;
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_destroy(attr: *mut pthread_attr_t) -> c_int {
+    ptr::drop_in_place(attr.cast::<RlctAttr>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getdetachstate(
+    attr: *const pthread_attr_t,
+    detachstate: *mut c_int,
+) -> c_int {
+    detachstate.write((*attr.cast::<RlctAttr>()).detachstate as c_int);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getguardsize(
+    attr: *const pthread_attr_t,
+    size: *mut size_t,
+) -> c_int {
+    size.write((*attr.cast::<RlctAttr>()).guardsize);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getinheritsched(
+    attr: *const pthread_attr_t,
+    inheritsched: *mut c_int,
+) -> c_int {
+    inheritsched.write((*attr.cast::<RlctAttr>()).inheritsched as c_int);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getschedparam(
+    attr: *const pthread_attr_t,
+    param: *mut sched_param,
+) -> c_int {
+    param.write((*attr.cast::<RlctAttr>()).param);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getschedpolicy(
+    attr: *const pthread_attr_t,
+    policy: *mut c_int,
+) -> c_int {
+    policy.write((*attr.cast::<RlctAttr>()).schedpolicy as c_int);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getscope(
+    attr: *const pthread_attr_t,
+    scope: *mut c_int,
+) -> c_int {
+    scope.write((*attr.cast::<RlctAttr>()).scope as c_int);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getstack(
+    attr: *const pthread_attr_t,
+    stackaddr: *mut *mut c_void,
+    stacksize: *mut size_t,
+) -> c_int {
+    let attr = &*attr.cast::<RlctAttr>();
+    stackaddr.write(attr.stack as *mut c_void);
+    stacksize.write(attr.stacksize);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getstacksize(
+    attr: *const pthread_attr_t,
+    stacksize: *mut size_t,
+) -> c_int {
+    stacksize.write((*attr.cast::<RlctAttr>()).stacksize);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_init(attr: *mut pthread_attr_t) -> c_int {
+    attr.cast::<RlctAttr>().write(RlctAttr::default());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setdetachstate(
+    attr: *mut pthread_attr_t,
+    detachstate: c_int,
+) -> c_int {
+    match detachstate {
+        PTHREAD_CREATE_JOINABLE | PTHREAD_CREATE_DETACHED => {
+            (*attr.cast::<RlctAttr>()).detachstate = detachstate as c_uchar;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setguardsize(
+    attr: *mut pthread_attr_t,
+    guardsize: size_t,
+) -> c_int {
+    (*attr.cast::<RlctAttr>()).guardsize = guardsize;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setinheritsched(
+    attr: *mut pthread_attr_t,
+    inheritsched: c_int,
+) -> c_int {
+    match inheritsched {
+        PTHREAD_INHERIT_SCHED | PTHREAD_EXPLICIT_SCHED => {
+            (*attr.cast::<RlctAttr>()).inheritsched = inheritsched as c_uchar;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setschedparam(
+    attr: *mut pthread_attr_t,
+    param: *const sched_param,
+) -> c_int {
+    (*attr.cast::<RlctAttr>()).param = param.read();
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setschedpolicy(
+    attr: *mut pthread_attr_t,
+    policy: c_int,
+) -> c_int {
+    match policy {
+        SCHED_OTHER | SCHED_FIFO | SCHED_RR => {
+            (*attr.cast::<RlctAttr>()).schedpolicy = policy as c_uchar;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setscope(attr: *mut pthread_attr_t, scope: c_int) -> c_int {
+    match scope {
+        PTHREAD_SCOPE_SYSTEM => {
+            (*attr.cast::<RlctAttr>()).scope = scope as c_uchar;
+            0
+        }
+        PTHREAD_SCOPE_PROCESS => ENOTSUP,
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setstack(
+    attr: *mut pthread_attr_t,
+    stackaddr: *mut c_void,
+    stacksize: size_t,
+) -> c_int {
+    if stacksize < PTHREAD_STACK_MIN as size_t {
+        return EINVAL;
+    }
+    let attr = &mut *attr.cast::<RlctAttr>();
+    attr.stack = stackaddr as size_t;
+    attr.stacksize = stacksize;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setstacksize(
+    attr: *mut pthread_attr_t,
+    stacksize: size_t,
+) -> c_int {
+    if stacksize < PTHREAD_STACK_MIN as size_t {
+        return EINVAL;
+    }
+    (*attr.cast::<RlctAttr>()).stacksize = stacksize;
+    0
+}
@@ -0,0 +1,84 @@
+use super::*;
+
+use crate::sync::{barrier::WaitResult, Barrier};
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct RlctBarrierAttr {
+    pshared: c_int,
+}
+
+impl Default for RlctBarrierAttr {
+    fn default() -> Self {
+        Self {
+            pshared: PTHREAD_PROCESS_PRIVATE,
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_barrier_destroy(barrier: *mut pthread_barrier_t) -> c_int {
+    ptr::drop_in_place(barrier.cast::<Barrier>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_barrier_init(
+    barrier: *mut pthread_barrier_t,
+    _attr: *const pthread_barrierattr_t,
+    count: c_uint,
+) -> c_int {
+    // Barriers only live in memory, so sharing them between processes needs nothing special
+    if count == 0 {
+        return EINVAL;
+    }
+    barrier.cast::<Barrier>().write(Barrier::new(count));
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_barrier_wait(barrier: *mut pthread_barrier_t) -> c_int {
+    match (*barrier.cast::<Barrier>()).wait() {
+        WaitResult::NotifiedAll => PTHREAD_BARRIER_SERIAL_THREAD,
+        WaitResult::Waited => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_barrierattr_destroy(attr: *mut pthread_barrierattr_t) -> c_int {
+    ptr::drop_in_place(attr.cast::<RlctBarrierAttr>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_barrierattr_getpshared(
+    attr: *const pthread_barrierattr_t,
+    pshared: *mut c_int,
+) -> c_int {
+    pshared.write((*attr.cast::<RlctBarrierAttr>()).pshared);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_barrierattr_init(attr: *mut pthread_barrierattr_t) -> c_int {
+    attr.cast::<RlctBarrierAttr>()
+        .write(RlctBarrierAttr::default());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_barrierattr_setpshared(
+    attr: *mut pthread_barrierattr_t,
+    pshared: c_int,
+) -> c_int {
+    match pshared {
+        PTHREAD_PROCESS_PRIVATE | PTHREAD_PROCESS_SHARED => {
+            (*attr.cast::<RlctBarrierAttr>()).pshared = pshared;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+const _: () = assert!(mem::size_of::<Barrier>() <= mem::size_of::<pthread_barrier_t>());
+const _: () = assert!(mem::size_of::<RlctBarrierAttr>() <= mem::size_of::<pthread_barrierattr_t>());
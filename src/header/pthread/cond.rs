@@ -0,0 +1,143 @@
+use super::*;
+
+use crate::{
+    header::time::{timespec, CLOCK_MONOTONIC, CLOCK_REALTIME},
+    sync::{Cond, RlctMutex},
+};
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct RlctCondAttr {
+    clock: clockid_t,
+    pshared: c_int,
+}
+
+impl Default for RlctCondAttr {
+    fn default() -> Self {
+        Self {
+            clock: CLOCK_REALTIME,
+            pshared: PTHREAD_PROCESS_PRIVATE,
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_cond_broadcast(cond: *mut pthread_cond_t) -> c_int {
+    (*cond.cast::<Cond>()).broadcast();
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_cond_destroy(cond: *mut pthread_cond_t) -> c_int {
+    ptr::drop_in_place(cond.cast::<Cond>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_cond_init(
+    cond: *mut pthread_cond_t,
+    attr: *const pthread_condattr_t,
+) -> c_int {
+    let attr = attr
+        .cast::<RlctCondAttr>()
+        .as_ref()
+        .copied()
+        .unwrap_or_default();
+    cond.cast::<Cond>().write(Cond::new(attr.clock));
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_cond_signal(cond: *mut pthread_cond_t) -> c_int {
+    (*cond.cast::<Cond>()).signal();
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_cond_timedwait(
+    cond: *mut pthread_cond_t,
+    mutex: *mut pthread_mutex_t,
+    abstime: *const timespec,
+) -> c_int {
+    let result = (*cond.cast::<Cond>()).timedwait(&*mutex.cast::<RlctMutex>(), &*abstime);
+    // Waiting is a cancellation point, acted upon once the mutex is held again
+    pthread::testcancel();
+    match result {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_cond_wait(
+    cond: *mut pthread_cond_t,
+    mutex: *mut pthread_mutex_t,
+) -> c_int {
+    let result = (*cond.cast::<Cond>()).wait(&*mutex.cast::<RlctMutex>());
+    pthread::testcancel();
+    match result {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_condattr_destroy(attr: *mut pthread_condattr_t) -> c_int {
+    ptr::drop_in_place(attr.cast::<RlctCondAttr>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_condattr_getclock(
+    attr: *const pthread_condattr_t,
+    clock: *mut clockid_t,
+) -> c_int {
+    clock.write((*attr.cast::<RlctCondAttr>()).clock);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_condattr_getpshared(
+    attr: *const pthread_condattr_t,
+    pshared: *mut c_int,
+) -> c_int {
+    pshared.write((*attr.cast::<RlctCondAttr>()).pshared);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_condattr_init(attr: *mut pthread_condattr_t) -> c_int {
+    attr.cast::<RlctCondAttr>().write(RlctCondAttr::default());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_condattr_setclock(
+    attr: *mut pthread_condattr_t,
+    clock: clockid_t,
+) -> c_int {
+    match clock {
+        CLOCK_REALTIME | CLOCK_MONOTONIC => {
+            (*attr.cast::<RlctCondAttr>()).clock = clock;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_condattr_setpshared(
+    attr: *mut pthread_condattr_t,
+    pshared: c_int,
+) -> c_int {
+    match pshared {
+        PTHREAD_PROCESS_PRIVATE | PTHREAD_PROCESS_SHARED => {
+            (*attr.cast::<RlctCondAttr>()).pshared = pshared;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+const _: () = assert!(mem::size_of::<Cond>() <= mem::size_of::<pthread_cond_t>());
+const _: () = assert!(mem::size_of::<RlctCondAttr>() <= mem::size_of::<pthread_condattr_t>());
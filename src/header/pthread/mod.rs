@@ -0,0 +1,291 @@
+//! pthread.h implementation for Redox, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/pthread.h.html
+
+use core::{
+    cell::Cell,
+    mem, ptr,
+    sync::atomic::{AtomicI32, Ordering},
+};
+
+use crate::{
+    header::{
+        errno::{EINVAL, ENOENT},
+        sched::*,
+        sys_types::*,
+    },
+    platform::types::*,
+    pthread::{self, Pthread, Retval, RlctAttr},
+};
+
+pub use self::{attr::*, barrier::*, cond::*, mutex::*, once::*, rwlock::*, spin::*, tls::*};
+
+mod attr;
+mod barrier;
+mod cond;
+mod mutex;
+mod once;
+mod rwlock;
+mod spin;
+pub(crate) mod tls;
+
+pub const PTHREAD_BARRIER_SERIAL_THREAD: c_int = -1;
+
+pub const PTHREAD_CANCEL_ENABLE: c_int = 0;
+pub const PTHREAD_CANCEL_DISABLE: c_int = 1;
+
+pub const PTHREAD_CANCEL_DEFERRED: c_int = 0;
+pub const PTHREAD_CANCEL_ASYNCHRONOUS: c_int = 1;
+
+// Defined in bits/pthread.h as a pointer, which cbindgen cannot express
+pub(crate) const PTHREAD_CANCELED: *mut c_void = usize::max_value() as *mut c_void;
+
+pub const PTHREAD_CREATE_JOINABLE: c_int = 0;
+pub const PTHREAD_CREATE_DETACHED: c_int = 1;
+
+pub const PTHREAD_INHERIT_SCHED: c_int = 0;
+pub const PTHREAD_EXPLICIT_SCHED: c_int = 1;
+
+pub const PTHREAD_MUTEX_NORMAL: c_int = 0;
+pub const PTHREAD_MUTEX_RECURSIVE: c_int = 1;
+pub const PTHREAD_MUTEX_ERRORCHECK: c_int = 2;
+pub const PTHREAD_MUTEX_DEFAULT: c_int = PTHREAD_MUTEX_NORMAL;
+
+pub const PTHREAD_MUTEX_STALLED: c_int = 0;
+pub const PTHREAD_MUTEX_ROBUST: c_int = 1;
+
+pub const PTHREAD_PRIO_NONE: c_int = 0;
+pub const PTHREAD_PRIO_INHERIT: c_int = 1;
+pub const PTHREAD_PRIO_PROTECT: c_int = 2;
+
+pub const PTHREAD_PROCESS_PRIVATE: c_int = 0;
+pub const PTHREAD_PROCESS_SHARED: c_int = 1;
+
+pub const PTHREAD_SCOPE_SYSTEM: c_int = 0;
+pub const PTHREAD_SCOPE_PROCESS: c_int = 1;
+
+pub const PTHREAD_DESTRUCTOR_ITERATIONS: c_int = 4;
+pub const PTHREAD_KEYS_MAX: c_int = 128;
+pub const PTHREAD_STACK_MIN: c_int = 16384;
+pub const PTHREAD_THREADS_MAX: c_int = -1;
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_cancel(thread: pthread_t) -> c_int {
+    match pthread::cancel(&*thread.cast()) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_create(
+    pthread: *mut pthread_t,
+    attr: *const pthread_attr_t,
+    start_routine: extern "C" fn(arg: *mut c_void) -> *mut c_void,
+    arg: *mut c_void,
+) -> c_int {
+    match pthread::create(attr.cast::<RlctAttr>().as_ref(), start_routine, arg) {
+        Ok(ptr) => {
+            pthread.write(ptr);
+            0
+        }
+        Err(pthread::Errno(code)) => code,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_detach(pthread: pthread_t) -> c_int {
+    match pthread::detach(&*pthread.cast()) {
+        Ok(()) => 0,
+        Err(pthread::Errno(errno)) => errno,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn pthread_equal(pthread1: pthread_t, pthread2: pthread_t) -> c_int {
+    core::ptr::eq(pthread1, pthread2).into()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_exit(value_ptr: *mut c_void) -> ! {
+    pthread::exit_current_thread(Retval(value_ptr))
+}
+
+// Thread scheduling is always system scope, so the concurrency level is merely a hint that is
+// remembered as POSIX asks.
+static CONCURRENCY: AtomicI32 = AtomicI32::new(0);
+
+#[no_mangle]
+pub extern "C" fn pthread_getconcurrency() -> c_int {
+    CONCURRENCY.load(Ordering::Relaxed)
+}
+
+#[no_mangle]
+pub extern "C" fn pthread_setconcurrency(concurrency: c_int) -> c_int {
+    if concurrency < 0 {
+        return EINVAL;
+    }
+    CONCURRENCY.store(concurrency, Ordering::Relaxed);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_getcpuclockid(
+    thread: pthread_t,
+    clock_out: *mut clockid_t,
+) -> c_int {
+    //TODO: Per-thread CPU clocks other than the calling thread's, and any at all on Redox
+    #[cfg(target_os = "linux")]
+    {
+        if ptr::eq(thread, pthread_self()) {
+            clock_out.write(crate::header::time::CLOCK_THREAD_CPUTIME_ID);
+            return 0;
+        }
+    }
+    ENOENT
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_getschedparam(
+    thread: pthread_t,
+    policy_out: *mut c_int,
+    param_out: *mut sched_param,
+) -> c_int {
+    match pthread::get_sched_param(&*thread.cast()) {
+        Ok((policy, param)) => {
+            policy_out.write(policy);
+            param_out.write(param);
+            0
+        }
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_join(thread: pthread_t, retval: *mut *mut c_void) -> c_int {
+    match pthread::join(&*thread.cast()) {
+        Ok(Retval(ret)) => {
+            if !retval.is_null() {
+                retval.write(ret);
+            }
+            0
+        }
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn pthread_self() -> pthread_t {
+    match pthread::current_thread() {
+        Some(thread) => thread as *const Pthread as pthread_t,
+        None => ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_setcancelstate(state: c_int, oldstate: *mut c_int) -> c_int {
+    let enabled = match state {
+        PTHREAD_CANCEL_ENABLE => true,
+        PTHREAD_CANCEL_DISABLE => false,
+        _ => return EINVAL,
+    };
+    let was_enabled = pthread::set_cancel_enabled(enabled);
+    if !oldstate.is_null() {
+        oldstate.write(if was_enabled {
+            PTHREAD_CANCEL_ENABLE
+        } else {
+            PTHREAD_CANCEL_DISABLE
+        });
+    }
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_setcanceltype(ty: c_int, oldty: *mut c_int) -> c_int {
+    let asynchronous = match ty {
+        PTHREAD_CANCEL_DEFERRED => false,
+        PTHREAD_CANCEL_ASYNCHRONOUS => true,
+        _ => return EINVAL,
+    };
+    let was_async = pthread::set_cancel_async(asynchronous);
+    if !oldty.is_null() {
+        oldty.write(if was_async {
+            PTHREAD_CANCEL_ASYNCHRONOUS
+        } else {
+            PTHREAD_CANCEL_DEFERRED
+        });
+    }
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_setschedparam(
+    thread: pthread_t,
+    policy: c_int,
+    param: *const sched_param,
+) -> c_int {
+    match pthread::set_sched_param(&*thread.cast(), policy, &*param) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_setschedprio(thread: pthread_t, prio: c_int) -> c_int {
+    match pthread::set_sched_priority(&*thread.cast(), prio) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn pthread_testcancel() {
+    pthread::testcancel();
+}
+
+// Cleanup handlers are pushed and popped by the macros in bits/pthread.h, which allocate the
+// entries on the stack of the caller.
+
+#[repr(C)]
+pub(crate) struct CleanupLinkedListEntry {
+    routine: extern "C" fn(arg: *mut c_void),
+    arg: *mut c_void,
+    prev: *const c_void,
+}
+
+impl CleanupLinkedListEntry {
+    pub(crate) const fn new(routine: extern "C" fn(arg: *mut c_void), arg: *mut c_void) -> Self {
+        Self {
+            routine,
+            arg,
+            prev: ptr::null(),
+        }
+    }
+}
+
+#[thread_local]
+static CLEANUP_LL_HEAD: Cell<*const CleanupLinkedListEntry> = Cell::new(ptr::null());
+
+#[no_mangle]
+pub unsafe extern "C" fn __relibc_internal_pthread_cleanup_push(new_entry: *mut c_void) {
+    let new_entry = &mut *new_entry.cast::<CleanupLinkedListEntry>();
+
+    new_entry.prev = CLEANUP_LL_HEAD.get().cast();
+    CLEANUP_LL_HEAD.set(new_entry);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn __relibc_internal_pthread_cleanup_pop(execute: c_int) {
+    let prev_head = &*CLEANUP_LL_HEAD.get();
+    CLEANUP_LL_HEAD.set(prev_head.prev.cast());
+
+    if execute != 0 {
+        (prev_head.routine)(prev_head.arg);
+    }
+}
+
+/// Run and pop every cleanup handler of the current thread, as it is exiting.
+pub(crate) unsafe fn run_cleanup_handlers() {
+    while let Some(entry) = CLEANUP_LL_HEAD.get().as_ref() {
+        CLEANUP_LL_HEAD.set(entry.prev.cast());
+        (entry.routine)(entry.arg);
+    }
+}
@@ -0,0 +1,222 @@
+use super::*;
+
+use crate::{
+    header::{
+        errno::{EBUSY, ENOTSUP},
+        time::timespec,
+    },
+    sync::{pthread_mutex::RlctMutexAttr, RlctMutex},
+};
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_consistent(mutex: *mut pthread_mutex_t) -> c_int {
+    match (*mutex.cast::<RlctMutex>()).make_consistent() {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_destroy(mutex: *mut pthread_mutex_t) -> c_int {
+    let mutex = &*mutex.cast::<RlctMutex>();
+    if mutex.is_locked() {
+        return EBUSY;
+    }
+    ptr::drop_in_place(mutex as *const RlctMutex as *mut RlctMutex);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_getprioceiling(
+    _mutex: *const pthread_mutex_t,
+    _prioceiling: *mut c_int,
+) -> c_int {
+    // Priority ceilings are only kept for mutexes using PTHREAD_PRIO_PROTECT, which is not
+    // supported
+    EINVAL
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_init(
+    mutex: *mut pthread_mutex_t,
+    attr: *const pthread_mutexattr_t,
+) -> c_int {
+    let attr = attr
+        .cast::<RlctMutexAttr>()
+        .as_ref()
+        .copied()
+        .unwrap_or_default();
+    mutex.cast::<RlctMutex>().write(RlctMutex::new(&attr));
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_lock(mutex: *mut pthread_mutex_t) -> c_int {
+    match (*mutex.cast::<RlctMutex>()).lock() {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_setprioceiling(
+    _mutex: *mut pthread_mutex_t,
+    _prioceiling: c_int,
+    _old_prioceiling: *mut c_int,
+) -> c_int {
+    EINVAL
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_timedlock(
+    mutex: *mut pthread_mutex_t,
+    abstime: *const timespec,
+) -> c_int {
+    match (*mutex.cast::<RlctMutex>()).lock_with_timeout(&*abstime) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_trylock(mutex: *mut pthread_mutex_t) -> c_int {
+    match (*mutex.cast::<RlctMutex>()).try_lock() {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutex_unlock(mutex: *mut pthread_mutex_t) -> c_int {
+    match (*mutex.cast::<RlctMutex>()).unlock() {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_destroy(attr: *mut pthread_mutexattr_t) -> c_int {
+    ptr::drop_in_place(attr.cast::<RlctMutexAttr>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_getprioceiling(
+    attr: *const pthread_mutexattr_t,
+    prioceiling: *mut c_int,
+) -> c_int {
+    prioceiling.write((*attr.cast::<RlctMutexAttr>()).prioceiling);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_getprotocol(
+    attr: *const pthread_mutexattr_t,
+    protocol: *mut c_int,
+) -> c_int {
+    protocol.write((*attr.cast::<RlctMutexAttr>()).protocol);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_getpshared(
+    attr: *const pthread_mutexattr_t,
+    pshared: *mut c_int,
+) -> c_int {
+    pshared.write((*attr.cast::<RlctMutexAttr>()).pshared);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_getrobust(
+    attr: *const pthread_mutexattr_t,
+    robust: *mut c_int,
+) -> c_int {
+    robust.write((*attr.cast::<RlctMutexAttr>()).robust);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_gettype(
+    attr: *const pthread_mutexattr_t,
+    ty: *mut c_int,
+) -> c_int {
+    ty.write((*attr.cast::<RlctMutexAttr>()).ty);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_init(attr: *mut pthread_mutexattr_t) -> c_int {
+    attr.cast::<RlctMutexAttr>().write(RlctMutexAttr::default());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_setprioceiling(
+    attr: *mut pthread_mutexattr_t,
+    prioceiling: c_int,
+) -> c_int {
+    (*attr.cast::<RlctMutexAttr>()).prioceiling = prioceiling;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_setprotocol(
+    attr: *mut pthread_mutexattr_t,
+    protocol: c_int,
+) -> c_int {
+    match protocol {
+        PTHREAD_PRIO_NONE => {
+            (*attr.cast::<RlctMutexAttr>()).protocol = protocol;
+            0
+        }
+        //TODO: Priority inheritance and protection
+        PTHREAD_PRIO_INHERIT | PTHREAD_PRIO_PROTECT => ENOTSUP,
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_setpshared(
+    attr: *mut pthread_mutexattr_t,
+    pshared: c_int,
+) -> c_int {
+    match pshared {
+        PTHREAD_PROCESS_PRIVATE | PTHREAD_PROCESS_SHARED => {
+            (*attr.cast::<RlctMutexAttr>()).pshared = pshared;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_setrobust(
+    attr: *mut pthread_mutexattr_t,
+    robust: c_int,
+) -> c_int {
+    match robust {
+        PTHREAD_MUTEX_STALLED | PTHREAD_MUTEX_ROBUST => {
+            (*attr.cast::<RlctMutexAttr>()).robust = robust;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_mutexattr_settype(
+    attr: *mut pthread_mutexattr_t,
+    ty: c_int,
+) -> c_int {
+    match ty {
+        PTHREAD_MUTEX_NORMAL | PTHREAD_MUTEX_RECURSIVE | PTHREAD_MUTEX_ERRORCHECK => {
+            (*attr.cast::<RlctMutexAttr>()).ty = ty;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+const _: () = assert!(mem::size_of::<RlctMutex>() <= mem::size_of::<pthread_mutex_t>());
+const _: () = assert!(mem::size_of::<RlctMutexAttr>() <= mem::size_of::<pthread_mutexattr_t>());
@@ -0,0 +1,18 @@
+use super::*;
+
+use crate::sync::Once;
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_once(
+    once: *mut pthread_once_t,
+    constructor: extern "C" fn(),
+) -> c_int {
+    let once = &*once.cast::<Once<()>>();
+
+    //TODO: Cancellation of the constructor should leave the once control reusable
+    once.call_once(|| constructor());
+
+    0
+}
+
+const _: () = assert!(mem::size_of::<Once<()>>() <= mem::size_of::<pthread_once_t>());
@@ -0,0 +1,131 @@
+use super::*;
+
+use crate::{header::time::timespec, sync::Rwlock};
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct RlctRwlockAttr {
+    pshared: c_int,
+}
+
+impl Default for RlctRwlockAttr {
+    fn default() -> Self {
+        Self {
+            pshared: PTHREAD_PROCESS_PRIVATE,
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_destroy(rwlock: *mut pthread_rwlock_t) -> c_int {
+    ptr::drop_in_place(rwlock.cast::<Rwlock>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_init(
+    rwlock: *mut pthread_rwlock_t,
+    _attr: *const pthread_rwlockattr_t,
+) -> c_int {
+    rwlock.cast::<Rwlock>().write(Rwlock::new());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_rdlock(rwlock: *mut pthread_rwlock_t) -> c_int {
+    match (*rwlock.cast::<Rwlock>()).acquire_read_lock(None) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_timedrdlock(
+    rwlock: *mut pthread_rwlock_t,
+    abstime: *const timespec,
+) -> c_int {
+    match (*rwlock.cast::<Rwlock>()).acquire_read_lock(Some(&*abstime)) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_timedwrlock(
+    rwlock: *mut pthread_rwlock_t,
+    abstime: *const timespec,
+) -> c_int {
+    match (*rwlock.cast::<Rwlock>()).acquire_write_lock(Some(&*abstime)) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_tryrdlock(rwlock: *mut pthread_rwlock_t) -> c_int {
+    match (*rwlock.cast::<Rwlock>()).try_acquire_read_lock() {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_trywrlock(rwlock: *mut pthread_rwlock_t) -> c_int {
+    match (*rwlock.cast::<Rwlock>()).try_acquire_write_lock() {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_unlock(rwlock: *mut pthread_rwlock_t) -> c_int {
+    (*rwlock.cast::<Rwlock>()).unlock();
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlock_wrlock(rwlock: *mut pthread_rwlock_t) -> c_int {
+    match (*rwlock.cast::<Rwlock>()).acquire_write_lock(None) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlockattr_destroy(attr: *mut pthread_rwlockattr_t) -> c_int {
+    ptr::drop_in_place(attr.cast::<RlctRwlockAttr>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlockattr_getpshared(
+    attr: *const pthread_rwlockattr_t,
+    pshared: *mut c_int,
+) -> c_int {
+    pshared.write((*attr.cast::<RlctRwlockAttr>()).pshared);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlockattr_init(attr: *mut pthread_rwlockattr_t) -> c_int {
+    attr.cast::<RlctRwlockAttr>()
+        .write(RlctRwlockAttr::default());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_rwlockattr_setpshared(
+    attr: *mut pthread_rwlockattr_t,
+    pshared: c_int,
+) -> c_int {
+    match pshared {
+        PTHREAD_PROCESS_PRIVATE | PTHREAD_PROCESS_SHARED => {
+            (*attr.cast::<RlctRwlockAttr>()).pshared = pshared;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+const _: () = assert!(mem::size_of::<Rwlock>() <= mem::size_of::<pthread_rwlock_t>());
+const _: () = assert!(mem::size_of::<RlctRwlockAttr>() <= mem::size_of::<pthread_rwlockattr_t>());
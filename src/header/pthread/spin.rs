@@ -0,0 +1,53 @@
+use super::*;
+
+use crate::header::errno::EBUSY;
+
+const UNLOCKED: c_int = 0;
+const LOCKED: c_int = 1;
+
+unsafe fn spinlock<'a>(lock: *mut pthread_spinlock_t) -> &'a AtomicI32 {
+    &*lock.cast::<AtomicI32>()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_spin_destroy(lock: *mut pthread_spinlock_t) -> c_int {
+    ptr::drop_in_place(lock.cast::<AtomicI32>());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_spin_init(
+    lock: *mut pthread_spinlock_t,
+    _pshared: c_int,
+) -> c_int {
+    // Spinlocks never sleep in the kernel, so process-shared ones need nothing special
+    lock.cast::<AtomicI32>().write(AtomicI32::new(UNLOCKED));
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_spin_lock(lock: *mut pthread_spinlock_t) -> c_int {
+    let lock = spinlock(lock);
+    loop {
+        match lock.compare_exchange_weak(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed) {
+            Ok(_) => return 0,
+            Err(_) => core::hint::spin_loop(),
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_spin_trylock(lock: *mut pthread_spinlock_t) -> c_int {
+    match spinlock(lock).compare_exchange(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed) {
+        Ok(_) => 0,
+        Err(_) => EBUSY,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_spin_unlock(lock: *mut pthread_spinlock_t) -> c_int {
+    spinlock(lock).store(UNLOCKED, Ordering::Release);
+    0
+}
+
+const _: () = assert!(mem::size_of::<AtomicI32>() <= mem::size_of::<pthread_spinlock_t>());
@@ -0,0 +1,130 @@
+use super::*;
+
+use crate::{header::errno::EAGAIN, sync::Mutex};
+
+/// A key slot. The sequence number is bumped whenever the slot is reused, so that values set
+/// under a deleted key are not visible through its successor.
+#[derive(Clone, Copy)]
+struct Key {
+    used: bool,
+    seq: usize,
+    destructor: Option<extern "C" fn(value: *mut c_void)>,
+}
+
+#[derive(Clone, Copy)]
+struct Record {
+    seq: usize,
+    value: *mut c_void,
+}
+
+const EMPTY_KEY: Key = Key {
+    used: false,
+    seq: 0,
+    destructor: None,
+};
+const EMPTY_RECORD: Record = Record {
+    seq: 0,
+    value: ptr::null_mut(),
+};
+
+static KEYS: Mutex<[Key; PTHREAD_KEYS_MAX as usize]> =
+    Mutex::new([EMPTY_KEY; PTHREAD_KEYS_MAX as usize]);
+
+#[thread_local]
+static mut VALUES: [Record; PTHREAD_KEYS_MAX as usize] = [EMPTY_RECORD; PTHREAD_KEYS_MAX as usize];
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_getspecific(key: pthread_key_t) -> *mut c_void {
+    let key = key as usize;
+    if key >= VALUES.len() {
+        return ptr::null_mut();
+    }
+
+    let seq = KEYS.lock()[key].seq;
+    let record = &VALUES[key];
+    if record.seq == seq {
+        record.value
+    } else {
+        ptr::null_mut()
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_key_create(
+    key_ptr: *mut pthread_key_t,
+    destructor: Option<extern "C" fn(value: *mut c_void)>,
+) -> c_int {
+    let mut keys = KEYS.lock();
+    let (index, key) = match keys.iter_mut().enumerate().find(|(_, key)| !key.used) {
+        Some(free) => free,
+        None => return EAGAIN,
+    };
+
+    key.used = true;
+    key.seq = key.seq.wrapping_add(1);
+    key.destructor = destructor;
+
+    key_ptr.write(index as pthread_key_t);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_key_delete(key: pthread_key_t) -> c_int {
+    let mut keys = KEYS.lock();
+    match keys.get_mut(key as usize) {
+        Some(key) if key.used => {
+            // Destructors are not run for a deleted key, as POSIX specifies
+            key.used = false;
+            key.destructor = None;
+            0
+        }
+        _ => EINVAL,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_setspecific(key: pthread_key_t, value: *const c_void) -> c_int {
+    let keys = KEYS.lock();
+    let seq = match keys.get(key as usize) {
+        Some(slot) if slot.used => slot.seq,
+        _ => return EINVAL,
+    };
+    drop(keys);
+
+    VALUES[key as usize] = Record {
+        seq,
+        value: value as *mut c_void,
+    };
+    0
+}
+
+/// Call the destructors of every non-null value of the current thread, repeating while they set
+/// new values, up to `PTHREAD_DESTRUCTOR_ITERATIONS` times.
+pub(crate) unsafe fn run_all_destructors() {
+    for _ in 0..PTHREAD_DESTRUCTOR_ITERATIONS {
+        let mut ran_any = false;
+
+        for index in 0..VALUES.len() {
+            let (seq, destructor) = {
+                let keys = KEYS.lock();
+                let key = &keys[index];
+                (key.seq, key.destructor.filter(|_| key.used))
+            };
+
+            let record = &mut VALUES[index];
+            if record.seq != seq || record.value.is_null() {
+                continue;
+            }
+            let value = mem::replace(&mut record.value, ptr::null_mut());
+
+            if let Some(destructor) = destructor {
+                destructor(value);
+                ran_any = true;
+            }
+        }
+
+        if !ran_any {
+            break;
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! sched.h implementation for Redox, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sched.h.html
+
+use crate::{
+    header::time::timespec,
+    platform::{types::*, Pal, Sys},
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct sched_param {
+    pub sched_priority: c_int,
+}
+
+pub const SCHED_OTHER: c_int = 0;
+pub const SCHED_FIFO: c_int = 1;
+pub const SCHED_RR: c_int = 2;
+
+#[no_mangle]
+pub extern "C" fn sched_get_priority_max(policy: c_int) -> c_int {
+    Sys::sched_get_priority_max(policy)
+}
+
+#[no_mangle]
+pub extern "C" fn sched_get_priority_min(policy: c_int) -> c_int {
+    Sys::sched_get_priority_min(policy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sched_getparam(pid: pid_t, param: *mut sched_param) -> c_int {
+    Sys::sched_getparam(pid, &mut *param)
+}
+
+#[no_mangle]
+pub extern "C" fn sched_getscheduler(pid: pid_t) -> c_int {
+    Sys::sched_getscheduler(pid)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sched_rr_get_interval(pid: pid_t, interval: *mut timespec) -> c_int {
+    Sys::sched_rr_get_interval(pid, &mut *interval)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sched_setparam(pid: pid_t, param: *const sched_param) -> c_int {
+    Sys::sched_setparam(pid, &*param)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sched_setscheduler(
+    pid: pid_t,
+    policy: c_int,
+    param: *const sched_param,
+) -> c_int {
+    Sys::sched_setscheduler(pid, policy, &*param)
+}
+
+#[no_mangle]
+pub extern "C" fn sched_yield() -> c_int {
+    Sys::sched_yield()
+}
@@ -1,4 +1,25 @@
-use crate::platform::types::*;
+//! semaphore.h implementation for relibc, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/semaphore.h.html
+
+use alloc::vec::Vec;
+use core::{mem, ptr, slice};
+
+use crate::{
+    header::{
+        errno::{EEXIST, EINVAL},
+        fcntl::{O_CREAT, O_EXCL, O_RDWR},
+        sys_mman::{self, shm_open, shm_unlink},
+        sys_stat::stat,
+        time::timespec,
+    },
+    platform::{self, types::*, Pal, Sys},
+    pthread::{self, Errno},
+    sync::{Mutex, Semaphore},
+};
+
+const SEM_VALUE_MAX: c_uint = c_int::max_value() as c_uint;
+
+/// How often to look again at a named semaphore that is still being created
+const CREATION_RETRIES: usize = 100;
 
 #[repr(C)]
 #[derive(Copy)]
@@ -12,50 +33,207 @@ impl Clone for sem_t {
         *self
     }
 }
-// #[no_mangle]
-pub extern "C" fn sem_init(sem: *mut sem_t, pshared: c_int, value: c_uint) -> c_int {
-    unimplemented!();
+
+/// A named semaphore that this process has mapped.
+struct Named {
+    dev: dev_t,
+    ino: ino_t,
+    sem: usize,
+    refs: usize,
 }
 
-// #[no_mangle]
-pub extern "C" fn sem_destroy(sem: *mut sem_t) -> c_int {
-    unimplemented!();
+/// Every name has only one mapping per process, however often it is opened.
+static NAMED: Mutex<Vec<Named>> = Mutex::new(Vec::new());
+
+unsafe fn get<'a>(sem: *mut sem_t) -> &'a Semaphore {
+    &*sem.cast::<Semaphore>()
 }
 
-/*
- *#[no_mangle]
- *pub extern "C" fn sem_open(name: *const c_char,
- *                    oflag: c_int, ...) -> *mut sem_t {
- *    unimplemented!();
- *}
- */
+fn to_c(result: Result<(), Errno>) -> c_int {
+    match result {
+        Ok(()) => 0,
+        Err(Errno(err)) => {
+            unsafe {
+                platform::errno = err;
+            }
+            -1
+        }
+    }
+}
 
-// #[no_mangle]
-pub extern "C" fn sem_close(sem: *mut sem_t) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn sem_init(sem: *mut sem_t, _pshared: c_int, value: c_uint) -> c_int {
+    if value > SEM_VALUE_MAX {
+        platform::errno = EINVAL;
+        return -1;
+    }
+    // The futex is not private to this process, so a semaphore in shared memory works across
+    // processes as it is
+    sem.cast::<Semaphore>()
+        .write(Semaphore::new(value as c_int));
+    0
 }
 
-// #[no_mangle]
-pub extern "C" fn sem_unlink(name: *const c_char) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub extern "C" fn sem_destroy(_sem: *mut sem_t) -> c_int {
+    0
+}
+
+/// The part of `sem_open` after the variadic arguments, which src/c/semaphore.c reads.
+#[no_mangle]
+pub unsafe extern "C" fn sys_sem_open(
+    name: *const c_char,
+    oflag: c_int,
+    mode: mode_t,
+    value: c_uint,
+) -> *mut sem_t {
+    if oflag & O_CREAT == O_CREAT && value > SEM_VALUE_MAX {
+        platform::errno = EINVAL;
+        return ptr::null_mut();
+    }
+
+    let mut named = NAMED.lock();
+
+    // Whoever creates the semaphore gives it its value right away, before anyone else maps it
+    let mut fd = -1;
+    if oflag & O_CREAT == O_CREAT {
+        fd = shm_open(name, O_RDWR | O_CREAT | O_EXCL, mode);
+        if fd >= 0 {
+            let mut init: sem_t = mem::zeroed();
+            ptr::write(
+                (&mut init as *mut sem_t).cast::<Semaphore>(),
+                Semaphore::new(value as c_int),
+            );
+            let bytes = slice::from_raw_parts(
+                (&init as *const sem_t).cast::<u8>(),
+                mem::size_of::<sem_t>(),
+            );
+            if Sys::write(fd, bytes) != bytes.len() as ssize_t {
+                let err = platform::errno;
+                shm_unlink(name);
+                Sys::close(fd);
+                platform::errno = err;
+                return ptr::null_mut();
+            }
+        } else if platform::errno != EEXIST || oflag & O_EXCL == O_EXCL {
+            return ptr::null_mut();
+        }
+    }
+    if fd < 0 {
+        fd = shm_open(name, O_RDWR, 0);
+        if fd < 0 {
+            return ptr::null_mut();
+        }
+    }
+
+    let sem = open_fd(&mut named, fd);
+    Sys::close(fd);
+    sem
 }
 
-// #[no_mangle]
-pub extern "C" fn sem_wait(sem: *mut sem_t) -> c_int {
-    unimplemented!();
+unsafe fn open_fd(named: &mut Vec<Named>, fd: c_int) -> *mut sem_t {
+    // Someone else might have created it a moment ago, and still be writing its value
+    let mut st: stat = mem::zeroed();
+    let mut retries = 0;
+    loop {
+        if Sys::fstat(fd, &mut st) < 0 {
+            return ptr::null_mut();
+        }
+        if st.st_size >= mem::size_of::<sem_t>() as off_t {
+            break;
+        }
+        if retries == CREATION_RETRIES {
+            platform::errno = EINVAL;
+            return ptr::null_mut();
+        }
+        retries += 1;
+        Sys::sched_yield();
+    }
+
+    if let Some(entry) = named
+        .iter_mut()
+        .find(|entry| entry.dev == st.st_dev && entry.ino == st.st_ino)
+    {
+        entry.refs += 1;
+        return entry.sem as *mut sem_t;
+    }
+
+    let sem = sys_mman::mmap(
+        ptr::null_mut(),
+        mem::size_of::<sem_t>(),
+        sys_mman::PROT_READ | sys_mman::PROT_WRITE,
+        sys_mman::MAP_SHARED,
+        fd,
+        0,
+    );
+    if sem as isize == -1 {
+        return ptr::null_mut();
+    }
+    named.push(Named {
+        dev: st.st_dev,
+        ino: st.st_ino,
+        sem: sem as usize,
+        refs: 1,
+    });
+    sem.cast()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sem_close(sem: *mut sem_t) -> c_int {
+    let mut named = NAMED.lock();
+    let i = match named.iter().position(|entry| entry.sem == sem as usize) {
+        Some(i) => i,
+        None => {
+            platform::errno = EINVAL;
+            return -1;
+        }
+    };
+    named[i].refs -= 1;
+    if named[i].refs == 0 {
+        named.remove(i);
+        sys_mman::munmap(sem.cast(), mem::size_of::<sem_t>());
+    }
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sem_unlink(name: *const c_char) -> c_int {
+    shm_unlink(name)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sem_wait(sem: *mut sem_t) -> c_int {
+    pthread::testcancel();
+    to_c(get(sem).wait_cancelable(None))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sem_timedwait(sem: *mut sem_t, abstime: *const timespec) -> c_int {
+    pthread::testcancel();
+    let sem = get(sem);
+    if sem.try_wait().is_ok() {
+        return 0;
+    }
+    // The time only matters when there is a need to wait
+    if (*abstime).tv_nsec < 0 || (*abstime).tv_nsec >= 1_000_000_000 {
+        platform::errno = EINVAL;
+        return -1;
+    }
+    to_c(sem.wait_cancelable(Some(&*abstime)))
 }
 
-// #[no_mangle]
-pub extern "C" fn sem_trywait(sem: *mut sem_t) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn sem_trywait(sem: *mut sem_t) -> c_int {
+    to_c(get(sem).try_wait())
 }
 
-// #[no_mangle]
-pub extern "C" fn sem_post(sem: *mut sem_t) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn sem_post(sem: *mut sem_t) -> c_int {
+    to_c(get(sem).post())
 }
 
-// #[no_mangle]
-pub extern "C" fn sem_getvalue(sem: *mut sem_t, sval: *mut c_int) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn sem_getvalue(sem: *mut sem_t, sval: *mut c_int) -> c_int {
+    *sval = get(sem).value();
+    0
 }
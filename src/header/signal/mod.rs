@@ -1,12 +1,17 @@
 //! signal implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/signal.h.html
 
-use core::mem;
+use core::{mem, ptr};
 
 use cbitset::BitSet;
 
 use crate::{
-    header::errno,
+    header::{
+        errno,
+        sys_types::{pthread_attr_t, pthread_t},
+        time::timespec,
+    },
     platform::{self, types::*, PalSignal, Sys},
+    pthread,
 };
 
 pub use self::sys::*;
@@ -24,11 +29,22 @@ type SigSet = BitSet<[c_ulong; 1]>;
 pub const SIG_DFL: usize = 0;
 pub const SIG_IGN: usize = 1;
 pub const SIG_ERR: isize = -1;
+pub const SIG_HOLD: usize = 2;
 
 pub const SIG_BLOCK: c_int = 0;
 pub const SIG_UNBLOCK: c_int = 1;
 pub const SIG_SETMASK: c_int = 2;
 
+pub const SIGEV_SIGNAL: c_int = 0;
+pub const SIGEV_NONE: c_int = 1;
+pub const SIGEV_THREAD: c_int = 2;
+
+pub const SI_USER: c_int = 0;
+pub const SI_QUEUE: c_int = -1;
+pub const SI_TIMER: c_int = -2;
+pub const SI_MESGQ: c_int = -3;
+pub const SI_ASYNCIO: c_int = -4;
+
 #[repr(C)]
 #[derive(Clone, Debug)]
 pub struct sigaction {
@@ -50,6 +66,78 @@ pub type sigset_t = c_ulong;
 
 pub type stack_t = sigaltstack;
 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union sigval {
+    pub sival_int: c_int,
+    pub sival_ptr: *mut c_void,
+}
+
+/// How a timer or other asynchronous event is reported. The padding keeps the structure at the
+/// 64 bytes other C libraries use.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct sigevent {
+    pub sigev_value: sigval,
+    pub sigev_signo: c_int,
+    pub sigev_notify: c_int,
+    pub sigev_notify_function: Option<extern "C" fn(sigval)>,
+    pub sigev_notify_attributes: *mut pthread_attr_t,
+    __relibc_internal_pad: [c_char; 32],
+}
+
+/// Information about a signal, laid out like the Linux kernel's. Which of the fields after
+/// `si_code` are valid depends on the signal, and `<bits/signal.h>` gives them their POSIX names.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct siginfo_t {
+    pub si_signo: c_int,
+    pub si_errno: c_int,
+    pub si_code: c_int,
+    pub __si_fields: __si_fields,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union __si_fields {
+    __pad: [c_int; 28],
+    pub __kill: __si_kill,
+    pub __sigchld: __si_sigchld,
+    pub __sigfault: __si_sigfault,
+    pub __sigpoll: __si_sigpoll,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct __si_kill {
+    pub si_pid: pid_t,
+    pub si_uid: uid_t,
+    pub si_value: sigval,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct __si_sigchld {
+    pub si_pid: pid_t,
+    pub si_uid: uid_t,
+    pub si_status: c_int,
+    pub si_utime: clock_t,
+    pub si_stime: clock_t,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct __si_sigfault {
+    pub si_addr: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct __si_sigpoll {
+    pub si_band: c_long,
+    pub si_fd: c_int,
+}
+
 #[no_mangle]
 pub extern "C" fn kill(pid: pid_t, sig: c_int) -> c_int {
     Sys::kill(pid, sig)
@@ -60,6 +148,14 @@ pub extern "C" fn killpg(pgrp: pid_t, sig: c_int) -> c_int {
     Sys::killpg(pgrp, sig)
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn pthread_kill(thread: pthread_t, sig: c_int) -> c_int {
+    match pthread::kill(&*thread.cast(), sig) {
+        Ok(()) => 0,
+        Err(pthread::Errno(error)) => error,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn pthread_sigmask(
     how: c_int,
@@ -155,19 +251,38 @@ pub extern "C" fn sigfillset(set: *mut sigset_t) -> c_int {
     0
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn sighold(sig: c_int) -> c_int {
-    unimplemented!();
+    let mut set = sigset_t::default();
+    if sigaddset(&mut set, sig) < 0 {
+        return -1;
+    }
+    sigprocmask(SIG_BLOCK, &set, ptr::null_mut())
 }
 
-// #[no_mangle]
-pub extern "C" fn sigignore(sig: c_int) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn sigignore(sig: c_int) -> c_int {
+    let sa = sigaction {
+        sa_handler: mem::transmute(SIG_IGN),
+        sa_flags: 0,
+        sa_restorer: None,
+        sa_mask: sigset_t::default(),
+    };
+    sigaction(sig, &sa, ptr::null_mut())
 }
 
-// #[no_mangle]
-pub extern "C" fn siginterrupt(sig: c_int, flag: c_int) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn siginterrupt(sig: c_int, flag: c_int) -> c_int {
+    let mut sa: sigaction = mem::zeroed();
+    if sigaction(sig, ptr::null(), &mut sa) < 0 {
+        return -1;
+    }
+    if flag != 0 {
+        sa.sa_flags &= !(SA_RESTART as c_ulong);
+    } else {
+        sa.sa_flags |= SA_RESTART as c_ulong;
+    }
+    sigaction(sig, &sa, ptr::null_mut())
 }
 
 #[no_mangle]
@@ -211,14 +326,18 @@ pub extern "C" fn signal(
     unsafe { old_sa.assume_init() }.sa_handler
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn sigpause(sig: c_int) -> c_int {
-    unimplemented!();
+    let mut set = sigset_t::default();
+    if sigprocmask(SIG_BLOCK, ptr::null(), &mut set) < 0 || sigdelset(&mut set, sig) < 0 {
+        return -1;
+    }
+    sigsuspend(&set)
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn sigpending(set: *mut sigset_t) -> c_int {
-    unimplemented!();
+    Sys::sigpending(set)
 }
 
 #[no_mangle]
@@ -226,24 +345,90 @@ pub extern "C" fn sigprocmask(how: c_int, set: *const sigset_t, oset: *mut sigse
     Sys::sigprocmask(how, set, oset)
 }
 
-// #[no_mangle]
+#[no_mangle]
+pub extern "C" fn sigqueue(pid: pid_t, sig: c_int, value: sigval) -> c_int {
+    Sys::sigqueue(pid, sig, value)
+}
+
+#[no_mangle]
 pub extern "C" fn sigrelse(sig: c_int) -> c_int {
-    unimplemented!();
+    let mut set = sigset_t::default();
+    if sigaddset(&mut set, sig) < 0 {
+        return -1;
+    }
+    sigprocmask(SIG_UNBLOCK, &set, ptr::null_mut())
 }
 
-// #[no_mangle]
-pub extern "C" fn sigset(sig: c_int, func: fn(c_int)) -> fn(c_int) {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn sigset(
+    sig: c_int,
+    func: Option<extern "C" fn(c_int)>,
+) -> Option<extern "C" fn(c_int)> {
+    let sig_err = mem::transmute(SIG_ERR);
+    let sig_hold = mem::transmute(SIG_HOLD);
+
+    let mut set = sigset_t::default();
+    if sigaddset(&mut set, sig) < 0 {
+        return sig_err;
+    }
+
+    // SIG_HOLD blocks the signal and leaves its disposition alone, anything else unblocks it
+    let mut old_sa: sigaction = mem::zeroed();
+    let mut old_set = sigset_t::default();
+    let ok = if func == sig_hold {
+        sigaction(sig, ptr::null(), &mut old_sa) == 0
+            && sigprocmask(SIG_BLOCK, &set, &mut old_set) == 0
+    } else {
+        let sa = sigaction {
+            sa_handler: func,
+            sa_flags: 0,
+            sa_restorer: None,
+            sa_mask: sigset_t::default(),
+        };
+        sigaction(sig, &sa, &mut old_sa) == 0 && sigprocmask(SIG_UNBLOCK, &set, &mut old_set) == 0
+    };
+    if !ok {
+        return sig_err;
+    }
+
+    if sigismember(&old_set, sig) == 1 {
+        sig_hold
+    } else {
+        old_sa.sa_handler
+    }
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn sigsuspend(sigmask: *const sigset_t) -> c_int {
-    unimplemented!();
+    pthread::cancellation_point(|| Sys::sigsuspend(sigmask))
 }
 
-// #[no_mangle]
-pub extern "C" fn sigwait(set: *const sigset_t, sig: *mut c_int) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub extern "C" fn sigtimedwait(
+    set: *const sigset_t,
+    info: *mut siginfo_t,
+    timeout: *const timespec,
+) -> c_int {
+    pthread::cancellation_point(|| Sys::sigtimedwait(set, info, timeout))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sigwait(set: *const sigset_t, sig: *mut c_int) -> c_int {
+    loop {
+        let signo = sigtimedwait(set, ptr::null_mut(), ptr::null());
+        if signo >= 0 {
+            *sig = signo;
+            return 0;
+        }
+        if platform::errno != errno::EINTR {
+            return platform::errno;
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn sigwaitinfo(set: *const sigset_t, info: *mut siginfo_t) -> c_int {
+    sigtimedwait(set, info, ptr::null())
 }
 
 pub const _signal_strings: [&str; 32] = [
@@ -0,0 +1,638 @@
+//! spawn.h implementation for relibc, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/spawn.h.html
+
+use alloc::vec::Vec;
+use core::{convert::Infallible, mem, ptr, slice};
+
+use crate::{
+    c_str::{CStr, CString},
+    header::{
+        errno::{EBADF, EINTR, EINVAL, ENAMETOOLONG, ENOENT},
+        fcntl::{FD_CLOEXEC, F_DUPFD, F_GETFD, F_SETFD, O_CLOEXEC},
+        limits::PATH_MAX,
+        sched::sched_param,
+        signal::{sigaction, sigismember, sigset_t, NSIG, SIG_BLOCK, SIG_IGN, SIG_SETMASK},
+        stdlib::getenv,
+        sys_mman,
+        unistd::PATH_SEPARATOR,
+    },
+    platform::{self, types::*, Pal, PalSignal, Sys},
+};
+
+#[cfg(target_os = "redox")]
+mod redox;
+
+pub const POSIX_SPAWN_RESETIDS: c_short = 0x01;
+pub const POSIX_SPAWN_SETPGROUP: c_short = 0x02;
+pub const POSIX_SPAWN_SETSIGDEF: c_short = 0x04;
+pub const POSIX_SPAWN_SETSIGMASK: c_short = 0x08;
+pub const POSIX_SPAWN_SETSCHEDPARAM: c_short = 0x10;
+pub const POSIX_SPAWN_SETSCHEDULER: c_short = 0x20;
+pub const POSIX_SPAWN_SETSID: c_short = 0x80;
+
+const POSIX_SPAWN_ALL: c_short = POSIX_SPAWN_RESETIDS
+    | POSIX_SPAWN_SETPGROUP
+    | POSIX_SPAWN_SETSIGDEF
+    | POSIX_SPAWN_SETSIGMASK
+    | POSIX_SPAWN_SETSCHEDPARAM
+    | POSIX_SPAWN_SETSCHEDULER
+    | POSIX_SPAWN_SETSID;
+
+/// The child only needs its own stack until it executes the program.
+const STACK_SIZE: usize = 64 * 1024;
+
+/// Where posix_spawnp looks for programs if there is no PATH, which is what glibc has
+#[cfg(target_os = "linux")]
+const DEFAULT_PATH: &[u8] = b"/bin:/usr/bin";
+#[cfg(target_os = "redox")]
+const DEFAULT_PATH: &[u8] = b"/bin;/usr/bin";
+
+#[repr(C)]
+pub struct posix_spawnattr_t {
+    flags: c_short,
+    pgroup: pid_t,
+    sigdefault: sigset_t,
+    sigmask: sigset_t,
+    schedpolicy: c_int,
+    schedparam: sched_param,
+}
+
+#[repr(C)]
+pub struct posix_spawn_file_actions_t {
+    // Can't be a normal Vec<T> because then the struct size won't be known
+    // from C.
+    ptr: *mut c_void,
+    length: size_t,
+    capacity: size_t,
+}
+
+enum FileAction {
+    Open {
+        fd: c_int,
+        path: CString,
+        oflag: c_int,
+        mode: mode_t,
+    },
+    Close(c_int),
+    Dup2 {
+        fd: c_int,
+        newfd: c_int,
+    },
+    Chdir(CString),
+    Fchdir(c_int),
+}
+
+impl posix_spawn_file_actions_t {
+    unsafe fn actions(&self) -> &[FileAction] {
+        slice::from_raw_parts(self.ptr.cast(), self.length)
+    }
+
+    unsafe fn push(&mut self, action: FileAction) -> c_int {
+        let mut actions = Vec::from_raw_parts(self.ptr.cast(), self.length, self.capacity);
+        actions.push(action);
+        self.set(actions);
+        0
+    }
+
+    fn set(&mut self, actions: Vec<FileAction>) {
+        let mut actions = mem::ManuallyDrop::new(actions);
+        self.ptr = actions.as_mut_ptr().cast();
+        self.length = actions.len();
+        self.capacity = actions.capacity();
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawn_file_actions_addchdir_np(
+    file_actions: *mut posix_spawn_file_actions_t,
+    path: *const c_char,
+) -> c_int {
+    (*file_actions).push(FileAction::Chdir(CStr::from_ptr(path).to_owned()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawn_file_actions_addclose(
+    file_actions: *mut posix_spawn_file_actions_t,
+    fildes: c_int,
+) -> c_int {
+    if fildes < 0 {
+        return EBADF;
+    }
+    (*file_actions).push(FileAction::Close(fildes))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawn_file_actions_adddup2(
+    file_actions: *mut posix_spawn_file_actions_t,
+    fildes: c_int,
+    newfildes: c_int,
+) -> c_int {
+    if fildes < 0 || newfildes < 0 {
+        return EBADF;
+    }
+    (*file_actions).push(FileAction::Dup2 {
+        fd: fildes,
+        newfd: newfildes,
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawn_file_actions_addfchdir_np(
+    file_actions: *mut posix_spawn_file_actions_t,
+    fildes: c_int,
+) -> c_int {
+    if fildes < 0 {
+        return EBADF;
+    }
+    (*file_actions).push(FileAction::Fchdir(fildes))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawn_file_actions_addopen(
+    file_actions: *mut posix_spawn_file_actions_t,
+    fildes: c_int,
+    path: *const c_char,
+    oflag: c_int,
+    mode: mode_t,
+) -> c_int {
+    if fildes < 0 {
+        return EBADF;
+    }
+    (*file_actions).push(FileAction::Open {
+        fd: fildes,
+        path: CStr::from_ptr(path).to_owned(),
+        oflag,
+        mode,
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawn_file_actions_destroy(
+    file_actions: *mut posix_spawn_file_actions_t,
+) -> c_int {
+    let file_actions = &mut *file_actions;
+    drop(Vec::from_raw_parts(
+        file_actions.ptr.cast::<FileAction>(),
+        file_actions.length,
+        file_actions.capacity,
+    ));
+    file_actions.set(Vec::new());
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawn_file_actions_init(
+    file_actions: *mut posix_spawn_file_actions_t,
+) -> c_int {
+    (*file_actions).set(Vec::new());
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn posix_spawnattr_destroy(_attr: *mut posix_spawnattr_t) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_getflags(
+    attr: *const posix_spawnattr_t,
+    flags: *mut c_short,
+) -> c_int {
+    *flags = (*attr).flags;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_getpgroup(
+    attr: *const posix_spawnattr_t,
+    pgroup: *mut pid_t,
+) -> c_int {
+    *pgroup = (*attr).pgroup;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_getschedparam(
+    attr: *const posix_spawnattr_t,
+    schedparam: *mut sched_param,
+) -> c_int {
+    *schedparam = (*attr).schedparam;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_getschedpolicy(
+    attr: *const posix_spawnattr_t,
+    schedpolicy: *mut c_int,
+) -> c_int {
+    *schedpolicy = (*attr).schedpolicy;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_getsigdefault(
+    attr: *const posix_spawnattr_t,
+    sigdefault: *mut sigset_t,
+) -> c_int {
+    *sigdefault = (*attr).sigdefault;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_getsigmask(
+    attr: *const posix_spawnattr_t,
+    sigmask: *mut sigset_t,
+) -> c_int {
+    *sigmask = (*attr).sigmask;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_init(attr: *mut posix_spawnattr_t) -> c_int {
+    attr.write(posix_spawnattr_t {
+        flags: 0,
+        pgroup: 0,
+        sigdefault: 0,
+        sigmask: 0,
+        schedpolicy: 0,
+        schedparam: sched_param::default(),
+    });
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_setflags(
+    attr: *mut posix_spawnattr_t,
+    flags: c_short,
+) -> c_int {
+    if flags & !POSIX_SPAWN_ALL != 0 {
+        return EINVAL;
+    }
+    (*attr).flags = flags;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_setpgroup(
+    attr: *mut posix_spawnattr_t,
+    pgroup: pid_t,
+) -> c_int {
+    (*attr).pgroup = pgroup;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_setschedparam(
+    attr: *mut posix_spawnattr_t,
+    schedparam: *const sched_param,
+) -> c_int {
+    (*attr).schedparam = *schedparam;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_setschedpolicy(
+    attr: *mut posix_spawnattr_t,
+    schedpolicy: c_int,
+) -> c_int {
+    (*attr).schedpolicy = schedpolicy;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_setsigdefault(
+    attr: *mut posix_spawnattr_t,
+    sigdefault: *const sigset_t,
+) -> c_int {
+    (*attr).sigdefault = *sigdefault;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnattr_setsigmask(
+    attr: *mut posix_spawnattr_t,
+    sigmask: *const sigset_t,
+) -> c_int {
+    (*attr).sigmask = *sigmask;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawn(
+    pid: *mut pid_t,
+    path: *const c_char,
+    file_actions: *const posix_spawn_file_actions_t,
+    attrp: *const posix_spawnattr_t,
+    argv: *const *mut c_char,
+    envp: *const *mut c_char,
+) -> c_int {
+    spawn(pid, path, false, file_actions, attrp, argv, envp)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn posix_spawnp(
+    pid: *mut pid_t,
+    file: *const c_char,
+    file_actions: *const posix_spawn_file_actions_t,
+    attrp: *const posix_spawnattr_t,
+    argv: *const *mut c_char,
+    envp: *const *mut c_char,
+) -> c_int {
+    spawn(pid, file, true, file_actions, attrp, argv, envp)
+}
+
+/// Everything the child needs to turn itself into the new process. On Linux it runs in the
+/// memory of the parent, so it must not allocate, nor leave anything behind but its errors.
+struct Child<'a> {
+    path: &'a CStr,
+    search: bool,
+    actions: &'a [FileAction],
+    attr: Option<&'a posix_spawnattr_t>,
+    argv: *const *mut c_char,
+    envp: *const *mut c_char,
+    /// The signal mask of the caller, who blocks every signal while the child starts up
+    mask: sigset_t,
+    /// The end of a pipe to report errors through, which closes when the program executes
+    pipe: c_int,
+}
+
+unsafe fn spawn(
+    pid: *mut pid_t,
+    path: *const c_char,
+    search: bool,
+    file_actions: *const posix_spawn_file_actions_t,
+    attrp: *const posix_spawnattr_t,
+    argv: *const *mut c_char,
+    envp: *const *mut c_char,
+) -> c_int {
+    let mut child = Child {
+        path: CStr::from_ptr(path),
+        search,
+        actions: file_actions
+            .as_ref()
+            .map_or(&[], |file_actions| file_actions.actions()),
+        attr: attrp.as_ref(),
+        argv,
+        envp,
+        mask: 0,
+        pipe: -1,
+    };
+
+    // A signal handler of the parent must not run in the child before it is reset
+    let all: sigset_t = !0;
+    Sys::sigprocmask(SIG_BLOCK, &all, &mut child.mask);
+
+    #[cfg(target_os = "redox")]
+    let result = redox::spawn(&child).unwrap_or_else(|| clone_child(&mut child));
+    #[cfg(not(target_os = "redox"))]
+    let result = clone_child(&mut child);
+
+    Sys::sigprocmask(SIG_SETMASK, &child.mask, ptr::null_mut());
+
+    match result {
+        Ok(child_pid) => {
+            if let Some(pid) = pid.as_mut() {
+                *pid = child_pid;
+            }
+            0
+        }
+        Err(err) => err,
+    }
+}
+
+/// Runs the child on a stack of its own in a clone of the caller, and waits until the program
+/// executes or the child fails.
+unsafe fn clone_child(child: &mut Child) -> Result<pid_t, c_int> {
+    let mut fds = [0; 2];
+    check(Sys::pipe2(&mut fds, O_CLOEXEC))?;
+
+    let stack_base = sys_mman::mmap(
+        ptr::null_mut(),
+        STACK_SIZE,
+        sys_mman::PROT_READ | sys_mman::PROT_WRITE,
+        sys_mman::MAP_PRIVATE | sys_mman::MAP_ANONYMOUS,
+        -1,
+        0,
+    );
+    if stack_base as isize == -1 {
+        let err = platform::errno;
+        Sys::close(fds[0]);
+        Sys::close(fds[1]);
+        return Err(err);
+    }
+    child.pipe = fds[1];
+
+    let mut stack = (stack_base as usize + STACK_SIZE) as *mut usize;
+    {
+        let mut push = |value: usize| {
+            stack = stack.offset(-1);
+            *stack = value;
+        };
+        push(child as *mut Child as usize);
+        push(child_main as usize);
+    }
+    let child_pid = Sys::vfork_clone(stack);
+    let clone_errno = platform::errno;
+
+    sys_mman::munmap(stack_base, STACK_SIZE);
+    Sys::close(fds[1]);
+
+    let result = if child_pid < 0 {
+        Err(clone_errno)
+    } else {
+        // Either the child reports an error, or the pipe closes without a word when the program
+        // executes
+        let mut err = [0; mem::size_of::<c_int>()];
+        let count = loop {
+            let count = Sys::read(fds[0], &mut err);
+            if count >= 0 || platform::errno != EINTR {
+                break count;
+            }
+        };
+        if count == err.len() as ssize_t {
+            let mut status = 0;
+            Sys::waitpid(child_pid, &mut status, 0);
+            Err(c_int::from_ne_bytes(err))
+        } else {
+            Ok(child_pid)
+        }
+    };
+    Sys::close(fds[0]);
+    result
+}
+
+extern "C" fn child_main(arg: *mut c_void) -> c_int {
+    let child = unsafe { &*arg.cast::<Child>() };
+    let mut pipe = child.pipe;
+    let err = match unsafe { child.run(&mut pipe) } {
+        Ok(()) => unsafe { child.exec() },
+        Err(err) => err,
+    };
+    Sys::write(pipe, &err.to_ne_bytes());
+    127
+}
+
+/// Turns the -1 of a failed call into the error number it left behind.
+fn check(result: c_int) -> Result<c_int, c_int> {
+    if result < 0 {
+        Err(unsafe { platform::errno })
+    } else {
+        Ok(result)
+    }
+}
+
+/// Gets the pipe out of the way of a file action about to take over its descriptor.
+fn move_pipe(pipe: &mut c_int, fd: c_int) -> Result<(), c_int> {
+    if *pipe == fd {
+        let moved = check(Sys::fcntl(fd, F_DUPFD, 0))?;
+        check(Sys::fcntl(moved, F_SETFD, FD_CLOEXEC))?;
+        Sys::close(fd);
+        *pipe = moved;
+    }
+    Ok(())
+}
+
+impl<'a> Child<'a> {
+    /// The attributes, if they have the flag set
+    fn attr_if(&self, flag: c_short) -> Option<&'a posix_spawnattr_t> {
+        self.attr.filter(|attr| attr.flags & flag == flag)
+    }
+
+    /// Applies the attributes and file actions.
+    unsafe fn run(&self, pipe: &mut c_int) -> Result<(), c_int> {
+        // Handlers are left to the parent, whose memory they would otherwise write to
+        for sig in 1..=NSIG as c_int {
+            let mut action: sigaction = mem::zeroed();
+            if Sys::sigaction(sig, None, Some(&mut action)) < 0 {
+                continue;
+            }
+            let handled = action
+                .sa_handler
+                .map_or(false, |handler| handler as usize != SIG_IGN);
+            let reset = self
+                .attr_if(POSIX_SPAWN_SETSIGDEF)
+                .map_or(false, |attr| sigismember(&attr.sigdefault, sig) == 1);
+            if handled || reset {
+                action.sa_handler = None;
+                Sys::sigaction(sig, Some(&action), None);
+            }
+        }
+
+        if self.attr_if(POSIX_SPAWN_SETSID).is_some() {
+            check(Sys::setsid())?;
+        }
+        if let Some(attr) = self.attr_if(POSIX_SPAWN_SETPGROUP) {
+            check(Sys::setpgid(0, attr.pgroup))?;
+        }
+        if let Some(attr) = self.attr_if(POSIX_SPAWN_SETSCHEDULER) {
+            check(Sys::sched_setscheduler(
+                0,
+                attr.schedpolicy,
+                &attr.schedparam,
+            ))?;
+        } else if let Some(attr) = self.attr_if(POSIX_SPAWN_SETSCHEDPARAM) {
+            check(Sys::sched_setparam(0, &attr.schedparam))?;
+        }
+        if self.attr_if(POSIX_SPAWN_RESETIDS).is_some() {
+            check(Sys::setregid(!0, Sys::getgid()))?;
+            check(Sys::setreuid(!0, Sys::getuid()))?;
+        }
+
+        for action in self.actions {
+            match *action {
+                FileAction::Open {
+                    fd,
+                    ref path,
+                    oflag,
+                    mode,
+                } => {
+                    move_pipe(pipe, fd)?;
+                    let opened = check(Sys::open(path, oflag, mode))?;
+                    if opened != fd {
+                        check(Sys::dup2(opened, fd))?;
+                        Sys::close(opened);
+                    }
+                }
+                FileAction::Close(fd) => {
+                    move_pipe(pipe, fd)?;
+                    // Closing what is not open is no reason to give up
+                    Sys::close(fd);
+                }
+                FileAction::Dup2 { fd, newfd } if fd == newfd => {
+                    // Rather than nothing, this lets the descriptor be inherited
+                    let fd_flags = check(Sys::fcntl(fd, F_GETFD, 0))?;
+                    check(Sys::fcntl(fd, F_SETFD, fd_flags & !FD_CLOEXEC))?;
+                }
+                FileAction::Dup2 { fd, newfd } => {
+                    move_pipe(pipe, newfd)?;
+                    check(Sys::dup2(fd, newfd))?;
+                }
+                FileAction::Chdir(ref path) => {
+                    check(Sys::chdir(path))?;
+                }
+                FileAction::Fchdir(fd) => {
+                    check(Sys::fchdir(fd))?;
+                }
+            }
+        }
+
+        let mask = self
+            .attr_if(POSIX_SPAWN_SETSIGMASK)
+            .map_or(self.mask, |attr| attr.sigmask);
+        Sys::sigprocmask(SIG_SETMASK, &mask, ptr::null_mut());
+        Ok(())
+    }
+
+    /// Executes the program, only returning the error if that fails.
+    unsafe fn exec(&self) -> c_int {
+        let result = self.find(|program| {
+            Sys::execve(program, self.argv, self.envp);
+            Err::<Infallible, _>(platform::errno)
+        });
+        match result {
+            Ok(never) => match never {},
+            Err(err) => err,
+        }
+    }
+
+    /// Tries the program, or with a search, each file of its name in the PATH until `f` succeeds
+    /// with one of them.
+    unsafe fn find<T>(&self, mut f: impl FnMut(&CStr) -> Result<T, c_int>) -> Result<T, c_int> {
+        let file = self.path.to_bytes();
+        if !self.search
+            || file.contains(&b'/')
+            || (cfg!(target_os = "redox") && file.contains(&b':'))
+        {
+            return f(self.path);
+        }
+
+        let mut error = ENOENT;
+        let path_env = getenv(c_str!("PATH").as_ptr());
+        let path_env = if path_env.is_null() {
+            DEFAULT_PATH
+        } else {
+            CStr::from_ptr(path_env).to_bytes()
+        };
+        let mut program = [0; PATH_MAX];
+        for dir in path_env.split(|&b| b == PATH_SEPARATOR) {
+            // An empty entry is the working directory
+            let dir: &[u8] = if dir.is_empty() { b"." } else { dir };
+            let len = dir.len() + 1 + file.len();
+            if len >= program.len() {
+                error = ENAMETOOLONG;
+                continue;
+            }
+            program[..dir.len()].copy_from_slice(dir);
+            program[dir.len()] = b'/';
+            program[dir.len() + 1..len].copy_from_slice(file);
+            program[len] = 0;
+
+            let program_c = CStr::from_bytes_with_nul_unchecked(&program[..=len]);
+            match f(program_c) {
+                Ok(found) => return Ok(found),
+                Err(ENOENT) => (),
+                Err(other) => error = other,
+            }
+        }
+        Err(error)
+    }
+}
@@ -0,0 +1,159 @@
+//! Redox loads the program into a new context from the outside, so the caller is not forked
+
+use alloc::string::String;
+use core::ptr;
+
+use super::{
+    check, Child, FileAction, POSIX_SPAWN_RESETIDS, POSIX_SPAWN_SETPGROUP,
+    POSIX_SPAWN_SETSCHEDPARAM, POSIX_SPAWN_SETSCHEDULER, POSIX_SPAWN_SETSID,
+    POSIX_SPAWN_SETSIGMASK,
+};
+use crate::{
+    c_str::{CStr, CString},
+    header::{
+        errno::{EINVAL, ENOENT},
+        fcntl::{FD_CLOEXEC, F_GETFD, F_SETFD},
+        signal::{sigset_t, SIG_BLOCK, SIG_SETMASK},
+    },
+    platform::{
+        sys::{
+            clone,
+            exec::{self, Image, Process},
+            path,
+        },
+        types::*,
+        Pal, PalSignal, Sys,
+    },
+};
+
+/// Starts the program, or leaves it to a fork of the caller by returning None. That is the case
+/// for setuid programs, which only execve can load through the `escalate:` scheme, for resetting
+/// the ids, and for fchdir actions, as where those lead only shows once the file actions have run.
+pub unsafe fn spawn(child: &Child) -> Option<Result<pid_t, c_int>> {
+    if child.attr_if(POSIX_SPAWN_RESETIDS).is_some()
+        && (Sys::geteuid() != Sys::getuid() || Sys::getegid() != Sys::getgid())
+    {
+        return None;
+    }
+
+    // Whether to fork has to be known before the file actions run, as some cannot run twice
+    let mut cwd = path::clone_cwd().map(String::from);
+    for action in child.actions {
+        match *action {
+            FileAction::Chdir(ref dir) => cwd = Some(resolve(cwd.as_deref(), dir).ok()?),
+            FileAction::Fchdir(_) => return None,
+            _ => (),
+        }
+    }
+    let program = child.find(|program| {
+        let program = CString::new(resolve(cwd.as_deref(), program)?).map_err(|_| EINVAL)?;
+        let image = Image::open(&program).map_err(|err| err.errno)?;
+        Ok((program, image.escalates()))
+    });
+    if let Ok((_, true)) = program {
+        return None;
+    }
+
+    Some(start(
+        child,
+        program.map(|(program, _)| program),
+        cwd.unwrap_or_default(),
+    ))
+}
+
+/// Applies the file actions to a copy of our file table, and loads the program into a new
+/// context with that file table and the attributes.
+unsafe fn start(
+    child: &Child,
+    program: Result<CString, c_int>,
+    cwd: String,
+) -> Result<pid_t, c_int> {
+    let mut applied = Ok(());
+    let filetable = clone::with_filetable_copy(&mut || {
+        applied =
+            apply(child.actions).and_then(|()| exec::close_on_exec().map_err(|err| err.errno));
+    })
+    .map_err(|err| err.errno)?;
+    applied?;
+
+    // Errors in the file actions come first, like they do with a fork
+    let program = program?;
+    let image = Image::open(&program).map_err(|err| err.errno)?;
+
+    // The new context starts out with the signal mask of the calling thread
+    let mask = child
+        .attr_if(POSIX_SPAWN_SETSIGMASK)
+        .map_or(child.mask, |attr| attr.sigmask);
+    Sys::sigprocmask(SIG_SETMASK, &mask, ptr::null_mut());
+    let process = Process::new(&filetable);
+    let all: sigset_t = !0;
+    Sys::sigprocmask(SIG_BLOCK, &all, ptr::null_mut());
+    let process = process.map_err(|err| err.errno)?;
+    let pid = process.pid() as pid_t;
+
+    // Every signal has its default action in the new program, which covers POSIX_SPAWN_SETSIGDEF
+    if child.attr_if(POSIX_SPAWN_SETSID).is_some() {
+        check(Sys::setpgid(pid, 0))?;
+    }
+    if let Some(attr) = child.attr_if(POSIX_SPAWN_SETPGROUP) {
+        check(Sys::setpgid(pid, attr.pgroup))?;
+    }
+    if let Some(attr) = child.attr_if(POSIX_SPAWN_SETSCHEDULER) {
+        check(Sys::sched_setscheduler(
+            pid,
+            attr.schedpolicy,
+            &attr.schedparam,
+        ))?;
+    } else if let Some(attr) = child.attr_if(POSIX_SPAWN_SETSCHEDPARAM) {
+        check(Sys::sched_setparam(pid, &attr.schedparam))?;
+    }
+
+    process
+        .start(image, &program, child.argv, child.envp, cwd.as_bytes())
+        .map_err(|err| err.errno)?;
+    Ok(pid)
+}
+
+/// Makes a path absolute against the working directory of the child, which is not ours.
+fn resolve(cwd: Option<&str>, path: &CStr) -> Result<String, c_int> {
+    let path = path.to_str().map_err(|_| EINVAL)?;
+    path::canonicalize_using_cwd(cwd, path).ok_or(ENOENT)
+}
+
+/// Runs the file actions, on the thread that has the file table of the child.
+unsafe fn apply(actions: &[FileAction]) -> Result<(), c_int> {
+    let mut cwd = path::clone_cwd().map(String::from);
+    for action in actions {
+        match *action {
+            FileAction::Open {
+                fd,
+                ref path,
+                oflag,
+                mode,
+            } => {
+                let path = CString::new(resolve(cwd.as_deref(), path)?).map_err(|_| EINVAL)?;
+                let opened = check(Sys::open(&path, oflag, mode))?;
+                if opened != fd {
+                    check(Sys::dup2(opened, fd))?;
+                    Sys::close(opened);
+                }
+            }
+            FileAction::Close(fd) => {
+                // Closing what is not open is no reason to give up
+                Sys::close(fd);
+            }
+            FileAction::Dup2 { fd, newfd } if fd == newfd => {
+                // Rather than nothing, this lets the descriptor be inherited
+                let fd_flags = check(Sys::fcntl(fd, F_GETFD, 0))?;
+                check(Sys::fcntl(fd, F_SETFD, fd_flags & !FD_CLOEXEC))?;
+            }
+            FileAction::Dup2 { fd, newfd } => {
+                check(Sys::dup2(fd, newfd))?;
+            }
+            FileAction::Chdir(ref dir) => cwd = Some(resolve(cwd.as_deref(), dir)?),
+            // spawn forks for these
+            FileAction::Fchdir(_) => unreachable!(),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::{
+    fs::File,
+    header::errno,
+    io::{self, Read, Write},
+    platform::{self, types::*, Pal, Sys},
+};
+
+/// What a `FILE` reads from and writes to, below its buffers
+pub trait Backend: Read + Write + Send {
+    /// Move the position like `lseek`, returning the new one, or -1 and setting errno
+    fn seek(&mut self, off: off_t, whence: c_int) -> off_t;
+
+    /// Release whatever the stream owns. Called once by `fclose`, after the final flush.
+    fn close(&mut self) -> c_int;
+
+    /// The file underneath the stream, if there is one
+    fn file(&mut self) -> Option<&mut File> {
+        None
+    }
+}
+
+impl Backend for File {
+    fn seek(&mut self, off: off_t, whence: c_int) -> off_t {
+        Sys::lseek(self.fd, off, whence)
+    }
+
+    fn close(&mut self) -> c_int {
+        // Reference files aren't closed on drop, so pretend to be a reference
+        self.reference = true;
+        Sys::close(self.fd)
+    }
+
+    fn file(&mut self) -> Option<&mut File> {
+        Some(self)
+    }
+}
+
+/// Sets errno and returns it as an I/O error
+pub fn fail<T>(err: c_int) -> io::Result<T> {
+    unsafe {
+        platform::errno = err;
+    }
+    Err(io::Error::from_raw_os_error(err))
+}
+
+/// Computes the target of a seek in a stream of `len` bytes at position `pos`, or returns -1
+/// and sets errno if it would end up outside `0..=max`
+pub fn seek_target(pos: usize, len: usize, max: usize, off: off_t, whence: c_int) -> off_t {
+    let base = match whence {
+        super::SEEK_SET => 0,
+        super::SEEK_CUR => pos as off_t,
+        super::SEEK_END => len as off_t,
+        _ => -1,
+    };
+    match base.checked_add(off) {
+        Some(target) if base >= 0 && target >= 0 && target as usize <= max => target,
+        _ => {
+            unsafe {
+                platform::errno = errno::EINVAL;
+            }
+            -1
+        }
+    }
+}
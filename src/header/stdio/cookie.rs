@@ -0,0 +1,90 @@
+use super::{
+    backend::{fail, Backend},
+    cookie_io_functions_t,
+};
+use crate::{
+    header::errno,
+    io::{self, Read, Write},
+    platform::{self, types::*},
+};
+
+/// The callbacks behind `fopencookie`
+pub struct Cookie {
+    cookie: *mut c_void,
+    // Taken by close, so nothing reaches the cookie after it is gone
+    io: Option<cookie_io_functions_t>,
+}
+
+// The callbacks are only ever called with the stream locked
+unsafe impl Send for Cookie {}
+
+impl Cookie {
+    pub fn new(cookie: *mut c_void, io: cookie_io_functions_t) -> Self {
+        Self {
+            cookie,
+            io: Some(io),
+        }
+    }
+}
+
+impl Read for Cookie {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let io = match self.io {
+            Some(io) => io,
+            None => return fail(errno::EBADF),
+        };
+        // Without a read function, the stream is always at its end
+        let read = match io.read {
+            Some(read) => read,
+            None => return Ok(0),
+        };
+        match unsafe { read(self.cookie, buf.as_mut_ptr() as *mut c_char, buf.len()) } {
+            n if n < 0 => Err(io::last_os_error()),
+            n => Ok(n as usize),
+        }
+    }
+}
+
+impl Write for Cookie {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let io = match self.io {
+            Some(io) => io,
+            None => return fail(errno::EBADF),
+        };
+        // Without a write function, output is thrown away
+        let write = match io.write {
+            Some(write) => write,
+            None => return Ok(buf.len()),
+        };
+        match unsafe { write(self.cookie, buf.as_ptr() as *const c_char, buf.len()) } {
+            n if n <= 0 => Err(io::last_os_error()),
+            n => Ok(n as usize),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for Cookie {
+    fn seek(&mut self, mut off: off_t, whence: c_int) -> off_t {
+        match self.io.and_then(|io| io.seek) {
+            Some(seek) if unsafe { seek(self.cookie, &mut off, whence) } != -1 => off,
+            Some(_) => -1,
+            None => {
+                unsafe {
+                    platform::errno = errno::ESPIPE;
+                }
+                -1
+            }
+        }
+    }
+
+    fn close(&mut self) -> c_int {
+        match self.io.take().and_then(|io| io.close) {
+            Some(close) => unsafe { close(self.cookie) },
+            None => 0,
+        }
+    }
+}
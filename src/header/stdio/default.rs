@@ -7,12 +7,10 @@ use alloc::{boxed::Box, vec::Vec};
 pub struct GlobalFile(UnsafeCell<FILE>);
 impl GlobalFile {
     fn new(file: c_int, flags: c_int) -> Self {
-        let file = File::new(file);
-        let writer = Box::new(LineWriter::new(unsafe { file.get_ref() }));
+        let writer = Box::new(LineWriter::new(File::new(file)));
         GlobalFile(UnsafeCell::new(FILE {
             lock: Mutex::new(()),
 
-            file,
             flags: constants::F_PERM | flags,
             read_buf: Buffer::Owned(vec![0; BUFSIZ as usize]),
             read_pos: 0,
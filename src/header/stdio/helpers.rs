@@ -1,6 +1,6 @@
 use alloc::boxed::Box;
 
-use super::{constants::*, Buffer, FILE};
+use super::{backend::Backend, constants::*, Buffer, FILE};
 use crate::{
     fs::File,
     header::{errno, fcntl::*, string::strchr},
@@ -37,8 +37,8 @@ pub unsafe fn parse_mode_flags(mode_str: *const c_char) -> i32 {
     flags
 }
 
-/// Open a file with the file descriptor `fd` in the mode `mode`
-pub unsafe fn _fdopen(fd: c_int, mode: *const c_char) -> Option<*mut FILE> {
+/// Parse the mode of a stream into the `F_*` flags it starts with
+pub unsafe fn parse_stream_flags(mode: *const c_char) -> Option<c_int> {
     if *mode != b'r' as i8 && *mode != b'w' as i8 && *mode != b'a' as i8 {
         platform::errno = errno::EINVAL;
         return None;
@@ -48,6 +48,16 @@ pub unsafe fn _fdopen(fd: c_int, mode: *const c_char) -> Option<*mut FILE> {
     if strchr(mode, b'+' as i32).is_null() {
         flags |= if *mode == b'r' as i8 { F_NOWR } else { F_NORD };
     }
+    if *mode == b'a' as i8 {
+        flags |= F_APP;
+    }
+
+    Some(flags)
+}
+
+/// Open a file with the file descriptor `fd` in the mode `mode`
+pub unsafe fn _fdopen(fd: c_int, mode: *const c_char) -> Option<*mut FILE> {
+    let flags = parse_stream_flags(mode)?;
 
     if !strchr(mode, b'e' as i32).is_null() {
         sys_fcntl(fd, F_SETFD, FD_CLOEXEC);
@@ -58,25 +68,25 @@ pub unsafe fn _fdopen(fd: c_int, mode: *const c_char) -> Option<*mut FILE> {
         if (f & O_APPEND) == 0 {
             sys_fcntl(fd, F_SETFL, f | O_APPEND);
         }
-        flags |= F_APP;
     }
 
-    let file = File::new(fd);
-    let writer = Box::new(BufWriter::new(file.get_ref()));
+    Some(_fnew(File::new(fd), flags))
+}
 
-    Some(Box::into_raw(Box::new(FILE {
+/// Create a stream on top of `backend`
+pub fn _fnew<B: Backend + 'static>(backend: B, flags: c_int) -> *mut FILE {
+    Box::into_raw(Box::new(FILE {
         lock: Mutex::new(()),
 
-        file,
         flags,
         read_buf: Buffer::Owned(vec![0; BUFSIZ as usize]),
         read_pos: 0,
         read_size: 0,
         unget: Vec::new(),
-        writer,
+        writer: Box::new(BufWriter::new(backend)),
 
         pid: None,
 
         orientation: 0,
-    })))
+    }))
 }
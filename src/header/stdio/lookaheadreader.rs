@@ -1,5 +1,13 @@
 use super::{fseek_locked, ftell_locked, FILE, SEEK_SET};
-use crate::core_io::Read;
+use crate::{
+    core_io::Read,
+    header::{
+        errno::EILSEQ,
+        stdlib::MB_LEN_MAX,
+        wchar::{mbrtowc, mbstate_t},
+    },
+    platform::{self, types::*},
+};
 struct LookAheadBuffer {
     buf: *const u8,
     pos: isize,
@@ -97,3 +105,85 @@ impl<'a> From<*const u8> for LookAheadReader<'a> {
         LookAheadReader(LookAheadReaderEnum::BUFFER(buff.into()))
     }
 }
+
+struct LookAheadWideBuffer {
+    buf: *const wchar_t,
+    pos: isize,
+    look_ahead: isize,
+}
+impl LookAheadWideBuffer {
+    fn look_ahead(&mut self) -> Result<Option<wchar_t>, i32> {
+        let wc = unsafe { *self.buf.offset(self.look_ahead) };
+        if wc == 0 {
+            Ok(None)
+        } else {
+            self.look_ahead += 1;
+            Ok(Some(wc))
+        }
+    }
+
+    fn commit(&mut self) {
+        self.pos = self.look_ahead;
+    }
+}
+
+enum WideLookAheadReaderEnum<'a> {
+    // Decodes the multibyte characters of a stream
+    FILE(LookAheadReader<'a>),
+    BUFFER(LookAheadWideBuffer),
+}
+
+/// The wide character version of `LookAheadReader`, for wscanf
+pub struct WideLookAheadReader<'a>(WideLookAheadReaderEnum<'a>);
+
+impl<'a> WideLookAheadReader<'a> {
+    pub fn lookahead1(&mut self) -> Result<Option<wchar_t>, i32> {
+        match &mut self.0 {
+            WideLookAheadReaderEnum::FILE(f) => {
+                let mut bytes = [0; MB_LEN_MAX as usize];
+                for len in 1..=bytes.len() {
+                    bytes[len - 1] = match f.lookahead1()? {
+                        Some(byte) => byte as c_char,
+                        None if len == 1 => return Ok(None),
+                        None => break,
+                    };
+                    let mut wc = 0;
+                    let mut state = mbstate_t::new();
+                    match unsafe { mbrtowc(&mut wc, bytes.as_ptr(), len, &mut state) } {
+                        // An incomplete character, so far
+                        n if n == usize::MAX - 1 => (),
+                        n if n == usize::MAX => break,
+                        _ => return Ok(Some(wc)),
+                    }
+                }
+                unsafe {
+                    platform::errno = EILSEQ;
+                }
+                Err(-1)
+            }
+            WideLookAheadReaderEnum::BUFFER(b) => b.look_ahead(),
+        }
+    }
+    pub fn commit(&mut self) {
+        match &mut self.0 {
+            WideLookAheadReaderEnum::FILE(f) => f.commit(),
+            WideLookAheadReaderEnum::BUFFER(b) => b.commit(),
+        }
+    }
+}
+
+impl<'a> From<&'a mut FILE> for WideLookAheadReader<'a> {
+    fn from(f: &'a mut FILE) -> WideLookAheadReader {
+        WideLookAheadReader(WideLookAheadReaderEnum::FILE(f.into()))
+    }
+}
+
+impl<'a> From<*const wchar_t> for WideLookAheadReader<'a> {
+    fn from(buff: *const wchar_t) -> WideLookAheadReader<'a> {
+        WideLookAheadReader(WideLookAheadReaderEnum::BUFFER(LookAheadWideBuffer {
+            buf: buff,
+            pos: 0,
+            look_ahead: 0,
+        }))
+    }
+}
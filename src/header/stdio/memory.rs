@@ -0,0 +1,301 @@
+use alloc::vec::Vec;
+use core::{cmp, ptr};
+
+use super::backend::{fail, seek_target, Backend};
+use crate::{
+    c_vec::CVec,
+    header::{
+        errno,
+        string::strnlen,
+        wchar::{mbrtowc, mbstate_t},
+    },
+    io::{self, Read, Write},
+    platform::{self, types::*},
+};
+
+/// The caller's buffer behind `fmemopen`, or one it allocated if it was given none
+pub struct FixedMemory {
+    buf: *mut u8,
+    size: usize,
+    len: usize,
+    pos: usize,
+    append: bool,
+    owned: bool,
+}
+
+// Nothing else touches the buffer through this stream
+unsafe impl Send for FixedMemory {}
+
+impl FixedMemory {
+    pub unsafe fn new(buf: *mut u8, size: usize, mode: u8) -> Option<Self> {
+        if size == 0 {
+            platform::errno = errno::EINVAL;
+            return None;
+        }
+
+        let owned = buf.is_null();
+        let buf = if owned {
+            let buf = platform::alloc(size) as *mut u8;
+            if buf.is_null() {
+                platform::errno = errno::ENOMEM;
+                return None;
+            }
+            ptr::write_bytes(buf, 0, size);
+            buf
+        } else {
+            buf
+        };
+
+        let len = match mode {
+            b'r' => size,
+            b'w' => {
+                *buf = 0;
+                0
+            }
+            _ => strnlen(buf as *const c_char, size),
+        };
+
+        Some(Self {
+            buf,
+            size,
+            len,
+            pos: if mode == b'a' { len } else { 0 },
+            append: mode == b'a',
+            owned,
+        })
+    }
+}
+
+impl Read for FixedMemory {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.buf.is_null() {
+            return fail(errno::EBADF);
+        }
+
+        let len = cmp::min(buf.len(), self.len.saturating_sub(self.pos));
+        unsafe {
+            ptr::copy_nonoverlapping(self.buf.add(self.pos), buf.as_mut_ptr(), len);
+        }
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+impl Write for FixedMemory {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buf.is_null() {
+            return fail(errno::EBADF);
+        }
+        if self.append {
+            self.pos = self.len;
+        }
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.pos == self.size {
+            return fail(errno::ENOSPC);
+        }
+
+        let len = cmp::min(buf.len(), self.size - self.pos);
+        unsafe {
+            ptr::copy_nonoverlapping(buf.as_ptr(), self.buf.add(self.pos), len);
+        }
+        self.pos += len;
+        if self.pos > self.len {
+            self.len = self.pos;
+            // The contents stay a string as long as there is room left for the terminator
+            if self.len < self.size {
+                unsafe {
+                    *self.buf.add(self.len) = 0;
+                }
+            }
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for FixedMemory {
+    fn seek(&mut self, off: off_t, whence: c_int) -> off_t {
+        let pos = seek_target(self.pos, self.len, self.size, off, whence);
+        if pos >= 0 {
+            self.pos = pos as usize;
+        }
+        pos
+    }
+
+    fn close(&mut self) -> c_int {
+        if self.owned {
+            unsafe {
+                platform::free(self.buf as *mut c_void);
+            }
+        }
+        self.buf = ptr::null_mut();
+        0
+    }
+}
+
+/// The heap buffer behind `open_memstream` and `open_wmemstream`, which grows as it is written to
+/// and is handed to the caller through `bufp` and `sizep` on every flush
+pub struct GrowingMemory<T> {
+    // Holds one element past the contents, so the contents are always terminated
+    buf: Option<CVec<T>>,
+    pos: usize,
+    bufp: *mut *mut T,
+    sizep: *mut size_t,
+    // The state of a character that was split between writes, for wide streams
+    state: mbstate_t,
+}
+
+// The caller must not touch `bufp` and `sizep` until the stream is flushed
+unsafe impl<T> Send for GrowingMemory<T> {}
+
+impl<T: Copy + Default> GrowingMemory<T> {
+    pub unsafe fn new(bufp: *mut *mut T, sizep: *mut size_t) -> Option<Self> {
+        if bufp.is_null() || sizep.is_null() {
+            platform::errno = errno::EINVAL;
+            return None;
+        }
+
+        let mut buf = CVec::new();
+        if buf.push(T::default()).is_err() {
+            platform::errno = errno::ENOMEM;
+            return None;
+        }
+
+        let mut stream = Self {
+            buf: Some(buf),
+            pos: 0,
+            bufp,
+            sizep,
+            state: mbstate_t::new(),
+        };
+        stream.sync();
+        Some(stream)
+    }
+
+    fn len(&self) -> usize {
+        self.buf.as_ref().map_or(0, |buf| buf.len() - 1)
+    }
+
+    /// Tells the caller where the buffer is and how much of it was written
+    fn sync(&mut self) {
+        let size = cmp::min(self.pos, self.len());
+        if let Some(buf) = &mut self.buf {
+            unsafe {
+                *self.bufp = buf.as_mut_ptr();
+                *self.sizep = size;
+            }
+        }
+    }
+
+    fn put(&mut self, items: &[T]) -> io::Result<usize> {
+        let pos = self.pos;
+        let buf = match &mut self.buf {
+            Some(buf) => buf,
+            None => return fail(errno::EBADF),
+        };
+        if items.is_empty() {
+            return Ok(0);
+        }
+        let end = match pos.checked_add(items.len()) {
+            Some(end) => end,
+            None => return fail(errno::EFBIG),
+        };
+
+        // Anything between the old end and the position was skipped by a seek, and reads as zero
+        if end >= buf.len() {
+            if buf.reserve(end + 1 - buf.len()).is_err() {
+                return fail(errno::ENOMEM);
+            }
+            while buf.len() <= end {
+                let _ = buf.push(T::default());
+            }
+        }
+        buf[pos..end].copy_from_slice(items);
+
+        self.pos = end;
+        self.sync();
+        Ok(items.len())
+    }
+}
+
+impl<T> Read for GrowingMemory<T> {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        fail(errno::EBADF)
+    }
+}
+
+impl Write for GrowingMemory<u8> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.put(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Write for GrowingMemory<wchar_t> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // The bytes are in the encoding of the current locale, as the stream encoded them
+        let mut chars = Vec::with_capacity(buf.len());
+        for byte in buf {
+            let mut wc = 0;
+            match unsafe {
+                mbrtowc(
+                    &mut wc,
+                    byte as *const u8 as *const c_char,
+                    1,
+                    &mut self.state,
+                )
+            } {
+                // An incomplete character, so far
+                n if n == usize::MAX - 1 => (),
+                n if n == usize::MAX => {
+                    self.state = mbstate_t::new();
+                    return fail(errno::EILSEQ);
+                }
+                _ => chars.push(wc),
+            }
+        }
+        self.put(&chars)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: Copy + Default> Backend for GrowingMemory<T>
+where
+    GrowingMemory<T>: Write,
+{
+    fn seek(&mut self, off: off_t, whence: c_int) -> off_t {
+        let pos = seek_target(
+            self.pos,
+            self.len(),
+            off_t::max_value() as usize,
+            off,
+            whence,
+        );
+        if pos >= 0 {
+            self.pos = pos as usize;
+            self.sync();
+        }
+        pos
+    }
+
+    fn close(&mut self) -> c_int {
+        self.sync();
+        // The buffer belongs to the caller from now on
+        if let Some(buf) = self.buf.take() {
+            buf.leak();
+        }
+        0
+    }
+}
@@ -17,7 +17,6 @@ use core::{
 use crate::{
     c_str::CStr,
     c_vec::CVec,
-    fs::File,
     header::{
         errno::{self, STR_ERROR},
         fcntl, stdlib,
@@ -38,11 +37,15 @@ mod default;
 pub use self::getdelim::*;
 mod getdelim;
 
+mod backend;
+mod cookie;
 mod ext;
 mod helpers;
-mod lookaheadreader;
-mod printf;
-mod scanf;
+pub(crate) mod lookaheadreader;
+mod memory;
+pub(crate) mod printf;
+pub(crate) mod scanf;
+use backend::Backend;
 use lookaheadreader::LookAheadReader;
 static mut TMPNAM_BUF: [c_char; L_tmpnam as usize + 1] = [0; L_tmpnam as usize + 1];
 
@@ -85,23 +88,44 @@ impl<W: core_io::Write> Pending for LineWriter<W> {
     }
 }
 
-pub trait Writer: Write + Pending {}
+pub trait Writer: Write + Pending {
+    /// The backend below the buffer, which reads go to directly
+    fn backend(&mut self) -> &mut dyn Backend;
+}
+
+impl<B: Backend> Writer for BufWriter<B> {
+    fn backend(&mut self) -> &mut dyn Backend {
+        self.get_mut()
+    }
+}
+impl<B: Backend> Writer for LineWriter<B> {
+    fn backend(&mut self) -> &mut dyn Backend {
+        self.get_mut()
+    }
+}
 
-impl<W: core_io::Write> Writer for BufWriter<W> {}
-impl<W: core_io::Write> Writer for LineWriter<W> {}
+/// The callbacks of a stream opened with `fopencookie`
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct cookie_io_functions_t {
+    pub read: Option<unsafe extern "C" fn(*mut c_void, *mut c_char, size_t) -> ssize_t>,
+    pub write: Option<unsafe extern "C" fn(*mut c_void, *const c_char, size_t) -> ssize_t>,
+    pub seek: Option<unsafe extern "C" fn(*mut c_void, *mut off_t, c_int) -> c_int>,
+    pub close: Option<unsafe extern "C" fn(*mut c_void) -> c_int>,
+}
 
 /// This struct gets exposed to the C API.
 pub struct FILE {
     lock: Mutex<()>,
 
-    file: File,
     // pub for stdio_ext
     pub(crate) flags: c_int,
     read_buf: Buffer<'static>,
     read_pos: usize,
     read_size: usize,
-    unget: Vec<u8>,
-    // pub for stdio_ext
+    // pub for ungetwc
+    pub(crate) unget: Vec<u8>,
+    // pub for stdio_ext, and owns the backend that reads go to
     pub(crate) writer: Box<dyn Writer + Send>,
 
     // Optional pid for use with popen/pclose
@@ -135,7 +159,7 @@ impl Read for FILE {
 impl BufRead for FILE {
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         if self.read_pos == self.read_size {
-            self.read_size = match self.file.read(&mut self.read_buf) {
+            self.read_size = match self.writer.backend().read(&mut self.read_buf) {
                 Ok(0) => {
                     self.flags |= F_EOF;
                     0
@@ -174,6 +198,17 @@ impl Write for FILE {
         }
     }
 }
+impl printf::WriteWide for FILE {
+    fn write_wide(&mut self, s: &[wchar_t]) -> io::Result<()> {
+        match unsafe { printf::encode(s) } {
+            Ok(bytes) => self.write_all(&bytes),
+            Err(err) => {
+                self.flags |= F_ERR;
+                Err(err)
+            }
+        }
+    }
+}
 impl WriteFmt for FILE {
     fn write_str(&mut self, s: &str) -> fmt::Result {
         self.write_all(s.as_bytes())
@@ -216,6 +251,13 @@ impl FILE {
             x => Err(x),
         }
     }
+
+    pub fn try_set_wide_orientation_unlocked(&mut self) -> core::result::Result<(), c_int> {
+        match self.try_set_orientation_unlocked(1) {
+            1..=i32::MAX => Ok(()),
+            x => Err(x),
+        }
+    }
 }
 
 pub struct LockGuard<'a>(&'a mut FILE);
@@ -266,14 +308,12 @@ pub unsafe extern "C" fn fclose(stream: *mut FILE) -> c_int {
     flockfile(stream);
 
     let mut r = stream.flush().is_err();
-    let close = Sys::close(*stream.file) < 0;
+    let close = stream.writer.backend().close() < 0;
     r = r || close;
 
     if stream.flags & constants::F_PERM == 0 {
         // Not one of stdin, stdout or stderr
-        let mut stream = Box::from_raw(stream);
-        // Reference files aren't closed on drop, so pretend to be a reference
-        stream.file.reference = true;
+        drop(Box::from_raw(stream));
     } else {
         funlockfile(stream);
     }
@@ -427,8 +467,14 @@ pub unsafe extern "C" fn fgets(
 /// Get the underlying file descriptor
 #[no_mangle]
 pub unsafe extern "C" fn fileno(stream: *mut FILE) -> c_int {
-    let stream = (*stream).lock();
-    *stream.file
+    let mut stream = (*stream).lock();
+    match stream.writer.backend().file() {
+        Some(file) => **file,
+        None => {
+            errno = errno::EBADF;
+            -1
+        }
+    }
 }
 
 /// Lock the file
@@ -439,6 +485,24 @@ pub unsafe extern "C" fn flockfile(file: *mut FILE) {
     (*file).lock.manual_lock();
 }
 
+/// Open a stream on the `size` bytes at `buf`, or on a buffer of its own if `buf` is null
+#[no_mangle]
+pub unsafe extern "C" fn fmemopen(
+    buf: *mut c_void,
+    size: size_t,
+    mode: *const c_char,
+) -> *mut FILE {
+    let flags = match helpers::parse_stream_flags(mode) {
+        Some(flags) => flags,
+        None => return ptr::null_mut(),
+    };
+
+    match memory::FixedMemory::new(buf as *mut u8, size, *mode as u8) {
+        Some(backend) => helpers::_fnew(backend, flags),
+        None => ptr::null_mut(),
+    }
+}
+
 /// Open the file in mode `mode`
 #[no_mangle]
 pub unsafe extern "C" fn fopen(filename: *const c_char, mode: *const c_char) -> *mut FILE {
@@ -473,6 +537,19 @@ pub unsafe extern "C" fn fopen(filename: *const c_char, mode: *const c_char) ->
     }
 }
 
+/// Open a stream that calls `io_funcs` with `cookie` to do its I/O
+#[no_mangle]
+pub unsafe extern "C" fn fopencookie(
+    cookie: *mut c_void,
+    mode: *const c_char,
+    io_funcs: cookie_io_functions_t,
+) -> *mut FILE {
+    match helpers::parse_stream_flags(mode) {
+        Some(flags) => helpers::_fnew(cookie::Cookie::new(cookie, io_funcs), flags),
+        None => ptr::null_mut(),
+    }
+}
+
 /// Insert a character into the stream
 #[no_mangle]
 pub unsafe extern "C" fn fputc(c: c_int, stream: *mut FILE) -> c_int {
@@ -539,13 +616,23 @@ pub unsafe extern "C" fn freopen(
     flockfile(stream);
 
     let _ = stream.flush();
+    // Streams without a file descriptor can't be reopened
+    let fd = match stream.writer.backend().file() {
+        Some(file) => **file,
+        None => {
+            errno = errno::EBADF;
+            funlockfile(stream);
+            fclose(stream);
+            return ptr::null_mut();
+        }
+    };
     if filename.is_null() {
         // Reopen stream in new mode
         if flags & fcntl::O_CLOEXEC > 0 {
-            fcntl::sys_fcntl(*stream.file, fcntl::F_SETFD, fcntl::FD_CLOEXEC);
+            fcntl::sys_fcntl(fd, fcntl::F_SETFD, fcntl::FD_CLOEXEC);
         }
         flags &= !(fcntl::O_CREAT | fcntl::O_EXCL | fcntl::O_CLOEXEC);
-        if fcntl::sys_fcntl(*stream.file, fcntl::F_SETFL, flags) < 0 {
+        if fcntl::sys_fcntl(fd, fcntl::F_SETFL, flags) < 0 {
             funlockfile(stream);
             fclose(stream);
             return ptr::null_mut();
@@ -558,10 +645,11 @@ pub unsafe extern "C" fn freopen(
             return ptr::null_mut();
         }
         let new = &mut *new; // Should be safe, new is not null
-        if *new.file == *stream.file {
-            new.file.fd = -1;
-        } else if Sys::dup2(*new.file, *stream.file) < 0
-            || fcntl::sys_fcntl(*stream.file, fcntl::F_SETFL, flags & fcntl::O_CLOEXEC) < 0
+        let new_file = new.writer.backend().file().unwrap(); // fopen always opens a file
+        if **new_file == fd {
+            new_file.fd = -1;
+        } else if Sys::dup2(**new_file, fd) < 0
+            || fcntl::sys_fcntl(fd, fcntl::F_SETFL, flags & fcntl::O_CLOEXEC) < 0
         {
             funlockfile(stream);
             fclose(new);
@@ -601,7 +689,7 @@ pub unsafe fn fseek_locked(stream: &mut FILE, mut off: off_t, whence: c_int) ->
         return -1;
     }
 
-    let err = Sys::lseek(*stream.file, off, whence);
+    let err = stream.writer.backend().seek(off, whence);
     if err < 0 {
         return err as c_int;
     }
@@ -632,7 +720,12 @@ pub unsafe extern "C" fn ftello(stream: *mut FILE) -> off_t {
     ftell_locked(&mut *stream)
 }
 pub unsafe extern "C" fn ftell_locked(stream: &mut FILE) -> off_t {
-    let pos = Sys::lseek(*stream.file, 0, SEEK_CUR);
+    // Buffered output would otherwise be missing from the position
+    if stream.flush().is_err() {
+        return -1;
+    }
+
+    let pos = stream.writer.backend().seek(0, SEEK_CUR);
     if pos < 0 {
         return -1;
     }
@@ -740,6 +833,25 @@ pub unsafe extern "C" fn getw(stream: *mut FILE) -> c_int {
     }
 }
 
+/// Open a stream that writes to a growing buffer, and stores the buffer and the size of what was
+/// written to `bufp` and `sizep` whenever it is flushed
+#[no_mangle]
+pub unsafe extern "C" fn open_memstream(bufp: *mut *mut c_char, sizep: *mut size_t) -> *mut FILE {
+    match memory::GrowingMemory::<u8>::new(bufp as *mut *mut u8, sizep) {
+        Some(backend) => helpers::_fnew(backend, F_NORD),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Like `open_memstream`, but the buffer holds wide characters
+#[no_mangle]
+pub unsafe extern "C" fn open_wmemstream(bufp: *mut *mut wchar_t, sizep: *mut size_t) -> *mut FILE {
+    match memory::GrowingMemory::<wchar_t>::new(bufp, sizep) {
+        Some(backend) => helpers::_fnew(backend, F_NORD),
+        None => ptr::null_mut(),
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn pclose(stream: *mut FILE) -> c_int {
     let pid = {
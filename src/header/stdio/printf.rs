@@ -4,10 +4,16 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
-use core::{char, cmp, f64, ffi::VaList, fmt, num::FpCategory, ops::Range, slice};
+use core::{cmp, f64, ffi::VaList, fmt, num::FpCategory, ops::Range, slice};
 
 use crate::{
-    header::errno::EILSEQ,
+    c_str::CStr,
+    header::{
+        errno::{EILSEQ, STR_ERROR},
+        locale,
+        stdlib::MB_LEN_MAX,
+        wchar::{mbrtowc, mbstate_t, wcrtomb},
+    },
     platform::{self, types::*},
 };
 
@@ -39,11 +45,13 @@ enum FmtKind {
     Scientific,
     Decimal,
     AnyNotation,
+    HexFloat,
 
     String,
     Char,
     Pointer,
     GetWritten,
+    Strerror,
 }
 #[derive(Clone, Copy, Debug)]
 enum Number {
@@ -101,7 +109,9 @@ impl VaArg {
         // automagically.
 
         match (fmtkind, intkind) {
-            (FmtKind::Percent, _) => panic!("Can't call arg_from on %"),
+            (FmtKind::Percent, _) | (FmtKind::Strerror, _) => {
+                panic!("Can't call arg_from on % or %m")
+            }
 
             (FmtKind::Char, IntKind::Long) | (FmtKind::Char, IntKind::LongLong) => {
                 VaArg::wint_t(ap.arg::<wint_t>())
@@ -132,9 +142,10 @@ impl VaArg {
                 VaArg::ssize_t(ap.arg::<ssize_t>())
             }
 
-            (FmtKind::AnyNotation, _) | (FmtKind::Decimal, _) | (FmtKind::Scientific, _) => {
-                VaArg::c_double(ap.arg::<c_double>())
-            }
+            (FmtKind::AnyNotation, _)
+            | (FmtKind::Decimal, _)
+            | (FmtKind::Scientific, _)
+            | (FmtKind::HexFloat, _) => VaArg::c_double(ap.arg::<c_double>()),
 
             (FmtKind::GetWritten, _) | (FmtKind::Pointer, _) | (FmtKind::String, _) => {
                 VaArg::pointer(ap.arg::<*const c_void>())
@@ -177,7 +188,9 @@ impl VaArg {
             VaArg::wint_t(i) => Untyped { wint_t: i },
         };
         match (fmtkind, intkind) {
-            (FmtKind::Percent, _) => panic!("Can't call transmute on %"),
+            (FmtKind::Percent, _) | (FmtKind::Strerror, _) => {
+                panic!("Can't call transmute on % or %m")
+            }
 
             (FmtKind::Char, IntKind::Long) | (FmtKind::Char, IntKind::LongLong) => {
                 VaArg::wint_t(untyped.wint_t)
@@ -208,9 +221,10 @@ impl VaArg {
                 VaArg::ssize_t(untyped.ssize_t)
             }
 
-            (FmtKind::AnyNotation, _) | (FmtKind::Decimal, _) | (FmtKind::Scientific, _) => {
-                VaArg::c_double(untyped.c_double)
-            }
+            (FmtKind::AnyNotation, _)
+            | (FmtKind::Decimal, _)
+            | (FmtKind::Scientific, _)
+            | (FmtKind::HexFloat, _) => VaArg::c_double(untyped.c_double),
 
             (FmtKind::GetWritten, _) | (FmtKind::Pointer, _) | (FmtKind::String, _) => {
                 VaArg::pointer(untyped.pointer)
@@ -279,9 +293,175 @@ static INF_STR_UPPER: &str = "INF";
 static NAN_STR_LOWER: &str = "nan";
 static NAN_STR_UPPER: &str = "NAN";
 
-unsafe fn pop_int_raw(format: &mut *const u8) -> Option<usize> {
+/// A character of a format string, which is a byte for printf and scanf and a wide character for
+/// their wide versions
+pub trait FormatChar: Copy + 'static {
+    /// Whether this is the wide version
+    const WIDE: bool;
+
+    /// The value of the character, which is the code point for a wide character
+    fn code(self) -> u32;
+
+    /// Writes text from the format that isn't part of a directive
+    fn write_text<W: Sink>(text: &[Self], w: &mut W) -> io::Result<()>;
+
+    /// The character if it is ASCII, as nothing else has a meaning in a directive
+    fn ascii(self) -> u8 {
+        match self.code() {
+            c @ 0..=0x7f => c as u8,
+            _ => 0xff,
+        }
+    }
+}
+
+impl FormatChar for u8 {
+    const WIDE: bool = false;
+
+    fn code(self) -> u32 {
+        self as u32
+    }
+
+    fn write_text<W: Sink>(text: &[Self], w: &mut W) -> io::Result<()> {
+        w.write_all(text)
+    }
+}
+
+impl FormatChar for wchar_t {
+    const WIDE: bool = true;
+
+    fn code(self) -> u32 {
+        self as u32
+    }
+
+    fn write_text<W: Sink>(text: &[Self], w: &mut W) -> io::Result<()> {
+        w.write_wide(text)
+    }
+}
+
+/// Converts wide characters to the multibyte encoding of the current locale
+pub unsafe fn encode(s: &[wchar_t]) -> io::Result<Vec<u8>> {
+    let mut state = mbstate_t::new();
+    let mut buf = [0 as c_char; MB_LEN_MAX as usize];
+    let mut bytes = Vec::with_capacity(s.len());
+    for &wc in s {
+        let len = wcrtomb(buf.as_mut_ptr(), wc, &mut state);
+        if len == usize::MAX {
+            return Err(io::last_os_error());
+        }
+        bytes.extend(buf[..len].iter().map(|&b| b as u8));
+    }
+    Ok(bytes)
+}
+
+/// Converts multibyte text in the encoding of the current locale to wide characters
+pub unsafe fn decode(s: &[u8]) -> io::Result<Vec<wchar_t>> {
+    let mut state = mbstate_t::new();
+    let mut chars = Vec::with_capacity(s.len());
+    let mut i = 0;
+    while i < s.len() {
+        let mut wc = 0;
+        match mbrtowc(
+            &mut wc,
+            s[i..].as_ptr() as *const c_char,
+            s.len() - i,
+            &mut state,
+        ) {
+            // Every piece of text ends on a character boundary, so this is never incomplete
+            len if len >= usize::MAX - 1 => {
+                platform::errno = EILSEQ;
+                return Err(io::last_os_error());
+            }
+            // A null character
+            0 => i += 1,
+            len => i += len,
+        }
+        chars.push(wc);
+    }
+    Ok(chars)
+}
+
+/// Something wprintf can write wide characters to
+pub trait WriteWide {
+    fn write_wide(&mut self, s: &[wchar_t]) -> io::Result<()>;
+}
+
+impl<'a, W: WriteWide + ?Sized> WriteWide for &'a mut W {
+    fn write_wide(&mut self, s: &[wchar_t]) -> io::Result<()> {
+        (**self).write_wide(s)
+    }
+}
+
+/// The output of the engine, which is written in bytes by printf and in wide characters by
+/// wprintf. Text of the other kind is converted with the locale's multibyte functions.
+pub trait Sink: Write {
+    /// Whether widths, precisions and the count of what was written are in wide characters
+    const WIDE: bool;
+
+    fn write_wide(&mut self, s: &[wchar_t]) -> io::Result<()>;
+
+    /// How long multibyte text is in the units of the output
+    fn len(text: &[u8]) -> usize;
+
+    /// How much has been written so far, in the units of the output
+    fn written(&self) -> usize;
+}
+
+impl<W: Write> Sink for platform::CountingWriter<W> {
+    const WIDE: bool = false;
+
+    fn write_wide(&mut self, s: &[wchar_t]) -> io::Result<()> {
+        let bytes = unsafe { encode(s)? };
+        self.write_all(&bytes)
+    }
+
+    fn len(text: &[u8]) -> usize {
+        text.len()
+    }
+
+    fn written(&self) -> usize {
+        self.written
+    }
+}
+
+/// The output of wprintf, which decodes what the conversions write as multibyte text
+struct WideSink<W> {
+    inner: W,
+    written: usize,
+}
+
+impl<W: WriteWide> Write for WideSink<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let chars = unsafe { decode(buf)? };
+        self.write_wide(&chars)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<W: WriteWide> Sink for WideSink<W> {
+    const WIDE: bool = true;
+
+    fn write_wide(&mut self, s: &[wchar_t]) -> io::Result<()> {
+        self.inner.write_wide(s)?;
+        self.written += s.len();
+        Ok(())
+    }
+
+    fn len(text: &[u8]) -> usize {
+        unsafe { decode(text).map_or(text.len(), |chars| chars.len()) }
+    }
+
+    fn written(&self) -> usize {
+        self.written
+    }
+}
+
+unsafe fn pop_int_raw<T: FormatChar>(format: &mut *const T) -> Option<usize> {
     let mut int = None;
-    while let Some(digit) = (**format as char).to_digit(10) {
+    while let Some(digit) = ((**format).ascii() as char).to_digit(10) {
         *format = format.add(1);
         if int.is_none() {
             int = Some(0);
@@ -291,19 +471,19 @@ unsafe fn pop_int_raw(format: &mut *const u8) -> Option<usize> {
     }
     int
 }
-unsafe fn pop_index(format: &mut *const u8) -> Option<usize> {
+unsafe fn pop_index<T: FormatChar>(format: &mut *const T) -> Option<usize> {
     // Peek ahead for a positional argument:
     let mut format2 = *format;
     if let Some(i) = pop_int_raw(&mut format2) {
-        if *format2 == b'$' {
+        if (*format2).ascii() == b'$' {
             *format = format2.add(1);
             return Some(i);
         }
     }
     None
 }
-unsafe fn pop_int(format: &mut *const u8) -> Option<Number> {
-    if **format == b'*' {
+unsafe fn pop_int<T: FormatChar>(format: &mut *const T) -> Option<Number> {
+    if (**format).ascii() == b'*' {
         *format = format.add(1);
         Some(pop_index(format).map(Number::Index).unwrap_or(Number::Next))
     } else {
@@ -366,6 +546,94 @@ fn float_string(float: c_double, precision: usize, trim: bool) -> String {
     string
 }
 
+/// Put the decimal point of the current locale into a number formatted the C way, along with
+/// its thousands separators if `group` is set. With `digits`, for the I flag, the locale's output
+/// digits and punctuation take the place of the ASCII ones.
+unsafe fn localize(number: String, group: bool, digits: bool) -> io::Result<Vec<u8>> {
+    let current = locale::current();
+    let numeric = current.numeric();
+    let point = match numeric.decimal_point.to_bytes() {
+        b"" => &b"."[..],
+        point => point,
+    };
+    if !group && !digits && point == b"." {
+        return Ok(number.into_bytes());
+    }
+
+    let ctype = current.ctype();
+    let outpunct = ctype
+        .maps
+        .iter()
+        .find(|map| map.name == b"to_outpunct")
+        .filter(|_| digits);
+    let punct = |text: &[u8]| match outpunct {
+        Some(map) => {
+            let chars: Vec<wchar_t> = decode(text)?
+                .into_iter()
+                .map(|c| map.apply(c as u32) as wchar_t)
+                .collect();
+            encode(&chars)
+        }
+        None => Ok(text.to_vec()),
+    };
+    let point = punct(point)?;
+    let sep = punct(numeric.thousands_sep.to_bytes())?;
+    let grouping = numeric.grouping.to_bytes();
+    let outdigits = if digits {
+        ctype
+            .outdigits
+            .iter()
+            .map(|&digit| encode(&[digit as wchar_t]))
+            .collect::<io::Result<Vec<_>>>()?
+    } else {
+        Vec::new()
+    };
+
+    let number = number.into_bytes();
+    let start = number
+        .iter()
+        .position(u8::is_ascii_digit)
+        .unwrap_or(number.len());
+    let end = number[start..]
+        .iter()
+        .position(|b| !b.is_ascii_digit())
+        .map_or(number.len(), |i| start + i);
+
+    // Group sizes count leftwards from the end of the integral digits. The last one repeats,
+    // and CHAR_MAX means no more grouping.
+    let mut cuts = Vec::new();
+    if group && !sep.is_empty() {
+        let mut len = end - start;
+        let mut sizes = grouping.iter();
+        let mut size = 0;
+        loop {
+            if let Some(&next) = sizes.next() {
+                size = next as usize;
+            }
+            if size == 0 || size as c_char == c_char::max_value() || len <= size {
+                break;
+            }
+            len -= size;
+            cuts.push(start + len);
+        }
+    }
+
+    let mut localized = Vec::with_capacity(number.len() + cuts.len() * sep.len());
+    for (i, &b) in number.iter().enumerate() {
+        if cuts.contains(&i) {
+            localized.extend_from_slice(&sep);
+        }
+        match b {
+            b'.' => localized.extend_from_slice(&point),
+            b'0'..=b'9' if !outdigits.is_empty() => {
+                localized.extend_from_slice(&outdigits[(b - b'0') as usize])
+            }
+            _ => localized.push(b),
+        }
+    }
+    Ok(localized)
+}
+
 fn float_exp(mut float: c_double) -> (c_double, isize) {
     let mut exp: isize = 0;
     while abs(float) >= 10.0 {
@@ -379,65 +647,145 @@ fn float_exp(mut float: c_double) -> (c_double, isize) {
     (float, exp)
 }
 
-fn fmt_float_exp<W: Write>(
+unsafe fn fmt_float_exp<W: Sink>(
     w: &mut W,
     exp_fmt: u8,
     trim: bool,
     precision: usize,
     float: c_double,
     exp: isize,
+    digits: bool,
     left: bool,
     pad_space: usize,
     pad_zero: usize,
 ) -> io::Result<()> {
-    let mut exp2 = exp;
-    let mut exp_len = 1;
-    while exp2 >= 10 {
-        exp2 /= 10;
-        exp_len += 1;
-    }
-
-    let string = float_string(float, precision, trim);
-    let len = string.len() + 2 + 2.max(exp_len);
+    let string = localize(float_string(float, precision, trim), false, digits)?;
+    let exp = format!("{}{:+03}", exp_fmt as char, exp);
+    let exp = if digits {
+        localize(exp, false, true)?
+    } else {
+        exp.into_bytes()
+    };
+    let len = W::len(&string) + W::len(&exp);
 
     pad(w, !left, b' ', len..pad_space)?;
-    let bytes = if string.starts_with('-') {
+    let bytes = if string.first() == Some(&b'-') {
         w.write_all(&[b'-'])?;
-        &string.as_bytes()[1..]
+        &string[1..]
     } else {
-        string.as_bytes()
+        &string[..]
     };
     pad(w, !left, b'0', len..pad_zero)?;
     w.write_all(bytes)?;
-    write!(w, "{}{:+03}", exp_fmt as char, exp)?;
+    w.write_all(&exp)?;
     pad(w, left, b' ', len..pad_space)?;
 
     Ok(())
 }
 
-fn fmt_float_normal<W: Write>(
+unsafe fn fmt_float_normal<W: Sink>(
     w: &mut W,
     trim: bool,
     precision: usize,
     float: c_double,
+    group: bool,
+    digits: bool,
     left: bool,
     pad_space: usize,
     pad_zero: usize,
 ) -> io::Result<usize> {
-    let string = float_string(float, precision, trim);
+    let string = localize(float_string(float, precision, trim), group, digits)?;
+    let len = W::len(&string);
 
-    pad(w, !left, b' ', string.len()..pad_space)?;
-    let bytes = if string.starts_with('-') {
+    pad(w, !left, b' ', len..pad_space)?;
+    let bytes = if string.first() == Some(&b'-') {
         w.write_all(&[b'-'])?;
-        &string.as_bytes()[1..]
+        &string[1..]
     } else {
-        string.as_bytes()
+        &string[..]
     };
-    pad(w, true, b'0', string.len()..pad_zero)?;
+    pad(w, true, b'0', len..pad_zero)?;
     w.write_all(bytes)?;
-    pad(w, left, b' ', string.len()..pad_space)?;
+    pad(w, left, b' ', len..pad_space)?;
+
+    Ok(len)
+}
+
+/// Write a float in hexadecimal notation, which is exact unless the precision asks for fewer
+/// digits than the 13 a double has, in which case the last one is rounded to even
+unsafe fn fmt_float_hex<W: Sink>(
+    w: &mut W,
+    fmt: u8,
+    alternate: bool,
+    precision: Option<usize>,
+    float: c_double,
+    sign: &[u8],
+    left: bool,
+    pad_space: usize,
+    pad_zero: usize,
+) -> io::Result<()> {
+    let bits = float.to_bits();
+    let biased = (bits >> 52 & 0x7ff) as isize;
+    let mut fraction = bits & ((1 << 52) - 1);
+    let mut lead = if biased == 0 { 0 } else { 1 };
+    let exp = match (biased, fraction) {
+        (0, 0) => 0,
+        (0, _) => -1022,
+        _ => biased - 1023,
+    };
+
+    let digits = match precision {
+        Some(precision) if precision < 13 => {
+            let shift = (13 - precision) * 4;
+            let rest = fraction & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            fraction >>= shift;
+            let last = if precision == 0 { lead } else { fraction };
+            if rest > half || (rest == half && last & 1 == 1) {
+                fraction += 1;
+                if fraction >> (precision * 4) != 0 {
+                    fraction = 0;
+                    lead += 1;
+                }
+            }
+            precision
+        }
+        Some(precision) => precision,
+        None => {
+            let mut digits = 13;
+            while digits > 0 && fraction & 0xf == 0 {
+                fraction >>= 4;
+                digits -= 1;
+            }
+            digits
+        }
+    };
 
-    Ok(string.len())
+    let mut string = format!("{}", lead);
+    if digits > 0 || alternate {
+        string.push('.');
+    }
+    if digits > 0 {
+        string.push_str(&format!("{:01$x}", fraction, digits.min(13)));
+        for _ in 13..digits {
+            string.push('0');
+        }
+    }
+    string.push_str(&format!("p{:+}", exp));
+    if fmt == b'A' {
+        string.make_ascii_uppercase();
+    }
+    let string = localize(string, false, false)?;
+    let len = sign.len() + 2 + W::len(&string);
+
+    pad(w, !left, b' ', len..pad_space)?;
+    w.write_all(sign)?;
+    w.write_all(&[b'0', fmt + (b'x' - b'a')])?;
+    pad(w, true, b'0', len..pad_zero)?;
+    w.write_all(&string)?;
+    pad(w, left, b' ', len..pad_space)?;
+
+    Ok(())
 }
 
 /// Write ±infinity or ±NaN representation for any floating-point style
@@ -467,8 +815,8 @@ fn fmt_float_nonfinite<W: Write>(w: &mut W, float: c_double, case: FmtCase) -> i
 }
 
 #[derive(Clone, Copy)]
-struct PrintfIter {
-    format: *const u8,
+struct PrintfIter<T: 'static> {
+    format: *const T,
 }
 #[derive(Clone, Copy, Debug)]
 struct PrintfArg {
@@ -478,6 +826,8 @@ struct PrintfArg {
     left: bool,
     sign_reserve: bool,
     sign_always: bool,
+    group: bool,
+    digits: bool,
     min_width: Number,
     precision: Option<Number>,
     intkind: IntKind,
@@ -485,26 +835,26 @@ struct PrintfArg {
     fmtkind: FmtKind,
 }
 #[derive(Debug)]
-enum PrintfFmt {
-    Plain(&'static [u8]),
+enum PrintfFmt<T: 'static> {
+    Plain(&'static [T]),
     Arg(PrintfArg),
 }
-impl Iterator for PrintfIter {
-    type Item = Result<PrintfFmt, ()>;
+impl<T: FormatChar> Iterator for PrintfIter<T> {
+    type Item = Result<PrintfFmt<T>, ()>;
     fn next(&mut self) -> Option<Self::Item> {
         unsafe {
             // Send PrintfFmt::Plain until the next %
             let mut len = 0;
-            while *self.format.add(len) != 0 && *self.format.add(len) != b'%' {
+            while (*self.format.add(len)).code() != 0 && (*self.format.add(len)).ascii() != b'%' {
                 len += 1;
             }
             if len > 0 {
-                let slice = slice::from_raw_parts(self.format as *const u8, len);
+                let slice = slice::from_raw_parts(self.format, len);
                 self.format = self.format.add(len);
                 return Some(Ok(PrintfFmt::Plain(slice)));
             }
             self.format = self.format.add(len);
-            if *self.format == 0 {
+            if (*self.format).code() == 0 {
                 return None;
             }
 
@@ -523,14 +873,18 @@ impl Iterator for PrintfIter {
             let mut left = false;
             let mut sign_reserve = false;
             let mut sign_always = false;
+            let mut group = false;
+            let mut digits = false;
 
             loop {
-                match *self.format {
+                match (*self.format).ascii() {
                     b'#' => alternate = true,
                     b'0' => zero = true,
                     b'-' => left = true,
                     b' ' => sign_reserve = true,
                     b'+' => sign_always = true,
+                    b'\'' => group = true,
+                    b'I' => digits = true,
                     _ => break,
                 }
                 self.format = self.format.add(1);
@@ -538,7 +892,7 @@ impl Iterator for PrintfIter {
 
             // Width and precision:
             let min_width = pop_int(&mut self.format).unwrap_or(Number::Static(0));
-            let precision = if *self.format == b'.' {
+            let precision = if (*self.format).ascii() == b'.' {
                 self.format = self.format.add(1);
                 match pop_int(&mut self.format) {
                     int @ Some(_) => int,
@@ -551,7 +905,7 @@ impl Iterator for PrintfIter {
             // Integer size:
             let mut intkind = IntKind::Int;
             loop {
-                intkind = match *self.format {
+                intkind = match (*self.format).ascii() {
                     b'h' => {
                         if intkind == IntKind::Short || intkind == IntKind::Byte {
                             IntKind::Byte
@@ -575,7 +929,7 @@ impl Iterator for PrintfIter {
 
                 self.format = self.format.add(1);
             }
-            let fmt = *self.format;
+            let fmt = (*self.format).ascii();
             let fmtkind = match fmt {
                 b'%' => FmtKind::Percent,
                 b'd' | b'i' => FmtKind::Signed,
@@ -583,10 +937,12 @@ impl Iterator for PrintfIter {
                 b'e' | b'E' => FmtKind::Scientific,
                 b'f' | b'F' => FmtKind::Decimal,
                 b'g' | b'G' => FmtKind::AnyNotation,
+                b'a' | b'A' => FmtKind::HexFloat,
                 b's' => FmtKind::String,
                 b'c' => FmtKind::Char,
                 b'p' => FmtKind::Pointer,
                 b'n' => FmtKind::GetWritten,
+                b'm' => FmtKind::Strerror,
                 _ => return Some(Err(())),
             };
             self.format = self.format.add(1);
@@ -598,6 +954,8 @@ impl Iterator for PrintfIter {
                 left,
                 sign_reserve,
                 sign_always,
+                group,
+                digits,
                 min_width,
                 precision,
                 intkind,
@@ -608,12 +966,15 @@ impl Iterator for PrintfIter {
     }
 }
 
-unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) -> io::Result<c_int> {
-    let w = &mut platform::CountingWriter::new(w);
+unsafe fn inner_printf<W: Sink, T: FormatChar>(
+    w: &mut W,
+    format: *const T,
+    mut ap: VaList,
+) -> io::Result<c_int> {
+    // What %m prints, before any writing gets a chance to change it
+    let errno = platform::errno;
 
-    let iterator = PrintfIter {
-        format: format as *const u8,
-    };
+    let iterator = PrintfIter { format };
 
     // Pre-fetch vararg types
     let mut varargs = VaListCache::default();
@@ -638,6 +999,9 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                 Number::Static(_) => (),
             }
         }
+        if arg.fmtkind == FmtKind::Strerror {
+            continue;
+        }
         match arg.index {
             Some(i) => {
                 positional.insert(i - 1, (arg.fmtkind, arg.intkind));
@@ -657,7 +1021,7 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
     for section in iterator {
         let arg = match section {
             Ok(PrintfFmt::Plain(text)) => {
-                w.write_all(text)?;
+                T::write_text(text, w)?;
                 continue;
             }
             Ok(PrintfFmt::Arg(arg)) => arg,
@@ -685,13 +1049,13 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
         let fmt = arg.fmt;
         let fmtkind = arg.fmtkind;
         let fmtcase = match fmt {
-            b'x' | b'f' | b'e' | b'g' => Some(FmtCase::Lower),
-            b'X' | b'F' | b'E' | b'G' => Some(FmtCase::Upper),
+            b'x' | b'f' | b'e' | b'g' | b'a' => Some(FmtCase::Lower),
+            b'X' | b'F' | b'E' | b'G' | b'A' => Some(FmtCase::Upper),
             _ => None,
         };
 
         let index = arg.index.map(|i| i - 1).unwrap_or_else(|| {
-            if fmtkind == FmtKind::Percent {
+            if fmtkind == FmtKind::Percent || fmtkind == FmtKind::Strerror {
                 0
             } else {
                 let i = varargs.i;
@@ -716,11 +1080,16 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                     VaArg::ssize_t(i) => i.to_string(),
                     VaArg::wint_t(_) => unreachable!("this should not be possible"),
                 };
-                let positive = !string.starts_with('-');
                 let zero = precision == Some(0) && string == "0";
+                let string = if arg.group || arg.digits {
+                    localize(string, arg.group, arg.digits)?
+                } else {
+                    string.into_bytes()
+                };
+                let positive = string.first() != Some(&b'-');
 
-                let mut len = string.len();
-                let mut final_len = string.len().max(precision.unwrap_or(0));
+                let mut len = W::len(&string);
+                let mut final_len = len.max(precision.unwrap_or(0));
                 if positive && (sign_reserve || sign_always) {
                     final_len += 1;
                 }
@@ -737,10 +1106,10 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                     } else if sign_always {
                         w.write_all(&[b'+'])?;
                     }
-                    string.as_bytes()
+                    &string[..]
                 } else {
                     w.write_all(&[b'-'])?;
-                    &string.as_bytes()[1..]
+                    &string[1..]
                 };
                 pad(w, true, b'0', len..precision.unwrap_or(pad_zero))?;
 
@@ -765,6 +1134,11 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                     VaArg::wint_t(_) => unreachable!("this should not be possible"),
                 };
                 let zero = precision == Some(0) && string == "0";
+                let string = if fmt == b'u' && (arg.group || arg.digits) {
+                    localize(string, arg.group, arg.digits)?
+                } else {
+                    string.into_bytes()
+                };
 
                 // If this int is padded out to be larger than it is, don't
                 // add an extra zero if octal.
@@ -775,9 +1149,9 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                     len = 0;
                     0
                 } else {
-                    len = string.len();
+                    len = W::len(&string);
                     len.max(precision.unwrap_or(0))
-                        + if alternate && string != "0" {
+                        + if alternate && string != b"0" {
                             match fmt {
                                 b'o' if no_precision => 1,
                                 b'x' | b'X' => 2,
@@ -790,7 +1164,7 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
 
                 pad(w, !left, b' ', final_len..pad_space)?;
 
-                if alternate && string != "0" {
+                if alternate && string != b"0" {
                     match fmt {
                         b'o' if no_precision => w.write_all(&[b'0'])?,
                         b'x' => w.write_all(&[b'0', b'x'])?,
@@ -801,7 +1175,7 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                 pad(w, true, b'0', len..precision.unwrap_or(pad_zero))?;
 
                 if !zero {
-                    w.write_all(string.as_bytes())?;
+                    w.write_all(&string)?;
                 }
 
                 pad(w, left, b' ', final_len..pad_space)?;
@@ -816,7 +1190,7 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                     let precision = precision.unwrap_or(6);
 
                     fmt_float_exp(
-                        w, fmt, false, precision, float, exp, left, pad_space, pad_zero,
+                        w, fmt, false, precision, float, exp, arg.digits, left, pad_space, pad_zero,
                     )?;
                 } else {
                     fmt_float_nonfinite(w, float, fmtcase.unwrap())?;
@@ -830,7 +1204,10 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                 if float.is_finite() {
                     let precision = precision.unwrap_or(6);
 
-                    fmt_float_normal(w, false, precision, float, left, pad_space, pad_zero)?;
+                    fmt_float_normal(
+                        w, false, precision, float, arg.group, arg.digits, left, pad_space,
+                        pad_zero,
+                    )?;
                 } else {
                     fmt_float_nonfinite(w, float, fmtcase.unwrap())?;
                 }
@@ -851,7 +1228,8 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                         // because that's how x/floor(log10(x)) works
                         let precision = precision.saturating_sub(1);
                         fmt_float_exp(
-                            w, exp_fmt, true, precision, log, exp, left, pad_space, pad_zero,
+                            w, exp_fmt, true, precision, log, exp, arg.digits, left, pad_space,
+                            pad_zero,
                         )?;
                     } else {
                         // Length of integral part will be the exponent of
@@ -860,12 +1238,38 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                         // of course be 0, 1 in length
                         let len = 1 + cmp::max(0, exp) as usize;
                         let precision = precision.saturating_sub(len);
-                        fmt_float_normal(w, true, precision, float, left, pad_space, pad_zero)?;
+                        fmt_float_normal(
+                            w, true, precision, float, arg.group, arg.digits, left, pad_space,
+                            pad_zero,
+                        )?;
                     }
                 } else {
                     fmt_float_nonfinite(w, float, fmtcase.unwrap())?;
                 }
             }
+            FmtKind::HexFloat => {
+                let float = match varargs.get(index, &mut ap, Some((arg.fmtkind, arg.intkind))) {
+                    VaArg::c_double(i) => i,
+                    _ => panic!("this should not be possible"),
+                };
+                if float.is_finite() {
+                    let sign: &[u8] = if float.is_sign_negative() {
+                        b"-"
+                    } else if sign_always {
+                        b"+"
+                    } else if sign_reserve {
+                        b" "
+                    } else {
+                        b""
+                    };
+
+                    fmt_float_hex(
+                        w, fmt, alternate, precision, float, sign, left, pad_space, pad_zero,
+                    )?;
+                } else {
+                    fmt_float_nonfinite(w, float, fmtcase.unwrap())?;
+                }
+            }
             FmtKind::String => {
                 let ptr = match varargs.get(index, &mut ap, Some((arg.fmtkind, arg.intkind))) {
                     VaArg::pointer(p) => p,
@@ -877,29 +1281,42 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                 } else {
                     let max = precision.unwrap_or(::core::usize::MAX);
 
-                    if intkind == IntKind::Long || intkind == IntKind::LongLong {
-                        // Handle wchar_t
+                    if (intkind == IntKind::Long || intkind == IntKind::LongLong) && W::WIDE {
+                        let ptr = ptr as *const wchar_t;
+                        let mut len = 0;
+                        while len < max && *ptr.add(len) != 0 {
+                            len += 1;
+                        }
+
+                        pad(w, !left, b' ', len..pad_space)?;
+                        w.write_wide(slice::from_raw_parts(ptr, len))?;
+                        pad(w, left, b' ', len..pad_space)?;
+                    } else if intkind == IntKind::Long || intkind == IntKind::LongLong {
+                        // Handle wchar_t, where the precision is in bytes but only whole
+                        // characters get written
                         let mut ptr = ptr as *const wchar_t;
-                        let mut string = String::new();
+                        let mut string = Vec::new();
 
                         while *ptr != 0 {
-                            let c = match char::from_u32(*ptr as _) {
-                                Some(c) => c,
-                                None => {
-                                    platform::errno = EILSEQ;
-                                    return Err(io::last_os_error());
-                                }
-                            };
-                            if string.len() + c.len_utf8() >= max {
+                            let c = encode(&[*ptr])?;
+                            if string.len() + c.len() > max {
                                 break;
                             }
-                            string.push(c);
+                            string.extend_from_slice(&c);
                             ptr = ptr.add(1);
                         }
 
                         pad(w, !left, b' ', string.len()..pad_space)?;
-                        w.write_all(string.as_bytes())?;
+                        w.write_all(&string)?;
                         pad(w, left, b' ', string.len()..pad_space)?;
+                    } else if W::WIDE {
+                        // The precision counts the wide characters the string converts to
+                        let string = decode(CStr::from_ptr(ptr).to_bytes())?;
+                        let len = string.len().min(max);
+
+                        pad(w, !left, b' ', len..pad_space)?;
+                        w.write_wide(&string[..len])?;
+                        pad(w, left, b' ', len..pad_space)?;
                     } else {
                         let mut len = 0;
                         while *ptr.add(len) != 0 && len < max {
@@ -919,18 +1336,12 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                     pad(w, left, b' ', 1..pad_space)?;
                 }
                 VaArg::wint_t(c) => {
-                    let c = match char::from_u32(c as _) {
-                        Some(c) => c,
-                        None => {
-                            platform::errno = EILSEQ;
-                            return Err(io::last_os_error());
-                        }
-                    };
-                    let mut buf = [0; 4];
+                    let c = c as wchar_t;
+                    let len = if W::WIDE { 1 } else { encode(&[c])?.len() };
 
-                    pad(w, !left, b' ', 1..pad_space)?;
-                    w.write_all(c.encode_utf8(&mut buf).as_bytes())?;
-                    pad(w, left, b' ', 1..pad_space)?;
+                    pad(w, !left, b' ', len..pad_space)?;
+                    w.write_wide(&[c])?;
+                    pad(w, left, b' ', len..pad_space)?;
                 }
                 _ => unreachable!("this should not be possible"),
             },
@@ -959,6 +1370,18 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                 }
                 pad(w, left, b' ', len..pad_space)?;
             }
+            FmtKind::Strerror => {
+                let string = if errno >= 0 && errno < STR_ERROR.len() as c_int {
+                    STR_ERROR[errno as usize].to_string()
+                } else {
+                    format!("Unknown error {}", errno)
+                };
+                let len = string.len().min(precision.unwrap_or(::core::usize::MAX));
+
+                pad(w, !left, b' ', len..pad_space)?;
+                w.write_all(&string.as_bytes()[..len])?;
+                pad(w, left, b' ', len..pad_space)?;
+            }
             FmtKind::GetWritten => {
                 let ptr = match varargs.get(index, &mut ap, Some((arg.fmtkind, arg.intkind))) {
                     VaArg::pointer(p) => p,
@@ -966,21 +1389,31 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                 };
 
                 match intkind {
-                    IntKind::Byte => *(ptr as *mut c_char) = w.written as c_char,
-                    IntKind::Short => *(ptr as *mut c_short) = w.written as c_short,
-                    IntKind::Int => *(ptr as *mut c_int) = w.written as c_int,
-                    IntKind::Long => *(ptr as *mut c_long) = w.written as c_long,
-                    IntKind::LongLong => *(ptr as *mut c_longlong) = w.written as c_longlong,
-                    IntKind::IntMax => *(ptr as *mut intmax_t) = w.written as intmax_t,
-                    IntKind::PtrDiff => *(ptr as *mut ptrdiff_t) = w.written as ptrdiff_t,
-                    IntKind::Size => *(ptr as *mut size_t) = w.written as size_t,
+                    IntKind::Byte => *(ptr as *mut c_char) = w.written() as c_char,
+                    IntKind::Short => *(ptr as *mut c_short) = w.written() as c_short,
+                    IntKind::Int => *(ptr as *mut c_int) = w.written() as c_int,
+                    IntKind::Long => *(ptr as *mut c_long) = w.written() as c_long,
+                    IntKind::LongLong => *(ptr as *mut c_longlong) = w.written() as c_longlong,
+                    IntKind::IntMax => *(ptr as *mut intmax_t) = w.written() as intmax_t,
+                    IntKind::PtrDiff => *(ptr as *mut ptrdiff_t) = w.written() as ptrdiff_t,
+                    IntKind::Size => *(ptr as *mut size_t) = w.written() as size_t,
                 }
             }
         }
     }
-    Ok(w.written as c_int)
+    Ok(w.written() as c_int)
 }
 
 pub unsafe fn printf<W: Write>(w: W, format: *const c_char, ap: VaList) -> c_int {
+    let w = &mut platform::CountingWriter::new(w);
+    inner_printf(w, format as *const u8, ap).unwrap_or(-1)
+}
+
+/// Like `printf`, but the format and the output are wide characters
+pub unsafe fn wprintf<W: WriteWide>(w: W, format: *const wchar_t, ap: VaList) -> c_int {
+    let w = &mut WideSink {
+        inner: w,
+        written: 0,
+    };
     inner_printf(w, format, ap).unwrap_or(-1)
 }
@@ -1,7 +1,13 @@
-use super::lookaheadreader::LookAheadReader;
-use crate::platform::types::*;
+use super::{
+    lookaheadreader::{LookAheadReader, WideLookAheadReader},
+    printf::{self, FormatChar},
+};
+use crate::{
+    header::{ctype, errno::ENOMEM, stdlib::strtod, wctype},
+    platform::{self, types::*},
+};
 use alloc::{string::String, vec::Vec};
-use core::ffi::VaList as va_list;
+use core::{cmp::Ordering, ffi::VaList as va_list, mem, ptr};
 
 #[derive(PartialEq, Eq)]
 enum IntKind {
@@ -15,24 +21,168 @@ enum IntKind {
     Size,
 }
 
+/// A character of the input or the format, which is a byte for scanf and a wide character for
+/// wscanf. It compares with bytes, so directives can be matched the same way for both.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Char(u32);
+
+impl Char {
+    fn ascii(self) -> u8 {
+        match self.0 {
+            c @ 0..=0x7f => c as u8,
+            _ => 0xff,
+        }
+    }
+
+    fn is_space(self, wide: bool) -> bool {
+        if wide {
+            wctype::iswspace(self.0) != 0
+        } else {
+            ctype::isspace(self.0 as c_int) != 0
+        }
+    }
+}
+
+impl PartialEq<u8> for Char {
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other as u32
+    }
+}
+
+impl PartialOrd<u8> for Char {
+    fn partial_cmp(&self, other: &u8) -> Option<Ordering> {
+        self.0.partial_cmp(&(*other as u32))
+    }
+}
+
+/// What the engine reads from, which is bytes for scanf and wide characters for wscanf
+trait Input {
+    type Format: FormatChar;
+
+    fn lookahead1(&mut self) -> Result<Option<Char>, c_int>;
+    fn commit(&mut self);
+}
+
+impl<'a> Input for LookAheadReader<'a> {
+    type Format = u8;
+
+    fn lookahead1(&mut self) -> Result<Option<Char>, c_int> {
+        LookAheadReader::lookahead1(self).map(|b| b.map(|b| Char(b as u32)))
+    }
+
+    fn commit(&mut self) {
+        LookAheadReader::commit(self)
+    }
+}
+
+impl<'a> Input for WideLookAheadReader<'a> {
+    type Format = wchar_t;
+
+    fn lookahead1(&mut self) -> Result<Option<Char>, c_int> {
+        WideLookAheadReader::lookahead1(self).map(|wc| wc.map(|wc| Char(wc as u32)))
+    }
+
+    fn commit(&mut self) {
+        WideLookAheadReader::commit(self)
+    }
+}
+
 /// Helper function for progressing a C string
-unsafe fn next_byte(string: &mut *const c_char) -> Result<u8, c_int> {
-    let c = **string as u8;
+unsafe fn next_byte<T: FormatChar>(string: &mut *const T) -> Result<Char, c_int> {
+    let c = (**string).code();
     *string = string.offset(1);
     if c == 0 {
         Err(-1)
     } else {
-        Ok(c)
+        Ok(Char(c))
     }
 }
 
-unsafe fn inner_scanf(
-    mut r: LookAheadReader,
-    mut format: *const c_char,
+/// Where %c, %s and %[ store what they read
+enum Dest {
+    Discard,
+    Buffer(*mut c_void),
+    /// With %m, the buffer is allocated once it is known how much goes in it
+    Allocate(*mut *mut c_void),
+}
+
+struct Output {
+    dest: Dest,
+    text: Vec<Char>,
+    /// Whether the text was read as wide characters
+    wide_input: bool,
+    /// Whether it is stored as wide characters, which %lc, %ls and %l[ do
+    wide_output: bool,
+}
+
+impl Output {
+    fn push(&mut self, c: Char) {
+        if let Dest::Discard = self.dest {
+            return;
+        }
+        self.text.push(c);
+    }
+
+    /// Stores the text, converting it with the multibyte functions of the locale if it was
+    /// read as the other kind of character, and terminates it if `terminate` is set. Returns
+    /// false if there was no memory for it or it couldn't be converted.
+    unsafe fn finish(self, terminate: bool) -> bool {
+        let converted = match (self.wide_input, self.wide_output) {
+            (false, false) => Ok(self.text.iter().map(|c| c.0 as u8).collect()),
+            (true, false) => {
+                let chars: Vec<wchar_t> = self.text.iter().map(|c| c.0 as wchar_t).collect();
+                printf::encode(&chars)
+            }
+            (false, true) => {
+                let bytes: Vec<u8> = self.text.iter().map(|c| c.0 as u8).collect();
+                return match printf::decode(&bytes) {
+                    Ok(chars) => Self::store(self.dest, chars, terminate),
+                    Err(_) => false,
+                };
+            }
+            (true, true) => {
+                let chars = self.text.iter().map(|c| c.0 as wchar_t).collect();
+                return Self::store(self.dest, chars, terminate);
+            }
+        };
+        match converted {
+            Ok(bytes) => Self::store(self.dest, bytes, terminate),
+            Err(_) => false,
+        }
+    }
+
+    /// Hands the characters over to the caller
+    unsafe fn store<T: Copy + Default>(dest: Dest, mut items: Vec<T>, terminate: bool) -> bool {
+        if terminate {
+            items.push(T::default());
+        }
+        match dest {
+            Dest::Discard => (),
+            Dest::Buffer(ptr) => {
+                ptr::copy_nonoverlapping(items.as_ptr(), ptr as *mut T, items.len());
+            }
+            Dest::Allocate(dest) => {
+                let ptr = platform::alloc(items.len() * mem::size_of::<T>()) as *mut T;
+                if ptr.is_null() {
+                    platform::errno = ENOMEM;
+                    return false;
+                }
+                ptr::copy_nonoverlapping(items.as_ptr(), ptr, items.len());
+                *dest = ptr as *mut c_void;
+            }
+        }
+        true
+    }
+}
+
+unsafe fn inner_scanf<R: Input>(
+    mut r: R,
+    mut format: *const R::Format,
     mut ap: va_list,
 ) -> Result<c_int, c_int> {
+    let wide = R::Format::WIDE;
     let mut matched = 0;
-    let mut byte = 0;
+    let mut byte = Char(0);
     let mut skip_read = false;
     let mut count = 0;
 
@@ -71,13 +221,13 @@ unsafe fn inner_scanf(
         }
     }
 
-    while *format != 0 {
+    while (*format).code() != 0 {
         let mut c = next_byte(&mut format)?;
 
-        if c == b' ' {
+        if c.is_space(wide) {
             maybe_read!(noreset);
 
-            while (byte as char).is_whitespace() {
+            while byte.is_space(wide) {
                 if !read!() {
                     return Ok(matched);
                 }
@@ -93,15 +243,52 @@ unsafe fn inner_scanf(
         } else {
             c = next_byte(&mut format)?;
 
+            let mut width = String::new();
+            while c >= b'0' && c <= b'9' {
+                width.push(c.ascii() as char);
+                c = next_byte(&mut format)?;
+            }
+
+            // %n$ stores into the nth argument instead of the next one
+            let mut index = None;
+            if c == b'$' && !width.is_empty() {
+                match width.parse::<usize>() {
+                    Ok(n) if n > 0 => index = Some(n),
+                    _ => return Err(-1),
+                }
+                width.clear();
+                c = next_byte(&mut format)?;
+            }
+
+            macro_rules! arg {
+                ($type:ty) => {
+                    match index {
+                        Some(n) => {
+                            let mut ap = (*ap).clone();
+                            for _ in 1..n {
+                                ap.arg::<*mut c_void>();
+                            }
+                            ap.arg::<$type>()
+                        }
+                        None => ap.arg::<$type>(),
+                    }
+                };
+            }
+
             let mut ignore = false;
             if c == b'*' {
                 ignore = true;
                 c = next_byte(&mut format)?;
             }
 
-            let mut width = String::new();
             while c >= b'0' && c <= b'9' {
-                width.push(c as char);
+                width.push(c.ascii() as char);
+                c = next_byte(&mut format)?;
+            }
+
+            // %m allocates the buffer for %c, %s and %[
+            let alloc = c == b'm';
+            if alloc {
                 c = next_byte(&mut format)?;
             }
             let mut width = if width.is_empty() {
@@ -113,13 +300,12 @@ unsafe fn inner_scanf(
                 }
             };
 
-            // When an EOF occurs, eof is set, stuff is marked matched
-            // as usual, and finally it is returned
+            // When an EOF occurs, eof is set and stuff is marked matched as usual
             let mut eof = false;
 
             let mut kind = IntKind::Int;
             loop {
-                kind = match c {
+                kind = match c.ascii() {
                     b'h' => {
                         if kind == IntKind::Short || kind == IntKind::Byte {
                             IntKind::Byte
@@ -144,12 +330,28 @@ unsafe fn inner_scanf(
                 c = next_byte(&mut format)?;
             }
 
+            macro_rules! output {
+                () => {
+                    Output {
+                        dest: if ignore {
+                            Dest::Discard
+                        } else if alloc {
+                            Dest::Allocate(arg!(*mut *mut c_void))
+                        } else {
+                            Dest::Buffer(arg!(*mut c_void))
+                        },
+                        text: Vec::new(),
+                        wide_input: wide,
+                        wide_output: kind == IntKind::Long,
+                    }
+                };
+            }
             if c != b'n' {
                 maybe_read!(noreset);
             }
-            match c {
+            match c.ascii() {
                 b'%' => {
-                    while (byte as char).is_whitespace() {
+                    while byte.is_space(wide) {
                         if !read!() {
                             return Ok(matched);
                         }
@@ -161,9 +363,9 @@ unsafe fn inner_scanf(
                         return Ok(matched);
                     }
                 }
-                b'd' | b'i' | b'o' | b'u' | b'x' | b'X' | b'f' | b'e' | b'g' | b'E' | b'a'
-                | b'p' => {
-                    while (byte as char).is_whitespace() {
+                b'd' | b'i' | b'o' | b'u' | b'x' | b'X' | b'a' | b'e' | b'f' | b'g' | b'A'
+                | b'E' | b'F' | b'G' | b'p' => {
+                    while byte.is_space(wide) {
                         if !read!() {
                             return Ok(matched);
                         }
@@ -172,21 +374,52 @@ unsafe fn inner_scanf(
                     let pointer = c == b'p';
                     // Pointers aren't automatic, but we do want to parse "0x"
                     let auto = c == b'i' || pointer;
-                    let float = c == b'f' || c == b'e' || c == b'g' || c == b'E' || c == b'a';
+                    let float = match c.ascii() {
+                        b'a' | b'e' | b'f' | b'g' | b'A' | b'E' | b'F' | b'G' => true,
+                        _ => false,
+                    };
 
-                    let mut radix = match c {
+                    let mut radix = match c.ascii() {
                         b'o' => 8,
                         b'x' | b'X' | b'p' => 16,
                         _ => 10,
                     };
 
                     let mut n = String::new();
-                    let mut dot = false;
 
-                    while width.map(|w| w > 0).unwrap_or(true)
+                    let mut bytes = Vec::new();
+
+                    if float {
+                        // Take bytes for as long as they could be the start of a float, and let
+                        // the parser behind strtod decide what they are
+                        while width.map(|w| w > 0).unwrap_or(true) {
+                            // The parser wants multibyte text, which matters for the decimal point
+                            let len = bytes.len();
+                            if wide {
+                                match printf::encode(&[byte.0 as wchar_t]) {
+                                    Ok(encoded) => bytes.extend_from_slice(&encoded),
+                                    Err(_) => break,
+                                }
+                            } else {
+                                bytes.push(byte.0 as u8);
+                            }
+                            if !strtod::scan(&bytes).partial {
+                                bytes.truncate(len);
+                                break;
+                            }
+                            r.commit();
+                            width = width.map(|w| w - 1);
+                            if width.map(|w| w > 0).unwrap_or(true) && !read!() {
+                                eof = true;
+                                break;
+                            }
+                        }
+                    }
+
+                    while !float
+                        && width.map(|w| w > 0).unwrap_or(true)
                         && ((byte >= b'0' && byte <= b'7')
                             || (radix >= 10 && (byte >= b'8' && byte <= b'9'))
-                            || (float && !dot && byte == b'.')
                             || (radix == 16
                                 && ((byte >= b'a' && byte <= b'f')
                                     || (byte >= b'A' && byte <= b'F'))))
@@ -214,36 +447,27 @@ unsafe fn inner_scanf(
                             }
                             continue;
                         }
-                        if byte == b'.' {
-                            // Don't allow another dot
-                            dot = true;
-                        }
-                        n.push(byte as char);
+                        n.push(byte.ascii() as char);
                         r.commit();
                         width = width.map(|w| w - 1);
                         if width.map(|w| w > 0).unwrap_or(true) && !read!() {
+                            eof = true;
                             break;
                         }
                     }
 
                     macro_rules! parse_type {
-                        (noformat $type:ident) => {{
-                            let n = if n.is_empty() {
-                                0 as $type
-                            } else {
-                                n.parse::<$type>().map_err(|_| 0)?
-                            };
+                        (float $type:ident) => {{
+                            // Anything that strtod wouldn't take all of is a matching failure
+                            let parsed = strtod::parse::<$type>(&bytes);
+                            if bytes.is_empty() || parsed.len != bytes.len() {
+                                return Ok(matched);
+                            }
                             if !ignore {
-                                *ap.arg::<*mut $type>() = n;
+                                *arg!(*mut $type) = parsed.value;
                                 matched += 1;
                             }
                         }};
-                        (c_double) => {
-                            parse_type!(noformat c_double);
-                        };
-                        (c_float) => {
-                            parse_type!(noformat c_float);
-                        };
                         ($type:ident) => {
                             parse_type!($type, $type);
                         };
@@ -254,7 +478,7 @@ unsafe fn inner_scanf(
                                 $type::from_str_radix(&n, radix).map_err(|_| 0)?
                             };
                             if !ignore {
-                                *ap.arg::<*mut $final>() = n as $final;
+                                *arg!(*mut $final) = n as $final;
                                 matched += 1;
                             }
                         }};
@@ -262,9 +486,9 @@ unsafe fn inner_scanf(
 
                     if float {
                         if kind == IntKind::Long || kind == IntKind::LongLong {
-                            parse_type!(c_double);
+                            parse_type!(float c_double);
                         } else {
-                            parse_type!(c_float);
+                            parse_type!(float c_float);
                         }
                     } else if c == b'p' {
                         parse_type!(size_t, *mut c_void);
@@ -326,19 +550,16 @@ unsafe fn inner_scanf(
                     }
                 }
                 b's' => {
-                    while (byte as char).is_whitespace() {
+                    while byte.is_space(wide) {
                         if !read!() {
                             return Ok(matched);
                         }
                     }
 
-                    let mut ptr: Option<*mut c_char> = if ignore { None } else { Some(ap.arg()) };
+                    let mut output = output!();
 
-                    while width.map(|w| w > 0).unwrap_or(true) && !(byte as char).is_whitespace() {
-                        if let Some(ref mut ptr) = ptr {
-                            **ptr = byte as c_char;
-                            *ptr = ptr.offset(1);
-                        }
+                    while width.map(|w| w > 0).unwrap_or(true) && !byte.is_space(wide) {
+                        output.push(byte);
                         width = width.map(|w| w - 1);
                         if width.map(|w| w > 0).unwrap_or(true) && !read!() {
                             eof = true;
@@ -346,19 +567,19 @@ unsafe fn inner_scanf(
                         }
                     }
 
-                    if let Some(ptr) = ptr {
-                        *ptr = 0;
+                    if !ignore {
+                        if !output.finish(true) {
+                            return Err(-1);
+                        }
                         matched += 1;
                         r.commit();
                     }
                 }
                 b'c' => {
-                    let ptr: Option<*mut c_char> = if ignore { None } else { Some(ap.arg()) };
+                    let mut output = output!();
 
-                    for i in 0..width.unwrap_or(1) {
-                        if let Some(ptr) = ptr {
-                            *ptr.add(i) = byte as c_char;
-                        }
+                    for _ in 0..width.unwrap_or(1) {
+                        output.push(byte);
                         width = width.map(|w| w - 1);
                         if width.map(|w| w > 0).unwrap_or(true) && !read!() {
                             eof = true;
@@ -366,7 +587,10 @@ unsafe fn inner_scanf(
                         }
                     }
 
-                    if ptr.is_some() {
+                    if !ignore {
+                        if !output.finish(false) {
+                            return Err(-1);
+                        }
                         matched += 1;
                         r.commit();
                     }
@@ -393,28 +617,27 @@ unsafe fn inner_scanf(
                             }
                             c = next_byte(&mut format)?;
                             if c == b']' {
-                                matches.push(b'-');
+                                matches.push(Char(b'-' as u32));
                                 break;
                             }
-                            prev += 1;
+                            prev.0 += 1;
                             while prev < c {
                                 matches.push(prev);
-                                prev += 1;
+                                prev.0 += 1;
                             }
                         } else if c == b']' {
                             break;
                         }
                     }
 
-                    let mut ptr: Option<*mut c_char> = if ignore { None } else { Some(ap.arg()) };
+                    let mut output = output!();
 
                     // While we haven't used up all the width, and it matches
                     let mut data_stored = false;
                     while width.map(|w| w > 0).unwrap_or(true) && !invert == matches.contains(&byte)
                     {
-                        if let Some(ref mut ptr) = ptr {
-                            **ptr = byte as c_char;
-                            *ptr = ptr.offset(1);
+                        if !ignore {
+                            output.push(byte);
                             data_stored = true;
                         }
                         r.commit();
@@ -429,23 +652,27 @@ unsafe fn inner_scanf(
                     }
 
                     if data_stored {
-                        *ptr.unwrap() = 0;
+                        if !output.finish(true) {
+                            return Err(-1);
+                        }
                         matched += 1;
                     }
                 }
                 b'n' => {
                     if !ignore {
-                        *ap.arg::<*mut c_int>() = count as c_int;
+                        // A character that was read but is kept for the next directive
+                        // hasn't been consumed yet
+                        *arg!(*mut c_int) = count - skip_read as c_int;
                     }
                 }
                 _ => return Err(-1),
             }
 
             if eof {
-                return Ok(matched);
-            }
-
-            if width != Some(0) && c != b'n' {
+                // Let the next directive find the end of the input by reading again, so that a
+                // %n after this can still be stored
+                skip_read = false;
+            } else if width != Some(0) && c != b'n' {
                 // It didn't hit the width, so an extra character was read and matched.
                 // But this character did not match so let's reuse it.
                 skip_read = true;
@@ -456,6 +683,14 @@ unsafe fn inner_scanf(
 }
 
 pub unsafe fn scanf(r: LookAheadReader, format: *const c_char, ap: va_list) -> c_int {
+    match inner_scanf(r, format as *const u8, ap) {
+        Ok(n) => n,
+        Err(n) => n,
+    }
+}
+
+/// Like `scanf`, but the format and the input are wide characters
+pub unsafe fn wscanf(r: WideLookAheadReader, format: *const wchar_t, ap: va_list) -> c_int {
     match inner_scanf(r, format, ap) {
         Ok(n) => n,
         Err(n) => n,
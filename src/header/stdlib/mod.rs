@@ -16,6 +16,7 @@ use crate::{
         errno::{self, *},
         fcntl::*,
         limits,
+        locale::{self, Codeset},
         stdio::flush_io_streams,
         string::*,
         time::constants::CLOCK_MONOTONIC,
@@ -29,13 +30,12 @@ use crate::{
 mod rand48;
 mod random;
 mod sort;
+pub(crate) mod strtod;
 
 pub const EXIT_FAILURE: c_int = 1;
 pub const EXIT_SUCCESS: c_int = 0;
 pub const RAND_MAX: c_int = 2_147_483_647;
 
-//Maximum number of bytes in a multibyte character for the current locale
-pub const MB_CUR_MAX: c_int = 4;
 //Maximum number of bytes in a multibyte characters for any locale
 pub const MB_LEN_MAX: c_int = 4;
 
@@ -274,7 +274,6 @@ pub unsafe extern "C" fn exit(status: c_int) {
         static __fini_array_start: extern "C" fn();
         static __fini_array_end: extern "C" fn();
 
-        fn pthread_terminate();
         fn _fini();
     }
 
@@ -296,8 +295,6 @@ pub unsafe extern "C" fn exit(status: c_int) {
 
     ld_so::fini();
 
-    pthread_terminate();
-
     flush_io_streams();
 
     Sys::exit(status);
@@ -522,10 +519,25 @@ pub unsafe extern "C" fn memalign(alignment: size_t, size: size_t) -> *mut c_voi
     }
 }
 
+/// The maximum number of bytes in a multibyte character for the current locale, which is what
+/// MB_CUR_MAX expands to
+#[no_mangle]
+pub extern "C" fn __ctype_get_mb_cur_max() -> size_t {
+    match locale::current().ctype().codeset {
+        Codeset::Utf8 => MB_LEN_MAX as size_t,
+        _ => 1,
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn mblen(s: *const c_char, n: size_t) -> c_int {
+    // None of the encodings have shift states
+    if s.is_null() {
+        return 0;
+    }
+
     let mut wc: wchar_t = 0;
-    let mut state: mbstate_t = mbstate_t {};
+    let mut state = mbstate_t::new();
     let result: usize = mbrtowc(&mut wc, s, n, &mut state);
 
     if result == -1isize as usize {
@@ -540,14 +552,22 @@ pub unsafe extern "C" fn mblen(s: *const c_char, n: size_t) -> c_int {
 
 #[no_mangle]
 pub unsafe extern "C" fn mbstowcs(pwcs: *mut wchar_t, mut s: *const c_char, n: size_t) -> size_t {
-    let mut state: mbstate_t = mbstate_t {};
+    let mut state = mbstate_t::new();
     mbsrtowcs(pwcs, &mut s, n, &mut state)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn mbtowc(pwc: *mut wchar_t, s: *const c_char, n: size_t) -> c_int {
-    let mut state: mbstate_t = mbstate_t {};
-    mbrtowc(pwc, s, n, &mut state) as c_int
+    if s.is_null() {
+        return 0;
+    }
+
+    let mut state = mbstate_t::new();
+    match mbrtowc(pwc, s, n, &mut state) {
+        // An incomplete character is as much of an error as an invalid one here
+        result if result == -1isize as usize || result == -2isize as usize => -1,
+        result => result as c_int,
+    }
 }
 
 fn inner_mktemp<T, F>(name: *mut c_char, suffix_len: c_int, mut attempt: F) -> Option<T>
@@ -943,11 +963,11 @@ pub unsafe extern "C" fn srandom(seed: c_uint) {
 
 #[no_mangle]
 pub unsafe extern "C" fn strtod(s: *const c_char, endptr: *mut *mut c_char) -> c_double {
-    strto_float_impl!(c_double, s, endptr)
+    strtod::strto_float(s, endptr)
 }
 #[no_mangle]
 pub unsafe extern "C" fn strtof(s: *const c_char, endptr: *mut *mut c_char) -> c_float {
-    strto_float_impl!(c_float, s, endptr)
+    strtod::strto_float(s, endptr)
 }
 
 pub fn is_positive(ch: c_char) -> Option<(bool, isize)> {
@@ -1195,14 +1215,19 @@ pub unsafe extern "C" fn valloc(size: size_t) -> *mut c_void {
 }
 
 #[no_mangle]
-pub extern "C" fn wcstombs(s: *mut c_char, pwcs: *mut *const wchar_t, n: size_t) -> size_t {
-    let mut state: mbstate_t = mbstate_t {};
-    wcsrtombs(s, pwcs, n, &mut state)
+pub unsafe extern "C" fn wcstombs(s: *mut c_char, mut pwcs: *const wchar_t, n: size_t) -> size_t {
+    let mut state = mbstate_t::new();
+    wcsrtombs(s, &mut pwcs, n, &mut state)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn wctomb(s: *mut c_char, wc: wchar_t) -> c_int {
-    let mut state: mbstate_t = mbstate_t {};
+    // There is no shift state to reset
+    if s.is_null() {
+        return 0;
+    }
+
+    let mut state = mbstate_t::new();
     let result: usize = wcrtomb(s, wc, &mut state);
 
     if result == -1isize as usize {
@@ -0,0 +1,367 @@
+//! Conversion of strings to floats, shared by strtod, strtof and the scanf family
+
+use alloc::string::String;
+use core::{cmp, ops::Neg, str::FromStr};
+
+use crate::{
+    c_str::CStr,
+    header::{ctype, errno::ERANGE, locale},
+    platform::{self, types::*},
+};
+
+pub trait Float: Copy + PartialOrd + FromStr + Neg<Output = Self> {
+    /// The width of the type
+    const BITS: u32;
+    /// The bits of precision, counting the implicit one
+    const MANTISSA: u32;
+    const ZERO: Self;
+    const MIN_POSITIVE: Self;
+    const INFINITY: Self;
+    const NAN: Self;
+
+    fn from_bits(bits: u64) -> Self;
+}
+
+impl Float for c_double {
+    const BITS: u32 = 64;
+    const MANTISSA: u32 = 53;
+    const ZERO: Self = 0.0;
+    const MIN_POSITIVE: Self = c_double::MIN_POSITIVE;
+    const INFINITY: Self = c_double::INFINITY;
+    const NAN: Self = c_double::NAN;
+
+    fn from_bits(bits: u64) -> Self {
+        c_double::from_bits(bits)
+    }
+}
+
+impl Float for c_float {
+    const BITS: u32 = 32;
+    const MANTISSA: u32 = 24;
+    const ZERO: Self = 0.0;
+    const MIN_POSITIVE: Self = c_float::MIN_POSITIVE;
+    const INFINITY: Self = c_float::INFINITY;
+    const NAN: Self = c_float::NAN;
+
+    fn from_bits(bits: u64) -> Self {
+        c_float::from_bits(bits as u32)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    None,
+    Infinity,
+    Nan,
+    Decimal,
+    Hex,
+}
+
+/// Where the number at the start of a string ends, without converting it
+pub struct Scan {
+    kind: Kind,
+    negative: bool,
+    /// The digits, after any sign and "0x"
+    mantissa: usize,
+    /// Whether any of the digits isn't a zero
+    nonzero: bool,
+    /// The length of the number, or 0 if there is none
+    pub len: usize,
+    /// Whether all of the string is the start of a number, so reading more could make it longer
+    pub partial: bool,
+}
+
+/// The length of the case-insensitive match of `word` at the start of `s`
+fn matching(s: &[u8], word: &[u8]) -> usize {
+    s.iter()
+        .zip(word)
+        .take_while(|(a, b)| a.to_ascii_lowercase() == **b)
+        .count()
+}
+
+fn decimal_point() -> &'static [u8] {
+    match locale::current().numeric().decimal_point.to_bytes() {
+        b"" => &b"."[..],
+        point => point,
+    }
+}
+
+/// Finds the longest float at the start of `s` in the syntax of strtod: a sign, then "inf",
+/// "infinity", "nan" or "nan(...)", or decimal or hexadecimal digits with the decimal point of
+/// the current locale and an exponent
+pub fn scan(s: &[u8]) -> Scan {
+    let negative = s.first() == Some(&b'-');
+    let i = match s.first() {
+        Some(b'-') | Some(b'+') => 1,
+        _ => 0,
+    };
+    let rest = &s[i..];
+    let mut scan = Scan {
+        kind: Kind::None,
+        negative,
+        mantissa: i,
+        nonzero: false,
+        len: 0,
+        partial: false,
+    };
+
+    let word = matching(rest, b"infinity");
+    if word >= 3 {
+        scan.kind = Kind::Infinity;
+        scan.len = i + if word == 8 { 8 } else { 3 };
+        scan.partial = word == rest.len();
+        return scan;
+    }
+    let word = cmp::max(word, matching(rest, b"nan"));
+    if word == 3 {
+        scan.kind = Kind::Nan;
+        scan.len = i + 3;
+        scan.partial = rest.len() == 3;
+        if rest.get(3) == Some(&b'(') {
+            let chars = rest[4..]
+                .iter()
+                .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'_')
+                .count();
+            match rest.get(4 + chars) {
+                Some(b')') => {
+                    scan.len = i + 5 + chars;
+                    scan.partial = scan.len == s.len();
+                }
+                Some(_) => (),
+                None => scan.partial = true,
+            }
+        }
+        return scan;
+    }
+    if word > 0 {
+        scan.partial = word == rest.len();
+        return scan;
+    }
+
+    let point = decimal_point();
+    let hex = rest.len() >= 2 && rest[0] == b'0' && rest[1] | 0x20 == b'x';
+    let radix = if hex { 16 } else { 10 };
+    scan.mantissa = if hex { i + 2 } else { i };
+
+    let mut j = scan.mantissa;
+    let mut digits = false;
+    let mut dot = false;
+    loop {
+        match s.get(j) {
+            Some(&b) if (b as char).is_digit(radix) => {
+                digits = true;
+                scan.nonzero |= b != b'0';
+                j += 1;
+            }
+            Some(_) if !dot && s[j..].starts_with(point) => {
+                dot = true;
+                j += point.len();
+            }
+            _ => break,
+        }
+    }
+    // The input may also stop halfway through a decimal point that takes more than one byte
+    let in_point = !dot && j < s.len() && point.starts_with(&s[j..]);
+    scan.partial = j == s.len() || in_point;
+
+    if !digits {
+        // "0x" with no digits after it is a zero followed by an "x"
+        if hex {
+            scan.kind = Kind::Decimal;
+            scan.mantissa = i;
+            scan.len = i + 1;
+        }
+        return scan;
+    }
+    scan.kind = if hex { Kind::Hex } else { Kind::Decimal };
+    scan.len = j;
+
+    let marker = if hex { b'p' } else { b'e' };
+    if !in_point && s.get(j).map(|b| b | 0x20) == Some(marker) {
+        let mut k = j + 1;
+        if let Some(b'+') | Some(b'-') = s.get(k) {
+            k += 1;
+        }
+        let exp_digits = s[k..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if exp_digits > 0 {
+            scan.len = k + exp_digits;
+        }
+        scan.partial = k + exp_digits == s.len();
+    }
+    scan
+}
+
+/// Converts hexadecimal digits like "1.8p+1" exactly, rounding to nearest even like the
+/// conversion in the other direction. Returns the bits of a float that is `bits` wide and has
+/// `mantissa` bits of precision, and whether the value is below the normal range and got rounded.
+fn hex_float(n: &str, bits: u32, mantissa: u32) -> (u64, bool) {
+    // The digits are value * 2^exp, and sticky says whether any that didn't fit were nonzero
+    let mut value: u64 = 0;
+    let mut exp: i64 = 0;
+    let mut sticky = false;
+    let mut dot = false;
+    let mut rest = "";
+    for (i, b) in n.bytes().enumerate() {
+        if b == b'.' {
+            dot = true;
+            continue;
+        }
+        let digit = match (b as char).to_digit(16) {
+            Some(digit) => digit as u64,
+            None => {
+                rest = &n[i..];
+                break;
+            }
+        };
+        if value >> 60 == 0 {
+            value = value << 4 | digit;
+            if dot {
+                exp -= 4;
+            }
+        } else {
+            sticky |= digit != 0;
+            if !dot {
+                exp += 4;
+            }
+        }
+    }
+    if let Some(rest) = rest.strip_prefix(|c: char| c == 'p' || c == 'P') {
+        let (negative, digits) = match rest.as_bytes().first() {
+            Some(b'-') => (true, &rest[1..]),
+            Some(b'+') => (false, &rest[1..]),
+            _ => (false, rest),
+        };
+        // Anything this far out is zero or infinity either way
+        let mut shift: i64 = 0;
+        for digit in digits.bytes().take_while(u8::is_ascii_digit) {
+            shift = cmp::min(shift * 10 + (digit - b'0') as i64, 1 << 20);
+        }
+        exp += if negative { -shift } else { shift };
+    }
+
+    let exp_bits = bits - mantissa;
+    let bias = (1 << (exp_bits - 1)) - 1;
+    if value == 0 {
+        return (0, false);
+    }
+
+    // Keep the top `mantissa` bits, or fewer if the result is subnormal
+    let top = exp + 63 - value.leading_zeros() as i64;
+    let mut lsb = cmp::max(top - (mantissa as i64 - 1), 2 - bias - mantissa as i64);
+    let shift = lsb - exp;
+    let mut inexact = sticky;
+    let mut m = if shift <= 0 {
+        value << -shift
+    } else if shift > 64 {
+        inexact = true;
+        0
+    } else {
+        let m = (value as u128 >> shift) as u64;
+        let dropped = value as u128 & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        inexact |= dropped != 0;
+        if dropped > half || (dropped == half && (sticky || m & 1 == 1)) {
+            m + 1
+        } else {
+            m
+        }
+    };
+    let underflow = inexact && top < 1 - bias;
+    if m >> mantissa != 0 {
+        m >>= 1;
+        lsb += 1;
+    }
+
+    let biased = if m >> (mantissa - 1) == 0 {
+        0
+    } else {
+        lsb + mantissa as i64 - 1 + bias
+    };
+    if biased > 2 * bias {
+        return (((1 << exp_bits) - 1) << (mantissa - 1), false);
+    }
+    (
+        (biased as u64) << (mantissa - 1) | m & ((1 << (mantissa - 1)) - 1),
+        underflow,
+    )
+}
+
+/// The float at the start of a string
+pub struct Parsed<F> {
+    pub value: F,
+    /// The length of the number, or 0 if there is none
+    pub len: usize,
+    /// Whether the number is too large or too small for the type
+    pub out_of_range: bool,
+}
+
+/// Converts the float at the start of `s`, correctly rounded
+pub fn parse<F: Float>(s: &[u8]) -> Parsed<F> {
+    let scan = scan(s);
+    let mut underflow = false;
+    let value = match scan.kind {
+        Kind::None => {
+            return Parsed {
+                value: F::ZERO,
+                len: 0,
+                out_of_range: false,
+            }
+        }
+        Kind::Infinity => F::INFINITY,
+        Kind::Nan => F::NAN,
+        Kind::Decimal | Kind::Hex => {
+            // Both conversions below want the digits in the C locale
+            let point = decimal_point();
+            let mut text = String::with_capacity(scan.len - scan.mantissa);
+            let mut i = scan.mantissa;
+            while i < scan.len {
+                if s[i..].starts_with(point) {
+                    text.push('.');
+                    i += point.len();
+                } else {
+                    text.push(s[i] as char);
+                    i += 1;
+                }
+            }
+            if scan.kind == Kind::Hex {
+                let (bits, tiny) = hex_float(&text, F::BITS, F::MANTISSA);
+                underflow = tiny;
+                F::from_bits(bits)
+            } else {
+                let value = text.parse().unwrap_or(F::ZERO);
+                // Decimals that are exact below the normal range need hundreds of digits, so
+                // count anything that ends up there as rounded
+                underflow = scan.nonzero && value < F::MIN_POSITIVE;
+                value
+            }
+        }
+    };
+
+    let finite = scan.kind == Kind::Decimal || scan.kind == Kind::Hex;
+    Parsed {
+        value: if scan.negative { -value } else { value },
+        len: scan.len,
+        out_of_range: finite && (value == F::INFINITY || underflow),
+    }
+}
+
+/// Skips leading whitespace and converts the float after it, like strtod
+pub unsafe fn strto_float<F: Float>(s: *const c_char, endptr: *mut *mut c_char) -> F {
+    let mut start = s;
+    while ctype::isspace(*start as c_int) != 0 {
+        start = start.add(1);
+    }
+
+    let parsed = parse::<F>(CStr::from_ptr(start).to_bytes());
+    if parsed.out_of_range {
+        platform::errno = ERANGE;
+    }
+    if !endptr.is_null() {
+        *endptr = if parsed.len == 0 {
+            s as *mut c_char
+        } else {
+            start.add(parsed.len) as *mut c_char
+        };
+    }
+    parsed.value
+}
@@ -1,11 +1,16 @@
 //! string implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/string.h.html
 
-use core::{mem, ptr, slice, usize};
+use core::{cmp::Ordering, mem, ptr, slice, usize};
 
 use cbitset::BitSet256;
 
 use crate::{
-    header::{errno::*, signal},
+    c_str::CStr,
+    header::{
+        errno::*,
+        locale::{self, collate, locale_t},
+        signal,
+    },
     platform::{self, types::*},
 };
 
@@ -148,8 +153,29 @@ pub unsafe extern "C" fn strcmp(s1: *const c_char, s2: *const c_char) -> c_int {
 
 #[no_mangle]
 pub unsafe extern "C" fn strcoll(s1: *const c_char, s2: *const c_char) -> c_int {
-    // relibc has no locale stuff (yet)
-    strcmp(s1, s2)
+    strcoll_l(s1, s2, locale::uselocale(ptr::null_mut()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strcoll_l(
+    s1: *const c_char,
+    s2: *const c_char,
+    locale: locale_t,
+) -> c_int {
+    let locale = locale::get(locale);
+    let data = locale.collate();
+    if !data.unicode {
+        return strcmp(s1, s2);
+    }
+
+    let codeset = locale.ctype().codeset;
+    let s1 = collate::decode(CStr::from_ptr(s1).to_bytes(), codeset);
+    let s2 = collate::decode(CStr::from_ptr(s2).to_bytes(), codeset);
+    match collate::compare(&s1, &s2, data.tailoring) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
 }
 
 #[no_mangle]
@@ -454,10 +480,32 @@ pub unsafe extern "C" fn strtok_r(
 
 #[no_mangle]
 pub unsafe extern "C" fn strxfrm(s1: *mut c_char, s2: *const c_char, n: size_t) -> size_t {
-    // relibc has no locale stuff (yet)
-    let len = strlen(s2);
-    if len < n {
-        strcpy(s1, s2);
+    strxfrm_l(s1, s2, n, locale::uselocale(ptr::null_mut()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strxfrm_l(
+    s1: *mut c_char,
+    s2: *const c_char,
+    n: size_t,
+    locale: locale_t,
+) -> size_t {
+    let locale = locale::get(locale);
+    let data = locale.collate();
+    if !data.unicode {
+        // Collating by byte value makes the transformation the identity
+        let len = strlen(s2);
+        if len < n {
+            strcpy(s1, s2);
+        }
+        return len;
+    }
+
+    let s2 = collate::decode(CStr::from_ptr(s2).to_bytes(), locale.ctype().codeset);
+    let key = collate::key_bytes(&collate::sort_key(&s2, data.tailoring));
+    if key.len() < n {
+        ptr::copy_nonoverlapping(key.as_ptr() as *const c_char, s1, key.len());
+        *s1.add(key.len()) = 0;
     }
-    len
+    key.len()
 }
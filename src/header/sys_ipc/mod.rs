@@ -0,0 +1,42 @@
+//! sys/ipc.h implementation for relibc, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_ipc.h.html
+
+use core::mem;
+
+use crate::{
+    header::sys_stat::{self, stat},
+    platform::types::*,
+};
+
+pub const IPC_CREAT: c_int = 0o1000;
+pub const IPC_EXCL: c_int = 0o2000;
+pub const IPC_NOWAIT: c_int = 0o4000;
+
+pub const IPC_PRIVATE: key_t = 0;
+
+pub const IPC_RMID: c_int = 0;
+pub const IPC_SET: c_int = 1;
+pub const IPC_STAT: c_int = 2;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ipc_perm {
+    pub __key: key_t,
+    pub uid: uid_t,
+    pub gid: gid_t,
+    pub cuid: uid_t,
+    pub cgid: gid_t,
+    pub mode: mode_t,
+    pub __seq: c_ushort,
+    pub __pad: c_ushort,
+    pub __unused: [c_ulong; 2],
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ftok(path: *const c_char, id: c_int) -> key_t {
+    let mut st: stat = mem::zeroed();
+    if sys_stat::stat(path, &mut st) < 0 {
+        return -1;
+    }
+    ((st.st_ino & 0xffff) as c_int | ((st.st_dev & 0xff) << 16) as c_int | (id & 0xff) << 24)
+        as key_t
+}
@@ -2,6 +2,7 @@ use crate::{
     c_str::{CStr, CString},
     header::{fcntl, unistd},
     platform::{types::*, Pal, Sys},
+    pthread,
 };
 
 pub use self::sys::*;
@@ -53,7 +54,7 @@ pub unsafe extern "C" fn mprotect(addr: *mut c_void, len: size_t, prot: c_int) -
 
 #[no_mangle]
 pub unsafe extern "C" fn msync(addr: *mut c_void, len: size_t, flags: c_int) -> c_int {
-    Sys::msync(addr, len, flags)
+    pthread::cancellation_point(|| Sys::msync(addr, len, flags))
 }
 
 #[no_mangle]
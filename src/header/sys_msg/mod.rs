@@ -0,0 +1,58 @@
+//! sys/msg.h implementation for relibc, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_msg.h.html
+
+use crate::{
+    header::sys_ipc::ipc_perm,
+    platform::{types::*, PalIpc, Sys},
+    pthread,
+};
+
+pub const MSG_NOERROR: c_int = 0o10000;
+pub const MSG_EXCEPT: c_int = 0o20000;
+
+pub type msgqnum_t = c_ulong;
+pub type msglen_t = c_ulong;
+
+#[repr(C)]
+pub struct msqid_ds {
+    pub msg_perm: ipc_perm,
+    pub msg_stime: time_t,
+    pub msg_rtime: time_t,
+    pub msg_ctime: time_t,
+    pub msg_cbytes: c_ulong,
+    pub msg_qnum: msgqnum_t,
+    pub msg_qbytes: msglen_t,
+    pub msg_lspid: pid_t,
+    pub msg_lrpid: pid_t,
+    pub __unused: [c_ulong; 2],
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn msgctl(msqid: c_int, cmd: c_int, buf: *mut msqid_ds) -> c_int {
+    Sys::msgctl(msqid, cmd, buf)
+}
+
+#[no_mangle]
+pub extern "C" fn msgget(key: key_t, msgflg: c_int) -> c_int {
+    Sys::msgget(key, msgflg)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn msgrcv(
+    msqid: c_int,
+    msgp: *mut c_void,
+    msgsz: size_t,
+    msgtyp: c_long,
+    msgflg: c_int,
+) -> ssize_t {
+    pthread::cancellation_point(|| Sys::msgrcv(msqid, msgp, msgsz, msgtyp, msgflg))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn msgsnd(
+    msqid: c_int,
+    msgp: *const c_void,
+    msgsz: size_t,
+    msgflg: c_int,
+) -> c_int {
+    pthread::cancellation_point(|| Sys::msgsnd(msqid, msgp, msgsz, msgflg))
+}
@@ -14,7 +14,8 @@ use crate::{
         },
         sys_time::timeval,
     },
-    platform::{self, types::*},
+    platform::{self, types::*, Pal, Sys},
+    pthread,
 };
 
 // fd_set is also defined in C because cbindgen is incompatible with mem::size_of booo
@@ -121,11 +122,19 @@ pub fn select_epoll(
             None => -1,
         }
     };
-    let res = epoll_wait(
-        *ep,
-        events.as_mut_ptr(),
-        events.len() as c_int,
-        epoll_timeout,
+    // Only the wait is a cancellation point, and the epoll file is closed if it is cancelled
+    let res = pthread::cancellation_point_cleanup(
+        || {
+            epoll_wait(
+                *ep,
+                events.as_mut_ptr(),
+                events.len() as c_int,
+                epoll_timeout,
+            )
+        },
+        || {
+            Sys::close(*ep);
+        },
     );
     if res < 0 {
         return -1;
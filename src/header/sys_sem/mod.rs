@@ -0,0 +1,71 @@
+//! sys/sem.h implementation for relibc, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_sem.h.html
+
+use core::ptr;
+
+use crate::{
+    header::{sys_ipc::ipc_perm, time::timespec},
+    platform::{types::*, PalIpc, Sys},
+};
+
+pub const SEM_UNDO: c_short = 0x1000;
+
+pub const GETPID: c_int = 11;
+pub const GETVAL: c_int = 12;
+pub const GETALL: c_int = 13;
+pub const GETNCNT: c_int = 14;
+pub const GETZCNT: c_int = 15;
+pub const SETVAL: c_int = 16;
+pub const SETALL: c_int = 17;
+
+#[repr(C)]
+pub struct sembuf {
+    pub sem_num: c_ushort,
+    pub sem_op: c_short,
+    pub sem_flg: c_short,
+}
+
+#[repr(C)]
+pub struct semid_ds {
+    pub sem_perm: ipc_perm,
+    pub sem_otime: time_t,
+    #[cfg(target_arch = "x86_64")]
+    pub __unused1: c_ulong,
+    pub sem_ctime: time_t,
+    #[cfg(target_arch = "x86_64")]
+    pub __unused2: c_ulong,
+    pub sem_nsems: c_ulong,
+    pub __unused3: c_ulong,
+    pub __unused4: c_ulong,
+}
+
+/// The part of `semctl` after the variadic argument, which src/c/sys_sem.c reads. `arg` is the
+/// `val` or the pointer out of `union semun`, depending on `cmd`.
+#[no_mangle]
+pub unsafe extern "C" fn sys_semctl(
+    semid: c_int,
+    semnum: c_int,
+    cmd: c_int,
+    arg: c_ulong,
+) -> c_int {
+    Sys::semctl(semid, semnum, cmd, arg)
+}
+
+#[no_mangle]
+pub extern "C" fn semget(key: key_t, nsems: c_int, semflg: c_int) -> c_int {
+    Sys::semget(key, nsems, semflg)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn semop(semid: c_int, sops: *mut sembuf, nsops: size_t) -> c_int {
+    Sys::semtimedop(semid, sops, nsops, ptr::null())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn semtimedop(
+    semid: c_int,
+    sops: *mut sembuf,
+    nsops: size_t,
+    timeout: *const timespec,
+) -> c_int {
+    Sys::semtimedop(semid, sops, nsops, timeout)
+}
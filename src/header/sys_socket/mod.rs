@@ -2,7 +2,10 @@
 
 use core::ptr;
 
-use crate::platform::{types::*, PalSocket, Sys};
+use crate::{
+    platform::{types::*, PalSocket, Sys},
+    pthread,
+};
 
 pub mod constants;
 
@@ -23,7 +26,7 @@ pub unsafe extern "C" fn accept(
     address_len: *mut socklen_t,
 ) -> c_int {
     trace_expr!(
-        Sys::accept(socket, address, address_len),
+        pthread::cancellation_point(|| Sys::accept(socket, address, address_len)),
         "accept({}, {:p}, {:p})",
         socket,
         address,
@@ -53,7 +56,7 @@ pub unsafe extern "C" fn connect(
     address_len: socklen_t,
 ) -> c_int {
     trace_expr!(
-        Sys::connect(socket, address, address_len),
+        pthread::cancellation_point(|| Sys::connect(socket, address, address_len)),
         "connect({}, {:p}, {})",
         socket,
         address,
@@ -142,7 +145,14 @@ pub unsafe extern "C" fn recvfrom(
     address_len: *mut socklen_t,
 ) -> ssize_t {
     trace_expr!(
-        Sys::recvfrom(socket, buffer, length, flags, address, address_len),
+        pthread::cancellation_point(|| Sys::recvfrom(
+            socket,
+            buffer,
+            length,
+            flags,
+            address,
+            address_len
+        )),
         "recvfrom({}, {:p}, {}, {:#x}, {:p}, {:p})",
         socket,
         buffer,
@@ -173,7 +183,9 @@ pub unsafe extern "C" fn sendto(
     dest_len: socklen_t,
 ) -> ssize_t {
     trace_expr!(
-        Sys::sendto(socket, message, length, flags, dest_addr, dest_len),
+        pthread::cancellation_point(|| Sys::sendto(
+            socket, message, length, flags, dest_addr, dest_len
+        )),
         "sendto({}, {:p}, {}, {:#x}, {:p}, {})",
         socket,
         message,
@@ -1,2 +1,85 @@
 //! sys/types.h
 use crate::platform::types::*;
+
+// The pthread types are opaque to C code. Their layouts live in the pthread module, which only
+// relies on these unions being large and aligned enough.
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_attr_t {
+    __relibc_internal_size: [c_uchar; 32],
+    __relibc_internal_align: c_long,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_barrier_t {
+    __relibc_internal_size: [c_uchar; 12],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_barrierattr_t {
+    __relibc_internal_size: [c_uchar; 4],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_cond_t {
+    __relibc_internal_size: [c_uchar; 8],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_condattr_t {
+    __relibc_internal_size: [c_uchar; 8],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_mutex_t {
+    __relibc_internal_size: [c_uchar; 12],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_mutexattr_t {
+    __relibc_internal_size: [c_uchar; 20],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_once_t {
+    __relibc_internal_size: [c_uchar; 4],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_rwlock_t {
+    __relibc_internal_size: [c_uchar; 4],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_rwlockattr_t {
+    __relibc_internal_size: [c_uchar; 4],
+    __relibc_internal_align: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union pthread_spinlock_t {
+    __relibc_internal_size: [c_uchar; 4],
+    __relibc_internal_align: c_int,
+}
+
+pub type pthread_key_t = c_ulong;
+pub type pthread_t = *mut c_void;
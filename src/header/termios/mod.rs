@@ -3,6 +3,7 @@
 use crate::{
     header::{errno, sys_ioctl},
     platform::{self, types::*},
+    pthread,
 };
 
 pub use self::sys::*;
@@ -152,7 +153,7 @@ pub unsafe extern "C" fn tcflush(fd: c_int, queue: c_int) -> c_int {
 
 #[no_mangle]
 pub unsafe extern "C" fn tcdrain(fd: c_int) -> c_int {
-    sys_ioctl::ioctl(fd, sys_ioctl::TCSBRK, 1 as *mut _)
+    pthread::cancellation_point(|| sys_ioctl::ioctl(fd, sys_ioctl::TCSBRK, 1 as *mut _))
 }
 
 #[no_mangle]
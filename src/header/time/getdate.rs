@@ -0,0 +1,185 @@
+//! getdate implementation, following
+//! https://pubs.opengroup.org/onlinepubs/9699919799/functions/getdate.html
+
+use alloc::vec::Vec;
+use core::ptr;
+
+use crate::{
+    c_str::{CStr, CString},
+    fs::File,
+    header::{
+        fcntl, locale,
+        stdlib::getenv,
+        sys_stat::{stat, S_IFMT, S_IFREG},
+    },
+    io::Read,
+    platform::{types::*, Pal, Sys},
+};
+
+use super::{days_from_civil, localtime_r, mktime, strptime, time, tm};
+
+/// The values of getdate_err.
+const DATEMSK_UNSET: c_int = 1;
+const DATEMSK_OPEN: c_int = 2;
+const DATEMSK_STAT: c_int = 3;
+const DATEMSK_NOT_REGULAR: c_int = 4;
+const DATEMSK_READ: c_int = 5;
+const NO_MATCH: c_int = 7;
+const INVALID_DATE: c_int = 8;
+
+/// Marks the fields that no conversion has set.
+const UNSET: c_int = c_int::min_value();
+
+/// Parses `string` with the first template in the DATEMSK file that matches all of it, filling
+/// in what the template leaves out from the current time.
+pub unsafe fn getdate(string: *const c_char) -> Result<tm, c_int> {
+    let templates = read_templates()?;
+    let time_data = locale::current().time();
+
+    for template in templates.split(|&b| b == b'\n') {
+        let template = match CString::new(template) {
+            Ok(template) if !template.to_bytes().is_empty() => template,
+            _ => continue,
+        };
+
+        let mut parsed = unset();
+        let end = strptime::strptime(string, template.as_ptr(), &mut parsed, time_data);
+        if !end.is_null() && *end == 0 {
+            return complete(parsed);
+        }
+    }
+    Err(NO_MATCH)
+}
+
+fn unset() -> tm {
+    tm {
+        tm_sec: UNSET,
+        tm_min: UNSET,
+        tm_hour: UNSET,
+        tm_mday: UNSET,
+        tm_mon: UNSET,
+        tm_year: UNSET,
+        tm_wday: UNSET,
+        tm_yday: UNSET,
+        tm_isdst: -1,
+        tm_gmtoff: 0,
+        tm_zone: ptr::null(),
+    }
+}
+
+unsafe fn read_templates() -> Result<Vec<u8>, c_int> {
+    let path = getenv(c_str!("DATEMSK").as_ptr());
+    if path.is_null() || *path == 0 {
+        return Err(DATEMSK_UNSET);
+    }
+
+    let mut file = File::open(CStr::from_ptr(path), fcntl::O_RDONLY | fcntl::O_CLOEXEC)
+        .map_err(|_| DATEMSK_OPEN)?;
+
+    let mut st = stat::default();
+    if Sys::fstat(file.fd, &mut st) < 0 {
+        return Err(DATEMSK_STAT);
+    }
+    if st.st_mode & S_IFMT != S_IFREG {
+        return Err(DATEMSK_NOT_REGULAR);
+    }
+
+    let mut templates = Vec::new();
+    file.read_to_end(&mut templates).map_err(|_| DATEMSK_READ)?;
+    Ok(templates)
+}
+
+/// Fills in the fields the template did not set, preferring dates in the future.
+unsafe fn complete(mut t: tm) -> Result<tm, c_int> {
+    let now = time(ptr::null_mut());
+    let mut current = unset();
+    if localtime_r(&now, &mut current).is_null() {
+        return Err(INVALID_DATE);
+    }
+
+    // Whether the day of the month was worked out here, rather than taken from the input
+    let mut mday_ok = false;
+
+    // Only a weekday means today if it is that day, or else the next one
+    if t.tm_wday != UNSET && t.tm_year == UNSET && t.tm_mon == UNSET && t.tm_mday == UNSET {
+        t.tm_year = current.tm_year;
+        t.tm_mon = current.tm_mon;
+        t.tm_mday = current.tm_mday + (t.tm_wday - current.tm_wday + 7) % 7;
+        mday_ok = true;
+    }
+
+    // Only a year means its first month
+    if t.tm_year != UNSET && t.tm_mon == UNSET && t.tm_mday == UNSET {
+        t.tm_mon = 0;
+    }
+
+    // A month without a day means its first day, or its first of the given weekday. Months
+    // that have passed are next year's if no year is given.
+    if t.tm_mon != UNSET && t.tm_mday == UNSET {
+        if t.tm_year == UNSET {
+            t.tm_year = current.tm_year + (t.tm_mon < current.tm_mon) as c_int;
+        }
+        t.tm_mday = 1;
+        if t.tm_wday != UNSET {
+            let first = days_from_civil(
+                time_t::from(t.tm_year) + 1900,
+                time_t::from(t.tm_mon) + 1,
+                1,
+            );
+            // 1970-01-01 was a Thursday
+            let first_wday = (first + 4).rem_euclid(7) as c_int;
+            t.tm_mday += (t.tm_wday - first_wday + 7) % 7;
+        }
+        mday_ok = true;
+    }
+
+    // Without any time the current one is used, otherwise what is missing is zero
+    if t.tm_hour == UNSET && t.tm_min == UNSET && t.tm_sec == UNSET {
+        t.tm_hour = current.tm_hour;
+        t.tm_min = current.tm_min;
+        t.tm_sec = current.tm_sec;
+    }
+    for field in [&mut t.tm_hour, &mut t.tm_min, &mut t.tm_sec].iter_mut() {
+        if **field == UNSET {
+            **field = 0;
+        }
+    }
+
+    // Without any date, today is used if the hour has yet to come and tomorrow otherwise
+    if t.tm_year == UNSET && t.tm_mon == UNSET && t.tm_mday == UNSET {
+        t.tm_year = current.tm_year;
+        t.tm_mon = current.tm_mon;
+        t.tm_mday = current.tm_mday + (t.tm_hour < current.tm_hour) as c_int;
+        mday_ok = true;
+    }
+
+    if t.tm_year == UNSET {
+        t.tm_year = current.tm_year;
+    }
+    if t.tm_mon == UNSET {
+        t.tm_mon = current.tm_mon;
+    }
+    if t.tm_mday == UNSET {
+        t.tm_mday = current.tm_mday;
+    }
+
+    // Days the month does not have are not carried over into the next one
+    if !mday_ok {
+        let year = time_t::from(t.tm_year) + 1900;
+        let month = time_t::from(t.tm_mon) + 1;
+        let next = if month == 12 {
+            days_from_civil(year + 1, 1, 1)
+        } else {
+            days_from_civil(year, month + 1, 1)
+        };
+        if time_t::from(t.tm_mday) > next - days_from_civil(year, month, 1) {
+            return Err(INVALID_DATE);
+        }
+    }
+
+    t.tm_isdst = -1;
+    if mktime(&mut t) == -1 {
+        return Err(INVALID_DATE);
+    }
+    Ok(t)
+}
@@ -1,16 +1,27 @@
 //! time implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/time.h.html
 
-use core::convert::{TryFrom, TryInto};
+use core::{
+    convert::{TryFrom, TryInto},
+    ptr,
+};
 
 use crate::{
-    header::errno::{EIO, EOVERFLOW},
-    platform::{self, types::*, Pal, Sys},
+    header::{
+        errno::{EINVAL, EIO, EOVERFLOW},
+        locale::{self, locale_t},
+        signal::sigevent,
+    },
+    platform::{self, types::*, Pal, PalSignal, Sys},
+    pthread,
 };
 
 pub use self::constants::*;
 
 pub mod constants;
+mod getdate;
 mod strftime;
+mod strptime;
+mod tz;
 
 #[repr(C)]
 #[derive(Default)]
@@ -61,17 +72,25 @@ static mut TM: tm = tm {
     tm_zone: UTC,
 };
 
+#[no_mangle]
+pub static mut timezone: c_long = 0;
+
+#[no_mangle]
+pub static mut daylight: c_int = 0;
+
+#[no_mangle]
+pub static mut tzname: [*mut c_char; 2] = [UTC as *mut c_char, UTC as *mut c_char];
+
 // The C Standard says that ctime and asctime return the same pointer.
 static mut ASCTIME: [c_char; 26] = [0; 26];
 
 #[repr(C)]
+#[derive(Default)]
 pub struct itimerspec {
     pub it_interval: timespec,
     pub it_value: timespec,
 }
 
-pub struct sigevent;
-
 #[no_mangle]
 pub unsafe extern "C" fn asctime(timeptr: *const tm) -> *mut c_char {
     asctime_r(timeptr, ASCTIME.as_mut_ptr().cast())
@@ -217,9 +236,35 @@ pub extern "C" fn difftime(time1: time_t, time0: time_t) -> c_double {
     (time1 - time0) as c_double
 }
 
-// #[no_mangle]
-pub extern "C" fn getdate(string: *const c_char) -> tm {
-    unimplemented!();
+#[no_mangle]
+pub static mut getdate_err: c_int = 0;
+
+static mut GETDATE_TM: tm = tm {
+    tm_sec: 0,
+    tm_min: 0,
+    tm_hour: 0,
+    tm_mday: 0,
+    tm_mon: 0,
+    tm_year: 0,
+    tm_wday: 0,
+    tm_yday: 0,
+    tm_isdst: 0,
+    tm_gmtoff: 0,
+    tm_zone: UTC,
+};
+
+#[no_mangle]
+pub unsafe extern "C" fn getdate(string: *const c_char) -> *mut tm {
+    match getdate::getdate(string) {
+        Ok(result) => {
+            GETDATE_TM = result;
+            &mut GETDATE_TM
+        }
+        Err(err) => {
+            getdate_err = err;
+            ptr::null_mut()
+        }
+    }
 }
 
 #[no_mangle]
@@ -227,16 +272,28 @@ pub unsafe extern "C" fn gmtime(timer: *const time_t) -> *mut tm {
     gmtime_r(timer, &mut TM)
 }
 
-const MONTH_DAYS: [[c_int; 12]; 2] = [
-    // Non-leap years:
-    [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
-    // Leap years:
-    [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
-];
+/// Days since the epoch of the given date, following
+/// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: time_t, month: time_t, day: time_t) -> time_t {
+    // Years start on March 1 here, so that leap days come last
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
 
-#[inline(always)]
-fn leap_year(year: c_int) -> bool {
-    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+/// The fields of `tm` in seconds since the epoch, as if they were UTC. Fields outside of their
+/// usual ranges carry over into the next larger ones.
+fn civil_seconds(tm: &tm) -> time_t {
+    let month = time_t::from(tm.tm_mon);
+    let year = time_t::from(tm.tm_year) + 1900 + month.div_euclid(12);
+    let days = days_from_civil(year, month.rem_euclid(12) + 1, 1) + time_t::from(tm.tm_mday) - 1;
+    days * 24 * 60 * 60
+        + time_t::from(tm.tm_hour) * 60 * 60
+        + time_t::from(tm.tm_min) * 60
+        + time_t::from(tm.tm_sec)
 }
 
 #[no_mangle]
@@ -344,54 +401,37 @@ pub unsafe extern "C" fn localtime(clock: *const time_t) -> *mut tm {
 
 #[no_mangle]
 pub unsafe extern "C" fn localtime_r(clock: *const time_t, t: *mut tm) -> *mut tm {
-    // TODO: Change tm_isdst, tm_gmtoff, tm_zone
-    gmtime_r(clock, t)
+    let local_type = tz::with_zone(|zone| zone.local_type(*clock));
+    let local = match (*clock).checked_add(local_type.utoff as time_t) {
+        Some(local) => local,
+        None => {
+            platform::errno = EOVERFLOW;
+            return ptr::null_mut();
+        }
+    };
+    if gmtime_r(&local, t).is_null() {
+        return ptr::null_mut();
+    }
+
+    (*t).tm_isdst = local_type.isdst as c_int;
+    (*t).tm_gmtoff = local_type.utoff;
+    (*t).tm_zone = local_type.name.as_ptr();
+    t
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn mktime(t: *mut tm) -> time_t {
-    let mut year = (*t).tm_year + 1900;
-    let mut month = (*t).tm_mon;
-    let mut day = (*t).tm_mday as i64 - 1;
-
-    let leap = if leap_year(year) { 1 } else { 0 };
-
-    if year < 1970 {
-        day = MONTH_DAYS[if leap_year(year) { 1 } else { 0 }][(*t).tm_mon as usize] as i64 - day;
-
-        while year < 1969 {
-            year += 1;
-            day += if leap_year(year) { 366 } else { 365 };
-        }
-
-        while month < 11 {
-            month += 1;
-            day += MONTH_DAYS[leap][month as usize] as i64;
-        }
-
-        -(day * (60 * 60 * 24)
-            - (((*t).tm_hour as i64) * (60 * 60) + ((*t).tm_min as i64) * 60 + (*t).tm_sec as i64))
-    } else {
-        while year > 1970 {
-            year -= 1;
-            day += if leap_year(year) { 366 } else { 365 };
-        }
-
-        while month > 0 {
-            month -= 1;
-            day += MONTH_DAYS[leap][month as usize] as i64;
-        }
-
-        (day * (60 * 60 * 24)
-            + ((*t).tm_hour as i64) * (60 * 60)
-            + ((*t).tm_min as i64) * 60
-            + (*t).tm_sec as i64)
+    let local = civil_seconds(&*t);
+    let time = tz::with_zone(|zone| zone.local_to_utc(local, (*t).tm_isdst));
+    if localtime_r(&time, t).is_null() {
+        return -1;
     }
+    time
 }
 
 #[no_mangle]
 pub extern "C" fn nanosleep(rqtp: *const timespec, rmtp: *mut timespec) -> c_int {
-    Sys::nanosleep(rqtp, rmtp)
+    pthread::cancellation_point(|| Sys::nanosleep(rqtp, rmtp))
 }
 
 #[no_mangle]
@@ -400,11 +440,29 @@ pub unsafe extern "C" fn strftime(
     maxsize: size_t,
     format: *const c_char,
     timeptr: *const tm,
+) -> size_t {
+    strftime_l(
+        s,
+        maxsize,
+        format,
+        timeptr,
+        locale::uselocale(ptr::null_mut()),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strftime_l(
+    s: *mut c_char,
+    maxsize: size_t,
+    format: *const c_char,
+    timeptr: *const tm,
+    locale: locale_t,
 ) -> size_t {
     let ret = strftime::strftime(
         &mut platform::StringWriter(s as *mut u8, maxsize),
         format,
         timeptr,
+        locale::get(locale).time(),
     );
     if ret < maxsize {
         ret
@@ -413,9 +471,13 @@ pub unsafe extern "C" fn strftime(
     }
 }
 
-// #[no_mangle]
-pub extern "C" fn strptime(buf: *const c_char, format: *const c_char, tm: *mut tm) -> *mut c_char {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn strptime(
+    buf: *const c_char,
+    format: *const c_char,
+    tm: *mut tm,
+) -> *mut c_char {
+    strptime::strptime(buf, format, tm, locale::current().time())
 }
 
 #[no_mangle]
@@ -430,65 +492,63 @@ pub unsafe extern "C" fn time(tloc: *mut time_t) -> time_t {
 
 #[no_mangle]
 pub unsafe extern "C" fn timelocal(tm: *mut tm) -> time_t {
-    //TODO: timezone
-    timegm(tm)
+    mktime(tm)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn timegm(tm: *mut tm) -> time_t {
-    let mut y = (*tm).tm_year as time_t + 1900;
-    let mut m = (*tm).tm_mon as time_t + 1;
-    if m <= 2 {
-        y -= 1;
-        m += 12;
+    let time = civil_seconds(&*tm);
+    if gmtime_r(&time, tm).is_null() {
+        return -1;
     }
-    let d = (*tm).tm_mday as time_t;
-    let h = (*tm).tm_hour as time_t;
-    let mi = (*tm).tm_min as time_t;
-    let s = (*tm).tm_sec as time_t;
-    (365 * y + y / 4 - y / 100 + y / 400 + 3 * (m + 1) / 5 + 30 * m + d - 719561) * 86400
-        + 3600 * h
-        + 60 * mi
-        + s
+    time
 }
 
-// #[no_mangle]
-pub extern "C" fn timer_create(
+#[no_mangle]
+pub unsafe extern "C" fn timer_create(
     clock_id: clockid_t,
     evp: *mut sigevent,
     timerid: *mut timer_t,
 ) -> c_int {
-    unimplemented!();
+    Sys::timer_create(clock_id, evp.as_ref(), timerid)
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn timer_delete(timerid: timer_t) -> c_int {
-    unimplemented!();
+    Sys::timer_delete(timerid)
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn tzset() {
-    unimplemented!();
+    tz::with_zone(|_| ());
 }
 
-// #[no_mangle]
-pub extern "C" fn timer_settime(
+#[no_mangle]
+pub unsafe extern "C" fn timer_settime(
     timerid: timer_t,
     flags: c_int,
     value: *const itimerspec,
     ovalue: *mut itimerspec,
 ) -> c_int {
-    unimplemented!();
+    let valid = |ts: &timespec| ts.tv_sec >= 0 && ts.tv_nsec >= 0 && ts.tv_nsec < 1_000_000_000;
+    let value_valid = value.as_ref().map_or(false, |value| {
+        valid(&value.it_value) && valid(&value.it_interval)
+    });
+    if !value_valid {
+        platform::errno = EINVAL;
+        return -1;
+    }
+    Sys::timer_settime(timerid, flags, value, ovalue)
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn timer_gettime(timerid: timer_t, value: *mut itimerspec) -> c_int {
-    unimplemented!();
+    Sys::timer_gettime(timerid, value)
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn timer_getoverrun(timerid: timer_t) -> c_int {
-    unimplemented!();
+    Sys::timer_getoverrun(timerid)
 }
 
 /*
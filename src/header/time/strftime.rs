@@ -1,14 +1,25 @@
 use alloc::string::String;
+use core::ptr;
 
-use crate::platform::{self, types::*, WriteByte};
+use crate::{
+    c_str::CStr,
+    header::locale::data::TimeData,
+    platform::{self, types::*, WriteByte},
+};
 
 use super::tm;
 
-pub unsafe fn strftime<W: WriteByte>(w: &mut W, format: *const c_char, t: *const tm) -> size_t {
+pub unsafe fn strftime<W: WriteByte>(
+    w: &mut W,
+    format: *const c_char,
+    t: *const tm,
+    time: &TimeData,
+) -> size_t {
     pub unsafe fn inner_strftime<W: WriteByte>(
         w: &mut W,
         mut format: *const c_char,
         t: *const tm,
+        time: &TimeData,
     ) -> bool {
         macro_rules! w {
             (byte $b:expr) => {{
@@ -26,10 +37,20 @@ pub unsafe fn strftime<W: WriteByte>(w: &mut W, format: *const c_char, t: *const
                 fmt.push_str($fmt);
                 fmt.push('\0');
 
-                if !inner_strftime(w, fmt.as_ptr() as *mut c_char, t) {
+                if !inner_strftime(w, fmt.as_ptr() as *mut c_char, t, time) {
                     return false;
                 }
             }};
+            (recurse_locale $fmt:expr) => {{
+                if !inner_strftime(w, $fmt.as_ptr(), t, time) {
+                    return false;
+                }
+            }};
+            (locale $str:expr) => {{
+                for &b in $str.to_bytes() {
+                    w!(byte b);
+                }
+            }};
             ($str:expr) => {{
                 if w.write_str($str).is_err() {
                     return false;
@@ -42,29 +63,6 @@ pub unsafe fn strftime<W: WriteByte>(w: &mut W, format: *const c_char, t: *const
                 }
             }};
         }
-        const WDAYS: [&str; 7] = [
-            "Sunday",
-            "Monday",
-            "Tuesday",
-            "Wednesday",
-            "Thursday",
-            "Friday",
-            "Saturday",
-        ];
-        const MONTHS: [&str; 12] = [
-            "January",
-            "Febuary",
-            "March",
-            "April",
-            "May",
-            "June",
-            "July",
-            "August",
-            "September",
-            "October",
-            "November",
-            "December",
-        ];
 
         while *format != 0 {
             if *format as u8 != b'%' {
@@ -76,7 +74,7 @@ pub unsafe fn strftime<W: WriteByte>(w: &mut W, format: *const c_char, t: *const
             format = format.offset(1);
 
             if *format as u8 == b'E' || *format as u8 == b'O' {
-                // Ignore because these do nothing without locale
+                // Locales have no alternative eras or digits, so use the plain conversions
                 format = format.offset(1);
             }
 
@@ -84,45 +82,56 @@ pub unsafe fn strftime<W: WriteByte>(w: &mut W, format: *const c_char, t: *const
                 b'%' => w!(byte b'%'),
                 b'n' => w!(byte b'\n'),
                 b't' => w!(byte b'\t'),
-                b'a' => w!(&WDAYS[(*t).tm_wday as usize][..3]),
-                b'A' => w!(WDAYS[(*t).tm_wday as usize]),
-                b'b' | b'h' => w!(&MONTHS[(*t).tm_mon as usize][..3]),
-                b'B' => w!(MONTHS[(*t).tm_mon as usize]),
-                b'C' => {
-                    let mut year = (*t).tm_year / 100;
-                    // Round up
-                    if (*t).tm_year % 100 != 0 {
-                        year += 1;
-                    }
-                    w!("{:02}", year + 19);
-                }
+                b'a' => w!(locale time.abday[(*t).tm_wday as usize]),
+                b'A' => w!(locale time.day[(*t).tm_wday as usize]),
+                b'b' | b'h' => w!(locale time.abmon[(*t).tm_mon as usize]),
+                b'B' => w!(locale time.mon[(*t).tm_mon as usize]),
+                b'c' => w!(recurse_locale time.d_t_fmt),
+                b'C' => w!("{:02}", ((*t).tm_year + 1900).div_euclid(100)),
                 b'd' => w!("{:02}", (*t).tm_mday),
                 b'D' => w!(recurse "%m/%d/%y"),
                 b'e' => w!("{:2}", (*t).tm_mday),
                 b'F' => w!(recurse "%Y-%m-%d"),
                 b'H' => w!("{:02}", (*t).tm_hour),
                 b'I' => w!("{:02}", ((*t).tm_hour + 12 - 1) % 12 + 1),
-                b'j' => w!("{:03}", (*t).tm_yday),
+                b'j' => w!("{:03}", (*t).tm_yday + 1),
                 b'k' => w!("{:2}", (*t).tm_hour),
                 b'l' => w!("{:2}", ((*t).tm_hour + 12 - 1) % 12 + 1),
                 b'm' => w!("{:02}", (*t).tm_mon + 1),
                 b'M' => w!("{:02}", (*t).tm_min),
-                b'p' => w!(if (*t).tm_hour < 12 { "AM" } else { "PM" }),
-                b'P' => w!(if (*t).tm_hour < 12 { "am" } else { "pm" }),
-                b'r' => w!(recurse "%I:%M:%S %p"),
+                b'p' => w!(locale time.am_pm[((*t).tm_hour >= 12) as usize]),
+                b'P' => {
+                    for &b in time.am_pm[((*t).tm_hour >= 12) as usize].to_bytes() {
+                        w!(byte b.to_ascii_lowercase());
+                    }
+                }
+                // Locales without a 12-hour clock leave this empty
+                b'r' if time.t_fmt_ampm.to_bytes().is_empty() => w!(recurse "%I:%M:%S %p"),
+                b'r' => w!(recurse_locale time.t_fmt_ampm),
                 b'R' => w!(recurse "%H:%M"),
-                // Nothing is modified in mktime, but the C standard of course requires a mutable pointer ._.
-                b's' => w!("{}", super::mktime(t as *mut tm)),
+                // mktime normalizes the fields it is given, so give it a copy
+                b's' => w!("{}", super::mktime(&mut ptr::read(t))),
                 b'S' => w!("{:02}", (*t).tm_sec),
                 b'T' => w!(recurse "%H:%M:%S"),
                 b'u' => w!("{}", ((*t).tm_wday + 7 - 1) % 7 + 1),
                 b'U' => w!("{}", ((*t).tm_yday + 7 - (*t).tm_wday) / 7),
                 b'w' => w!("{}", (*t).tm_wday),
                 b'W' => w!("{}", ((*t).tm_yday + 7 - ((*t).tm_wday + 6) % 7) / 7),
+                b'x' => w!(recurse_locale time.d_fmt),
+                b'X' => w!(recurse_locale time.t_fmt),
                 b'y' => w!("{:02}", (*t).tm_year % 100),
                 b'Y' => w!("{}", (*t).tm_year + 1900),
-                b'z' => w!("+0000"), // TODO
-                b'Z' => w!("UTC"),   // TODO
+                b'z' => {
+                    let offset = (*t).tm_gmtoff;
+                    let sign = if offset < 0 { '-' } else { '+' };
+                    let offset = offset.abs() / 60;
+                    w!("{}{:02}{:02}", sign, offset / 60, offset % 60);
+                }
+                b'Z' => {
+                    if !(*t).tm_zone.is_null() {
+                        w!(locale CStr::from_ptr((*t).tm_zone));
+                    }
+                }
                 b'+' => w!(recurse "%a %b %d %T %Z %Y"),
                 _ => return false,
             }
@@ -133,7 +142,7 @@ pub unsafe fn strftime<W: WriteByte>(w: &mut W, format: *const c_char, t: *const
     }
 
     let mut w = platform::CountingWriter::new(w);
-    if !inner_strftime(&mut w, format, t) {
+    if !inner_strftime(&mut w, format, t, time) {
         return 0;
     }
 
@@ -0,0 +1,387 @@
+//! strptime implementation, the inverse of strftime, following
+//! https://pubs.opengroup.org/onlinepubs/9699919799/functions/strptime.html
+
+use core::ptr;
+
+use crate::{c_str::CStr, header::locale::data::TimeData, platform::types::*};
+
+use super::{days_from_civil, gmtime_r, localtime_r, tm};
+
+/// What the conversions have said about the date, which is only put together at the end as the
+/// fields can come in any order.
+#[derive(Default)]
+struct Fields {
+    year: Option<c_int>,
+    century: Option<c_int>,
+    year_of_century: Option<c_int>,
+    pm: Option<bool>,
+    hour12: bool,
+    /// The week number, and whether weeks start on Monday.
+    week: Option<(c_int, bool)>,
+    have_mon: bool,
+    have_mday: bool,
+    have_wday: bool,
+    have_yday: bool,
+    /// `%s` sets everything at once.
+    epoch: bool,
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    tm: &'a mut tm,
+    time: &'a TimeData,
+    fields: Fields,
+}
+
+/// Parses `input` according to `format`, returning a pointer to the first character that was
+/// not consumed, or null if the input does not match.
+pub unsafe fn strptime(
+    input: *const c_char,
+    format: *const c_char,
+    tm: *mut tm,
+    time: &TimeData,
+) -> *mut c_char {
+    let input = CStr::from_ptr(input).to_bytes();
+    let mut parser = Parser {
+        input,
+        tm: &mut *tm,
+        time,
+        fields: Fields::default(),
+    };
+    if parser.parse(CStr::from_ptr(format).to_bytes()).is_none() {
+        return ptr::null_mut();
+    }
+    parser.finish();
+
+    let consumed = input.len() - parser.input.len();
+    input.as_ptr().add(consumed) as *mut c_char
+}
+
+impl<'a> Parser<'a> {
+    fn parse(&mut self, mut format: &[u8]) -> Option<()> {
+        while let Some((&c, rest)) = format.split_first() {
+            format = rest;
+
+            // Whitespace in the format matches any amount of whitespace, including none
+            if is_space(c) {
+                self.skip_space();
+                continue;
+            }
+            if c != b'%' {
+                self.expect(c)?;
+                continue;
+            }
+
+            let (mut conversion, rest) = format.split_first()?;
+            format = rest;
+            if *conversion == b'E' || *conversion == b'O' {
+                // Without alternative eras or digits in any locale, these parse like the plain ones
+                let (next, rest) = format.split_first()?;
+                conversion = next;
+                format = rest;
+            }
+            self.conversion(*conversion)?;
+        }
+        Some(())
+    }
+
+    fn conversion(&mut self, conversion: u8) -> Option<()> {
+        let time = self.time;
+        match conversion {
+            b'%' => self.expect(b'%')?,
+            b'n' | b't' => self.skip_space(),
+            b'a' | b'A' => {
+                self.tm.tm_wday = self.name(&time.day, &time.abday)? as c_int;
+                self.fields.have_wday = true;
+            }
+            b'b' | b'B' | b'h' => {
+                self.tm.tm_mon = self.name(&time.mon, &time.abmon)? as c_int;
+                self.fields.have_mon = true;
+            }
+            b'c' => self.parse(time.d_t_fmt.to_bytes())?,
+            b'C' => self.fields.century = Some(self.number(2, 0, 99)?),
+            b'd' | b'e' => {
+                self.tm.tm_mday = self.number(2, 1, 31)?;
+                self.fields.have_mday = true;
+            }
+            b'D' => self.parse(b"%m/%d/%y")?,
+            b'F' => self.parse(b"%Y-%m-%d")?,
+            b'H' | b'k' => {
+                self.tm.tm_hour = self.number(2, 0, 23)?;
+                self.fields.hour12 = false;
+            }
+            b'I' | b'l' => {
+                self.tm.tm_hour = self.number(2, 1, 12)? % 12;
+                self.fields.hour12 = true;
+            }
+            b'j' => {
+                self.tm.tm_yday = self.number(3, 1, 366)? - 1;
+                self.fields.have_yday = true;
+            }
+            b'm' => {
+                self.tm.tm_mon = self.number(2, 1, 12)? - 1;
+                self.fields.have_mon = true;
+            }
+            b'M' => self.tm.tm_min = self.number(2, 0, 59)?,
+            b'p' | b'P' => {
+                self.skip_space();
+                let len = |s: &CStr| s.to_bytes().len();
+                // Try the longer one first in case one is a prefix of the other
+                let pm_first = len(time.am_pm[1]) > len(time.am_pm[0]);
+                let order = if pm_first { [1, 0] } else { [0, 1] };
+                let matched = order
+                    .iter()
+                    .copied()
+                    .find(|&i| self.skip_name(time.am_pm[i].to_bytes()))?;
+                self.fields.pm = Some(matched == 1);
+            }
+            // Locales without a 12-hour clock leave this empty
+            b'r' if time.t_fmt_ampm.to_bytes().is_empty() => self.parse(b"%I:%M:%S %p")?,
+            b'r' => self.parse(time.t_fmt_ampm.to_bytes())?,
+            b'R' => self.parse(b"%H:%M")?,
+            b's' => {
+                let secs = self.signed_number()?;
+                if unsafe { localtime_r(&secs, self.tm) }.is_null() {
+                    return None;
+                }
+                self.fields = Fields {
+                    epoch: true,
+                    ..Fields::default()
+                };
+            }
+            b'S' => self.tm.tm_sec = self.number(2, 0, 61)?,
+            b'T' => self.parse(b"%H:%M:%S")?,
+            b'u' => {
+                self.tm.tm_wday = self.number(1, 1, 7)? % 7;
+                self.fields.have_wday = true;
+            }
+            b'U' => self.fields.week = Some((self.number(2, 0, 53)?, false)),
+            b'w' => {
+                self.tm.tm_wday = self.number(1, 0, 6)?;
+                self.fields.have_wday = true;
+            }
+            b'W' => self.fields.week = Some((self.number(2, 0, 53)?, true)),
+            b'x' => self.parse(time.d_fmt.to_bytes())?,
+            b'X' => self.parse(time.t_fmt.to_bytes())?,
+            b'y' => self.fields.year_of_century = Some(self.number(2, 0, 99)?),
+            b'Y' => self.fields.year = Some(self.number(4, 0, 9999)?),
+            b'z' => self.tm.tm_gmtoff = self.utc_offset()?,
+            b'Z' => {
+                // Zone names are ambiguous, so they are only skipped
+                let len = self
+                    .input
+                    .iter()
+                    .take_while(|&&b| b != 0 && !is_space(b))
+                    .count();
+                self.input = &self.input[len..];
+            }
+            b'+' => self.parse(b"%a %b %d %T %Z %Y")?,
+            _ => return None,
+        }
+        Some(())
+    }
+
+    /// Fills in the fields that follow from the ones that were parsed.
+    fn finish(&mut self) {
+        let fields = &self.fields;
+        if fields.epoch {
+            return;
+        }
+
+        if fields.hour12 && fields.pm == Some(true) {
+            self.tm.tm_hour += 12;
+        }
+
+        let year = match (fields.year, fields.century, fields.year_of_century) {
+            (Some(year), _, _) => Some(year),
+            (None, Some(century), Some(year)) => Some(century * 100 + year),
+            (None, Some(century), None) => Some(century * 100),
+            // POSIX says 69 to 99 are in the 20th century, the rest in the 21st
+            (None, None, Some(year)) if year >= 69 => Some(1900 + year),
+            (None, None, Some(year)) => Some(2000 + year),
+            (None, None, None) => None,
+        };
+        if let Some(year) = year {
+            self.tm.tm_year = year - 1900;
+        }
+
+        let have_date = fields.have_mon && fields.have_mday;
+        let mut have_yday = fields.have_yday;
+        let year = time_t::from(self.tm.tm_year) + 1900;
+        let jan1 = days_from_civil(year, 1, 1);
+
+        // The day of the year can come from a week number and a weekday
+        if let (Some((week, monday_first)), true, false, false) =
+            (fields.week, fields.have_wday, have_yday, have_date)
+        {
+            let week_start = if monday_first { 1 } else { 0 };
+            let jan1_wday = (jan1 + 4).rem_euclid(7) as c_int;
+            let first_week = (7 + week_start - jan1_wday) % 7;
+            self.tm.tm_yday = first_week + (week - 1) * 7 + (self.tm.tm_wday + 7 - week_start) % 7;
+            have_yday = true;
+        }
+
+        let day = if have_date {
+            days_from_civil(
+                year,
+                time_t::from(self.tm.tm_mon) + 1,
+                time_t::from(self.tm.tm_mday),
+            )
+        } else if have_yday {
+            jan1 + time_t::from(self.tm.tm_yday)
+        } else {
+            return;
+        };
+
+        let mut date = tm {
+            tm_sec: 0,
+            tm_min: 0,
+            tm_hour: 0,
+            tm_mday: 0,
+            tm_mon: 0,
+            tm_year: 0,
+            tm_wday: 0,
+            tm_yday: 0,
+            tm_isdst: 0,
+            tm_gmtoff: 0,
+            tm_zone: ptr::null(),
+        };
+        if unsafe { gmtime_r(&(day * 24 * 60 * 60), &mut date) }.is_null() {
+            return;
+        }
+        if !have_date {
+            // Week 0 may start in the previous year
+            self.tm.tm_year = date.tm_year;
+            self.tm.tm_mon = date.tm_mon;
+            self.tm.tm_mday = date.tm_mday;
+        }
+        if !fields.have_wday {
+            self.tm.tm_wday = date.tm_wday;
+        }
+        self.tm.tm_yday = date.tm_yday;
+    }
+
+    fn skip_space(&mut self) {
+        let len = self.input.iter().take_while(|&&b| is_space(b)).count();
+        self.input = &self.input[len..];
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> {
+        let (&first, rest) = self.input.split_first()?;
+        if first != c {
+            return None;
+        }
+        self.input = rest;
+        Some(())
+    }
+
+    /// Reads a number of at most `digits` digits, after skipping whitespace.
+    fn number(&mut self, digits: usize, min: c_int, max: c_int) -> Option<c_int> {
+        self.skip_space();
+        let len = self
+            .input
+            .iter()
+            .take(digits)
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return None;
+        }
+        let value = self.input[..len]
+            .iter()
+            .fold(0, |value, &b| value * 10 + c_int::from(b - b'0'));
+        self.input = &self.input[len..];
+
+        if value >= min && value <= max {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    fn signed_number(&mut self) -> Option<time_t> {
+        self.skip_space();
+        let negative = self.input.first() == Some(&b'-');
+        if negative || self.input.first() == Some(&b'+') {
+            self.input = &self.input[1..];
+        }
+
+        let len = self.input.iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        let mut value: time_t = 0;
+        for &b in &self.input[..len] {
+            let digit = time_t::from(b - b'0');
+            value = value.checked_mul(10)?;
+            value = if negative {
+                value.checked_sub(digit)?
+            } else {
+                value.checked_add(digit)?
+            };
+        }
+        self.input = &self.input[len..];
+        Some(value)
+    }
+
+    /// `Z`, or `+hh`, `+hhmm` or `+hh:mm` with either sign, in seconds east of UTC.
+    fn utc_offset(&mut self) -> Option<c_long> {
+        self.skip_space();
+        if self.expect(b'Z').is_some() {
+            return Some(0);
+        }
+        let sign = match self.input.first()? {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        self.input = &self.input[1..];
+
+        let hours = self.fixed_number(2, 0, 24)?;
+        if self.input.first() == Some(&b':') {
+            self.input = &self.input[1..];
+        }
+        let minutes = match self.input.first() {
+            Some(b) if b.is_ascii_digit() => self.fixed_number(2, 0, 59)?,
+            _ => 0,
+        };
+        Some(sign * c_long::from(hours * 60 * 60 + minutes * 60))
+    }
+
+    /// A number of exactly `digits` digits.
+    fn fixed_number(&mut self, digits: usize, min: c_int, max: c_int) -> Option<c_int> {
+        if self.input.len() < digits || !self.input[..digits].iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.number(digits, min, max)
+    }
+
+    /// Matches either a full or an abbreviated name, ignoring case, and returns its index.
+    fn name(&mut self, full: &[&CStr], abbreviated: &[&CStr]) -> Option<usize> {
+        self.skip_space();
+        // Full names come first, as abbreviations are usually prefixes of them
+        for names in [full, abbreviated].iter() {
+            for (i, name) in names.iter().enumerate() {
+                if self.skip_name(name.to_bytes()) {
+                    return Some(i);
+                }
+            }
+        }
+        None
+    }
+
+    fn skip_name(&mut self, name: &[u8]) -> bool {
+        if name.is_empty()
+            || self.input.len() < name.len()
+            || !self.input[..name.len()].eq_ignore_ascii_case(name)
+        {
+            return false;
+        }
+        self.input = &self.input[name.len()..];
+        true
+    }
+}
+
+fn is_space(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c)
+}
@@ -0,0 +1,624 @@
+//! Time zones, described either by a POSIX TZ string, following
+//! https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html#tag_08_03, or by a
+//! TZif file as specified in RFC 8536, which is what /usr/share/zoneinfo and /etc/localtime
+//! contain.
+//!
+//! The zone is loaded again whenever TZ changes, so setting TZ takes effect without calling tzset.
+//! Leap seconds in TZif files are ignored, as time_t does not count them.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::convert::TryInto;
+
+use crate::{
+    c_str::{CStr, CString},
+    fs::File,
+    header::{fcntl, stdlib::getenv},
+    io::Read,
+    platform::types::*,
+    sync::Mutex,
+};
+
+use super::days_from_civil;
+
+const SECS_PER_DAY: time_t = 24 * 60 * 60;
+
+/// Where zone names that are not absolute paths are looked up when TZDIR is not set.
+const ZONEINFO: &[u8] = b"/usr/share/zoneinfo";
+
+/// The zone used when TZ is not set.
+const LOCALTIME: &[u8] = b"/etc/localtime";
+
+/// The rules of a TZ string that names a daylight saving time zone without saying when it
+/// applies, such as "EST5EDT". These are the current rules of the United States.
+const DEFAULT_RULES: &[u8] = b",M3.2.0,M11.1.0";
+
+/// Offsets and transition times in TZ strings, in hours.
+const MAX_OFFSET_HOURS: time_t = 24;
+const MAX_TIME_HOURS: time_t = 167;
+
+#[derive(Clone, Copy)]
+pub(crate) struct LocalType {
+    /// Seconds east of UTC.
+    pub utoff: c_long,
+    pub isdst: bool,
+    pub name: &'static CStr,
+}
+
+const UTC_TYPE: LocalType = LocalType {
+    utoff: 0,
+    isdst: false,
+    name: c_str!("UTC"),
+};
+
+#[derive(Clone, Copy)]
+enum Date {
+    /// `Jn`: day 1 to 365, where February 29 is never counted.
+    Julian(time_t),
+    /// `n`: day 0 to 365, counting February 29 in leap years.
+    Day(time_t),
+    /// `Mm.w.d`: weekday `d` of week `w` in month `m`, where week 5 is the last one.
+    Month {
+        month: time_t,
+        week: time_t,
+        weekday: time_t,
+    },
+}
+
+impl Date {
+    /// The day this date falls on in `year`, in days since the epoch.
+    fn day(self, year: time_t) -> time_t {
+        let jan1 = days_from_civil(year, 1, 1);
+        match self {
+            Date::Julian(n) => {
+                let leap = days_from_civil(year + 1, 1, 1) - jan1 == 366;
+                jan1 + n - 1 + if leap && n >= 60 { 1 } else { 0 }
+            }
+            Date::Day(n) => jan1 + n,
+            Date::Month {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                let next = if month == 12 {
+                    days_from_civil(year + 1, 1, 1)
+                } else {
+                    days_from_civil(year, month + 1, 1)
+                };
+                // 1970-01-01 was a Thursday
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first + (weekday - first_weekday).rem_euclid(7) + (week - 1) * 7;
+                while day >= next {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Transition {
+    date: Date,
+    /// Seconds after midnight local time, which may be negative or more than a day.
+    time: time_t,
+}
+
+impl Transition {
+    /// When this transition happens in `year`, for a zone that is `utoff` east of UTC until then.
+    fn at(self, year: time_t, utoff: c_long) -> time_t {
+        self.date.day(year) * SECS_PER_DAY + self.time - utoff as time_t
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Rule {
+    std: LocalType,
+    /// The daylight saving time zone along with when it starts and ends.
+    dst: Option<(LocalType, Transition, Transition)>,
+}
+
+impl Rule {
+    fn local_type(&self, t: time_t) -> LocalType {
+        let (dst, start, end) = match self.dst {
+            Some(dst) => dst,
+            None => return self.std,
+        };
+
+        let year = year_of((t + self.std.utoff as time_t).div_euclid(SECS_PER_DAY));
+        let start = start.at(year, self.std.utoff);
+        let end = end.at(year, dst.utoff);
+        let in_dst = if start < end {
+            t >= start && t < end
+        } else {
+            // Southern hemisphere, daylight saving time spans the new year
+            t < end || t >= start
+        };
+
+        if in_dst {
+            dst
+        } else {
+            self.std
+        }
+    }
+}
+
+pub(crate) struct Zone {
+    /// When each local time type starts, sorted by time.
+    transitions: Vec<(time_t, usize)>,
+    types: Vec<LocalType>,
+    /// Applies after the last transition.
+    rule: Option<Rule>,
+}
+
+impl Zone {
+    const fn utc() -> Self {
+        Zone {
+            transitions: Vec::new(),
+            types: Vec::new(),
+            rule: None,
+        }
+    }
+
+    fn from_rule(rule: Rule) -> Self {
+        Zone {
+            transitions: Vec::new(),
+            types: Vec::new(),
+            rule: Some(rule),
+        }
+    }
+
+    /// The local time type in effect at `t`.
+    pub fn local_type(&self, t: time_t) -> LocalType {
+        match (self.transitions.last(), self.rule) {
+            (Some(&(last, _)), Some(_)) if t < last => self.table_type(t),
+            (Some(_), None) => self.table_type(t),
+            (_, Some(rule)) => rule.local_type(t),
+            (None, None) => self.types.first().copied().unwrap_or(UTC_TYPE),
+        }
+    }
+
+    fn table_type(&self, t: time_t) -> LocalType {
+        match self.transitions.binary_search_by(|&(at, _)| at.cmp(&t)) {
+            Ok(i) => self.types[self.transitions[i].1],
+            // Times before the first transition use the first type
+            Err(0) => self.types[0],
+            Err(i) => self.types[self.transitions[i - 1].1],
+        }
+    }
+
+    /// Converts `local`, seconds since the epoch as if local time was UTC, to the time it stands
+    /// for. `isdst` is positive if `local` is known to be daylight saving time, zero if it is
+    /// known to be standard time, and negative if this should be worked out.
+    pub fn local_to_utc(&self, local: time_t, isdst: c_int) -> time_t {
+        // The types in effect around `local`, assuming transitions are more than two days apart
+        let candidates = [
+            self.local_type(local - 2 * SECS_PER_DAY),
+            self.local_type(local),
+            self.local_type(local + 2 * SECS_PER_DAY),
+        ];
+
+        let mut consistent = None;
+        for ty in candidates.iter() {
+            let t = local - ty.utoff as time_t;
+            let actual = self.local_type(t);
+            if actual.utoff == ty.utoff && actual.isdst == ty.isdst {
+                if isdst < 0 || (isdst > 0) == actual.isdst {
+                    return t;
+                }
+                consistent.get_or_insert(t);
+            }
+        }
+
+        // The wrong kind of time was asked for, which moves the result by the difference of the
+        // offsets, or the time was skipped when clocks were set forward
+        if isdst >= 0 {
+            let (std, dst) = self.std_dst();
+            let wanted = candidates
+                .iter()
+                .copied()
+                .find(|ty| ty.isdst == (isdst > 0))
+                .or(if isdst > 0 { dst } else { Some(std) });
+            if let Some(ty) = wanted {
+                return local - ty.utoff as time_t;
+            }
+        }
+        consistent.unwrap_or(local - candidates[0].utoff as time_t)
+    }
+
+    /// The standard and daylight saving time types that the tzname, timezone and daylight
+    /// globals describe.
+    fn std_dst(&self) -> (LocalType, Option<LocalType>) {
+        if let Some(rule) = self.rule {
+            return (rule.std, rule.dst.map(|(dst, _, _)| dst));
+        }
+
+        let mut std = None;
+        let mut dst = None;
+        for &(_, i) in self.transitions.iter() {
+            let ty = self.types[i];
+            if ty.isdst {
+                dst = Some(ty);
+            } else {
+                std = Some(ty);
+            }
+        }
+        let std = std.or_else(|| self.types.first().copied());
+        (std.unwrap_or(UTC_TYPE), dst)
+    }
+}
+
+struct State {
+    /// The value of TZ the zone was loaded for.
+    tz: Option<Vec<u8>>,
+    loaded: bool,
+    zone: Zone,
+}
+
+static STATE: Mutex<State> = Mutex::new(State {
+    tz: None,
+    loaded: false,
+    zone: Zone::utc(),
+});
+
+/// Zone names are never freed, as tm_zone and tzname may point to them after TZ has changed.
+static NAMES: Mutex<Vec<&'static CStr>> = Mutex::new(Vec::new());
+
+/// Runs `f` with the zone TZ currently describes, loading it first if TZ has changed.
+pub(crate) fn with_zone<T>(f: impl FnOnce(&Zone) -> T) -> T {
+    let mut state = STATE.lock();
+    let tz = unsafe { env(c_str!("TZ")) };
+    if !state.loaded || state.tz.as_deref() != tz {
+        state.zone = load(tz);
+        state.tz = tz.map(|tz| tz.to_vec());
+        state.loaded = true;
+
+        let (std, dst) = state.zone.std_dst();
+        unsafe {
+            super::timezone = -std.utoff;
+            super::daylight = dst.is_some() as c_int;
+            super::tzname = [
+                std.name.as_ptr() as *mut c_char,
+                dst.unwrap_or(std).name.as_ptr() as *mut c_char,
+            ];
+        }
+    }
+    f(&state.zone)
+}
+
+/// The value of the environment variable `name`, which is only valid until the environment is
+/// changed.
+unsafe fn env(name: &CStr) -> Option<&'static [u8]> {
+    let value = getenv(name.as_ptr());
+    if value.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(value).to_bytes())
+    }
+}
+
+fn load(tz: Option<&[u8]>) -> Zone {
+    let zone = match tz {
+        None => read_file(LOCALTIME).and_then(|data| parse_tzif(&data)),
+        Some(b"") => None,
+        // A leading colon means the rest can only be a file
+        Some(tz) if tz[0] == b':' => read_zone(&tz[1..]).and_then(|data| parse_tzif(&data)),
+        Some(tz) => read_zone(tz)
+            .and_then(|data| parse_tzif(&data))
+            .or_else(|| parse_rule(tz).map(Zone::from_rule)),
+    };
+    zone.unwrap_or_else(Zone::utc)
+}
+
+/// Reads the zone `name`, which is either an absolute path or relative to TZDIR.
+fn read_zone(name: &[u8]) -> Option<Vec<u8>> {
+    if name.first() == Some(&b'/') {
+        return read_file(name);
+    }
+
+    // Names must not be able to escape the zone directory
+    if name.is_empty() || name.split(|&b| b == b'/').any(|part| part == b"..") {
+        return None;
+    }
+    let mut path = unsafe { env(c_str!("TZDIR")) }
+        .filter(|dir| !dir.is_empty())
+        .unwrap_or(ZONEINFO)
+        .to_vec();
+    path.push(b'/');
+    path.extend_from_slice(name);
+    read_file(&path)
+}
+
+fn read_file(path: &[u8]) -> Option<Vec<u8>> {
+    let path = CString::new(path).ok()?;
+    let mut file = File::open(&path, fcntl::O_RDONLY | fcntl::O_CLOEXEC).ok()?;
+    let mut data = Vec::new();
+    file.read_to_end(&mut data).ok()?;
+    Some(data)
+}
+
+fn intern(name: &[u8]) -> &'static CStr {
+    let mut names = NAMES.lock();
+    if let Some(interned) = names.iter().find(|interned| interned.to_bytes() == name) {
+        return interned;
+    }
+
+    let mut bytes = name.to_vec();
+    bytes.push(0);
+    let bytes: &'static [u8] = Box::leak(bytes.into_boxed_slice());
+    let interned = unsafe { CStr::from_bytes_with_nul_unchecked(bytes) };
+    names.push(interned);
+    interned
+}
+
+/// The year `days` since the epoch fall in.
+fn year_of(days: time_t) -> time_t {
+    let mut year = 1970 + (days * 400).div_euclid(146097);
+    while days_from_civil(year, 1, 1) > days {
+        year -= 1;
+    }
+    while days_from_civil(year + 1, 1, 1) <= days {
+        year += 1;
+    }
+    year
+}
+
+/// Parses a TZ string such as "EST5EDT,M3.2.0,M11.1.0" or "<+0330>-3:30".
+fn parse_rule(mut s: &[u8]) -> Option<Rule> {
+    let std_name = zone_name(&mut s)?;
+    // TZ strings count hours west of UTC
+    let std_offset = -signed_hms(&mut s, MAX_OFFSET_HOURS)?;
+    let std = LocalType {
+        utoff: std_offset as c_long,
+        isdst: false,
+        name: intern(std_name),
+    };
+    if s.is_empty() {
+        return Some(Rule { std, dst: None });
+    }
+
+    let dst_name = zone_name(&mut s)?;
+    let dst_offset = match s.first() {
+        None | Some(b',') => std_offset + 60 * 60,
+        Some(_) => -signed_hms(&mut s, MAX_OFFSET_HOURS)?,
+    };
+    let dst = LocalType {
+        utoff: dst_offset as c_long,
+        isdst: true,
+        name: intern(dst_name),
+    };
+
+    if s.is_empty() {
+        s = DEFAULT_RULES;
+    }
+    expect(&mut s, b',')?;
+    let start = transition(&mut s)?;
+    expect(&mut s, b',')?;
+    let end = transition(&mut s)?;
+    if !s.is_empty() {
+        return None;
+    }
+
+    Some(Rule {
+        std,
+        dst: Some((dst, start, end)),
+    })
+}
+
+/// A zone name is either at least three letters, or quoted in angle brackets, in which case it
+/// may also contain digits and signs.
+fn zone_name<'a>(s: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let (name, rest) = if s.first() == Some(&b'<') {
+        let end = s.iter().position(|&b| b == b'>')?;
+        let name = &s[1..end];
+        if !name
+            .iter()
+            .all(|&b| b.is_ascii_alphanumeric() || b == b'+' || b == b'-')
+        {
+            return None;
+        }
+        (name, &s[end + 1..])
+    } else {
+        let end = s
+            .iter()
+            .position(|b| !b.is_ascii_alphabetic())
+            .unwrap_or(s.len());
+        (&s[..end], &s[end..])
+    };
+
+    if name.len() < 3 {
+        return None;
+    }
+    *s = rest;
+    Some(name)
+}
+
+/// `[+|-]hh[:mm[:ss]]`, in seconds.
+fn signed_hms(s: &mut &[u8], max_hours: time_t) -> Option<time_t> {
+    let sign = match s.first() {
+        Some(b'-') => -1,
+        Some(b'+') => 1,
+        _ => return hms(s, max_hours),
+    };
+    *s = &s[1..];
+    Some(sign * hms(s, max_hours)?)
+}
+
+fn hms(s: &mut &[u8], max_hours: time_t) -> Option<time_t> {
+    let hours = number(s, 0, max_hours)?;
+    let mut secs = hours * 60 * 60;
+    if s.first() == Some(&b':') {
+        *s = &s[1..];
+        secs += number(s, 0, 59)? * 60;
+        if s.first() == Some(&b':') {
+            *s = &s[1..];
+            secs += number(s, 0, 59)?;
+        }
+    }
+    Some(secs)
+}
+
+fn transition(s: &mut &[u8]) -> Option<Transition> {
+    let date = match s.first()? {
+        b'J' => {
+            *s = &s[1..];
+            Date::Julian(number(s, 1, 365)?)
+        }
+        b'M' => {
+            *s = &s[1..];
+            let month = number(s, 1, 12)?;
+            expect(s, b'.')?;
+            let week = number(s, 1, 5)?;
+            expect(s, b'.')?;
+            let weekday = number(s, 0, 6)?;
+            Date::Month {
+                month,
+                week,
+                weekday,
+            }
+        }
+        _ => Date::Day(number(s, 0, 365)?),
+    };
+
+    let time = if s.first() == Some(&b'/') {
+        *s = &s[1..];
+        signed_hms(s, MAX_TIME_HOURS)?
+    } else {
+        2 * 60 * 60
+    };
+    Some(Transition { date, time })
+}
+
+fn number(s: &mut &[u8], min: time_t, max: time_t) -> Option<time_t> {
+    let len = s.iter().take_while(|b| b.is_ascii_digit()).count();
+    if len == 0 || len > 3 {
+        return None;
+    }
+    let value = s[..len]
+        .iter()
+        .fold(0, |value, &b| value * 10 + time_t::from(b - b'0'));
+    *s = &s[len..];
+    if value >= min && value <= max {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+fn expect(s: &mut &[u8], b: u8) -> Option<()> {
+    if s.first() == Some(&b) {
+        *s = &s[1..];
+        Some(())
+    } else {
+        None
+    }
+}
+
+/// The counts in a TZif header.
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+const HEADER_LEN: usize = 44;
+
+impl Header {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < HEADER_LEN || &data[..4] != b"TZif" {
+            return None;
+        }
+        let count = |i: usize| -> Option<usize> {
+            let bytes = data[20 + i * 4..24 + i * 4].try_into().ok()?;
+            u32::from_be_bytes(bytes).try_into().ok()
+        };
+        Some(Header {
+            version: data[4],
+            isutcnt: count(0)?,
+            isstdcnt: count(1)?,
+            leapcnt: count(2)?,
+            timecnt: count(3)?,
+            typecnt: count(4)?,
+            charcnt: count(5)?,
+        })
+    }
+
+    /// The length of the data block following the header, where times are `time_len` bytes.
+    fn data_len(&self, time_len: usize) -> usize {
+        self.timecnt * (time_len + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_len + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+fn parse_tzif(data: &[u8]) -> Option<Zone> {
+    let mut header = Header::parse(data)?;
+    let mut time_len = 4;
+    let mut pos = HEADER_LEN;
+
+    // Version 2 and later repeat everything with 64-bit times after the version 1 data
+    if header.version >= b'2' {
+        pos += header.data_len(4);
+        header = Header::parse(data.get(pos..)?)?;
+        time_len = 8;
+        pos += HEADER_LEN;
+    }
+    let block = data.get(pos..pos + header.data_len(time_len))?;
+    if header.typecnt == 0 || header.charcnt == 0 {
+        return None;
+    }
+
+    let (times, block) = block.split_at(header.timecnt * time_len);
+    let (indices, block) = block.split_at(header.timecnt);
+    let (ttinfos, block) = block.split_at(header.typecnt * 6);
+    let chars = &block[..header.charcnt];
+
+    let mut types = Vec::with_capacity(header.typecnt);
+    for ttinfo in ttinfos.chunks_exact(6) {
+        let utoff = i32::from_be_bytes(ttinfo[..4].try_into().ok()?);
+        let name = chars.get(usize::from(ttinfo[5])..)?;
+        let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(name.len())];
+        types.push(LocalType {
+            utoff: utoff as c_long,
+            isdst: ttinfo[4] != 0,
+            name: intern(name),
+        });
+    }
+
+    let mut transitions = Vec::with_capacity(header.timecnt);
+    for (time, &index) in times.chunks_exact(time_len).zip(indices) {
+        let time = if time_len == 8 {
+            i64::from_be_bytes(time.try_into().ok()?) as time_t
+        } else {
+            i32::from_be_bytes(time.try_into().ok()?) as time_t
+        };
+        if usize::from(index) >= types.len() {
+            return None;
+        }
+        transitions.push((time, usize::from(index)));
+    }
+
+    // The footer is a TZ string between newlines describing times after the last transition
+    let mut rule = None;
+    if header.version >= b'2' {
+        let footer = data.get(pos + header.data_len(time_len)..)?;
+        let footer = footer.strip_prefix(b"\n")?;
+        let footer = &footer[..footer.iter().position(|&b| b == b'\n')?];
+        if !footer.is_empty() {
+            rule = parse_rule(footer);
+        }
+    }
+
+    Some(Zone {
+        transitions,
+        types,
+        rule,
+    })
+}
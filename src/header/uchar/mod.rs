@@ -0,0 +1,113 @@
+//! uchar implementation for Redox, following the C11 standard's description of uchar.h
+
+use crate::{
+    header::{
+        errno::EILSEQ,
+        wchar::{mbrtowc, mbstate_t, wcrtomb},
+    },
+    platform::{self, types::*},
+};
+
+#[no_mangle]
+pub unsafe extern "C" fn c16rtomb(s: *mut c_char, c16: char16_t, ps: *mut mbstate_t) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+    let c16 = if s.is_null() { 0 } else { c16 };
+
+    // A high surrogate is held back until the low one after it completes the character
+    let wc = match (ps.__surrogate, c16) {
+        (0, 0xd800..=0xdbff) => {
+            ps.__surrogate = c16;
+            return 0;
+        }
+        (0, 0xdc00..=0xdfff) => None,
+        (0, _) => Some(c16 as wchar_t),
+        (high, 0xdc00..=0xdfff) => {
+            Some(0x10000 + ((high as wchar_t - 0xd800) << 10 | (c16 as wchar_t - 0xdc00)))
+        }
+        _ => None,
+    };
+    ps.__surrogate = 0;
+
+    match wc {
+        Some(wc) => wcrtomb(s, wc, ps),
+        None => {
+            platform::errno = EILSEQ;
+            -1isize as usize
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn c32rtomb(s: *mut c_char, c32: char32_t, ps: *mut mbstate_t) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+
+    wcrtomb(
+        s,
+        c32 as wchar_t,
+        if ps.is_null() { &mut INTERNAL } else { ps },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mbrtoc16(
+    pc16: *mut char16_t,
+    s: *const c_char,
+    n: size_t,
+    ps: *mut mbstate_t,
+) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+
+    // The low surrogate of the previous character comes out without reading anything
+    if ps.__surrogate != 0 {
+        if !pc16.is_null() {
+            *pc16 = ps.__surrogate;
+        }
+        ps.__surrogate = 0;
+        return -3isize as usize;
+    }
+
+    let mut wc: wchar_t = 0;
+    let result = mbrtowc(&mut wc, s, n, ps);
+    if result < -2isize as usize {
+        let c16 = if wc >= 0x10000 {
+            let offset = (wc - 0x10000) as u32;
+            ps.__surrogate = 0xdc00 | (offset & 0x3ff) as char16_t;
+            0xd800 | (offset >> 10) as char16_t
+        } else {
+            wc as char16_t
+        };
+        if !pc16.is_null() {
+            *pc16 = c16;
+        }
+    }
+    result
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mbrtoc32(
+    pc32: *mut char32_t,
+    s: *const c_char,
+    n: size_t,
+    ps: *mut mbstate_t,
+) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+
+    mbrtowc(
+        pc32 as *mut wchar_t,
+        s,
+        n,
+        if ps.is_null() { &mut INTERNAL } else { ps },
+    )
+}
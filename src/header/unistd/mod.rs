@@ -9,6 +9,7 @@ use crate::{
         time::timespec,
     },
     platform::{self, types::*, Pal, Sys},
+    pthread,
 };
 use alloc::collections::LinkedList;
 
@@ -106,6 +107,9 @@ pub unsafe extern "C" fn chown(path: *const c_char, owner: uid_t, group: gid_t)
 
 #[no_mangle]
 pub extern "C" fn close(fildes: c_int) -> c_int {
+    // Only a request made before the call is acted on, as the descriptor is released even when
+    // closing it is interrupted
+    pthread::testcancel();
     Sys::close(fildes)
 }
 
@@ -134,24 +138,7 @@ pub extern "C" fn encrypt(block: [c_char; 64], edflag: c_int) {
     unimplemented!();
 }
 
-// #[no_mangle]
-// pub extern "C" fn execl(path: *const c_char, args: *const *mut c_char) -> c_int {
-//     unimplemented!();
-// }
-
-// #[no_mangle]
-// pub extern "C" fn execle(
-//   path: *const c_char,
-//   args: *const *mut c_char,
-//   envp: *const *mut c_char,
-// ) -> c_int {
-//     unimplemented!();
-// }
-
-// #[no_mangle]
-// pub extern "C" fn execlp(file: *const c_char, args: *const *mut c_char) -> c_int {
-//     unimplemented!();
-// }
+// execl, execle and execlp are variadic, so they live in src/c/unistd.c
 
 #[no_mangle]
 pub unsafe extern "C" fn execv(path: *const c_char, argv: *const *mut c_char) -> c_int {
@@ -169,10 +156,10 @@ pub unsafe extern "C" fn execve(
 }
 
 #[cfg(target_os = "linux")]
-const PATH_SEPARATOR: u8 = b':';
+pub(crate) const PATH_SEPARATOR: u8 = b':';
 
 #[cfg(target_os = "redox")]
-const PATH_SEPARATOR: u8 = b';';
+pub(crate) const PATH_SEPARATOR: u8 = b';';
 
 #[no_mangle]
 pub unsafe extern "C" fn execvp(file: *const c_char, argv: *const *mut c_char) -> c_int {
@@ -232,6 +219,7 @@ pub extern "C" fn fork() -> pid_t {
     }
     let pid = Sys::fork();
     if pid == 0 {
+        unsafe { pthread::reinit_after_fork() };
         for child in &fork_hooks[2] {
             child();
         }
@@ -245,7 +233,7 @@ pub extern "C" fn fork() -> pid_t {
 
 #[no_mangle]
 pub extern "C" fn fsync(fildes: c_int) -> c_int {
-    Sys::fsync(fildes)
+    pthread::cancellation_point(|| Sys::fsync(fildes))
 }
 
 #[no_mangle]
@@ -402,9 +390,9 @@ pub extern "C" fn getppid() -> pid_t {
     Sys::getppid()
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn getsid(pid: pid_t) -> pid_t {
-    unimplemented!();
+    Sys::getsid(pid)
 }
 
 #[no_mangle]
@@ -540,7 +528,7 @@ pub extern "C" fn pwrite(
 pub extern "C" fn read(fildes: c_int, buf: *const c_void, nbyte: size_t) -> ssize_t {
     let buf = unsafe { slice::from_raw_parts_mut(buf as *mut u8, nbyte as usize) };
     trace_expr!(
-        Sys::read(fildes, buf),
+        pthread::cancellation_point(|| Sys::read(fildes, buf)),
         "read({}, {:p}, {})",
         fildes,
         buf,
@@ -590,9 +578,9 @@ pub extern "C" fn setreuid(ruid: uid_t, euid: uid_t) -> c_int {
     Sys::setreuid(ruid, euid)
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn setsid() -> pid_t {
-    unimplemented!();
+    Sys::setsid()
 }
 
 #[no_mangle]
@@ -607,7 +595,7 @@ pub extern "C" fn sleep(seconds: c_uint) -> c_uint {
         tv_nsec: 0,
     };
     let rmtp = ptr::null_mut();
-    Sys::nanosleep(&rqtp, rmtp);
+    pthread::cancellation_point(|| Sys::nanosleep(&rqtp, rmtp));
     0
 }
 
@@ -735,16 +723,23 @@ pub extern "C" fn usleep(useconds: useconds_t) -> c_int {
         tv_nsec: ((useconds % 1_000_000) * 1000) as i64,
     };
     let rmtp = ptr::null_mut();
-    Sys::nanosleep(&rqtp, rmtp)
+    pthread::cancellation_point(|| Sys::nanosleep(&rqtp, rmtp))
 }
 
-// #[no_mangle]
+// On Linux x86_64 and aarch64, vfork is written in assembly, as the child returns on the stack of
+// the parent
+#[cfg(any(
+    target_os = "redox",
+    not(any(target_arch = "x86_64", target_arch = "aarch64"))
+))]
+#[no_mangle]
 pub extern "C" fn vfork() -> pid_t {
-    unimplemented!();
+    // Without a way to share the address space, a copy of it has to do
+    fork()
 }
 
 #[no_mangle]
 pub extern "C" fn write(fildes: c_int, buf: *const c_void, nbyte: size_t) -> ssize_t {
     let buf = unsafe { slice::from_raw_parts(buf as *const u8, nbyte as usize) };
-    Sys::write(fildes, buf)
+    pthread::cancellation_point(|| Sys::write(fildes, buf))
 }
@@ -1,18 +1,77 @@
 //! wchar implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/wchar.h.html
 
-use core::{char, ffi::VaList as va_list, mem, ptr, slice, usize};
+use core::{char, cmp, cmp::Ordering, ffi::VaList as va_list, mem, ptr, slice, usize};
 
 use crate::{
     header::{
-        ctype::isspace, errno::ERANGE, stdio::*, stdlib::MB_CUR_MAX, string, time::*, wctype::*,
+        ctype::isspace,
+        errno::{EILSEQ, ERANGE},
+        locale::{self, collate, locale_t, Codeset},
+        stdio::{
+            lookaheadreader::WideLookAheadReader,
+            printf::{self, WriteWide},
+            scanf, *,
+        },
+        stdlib::MB_LEN_MAX,
+        string,
+        time::*,
+        wctype::{nonspacing, wide, *},
     },
+    io::{self, Read},
     platform::{self, types::*},
 };
 
-mod utf8;
+pub(crate) mod utf8;
+
+/// The state of a conversion between multibyte and wide characters, which holds on to a
+/// character that is split across calls. All zeroes is the initial state.
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub struct mbstate_t;
+pub struct mbstate_t {
+    /// The bits of an incomplete character read so far
+    __value: u32,
+    /// How many more bytes that character needs
+    __pending: u8,
+    /// The range the next byte must be in, which is narrower right after some lead bytes
+    __lower: u8,
+    __upper: u8,
+    /// Half of a surrogate pair held back by mbrtoc16 or c16rtomb, or 0
+    pub(crate) __surrogate: u16,
+}
+
+impl mbstate_t {
+    pub const fn new() -> Self {
+        Self {
+            __value: 0,
+            __pending: 0,
+            __lower: 0,
+            __upper: 0,
+            __surrogate: 0,
+        }
+    }
+}
+
+/// The wide character a byte stands for in a single-byte locale
+pub(crate) fn byte_to_wide(codeset: Codeset, b: u8) -> wchar_t {
+    match codeset {
+        Codeset::Latin1 => b as wchar_t,
+        // The C locale gives bytes that aren't ASCII code points of their own, outside of any
+        // real character, so that any string survives a round trip
+        _ if b >= 0x80 => 0xdf00 + b as wchar_t,
+        _ => b as wchar_t,
+    }
+}
+
+/// The byte a wide character is in a single-byte locale, if there is one
+fn wide_to_byte(codeset: Codeset, wc: wchar_t) -> Option<u8> {
+    match (codeset, wc) {
+        (_, 0..=0x7f) => Some(wc as u8),
+        (Codeset::Latin1, 0x80..=0xff) => Some(wc as u8),
+        (Codeset::Latin1, _) => None,
+        (_, 0xdf80..=0xdfff) => Some(wc as u8),
+        _ => None,
+    }
+}
 
 #[no_mangle]
 pub unsafe extern "C" fn btowc(c: c_int) -> wint_t {
@@ -23,7 +82,7 @@ pub unsafe extern "C" fn btowc(c: c_int) -> wint_t {
 
     let uc = c as u8;
     let c = uc as c_char;
-    let mut ps: mbstate_t = mbstate_t;
+    let mut ps = mbstate_t::new();
     let mut wc: wchar_t = 0;
     let saved_errno = platform::errno;
     let status = mbrtowc(&mut wc, &c as *const c_char, 1, &mut ps);
@@ -34,58 +93,91 @@ pub unsafe extern "C" fn btowc(c: c_int) -> wint_t {
     wc as wint_t
 }
 
+/// Reads a multibyte character from a stream that is already locked
+unsafe fn fgetwc_locked(stream: &mut FILE) -> wint_t {
+    if let Err(_) = stream.try_set_wide_orientation_unlocked() {
+        return WEOF;
+    }
+
+    let mut bytes: [c_char; MB_LEN_MAX as usize] = [0; MB_LEN_MAX as usize];
+    for len in 1..=bytes.len() {
+        let mut byte = [0];
+        match stream.read(&mut byte) {
+            Ok(1) => bytes[len - 1] = byte[0] as c_char,
+            // The stream ended in the middle of a character
+            Ok(_) if len > 1 => break,
+            _ => return WEOF,
+        }
+
+        let mut wc = 0;
+        let mut ps = mbstate_t::new();
+        match mbrtowc(&mut wc, bytes.as_ptr(), len, &mut ps) {
+            n if n == usize::max_value() - 1 => (),
+            n if n == usize::max_value() => break,
+            _ => return wc as wint_t,
+        }
+    }
+    platform::errno = EILSEQ;
+    stream.flags |= F_ERR;
+    WEOF
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn fgetwc(stream: *mut FILE) -> wint_t {
-    //TODO: Real multibyte
-    btowc(fgetc(stream))
+    let mut stream = (*stream).lock();
+    fgetwc_locked(&mut *stream)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn fgetws(ws: *mut wchar_t, n: c_int, stream: *mut FILE) -> *mut wchar_t {
-    //TODO: lock
+    if n < 1 {
+        return ptr::null_mut();
+    }
+    let mut stream = (*stream).lock();
+
+    // Read up to and including a newline, leaving room for the terminator
     let mut i = 0;
     while ((i + 1) as c_int) < n {
-        let wc = fgetwc(stream);
+        let wc = fgetwc_locked(&mut *stream);
         if wc == WEOF {
-            return ptr::null_mut();
+            if i == 0 || stream.flags & F_ERR != 0 {
+                return ptr::null_mut();
+            }
+            break;
         }
         *ws.add(i) = wc as wchar_t;
         i += 1;
+        if wc == '\n' as wint_t {
+            break;
+        }
     }
-    while (i as c_int) < n {
-        *ws.add(i) = 0;
-        i += 1;
-    }
+    *ws.add(i) = 0;
     ws
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn fputwc(wc: wchar_t, stream: *mut FILE) -> wint_t {
-    //Convert wchar_t to multibytes first
-    static mut INTERNAL: mbstate_t = mbstate_t;
-    let mut bytes: [c_char; MB_CUR_MAX as usize] = [0; MB_CUR_MAX as usize];
-
-    let amount = wcrtomb(bytes.as_mut_ptr(), wc, &mut INTERNAL);
-
-    for i in 0..amount {
-        fputc(bytes[i] as c_int, &mut *stream);
+    let mut stream = (*stream).lock();
+    if let Err(_) = stream.try_set_wide_orientation_unlocked() {
+        return WEOF;
     }
 
-    wc as wint_t
+    match stream.write_wide(&[wc]) {
+        Ok(()) => wc as wint_t,
+        Err(_) => WEOF,
+    }
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn fputws(ws: *const wchar_t, stream: *mut FILE) -> c_int {
-    let mut i = 0;
-    loop {
-        let wc = *ws.add(i);
-        if wc == 0 {
-            return 0;
-        }
-        if fputwc(wc, stream) == WEOF {
-            return -1;
-        }
-        i += 1;
+    let mut stream = (*stream).lock();
+    if let Err(_) = stream.try_set_wide_orientation_unlocked() {
+        return -1;
+    }
+
+    match stream.write_wide(slice::from_raw_parts(ws, wcslen(ws))) {
+        Ok(()) => 0,
+        Err(_) => -1,
     }
 }
 
@@ -106,8 +198,7 @@ pub unsafe extern "C" fn getwchar() -> wint_t {
 
 #[no_mangle]
 pub unsafe extern "C" fn mbsinit(ps: *const mbstate_t) -> c_int {
-    //Add a check for the state maybe
-    if ps.is_null() {
+    if ps.is_null() || ((*ps).__pending == 0 && (*ps).__surrogate == 0) {
         1
     } else {
         0
@@ -116,11 +207,15 @@ pub unsafe extern "C" fn mbsinit(ps: *const mbstate_t) -> c_int {
 
 #[no_mangle]
 pub unsafe extern "C" fn mbrlen(s: *const c_char, n: size_t, ps: *mut mbstate_t) -> size_t {
-    static mut INTERNAL: mbstate_t = mbstate_t;
-    mbrtowc(ptr::null_mut(), s, n, &mut INTERNAL)
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+    mbrtowc(
+        ptr::null_mut(),
+        s,
+        n,
+        if ps.is_null() { &mut INTERNAL } else { ps },
+    )
 }
 
-//Only works for UTF8 at the moment
 #[no_mangle]
 pub unsafe extern "C" fn mbrtowc(
     pwc: *mut wchar_t,
@@ -128,16 +223,37 @@ pub unsafe extern "C" fn mbrtowc(
     n: size_t,
     ps: *mut mbstate_t,
 ) -> size_t {
-    static mut INTERNAL: mbstate_t = mbstate_t;
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
 
-    if ps.is_null() {
-        let ps = &mut INTERNAL;
-    }
-    if s.is_null() {
-        let xs: [c_char; 1] = [0];
-        utf8::mbrtowc(pwc, &xs[0] as *const c_char, 1, ps)
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+    // A null string resets the state, which is only allowed between characters
+    let nul: c_char = 0;
+    let (pwc, s, n) = if s.is_null() {
+        (ptr::null_mut(), &nul as *const c_char, 1)
     } else {
-        utf8::mbrtowc(pwc, s, n, ps)
+        (pwc, s, n)
+    };
+
+    match locale::current().ctype().codeset {
+        Codeset::Utf8 => utf8::mbrtowc(pwc, s, n, ps),
+        codeset => {
+            if n == 0 {
+                return -2isize as usize;
+            }
+            let wc = byte_to_wide(codeset, *s as u8);
+            if !pwc.is_null() {
+                *pwc = wc;
+            }
+            if wc == 0 {
+                0
+            } else {
+                1
+            }
+        }
     }
 }
 
@@ -151,11 +267,13 @@ pub unsafe extern "C" fn mbsnrtowcs(
     dst_len: size_t,
     ps: *mut mbstate_t,
 ) -> size_t {
-    static mut INTERNAL: mbstate_t = mbstate_t;
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
 
-    if ps.is_null() {
-        let ps = &mut INTERNAL;
-    }
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
 
     let mut src = *src_ptr;
 
@@ -170,7 +288,7 @@ pub unsafe extern "C" fn mbsnrtowcs(
         // Stop in the event a decoding error occured.
         if amount == -1isize as usize {
             *src_ptr = src.add(src_offset);
-            return 1isize as usize;
+            return -1isize as usize;
         }
 
         // Stop decoding early in the event we encountered a partial character.
@@ -222,57 +340,154 @@ pub unsafe extern "C" fn putwchar(wc: wchar_t) -> wint_t {
     fputwc(wc, &mut *stdout)
 }
 
-// #[no_mangle]
-pub extern "C" fn swprintf(
-    s: *mut wchar_t,
-    n: size_t,
+#[no_mangle]
+pub unsafe extern "C" fn ungetwc(wc: wint_t, stream: *mut FILE) -> wint_t {
+    let mut stream = (*stream).lock();
+    if wc == WEOF || stream.try_set_wide_orientation_unlocked().is_err() {
+        return WEOF;
+    }
+
+    let mut bytes: [c_char; MB_LEN_MAX as usize] = [0; MB_LEN_MAX as usize];
+    let mut ps = mbstate_t::new();
+    let amount = wcrtomb(bytes.as_mut_ptr(), wc as wchar_t, &mut ps);
+    if amount == usize::max_value() {
+        return WEOF;
+    }
+
+    // The bytes that were pushed back are read from the end
+    for &byte in bytes[..amount].iter().rev() {
+        stream.unget.push(byte as u8);
+    }
+    wc
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn vfwprintf(
+    stream: *mut FILE,
     format: *const wchar_t,
-    ap: va_list,
+    arg: va_list,
 ) -> c_int {
-    unimplemented!();
+    let mut stream = (*stream).lock();
+    if let Err(_) = stream.try_set_wide_orientation_unlocked() {
+        return -1;
+    }
+
+    printf::wprintf(&mut *stream, format, arg)
 }
 
-// #[no_mangle]
-pub extern "C" fn swscanf(s: *const wchar_t, format: *const wchar_t, ap: va_list) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn vfwscanf(
+    stream: *mut FILE,
+    format: *const wchar_t,
+    arg: va_list,
+) -> c_int {
+    let mut stream = (*stream).lock();
+    if let Err(_) = stream.try_set_wide_orientation_unlocked() {
+        return -1;
+    }
+
+    let f: &mut FILE = &mut *stream;
+    let reader: WideLookAheadReader = f.into();
+    scanf::wscanf(reader, format, arg)
 }
 
-// #[no_mangle]
-pub extern "C" fn ungetwc(wc: wint_t, stream: *mut FILE) -> wint_t {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn vwprintf(format: *const wchar_t, arg: va_list) -> c_int {
+    vfwprintf(&mut *stdout, format, arg)
 }
 
-// #[no_mangle]
-pub extern "C" fn vfwprintf(stream: *mut FILE, format: *const wchar_t, arg: va_list) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn vwscanf(format: *const wchar_t, arg: va_list) -> c_int {
+    vfwscanf(&mut *stdin, format, arg)
 }
 
-// #[no_mangle]
-pub extern "C" fn vwprintf(format: *const wchar_t, arg: va_list) -> c_int {
-    unimplemented!();
+/// The buffer of swprintf, which keeps as much of the output as fits and a terminator after it
+struct WideStringWriter {
+    buf: *mut wchar_t,
+    // Not counting the terminator
+    room: usize,
 }
 
-// #[no_mangle]
-pub extern "C" fn vswprintf(
+impl WriteWide for WideStringWriter {
+    fn write_wide(&mut self, s: &[wchar_t]) -> io::Result<()> {
+        let len = cmp::min(s.len(), self.room);
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), self.buf, len);
+            self.buf = self.buf.add(len);
+            *self.buf = 0;
+        }
+        self.room -= len;
+        Ok(())
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn vswprintf(
     s: *mut wchar_t,
     n: size_t,
     format: *const wchar_t,
     arg: va_list,
 ) -> c_int {
-    unimplemented!();
+    if n == 0 {
+        return -1;
+    }
+    *s = 0;
+
+    let written = printf::wprintf(
+        WideStringWriter {
+            buf: s,
+            room: n - 1,
+        },
+        format,
+        arg,
+    );
+    // Unlike snprintf, running out of room is an error
+    if written as size_t >= n {
+        return -1;
+    }
+    written
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn vswscanf(
+    s: *const wchar_t,
+    format: *const wchar_t,
+    arg: va_list,
+) -> c_int {
+    let reader = s.into();
+    scanf::wscanf(reader, format, arg)
 }
 
 //widechar to multibyte
 #[no_mangle]
 pub unsafe extern "C" fn wcrtomb(s: *mut c_char, wc: wchar_t, ps: *mut mbstate_t) -> size_t {
-    let mut buffer: [c_char; MB_CUR_MAX as usize] = [0; MB_CUR_MAX as usize];
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+    let mut buffer: [c_char; MB_LEN_MAX as usize] = [0; MB_LEN_MAX as usize];
     let (s_cpy, wc_cpy) = if s.is_null() {
         (buffer.as_mut_ptr(), 0)
     } else {
         (s, wc)
     };
 
-    utf8::wcrtomb(s_cpy, wc_cpy, ps)
+    match locale::current().ctype().codeset {
+        Codeset::Utf8 => utf8::wcrtomb(s_cpy, wc_cpy, ps),
+        codeset => match wide_to_byte(codeset, wc_cpy) {
+            Some(b) => {
+                *s_cpy = b as c_char;
+                1
+            }
+            None => {
+                platform::errno = EILSEQ;
+                -1isize as usize
+            }
+        },
+    }
 }
 
 #[no_mangle]
@@ -300,8 +515,30 @@ pub unsafe extern "C" fn wcscmp(ws1: *const wchar_t, ws2: *const wchar_t) -> c_i
 
 #[no_mangle]
 pub unsafe extern "C" fn wcscoll(ws1: *const wchar_t, ws2: *const wchar_t) -> c_int {
-    //TODO: locale comparison
-    wcscmp(ws1, ws2)
+    wcscoll_l(ws1, ws2, locale::uselocale(ptr::null_mut()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wcscoll_l(
+    ws1: *const wchar_t,
+    ws2: *const wchar_t,
+    locale: locale_t,
+) -> c_int {
+    let data = locale::get(locale).collate();
+    if !data.unicode {
+        return wcscmp(ws1, ws2);
+    }
+
+    match collate::compare(code_points(ws1), code_points(ws2), data.tailoring) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+/// The characters of a wide string, as code points for collation
+unsafe fn code_points<'a>(ws: *const wchar_t) -> &'a [u32] {
+    slice::from_raw_parts(ws as *const u32, wcslen(ws))
 }
 
 #[no_mangle]
@@ -436,14 +673,69 @@ pub unsafe extern "C" fn wcsrchr(ws1: *const wchar_t, wc: wchar_t) -> *mut wchar
     last_matching_wc as *mut wchar_t
 }
 
-// #[no_mangle]
-pub extern "C" fn wcsrtombs(
+//Convert a wide string to a multibyte string with a limited amount of wide characters
+//Required for in POSIX.1-2008
+#[no_mangle]
+pub unsafe extern "C" fn wcsnrtombs(
     dst: *mut c_char,
     src: *mut *const wchar_t,
+    nwc: size_t,
     len: size_t,
     ps: *mut mbstate_t,
 ) -> size_t {
-    unimplemented!();
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+    let mut ws = *src;
+    let mut written = 0;
+    let mut buffer: [c_char; MB_LEN_MAX as usize] = [0; MB_LEN_MAX as usize];
+
+    for _ in 0..nwc {
+        let amount = wcrtomb(buffer.as_mut_ptr(), *ws, ps);
+        if amount == -1isize as usize {
+            if !dst.is_null() {
+                *src = ws;
+            }
+            return amount;
+        }
+
+        if !dst.is_null() {
+            // Only whole characters are stored
+            if written + amount > len {
+                break;
+            }
+            ptr::copy_nonoverlapping(buffer.as_ptr(), dst.add(written), amount);
+        }
+
+        // The terminator is stored, but not counted, and the source is set to null after it
+        if *ws == 0 {
+            if !dst.is_null() {
+                *src = ptr::null();
+            }
+            return written;
+        }
+        written += amount;
+        ws = ws.add(1);
+    }
+
+    if !dst.is_null() {
+        *src = ws;
+    }
+    written
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wcsrtombs(
+    dst: *mut c_char,
+    src: *mut *const wchar_t,
+    len: size_t,
+    ps: *mut mbstate_t,
+) -> size_t {
+    wcsnrtombs(dst, src, size_t::max_value(), len, ps)
 }
 
 #[no_mangle]
@@ -637,28 +929,106 @@ pub extern "C" fn wcswcs(ws1: *const wchar_t, ws2: *const wchar_t) -> *mut wchar
     unimplemented!();
 }
 
-// #[no_mangle]
-pub extern "C" fn wcswidth(pwcs: *const wchar_t, n: size_t) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn wcswidth(pwcs: *const wchar_t, n: size_t) -> c_int {
+    let mut total = 0;
+    for i in 0..n {
+        let wc = *pwcs.add(i);
+        if wc == 0 {
+            break;
+        }
+        match wcwidth(wc) {
+            -1 => return -1,
+            width => total += width,
+        }
+    }
+    total
 }
 
-// #[no_mangle]
-pub extern "C" fn wcsxfrm(ws1: *mut wchar_t, ws2: *const wchar_t, n: size_t) -> size_t {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn wcsxfrm(ws1: *mut wchar_t, ws2: *const wchar_t, n: size_t) -> size_t {
+    wcsxfrm_l(ws1, ws2, n, locale::uselocale(ptr::null_mut()))
 }
 
 #[no_mangle]
-pub extern "C" fn wctob(c: wint_t) -> c_int {
-    if c <= 0x7F {
-        c as c_int
-    } else {
-        EOF
+pub unsafe extern "C" fn wcsxfrm_l(
+    ws1: *mut wchar_t,
+    ws2: *const wchar_t,
+    n: size_t,
+    locale: locale_t,
+) -> size_t {
+    let data = locale::get(locale).collate();
+    if !data.unicode {
+        let len = wcslen(ws2);
+        if len < n {
+            wcscpy(ws1, ws2);
+        }
+        return len;
+    }
+
+    let key = collate::key_wide(&collate::sort_key(code_points(ws2), data.tailoring));
+    if key.len() < n {
+        ptr::copy_nonoverlapping(key.as_ptr(), ws1, key.len());
+        *ws1.add(key.len()) = 0;
     }
+    key.len()
 }
 
-// #[no_mangle]
+#[no_mangle]
+pub unsafe extern "C" fn wctob(c: wint_t) -> c_int {
+    if c == WEOF {
+        return EOF;
+    }
+
+    let mut buffer: [c_char; MB_LEN_MAX as usize] = [0; MB_LEN_MAX as usize];
+    let mut ps = mbstate_t::new();
+    let saved_errno = platform::errno;
+    match wcrtomb(buffer.as_mut_ptr(), c as wchar_t, &mut ps) {
+        1 => buffer[0] as u8 as c_int,
+        _ => {
+            platform::errno = saved_errno;
+            EOF
+        }
+    }
+}
+
+#[no_mangle]
 pub extern "C" fn wcwidth(wc: wchar_t) -> c_int {
-    unimplemented!();
+    let wc = wc as u32;
+    if wc < 0xff {
+        return if ((wc + 1) & 0x7f) >= 0x21 {
+            1
+        } else if wc != 0 {
+            -1
+        } else {
+            0
+        };
+    }
+    // Surrogates and the last two code points of each plane are not characters, and the line
+    // and paragraph separators are not printable
+    if wc.wrapping_sub(0xd800) < 0x800 || wc & 0xfffe == 0xfffe || wc.wrapping_sub(0x2028) < 2 {
+        return -1;
+    }
+    if wc < 0x20000 {
+        return if nonspacing::is(wc as usize) != 0 {
+            0
+        } else if wide::is(wc as usize) != 0 {
+            2
+        } else {
+            1
+        };
+    }
+    // The next two planes are CJK ideographs, and the tags and variation selectors of the
+    // fourteenth are invisible
+    if wc < 0x40000 {
+        2
+    } else if wc == 0xe0001 || wc.wrapping_sub(0xe0020) < 0x60 || wc.wrapping_sub(0xe0100) < 0xf0 {
+        0
+    } else if wc > 0x10ffff {
+        -1
+    } else {
+        1
+    }
 }
 
 #[no_mangle]
@@ -717,16 +1087,6 @@ pub unsafe extern "C" fn wmemset(ws: *mut wchar_t, wc: wchar_t, n: size_t) -> *m
     ws
 }
 
-// #[no_mangle]
-pub extern "C" fn wprintf(format: *const wchar_t, ap: va_list) -> c_int {
-    unimplemented!();
-}
-
-// #[no_mangle]
-pub extern "C" fn wscanf(format: *const wchar_t, ap: va_list) -> c_int {
-    unimplemented!();
-}
-
 #[no_mangle]
 pub extern "C" fn wcscasecmp(mut s1: *const wchar_t, mut s2: *const wchar_t) -> c_int {
     unsafe {
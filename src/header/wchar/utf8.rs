@@ -1,7 +1,7 @@
 //UTF implementation parts for wchar.h.
 //Partially ported from the Sortix libc
 
-use core::{char, slice, str, usize};
+use core::{char, slice, usize};
 
 use crate::{
     header::errno,
@@ -10,72 +10,85 @@ use crate::{
 
 use super::mbstate_t;
 
-// Based on
-// https://github.com/rust-lang/rust/blob/f24ce9b/library/core/src/str/validations.rs#L232-L257,
-// because apparently somebody removed the `pub use` statement from `core::str`.
-
 // https://tools.ietf.org/html/rfc3629
-static UTF8_CHAR_WIDTH: [u8; 256] = [
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, // 0x1F
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, // 0x3F
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, // 0x5F
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, // 0x7F
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, // 0x9F
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, // 0xBF
-    0, 0, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, // 0xDF
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, // 0xEF
-    4, 4, 4, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // 0xFF
-];
-
-// Given a first byte, determines how many bytes are in this UTF-8 character.
-#[inline]
-fn utf8_char_width(b: u8) -> usize {
-    UTF8_CHAR_WIDTH[usize::from(b)].into()
-}
-
-//It's guaranteed that we don't have any nullpointers here
-pub unsafe fn mbrtowc(pwc: *mut wchar_t, s: *const c_char, n: usize, ps: *mut mbstate_t) -> usize {
-    let size = utf8_char_width(*s as u8);
-    if size > n {
-        platform::errno = errno::EILSEQ;
-        return -2isize as usize;
-    }
-    if size == 0 {
-        platform::errno = errno::EILSEQ;
-        return -1isize as usize;
+/// What a lead byte says about the character it starts: the bits it contributes, how many
+/// continuation bytes follow, and the range the first of them must be in. The narrower ranges
+/// rule out overlong encodings, surrogates and anything above U+10FFFF.
+fn lead(b: u8) -> Option<(u32, u8, u8, u8)> {
+    match b {
+        0xc2..=0xdf => Some((b as u32 & 0x1f, 1, 0x80, 0xbf)),
+        0xe0 => Some((0, 2, 0xa0, 0xbf)),
+        0xe1..=0xec | 0xee..=0xef => Some((b as u32 & 0x0f, 2, 0x80, 0xbf)),
+        0xed => Some((0x0d, 2, 0x80, 0x9f)),
+        0xf0 => Some((0, 3, 0x90, 0xbf)),
+        0xf1..=0xf3 => Some((b as u32 & 0x07, 3, 0x80, 0xbf)),
+        0xf4 => Some((0x04, 3, 0x80, 0x8f)),
+        _ => None,
     }
+}
 
-    let slice = slice::from_raw_parts(s as *const u8, size);
-    let decoded = str::from_utf8(slice);
-    if decoded.is_err() {
-        platform::errno = errno::EILSEQ;
-        return -1isize as usize;
-    }
-
-    let wc = decoded.unwrap();
-
-    let result: wchar_t = wc.chars().next().unwrap() as wchar_t;
-
-    if !pwc.is_null() {
-        *pwc = result;
+/// Decodes at most `n` bytes of `s`, continuing the incomplete character in `ps` if there is
+/// one. When the bytes run out in the middle of a character, they are kept in `ps` and -2 is
+/// returned, so the rest can be passed in the next call.
+pub unsafe fn mbrtowc(pwc: *mut wchar_t, s: *const c_char, n: usize, ps: &mut mbstate_t) -> usize {
+    let mut value = ps.__value;
+    let mut pending = ps.__pending;
+    let mut lower = ps.__lower;
+    let mut upper = ps.__upper;
+
+    // n is often a limit far beyond the end of the string, so bytes are only read one by one
+    for i in 0..n {
+        let b = *s.add(i) as u8;
+        if pending == 0 {
+            if b < 0x80 {
+                value = b as u32;
+            } else {
+                match lead(b) {
+                    Some((bits, more, low, high)) => {
+                        value = bits;
+                        pending = more;
+                        lower = low;
+                        upper = high;
+                        continue;
+                    }
+                    None => {
+                        *ps = mbstate_t::new();
+                        platform::errno = errno::EILSEQ;
+                        return -1isize as usize;
+                    }
+                }
+            }
+        } else {
+            if b < lower || b > upper {
+                *ps = mbstate_t::new();
+                platform::errno = errno::EILSEQ;
+                return -1isize as usize;
+            }
+            value = value << 6 | (b & 0x3f) as u32;
+            pending -= 1;
+            lower = 0x80;
+            upper = 0xbf;
+            if pending > 0 {
+                continue;
+            }
+        }
+
+        *ps = mbstate_t::new();
+        if !pwc.is_null() {
+            *pwc = value as wchar_t;
+        }
+        return if value == 0 { 0 } else { i + 1 };
     }
 
-    if result != 0 {
-        size
-    } else {
-        0
-    }
+    ps.__value = value;
+    ps.__pending = pending;
+    ps.__lower = lower;
+    ps.__upper = upper;
+    -2isize as usize
 }
 
 //It's guaranteed that we don't have any nullpointers here
-pub unsafe fn wcrtomb(s: *mut c_char, wc: wchar_t, ps: *mut mbstate_t) -> usize {
+pub unsafe fn wcrtomb(s: *mut c_char, wc: wchar_t, _ps: &mut mbstate_t) -> usize {
     let dc = char::from_u32(wc as u32);
 
     if dc.is_none() {
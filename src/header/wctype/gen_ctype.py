@@ -0,0 +1,307 @@
+#!/usr/bin/env python3
+"""Generates the tables behind the wctype.h classes and case mappings: alpha.rs, lower.rs,
+print.rs, punct.rs, upper.rs and casemap.rs.
+
+The classes are the ones of glibc's i18n locale, so that programs see the same characters as
+letters or punctuation on either C library:
+
+- alpha: the Alphabetic property, and decimal digits other than 0-9, which POSIX does not let
+  iswdigit() accept but which should still count for iswalnum()
+- upper and lower: the Uppercase and Lowercase properties, and characters with a mapping to
+  the other case
+- print: everything assigned except control characters and the line and paragraph separators
+- punct: printable characters that are not spaces, letters or digits
+
+The class tables have the layout of the ones gen_width.py writes, with the few characters
+past the first two planes listed as ranges. Case mappings are runs of characters that map at
+the same distance, either one after the other or alternating with their other case.
+
+Most of the data comes from the unicodedata module, which has to be of UNICODE_VERSION, as
+Python 3.9 and 3.10 are:
+
+- general categories
+- the Uppercase and Lowercase properties, which str.isupper() and str.islower() report for a
+  single character
+- the simple lower case mapping, which the re module uses for case-insensitive matching
+- the simple upper and title case mappings, where the full ones are a single character
+
+What unicodedata lacks comes from Perl's Unicode::UCD: Other_Alphabetic, the marks and other
+characters that are alphabetic without being letters, and the simple upper and title case of
+the few characters whose full mappings have several characters. Perl may have a later Unicode
+version, like the 14.0.0 of Perl 5.36, so only characters assigned in UNICODE_VERSION are taken
+from it:
+
+    python3.9 src/header/wctype/gen_ctype.py
+"""
+
+import _sre
+import os
+import subprocess
+import unicodedata
+
+# Has to match UNICODE_VERSION in gen_width.py and locale/gen_ducet.py, so that every table
+# is made from the same characters
+UNICODE_VERSION = "13.0.0"
+
+LIMIT = 0x20000
+BLOCK = 256
+
+CHARS = [c for c in range(0x110000) if not 0xD800 <= c <= 0xDFFF]
+
+
+def perl(script):
+    return subprocess.run(
+        ["perl", "-MUnicode::UCD=prop_invlist,prop_invmap", "-e", script],
+        capture_output=True,
+        check=True,
+        text=True,
+    ).stdout
+
+
+def property_set(name):
+    bounds = [int(b) for b in perl('print join(" ", prop_invlist("{}"))'.format(name)).split()]
+    bounds.append(0x110000)
+    chars = set()
+    for start, end in zip(bounds[::2], bounds[1::2]):
+        chars.update(range(start, end))
+    return chars
+
+
+def case_mapping(name):
+    """A simple case mapping from Perl, as a dictionary of the characters that it changes"""
+    rows = perl(
+        'my ($list, $map, $format) = prop_invmap("{}");'
+        'die "unexpected format $format" unless $format eq "a";'
+        'print "$list->[$_] $map->[$_]\\n" for 0..$#$list'.format(name)
+    ).split("\n")
+    rows = [tuple(int(v) for v in row.split()) for row in rows if row]
+    mapping = {}
+    for (start, value), (end, _) in zip(rows, rows[1:] + [(0x110000, 0)]):
+        # In the "adjusted" format, a run of characters maps to consecutive values
+        if value:
+            for c in range(start, end):
+                mapping[c] = value + c - start
+    return mapping
+
+
+def simple_mapping(full, name):
+    """A simple case mapping, which is the full one unless that has several characters"""
+    mapping = {}
+    fallback = None
+    for c in CHARS:
+        value = full(chr(c))
+        if len(value) == 1:
+            value = ord(value)
+        else:
+            if fallback is None:
+                fallback = case_mapping(name)
+            value = fallback.get(c, c)
+        if value != c:
+            mapping[c] = value
+    return mapping
+
+
+if unicodedata.unidata_version != UNICODE_VERSION:
+    raise SystemExit(
+        "Python has Unicode {}, not {}".format(unicodedata.unidata_version, UNICODE_VERSION)
+    )
+PERL_VERSION = perl("print Unicode::UCD::UnicodeVersion()")
+if tuple(map(int, PERL_VERSION.split("."))) < tuple(map(int, UNICODE_VERSION.split("."))):
+    raise SystemExit("Perl has Unicode {}, older than {}".format(PERL_VERSION, UNICODE_VERSION))
+
+ASSIGNED = {c for c in CHARS if unicodedata.category(chr(c)) != "Cn"}
+LETTERS = {
+    c for c in ASSIGNED if unicodedata.category(chr(c)) in ("Lu", "Ll", "Lt", "Lm", "Lo", "Nl")
+}
+# Unicode::UCD has no Other_Alphabetic, but it is what is alphabetic without being a letter
+OTHER_ALPHABETIC = (
+    property_set("Alphabetic")
+    - property_set("General_Category=L")
+    - property_set("General_Category=Nl")
+) & ASSIGNED
+
+ALPHABETIC = LETTERS | OTHER_ALPHABETIC
+UPPERCASE = {c for c in CHARS if chr(c).isupper()}
+LOWERCASE = {c for c in CHARS if chr(c).islower()}
+TO_UPPER = simple_mapping(str.upper, "Simple_Uppercase_Mapping")
+TO_LOWER = {c: _sre.unicode_tolower(c) for c in CHARS if _sre.unicode_tolower(c) != c}
+TO_TITLE = simple_mapping(str.title, "Simple_Titlecase_Mapping")
+
+
+def category(c):
+    return unicodedata.category(chr(c))
+
+
+def space(c):
+    # No-break spaces are left out, as they are meant to hold words together
+    return (
+        c in (0x09, 0x0A, 0x0B, 0x0C, 0x0D)
+        or category(c) == "Zs" and c not in (0x00A0, 0x2007, 0x202F)
+        or category(c) in ("Zl", "Zp")
+    )
+
+
+def alpha(c):
+    return c in ALPHABETIC or category(c) == "Nd" and not 0x30 <= c <= 0x39
+
+
+def print_(c):
+    return category(c) not in ("Cn", "Cs", "Cc", "Zl", "Zp")
+
+
+def punct(c):
+    return print_(c) and not space(c) and not alpha(c) and not 0x30 <= c <= 0x39
+
+
+def upper(c):
+    return c in UPPERCASE or TO_LOWER.get(c, c) != c
+
+
+def lower(c):
+    return c in LOWERCASE or TO_UPPER.get(c, c) != c
+
+
+def table(bits):
+    blocks = []
+    index = []
+    # The index takes up the first blocks of the table
+    first = (LIMIT // BLOCK) // (BLOCK // 8)
+    for start in range(0, LIMIT, BLOCK):
+        block = bytes(
+            sum(bits[start + i * 8 + j] << j for j in range(8)) for i in range(BLOCK // 8)
+        )
+        if block not in blocks:
+            blocks.append(block)
+        index.append(first + blocks.index(block))
+    if max(index) > 0xFF:
+        raise ValueError("too many distinct blocks for a byte index")
+    return index + [b for block in blocks for b in block]
+
+
+def ranges(chars):
+    result = []
+    for c in chars:
+        if result and result[-1][1] == c - 1:
+            result[-1][1] = c
+        else:
+            result.append([c, c])
+    return result
+
+
+def items(values, fmt):
+    """Fills lines with numbers, the way rustfmt lays them out"""
+    lines = []
+    line = "   "
+    for value in values:
+        item = " " + fmt(value) + ","
+        if len(line) + len(item) > 99:
+            lines.append(line)
+            line = "   "
+        line += item
+    lines.append(line)
+    return "\n".join(lines)
+
+
+def rows(values, fmt):
+    """One item per line, which is how rustfmt lays out arrays of tuples"""
+    return "\n".join("    " + fmt(value) + "," for value in values)
+
+
+def output(name):
+    path = os.path.join(os.path.dirname(os.path.abspath(__file__)), name + ".rs")
+    f = open(path, "w")
+    f.write(
+        "// Generated by gen_ctype.py from Unicode {}, do not edit\n\n".format(UNICODE_VERSION)
+    )
+    return f
+
+
+def write_class(name, doc, predicate):
+    chars = [c for c in CHARS if predicate(c)]
+    data = table([predicate(c) for c in range(LIMIT)])
+    rest = ranges(c for c in chars if c >= LIMIT)
+
+    with output(name) as f:
+        f.write("use crate::platform::types::*;\n\n")
+        f.write("/// {}\n".format(doc))
+        f.write("pub fn is(wc: usize) -> c_uchar {\n")
+        f.write("    if wc < 0x{:x} {{\n".format(LIMIT))
+        f.write("        return (table[(table[wc >> 8] as usize) * 32 + ((wc & 255) >> 3)] >> (wc & 7)) & 1;\n")
+        f.write("    }\n")
+        if rest:
+            f.write("    c_uchar::from(\n")
+            f.write("        ranges\n")
+            f.write("            .iter()\n")
+            f.write("            .any(|&(first, last)| wc >= first && wc <= last),\n")
+            f.write("    )\n")
+        else:
+            f.write("    0\n")
+        f.write("}\n\n")
+        f.write("const table: [c_uchar; {}] = [\n".format(len(data)))
+        f.write(items(data, str))
+        f.write("\n];\n")
+        if rest:
+            f.write("\n/// The characters past the first two planes\n")
+            f.write("const ranges: [(usize, usize); {}] = [\n".format(len(rest)))
+            f.write(rows(rest, lambda r: "(0x{:x}, 0x{:x})".format(*r)))
+            f.write("\n];\n")
+
+
+def runs(mapping):
+    """Splits a mapping into runs of the first and last character, the step between them and
+    the distance they map at"""
+    result = []
+    for c in sorted(mapping):
+        delta = mapping[c] - c
+        if result:
+            first, last, step, run_delta = result[-1]
+            if run_delta == delta and c - last in (1, 2) and (first == last or c - last == step):
+                result[-1] = [first, c, c - last, delta]
+                continue
+        result.append([c, c, 1, delta])
+    return result
+
+
+def write_casemap():
+    upper_runs = runs(TO_UPPER)
+    lower_runs = runs(TO_LOWER)
+    # Title case is upper case except for digraphs like dz, and Georgian, whose upper case
+    # letters are only used in all-caps text
+    title = sorted(
+        (c, TO_TITLE.get(c, c))
+        for c in set(TO_UPPER) | set(TO_TITLE)
+        if TO_TITLE.get(c, c) != TO_UPPER.get(c, c)
+    )
+
+    def run(r):
+        return "(0x{:x}, 0x{:x}, {}, {})".format(*r)
+
+    with output("casemap") as f:
+        f.write(
+            "/// Runs of characters with an upper case: the first and last character, the step\n"
+            "/// between the characters of the run, which is 2 where the cases alternate, and how\n"
+            "/// far from them their upper case is\n"
+        )
+        f.write("pub const TO_UPPER: [(u32, u32, u32, i32); {}] = [\n".format(len(upper_runs)))
+        f.write(rows(upper_runs, run))
+        f.write("\n];\n\n")
+        f.write("/// Runs of characters with a lower case, like `TO_UPPER`\n")
+        f.write("pub const TO_LOWER: [(u32, u32, u32, i32); {}] = [\n".format(len(lower_runs)))
+        f.write(rows(lower_runs, run))
+        f.write("\n];\n\n")
+        f.write("/// The characters whose title case is not their upper case, with their title case\n")
+        f.write("pub const TO_TITLE: [(u32, u32); {}] = [\n".format(len(title)))
+        f.write(rows(title, lambda t: "(0x{:x}, 0x{:x})".format(*t)))
+        f.write("\n];\n")
+
+
+write_class("alpha", "Whether a character is a letter, or a digit other than 0-9", alpha)
+write_class("lower", "Whether a character is lower case", lower)
+write_class("print", "Whether a character is assigned and printable", print_)
+write_class(
+    "punct",
+    "Whether a character is printable, but neither a space nor a letter or digit",
+    punct,
+)
+write_class("upper", "Whether a character is upper case", upper)
+write_casemap()
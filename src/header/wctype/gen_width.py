@@ -0,0 +1,118 @@
+#!/usr/bin/env python3
+"""Generates the tables behind wcwidth(): nonspacing.rs and wide.rs.
+
+The tables cover the first two planes, as wcwidth() handles the rest with a few ranges.
+They have the same layout as the musl tables in alpha.rs and punct.rs: each 256-character
+block of the bitmap is stored once, and the first 512 bytes say which block to use.
+
+The data comes from the unicodedata module, which has to be of UNICODE_VERSION, as Python 3.9
+and 3.10 are:
+
+    python3.9 src/header/wctype/gen_width.py
+"""
+
+import os
+import unicodedata
+
+# The one Unicode version behind gen_ctype.py, gen_width.py and locale/gen_ducet.py, so that
+# the classes, the widths and the collation order know the same characters. Change it in all
+# three at once.
+UNICODE_VERSION = "13.0.0"
+
+LIMIT = 0x20000
+BLOCK = 256
+
+# Format characters that are drawn, like the Arabic number sign that spans the digits after
+# it. This is the Prepended_Concatenation_Mark property from PropList.txt, which unicodedata
+# doesn't have.
+PREPENDED_CONCATENATION_MARKS = set(range(0x0600, 0x0606)) | {
+    0x06DD,
+    0x070F,
+    0x08E2,
+    0x110BD,
+    0x110CD,
+}
+
+
+def nonspacing(c):
+    """Combining marks and format characters, which take no column of their own"""
+    # Hangul medial vowels and final consonants join the initial consonant before them
+    if 0x1160 <= c <= 0x11FF or 0xD7B0 <= c <= 0xD7FF:
+        return True
+    # The soft hyphen shows up as a hyphen when it breaks a line, so like the marks above it
+    # keeps its column
+    if c == 0x00AD or c in PREPENDED_CONCATENATION_MARKS:
+        return False
+    return unicodedata.category(chr(c)) in ("Mn", "Me", "Cf")
+
+
+def wide(c):
+    """East Asian wide and fullwidth characters, which since Unicode 9 include every
+    character with emoji presentation"""
+    if 0xD800 <= c <= 0xDFFF:
+        return False
+    return unicodedata.east_asian_width(chr(c)) in ("W", "F") and not nonspacing(c)
+
+
+def table(predicate):
+    bits = [predicate(c) for c in range(LIMIT)]
+    blocks = []
+    index = []
+    # The index takes up the first blocks of the table
+    first = (LIMIT // BLOCK) // (BLOCK // 8)
+    for start in range(0, LIMIT, BLOCK):
+        block = bytes(
+            sum(bits[start + i * 8 + j] << j for j in range(8)) for i in range(BLOCK // 8)
+        )
+        if block not in blocks:
+            blocks.append(block)
+        index.append(first + blocks.index(block))
+    if max(index) > 0xFF:
+        raise ValueError("too many distinct blocks for a byte index")
+    return index + [b for block in blocks for b in block]
+
+
+def write(name, doc, predicate):
+    data = table(predicate)
+    lines = []
+    line = "   "
+    for value in data:
+        item = " {},".format(value)
+        if len(line) + len(item) > 99:
+            lines.append(line)
+            line = "   "
+        line += item
+    lines.append(line)
+
+    with open(os.path.join(os.path.dirname(os.path.abspath(__file__)), name + ".rs"), "w") as f:
+        f.write(
+            "// Generated by gen_width.py from Unicode {}, do not edit\n\n".format(UNICODE_VERSION)
+        )
+        f.write("use crate::platform::types::*;\n\n")
+        f.write("/// {}\n".format(doc))
+        f.write("pub fn is(wc: usize) -> c_uchar {\n")
+        f.write("    if wc < 0x{:x} {{\n".format(LIMIT))
+        f.write("        return (table[(table[wc >> 8] as usize) * 32 + ((wc & 255) >> 3)] >> (wc & 7)) & 1;\n")
+        f.write("    }\n")
+        f.write("    0\n")
+        f.write("}\n\n")
+        f.write("const table: [c_uchar; {}] = [\n".format(len(data)))
+        f.write("\n".join(lines))
+        f.write("\n];\n")
+
+
+if unicodedata.unidata_version != UNICODE_VERSION:
+    raise SystemExit(
+        "Python has Unicode {}, not {}".format(unicodedata.unidata_version, UNICODE_VERSION)
+    )
+
+write(
+    "nonspacing",
+    "Whether a character in the first two planes takes no column, like a combining mark",
+    nonspacing,
+)
+write(
+    "wide",
+    "Whether a character in the first two planes takes two columns",
+    wide,
+)
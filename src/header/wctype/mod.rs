@@ -1,17 +1,22 @@
-//! wchar implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/wctype.h.html
+//! wctype implementation for Redox, following https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/wctype.h.html
 
 use crate::{
     c_str::CStr,
-    header::ctype,
+    header::locale::{self, data, locale_t, Codeset, Locale},
     platform::types::*,
 };
-use self::casecmp::casemap;
 
 mod alpha;
-mod casecmp;
+mod casemap;
+mod lower;
+pub(crate) mod nonspacing;
+mod print;
 mod punct;
+mod upper;
+pub(crate) mod wide;
 
 pub type wctype_t = u32;
+pub type wctrans_t = u32;
 
 pub const WEOF: wint_t = 0xFFFF_FFFFu32;
 
@@ -28,29 +33,140 @@ pub const WCTYPE_SPACE: wctype_t = 10;
 pub const WCTYPE_UPPER: wctype_t = 11;
 pub const WCTYPE_XDIGIT: wctype_t = 12;
 
+pub(crate) const WCTRANS_TOLOWER: wctrans_t = 1;
+pub(crate) const WCTRANS_TOUPPER: wctrans_t = 2;
+pub(crate) const WCTRANS_TOTITLE: wctrans_t = 3;
+
+/// Classes and mappings that a locale defines itself are numbered from here, in the order the
+/// locale defines them. Like glibc's, they only mean something to the locale they came from.
+const LOCALE_DEFINED: u32 = 0x100;
+
+/// Whether `wc` is in the character set of `locale`. Characters outside of it are not in any
+/// class and map to themselves, so that the "C" locale only knows about ASCII.
+fn in_charset(wc: wint_t, locale: &Locale) -> bool {
+    match locale.ctype().codeset {
+        Codeset::Ascii => wc < 0x80,
+        Codeset::Latin1 => wc <= 0xff,
+        Codeset::Utf8 => wc < 0x11_0000,
+    }
+}
+
+fn is_space(wc: wint_t) -> bool {
+    // The no-break spaces U+00A0, U+2007 and U+202F are left out, as they hold words together
+    is_blank(wc) || matches!(wc, 0x0a..=0x0d | 0x2028 | 0x2029)
+}
+
+fn is_blank(wc: wint_t) -> bool {
+    matches!(
+        wc,
+        0x09 | 0x20 | 0x1680 | 0x2000..=0x2006 | 0x2008..=0x200a | 0x205f | 0x3000
+    )
+}
+
+fn is_cntrl(wc: wint_t) -> bool {
+    matches!(wc, 0..=0x1f | 0x7f..=0x9f | 0x2028 | 0x2029)
+}
+
+fn is_digit(wc: wint_t) -> bool {
+    // Other scripts' digits are alphabetic instead, as POSIX only allows 0-9 here
+    wc.wrapping_sub('0' as wint_t) < 10
+}
+
+fn is_xdigit(wc: wint_t) -> bool {
+    is_digit(wc) || (wc | 32).wrapping_sub('a' as wint_t) < 6
+}
+
+/// Whether `wc` is in `class` in `locale`.
+pub(crate) fn is_class(wc: wint_t, class: wctype_t, locale: &Locale) -> bool {
+    if class >= LOCALE_DEFINED {
+        return locale
+            .ctype()
+            .classes
+            .get((class - LOCALE_DEFINED) as usize)
+            .map_or(false, |class| class.contains(wc));
+    }
+    if !in_charset(wc, locale) {
+        return false;
+    }
+
+    let wc_usize = wc as usize;
+    match class {
+        WCTYPE_ALNUM => is_digit(wc) || alpha::is(wc_usize) != 0,
+        WCTYPE_ALPHA => alpha::is(wc_usize) != 0,
+        WCTYPE_BLANK => is_blank(wc),
+        WCTYPE_CNTRL => is_cntrl(wc),
+        WCTYPE_DIGIT => is_digit(wc),
+        WCTYPE_GRAPH => !is_space(wc) && print::is(wc_usize) != 0,
+        WCTYPE_LOWER => lower::is(wc_usize) != 0,
+        WCTYPE_PRINT => print::is(wc_usize) != 0,
+        WCTYPE_PUNCT => punct::is(wc_usize) != 0,
+        WCTYPE_SPACE => is_space(wc),
+        WCTYPE_UPPER => upper::is(wc_usize) != 0,
+        WCTYPE_XDIGIT => is_xdigit(wc),
+        _ => false,
+    }
+}
+
+/// Looks `wc` up in the runs of a case mapping.
+fn convert_case(wc: wint_t, runs: &[(u32, u32, u32, i32)]) -> wint_t {
+    let i = runs.partition_point(|&(_, last, _, _)| last < wc);
+    match runs.get(i) {
+        Some(&(first, _, step, delta)) if wc >= first && (wc - first) % step == 0 => {
+            (wc as i32 + delta) as wint_t
+        }
+        _ => wc,
+    }
+}
+
+/// Maps `wc` by `trans` in `locale`. Mappings to characters outside of the locale's character
+/// set are left out.
+pub(crate) fn map(wc: wint_t, trans: wctrans_t, locale: &Locale) -> wint_t {
+    let ctype = locale.ctype();
+    if trans >= LOCALE_DEFINED {
+        return ctype
+            .maps
+            .get((trans - LOCALE_DEFINED) as usize)
+            .map_or(wc, |map| map.apply(wc));
+    }
+    if !in_charset(wc, locale) {
+        return wc;
+    }
+
+    let overrides = match trans {
+        WCTRANS_TOLOWER => ctype.tolower,
+        WCTRANS_TOUPPER => ctype.toupper,
+        WCTRANS_TOTITLE => ctype.totitle,
+        _ => return wc,
+    };
+    if let Some(mapped) = data::lookup(overrides, wc) {
+        return mapped;
+    }
+
+    let mapped = match trans {
+        WCTRANS_TOLOWER => convert_case(wc, &casemap::TO_LOWER),
+        WCTRANS_TOUPPER => convert_case(wc, &casemap::TO_UPPER),
+        _ => data::lookup(&casemap::TO_TITLE, wc)
+            .unwrap_or_else(|| convert_case(wc, &casemap::TO_UPPER)),
+    };
+    if in_charset(mapped, locale) {
+        mapped
+    } else {
+        wc
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn iswctype(wc: wint_t, desc: wctype_t) -> c_int {
-    match desc {
-        WCTYPE_ALNUM => iswalnum(wc),
-        WCTYPE_ALPHA => iswalpha(wc),
-        WCTYPE_BLANK => iswblank(wc),
-        WCTYPE_CNTRL => iswcntrl(wc),
-        WCTYPE_DIGIT => iswdigit(wc),
-        WCTYPE_GRAPH => iswgraph(wc),
-        WCTYPE_LOWER => iswlower(wc),
-        WCTYPE_PRINT => iswprint(wc),
-        WCTYPE_PUNCT => iswpunct(wc),
-        WCTYPE_SPACE => iswspace(wc),
-        WCTYPE_UPPER => iswupper(wc),
-        WCTYPE_XDIGIT => iswxdigit(wc),
-        _ => 0,
-    }
+    c_int::from(is_class(wc, desc, locale::current()))
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn wctype(name: *const c_char) -> wctype_t {
-    let name_cstr = CStr::from_ptr(name);
-    match name_cstr.to_bytes() {
+pub unsafe extern "C" fn iswctype_l(wc: wint_t, desc: wctype_t, locale: locale_t) -> c_int {
+    c_int::from(is_class(wc, desc, locale::get(locale)))
+}
+
+fn find_class(name: &[u8], locale: &Locale) -> wctype_t {
+    match name {
         b"alnum" => WCTYPE_ALNUM,
         b"alpha" => WCTYPE_ALPHA,
         b"blank" => WCTYPE_BLANK,
@@ -63,101 +179,195 @@ pub unsafe extern "C" fn wctype(name: *const c_char) -> wctype_t {
         b"space" => WCTYPE_SPACE,
         b"upper" => WCTYPE_UPPER,
         b"xdigit" => WCTYPE_XDIGIT,
-        _ => 0
+        _ => locale
+            .ctype()
+            .classes
+            .iter()
+            .position(|class| class.name == name)
+            .map_or(0, |i| LOCALE_DEFINED + i as wctype_t),
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn wctype(name: *const c_char) -> wctype_t {
+    find_class(CStr::from_ptr(name).to_bytes(), locale::current())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wctype_l(name: *const c_char, locale: locale_t) -> wctype_t {
+    find_class(CStr::from_ptr(name).to_bytes(), locale::get(locale))
+}
+
 #[no_mangle]
 pub extern "C" fn iswalnum(wc: wint_t) -> c_int {
-    c_int::from(iswdigit(wc) != 0 || iswalpha(wc) != 0)
+    iswctype(wc, WCTYPE_ALNUM)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswalnum_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_ALNUM, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswalpha(wc: wint_t) -> c_int {
-    c_int::from(alpha::is(wc as usize))
+    iswctype(wc, WCTYPE_ALPHA)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswalpha_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_ALPHA, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswblank(wc: wint_t) -> c_int {
-    ctype::isblank(wc as c_int)
+    iswctype(wc, WCTYPE_BLANK)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswblank_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_BLANK, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswcntrl(wc: wint_t) -> c_int {
-    c_int::from(
-        wc < 32 ||
-        wc.wrapping_sub(0x7f) < 33 ||
-        wc.wrapping_sub(0x2028) < 2 ||
-        wc.wrapping_sub(0xfff9) < 3
-    )
+    iswctype(wc, WCTYPE_CNTRL)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswcntrl_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_CNTRL, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswdigit(wc: wint_t) -> c_int {
-    c_int::from(wc.wrapping_sub('0' as wint_t) < 10)
+    c_int::from(is_digit(wc))
+}
+
+#[no_mangle]
+pub extern "C" fn iswdigit_l(wc: wint_t, _locale: locale_t) -> c_int {
+    iswdigit(wc)
 }
 
 #[no_mangle]
 pub extern "C" fn iswgraph(wc: wint_t) -> c_int {
-    c_int::from(iswspace(wc) == 0 && iswprint(wc) != 0)
+    iswctype(wc, WCTYPE_GRAPH)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswgraph_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_GRAPH, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswlower(wc: wint_t) -> c_int {
-    c_int::from(towupper(wc) != wc)
+    iswctype(wc, WCTYPE_LOWER)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswlower_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_LOWER, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswprint(wc: wint_t) -> c_int {
-    if wc < 0xff {
-        c_int::from((wc+1 & 0x7f) >= 0x21)
-    } else if wc < 0x2028
-        || wc.wrapping_sub(0x202a) < 0xd800-0x202a
-        || wc.wrapping_sub(0xe000) < 0xfff9-0xe000 {
-        1
-    } else if wc.wrapping_sub(0xfffc) > 0x10ffff-0xfffc
-        || (wc&0xfffe)==0xfffe {
-        0
-    } else {
-        1
-    }
+    iswctype(wc, WCTYPE_PRINT)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswprint_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_PRINT, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswpunct(wc: wint_t) -> c_int {
-    c_int::from(punct::is(wc as usize))
+    iswctype(wc, WCTYPE_PUNCT)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswpunct_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_PUNCT, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswspace(wc: wint_t) -> c_int {
-    c_int::from([
-        ' ' as wint_t, '\t' as wint_t, '\n' as wint_t, '\r' as wint_t,
-        11, 12, 0x0085,
-        0x2000, 0x2001, 0x2002, 0x2003, 0x2004, 0x2005,
-        0x2006, 0x2008, 0x2009, 0x200a,
-        0x2028, 0x2029, 0x205f, 0x3000
-    ].contains(&wc))
+    iswctype(wc, WCTYPE_SPACE)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswspace_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_SPACE, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswupper(wc: wint_t) -> c_int {
-    c_int::from(towlower(wc) != wc)
+    iswctype(wc, WCTYPE_UPPER)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iswupper_l(wc: wint_t, locale: locale_t) -> c_int {
+    iswctype_l(wc, WCTYPE_UPPER, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn iswxdigit(wc: wint_t) -> c_int {
-    c_int::from(
-        wc.wrapping_sub('0' as wint_t) < 10 ||
-        (wc|32).wrapping_sub('a' as wint_t) < 6
-    )
+    c_int::from(is_xdigit(wc))
+}
+
+#[no_mangle]
+pub extern "C" fn iswxdigit_l(wc: wint_t, _locale: locale_t) -> c_int {
+    iswxdigit(wc)
+}
+
+fn find_trans(name: &[u8], locale: &Locale) -> wctrans_t {
+    match name {
+        b"tolower" => WCTRANS_TOLOWER,
+        b"toupper" => WCTRANS_TOUPPER,
+        b"totitle" => WCTRANS_TOTITLE,
+        _ => locale
+            .ctype()
+            .maps
+            .iter()
+            .position(|map| map.name == name)
+            .map_or(0, |i| LOCALE_DEFINED + i as wctrans_t),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wctrans(name: *const c_char) -> wctrans_t {
+    find_trans(CStr::from_ptr(name).to_bytes(), locale::current())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wctrans_l(name: *const c_char, locale: locale_t) -> wctrans_t {
+    find_trans(CStr::from_ptr(name).to_bytes(), locale::get(locale))
+}
+
+#[no_mangle]
+pub extern "C" fn towctrans(wc: wint_t, trans: wctrans_t) -> wint_t {
+    map(wc, trans, locale::current())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn towctrans_l(wc: wint_t, trans: wctrans_t, locale: locale_t) -> wint_t {
+    map(wc, trans, locale::get(locale))
 }
 
 #[no_mangle]
 pub extern "C" fn towlower(wc: wint_t) -> wint_t {
-    casemap(wc, 0)
+    towctrans(wc, WCTRANS_TOLOWER)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn towlower_l(wc: wint_t, locale: locale_t) -> wint_t {
+    towctrans_l(wc, WCTRANS_TOLOWER, locale)
 }
 
 #[no_mangle]
 pub extern "C" fn towupper(wc: wint_t) -> wint_t {
-    casemap(wc, 1)
+    towctrans(wc, WCTRANS_TOUPPER)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn towupper_l(wc: wint_t, locale: locale_t) -> wint_t {
+    towctrans_l(wc, WCTRANS_TOUPPER, locale)
 }
@@ -0,0 +1,193 @@
+// Generated by gen_width.py from Unicode 13.0.0, do not edit
+
+use crate::platform::types::*;
+
+/// Whether a character in the first two planes takes two columns
+pub fn is(wc: usize) -> c_uchar {
+    if wc < 0x20000 {
+        return (table[(table[wc >> 8] as usize) * 32 + ((wc & 255) >> 3)] >> (wc & 7)) & 1;
+    }
+    0
+}
+
+const table: [c_uchar; 4384] = [
+    16, 16, 16, 17, 16, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 16, 16, 33, 34,
+    35, 36, 37, 38, 39, 40, 16, 41, 42, 43, 16, 44, 45, 46, 47, 48, 16, 16, 16, 49, 50, 51, 52, 53,
+    54, 53, 55, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 56, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 57, 16, 58, 59,
+    60, 61, 62, 63, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 64,
+    16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+    16, 16, 16, 16, 16, 16, 16, 16, 16, 53, 53, 65, 16, 66, 67, 68, 69, 70, 71, 72, 73, 74, 16, 75,
+    76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 53, 95, 96, 97, 98,
+    16, 16, 16, 99, 100, 101, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 16, 16, 16, 16, 102, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 16, 16, 103, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 16, 16, 104, 105, 53, 53, 106, 107, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 108, 53, 53, 53, 53, 53, 53, 53,
+    53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 109, 110, 111, 112, 113, 114, 115, 116, 16, 16,
+    117, 53, 53, 53, 53, 53, 118, 119, 120, 53, 53, 53, 53, 53, 121, 122, 53, 53, 123, 124, 125,
+    53, 126, 127, 53, 128, 129, 130, 131, 132, 133, 134, 135, 136, 53, 53, 53, 53, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 15, 40, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 128, 1, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 120, 224, 255,
+    0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 252, 255, 0, 0, 0, 0, 0,
+    0, 0, 24, 0, 0, 0, 0, 0, 192, 0, 128, 0, 0, 0, 176, 0, 248, 255, 255, 255, 255, 255, 255, 0, 0,
+    32, 0, 0, 255, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 96, 6, 0,
+    0, 2, 58, 12, 96, 134, 127, 79, 48, 0, 0, 128, 17, 120, 6, 0, 0, 2, 146, 44, 120, 198, 253,
+    161, 63, 0, 128, 255, 17, 64, 4, 0, 0, 2, 18, 12, 64, 196, 254, 255, 48, 0, 252, 1, 17, 96, 6,
+    0, 0, 2, 18, 12, 96, 198, 31, 79, 48, 0, 0, 255, 19, 56, 194, 41, 231, 56, 0, 60, 56, 194, 126,
+    255, 63, 0, 0, 248, 0, 32, 2, 0, 0, 2, 0, 28, 32, 194, 159, 248, 48, 0, 127, 0, 0, 32, 2, 0, 0,
+    2, 16, 12, 32, 194, 159, 191, 48, 0, 249, 255, 0, 32, 2, 0, 0, 0, 0, 0, 32, 2, 15, 0, 48, 0, 0,
+    0, 17, 0, 128, 3, 0, 0, 4, 208, 128, 123, 160, 0, 63, 0, 227, 255, 1, 0, 0, 0, 0, 0, 0, 120, 0,
+    0, 0, 240, 255, 255, 255, 255, 41, 8, 0, 0, 80, 0, 0, 192, 160, 192, 0, 12, 255, 255, 255, 255,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 224, 1, 0, 0, 0, 0, 1, 0, 0, 0, 32, 0, 32, 0, 248, 255,
+    255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 223,
+    0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 194, 128, 194, 0, 0, 0, 0,
+    0, 194, 0, 0, 0, 0, 194, 128, 194, 0, 128, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0,
+    24, 0, 0, 0, 224, 0, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 192, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 32, 224, 255, 0,
+    0, 128, 255, 0, 0, 240, 255, 0, 32, 242, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 252, 0,
+    252, 0, 128, 0, 252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 0, 0, 0, 0, 248, 0, 0, 0, 0, 0,
+    0, 0, 0, 192, 255, 0, 0, 0, 128, 0, 240, 0, 240, 14, 0, 0, 0, 0, 192, 224, 255, 0, 0, 0, 0, 0,
+    240, 0, 0, 0, 252, 0, 56, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 96, 0,
+    252, 0, 252, 0, 192, 0, 0, 254, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    240, 0, 0, 0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 15, 0, 0, 0, 0, 0, 0,
+    0, 7, 0, 28, 0, 0, 0, 0, 0, 0, 0, 254, 0, 0, 0, 0, 0, 24, 0, 255, 0, 0, 0, 0, 0, 248, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    192, 192, 0, 0, 0, 0, 192, 192, 0, 85, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 32, 0, 32, 0, 48, 16, 0,
+    0, 35, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 12, 0, 0, 128, 0, 224, 0, 0, 0, 0, 255,
+    255, 0, 0, 0, 0, 254, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 30, 9, 0, 0, 0, 0, 0, 128, 255, 255, 255, 0, 248, 255, 255, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 48, 0, 0, 0, 0, 0, 0, 255, 15, 0, 0, 0,
+    0, 128, 0, 0, 8, 0, 2, 12, 0, 96, 48, 64, 16, 0, 0, 4, 44, 36, 32, 12, 0, 0, 0, 1, 0, 0, 0, 80,
+    184, 0, 0, 0, 0, 0, 0, 0, 224, 0, 0, 0, 1, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0,
+    0, 0, 0, 33, 0, 0, 0, 48, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    128, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 1, 0, 0, 0,
+    0, 64, 223, 0, 0, 0, 0, 0, 0, 0, 127, 254, 127, 0, 0, 128, 255, 128, 128, 128, 128, 128, 128,
+    128, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 195, 255, 127, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 249, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 240, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 248, 255, 255, 255,
+    255, 31, 0, 0, 0, 0, 0, 0, 224, 0, 252, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 192,
+    63, 0, 252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 127, 255, 255, 255, 255, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 64, 0, 60, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 128, 255, 0, 192, 0, 12, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 248, 255, 255, 7, 0, 0, 128, 255, 129, 129, 129, 255, 128, 128, 0, 0,
+    0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 252, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 255, 7, 31, 0, 0, 128, 160, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 252, 255, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 0, 192, 0, 0, 255, 255, 0, 0, 255, 255,
+    255, 255, 255, 255, 255, 255, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 3, 3,
+    3, 227, 255, 128, 255, 193, 0, 16, 0, 0, 128, 0, 0, 72, 0, 192, 0, 192, 255, 255, 255, 255, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 120, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 128, 0, 224, 254, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 192, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 254,
+    255, 0, 0, 0, 240, 0, 0, 0, 0, 240, 31, 0, 0, 0, 248, 0, 0, 0, 0, 0, 248, 0, 0, 0, 64, 0, 0, 0,
+    0, 240, 0, 192, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 192, 0, 252, 0, 0, 0, 0, 240, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 240,
+    127, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    0, 0, 0, 0, 0, 0, 128, 255, 0, 0, 192, 255, 0, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 192, 2, 0, 0, 0, 0, 64, 110, 0, 0, 64, 0, 0,
+    0, 0, 0, 0, 0, 0, 128, 127, 0, 255, 255, 255, 255, 255, 255, 0, 0, 200, 7, 0, 0, 0, 112, 0, 0,
+    0, 124, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 3, 0, 0, 0, 0,
+    0, 144, 15, 16, 1, 0, 0, 192, 120, 0, 254, 0, 254, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255,
+    0, 0, 0, 0, 128, 7, 128, 255, 0, 0, 0, 0, 0, 0, 192, 1, 0, 0, 192, 0, 0, 0, 248, 0, 0, 0, 252,
+    225, 255, 1, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254,
+    255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 248, 255, 0, 0, 0, 0, 0, 0, 248, 3, 0, 0, 0, 0,
+    0, 255, 0, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 0, 0, 0, 128, 0, 0, 0, 0, 0, 196, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0,
+    0, 0, 0, 255, 0, 0, 0, 0, 0, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0,
+    240, 255, 255, 0, 0, 128, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 3, 0, 0, 0, 255, 127, 0, 0, 0,
+    0, 0, 0, 0, 0, 252, 223, 0, 0, 0, 254, 0, 252, 0, 0, 0, 0, 0, 0, 32, 0, 0, 255, 0, 0, 0, 0,
+    128, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 224, 255, 0, 0, 4, 0, 0, 0, 0, 128, 255,
+    255, 255, 255, 255, 255, 255, 255, 128, 66, 0, 64, 0, 252, 0, 0, 0, 0, 0, 0, 0, 248, 0, 252,
+    16, 96, 6, 0, 0, 2, 18, 4, 96, 198, 126, 31, 48, 224, 224, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 252,
+    255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 252, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0,
+    192, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 224, 255, 0, 252, 0, 224, 255, 255, 0, 0, 0,
+    0, 0, 0, 0, 254, 0, 252, 255, 255, 255, 255, 255, 255, 0, 0, 0, 24, 0, 240, 0, 0, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 240, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 127, 128, 13, 144, 0, 0, 0, 64, 6, 128, 255, 0, 252,
+    255, 255, 255, 255, 255, 255, 255, 255, 0, 3, 0, 0, 0, 0, 0, 3, 224, 255, 255, 255, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 254,
+    0, 2, 0, 0, 0, 0, 128, 0, 192, 255, 0, 0, 0, 224, 0, 0, 0, 0, 3, 0, 0, 1, 128, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 128, 4, 0, 0, 0, 0, 128, 75, 0, 255, 0, 252, 64, 2, 0, 0, 0, 128,
+    4, 254, 0, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 0, 0, 0, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 0, 0, 0, 0, 0, 0, 252, 127, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 224, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 128, 0,
+    254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 128, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0,
+    0, 0, 254, 0, 0, 0, 128, 0, 60, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0,
+    0, 0, 192, 192, 255, 0, 0, 0, 0, 0, 0, 0, 0, 192, 255, 0, 4, 4, 0, 0, 31, 0, 0, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 255, 255, 255, 255, 255,
+    255, 255, 255, 239, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 0, 224, 0, 254,
+    0, 12, 240, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 255, 0, 0, 0, 0, 128, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 255, 255, 0, 0, 0, 0, 0,
+    0, 0, 0, 192, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 0, 0, 240, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 255, 0, 0, 0, 254, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 32, 155, 33, 0, 20, 16, 0, 0, 0, 0, 0, 0, 0, 64, 24, 32, 32,
+    0, 0, 0, 132, 160, 3, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 255, 7, 1, 0, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 128, 0, 0, 6, 36, 248, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0,
+    0, 0, 224, 0, 192, 0, 60, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 124, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 128, 255, 255, 255,
+    255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 0, 60, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 1, 0, 0, 0, 0, 0, 0, 0, 224, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 1, 0, 0, 0, 0, 0, 0, 192, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 16, 0, 0, 0, 105, 1, 8, 245, 123,
+    21, 105, 85, 105, 8, 8, 161, 0, 4, 0, 240, 17, 4, 0, 240, 255, 255, 255, 255, 255, 255, 252,
+    255, 16, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 255, 0, 128, 1, 0, 1, 128,
+    1, 0, 0, 0, 192, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 254, 7, 0, 192,
+    255, 255, 255, 255, 255, 255, 63, 0, 0, 0, 255, 255, 255, 255, 1, 224, 191, 255, 255, 255, 255,
+    255, 255, 255, 255, 223, 255, 255, 15, 0, 255, 255, 255, 255, 255, 135, 15, 0, 255, 255, 17,
+    255, 255, 255, 255, 255, 255, 255, 255, 127, 253, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 159, 255, 255, 255, 255, 255,
+    255, 255, 63, 0, 120, 255, 255, 255, 0, 0, 4, 0, 0, 96, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    248, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255,
+    255, 255, 255, 255, 63, 16, 231, 255, 0, 248, 240, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 240, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 255, 255, 255, 255, 0, 240, 0, 0, 0, 0, 0,
+    0, 0, 255, 0, 252, 0, 0, 0, 0, 0, 255, 0, 0, 0, 192, 252, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 0, 240, 255, 255, 255, 255, 255, 247, 191, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 240, 255, 0, 192, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0,
+    0, 0, 248, 255, 255, 255, 255, 0, 252,
+];
@@ -133,6 +133,12 @@ pub fn static_init(sp: &'static Stack) {
             _ => (),
         }
     }
+
+    // Without TLS, a TCB is still needed to hold the state of the main thread
+    unsafe {
+        let tcb = Tcb::new(0).expect_notls("failed to allocate TCB");
+        tcb.activate();
+    }
 }
 
 #[cfg(any(target_os = "linux", target_os = "redox"))]
@@ -7,6 +7,7 @@ use crate::{
     header::sys_mman,
     ld_so::{linker::Linker, ExpectTlsFree},
     platform::{Pal, Sys},
+    pthread::Pthread,
     sync::mutex::Mutex,
 };
 
@@ -49,6 +50,8 @@ pub struct Tcb {
     pub linker_ptr: *const Mutex<Linker>,
     /// pointer to rust memory allocator structure
     pub mspace: usize,
+    /// Thread state used by pthread.h
+    pub pthread: Pthread,
 }
 
 impl Tcb {
@@ -71,6 +74,7 @@ impl Tcb {
                 num_copied_masters: 0,
                 linker_ptr: ptr::null(),
                 mspace: 0,
+                pthread: Pthread::new(),
             },
         );
 
@@ -168,6 +172,12 @@ impl Tcb {
         Self::os_arch_activate(self.tcb_ptr as usize);
     }
 
+    /// Unmap the TLS and the TCB itself, for a thread that has exited
+    pub unsafe fn unmap(&mut self) {
+        let tls_start = self.tls_end.sub(self.tls_len);
+        sys_mman::munmap(tls_start as *mut _, self.tls_len + self.tcb_len);
+    }
+
     /// Mapping with correct flags for TCB and TLS
     unsafe fn map(size: usize) -> Result<&'static mut [u8]> {
         let ptr = sys_mman::mmap(
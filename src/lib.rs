@@ -54,6 +54,7 @@ pub mod header;
 pub mod io;
 pub mod ld_so;
 pub mod platform;
+pub mod pthread;
 pub mod start;
 pub mod sync;
 
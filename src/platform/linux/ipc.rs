@@ -0,0 +1,62 @@
+use super::{
+    super::{types::*, PalIpc},
+    e, Sys,
+};
+use crate::header::{sys_msg::msqid_ds, sys_sem::sembuf, sys_shm::shmid_ds, time::timespec};
+
+impl PalIpc for Sys {
+    unsafe fn msgctl(msqid: c_int, cmd: c_int, buf: *mut msqid_ds) -> c_int {
+        e(syscall!(MSGCTL, msqid, cmd, buf)) as c_int
+    }
+
+    fn msgget(key: key_t, msgflg: c_int) -> c_int {
+        unsafe { e(syscall!(MSGGET, key, msgflg)) as c_int }
+    }
+
+    unsafe fn msgrcv(
+        msqid: c_int,
+        msgp: *mut c_void,
+        msgsz: size_t,
+        msgtyp: c_long,
+        msgflg: c_int,
+    ) -> ssize_t {
+        e(syscall!(MSGRCV, msqid, msgp, msgsz, msgtyp, msgflg)) as ssize_t
+    }
+
+    unsafe fn msgsnd(msqid: c_int, msgp: *const c_void, msgsz: size_t, msgflg: c_int) -> c_int {
+        e(syscall!(MSGSND, msqid, msgp, msgsz, msgflg)) as c_int
+    }
+
+    unsafe fn semctl(semid: c_int, semnum: c_int, cmd: c_int, arg: c_ulong) -> c_int {
+        e(syscall!(SEMCTL, semid, semnum, cmd, arg)) as c_int
+    }
+
+    fn semget(key: key_t, nsems: c_int, semflg: c_int) -> c_int {
+        unsafe { e(syscall!(SEMGET, key, nsems, semflg)) as c_int }
+    }
+
+    unsafe fn semtimedop(
+        semid: c_int,
+        sops: *mut sembuf,
+        nsops: size_t,
+        timeout: *const timespec,
+    ) -> c_int {
+        e(syscall!(SEMTIMEDOP, semid, sops, nsops, timeout)) as c_int
+    }
+
+    unsafe fn shmat(shmid: c_int, shmaddr: *const c_void, shmflg: c_int) -> *mut c_void {
+        e(syscall!(SHMAT, shmid, shmaddr, shmflg)) as *mut c_void
+    }
+
+    unsafe fn shmctl(shmid: c_int, cmd: c_int, buf: *mut shmid_ds) -> c_int {
+        e(syscall!(SHMCTL, shmid, cmd, buf)) as c_int
+    }
+
+    unsafe fn shmdt(shmaddr: *const c_void) -> c_int {
+        e(syscall!(SHMDT, shmaddr)) as c_int
+    }
+
+    fn shmget(key: key_t, size: size_t, shmflg: c_int) -> c_int {
+        unsafe { e(syscall!(SHMGET, key, size, shmflg)) as c_int }
+    }
+}
@@ -4,7 +4,7 @@ use core_io::Write;
 use super::{errno, types::*, Pal};
 use crate::{
     c_str::CStr,
-    header::{dirent::dirent, signal::SIGCHLD, sys_stat::S_IFIFO},
+    header::{dirent::dirent, sched::sched_param, signal::SIGCHLD, sys_stat::S_IFIFO},
 };
 // use header::sys_resource::rusage;
 use crate::header::{
@@ -17,9 +17,12 @@ use crate::header::{
 use crate::header::{sys_utsname::utsname, time::timespec};
 
 mod epoll;
+mod ipc;
 mod ptrace;
 mod signal;
 mod socket;
+mod timer;
+mod vfork;
 
 const AT_FDCWD: c_int = -100;
 const AT_EMPTY_PATH: c_int = 0x1000;
@@ -30,7 +33,10 @@ const CLONE_VM: usize = 0x0100;
 const CLONE_FS: usize = 0x0200;
 const CLONE_FILES: usize = 0x0400;
 const CLONE_SIGHAND: usize = 0x0800;
+const CLONE_VFORK: usize = 0x4000;
 const CLONE_THREAD: usize = 0x00010000;
+const CLONE_PARENT_SETTID: usize = 0x00100000;
+const CLONE_CHILD_CLEARTID: usize = 0x00200000;
 
 #[repr(C)]
 #[derive(Default)]
@@ -128,11 +134,17 @@ impl Pal for Sys {
 
     fn exit(status: c_int) -> ! {
         unsafe {
-            syscall!(EXIT, status);
+            syscall!(EXIT_GROUP, status);
         }
         loop {}
     }
 
+    unsafe fn exit_thread(_os_tid: *mut pid_t) -> ! {
+        // The kernel clears it, as the thread was started with CLONE_CHILD_CLEARTID
+        syscall!(EXIT, 0);
+        loop {}
+    }
+
     fn fchdir(fildes: c_int) -> c_int {
         e(unsafe { syscall!(FCHDIR, fildes) }) as c_int
     }
@@ -267,6 +279,10 @@ impl Pal for Sys {
         e(syscall!(GETRLIMIT, resource, rlim)) as c_int
     }
 
+    fn getsid(pid: pid_t) -> pid_t {
+        e(unsafe { syscall!(GETSID, pid) }) as pid_t
+    }
+
     fn gettid() -> pid_t {
         e(unsafe { syscall!(GETTID) }) as pid_t
     }
@@ -360,8 +376,14 @@ impl Pal for Sys {
     }
 
     #[cfg(target_arch = "x86_64")]
-    unsafe fn pte_clone(stack: *mut usize) -> pid_t {
-        let flags = CLONE_VM | CLONE_FS | CLONE_FILES | CLONE_SIGHAND | CLONE_THREAD;
+    unsafe fn rlct_clone(stack: *mut usize, os_tid: *mut pid_t) -> pid_t {
+        let flags = CLONE_VM
+            | CLONE_FS
+            | CLONE_FILES
+            | CLONE_SIGHAND
+            | CLONE_THREAD
+            | CLONE_PARENT_SETTID
+            | CLONE_CHILD_CLEARTID;
         let pid;
         asm!("
             # Call clone syscall
@@ -397,8 +419,8 @@ impl Pal for Sys {
             inout("rax") SYS_CLONE => pid,
             inout("rdi") flags => _,
             inout("rsi") stack => _,
-            inout("rdx") 0 => _,
-            inout("r10") 0 => _,
+            inout("rdx") os_tid => _,
+            inout("r10") os_tid => _,
             inout("r8") 0 => _,
             //TODO: out("rbx") _,
             out("rcx") _,
@@ -436,6 +458,35 @@ impl Pal for Sys {
         e(unsafe { syscall!(UNLINKAT, AT_FDCWD, path.as_ptr(), AT_REMOVEDIR) }) as c_int
     }
 
+    fn sched_get_priority_max(policy: c_int) -> c_int {
+        e(unsafe { syscall!(SCHED_GET_PRIORITY_MAX, policy) }) as c_int
+    }
+
+    fn sched_get_priority_min(policy: c_int) -> c_int {
+        e(unsafe { syscall!(SCHED_GET_PRIORITY_MIN, policy) }) as c_int
+    }
+
+    fn sched_getparam(pid: pid_t, param: &mut sched_param) -> c_int {
+        e(unsafe { syscall!(SCHED_GETPARAM, pid, param as *mut sched_param) }) as c_int
+    }
+
+    fn sched_getscheduler(pid: pid_t) -> c_int {
+        e(unsafe { syscall!(SCHED_GETSCHEDULER, pid) }) as c_int
+    }
+
+    fn sched_rr_get_interval(pid: pid_t, interval: &mut timespec) -> c_int {
+        e(unsafe { syscall!(SCHED_RR_GET_INTERVAL, pid, interval as *mut timespec) }) as c_int
+    }
+
+    fn sched_setparam(pid: pid_t, param: &sched_param) -> c_int {
+        e(unsafe { syscall!(SCHED_SETPARAM, pid, param as *const sched_param) }) as c_int
+    }
+
+    fn sched_setscheduler(pid: pid_t, policy: c_int, param: &sched_param) -> c_int {
+        e(unsafe { syscall!(SCHED_SETSCHEDULER, pid, policy, param as *const sched_param) })
+            as c_int
+    }
+
     fn sched_yield() -> c_int {
         e(unsafe { syscall!(SCHED_YIELD) }) as c_int
     }
@@ -452,6 +503,10 @@ impl Pal for Sys {
         e(unsafe { syscall!(SETREUID, ruid, euid) }) as c_int
     }
 
+    fn setsid() -> pid_t {
+        e(unsafe { syscall!(SETSID) }) as pid_t
+    }
+
     fn symlink(path1: &CStr, path2: &CStr) -> c_int {
         e(unsafe { syscall!(SYMLINKAT, path1.as_ptr(), AT_FDCWD, path2.as_ptr()) }) as c_int
     }
@@ -468,6 +523,48 @@ impl Pal for Sys {
         e(unsafe { syscall!(UNLINKAT, AT_FDCWD, path.as_ptr(), 0) }) as c_int
     }
 
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn vfork_clone(stack: *mut usize) -> pid_t {
+        let flags = CLONE_VM | CLONE_VFORK | SIGCHLD as usize;
+        let pid;
+        asm!("
+            # Call clone syscall
+            syscall
+
+            # Check if child or parent
+            test rax, rax
+            jnz 1f
+
+            # Load registers
+            pop rax
+            pop rdi
+
+            # Call entry point
+            call rax
+
+            # Exit with its return value
+            mov rdi, rax
+            mov rax, 60
+            syscall
+
+            # Invalid instruction on failure to exit
+            ud2
+
+            # Return PID if parent
+            1:
+            ",
+            inout("rax") SYS_CLONE => pid,
+            inout("rdi") flags => _,
+            inout("rsi") stack => _,
+            inout("rdx") 0 => _,
+            inout("r10") 0 => _,
+            inout("r8") 0 => _,
+            out("rcx") _,
+            out("r11") _,
+        );
+        e(pid) as pid_t
+    }
+
     fn waitpid(pid: pid_t, stat_loc: *mut c_int, options: c_int) -> pid_t {
         e(unsafe { syscall!(WAIT4, pid, stat_loc, options, 0) }) as pid_t
     }
@@ -1,12 +1,13 @@
 use core::mem;
 
 use super::{
-    super::{types::*, PalSignal},
-    e, Sys,
+    super::{types::*, Pal, PalSignal},
+    e, timer, Sys,
 };
 use crate::header::{
-    signal::{sigaction, sigset_t, stack_t},
+    signal::{__si_kill, sigaction, sigevent, siginfo_t, sigset_t, sigval, stack_t, SI_QUEUE},
     sys_time::itimerval,
+    time::{itimerspec, timespec},
 };
 
 impl PalSignal for Sys {
@@ -51,7 +52,76 @@ impl PalSignal for Sys {
         e(unsafe { syscall!(SIGALTSTACK, ss, old_ss) }) as c_int
     }
 
+    fn sigpending(set: *mut sigset_t) -> c_int {
+        e(unsafe { syscall!(RT_SIGPENDING, set, mem::size_of::<sigset_t>()) }) as c_int
+    }
+
     fn sigprocmask(how: c_int, set: *const sigset_t, oset: *mut sigset_t) -> c_int {
         e(unsafe { syscall!(RT_SIGPROCMASK, how, set, oset, mem::size_of::<sigset_t>()) }) as c_int
     }
+
+    fn sigqueue(pid: pid_t, sig: c_int, value: sigval) -> c_int {
+        let mut info: siginfo_t = unsafe { mem::zeroed() };
+        info.si_signo = sig;
+        info.si_code = SI_QUEUE;
+        info.__si_fields.__kill = __si_kill {
+            si_pid: Sys::getpid(),
+            si_uid: Sys::getuid(),
+            si_value: value,
+        };
+        e(unsafe { syscall!(RT_SIGQUEUEINFO, pid, sig, &info as *const siginfo_t) }) as c_int
+    }
+
+    fn sigsuspend(set: *const sigset_t) -> c_int {
+        e(unsafe { syscall!(RT_SIGSUSPEND, set, mem::size_of::<sigset_t>()) }) as c_int
+    }
+
+    fn sigtimedwait(set: *const sigset_t, info: *mut siginfo_t, timeout: *const timespec) -> c_int {
+        e(unsafe {
+            syscall!(
+                RT_SIGTIMEDWAIT,
+                set,
+                info,
+                timeout,
+                mem::size_of::<sigset_t>()
+            )
+        }) as c_int
+    }
+
+    fn timer_create(clock_id: clockid_t, evp: Option<&sigevent>, timerid: *mut timer_t) -> c_int {
+        unsafe { timer::create(clock_id, evp, timerid) }
+    }
+
+    fn timer_delete(timerid: timer_t) -> c_int {
+        timer::delete(timerid)
+    }
+
+    fn timer_getoverrun(timerid: timer_t) -> c_int {
+        e(unsafe { syscall!(TIMER_GETOVERRUN, timer::kernel_id(timerid)) }) as c_int
+    }
+
+    fn timer_gettime(timerid: timer_t, value: *mut itimerspec) -> c_int {
+        e(unsafe { syscall!(TIMER_GETTIME, timer::kernel_id(timerid), value) }) as c_int
+    }
+
+    fn timer_settime(
+        timerid: timer_t,
+        flags: c_int,
+        value: *const itimerspec,
+        ovalue: *mut itimerspec,
+    ) -> c_int {
+        e(unsafe {
+            syscall!(
+                TIMER_SETTIME,
+                timer::kernel_id(timerid),
+                flags,
+                value,
+                ovalue
+            )
+        }) as c_int
+    }
+
+    fn tkill(tid: pid_t, sig: c_int) -> c_int {
+        e(unsafe { syscall!(TKILL, tid, sig) }) as c_int
+    }
 }
@@ -0,0 +1,218 @@
+//! POSIX timers on top of the kernel's. The kernel can only send signals, so a timer with
+//! `SIGEV_THREAD` gets a thread of its own, which the kernel signals and which then calls the
+//! notification function.
+
+use alloc::boxed::Box;
+use core::{
+    ptr,
+    sync::atomic::{AtomicBool, AtomicI32, Ordering},
+};
+
+use super::{
+    super::{errno, types::*, Pal, PalSignal},
+    e, Sys,
+};
+use crate::{
+    header::{
+        errno::EINVAL,
+        pthread::PTHREAD_CREATE_DETACHED,
+        signal::{sigevent, sigset_t, sigval, SIGEV_SIGNAL, SIGEV_THREAD, SIG_BLOCK, SIG_SETMASK},
+    },
+    pthread::{self, RlctAttr},
+    sync::Semaphore,
+};
+
+const SIGEV_THREAD_ID: c_int = 4;
+
+/// What the kernel sends timer threads, next to the signal used for thread cancellation.
+const SIGRT_RLCT_TIMER: c_int = 33;
+
+/// `struct sigevent` as the kernel reads it.
+#[repr(C)]
+struct KernelSigevent {
+    value: sigval,
+    signo: c_int,
+    notify: c_int,
+    tid: pid_t,
+    pad: [c_int; 11],
+}
+
+/// A timer with `SIGEV_THREAD`. Its thread frees it once `timer_delete` has asked it to exit.
+struct ThreadTimer {
+    clock_id: clockid_t,
+    function: extern "C" fn(sigval),
+    value: sigval,
+    /// The kernel timer, or a negated error number if the thread could not create it
+    id: AtomicI32,
+    tid: AtomicI32,
+    created: Semaphore,
+    deleted: AtomicBool,
+}
+
+// Kernel timer IDs are never negative, so the handles of timers with a thread have the top bit
+// set, with the address shifted into the rest.
+fn thread_timer_handle(timer: *mut ThreadTimer) -> timer_t {
+    ((timer as usize >> 1) | !(usize::max_value() >> 1)) as timer_t
+}
+
+fn thread_timer(timerid: timer_t) -> Option<&'static ThreadTimer> {
+    if (timerid as isize) < 0 {
+        Some(unsafe { &*(((timerid as usize) << 1) as *const ThreadTimer) })
+    } else {
+        None
+    }
+}
+
+pub fn kernel_id(timerid: timer_t) -> c_int {
+    match thread_timer(timerid) {
+        Some(timer) => timer.id.load(Ordering::Relaxed),
+        None => timerid as usize as c_int,
+    }
+}
+
+pub unsafe fn create(clock_id: clockid_t, evp: Option<&sigevent>, timerid: *mut timer_t) -> c_int {
+    let event = match evp {
+        Some(evp) if evp.sigev_notify == SIGEV_THREAD => {
+            return create_thread(clock_id, evp, timerid);
+        }
+        Some(evp) => Some(KernelSigevent {
+            value: evp.sigev_value,
+            signo: evp.sigev_signo,
+            notify: evp.sigev_notify,
+            tid: 0,
+            pad: [0; 11],
+        }),
+        // The kernel sends SIGALRM with the timer ID as value, as POSIX asks
+        None => None,
+    };
+
+    let mut id: c_int = 0;
+    let event_ptr = event
+        .as_ref()
+        .map_or(ptr::null(), |event| event as *const KernelSigevent);
+    if e(syscall!(
+        TIMER_CREATE,
+        clock_id,
+        event_ptr,
+        &mut id as *mut c_int
+    )) == !0
+    {
+        return -1;
+    }
+    *timerid = id as usize as timer_t;
+    0
+}
+
+unsafe fn create_thread(clock_id: clockid_t, evp: &sigevent, timerid: *mut timer_t) -> c_int {
+    let function = match evp.sigev_notify_function {
+        Some(function) => function,
+        None => {
+            errno = EINVAL;
+            return -1;
+        }
+    };
+
+    let mut attrs = evp
+        .sigev_notify_attributes
+        .cast::<RlctAttr>()
+        .as_ref()
+        .copied()
+        .unwrap_or_default();
+    attrs.detachstate = PTHREAD_CREATE_DETACHED as c_uchar;
+
+    let timer = Box::into_raw(Box::new(ThreadTimer {
+        clock_id,
+        function,
+        value: evp.sigev_value,
+        id: AtomicI32::new(0),
+        tid: AtomicI32::new(0),
+        created: Semaphore::new(0),
+        deleted: AtomicBool::new(false),
+    }));
+
+    // The thread inherits a mask with every signal blocked, so the timer's signal can never
+    // reach it before it waits for it
+    let all: sigset_t = !0;
+    let mut old: sigset_t = 0;
+    Sys::sigprocmask(SIG_BLOCK, &all, &mut old);
+    let result = pthread::create(Some(&attrs), timer_thread, timer.cast());
+    Sys::sigprocmask(SIG_SETMASK, &old, ptr::null_mut());
+
+    if let Err(pthread::Errno(err)) = result {
+        drop(Box::from_raw(timer));
+        errno = err;
+        return -1;
+    }
+
+    // Signal handlers of the caller are no reason to give up
+    while (*timer).created.wait(None).is_err() {}
+    let id = (*timer).id.load(Ordering::Acquire);
+    if id < 0 {
+        release(&*timer);
+        errno = -id;
+        return -1;
+    }
+
+    *timerid = thread_timer_handle(timer);
+    0
+}
+
+extern "C" fn timer_thread(arg: *mut c_void) -> *mut c_void {
+    let timer = unsafe { &*arg.cast::<ThreadTimer>() };
+    let tid = Sys::gettid();
+    timer.tid.store(tid, Ordering::Relaxed);
+
+    let event = KernelSigevent {
+        value: sigval { sival_ptr: arg },
+        signo: SIGRT_RLCT_TIMER,
+        notify: SIGEV_SIGNAL | SIGEV_THREAD_ID,
+        tid,
+        pad: [0; 11],
+    };
+    let mut id: c_int = 0;
+    let result = unsafe {
+        syscall!(
+            TIMER_CREATE,
+            timer.clock_id,
+            &event as *const KernelSigevent,
+            &mut id as *mut c_int
+        )
+    } as isize;
+    let id = if result < 0 { result as c_int } else { id };
+    timer.id.store(id, Ordering::Release);
+    let _ = timer.created.post();
+
+    // Without a timer, there is only the request to exit left to wait for
+    let set: sigset_t = 1 << (SIGRT_RLCT_TIMER - 1);
+    loop {
+        let sig = Sys::sigtimedwait(&set, ptr::null_mut(), ptr::null());
+        if timer.deleted.load(Ordering::Acquire) {
+            break;
+        }
+        if sig == SIGRT_RLCT_TIMER {
+            (timer.function)(timer.value);
+        }
+    }
+
+    unsafe {
+        drop(Box::from_raw(arg.cast::<ThreadTimer>()));
+    }
+    ptr::null_mut()
+}
+
+/// Tells the thread of a timer to exit, after which it must not be used anymore.
+fn release(timer: &ThreadTimer) {
+    let tid = timer.tid.load(Ordering::Relaxed);
+    timer.deleted.store(true, Ordering::Release);
+    Sys::tkill(tid, SIGRT_RLCT_TIMER);
+}
+
+pub fn delete(timerid: timer_t) -> c_int {
+    if e(unsafe { syscall!(TIMER_DELETE, kernel_id(timerid)) }) == !0 {
+        return -1;
+    }
+    if let Some(timer) = thread_timer(timerid) {
+        release(timer);
+    }
+    0
+}
@@ -0,0 +1,52 @@
+//! vfork, which cannot be written in Rust: the child returns on the stack of the parent, and may
+//! overwrite whatever the parent kept there before it gets to run again.
+
+#![cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+
+use core::arch::global_asm;
+
+use super::{e, types::*};
+
+// The return address is popped off the stack and kept in a register, which the child cannot touch
+#[cfg(target_arch = "x86_64")]
+global_asm!(
+    "
+    .globl vfork
+    .type vfork, @function
+    .p2align 4
+vfork:
+    pop rdx
+    mov eax, 58
+    syscall
+    push rdx
+    mov rdi, rax
+    jmp __relibc_internal_vfork_ret
+    .size vfork, . - vfork
+"
+);
+
+// The return address is in a register already. Without vfork, this is clone with CLONE_VM,
+// CLONE_VFORK and SIGCHLD, on the same stack.
+#[cfg(target_arch = "aarch64")]
+global_asm!(
+    "
+    .globl vfork
+    .type vfork, @function
+    .p2align 2
+vfork:
+    mov x8, 220
+    mov x0, 0x4111
+    mov x1, 0
+    mov x2, 0
+    mov x3, 0
+    mov x4, 0
+    svc 0
+    b __relibc_internal_vfork_ret
+    .size vfork, . - vfork
+"
+);
+
+#[no_mangle]
+extern "C" fn __relibc_internal_vfork_ret(ret: usize) -> pid_t {
+    e(ret) as pid_t
+}
@@ -13,7 +13,7 @@ mod allocator;
 #[path = "allocator/ralloc.rs"]
 mod allocator;
 
-pub use self::pal::{Pal, PalEpoll, PalPtrace, PalSignal, PalSocket};
+pub use self::pal::{Pal, PalEpoll, PalIpc, PalPtrace, PalSignal, PalSocket};
 
 mod pal;
 
@@ -30,8 +30,6 @@ pub(crate) mod sys;
 #[cfg(test)]
 mod test;
 
-mod pte;
-
 pub use self::rlb::{Line, RawLineBuffer};
 pub mod rlb;
 
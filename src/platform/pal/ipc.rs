@@ -0,0 +1,37 @@
+use super::super::{types::*, Pal};
+use crate::header::{sys_msg::msqid_ds, sys_sem::sembuf, sys_shm::shmid_ds, time::timespec};
+
+pub trait PalIpc: Pal {
+    unsafe fn msgctl(msqid: c_int, cmd: c_int, buf: *mut msqid_ds) -> c_int;
+
+    fn msgget(key: key_t, msgflg: c_int) -> c_int;
+
+    unsafe fn msgrcv(
+        msqid: c_int,
+        msgp: *mut c_void,
+        msgsz: size_t,
+        msgtyp: c_long,
+        msgflg: c_int,
+    ) -> ssize_t;
+
+    unsafe fn msgsnd(msqid: c_int, msgp: *const c_void, msgsz: size_t, msgflg: c_int) -> c_int;
+
+    unsafe fn semctl(semid: c_int, semnum: c_int, cmd: c_int, arg: c_ulong) -> c_int;
+
+    fn semget(key: key_t, nsems: c_int, semflg: c_int) -> c_int;
+
+    unsafe fn semtimedop(
+        semid: c_int,
+        sops: *mut sembuf,
+        nsops: size_t,
+        timeout: *const timespec,
+    ) -> c_int;
+
+    unsafe fn shmat(shmid: c_int, shmaddr: *const c_void, shmflg: c_int) -> *mut c_void;
+
+    unsafe fn shmctl(shmid: c_int, cmd: c_int, buf: *mut shmid_ds) -> c_int;
+
+    unsafe fn shmdt(shmaddr: *const c_void) -> c_int;
+
+    fn shmget(key: key_t, size: size_t, shmflg: c_int) -> c_int;
+}
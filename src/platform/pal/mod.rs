@@ -3,6 +3,7 @@ use crate::{
     c_str::CStr,
     header::{
         dirent::dirent,
+        sched::sched_param,
         sys_resource::rlimit,
         sys_stat::stat,
         sys_statvfs::statvfs,
@@ -15,6 +16,9 @@ use crate::{
 pub use self::epoll::PalEpoll;
 mod epoll;
 
+pub use self::ipc::PalIpc;
+mod ipc;
+
 pub use self::ptrace::PalPtrace;
 mod ptrace;
 
@@ -47,6 +51,10 @@ pub trait Pal {
 
     fn exit(status: c_int) -> !;
 
+    /// End the calling thread, clearing the `os_tid` that `rlct_clone` stored once it no longer
+    /// uses its stack.
+    unsafe fn exit_thread(os_tid: *mut pid_t) -> !;
+
     fn fchdir(fildes: c_int) -> c_int;
 
     fn fchmod(fildes: c_int, mode: mode_t) -> c_int;
@@ -100,6 +108,8 @@ pub trait Pal {
 
     unsafe fn getrlimit(resource: c_int, rlim: *mut rlimit) -> c_int;
 
+    fn getsid(pid: pid_t) -> pid_t;
+
     fn gettid() -> pid_t;
 
     fn gettimeofday(tp: *mut timeval, tzp: *mut timezone) -> c_int;
@@ -145,7 +155,10 @@ pub trait Pal {
 
     fn pipe2(fildes: &mut [c_int], flags: c_int) -> c_int;
 
-    unsafe fn pte_clone(stack: *mut usize) -> pid_t;
+    /// Start a thread sharing the address space, which pops its entry point and arguments off
+    /// `stack`. Its kernel thread ID is stored in `os_tid` before this returns, and cleared when
+    /// it exits.
+    unsafe fn rlct_clone(stack: *mut usize, os_tid: *mut pid_t) -> pid_t;
 
     fn read(fildes: c_int, buf: &mut [u8]) -> ssize_t;
 
@@ -155,6 +168,20 @@ pub trait Pal {
 
     fn rmdir(path: &CStr) -> c_int;
 
+    fn sched_get_priority_max(policy: c_int) -> c_int;
+
+    fn sched_get_priority_min(policy: c_int) -> c_int;
+
+    fn sched_getparam(pid: pid_t, param: &mut sched_param) -> c_int;
+
+    fn sched_getscheduler(pid: pid_t) -> c_int;
+
+    fn sched_rr_get_interval(pid: pid_t, interval: &mut timespec) -> c_int;
+
+    fn sched_setparam(pid: pid_t, param: &sched_param) -> c_int;
+
+    fn sched_setscheduler(pid: pid_t, policy: c_int, param: &sched_param) -> c_int;
+
     fn sched_yield() -> c_int;
 
     fn setpgid(pid: pid_t, pgid: pid_t) -> c_int;
@@ -163,6 +190,8 @@ pub trait Pal {
 
     fn setreuid(ruid: uid_t, euid: uid_t) -> c_int;
 
+    fn setsid() -> pid_t;
+
     fn symlink(path1: &CStr, path2: &CStr) -> c_int;
 
     fn umask(mask: mode_t) -> mode_t;
@@ -171,6 +200,11 @@ pub trait Pal {
 
     fn unlink(path: &CStr) -> c_int;
 
+    /// Start a process which shares the address space, if it can, and suspend the calling thread
+    /// until it executes another program or exits. Like with `rlct_clone`, it pops its entry point
+    /// and argument off `stack`, and it exits with the status the entry point returns.
+    unsafe fn vfork_clone(stack: *mut usize) -> pid_t;
+
     fn waitpid(pid: pid_t, stat_loc: *mut c_int, options: c_int) -> pid_t;
 
     fn write(fildes: c_int, buf: &[u8]) -> ssize_t;
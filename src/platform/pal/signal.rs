@@ -1,7 +1,8 @@
 use super::super::{types::*, Pal};
 use crate::header::{
-    signal::{sigaction, sigset_t, stack_t},
+    signal::{sigaction, sigevent, siginfo_t, sigset_t, sigval, stack_t},
     sys_time::itimerval,
+    time::{itimerspec, timespec},
 };
 
 pub trait PalSignal: Pal {
@@ -19,5 +20,30 @@ pub trait PalSignal: Pal {
 
     fn sigaltstack(ss: *const stack_t, old_ss: *mut stack_t) -> c_int;
 
+    fn sigpending(set: *mut sigset_t) -> c_int;
+
     fn sigprocmask(how: c_int, set: *const sigset_t, oset: *mut sigset_t) -> c_int;
+
+    fn sigqueue(pid: pid_t, sig: c_int, value: sigval) -> c_int;
+
+    fn sigsuspend(set: *const sigset_t) -> c_int;
+
+    fn sigtimedwait(set: *const sigset_t, info: *mut siginfo_t, timeout: *const timespec) -> c_int;
+
+    fn timer_create(clock_id: clockid_t, evp: Option<&sigevent>, timerid: *mut timer_t) -> c_int;
+
+    fn timer_delete(timerid: timer_t) -> c_int;
+
+    fn timer_getoverrun(timerid: timer_t) -> c_int;
+
+    fn timer_gettime(timerid: timer_t, value: *mut itimerspec) -> c_int;
+
+    fn timer_settime(
+        timerid: timer_t,
+        flags: c_int,
+        value: *const itimerspec,
+        ovalue: *mut itimerspec,
+    ) -> c_int;
+
+    fn tkill(tid: pid_t, sig: c_int) -> c_int;
 }
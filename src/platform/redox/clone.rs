@@ -7,6 +7,8 @@ use alloc::vec::Vec;
 use syscall::data::Map;
 use syscall::flag::{MapFlags, O_CLOEXEC};
 use syscall::error::{Error, Result, EINVAL, ENAMETOOLONG};
+use syscall::flag::{FUTEX_WAIT, FUTEX_WAKE};
+use syscall::number::{SYS_EXIT, SYS_FUTEX};
 use syscall::SIGCONT;
 
 use super::extra::{create_set_addr_space_buf, FdGuard};
@@ -14,12 +16,21 @@ use super::extra::{create_set_addr_space_buf, FdGuard};
 pub use redox_exec::*;
 
 /// Spawns a new context sharing the same address space as the current one (i.e. a new thread).
-pub unsafe fn pte_clone_impl(stack: *mut usize) -> Result<usize> {
+/// Its ID is stored in `os_tid` before it starts running.
+pub unsafe fn rlct_clone_impl(stack: *mut usize, os_tid: *mut i32) -> Result<usize> {
     let cur_pid_fd = FdGuard::new(syscall::open("thisproc:current/open_via_dup", O_CLOEXEC)?);
+
+    // Reuse file table
+    let cur_filetable_fd = FdGuard::new(syscall::dup(*cur_pid_fd, b"filetable")?);
+
+    clone_thread(&cur_pid_fd, &cur_filetable_fd, stack, os_tid, true)
+}
+
+unsafe fn clone_thread(cur_pid_fd: &FdGuard, filetable_fd: &FdGuard, stack: *mut usize, os_tid: *mut i32, sigstack: bool) -> Result<usize> {
     let (new_pid_fd, new_pid) = new_context()?;
 
     // Allocate a new signal stack.
-    {
+    if sigstack {
         let sigstack_fd = FdGuard::new(syscall::dup(*new_pid_fd, b"sigstack")?);
 
         const SIGSTACK_SIZE: usize = 1024 * 256;
@@ -30,67 +41,162 @@ pub unsafe fn pte_clone_impl(stack: *mut usize) -> Result<usize> {
         let _ = syscall::write(*sigstack_fd, &usize::to_ne_bytes(target_sigstack))?;
     }
 
-    copy_str(*cur_pid_fd, *new_pid_fd, "name")?;
+    copy_str(**cur_pid_fd, *new_pid_fd, "name")?;
 
     // Reuse existing address space
     {
-        let cur_addr_space_fd = FdGuard::new(syscall::dup(*cur_pid_fd, b"addrspace")?);
+        let cur_addr_space_fd = FdGuard::new(syscall::dup(**cur_pid_fd, b"addrspace")?);
         let new_addr_space_sel_fd = FdGuard::new(syscall::dup(*new_pid_fd, b"current-addrspace")?);
 
-        let buf = create_set_addr_space_buf(*cur_addr_space_fd, __relibc_internal_pte_clone_ret as usize, stack as usize);
+        let buf = create_set_addr_space_buf(*cur_addr_space_fd, __relibc_internal_rlct_clone_ret as usize, stack as usize);
         let _ = syscall::write(*new_addr_space_sel_fd, &buf)?;
     }
 
-    // Reuse file table
+    // Use the given file table, which is ours for threads
     {
-        let cur_filetable_fd = FdGuard::new(syscall::dup(*cur_pid_fd, b"filetable")?);
         let new_filetable_sel_fd = FdGuard::new(syscall::dup(*new_pid_fd, b"current-filetable")?);
 
-        let _ = syscall::write(*new_filetable_sel_fd, &usize::to_ne_bytes(*cur_filetable_fd))?;
+        let _ = syscall::write(*new_filetable_sel_fd, &usize::to_ne_bytes(**filetable_fd))?;
     }
 
     // Reuse sigactions (on Linux, CLONE_THREAD requires CLONE_SIGHAND which implies the sigactions
     // table is reused).
     {
-        let cur_sigaction_fd = FdGuard::new(syscall::dup(*cur_pid_fd, b"sigactions")?);
+        let cur_sigaction_fd = FdGuard::new(syscall::dup(**cur_pid_fd, b"sigactions")?);
         let new_sigaction_sel_fd = FdGuard::new(syscall::dup(*new_pid_fd, b"current-sigactions")?);
 
         let _ = syscall::write(*new_sigaction_sel_fd, &usize::to_ne_bytes(*cur_sigaction_fd))?;
     }
 
-    copy_env_regs(*cur_pid_fd, *new_pid_fd)?;
+    copy_env_regs(**cur_pid_fd, *new_pid_fd)?;
+
+    *os_tid = new_pid as i32;
 
     // Unblock context.
     syscall::kill(new_pid, SIGCONT)?;
     let _ = syscall::waitpid(new_pid, &mut 0, syscall::WUNTRACED | syscall::WCONTINUED);
 
-    Ok(0)
+    Ok(new_pid)
+}
+
+/// What the thread of `with_filetable_copy` runs.
+struct FiletableJob<'a> {
+    f: &'a mut dyn FnMut(),
+    /// Our own descriptors of the process and its file table, which are in the copy as well
+    own_fds: [usize; 2],
+    os_tid: i32,
+}
+
+const FILETABLE_STACK_SIZE: usize = 64 * 1024;
+
+/// Runs `f` on a thread of its own, with a copy of our file table rather than the one our threads
+/// share, and returns that copy. Files are only ever opened and closed in the file table of the
+/// caller, so this is how posix_spawn applies file actions for the process it starts.
+///
+/// The thread has the signal mask and TLS of the caller, who waits until it is done.
+pub unsafe fn with_filetable_copy(f: &mut dyn FnMut()) -> Result<FdGuard> {
+    let cur_pid_fd = FdGuard::new(syscall::open("thisproc:current/open_via_dup", O_CLOEXEC)?);
+    let cur_filetable_fd = FdGuard::new(syscall::dup(*cur_pid_fd, b"filetable")?);
+    let filetable_fd = FdGuard::new(syscall::dup(*cur_filetable_fd, b"copy")?);
+
+    let mut job = FiletableJob { f, own_fds: [*cur_pid_fd, *cur_filetable_fd], os_tid: 0 };
+    let job_ptr: *mut FiletableJob = &mut job;
+
+    let stack_base = syscall::fmap(!0, &Map { address: 0, flags: MapFlags::PROT_READ | MapFlags::PROT_WRITE | MapFlags::MAP_PRIVATE, offset: 0, size: FILETABLE_STACK_SIZE })?;
+    let mut stack = (stack_base + FILETABLE_STACK_SIZE) as *mut usize;
+
+    // Popped into the entry point and its arguments by __relibc_internal_rlct_clone_ret
+    for value in [0, 0, 0, 0, 0, job_ptr as usize, filetable_thread as usize] {
+        stack = stack.sub(1);
+        stack.write(value);
+    }
+
+    let cloned = clone_thread(&cur_pid_fd, &filetable_fd, stack, core::ptr::addr_of_mut!((*job_ptr).os_tid), false);
+    if cloned.is_ok() {
+        loop {
+            let os_tid = core::ptr::addr_of!((*job_ptr).os_tid).read_volatile();
+            if os_tid == 0 {
+                break;
+            }
+            let _ = syscall::futex(core::ptr::addr_of_mut!((*job_ptr).os_tid), FUTEX_WAIT, os_tid, 0, core::ptr::null_mut());
+        }
+    }
+    let _ = syscall::funmap(stack_base, FILETABLE_STACK_SIZE);
+
+    cloned.map(|_| filetable_fd)
+}
+
+unsafe extern "C" fn filetable_thread(job: *mut FiletableJob) -> ! {
+    for &fd in &(*job).own_fds {
+        let _ = syscall::close(fd);
+    }
+    ((*job).f)();
+    exit_thread_impl(core::ptr::addr_of_mut!((*job).os_tid))
+}
+
+/// Ends the current thread like CLONE_CHILD_CLEARTID does on Linux: `os_tid` is cleared and
+/// anyone waiting on it is woken. Whoever sees it cleared may free the thread's stack right
+/// away, so nothing after the store touches memory.
+pub unsafe fn exit_thread_impl(os_tid: *mut i32) -> ! {
+    __relibc_internal_exit_thread(os_tid, SYS_FUTEX, FUTEX_WAKE, SYS_EXIT)
 }
 
 //TODO: aarch64
 #[cfg(target_arch = "aarch64")]
 core::arch::global_asm!("
-    .globl __relibc_internal_pte_clone_ret
-    .type __relibc_internal_pte_clone_ret, @function
+    .globl __relibc_internal_rlct_clone_ret
+    .type __relibc_internal_rlct_clone_ret, @function
     .p2align 6
-__relibc_internal_pte_clone_ret:
-    b __relibc_internal_pte_clone_ret
+__relibc_internal_rlct_clone_ret:
+    b __relibc_internal_rlct_clone_ret
 
-    .size __relibc_internal_pte_clone_ret, . - __relibc_internal_pte_clone_ret
+    .size __relibc_internal_rlct_clone_ret, . - __relibc_internal_rlct_clone_ret
 ");
 
 #[cfg(target_arch = "aarch64")]
 extern "C" {
-    fn __relibc_internal_pte_clone_ret();
+    fn __relibc_internal_rlct_clone_ret();
+}
+
+#[cfg(target_arch = "aarch64")]
+core::arch::global_asm!("
+    .globl __relibc_internal_exit_thread
+    .type __relibc_internal_exit_thread, @function
+    .p2align 6
+__relibc_internal_exit_thread:
+    # futex(os_tid, FUTEX_WAKE, i32::MAX, 0, 0) after the store, then exit(0)
+    mov x8, x1
+    mov x1, x2
+    mov x19, x3
+    mov w2, #0x7fffffff
+    mov x3, xzr
+    mov x4, xzr
+    str wzr, [x0]
+    svc 0
+    mov x8, x19
+    mov x0, xzr
+    svc 0
+    udf #0
+    .size __relibc_internal_exit_thread, . - __relibc_internal_exit_thread
+");
+
+#[cfg(target_arch = "aarch64")]
+extern "C" {
+    fn __relibc_internal_exit_thread(
+        os_tid: *mut i32,
+        futex: usize,
+        wake: usize,
+        exit: usize,
+    ) -> !;
 }
 
 //TODO: x86
 #[cfg(target_arch = "x86")]
 core::arch::global_asm!("
-    .globl __relibc_internal_pte_clone_ret
-    .type __relibc_internal_pte_clone_ret, @function
+    .globl __relibc_internal_rlct_clone_ret
+    .type __relibc_internal_rlct_clone_ret, @function
     .p2align 6
-__relibc_internal_pte_clone_ret:
+__relibc_internal_rlct_clone_ret:
     # Load registers
     pop eax
 
@@ -107,20 +213,53 @@ __relibc_internal_pte_clone_ret:
     call eax
 
     ret
-    .size __relibc_internal_pte_clone_ret, . - __relibc_internal_pte_clone_ret
+    .size __relibc_internal_rlct_clone_ret, . - __relibc_internal_rlct_clone_ret
+");
+
+#[cfg(target_arch = "x86")]
+extern "cdecl" {
+    fn __relibc_internal_rlct_clone_ret();
+}
+
+#[cfg(target_arch = "x86")]
+core::arch::global_asm!("
+    .globl __relibc_internal_exit_thread
+    .type __relibc_internal_exit_thread, @function
+    .p2align 6
+__relibc_internal_exit_thread:
+    # futex(os_tid, FUTEX_WAKE, i32::MAX, 0, 0) after the store, then exit(0)
+    mov ebx, [esp + 4]
+    mov eax, [esp + 8]
+    mov ecx, [esp + 12]
+    mov ebp, [esp + 16]
+    mov edx, 0x7fffffff
+    xor esi, esi
+    xor edi, edi
+    mov DWORD PTR [ebx], 0
+    int 0x80
+    mov eax, ebp
+    xor ebx, ebx
+    int 0x80
+    ud2
+    .size __relibc_internal_exit_thread, . - __relibc_internal_exit_thread
 ");
 
 #[cfg(target_arch = "x86")]
 extern "cdecl" {
-    fn __relibc_internal_pte_clone_ret();
+    fn __relibc_internal_exit_thread(
+        os_tid: *mut i32,
+        futex: usize,
+        wake: usize,
+        exit: usize,
+    ) -> !;
 }
 
 #[cfg(target_arch = "x86_64")]
 core::arch::global_asm!("
-    .globl __relibc_internal_pte_clone_ret
-    .type __relibc_internal_pte_clone_ret, @function
+    .globl __relibc_internal_rlct_clone_ret
+    .type __relibc_internal_rlct_clone_ret, @function
     .p2align 6
-__relibc_internal_pte_clone_ret:
+__relibc_internal_rlct_clone_ret:
     # Load registers
     pop rax
     pop rdi
@@ -143,10 +282,42 @@ __relibc_internal_pte_clone_ret:
     call rax
 
     ret
-    .size __relibc_internal_pte_clone_ret, . - __relibc_internal_pte_clone_ret
+    .size __relibc_internal_rlct_clone_ret, . - __relibc_internal_rlct_clone_ret
+");
+
+#[cfg(target_arch = "x86_64")]
+extern "sysv64" {
+    fn __relibc_internal_rlct_clone_ret();
+}
+
+#[cfg(target_arch = "x86_64")]
+core::arch::global_asm!("
+    .globl __relibc_internal_exit_thread
+    .type __relibc_internal_exit_thread, @function
+    .p2align 6
+__relibc_internal_exit_thread:
+    # futex(os_tid, FUTEX_WAKE, i32::MAX, 0, 0) after the store, then exit(0)
+    mov rax, rsi
+    mov rsi, rdx
+    mov r12, rcx
+    mov edx, 0x7fffffff
+    xor r10d, r10d
+    xor r8d, r8d
+    mov DWORD PTR [rdi], 0
+    syscall
+    mov rax, r12
+    xor edi, edi
+    syscall
+    ud2
+    .size __relibc_internal_exit_thread, . - __relibc_internal_exit_thread
 ");
 
 #[cfg(target_arch = "x86_64")]
 extern "sysv64" {
-    fn __relibc_internal_pte_clone_ret();
+    fn __relibc_internal_exit_thread(
+        os_tid: *mut i32,
+        futex: usize,
+        wake: usize,
+        exit: usize,
+    ) -> !;
 }
@@ -7,75 +7,147 @@ use crate::platform::{sys::{S_ISUID, S_ISGID}, types::*};
 use syscall::data::Stat;
 use syscall::flag::*;
 use syscall::error::*;
-use redox_exec::{FdGuard, ExtraInfo, FexecResult};
+use redox_exec::{create_set_addr_space_buf, FdGuard, ExtraInfo, FexecResult};
 
-fn fexec_impl(file: File, path: &[u8], args: &[&[u8]], envs: &[&[u8]], total_args_envs_size: usize, extrainfo: &ExtraInfo, interp_override: Option<redox_exec::InterpOverride>) -> Result<usize> {
+fn fexec_impl(file: File, open_via_dup: FdGuard, path: &[u8], args: &[&[u8]], envs: &[&[u8]], total_args_envs_size: usize, cwd: &[u8], interp_override: Option<redox_exec::InterpOverride>) -> Result<FdGuard> {
     let fd = *file;
     core::mem::forget(file);
     let image_file = FdGuard::new(fd as usize);
 
-    let open_via_dup = FdGuard::new(syscall::open("thisproc:current/open_via_dup", 0)?);
     let memory = FdGuard::new(syscall::open("memory:", 0)?);
 
-    let addrspace_selection_fd = match redox_exec::fexec_impl(image_file, open_via_dup, &memory, path, args.iter().rev(), envs.iter().rev(), total_args_envs_size, extrainfo, interp_override)? {
+    let extrainfo = ExtraInfo { cwd: Some(cwd) };
+    let addrspace_selection_fd = match redox_exec::fexec_impl(image_file, open_via_dup, &memory, path, args.iter().rev(), envs.iter().rev(), total_args_envs_size, &extrainfo, interp_override)? {
         FexecResult::Normal { addrspace_handle } => addrspace_handle,
         FexecResult::Interp { image_file, open_via_dup, path, interp_override: new_interp_override } => {
             drop(image_file);
-            drop(open_via_dup);
             drop(memory);
 
             // According to elf(5), PT_INTERP requires that the interpreter path be
             // null-terminated. Violating this should therefore give the "format error" ENOEXEC.
             let path_cstr = CStr::from_bytes_with_nul(&path).map_err(|_| Error::new(ENOEXEC))?;
 
-            return execve(path_cstr, ArgEnv::Parsed { total_args_envs_size, args, envs }, Some(new_interp_override));
+            // The O_CLOEXEC descriptors are closed already, and the interpreter is not what gets
+            // setuid, so it is loaded like a spawned program.
+            let image = Image::open(path_cstr)?;
+            return load(image, path_cstr, ArgEnv::Parsed { total_args_envs_size, args, envs }, Some(new_interp_override), open_via_dup, Some(cwd));
         }
     };
     drop(memory);
 
-    // Dropping this FD will cause the address space switch.
-    drop(addrspace_selection_fd);
-
-    unreachable!();
+    Ok(addrspace_selection_fd)
 }
 pub enum ArgEnv<'a> {
     C { argv: *const *mut c_char, envp: *const *mut c_char },
     Parsed { args: &'a [&'a [u8]], envs: &'a [&'a [u8]], total_args_envs_size: usize },
 }
-pub fn execve(path: &CStr, arg_env: ArgEnv, interp_override: Option<redox_exec::InterpOverride>) -> Result<usize> {
-    // NOTE: We must omit O_CLOEXEC and close manually, otherwise it will be closed before we
-    // have even read it!
-    let mut image_file = File::open(path, O_RDONLY as c_int).map_err(|_| Error::new(ENOENT))?;
-
-    // With execve now being implemented in userspace, we need to check ourselves that this
-    // file is actually executable. While checking for read permission is unnecessary as the
-    // scheme will not allow us to read otherwise, the execute bit is completely unenforced. We
-    // have the permission to mmap executable memory and fill it with the program even if it is
-    // unset, so the best we can do is check that nothing is executed by accident.
-    //
-    // TODO: At some point we might have capabilities limiting the ability to allocate
-    // executable memory, and in that case we might use the `escalate:` scheme as we already do
-    // when the binary needs setuid/setgid.
-
-    let mut stat = Stat::default();
-    syscall::fstat(*image_file as usize, &mut stat)?;
-    let uid = syscall::getuid()?;
-    let gid = syscall::getuid()?;
-
-    let mode = if uid == stat.st_uid as usize {
-        (stat.st_mode >> 3 * 2) & 0o7
-    } else if gid == stat.st_gid as usize {
-        (stat.st_mode >> 3 * 1) & 0o7
-    } else {
-        stat.st_mode & 0o7
-    };
+/// An executable that has been checked and is ready to be loaded, or the interpreter of a script.
+pub struct Image {
+    file: File,
+    /// The interpreter of a script, which gets the script as its first argument.
+    interpreter: Option<CString>,
+    wants_setugid: bool,
+}
+impl Image {
+    pub fn open(path: &CStr) -> Result<Self> {
+        // NOTE: We must omit O_CLOEXEC and close manually, otherwise it will be closed before we
+        // have even read it!
+        let mut image_file = File::open(path, O_RDONLY as c_int).map_err(|_| Error::new(ENOENT))?;
+
+        // With execve now being implemented in userspace, we need to check ourselves that this
+        // file is actually executable. While checking for read permission is unnecessary as the
+        // scheme will not allow us to read otherwise, the execute bit is completely unenforced. We
+        // have the permission to mmap executable memory and fill it with the program even if it is
+        // unset, so the best we can do is check that nothing is executed by accident.
+        //
+        // TODO: At some point we might have capabilities limiting the ability to allocate
+        // executable memory, and in that case we might use the `escalate:` scheme as we already do
+        // when the binary needs setuid/setgid.
+
+        let mut stat = Stat::default();
+        syscall::fstat(*image_file as usize, &mut stat)?;
+        let uid = syscall::getuid()?;
+        let gid = syscall::getuid()?;
+
+        let mode = if uid == stat.st_uid as usize {
+            (stat.st_mode >> 3 * 2) & 0o7
+        } else if gid == stat.st_gid as usize {
+            (stat.st_mode >> 3 * 1) & 0o7
+        } else {
+            stat.st_mode & 0o7
+        };
+
+        if mode & 0o1 == 0o0 {
+            return Err(Error::new(EPERM));
+        }
+        let wants_setugid = stat.st_mode & ((S_ISUID | S_ISGID) as u16) != 0;
+
+        // Read shebang (for example #!/bin/sh)
+        let is_interpreted = {
+            let mut read = 0;
+            let mut shebang = [0; 2];
+
+            while read < 2 {
+                match image_file.read(&mut shebang).map_err(|_| Error::new(ENOEXEC))? {
+                    0 => break,
+                    i => read += i,
+                }
+            }
+            shebang == *b"#!"
+        };
+
+        let interpreter = if is_interpreted {
+            // TODO: Does this support prepending args to the interpreter? E.g.
+            // #!/usr/bin/env python3
+
+            // So, this file is interpreted.
+            // Then, read the actual interpreter:
+            let mut interpreter = Vec::new();
+            BufReader::new(&mut image_file).read_until(b'\n', &mut interpreter).map_err(|_| Error::new(EIO))?;
+            if interpreter.ends_with(&[b'\n']) {
+                interpreter.pop().unwrap();
+            }
+            let cstring = CString::new(interpreter).map_err(|_| Error::new(ENOEXEC))?;
+            image_file = File::open(&cstring, O_RDONLY as c_int).map_err(|_| Error::new(ENOENT))?;
+            Some(cstring)
+        } else {
+            image_file.seek(SeekFrom::Start(0)).map_err(|_| Error::new(EIO))?;
+            None
+        };
+
+        Ok(Self { file: image_file, interpreter, wants_setugid })
+    }
 
-    if mode & 0o1 == 0o0 {
-        return Err(Error::new(EPERM));
+    /// Whether loading the image takes the `escalate:` scheme, which only execve can ask for help.
+    pub fn escalates(&self) -> bool {
+        // According to execve(2), Linux and most other UNIXes ignore setuid/setgid for interpreted
+        // executables and thereby simply keep the privileges as is. For compatibility we do that
+        // too.
+        self.wants_setugid && self.interpreter.is_none()
     }
-    let wants_setugid = stat.st_mode & ((S_ISUID | S_ISGID) as u16) != 0;
+}
+pub fn execve(path: &CStr, arg_env: ArgEnv) -> Result<usize> {
+    let image = Image::open(path)?;
+    let open_via_dup = FdGuard::new(syscall::open("thisproc:current/open_via_dup", 0)?);
+    let addrspace_selection_fd = load(image, path, arg_env, None, open_via_dup, None)?;
 
-    let cwd: Box<[u8]> = super::path::clone_cwd().unwrap_or_default().into();
+    // Dropping this FD will cause the address space switch.
+    drop(addrspace_selection_fd);
+
+    unreachable!();
+}
+/// Loads the image into the context of `open_via_dup`, returning the FD whose closing switches it
+/// to the new address space. Without a `cwd`, this is an execve: the program gets our working
+/// directory, our O_CLOEXEC file descriptors are closed, and a setuid program escalates instead.
+fn load(image: Image, path: &CStr, arg_env: ArgEnv, interp_override: Option<redox_exec::InterpOverride>, open_via_dup: FdGuard, cwd: Option<&[u8]>) -> Result<FdGuard> {
+    let is_execve = cwd.is_none();
+    let escalate = is_execve && image.escalates();
+    let Image { file: image_file, interpreter, .. } = image;
+
+    let cwd: Box<[u8]> = match cwd {
+        Some(cwd) => cwd.into(),
+        None => super::path::clone_cwd().unwrap_or_default().into(),
+    };
 
     // Count arguments
     let mut len = 0;
@@ -91,50 +163,8 @@ pub fn execve(path: &CStr, arg_env: ArgEnv, interp_override: Option<redox_exec::
 
     let mut args: Vec<&[u8]> = Vec::with_capacity(len);
 
-    // Read shebang (for example #!/bin/sh)
-    let mut _interpreter_path = None;
-    let is_interpreted = {
-        let mut read = 0;
-        let mut shebang = [0; 2];
-
-        while read < 2 {
-            match image_file.read(&mut shebang).map_err(|_| Error::new(ENOEXEC))? {
-                0 => break,
-                i => read += i,
-            }
-        }
-        shebang == *b"#!"
-    };
-    // Since the fexec implementation is almost fully done in userspace, the kernel can no longer
-    // set UID/GID accordingly, and this code checking for them before using interfaces to upgrade
-    // UID/GID, can not be trusted. So we ask the `escalate:` scheme for help. Note that
-    // `escalate:` can be deliberately excluded from the scheme namespace to deny privilege
-    // escalation (such as su/sudo/doas) for untrusted processes.
-    //
-    // According to execve(2), Linux and most other UNIXes ignore setuid/setgid for interpreted
-    // executables and thereby simply keep the privileges as is. For compatibility we do that
-    // too.
-
-    if is_interpreted {
-        // TODO: Does this support prepending args to the interpreter? E.g.
-        // #!/usr/bin/env python3
-
-        // So, this file is interpreted.
-        // Then, read the actual interpreter:
-        let mut interpreter = Vec::new();
-        BufReader::new(&mut image_file).read_until(b'\n', &mut interpreter).map_err(|_| Error::new(EIO))?;
-        if interpreter.ends_with(&[b'\n']) {
-            interpreter.pop().unwrap();
-        }
-        let cstring = CString::new(interpreter).map_err(|_| Error::new(ENOEXEC))?;
-        image_file = File::open(&cstring, O_RDONLY as c_int).map_err(|_| Error::new(ENOENT))?;
-
-        // Make sure path is kept alive long enough, and push it to the arguments
-        _interpreter_path = Some(cstring);
-        let path_ref = _interpreter_path.as_ref().unwrap();
-        args.push(path_ref.as_bytes());
-    } else {
-        image_file.seek(SeekFrom::Start(0)).map_err(|_| Error::new(EIO))?;
+    if let Some(ref interpreter) = interpreter {
+        args.push(interpreter.as_bytes());
     }
 
     let (total_args_envs_size, args, envs): (usize, Vec<_>, Vec<_>) = match arg_env {
@@ -175,33 +205,19 @@ pub fn execve(path: &CStr, arg_env: ArgEnv, interp_override: Option<redox_exec::
         }
     };
 
-
-    // Close all O_CLOEXEC file descriptors. TODO: close_range?
-    {
-        // NOTE: This approach of implementing O_CLOEXEC will not work in multithreaded
-        // scenarios. While execve() is undefined according to POSIX if there exist sibling
-        // threads, it could still be allowed by keeping certain file descriptors and instead
-        // set the active file table.
-        let files_fd = File::new(syscall::open("thisproc:current/filetable", O_RDONLY)? as c_int);
-        for line in BufReader::new(files_fd).lines() {
-            let line = match line {
-                Ok(l) => l,
-                Err(_) => break,
-            };
-            let fd = match line.parse::<usize>() {
-                Ok(f) => f,
-                Err(_) => continue,
-            };
-
-            let flags = syscall::fcntl(fd, F_GETFD, 0)?;
-
-            if flags & O_CLOEXEC == O_CLOEXEC {
-                let _ = syscall::close(fd);
-            }
-        }
+    if is_execve {
+        close_on_exec()?;
     }
 
-    if !is_interpreted && wants_setugid {
+    // Since the fexec implementation is almost fully done in userspace, the kernel can no longer
+    // set UID/GID accordingly, and this code checking for them before using interfaces to upgrade
+    // UID/GID, can not be trusted. So we ask the `escalate:` scheme for help. Note that
+    // `escalate:` can be deliberately excluded from the scheme namespace to deny privilege
+    // escalation (such as su/sudo/doas) for untrusted processes.
+    if escalate {
+        // The scheme takes over this process, not the one of `open_via_dup`.
+        drop(open_via_dup);
+
         // Make sure the last file descriptor not covered by O_CLOEXEC is not leaked.
         drop(image_file);
 
@@ -237,9 +253,34 @@ pub fn execve(path: &CStr, arg_env: ArgEnv, interp_override: Option<redox_exec::
 
         unreachable!()
     } else {
-        let extrainfo = ExtraInfo { cwd: Some(&cwd) };
-        fexec_impl(image_file, path.to_bytes(), &args, &envs, total_args_envs_size, &extrainfo, interp_override)
+        fexec_impl(image_file, open_via_dup, path.to_bytes(), &args, &envs, total_args_envs_size, &cwd, interp_override)
+    }
+}
+/// Closes all O_CLOEXEC file descriptors in the file table of the calling context.
+pub fn close_on_exec() -> Result<()> {
+    // TODO: close_range?
+    // NOTE: This approach of implementing O_CLOEXEC will not work in multithreaded
+    // scenarios. While execve() is undefined according to POSIX if there exist sibling
+    // threads, it could still be allowed by keeping certain file descriptors and instead
+    // set the active file table.
+    let files_fd = File::new(syscall::open("thisproc:current/filetable", O_RDONLY)? as c_int);
+    for line in BufReader::new(files_fd).lines() {
+        let line = match line {
+            Ok(l) => l,
+            Err(_) => break,
+        };
+        let fd = match line.parse::<usize>() {
+            Ok(f) => f,
+            Err(_) => continue,
+        };
+
+        let flags = syscall::fcntl(fd, F_GETFD, 0)?;
+
+        if flags & O_CLOEXEC == O_CLOEXEC {
+            let _ = syscall::close(fd);
+        }
     }
+    Ok(())
 }
 fn flatten_with_nul<T>(iter: impl IntoIterator<Item = T>) -> Box<[u8]> where T: AsRef<[u8]> {
     let mut vec = Vec::new();
@@ -249,3 +290,74 @@ fn flatten_with_nul<T>(iter: impl IntoIterator<Item = T>) -> Box<[u8]> where T:
     }
     vec.into_boxed_slice()
 }
+/// A new context for posix_spawn to load a program into. It is killed if it never starts.
+pub struct Process {
+    open_via_dup: Option<FdGuard>,
+    pid: usize,
+    started: bool,
+}
+impl Process {
+    /// Creates a context with the given file table, which takes its ids, process group and signal
+    /// mask from the calling thread. It does not run until it is started.
+    pub fn new(filetable: &FdGuard) -> Result<Self> {
+        let (new_pid_fd, pid) = redox_exec::new_context()?;
+        let process = Self { open_via_dup: Some(new_pid_fd), pid, started: false };
+        let new_pid_fd = process.open_via_dup.as_ref().unwrap();
+        let cur_pid_fd = FdGuard::new(syscall::open("thisproc:current/open_via_dup", O_CLOEXEC)?);
+
+        // fexec_impl replaces the address space and signal actions it finds, so there have to be
+        // some, but nothing of ours is left in them.
+        {
+            let cur_addr_space_fd = FdGuard::new(syscall::dup(*cur_pid_fd, b"addrspace")?);
+            let empty_addr_space_fd = FdGuard::new(syscall::dup(*cur_addr_space_fd, b"empty")?);
+            let new_addr_space_sel_fd = FdGuard::new(syscall::dup(**new_pid_fd, b"current-addrspace")?);
+
+            let _ = syscall::write(*new_addr_space_sel_fd, &create_set_addr_space_buf(*empty_addr_space_fd, 0, 0))?;
+        }
+        {
+            let cur_sigaction_fd = FdGuard::new(syscall::dup(*cur_pid_fd, b"sigactions")?);
+            let empty_sigaction_fd = FdGuard::new(syscall::dup(*cur_sigaction_fd, b"empty")?);
+            let new_sigaction_sel_fd = FdGuard::new(syscall::dup(**new_pid_fd, b"current-sigactions")?);
+
+            let _ = syscall::write(*new_sigaction_sel_fd, &usize::to_ne_bytes(*empty_sigaction_fd))?;
+        }
+        {
+            let new_filetable_sel_fd = FdGuard::new(syscall::dup(**new_pid_fd, b"current-filetable")?);
+
+            let _ = syscall::write(*new_filetable_sel_fd, &usize::to_ne_bytes(**filetable))?;
+        }
+
+        Ok(process)
+    }
+
+    pub fn pid(&self) -> usize {
+        self.pid
+    }
+
+    /// Loads the program and lets it run, in `cwd` as its working directory.
+    pub fn start(mut self, image: Image, path: &CStr, argv: *const *mut c_char, envp: *const *mut c_char, cwd: &[u8]) -> Result<()> {
+        let open_via_dup = self.open_via_dup.take().unwrap();
+        let addrspace_selection_fd = load(image, path, ArgEnv::C { argv, envp }, None, open_via_dup, Some(cwd))?;
+
+        // Dropping this FD will cause the address space switch.
+        drop(addrspace_selection_fd);
+
+        // Unblock context.
+        syscall::kill(self.pid, SIGCONT)?;
+        self.started = true;
+
+        // Like after fork, this clears the waitpid queue of the SIGCONT.
+        let _ = syscall::waitpid(self.pid, &mut 0, WUNTRACED | WCONTINUED);
+
+        Ok(())
+    }
+}
+impl Drop for Process {
+    fn drop(&mut self) {
+        if !self.started {
+            drop(self.open_via_dup.take());
+            let _ = syscall::kill(self.pid, SIGKILL);
+            let _ = syscall::waitpid(self.pid, &mut 0, WaitFlags::empty());
+        }
+    }
+}
@@ -0,0 +1,1132 @@
+//! Redox has no System V IPC, so it is emulated with shared memory objects. A registry, which is
+//! a shared memory object itself, maps keys to identifiers, and every identifier has an object of
+//! its own for the segment, the semaphore values or the queued messages. The lock in the registry
+//! serializes everything, and callers that block sleep on a futex in their registry entry.
+//!
+//! Unlike on Linux, attachments are not inherited by `fork`, and `SEM_UNDO` adjustments are only
+//! undone when the process exits through `exit`.
+
+use alloc::vec::Vec;
+use core::{
+    cmp, mem, ptr, slice,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use super::{
+    super::{types::*, Pal, PalIpc},
+    Sys,
+};
+use crate::{
+    header::{
+        errno::*,
+        fcntl::{O_CREAT, O_RDWR, O_TRUNC},
+        stdlib::atexit,
+        sys_ipc::*,
+        sys_mman::{shm_open, shm_unlink, MAP_FIXED, MAP_SHARED, PROT_READ, PROT_WRITE},
+        sys_msg::*,
+        sys_sem::*,
+        sys_shm::*,
+        time::{timespec, CLOCK_MONOTONIC, CLOCK_REALTIME},
+    },
+    platform,
+    pthread::Errno,
+    sync::{relative_timeout, Mutex, MutexGuard},
+};
+
+const REGISTRY_NAME: &[u8] = b"/relibc-sysv-ipc\0";
+const OBJECT_PREFIX: &[u8] = b"/relibc-sysv-";
+
+const SLOTS: usize = 256;
+/// Identifiers are the slot plus a multiple of `SLOTS`, so that a reused slot has a new one
+const SEQS: c_int = c_int::max_value() / SLOTS as c_int;
+
+const FREE: c_int = 0;
+const SHM: c_int = 1;
+const SEM: c_int = 2;
+const MSG: c_int = 3;
+
+const READ: mode_t = 0o4;
+const WRITE: mode_t = 0o2;
+
+const SHMMAX: size_t = isize::max_value() as size_t;
+const SEMMSL: c_int = 32000;
+const SEMOPM: size_t = 500;
+const SEMVMX: c_int = 32767;
+const MSGMAX: size_t = 8192;
+const MSGMNB: size_t = 16384;
+/// Room for the messages of a full queue, and their headers
+const MSG_STORAGE: size_t = 2 * MSGMNB;
+
+#[repr(C)]
+struct Entry {
+    kind: c_int,
+    seq: c_int,
+    /// A removed segment stays around until it is no longer attached
+    removed: bool,
+    /// The futex that blocked callers sleep on, bumped whenever the entry changes
+    changed: c_int,
+    perm: ipc_perm,
+    /// The size of a segment, the number of semaphores in a set, or the capacity of a queue
+    size: size_t,
+    ctime: time_t,
+
+    shm_atime: time_t,
+    shm_dtime: time_t,
+    shm_cpid: pid_t,
+    shm_lpid: pid_t,
+    shm_nattch: shmatt_t,
+
+    sem_otime: time_t,
+
+    msg_stime: time_t,
+    msg_rtime: time_t,
+    msg_lspid: pid_t,
+    msg_lrpid: pid_t,
+    msg_cbytes: size_t,
+    msg_qnum: size_t,
+    /// How much of the storage the queued messages take up
+    msg_used: size_t,
+}
+
+/// A new registry object is all zeroes, which is an unlocked mutex around free entries
+type Registry = Mutex<[Entry; SLOTS]>;
+
+/// One semaphore of a set
+#[repr(C)]
+struct Sem {
+    value: c_int,
+    pid: pid_t,
+    ncnt: c_int,
+    zcnt: c_int,
+}
+
+/// The header of a queued message, which is followed by its text, padded to the alignment of the
+/// next header
+#[repr(C)]
+struct Message {
+    mtype: c_long,
+    len: size_t,
+}
+
+struct Attachment {
+    addr: usize,
+    size: size_t,
+    id: c_int,
+}
+
+struct Undo {
+    id: c_int,
+    num: c_ushort,
+    adjust: c_int,
+}
+
+/// The adjustments to undo at exit, which belong to the process `pid` only
+struct Undos {
+    pid: pid_t,
+    list: Vec<Undo>,
+}
+
+static REGISTRY: AtomicUsize = AtomicUsize::new(0);
+static ATTACHED: Mutex<Vec<Attachment>> = Mutex::new(Vec::new());
+static UNDO: Mutex<Undos> = Mutex::new(Undos {
+    pid: 0,
+    list: Vec::new(),
+});
+
+fn or_errno<T>(result: Result<T, Errno>, fail: T) -> T {
+    match result {
+        Ok(ok) => ok,
+        Err(Errno(err)) => {
+            unsafe {
+                platform::errno = err;
+            }
+            fail
+        }
+    }
+}
+
+fn last_error() -> Errno {
+    Errno(unsafe { platform::errno })
+}
+
+fn now() -> time_t {
+    let mut now = timespec::default();
+    Sys::clock_gettime(CLOCK_REALTIME, &mut now);
+    now.tv_sec
+}
+
+unsafe fn registry() -> Result<&'static Registry, Errno> {
+    let mut addr = REGISTRY.load(Ordering::Acquire);
+    if addr == 0 {
+        let size = mem::size_of::<Registry>();
+        let fd = shm_open(REGISTRY_NAME.as_ptr().cast(), O_RDWR | O_CREAT, 0o666);
+        if fd < 0 {
+            return Err(last_error());
+        }
+        // Everyone has to be able to use it, whatever the umask of whoever created it. Growing
+        // it is harmless when someone else already did.
+        Sys::fchmod(fd, 0o666);
+        let mapped = if Sys::ftruncate(fd, size as off_t) < 0 {
+            !0 as *mut c_void
+        } else {
+            Sys::mmap(
+                ptr::null_mut(),
+                size,
+                PROT_READ | PROT_WRITE,
+                MAP_SHARED,
+                fd,
+                0,
+            )
+        };
+        let err = last_error();
+        Sys::close(fd);
+        if mapped as isize == -1 {
+            return Err(err);
+        }
+
+        addr = match REGISTRY.compare_exchange(
+            0,
+            mapped as usize,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => mapped as usize,
+            Err(other) => {
+                Sys::munmap(mapped, size);
+                other
+            }
+        };
+    }
+    Ok(&*(addr as *const Registry))
+}
+
+fn id_of(slot: usize, entry: &Entry) -> c_int {
+    entry.seq * SLOTS as c_int + slot as c_int
+}
+
+fn slot_of(id: c_int) -> usize {
+    id as usize % SLOTS
+}
+
+fn lookup(entries: &mut [Entry; SLOTS], kind: c_int, id: c_int) -> Result<&mut Entry, Errno> {
+    if id < 0 {
+        return Err(Errno(EINVAL));
+    }
+    let entry = &mut entries[slot_of(id)];
+    if entry.kind != kind || entry.seq != id / SLOTS as c_int || entry.removed {
+        return Err(Errno(EINVAL));
+    }
+    Ok(entry)
+}
+
+/// Whether the caller has all of the `wanted` permissions, a combination of `READ` and `WRITE`
+fn permitted(perm: &ipc_perm, wanted: mode_t) -> bool {
+    let uid = Sys::geteuid();
+    if uid == 0 {
+        return true;
+    }
+    let gid = Sys::getegid();
+    let granted = if uid == perm.uid || uid == perm.cuid {
+        perm.mode >> 6
+    } else if gid == perm.gid || gid == perm.cgid {
+        perm.mode >> 3
+    } else {
+        perm.mode
+    };
+    granted & wanted == wanted
+}
+
+fn check_permitted(perm: &ipc_perm, wanted: mode_t) -> Result<(), Errno> {
+    if permitted(perm, wanted) {
+        Ok(())
+    } else {
+        Err(Errno(EACCES))
+    }
+}
+
+fn check_owner(perm: &ipc_perm) -> Result<(), Errno> {
+    let uid = Sys::geteuid();
+    if uid == 0 || uid == perm.uid || uid == perm.cuid {
+        Ok(())
+    } else {
+        Err(Errno(EPERM))
+    }
+}
+
+/// Finds the entry of `kind` for `key`, or sets up a new one if `flags` ask for it. Returns the
+/// slot, and whether the entry is new, in which case the caller has to create its object.
+fn get(
+    entries: &mut [Entry; SLOTS],
+    kind: c_int,
+    key: key_t,
+    flags: c_int,
+) -> Result<(usize, bool), Errno> {
+    if key != IPC_PRIVATE {
+        if let Some(slot) = entries
+            .iter()
+            .position(|entry| entry.kind == kind && !entry.removed && entry.perm.__key == key)
+        {
+            if flags & (IPC_CREAT | IPC_EXCL) == IPC_CREAT | IPC_EXCL {
+                return Err(Errno(EEXIST));
+            }
+            let wanted = (flags >> 6 | flags >> 3 | flags) & 0o7;
+            check_permitted(&entries[slot].perm, wanted)?;
+            return Ok((slot, false));
+        }
+        if flags & IPC_CREAT == 0 {
+            return Err(Errno(ENOENT));
+        }
+    }
+
+    let slot = entries
+        .iter()
+        .position(|entry| entry.kind == FREE)
+        .ok_or(Errno(ENOSPC))?;
+    let entry = &mut entries[slot];
+    let (seq, changed) = (entry.seq, entry.changed);
+    *entry = unsafe { mem::zeroed() };
+    entry.kind = kind;
+    entry.seq = seq;
+    entry.changed = changed;
+    entry.perm.__key = key;
+    entry.perm.uid = Sys::geteuid();
+    entry.perm.cuid = entry.perm.uid;
+    entry.perm.gid = Sys::getegid();
+    entry.perm.cgid = entry.perm.gid;
+    entry.perm.mode = flags & 0o777;
+    entry.perm.__seq = seq as c_ushort;
+    entry.ctime = now();
+    Ok((slot, true))
+}
+
+fn object_name(id: c_int) -> [u8; 32] {
+    let mut name = [0; 32];
+    name[..OBJECT_PREFIX.len()].copy_from_slice(OBJECT_PREFIX);
+
+    let mut digits = [0; 10];
+    let mut i = digits.len();
+    let mut rest = id as u32;
+    loop {
+        i -= 1;
+        digits[i] = b'0' + (rest % 10) as u8;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    name[OBJECT_PREFIX.len()..][..digits.len() - i].copy_from_slice(&digits[i..]);
+    name
+}
+
+/// Opens the object that holds the contents of `id`, or creates it with `size` bytes.
+unsafe fn open_object(id: c_int, create: Option<size_t>) -> Result<c_int, Errno> {
+    let name = object_name(id);
+    let fd = match create {
+        // Whatever a crashed process left behind is cleared
+        Some(_) => shm_open(name.as_ptr().cast(), O_RDWR | O_CREAT | O_TRUNC, 0o666),
+        None => shm_open(name.as_ptr().cast(), O_RDWR, 0),
+    };
+    if fd < 0 {
+        return Err(last_error());
+    }
+    if let Some(size) = create {
+        // Access is checked against the registry, not the object
+        Sys::fchmod(fd, 0o666);
+        if Sys::ftruncate(fd, size as off_t) < 0 {
+            let err = last_error();
+            Sys::close(fd);
+            shm_unlink(name.as_ptr().cast());
+            return Err(err);
+        }
+    }
+    Ok(fd)
+}
+
+/// Runs `f` on the contents of `id`, seen as `len` values of `T`.
+unsafe fn with_object<T, R>(
+    id: c_int,
+    len: usize,
+    f: impl FnOnce(&mut [T]) -> R,
+) -> Result<R, Errno> {
+    let fd = open_object(id, None)?;
+    let size = len * mem::size_of::<T>();
+    let addr = Sys::mmap(
+        ptr::null_mut(),
+        size,
+        PROT_READ | PROT_WRITE,
+        MAP_SHARED,
+        fd,
+        0,
+    );
+    let err = last_error();
+    Sys::close(fd);
+    if addr as isize == -1 {
+        return Err(err);
+    }
+    let result = f(slice::from_raw_parts_mut(addr.cast(), len));
+    Sys::munmap(addr, size);
+    Ok(result)
+}
+
+/// Sets up the entry in `slot`, which `get` just made, by creating its object.
+unsafe fn create_object(
+    entries: &mut [Entry; SLOTS],
+    slot: usize,
+    size: size_t,
+) -> Result<c_int, Errno> {
+    let id = id_of(slot, &entries[slot]);
+    match open_object(id, Some(size)) {
+        Ok(fd) => {
+            Sys::close(fd);
+            Ok(id)
+        }
+        Err(err) => {
+            entries[slot].kind = FREE;
+            Err(err)
+        }
+    }
+}
+
+fn wake(entry: &mut Entry) {
+    entry.changed = entry.changed.wrapping_add(1);
+    Sys::futex(
+        &mut entry.changed,
+        syscall::FUTEX_WAKE as c_int,
+        c_int::max_value(),
+        0,
+    );
+}
+
+/// Frees the entry in `slot`, and wakes up whoever waits for it to change, so that they find out.
+unsafe fn remove(entries: &mut [Entry; SLOTS], slot: usize) {
+    let entry = &mut entries[slot];
+    let name = object_name(id_of(slot, entry));
+    shm_unlink(name.as_ptr().cast());
+    entry.kind = FREE;
+    entry.removed = false;
+    entry.seq = (entry.seq + 1) % SEQS;
+    wake(entry);
+}
+
+/// Unlocks the registry and sleeps until the entry in `slot` changes, or until `deadline` on
+/// `CLOCK_MONOTONIC` has passed, and then locks the registry again. Fails with `EINTR` if a
+/// signal handler interrupted the sleep, or with `ETIMEDOUT` if the deadline had already passed.
+fn sleep<'a>(
+    registry: &'a Registry,
+    mut entries: MutexGuard<'a, [Entry; SLOTS]>,
+    slot: usize,
+    deadline: Option<&timespec>,
+) -> (MutexGuard<'a, [Entry; SLOTS]>, Result<(), Errno>) {
+    let futex: *mut c_int = &mut entries[slot].changed;
+    let changed = entries[slot].changed;
+    drop(entries);
+
+    let timeout = match deadline.map(|deadline| relative_timeout(CLOCK_MONOTONIC, deadline)) {
+        Some(Ok(timeout)) => Some(timeout),
+        Some(Err(err)) => return (registry.lock(), Err(err)),
+        None => None,
+    };
+    let result = Sys::futex(
+        futex,
+        syscall::FUTEX_WAIT as c_int,
+        changed,
+        timeout
+            .as_ref()
+            .map_or(0, |timeout| timeout as *const timespec as usize),
+    );
+
+    let entries = registry.lock();
+    if result == -EINTR {
+        (entries, Err(Errno(EINTR)))
+    } else {
+        (entries, Ok(()))
+    }
+}
+
+/// Applies all of `sops` to `sems`, or none of them. Returns the index of the operation that has to
+/// wait, if there is one.
+fn try_semop(sems: &mut [Sem], sops: &[sembuf]) -> Result<Option<usize>, Errno> {
+    let mut values: Vec<c_int> = sems.iter().map(|sem| sem.value).collect();
+    for (i, op) in sops.iter().enumerate() {
+        let value = &mut values[op.sem_num as usize];
+        let sem_op = op.sem_op as c_int;
+        if sem_op == 0 {
+            if *value != 0 {
+                return Ok(Some(i));
+            }
+        } else if *value + sem_op < 0 {
+            return Ok(Some(i));
+        } else if *value + sem_op > SEMVMX {
+            return Err(Errno(ERANGE));
+        } else {
+            *value += sem_op;
+        }
+    }
+
+    let pid = Sys::getpid();
+    for (sem, value) in sems.iter_mut().zip(values) {
+        sem.value = value;
+    }
+    for op in sops {
+        sems[op.sem_num as usize].pid = pid;
+    }
+    Ok(None)
+}
+
+fn record_undo(semid: c_int, sops: &[sembuf]) {
+    let mut undo = UNDO.lock();
+    let pid = Sys::getpid();
+    // A child starts out without adjustments, and has to undo them itself
+    if undo.pid != pid {
+        undo.pid = pid;
+        undo.list.clear();
+        unsafe {
+            atexit(Some(undo_all));
+        }
+    }
+
+    for op in sops {
+        if op.sem_flg & SEM_UNDO == 0 || op.sem_op == 0 {
+            continue;
+        }
+        match undo
+            .list
+            .iter_mut()
+            .find(|undo| undo.id == semid && undo.num == op.sem_num)
+        {
+            Some(undo) => undo.adjust -= op.sem_op as c_int,
+            None => undo.list.push(Undo {
+                id: semid,
+                num: op.sem_num,
+                adjust: -(op.sem_op as c_int),
+            }),
+        }
+    }
+}
+
+/// Forgets the adjustments to the semaphore `num` of the set `semid`, or to all of them.
+fn clear_undo(semid: c_int, num: Option<c_ushort>) {
+    let mut undo = UNDO.lock();
+    if undo.pid == Sys::getpid() {
+        undo.list
+            .retain(|undo| undo.id != semid || num.map_or(false, |num| num != undo.num));
+    }
+}
+
+extern "C" fn undo_all() {
+    let list = {
+        let mut undo = UNDO.lock();
+        if undo.pid != Sys::getpid() {
+            return;
+        }
+        mem::take(&mut undo.list)
+    };
+    if list.is_empty() {
+        return;
+    }
+
+    let registry = match unsafe { registry() } {
+        Ok(registry) => registry,
+        Err(_) => return,
+    };
+    let mut entries = registry.lock();
+    for undo in list {
+        let entry = match lookup(&mut entries, SEM, undo.id) {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        let _ = unsafe {
+            with_object(undo.id, entry.size, |sems: &mut [Sem]| {
+                let sem = &mut sems[undo.num as usize];
+                sem.value = cmp::max(0, cmp::min(sem.value + undo.adjust, SEMVMX));
+            })
+        };
+        wake(entry);
+    }
+}
+
+unsafe fn shmget(key: key_t, size: size_t, shmflg: c_int) -> Result<c_int, Errno> {
+    let mut entries = registry()?.lock();
+    let (slot, new) = get(&mut entries, SHM, key, shmflg)?;
+    if !new {
+        if size > entries[slot].size {
+            return Err(Errno(EINVAL));
+        }
+        return Ok(id_of(slot, &entries[slot]));
+    }
+
+    if size == 0 || size > SHMMAX {
+        entries[slot].kind = FREE;
+        return Err(Errno(EINVAL));
+    }
+    entries[slot].size = size;
+    entries[slot].shm_cpid = Sys::getpid();
+    create_object(&mut entries, slot, size)
+}
+
+unsafe fn shmat(shmid: c_int, shmaddr: *const c_void, shmflg: c_int) -> Result<*mut c_void, Errno> {
+    let mut entries = registry()?.lock();
+    let entry = lookup(&mut entries, SHM, shmid)?;
+
+    let (wanted, prot) = if shmflg & SHM_RDONLY == SHM_RDONLY {
+        (READ, PROT_READ)
+    } else {
+        (READ | WRITE, PROT_READ | PROT_WRITE)
+    };
+    check_permitted(&entry.perm, wanted)?;
+
+    let mut addr = shmaddr as usize;
+    let mut flags = MAP_SHARED;
+    if addr != 0 {
+        if shmflg & SHM_RND == SHM_RND {
+            addr -= addr % SHMLBA as usize;
+        } else if addr % SHMLBA as usize != 0 {
+            return Err(Errno(EINVAL));
+        }
+        flags |= MAP_FIXED;
+    }
+
+    let fd = open_object(shmid, None)?;
+    let mapped = Sys::mmap(addr as *mut c_void, entry.size, prot, flags, fd, 0);
+    let err = last_error();
+    Sys::close(fd);
+    if mapped as isize == -1 {
+        return Err(err);
+    }
+
+    entry.shm_nattch += 1;
+    entry.shm_atime = now();
+    entry.shm_lpid = Sys::getpid();
+    ATTACHED.lock().push(Attachment {
+        addr: mapped as usize,
+        size: entry.size,
+        id: shmid,
+    });
+    Ok(mapped)
+}
+
+unsafe fn shmdt(shmaddr: *const c_void) -> Result<c_int, Errno> {
+    let mut entries = registry()?.lock();
+    let attachment = {
+        let mut attached = ATTACHED.lock();
+        let i = attached
+            .iter()
+            .position(|attachment| attachment.addr == shmaddr as usize)
+            .ok_or(Errno(EINVAL))?;
+        attached.remove(i)
+    };
+    Sys::munmap(attachment.addr as *mut c_void, attachment.size);
+
+    // The segment may have been removed in the meantime
+    let slot = slot_of(attachment.id);
+    let entry = &mut entries[slot];
+    if entry.kind == SHM && entry.seq == attachment.id / SLOTS as c_int {
+        entry.shm_nattch -= 1;
+        entry.shm_dtime = now();
+        entry.shm_lpid = Sys::getpid();
+        if entry.removed && entry.shm_nattch == 0 {
+            remove(&mut entries, slot);
+        }
+    }
+    Ok(0)
+}
+
+unsafe fn shmctl(shmid: c_int, cmd: c_int, buf: *mut shmid_ds) -> Result<c_int, Errno> {
+    let mut entries = registry()?.lock();
+    let entry = lookup(&mut entries, SHM, shmid)?;
+    match cmd {
+        IPC_STAT => {
+            check_permitted(&entry.perm, READ)?;
+            *buf = shmid_ds {
+                shm_perm: entry.perm,
+                shm_segsz: entry.size,
+                shm_atime: entry.shm_atime,
+                shm_dtime: entry.shm_dtime,
+                shm_ctime: entry.ctime,
+                shm_cpid: entry.shm_cpid,
+                shm_lpid: entry.shm_lpid,
+                shm_nattch: entry.shm_nattch,
+                __unused: [0; 2],
+            };
+        }
+        IPC_SET => {
+            check_owner(&entry.perm)?;
+            entry.perm.uid = (*buf).shm_perm.uid;
+            entry.perm.gid = (*buf).shm_perm.gid;
+            entry.perm.mode = (*buf).shm_perm.mode & 0o777;
+            entry.ctime = now();
+        }
+        IPC_RMID => {
+            check_owner(&entry.perm)?;
+            if entry.shm_nattch == 0 {
+                remove(&mut entries, slot_of(shmid));
+            } else {
+                // Whoever asks for the key from now on gets a new segment
+                entry.removed = true;
+                entry.perm.__key = IPC_PRIVATE;
+            }
+        }
+        _ => return Err(Errno(EINVAL)),
+    }
+    Ok(0)
+}
+
+unsafe fn semget(key: key_t, nsems: c_int, semflg: c_int) -> Result<c_int, Errno> {
+    if nsems < 0 || nsems > SEMMSL {
+        return Err(Errno(EINVAL));
+    }
+    let mut entries = registry()?.lock();
+    let (slot, new) = get(&mut entries, SEM, key, semflg)?;
+    if !new {
+        if nsems as size_t > entries[slot].size {
+            return Err(Errno(EINVAL));
+        }
+        return Ok(id_of(slot, &entries[slot]));
+    }
+
+    if nsems == 0 {
+        entries[slot].kind = FREE;
+        return Err(Errno(EINVAL));
+    }
+    entries[slot].size = nsems as size_t;
+    create_object(&mut entries, slot, nsems as size_t * mem::size_of::<Sem>())
+}
+
+unsafe fn semtimedop(
+    semid: c_int,
+    sops: &[sembuf],
+    timeout: Option<&timespec>,
+) -> Result<c_int, Errno> {
+    if sops.is_empty() {
+        return Err(Errno(EINVAL));
+    }
+    if sops.len() > SEMOPM {
+        return Err(Errno(E2BIG));
+    }
+    let deadline = match timeout {
+        Some(timeout) => {
+            if timeout.tv_sec < 0 || timeout.tv_nsec < 0 || timeout.tv_nsec >= 1_000_000_000 {
+                return Err(Errno(EINVAL));
+            }
+            let mut deadline = timespec::default();
+            Sys::clock_gettime(CLOCK_MONOTONIC, &mut deadline);
+            deadline.tv_sec += timeout.tv_sec;
+            deadline.tv_nsec += timeout.tv_nsec;
+            if deadline.tv_nsec >= 1_000_000_000 {
+                deadline.tv_sec += 1;
+                deadline.tv_nsec -= 1_000_000_000;
+            }
+            Some(deadline)
+        }
+        None => None,
+    };
+    let alter = sops.iter().any(|op| op.sem_op != 0);
+
+    let registry = registry()?;
+    let mut entries = registry.lock();
+    // The semaphore that this call is counted as waiting for, and how it went
+    let mut waiting: Option<(usize, bool)> = None;
+    let mut woken = Ok(());
+    loop {
+        let entry = match lookup(&mut entries, SEM, semid) {
+            Ok(entry) => entry,
+            Err(_) if waiting.is_some() => return Err(Errno(EIDRM)),
+            Err(err) => return Err(err),
+        };
+        if sops.iter().any(|op| op.sem_num as size_t >= entry.size) {
+            return Err(Errno(EFBIG));
+        }
+        check_permitted(&entry.perm, if alter { WRITE } else { READ })?;
+
+        let blocked = with_object(semid, entry.size, |sems: &mut [Sem]| {
+            if let Some((num, zero)) = waiting.take() {
+                if zero {
+                    sems[num].zcnt -= 1;
+                } else {
+                    sems[num].ncnt -= 1;
+                }
+            }
+            woken?;
+
+            let blocked = try_semop(sems, sops)?;
+            if let Some(i) = blocked {
+                let op = &sops[i];
+                if op.sem_flg as c_int & IPC_NOWAIT == IPC_NOWAIT {
+                    return Err(Errno(EAGAIN));
+                }
+                let num = op.sem_num as usize;
+                if op.sem_op == 0 {
+                    sems[num].zcnt += 1;
+                } else {
+                    sems[num].ncnt += 1;
+                }
+                waiting = Some((num, op.sem_op == 0));
+            }
+            Ok(blocked)
+        })??;
+
+        if blocked.is_none() {
+            entry.sem_otime = now();
+            wake(entry);
+            drop(entries);
+            record_undo(semid, sops);
+            return Ok(0);
+        }
+
+        let (locked, result) = sleep(registry, entries, slot_of(semid), deadline.as_ref());
+        entries = locked;
+        // A timeout makes the call fail like IPC_NOWAIT does
+        woken = result.map_err(|Errno(err)| Errno(if err == ETIMEDOUT { EAGAIN } else { err }));
+    }
+}
+
+unsafe fn semctl(semid: c_int, semnum: c_int, cmd: c_int, arg: c_ulong) -> Result<c_int, Errno> {
+    let mut entries = registry()?.lock();
+    let entry = lookup(&mut entries, SEM, semid)?;
+    let nsems = entry.size;
+    let num = || {
+        if semnum < 0 || semnum as size_t >= nsems {
+            Err(Errno(EINVAL))
+        } else {
+            Ok(semnum as usize)
+        }
+    };
+
+    match cmd {
+        IPC_STAT => {
+            check_permitted(&entry.perm, READ)?;
+            let buf = arg as *mut semid_ds;
+            ptr::write_bytes(buf, 0, 1);
+            (*buf).sem_perm = entry.perm;
+            (*buf).sem_otime = entry.sem_otime;
+            (*buf).sem_ctime = entry.ctime;
+            (*buf).sem_nsems = nsems as c_ulong;
+            Ok(0)
+        }
+        IPC_SET => {
+            check_owner(&entry.perm)?;
+            let buf = arg as *const semid_ds;
+            entry.perm.uid = (*buf).sem_perm.uid;
+            entry.perm.gid = (*buf).sem_perm.gid;
+            entry.perm.mode = (*buf).sem_perm.mode & 0o777;
+            entry.ctime = now();
+            Ok(0)
+        }
+        IPC_RMID => {
+            check_owner(&entry.perm)?;
+            remove(&mut entries, slot_of(semid));
+            drop(entries);
+            clear_undo(semid, None);
+            Ok(0)
+        }
+        GETVAL | GETPID | GETNCNT | GETZCNT => {
+            check_permitted(&entry.perm, READ)?;
+            let num = num()?;
+            with_object(semid, nsems, |sems: &mut [Sem]| match cmd {
+                GETVAL => sems[num].value,
+                GETPID => sems[num].pid,
+                GETNCNT => sems[num].ncnt,
+                _ => sems[num].zcnt,
+            })
+        }
+        GETALL => {
+            check_permitted(&entry.perm, READ)?;
+            let array = slice::from_raw_parts_mut(arg as *mut c_ushort, nsems);
+            with_object(semid, nsems, |sems: &mut [Sem]| {
+                for (value, sem) in array.iter_mut().zip(sems.iter()) {
+                    *value = sem.value as c_ushort;
+                }
+            })?;
+            Ok(0)
+        }
+        SETVAL => {
+            check_permitted(&entry.perm, WRITE)?;
+            let num = num()?;
+            let value = arg as c_int;
+            if value < 0 || value > SEMVMX {
+                return Err(Errno(ERANGE));
+            }
+            with_object(semid, nsems, |sems: &mut [Sem]| sems[num].value = value)?;
+            entry.ctime = now();
+            wake(entry);
+            drop(entries);
+            clear_undo(semid, Some(num as c_ushort));
+            Ok(0)
+        }
+        SETALL => {
+            check_permitted(&entry.perm, WRITE)?;
+            let array = slice::from_raw_parts(arg as *const c_ushort, nsems);
+            if array.iter().any(|&value| value as c_int > SEMVMX) {
+                return Err(Errno(ERANGE));
+            }
+            with_object(semid, nsems, |sems: &mut [Sem]| {
+                for (sem, &value) in sems.iter_mut().zip(array) {
+                    sem.value = value as c_int;
+                }
+            })?;
+            entry.ctime = now();
+            wake(entry);
+            drop(entries);
+            clear_undo(semid, None);
+            Ok(0)
+        }
+        _ => Err(Errno(EINVAL)),
+    }
+}
+
+/// The room that a message with `len` bytes of text takes up in the storage of a queue
+fn stored_size(len: size_t) -> size_t {
+    let align = mem::align_of::<Message>();
+    mem::size_of::<Message>() + (len + align - 1) / align * align
+}
+
+unsafe fn msgget(key: key_t, msgflg: c_int) -> Result<c_int, Errno> {
+    let mut entries = registry()?.lock();
+    let (slot, new) = get(&mut entries, MSG, key, msgflg)?;
+    if !new {
+        return Ok(id_of(slot, &entries[slot]));
+    }
+    entries[slot].size = MSGMNB;
+    create_object(&mut entries, slot, MSG_STORAGE)
+}
+
+unsafe fn msgsnd(
+    msqid: c_int,
+    msgp: *const c_void,
+    msgsz: size_t,
+    msgflg: c_int,
+) -> Result<c_int, Errno> {
+    let mtype = *msgp.cast::<c_long>();
+    if msgsz > MSGMAX || mtype < 1 {
+        return Err(Errno(EINVAL));
+    }
+    let text = slice::from_raw_parts(msgp.cast::<u8>().add(mem::size_of::<c_long>()), msgsz);
+
+    let registry = registry()?;
+    let mut entries = registry.lock();
+    let mut waited = false;
+    loop {
+        let entry = match lookup(&mut entries, MSG, msqid) {
+            Ok(entry) => entry,
+            Err(_) if waited => return Err(Errno(EIDRM)),
+            Err(err) => return Err(err),
+        };
+        check_permitted(&entry.perm, WRITE)?;
+
+        let stored = stored_size(msgsz);
+        if entry.msg_cbytes + msgsz <= entry.size
+            && entry.msg_qnum < entry.size
+            && entry.msg_used + stored <= MSG_STORAGE
+        {
+            let used = entry.msg_used;
+            with_object(msqid, MSG_STORAGE, |storage: &mut [u8]| {
+                let header = storage[used..].as_mut_ptr().cast::<Message>();
+                header.write(Message { mtype, len: msgsz });
+                storage[used + mem::size_of::<Message>()..][..msgsz].copy_from_slice(text);
+            })?;
+            entry.msg_used += stored;
+            entry.msg_cbytes += msgsz;
+            entry.msg_qnum += 1;
+            entry.msg_lspid = Sys::getpid();
+            entry.msg_stime = now();
+            wake(entry);
+            return Ok(0);
+        }
+
+        if msgflg & IPC_NOWAIT == IPC_NOWAIT {
+            return Err(Errno(EAGAIN));
+        }
+        let (locked, result) = sleep(registry, entries, slot_of(msqid), None);
+        entries = locked;
+        result?;
+        waited = true;
+    }
+}
+
+unsafe fn msgrcv(
+    msqid: c_int,
+    msgp: *mut c_void,
+    msgsz: size_t,
+    msgtyp: c_long,
+    msgflg: c_int,
+) -> Result<ssize_t, Errno> {
+    let registry = registry()?;
+    let mut entries = registry.lock();
+    let mut waited = false;
+    loop {
+        let entry = match lookup(&mut entries, MSG, msqid) {
+            Ok(entry) => entry,
+            Err(_) if waited => return Err(Errno(EIDRM)),
+            Err(err) => return Err(err),
+        };
+        check_permitted(&entry.perm, READ)?;
+
+        let used = entry.msg_used;
+        let received = with_object(msqid, MSG_STORAGE, |storage: &mut [u8]| {
+            // Finds the first message of the wanted type, or the first of the lowest type
+            let mut found: Option<(usize, c_long)> = None;
+            let mut offset = 0;
+            while offset < used {
+                let header = &*storage[offset..].as_ptr().cast::<Message>();
+                let matches = match msgtyp {
+                    0 => true,
+                    _ if msgtyp > 0 => {
+                        (header.mtype == msgtyp) != (msgflg & MSG_EXCEPT == MSG_EXCEPT)
+                    }
+                    _ => {
+                        header.mtype as c_ulong <= msgtyp.unsigned_abs()
+                            && found.map_or(true, |(_, mtype)| header.mtype < mtype)
+                    }
+                };
+                if matches {
+                    found = Some((offset, header.mtype));
+                    if msgtyp >= 0 {
+                        break;
+                    }
+                }
+                offset += stored_size(header.len);
+            }
+
+            let (offset, mtype) = match found {
+                Some(found) => found,
+                None => return Ok(None),
+            };
+            let len = (*storage[offset..].as_ptr().cast::<Message>()).len;
+            if len > msgsz && msgflg & MSG_NOERROR == 0 {
+                return Err(Errno(E2BIG));
+            }
+            let copied = cmp::min(len, msgsz);
+            *msgp.cast::<c_long>() = mtype;
+            ptr::copy_nonoverlapping(
+                storage[offset + mem::size_of::<Message>()..].as_ptr(),
+                msgp.cast::<u8>().add(mem::size_of::<c_long>()),
+                copied,
+            );
+
+            let stored = stored_size(len);
+            storage.copy_within(offset + stored..used, offset);
+            Ok(Some((len, stored, copied)))
+        })??;
+
+        if let Some((len, stored, copied)) = received {
+            entry.msg_used -= stored;
+            entry.msg_cbytes -= len;
+            entry.msg_qnum -= 1;
+            entry.msg_lrpid = Sys::getpid();
+            entry.msg_rtime = now();
+            wake(entry);
+            return Ok(copied as ssize_t);
+        }
+
+        if msgflg & IPC_NOWAIT == IPC_NOWAIT {
+            return Err(Errno(ENOMSG));
+        }
+        let (locked, result) = sleep(registry, entries, slot_of(msqid), None);
+        entries = locked;
+        result?;
+        waited = true;
+    }
+}
+
+unsafe fn msgctl(msqid: c_int, cmd: c_int, buf: *mut msqid_ds) -> Result<c_int, Errno> {
+    let mut entries = registry()?.lock();
+    let entry = lookup(&mut entries, MSG, msqid)?;
+    match cmd {
+        IPC_STAT => {
+            check_permitted(&entry.perm, READ)?;
+            *buf = msqid_ds {
+                msg_perm: entry.perm,
+                msg_stime: entry.msg_stime,
+                msg_rtime: entry.msg_rtime,
+                msg_ctime: entry.ctime,
+                msg_cbytes: entry.msg_cbytes as c_ulong,
+                msg_qnum: entry.msg_qnum as msgqnum_t,
+                msg_qbytes: entry.size as msglen_t,
+                msg_lspid: entry.msg_lspid,
+                msg_lrpid: entry.msg_lrpid,
+                __unused: [0; 2],
+            };
+        }
+        IPC_SET => {
+            check_owner(&entry.perm)?;
+            // The storage is only large enough for the default capacity
+            if (*buf).msg_qbytes as size_t > MSGMNB {
+                return Err(Errno(EPERM));
+            }
+            entry.perm.uid = (*buf).msg_perm.uid;
+            entry.perm.gid = (*buf).msg_perm.gid;
+            entry.perm.mode = (*buf).msg_perm.mode & 0o777;
+            entry.size = (*buf).msg_qbytes as size_t;
+            entry.ctime = now();
+            wake(entry);
+        }
+        IPC_RMID => {
+            check_owner(&entry.perm)?;
+            remove(&mut entries, slot_of(msqid));
+        }
+        _ => return Err(Errno(EINVAL)),
+    }
+    Ok(0)
+}
+
+impl PalIpc for Sys {
+    unsafe fn msgctl(msqid: c_int, cmd: c_int, buf: *mut msqid_ds) -> c_int {
+        or_errno(msgctl(msqid, cmd, buf), -1)
+    }
+
+    fn msgget(key: key_t, msgflg: c_int) -> c_int {
+        or_errno(unsafe { msgget(key, msgflg) }, -1)
+    }
+
+    unsafe fn msgrcv(
+        msqid: c_int,
+        msgp: *mut c_void,
+        msgsz: size_t,
+        msgtyp: c_long,
+        msgflg: c_int,
+    ) -> ssize_t {
+        or_errno(msgrcv(msqid, msgp, msgsz, msgtyp, msgflg), -1)
+    }
+
+    unsafe fn msgsnd(msqid: c_int, msgp: *const c_void, msgsz: size_t, msgflg: c_int) -> c_int {
+        or_errno(msgsnd(msqid, msgp, msgsz, msgflg), -1)
+    }
+
+    unsafe fn semctl(semid: c_int, semnum: c_int, cmd: c_int, arg: c_ulong) -> c_int {
+        or_errno(semctl(semid, semnum, cmd, arg), -1)
+    }
+
+    fn semget(key: key_t, nsems: c_int, semflg: c_int) -> c_int {
+        or_errno(unsafe { semget(key, nsems, semflg) }, -1)
+    }
+
+    unsafe fn semtimedop(
+        semid: c_int,
+        sops: *mut sembuf,
+        nsops: size_t,
+        timeout: *const timespec,
+    ) -> c_int {
+        let sops = if nsops == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(sops, nsops)
+        };
+        or_errno(semtimedop(semid, sops, timeout.as_ref()), -1)
+    }
+
+    unsafe fn shmat(shmid: c_int, shmaddr: *const c_void, shmflg: c_int) -> *mut c_void {
+        or_errno(shmat(shmid, shmaddr, shmflg), !0 as *mut c_void)
+    }
+
+    unsafe fn shmctl(shmid: c_int, cmd: c_int, buf: *mut shmid_ds) -> c_int {
+        or_errno(shmctl(shmid, cmd, buf), -1)
+    }
+
+    unsafe fn shmdt(shmaddr: *const c_void) -> c_int {
+        or_errno(shmdt(shmaddr), -1)
+    }
+
+    fn shmget(key: key_t, size: size_t, shmflg: c_int) -> c_int {
+        or_errno(unsafe { shmget(key, size, shmflg) }, -1)
+    }
+}
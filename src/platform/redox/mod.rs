@@ -15,6 +15,7 @@ use crate::{
         dirent::dirent,
         errno::{EINVAL, EIO, ENOMEM, EPERM, ERANGE},
         fcntl,
+        sched::{sched_param, SCHED_OTHER},
         string::strlen,
         sys_mman::{MAP_ANONYMOUS, PROT_READ, PROT_WRITE},
         sys_random,
@@ -42,14 +43,16 @@ fn round_up_to_page_size(val: usize) -> usize {
     (val + PAGE_SIZE - 1) / PAGE_SIZE * PAGE_SIZE
 }
 
-mod clone;
+pub(crate) mod clone;
 mod epoll;
-mod exec;
+pub(crate) mod exec;
 mod extra;
+mod ipc;
 pub(crate) mod path;
 mod ptrace;
 mod signal;
 mod socket;
+mod timer;
 
 macro_rules! path_from_c_str {
     ($c_str:expr) => {{
@@ -217,12 +220,17 @@ impl Pal for Sys {
         loop {}
     }
 
+    unsafe fn exit_thread(os_tid: *mut pid_t) -> ! {
+        // Every thread is its own context, so exiting only ends the current one
+        clone::exit_thread_impl(os_tid)
+    }
+
     unsafe fn execve(
         path: &CStr,
         argv: *const *mut c_char,
         envp: *const *mut c_char,
     ) -> c_int {
-        e(self::exec::execve(path, self::exec::ArgEnv::C { argv, envp }, None)) as c_int
+        e(self::exec::execve(path, self::exec::ArgEnv::C { argv, envp })) as c_int
     }
 
     fn fchdir(fd: c_int) -> c_int {
@@ -533,6 +541,11 @@ impl Pal for Sys {
         0
     }
 
+    fn getsid(pid: pid_t) -> pid_t {
+        // TODO: Without sessions, setsid only makes a process group, so that is what to report
+        Self::getpgid(pid)
+    }
+
     fn gettid() -> pid_t {
         //TODO
         Self::getpid()
@@ -728,8 +741,9 @@ impl Pal for Sys {
         res as c_int
     }
 
-    unsafe fn pte_clone(stack: *mut usize) -> pid_t {
-        e(clone::pte_clone_impl(stack)) as pid_t
+    unsafe fn rlct_clone(stack: *mut usize, os_tid: *mut pid_t) -> pid_t {
+        // Redox cannot clear the thread ID for us, see exit_thread
+        e(clone::rlct_clone_impl(stack, os_tid)) as pid_t
     }
 
     fn read(fd: c_int, buf: &mut [u8]) -> ssize_t {
@@ -760,6 +774,46 @@ impl Pal for Sys {
         e(canonicalize(path).and_then(|path| syscall::rmdir(&path))) as c_int
     }
 
+    // Redox only has one scheduling policy, with no priorities
+
+    fn sched_get_priority_max(policy: c_int) -> c_int {
+        match policy {
+            SCHED_OTHER => 0,
+            _ => e(Err(syscall::Error::new(syscall::EINVAL))) as c_int,
+        }
+    }
+
+    fn sched_get_priority_min(policy: c_int) -> c_int {
+        Self::sched_get_priority_max(policy)
+    }
+
+    fn sched_getparam(_pid: pid_t, param: &mut sched_param) -> c_int {
+        param.sched_priority = 0;
+        0
+    }
+
+    fn sched_getscheduler(_pid: pid_t) -> c_int {
+        SCHED_OTHER
+    }
+
+    fn sched_rr_get_interval(_pid: pid_t, _interval: &mut timespec) -> c_int {
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
+    fn sched_setparam(_pid: pid_t, param: &sched_param) -> c_int {
+        match param.sched_priority {
+            0 => 0,
+            _ => e(Err(syscall::Error::new(syscall::EINVAL))) as c_int,
+        }
+    }
+
+    fn sched_setscheduler(pid: pid_t, policy: c_int, param: &sched_param) -> c_int {
+        match policy {
+            SCHED_OTHER => Self::sched_setparam(pid, param),
+            _ => e(Err(syscall::Error::new(syscall::EINVAL))) as c_int,
+        }
+    }
+
     fn sched_yield() -> c_int {
         e(syscall::sched_yield()) as c_int
     }
@@ -776,6 +830,14 @@ impl Pal for Sys {
         e(syscall::setreuid(ruid as usize, euid as usize)) as c_int
     }
 
+    fn setsid() -> pid_t {
+        // TODO: Redox has no sessions yet, so the closest is a process group of our own
+        if Self::setpgid(0, 0) < 0 {
+            return -1;
+        }
+        Self::getpid()
+    }
+
     fn symlink(path1: &CStr, path2: &CStr) -> c_int {
         let mut file = match File::create(
             path2,
@@ -887,6 +949,19 @@ impl Pal for Sys {
         e(canonicalize(path).and_then(|path| syscall::unlink(&path))) as c_int
     }
 
+    unsafe fn vfork_clone(stack: *mut usize) -> pid_t {
+        // Processes cannot share an address space, so the child works on a copy, on the stack it
+        // was forked on. posix_spawn loads most programs into new contexts itself, and only forks
+        // for setuid programs, which need the `escalate:` scheme, for resetting the ids and for
+        // fchdir file actions.
+        let pid = Self::fork();
+        if pid == 0 {
+            let entry: extern "C" fn(*mut c_void) -> c_int = mem::transmute(*stack);
+            Self::exit(entry(*stack.add(1) as *mut c_void));
+        }
+        pid
+    }
+
     fn waitpid(mut pid: pid_t, stat_loc: *mut c_int, options: c_int) -> pid_t {
         if pid == !0 {
             pid = 0;
@@ -1,19 +1,255 @@
-use core::mem;
-use syscall;
+use core::{
+    cell::Cell,
+    mem, ptr,
+    sync::atomic::{AtomicI32, AtomicUsize, Ordering},
+};
+use syscall::{self, flag::SigActionFlags};
 
 use super::{
     super::{types::*, Pal, PalSignal},
-    e, Sys,
+    e, timer, Sys,
 };
 use crate::{
     header::{
-        errno::EINVAL,
-        signal::{sigaction, sigset_t, stack_t},
+        errno::{EAGAIN, EINTR, EINVAL, ENOTSUP},
+        signal::{
+            __si_kill, sigaction, sigevent, siginfo_t, sigset_t, sigval, stack_t, NSIG, SA_SIGINFO,
+            SIGKILL, SIGSTOP, SIG_BLOCK, SIG_DFL, SIG_IGN, SIG_SETMASK, SIG_UNBLOCK, SI_QUEUE,
+            SI_USER,
+        },
         sys_time::{itimerval, ITIMER_REAL},
+        time::{itimerspec, timespec, CLOCK_MONOTONIC},
     },
     platform::errno,
+    sync::{relative_timeout, Mutex},
 };
 
+const FUTEX_WAIT: c_int = 0;
+
+const UNSET: AtomicUsize = AtomicUsize::new(0);
+const NO_CODE: AtomicI32 = AtomicI32::new(SI_USER);
+
+/// What the program set for each signal: SIG_DFL, SIG_IGN or the address of its handler. The
+/// kernel only sees `dispatch` in place of a handler.
+static HANDLERS: [AtomicUsize; NSIG + 1] = [UNSET; NSIG + 1];
+/// The signals whose handler was set with SA_SIGINFO
+static SIGINFO: AtomicUsize = AtomicUsize::new(0);
+
+/// The kernel gives handlers nothing but the signal number, so what siginfo_t says about the
+/// sender is left here by this process when it signals itself. `SENT` has the signals it sent,
+/// and `QUEUED` those of them that carry a code and a value in `CODES` and `VALUES`.
+static SENT: AtomicUsize = AtomicUsize::new(0);
+static QUEUED: AtomicUsize = AtomicUsize::new(0);
+static CODES: [AtomicI32; NSIG + 1] = [NO_CODE; NSIG + 1];
+static VALUES: [AtomicUsize; NSIG + 1] = [UNSET; NSIG + 1];
+
+/// Counts the signals handled by this thread. Sleeping on the count with a futex cannot miss a
+/// signal that arrives just before the sleep, unlike sleeping until interrupted.
+#[thread_local]
+static DELIVERED: AtomicI32 = AtomicI32::new(0);
+
+/// The signals that sigtimedwait lets through to `dispatch`, to take the first that arrives
+#[derive(Clone, Copy)]
+struct Catch {
+    set: sigset_t,
+    taken: Option<siginfo_t>,
+    /// The ones that arrived after the first, which are raised again once they are blocked
+    others: sigset_t,
+}
+
+#[thread_local]
+static CATCH: Cell<Option<Catch>> = Cell::new(None);
+
+/// The signals that `catch` has the dispatcher stand in for: how many threads are waiting for
+/// each, and the action to set again once none are. sigaction() changes that action instead of
+/// the one the kernel has while threads wait, so that it is not undone when they are done.
+struct StandIns {
+    waiters: [usize; NSIG + 1],
+    saved: [Option<syscall::SigAction>; NSIG + 1],
+}
+
+static STAND_INS: Mutex<StandIns> = Mutex::new(StandIns {
+    waiters: [0; NSIG + 1],
+    saved: [None; NSIG + 1],
+});
+
+/// Runs `f` with the stand-ins locked. Signals are blocked meanwhile, so that a handler that
+/// calls sigaction() cannot wait for the lock that the code it interrupted holds.
+fn with_stand_ins<T>(f: impl FnOnce(&mut StandIns) -> T) -> T {
+    let all = !0;
+    let mut old = 0;
+    Sys::sigprocmask(SIG_BLOCK, &all, &mut old);
+    let ret = f(&mut STAND_INS.lock());
+    Sys::sigprocmask(SIG_SETMASK, &old, ptr::null_mut());
+    ret
+}
+
+/// The bit for `sig`, in a sigset_t as well as in the sets above
+fn sigmask(sig: usize) -> usize {
+    1 << (sig - 1)
+}
+
+fn is_dispatch(action: &syscall::SigAction) -> bool {
+    action.sa_handler.map(|handler| handler as usize) == Some(dispatch as usize)
+}
+
+/// The siginfo_t for a signal that has arrived, taking what the sender left for it.
+fn siginfo(sig: usize) -> siginfo_t {
+    let mut info: siginfo_t = unsafe { mem::zeroed() };
+    info.si_signo = sig as c_int;
+    info.si_code = SI_USER;
+    if SENT.fetch_and(!sigmask(sig), Ordering::Acquire) & sigmask(sig) != 0 {
+        let mut value = sigval {
+            sival_ptr: ptr::null_mut(),
+        };
+        if QUEUED.fetch_and(!sigmask(sig), Ordering::Acquire) & sigmask(sig) != 0 {
+            info.si_code = CODES[sig].load(Ordering::Relaxed);
+            value.sival_ptr = VALUES[sig].load(Ordering::Relaxed) as *mut c_void;
+        }
+        info.__si_fields.__kill = __si_kill {
+            si_pid: Sys::getpid(),
+            si_uid: Sys::getuid(),
+            si_value: value,
+        };
+    }
+    info
+}
+
+/// The handler the kernel calls for every signal the program handles.
+extern "C" fn dispatch(sig: usize) {
+    DELIVERED.fetch_add(1, Ordering::Relaxed);
+
+    if let Some(mut catch) = CATCH.get() {
+        if catch.set & sigmask(sig) as sigset_t != 0 {
+            if catch.taken.is_none() {
+                catch.taken = Some(siginfo(sig));
+            } else {
+                catch.others |= sigmask(sig) as sigset_t;
+            }
+            CATCH.set(Some(catch));
+            return;
+        }
+    }
+
+    let mut info = siginfo(sig);
+    match HANDLERS[sig].load(Ordering::Acquire) {
+        SIG_IGN => (),
+        SIG_DFL => {
+            // Only `catch` puts the dispatcher in place of the default action, for signals that
+            // must be blocked in every other thread. One that arrives anyway is raised again.
+            let default = syscall::SigAction::default();
+            let _ = syscall::sigaction(sig, Some(&default), None);
+            let _ = syscall::kill(Sys::getpid() as usize, sig);
+        }
+        handler => unsafe {
+            if SIGINFO.load(Ordering::Relaxed) & sigmask(sig) != 0 {
+                let handler: extern "C" fn(c_int, *mut siginfo_t, *mut c_void) =
+                    mem::transmute(handler);
+                handler(sig as c_int, &mut info, ptr::null_mut());
+            } else {
+                let handler: extern "C" fn(c_int) = mem::transmute(handler);
+                handler(sig as c_int);
+            }
+        },
+    }
+}
+
+/// Sends `sig` to `pid`, a thread of this process, recording the code and value that siginfo_t
+/// reports for it.
+pub(super) fn queue(pid: pid_t, sig: c_int, code: c_int, value: sigval) -> c_int {
+    if sig > 0 && sig as usize <= NSIG {
+        let sig = sig as usize;
+        CODES[sig].store(code, Ordering::Relaxed);
+        VALUES[sig].store(unsafe { value.sival_ptr } as usize, Ordering::Relaxed);
+        QUEUED.fetch_or(sigmask(sig), Ordering::Release);
+        SENT.fetch_or(sigmask(sig), Ordering::Release);
+    }
+    e(syscall::kill(pid as usize, sig as usize)) as c_int
+}
+
+/// Lets the signals in `set` through to `dispatch` while `wait` runs, with `catch` saying which
+/// arrived. `wait` gets the count of delivered signals from before they were let through.
+unsafe fn catch<T>(set: sigset_t, wait: impl FnOnce(i32) -> T) -> (Catch, T) {
+    // Signals without a handler have the dispatcher stand in for their default action or for
+    // ignoring them. SA_RESETHAND would leave the next one to the default action.
+    let mut standing_in: sigset_t = 0;
+    with_stand_ins(|stand_ins| {
+        for sig in 1..=NSIG {
+            if set & sigmask(sig) as sigset_t == 0 || sig == SIGKILL || sig == SIGSTOP {
+                continue;
+            }
+            if stand_ins.waiters[sig] > 0 {
+                stand_ins.waiters[sig] += 1;
+                standing_in |= sigmask(sig) as sigset_t;
+                continue;
+            }
+            let mut old = syscall::SigAction::default();
+            if syscall::sigaction(sig, None, Some(&mut old)).is_err() {
+                continue;
+            }
+            let dispatched = is_dispatch(&old);
+            if !dispatched || old.sa_flags.contains(SigActionFlags::SA_RESETHAND) {
+                if !dispatched {
+                    HANDLERS[sig].store(
+                        old.sa_handler.map_or(SIG_DFL, |handler| handler as usize),
+                        Ordering::Release,
+                    );
+                    SIGINFO.fetch_and(!sigmask(sig), Ordering::Relaxed);
+                }
+                let new = syscall::SigAction {
+                    sa_handler: Some(dispatch),
+                    sa_flags: old.sa_flags - SigActionFlags::SA_RESETHAND,
+                    ..old
+                };
+                if syscall::sigaction(sig, Some(&new), None).is_err() {
+                    continue;
+                }
+            }
+            stand_ins.waiters[sig] = 1;
+            stand_ins.saved[sig] = Some(old);
+            standing_in |= sigmask(sig) as sigset_t;
+        }
+    });
+
+    CATCH.set(Some(Catch {
+        set,
+        taken: None,
+        others: 0,
+    }));
+    let seen = DELIVERED.load(Ordering::Relaxed);
+    let mut old_mask = 0;
+    Sys::sigprocmask(SIG_UNBLOCK, &set, &mut old_mask);
+    // Pending signals are delivered when the kernel next switches to this thread
+    Sys::sched_yield();
+    let ret = wait(seen);
+    Sys::sigprocmask(SIG_SETMASK, &old_mask, ptr::null_mut());
+    let catch = CATCH.take().unwrap();
+
+    // The last thread to be done sets the action again, which is the one of the last
+    // sigaction() made while they waited, if any was
+    with_stand_ins(|stand_ins| {
+        for sig in 1..=NSIG {
+            if standing_in & sigmask(sig) as sigset_t == 0 {
+                continue;
+            }
+            stand_ins.waiters[sig] -= 1;
+            if stand_ins.waiters[sig] == 0 {
+                if let Some(saved) = stand_ins.saved[sig].take() {
+                    let _ = syscall::sigaction(sig, Some(&saved), None);
+                }
+            }
+        }
+    });
+    // What the sender left for these stays for when they arrive again
+    for sig in 1..=NSIG {
+        if catch.others & sigmask(sig) as sigset_t != 0 {
+            let _ = syscall::kill(Sys::getpid() as usize, sig);
+        }
+    }
+
+    (catch, ret)
+}
+
 impl PalSignal for Sys {
     fn getitimer(which: c_int, out: *mut itimerval) -> c_int {
         let path = match which {
@@ -49,6 +285,9 @@ impl PalSignal for Sys {
     }
 
     fn kill(pid: pid_t, sig: c_int) -> c_int {
+        if pid == Self::getpid() && sig > 0 && sig as usize <= NSIG {
+            SENT.fetch_or(sigmask(sig as usize), Ordering::Release);
+        }
         e(syscall::kill(pid as usize, sig as usize)) as c_int
     }
 
@@ -106,35 +345,103 @@ impl PalSignal for Sys {
     }
 
     fn sigaction(sig: c_int, act: Option<&sigaction>, oact: Option<&mut sigaction>) -> c_int {
-        let new_opt = act.map(|act| {
-            let m = act.sa_mask;
-            let sa_handler = unsafe { mem::transmute(act.sa_handler) };
-            syscall::SigAction {
-                sa_handler,
-                sa_mask: [m as u64, 0],
-                sa_flags: syscall::SigActionFlags::from_bits(act.sa_flags as usize)
-                    .expect("sigaction: invalid bit pattern"),
-            }
-        });
-        let mut old_opt = oact.as_ref().map(|_| syscall::SigAction::default());
-        let ret = e(syscall::sigaction(
-            sig as usize,
-            new_opt.as_ref(),
-            old_opt.as_mut(),
-        )) as c_int;
-        if let (Some(old), Some(oact)) = (old_opt, oact) {
-            oact.sa_handler = unsafe { mem::transmute(old.sa_handler) };
-            let m = old.sa_mask;
-            oact.sa_mask = m[0] as c_ulong;
-            oact.sa_flags = old.sa_flags.bits() as c_ulong;
+        if sig <= 0 || sig as usize > NSIG {
+            unsafe {
+                errno = EINVAL;
+            }
+            return -1;
         }
-        ret
+        let sig = sig as usize;
+
+        with_stand_ins(|stand_ins| {
+            // Handlers are called by the dispatcher, which the kernel calls in their place
+            let mut old_handler = HANDLERS[sig].load(Ordering::Acquire);
+            let old_siginfo = SIGINFO.load(Ordering::Relaxed);
+            let new_opt = act.map(|act| {
+                let handler = act.sa_handler.map_or(SIG_DFL, |handler| handler as usize);
+                old_handler = HANDLERS[sig].swap(handler, Ordering::AcqRel);
+                if act.sa_flags as usize & SA_SIGINFO != 0 {
+                    SIGINFO.fetch_or(sigmask(sig), Ordering::Relaxed);
+                } else {
+                    SIGINFO.fetch_and(!sigmask(sig), Ordering::Relaxed);
+                }
+
+                let m = act.sa_mask;
+                syscall::SigAction {
+                    sa_handler: match handler {
+                        SIG_DFL | SIG_IGN => unsafe { mem::transmute(handler) },
+                        _ => Some(dispatch),
+                    },
+                    sa_mask: [m as u64, 0],
+                    sa_flags: SigActionFlags::from_bits(act.sa_flags as usize)
+                        .expect("sigaction: invalid bit pattern"),
+                }
+            });
+
+            // While threads wait for the signal, the dispatcher stays in place, and the action
+            // is only set once they are done
+            let waiting = stand_ins.waiters[sig] > 0;
+            let kernel_new = match new_opt {
+                Some(new) if waiting => Some(syscall::SigAction {
+                    sa_handler: Some(dispatch),
+                    sa_flags: new.sa_flags - SigActionFlags::SA_RESETHAND,
+                    ..new
+                }),
+                new_opt => new_opt,
+            };
+            let mut old_opt = oact.as_ref().map(|_| syscall::SigAction::default());
+            let ret = e(syscall::sigaction(
+                sig,
+                kernel_new.as_ref(),
+                old_opt.as_mut(),
+            )) as c_int;
+            if ret < 0 {
+                if act.is_some() {
+                    HANDLERS[sig].store(old_handler, Ordering::Release);
+                    SIGINFO.store(old_siginfo, Ordering::Relaxed);
+                }
+                return ret;
+            }
+            if waiting {
+                if old_opt.is_some() {
+                    old_opt = stand_ins.saved[sig];
+                }
+                if new_opt.is_some() {
+                    stand_ins.saved[sig] = new_opt;
+                }
+            }
+
+            if let (Some(old), Some(oact)) = (old_opt, oact) {
+                oact.sa_handler = if is_dispatch(&old) {
+                    unsafe { mem::transmute(old_handler) }
+                } else {
+                    unsafe { mem::transmute(old.sa_handler) }
+                };
+                let m = old.sa_mask;
+                oact.sa_mask = m[0] as c_ulong;
+                oact.sa_flags = old.sa_flags.bits() as c_ulong;
+            }
+            ret
+        })
     }
 
     fn sigaltstack(ss: *const stack_t, old_ss: *mut stack_t) -> c_int {
         unimplemented!()
     }
 
+    fn sigpending(set: *mut sigset_t) -> c_int {
+        // The kernel has no way to ask which signals are pending. The ones this process sent
+        // itself are known until they reach the dispatcher, and are pending while blocked.
+        let mut blocked = 0;
+        if Self::sigprocmask(SIG_BLOCK, ptr::null(), &mut blocked) < 0 {
+            return -1;
+        }
+        unsafe {
+            *set = SENT.load(Ordering::Acquire) as sigset_t & blocked;
+        }
+        0
+    }
+
     fn sigprocmask(how: c_int, set: *const sigset_t, oset: *mut sigset_t) -> c_int {
         let new_opt = if set.is_null() {
             None
@@ -152,4 +459,147 @@ impl PalSignal for Sys {
         }
         ret
     }
+
+    fn sigqueue(pid: pid_t, sig: c_int, value: sigval) -> c_int {
+        // The value is passed on through memory, so it can only go to this process
+        if pid != Self::getpid() {
+            unsafe {
+                errno = ENOTSUP;
+            }
+            return -1;
+        }
+        queue(pid, sig, SI_QUEUE, value)
+    }
+
+    fn sigsuspend(set: *const sigset_t) -> c_int {
+        let seen = DELIVERED.load(Ordering::Relaxed);
+        let mut old = 0;
+        if Self::sigprocmask(SIG_SETMASK, set, &mut old) < 0 {
+            return -1;
+        }
+
+        // A signal that arrived since the count was read, even one let through by the new mask
+        // before the sleep, ends it
+        Self::sched_yield();
+        loop {
+            let delivered = DELIVERED.load(Ordering::Relaxed);
+            if delivered != seen {
+                break;
+            }
+            Self::futex(
+                &DELIVERED as *const AtomicI32 as *mut c_int,
+                FUTEX_WAIT,
+                delivered,
+                0,
+            );
+        }
+
+        Self::sigprocmask(SIG_SETMASK, &old, ptr::null_mut());
+        unsafe {
+            errno = EINTR;
+        }
+        -1
+    }
+
+    fn sigtimedwait(set: *const sigset_t, info: *mut siginfo_t, timeout: *const timespec) -> c_int {
+        let deadline = match unsafe { timeout.as_ref() } {
+            Some(timeout) => {
+                if timeout.tv_nsec < 0 || timeout.tv_nsec >= 1_000_000_000 {
+                    unsafe {
+                        errno = EINVAL;
+                    }
+                    return -1;
+                }
+                let mut now = timespec::default();
+                if Self::clock_gettime(CLOCK_MONOTONIC, &mut now) < 0 {
+                    return -1;
+                }
+                let mut deadline = timespec {
+                    tv_sec: now.tv_sec + timeout.tv_sec,
+                    tv_nsec: now.tv_nsec + timeout.tv_nsec,
+                };
+                if deadline.tv_nsec >= 1_000_000_000 {
+                    deadline.tv_sec += 1;
+                    deadline.tv_nsec -= 1_000_000_000;
+                }
+                Some(deadline)
+            }
+            None => None,
+        };
+
+        // Until one of them is taken, or a handler for another signal runs, or time is up
+        let (catch, err) = unsafe {
+            catch(*set, |seen| loop {
+                let delivered = DELIVERED.load(Ordering::Relaxed);
+                if CATCH.get().map_or(false, |catch| catch.taken.is_some()) {
+                    return 0;
+                }
+                if delivered != seen {
+                    return EINTR;
+                }
+                let relative = match deadline {
+                    Some(ref deadline) => match relative_timeout(CLOCK_MONOTONIC, deadline) {
+                        Ok(relative) => Some(relative),
+                        Err(_) => return EAGAIN,
+                    },
+                    None => None,
+                };
+                Self::futex(
+                    &DELIVERED as *const AtomicI32 as *mut c_int,
+                    FUTEX_WAIT,
+                    delivered,
+                    relative
+                        .as_ref()
+                        .map_or(0, |relative| relative as *const timespec as usize),
+                );
+            })
+        };
+
+        match catch.taken {
+            Some(taken) => {
+                if !info.is_null() {
+                    unsafe {
+                        *info = taken;
+                    }
+                }
+                taken.si_signo
+            }
+            None => {
+                unsafe {
+                    errno = err;
+                }
+                -1
+            }
+        }
+    }
+
+    fn timer_create(clock_id: clockid_t, evp: Option<&sigevent>, timerid: *mut timer_t) -> c_int {
+        unsafe { timer::create(clock_id, evp, timerid) }
+    }
+
+    fn timer_delete(timerid: timer_t) -> c_int {
+        unsafe { timer::delete(timerid) }
+    }
+
+    fn timer_getoverrun(timerid: timer_t) -> c_int {
+        unsafe { timer::getoverrun(timerid) }
+    }
+
+    fn timer_gettime(timerid: timer_t, value: *mut itimerspec) -> c_int {
+        unsafe { timer::gettime(timerid, value) }
+    }
+
+    fn timer_settime(
+        timerid: timer_t,
+        flags: c_int,
+        value: *const itimerspec,
+        ovalue: *mut itimerspec,
+    ) -> c_int {
+        unsafe { timer::settime(timerid, flags, value, ovalue) }
+    }
+
+    fn tkill(tid: pid_t, sig: c_int) -> c_int {
+        // Every thread is a context with its own ID
+        Self::kill(tid, sig)
+    }
 }
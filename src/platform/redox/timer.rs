@@ -0,0 +1,290 @@
+//! POSIX timers, which the kernel knows nothing of. Every timer that notifies anyone has a thread
+//! of its own, which sleeps until the timer expires.
+
+use alloc::boxed::Box;
+use core::{cell::UnsafeCell, cmp, ptr};
+
+use super::{
+    super::{errno, types::*, Pal, PalSignal},
+    signal, Sys,
+};
+use crate::{
+    header::{
+        errno::EINVAL,
+        pthread::PTHREAD_CREATE_DETACHED,
+        signal::{
+            sigevent, sigset_t, sigval, NSIG, SIGALRM, SIGEV_NONE, SIGEV_SIGNAL, SIGEV_THREAD,
+            SIG_BLOCK, SIG_SETMASK, SI_TIMER,
+        },
+        time::{itimerspec, timespec, CLOCK_MONOTONIC, CLOCK_REALTIME, TIMER_ABSTIME},
+    },
+    pthread::{self, RlctAttr},
+    sync::{pthread_mutex::RlctMutexAttr, Cond, RlctMutex},
+};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+pub struct Timer {
+    clock_id: clockid_t,
+    event: sigevent,
+    /// Where its signals go. Every thread is a context of its own, and the one that created the
+    /// timer is the closest there is to the process as a whole.
+    pid: pid_t,
+    lock: RlctMutex,
+    /// Signalled whenever the state changes, to wake up the thread
+    cond: Cond,
+    state: UnsafeCell<State>,
+}
+
+struct State {
+    /// When the timer expires next, in nanoseconds of its clock, unless it is disarmed
+    deadline: Option<i128>,
+    interval: i128,
+    overrun: c_int,
+    deleted: bool,
+}
+
+impl State {
+    /// Moves past the expirations up to `now`, returning how many were missed beyond the first.
+    fn expire(&mut self, now: i128) -> Option<c_int> {
+        let deadline = self.deadline.filter(|&deadline| deadline <= now)?;
+        if self.interval == 0 {
+            self.deadline = None;
+            return Some(0);
+        }
+        let missed = (now - deadline) / self.interval;
+        self.deadline = Some(deadline + (missed + 1) * self.interval);
+        Some(cmp::min(missed, c_int::max_value() as i128) as c_int)
+    }
+
+    fn get(&self, now: i128) -> itimerspec {
+        itimerspec {
+            // A timer that has just expired, but whose thread has yet to notice, is still armed
+            it_value: self.deadline.map_or_else(timespec::default, |deadline| {
+                to_timespec(cmp::max(deadline - now, 1))
+            }),
+            it_interval: to_timespec(self.interval),
+        }
+    }
+}
+
+impl Timer {
+    fn has_thread(&self) -> bool {
+        self.event.sigev_notify != SIGEV_NONE
+    }
+
+    /// Runs `f` with the state locked, after catching up on expirations no thread looks after.
+    fn with_state<T>(&self, f: impl FnOnce(&mut State, i128) -> T) -> Result<T, ()> {
+        let now = now(self.clock_id)?;
+        let _ = self.lock.lock();
+        let state = unsafe { &mut *self.state.get() };
+        if !self.has_thread() {
+            state.expire(now);
+        }
+        let result = f(state, now);
+        let _ = self.lock.unlock();
+        Ok(result)
+    }
+}
+
+fn to_nanos(ts: &timespec) -> i128 {
+    ts.tv_sec as i128 * NANOS_PER_SEC + ts.tv_nsec as i128
+}
+
+fn to_timespec(nanos: i128) -> timespec {
+    timespec {
+        tv_sec: (nanos / NANOS_PER_SEC) as time_t,
+        tv_nsec: (nanos % NANOS_PER_SEC) as c_long,
+    }
+}
+
+fn now(clock_id: clockid_t) -> Result<i128, ()> {
+    let mut ts = timespec::default();
+    if Sys::clock_gettime(clock_id, &mut ts) < 0 {
+        return Err(());
+    }
+    Ok(to_nanos(&ts))
+}
+
+unsafe fn get(timerid: timer_t) -> &'static Timer {
+    &*timerid.cast::<Timer>()
+}
+
+pub unsafe fn create(clock_id: clockid_t, evp: Option<&sigevent>, timerid: *mut timer_t) -> c_int {
+    let event = match evp {
+        Some(evp) => *evp,
+        None => {
+            let mut event: sigevent = core::mem::zeroed();
+            event.sigev_notify = SIGEV_SIGNAL;
+            event.sigev_signo = SIGALRM as c_int;
+            event
+        }
+    };
+    let valid = (clock_id == CLOCK_REALTIME || clock_id == CLOCK_MONOTONIC)
+        && match event.sigev_notify {
+            SIGEV_NONE => true,
+            SIGEV_SIGNAL => event.sigev_signo > 0 && event.sigev_signo as usize <= NSIG,
+            SIGEV_THREAD => event.sigev_notify_function.is_some(),
+            _ => false,
+        };
+    if !valid {
+        errno = EINVAL;
+        return -1;
+    }
+
+    let timer = Box::into_raw(Box::new(Timer {
+        clock_id,
+        event,
+        pid: Sys::getpid(),
+        lock: RlctMutex::new(&RlctMutexAttr::default()),
+        cond: Cond::new(clock_id),
+        state: UnsafeCell::new(State {
+            deadline: None,
+            interval: 0,
+            overrun: 0,
+            deleted: false,
+        }),
+    }));
+    if evp.is_none() {
+        (*timer).event.sigev_value = sigval {
+            sival_ptr: timer.cast(),
+        };
+    }
+
+    if (*timer).has_thread() {
+        let mut attrs = match event.sigev_notify {
+            SIGEV_THREAD => event
+                .sigev_notify_attributes
+                .cast::<RlctAttr>()
+                .as_ref()
+                .copied()
+                .unwrap_or_default(),
+            _ => RlctAttr::default(),
+        };
+        attrs.detachstate = PTHREAD_CREATE_DETACHED as c_uchar;
+
+        // Signals are for the other threads to handle
+        let all: sigset_t = !0;
+        let mut old: sigset_t = 0;
+        Sys::sigprocmask(SIG_BLOCK, &all, &mut old);
+        let result = pthread::create(Some(&attrs), timer_thread, timer.cast());
+        Sys::sigprocmask(SIG_SETMASK, &old, ptr::null_mut());
+
+        if let Err(pthread::Errno(err)) = result {
+            drop(Box::from_raw(timer));
+            errno = err;
+            return -1;
+        }
+    }
+
+    *timerid = timer.cast();
+    0
+}
+
+extern "C" fn timer_thread(arg: *mut c_void) -> *mut c_void {
+    let timer = unsafe { &*arg.cast::<Timer>() };
+    let state = unsafe { &mut *timer.state.get() };
+
+    let _ = timer.lock.lock();
+    while !state.deleted {
+        let now = match now(timer.clock_id) {
+            Ok(now) => now,
+            Err(()) => break,
+        };
+        if let Some(overrun) = state.expire(now) {
+            state.overrun = overrun;
+            let _ = timer.lock.unlock();
+            notify(timer);
+            let _ = timer.lock.lock();
+            continue;
+        }
+        let _ = match state.deadline {
+            Some(deadline) => timer.cond.timedwait(&timer.lock, &to_timespec(deadline)),
+            None => timer.cond.wait(&timer.lock),
+        };
+    }
+    let _ = timer.lock.unlock();
+
+    unsafe {
+        drop(Box::from_raw(arg.cast::<Timer>()));
+    }
+    ptr::null_mut()
+}
+
+fn notify(timer: &Timer) {
+    let event = &timer.event;
+    match event.sigev_notify {
+        // The thread can't tell whether the last signal is still pending, so overruns only count
+        // what it missed itself
+        SIGEV_SIGNAL => {
+            signal::queue(timer.pid, event.sigev_signo, SI_TIMER, event.sigev_value);
+        }
+        SIGEV_THREAD => {
+            if let Some(function) = event.sigev_notify_function {
+                function(event.sigev_value);
+            }
+        }
+        _ => (),
+    }
+}
+
+pub unsafe fn delete(timerid: timer_t) -> c_int {
+    let timer = get(timerid);
+    if !timer.has_thread() {
+        drop(Box::from_raw(timerid.cast::<Timer>()));
+        return 0;
+    }
+
+    // The thread frees the timer once it sees this, which it can only do after the unlock
+    let _ = timer.lock.lock();
+    (*timer.state.get()).deleted = true;
+    timer.cond.signal();
+    let _ = timer.lock.unlock();
+    0
+}
+
+pub unsafe fn getoverrun(timerid: timer_t) -> c_int {
+    get(timerid)
+        .with_state(|state, _| state.overrun)
+        .unwrap_or(-1)
+}
+
+pub unsafe fn gettime(timerid: timer_t, value: *mut itimerspec) -> c_int {
+    match get(timerid).with_state(|state, now| state.get(now)) {
+        Ok(current) => {
+            *value = current;
+            0
+        }
+        Err(()) => -1,
+    }
+}
+
+pub unsafe fn settime(
+    timerid: timer_t,
+    flags: c_int,
+    value: *const itimerspec,
+    ovalue: *mut itimerspec,
+) -> c_int {
+    let timer = get(timerid);
+    let result = timer.with_state(|state, now| {
+        if let Some(ovalue) = ovalue.as_mut() {
+            *ovalue = state.get(now);
+        }
+
+        let it_value = to_nanos(&(*value).it_value);
+        state.deadline = if it_value == 0 {
+            None
+        } else if flags & TIMER_ABSTIME == TIMER_ABSTIME {
+            Some(it_value)
+        } else {
+            Some(now + it_value)
+        };
+        state.interval = to_nanos(&(*value).it_interval);
+        state.overrun = 0;
+        timer.cond.signal();
+    });
+    match result {
+        Ok(()) => 0,
+        Err(()) => -1,
+    }
+}
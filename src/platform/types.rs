@@ -46,6 +46,8 @@ pub type c_ulong = u64;
 
 pub type wchar_t = i32;
 pub type wint_t = u32;
+pub type char16_t = u16;
+pub type char32_t = u32;
 
 pub type regoff_t = size_t;
 pub type off_t = c_long;
@@ -53,6 +55,7 @@ pub type mode_t = c_int;
 pub type time_t = c_long;
 pub type pid_t = c_int;
 pub type id_t = c_uint;
+pub type key_t = c_int;
 pub type gid_t = c_int;
 pub type uid_t = c_int;
 pub type dev_t = c_long;
@@ -0,0 +1,546 @@
+//! Relibc Threads, or RLCT: the thread implementation behind pthread.h.
+//!
+//! Every thread owns a `Tcb`, which holds its `Pthread` structure. A `pthread_t` is simply a
+//! pointer to that structure.
+
+use core::{
+    cell::Cell,
+    fmt, mem, ptr,
+    sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering},
+};
+
+use alloc::vec::Vec;
+
+use crate::{
+    header::{
+        errno::{EAGAIN, EDEADLK, EINVAL, ESRCH},
+        pthread::{tls, CleanupLinkedListEntry, PTHREAD_CANCELED},
+        sched::sched_param,
+        signal, sys_mman,
+        sys_types::{pthread_attr_t, pthread_t},
+    },
+    ld_so::tcb::{round_up, Tcb},
+    platform::{types::*, Pal, PalSignal, Sys},
+    sync::{Mutex, Waitval},
+};
+
+/// An error code, as returned by the pthread functions instead of being stored in errno.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Errno(pub c_int);
+
+#[derive(Clone, Copy)]
+pub struct Retval(pub *mut c_void);
+
+const DETACHED: usize = 1;
+const EXITED: usize = 2;
+const CANCEL_DISABLED: usize = 4;
+const CANCEL_ASYNC: usize = 8;
+const MAPPED_STACK: usize = 16;
+/// Set while the thread is blocked in a cancellation point, where deferred cancellation is acted
+/// on as soon as it is requested.
+const IN_CANCEL_POINT: usize = 32;
+
+/// Signal used to interrupt a thread that is being cancelled, the same one glibc uses.
+#[cfg(target_os = "linux")]
+const SIGRT_RLCT_CANCEL: c_int = 32;
+
+const FUTEX_WAIT: c_int = 0;
+
+pub const RLCT_DEFAULT_STACK_SIZE: usize = 1024 * 1024;
+
+pub struct Pthread {
+    pub(crate) waitval: Waitval<Retval>,
+    pub(crate) has_queued_cancelation: AtomicBool,
+    pub(crate) flags: AtomicUsize,
+
+    pub(crate) stack_base: *mut c_void,
+    pub(crate) stack_size: usize,
+
+    /// The kernel thread ID. It is set before `pthread_create` returns and, on Linux, cleared by
+    /// the kernel once the thread is gone (`CLONE_PARENT_SETTID` and `CLONE_CHILD_CLEARTID`).
+    pub(crate) os_tid: AtomicI32,
+}
+
+impl Pthread {
+    pub const fn new() -> Self {
+        Self {
+            waitval: Waitval::new(),
+            has_queued_cancelation: AtomicBool::new(false),
+            flags: AtomicUsize::new(0),
+            stack_base: ptr::null_mut(),
+            stack_size: 0,
+            os_tid: AtomicI32::new(0),
+        }
+    }
+
+    unsafe fn tcb(&self) -> *mut Tcb {
+        (self as *const Self as *mut u8).sub(offset_of!(Tcb, pthread)) as *mut Tcb
+    }
+}
+
+impl fmt::Debug for Pthread {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Pthread")
+            .field("flags", &self.flags.load(Ordering::Relaxed))
+            .field("stack_base", &self.stack_base)
+            .field("stack_size", &self.stack_size)
+            .field("os_tid", &self.os_tid.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+/// Threads which can no longer be joined, waiting for the kernel to be done with them so that
+/// their stack and TCB can be freed.
+static DEAD_THREADS: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+#[thread_local]
+static CANCEL_RECURSION: Cell<bool> = Cell::new(false);
+
+/// Set up the main thread. Called once, during startup.
+pub unsafe fn init() {
+    let thread = match current_thread() {
+        Some(thread) => thread,
+        None => return,
+    };
+    thread.os_tid.store(Sys::gettid(), Ordering::Relaxed);
+
+    // The handler only exits the thread when it is in a cancellation point or cancels
+    // asynchronously, and any other call it interrupts is restarted rather than failing with EINTR
+    #[cfg(target_os = "linux")]
+    {
+        let action = signal::sigaction {
+            sa_handler: Some(cancel_sighandler),
+            sa_flags: signal::SA_RESTART as c_ulong,
+            sa_restorer: None,
+            sa_mask: 0,
+        };
+        signal::sigaction(SIGRT_RLCT_CANCEL, &action, ptr::null_mut());
+    }
+}
+
+/// Called in the child after `fork`, where the only remaining thread has a new ID.
+pub unsafe fn reinit_after_fork() {
+    if let Some(thread) = current_thread() {
+        thread.os_tid.store(Sys::gettid(), Ordering::Relaxed);
+    }
+}
+
+pub fn current_thread() -> Option<&'static Pthread> {
+    unsafe { Tcb::current().map(|tcb| &tcb.pthread) }
+}
+
+/// The kernel ID of the current thread, which is what mutexes record as their owner.
+pub fn current_os_tid() -> pid_t {
+    match current_thread() {
+        Some(thread) => match thread.os_tid.load(Ordering::Relaxed) {
+            0 => Sys::gettid(),
+            tid => tid,
+        },
+        None => Sys::gettid(),
+    }
+}
+
+/// The attributes of a new thread, behind `pthread_attr_t`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct RlctAttr {
+    pub guardsize: size_t,
+    pub stacksize: size_t,
+    pub stack: size_t,
+    pub param: sched_param,
+    pub detachstate: c_uchar,
+    pub inheritsched: c_uchar,
+    pub schedpolicy: c_uchar,
+    pub scope: c_uchar,
+}
+
+impl Default for RlctAttr {
+    fn default() -> Self {
+        Self {
+            guardsize: Sys::getpagesize(),
+            stacksize: RLCT_DEFAULT_STACK_SIZE,
+            stack: 0,
+            param: sched_param { sched_priority: 0 },
+            detachstate: crate::header::pthread::PTHREAD_CREATE_JOINABLE as c_uchar,
+            inheritsched: crate::header::pthread::PTHREAD_INHERIT_SCHED as c_uchar,
+            schedpolicy: crate::header::sched::SCHED_OTHER as c_uchar,
+            scope: crate::header::pthread::PTHREAD_SCOPE_SYSTEM as c_uchar,
+        }
+    }
+}
+
+pub(crate) unsafe fn create(
+    attrs: Option<&RlctAttr>,
+    start_routine: extern "C" fn(arg: *mut c_void) -> *mut c_void,
+    arg: *mut c_void,
+) -> Result<pthread_t, Errno> {
+    reap_dead_threads();
+
+    let attrs = attrs.copied().unwrap_or_default();
+    let current_tcb = Tcb::current().ok_or(Errno(EAGAIN))?;
+    let page_size = Sys::getpagesize();
+
+    // Use the caller's stack if there is one, otherwise map a new one with a guard page below it
+    let (stack_base, stack_size, mut flags) = if attrs.stack != 0 {
+        (attrs.stack as *mut c_void, attrs.stacksize, 0)
+    } else {
+        let guard_size = round_up(attrs.guardsize, page_size);
+        let stack_size = round_up(attrs.stacksize, page_size) + guard_size;
+        let stack_base = sys_mman::mmap(
+            ptr::null_mut(),
+            stack_size,
+            sys_mman::PROT_READ | sys_mman::PROT_WRITE,
+            sys_mman::MAP_PRIVATE | sys_mman::MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+        if stack_base as isize == -1 {
+            return Err(Errno(EAGAIN));
+        }
+        if guard_size > 0 {
+            sys_mman::mprotect(stack_base, guard_size, sys_mman::PROT_NONE);
+        }
+        (stack_base, stack_size, MAPPED_STACK)
+    };
+    if attrs.detachstate as c_int == crate::header::pthread::PTHREAD_CREATE_DETACHED {
+        flags |= DETACHED;
+    }
+
+    let new_tcb = match Tcb::new(current_tcb.tls_len) {
+        Ok(tcb) => tcb,
+        Err(_) => {
+            if flags & MAPPED_STACK != 0 {
+                sys_mman::munmap(stack_base, stack_size);
+            }
+            return Err(Errno(EAGAIN));
+        }
+    };
+    new_tcb.masters_ptr = current_tcb.masters_ptr;
+    new_tcb.masters_len = current_tcb.masters_len;
+    new_tcb.linker_ptr = current_tcb.linker_ptr;
+    new_tcb.mspace = current_tcb.mspace;
+    if new_tcb.copy_masters().is_err() {
+        new_tcb.unmap();
+        if flags & MAPPED_STACK != 0 {
+            sys_mman::munmap(stack_base, stack_size);
+        }
+        return Err(Errno(EAGAIN));
+    }
+
+    new_tcb.pthread.stack_base = stack_base;
+    new_tcb.pthread.stack_size = stack_size;
+    new_tcb.pthread.flags.store(flags, Ordering::Relaxed);
+
+    // A stack given by the caller may end anywhere
+    let stack_end = (stack_base as usize + stack_size) & !15;
+    let mut stack = stack_end as *mut usize;
+    {
+        let mut push = |value: usize| {
+            stack = stack.offset(-1);
+            *stack = value;
+        };
+
+        //WARNING: Stack must be 128-bit aligned for SSE
+        push(0);
+        push(0);
+        push(0);
+        push(new_tcb as *mut Tcb as usize);
+        push(arg as usize);
+        push(start_routine as usize);
+
+        push(new_thread_shim as usize);
+    }
+
+    let os_tid_ptr = &new_tcb.pthread.os_tid as *const AtomicI32 as *mut pid_t;
+    let id = Sys::rlct_clone(stack, os_tid_ptr);
+    if id < 0 {
+        new_tcb.unmap();
+        if flags & MAPPED_STACK != 0 {
+            sys_mman::munmap(stack_base, stack_size);
+        }
+        return Err(Errno(EAGAIN));
+    }
+
+    if attrs.inheritsched as c_int == crate::header::pthread::PTHREAD_EXPLICIT_SCHED && id > 0 {
+        Sys::sched_setscheduler(id, attrs.schedpolicy as c_int, &attrs.param);
+    }
+
+    Ok(&new_tcb.pthread as *const Pthread as pthread_t)
+}
+
+/// The first code to run in a new thread, called by `rlct_clone` with the arguments it pushed
+unsafe extern "C" fn new_thread_shim(
+    start_routine: extern "C" fn(arg: *mut c_void) -> *mut c_void,
+    arg: *mut c_void,
+    tcb: *mut Tcb,
+) -> ! {
+    let tcb = &mut *tcb;
+    tcb.activate();
+
+    let retval = start_routine(arg);
+    exit_current_thread(Retval(retval))
+}
+
+pub(crate) unsafe fn join(thread: &Pthread) -> Result<Retval, Errno> {
+    // Joining is a cancellation point
+    testcancel();
+
+    if let Some(current) = current_thread() {
+        if ptr::eq(current, thread) {
+            return Err(Errno(EDEADLK));
+        }
+    }
+    if thread.flags.load(Ordering::Acquire) & DETACHED != 0 {
+        return Err(Errno(EINVAL));
+    }
+
+    let retval = *thread.waitval.wait();
+
+    // The thread is done with its Pthread structure, but may still be running on its stack
+    wait_for_os_exit(thread);
+    dealloc_thread(thread);
+
+    Ok(retval)
+}
+
+pub(crate) unsafe fn detach(thread: &Pthread) -> Result<(), Errno> {
+    let old = thread.flags.fetch_or(DETACHED, Ordering::AcqRel);
+    if old & DETACHED != 0 {
+        return Err(Errno(EINVAL));
+    }
+    if old & EXITED != 0 {
+        // The thread has already returned, and nobody is going to join it now
+        queue_for_reaping(thread);
+    }
+    Ok(())
+}
+
+pub unsafe fn exit_current_thread(retval: Retval) -> ! {
+    let this = current_thread().expect("failed to obtain current thread when exiting");
+
+    crate::header::pthread::run_cleanup_handlers();
+    tls::run_all_destructors();
+
+    this.waitval.post(retval);
+    if this.flags.fetch_or(EXITED, Ordering::AcqRel) & DETACHED != 0 {
+        queue_for_reaping(this);
+    }
+
+    Sys::exit_thread(&this.os_tid as *const AtomicI32 as *mut pid_t)
+}
+
+pub(crate) unsafe fn cancel(thread: &Pthread) -> Result<(), Errno> {
+    thread.has_queued_cancelation.store(true, Ordering::SeqCst);
+
+    // Interrupt the thread if it would act on the request right away. Otherwise it notices the
+    // request at its next cancellation point, which checks for it after setting IN_CANCEL_POINT.
+    #[cfg(target_os = "linux")]
+    {
+        let flags = thread.flags.load(Ordering::SeqCst);
+        let os_tid = thread.os_tid.load(Ordering::Relaxed);
+        if flags & CANCEL_DISABLED == 0
+            && flags & (CANCEL_ASYNC | IN_CANCEL_POINT) != 0
+            && os_tid != 0
+            && Sys::tkill(os_tid, SIGRT_RLCT_CANCEL) < 0
+        {
+            return Err(Errno(ESRCH));
+        }
+    }
+    // Redox has no signal to spare, so cancellation is only noticed at cancellation points there
+
+    Ok(())
+}
+
+/// A cancellation point: exit if cancellation is enabled and was requested.
+pub fn testcancel() {
+    let this = match current_thread() {
+        Some(this) => this,
+        None => return,
+    };
+
+    if this.flags.load(Ordering::SeqCst) & CANCEL_DISABLED == 0
+        && this.has_queued_cancelation.load(Ordering::SeqCst)
+        && !CANCEL_RECURSION.replace(true)
+    {
+        unsafe { exit_current_thread(Retval(PTHREAD_CANCELED)) }
+    }
+}
+
+/// Run `f`, a call that can block, as a cancellation point. A request made before the call is
+/// acted on right away, and one made while it is blocked interrupts it, instead of making it
+/// fail with EINTR, and exits the thread.
+pub fn cancellation_point<T>(f: impl FnOnce() -> T) -> T {
+    let this = match current_thread() {
+        Some(this) => this,
+        None => return f(),
+    };
+
+    let outer = this.flags.fetch_or(IN_CANCEL_POINT, Ordering::SeqCst) & IN_CANCEL_POINT;
+    testcancel();
+    let ret = f();
+    if outer == 0 {
+        this.flags.fetch_and(!IN_CANCEL_POINT, Ordering::SeqCst);
+    }
+    ret
+}
+
+/// Like `cancellation_point`, but run `cleanup` if the thread is cancelled in `f`, to release what
+/// the caller would otherwise release after `f` returns.
+pub fn cancellation_point_cleanup<T, C: FnOnce()>(f: impl FnOnce() -> T, cleanup: C) -> T {
+    extern "C" fn run_cleanup<C: FnOnce()>(arg: *mut c_void) {
+        if let Some(cleanup) = unsafe { (*arg.cast::<Option<C>>()).take() } {
+            cleanup();
+        }
+    }
+
+    let mut cleanup = Some(cleanup);
+    let mut entry = CleanupLinkedListEntry::new(
+        run_cleanup::<C>,
+        &mut cleanup as *mut Option<C> as *mut c_void,
+    );
+    unsafe {
+        let entry_ptr = &mut entry as *mut CleanupLinkedListEntry as *mut c_void;
+        crate::header::pthread::__relibc_internal_pthread_cleanup_push(entry_ptr);
+        let ret = cancellation_point(f);
+        crate::header::pthread::__relibc_internal_pthread_cleanup_pop(0);
+        ret
+    }
+}
+
+#[cfg(target_os = "linux")]
+extern "C" fn cancel_sighandler(_: c_int) {
+    let this = match current_thread() {
+        Some(this) => this,
+        None => return,
+    };
+    if this.flags.load(Ordering::SeqCst) & (CANCEL_ASYNC | IN_CANCEL_POINT) != 0 {
+        testcancel();
+    }
+}
+
+/// Enable or disable cancellation for the current thread, returning whether it was enabled.
+pub fn set_cancel_enabled(enabled: bool) -> bool {
+    let this = match current_thread() {
+        Some(this) => this,
+        None => return true,
+    };
+    let old = if enabled {
+        this.flags.fetch_and(!CANCEL_DISABLED, Ordering::SeqCst)
+    } else {
+        this.flags.fetch_or(CANCEL_DISABLED, Ordering::SeqCst)
+    };
+    if enabled && this.flags.load(Ordering::Relaxed) & CANCEL_ASYNC != 0 {
+        testcancel();
+    }
+    old & CANCEL_DISABLED == 0
+}
+
+/// Switch the current thread between asynchronous and deferred cancellation, returning whether
+/// it was asynchronous.
+pub fn set_cancel_async(asynchronous: bool) -> bool {
+    let this = match current_thread() {
+        Some(this) => this,
+        None => return false,
+    };
+    let old = if asynchronous {
+        this.flags.fetch_or(CANCEL_ASYNC, Ordering::SeqCst)
+    } else {
+        this.flags.fetch_and(!CANCEL_ASYNC, Ordering::SeqCst)
+    };
+    if asynchronous {
+        testcancel();
+    }
+    old & CANCEL_ASYNC != 0
+}
+
+pub(crate) unsafe fn get_sched_param(thread: &Pthread) -> Result<(c_int, sched_param), Errno> {
+    let os_tid = thread.os_tid.load(Ordering::Relaxed);
+    let policy = Sys::sched_getscheduler(os_tid);
+    if policy < 0 {
+        return Err(Errno(crate::platform::errno));
+    }
+    let mut param = sched_param { sched_priority: 0 };
+    if Sys::sched_getparam(os_tid, &mut param) < 0 {
+        return Err(Errno(crate::platform::errno));
+    }
+    Ok((policy, param))
+}
+
+pub(crate) unsafe fn set_sched_param(
+    thread: &Pthread,
+    policy: c_int,
+    param: &sched_param,
+) -> Result<(), Errno> {
+    if Sys::sched_setscheduler(thread.os_tid.load(Ordering::Relaxed), policy, param) < 0 {
+        return Err(Errno(crate::platform::errno));
+    }
+    Ok(())
+}
+
+pub(crate) unsafe fn set_sched_priority(thread: &Pthread, prio: c_int) -> Result<(), Errno> {
+    let param = sched_param {
+        sched_priority: prio,
+    };
+    if Sys::sched_setparam(thread.os_tid.load(Ordering::Relaxed), &param) < 0 {
+        return Err(Errno(crate::platform::errno));
+    }
+    Ok(())
+}
+
+pub(crate) unsafe fn kill(thread: &Pthread, sig: c_int) -> Result<(), Errno> {
+    if Sys::tkill(thread.os_tid.load(Ordering::Relaxed), sig) < 0 {
+        return Err(Errno(crate::platform::errno));
+    }
+    Ok(())
+}
+
+/// Block until the kernel has stopped running `thread`.
+unsafe fn wait_for_os_exit(thread: &Pthread) {
+    loop {
+        let os_tid = thread.os_tid.load(Ordering::Acquire);
+        if os_tid == 0 {
+            break;
+        }
+        Sys::futex(
+            &thread.os_tid as *const AtomicI32 as *mut c_int,
+            FUTEX_WAIT,
+            os_tid,
+            0,
+        );
+    }
+}
+
+/// Whether `thread` can be deallocated without waiting.
+fn has_os_exited(thread: &Pthread) -> bool {
+    thread.os_tid.load(Ordering::Acquire) == 0
+}
+
+unsafe fn queue_for_reaping(thread: &Pthread) {
+    DEAD_THREADS.lock().push(thread as *const Pthread as usize);
+}
+
+unsafe fn reap_dead_threads() {
+    DEAD_THREADS.lock().retain(|&thread| {
+        let thread = &*(thread as *const Pthread);
+        if has_os_exited(thread) {
+            dealloc_thread(thread);
+            false
+        } else {
+            true
+        }
+    });
+}
+
+unsafe fn dealloc_thread(thread: &Pthread) {
+    let flags = thread.flags.load(Ordering::Relaxed);
+    let (stack_base, stack_size) = (thread.stack_base, thread.stack_size);
+    let tcb = &mut *thread.tcb();
+
+    tcb.unmap();
+    if flags & MAPPED_STACK != 0 {
+        sys_mman::munmap(stack_base, stack_size);
+    }
+}
+
+// Make sure the opaque C types are big enough for what they hide
+const _: () = assert!(mem::size_of::<RlctAttr>() <= mem::size_of::<pthread_attr_t>());
@@ -5,7 +5,7 @@ use crate::{
     header::{libgen, stdio, stdlib},
     ld_so,
     platform::{self, get_auxvs, new_mspace, types::*, Pal, Sys},
-    ALLOCATOR,
+    pthread, ALLOCATOR,
 };
 
 #[repr(C)]
@@ -111,11 +111,8 @@ extern "C" fn init_array() {
         }
     }
 
-    extern "C" {
-        fn pthread_init();
-    }
     unsafe {
-        pthread_init();
+        pthread::init();
         init_complete = true
     }
 }
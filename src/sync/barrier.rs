@@ -0,0 +1,44 @@
+use super::AtomicLock;
+use crate::platform::types::*;
+use core::sync::atomic::Ordering;
+
+pub enum WaitResult {
+    Waited,
+    NotifiedAll,
+}
+
+/// A reusable barrier. Threads count themselves in, and the last one to arrive starts a new
+/// generation, which is what everyone else is sleeping on.
+pub struct Barrier {
+    count: AtomicLock,
+    generation: AtomicLock,
+    original_count: c_uint,
+}
+
+impl Barrier {
+    pub const fn new(count: c_uint) -> Self {
+        Self {
+            count: AtomicLock::new(0),
+            generation: AtomicLock::new(0),
+            original_count: count,
+        }
+    }
+
+    pub fn wait(&self) -> WaitResult {
+        let generation = self.generation.load(Ordering::Acquire);
+
+        if self.count.fetch_add(1, Ordering::AcqRel) as c_uint + 1 == self.original_count {
+            // Everybody is here. Nobody can arrive for the next generation before it starts, so
+            // the counter can be reset without racing.
+            self.count.store(0, Ordering::Relaxed);
+            self.generation.fetch_add(1, Ordering::Release);
+            self.generation.notify_all();
+            return WaitResult::NotifiedAll;
+        }
+
+        while self.generation.load(Ordering::Acquire) == generation {
+            self.generation.wait_if(generation, None);
+        }
+        WaitResult::Waited
+    }
+}
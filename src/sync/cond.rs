@@ -0,0 +1,84 @@
+use super::{relative_timeout, AtomicLock, RlctMutex};
+use crate::{
+    header::{
+        errno::ETIMEDOUT,
+        time::{timespec, CLOCK_MONOTONIC, CLOCK_REALTIME},
+    },
+    platform::types::*,
+    pthread::Errno,
+};
+use core::sync::atomic::Ordering;
+
+/// A condition variable. Waiters sleep on a sequence number, which every signal and broadcast
+/// increments, so that a wakeup between unlocking the mutex and sleeping is never lost.
+pub struct Cond {
+    seq: AtomicLock,
+    /// Anything but `CLOCK_MONOTONIC` means `CLOCK_REALTIME`, so that a zeroed condition variable
+    /// is valid on every platform.
+    clock: clockid_t,
+}
+
+impl Cond {
+    pub const fn new(clock: clockid_t) -> Self {
+        Self {
+            seq: AtomicLock::new(0),
+            clock,
+        }
+    }
+
+    pub fn broadcast(&self) {
+        self.seq.fetch_add(1, Ordering::SeqCst);
+        self.seq.notify_all();
+    }
+
+    pub fn signal(&self) {
+        self.seq.fetch_add(1, Ordering::SeqCst);
+        self.seq.notify_one();
+    }
+
+    pub fn wait(&self, mutex: &RlctMutex) -> Result<(), Errno> {
+        self.wait_inner(mutex, None)
+    }
+
+    /// Like `wait`, but gives up once the absolute time `deadline` has passed, measured against
+    /// the clock the condition variable was created with.
+    pub fn timedwait(&self, mutex: &RlctMutex, deadline: &timespec) -> Result<(), Errno> {
+        self.wait_inner(mutex, Some(deadline))
+    }
+
+    fn clock(&self) -> clockid_t {
+        if self.clock == CLOCK_MONOTONIC {
+            CLOCK_MONOTONIC
+        } else {
+            CLOCK_REALTIME
+        }
+    }
+
+    fn wait_inner(&self, mutex: &RlctMutex, deadline: Option<&timespec>) -> Result<(), Errno> {
+        let seq = self.seq.load(Ordering::SeqCst);
+
+        mutex.unlock()?;
+
+        let result = match deadline {
+            Some(deadline) => match relative_timeout(self.clock(), deadline) {
+                Ok(timeout) => {
+                    if self.seq.wait_if(seq, Some(&timeout)) == -ETIMEDOUT {
+                        Err(Errno(ETIMEDOUT))
+                    } else {
+                        Ok(())
+                    }
+                }
+                Err(err) => Err(err),
+            },
+            None => {
+                self.seq.wait_if(seq, None);
+                Ok(())
+            }
+        };
+
+        // The mutex must be held again whatever the outcome, including a timeout.
+        mutex.lock()?;
+
+        result
+    }
+}
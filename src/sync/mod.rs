@@ -1,15 +1,31 @@
+pub mod barrier;
+pub mod cond;
 pub mod mutex;
 pub mod once;
+pub mod pthread_mutex;
+pub mod rwlock;
 pub mod semaphore;
+pub mod waitval;
 
 pub use self::{
+    barrier::Barrier,
+    cond::Cond,
     mutex::{Mutex, MutexGuard},
     once::Once,
+    pthread_mutex::RlctMutex,
+    rwlock::Rwlock,
     semaphore::Semaphore,
+    waitval::Waitval,
 };
 
-use crate::header::time::timespec;
-use crate::platform::{types::*, Pal, Sys};
+use crate::header::{
+    errno::ETIMEDOUT,
+    time::{clock_gettime, timespec},
+};
+use crate::{
+    platform::{types::*, Pal, Sys},
+    pthread::Errno,
+};
 use core::{
     cell::UnsafeCell,
     ops::Deref,
@@ -48,13 +64,15 @@ impl AtomicLock {
             0
         );
     }
-    pub fn wait_if(&self, value: c_int, timeout_opt: Option<&timespec>) {
+    /// Sleep as long as the value is `value`, for at most `timeout_opt`. Returns the raw futex
+    /// result, which is `-ETIMEDOUT` if the timeout expired.
+    pub fn wait_if(&self, value: c_int, timeout_opt: Option<&timespec>) -> c_int {
         Sys::futex(
             unsafe { &mut *self.atomic.get() }.get_mut(),
             FUTEX_WAIT,
             value,
             timeout_opt.map_or(0, |timeout| timeout as *const timespec as usize)
-        );
+        )
     }
 
     /// A general way to efficiently wait for what might be a long time, using two closures:
@@ -121,3 +139,25 @@ impl Deref for AtomicLock {
         unsafe { &*self.atomic.get() }
     }
 }
+
+/// Convert the absolute time `deadline`, measured against `clock`, into the relative timeout that
+/// the futex call expects. Fails with `ETIMEDOUT` if the deadline has already passed.
+pub(crate) fn relative_timeout(clock: clockid_t, deadline: &timespec) -> Result<timespec, Errno> {
+    let mut now = timespec::default();
+    if clock_gettime(clock, &mut now) < 0 {
+        return Err(Errno(unsafe { crate::platform::errno }));
+    }
+
+    let mut tv_sec = deadline.tv_sec - now.tv_sec;
+    let mut tv_nsec = deadline.tv_nsec - now.tv_nsec;
+    if tv_nsec < 0 {
+        tv_sec -= 1;
+        tv_nsec += 1_000_000_000;
+    }
+
+    if tv_sec < 0 || (tv_sec == 0 && tv_nsec == 0) {
+        Err(Errno(ETIMEDOUT))
+    } else {
+        Ok(timespec { tv_sec, tv_nsec })
+    }
+}
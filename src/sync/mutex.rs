@@ -1,9 +1,9 @@
-use super::{AtomicLock, AttemptStatus};
+use super::AtomicLock;
 use crate::platform::types::*;
 use core::{
     cell::UnsafeCell,
     ops::{Deref, DerefMut},
-    sync::atomic::Ordering::SeqCst,
+    sync::atomic::{self, Ordering::SeqCst},
 };
 
 const UNLOCKED: c_int = 0;
@@ -50,25 +50,19 @@ impl<T> Mutex<T> {
     /// your responsibility to unlock it after usage. Mostly useful for FFI:
     /// Prefer normal .lock() where possible.
     pub unsafe fn manual_lock(&self) -> &mut T {
-        self.lock.wait_until(
-            |lock| {
-                lock.compare_exchange_weak(UNLOCKED, LOCKED, SeqCst, SeqCst)
-                    .map(|_| AttemptStatus::Desired)
-                    .unwrap_or_else(|e| match e {
-                        WAITING => AttemptStatus::Waiting,
-                        _ => AttemptStatus::Other,
-                    })
-            },
-            |lock| match lock
-                .compare_exchange_weak(LOCKED, WAITING, SeqCst, SeqCst)
-                .unwrap_or_else(|e| e)
-            {
-                UNLOCKED => AttemptStatus::Desired,
-                WAITING => AttemptStatus::Waiting,
-                _ => AttemptStatus::Other,
-            },
-            WAITING,
-        );
+        // First, try spinning for really short durations
+        for _ in 0..999 {
+            atomic::spin_loop_hint();
+            if self.manual_try_lock().is_ok() {
+                return &mut *self.content.get();
+            }
+        }
+
+        // Once we may have slept, others may be asleep behind us too, so the lock is taken as
+        // WAITING for the unlock to wake the next one
+        while self.lock.swap(WAITING, SeqCst) != UNLOCKED {
+            self.lock.wait_if(WAITING, None);
+        }
         &mut *self.content.get()
     }
     /// Unlock the mutex, if it's locked.
@@ -0,0 +1,199 @@
+use super::{relative_timeout, AtomicLock};
+use crate::{
+    header::{
+        errno::{EAGAIN, EBUSY, EDEADLK, EINVAL, EPERM, ETIMEDOUT},
+        pthread::{
+            PTHREAD_MUTEX_DEFAULT, PTHREAD_MUTEX_ERRORCHECK, PTHREAD_MUTEX_RECURSIVE,
+            PTHREAD_MUTEX_ROBUST, PTHREAD_MUTEX_STALLED, PTHREAD_PRIO_NONE,
+            PTHREAD_PROCESS_PRIVATE,
+        },
+        time::{timespec, CLOCK_REALTIME},
+    },
+    platform::types::*,
+    pthread::{self, Errno},
+};
+use core::sync::atomic::{AtomicU32 as AtomicUint, Ordering};
+
+const UNLOCKED: c_int = 0;
+const WAITING_BIT: c_int = 1 << 31;
+const OWNER_MASK: c_int = !WAITING_BIT;
+
+const SPIN_COUNT: usize = 100;
+
+/// The mutex behind `pthread_mutex_t`. The lock word holds the thread ID of the owner, so that
+/// the error checking and recursive kinds need no additional state to find it.
+pub struct RlctMutex {
+    inner: AtomicLock,
+    recursive_count: AtomicUint,
+    ty: u8,
+    robust: bool,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct RlctMutexAttr {
+    pub prioceiling: c_int,
+    pub protocol: c_int,
+    pub pshared: c_int,
+    pub robust: c_int,
+    pub ty: c_int,
+}
+
+impl Default for RlctMutexAttr {
+    fn default() -> Self {
+        Self {
+            prioceiling: 0,
+            protocol: PTHREAD_PRIO_NONE,
+            pshared: PTHREAD_PROCESS_PRIVATE,
+            robust: PTHREAD_MUTEX_STALLED,
+            ty: PTHREAD_MUTEX_DEFAULT,
+        }
+    }
+}
+
+impl RlctMutex {
+    pub(crate) fn new(attr: &RlctMutexAttr) -> Self {
+        Self {
+            inner: AtomicLock::new(UNLOCKED),
+            recursive_count: AtomicUint::new(0),
+            ty: attr.ty as u8,
+            robust: attr.robust == PTHREAD_MUTEX_ROBUST,
+        }
+    }
+
+    pub fn lock(&self) -> Result<(), Errno> {
+        self.lock_inner(None)
+    }
+    pub fn lock_with_timeout(&self, deadline: &timespec) -> Result<(), Errno> {
+        self.lock_inner(Some(deadline))
+    }
+
+    pub fn try_lock(&self) -> Result<(), Errno> {
+        let this_thread = pthread::current_os_tid();
+
+        match self.inner.compare_exchange(
+            UNLOCKED,
+            this_thread,
+            Ordering::Acquire,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => {
+                if self.ty == PTHREAD_MUTEX_RECURSIVE as u8 {
+                    self.recursive_count.store(1, Ordering::Relaxed);
+                }
+                Ok(())
+            }
+            Err(state) if state & OWNER_MASK == this_thread => self.relock(),
+            Err(_) => Err(Errno(EBUSY)),
+        }
+    }
+
+    /// Handle an attempt by the owner to lock the mutex again.
+    fn relock(&self) -> Result<(), Errno> {
+        if self.ty == PTHREAD_MUTEX_RECURSIVE as u8 {
+            let count = self.recursive_count.load(Ordering::Relaxed);
+            if count == u32::max_value() {
+                return Err(Errno(EAGAIN));
+            }
+            self.recursive_count.store(count + 1, Ordering::Relaxed);
+            Ok(())
+        } else if self.ty == PTHREAD_MUTEX_ERRORCHECK as u8 {
+            Err(Errno(EDEADLK))
+        } else {
+            Err(Errno(EBUSY))
+        }
+    }
+
+    fn lock_inner(&self, deadline: Option<&timespec>) -> Result<(), Errno> {
+        let this_thread = pthread::current_os_tid();
+
+        // Spin for a short while first, since most critical sections are short.
+        // Relocking a normal mutex ends up sleeping below, and deadlocks as POSIX says it should.
+        for _ in 0..SPIN_COUNT {
+            match self.try_lock() {
+                Err(Errno(EBUSY)) => core::hint::spin_loop(),
+                other => return other,
+            }
+        }
+
+        // Once we had to sleep, keep the waiting bit when acquiring, since there may be more
+        // sleepers that the next unlock has to wake.
+        loop {
+            let state = self.inner.load(Ordering::Relaxed);
+
+            if state == UNLOCKED {
+                match self.inner.compare_exchange_weak(
+                    UNLOCKED,
+                    this_thread | WAITING_BIT,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        if self.ty == PTHREAD_MUTEX_RECURSIVE as u8 {
+                            self.recursive_count.store(1, Ordering::Relaxed);
+                        }
+                        return Ok(());
+                    }
+                    Err(_) => continue,
+                }
+            }
+
+            if state & WAITING_BIT == 0
+                && self
+                    .inner
+                    .compare_exchange_weak(
+                        state,
+                        state | WAITING_BIT,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_err()
+            {
+                continue;
+            }
+
+            match deadline {
+                Some(deadline) => {
+                    let timeout = relative_timeout(CLOCK_REALTIME, deadline)?;
+                    if self.inner.wait_if(state | WAITING_BIT, Some(&timeout)) == -ETIMEDOUT {
+                        return Err(Errno(ETIMEDOUT));
+                    }
+                }
+                None => {
+                    self.inner.wait_if(state | WAITING_BIT, None);
+                }
+            }
+        }
+    }
+
+    pub fn unlock(&self) -> Result<(), Errno> {
+        if self.ty != 0 || self.robust {
+            let state = self.inner.load(Ordering::Relaxed);
+            if state & OWNER_MASK != pthread::current_os_tid() {
+                return Err(Errno(EPERM));
+            }
+        }
+
+        if self.ty == PTHREAD_MUTEX_RECURSIVE as u8 {
+            let count = self.recursive_count.load(Ordering::Relaxed) - 1;
+            self.recursive_count.store(count, Ordering::Relaxed);
+            if count > 0 {
+                return Ok(());
+            }
+        }
+
+        if self.inner.swap(UNLOCKED, Ordering::Release) & WAITING_BIT != 0 {
+            self.inner.notify_one();
+        }
+        Ok(())
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.inner.load(Ordering::Relaxed) != UNLOCKED
+    }
+
+    pub fn make_consistent(&self) -> Result<(), Errno> {
+        //TODO: Robust mutexes need the kernel's robust list to notice dead owners, until then a
+        //robust mutex is never inconsistent.
+        Err(Errno(EINVAL))
+    }
+}
@@ -0,0 +1,125 @@
+use super::{relative_timeout, AtomicLock};
+use crate::{
+    header::{
+        errno::{EAGAIN, EBUSY, ETIMEDOUT},
+        time::{timespec, CLOCK_REALTIME},
+    },
+    platform::types::*,
+    pthread::Errno,
+};
+use core::sync::atomic::Ordering;
+
+const WAITING_BIT: c_int = 1 << 31;
+const EXCLUSIVE: c_int = 1 << 30;
+const READERS_MASK: c_int = EXCLUSIVE - 1;
+
+/// A readers-writer lock, packed into a single futex word: the number of readers, a bit for an
+/// exclusive owner, and a bit telling unlockers that somebody is asleep.
+pub struct Rwlock {
+    state: AtomicLock,
+}
+
+impl Rwlock {
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicLock::new(0),
+        }
+    }
+
+    pub fn acquire_read_lock(&self, deadline: Option<&timespec>) -> Result<(), Errno> {
+        self.acquire(deadline, Self::try_acquire_read_lock)
+    }
+    pub fn acquire_write_lock(&self, deadline: Option<&timespec>) -> Result<(), Errno> {
+        self.acquire(deadline, Self::try_acquire_write_lock)
+    }
+
+    pub fn try_acquire_read_lock(&self) -> Result<(), Errno> {
+        let mut state = self.state.load(Ordering::Relaxed);
+        loop {
+            if state & EXCLUSIVE != 0 {
+                return Err(Errno(EBUSY));
+            }
+            if state & READERS_MASK == READERS_MASK {
+                return Err(Errno(EAGAIN));
+            }
+            match self.state.compare_exchange_weak(
+                state,
+                state + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Ok(()),
+                Err(actual) => state = actual,
+            }
+        }
+    }
+    pub fn try_acquire_write_lock(&self) -> Result<(), Errno> {
+        let mut state = self.state.load(Ordering::Relaxed);
+        loop {
+            if state & (EXCLUSIVE | READERS_MASK) != 0 {
+                return Err(Errno(EBUSY));
+            }
+            match self.state.compare_exchange_weak(
+                state,
+                state | EXCLUSIVE,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Ok(()),
+                Err(actual) => state = actual,
+            }
+        }
+    }
+
+    fn acquire(
+        &self,
+        deadline: Option<&timespec>,
+        try_acquire: fn(&Self) -> Result<(), Errno>,
+    ) -> Result<(), Errno> {
+        loop {
+            match try_acquire(self) {
+                Err(Errno(EBUSY)) => (),
+                other => return other,
+            }
+
+            // Tell the current owner(s) to wake us up, then sleep unless the lock changed hands
+            // in the meantime.
+            let state = self.state.fetch_or(WAITING_BIT, Ordering::Relaxed) | WAITING_BIT;
+            if state & (EXCLUSIVE | READERS_MASK) == 0 {
+                continue;
+            }
+
+            match deadline {
+                Some(deadline) => {
+                    let timeout = relative_timeout(CLOCK_REALTIME, deadline)?;
+                    if self.state.wait_if(state, Some(&timeout)) == -ETIMEDOUT {
+                        return Err(Errno(ETIMEDOUT));
+                    }
+                }
+                None => {
+                    self.state.wait_if(state, None);
+                }
+            }
+        }
+    }
+
+    pub fn unlock(&self) {
+        let state = self.state.load(Ordering::Relaxed);
+
+        let old = if state & EXCLUSIVE != 0 {
+            self.state
+                .fetch_and(!(EXCLUSIVE | WAITING_BIT), Ordering::Release)
+        } else {
+            let old = self.state.fetch_sub(1, Ordering::Release);
+            if (old - 1) & READERS_MASK != 0 {
+                // Other readers still hold the lock, and will wake the waiters later.
+                return;
+            }
+            self.state.fetch_and(!WAITING_BIT, Ordering::Relaxed)
+        };
+
+        if old & WAITING_BIT != 0 {
+            self.state.notify_all();
+        }
+    }
+}
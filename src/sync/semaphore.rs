@@ -1,11 +1,19 @@
 // From https://www.remlab.net/op/futex-misc.shtml
 //TODO: improve implementation
 
-use super::AtomicLock;
-use crate::header::time::timespec;
-use crate::platform::{types::*, Pal, Sys};
+use super::{relative_timeout, AtomicLock};
+use crate::{
+    header::{
+        errno::{EAGAIN, EINTR, EOVERFLOW, ETIMEDOUT},
+        time::{timespec, CLOCK_REALTIME},
+    },
+    platform::types::*,
+    pthread::{self, Errno},
+};
 use core::sync::atomic::Ordering;
 
+/// A counting semaphore. It is nothing but its value, so that it works just as well in memory
+/// shared between processes.
 pub struct Semaphore {
     lock: AtomicLock,
 }
@@ -17,31 +25,85 @@ impl Semaphore {
         }
     }
 
-    pub fn post(&self) {
-        self.lock.fetch_add(1, Ordering::Relaxed);
+    /// Increments the value and wakes up a waiter, unless the value would overflow.
+    pub fn post(&self) -> Result<(), Errno> {
+        let mut value = self.lock.load(Ordering::Relaxed);
+        loop {
+            if value == c_int::max_value() {
+                return Err(Errno(EOVERFLOW));
+            }
+            match self.lock.compare_exchange_weak(
+                value,
+                value + 1,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(err) => value = err,
+            }
+        }
         self.lock.notify_one();
+        Ok(())
     }
 
-    pub fn wait(&self, timeout_opt: Option<&timespec>) {
-        let mut value = 1;
-
+    /// Decrements the value if that can be done without waiting, and fails with `EAGAIN`
+    /// otherwise.
+    pub fn try_wait(&self) -> Result<(), Errno> {
+        let mut value = self.lock.load(Ordering::Relaxed);
         loop {
+            if value <= 0 {
+                return Err(Errno(EAGAIN));
+            }
             match self.lock.compare_exchange_weak(
                 value,
                 value - 1,
                 Ordering::Acquire,
                 Ordering::Relaxed,
             ) {
-                Ok(ok) => return,
-                Err(err) => {
-                    value = err;
-                }
+                Ok(_) => return Ok(()),
+                Err(err) => value = err,
             }
+        }
+    }
+
+    /// Waits until the value can be decremented, or until the absolute time `deadline` on
+    /// `CLOCK_REALTIME` has passed. A signal handler interrupting the wait makes it fail with
+    /// `EINTR`.
+    pub fn wait(&self, deadline: Option<&timespec>) -> Result<(), Errno> {
+        self.wait_with(deadline, |timeout| self.lock.wait_if(0, timeout))
+    }
+
+    /// Like `wait`, but sleeping is a cancellation point. The thread is never cancelled after it
+    /// has decremented the value.
+    pub fn wait_cancelable(&self, deadline: Option<&timespec>) -> Result<(), Errno> {
+        self.wait_with(deadline, |timeout| {
+            pthread::cancellation_point(|| self.lock.wait_if(0, timeout))
+        })
+    }
 
-            if value == 0 {
-                self.lock.wait_if(0, timeout_opt);
-                value = 1;
+    fn wait_with(
+        &self,
+        deadline: Option<&timespec>,
+        sleep: impl Fn(Option<&timespec>) -> c_int,
+    ) -> Result<(), Errno> {
+        loop {
+            if self.try_wait().is_ok() {
+                return Ok(());
+            }
+
+            let timeout = match deadline {
+                Some(deadline) => Some(relative_timeout(CLOCK_REALTIME, deadline)?),
+                None => None,
+            };
+            match -sleep(timeout.as_ref()) {
+                EINTR => return Err(Errno(EINTR)),
+                ETIMEDOUT => return Err(Errno(ETIMEDOUT)),
+                _ => (),
             }
         }
     }
+
+    pub fn value(&self) -> c_int {
+        self.lock.load(Ordering::Relaxed)
+    }
 }
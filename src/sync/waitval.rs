@@ -0,0 +1,38 @@
+use super::AtomicLock;
+use core::{cell::UnsafeCell, mem::MaybeUninit, sync::atomic::Ordering};
+
+const PENDING: i32 = 0;
+const READY: i32 = 1;
+
+/// A value that is written exactly once and can be waited for by any number of threads, such as
+/// the return value of a thread.
+pub struct Waitval<T> {
+    state: AtomicLock,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+unsafe impl<T: Send> Send for Waitval<T> {}
+unsafe impl<T: Send + Sync> Sync for Waitval<T> {}
+
+impl<T> Waitval<T> {
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicLock::new(PENDING),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Set the value and wake up all waiters. Must be called at most once.
+    pub unsafe fn post(&self, value: T) {
+        (*self.value.get()).write(value);
+        self.state.store(READY, Ordering::Release);
+        self.state.notify_all();
+    }
+
+    /// Block until the value has been posted.
+    pub fn wait(&self) -> &T {
+        while self.state.load(Ordering::Acquire) == PENDING {
+            self.state.wait_if(PENDING, None);
+        }
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+}
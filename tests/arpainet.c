@@ -1,5 +1,6 @@
 #include <arpa/inet.h>
 #include <assert.h>
+#include <errno.h>
 #include <string.h>
 #include <stdlib.h>
 
@@ -22,4 +23,44 @@ int main(void) {
     struct in_addr* addr = malloc(sizeof addr);
     inet_aton(addr_str, addr);
     assert(strcmp(inet_ntoa(*addr), addr_str) == 0);
+
+    struct in_addr addr4;
+    char str[INET6_ADDRSTRLEN];
+    assert(inet_pton(AF_INET, "192.0.2.33", &addr4) == 1);
+    assert(inet_ntop(AF_INET, &addr4, str, sizeof(str)) == str);
+    assert(strcmp(str, "192.0.2.33") == 0);
+    assert(inet_pton(AF_INET, "192.0.2", &addr4) == 0);
+    assert(inet_pton(AF_INET, "192.0.2.256", &addr4) == 0);
+
+    // IPv6 addresses are written in the shortest form from RFC 5952
+    const char *addrs6[][2] = {
+        { "::", "::" },
+        { "::1", "::1" },
+        { "2001:DB8:0:0:0:0:0:1", "2001:db8::1" },
+        { "2001:db8:0:0:1:0:0:1", "2001:db8::1:0:0:1" },
+        { "2001:db8:0:1:1:1:1:1", "2001:db8:0:1:1:1:1:1" },
+        { "fe80::0:0:1", "fe80::1" },
+        { "1:2:3:4:5:6:7::", "1:2:3:4:5:6:7:0" },
+        { "::ffff:192.0.2.1", "::ffff:192.0.2.1" },
+        { "::192.0.2.1", "::192.0.2.1" },
+    };
+    struct in6_addr addr6;
+    for (size_t i = 0; i < sizeof(addrs6) / sizeof(addrs6[0]); i++) {
+        assert(inet_pton(AF_INET6, addrs6[i][0], &addr6) == 1);
+        assert(inet_ntop(AF_INET6, &addr6, str, sizeof(str)) == str);
+        assert(strcmp(str, addrs6[i][1]) == 0);
+    }
+    const char *invalid6[] = {
+        "", ":", ":::", "1::2::3", "1:2:3:4:5:6:7:8:9", "1:2:3:4:5:6:7:8::",
+        "12345::", "::g", "1:", ":1", "::192.0.2", "::1.2.3.4:5",
+    };
+    for (size_t i = 0; i < sizeof(invalid6) / sizeof(invalid6[0]); i++) {
+        assert(inet_pton(AF_INET6, invalid6[i], &addr6) == 0);
+    }
+
+    assert(inet_pton(AF_INET6, "2001:db8::1", &addr6) == 1);
+    assert(inet_ntop(AF_INET6, &addr6, str, strlen("2001:db8::1")) == NULL);
+    assert(errno == ENOSPC);
+    assert(inet_pton(AF_UNIX, "::", &addr6) == -1);
+    assert(errno == EAFNOSUPPORT);
 }
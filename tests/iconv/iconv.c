@@ -0,0 +1,118 @@
+#include <errno.h>
+#include <iconv.h>
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+// Converts `len` bytes of `in`, printing what comes out as hex, with what iconv() returned and
+// how much of the input and output it used
+void convert(const char *to, const char *from, const char *in, size_t len, size_t outlen) {
+    iconv_t cd = iconv_open(to, from);
+    ERROR_IF(iconv_open, cd, == (iconv_t) -1);
+
+    char out[64];
+    char *inbuf = (char *) in;
+    char *outbuf = out;
+    size_t inleft = len;
+    size_t outleft = outlen;
+    size_t status = iconv(cd, &inbuf, &inleft, &outbuf, &outleft);
+    int error = errno;
+
+    printf("%s -> %s:", from, to);
+    for (char *p = out; p < outbuf; p++) {
+        printf(" %02x", (unsigned char) *p);
+    }
+    if (status == (size_t) -1) {
+        printf(" (%s", strerror(error));
+    } else {
+        printf(" (%zu", status);
+    }
+    printf(", %zu in and %zu out left)\n", inleft, outleft);
+
+    int close_status = iconv_close(cd);
+    ERROR_IF(iconv_close, close_status, == -1);
+}
+
+#define CONVERT(to, from, in) convert(to, from, in, sizeof(in) - 1, 64)
+
+int main(void) {
+    // Transliteration in glibc depends on the locale, while relibc always strips accents
+    char *locale = setlocale(LC_CTYPE, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+
+    // Single-byte character sets
+    CONVERT("ISO-8859-1", "UTF-8", "Grüße");
+    CONVERT("UTF-8", "ISO-8859-1", "Gr\xfc\xdf" "e");
+    CONVERT("UTF-8", "ISO-8859-15", "\xa4 \xbd");
+    CONVERT("ISO-8859-2", "UTF-8", "Łódź");
+    CONVERT("UTF-8", "ISO-8859-5", "\xbc\xde\xe1\xda\xd2\xd0");
+    CONVERT("UTF-8", "ISO-8859-7", "\xc1\xe8\xde\xed\xe1");
+    CONVERT("UTF-8", "WINDOWS-1252", "\x80 \x93x\x94 \x9f");
+    CONVERT("cp1252", "utf8", "€…");
+    CONVERT("US-ASCII", "UTF-8", "plain");
+
+    // Unicode encodings: UTF-16 and UTF-32 without an order start with a byte order mark,
+    // and are read as big endian unless one says otherwise
+    CONVERT("UTF-16LE", "UTF-8", "aé😀");
+    CONVERT("UTF-16BE", "UTF-8", "aé😀");
+    CONVERT("UTF-32BE", "UTF-8", "a😀");
+    CONVERT("UTF-8", "UTF-16", "\x00\x61\xd8\x3d\xde\x00");
+    CONVERT("UTF-8", "UTF-16", "\xff\xfe\x61\x00\x3d\xd8\x00\xde");
+    CONVERT("UTF-8", "UTF-32", "\xff\xfe\x00\x00\x00\xf6\x01\x00");
+    CONVERT("UTF-8", "UTF-16LE", "\x3d\xd8");
+    CONVERT("UTF-8", "UTF-16LE", "\x00\xd8\x61\x00");
+
+    // Chinese and Japanese
+    CONVERT("GB18030", "UTF-8", "中文 é € 😀");
+    CONVERT("UTF-8", "GB18030", "\xd6\xd0\xce\xc4 \xa8\xa6 \xa2\xe3 \x94\x39\xda\x33");
+    CONVERT("GBK", "UTF-8", "中文");
+    CONVERT("SHIFT_JIS", "UTF-8", "日本語 ｱ");
+    CONVERT("UTF-8", "SJIS", "\x93\xfa\x96\x7b\x8c\xea \xb1");
+    CONVERT("EUC-JP", "UTF-8", "日本語 ｱ");
+
+    // A character that doesn't fit stays in the input
+    convert("UTF-16LE", "UTF-8", "aé", 3, 3);
+    // Invalid input stops the conversion where it starts
+    CONVERT("UTF-16LE", "UTF-8", "a\xff" "b");
+    CONVERT("UTF-8", "SHIFT_JIS", "a\x85\x40");
+    // So does a character that the output can't have
+    CONVERT("ISO-8859-1", "UTF-8", "a€b");
+    // An incomplete character at the end is left for the next call
+    CONVERT("UTF-16LE", "UTF-8", "a\xe2\x82");
+    CONVERT("UTF-8", "UTF-16LE", "a\x00\x3d\xd8\x00");
+
+    // Characters that can't be converted can be spelled with others, or left out
+    CONVERT("ASCII//TRANSLIT", "UTF-8", "Grüße “x” – € 東");
+    CONVERT("ISO-8859-1//TRANSLIT", "UTF-8", "€ ő ü");
+    CONVERT("ISO-8859-1//IGNORE", "UTF-8", "a€b");
+    CONVERT("ASCII//TRANSLIT//IGNORE", "UTF-8", "a€b");
+
+    // Unknown character sets
+    iconv_t cd = iconv_open("UTF-8", "EBCDIC-NONSENSE");
+    printf("iconv_open(\"UTF-8\", \"EBCDIC-NONSENSE\") = %d, %s\n", cd == (iconv_t) -1,
+           strerror(errno));
+
+    // A conversion can be fed in pieces, and a null input resets it
+    cd = iconv_open("UTF-16LE", "UTF-8");
+    ERROR_IF(iconv_open, cd, == (iconv_t) -1);
+    const char *text = "€uro";
+    char out[16];
+    char *inbuf = (char *) text;
+    char *outbuf = out;
+    size_t inleft = 2;
+    size_t outleft = sizeof(out);
+    size_t status = iconv(cd, &inbuf, &inleft, &outbuf, &outleft);
+    printf("first piece: %d %s, %zu left\n", (int) status, strerror(errno), inleft);
+    inleft = strlen(inbuf);
+    status = iconv(cd, &inbuf, &inleft, &outbuf, &outleft);
+    printf("second piece: %zu, %zu left, %zu out\n", status, inleft, sizeof(out) - outleft);
+    status = iconv(cd, NULL, NULL, &outbuf, &outleft);
+    printf("reset: %zu, %zu out\n", status, sizeof(out) - outleft);
+    status = iconv(cd, NULL, NULL, NULL, NULL);
+    printf("reset without output: %zu\n", status);
+    int close_status = iconv_close(cd);
+    ERROR_IF(iconv_close, close_status, == -1);
+}
@@ -0,0 +1,76 @@
+#include <errno.h>
+#include <libintl.h>
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+static void plurals(void) {
+    unsigned long counts[] = { 0, 1, 2, 5, 12, 22, 101 };
+    for (size_t i = 0; i < sizeof(counts) / sizeof(counts[0]); i++) {
+        printf(ngettext("%lu file", "%lu files", counts[i]), counts[i]);
+        putchar(i + 1 < sizeof(counts) / sizeof(counts[0]) ? ' ' : '\n');
+    }
+}
+
+int main(void) {
+    // Without a locale, nothing is translated
+    printf("textdomain: %s\n", textdomain(NULL));
+    printf("bindtextdomain: %s\n", bindtextdomain("test", NULL));
+    char *dirname = bindtextdomain("test", "messages");
+    ERROR_IF(bindtextdomain, dirname, == NULL);
+    printf("bindtextdomain: %s\n", dirname);
+    char *domain = textdomain("test");
+    ERROR_IF(textdomain, domain, == NULL);
+    printf("textdomain: %s\n", domain);
+    printf("C: %s\n", gettext("Hello, world!"));
+
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+    char *locale = setlocale(LC_ALL, "de_DE.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+
+    errno = ERANGE;
+    printf("%s\n", gettext("Hello, world!"));
+    printf("errno is kept: %s\n", errno == ERANGE ? "yes" : "no");
+    printf("%s\n", gettext("File not found"));
+    printf("%s\n", gettext("Untranslated"));
+    printf("%s\n", gettext("Missing"));
+    printf("%s\n", gettext("menu\004Open"));
+    printf("%s\n", dgettext("test", "File not found"));
+    printf("%s\n", dcgettext("test", "File not found", LC_MESSAGES));
+    printf("%s\n", dgettext("missing", "File not found"));
+    plurals();
+    printf(dngettext("test", "%lu file", "%lu files", 3), 3ul);
+    putchar('\n');
+    printf(dcngettext("missing", "%lu file", "%lu files", 3, LC_MESSAGES), 3ul);
+    putchar('\n');
+
+    // LANGUAGE lists the languages to try, ahead of the locale. GNU gettext only sees changes
+    // to it once the locale changes.
+    status = setenv("LANGUAGE", "xx:pl:de", 1);
+    ERROR_IF(setenv, status, == -1);
+    locale = setlocale(LC_ALL, "C");
+    ERROR_IF(setlocale, locale, == NULL);
+    locale = setlocale(LC_ALL, "de_DE.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    printf("%s\n", gettext("Hello, world!"));
+    printf("%s\n", gettext("File not found"));
+    plurals();
+    status = unsetenv("LANGUAGE");
+    ERROR_IF(unsetenv, status, == -1);
+
+    // Going back to the default domain
+    domain = textdomain("");
+    ERROR_IF(textdomain, domain, == NULL);
+    printf("textdomain: %s\n", domain);
+    printf("%s\n", gettext("Hello, world!"));
+
+    printf("bind_textdomain_codeset: %s\n",
+        bind_textdomain_codeset("test", "UTF-8"));
+    printf("bind_textdomain_codeset: %s\n",
+        bind_textdomain_codeset("test", NULL));
+}
@@ -1,15 +1,84 @@
+#include <ctype.h>
+#include <errno.h>
 #include <locale.h>
 #include <stdio.h>
+#include <stdlib.h>
 #include <string.h>
 
 #include "test_helpers.h"
 
 int main(void) {
-    // TODO: Implement locale properly and test it here
     char* val = setlocale(LC_ALL, NULL);
     if (strcmp(val, "C") == 0) {
         puts("success!");
     } else {
         printf("setlocale returned the wrong value: %s", val);
     }
+
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    val = setlocale(LC_ALL, "de_DE.UTF-8");
+    ERROR_IF(setlocale, val, == NULL);
+    printf("setlocale: %s\n", val);
+
+    struct lconv* lc = localeconv();
+    printf("decimal_point: %s, thousands_sep: %s, grouping: %d %d\n",
+        lc->decimal_point, lc->thousands_sep, lc->grouping[0], lc->grouping[1]);
+    printf("int_curr_symbol: \"%s\", currency_symbol: %s, frac_digits: %d\n",
+        lc->int_curr_symbol, lc->currency_symbol, lc->frac_digits);
+    printf("strtod: %d\n", (int) (strtod("3,5", NULL) * 10));
+
+    // Categories can be set separately, which makes the name composite
+    val = setlocale(LC_NUMERIC, "C");
+    ERROR_IF(setlocale, val, == NULL);
+    printf("setlocale: %s\n", val);
+    printf("strtod: %d\n", (int) (strtod("3,5", NULL) * 10));
+
+    char* saved = strdup(setlocale(LC_ALL, NULL));
+    printf("setlocale: %s\n", saved);
+    setlocale(LC_ALL, "C");
+    val = setlocale(LC_ALL, saved);
+    ERROR_IF(setlocale, val, == NULL);
+    printf("restored: %s\n", setlocale(LC_NUMERIC, NULL));
+    free(saved);
+
+    val = setlocale(LC_ALL, "xx_XX");
+    printf("unknown locale: %s, still: %s\n", val ? val : "(null)", setlocale(LC_TIME, NULL));
+    setlocale(LC_ALL, "C");
+
+    // Locale objects
+    locale_t de = newlocale(LC_ALL_MASK, "de_DE.ISO-8859-1", (locale_t) 0);
+    ERROR_IF(newlocale, de, == (locale_t) 0);
+    printf("isalpha_l: %d %d\n", !!isalpha_l(0xe4, de), !!isalpha_l(0xe4, LC_GLOBAL_LOCALE));
+    printf("toupper_l: %x %x\n", toupper_l(0xe4, de), toupper_l(0xe4, LC_GLOBAL_LOCALE));
+    printf("ispunct_l: %d, isspace_l: %d\n", !!ispunct_l(0xa7, de), !!isspace_l(' ', de));
+
+    locale_t old = uselocale(de);
+    printf("uselocale: %d\n", old == LC_GLOBAL_LOCALE);
+    printf("thread decimal_point: %s, isalpha: %d\n", localeconv()->decimal_point, !!isalpha(0xe4));
+    printf("global: %s\n", setlocale(LC_ALL, NULL));
+    uselocale(old);
+    printf("back to decimal_point: %s, isalpha: %d\n", localeconv()->decimal_point, !!isalpha(0xe4));
+
+    locale_t copy = duplocale(de);
+    ERROR_IF(duplocale, copy, == (locale_t) 0);
+    freelocale(de);
+
+    // Only LC_NUMERIC of the base is replaced
+    copy = newlocale(LC_NUMERIC_MASK, "C", copy);
+    ERROR_IF(newlocale, copy, == (locale_t) 0);
+    uselocale(copy);
+    printf("modified decimal_point: %s, mon_decimal_point: %s\n",
+        localeconv()->decimal_point, localeconv()->mon_decimal_point);
+    uselocale(LC_GLOBAL_LOCALE);
+    freelocale(copy);
+
+    errno = 0;
+    locale_t bad = newlocale(LC_ALL_MASK, "xx_XX", (locale_t) 0);
+    printf("unknown: %d %s\n", bad == (locale_t) 0, strerror(errno));
+    errno = 0;
+    bad = newlocale(1 << 20, "C", (locale_t) 0);
+    printf("invalid mask: %d %s\n", bad == (locale_t) 0, strerror(errno));
 }
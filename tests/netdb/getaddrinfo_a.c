@@ -0,0 +1,499 @@
+// getaddrinfo_a() with both modes and notifications, waiting for and cancelling its requests,
+// and the non-blocking queries driven with epoll, against a fake name server on localhost
+
+#include <arpa/inet.h>
+#include <netdb.h>
+#include <netinet/in.h>
+#include <poll.h>
+#include <pthread.h>
+#include <signal.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <strings.h>
+#include <sys/epoll.h>
+#include <sys/socket.h>
+#include <sys/wait.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+// Makes relibc read a file in place of one in /etc, which the tests cannot change
+void __relibc_internal_netdb_redirect(const char *file, const char *path);
+
+#define TYPE_A 1
+#define TYPE_AAAA 28
+
+#define RCODE_NXDOMAIN 3
+
+struct response {
+    unsigned char data[4096];
+    size_t len;
+    uint16_t answers;
+};
+
+static void start(struct response *r, const unsigned char *query, size_t question_len, int rcode, int truncated) {
+    memcpy(r->data, query, question_len);
+    r->data[2] = 0x81 | (truncated ? 0x02 : 0);
+    r->data[3] = 0x80 | rcode;
+    memset(&r->data[6], 0, 6);
+    r->len = question_len;
+    r->answers = 0;
+}
+
+static void answer(struct response *r, int type, const void *data, size_t len) {
+    unsigned char *p = &r->data[r->len];
+    unsigned char header[] = {
+        0xc0, 12, 0, type, 0, 1, 0, 0, 0, 60, len >> 8, len & 0xff
+    };
+    memcpy(p, header, sizeof(header));
+    memcpy(p + sizeof(header), data, len);
+    r->len += sizeof(header) + len;
+    r->answers++;
+    r->data[6] = r->answers >> 8;
+    r->data[7] = r->answers & 0xff;
+}
+
+static void answer_a(struct response *r, const char *addr) {
+    struct in_addr in;
+    inet_pton(AF_INET, addr, &in);
+    answer(r, TYPE_A, &in, 4);
+}
+
+// Answers `query`, returning 0 to send no answer
+static int respond(struct response *r, const unsigned char *query, size_t len, int tcp) {
+    char name[256] = "";
+    size_t i = 12;
+    while (i < len && query[i] != 0) {
+        size_t label = query[i];
+        if (name[0]) {
+            strcat(name, ".");
+        }
+        strncat(name, (const char *)&query[i + 1], label);
+        i += label + 1;
+    }
+    int type = (query[i + 1] << 8) | query[i + 2];
+    size_t question_len = i + 5;
+
+    static int slow_queries = 0;
+    static int late_queries = 0;
+
+    if (strcasecmp(name, "www.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_A) {
+            answer_a(r, "192.0.2.1");
+        }
+    } else if (strcasecmp(name, "mail.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_A) {
+            answer_a(r, "192.0.2.2");
+        }
+    } else if (strcasecmp(name, "v6.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_AAAA) {
+            struct in6_addr in6;
+            inet_pton(AF_INET6, "2001:db8::6", &in6);
+            answer(r, TYPE_AAAA, &in6, 16);
+        }
+    } else if (strcasecmp(name, "slow.example.test") == 0) {
+        // The first query for each of these goes unanswered
+        if (slow_queries++ == 0) {
+            return 0;
+        }
+        start(r, query, question_len, 0, 0);
+        answer_a(r, "192.0.2.4");
+    } else if (strcasecmp(name, "late.example.test") == 0) {
+        if (late_queries++ == 0) {
+            return 0;
+        }
+        start(r, query, question_len, 0, 0);
+        answer_a(r, "192.0.2.5");
+    } else if (strcasecmp(name, "never.example.test") == 0) {
+        return 0;
+    } else if (strcasecmp(name, "big.example.test") == 0) {
+        // Too many addresses for UDP
+        start(r, query, question_len, 0, !tcp);
+        if (tcp && type == TYPE_A) {
+            for (int n = 1; n <= 40; n++) {
+                char addr[16];
+                snprintf(addr, sizeof(addr), "198.51.100.%d", n);
+                answer_a(r, addr);
+            }
+        }
+    } else {
+        start(r, query, question_len, RCODE_NXDOMAIN, 0);
+    }
+    return 1;
+}
+
+static void serve(int udp, int tcp) {
+    for (;;) {
+        struct pollfd fds[] = { { udp, POLLIN, 0 }, { tcp, POLLIN, 0 } };
+        if (poll(fds, 2, -1) < 0) {
+            _exit(1);
+        }
+
+        unsigned char query[512];
+        struct response r;
+        if (fds[0].revents & POLLIN) {
+            struct sockaddr_storage from;
+            socklen_t from_len = sizeof(from);
+            ssize_t len = recvfrom(udp, query, sizeof(query), 0, (struct sockaddr *)&from, &from_len);
+            if (len < 12 || !respond(&r, query, len, 0)) {
+                continue;
+            }
+            sendto(udp, r.data, r.len, 0, (struct sockaddr *)&from, from_len);
+        }
+        if (fds[1].revents & POLLIN) {
+            int conn = accept(tcp, NULL, NULL);
+            unsigned char len[2];
+            if (conn < 0 || read(conn, len, 2) != 2) {
+                _exit(1);
+            }
+            size_t query_len = (len[0] << 8) | len[1];
+            size_t got = 0;
+            while (got < query_len) {
+                ssize_t n = read(conn, &query[got], query_len - got);
+                if (n <= 0) {
+                    _exit(1);
+                }
+                got += n;
+            }
+            respond(&r, query, query_len, 1);
+            unsigned char r_len[2] = { r.len >> 8, r.len & 0xff };
+            write(conn, r_len, 2);
+            write(conn, r.data, r.len);
+            close(conn);
+        }
+    }
+}
+
+static const char *error(int code) {
+    switch (code) {
+        case 0: return "0";
+        case EAI_NONAME: return "EAI_NONAME";
+        case EAI_AGAIN: return "EAI_AGAIN";
+        case EAI_BADFLAGS: return "EAI_BADFLAGS";
+        case EAI_INPROGRESS: return "EAI_INPROGRESS";
+        case EAI_CANCELED: return "EAI_CANCELED";
+        case EAI_NOTCANCELED: return "EAI_NOTCANCELED";
+        case EAI_ALLDONE: return "EAI_ALLDONE";
+        default: return "other";
+    }
+}
+
+static void print_result(const char *name, int code, struct addrinfo *res) {
+    printf("%s: %s", name, error(code));
+    if (code != 0) {
+        printf("\n");
+        return;
+    }
+    int count = 0;
+    for (struct addrinfo *ai = res; ai; ai = ai->ai_next) {
+        // Only the first few of many addresses
+        if (count++ == 3) {
+            printf(" ...");
+            continue;
+        } else if (count > 3) {
+            continue;
+        }
+        char addr[INET6_ADDRSTRLEN];
+        if (ai->ai_family == AF_INET) {
+            inet_ntop(AF_INET, &((struct sockaddr_in *)ai->ai_addr)->sin_addr, addr, sizeof(addr));
+        } else {
+            inet_ntop(AF_INET6, &((struct sockaddr_in6 *)ai->ai_addr)->sin6_addr, addr, sizeof(addr));
+        }
+        printf(" %s", addr);
+    }
+    printf(" (%d)\n", count);
+    freeaddrinfo(res);
+}
+
+static void print_requests(struct gaicb **list, int nitems) {
+    for (int i = 0; i < nitems; i++) {
+        print_result(list[i]->ar_name, gai_error(list[i]), list[i]->ar_result);
+    }
+}
+
+static struct addrinfo hints;
+
+static struct gaicb request(const char *name) {
+    struct gaicb cb;
+    memset(&cb, 0, sizeof(cb));
+    cb.ar_name = name;
+    cb.ar_request = &hints;
+    return cb;
+}
+
+// Waits for all of the requests to finish
+static void suspend(struct gaicb **list, int nitems) {
+    for (;;) {
+        int done = 1;
+        for (int i = 0; i < nitems; i++) {
+            if (gai_error(list[i]) == EAI_INPROGRESS) {
+                done = 0;
+            }
+        }
+        if (done) {
+            return;
+        }
+        int status = gai_suspend((const struct gaicb **)list, nitems, NULL);
+        ERROR_IF(gai_suspend, status, != 0);
+    }
+}
+
+static pthread_mutex_t notified_lock = PTHREAD_MUTEX_INITIALIZER;
+static pthread_cond_t notified_cond = PTHREAD_COND_INITIALIZER;
+static int notified = 0;
+
+static void notify(union sigval value) {
+    pthread_mutex_lock(&notified_lock);
+    notified = value.sival_int;
+    pthread_cond_signal(&notified_cond);
+    pthread_mutex_unlock(&notified_lock);
+}
+
+static int wait_notified(void) {
+    pthread_mutex_lock(&notified_lock);
+    while (notified == 0) {
+        pthread_cond_wait(&notified_cond, &notified_lock);
+    }
+    int value = notified;
+    notified = 0;
+    pthread_mutex_unlock(&notified_lock);
+    return value;
+}
+
+static void wait_mode(void) {
+    struct gaicb www = request("www");
+    struct gaicb big = request("big");
+    struct gaicb missing = request("missing");
+    struct gaicb numeric = request("192.0.2.99");
+    struct gaicb bad = request("www");
+    struct addrinfo bad_hints = hints;
+    bad_hints.ai_flags = 0x10000;
+    bad.ar_request = &bad_hints;
+    struct gaicb *list[] = { &www, &big, &missing, &numeric, &bad };
+
+    int status = getaddrinfo_a(GAI_WAIT, list, 5, NULL);
+    printf("getaddrinfo_a GAI_WAIT: %d\n", status);
+    print_requests(list, 5);
+}
+
+static void thread_notification(void) {
+    struct gaicb www = request("www");
+    struct gaicb v6 = request("v6");
+    struct gaicb *list[] = { &www, &v6 };
+
+    struct sigevent sev;
+    memset(&sev, 0, sizeof(sev));
+    sev.sigev_notify = SIGEV_THREAD;
+    sev.sigev_notify_function = notify;
+    sev.sigev_value.sival_int = 42;
+    int status = getaddrinfo_a(GAI_NOWAIT, list, 2, &sev);
+    printf("getaddrinfo_a GAI_NOWAIT with SIGEV_THREAD: %d\n", status);
+    printf("notified with %d\n", wait_notified());
+    print_requests(list, 2);
+}
+
+static void signal_notification(void) {
+    struct gaicb slow = request("slow");
+    struct gaicb mail = request("mail");
+    struct gaicb *list[] = { &slow, NULL, &mail };
+
+    sigset_t set;
+    sigemptyset(&set);
+    sigaddset(&set, SIGUSR1);
+    sigprocmask(SIG_BLOCK, &set, NULL);
+
+    struct sigevent sev;
+    memset(&sev, 0, sizeof(sev));
+    sev.sigev_notify = SIGEV_SIGNAL;
+    sev.sigev_signo = SIGUSR1;
+    sev.sigev_value.sival_int = 7;
+    int status = getaddrinfo_a(GAI_NOWAIT, list, 3, &sev);
+    printf("getaddrinfo_a GAI_NOWAIT with SIGEV_SIGNAL: %d\n", status);
+
+    // One request finishing ends gai_suspend, but the signal only comes after both have
+    status = gai_suspend((const struct gaicb **)list, 3, NULL);
+    printf("gai_suspend: %s\n", error(status));
+    siginfo_t info;
+    int signo = sigwaitinfo(&set, &info);
+    printf("signal %s with %d\n", signo == SIGUSR1 ? "SIGUSR1" : "other", info.si_value.sival_int);
+    sigprocmask(SIG_UNBLOCK, &set, NULL);
+
+    struct gaicb *requests[] = { &slow, &mail };
+    print_requests(requests, 2);
+}
+
+static void cancel(void) {
+    struct gaicb never = request("never");
+    struct gaicb *list[] = { &never };
+    int status = getaddrinfo_a(GAI_NOWAIT, list, 1, NULL);
+    printf("getaddrinfo_a GAI_NOWAIT: %d\n", status);
+    printf("gai_error: %s\n", error(gai_error(&never)));
+
+    struct timespec timeout = { 0, 100000000 };
+    status = gai_suspend((const struct gaicb **)list, 1, &timeout);
+    printf("gai_suspend for 100 ms: %s\n", error(status));
+
+    printf("gai_cancel: %s\n", error(gai_cancel(&never)));
+    printf("gai_error: %s\n", error(gai_error(&never)));
+    printf("gai_cancel again: %s\n", error(gai_cancel(&never)));
+
+    const struct gaicb *none[] = { NULL };
+    printf("gai_suspend without requests: %s\n", error(gai_suspend(none, 1, NULL)));
+
+    // Cancelling the last request of a batch notifies it
+    struct gaicb www = request("www");
+    never = request("never");
+    struct gaicb *batch[] = { &www, &never };
+    struct sigevent sev;
+    memset(&sev, 0, sizeof(sev));
+    sev.sigev_notify = SIGEV_THREAD;
+    sev.sigev_notify_function = notify;
+    sev.sigev_value.sival_int = 9;
+    getaddrinfo_a(GAI_NOWAIT, batch, 2, &sev);
+    suspend(batch, 1);
+    printf("gai_cancel after the other request: %s\n", error(gai_cancel(&never)));
+    printf("notified with %d\n", wait_notified());
+    print_requests(batch, 2);
+}
+
+struct query {
+    const char *name;
+    struct gai_query *query;
+    int code;
+    struct addrinfo *res;
+};
+
+static void queries(void) {
+    struct query queries[] = {
+        { "www", NULL, EAI_INPROGRESS, NULL },
+        { "late", NULL, EAI_INPROGRESS, NULL },
+        { "big", NULL, EAI_INPROGRESS, NULL },
+        { "missing", NULL, EAI_INPROGRESS, NULL },
+    };
+    const int count = sizeof(queries) / sizeof(queries[0]);
+
+    int epfd = epoll_create1(EPOLL_CLOEXEC);
+    ERROR_IF(epoll_create1, epfd, == -1);
+    for (int i = 0; i < count; i++) {
+        int status = gai_query_start(queries[i].name, NULL, &hints, &queries[i].query);
+        ERROR_IF(gai_query_start, status, != 0);
+        struct epoll_event event = { .events = EPOLLIN, .data.ptr = &queries[i] };
+        status = epoll_ctl(epfd, EPOLL_CTL_ADD, gai_query_fd(queries[i].query), &event);
+        ERROR_IF(epoll_ctl, status, == -1);
+    }
+
+    // Every query is processed when its fd is readable or its timeout has passed
+    int remaining = count;
+    while (remaining > 0) {
+        int timeout = -1;
+        for (int i = 0; i < count; i++) {
+            if (queries[i].code == EAI_INPROGRESS) {
+                int query_timeout = gai_query_timeout(queries[i].query);
+                if (query_timeout >= 0 && (timeout < 0 || query_timeout < timeout)) {
+                    timeout = query_timeout;
+                }
+            }
+        }
+        struct epoll_event events[4];
+        int n = epoll_wait(epfd, events, 4, timeout);
+        ERROR_IF(epoll_wait, n, == -1);
+        for (int i = 0; i < count; i++) {
+            if (queries[i].code != EAI_INPROGRESS) {
+                continue;
+            }
+            queries[i].code = gai_query_process(queries[i].query, &queries[i].res);
+            if (queries[i].code != EAI_INPROGRESS) {
+                epoll_ctl(epfd, EPOLL_CTL_DEL, gai_query_fd(queries[i].query), NULL);
+                remaining--;
+            }
+        }
+    }
+    close(epfd);
+
+    for (int i = 0; i < count; i++) {
+        print_result(queries[i].name, queries[i].code, queries[i].res);
+    }
+    struct addrinfo *res;
+    printf("gai_query_process after the end: %s\n", error(gai_query_process(queries[0].query, &res)));
+    for (int i = 0; i < count; i++) {
+        gai_query_free(queries[i].query);
+    }
+
+    // Wrong arguments are found at once
+    struct gai_query *query;
+    struct addrinfo bad_hints = hints;
+    bad_hints.ai_flags = AI_CANONNAME;
+    printf("gai_query_start without a name: %s\n", error(gai_query_start(NULL, "80", &bad_hints, &query)));
+
+    // A query can be freed before it has finished
+    int status = gai_query_start("never", NULL, &hints, &query);
+    ERROR_IF(gai_query_start, status, != 0);
+    printf("gai_query_process: %s\n", error(gai_query_process(query, &res)));
+    gai_query_free(query);
+}
+
+// Binds a socket of `type` to a port on 127.0.0.1, `port` or any if it is 0
+static int bind_socket(int type, in_port_t *port) {
+    int fd = socket(AF_INET, type, 0);
+    ERROR_IF(socket, fd, == -1);
+    struct sockaddr_in sin;
+    memset(&sin, 0, sizeof(sin));
+    sin.sin_family = AF_INET;
+    sin.sin_port = *port;
+    sin.sin_addr.s_addr = htonl(INADDR_LOOPBACK);
+    int status = bind(fd, (struct sockaddr *)&sin, sizeof(sin));
+    ERROR_IF(bind, status, == -1);
+    socklen_t len = sizeof(sin);
+    status = getsockname(fd, (struct sockaddr *)&sin, &len);
+    ERROR_IF(getsockname, status, == -1);
+    *port = sin.sin_port;
+    return fd;
+}
+
+int main(void) {
+    in_port_t port = 0;
+    int udp = bind_socket(SOCK_DGRAM, &port);
+    int tcp = bind_socket(SOCK_STREAM, &port);
+    int status = listen(tcp, 8);
+    ERROR_IF(listen, status, == -1);
+
+    char conf[] = "/tmp/resolvconf-XXXXXX";
+    int fd = mkstemp(conf);
+    ERROR_IF(mkstemp, fd, == -1);
+    FILE *f = fdopen(fd, "w");
+    ERROR_IF(fdopen, f, == NULL);
+    fprintf(f,
+        "nameserver [127.0.0.1]:%d\n"
+        "search example.test\n"
+        "options ndots:1 timeout:1 attempts:2\n",
+        ntohs(port));
+    fclose(f);
+    __relibc_internal_netdb_redirect("/etc/resolv.conf", conf);
+
+    pid_t pid = fork();
+    ERROR_IF(fork, pid, == -1);
+    if (pid == 0) {
+        serve(udp, tcp);
+    }
+    close(udp);
+    close(tcp);
+
+    memset(&hints, 0, sizeof(hints));
+    hints.ai_socktype = SOCK_STREAM;
+
+    wait_mode();
+    thread_notification();
+    signal_notification();
+    cancel();
+    queries();
+
+    kill(pid, SIGKILL);
+    waitpid(pid, NULL, 0);
+    unlink(conf);
+}
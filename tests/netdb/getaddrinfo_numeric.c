@@ -0,0 +1,115 @@
+// getaddrinfo() with numeric hosts and services, which needs no network
+
+#include <arpa/inet.h>
+#include <netdb.h>
+#include <netinet/in.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/socket.h>
+
+#include "test_helpers.h"
+
+static const char *error(int code) {
+    switch (code) {
+        case 0: return "0";
+        case EAI_BADFLAGS: return "EAI_BADFLAGS";
+        case EAI_NONAME: return "EAI_NONAME";
+        case EAI_FAMILY: return "EAI_FAMILY";
+        case EAI_SOCKTYPE: return "EAI_SOCKTYPE";
+        case EAI_SERVICE: return "EAI_SERVICE";
+        case EAI_ADDRFAMILY: return "EAI_ADDRFAMILY";
+        default: return "other";
+    }
+}
+
+static const char *socktype(int type) {
+    switch (type) {
+        case SOCK_STREAM: return "stream";
+        case SOCK_DGRAM: return "dgram";
+        case SOCK_RAW: return "raw";
+        default: return "other";
+    }
+}
+
+static void test(const char *node, const char *service, int flags, int family, int type, int protocol) {
+    struct addrinfo hints;
+    memset(&hints, 0, sizeof(hints));
+    hints.ai_flags = flags;
+    hints.ai_family = family;
+    hints.ai_socktype = type;
+    hints.ai_protocol = protocol;
+
+    struct addrinfo *res;
+    int code = getaddrinfo(node, service, &hints, &res);
+    printf("%s %s flags %#x family %d socktype %d protocol %d: %s\n",
+        node ? node : "NULL", service ? service : "NULL", flags, family, type, protocol, error(code));
+    if (code != 0) {
+        return;
+    }
+
+    for (struct addrinfo *ai = res; ai; ai = ai->ai_next) {
+        char addr[INET6_ADDRSTRLEN];
+        int port;
+        if (ai->ai_family == AF_INET) {
+            struct sockaddr_in *sin = (struct sockaddr_in *)ai->ai_addr;
+            inet_ntop(AF_INET, &sin->sin_addr, addr, sizeof(addr));
+            port = ntohs(sin->sin_port);
+            if (ai->ai_addrlen != sizeof(*sin)) {
+                puts("  wrong ai_addrlen");
+            }
+        } else {
+            struct sockaddr_in6 *sin6 = (struct sockaddr_in6 *)ai->ai_addr;
+            inet_ntop(AF_INET6, &sin6->sin6_addr, addr, sizeof(addr));
+            port = ntohs(sin6->sin6_port);
+            if (ai->ai_family != AF_INET6 || ai->ai_addrlen != sizeof(*sin6)) {
+                puts("  wrong ai_family or ai_addrlen");
+            }
+            if (sin6->sin6_scope_id != 0) {
+                printf("  scope %u\n", sin6->sin6_scope_id);
+            }
+        }
+        printf("  %s %s %d port %d flags %#x canonname %s\n",
+            addr, socktype(ai->ai_socktype), ai->ai_protocol, port, ai->ai_flags,
+            ai->ai_canonname ? ai->ai_canonname : "NULL");
+    }
+    freeaddrinfo(res);
+}
+
+int main(void) {
+    // Numeric hosts of both families
+    test("127.0.0.1", "80", AI_NUMERICHOST, AF_UNSPEC, SOCK_STREAM, 0);
+    test("::1", "80", AI_NUMERICHOST, AF_UNSPEC, SOCK_STREAM, 0);
+    test("2001:db8::1", "8080", AI_NUMERICHOST | AI_CANONNAME, AF_INET6, SOCK_DGRAM, 0);
+    test("fe80::1%42", NULL, AI_NUMERICHOST, AF_INET6, SOCK_STREAM, 0);
+    test("::ffff:1.2.3.4", "1", AI_NUMERICHOST, AF_INET, SOCK_STREAM, 0);
+
+    // Every socket type when none is asked for
+    test("10.0.0.1", "53", AI_NUMERICHOST, AF_INET, 0, 0);
+    test("10.0.0.1", NULL, AI_NUMERICHOST, AF_INET, 0, IPPROTO_UDP);
+    test("10.0.0.1", NULL, AI_NUMERICHOST, AF_INET, 0, IPPROTO_ICMP);
+    test("10.0.0.1", "53", AI_NUMERICHOST, AF_INET, SOCK_STREAM, IPPROTO_UDP);
+
+    // Family mismatches
+    test("127.0.0.1", "80", AI_NUMERICHOST, AF_INET6, SOCK_STREAM, 0);
+    test("127.0.0.1", "80", AI_NUMERICHOST | AI_V4MAPPED, AF_INET6, SOCK_STREAM, 0);
+    test("::1", "80", AI_NUMERICHOST, AF_INET, SOCK_STREAM, 0);
+
+    // Without a host
+    test(NULL, "80", AI_PASSIVE, AF_INET, SOCK_STREAM, 0);
+    test(NULL, "80", AI_PASSIVE, AF_INET6, SOCK_STREAM, 0);
+    test(NULL, "80", 0, AF_INET, SOCK_STREAM, 0);
+    test(NULL, "80", 0, AF_INET6, SOCK_STREAM, 0);
+
+    // Services
+    test("127.0.0.1", "http", AI_NUMERICHOST, AF_INET, SOCK_STREAM, 0);
+    test("127.0.0.1", "http", AI_NUMERICHOST | AI_NUMERICSERV, AF_INET, SOCK_STREAM, 0);
+    test("127.0.0.1", "no-such-service", AI_NUMERICHOST, AF_INET, SOCK_STREAM, 0);
+    test("127.0.0.1", "80", AI_NUMERICHOST, AF_INET, SOCK_RAW, 0);
+
+    // Errors
+    test("localhost", "80", AI_NUMERICHOST, AF_UNSPEC, SOCK_STREAM, 0);
+    test(NULL, NULL, 0, AF_UNSPEC, 0, 0);
+    test(NULL, "80", AI_CANONNAME, AF_UNSPEC, SOCK_STREAM, 0);
+    test("127.0.0.1", "80", 0x10000, AF_UNSPEC, SOCK_STREAM, 0);
+    test("127.0.0.1", "80", AI_NUMERICHOST, AF_UNIX, SOCK_STREAM, 0);
+}
@@ -0,0 +1,77 @@
+// getnameinfo() with numeric hosts, which needs no network
+
+#include <arpa/inet.h>
+#include <netdb.h>
+#include <netinet/in.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/socket.h>
+
+#include "test_helpers.h"
+
+static const char *error(int code) {
+    switch (code) {
+        case 0: return "0";
+        case EAI_NONAME: return "EAI_NONAME";
+        case EAI_FAMILY: return "EAI_FAMILY";
+        case EAI_OVERFLOW: return "EAI_OVERFLOW";
+        default: return "other";
+    }
+}
+
+static void test(const struct sockaddr *sa, socklen_t len, size_t hostlen, size_t servlen, int flags) {
+    char host[NI_MAXHOST] = "";
+    char serv[NI_MAXSERV] = "";
+    int code = getnameinfo(sa, len, hostlen ? host : NULL, hostlen, servlen ? serv : NULL, servlen, flags);
+    printf("flags %#x: %s", flags, error(code));
+    if (code == 0) {
+        printf(" host \"%s\" serv \"%s\"", host, serv);
+    }
+    putchar('\n');
+}
+
+int main(void) {
+    struct sockaddr_in sin;
+    memset(&sin, 0, sizeof(sin));
+    sin.sin_family = AF_INET;
+    sin.sin_port = htons(80);
+    inet_pton(AF_INET, "192.0.2.1", &sin.sin_addr);
+    const struct sockaddr *sa = (const struct sockaddr *)&sin;
+
+    test(sa, sizeof(sin), NI_MAXHOST, NI_MAXSERV, NI_NUMERICHOST | NI_NUMERICSERV);
+    test(sa, sizeof(sin), NI_MAXHOST, NI_MAXSERV, NI_NUMERICHOST);
+    test(sa, sizeof(sin), 0, NI_MAXSERV, 0);
+    test(sa, sizeof(sin), NI_MAXHOST, 0, NI_NUMERICHOST);
+    test(sa, sizeof(sin), NI_MAXHOST, NI_MAXSERV, NI_NUMERICHOST | NI_NAMEREQD);
+    test(sa, sizeof(sin) - 1, NI_MAXHOST, NI_MAXSERV, NI_NUMERICHOST);
+
+    // Buffers that are too small, including for the null byte
+    test(sa, sizeof(sin), 9, 0, NI_NUMERICHOST);
+    test(sa, sizeof(sin), 10, 0, NI_NUMERICHOST);
+    test(sa, sizeof(sin), 0, 2, NI_NUMERICSERV);
+    test(sa, sizeof(sin), 0, 3, NI_NUMERICSERV);
+
+    // Services depend on the protocol
+    sin.sin_port = htons(69);
+    test(sa, sizeof(sin), 0, NI_MAXSERV, NI_DGRAM);
+    sin.sin_port = htons(443);
+    test(sa, sizeof(sin), 0, NI_MAXSERV, 0);
+
+    struct sockaddr_in6 sin6;
+    memset(&sin6, 0, sizeof(sin6));
+    sin6.sin6_family = AF_INET6;
+    sin6.sin6_port = htons(8080);
+    inet_pton(AF_INET6, "2001:db8::1", &sin6.sin6_addr);
+    sa = (const struct sockaddr *)&sin6;
+
+    test(sa, sizeof(sin6), NI_MAXHOST, NI_MAXSERV, NI_NUMERICHOST | NI_NUMERICSERV);
+    test(sa, sizeof(sin), NI_MAXHOST, NI_MAXSERV, NI_NUMERICHOST | NI_NUMERICSERV);
+
+    inet_pton(AF_INET6, "fe80::1", &sin6.sin6_addr);
+    sin6.sin6_scope_id = 42;
+    test(sa, sizeof(sin6), NI_MAXHOST, 0, NI_NUMERICHOST);
+
+    inet_pton(AF_INET6, "::ffff:192.0.2.1", &sin6.sin6_addr);
+    sin6.sin6_scope_id = 0;
+    test(sa, sizeof(sin6), NI_MAXHOST, 0, NI_NUMERICHOST);
+}
@@ -0,0 +1,220 @@
+// Names from a test hosts file, with the order of the hosts file and DNS from a test
+// nsswitch.conf. The only name server is down, so that nothing is found without the hosts file.
+
+#include <arpa/inet.h>
+#include <netdb.h>
+#include <netinet/in.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/socket.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+// Makes relibc read a file in place of one in /etc, which the tests cannot change
+void __relibc_internal_netdb_redirect(const char *file, const char *path);
+
+static const char *error(int code) {
+    switch (code) {
+        case EAI_NONAME: return "EAI_NONAME";
+        case EAI_AGAIN: return "EAI_AGAIN";
+        default: return "other";
+    }
+}
+
+static const char *h_error(int code) {
+    switch (code) {
+        case HOST_NOT_FOUND: return "HOST_NOT_FOUND";
+        case TRY_AGAIN: return "TRY_AGAIN";
+        default: return "other";
+    }
+}
+
+static void print_hostent(const struct hostent *h) {
+    printf("%s (%s)", h->h_name, h->h_addrtype == AF_INET ? "AF_INET" : "AF_INET6");
+    for (char **alias = h->h_aliases; *alias; alias++) {
+        printf(" alias %s", *alias);
+    }
+    for (char **addr = h->h_addr_list; *addr; addr++) {
+        char buf[INET6_ADDRSTRLEN];
+        inet_ntop(h->h_addrtype, *addr, buf, sizeof(buf));
+        printf(" %s", buf);
+    }
+    printf("\n");
+}
+
+static void by_name(const char *name) {
+    struct hostent *h = gethostbyname(name);
+    printf("gethostbyname %s: ", name);
+    if (h == NULL) {
+        printf("%s\n", h_error(h_errno));
+    } else {
+        print_hostent(h);
+    }
+}
+
+static void by_addr(int family, const char *addr) {
+    unsigned char buf[16];
+    inet_pton(family, addr, buf);
+    struct hostent *h = gethostbyaddr(buf, family == AF_INET ? 4 : 16, family);
+    printf("gethostbyaddr %s: ", addr);
+    if (h == NULL) {
+        printf("%s\n", h_error(h_errno));
+    } else {
+        print_hostent(h);
+    }
+}
+
+static void lookup(const char *name, int family, int flags) {
+    struct addrinfo hints, *res;
+    memset(&hints, 0, sizeof(hints));
+    hints.ai_family = family;
+    hints.ai_socktype = SOCK_STREAM;
+    hints.ai_flags = flags;
+
+    int code = getaddrinfo(name, NULL, &hints, &res);
+    printf("getaddrinfo %s:", name);
+    if (code != 0) {
+        printf(" %s\n", error(code));
+        return;
+    }
+    for (struct addrinfo *ai = res; ai; ai = ai->ai_next) {
+        char addr[INET6_ADDRSTRLEN];
+        if (ai->ai_family == AF_INET) {
+            inet_ntop(AF_INET, &((struct sockaddr_in *)ai->ai_addr)->sin_addr, addr, sizeof(addr));
+        } else {
+            inet_ntop(AF_INET6, &((struct sockaddr_in6 *)ai->ai_addr)->sin6_addr, addr, sizeof(addr));
+        }
+        printf(" %s", addr);
+    }
+    printf("\n");
+    freeaddrinfo(res);
+}
+
+static void name_of(int family, const char *addr) {
+    struct sockaddr_storage ss;
+    memset(&ss, 0, sizeof(ss));
+    socklen_t len;
+    if (family == AF_INET) {
+        struct sockaddr_in *sin = (struct sockaddr_in *)&ss;
+        sin->sin_family = AF_INET;
+        inet_pton(AF_INET, addr, &sin->sin_addr);
+        len = sizeof(*sin);
+    } else {
+        struct sockaddr_in6 *sin6 = (struct sockaddr_in6 *)&ss;
+        sin6->sin6_family = AF_INET6;
+        inet_pton(AF_INET6, addr, &sin6->sin6_addr);
+        len = sizeof(*sin6);
+    }
+    char host[NI_MAXHOST];
+    int code = getnameinfo((struct sockaddr *)&ss, len, host, sizeof(host), NULL, 0, NI_NAMEREQD);
+    printf("getnameinfo %s: %s\n", addr, code == 0 ? host : error(code));
+}
+
+static void write_file(char *path, const char *contents) {
+    int fd = mkstemp(path);
+    ERROR_IF(mkstemp, fd, == -1);
+    FILE *f = fdopen(fd, "w");
+    ERROR_IF(fdopen, f, == NULL);
+    fputs(contents, f);
+    fclose(f);
+}
+
+static void set_sources(char *path, const char *contents) {
+    FILE *f = fopen(path, "w");
+    ERROR_IF(fopen, f, == NULL);
+    fputs(contents, f);
+    fclose(f);
+    printf("%s", contents);
+}
+
+int main(void) {
+    char hosts[] = "/tmp/hosts-XXXXXX";
+    write_file(hosts,
+        "# Static hosts\n"
+        "127.0.0.1\tlocalhost\n"
+        "::1\tlocalhost ip6-localhost ip6-loopback\n"
+        "\n"
+        "192.0.2.10\tgateway.example.test gateway gw # the router\n"
+        "192.0.2.11  multi.example.test multi\n"
+        "192.0.2.12  multi.example.test\n"
+        "2001:db8::10 six.example.test six\n"
+        "2001:db8::11 multi.example.test\n"
+        "not-an-address broken\n"
+        "192.0.2.13\n");
+    __relibc_internal_netdb_redirect("/etc/hosts", hosts);
+
+    char nsswitch[] = "/tmp/nsswitch-XXXXXX";
+    write_file(nsswitch, "");
+    __relibc_internal_netdb_redirect("/etc/nsswitch.conf", nsswitch);
+
+    // A port nothing listens on, for a name server that is down
+    int fd = socket(AF_INET, SOCK_DGRAM, 0);
+    ERROR_IF(socket, fd, == -1);
+    struct sockaddr_in sin;
+    memset(&sin, 0, sizeof(sin));
+    sin.sin_family = AF_INET;
+    sin.sin_addr.s_addr = htonl(INADDR_LOOPBACK);
+    int status = bind(fd, (struct sockaddr *)&sin, sizeof(sin));
+    ERROR_IF(bind, status, == -1);
+    socklen_t len = sizeof(sin);
+    status = getsockname(fd, (struct sockaddr *)&sin, &len);
+    ERROR_IF(getsockname, status, == -1);
+    close(fd);
+
+    char conf[] = "/tmp/resolvconf-XXXXXX";
+    char conf_contents[128];
+    snprintf(conf_contents, sizeof(conf_contents),
+        "nameserver [127.0.0.1]:%d\noptions timeout:1 attempts:1\n", ntohs(sin.sin_port));
+    write_file(conf, conf_contents);
+    __relibc_internal_netdb_redirect("/etc/resolv.conf", conf);
+
+    sethostent(1);
+    struct hostent *h;
+    while ((h = gethostent()) != NULL) {
+        printf("gethostent: ");
+        print_hostent(h);
+    }
+    endhostent();
+
+    by_name("GW");
+    by_name("multi.example.test");
+    by_name("six");
+    by_name("192.0.2.99");
+    by_name("broken");
+
+    by_addr(AF_INET, "192.0.2.12");
+    by_addr(AF_INET6, "2001:db8::10");
+    by_addr(AF_INET6, "::ffff:192.0.2.10");
+
+    lookup("LOCALHOST", AF_INET, 0);
+    lookup("localhost", AF_INET6, 0);
+    lookup("multi.example.test", AF_INET, 0);
+    lookup("multi.example.test", AF_INET6, 0);
+    lookup("multi", AF_INET6, 0);
+    lookup("six", AF_UNSPEC, 0);
+    lookup("six", AF_INET, 0);
+    lookup("gw", AF_INET6, AI_V4MAPPED);
+    lookup("gw", AF_INET6, 0);
+    lookup("broken", AF_UNSPEC, 0);
+
+    name_of(AF_INET, "192.0.2.10");
+    name_of(AF_INET6, "2001:db8::10");
+    name_of(AF_INET, "192.0.2.14");
+
+    set_sources(nsswitch, "hosts: dns\n");
+    lookup("gw", AF_INET, 0);
+    by_name("gw");
+    name_of(AF_INET, "192.0.2.10");
+
+    set_sources(nsswitch, "passwd: files\nhosts:dns [NOTFOUND=return] files\n");
+    lookup("gw", AF_INET, 0);
+
+    set_sources(nsswitch, "hosts: mdns4_minimal myhostname\n");
+    lookup("gw", AF_INET, 0);
+
+    unlink(hosts);
+    unlink(nsswitch);
+    unlink(conf);
+}
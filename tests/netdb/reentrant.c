@@ -0,0 +1,169 @@
+// The reentrant netdb functions, with caller buffers that are too small and large enough, and the
+// ones that aren't reentrant from several threads at once, which each get their own results
+
+#include <arpa/inet.h>
+#include <errno.h>
+#include <netdb.h>
+#include <netinet/in.h>
+#include <pthread.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/socket.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+// Makes relibc read a file in place of one in /etc, which the tests cannot change
+void __relibc_internal_netdb_redirect(const char *file, const char *path);
+
+#define THREADS 4
+#define ROUNDS 200
+
+static const char *names[THREADS] = { "alpha", "beta", "gamma", "delta" };
+
+static void print_hostent(const struct hostent *h) {
+    printf("%s (%s)", h->h_name, h->h_addrtype == AF_INET ? "AF_INET" : "AF_INET6");
+    for (char **alias = h->h_aliases; *alias; alias++) {
+        printf(" alias %s", *alias);
+    }
+    for (char **addr = h->h_addr_list; *addr; addr++) {
+        char buf[INET6_ADDRSTRLEN];
+        inet_ntop(h->h_addrtype, *addr, buf, sizeof(buf));
+        printf(" %s", buf);
+    }
+    printf("\n");
+}
+
+static void *resolve(void *arg) {
+    const char *name = arg;
+    char expected[32];
+    snprintf(expected, sizeof(expected), "%s.example.test", name);
+    size_t wrong = 0;
+    for (int i = 0; i < ROUNDS; i++) {
+        struct hostent *h = gethostbyname(name);
+        if (h == NULL || strcmp(h->h_name, expected) != 0) {
+            wrong++;
+        }
+        struct protoent *p = getprotobynumber(6);
+        if (p == NULL || strcmp(p->p_name, "tcp") != 0) {
+            wrong++;
+        }
+        // Another thread's call must not have changed the result
+        if (h == NULL || strcmp(h->h_name, expected) != 0) {
+            wrong++;
+        }
+    }
+    return (void *)wrong;
+}
+
+static void by_name(const char *name, int family, size_t buflen) {
+    struct hostent ret, *result;
+    char *buf = malloc(buflen);
+    int h_err = 0;
+    int status = gethostbyname2_r(name, family, &ret, buf, buflen, &result, &h_err);
+    printf("gethostbyname2_r %s with %zu bytes: %s, h_errno %d, ", name, buflen,
+        status == 0 ? "0" : status == ERANGE ? "ERANGE" : "other", h_err);
+    if (result == NULL) {
+        printf("no result\n");
+    } else {
+        print_hostent(result);
+    }
+    free(buf);
+}
+
+int main(void) {
+    char hosts[] = "/tmp/hosts-XXXXXX";
+    int fd = mkstemp(hosts);
+    ERROR_IF(mkstemp, fd, == -1);
+    FILE *f = fdopen(fd, "w");
+    ERROR_IF(fdopen, f, == NULL);
+    fputs(
+        "192.0.2.1 alpha.example.test alpha\n"
+        "192.0.2.2 beta.example.test beta\n"
+        "192.0.2.3 gamma.example.test gamma\n"
+        "192.0.2.4 delta.example.test delta\n"
+        "192.0.2.5 many.example.test many a-rather-long-alias another-rather-long-alias\n"
+        "192.0.2.6 many.example.test\n"
+        "2001:db8::5 many.example.test many\n",
+        f);
+    fclose(f);
+    __relibc_internal_netdb_redirect("/etc/hosts", hosts);
+
+    char nsswitch[] = "/tmp/nsswitch-XXXXXX";
+    fd = mkstemp(nsswitch);
+    ERROR_IF(mkstemp, fd, == -1);
+    f = fdopen(fd, "w");
+    ERROR_IF(fdopen, f, == NULL);
+    fputs("hosts: files\n", f);
+    fclose(f);
+    __relibc_internal_netdb_redirect("/etc/nsswitch.conf", nsswitch);
+
+    pthread_t threads[THREADS];
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_create(&threads[i], NULL, resolve, (void *)names[i]);
+        ERROR_IF(pthread_create, status, != 0);
+    }
+    for (int i = 0; i < THREADS; i++) {
+        void *wrong;
+        int status = pthread_join(threads[i], &wrong);
+        ERROR_IF(pthread_join, status, != 0);
+        printf("%s: %zu wrong results\n", names[i], (size_t)wrong);
+    }
+
+    by_name("many", AF_INET, 16);
+    by_name("many", AF_INET, 1024);
+    by_name("MANY.example.test", AF_INET6, 1024);
+    by_name("192.0.2.99", AF_INET, 1024);
+    by_name("missing", AF_INET, 1024);
+
+    struct hostent host, *host_result;
+    char buf[1024];
+    int h_err;
+    struct in_addr addr;
+    inet_pton(AF_INET, "192.0.2.3", &addr);
+    int status = gethostbyaddr_r(&addr, sizeof(addr), AF_INET, &host, buf, sizeof(buf), &host_result, &h_err);
+    printf("gethostbyaddr_r 192.0.2.3: %d, ", status);
+    print_hostent(host_result);
+
+    // An entry that doesn't fit is given again with a larger buffer
+    sethostent(1);
+    int count = 0;
+    size_t buflen = 8;
+    while ((status = gethostent_r(&host, buf, buflen, &host_result, &h_err)) != ENOENT) {
+        if (status == ERANGE) {
+            buflen *= 2;
+            continue;
+        }
+        count++;
+    }
+    endhostent();
+    printf("gethostent_r: %d hosts, %s at the end\n", count, host_result == NULL ? "NULL" : "not NULL");
+
+    struct servent serv, *serv_result;
+    status = getservbyname_r("http", "tcp", &serv, buf, 4, &serv_result);
+    printf("getservbyname_r http/tcp with 4 bytes: %s\n", status == ERANGE ? "ERANGE" : "other");
+    status = getservbyname_r("http", "tcp", &serv, buf, sizeof(buf), &serv_result);
+    printf("getservbyname_r http/tcp: %d, %s %d/%s\n", status, serv_result->s_name,
+        ntohs(serv_result->s_port), serv_result->s_proto);
+    status = getservbyport_r(htons(80), "tcp", &serv, buf, sizeof(buf), &serv_result);
+    printf("getservbyport_r 80/tcp: %d, %s\n", status, serv_result->s_name);
+    status = getservbyname_r("no-such-service", NULL, &serv, buf, sizeof(buf), &serv_result);
+    printf("getservbyname_r no-such-service: %d, %s\n", status, serv_result == NULL ? "NULL" : "not NULL");
+
+    struct protoent proto, *proto_result;
+    status = getprotobyname_r("udp", &proto, buf, sizeof(buf), &proto_result);
+    printf("getprotobyname_r udp: %d, %d\n", status, proto_result->p_proto);
+    status = getprotobynumber_r(6, &proto, buf, sizeof(buf), &proto_result);
+    printf("getprotobynumber_r 6: %d, %s\n", status, proto_result->p_name);
+    status = getprotobyname_r("no-such-protocol", &proto, buf, sizeof(buf), &proto_result);
+    printf("getprotobyname_r no-such-protocol: %d, %s\n", status, proto_result == NULL ? "NULL" : "not NULL");
+
+    struct netent net, *net_result;
+    status = getnetbyname_r("no-such-network", &net, buf, sizeof(buf), &net_result, &h_err);
+    printf("getnetbyname_r no-such-network: %d, %s, h_errno %d\n", status,
+        net_result == NULL ? "NULL" : "not NULL", h_err);
+
+    unlink(hosts);
+    unlink(nsswitch);
+}
@@ -0,0 +1,344 @@
+// The resolver, against a fake name server on localhost that a test resolv.conf points at
+
+#include <arpa/inet.h>
+#include <netdb.h>
+#include <netinet/in.h>
+#include <poll.h>
+#include <signal.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <strings.h>
+#include <sys/socket.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+// Makes relibc read a file in place of one in /etc, which the tests cannot change
+void __relibc_internal_netdb_redirect(const char *file, const char *path);
+
+#define TYPE_A 1
+#define TYPE_PTR 12
+#define TYPE_AAAA 28
+
+#define RCODE_SERVFAIL 2
+#define RCODE_NXDOMAIN 3
+
+struct response {
+    unsigned char data[4096];
+    size_t len;
+    uint16_t answers;
+};
+
+// Starts a response to the query in `query`, which is `question_len` bytes up to the end of the
+// question
+static void start(struct response *r, const unsigned char *query, size_t question_len, int rcode, int truncated) {
+    memcpy(r->data, query, question_len);
+    r->data[2] = 0x81 | (truncated ? 0x02 : 0);
+    r->data[3] = 0x80 | rcode;
+    memset(&r->data[6], 0, 6);
+    r->len = question_len;
+    r->answers = 0;
+}
+
+static void answer(struct response *r, int type, const void *data, size_t len) {
+    unsigned char *p = &r->data[r->len];
+    // The name is a pointer to the one in the question
+    unsigned char header[] = {
+        0xc0, 12, 0, type, 0, 1, 0, 0, 0, 60, len >> 8, len & 0xff
+    };
+    memcpy(p, header, sizeof(header));
+    memcpy(p + sizeof(header), data, len);
+    r->len += sizeof(header) + len;
+    r->answers++;
+    r->data[6] = r->answers >> 8;
+    r->data[7] = r->answers & 0xff;
+}
+
+static void answer_a(struct response *r, const char *addr) {
+    struct in_addr in;
+    inet_pton(AF_INET, addr, &in);
+    answer(r, TYPE_A, &in, 4);
+}
+
+// Answers `query`, returning 0 to send no answer. `tcp` is whether it came over TCP.
+static int respond(struct response *r, const unsigned char *query, size_t len, int tcp) {
+    // Read the name in the question
+    char name[256] = "";
+    size_t i = 12;
+    while (i < len && query[i] != 0) {
+        size_t label = query[i];
+        if (name[0]) {
+            strcat(name, ".");
+        }
+        strncat(name, (const char *)&query[i + 1], label);
+        i += label + 1;
+    }
+    int type = (query[i + 1] << 8) | query[i + 2];
+    size_t question_len = i + 5;
+
+    static int slow_queries = 0;
+
+    if (strcasecmp(name, "www.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_A) {
+            answer_a(r, "192.0.2.1");
+        }
+    } else if (strcasecmp(name, "dots.example.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_A) {
+            answer_a(r, "192.0.2.2");
+        }
+    } else if (strcasecmp(name, "dots.example") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_A) {
+            answer_a(r, "192.0.2.7");
+        }
+    } else if (strcasecmp(name, "more.dots.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_A) {
+            answer_a(r, "192.0.2.8");
+        }
+    } else if (strcasecmp(name, "absolute.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        answer_a(r, "192.0.2.9");
+    } else if (strcasecmp(name, "www.other.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_A) {
+            answer_a(r, "192.0.2.5");
+        }
+    } else if (strcasecmp(name, "v6.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        if (type == TYPE_AAAA) {
+            struct in6_addr in6;
+            inet_pton(AF_INET6, "2001:db8::6", &in6);
+            answer(r, TYPE_AAAA, &in6, 16);
+        }
+    } else if (strcasecmp(name, "spoofed.example.test") == 0) {
+        start(r, query, question_len, 0, 0);
+        answer_a(r, "192.0.2.3");
+    } else if (strcasecmp(name, "slow.example.test") == 0) {
+        // The first query goes unanswered
+        if (slow_queries++ == 0) {
+            return 0;
+        }
+        start(r, query, question_len, 0, 0);
+        answer_a(r, "192.0.2.4");
+    } else if (strcasecmp(name, "big.example.test") == 0) {
+        // Too many addresses for UDP
+        start(r, query, question_len, 0, !tcp);
+        if (tcp && type == TYPE_A) {
+            for (int n = 1; n <= 40; n++) {
+                char addr[16];
+                snprintf(addr, sizeof(addr), "198.51.100.%d", n);
+                answer_a(r, addr);
+            }
+        }
+    } else if (strcasecmp(name, "fail.example.test") == 0) {
+        start(r, query, question_len, RCODE_SERVFAIL, 0);
+    } else if (strcasecmp(name, "1.2.0.192.in-addr.arpa") == 0 && type == TYPE_PTR) {
+        start(r, query, question_len, 0, 0);
+        const char ptr[] = "\3www\7example\4test";
+        answer(r, TYPE_PTR, ptr, sizeof(ptr));
+    } else {
+        start(r, query, question_len, RCODE_NXDOMAIN, 0);
+    }
+    return 1;
+}
+
+static void serve(int udp, int tcp, int spoofer) {
+    for (;;) {
+        struct pollfd fds[] = { { udp, POLLIN, 0 }, { tcp, POLLIN, 0 } };
+        if (poll(fds, 2, -1) < 0) {
+            _exit(1);
+        }
+
+        unsigned char query[512];
+        struct response r;
+        if (fds[0].revents & POLLIN) {
+            struct sockaddr_storage from;
+            socklen_t from_len = sizeof(from);
+            ssize_t len = recvfrom(udp, query, sizeof(query), 0, (struct sockaddr *)&from, &from_len);
+            if (len < 12 || !respond(&r, query, len, 0)) {
+                continue;
+            }
+            const struct sockaddr *to = (const struct sockaddr *)&from;
+            if (strncasecmp((const char *)&query[13], "spoofed", 7) == 0) {
+                // Wrong answers come first: from elsewhere, with the wrong ID and to another
+                // question
+                struct response wrong;
+                start(&wrong, query, r.len - 16, 0, 0);
+                answer_a(&wrong, "192.0.2.66");
+                sendto(spoofer, wrong.data, wrong.len, 0, to, from_len);
+                wrong.data[0] ^= 0xff;
+                sendto(udp, wrong.data, wrong.len, 0, to, from_len);
+                wrong.data[0] ^= 0xff;
+                wrong.data[14] = 'x';
+                sendto(udp, wrong.data, wrong.len, 0, to, from_len);
+            }
+            sendto(udp, r.data, r.len, 0, to, from_len);
+        }
+        if (fds[1].revents & POLLIN) {
+            int conn = accept(tcp, NULL, NULL);
+            unsigned char len[2];
+            if (conn < 0 || read(conn, len, 2) != 2) {
+                _exit(1);
+            }
+            size_t query_len = (len[0] << 8) | len[1];
+            size_t got = 0;
+            while (got < query_len) {
+                ssize_t n = read(conn, &query[got], query_len - got);
+                if (n <= 0) {
+                    _exit(1);
+                }
+                got += n;
+            }
+            respond(&r, query, query_len, 1);
+            unsigned char r_len[2] = { r.len >> 8, r.len & 0xff };
+            write(conn, r_len, 2);
+            write(conn, r.data, r.len);
+            close(conn);
+        }
+    }
+}
+
+static const char *error(int code) {
+    switch (code) {
+        case EAI_NONAME: return "EAI_NONAME";
+        case EAI_AGAIN: return "EAI_AGAIN";
+        default: return "other";
+    }
+}
+
+static void lookup(const char *name, int family) {
+    struct addrinfo hints, *res;
+    memset(&hints, 0, sizeof(hints));
+    hints.ai_family = family;
+    hints.ai_socktype = SOCK_STREAM;
+
+    int code = getaddrinfo(name, NULL, &hints, &res);
+    printf("%s:", name);
+    if (code != 0) {
+        printf(" %s\n", error(code));
+        return;
+    }
+    int count = 0;
+    for (struct addrinfo *ai = res; ai; ai = ai->ai_next) {
+        // Only the first few of many addresses
+        if (count++ == 3) {
+            printf(" ...");
+            continue;
+        } else if (count > 3) {
+            continue;
+        }
+        char addr[INET6_ADDRSTRLEN];
+        if (ai->ai_family == AF_INET) {
+            inet_ntop(AF_INET, &((struct sockaddr_in *)ai->ai_addr)->sin_addr, addr, sizeof(addr));
+        } else {
+            inet_ntop(AF_INET6, &((struct sockaddr_in6 *)ai->ai_addr)->sin6_addr, addr, sizeof(addr));
+        }
+        printf(" %s", addr);
+    }
+    printf(" (%d)\n", count);
+    freeaddrinfo(res);
+}
+
+// Binds a socket of `type` to a port on 127.0.0.1, `port` or any if it is 0
+static int bind_socket(int type, in_port_t *port) {
+    int fd = socket(AF_INET, type, 0);
+    ERROR_IF(socket, fd, == -1);
+    struct sockaddr_in sin;
+    memset(&sin, 0, sizeof(sin));
+    sin.sin_family = AF_INET;
+    sin.sin_port = *port;
+    sin.sin_addr.s_addr = htonl(INADDR_LOOPBACK);
+    int status = bind(fd, (struct sockaddr *)&sin, sizeof(sin));
+    ERROR_IF(bind, status, == -1);
+    socklen_t len = sizeof(sin);
+    status = getsockname(fd, (struct sockaddr *)&sin, &len);
+    ERROR_IF(getsockname, status, == -1);
+    *port = sin.sin_port;
+    return fd;
+}
+
+int main(void) {
+    // The server answers on the same port over UDP and TCP
+    in_port_t port = 0;
+    int udp = bind_socket(SOCK_DGRAM, &port);
+    int tcp = bind_socket(SOCK_STREAM, &port);
+    int status = listen(tcp, 8);
+    ERROR_IF(listen, status, == -1);
+    in_port_t spoofer_port = 0;
+    int spoofer = bind_socket(SOCK_DGRAM, &spoofer_port);
+
+    // A port nothing listens on, for a name server that is down
+    in_port_t closed_port = 0;
+    close(bind_socket(SOCK_DGRAM, &closed_port));
+
+    char conf[] = "/tmp/resolvconf-XXXXXX";
+    int fd = mkstemp(conf);
+    ERROR_IF(mkstemp, fd, == -1);
+    FILE *f = fdopen(fd, "w");
+    ERROR_IF(fdopen, f, == NULL);
+    fprintf(f,
+        "# A comment\n"
+        "nameserver [127.0.0.1]:%d\n"
+        "nameserver [127.0.0.1]:%d ; the fake server\n"
+        "domain wrong.test\n"
+        "search example.test\n"
+        "options ndots:1 timeout:1 attempts:2\n",
+        ntohs(closed_port), ntohs(port));
+    fclose(f);
+    __relibc_internal_netdb_redirect("/etc/resolv.conf", conf);
+
+    pid_t pid = fork();
+    ERROR_IF(fork, pid, == -1);
+    if (pid == 0) {
+        serve(udp, tcp, spoofer);
+    }
+    close(udp);
+    close(tcp);
+    close(spoofer);
+
+    // Search domains are tried first for names with fewer dots than ndots, and last otherwise
+    lookup("www", AF_INET);
+    lookup("www.example.test", AF_INET);
+    lookup("dots.example", AF_INET);
+    lookup("more.dots", AF_INET);
+    lookup("absolute.", AF_INET);
+    lookup("missing", AF_INET);
+    lookup("v6", AF_UNSPEC);
+    lookup("v6", AF_INET);
+
+    // LOCALDOMAIN replaces the search list
+    setenv("LOCALDOMAIN", "other.test", 1);
+    lookup("www", AF_INET);
+    unsetenv("LOCALDOMAIN");
+
+    // RES_OPTIONS adds options, here so that names with a dot are tried as they are last
+    setenv("RES_OPTIONS", "ndots:3", 1);
+    lookup("dots.example", AF_INET);
+    unsetenv("RES_OPTIONS");
+
+    // Answers with the wrong ID or question, or from elsewhere, are left out
+    lookup("spoofed", AF_INET);
+    // Queries are sent again after the timeout
+    lookup("slow", AF_INET);
+    // Truncated answers are asked for again over TCP
+    lookup("big", AF_INET);
+    lookup("fail", AF_INET);
+
+    struct sockaddr_in sin;
+    memset(&sin, 0, sizeof(sin));
+    sin.sin_family = AF_INET;
+    inet_pton(AF_INET, "192.0.2.1", &sin.sin_addr);
+    char host[NI_MAXHOST];
+    status = getnameinfo((struct sockaddr *)&sin, sizeof(sin), host, sizeof(host), NULL, 0, NI_NAMEREQD);
+    printf("192.0.2.1: %s\n", status == 0 ? host : error(status));
+
+    kill(pid, SIGKILL);
+    waitpid(pid, NULL, 0);
+    unlink(conf);
+}
@@ -0,0 +1,81 @@
+#include <errno.h>
+#include <locale.h>
+#include <nl_types.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+static const char *error(void) {
+    switch (errno) {
+        case ENOENT: return "ENOENT";
+        case ENOMSG: return "ENOMSG";
+        case EBADF: return "EBADF";
+        default: return strerror(errno);
+    }
+}
+
+static void get(nl_catd catd, int set_id, int msg_id, const char *s) {
+    errno = 0;
+    char *message = catgets(catd, set_id, msg_id, s);
+    printf("%d.%d: %s", set_id, msg_id, message);
+    if (message == s) {
+        printf(" (%s)", error());
+    }
+    putchar('\n');
+}
+
+int main(void) {
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+    char *locale = setlocale(LC_ALL, "de_DE.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+
+    // The catalog is found through the language, as there is none for the whole locale name
+    status = setenv("NLSPATH", "messages/%L/%N.cat:messages/%l/%N.cat", 1);
+    ERROR_IF(setenv, status, == -1);
+    nl_catd catd = catopen("test", NL_CAT_LOCALE);
+    ERROR_IF(catopen, catd, == (nl_catd) -1);
+    get(catd, 1, 1, "Hello, world!");
+    get(catd, 1, 2, "File not found");
+    get(catd, 2, 1, "Second set");
+    get(catd, 2, 7, "Seventh message");
+    get(catd, 1, 3, "Missing message");
+    get(catd, 3, 1, "Missing set");
+    status = catclose(catd);
+    ERROR_IF(catclose, status, == -1);
+
+    // Without NL_CAT_LOCALE, the locale is taken from LANG
+    status = setenv("LANG", "de_AT", 1);
+    ERROR_IF(setenv, status, == -1);
+    catd = catopen("test", 0);
+    ERROR_IF(catopen, catd, == (nl_catd) -1);
+    get(catd, 1, 1, "Hello, world!");
+    catclose(catd);
+
+    status = unsetenv("LANG");
+    ERROR_IF(unsetenv, status, == -1);
+    errno = 0;
+    catd = catopen("test", 0);
+    printf("catopen without LANG: %s\n",
+        catd == (nl_catd) -1 ? error() : "found");
+
+    // Names with a slash are paths, and NLSPATH isn't used for them
+    catd = catopen("messages/de/test.cat", 0);
+    ERROR_IF(catopen, catd, == (nl_catd) -1);
+    get(catd, 2, 1, "Second set");
+    catclose(catd);
+
+    errno = 0;
+    catd = catopen("missing", NL_CAT_LOCALE);
+    printf("catopen of a missing catalog: %s\n",
+        catd == (nl_catd) -1 ? error() : "found");
+
+    printf("catgets of an invalid catalog: %s\n",
+        catgets((nl_catd) -1, 1, 1, "Hello, world!"));
+    errno = 0;
+    status = catclose((nl_catd) -1);
+    printf("catclose of an invalid catalog: %d (%s)\n", status, error());
+}
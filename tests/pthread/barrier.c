@@ -0,0 +1,63 @@
+#include <pthread.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "test_helpers.h"
+
+#define THREADS 4
+#define ROUNDS 3
+
+static pthread_barrier_t barrier;
+static pthread_spinlock_t spinlock;
+static int arrived[ROUNDS];
+static int serial[ROUNDS];
+
+static void *worker(void *arg) {
+    (void) arg;
+    for (int round = 0; round < ROUNDS; round++) {
+        pthread_spin_lock(&spinlock);
+        arrived[round]++;
+        pthread_spin_unlock(&spinlock);
+
+        int status = pthread_barrier_wait(&barrier);
+        if (status == PTHREAD_BARRIER_SERIAL_THREAD) {
+            // Everybody must have arrived by the time anyone leaves
+            serial[round] = arrived[round];
+        } else {
+            ERROR_IF(pthread_barrier_wait, status, != 0);
+        }
+    }
+    return NULL;
+}
+
+int main(void) {
+    printf("Zero count rejected: %d\n", pthread_barrier_init(&barrier, NULL, 0) == EINVAL);
+
+    int status = pthread_barrier_init(&barrier, NULL, THREADS);
+    ERROR_IF(pthread_barrier_init, status, != 0);
+    status = pthread_spin_init(&spinlock, PTHREAD_PROCESS_PRIVATE);
+    ERROR_IF(pthread_spin_init, status, != 0);
+
+    pthread_t threads[THREADS];
+    for (int i = 0; i < THREADS; i++) {
+        status = pthread_create(&threads[i], NULL, worker, NULL);
+        ERROR_IF(pthread_create, status, != 0);
+    }
+    for (int i = 0; i < THREADS; i++) {
+        status = pthread_join(threads[i], NULL);
+        ERROR_IF(pthread_join, status, != 0);
+    }
+
+    for (int round = 0; round < ROUNDS; round++) {
+        printf("Round %d: %d threads arrived before the serial thread left\n", round, serial[round]);
+    }
+
+    printf("trylock: %d\n", pthread_spin_trylock(&spinlock));
+    printf("trylock while locked fails: %d\n", pthread_spin_trylock(&spinlock) == EBUSY);
+    pthread_spin_unlock(&spinlock);
+
+    pthread_spin_destroy(&spinlock);
+    pthread_barrier_destroy(&barrier);
+
+    return EXIT_SUCCESS;
+}
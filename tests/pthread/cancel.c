@@ -0,0 +1,199 @@
+#include <poll.h>
+#include <pthread.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+static pthread_mutex_t lock = PTHREAD_MUTEX_INITIALIZER;
+static pthread_cond_t cond = PTHREAD_COND_INITIALIZER;
+static int started = 0;
+
+static void cleanup(void *arg) {
+    printf("Cleanup handler %d\n", (int) (intptr_t) arg);
+}
+
+static void unlock(void *arg) {
+    pthread_mutex_unlock(arg);
+    puts("Mutex unlocked by cleanup handler");
+}
+
+static void *popping(void *arg) {
+    (void) arg;
+    pthread_cleanup_push(cleanup, (void *) 1);
+    pthread_cleanup_push(cleanup, (void *) 2);
+    pthread_cleanup_pop(0);
+    pthread_cleanup_pop(1);
+    return NULL;
+}
+
+static void *exiting(void *arg) {
+    (void) arg;
+    pthread_cleanup_push(cleanup, (void *) 3);
+    pthread_cleanup_push(cleanup, (void *) 4);
+    pthread_exit(NULL);
+    pthread_cleanup_pop(0);
+    pthread_cleanup_pop(0);
+    return NULL;
+}
+
+static void *waiting(void *arg) {
+    (void) arg;
+    pthread_mutex_lock(&lock);
+    started = 1;
+    pthread_cond_signal(&cond);
+
+    // Waiting on a condition variable is a cancellation point, after which the mutex is held
+    pthread_cleanup_push(unlock, &lock);
+    for (;;) {
+        pthread_cond_wait(&cond, &lock);
+    }
+    pthread_cleanup_pop(1);
+    return NULL;
+}
+
+static void *reading(void *arg) {
+    int *fds = arg;
+    pthread_mutex_lock(&lock);
+    started = 1;
+    pthread_cond_signal(&cond);
+    pthread_mutex_unlock(&lock);
+
+    // Nothing is ever written, so only cancellation can end the read
+    char c;
+    pthread_cleanup_push(cleanup, (void *) 5);
+    ssize_t n = read(fds[0], &c, 1);
+    printf("read returned %zd\n", n);
+    pthread_cleanup_pop(0);
+    return NULL;
+}
+
+static void *polling(void *arg) {
+    int *fds = arg;
+    pthread_mutex_lock(&lock);
+    started = 1;
+    pthread_cond_signal(&cond);
+    pthread_mutex_unlock(&lock);
+
+    struct pollfd pfd = { .fd = fds[0], .events = POLLIN };
+    int n = poll(&pfd, 1, -1);
+    printf("poll returned %d\n", n);
+    return NULL;
+}
+
+static void *disabled(void *arg) {
+    (void) arg;
+    int oldstate;
+    pthread_setcancelstate(PTHREAD_CANCEL_DISABLE, &oldstate);
+
+    pthread_mutex_lock(&lock);
+    started = 1;
+    pthread_cond_signal(&cond);
+    pthread_mutex_unlock(&lock);
+
+    // Wait for the cancellation request, which must stay pending
+    usleep(100000);
+    pthread_testcancel();
+    puts("Still running with cancellation disabled");
+
+    pthread_setcancelstate(oldstate, NULL);
+    pthread_testcancel();
+    puts("Cancellation was not acted upon");
+    return NULL;
+}
+
+static void *disabled_reading(void *arg) {
+    int *fds = arg;
+    int oldstate;
+    pthread_setcancelstate(PTHREAD_CANCEL_DISABLE, &oldstate);
+
+    pthread_mutex_lock(&lock);
+    started = 1;
+    pthread_cond_signal(&cond);
+    pthread_mutex_unlock(&lock);
+
+    // The request must neither end nor interrupt the read, which returns once a byte is written
+    char c;
+    ssize_t n = read(fds[0], &c, 1);
+    printf("read with cancellation disabled returned %zd\n", n);
+
+    pthread_setcancelstate(oldstate, NULL);
+    pthread_testcancel();
+    puts("Cancellation was not acted upon");
+    return NULL;
+}
+
+static void start_and_cancel(void *(*start_routine)(void *), void *arg, int wake_fd) {
+    pthread_t thread;
+    void *retval;
+
+    started = 0;
+    int status = pthread_create(&thread, NULL, start_routine, arg);
+    ERROR_IF(pthread_create, status, != 0);
+
+    pthread_mutex_lock(&lock);
+    while (!started) {
+        pthread_cond_wait(&cond, &lock);
+    }
+    pthread_mutex_unlock(&lock);
+
+    // Give the thread time to block
+    usleep(10000);
+
+    status = pthread_cancel(thread);
+    ERROR_IF(pthread_cancel, status, != 0);
+    if (wake_fd != -1) {
+        usleep(10000);
+        ssize_t n = write(wake_fd, "x", 1);
+        ERROR_IF(write, n, == -1);
+    }
+    status = pthread_join(thread, &retval);
+    ERROR_IF(pthread_join, status, != 0);
+    printf("Thread was canceled: %d\n", retval == PTHREAD_CANCELED);
+}
+
+int main(void) {
+    pthread_t thread;
+    int status;
+
+    status = pthread_create(&thread, NULL, popping, NULL);
+    ERROR_IF(pthread_create, status, != 0);
+    status = pthread_join(thread, NULL);
+    ERROR_IF(pthread_join, status, != 0);
+
+    status = pthread_create(&thread, NULL, exiting, NULL);
+    ERROR_IF(pthread_create, status, != 0);
+    status = pthread_join(thread, NULL);
+    ERROR_IF(pthread_join, status, != 0);
+
+    start_and_cancel(waiting, NULL, -1);
+
+    int fds[2];
+    status = pipe(fds);
+    ERROR_IF(pipe, status, == -1);
+    start_and_cancel(reading, fds, -1);
+
+    // Whatever poll opens is closed again when it is canceled
+    int before = dup(0);
+    ERROR_IF(dup, before, == -1);
+    close(before);
+    start_and_cancel(polling, fds, -1);
+    int after = dup(0);
+    ERROR_IF(dup, after, == -1);
+    close(after);
+    printf("No file leaked by poll: %d\n", before == after);
+    close(fds[0]);
+    close(fds[1]);
+
+    start_and_cancel(disabled, NULL, -1);
+
+    status = pipe(fds);
+    ERROR_IF(pipe, status, == -1);
+    start_and_cancel(disabled_reading, fds, fds[1]);
+    close(fds[0]);
+    close(fds[1]);
+
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,73 @@
+#include <pthread.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+#define THREADS 4
+
+static pthread_mutex_t lock = PTHREAD_MUTEX_INITIALIZER;
+static pthread_cond_t cond = PTHREAD_COND_INITIALIZER;
+static int ready = 0;
+static int woken = 0;
+
+static void *waiter(void *arg) {
+    (void) arg;
+    pthread_mutex_lock(&lock);
+    while (!ready) {
+        int status = pthread_cond_wait(&cond, &lock);
+        ERROR_IF(pthread_cond_wait, status, != 0);
+    }
+    woken++;
+    pthread_mutex_unlock(&lock);
+    return NULL;
+}
+
+int main(void) {
+    pthread_t threads[THREADS];
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_create(&threads[i], NULL, waiter, NULL);
+        ERROR_IF(pthread_create, status, != 0);
+    }
+
+    pthread_mutex_lock(&lock);
+    ready = 1;
+    pthread_cond_broadcast(&cond);
+    pthread_mutex_unlock(&lock);
+
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_join(threads[i], NULL);
+        ERROR_IF(pthread_join, status, != 0);
+    }
+    printf("Woken: %d\n", woken);
+
+    // Nobody signals, so this has to time out
+    pthread_condattr_t attr;
+    pthread_cond_t timed;
+    clockid_t clock;
+    pthread_condattr_init(&attr);
+    pthread_condattr_setclock(&attr, CLOCK_MONOTONIC);
+    pthread_condattr_getclock(&attr, &clock);
+    printf("Clock is monotonic: %d\n", clock == CLOCK_MONOTONIC);
+    pthread_cond_init(&timed, &attr);
+    pthread_condattr_destroy(&attr);
+
+    struct timespec deadline;
+    clock_gettime(CLOCK_MONOTONIC, &deadline);
+    deadline.tv_nsec += 10000000;
+    if (deadline.tv_nsec >= 1000000000) {
+        deadline.tv_sec += 1;
+        deadline.tv_nsec -= 1000000000;
+    }
+
+    pthread_mutex_lock(&lock);
+    int status = pthread_cond_timedwait(&timed, &lock, &deadline);
+    printf("Timed out: %d\n", status == ETIMEDOUT);
+    printf("Mutex held after timeout: %d\n", pthread_mutex_trylock(&lock) == EBUSY);
+    pthread_mutex_unlock(&lock);
+
+    pthread_cond_destroy(&timed);
+
+    return EXIT_SUCCESS;
+}
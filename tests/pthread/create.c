@@ -0,0 +1,97 @@
+#include <pthread.h>
+#include <sched.h>
+#include <signal.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "test_helpers.h"
+
+static void *thread_main(void *arg) {
+    intptr_t value = (intptr_t) arg;
+    printf("Thread %d running\n", (int) value);
+    return (void *) (value * 2);
+}
+
+static pthread_mutex_t lock = PTHREAD_MUTEX_INITIALIZER;
+
+static void *blocked_main(void *arg) {
+    pthread_mutex_lock(&lock);
+    pthread_mutex_unlock(&lock);
+    return arg;
+}
+
+static void *exiting_main(void *arg) {
+    pthread_exit(arg);
+    puts("pthread_exit returned");
+    return NULL;
+}
+
+int main(void) {
+    pthread_t thread;
+    void *retval;
+
+    int status = pthread_create(&thread, NULL, thread_main, (void *) 21);
+    ERROR_IF(pthread_create, status, != 0);
+    status = pthread_join(thread, &retval);
+    ERROR_IF(pthread_join, status, != 0);
+    printf("Thread returned %d\n", (int) (intptr_t) retval);
+
+    status = pthread_create(&thread, NULL, exiting_main, (void *) 7);
+    ERROR_IF(pthread_create, status, != 0);
+    status = pthread_join(thread, &retval);
+    ERROR_IF(pthread_join, status, != 0);
+    printf("Thread exited with %d\n", (int) (intptr_t) retval);
+
+    // The new thread can be addressed as soon as pthread_create returns
+    pthread_mutex_lock(&lock);
+    status = pthread_create(&thread, NULL, blocked_main, NULL);
+    ERROR_IF(pthread_create, status, != 0);
+    status = pthread_kill(thread, 0);
+    ERROR_IF(pthread_kill, status, != 0);
+    int policy;
+    struct sched_param param;
+    status = pthread_getschedparam(thread, &policy, &param);
+    ERROR_IF(pthread_getschedparam, status, != 0);
+    printf("New thread policy is SCHED_OTHER: %d\n", policy == SCHED_OTHER);
+    pthread_mutex_unlock(&lock);
+    status = pthread_join(thread, NULL);
+    ERROR_IF(pthread_join, status, != 0);
+
+    pthread_attr_t attr;
+    status = pthread_attr_init(&attr);
+    ERROR_IF(pthread_attr_init, status, != 0);
+
+    size_t stacksize;
+    status = pthread_attr_setstacksize(&attr, 256 * 1024);
+    ERROR_IF(pthread_attr_setstacksize, status, != 0);
+    status = pthread_attr_getstacksize(&attr, &stacksize);
+    ERROR_IF(pthread_attr_getstacksize, status, != 0);
+    printf("Stack size: %zu\n", stacksize);
+
+    status = pthread_attr_setstacksize(&attr, 1);
+    printf("Too small stack size rejected: %d\n", status == EINVAL);
+
+    int detachstate;
+    status = pthread_attr_setdetachstate(&attr, PTHREAD_CREATE_DETACHED);
+    ERROR_IF(pthread_attr_setdetachstate, status, != 0);
+    status = pthread_attr_getdetachstate(&attr, &detachstate);
+    ERROR_IF(pthread_attr_getdetachstate, status, != 0);
+    printf("Detached: %d\n", detachstate == PTHREAD_CREATE_DETACHED);
+
+    status = pthread_attr_setdetachstate(&attr, PTHREAD_CREATE_JOINABLE);
+    ERROR_IF(pthread_attr_setdetachstate, status, != 0);
+    status = pthread_create(&thread, &attr, thread_main, (void *) 3);
+    ERROR_IF(pthread_create, status, != 0);
+    status = pthread_join(thread, &retval);
+    ERROR_IF(pthread_join, status, != 0);
+    printf("Thread with attributes returned %d\n", (int) (intptr_t) retval);
+
+    status = pthread_attr_destroy(&attr);
+    ERROR_IF(pthread_attr_destroy, status, != 0);
+
+    printf("Joining self fails: %d\n", pthread_join(pthread_self(), NULL) == EDEADLK);
+    printf("Self is equal to self: %d\n", pthread_equal(pthread_self(), pthread_self()) != 0);
+
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,68 @@
+#include <pthread.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "test_helpers.h"
+
+#define THREADS 4
+#define ITERATIONS 10000
+
+static pthread_mutex_t counter_lock = PTHREAD_MUTEX_INITIALIZER;
+static int counter = 0;
+
+static void *increment(void *arg) {
+    (void) arg;
+    for (int i = 0; i < ITERATIONS; i++) {
+        int status = pthread_mutex_lock(&counter_lock);
+        ERROR_IF(pthread_mutex_lock, status, != 0);
+        counter++;
+        status = pthread_mutex_unlock(&counter_lock);
+        ERROR_IF(pthread_mutex_unlock, status, != 0);
+    }
+    return NULL;
+}
+
+int main(void) {
+    pthread_t threads[THREADS];
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_create(&threads[i], NULL, increment, NULL);
+        ERROR_IF(pthread_create, status, != 0);
+    }
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_join(threads[i], NULL);
+        ERROR_IF(pthread_join, status, != 0);
+    }
+    printf("Counter: %d\n", counter);
+
+    printf("trylock on unlocked mutex: %d\n", pthread_mutex_trylock(&counter_lock));
+    printf("trylock on locked mutex: %d\n", pthread_mutex_trylock(&counter_lock) == EBUSY);
+    pthread_mutex_unlock(&counter_lock);
+
+    pthread_mutexattr_t attr;
+    pthread_mutex_t mutex;
+    int type;
+
+    pthread_mutexattr_init(&attr);
+    pthread_mutexattr_settype(&attr, PTHREAD_MUTEX_RECURSIVE);
+    pthread_mutexattr_gettype(&attr, &type);
+    printf("Recursive type: %d\n", type == PTHREAD_MUTEX_RECURSIVE);
+    pthread_mutex_init(&mutex, &attr);
+    printf("Recursive lock: %d\n", pthread_mutex_lock(&mutex));
+    printf("Recursive relock: %d\n", pthread_mutex_lock(&mutex));
+    printf("Recursive unlock: %d\n", pthread_mutex_unlock(&mutex));
+    printf("Recursive unlock: %d\n", pthread_mutex_unlock(&mutex));
+    printf("Recursive extra unlock fails: %d\n", pthread_mutex_unlock(&mutex) == EPERM);
+    pthread_mutex_destroy(&mutex);
+
+    pthread_mutexattr_settype(&attr, PTHREAD_MUTEX_ERRORCHECK);
+    pthread_mutex_init(&mutex, &attr);
+    printf("Errorcheck lock: %d\n", pthread_mutex_lock(&mutex));
+    printf("Errorcheck relock fails: %d\n", pthread_mutex_lock(&mutex) == EDEADLK);
+    printf("Errorcheck unlock: %d\n", pthread_mutex_unlock(&mutex));
+    printf("Errorcheck extra unlock fails: %d\n", pthread_mutex_unlock(&mutex) == EPERM);
+    pthread_mutex_destroy(&mutex);
+
+    pthread_mutexattr_destroy(&attr);
+
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,38 @@
+#include <pthread.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "test_helpers.h"
+
+#define THREADS 4
+
+static pthread_once_t once = PTHREAD_ONCE_INIT;
+static int calls = 0;
+
+static void init(void) {
+    calls++;
+}
+
+static void *worker(void *arg) {
+    (void) arg;
+    int status = pthread_once(&once, init);
+    ERROR_IF(pthread_once, status, != 0);
+    return NULL;
+}
+
+int main(void) {
+    pthread_t threads[THREADS];
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_create(&threads[i], NULL, worker, NULL);
+        ERROR_IF(pthread_create, status, != 0);
+    }
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_join(threads[i], NULL);
+        ERROR_IF(pthread_join, status, != 0);
+    }
+    worker(NULL);
+
+    printf("Constructor calls: %d\n", calls);
+
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,59 @@
+#include <pthread.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "test_helpers.h"
+
+static pthread_key_t key;
+static pthread_mutex_t lock = PTHREAD_MUTEX_INITIALIZER;
+static int destructor_calls = 0;
+
+static void destructor(void *value) {
+    pthread_mutex_lock(&lock);
+    destructor_calls++;
+    pthread_mutex_unlock(&lock);
+
+    // Setting a value again means the destructor is called in the next iteration
+    if ((intptr_t) value == 1) {
+        pthread_setspecific(key, (void *) 2);
+    }
+}
+
+static void *worker(void *arg) {
+    printf("Initial value in thread: %p\n", pthread_getspecific(key));
+    int status = pthread_setspecific(key, arg);
+    ERROR_IF(pthread_setspecific, status, != 0);
+    return pthread_getspecific(key);
+}
+
+int main(void) {
+    int status = pthread_key_create(&key, destructor);
+    ERROR_IF(pthread_key_create, status, != 0);
+
+    status = pthread_setspecific(key, (void *) 42);
+    ERROR_IF(pthread_setspecific, status, != 0);
+
+    pthread_t thread;
+    void *retval;
+    status = pthread_create(&thread, NULL, worker, (void *) 1);
+    ERROR_IF(pthread_create, status, != 0);
+    status = pthread_join(thread, &retval);
+    ERROR_IF(pthread_join, status, != 0);
+
+    printf("Value in thread: %d\n", (int) (intptr_t) retval);
+    printf("Value in main thread: %d\n", (int) (intptr_t) pthread_getspecific(key));
+    printf("Destructor calls: %d\n", destructor_calls);
+
+    status = pthread_key_delete(key);
+    ERROR_IF(pthread_key_delete, status, != 0);
+    printf("Deleting twice fails: %d\n", pthread_key_delete(key) == EINVAL);
+
+    // A new key never sees the values of a deleted one
+    status = pthread_key_create(&key, NULL);
+    ERROR_IF(pthread_key_create, status, != 0);
+    printf("Value of new key: %p\n", pthread_getspecific(key));
+    pthread_key_delete(key);
+
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,138 @@
+#include <errno.h>
+#include <fcntl.h>
+#include <limits.h>
+#include <pthread.h>
+#include <semaphore.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/mman.h>
+#include <sys/wait.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+#define NAME "/relibc-test-semaphore"
+
+void *poster(void *arg) {
+    struct timespec ts = { 0, 10000000 };
+    nanosleep(&ts, NULL);
+    int status = sem_post(arg);
+    ERROR_IF(sem_post, status, == -1);
+    return NULL;
+}
+
+int value(sem_t *sem) {
+    int sval;
+    int status = sem_getvalue(sem, &sval);
+    ERROR_IF(sem_getvalue, status, == -1);
+    return sval;
+}
+
+int main(void) {
+    // Unnamed
+    sem_t sem;
+    int status = sem_init(&sem, 0, 2);
+    ERROR_IF(sem_init, status, == -1);
+    status = sem_trywait(&sem);
+    ERROR_IF(sem_trywait, status, == -1);
+    status = sem_wait(&sem);
+    ERROR_IF(sem_wait, status, == -1);
+    status = sem_trywait(&sem);
+    printf("trywait: %d, %s, value %d\n", status, strerror(errno), value(&sem));
+
+    status = sem_post(&sem);
+    ERROR_IF(sem_post, status, == -1);
+    printf("posted: value %d\n", value(&sem));
+
+    status = sem_wait(&sem);
+    ERROR_IF(sem_wait, status, == -1);
+    struct timespec deadline = { 0, 1000000000 };
+    status = sem_timedwait(&sem, &deadline);
+    printf("timedwait invalid: %d, %s\n", status, strerror(errno));
+
+    status = clock_gettime(CLOCK_REALTIME, &deadline);
+    ERROR_IF(clock_gettime, status, == -1);
+    deadline.tv_nsec += 20000000;
+    if (deadline.tv_nsec >= 1000000000) {
+        deadline.tv_sec++;
+        deadline.tv_nsec -= 1000000000;
+    }
+    status = sem_timedwait(&sem, &deadline);
+    printf("timedwait expired: %d, %s\n", status, strerror(errno));
+
+    pthread_t thread;
+    status = pthread_create(&thread, NULL, poster, &sem);
+    ERROR_IF(pthread_create, status, != 0);
+    status = sem_wait(&sem);
+    ERROR_IF(sem_wait, status, == -1);
+    printf("woken by thread: value %d\n", value(&sem));
+    pthread_join(thread, NULL);
+
+    status = sem_destroy(&sem);
+    ERROR_IF(sem_destroy, status, == -1);
+    status = sem_init(&sem, 0, (unsigned) SEM_VALUE_MAX + 1);
+    printf("too large: %d, %s\n", status, strerror(errno));
+
+    // Unnamed, shared with a child process
+    sem_t *shared = mmap(NULL, sizeof(sem_t), PROT_READ | PROT_WRITE,
+        MAP_SHARED | MAP_ANONYMOUS, -1, 0);
+    ERROR_IF(mmap, shared, == MAP_FAILED);
+    status = sem_init(shared, 1, 0);
+    ERROR_IF(sem_init, status, == -1);
+    pid_t pid = fork();
+    ERROR_IF(fork, pid, == -1);
+    if (pid == 0) {
+        sem_post(shared);
+        _exit(0);
+    }
+    status = sem_wait(shared);
+    ERROR_IF(sem_wait, status, == -1);
+    printf("woken by process: value %d\n", value(shared));
+    waitpid(pid, NULL, 0);
+    sem_destroy(shared);
+    munmap(shared, sizeof(sem_t));
+
+    // Named
+    sem_unlink(NAME);
+    sem_t *named = sem_open(NAME, O_CREAT | O_EXCL, 0600, 1);
+    ERROR_IF(sem_open, named, == SEM_FAILED);
+    sem_t *again = sem_open(NAME, 0);
+    ERROR_IF(sem_open, again, == SEM_FAILED);
+    printf("same address: %d, value %d\n", named == again, value(again));
+    sem_t *exclusive = sem_open(NAME, O_CREAT | O_EXCL, 0600, 1);
+    printf("exclusive: %d, %s\n", exclusive == SEM_FAILED, strerror(errno));
+
+    status = sem_wait(named);
+    ERROR_IF(sem_wait, status, == -1);
+    pid = fork();
+    ERROR_IF(fork, pid, == -1);
+    if (pid == 0) {
+        sem_t *child = sem_open(NAME, O_CREAT, 0600, 5);
+        if (child == SEM_FAILED) {
+            _exit(1);
+        }
+        for (int i = 0; i < 3; i++) {
+            sem_post(child);
+        }
+        sem_close(child);
+        _exit(0);
+    }
+    for (int i = 0; i < 3; i++) {
+        status = sem_wait(named);
+        ERROR_IF(sem_wait, status, == -1);
+    }
+    int child_status;
+    waitpid(pid, &child_status, 0);
+    printf("woken by named: child %d, value %d\n", WEXITSTATUS(child_status), value(named));
+
+    status = sem_close(again);
+    ERROR_IF(sem_close, status, == -1);
+    printf("still open: value %d\n", value(named));
+    status = sem_close(named);
+    ERROR_IF(sem_close, status, == -1);
+    status = sem_unlink(NAME);
+    ERROR_IF(sem_unlink, status, == -1);
+    named = sem_open(NAME, 0);
+    printf("unlinked: %d, %s\n", named == SEM_FAILED, strerror(errno));
+}
@@ -0,0 +1,85 @@
+#include <errno.h>
+#include <signal.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+volatile sig_atomic_t handled = 0;
+
+void handler(int sig) {
+    handled++;
+}
+
+int blocked(int sig) {
+    sigset_t set;
+    int status = sigprocmask(SIG_BLOCK, NULL, &set);
+    ERROR_IF(sigprocmask, status, == -1);
+    return sigismember(&set, sig);
+}
+
+int restarts(int sig) {
+    struct sigaction sa;
+    int status = sigaction(sig, NULL, &sa);
+    ERROR_IF(sigaction, status, == -1);
+    return (sa.sa_flags & SA_RESTART) != 0;
+}
+
+int main(void) {
+    void (*old)(int) = signal(SIGUSR1, handler);
+    ERROR_IF(signal, old, == SIG_ERR);
+
+    // A held signal waits until it is released
+    int status = sighold(SIGUSR1);
+    ERROR_IF(sighold, status, == -1);
+    raise(SIGUSR1);
+    printf("held: handled %d, blocked %d\n", handled, blocked(SIGUSR1));
+    status = sigrelse(SIGUSR1);
+    ERROR_IF(sigrelse, status, == -1);
+    printf("released: handled %d, blocked %d\n", handled, blocked(SIGUSR1));
+
+    // Suspending with a mask that lets the pending signal through returns once it is handled,
+    // with the old mask back in place
+    handled = 0;
+    sighold(SIGUSR1);
+    raise(SIGUSR1);
+    sigset_t mask;
+    sigemptyset(&mask);
+    status = sigsuspend(&mask);
+    printf("sigsuspend: %d, %s, handled %d, blocked %d\n",
+        status, strerror(errno), handled, blocked(SIGUSR1));
+
+    handled = 0;
+    raise(SIGUSR1);
+    status = sigpause(SIGUSR1);
+    printf("sigpause: %d, %s, handled %d, blocked %d\n",
+        status, strerror(errno), handled, blocked(SIGUSR1));
+    sigrelse(SIGUSR1);
+
+    // System V dispositions
+    old = sigset(SIGUSR2, SIG_HOLD);
+    printf("sigset hold: was default %d, blocked %d\n", old == SIG_DFL, blocked(SIGUSR2));
+    old = sigset(SIGUSR2, handler);
+    printf("sigset handler: was held %d, blocked %d\n", old == SIG_HOLD, blocked(SIGUSR2));
+    handled = 0;
+    raise(SIGUSR2);
+    printf("sigset handled: %d\n", handled);
+    old = sigset(SIGUSR2, SIG_DFL);
+    printf("sigset default: was handler %d\n", old == handler);
+
+    status = sigignore(SIGUSR2);
+    ERROR_IF(sigignore, status, == -1);
+    raise(SIGUSR2);
+    old = signal(SIGUSR2, SIG_DFL);
+    printf("sigignore: ignored %d\n", old == SIG_IGN);
+
+    status = siginterrupt(SIGUSR1, 1);
+    ERROR_IF(siginterrupt, status, == -1);
+    printf("siginterrupt 1: restarts %d\n", restarts(SIGUSR1));
+    status = siginterrupt(SIGUSR1, 0);
+    ERROR_IF(siginterrupt, status, == -1);
+    printf("siginterrupt 0: restarts %d\n", restarts(SIGUSR1));
+
+    status = sighold(0);
+    printf("invalid: %d, %s\n", status, strerror(errno));
+}
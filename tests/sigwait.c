@@ -0,0 +1,58 @@
+#include <errno.h>
+#include <signal.h>
+#include <stdio.h>
+#include <string.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    sigset_t set;
+    sigemptyset(&set);
+    sigaddset(&set, SIGUSR1);
+    sigaddset(&set, SIGUSR2);
+    int status = sigprocmask(SIG_BLOCK, &set, NULL);
+    ERROR_IF(sigprocmask, status, == -1);
+
+    // Blocked signals stay pending until they are waited for
+    status = raise(SIGUSR1);
+    ERROR_IF(raise, status, == -1);
+    sigset_t pending;
+    status = sigpending(&pending);
+    ERROR_IF(sigpending, status, == -1);
+    printf("pending: SIGUSR1 %d, SIGUSR2 %d\n",
+        sigismember(&pending, SIGUSR1), sigismember(&pending, SIGUSR2));
+
+    int sig;
+    status = sigwait(&set, &sig);
+    ERROR_IF(sigwait, status, != 0);
+    printf("sigwait: %s\n", sig == SIGUSR1 ? "SIGUSR1" : "other");
+    status = sigpending(&pending);
+    ERROR_IF(sigpending, status, == -1);
+    printf("pending: SIGUSR1 %d\n", sigismember(&pending, SIGUSR1));
+
+    // A queued signal carries its value
+    union sigval value = { .sival_int = 42 };
+    status = sigqueue(getpid(), SIGUSR2, value);
+    ERROR_IF(sigqueue, status, == -1);
+    siginfo_t info;
+    sig = sigwaitinfo(&set, &info);
+    ERROR_IF(sigwaitinfo, sig, == -1);
+    printf("sigwaitinfo: signo %d, code %d, value %d, own pid %d\n",
+        info.si_signo == SIGUSR2, info.si_code == SI_QUEUE, info.si_value.sival_int,
+        info.si_pid == getpid());
+
+    status = kill(getpid(), SIGUSR1);
+    ERROR_IF(kill, status, == -1);
+    struct timespec timeout = { 1, 0 };
+    sig = sigtimedwait(&set, &info, &timeout);
+    ERROR_IF(sigtimedwait, sig, == -1);
+    printf("sigtimedwait: signo %d, code %d, own pid %d\n",
+        sig == SIGUSR1, info.si_code == SI_USER, info.si_pid == getpid());
+
+    timeout.tv_sec = 0;
+    timeout.tv_nsec = 10000000;
+    sig = sigtimedwait(&set, &info, &timeout);
+    printf("timeout: %d, %s\n", sig, strerror(errno));
+}
@@ -0,0 +1,207 @@
+#include <errno.h>
+#include <fcntl.h>
+#include <limits.h>
+#include <signal.h>
+#include <spawn.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+extern char **environ;
+
+// Runs a shell command and reports how it ended.
+void run(const char *name, const posix_spawn_file_actions_t *actions,
+        const posix_spawnattr_t *attr, char *command, char **envp) {
+    char *argv[] = { "sh", "-c", command, NULL };
+    fflush(stdout);
+    pid_t pid;
+    int err = posix_spawnp(&pid, "sh", actions, attr, argv, envp);
+    if (err != 0) {
+        printf("%s: %s\n", name, strerror(err));
+        return;
+    }
+
+    int status;
+    pid_t waited = waitpid(pid, &status, 0);
+    ERROR_IF(waitpid, waited, == -1);
+    if (WIFEXITED(status)) {
+        printf("%s: exited with %d\n", name, WEXITSTATUS(status));
+    } else if (WIFSIGNALED(status)) {
+        printf("%s: killed by signal %d\n", name, WTERMSIG(status));
+    }
+}
+
+int main(void) {
+    // The environment is the one given
+    char *envp[] = { "GREETING=hello", NULL };
+    char *argv[] = { "sh", "-c", "echo $GREETING from $0", NULL };
+    pid_t pid;
+    int err = posix_spawn(&pid, "/bin/sh", NULL, NULL, argv, envp);
+    ERROR_IF(posix_spawn, err, != 0);
+    int status;
+    pid_t waited = waitpid(pid, &status, 0);
+    ERROR_IF(waitpid, waited, == -1);
+    printf("waited for child: %d\n", waited == pid);
+
+    run("status", NULL, NULL, "exit 3", environ);
+
+    // Errors are reported by the caller
+    err = posix_spawn(&pid, "/nonexistent", NULL, NULL, argv, environ);
+    printf("missing path: %s\n", strerror(err));
+    err = posix_spawnp(&pid, "nonexistent-program", NULL, NULL, argv, environ);
+    printf("missing program: %s\n", strerror(err));
+
+    // Without PATH, programs are looked for in the default places, and an empty entry stands for
+    // the working directory
+    char *path = strdup(getenv("PATH"));
+    char cwd[PATH_MAX];
+    char *cwd_status = getcwd(cwd, sizeof(cwd));
+    ERROR_IF(getcwd, cwd_status, == NULL);
+    unsetenv("PATH");
+    run("default path", NULL, NULL, "exit 0", environ);
+    setenv("PATH", "/nonexistent:", 1);
+    int changed = chdir("/bin");
+    ERROR_IF(chdir, changed, == -1);
+    run("empty path entry", NULL, NULL, "exit 0", environ);
+    changed = chdir(cwd);
+    ERROR_IF(chdir, changed, == -1);
+    setenv("PATH", path, 1);
+    free(path);
+
+    // File actions
+    int fds[2];
+    int status2 = pipe(fds);
+    ERROR_IF(pipe, status2, == -1);
+
+    posix_spawn_file_actions_t actions;
+    err = posix_spawn_file_actions_init(&actions);
+    ERROR_IF(posix_spawn_file_actions_init, err, != 0);
+    err = posix_spawn_file_actions_adddup2(&actions, fds[1], STDOUT_FILENO);
+    ERROR_IF(posix_spawn_file_actions_adddup2, err, != 0);
+    err = posix_spawn_file_actions_addclose(&actions, fds[0]);
+    ERROR_IF(posix_spawn_file_actions_addclose, err, != 0);
+    err = posix_spawn_file_actions_addclose(&actions, fds[1]);
+    ERROR_IF(posix_spawn_file_actions_addclose, err, != 0);
+    err = posix_spawn_file_actions_addchdir_np(&actions, "/");
+    ERROR_IF(posix_spawn_file_actions_addchdir_np, err, != 0);
+    run("redirected", &actions, NULL, "pwd", environ);
+    close(fds[1]);
+
+    char buf[64] = { 0 };
+    ssize_t count = read(fds[0], buf, sizeof(buf) - 1);
+    ERROR_IF(read, count, == -1);
+    printf("read from child: %s", buf);
+    close(fds[0]);
+
+    err = posix_spawn_file_actions_destroy(&actions);
+    ERROR_IF(posix_spawn_file_actions_destroy, err, != 0);
+
+    err = posix_spawn_file_actions_init(&actions);
+    ERROR_IF(posix_spawn_file_actions_init, err, != 0);
+    err = posix_spawn_file_actions_addclose(&actions, -1);
+    printf("invalid descriptor: %s\n", strerror(err));
+    err = posix_spawn_file_actions_addopen(&actions, STDIN_FILENO, "/nonexistent", O_RDONLY, 0);
+    ERROR_IF(posix_spawn_file_actions_addopen, err, != 0);
+    run("failed action", &actions, NULL, "true", environ);
+    err = posix_spawn_file_actions_destroy(&actions);
+    ERROR_IF(posix_spawn_file_actions_destroy, err, != 0);
+
+    err = posix_spawn_file_actions_init(&actions);
+    ERROR_IF(posix_spawn_file_actions_init, err, != 0);
+    err = posix_spawn_file_actions_addopen(&actions, STDIN_FILENO, "/dev/null", O_RDONLY, 0);
+    ERROR_IF(posix_spawn_file_actions_addopen, err, != 0);
+    run("opened", &actions, NULL, "read line; echo read $?", environ);
+    err = posix_spawn_file_actions_destroy(&actions);
+    ERROR_IF(posix_spawn_file_actions_destroy, err, != 0);
+
+    // Attributes
+    posix_spawnattr_t attr;
+    err = posix_spawnattr_init(&attr);
+    ERROR_IF(posix_spawnattr_init, err, != 0);
+    err = posix_spawnattr_setflags(&attr, 0x4000);
+    printf("invalid flags: %s\n", strerror(err));
+
+    // An ignored signal stays ignored, unless it is to be reset
+    signal(SIGUSR2, SIG_IGN);
+    run("ignored", NULL, &attr, "kill -USR2 $$; echo survived", environ);
+
+    sigset_t set;
+    sigemptyset(&set);
+    sigaddset(&set, SIGUSR2);
+    err = posix_spawnattr_setsigdefault(&attr, &set);
+    ERROR_IF(posix_spawnattr_setsigdefault, err, != 0);
+    err = posix_spawnattr_setflags(&attr, POSIX_SPAWN_SETSIGDEF);
+    ERROR_IF(posix_spawnattr_setflags, err, != 0);
+    run("reset", NULL, &attr, "kill -USR2 $$; echo survived", environ);
+    signal(SIGUSR2, SIG_DFL);
+
+    sigemptyset(&set);
+    sigaddset(&set, SIGUSR1);
+    err = posix_spawnattr_setsigmask(&attr, &set);
+    ERROR_IF(posix_spawnattr_setsigmask, err, != 0);
+    err = posix_spawnattr_setflags(&attr, POSIX_SPAWN_SETSIGMASK);
+    ERROR_IF(posix_spawnattr_setflags, err, != 0);
+    run("blocked", NULL, &attr, "kill -USR1 $$; echo survived", environ);
+
+    short flags;
+    err = posix_spawnattr_getflags(&attr, &flags);
+    ERROR_IF(posix_spawnattr_getflags, err, != 0);
+    sigset_t mask;
+    err = posix_spawnattr_getsigmask(&attr, &mask);
+    ERROR_IF(posix_spawnattr_getsigmask, err, != 0);
+    printf("flags: %d, mask has SIGUSR1 %d\n", flags == POSIX_SPAWN_SETSIGMASK,
+        sigismember(&mask, SIGUSR1));
+
+    // A child that waits for the pipe to close, so it can be looked at
+    status2 = pipe(fds);
+    ERROR_IF(pipe, status2, == -1);
+    err = posix_spawn_file_actions_init(&actions);
+    ERROR_IF(posix_spawn_file_actions_init, err, != 0);
+    err = posix_spawn_file_actions_adddup2(&actions, fds[0], STDIN_FILENO);
+    ERROR_IF(posix_spawn_file_actions_adddup2, err, != 0);
+    err = posix_spawn_file_actions_addclose(&actions, fds[1]);
+    ERROR_IF(posix_spawn_file_actions_addclose, err, != 0);
+
+    err = posix_spawnattr_setpgroup(&attr, 0);
+    ERROR_IF(posix_spawnattr_setpgroup, err, != 0);
+    err = posix_spawnattr_setflags(&attr, POSIX_SPAWN_SETPGROUP);
+    ERROR_IF(posix_spawnattr_setflags, err, != 0);
+    char *wait_argv[] = { "sh", "-c", "read line", NULL };
+    err = posix_spawn(&pid, "/bin/sh", &actions, &attr, wait_argv, environ);
+    ERROR_IF(posix_spawn, err, != 0);
+    printf("own process group: %d\n", getpgid(pid) == pid);
+    close(fds[1]);
+    waited = waitpid(pid, &status, 0);
+    ERROR_IF(waitpid, waited, == -1);
+    close(fds[0]);
+
+    status2 = pipe(fds);
+    ERROR_IF(pipe, status2, == -1);
+    err = posix_spawn_file_actions_destroy(&actions);
+    ERROR_IF(posix_spawn_file_actions_destroy, err, != 0);
+    err = posix_spawn_file_actions_init(&actions);
+    ERROR_IF(posix_spawn_file_actions_init, err, != 0);
+    err = posix_spawn_file_actions_adddup2(&actions, fds[0], STDIN_FILENO);
+    ERROR_IF(posix_spawn_file_actions_adddup2, err, != 0);
+    err = posix_spawn_file_actions_addclose(&actions, fds[1]);
+    ERROR_IF(posix_spawn_file_actions_addclose, err, != 0);
+
+    err = posix_spawnattr_setflags(&attr, POSIX_SPAWN_SETSID);
+    ERROR_IF(posix_spawnattr_setflags, err, != 0);
+    err = posix_spawn(&pid, "/bin/sh", &actions, &attr, wait_argv, environ);
+    ERROR_IF(posix_spawn, err, != 0);
+    printf("own session: %d\n", getsid(pid) == pid);
+    close(fds[1]);
+    waited = waitpid(pid, &status, 0);
+    ERROR_IF(waitpid, waited, == -1);
+    close(fds[0]);
+
+    err = posix_spawn_file_actions_destroy(&actions);
+    ERROR_IF(posix_spawn_file_actions_destroy, err, != 0);
+    err = posix_spawnattr_destroy(&attr);
+    ERROR_IF(posix_spawnattr_destroy, err, != 0);
+}
@@ -49,7 +49,13 @@ fn main() {
     for bin in env::args().skip(1) {
         println!("# {} #", bin);
 
-        match Command::new(&bin).arg("test").arg("args").output() {
+        // Expected output assumes UTC, whatever the local time zone is
+        match Command::new(&bin)
+            .arg("test")
+            .arg("args")
+            .env("TZ", "UTC")
+            .output()
+        {
             Ok(output) => {
                 if let Err(failure) = expected(&bin, "stdout", &output.stdout, output.status) {
                     println!("{}", failure);
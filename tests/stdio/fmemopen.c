@@ -0,0 +1,69 @@
+#include <errno.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char buf[16];
+    memset(buf, 'x', sizeof(buf));
+    FILE *f = fmemopen(buf, sizeof(buf), "w+");
+    ERROR_IF(fmemopen, f, == NULL);
+
+    // Truncated when opened for writing, and terminated after what is written
+    printf("truncated: \"%s\"\n", buf);
+    int result = fprintf(f, "hello %d", 42);
+    ERROR_IF(fprintf, result, < 0);
+    result = fflush(f);
+    ERROR_IF(fflush, result, == EOF);
+    printf("written: \"%s\", position %ld\n", buf, ftell(f));
+
+    rewind(f);
+    char line[32];
+    char *s = fgets(line, sizeof(line), f);
+    ERROR_IF(fgets, s, == NULL);
+    printf("read back: \"%s\", eof %d\n", line, fgetc(f) == EOF && feof(f));
+
+    // Seeking is limited to the buffer
+    result = fseek(f, 17, SEEK_SET);
+    printf("past the end: %d, %s\n", result, strerror(errno));
+    result = fseek(f, -3, SEEK_END);
+    ERROR_IF(fseek, result, == -1);
+    printf("from the end: %ld\n", ftell(f));
+    printf("fileno: %d\n", fileno(f));
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+
+    // Reading the caller's buffer
+    char text[] = "line one\nline two\n";
+    f = fmemopen(text, strlen(text), "r");
+    ERROR_IF(fmemopen, f, == NULL);
+    while (fgets(line, sizeof(line), f) != NULL) {
+        printf("line: %s", line);
+    }
+    printf("eof: %d\n", feof(f) != 0);
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+
+    // Appending starts at the first null byte
+    char append[16] = "abc";
+    f = fmemopen(append, sizeof(append), "a");
+    ERROR_IF(fmemopen, f, == NULL);
+    result = fputs("def", f);
+    ERROR_IF(fputs, result, == EOF);
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+    printf("appended: \"%s\"\n", append);
+
+    // Without a buffer, the stream allocates one
+    f = fmemopen(NULL, 32, "w+");
+    ERROR_IF(fmemopen, f, == NULL);
+    result = fputs("scratch space", f);
+    ERROR_IF(fputs, result, == EOF);
+    rewind(f);
+    s = fgets(line, sizeof(line), f);
+    ERROR_IF(fgets, s, == NULL);
+    printf("own buffer: \"%s\"\n", line);
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+}
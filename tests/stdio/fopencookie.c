@@ -0,0 +1,107 @@
+#include <ctype.h>
+#include <errno.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/types.h>
+
+#include "test_helpers.h"
+
+struct cookie {
+    char data[64];
+    size_t len;
+    size_t pos;
+};
+
+static ssize_t cookie_read(void *c, char *buf, size_t size) {
+    struct cookie *cookie = c;
+    size_t left = cookie->len - cookie->pos;
+    if (size > left) {
+        size = left;
+    }
+    memcpy(buf, cookie->data + cookie->pos, size);
+    cookie->pos += size;
+    return size;
+}
+
+// Stores everything in upper case
+static ssize_t cookie_write(void *c, const char *buf, size_t size) {
+    struct cookie *cookie = c;
+    if (size > sizeof(cookie->data) - cookie->pos) {
+        size = sizeof(cookie->data) - cookie->pos;
+    }
+    for (size_t i = 0; i < size; i++) {
+        cookie->data[cookie->pos++] = toupper((unsigned char) buf[i]);
+    }
+    if (cookie->pos > cookie->len) {
+        cookie->len = cookie->pos;
+    }
+    return size;
+}
+
+static int cookie_seek(void *c, off_t *offset, int whence) {
+    struct cookie *cookie = c;
+    off_t pos = *offset;
+    if (whence == SEEK_CUR) {
+        pos += cookie->pos;
+    } else if (whence == SEEK_END) {
+        pos += cookie->len;
+    }
+    if (pos < 0 || pos > (off_t) cookie->len) {
+        errno = EINVAL;
+        return -1;
+    }
+    cookie->pos = pos;
+    *offset = pos;
+    return 0;
+}
+
+static int cookie_close(void *c) {
+    struct cookie *cookie = c;
+    printf("closing with %zu bytes\n", cookie->len);
+    return 0;
+}
+
+int main(void) {
+    struct cookie cookie = { .len = 0, .pos = 0 };
+    cookie_io_functions_t io = {
+        .read = cookie_read,
+        .write = cookie_write,
+        .seek = cookie_seek,
+        .close = cookie_close,
+    };
+    FILE *f = fopencookie(&cookie, "w+", io);
+    ERROR_IF(fopencookie, f, == NULL);
+
+    int result = fprintf(f, "hello, %s", "cookie");
+    ERROR_IF(fprintf, result, < 0);
+    printf("buffered: %zu\n", cookie.len);
+    result = fflush(f);
+    ERROR_IF(fflush, result, == EOF);
+    printf("written: \"%.*s\"\n", (int) cookie.len, cookie.data);
+
+    rewind(f);
+    char line[64];
+    char *s = fgets(line, sizeof(line), f);
+    ERROR_IF(fgets, s, == NULL);
+    printf("read back: \"%s\"\n", line);
+
+    result = fseek(f, 7, SEEK_SET);
+    ERROR_IF(fseek, result, == -1);
+    long pos = ftell(f);
+    printf("position: %ld, next: %c\n", pos, fgetc(f));
+    result = fseek(f, 100, SEEK_SET);
+    printf("past the end: %d, %s\n", result, strerror(errno));
+    printf("fileno: %d\n", fileno(f));
+
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+
+    // Without functions, nothing can be read and seeking fails
+    cookie_io_functions_t none = { NULL, NULL, NULL, NULL };
+    f = fopencookie(&cookie, "r", none);
+    ERROR_IF(fopencookie, f, == NULL);
+    printf("read: %d\n", fgetc(f));
+    printf("seek: %d\n", fseek(f, 0, SEEK_SET));
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+}
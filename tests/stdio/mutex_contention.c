@@ -0,0 +1,52 @@
+#include <pthread.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+#define THREADS 8
+#define ITERATIONS 1000
+
+static FILE *f;
+static int counter = 0;
+
+static void *increment(void *arg) {
+    (void) arg;
+    // Holding the lock across a sleep makes the others give up spinning and wait on it, so every
+    // unlock has sleepers to wake
+    struct timespec delay = { .tv_sec = 0, .tv_nsec = 1000 };
+    for (int i = 0; i < ITERATIONS; i++) {
+        flockfile(f);
+        int value = counter;
+        if (i % 10 == 0) {
+            nanosleep(&delay, NULL);
+        }
+        counter = value + 1;
+        funlockfile(f);
+    }
+    return NULL;
+}
+
+int main(void) {
+    f = fopen("stdio/stdio.in", "r");
+    ERROR_IF(fopen, f, == NULL);
+
+    pthread_t threads[THREADS];
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_create(&threads[i], NULL, increment, NULL);
+        ERROR_IF(pthread_create, status, != 0);
+    }
+    for (int i = 0; i < THREADS; i++) {
+        int status = pthread_join(threads[i], NULL);
+        ERROR_IF(pthread_join, status, != 0);
+    }
+    printf("Counter: %d\n", counter);
+
+    if (ftrylockfile(f)) {
+        puts("Mutex locked but it shouldn't be");
+        exit(EXIT_FAILURE);
+    }
+    funlockfile(f);
+    fclose(f);
+}
@@ -0,0 +1,79 @@
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char *buf;
+    size_t size;
+    FILE *f = open_memstream(&buf, &size);
+    ERROR_IF(open_memstream, f, == NULL);
+    printf("opened: %zu\n", size);
+
+    int result = fprintf(f, "hello");
+    ERROR_IF(fprintf, result, < 0);
+    result = fflush(f);
+    ERROR_IF(fflush, result, == EOF);
+    printf("flushed: %zu \"%s\"\n", size, buf);
+
+    // The buffer grows as needed
+    for (int i = 0; i < 1000; i++) {
+        result = fprintf(f, "%d,", i);
+        ERROR_IF(fprintf, result, < 0);
+    }
+    result = fflush(f);
+    ERROR_IF(fflush, result, == EOF);
+    printf("grown: %zu %zu \"%.12s...%s\"\n", size, strlen(buf), buf, buf + size - 8);
+
+    // The size follows the position, the contents stay
+    size_t end = size;
+    result = fseek(f, 5, SEEK_SET);
+    ERROR_IF(fseek, result, == -1);
+    result = fflush(f);
+    ERROR_IF(fflush, result, == EOF);
+    printf("rewound: %zu %zu\n", size, strlen(buf));
+
+    result = fseek(f, end, SEEK_SET);
+    ERROR_IF(fseek, result, == -1);
+    result = fputs("!", f);
+    ERROR_IF(fputs, result, == EOF);
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+    printf("closed: %zu \"...%s\"\n", size, buf + size - 8);
+    free(buf);
+
+    // Seeking past the end leaves a gap of null bytes
+    f = open_memstream(&buf, &size);
+    ERROR_IF(open_memstream, f, == NULL);
+    result = fseek(f, 3, SEEK_SET);
+    ERROR_IF(fseek, result, == -1);
+    result = fputs("gap", f);
+    ERROR_IF(fputs, result, == EOF);
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+    printf("gap: %zu", size);
+    for (size_t i = 0; i <= size; i++) {
+        printf(" %d", buf[i]);
+    }
+    printf("\n");
+    free(buf);
+
+    // Wide streams count in wide characters, decoded in the codeset of the locale
+    ERROR_IF(setlocale, setlocale(LC_ALL, "C.UTF-8"), == NULL);
+    wchar_t *wbuf;
+    f = open_wmemstream(&wbuf, &size);
+    ERROR_IF(open_wmemstream, f, == NULL);
+    result = fputws(L"wide é€", f);
+    ERROR_IF(fputws, result, == -1);
+    result = fclose(f);
+    ERROR_IF(fclose, result, == EOF);
+    printf("wide: %zu", size);
+    for (size_t i = 0; i <= size; i++) {
+        printf(" %x", (unsigned) wbuf[i]);
+    }
+    printf("\n");
+    free(wbuf);
+}
@@ -0,0 +1,41 @@
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "test_helpers.h"
+
+static void print_numbers(void) {
+    printf("%'d %'d %'d %'d\n", 0, 123, 1234, -1234567);
+    printf("%'u %'lu %'hu\n", 1234567u, 123456789ul, (unsigned short) 65535);
+    printf("[%'12d] [%-'12d] [%+'d] [%'.8d]\n", 1234567, 1234567, 1234567, 1234);
+    printf("%'.2f %'f %'.0f %f\n", 1234567.891, -1234.5, 999999.0, 1234.5);
+    printf("%'g %'g %'e %.3a\n", 12345.5, 1234567.0, 1234567.0, 1.5);
+    printf("%Id %'Id %I'd\n", 42, 1234567, 1234567);
+}
+
+static void print_digits(void) {
+    printf("%Id %'Id %I'd %d\n", 42, 1234567, -1234567, 42);
+    printf("[%I5d] [%-I5d] [%I.0d] %Iu %Ix %Io\n", 42, 42, 0, 1234u, 255u, 8u);
+    printf("%I.2f %'I.2f %f %Ie %Ig %Ia\n", 1234567.891, 1234567.891, 1.5, 1234.5, 0.25, 1.5);
+}
+
+int main(void) {
+    puts("C:");
+    print_numbers();
+
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    char *name = setlocale(LC_NUMERIC, "de_DE.UTF-8");
+    ERROR_IF(setlocale, name, == NULL);
+    puts("de_DE:");
+    print_numbers();
+
+    // The digits come from LC_CTYPE
+    name = setlocale(LC_ALL, "fa_IR.UTF-8");
+    ERROR_IF(setlocale, name, == NULL);
+    puts("fa_IR:");
+    print_numbers();
+    print_digits();
+}
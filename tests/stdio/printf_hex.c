@@ -0,0 +1,75 @@
+#include <errno.h>
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    double values[] = {
+        0.0, -0.0, 1.0, 0.5, 3.0, -10.1, 1.0 / 3.0, M_PI,
+        0x1.fffffffffffffp+1023, 0x1p-1022, 0x1p-1025, 0x1p-1074, 0x1.23p-1030,
+    };
+    size_t count = sizeof(values) / sizeof(double);
+
+    puts("%a and %A:");
+    for (size_t i = 0; i < count; i++) {
+        printf("%a %A\n", values[i], values[i]);
+    }
+    printf("%a %A\n", INFINITY, -INFINITY);
+
+    puts("Precision:");
+    printf("%.0a %.0a %.0a %.0a\n", 1.0, 1.5, 2.5, 0x1.7p+0);
+    printf("%.1a %.1a %.1a\n", 0x1.08p+0, 0x1.18p+0, 0x1.f8p+0);
+    printf("%.3a %.3a\n", 1.0 / 3.0, -M_PI);
+    printf("%.2a %.2a\n", 0x1.fffp+0, 0x0.fffp-1022);
+    printf("%.20a\n", 1.0);
+    printf("%.0a %.3a\n", 0.0, 0.0);
+
+    puts("Flags and width:");
+    printf("%#a %#.0a %#.0a\n", 1.0, 1.0, 0.0);
+    printf("%+a % a %+a\n", 1.0, 1.0, -1.0);
+    printf("[%12a] [%-12a] [%012a]\n", 1.5, 1.5, 1.5);
+    printf("[%020.3A] [%+015a] [% 015a]\n", -M_PI, 2.0, 2.0);
+    printf("[%*.*a]\n", 14, 1, 1.75);
+
+    puts("%m:");
+    errno = EINVAL;
+    printf("%m\n");
+    printf("[%.7m] [%20m] [%-20m]\n");
+    printf("%d %m %d\n", 1, 2);
+    errno = 99999;
+    printf("%m\n");
+
+    puts("Reading them back:");
+    for (size_t i = 0; i < count; i++) {
+        char buf[64];
+        double d;
+        float f;
+        snprintf(buf, sizeof(buf), "%a", values[i]);
+        int matched = sscanf(buf, "%la", &d);
+        ERROR_IF(sscanf, matched, != 1);
+        matched = sscanf(buf, "%a", &f);
+        ERROR_IF(sscanf, matched, != 1);
+        printf("%s: %s, %a\n", buf,
+            memcmp(&d, &values[i], sizeof(double)) == 0 ? "same" : "different", f);
+    }
+
+    const char *inputs[] = {
+        "0X1.8P+1", "-0x.8p-1", "+0x10", "0x1p-1074", "0x1.00000000000008p0",
+        "0x1.00000000000018p0", "0x1.000000000000080000001p0", "0x1p1024", "0x.000001p-1050",
+        "-2.5e2", "0x1.8 rest",
+    };
+    for (size_t i = 0; i < sizeof(inputs) / sizeof(char *); i++) {
+        double d = 0;
+        char rest[16] = "";
+        int matched = sscanf(inputs[i], "%lA %15s", &d, rest);
+        printf("%s: %d %a \"%s\"\n", inputs[i], matched, d, rest);
+    }
+
+    double d;
+    float f;
+    char rest[16];
+    int matched = sscanf("0x1.8p1 0x1.000001p0", "%5lf%15s %e", &d, rest, &f);
+    printf("width: %d %a %s %a\n", matched, d, rest, f);
+}
@@ -0,0 +1,44 @@
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char *key = NULL, *value = NULL;
+    int matched = sscanf("name = some value here", "%ms = %m[^\n]", &key, &value);
+    printf("%d \"%s\" \"%s\"\n", matched, key, value);
+    free(key);
+    free(value);
+
+    char *chars = NULL;
+    int n;
+    matched = sscanf("abcdefgh", "%3mc%n", &chars, &n);
+    printf("%d %.3s %d\n", matched, chars, n);
+    free(chars);
+
+    // Width limits what is read, and the buffer only needs to hold that
+    char *word = NULL;
+    matched = sscanf("overlong", "%4ms", &word);
+    printf("%d \"%s\"\n", matched, word);
+    free(word);
+
+    // Nothing is allocated when nothing matches
+    word = NULL;
+    matched = sscanf("123", "%m[a-z]", &word);
+    printf("%d %s\n", matched, word == NULL ? "NULL" : word);
+
+    matched = sscanf("", "%ms", &word);
+    printf("%d %s\n", matched, word == NULL ? "NULL" : word);
+
+    puts("Positional:");
+    int x = 0, y = 0;
+    char s[16] = "";
+    matched = sscanf("1 2 three", "%2$d %1$d %3$s", &x, &y, s);
+    printf("%d %d %d %s\n", matched, x, y, s);
+
+    double d = 0;
+    char *p = NULL;
+    matched = sscanf("word 2.5", "%2$ms %1$lf%3$n", &d, &p, &n);
+    printf("%d %s %g %d\n", matched, p, d, n);
+    free(p);
+}
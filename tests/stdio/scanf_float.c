@@ -0,0 +1,35 @@
+#include <stdio.h>
+#include <stdlib.h>
+
+int main(void) {
+    const char *inputs[] = {
+        "1.5", "-2.5e2", "+.5E-1", "3.", "1e400", "-1e-400", "0x1.8p1", "-0X.8P-1", "0x10",
+        "inf", "-INF", "Infinity", "nan", "-nan", "NaN(abc_1)", "123456789012345678901234567890",
+        "2.2250738585072011e-308", "0.1", "1e+5x", "7.25,8",
+    };
+    for (size_t i = 0; i < sizeof(inputs) / sizeof(char *); i++) {
+        double d = 0;
+        float f = 0;
+        char rest[16] = "";
+        int matched = sscanf(inputs[i], "%lf%15s", &d, rest);
+        sscanf(inputs[i], "%f", &f);
+        printf("%s: %d %a %a \"%s\" same as strtod: %s\n", inputs[i], matched, d, f, rest,
+            d == strtod(inputs[i], NULL) || d != d ? "yes" : "no");
+    }
+
+    // Input that strtod would only take part of doesn't match
+    const char *failures[] = {"1e", "1e+", "-", ".", "0x", "in", "nan("};
+    for (size_t i = 0; i < sizeof(failures) / sizeof(char *); i++) {
+        double d = 42;
+        int matched = sscanf(failures[i], "%lf", &d);
+        printf("%s: %d %g\n", failures[i], matched, d);
+    }
+
+    double a, b, c;
+    int matched = sscanf("1.5 -2e1 infinity", "%lg %le %lF", &a, &b, &c);
+    printf("%d %g %g %g\n", matched, a, b, c);
+    matched = sscanf("12.345678", "%4lf%lf", &a, &b);
+    printf("%d %g %g\n", matched, a, b);
+    matched = sscanf("1.5e3:2", "%lA:%lG", &a, &b);
+    printf("%d %g %g\n", matched, a, b);
+}
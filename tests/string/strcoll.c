@@ -0,0 +1,99 @@
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+static const char *words[] = {
+    "zebra", "Apple", "apple", "äpple", "Äpple", "ångström", "Åland", "öl", "oliv",
+    "résumé", "Résumé", "resume", "co-op", "coop", "Coop", "Zürich", "yxa", "10", "9",
+    "Ελλάδα", "東京",
+};
+#define WORDS (sizeof(words) / sizeof(words[0]))
+
+static int compare(const void *a, const void *b) {
+    return strcoll(*(const char **) a, *(const char **) b);
+}
+
+static int sign(int n) {
+    return (n > 0) - (n < 0);
+}
+
+void sorted(const char *name) {
+    const char *sorted[WORDS];
+    memcpy(sorted, words, sizeof(words));
+    qsort(sorted, WORDS, sizeof(sorted[0]), compare);
+
+    printf("%s:", name);
+    for (size_t i = 0; i < WORDS; i++) {
+        printf(" %s", sorted[i]);
+    }
+    printf("\n");
+
+    // Sort keys compare like the strings they are made from
+    char keys[WORDS][256];
+    for (size_t i = 0; i < WORDS; i++) {
+        size_t len = strxfrm(keys[i], words[i], sizeof(keys[i]));
+        if (len >= sizeof(keys[i])) {
+            printf("strxfrm(\"%s\") needs %zu bytes\n", words[i], len + 1);
+            exit(EXIT_FAILURE);
+        }
+    }
+    for (size_t i = 0; i < WORDS; i++) {
+        for (size_t j = 0; j < WORDS; j++) {
+            int coll = sign(strcoll(words[i], words[j]));
+            int keys_cmp = sign(strcmp(keys[i], keys[j]));
+            if (coll != keys_cmp) {
+                printf("strcoll(\"%s\", \"%s\") is %d, but the keys compare %d\n",
+                       words[i], words[j], coll, keys_cmp);
+                exit(EXIT_FAILURE);
+            }
+        }
+    }
+}
+
+int main(void) {
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    // The C locale compares bytes, and transforming a string copies it
+    sorted("C");
+    char buf[16];
+    size_t len = strxfrm(buf, "abc", sizeof(buf));
+    printf("strxfrm: %zu %s\n", len, buf);
+
+    char *locale = setlocale(LC_COLLATE, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    sorted("C.UTF-8");
+
+    locale = setlocale(LC_ALL, "de_DE.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    sorted("de_DE.UTF-8");
+
+    // The length of the key is returned even if it doesn't fit
+    char key[256];
+    len = strxfrm(NULL, "résumé", 0);
+    printf("strxfrm(NULL, 0) = strxfrm(key, 256): %d\n", len == strxfrm(key, "résumé", sizeof(key)));
+
+    // Accents matter less than letters, and case less than accents
+    printf("%d %d %d\n", sign(strcoll("resume", "résumé")), sign(strcoll("résumé", "resumf")),
+           sign(strcoll("Resume", "résumé")));
+
+    locale = setlocale(LC_ALL, "sv_SE.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    sorted("sv_SE.UTF-8");
+
+    // strcoll_l() uses the given locale rather than the current one
+    locale_t de = newlocale(LC_ALL_MASK, "de_DE.UTF-8", (locale_t) 0);
+    ERROR_IF(newlocale, de, == (locale_t) 0);
+    printf("strcoll(\"öl\", \"zebra\") = %d, strcoll_l(\"öl\", \"zebra\", de) = %d\n",
+           sign(strcoll("öl", "zebra")), sign(strcoll_l("öl", "zebra", de)));
+    freelocale(de);
+
+    // In a single-byte locale, the bytes are Latin-1 characters
+    locale = setlocale(LC_ALL, "de_DE.ISO-8859-1");
+    ERROR_IF(setlocale, locale, == NULL);
+    printf("strcoll(\"\\xe4\", \"b\") = %d\n", sign(strcoll("\xe4", "b")));
+}
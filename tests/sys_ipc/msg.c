@@ -0,0 +1,103 @@
+#include <errno.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/ipc.h>
+#include <sys/msg.h>
+#include <sys/wait.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+struct message {
+    long mtype;
+    char mtext[64];
+};
+
+void send_text(int id, long mtype, const char *text) {
+    struct message msg = { mtype };
+    strcpy(msg.mtext, text);
+    int result = msgsnd(id, &msg, strlen(text) + 1, 0);
+    ERROR_IF(msgsnd, result, == -1);
+}
+
+void receive(int id, long msgtyp, int flags) {
+    struct message msg;
+    ssize_t len = msgrcv(id, &msg, sizeof(msg.mtext), msgtyp, flags);
+    if (len == -1) {
+        printf("receive %ld: %s\n", msgtyp, strerror(errno));
+    } else {
+        printf("receive %ld: %ld, %s (%zd)\n", msgtyp, msg.mtype, msg.mtext, len);
+    }
+}
+
+int main(void) {
+    int id = msgget(IPC_PRIVATE, IPC_CREAT | 0600);
+    ERROR_IF(msgget, id, == -1);
+
+    send_text(id, 3, "three");
+    send_text(id, 1, "one");
+    send_text(id, 2, "two");
+    send_text(id, 1, "another one");
+
+    struct msqid_ds ds;
+    int result = msgctl(id, IPC_STAT, &ds);
+    ERROR_IF(msgctl, result, == -1);
+    printf("stat: %lu messages, mode %o, last sender %d\n", (unsigned long) ds.msg_qnum,
+        ds.msg_perm.mode & 0777, ds.msg_lspid == getpid());
+
+    // The first message, the first of a type, and the first of the lowest type up to a limit
+    receive(id, 0, 0);
+    receive(id, 2, 0);
+    receive(id, -5, 0);
+    receive(id, 7, IPC_NOWAIT);
+
+    struct message small;
+    ssize_t len = msgrcv(id, &small, 4, 0, 0);
+    printf("too long: %zd, %s\n", len, strerror(errno));
+    len = msgrcv(id, &small, 4, 0, MSG_NOERROR);
+    ERROR_IF(msgrcv, len, == -1);
+    printf("truncated: %zd, %.4s\n", len, small.mtext);
+    receive(id, 0, IPC_NOWAIT);
+
+    struct message invalid = { 0 };
+    result = msgsnd(id, &invalid, 1, 0);
+    printf("invalid type: %d, %s\n", result, strerror(errno));
+
+    // Waiting for a message from another process
+    fflush(stdout);
+    pid_t pid = fork();
+    ERROR_IF(fork, pid, == -1);
+    if (pid == 0) {
+        struct timespec ts = { 0, 10000000 };
+        nanosleep(&ts, NULL);
+        send_text(id, 4, "from the child");
+        _exit(0);
+    }
+    receive(id, 4, 0);
+    int status;
+    pid_t waited = waitpid(pid, &status, 0);
+    ERROR_IF(waitpid, waited, == -1);
+
+    // A full queue
+    result = msgctl(id, IPC_STAT, &ds);
+    ERROR_IF(msgctl, result, == -1);
+    ds.msg_qbytes = 100;
+    result = msgctl(id, IPC_SET, &ds);
+    ERROR_IF(msgctl, result, == -1);
+    struct message big = { 1 };
+    memset(big.mtext, 'x', sizeof(big.mtext));
+    result = msgsnd(id, &big, sizeof(big.mtext), IPC_NOWAIT);
+    ERROR_IF(msgsnd, result, == -1);
+    result = msgsnd(id, &big, sizeof(big.mtext), IPC_NOWAIT);
+    printf("full: %d, %s\n", result, strerror(errno));
+    result = msgctl(id, IPC_STAT, &ds);
+    ERROR_IF(msgctl, result, == -1);
+    printf("stat: %lu messages, %lu bytes at most\n", (unsigned long) ds.msg_qnum,
+        (unsigned long) ds.msg_qbytes);
+
+    result = msgctl(id, IPC_RMID, NULL);
+    ERROR_IF(msgctl, result, == -1);
+    result = msgsnd(id, &big, 1, 0);
+    printf("removed: %d, %s\n", result, strerror(errno));
+}
@@ -0,0 +1,140 @@
+#include <errno.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/ipc.h>
+#include <sys/sem.h>
+#include <sys/wait.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+union semun {
+    int val;
+    struct semid_ds *buf;
+    unsigned short *array;
+};
+
+int value(int id, int num) {
+    int value = semctl(id, num, GETVAL);
+    ERROR_IF(semctl, value, == -1);
+    return value;
+}
+
+// Waits until someone is blocked on the semaphore
+void wait_for_waiter(int id, int num, int cmd) {
+    struct timespec ts = { 0, 1000000 };
+    for (;;) {
+        int count = semctl(id, num, cmd);
+        ERROR_IF(semctl, count, == -1);
+        if (count > 0) {
+            return;
+        }
+        nanosleep(&ts, NULL);
+    }
+}
+
+pid_t spawn_op(int id, short num, short op, short flg, int use_exit) {
+    fflush(stdout);
+    pid_t pid = fork();
+    ERROR_IF(fork, pid, == -1);
+    if (pid == 0) {
+        struct sembuf sop = { num, op, flg };
+        int status = semop(id, &sop, 1) == -1 ? errno : 0;
+        if (use_exit) {
+            // The function rather than the macro from test_helpers.h, so that the process exits
+            // normally
+            (exit)(status);
+        }
+        _exit(status);
+    }
+    return pid;
+}
+
+int child_status(pid_t pid) {
+    int status;
+    pid_t waited = waitpid(pid, &status, 0);
+    ERROR_IF(waitpid, waited, == -1);
+    return WEXITSTATUS(status);
+}
+
+int main(void) {
+    int id = semget(IPC_PRIVATE, 2, IPC_CREAT | 0600);
+    ERROR_IF(semget, id, == -1);
+    printf("initial: %d %d\n", value(id, 0), value(id, 1));
+
+    union semun arg;
+    arg.val = 2;
+    int result = semctl(id, 0, SETVAL, arg);
+    ERROR_IF(semctl, result, == -1);
+    unsigned short values[2] = { 3, 1 };
+    arg.array = values;
+    result = semctl(id, 0, SETALL, arg);
+    ERROR_IF(semctl, result, == -1);
+    values[0] = values[1] = 0;
+    result = semctl(id, 0, GETALL, arg);
+    ERROR_IF(semctl, result, == -1);
+    printf("set all: %d %d\n", values[0], values[1]);
+    arg.val = 40000;
+    result = semctl(id, 0, SETVAL, arg);
+    printf("too large: %d, %s\n", result, strerror(errno));
+
+    // All operations happen at once, or none do
+    struct sembuf ops[2] = { { 0, -2, 0 }, { 1, -1, 0 } };
+    result = semop(id, ops, 2);
+    ERROR_IF(semop, result, == -1);
+    printf("decremented: %d %d\n", value(id, 0), value(id, 1));
+    printf("last: %d\n", semctl(id, 0, GETPID) == getpid());
+    ops[0].sem_op = 1;
+    ops[1].sem_flg = IPC_NOWAIT;
+    result = semop(id, ops, 2);
+    printf("nowait: %d, %s\n", result, strerror(errno));
+    printf("unchanged: %d %d\n", value(id, 0), value(id, 1));
+    struct timespec timeout = { 0, 10000000 };
+    ops[1].sem_flg = 0;
+    result = semtimedop(id, ops, 2, &timeout);
+    printf("timed out: %d, %s\n", result, strerror(errno));
+    struct sembuf missing = { 5, 1, 0 };
+    result = semop(id, &missing, 1);
+    printf("out of range: %d, %s\n", result, strerror(errno));
+
+    // Waking up another process
+    pid_t pid = spawn_op(id, 1, -1, 0, 0);
+    wait_for_waiter(id, 1, GETNCNT);
+    printf("waiting for increase: %d\n", semctl(id, 1, GETNCNT));
+    struct sembuf post = { 1, 1, 0 };
+    result = semop(id, &post, 1);
+    ERROR_IF(semop, result, == -1);
+    int status = child_status(pid);
+    printf("woken: %d, value %d, last %d\n", status, value(id, 1), semctl(id, 1, GETPID) == pid);
+
+    pid = spawn_op(id, 0, 0, 0, 0);
+    wait_for_waiter(id, 0, GETZCNT);
+    printf("waiting for zero: %d\n", semctl(id, 0, GETZCNT));
+    struct sembuf take = { 0, -1, 0 };
+    result = semop(id, &take, 1);
+    ERROR_IF(semop, result, == -1);
+    status = child_status(pid);
+    printf("woken: %d, value %d\n", status, value(id, 0));
+
+    // Adjustments are undone when the process exits
+    pid = spawn_op(id, 0, 3, SEM_UNDO, 1);
+    status = child_status(pid);
+    printf("undone: %d, value %d\n", status, value(id, 0));
+
+    struct semid_ds ds;
+    arg.buf = &ds;
+    result = semctl(id, 0, IPC_STAT, arg);
+    ERROR_IF(semctl, result, == -1);
+    printf("stat: %lu semaphores, mode %o\n", (unsigned long) ds.sem_nsems, ds.sem_perm.mode & 0777);
+
+    // Removing the set wakes up whoever waits on it
+    pid = spawn_op(id, 0, -1, 0, 0);
+    wait_for_waiter(id, 0, GETNCNT);
+    result = semctl(id, 0, IPC_RMID);
+    ERROR_IF(semctl, result, == -1);
+    printf("removed while waiting: %s\n", strerror(child_status(pid)));
+    result = semop(id, &post, 1);
+    printf("removed: %d, %s\n", result, strerror(errno));
+}
@@ -0,0 +1,89 @@
+#include <errno.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/ipc.h>
+#include <sys/shm.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    key_t key = ftok(".", 'S');
+    ERROR_IF(ftok, key, == -1);
+    printf("ftok is stable: %d\n", key == ftok(".", 'S'));
+    printf("ftok uses the id: %d\n", key != ftok(".", 'T'));
+    key_t missing = ftok("/nonexistent", 'S');
+    printf("ftok missing: %d, %s\n", missing, strerror(errno));
+
+    // Clean up after an earlier run that failed
+    int stale = shmget(key, 0, 0);
+    if (stale != -1) {
+        shmctl(stale, IPC_RMID, NULL);
+    }
+
+    int id = shmget(key, 4096, IPC_CREAT | IPC_EXCL | 0600);
+    ERROR_IF(shmget, id, == -1);
+    int again = shmget(key, 0, 0);
+    printf("same id: %d\n", again == id);
+    int exclusive = shmget(key, 4096, IPC_CREAT | IPC_EXCL | 0600);
+    printf("exclusive: %d, %s\n", exclusive, strerror(errno));
+    int larger = shmget(key, 8192, 0);
+    printf("larger: %d, %s\n", larger, strerror(errno));
+    int empty = shmget(IPC_PRIVATE, 0, IPC_CREAT | 0600);
+    printf("empty: %d, %s\n", empty, strerror(errno));
+
+    char *shared = shmat(id, NULL, 0);
+    ERROR_IF(shmat, shared, == (void *) -1);
+    strcpy(shared, "hello from the parent");
+
+    fflush(stdout);
+    pid_t pid = fork();
+    ERROR_IF(fork, pid, == -1);
+    if (pid == 0) {
+        char *child = shmat(id, NULL, 0);
+        if (child == (void *) -1) {
+            _exit(1);
+        }
+        int same = strcmp(child, "hello from the parent") == 0;
+        strcpy(child, "hello from the child");
+        shmdt(child);
+        _exit(same ? 0 : 2);
+    }
+    int status;
+    pid_t waited = waitpid(pid, &status, 0);
+    ERROR_IF(waitpid, waited, == -1);
+    printf("child: %d, %s\n", WEXITSTATUS(status), shared);
+
+    char *readonly = shmat(id, NULL, SHM_RDONLY);
+    ERROR_IF(shmat, readonly, == (void *) -1);
+    struct shmid_ds ds;
+    int result = shmctl(id, IPC_STAT, &ds);
+    ERROR_IF(shmctl, result, == -1);
+    printf("stat: size %zu, attached %lu, mode %o, creator %d, last %d\n",
+        ds.shm_segsz, (unsigned long) ds.shm_nattch, ds.shm_perm.mode & 0777,
+        ds.shm_cpid == getpid(), ds.shm_lpid == getpid());
+    result = shmdt(readonly);
+    ERROR_IF(shmdt, result, == -1);
+
+    ds.shm_perm.mode = 0640;
+    result = shmctl(id, IPC_SET, &ds);
+    ERROR_IF(shmctl, result, == -1);
+    result = shmctl(id, IPC_STAT, &ds);
+    ERROR_IF(shmctl, result, == -1);
+    printf("set: mode %o, attached %lu\n", ds.shm_perm.mode & 0777,
+        (unsigned long) ds.shm_nattch);
+
+    // A removed segment stays usable until it is detached, but its key is free again
+    result = shmctl(id, IPC_RMID, NULL);
+    ERROR_IF(shmctl, result, == -1);
+    again = shmget(key, 0, 0);
+    printf("removed key: %d, %s\n", again, strerror(errno));
+    printf("still attached: %s\n", shared);
+    result = shmdt(shared);
+    ERROR_IF(shmdt, result, == -1);
+    result = shmdt(shared);
+    printf("detach twice: %d, %s\n", result, strerror(errno));
+    void *invalid = shmat(id, NULL, 0);
+    printf("attach removed: %d, %s\n", invalid == (void *) -1, strerror(errno));
+}
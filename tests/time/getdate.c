@@ -0,0 +1,82 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+void check_err(const char* input) {
+    getdate_err = 0;
+    struct tm* tm = getdate(input);
+    printf("%s: %s, getdate_err %d\n", input, tm ? "matched" : "no match", getdate_err);
+}
+
+// Prints only the date, as the time may come from the current one
+void print(const char* input) {
+    struct tm* tm = getdate(input);
+    if (tm == NULL) {
+        printf("%s: getdate_err %d\n", input, getdate_err);
+        return;
+    }
+    printf("%s: %d-%02d-%02d, wday %d, yday %d\n", input,
+        tm->tm_year + 1900, tm->tm_mon + 1, tm->tm_mday, tm->tm_wday, tm->tm_yday);
+}
+
+int main(void) {
+    int status = setenv("TZ", "UTC", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    status = unsetenv("DATEMSK");
+    ERROR_IF(unsetenv, status, == -1);
+    check_err("2018-07-17 15:00:00");
+
+    status = setenv("DATEMSK", "time/missing.msk", 1);
+    ERROR_IF(setenv, status, == -1);
+    check_err("2018-07-17 15:00:00");
+
+    status = setenv("DATEMSK", "time", 1);
+    ERROR_IF(setenv, status, == -1);
+    check_err("2018-07-17 15:00:00");
+
+    status = setenv("DATEMSK", "time/getdate.msk", 1);
+    ERROR_IF(setenv, status, == -1);
+    check_err("not a date");
+    check_err("2018-07-17 15:00:00 and more");
+    check_err("31.02.2018");
+
+    // Complete dates do not depend on the current time
+    print("2018-07-17 15:00:00");
+    struct tm* tm = getdate("2018-07-17 15:00:00");
+    ERROR_IF(getdate, tm, == NULL);
+    printf("  %02d:%02d:%02d\n", tm->tm_hour, tm->tm_min, tm->tm_sec);
+    print("29.02.2020");
+    print("February 2019");
+    print("2020");
+
+    // Everything else is filled in from now, preferring the future
+    time_t now = time(NULL);
+    struct tm today;
+    ERROR_IF(localtime_r, localtime_r(&now, &today), == NULL);
+
+    tm = getdate("Friday");
+    ERROR_IF(getdate, tm, == NULL);
+    time_t t = mktime(tm);
+    printf("Friday: wday %d, within a week %d, time kept %d\n", tm->tm_wday,
+        t >= now - 1 && t < now + 7 * 24 * 60 * 60,
+        tm->tm_hour == today.tm_hour && tm->tm_min == today.tm_min);
+
+    tm = getdate("March");
+    ERROR_IF(getdate, tm, == NULL);
+    printf("March: mon %d, mday %d, year %s\n", tm->tm_mon + 1, tm->tm_mday,
+        tm->tm_year == today.tm_year + (today.tm_mon > 2) ? "ok" : "wrong");
+
+    tm = getdate("Monday September");
+    ERROR_IF(getdate, tm, == NULL);
+    printf("Monday September: mon %d, wday %d, first week %d\n",
+        tm->tm_mon + 1, tm->tm_wday, tm->tm_mday <= 7);
+
+    tm = getdate("00:00");
+    ERROR_IF(getdate, tm, == NULL);
+    t = mktime(tm);
+    printf("00:00: %02d:%02d:%02d, within a day %d\n", tm->tm_hour, tm->tm_min, tm->tm_sec,
+        t > now - 24 * 60 * 60 && t <= now + 24 * 60 * 60);
+}
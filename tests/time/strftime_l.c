@@ -0,0 +1,49 @@
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+void print(locale_t locale, const struct tm* tm, const char* fmt) {
+    char out[64];
+    size_t n = strftime_l(out, sizeof(out), fmt, tm, locale);
+    printf("%zu: %s\n", n, out);
+}
+
+int main(void) {
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    locale_t de = newlocale(LC_TIME_MASK, "de_DE.UTF-8", (locale_t) 0);
+    ERROR_IF(newlocale, de, == (locale_t) 0);
+    locale_t c = newlocale(LC_ALL_MASK, "C", (locale_t) 0);
+    ERROR_IF(newlocale, c, == (locale_t) 0);
+
+    time_t timestamp = 1521050400;
+    struct tm tm;
+    gmtime_r(&timestamp, &tm);
+
+    print(c, &tm, "%a %A %b %B");
+    print(de, &tm, "%a %A %b %B");
+    print(c, &tm, "%c");
+    print(de, &tm, "%c");
+    print(c, &tm, "%x %X");
+    print(de, &tm, "%x %X");
+    print(c, &tm, "%r %p %P");
+    // The German locale has no 12-hour clock
+    print(de, &tm, "%r [%p]");
+
+    // strftime() follows the locale of the thread
+    char out[64];
+    uselocale(de);
+    size_t n = strftime(out, sizeof(out), "%A, %e. %B %Y", &tm);
+    printf("%zu: %s\n", n, out);
+    uselocale(LC_GLOBAL_LOCALE);
+    n = strftime(out, sizeof(out), "%A, %e. %B %Y", &tm);
+    printf("%zu: %s\n", n, out);
+
+    freelocale(de);
+    freelocale(c);
+}
@@ -0,0 +1,117 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+void print_tm(const struct tm* tm) {
+    printf("%d-%02d-%02d %02d:%02d:%02d, wday %d, yday %d\n",
+        tm->tm_year + 1900, tm->tm_mon + 1, tm->tm_mday,
+        tm->tm_hour, tm->tm_min, tm->tm_sec, tm->tm_wday, tm->tm_yday);
+}
+
+void parse(const char* input, const char* format) {
+    struct tm tm;
+    memset(&tm, 0, sizeof(tm));
+    tm.tm_year = 100;
+
+    char* end = strptime(input, format, &tm);
+    if (end == NULL) {
+        printf("\"%s\" \"%s\": no match\n", input, format);
+        return;
+    }
+    printf("\"%s\" \"%s\": rest \"%s\", ", input, format, end);
+    print_tm(&tm);
+}
+
+// Formats the same time with strftime and parses it back
+void round_trip(const struct tm* tm, const char* format) {
+    char buf[64];
+    size_t n = strftime(buf, sizeof(buf), format, tm);
+    ERROR_IF(strftime, n, == 0);
+
+    struct tm parsed;
+    memset(&parsed, 0, sizeof(parsed));
+    char* end = strptime(buf, format, &parsed);
+    ERROR_IF(strptime, end, == NULL);
+
+    printf("%s: %s, ", format, buf);
+    print_tm(&parsed);
+    if (*end != '\0') {
+        printf("  left \"%s\"\n", end);
+    }
+}
+
+int main(void) {
+    int status = setenv("TZ", "UTC", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    time_t t = 1531839600;
+    struct tm tm;
+    ERROR_IF(gmtime_r, gmtime_r(&t, &tm), == NULL);
+
+    const char* formats[] = {
+        "%a %A %b %B %d %Y",
+        "%h %e %C%y %H:%M:%S",
+        "%c",
+        "%D %T",
+        "%F %R",
+        "%x %X",
+        "%I:%M:%S %p",
+        "%l %P %k",
+        "%r",
+        "%j %Y %T",
+        "%U %w %Y",
+        "%W %u %Y",
+        "%s",
+        "%+",
+        "%n%t%%%m-%d-%Y",
+        "%Ey %Om %Od %EY %OH %OM %OS",
+    };
+    for (int i = 0; i < sizeof(formats) / sizeof(formats[0]); i++) {
+        round_trip(&tm, formats[i]);
+    }
+
+    // Whitespace in the format matches any whitespace, including none
+    parse("  2018 \t 7\n17", " %Y %m %d");
+    parse("20180717", "%Y %m%d");
+    parse("2018-7-1", "%Y-%m-%e");
+    // Names ignore case and may be abbreviated either way
+    parse("tUESDAY, jul 17 2018", "%a, %B %d %Y");
+    parse("Tue July 17 2018", "%A %b %d %Y");
+    // Two digit years
+    parse("69", "%y");
+    parse("68", "%y");
+    parse("19 05", "%C %y");
+    parse("21", "%C");
+    // Derived dates, including a week 0 that starts the year before
+    parse("60 2016", "%j %Y");
+    parse("00 0 2017", "%U %w %Y");
+    parse("52 7 2017", "%W %u %Y");
+    // Hours
+    parse("12:00 AM", "%I:%M %p");
+    parse("12:00 pm", "%I:%M %p");
+    parse("23", "%H");
+    // Time zones
+    parse("+0530 IST", "%z %Z");
+    parse("-08:00", "%z");
+    parse("Z", "%z");
+    parse("0", "%s");
+    parse("-86400", "%s");
+    // Whatever the format does not consume is left over
+    parse("2018-07-17T15:00:00Z", "%F");
+    parse("2018-07-17T15:00:00Z", "%FT%T");
+
+    // Mismatches
+    parse("32", "%d");
+    parse("13", "%m");
+    parse("24", "%H");
+    parse("0", "%I");
+    parse("367", "%j");
+    parse("x", "%Y");
+    parse("Jux", "%b");
+    parse("2018/07", "%Y-%m");
+    parse("15:00", "%H:%M:%S");
+    parse("+5", "%z");
+}
@@ -0,0 +1,161 @@
+#include <errno.h>
+#include <signal.h>
+#include <stdio.h>
+#include <string.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+volatile sig_atomic_t signals = 0;
+
+void handler(int sig) {
+    signals++;
+}
+
+volatile int calls = 0;
+volatile int in_notify = 0;
+volatile int value_ok = 1;
+
+void notify(union sigval value) {
+    in_notify = 1;
+    if (value.sival_ptr != &calls) {
+        value_ok = 0;
+    }
+    calls++;
+    in_notify = 0;
+}
+
+void sleep_ms(long ms) {
+    struct timespec ts = { ms / 1000, (ms % 1000) * 1000000 };
+    while (nanosleep(&ts, &ts) == -1 && errno == EINTR) {}
+}
+
+long remaining_ms(timer_t timer) {
+    struct itimerspec value;
+    int status = timer_gettime(timer, &value);
+    ERROR_IF(timer_gettime, status, == -1);
+    return value.it_value.tv_sec * 1000 + (value.it_value.tv_nsec + 999999) / 1000000;
+}
+
+int main(void) {
+    // Without notification, the timer can only be read
+    struct sigevent event;
+    memset(&event, 0, sizeof(event));
+    event.sigev_notify = SIGEV_NONE;
+    timer_t timer;
+    int status = timer_create(CLOCK_MONOTONIC, &event, &timer);
+    ERROR_IF(timer_create, status, == -1);
+
+    struct itimerspec value = { { 0, 0 }, { 10, 0 } };
+    status = timer_settime(timer, 0, &value, NULL);
+    ERROR_IF(timer_settime, status, == -1);
+    long ms = remaining_ms(timer);
+    printf("relative: %d\n", ms > 9000 && ms <= 10000);
+
+    struct timespec now;
+    status = clock_gettime(CLOCK_MONOTONIC, &now);
+    ERROR_IF(clock_gettime, status, == -1);
+    value.it_value.tv_sec = now.tv_sec + 5;
+    value.it_value.tv_nsec = now.tv_nsec;
+    struct itimerspec old;
+    status = timer_settime(timer, TIMER_ABSTIME, &value, &old);
+    ERROR_IF(timer_settime, status, == -1);
+    ms = remaining_ms(timer);
+    printf("absolute: %d, old %d\n", ms > 4000 && ms <= 5000,
+        old.it_value.tv_sec == 9 && old.it_interval.tv_sec == 0);
+
+    // A time that has passed expires immediately
+    value.it_value.tv_sec = now.tv_sec - 1;
+    status = timer_settime(timer, TIMER_ABSTIME, &value, NULL);
+    ERROR_IF(timer_settime, status, == -1);
+    printf("passed: %ld\n", remaining_ms(timer));
+
+    value.it_value.tv_sec = 0;
+    value.it_value.tv_nsec = 20000000;
+    status = timer_settime(timer, 0, &value, NULL);
+    ERROR_IF(timer_settime, status, == -1);
+    sleep_ms(40);
+    printf("expired: %ld\n", remaining_ms(timer));
+
+    status = timer_delete(timer);
+    ERROR_IF(timer_delete, status, == -1);
+
+    // Periodic signals
+    struct sigaction action;
+    memset(&action, 0, sizeof(action));
+    action.sa_handler = handler;
+    status = sigaction(SIGUSR1, &action, NULL);
+    ERROR_IF(sigaction, status, == -1);
+
+    event.sigev_notify = SIGEV_SIGNAL;
+    event.sigev_signo = SIGUSR1;
+    status = timer_create(CLOCK_REALTIME, &event, &timer);
+    ERROR_IF(timer_create, status, == -1);
+
+    struct itimerspec periodic = { { 0, 5000000 }, { 0, 5000000 } };
+    status = timer_settime(timer, 0, &periodic, NULL);
+    ERROR_IF(timer_settime, status, == -1);
+    while (signals < 3) {
+        sleep_ms(1);
+    }
+    ms = remaining_ms(timer);
+    printf("signals: %d, still armed %d\n", signals >= 3, ms > 0 && ms <= 5);
+
+    // While the signal is blocked, further expirations are counted as overruns
+    sigset_t set;
+    sigemptyset(&set);
+    sigaddset(&set, SIGUSR1);
+    status = sigprocmask(SIG_BLOCK, &set, NULL);
+    ERROR_IF(sigprocmask, status, == -1);
+    sleep_ms(100);
+    status = sigprocmask(SIG_UNBLOCK, &set, NULL);
+    ERROR_IF(sigprocmask, status, == -1);
+    int overrun = timer_getoverrun(timer);
+    ERROR_IF(timer_getoverrun, overrun, == -1);
+    printf("overrun: %d\n", overrun > 5);
+
+    value.it_value.tv_sec = 0;
+    value.it_value.tv_nsec = 0;
+    status = timer_settime(timer, 0, &value, &old);
+    ERROR_IF(timer_settime, status, == -1);
+    printf("disarmed: %ld, old interval %ld\n", remaining_ms(timer), old.it_interval.tv_nsec);
+    status = timer_delete(timer);
+    ERROR_IF(timer_delete, status, == -1);
+
+    // A function called on another thread
+    event.sigev_notify = SIGEV_THREAD;
+    event.sigev_notify_function = notify;
+    event.sigev_value.sival_ptr = (void*)&calls;
+    status = timer_create(CLOCK_MONOTONIC, &event, &timer);
+    ERROR_IF(timer_create, status, == -1);
+    status = timer_settime(timer, 0, &periodic, NULL);
+    ERROR_IF(timer_settime, status, == -1);
+    while (calls < 3) {
+        sleep_ms(1);
+    }
+    status = timer_delete(timer);
+    ERROR_IF(timer_delete, status, == -1);
+    // A call that was already under way may still finish after the timer is gone
+    sleep_ms(20);
+    while (in_notify) {
+        sleep_ms(1);
+    }
+    int after = calls;
+    sleep_ms(20);
+    printf("calls: %d, value %d, stopped %d\n", calls >= 3, value_ok, calls == after);
+
+    // Errors
+    value.it_value.tv_nsec = 1000000000;
+    status = timer_create(CLOCK_MONOTONIC, NULL, &timer);
+    ERROR_IF(timer_create, status, == -1);
+    status = timer_settime(timer, 0, &value, NULL);
+    printf("invalid time: %d, %s\n", status, strerror(errno));
+    status = timer_settime(timer, 0, NULL, NULL);
+    printf("no time: %d, %s\n", status, strerror(errno));
+    status = timer_delete(timer);
+    ERROR_IF(timer_delete, status, == -1);
+
+    event.sigev_notify = 1234;
+    status = timer_create(CLOCK_MONOTONIC, &event, &timer);
+    printf("invalid notification: %d, %s\n", status, strerror(errno));
+}
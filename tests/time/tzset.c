@@ -0,0 +1,92 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+void set_tz(const char* tz) {
+    int status = setenv("TZ", tz, 1);
+    ERROR_IF(setenv, status, == -1);
+    tzset();
+    printf("%s: timezone %ld, daylight %d, tzname %s %s\n",
+        tz, timezone, daylight, tzname[0], tzname[1]);
+}
+
+void print(time_t t) {
+    struct tm tm;
+    struct tm* result = localtime_r(&t, &tm);
+    ERROR_IF(localtime_r, result, == NULL);
+
+    char out[64];
+    strftime(out, sizeof(out), "%Y-%m-%d %H:%M:%S %z %Z", &tm);
+    printf("  %ld: %s, isdst %d, gmtoff %ld, zone %s\n",
+        t, out, tm.tm_isdst, tm.tm_gmtoff, tm.tm_zone);
+}
+
+void make(int year, int mon, int mday, int hour, int min, int isdst) {
+    struct tm tm = {
+        .tm_year = year - 1900,
+        .tm_mon = mon - 1,
+        .tm_mday = mday,
+        .tm_hour = hour,
+        .tm_min = min,
+        .tm_isdst = isdst,
+    };
+    time_t t = mktime(&tm);
+    printf("  mktime %d-%02d-%02d %02d:%02d isdst %d: %ld, %d-%02d-%02d %02d:%02d, wday %d, yday %d, isdst %d\n",
+        year, mon, mday, hour, min, isdst, t,
+        tm.tm_year + 1900, tm.tm_mon + 1, tm.tm_mday, tm.tm_hour, tm.tm_min,
+        tm.tm_wday, tm.tm_yday, tm.tm_isdst);
+}
+
+int main(void) {
+    set_tz("EST5EDT,M3.2.0,M11.1.0");
+    print(1515000000);
+    print(1520751599); // Just before clocks go forward
+    print(1520751600);
+    print(1541311199); // Just before clocks go back
+    print(1541311200);
+    make(2018, 3, 14, 14, 0, -1);
+    make(2018, 3, 11, 2, 30, -1); // Skipped
+    make(2018, 11, 4, 1, 30, 0); // Repeated
+    make(2018, 11, 4, 1, 30, 1);
+    make(2018, 7, 15, 12, 0, 0); // Not standard time, so an hour later
+    make(2018, 14, 35, 25, 61, -1); // Out of range fields carry over
+
+    // Without rules, the ones of the United States apply
+    set_tz("XST6XDT");
+    print(1521050400);
+
+    set_tz("<+0330>-3:30");
+    print(0);
+
+    set_tz("AEST-10AEDT,M10.1.0,M4.1.0/3");
+    print(1515000000);
+    print(1530000000);
+
+    set_tz("XST3XDT,J60/-1,300/26");
+    print(952000000);
+    print(972000000);
+
+    set_tz("");
+    print(0);
+
+    // TZif files, see zoneinfo/test.zi
+    int status = setenv("TZDIR", "zoneinfo", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    time_t times[] = { -2240524800, 725846400, 741484800, 1514764800, 1530403200, 4102444800, 4118083200 };
+    const char* zones[] = { "Test/Zone", "Test/Fat", ":Test/V1" };
+    for (int i = 0; i < sizeof(zones) / sizeof(zones[0]); i++) {
+        set_tz(zones[i]);
+        for (int j = 0; j < sizeof(times) / sizeof(times[0]); j++) {
+            print(times[j]);
+        }
+    }
+    make(1993, 7, 1, 12, 0, -1);
+    make(2050, 7, 1, 12, 0, -1);
+
+    // Missing zones fall back to UTC
+    set_tz("Test/Missing");
+    print(0);
+}
@@ -0,0 +1,71 @@
+#include <errno.h>
+#include <locale.h>
+#include <stdio.h>
+#include <string.h>
+#include <uchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char *locale = setlocale(LC_ALL, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+
+    // Characters outside of the BMP come out as two halves of a surrogate pair
+    const char *in = "zß\U0001F34C";
+    size_t len = strlen(in) + 1;
+    mbstate_t state;
+    memset(&state, 0, sizeof state);
+    printf("mbrtoc16:");
+    while (len > 0) {
+        char16_t c16 = 0;
+        size_t result = mbrtoc16(&c16, in, len, &state);
+        if (result == (size_t) -3) {
+            printf(" %#x (from the state)", c16);
+            continue;
+        }
+        ERROR_IF(mbrtoc16, result, > len);
+        printf(" %#x (%zu bytes)", c16, result);
+        if (result == 0) {
+            break;
+        }
+        in += result;
+        len -= result;
+    }
+    printf("\n");
+
+    char16_t units[] = {u'z', 0xd83c, 0xdf4c, u'ß'};
+    char out[16];
+    size_t pos = 0;
+    memset(&state, 0, sizeof state);
+    printf("c16rtomb:");
+    for (size_t i = 0; i < sizeof units / sizeof units[0]; i++) {
+        size_t result = c16rtomb(out + pos, units[i], &state);
+        ERROR_IF(c16rtomb, result, == (size_t) -1);
+        printf(" %zu", result);
+        pos += result;
+    }
+    out[pos] = 0;
+    printf(" bytes: %s\n", out);
+
+    memset(&state, 0, sizeof state);
+    errno = 0;
+    size_t result = c16rtomb(out, 0xdf4c, &state);
+    printf("lone low surrogate: %s (%s)\n", result == (size_t) -1 ? "error" : "converted", strerror(errno));
+    c16rtomb(out, 0xd83c, &state);
+    result = c16rtomb(out, u'a', &state);
+    printf("high surrogate without a low one: %s\n", result == (size_t) -1 ? "error" : "converted");
+
+    memset(&state, 0, sizeof state);
+    char32_t c32 = 0;
+    result = mbrtoc32(&c32, "\xf0\x9f", 2, &state);
+    printf("mbrtoc32: %s", result == (size_t) -2 ? "incomplete" : "complete");
+    result = mbrtoc32(&c32, "\x8d\x8c", 2, &state);
+    printf(", then %zu bytes for %#x\n", result, (unsigned int) c32);
+
+    result = c32rtomb(out, U'水', &state);
+    out[result] = 0;
+    printf("c32rtomb: %zu bytes: %s\n", result, out);
+    errno = 0;
+    result = c32rtomb(out, 0x110000, &state);
+    printf("c32rtomb beyond U+10FFFF: %s (%s)\n", result == (size_t) -1 ? "error" : "converted", strerror(errno));
+}
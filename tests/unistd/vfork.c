@@ -0,0 +1,48 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+void wait_for(pid_t pid) {
+    int status;
+    pid_t waited = waitpid(pid, &status, 0);
+    ERROR_IF(waitpid, waited, == -1);
+    printf("child exited with %d\n", WEXITSTATUS(status));
+    fflush(stdout);
+}
+
+int main(void) {
+    // The child shares the memory of the parent, which waits until it exits
+    volatile int shared = 0;
+    pid_t pid = vfork();
+    ERROR_IF(vfork, pid, == -1);
+    if (pid == 0) {
+        shared = 42;
+        _exit(3);
+    }
+    printf("shared: %d\n", shared);
+    wait_for(pid);
+
+    // Or until it executes another program
+    pid = vfork();
+    ERROR_IF(vfork, pid, == -1);
+    if (pid == 0) {
+        execlp("sh", "sh", "-c", "echo $0 $1", "execlp", "works", (char *) NULL);
+        _exit(127);
+    }
+    wait_for(pid);
+
+    char *envp[] = { "GREETING=execle works", NULL };
+    pid = vfork();
+    ERROR_IF(vfork, pid, == -1);
+    if (pid == 0) {
+        execle("/bin/sh", "sh", "-c", "echo $GREETING; exit 5", (char *) NULL, envp);
+        _exit(127);
+    }
+    wait_for(pid);
+
+    int status = execl("/bin/sh", "sh", "-c", "echo execl works", (char *) NULL);
+    ERROR_IF(execl, status, == -1);
+}
@@ -1,3 +1,4 @@
+#include <locale.h>
 #include <stdio.h>
 #include <string.h>
 #include <wchar.h>
@@ -5,6 +6,7 @@
 #include "test_helpers.h"
 
 int main(void) {
+    setlocale(LC_ALL, "C.UTF-8");
     mbstate_t state;
     memset(&state, 0, sizeof state);
     char in[] = u8"z\u00df\u6c34\U0001F34C"; // or u8"zß水🍌"
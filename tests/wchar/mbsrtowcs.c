@@ -1,3 +1,4 @@
+#include <locale.h>
 #include <stdio.h>
 #include <string.h>
 #include <wchar.h>
@@ -20,6 +21,7 @@ void print_as_wide(const char* mbstr)
 }
 
 int main(void) {
+    setlocale(LC_ALL, "C.UTF-8");
     const char* mbstr = u8"z\u00df\u6c34\U0001f34c"; // or u8"zß水🍌"
     print_as_wide(mbstr);
 }
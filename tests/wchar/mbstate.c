@@ -0,0 +1,117 @@
+#include <errno.h>
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+static void print_result(size_t result, wchar_t wc) {
+    if (result == (size_t) -1) {
+        printf("invalid (%s)", strerror(errno));
+    } else if (result == (size_t) -2) {
+        printf("incomplete");
+    } else {
+        printf("%zu byte(s), U+%04X", result, (unsigned int) wc);
+    }
+}
+
+static void convert(const char *s, size_t n, mbstate_t *state) {
+    wchar_t wc = 0;
+    size_t result = mbrtowc(&wc, s, n, state);
+    print_result(result, wc);
+}
+
+static void decode(const char *name, const char *s, size_t len) {
+    mbstate_t state;
+    memset(&state, 0, sizeof state);
+    printf("%s:", name);
+    for (size_t i = 0; i < len; i++) {
+        wchar_t wc = 0;
+        size_t result = mbrtowc(&wc, s + i, 1, &state);
+        printf(" [");
+        print_result(result, wc);
+        printf("]");
+        if (result == (size_t) -1) {
+            break;
+        }
+    }
+    printf("\n");
+}
+
+int main(void) {
+    char *locale = setlocale(LC_ALL, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    printf("MB_CUR_MAX in C.UTF-8: %zu\n", MB_CUR_MAX);
+
+    // Characters can be split anywhere and fed in one byte at a time
+    decode("one byte at a time", "zß水\U0001F34C", 10);
+    decode("overlong", "\xc0\x80", 2);
+    decode("overlong three bytes", "\xe0\x80\x80", 3);
+    decode("surrogate", "\xed\xa0\x80", 3);
+    decode("beyond U+10FFFF", "\xf4\x90\x80\x80", 4);
+    decode("stray continuation", "\x80", 1);
+
+    mbstate_t state;
+    memset(&state, 0, sizeof state);
+    wchar_t wc = 0;
+    printf("two bytes then two more: ");
+    convert("\xf0\x9f", 2, &state);
+    printf(", initial state %d, ", mbsinit(&state));
+    convert("\x8d\x8cz", 3, &state);
+    printf(", initial state %d\n", mbsinit(&state));
+
+    printf("no bytes: ");
+    convert("z", 0, &state);
+    printf("\n");
+
+    mbrtowc(&wc, "\xe6", 1, &state);
+    printf("resetting in the middle of a character: ");
+    convert(NULL, 0, &state);
+    printf("\n");
+
+    // mbrlen has its own state when it isn't given one
+    size_t first = mbrlen("\xe6\xb0", 2, NULL);
+    size_t second = mbrlen("\xb4", 1, NULL);
+    printf("mbrlen: %s then %zu\n", first == (size_t) -2 ? "incomplete" : "complete", second);
+
+    const wchar_t *src = L"zß水\U0001F34C";
+    printf("wcsrtombs length: %zu\n", wcsrtombs(NULL, &src, 0, NULL));
+    char buf[16];
+    memset(buf, 'x', sizeof buf);
+    size_t len = wcsrtombs(buf, &src, 5, NULL);
+    printf("wcsrtombs into 5 bytes: %zu, %zu character(s) left\n", len, wcslen(src));
+    len = wcsrtombs(buf + len, &src, sizeof buf - len, NULL);
+    printf("then %zu, source %s: %s\n", len, src == NULL ? "finished" : "not finished", buf);
+    wchar_t bad[] = {L'a', 0xd800, L'b', 0};
+    src = bad;
+    errno = 0;
+    len = wcsrtombs(buf, &src, sizeof buf, NULL);
+    printf("unpaired surrogate: %s, stopped at %td (%s)\n", len == (size_t) -1 ? "error" : "converted", src - bad, strerror(errno));
+    printf("wcstombs: %zu\n", wcstombs(buf, L"été", sizeof buf));
+    printf("mbtowc on an incomplete character: %d\n", mbtowc(&wc, "\xc3", 1));
+    printf("wctob: %d %d\n", wctob(L'A'), wctob(L'é'));
+
+    // In the C locale every byte is a character, and the ones that aren't ASCII get wide
+    // characters of their own
+    locale = setlocale(LC_CTYPE, "C");
+    ERROR_IF(setlocale, locale, == NULL);
+    printf("MB_CUR_MAX in C: %zu\n", MB_CUR_MAX);
+    printf("C: ");
+    convert("\xe9", 1, &state);
+    printf(", back to byte %#x, ", wctob(0xdfe9));
+    errno = 0;
+    printf("%s from U+00E9 (%s)\n", wcrtomb(buf, L'é', &state) == (size_t) -1 ? "no byte" : "a byte", strerror(errno));
+
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+    locale = setlocale(LC_CTYPE, "de_DE.ISO-8859-1");
+    ERROR_IF(setlocale, locale, == NULL);
+    printf("MB_CUR_MAX in de_DE.ISO-8859-1: %zu\n", MB_CUR_MAX);
+    printf("ISO-8859-1: ");
+    convert("\xe9", 1, &state);
+    printf(", back to byte %#x, ", wctob(L'é'));
+    errno = 0;
+    printf("%s from U+20AC (%s)\n", wcrtomb(buf, L'€', &state) == (size_t) -1 ? "no byte" : "a byte", strerror(errno));
+}
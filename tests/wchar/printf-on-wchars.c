@@ -1,8 +1,10 @@
+#include <locale.h>
 #include <stdio.h>
 #include <sys/types.h>
 #include <wchar.h>
 
 int main() {
+    setlocale(LC_ALL, "C.UTF-8");
     wint_t a = L'1';
     wint_t b = L'2';
     wint_t c = L'a';
@@ -1,3 +1,4 @@
+#include <locale.h>
 #include <string.h>
 #include <stdio.h>
 #include <wchar.h>
@@ -6,6 +7,7 @@
 #include "test_helpers.h"
 
 int main(void) {
+    setlocale(LC_ALL, "C.UTF-8");
     wchar_t *wcs = L"zß水🍌";
 
     for (int i = 0; wcs[i] != L'\0'; i++) {
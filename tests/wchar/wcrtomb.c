@@ -1,3 +1,4 @@
+#include <locale.h>
 #include <string.h>  
 #include <stdio.h>  
 #include <wchar.h> 
@@ -6,6 +7,7 @@
 #include "test_helpers.h"
 
 int main(void) {
+    setlocale(LC_ALL, "C.UTF-8");
     mbstate_t state;
     memset(&state, 0, sizeof state);
     wchar_t in[] = L"zß水🍌"; // or "z\u00df\u6c34\U0001F34C"
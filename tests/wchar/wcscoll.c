@@ -0,0 +1,59 @@
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+static int sign(int n) {
+    return (n > 0) - (n < 0);
+}
+
+void compare(const wchar_t *a, const wchar_t *b) {
+    wchar_t ka[128], kb[128];
+    size_t la = wcsxfrm(ka, a, 128);
+    size_t lb = wcsxfrm(kb, b, 128);
+    if (la >= 128 || lb >= 128) {
+        puts("wcsxfrm: key too long");
+        exit(EXIT_FAILURE);
+    }
+    printf("%ls %ls: wcscoll %d, wcsxfrm %d\n", a, b, sign(wcscoll(a, b)), sign(wcscmp(ka, kb)));
+}
+
+int main(void) {
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    char *locale = setlocale(LC_ALL, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    compare(L"Zebra", L"apple");
+    compare(L"äpple", L"zebra");
+    wchar_t key[8];
+    size_t len = wcsxfrm(key, L"abc", 8);
+    printf("wcsxfrm: %zu %ls\n", len, key);
+
+    locale = setlocale(LC_ALL, "de_DE.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    compare(L"Zebra", L"apple");
+    compare(L"äpple", L"zebra");
+    compare(L"äpple", L"apple");
+    compare(L"Apple", L"apple");
+    compare(L"co-op", L"coop");
+    compare(L"coop", L"co-op");
+    compare(L"abc", L"abc");
+    compare(L"", L"a");
+    printf("wcsxfrm(NULL, 0) = wcsxfrm(key, 8): %d\n", wcsxfrm(NULL, L"abc", 0) == wcsxfrm(key, L"abc", 8));
+
+    locale = setlocale(LC_ALL, "sv_SE.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    compare(L"äpple", L"zebra");
+    compare(L"Ångström", L"Ärlig");
+    compare(L"yxa", L"Über");
+    compare(L"Över", L"Zürich");
+
+    locale_t c = newlocale(LC_ALL_MASK, "C", (locale_t) 0);
+    ERROR_IF(newlocale, c, == (locale_t) 0);
+    printf("wcscoll_l(\"äpple\", \"zebra\", C) = %d\n", sign(wcscoll_l(L"äpple", L"zebra", c)));
+    freelocale(c);
+}
@@ -0,0 +1,48 @@
+#include <locale.h>
+#include <stdio.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char *locale = setlocale(LC_ALL, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+
+    struct {
+        wchar_t wc;
+        const char *name;
+    } chars[] = {
+        {L'a', "LATIN SMALL LETTER A"},
+        {0, "NULL"},
+        {L'\n', "LINE FEED"},
+        {0x7f, "DELETE"},
+        {0x9f, "APPLICATION PROGRAM COMMAND"},
+        {0xa0, "NO-BREAK SPACE"},
+        {0xad, "SOFT HYPHEN"},
+        {0xe9, "LATIN SMALL LETTER E WITH ACUTE"},
+        {0x301, "COMBINING ACUTE ACCENT"},
+        {0x600, "ARABIC NUMBER SIGN"},
+        {0x1160, "HANGUL JUNGSEONG FILLER"},
+        {0x200b, "ZERO WIDTH SPACE"},
+        {0x2764, "HEAVY BLACK HEART"},
+        {0x231a, "WATCH"},
+        {0x6c34, "CJK UNIFIED IDEOGRAPH-6C34"},
+        {0xac00, "HANGUL SYLLABLE GA"},
+        {0xfe0f, "VARIATION SELECTOR-16"},
+        {0xff21, "FULLWIDTH LATIN CAPITAL LETTER A"},
+        {0xffff, "U+FFFF"},
+        {0x1f34c, "BANANA"},
+        {0x1f1e6, "REGIONAL INDICATOR SYMBOL LETTER A"},
+        {0x20000, "CJK UNIFIED IDEOGRAPH-20000"},
+        {0xe0001, "LANGUAGE TAG"},
+        {0xe0100, "VARIATION SELECTOR-17"},
+    };
+    for (size_t i = 0; i < sizeof chars / sizeof chars[0]; i++) {
+        printf("%s: %d\n", chars[i].name, wcwidth(chars[i].wc));
+    }
+
+    const wchar_t *s = L"水á\U0001F34C";
+    printf("wcswidth of the whole string: %d\n", wcswidth(s, 10));
+    printf("wcswidth of the first two characters: %d\n", wcswidth(s, 2));
+    printf("wcswidth with a control character: %d\n", wcswidth(L"a\tb", 3));
+}
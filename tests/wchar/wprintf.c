@@ -0,0 +1,70 @@
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char *locale = setlocale(LC_ALL, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+
+    int n = 0;
+    int ret = wprintf(L"%d %5.2f %-6ls| %s %c %lc %x%n\n", -12, 3.14159, L"wide", "narrow", 'c', L'€', 255, &n);
+    ERROR_IF(wprintf, ret, < 0);
+    wprintf(L"wprintf returned %d, %%n gave %d\n", ret, n);
+
+    // Widths and precisions count characters, not bytes
+    wchar_t buf[64];
+    ret = swprintf(buf, 64, L"[%5ls] [%-4lc] [%.2ls] [%5s] [%.2s]%n", L"héé", L'€', L"€uro", "né", "日本語", &n);
+    wprintf(L"swprintf returned %d, %%n gave %d: %ls\n", ret, n, buf);
+
+    // Running out of room is an error, but what fits is kept
+    ret = swprintf(buf, 6, L"%ls", L"truncated");
+    wprintf(L"truncated swprintf returned %d\n", ret);
+    ret = swprintf(buf, 6, L"%d", 12345);
+    wprintf(L"exact fit returned %d: %ls\n", ret, buf);
+
+    // A file written in wide characters holds their multibyte encoding
+    FILE *f = tmpfile();
+    ERROR_IF(tmpfile, f, == NULL);
+    ret = fwprintf(f, L"%ls %d\nsecond line\n", L"Grüße", 2);
+    wprintf(L"fwprintf returned %d, orientation %d\n", ret, fwide(f, 0));
+    wprintf(L"fprintf on a wide stream returned %d\n", fprintf(f, "bytes"));
+    ret = fputws(L"ünïcode\n", f);
+    ERROR_IF(fputws, ret, < 0);
+
+    rewind(f);
+    wchar_t line[32];
+    while (fgetws(line, 32, f) != NULL) {
+        wprintf(L"line of %zu characters: %ls", wcslen(line), line);
+    }
+
+    // Characters that are pushed back are read again, in reverse order
+    rewind(f);
+    wint_t c = fgetwc(f);
+    wprintf(L"first character %lc\n", (wchar_t) c);
+    ungetwc(L'ß', f);
+    ungetwc(L'€', f);
+    wprintf(L"read back %lc", (wchar_t) fgetwc(f));
+    wprintf(L"%lc", (wchar_t) fgetwc(f));
+    wprintf(L"%lc\n", (wchar_t) fgetwc(f));
+    fclose(f);
+
+    // Reading a byte stream in wide characters fails
+    f = tmpfile();
+    ERROR_IF(tmpfile, f, == NULL);
+    fputs("bytes\n", f);
+    wprintf(L"fputwc on a byte stream returned %s\n", fputwc(L'x', f) == WEOF ? "WEOF" : "a character");
+    wprintf(L"fwprintf on a byte stream returned %d\n", fwprintf(f, L"x"));
+    fclose(f);
+
+    wchar_t *stream_buf;
+    size_t stream_size;
+    f = open_wmemstream(&stream_buf, &stream_size);
+    ERROR_IF(open_wmemstream, f, == NULL);
+    fwprintf(f, L"%ls=%.1f", L"π", 3.14159);
+    fclose(f);
+    wprintf(L"open_wmemstream got %zu characters: %ls\n", stream_size, stream_buf);
+    free(stream_buf);
+}
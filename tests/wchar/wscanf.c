@@ -0,0 +1,50 @@
+#include <locale.h>
+#include <stdio.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char *locale = setlocale(LC_ALL, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+
+    int i = 0, n = 0;
+    double d = 0;
+    wchar_t word[16] = {0};
+    wchar_t c = 0;
+    char byte = 0;
+    int ret = swscanf(L"42 héllo 3.5 €x rest", L"%d %ls %lf %lc%c%n", &i, word, &d, &c, &byte, &n);
+    printf("swscanf returned %d: %d %ls %g %lc %c, %%n gave %d\n", ret, i, word, d, c, byte, n);
+
+    // Narrow conversions store the multibyte encoding of what they read
+    char narrow[16] = {0};
+    char chars[8] = {0};
+    ret = swscanf(L"日本 語x", L"%s %2c", narrow, chars);
+    printf("narrow conversions returned %d: %s %s\n", ret, narrow, chars);
+
+    // Scansets and widths count wide characters
+    wchar_t greek[8] = {0};
+    wchar_t rest[8] = {0};
+    ret = swscanf(L"αβγδεζ!", L"%3l[α-ω]%l[^!]", greek, rest);
+    printf("scansets returned %d: %ls %ls\n", ret, greek, rest);
+
+    ret = swscanf(L"", L"%d", &i);
+    printf("empty input returned %d\n", ret);
+
+    FILE *f = tmpfile();
+    ERROR_IF(tmpfile, f, == NULL);
+    ret = fputws(L"7 naïve ∑\n", f);
+    ERROR_IF(fputws, ret, < 0);
+    rewind(f);
+
+    ret = fwscanf(f, L"%d %ls", &i, word);
+    printf("fwscanf returned %d: %d %ls\n", ret, i, word);
+    wint_t wc = fgetwc(f);
+    printf("then %d\n", (int) wc);
+    ungetwc(L'∆', f);
+    ret = fwscanf(f, L"%lc %lc", &c, word);
+    printf("after ungetwc fwscanf returned %d: %lc %lc\n", ret, c, word[0]);
+    ret = fwscanf(f, L" %lc", &c);
+    printf("at the end fwscanf returned %d\n", ret);
+    fclose(f);
+}
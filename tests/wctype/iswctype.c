@@ -0,0 +1,104 @@
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <wctype.h>
+
+#include "test_helpers.h"
+
+static const char *names[] = {
+    "alnum", "alpha", "blank", "cntrl", "digit", "graph",
+    "lower", "print", "punct", "space", "upper", "xdigit",
+};
+#define NAMES (sizeof(names) / sizeof(names[0]))
+
+static const wint_t chars[] = {
+    L'a', L'Z', L'5', L' ', L'\t', L'!', 0x7f,
+    0x00a0, // No-break space
+    0x00df, // Sharp s
+    0x00e9, // e with acute
+    0x0391, // Greek capital alpha
+    0x03c2, // Greek final sigma
+    0x0416, // Cyrillic capital zhe
+    0x0451, // Cyrillic small io
+    0x0130, // Latin capital I with dot above
+    0x0131, // Latin small dotless i
+    0x01c5, // Capital D with small z with caron, which is title case
+    0x0301, // Combining acute accent
+    0x0661, // Arabic-Indic digit one
+    0x1680, // Ogham space mark
+    0x2028, // Line separator
+    0x3000, // Ideographic space
+    0x4e2d, // CJK ideograph
+    0x20000, // CJK ideograph outside of the first plane
+    0xe000, // Private use
+    0x0378, // Unassigned
+};
+#define CHARS (sizeof(chars) / sizeof(chars[0]))
+
+void classify(const char *locale) {
+    printf("%s:\n", locale);
+    for (size_t i = 0; i < CHARS; i++) {
+        printf("  U+%04X", (unsigned) chars[i]);
+        for (size_t j = 0; j < NAMES; j++) {
+            if (iswctype(chars[i], wctype(names[j]))) {
+                printf(" %s", names[j]);
+            }
+        }
+        printf("\n");
+    }
+}
+
+int main(void) {
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    // The C locale only has ASCII
+    classify("C");
+
+    char *locale = setlocale(LC_CTYPE, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    classify("C.UTF-8");
+
+    // The isw* functions are the same as the classes of the same name
+    printf("iswalpha(U+03B1) = %d, iswupper(U+0416) = %d, iswlower(U+0451) = %d\n",
+           !!iswalpha(0x3b1), !!iswupper(0x416), !!iswlower(0x451));
+    printf("iswspace(U+3000) = %d, iswpunct(U+00BF) = %d, iswdigit(U+0661) = %d\n",
+           !!iswspace(0x3000), !!iswpunct(0xbf), !!iswdigit(0x661));
+    printf("iswctype(L'a', 0) = %d, wctype(\"front\") = %d\n",
+           iswctype(L'a', 0), wctype("front") != 0);
+
+    // Locales can define classes of their own
+    locale = setlocale(LC_CTYPE, "tr_TR.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    wctype_t front = wctype("front");
+    wctype_t back = wctype("back");
+    ERROR_IF(wctype, front, == 0);
+    ERROR_IF(wctype, back, == 0);
+    const wchar_t *words = L"gözlük kitaplık";
+    printf("%ls:", words);
+    for (const wchar_t *c = words; *c; c++) {
+        if (iswctype(*c, front)) {
+            printf(" %lc front", *c);
+        } else if (iswctype(*c, back)) {
+            printf(" %lc back", *c);
+        }
+    }
+    printf("\n");
+
+    // The _l variants use the given locale rather than the current one
+    locale_t c = newlocale(LC_CTYPE_MASK, "C", (locale_t) 0);
+    ERROR_IF(newlocale, c, == (locale_t) 0);
+    locale_t tr = newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", (locale_t) 0);
+    ERROR_IF(newlocale, tr, == (locale_t) 0);
+    locale = setlocale(LC_CTYPE, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    printf("iswalpha_l(U+03B1, C) = %d, iswalpha(U+03B1) = %d\n",
+           !!iswalpha_l(0x3b1, c), !!iswalpha(0x3b1));
+    printf("iswupper_l(U+0416, C) = %d, iswspace_l(U+3000, C) = %d\n",
+           !!iswupper_l(0x416, c), !!iswspace_l(0x3000, c));
+    printf("wctype_l(\"front\", C) = %d, iswctype_l(L'e', wctype_l(\"front\", tr), tr) = %d\n",
+           wctype_l("front", c) != 0, !!iswctype_l(L'e', wctype_l("front", tr), tr));
+    freelocale(tr);
+    freelocale(c);
+}
@@ -0,0 +1,92 @@
+#include <ctype.h>
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <wctype.h>
+
+#include "test_helpers.h"
+
+void convert(const wchar_t *s, wctrans_t trans) {
+    for (; *s; s++) {
+        printf("%lc", towctrans(*s, trans));
+    }
+}
+
+void cases(const wchar_t *s) {
+    wctrans_t toupper = wctrans("toupper");
+    wctrans_t tolower = wctrans("tolower");
+    wctrans_t totitle = wctrans("totitle");
+    ERROR_IF(wctrans, toupper, == 0);
+    ERROR_IF(wctrans, tolower, == 0);
+    ERROR_IF(wctrans, totitle, == 0);
+
+    printf("%ls: ", s);
+    convert(s, toupper);
+    printf(" ");
+    convert(s, tolower);
+    // Title case is for the first letter of a word, which the rest follow in lower case
+    printf(" %lc", towctrans(s[0], totitle));
+    convert(s + 1, tolower);
+    printf("\n");
+}
+
+int main(void) {
+    // The locales used by the tests live in tests/locales
+    int status = setenv("LOCPATH", "locales", 1);
+    ERROR_IF(setenv, status, == -1);
+
+    // The C locale only maps ASCII
+    printf("C: towupper(L'a') = %#x, towupper(U+00E9) = %#x, towlower(U+0416) = %#x\n",
+           (unsigned) towupper(L'a'), (unsigned) towupper(0xe9), (unsigned) towlower(0x416));
+
+    char *locale = setlocale(LC_CTYPE, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    cases(L"Οδυσσεύς");
+    cases(L"Ἀθῆναι");
+    cases(L"Москва");
+    cases(L"ёлка");
+    cases(L"İstanbul");
+    cases(L"ılık");
+    cases(L"ǆungla");
+    cases(L"ǅemal");
+    cases(L"Straße");
+    cases(L"თბილისი");
+    // Mappings that aren't known give the character back
+    printf("wctrans(\"toascii\") = %d, towctrans(L'a', 0) = %lc\n",
+           wctrans("toascii") != 0, towctrans(L'a', 0));
+
+    // Turkish has a dotted and a dotless i in both cases
+    locale = setlocale(LC_CTYPE, "tr_TR.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    cases(L"istanbul");
+    cases(L"ılık");
+    cases(L"İZMİR");
+    cases(L"IĞDIR");
+    // Bytes whose other case is not a single byte keep their case
+    printf("toupper('i') = %c, tolower('I') = %c, toupper('a') = %c\n",
+           toupper('i'), tolower('I'), toupper('a'));
+
+    // Locales can define mappings of their own
+    wctrans_t toascii = wctrans("toascii");
+    ERROR_IF(wctrans, toascii, == 0);
+    const wchar_t *words = L"Işık Çağrı Öğüş";
+    printf("%ls: ", words);
+    convert(words, toascii);
+    printf("\n");
+
+    // The _l variants use the given locale rather than the current one
+    locale_t c = newlocale(LC_CTYPE_MASK, "C", (locale_t) 0);
+    ERROR_IF(newlocale, c, == (locale_t) 0);
+    locale_t tr = newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", (locale_t) 0);
+    ERROR_IF(newlocale, tr, == (locale_t) 0);
+    locale = setlocale(LC_CTYPE, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    printf("towupper(L'i') = %lc, towupper_l(L'i', tr) = %lc, towlower_l(L'I', tr) = %lc\n",
+           towupper(L'i'), towupper_l(L'i', tr), towlower_l(L'I', tr));
+    printf("towupper_l(U+00E9, C) = %#x, towlower_l(U+0416, C) = %#x\n",
+           (unsigned) towupper_l(0xe9, c), (unsigned) towlower_l(0x416, c));
+    printf("towctrans_l(U+00E7, wctrans_l(\"toascii\", tr), tr) = %lc, wctrans_l(\"toascii\", C) = %d\n",
+           towctrans_l(0xe7, wctrans_l("toascii", tr), tr), wctrans_l("toascii", c) != 0);
+    freelocale(tr);
+    freelocale(c);
+}